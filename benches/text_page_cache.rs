@@ -0,0 +1,73 @@
+// Measures how many times Pdfium's text page is loaded when several text-consuming functions
+// are called on each page of a document. Run this benchmark with:
+
+// cargo bench --bench text_page_cache --features test_support
+
+// The benchmark binds to MockPdfiumBindings rather than a real Pdfium library, so that
+// every call to FPDFText_LoadPage() can be counted. Timings reflect pdfium-render's own
+// overhead only.
+
+use pdfium_render::prelude::*;
+use std::os::raw::c_int;
+use std::time::Instant;
+
+const PAGE_COUNT: c_int = 100;
+
+const ITERATIONS: usize = 100;
+
+fn main() -> Result<(), PdfiumError> {
+    let mock = MockPdfiumBindings::new();
+
+    mock.allow_library_lifecycle()
+        .allow_document_loading(PAGE_COUNT)
+        .allow_text_extraction(100);
+
+    let pdfium = Pdfium::new(Box::new(mock.clone()));
+
+    let document = pdfium.load_pdf_from_byte_slice(b"%PDF-1.7", None)?;
+
+    let mut text_calls = 0;
+
+    let start = Instant::now();
+
+    for _ in 0..ITERATIONS {
+        for page in document.pages().iter() {
+            // Each of these retrieves the page's text. Before text pages were cached,
+            // each call loaded the text page afresh.
+
+            let mut chars = 0;
+
+            chars += page.text()?.len() as usize;
+            chars += page.text()?.chars().len();
+
+            if !page.text()?.is_empty() {
+                chars += page.text()?.chars().iter().count();
+            }
+
+            assert_eq!(chars, 300);
+
+            text_calls += 4;
+        }
+    }
+
+    let elapsed = start.elapsed();
+
+    let loads = mock.call_count("FPDFText_LoadPage");
+
+    let pages = PAGE_COUNT as usize * ITERATIONS;
+
+    println!(
+        "{} pages, {} calls to PdfPage::text(), {} calls to FPDFText_LoadPage() in {:?}",
+        pages, text_calls, loads, elapsed
+    );
+    println!(
+        "{} calls to FPDFText_LoadPage() per page, down from {}",
+        loads / pages,
+        text_calls / pages
+    );
+
+    assert_eq!(loads, pages);
+    assert_eq!(mock.call_count("FPDFText_ClosePage"), pages);
+
+    Ok(())
+}
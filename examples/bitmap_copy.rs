@@ -0,0 +1,84 @@
+use pdfium_render::prelude::*;
+use std::time::{Duration, Instant};
+
+fn main() -> Result<(), PdfiumError> {
+    // For general comments about pdfium-render and binding to Pdfium, see export.rs.
+
+    let pdfium = Pdfium::default();
+
+    let document = pdfium.load_pdf_from_file("test/export-test.pdf", None)?;
+
+    let page = document.pages().first()?;
+
+    // Render the page at the pixel size of an A4 page at 300 DPI.
+
+    let bitmap = page.render_with_config(
+        &PdfRenderConfig::new()
+            .set_target_width(2480)
+            .set_maximum_height(3508),
+    )?;
+
+    println!(
+        "Rendered {} x {} pixel bitmap with a stride of {} bytes ({} bytes in total)",
+        bitmap.width(),
+        bitmap.height(),
+        bitmap.stride(),
+        bitmap.stride() * bitmap.height() as usize
+    );
+
+    const ITERATIONS: u32 = 20;
+
+    // Copying the bitmap buffer into a Vec and then converting the copy into RGBA
+    // allocates and copies the pixel data twice...
+
+    let two_copies = time(ITERATIONS, || {
+        bitmap
+            .as_raw_bytes()
+            .chunks_exact(4)
+            .flat_map(|channels| [channels[2], channels[1], channels[0], channels[3]])
+            .collect::<Vec<_>>()
+            .len()
+    });
+
+    // ... whereas PdfBitmap::as_rgba_bytes() converts the pixel data while copying it
+    // out of the bitmap buffer, allocating and copying only once ...
+
+    let one_copy = time(ITERATIONS, || bitmap.as_rgba_bytes().len());
+
+    // ... and PdfBitmap::as_raw_slice() gives direct access to the bitmap buffer, allowing
+    // pixel data to be copied straight into a caller's own drawing surface without any
+    // intermediate allocation at all. Each scanline is PdfBitmap::stride() bytes long.
+
+    let mut surface = vec![0; bitmap.width() as usize * bitmap.height() as usize * 4];
+
+    let zero_copies = time(ITERATIONS, || {
+        let row_length = bitmap.width() as usize * 4;
+
+        for (source, destination) in bitmap
+            .as_raw_slice()
+            .chunks_exact(bitmap.stride())
+            .zip(surface.chunks_exact_mut(row_length))
+        {
+            destination.copy_from_slice(&source[..row_length]);
+        }
+
+        surface.len()
+    });
+
+    println!("as_raw_bytes() then convert: {:?} per page", two_copies);
+    println!("as_rgba_bytes():             {:?} per page", one_copy);
+    println!("as_raw_slice() blit:         {:?} per page", zero_copies);
+
+    Ok(())
+}
+
+/// Returns the average time taken to run the given function over the given number of iterations.
+fn time(iterations: u32, mut f: impl FnMut() -> usize) -> Duration {
+    let start = Instant::now();
+
+    for _ in 0..iterations {
+        assert!(f() > 0);
+    }
+
+    start.elapsed() / iterations
+}
@@ -0,0 +1,72 @@
+use image_025::ImageFormat;
+use pdfium_render::prelude::*;
+
+fn main() -> Result<(), PdfiumError> {
+    // For general comments about pdfium-render and binding to Pdfium, see export.rs.
+
+    let pdfium = Pdfium::default();
+
+    let path = std::env::args()
+        .nth(1)
+        .unwrap_or_else(|| "test/export-test.pdf".to_owned());
+
+    let document = pdfium.load_pdf_from_file(&path, None)?;
+
+    // Builds a contact sheet showing thumbnails of up to the first twelve pages of
+    // a document in a grid four thumbnails wide and three thumbnails high. Every page
+    // is rendered directly into a single shared bitmap, so no intermediate bitmaps
+    // or image conversions are needed.
+
+    const COLUMNS: Pixels = 4;
+    const ROWS: Pixels = 3;
+    const CELL_WIDTH: Pixels = 300;
+    const CELL_HEIGHT: Pixels = 400;
+    const MARGIN: Pixels = 20;
+
+    let mut sheet = PdfBitmap::create_empty(
+        COLUMNS * (CELL_WIDTH + MARGIN) + MARGIN,
+        ROWS * (CELL_HEIGHT + MARGIN) + MARGIN,
+        PdfBitmapFormat::BGRA,
+        pdfium.bindings(),
+    )?;
+
+    // Fill the sheet with a gray background, so the margins between cells are visible.
+
+    sheet.fill_rect(
+        0,
+        0,
+        sheet.width(),
+        sheet.height(),
+        PdfColor::new(200, 200, 200, 255),
+    )?;
+
+    // Each page is scaled to fit its cell and centered within it. The whole cell is cleared
+    // to white before the page is rendered. The bitmap format set in the render configuration
+    // must match the format of the shared bitmap.
+
+    let config = PdfRenderConfig::new()
+        .set_format(PdfBitmapFormat::BGRA)
+        .set_clear_color(PdfColor::WHITE);
+
+    for (index, page) in document
+        .pages()
+        .iter()
+        .take((COLUMNS * ROWS) as usize)
+        .enumerate()
+    {
+        let index = index as Pixels;
+
+        let x = MARGIN + (index % COLUMNS) * (CELL_WIDTH + MARGIN);
+
+        let y = MARGIN + (index / COLUMNS) * (CELL_HEIGHT + MARGIN);
+
+        page.render_into_bitmap_at(&mut sheet, x, y, CELL_WIDTH, CELL_HEIGHT, &config)?;
+    }
+
+    sheet
+        .as_image()
+        .save_with_format("contact-sheet-test.png", ImageFormat::Png)
+        .map_err(|_| PdfiumError::ImageError)?;
+
+    Ok(())
+}
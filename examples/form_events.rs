@@ -0,0 +1,80 @@
+use pdfium_render::prelude::*;
+
+pub fn main() -> Result<(), PdfiumError> {
+    // For general comments about pdfium-render and binding to Pdfium, see export.rs.
+    // For an example of filling form fields without simulating user input, see form_fields.rs.
+
+    let pdfium = Pdfium::default();
+
+    let document = pdfium.load_pdf_from_file("test/form-test.pdf", None)?;
+
+    let form = document.form().ok_or(PdfiumError::UnknownFormType)?;
+
+    let page = document.pages().first()?;
+
+    // An interactive viewer renders each page to a bitmap and translates pointer positions
+    // in the bitmap back to positions on the page. Here, we pretend the user clicked in the
+    // center of the first text field on the page.
+
+    let render_config = PdfRenderConfig::new().set_target_width(1000);
+
+    let (index, bounds) = page
+        .annotations()
+        .iter()
+        .enumerate()
+        .find_map(|(index, annotation)| match annotation.as_form_field() {
+            Some(PdfFormField::Text(_)) => annotation.bounds().ok().map(|bounds| (index, bounds)),
+            _ => None,
+        })
+        .ok_or(PdfiumError::FormFieldTypeDoesNotSupportOperation)?;
+
+    let (pixel_x, pixel_y) = render_config.page_to_pixel(
+        &page,
+        (bounds.left() + bounds.right()) / 2.0,
+        (bounds.top() + bounds.bottom()) / 2.0,
+    )?;
+
+    println!("User clicked at pixel ({}, {})", pixel_x, pixel_y);
+
+    let (x, y) = render_config.pixel_to_page(&page, pixel_x, pixel_y)?;
+
+    // Forward the click and some keystrokes to Pdfium's form fill module, exactly as
+    // a PDF viewer would.
+
+    let handler = form.event_handler();
+
+    let modifiers = PdfFormEventModifiers::empty();
+
+    handler.on_mouse_down(&page, x, y, modifiers);
+    handler.on_mouse_up(&page, x, y, modifiers);
+
+    for char in "Hello from pdfium-render".chars() {
+        handler.on_char(&page, char, modifiers);
+    }
+
+    // Pdfium tells us which parts of the page need to be re-rendered to show the new text.
+
+    for rect in handler.take_invalidated_rects(&page) {
+        println!("Re-render page area {:?}", rect);
+    }
+
+    // Removing the focus from the field commits the typed text to the field's value.
+
+    handler.kill_focus()?;
+
+    println!(
+        "Form has unsaved changes: {}",
+        handler.has_unsaved_changes()
+    );
+
+    let annotation = page.annotations().get(index as PdfPageAnnotationIndex)?;
+
+    if let Some(field) = annotation
+        .as_form_field()
+        .and_then(|field| field.as_text_field())
+    {
+        println!("Text field now has value: {:?}", field.value());
+    }
+
+    Ok(())
+}
@@ -0,0 +1,28 @@
+use pdfium_render::prelude::*;
+use serde_json::json;
+
+pub fn main() -> Result<(), PdfiumError> {
+    // For general comments about pdfium-render and binding to Pdfium, see export.rs.
+
+    let pdfium = Pdfium::default();
+
+    let document = pdfium.load_pdf_from_file("test/export-test.pdf", None)?;
+
+    // PdfPage::extract_layout() returns the positioned text runs, images, and paths
+    // on a page, in content stream order. When the serde crate feature is enabled,
+    // the returned PdfPageLayout implements serde::Serialize, so the layout of every page
+    // can be written out in any format supported by serde. Here, we output JSON.
+
+    let pages = document
+        .pages()
+        .iter()
+        .map(|page| page.extract_layout())
+        .collect::<Result<Vec<_>, _>>()?;
+
+    println!(
+        "{}",
+        serde_json::to_string_pretty(&json!({ "pages": pages })).unwrap()
+    );
+
+    Ok(())
+}
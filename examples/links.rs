@@ -18,9 +18,10 @@ pub fn main() -> Result<(), PdfiumError> {
 
         for (link_index, link) in page.links().iter().enumerate() {
             println!(
-                "Page {} link {} has action of type {:?}",
+                "Page {} link {} at {:?} has action of type {:?}",
                 page_index,
                 link_index,
+                link.bounds(),
                 link.action().map(|action| action.action_type())
             );
 
@@ -36,6 +37,15 @@ pub fn main() -> Result<(), PdfiumError> {
                 if let Some(uri_action) = action.as_uri_action() {
                     println!("Link URI destination: {:#?}", uri_action.uri())
                 }
+                if let Some(remote_destination_action) = action.as_remote_destination_action() {
+                    println!(
+                        "Remote destination file path: {:#?}",
+                        remote_destination_action.file_path()
+                    )
+                }
+                if let Some(launch_action) = action.as_launch_action() {
+                    println!("Launch file path: {:#?}", launch_action.file_path())
+                }
             }
 
             links_count += 1;
@@ -0,0 +1,44 @@
+use pdfium_render::prelude::*;
+use std::fs;
+
+pub fn main() -> Result<(), PdfiumError> {
+    // For general comments about pdfium-render and binding to Pdfium, see export.rs.
+
+    let pdfium = Pdfium::default();
+
+    let path = std::env::args()
+        .nth(1)
+        .unwrap_or_else(|| "test/export-untagged-test.pdf".to_owned());
+
+    let document = pdfium.load_pdf_from_file(&path, None)?;
+
+    // PdfDocument::export_to_markdown() and PdfDocument::export_to_html() convert the
+    // text, links, and images in a document. Headings are taken from the structure tree
+    // of a tagged document, or inferred from font sizes otherwise. Here, images are saved
+    // as separate PNG files in an images/ directory next to the converted output,
+    // and the document's bookmarks are included as a table of contents.
+
+    let options = PdfExportOptions::new()
+        .set_image_handling(PdfExportImageHandling::Reference)
+        .set_image_directory("images")
+        .include_outline(true);
+
+    let markdown = document.export_to_markdown(&options)?;
+
+    println!("{}", markdown.content());
+
+    fs::write("export-test.md", markdown.content()).map_err(PdfiumError::IoError)?;
+
+    markdown.save_images_to_directory(".")?;
+
+    // HTML output can also reproduce the layout of each page, placing every line of text
+    // and every image at its original position using CSS.
+
+    let html = document.export_to_html(&options.set_layout(PdfExportLayout::Positioned))?;
+
+    fs::write("export-test.html", html.content()).map_err(PdfiumError::IoError)?;
+
+    html.save_images_to_directory(".")?;
+
+    Ok(())
+}
@@ -0,0 +1,109 @@
+// Printing directly to a Windows printer device context is only supported on Windows,
+// and only when the windows_print feature is enabled. Run this example with:
+
+// cargo run --example print --features windows_print -- "Printer Name" path/to/file.pdf
+
+#[cfg(all(target_os = "windows", feature = "windows_print"))]
+fn main() -> Result<(), pdfium_render::prelude::PdfiumError> {
+    use pdfium_render::prelude::*;
+    use windows::core::{w, PCWSTR};
+    use windows::Win32::Graphics::Gdi::{CreateDCW, DeleteDC, GetDeviceCaps, HORZRES, VERTRES};
+    use windows::Win32::Storage::Xps::{EndDoc, EndPage, StartDocW, StartPage, DOCINFOW};
+
+    // For general comments about pdfium-render and binding to Pdfium, see export.rs.
+
+    let mut args = std::env::args().skip(1);
+
+    let printer = args
+        .next()
+        .unwrap_or_else(|| "Microsoft Print to PDF".to_owned());
+
+    let path = args
+        .next()
+        .unwrap_or_else(|| "test/export-test.pdf".to_owned());
+
+    let pdfium = Pdfium::default();
+
+    let document = pdfium.load_pdf_from_file(&path, None)?;
+
+    // Rendering to a printer device context sends vector output to the printer, rather
+    // than rasterizing each page into a (potentially very large) bitmap first.
+    // PostScript printers can be sent PostScript directly by changing the print mode.
+
+    pdfium.set_print_mode(PdfPrintMode::Emf);
+
+    let printer = printer.encode_utf16().chain(Some(0)).collect::<Vec<_>>();
+
+    let dc = unsafe {
+        CreateDCW(
+            w!("WINSPOOL"),
+            PCWSTR(printer.as_ptr()),
+            PCWSTR::null(),
+            None,
+        )
+    };
+
+    if dc.is_invalid() {
+        println!("Unable to open a device context for the printer");
+
+        return Ok(());
+    }
+
+    // Scale each page to fit the printable area of the paper.
+
+    let (width, height) = unsafe {
+        (
+            GetDeviceCaps(Some(dc), HORZRES),
+            GetDeviceCaps(Some(dc), VERTRES),
+        )
+    };
+
+    let config = PdfRenderConfig::new()
+        .set_target_width(width)
+        .set_maximum_height(height)
+        .use_print_quality(true)
+        .render_annotations(true);
+
+    let doc_info = DOCINFOW {
+        cbSize: std::mem::size_of::<DOCINFOW>() as i32,
+        lpszDocName: w!("pdfium-render print example"),
+        ..Default::default()
+    };
+
+    let result = unsafe {
+        if StartDocW(dc, &doc_info) > 0 {
+            let mut result = Ok(());
+
+            for page in document.pages().iter() {
+                StartPage(dc);
+
+                result = page.render_to_hdc(dc, &config);
+
+                EndPage(dc);
+
+                if result.is_err() {
+                    break;
+                }
+            }
+
+            EndDoc(dc);
+
+            result
+        } else {
+            println!("Unable to start a print job");
+
+            Ok(())
+        }
+    };
+
+    unsafe {
+        let _ = DeleteDC(dc);
+    }
+
+    result
+}
+
+#[cfg(not(all(target_os = "windows", feature = "windows_print")))]
+fn main() {
+    println!("This example requires Windows and the windows_print feature.");
+}
@@ -0,0 +1,81 @@
+use image_025::{GenericImage, ImageFormat, RgbaImage};
+use pdfium_render::prelude::*;
+
+fn main() -> Result<(), PdfiumError> {
+    // For general comments about pdfium-render and binding to Pdfium, see export.rs.
+
+    let pdfium = Pdfium::default();
+
+    // Renders the first page of a document as four separate tiles, stitches the tiles
+    // together, and confirms the stitched result is identical to a render of the whole page.
+    // This is the approach an interactive viewer might take when displaying a page at
+    // high zoom, rendering only those tiles that are currently visible.
+
+    let document = pdfium.load_pdf_from_file("test/export-test.pdf", None)?;
+
+    let page = document.pages().first()?;
+
+    // Rendering a sub-region of a page disables rendering of form data, so we disable it
+    // for the full render too, to ensure both renders take the same path through Pdfium.
+
+    let config = || {
+        PdfRenderConfig::new()
+            .set_target_width(2000)
+            .render_form_data(false)
+    };
+
+    // Render the whole page in one pass, for comparison...
+
+    let full = page.render_with_config(&config())?.as_image().into_rgba8();
+
+    let (width, height) = full.dimensions();
+
+    // ... then render the page in four tiles. The tiles in the right-hand column and
+    // bottom row may extend past the edge of the page; pdfium-render clamps them automatically.
+
+    let tile_width = (width + 1) / 2;
+
+    let tile_height = (height + 1) / 2;
+
+    let mut stitched = RgbaImage::new(width, height);
+
+    for row in 0..2 {
+        for column in 0..2 {
+            let left = column * tile_width;
+
+            let top = row * tile_height;
+
+            let tile = page
+                .render_with_config(&config().render_sub_region(
+                    left as Pixels,
+                    top as Pixels,
+                    tile_width as Pixels,
+                    tile_height as Pixels,
+                ))?
+                .as_image();
+
+            println!(
+                "Rendered tile at ({}, {}) with size {} x {}",
+                left,
+                top,
+                tile.width(),
+                tile.height()
+            );
+
+            stitched
+                .copy_from(&tile.into_rgba8(), left, top)
+                .map_err(|_| PdfiumError::ImageError)?;
+        }
+    }
+
+    println!(
+        "Stitched tiles match full render: {}",
+        stitched.as_raw() == full.as_raw()
+    );
+
+    stitched
+        .save_with_format("render-tiles-test.png", ImageFormat::Png)
+        .map_err(|_| PdfiumError::ImageError)?;
+
+    Ok(())
+}
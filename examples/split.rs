@@ -0,0 +1,33 @@
+use pdfium_render::prelude::*;
+
+fn main() -> Result<(), PdfiumError> {
+    // For general comments about pdfium-render and binding to Pdfium, see export.rs.
+
+    let pdfium = Pdfium::default();
+
+    let document = pdfium.load_pdf_from_file("test/export-test.pdf", None)?;
+
+    // PdfDocument::split_into_single_pages() returns an iterator that creates a new
+    // single-page document each time it is advanced. Since each new document is saved
+    // and then dropped before the next one is created, only one new document is held
+    // in memory at a time, no matter how many pages the source document contains.
+
+    for (index, page) in document.split_into_single_pages().enumerate() {
+        page?.save_to_file(&format!("split-test-page-{}.pdf", index + 1))?;
+    }
+
+    // PdfDocument::split() does the same for arbitrary ranges of pages. All ranges are
+    // validated before any new document is created, so an invalid or overlapping range
+    // results in an error before any work is done.
+
+    assert!(document.split([0..2, 1..3]).is_err());
+
+    for (index, pages) in document
+        .split([0..2, 2..document.pages().len()])?
+        .enumerate()
+    {
+        pages?.save_to_file(&format!("split-test-range-{}.pdf", index + 1))?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,57 @@
+use pdfium_render::prelude::*;
+
+fn main() -> Result<(), PdfiumError> {
+    // For general comments about pdfium-render and binding to Pdfium, see export.rs.
+
+    let pdfium = Pdfium::default();
+
+    let mut document = pdfium.create_new_pdf()?;
+
+    // Create a simple template containing a {{NAME}} placeholder on each of two pages.
+
+    let font = document.fonts_mut().times_roman();
+
+    for _ in 0..2 {
+        let mut page = document
+            .pages_mut()
+            .create_page_at_end(PdfPagePaperSize::a4())?;
+
+        page.objects_mut().create_text_object(
+            PdfPoints::new(72.0),
+            PdfPoints::new(700.0),
+            "Certificate awarded to",
+            font,
+            PdfPoints::new(24.0),
+        )?;
+
+        page.objects_mut().create_text_object(
+            PdfPoints::new(72.0),
+            PdfPoints::new(650.0),
+            "{{NAME}}",
+            font,
+            PdfPoints::new(36.0),
+        )?;
+    }
+
+    // Fill in the template by replacing every {{NAME}} placeholder in the document.
+    // The replacement name is wider than the placeholder, so we shrink its font size
+    // to make it fit in the space taken by the placeholder. The position, color, and
+    // font of each placeholder are retained.
+
+    for page in document.pages().iter() {
+        for mut object in page.objects().iter() {
+            if let Some(object) = object.as_text_object_mut() {
+                let text = object.text();
+
+                if text.contains("{{NAME}}") {
+                    object.replace_text_preserving_layout(
+                        &text.replace("{{NAME}}", "Ada Lovelace"),
+                        PdfTextFitMode::ShrinkToFit,
+                    )?;
+                }
+            }
+        }
+    }
+
+    document.save_to_file("test/template-fill-test.pdf")
+}
@@ -0,0 +1,156 @@
+// Browser tests for rendering pages directly into canvas elements. These tests are only
+// compiled when building for WASM. Run them with:
+//
+// `PDFIUM_WASM_BASE_URL=http://localhost:4000/ wasm-pack test --headless --chrome examples/`
+//
+// The pdfium.js and pdfium.wasm files described in examples/README.md must be available
+// at the given base URL, served with CORS headers that allow them to be fetched by the test
+// runner. If PDFIUM_WASM_BASE_URL is not set, http://localhost:4000/ is used.
+
+#![cfg(target_arch = "wasm32")]
+
+use js_sys::{Function, Reflect};
+use pdfium_render::prelude::*;
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
+use wasm_bindgen_test::*;
+use web_sys::{
+    CanvasRenderingContext2d, HtmlCanvasElement, OffscreenCanvas, OffscreenCanvasRenderingContext2d,
+};
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+#[wasm_bindgen(inline_js = r#"
+export async function load_pdfium_module(base_url) {
+    // pdfium.js is not an ES module, so it is evaluated in global scope, where it
+    // defines the PDFiumModule() factory function.
+
+    const source = await (await fetch(base_url + "pdfium.js")).text();
+
+    (0, eval)(source);
+
+    return await PDFiumModule({ locateFile: (path) => base_url + path });
+}
+"#)]
+extern "C" {
+    async fn load_pdfium_module(base_url: &str) -> JsValue;
+}
+
+/// Loads the Pdfium WASM module and binds pdfium-render to it, as index.html does.
+async fn bind_to_pdfium() -> Pdfium {
+    let pdfium_module =
+        load_pdfium_module(option_env!("PDFIUM_WASM_BASE_URL").unwrap_or("http://localhost:4000/"))
+            .await;
+
+    let local_module = wasm_bindgen::exports();
+
+    let initialize = Reflect::get(&local_module, &JsValue::from("initialize_pdfium_render"))
+        .unwrap()
+        .dyn_into::<Function>()
+        .unwrap();
+
+    assert_eq!(
+        initialize
+            .call3(
+                &JsValue::NULL,
+                &pdfium_module,
+                &local_module,
+                &JsValue::FALSE
+            )
+            .unwrap(),
+        JsValue::TRUE
+    );
+
+    Pdfium::default()
+}
+
+/// Creates a new document containing a single page entirely covered by a solid red rectangle.
+fn create_solid_red_document(pdfium: &Pdfium) -> PdfDocument {
+    let mut document = pdfium.create_new_pdf().unwrap();
+
+    let size = PdfPagePaperSize::a4();
+
+    let width = size.width();
+
+    let height = size.height();
+
+    document
+        .pages_mut()
+        .create_page_at_end(size)
+        .unwrap()
+        .objects_mut()
+        .create_path_object_rect(
+            PdfRect::new(PdfPoints::ZERO, PdfPoints::ZERO, height, width),
+            None,
+            None,
+            Some(PdfColor::new(255, 0, 0, 255)),
+        )
+        .unwrap();
+
+    document
+}
+
+#[wasm_bindgen_test]
+async fn test_render_into_canvas() {
+    let pdfium = bind_to_pdfium().await;
+
+    let document = create_solid_red_document(&pdfium);
+
+    let canvas = web_sys::window()
+        .unwrap()
+        .document()
+        .unwrap()
+        .create_element("canvas")
+        .unwrap()
+        .dyn_into::<HtmlCanvasElement>()
+        .unwrap();
+
+    document
+        .pages()
+        .first()
+        .unwrap()
+        .render_into_canvas(&canvas, &PdfRenderConfig::new().set_target_width(100))
+        .unwrap();
+
+    assert_eq!(canvas.width(), 100);
+
+    let context = canvas
+        .get_context("2d")
+        .unwrap()
+        .unwrap()
+        .dyn_into::<CanvasRenderingContext2d>()
+        .unwrap();
+
+    let pixel = context.get_image_data(50.0, 50.0, 1.0, 1.0).unwrap().data();
+
+    assert_eq!(pixel.0, vec![255, 0, 0, 255]);
+}
+
+#[wasm_bindgen_test]
+async fn test_render_into_offscreen_canvas() {
+    let pdfium = bind_to_pdfium().await;
+
+    let document = create_solid_red_document(&pdfium);
+
+    let canvas = OffscreenCanvas::new(1, 1).unwrap();
+
+    document
+        .pages()
+        .first()
+        .unwrap()
+        .render_into_offscreen_canvas(&canvas, &PdfRenderConfig::new().set_target_width(100))
+        .unwrap();
+
+    assert_eq!(canvas.width(), 100);
+
+    let context = canvas
+        .get_context("2d")
+        .unwrap()
+        .unwrap()
+        .dyn_into::<OffscreenCanvasRenderingContext2d>()
+        .unwrap();
+
+    let pixel = context.get_image_data(50.0, 50.0, 1.0, 1.0).unwrap().data();
+
+    assert_eq!(pixel.0, vec![255, 0, 0, 255]);
+}
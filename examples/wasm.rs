@@ -5,7 +5,7 @@ use pdfium_render::prelude::*;
 use wasm_bindgen::prelude::*;
 
 #[cfg(target_arch = "wasm32")]
-use web_sys::ImageData;
+use web_sys::{HtmlCanvasElement, ImageData};
 
 // See https://github.com/ajrcarey/pdfium-render/tree/master/examples for information
 // on how to build and package this example alongside a WASM build of Pdfium, suitable
@@ -140,6 +140,111 @@ pub async fn get_image_data_for_page(
         .unwrap()
 }
 
+/// Downloads the given URL, opens it as a PDF document, then renders the given page index
+/// directly into the given canvas element using the given bitmap width. Unlike
+/// get_image_data_for_page(), no intermediate copies of the rendered pixel data are made.
+#[cfg(target_arch = "wasm32")]
+#[wasm_bindgen]
+pub async fn render_page_into_canvas(
+    url: String,
+    index: PdfPageIndex,
+    canvas: HtmlCanvasElement,
+    width: Pixels,
+) {
+    Pdfium::default()
+        .load_pdf_from_fetch(url, None)
+        .await
+        .unwrap()
+        .pages()
+        .get(index)
+        .unwrap()
+        .render_into_canvas(&canvas, &PdfRenderConfig::new().set_target_width(width))
+        .unwrap();
+}
+
+/// Opens the given URL as a PDF document twice, first downloading the entire document and then
+/// downloading only the data Pdfium needs using HTTP range requests, logging how long it took
+/// before the first page could be rendered each time. It then pages through the
+/// progressively loaded document, rendering each page into the given canvas element as soon
+/// as its data has been downloaded.
+///
+/// The difference in first-page latency is most pronounced for large, linearized documents
+/// served by a server that supports range requests.
+#[cfg(target_arch = "wasm32")]
+#[wasm_bindgen]
+pub async fn render_pages_progressively(url: String, canvas: HtmlCanvasElement, width: Pixels) {
+    let pdfium = Pdfium::default();
+
+    let config = PdfRenderConfig::new().set_target_width(width);
+
+    // Download the entire document before rendering the first page...
+
+    let start = js_sys::Date::now();
+
+    {
+        let document = pdfium
+            .load_pdf_from_fetch(&url, PdfFetchOptions::new().use_range_requests(false))
+            .await
+            .unwrap();
+
+        document
+            .pages()
+            .first()
+            .unwrap()
+            .render_into_canvas(&canvas, &config)
+            .unwrap();
+    }
+
+    log::info!(
+        "First page rendered after {} ms when downloading the entire document",
+        js_sys::Date::now() - start
+    );
+
+    // ... then download only what is needed to render the first page.
+
+    let start = js_sys::Date::now();
+
+    let document = pdfium
+        .load_pdf_from_fetch(&url, PdfFetchOptions::new())
+        .await
+        .unwrap();
+
+    let pages = document.pages();
+
+    pages
+        .first()
+        .unwrap()
+        .render_into_canvas(&canvas, &config)
+        .unwrap();
+
+    log::info!(
+        "First page rendered after {} ms when downloading on demand",
+        js_sys::Date::now() - start
+    );
+
+    // Page through the rest of the document, downloading each page's data before accessing it.
+    // Documents loaded from servers that do not support range requests are downloaded in full,
+    // so no availability tracking is needed.
+
+    for index in 1..pages.len() {
+        if let Some(availability) = document.availability() {
+            availability.fetch_page(index).await.unwrap();
+        }
+
+        pages
+            .get(index)
+            .unwrap()
+            .render_into_canvas(&canvas, &config)
+            .unwrap();
+
+        log::info!(
+            "Page {} rendered after {} ms",
+            index,
+            js_sys::Date::now() - start
+        );
+    }
+}
+
 // Source files in examples/ directory are expected to always have a main() entry-point.
 // Since we're compiling to WASM, we'll never actually use this.
 #[allow(dead_code)]
@@ -36,6 +36,12 @@ pub(crate) mod wasm;
 #[cfg(feature = "thread_safe")]
 pub(crate) mod thread_safe;
 
+// A test double that records calls and returns programmed values, for unit testing
+// code built on pdfium-render without a Pdfium library.
+
+#[cfg(feature = "test_support")]
+pub mod mock;
+
 // The following dummy declarations are used only when running cargo doc.
 // They allow documentation of any target-specific functionality to be included
 // in documentation generated on a different target.
@@ -81,7 +87,7 @@ use crate::bindgen::FPDF_SKIA_CANVAS;
 use crate::bindgen::{FPDF_BSTR, FPDF_RESULT};
 
 use crate::bindings::version::PdfiumApiVersion;
-use crate::error::{PdfiumError, PdfiumInternalError};
+use crate::error::{PdfiumError, PdfiumErrorContext, PdfiumInternalError};
 use crate::pdf::color::PdfColor;
 use crate::pdf::document::page::object::private::internal::PdfPageObjectPrivate;
 use crate::pdf::document::page::object::PdfPageObject;
@@ -91,7 +97,8 @@ use crate::utils::pixels::{
     bgra_to_rgba, rgba_to_bgra, unaligned_bgr_to_rgba, unaligned_rgb_to_bgra,
 };
 use crate::utils::utf16le::{
-    get_pdfium_utf16le_bytes_from_str, get_string_from_pdfium_utf16le_bytes,
+    get_pdfium_utf16le_bytes_from_str, get_pdfium_utf16le_words_from_str,
+    get_string_from_pdfium_utf16le_bytes,
 };
 use std::os::raw::{
     c_char, c_double, c_float, c_int, c_long, c_uchar, c_uint, c_ulong, c_ushort, c_void,
@@ -172,6 +179,20 @@ pub trait PdfiumLibraryBindings {
         }
     }
 
+    /// Annotates the given [PdfiumError] with a [PdfiumErrorContext] naming the given Pdfium
+    /// function as the point of failure, along with the value currently returned by
+    /// [PdfiumLibraryBindings::FPDF_GetLastError].
+    ///
+    /// This should be called immediately after the failed function call, so that the
+    /// last error value has not been changed by any subsequent call.
+    #[inline]
+    fn error_in(&self, function: &'static str, error: PdfiumError) -> PdfiumError {
+        error.with_context(PdfiumErrorContext::new(
+            function,
+            self.FPDF_GetLastError() as u32,
+        ))
+    }
+
     /// Converts the given Rust `&str` into an UTF16-LE encoded byte buffer.
     #[inline]
     fn get_pdfium_utf16le_bytes_from_str(&self, str: &str) -> Vec<u8> {
@@ -242,6 +263,27 @@ pub trait PdfiumLibraryBindings {
         PdfiumApiVersion::current()
     }
 
+    /// Returns `true` if the given Pdfium function is exported by the Pdfium library
+    /// these bindings are bound to.
+    ///
+    /// A small number of recently added Pdfium functions are treated as optional when binding
+    /// to an external Pdfium library, so that older builds of Pdfium that do not export those
+    /// functions can still be loaded. Calling an optional function that is not exported by the
+    /// bound library returns a failure value rather than crashing. This function can be used to
+    /// check whether an optional function is actually available.
+    ///
+    /// The optional functions are:
+    /// * [PdfiumLibraryBindings::FPDFPageObj_GetIsActive]
+    /// * [PdfiumLibraryBindings::FPDFPageObj_GetRotatedBounds]
+    /// * [PdfiumLibraryBindings::FPDFPageObj_SetIsActive]
+    ///
+    /// Returns `true` for all other functions, since binding to a Pdfium library fails
+    /// if any other function is missing.
+    #[inline]
+    fn is_function_available(&self, _function: &str) -> bool {
+        true
+    }
+
     /// Initializes the PDFium library and allocate global resources for it.
     ///
     ///    `config` - configuration information.
@@ -276,6 +318,7 @@ pub trait PdfiumLibraryBindings {
     fn FPDF_SetSandBoxPolicy(&self, policy: FPDF_DWORD, enable: FPDF_BOOL);
 
     #[cfg(not(target_arch = "wasm32"))]
+    #[cfg(target_os = "windows")]
     #[cfg(feature = "pdfium_use_win32")]
     /// Sets printing mode when printing on Windows.
     ///
@@ -3172,6 +3215,7 @@ pub trait PdfiumLibraryBindings {
     fn FPDFBitmap_Destroy(&self, bitmap: FPDF_BITMAP);
 
     #[cfg(not(target_arch = "wasm32"))]
+    #[cfg(target_os = "windows")]
     #[cfg(feature = "pdfium_use_win32")]
     /// Renders the contents of a page to a device (screen, bitmap, or printer).
     /// This function is only supported on Windows.
@@ -3930,7 +3974,7 @@ pub trait PdfiumLibraryBindings {
         self.FPDFAnnot_SetStringValue(
             annot,
             key,
-            get_pdfium_utf16le_bytes_from_str(value).as_ptr() as FPDF_WIDESTRING,
+            get_pdfium_utf16le_words_from_str(value).as_ptr(),
         )
     }
 
@@ -4031,7 +4075,7 @@ pub trait PdfiumLibraryBindings {
         self.FPDFAnnot_SetAP(
             annot,
             appearanceMode,
-            get_pdfium_utf16le_bytes_from_str(value).as_ptr() as FPDF_WIDESTRING,
+            get_pdfium_utf16le_words_from_str(value).as_ptr(),
         )
     }
 
@@ -4537,10 +4581,7 @@ pub trait PdfiumLibraryBindings {
         annot: FPDF_ANNOTATION,
         name: &str,
     ) -> FPDF_ATTACHMENT {
-        self.FPDFAnnot_AddFileAttachment(
-            annot,
-            get_pdfium_utf16le_bytes_from_str(name).as_ptr() as FPDF_WIDESTRING,
-        )
+        self.FPDFAnnot_AddFileAttachment(annot, get_pdfium_utf16le_words_from_str(name).as_ptr())
     }
 
     ///  Initializes the form fill environment.
@@ -5614,10 +5655,7 @@ pub trait PdfiumLibraryBindings {
     #[inline]
     #[allow(non_snake_case)]
     fn FPDFBookmark_Find_str(&self, document: FPDF_DOCUMENT, title: &str) -> FPDF_BOOKMARK {
-        self.FPDFBookmark_Find(
-            document,
-            get_pdfium_utf16le_bytes_from_str(title).as_ptr() as FPDF_WIDESTRING,
-        )
+        self.FPDFBookmark_Find(document, get_pdfium_utf16le_words_from_str(title).as_ptr())
     }
 
     /// Gets the destination associated with `bookmark`.
@@ -6710,7 +6748,7 @@ pub trait PdfiumLibraryBindings {
     ) -> FPDF_SCHHANDLE {
         self.FPDFText_FindStart(
             text_page,
-            get_pdfium_utf16le_bytes_from_str(findwhat).as_ptr() as FPDF_WIDESTRING,
+            get_pdfium_utf16le_words_from_str(findwhat).as_ptr(),
             flags,
             start_index,
         )
@@ -7206,7 +7244,7 @@ pub trait PdfiumLibraryBindings {
     fn FPDFText_SetText_str(&self, text_object: FPDF_PAGEOBJECT, text: &str) -> FPDF_BOOL {
         self.FPDFText_SetText(
             text_object,
-            get_pdfium_utf16le_bytes_from_str(text).as_ptr() as FPDF_WIDESTRING,
+            get_pdfium_utf16le_words_from_str(text).as_ptr(),
         )
     }
 
@@ -9191,10 +9229,7 @@ pub trait PdfiumLibraryBindings {
     /// Returns a handle to the new attachment object, or `NULL` on failure.
     #[allow(non_snake_case)]
     fn FPDFDoc_AddAttachment_str(&self, document: FPDF_DOCUMENT, name: &str) -> FPDF_ATTACHMENT {
-        self.FPDFDoc_AddAttachment(
-            document,
-            get_pdfium_utf16le_bytes_from_str(name).as_ptr() as FPDF_WIDESTRING,
-        )
+        self.FPDFDoc_AddAttachment(document, get_pdfium_utf16le_words_from_str(name).as_ptr())
     }
 
     /// Gets the embedded attachment at `index` in `document`. Note that the returned
@@ -9311,7 +9346,7 @@ pub trait PdfiumLibraryBindings {
         self.FPDFAttachment_SetStringValue(
             attachment,
             key,
-            get_pdfium_utf16le_bytes_from_str(value).as_ptr() as FPDF_WIDESTRING,
+            get_pdfium_utf16le_words_from_str(value).as_ptr(),
         )
     }
 
@@ -52,6 +52,7 @@ pub(crate) struct DynamicPdfiumBindings {
     extern_FPDF_InitLibrary: unsafe extern "C" fn(),
     extern_FPDF_SetSandBoxPolicy: unsafe extern "C" fn(policy: FPDF_DWORD, enable: FPDF_BOOL),
     extern_FPDF_DestroyLibrary: unsafe extern "C" fn(),
+    #[cfg(target_os = "windows")]
     #[cfg(feature = "pdfium_use_win32")]
     extern_FPDF_SetPrintMode: unsafe extern "C" fn(mode: c_int),
     extern_FPDF_GetLastError: unsafe extern "C" fn() -> c_ulong,
@@ -764,6 +765,7 @@ pub(crate) struct DynamicPdfiumBindings {
         stride: c_int,
     ) -> FPDF_BITMAP,
     extern_FPDFBitmap_Destroy: unsafe extern "C" fn(bitmap: FPDF_BITMAP),
+    #[cfg(target_os = "windows")]
     #[cfg(feature = "pdfium_use_win32")]
     extern_FPDF_RenderPage: unsafe extern "C" fn(
         dc: windows::Win32::Graphics::Gdi::HDC,
@@ -1663,11 +1665,12 @@ pub(crate) struct DynamicPdfiumBindings {
         unsafe extern "C" fn(page_object: FPDF_PAGEOBJECT) -> FPDF_BOOL,
     extern_FPDFPageObj_GetType: unsafe extern "C" fn(page_object: FPDF_PAGEOBJECT) -> c_int,
     #[cfg(any(feature = "pdfium_future", feature = "pdfium_6996"))]
-    extern_FPDFPageObj_GetIsActive:
+    extern_FPDFPageObj_GetIsActive: Option<
         unsafe extern "C" fn(page_object: FPDF_PAGEOBJECT, active: *mut FPDF_BOOL) -> FPDF_BOOL,
+    >,
     #[cfg(any(feature = "pdfium_future", feature = "pdfium_6996"))]
     extern_FPDFPageObj_SetIsActive:
-        unsafe extern "C" fn(page_object: FPDF_PAGEOBJECT, active: FPDF_BOOL) -> FPDF_BOOL,
+        Option<unsafe extern "C" fn(page_object: FPDF_PAGEOBJECT, active: FPDF_BOOL) -> FPDF_BOOL>,
     extern_FPDFPageObj_Transform: unsafe extern "C" fn(
         page_object: FPDF_PAGEOBJECT,
         a: c_double,
@@ -1988,10 +1991,12 @@ pub(crate) struct DynamicPdfiumBindings {
         right: *mut c_float,
         top: *mut c_float,
     ) -> FPDF_BOOL,
-    extern_FPDFPageObj_GetRotatedBounds: unsafe extern "C" fn(
-        page_object: FPDF_PAGEOBJECT,
-        quad_points: *mut FS_QUADPOINTSF,
-    ) -> FPDF_BOOL,
+    extern_FPDFPageObj_GetRotatedBounds: Option<
+        unsafe extern "C" fn(
+            page_object: FPDF_PAGEOBJECT,
+            quad_points: *mut FS_QUADPOINTSF,
+        ) -> FPDF_BOOL,
+    >,
     extern_FPDFPageObj_SetBlendMode:
         unsafe extern "C" fn(page_object: FPDF_PAGEOBJECT, blend_mode: FPDF_BYTESTRING),
     extern_FPDFPageObj_SetStrokeColor: unsafe extern "C" fn(
@@ -2219,6 +2224,14 @@ impl DynamicPdfiumBindings {
         }
     }
 
+    /// Binds the given optional function, returning `None` if the function is not exported
+    /// by the given library rather than failing.
+    fn bind_optional<T: Copy>(library: &Library, function: &str) -> Option<T> {
+        Self::bind::<T>(library, function)
+            .ok()
+            .map(|symbol| *symbol)
+    }
+
     pub fn new(library: Library) -> Result<Self, PdfiumError> {
         Ok(DynamicPdfiumBindings {
             extern_FPDF_InitLibraryWithConfig: *(Self::bind(
@@ -2228,6 +2241,7 @@ impl DynamicPdfiumBindings {
             extern_FPDF_InitLibrary: *(Self::bind(&library, "FPDF_InitLibrary")?),
             extern_FPDF_SetSandBoxPolicy: *(Self::bind(&library, "FPDF_SetSandBoxPolicy")?),
             extern_FPDF_DestroyLibrary: *(Self::bind(&library, "FPDF_DestroyLibrary")?),
+            #[cfg(target_os = "windows")]
             #[cfg(feature = "pdfium_use_win32")]
             extern_FPDF_SetPrintMode: *(Self::bind(&library, "FPDF_SetPrintMode")?),
             extern_FPDF_GetLastError: *(Self::bind(&library, "FPDF_GetLastError")?),
@@ -2593,6 +2607,7 @@ impl DynamicPdfiumBindings {
             extern_FPDFBitmap_Create: *(Self::bind(&library, "FPDFBitmap_Create")?),
             extern_FPDFBitmap_CreateEx: *(Self::bind(&library, "FPDFBitmap_CreateEx")?),
             extern_FPDFBitmap_Destroy: *(Self::bind(&library, "FPDFBitmap_Destroy")?),
+            #[cfg(target_os = "windows")]
             #[cfg(feature = "pdfium_use_win32")]
             extern_FPDF_RenderPage: *(Self::bind(&library, "FPDF_RenderPage")?),
             extern_FPDFBitmap_GetFormat: *(Self::bind(&library, "FPDFBitmap_GetFormat")?),
@@ -3092,10 +3107,18 @@ impl DynamicPdfiumBindings {
                 "FPDFPageObj_HasTransparency",
             )?),
             extern_FPDFPageObj_GetType: *(Self::bind(&library, "FPDFPageObj_GetType")?),
+            // FPDFPageObj_GetIsActive() and FPDFPageObj_SetIsActive() are optional, since
+            // they are missing from many otherwise-compatible builds of Pdfium.
             #[cfg(any(feature = "pdfium_future", feature = "pdfium_6996"))]
-            extern_FPDFPageObj_GetIsActive: *(Self::bind(&library, "FPDFPageObj_GetIsActive")?),
+            extern_FPDFPageObj_GetIsActive: Self::bind_optional(
+                &library,
+                "FPDFPageObj_GetIsActive",
+            ),
             #[cfg(any(feature = "pdfium_future", feature = "pdfium_6996"))]
-            extern_FPDFPageObj_SetIsActive: *(Self::bind(&library, "FPDFPageObj_SetIsActive")?),
+            extern_FPDFPageObj_SetIsActive: Self::bind_optional(
+                &library,
+                "FPDFPageObj_SetIsActive",
+            ),
             extern_FPDFPageObj_Transform: *(Self::bind(&library, "FPDFPageObj_Transform")?),
             #[cfg(any(
                 feature = "pdfium_future",
@@ -3208,10 +3231,12 @@ impl DynamicPdfiumBindings {
             extern_FPDFPageObj_CreateNewPath: *(Self::bind(&library, "FPDFPageObj_CreateNewPath")?),
             extern_FPDFPageObj_CreateNewRect: *(Self::bind(&library, "FPDFPageObj_CreateNewRect")?),
             extern_FPDFPageObj_GetBounds: *(Self::bind(&library, "FPDFPageObj_GetBounds")?),
-            extern_FPDFPageObj_GetRotatedBounds: *(Self::bind(
+            // FPDFPageObj_GetRotatedBounds() is optional, since it is missing from
+            // older builds of Pdfium.
+            extern_FPDFPageObj_GetRotatedBounds: Self::bind_optional(
                 &library,
                 "FPDFPageObj_GetRotatedBounds",
-            )?),
+            ),
             extern_FPDFPageObj_SetBlendMode: *(Self::bind(&library, "FPDFPageObj_SetBlendMode")?),
             extern_FPDFPageObj_SetStrokeColor: *(Self::bind(
                 &library,
@@ -3356,6 +3381,18 @@ impl DynamicPdfiumBindings {
 }
 
 impl PdfiumLibraryBindings for DynamicPdfiumBindings {
+    #[inline]
+    fn is_function_available(&self, function: &str) -> bool {
+        match function {
+            #[cfg(any(feature = "pdfium_future", feature = "pdfium_6996"))]
+            "FPDFPageObj_GetIsActive" => self.extern_FPDFPageObj_GetIsActive.is_some(),
+            #[cfg(any(feature = "pdfium_future", feature = "pdfium_6996"))]
+            "FPDFPageObj_SetIsActive" => self.extern_FPDFPageObj_SetIsActive.is_some(),
+            "FPDFPageObj_GetRotatedBounds" => self.extern_FPDFPageObj_GetRotatedBounds.is_some(),
+            _ => true,
+        }
+    }
+
     #[inline]
     #[allow(non_snake_case)]
     fn FPDF_InitLibraryWithConfig(&self, config: *const FPDF_LIBRARY_CONFIG) {
@@ -3386,6 +3423,7 @@ impl PdfiumLibraryBindings for DynamicPdfiumBindings {
         }
     }
 
+    #[cfg(target_os = "windows")]
     #[cfg(feature = "pdfium_use_win32")]
     #[allow(non_snake_case)]
     fn FPDF_SetPrintMode(&self, mode: c_int) {
@@ -4944,6 +4982,7 @@ impl PdfiumLibraryBindings for DynamicPdfiumBindings {
         unsafe { (self.extern_FPDFBitmap_Destroy)(bitmap) }
     }
 
+    #[cfg(target_os = "windows")]
     #[cfg(feature = "pdfium_use_win32")]
     #[allow(non_snake_case)]
     fn FPDF_RenderPage(
@@ -7165,7 +7204,10 @@ impl PdfiumLibraryBindings for DynamicPdfiumBindings {
         page_object: FPDF_PAGEOBJECT,
         active: *mut FPDF_BOOL,
     ) -> FPDF_BOOL {
-        unsafe { (self.extern_FPDFPageObj_GetIsActive)(page_object, active) }
+        match self.extern_FPDFPageObj_GetIsActive {
+            Some(function) => unsafe { function(page_object, active) },
+            None => self.FALSE(),
+        }
     }
 
     #[cfg(any(feature = "pdfium_future", feature = "pdfium_6996"))]
@@ -7176,7 +7218,10 @@ impl PdfiumLibraryBindings for DynamicPdfiumBindings {
         page_object: FPDF_PAGEOBJECT,
         active: FPDF_BOOL,
     ) -> FPDF_BOOL {
-        unsafe { (self.extern_FPDFPageObj_SetIsActive)(page_object, active) }
+        match self.extern_FPDFPageObj_SetIsActive {
+            Some(function) => unsafe { function(page_object, active) },
+            None => self.FALSE(),
+        }
     }
 
     #[inline]
@@ -7860,7 +7905,10 @@ impl PdfiumLibraryBindings for DynamicPdfiumBindings {
         page_object: FPDF_PAGEOBJECT,
         quad_points: *mut FS_QUADPOINTSF,
     ) -> FPDF_BOOL {
-        unsafe { (self.extern_FPDFPageObj_GetRotatedBounds)(page_object, quad_points) }
+        match self.extern_FPDFPageObj_GetRotatedBounds {
+            Some(function) => unsafe { function(page_object, quad_points) },
+            None => self.FALSE(),
+        }
     }
 
     #[inline]
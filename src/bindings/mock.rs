@@ -0,0 +1,2678 @@
+//! Defines the [MockPdfiumBindings] struct, a test double implementing the
+//! [PdfiumLibraryBindings] trait that allows code built on `pdfium-render` to be unit tested
+//! without a Pdfium library being present.
+
+use crate::bindgen::{
+    size_t, FPDFBitmap_BGRA, FPDFBitmap_BGRx, FPDF_CharsetFontMap, FPDFANNOT_COLORTYPE,
+    FPDF_ACTION, FPDF_ANNOTATION, FPDF_ANNOTATION_SUBTYPE, FPDF_ANNOT_APPEARANCEMODE,
+    FPDF_ATTACHMENT, FPDF_AVAIL, FPDF_BITMAP, FPDF_BOOKMARK, FPDF_BOOL, FPDF_CLIPPATH,
+    FPDF_COLORSCHEME, FPDF_DEST, FPDF_DOCUMENT, FPDF_DUPLEXTYPE, FPDF_DWORD, FPDF_FILEACCESS,
+    FPDF_FILEIDTYPE, FPDF_FILEWRITE, FPDF_FONT, FPDF_FORMFILLINFO, FPDF_FORMHANDLE, FPDF_GLYPHPATH,
+    FPDF_IMAGEOBJ_METADATA, FPDF_JAVASCRIPT_ACTION, FPDF_LIBRARY_CONFIG, FPDF_LINK,
+    FPDF_OBJECT_TYPE, FPDF_PAGE, FPDF_PAGELINK, FPDF_PAGEOBJECT, FPDF_PAGEOBJECTMARK,
+    FPDF_PAGERANGE, FPDF_PATHSEGMENT, FPDF_SCHHANDLE, FPDF_SIGNATURE, FPDF_STRUCTELEMENT,
+    FPDF_STRUCTELEMENT_ATTR, FPDF_STRUCTTREE, FPDF_SYSFONTINFO, FPDF_TEXTPAGE,
+    FPDF_TEXT_RENDERMODE, FPDF_WCHAR, FPDF_WIDESTRING, FPDF_XOBJECT, FS_FLOAT, FS_MATRIX,
+    FS_POINTF, FS_QUADPOINTSF, FS_RECTF, FS_SIZEF, FX_DOWNLOADHINTS, FX_FILEAVAIL, IFSDK_PAUSE,
+};
+
+#[cfg(any(
+    feature = "pdfium_future",
+    feature = "pdfium_6996",
+    feature = "pdfium_6721",
+    feature = "pdfium_6666",
+    feature = "pdfium_6611",
+    feature = "pdfium_6569",
+    feature = "pdfium_6555",
+    feature = "pdfium_6490",
+))]
+use crate::bindgen::FPDF_STRUCTELEMENT_ATTR_VALUE;
+
+#[cfg(feature = "pdfium_use_skia")]
+use crate::bindgen::FPDF_SKIA_CANVAS;
+
+#[cfg(feature = "pdfium_enable_xfa")]
+use crate::bindgen::{FPDF_BSTR, FPDF_RESULT};
+
+use crate::bindings::PdfiumLibraryBindings;
+use std::any::{type_name, Any};
+use std::cell::RefCell;
+use std::collections::{HashMap, VecDeque};
+use std::os::raw::{
+    c_char, c_double, c_float, c_int, c_long, c_uchar, c_uint, c_ulong, c_ushort, c_void,
+};
+use std::rc::Rc;
+
+#[cfg(doc)]
+use crate::pdfium::Pdfium;
+
+/// A single call to a Pdfium function recorded by [MockPdfiumBindings].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MockPdfiumCall {
+    function: &'static str,
+    arguments: Vec<String>,
+}
+
+impl MockPdfiumCall {
+    /// Returns the name of the Pdfium function that was called, for example `FPDF_GetPageCount`.
+    #[inline]
+    pub fn function(&self) -> &'static str {
+        self.function
+    }
+
+    /// Returns the arguments passed to the Pdfium function, in order, each formatted
+    /// using its `Debug` implementation. Handles and other pointers are formatted as
+    /// hexadecimal addresses.
+    #[inline]
+    pub fn arguments(&self) -> &[String] {
+        self.arguments.as_slice()
+    }
+
+    /// Returns the argument at the given position parsed as a number, or `None` if there is
+    /// no argument at that position or it does not hold a number.
+    pub fn numeric_argument<T: std::str::FromStr>(&self, index: usize) -> Option<T> {
+        self.arguments
+            .get(index)
+            .and_then(|argument| argument.parse().ok())
+    }
+}
+
+/// A behavior that computes the return value of a mocked Pdfium function each time it is called.
+type MockPdfiumBehavior = Box<dyn FnMut(&MockPdfiumCall) -> Box<dyn Any>>;
+
+/// A bitmap created by a mocked call to `FPDFBitmap_Create()` or `FPDFBitmap_CreateEx()`.
+/// Pixel data is always stored in four bytes per pixel, with no padding between rows.
+struct MockBitmap {
+    width: c_int,
+    height: c_int,
+    format: c_int,
+    buffer: Vec<u8>,
+}
+
+#[derive(Default)]
+struct MockPdfiumState {
+    calls: Vec<MockPdfiumCall>,
+    queued: HashMap<&'static str, VecDeque<Box<dyn Any>>>,
+    behaviors: HashMap<&'static str, MockPdfiumBehavior>,
+    bitmaps: HashMap<usize, MockBitmap>,
+    next_handle: usize,
+}
+
+/// A test double implementing the [PdfiumLibraryBindings] trait, allowing code built on
+/// `pdfium-render` to be unit tested without a Pdfium library being present.
+///
+/// Every call to a Pdfium function is recorded, along with its arguments, and can be inspected
+/// using the [MockPdfiumBindings::calls()], [MockPdfiumBindings::calls_to()], and
+/// [MockPdfiumBindings::call_count()] functions. The value returned by each call is taken from:
+/// * the queue of values programmed for that function using [MockPdfiumBindings::returns()],
+///   if the queue is not empty; otherwise
+/// * the behavior programmed for that function using [MockPdfiumBindings::returns_always()]
+///   or [MockPdfiumBindings::returns_with()], if any.
+///
+/// A call to any function that has not been programmed panics, naming the function and its
+/// arguments. Since the mock is generated from the full list of functions in the
+/// [PdfiumLibraryBindings] trait, this includes functions that are newly added to the trait.
+///
+/// Ready-made behaviors for initializing the library, loading a document, and rendering
+/// pages can be programmed using [MockPdfiumBindings::allow_library_lifecycle()],
+/// [MockPdfiumBindings::allow_document_loading()], and
+/// [MockPdfiumBindings::allow_page_rendering()] respectively.
+///
+/// Cloned instances share the same programmed behaviors and recorded calls, so a clone can be
+/// passed to [Pdfium::new()] while the original is kept for inspection:
+///
+/// ```
+/// use pdfium_render::prelude::*;
+///
+/// let mock = MockPdfiumBindings::new();
+///
+/// mock.allow_library_lifecycle().allow_document_loading(3);
+///
+/// let pdfium = Pdfium::new(Box::new(mock.clone()));
+///
+/// let document = pdfium.load_pdf_from_byte_slice(b"%PDF-1.7", None).unwrap();
+///
+/// assert_eq!(document.pages().len(), 3);
+/// assert_eq!(mock.call_count("FPDF_LoadMemDocument64"), 1);
+/// ```
+///
+/// This struct is only available when this crate's `test_support` feature is enabled.
+#[derive(Clone, Default)]
+pub struct MockPdfiumBindings {
+    state: Rc<RefCell<MockPdfiumState>>,
+}
+
+impl MockPdfiumBindings {
+    /// Creates a new [MockPdfiumBindings] object with no programmed behaviors.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Queues the given value to be returned by the next call to the given Pdfium function
+    /// that is not already answered by an earlier queued value. Queued values take precedence
+    /// over any behavior programmed using [MockPdfiumBindings::returns_always()] or
+    /// [MockPdfiumBindings::returns_with()].
+    ///
+    /// The type of the value must exactly match the return type of the function;
+    /// a call receiving a value of the wrong type panics.
+    pub fn returns<R: 'static>(&self, function: &'static str, value: R) -> &Self {
+        self.state
+            .borrow_mut()
+            .queued
+            .entry(function)
+            .or_default()
+            .push_back(Box::new(value));
+
+        self
+    }
+
+    /// Programs the given Pdfium function to return a clone of the given value whenever
+    /// it is called and no queued value is available, replacing any behavior programmed
+    /// for the function previously.
+    pub fn returns_always<R: Clone + 'static>(&self, function: &'static str, value: R) -> &Self {
+        self.returns_with(function, move |_| value.clone())
+    }
+
+    /// Programs the given Pdfium function to return the result of calling the given callback
+    /// whenever it is called and no queued value is available, replacing any behavior
+    /// programmed for the function previously. The callback receives the recorded call.
+    pub fn returns_with<R: 'static>(
+        &self,
+        function: &'static str,
+        mut behavior: impl FnMut(&MockPdfiumCall) -> R + 'static,
+    ) -> &Self {
+        self.state
+            .borrow_mut()
+            .behaviors
+            .insert(function, Box::new(move |call| Box::new(behavior(call))));
+
+        self
+    }
+
+    /// Allows calls to the given Pdfium functions that return no value.
+    pub fn allow(&self, functions: &[&'static str]) -> &Self {
+        for function in functions {
+            self.returns_always(function, ());
+        }
+
+        self
+    }
+
+    /// Programs the functions called when creating and dropping a [Pdfium] instance,
+    /// and the error reporting function used when a Pdfium call fails.
+    pub fn allow_library_lifecycle(&self) -> &Self {
+        self.allow(&[
+            "FPDF_InitLibrary",
+            "FPDF_InitLibraryWithConfig",
+            "FPDF_DestroyLibrary",
+        ])
+        .returns_always("FPDF_GetLastError", 0 as c_ulong)
+    }
+
+    /// Programs the functions called when loading, creating, and closing a document with
+    /// the given number of US Letter-sized pages, and when loading and closing those pages.
+    /// The document has no form fields, bookmarks, or other interactive features.
+    pub fn allow_document_loading(&self, page_count: c_int) -> &Self {
+        let next_handle = self.handle_factory();
+
+        let document = next_handle.clone();
+        let new_document = next_handle.clone();
+        let custom_document = next_handle.clone();
+        let page = next_handle.clone();
+        let form = next_handle;
+
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let file_document = document.clone();
+
+            self.returns_with("FPDF_LoadDocument", move |_| {
+                file_document() as FPDF_DOCUMENT
+            });
+        }
+
+        self.returns_with("FPDF_LoadMemDocument64", move |_| {
+            document() as FPDF_DOCUMENT
+        })
+        .returns_with("FPDF_CreateNewDocument", move |_| {
+            new_document() as FPDF_DOCUMENT
+        })
+        .returns_with("FPDF_LoadCustomDocument", move |_| {
+            custom_document() as FPDF_DOCUMENT
+        })
+        .returns_always("FPDF_GetPageCount", page_count)
+        .returns_always("FPDF_GetMetaText", 0 as c_ulong)
+        .returns_always("FPDF_GetPageLabel", 0 as c_ulong)
+        .returns_always("FPDF_GetSecurityHandlerRevision", -1 as c_int)
+        .returns_with("FPDFDOC_InitFormFillEnvironment", move |_| {
+            form() as FPDF_FORMHANDLE
+        })
+        .returns_always("FPDF_GetFormType", 0 as c_int)
+        .returns_with("FPDF_LoadPage", move |_| page() as FPDF_PAGE)
+        .returns_always("FPDF_GetPageWidthF", 612.0 as c_float)
+        .returns_always("FPDF_GetPageHeightF", 792.0 as c_float)
+        .returns_always("FPDF_GetPageWidth", 612.0 as c_double)
+        .returns_always("FPDF_GetPageHeight", 792.0 as c_double)
+        .returns_always("FPDFPage_GetRotation", 0 as c_int)
+        .allow(&[
+            "FPDF_ClosePage",
+            "FPDF_CloseDocument",
+            "FPDFDOC_ExitFormFillEnvironment",
+            "FORM_OnAfterLoadPage",
+            "FORM_OnBeforeClosePage",
+            "FORM_DoDocumentJSAction",
+            "FORM_DoDocumentOpenAction",
+            "FORM_DoPageAAction",
+            "FPDF_SetFormFieldHighlightColor",
+            "FPDF_SetFormFieldHighlightAlpha",
+        ])
+    }
+
+    /// Programs the functions called when loading and closing the text page of a page.
+    /// Every text page contains the given number of characters, although no other
+    /// information about those characters is available.
+    pub fn allow_text_extraction(&self, char_count: c_int) -> &Self {
+        let text_page = self.handle_factory();
+
+        self.returns_with("FPDFText_LoadPage", move |_| text_page() as FPDF_TEXTPAGE)
+            .returns_always("FPDFText_CountChars", char_count)
+            .allow(&["FPDFText_ClosePage"])
+    }
+
+    /// Returns a function that creates a new, distinct handle each time it is called.
+    fn handle_factory(&self) -> impl Fn() -> *mut c_void + Clone {
+        let state = self.state.clone();
+
+        move || {
+            let mut state = state.borrow_mut();
+
+            state.next_handle += 1;
+
+            // Handles are never dereferenced, so any distinct non-null address will do.
+
+            (state.next_handle * 16) as *mut c_void
+        }
+    }
+
+    /// Programs the functions called when creating, rendering pages into, and reading back
+    /// bitmaps. Bitmaps are backed by real buffers, so pixel data can be read back; rendering
+    /// itself draws nothing, so every rendered pixel retains the color used to clear the bitmap.
+    pub fn allow_page_rendering(&self) -> &Self {
+        let create_state = self.state.clone();
+
+        let create = move |call: &MockPdfiumCall, format: c_int| {
+            let width = call.numeric_argument::<c_int>(0).unwrap_or(0).max(0);
+            let height = call.numeric_argument::<c_int>(1).unwrap_or(0).max(0);
+
+            let mut state = create_state.borrow_mut();
+
+            state.next_handle += 1;
+
+            let handle = state.next_handle * 16;
+
+            state.bitmaps.insert(
+                handle,
+                MockBitmap {
+                    width,
+                    height,
+                    format,
+                    buffer: vec![0; (width * height * 4) as usize],
+                },
+            );
+
+            handle as FPDF_BITMAP
+        };
+
+        let create_ex = create.clone();
+
+        let fill_state = self.state.clone();
+
+        let fill = move |call: &MockPdfiumCall| {
+            let left = call.numeric_argument::<c_int>(1).unwrap_or(0).max(0);
+            let top = call.numeric_argument::<c_int>(2).unwrap_or(0).max(0);
+            let width = call.numeric_argument::<c_int>(3).unwrap_or(0);
+            let height = call.numeric_argument::<c_int>(4).unwrap_or(0);
+            let color = call.numeric_argument::<FPDF_DWORD>(5).unwrap_or(0) as u32;
+
+            if let Some(bitmap) = fill_state.borrow_mut().bitmaps.get_mut(&parse_handle(call)) {
+                let right = (left + width).min(bitmap.width);
+                let bottom = (top + height).min(bitmap.height);
+
+                for y in top..bottom {
+                    for x in left..right {
+                        let offset = ((y * bitmap.width + x) * 4) as usize;
+
+                        bitmap.buffer[offset..offset + 4].copy_from_slice(&color.to_le_bytes());
+                    }
+                }
+            }
+        };
+
+        let buffer_state = self.state.clone();
+        let destroy_state = self.state.clone();
+
+        #[cfg(any(
+            feature = "pdfium_future",
+            feature = "pdfium_6996",
+            feature = "pdfium_6721",
+            feature = "pdfium_6666"
+        ))]
+        self.returns_with("FPDFBitmap_FillRect", move |call| {
+            fill(call);
+
+            1 as FPDF_BOOL
+        });
+
+        #[cfg(not(any(
+            feature = "pdfium_future",
+            feature = "pdfium_6996",
+            feature = "pdfium_6721",
+            feature = "pdfium_6666"
+        )))]
+        self.returns_with("FPDFBitmap_FillRect", fill);
+
+        self.returns_with("FPDFBitmap_Create", move |call| {
+            // A bitmap created with an alpha channel uses the BGRA format.
+
+            let format = if call.numeric_argument::<c_int>(2).unwrap_or(0) == 0 {
+                FPDFBitmap_BGRx as c_int
+            } else {
+                FPDFBitmap_BGRA as c_int
+            };
+
+            create(call, format)
+        })
+        .returns_with("FPDFBitmap_CreateEx", move |call| {
+            create_ex(call, call.numeric_argument(2).unwrap_or(0))
+        })
+        .returns_with("FPDFBitmap_GetBuffer", move |call| {
+            buffer_state
+                .borrow_mut()
+                .bitmaps
+                .get_mut(&parse_handle(call))
+                .map(|bitmap| bitmap.buffer.as_mut_ptr() as *mut c_void)
+                .unwrap_or(std::ptr::null_mut())
+        })
+        .returns_with(
+            "FPDFBitmap_GetWidth",
+            self.bitmap_property(|bitmap| bitmap.width),
+        )
+        .returns_with(
+            "FPDFBitmap_GetHeight",
+            self.bitmap_property(|bitmap| bitmap.height),
+        )
+        .returns_with(
+            "FPDFBitmap_GetStride",
+            self.bitmap_property(|bitmap| bitmap.width * 4),
+        )
+        .returns_with(
+            "FPDFBitmap_GetFormat",
+            self.bitmap_property(|bitmap| bitmap.format),
+        )
+        .returns_with("FPDFBitmap_Destroy", move |call| {
+            destroy_state
+                .borrow_mut()
+                .bitmaps
+                .remove(&parse_handle(call));
+        })
+        .allow(&[
+            "FPDF_RenderPageBitmap",
+            "FPDF_RenderPageBitmapWithMatrix",
+            "FPDF_FFLDraw",
+        ])
+    }
+
+    /// Returns a behavior that reports a property of the mock bitmap passed as the first
+    /// argument of each call, or zero if the bitmap does not exist.
+    fn bitmap_property(
+        &self,
+        property: impl Fn(&MockBitmap) -> c_int + 'static,
+    ) -> impl FnMut(&MockPdfiumCall) -> c_int + 'static {
+        let state = self.state.clone();
+
+        move |call| {
+            state
+                .borrow()
+                .bitmaps
+                .get(&parse_handle(call))
+                .map(&property)
+                .unwrap_or(0)
+        }
+    }
+
+    /// Returns all the calls recorded by this [MockPdfiumBindings] object, in the order
+    /// they were made.
+    #[inline]
+    pub fn calls(&self) -> Vec<MockPdfiumCall> {
+        self.state.borrow().calls.clone()
+    }
+
+    /// Returns all the recorded calls to the given Pdfium function, in the order they were made.
+    pub fn calls_to(&self, function: &str) -> Vec<MockPdfiumCall> {
+        self.state
+            .borrow()
+            .calls
+            .iter()
+            .filter(|call| call.function == function)
+            .cloned()
+            .collect()
+    }
+
+    /// Returns the number of recorded calls to the given Pdfium function.
+    pub fn call_count(&self, function: &str) -> usize {
+        self.state
+            .borrow()
+            .calls
+            .iter()
+            .filter(|call| call.function == function)
+            .count()
+    }
+
+    /// Discards all recorded calls. Programmed values and behaviors are retained.
+    #[inline]
+    pub fn clear_calls(&self) {
+        self.state.borrow_mut().calls.clear();
+    }
+
+    /// Records a call to the given Pdfium function and returns its programmed result.
+    fn invoke<R: 'static>(&self, function: &'static str, arguments: Vec<String>) -> R {
+        let call = MockPdfiumCall {
+            function,
+            arguments,
+        };
+
+        // The state must not be borrowed while a behavior runs, since behaviors
+        // may themselves access the state.
+
+        let (queued, behavior) = {
+            let mut state = self.state.borrow_mut();
+
+            state.calls.push(call.clone());
+
+            match state
+                .queued
+                .get_mut(function)
+                .and_then(|queue| queue.pop_front())
+            {
+                Some(value) => (Some(value), None),
+                None => (None, state.behaviors.remove(function)),
+            }
+        };
+
+        let value = match (queued, behavior) {
+            (Some(value), _) => value,
+            (None, Some(mut behavior)) => {
+                let value = behavior(&call);
+
+                self.state
+                    .borrow_mut()
+                    .behaviors
+                    .entry(function)
+                    .or_insert(behavior);
+
+                value
+            }
+            (None, None) => panic!(
+                "Unexpected call to {}({}) on MockPdfiumBindings",
+                function,
+                call.arguments.join(", ")
+            ),
+        };
+
+        match value.downcast::<R>() {
+            Ok(value) => *value,
+            Err(_) => panic!(
+                "MockPdfiumBindings was programmed with a value of the wrong type for {}; expected {}",
+                function,
+                type_name::<R>()
+            ),
+        }
+    }
+}
+
+/// Returns the handle passed as the first argument of the given call.
+fn parse_handle(call: &MockPdfiumCall) -> usize {
+    call.arguments()
+        .first()
+        .and_then(|argument| usize::from_str_radix(argument.trim_start_matches("0x"), 16).ok())
+        .unwrap_or(0)
+}
+
+/// Implements every required function of the [PdfiumLibraryBindings] trait on
+/// [MockPdfiumBindings] by recording the call and returning its programmed result.
+/// Functions with default implementations in the trait are not overridden, so calls to them
+/// are recorded as calls to the underlying Pdfium functions they wrap.
+macro_rules! mock_pdfium_bindings {
+    ($($(#[$attribute:meta])* fn $function:ident($($argument:ident: $argument_type:ty),* $(,)?) $(-> $return_type:ty)?;)*) => {
+        impl PdfiumLibraryBindings for MockPdfiumBindings {
+            $(
+                $(#[$attribute])*
+                #[allow(non_snake_case)]
+                fn $function(&self, $($argument: $argument_type),*) $(-> $return_type)? {
+                    self.invoke(
+                        stringify!($function),
+                        vec![$(format!("{:?}", $argument)),*],
+                    )
+                }
+            )*
+        }
+    };
+}
+
+mock_pdfium_bindings! {
+    fn FPDF_InitLibraryWithConfig(config: *const FPDF_LIBRARY_CONFIG);
+    fn FPDF_InitLibrary();
+    fn FPDF_DestroyLibrary();
+    fn FPDF_SetSandBoxPolicy(policy: FPDF_DWORD, enable: FPDF_BOOL);
+    #[cfg(not(target_arch = "wasm32"))]
+    #[cfg(target_os = "windows")]
+    #[cfg(feature = "pdfium_use_win32")]
+    fn FPDF_SetPrintMode(mode: c_int);
+    fn FPDF_GetLastError() -> c_ulong;
+    fn FPDF_CreateNewDocument() -> FPDF_DOCUMENT;
+    #[cfg(not(target_arch = "wasm32"))]
+    fn FPDF_LoadDocument(file_path: &str, password: Option<&str>) -> FPDF_DOCUMENT;
+    fn FPDF_LoadMemDocument64(data_buf: &[u8], password: Option<&str>) -> FPDF_DOCUMENT;
+    fn FPDF_LoadCustomDocument(
+        pFileAccess: *mut FPDF_FILEACCESS,
+        password: Option<&str>,
+    ) -> FPDF_DOCUMENT;
+    fn FPDF_SaveAsCopy(
+        document: FPDF_DOCUMENT,
+        pFileWrite: *mut FPDF_FILEWRITE,
+        flags: FPDF_DWORD,
+    ) -> FPDF_BOOL;
+    fn FPDF_SaveWithVersion(
+        document: FPDF_DOCUMENT,
+        pFileWrite: *mut FPDF_FILEWRITE,
+        flags: FPDF_DWORD,
+        fileVersion: c_int,
+    ) -> FPDF_BOOL;
+    fn FPDFAvail_Create(file_avail: *mut FX_FILEAVAIL, file: *mut FPDF_FILEACCESS) -> FPDF_AVAIL;
+    fn FPDFAvail_Destroy(avail: FPDF_AVAIL);
+    fn FPDFAvail_IsDocAvail(avail: FPDF_AVAIL, hints: *mut FX_DOWNLOADHINTS) -> c_int;
+    fn FPDFAvail_GetDocument(avail: FPDF_AVAIL, password: Option<&str>) -> FPDF_DOCUMENT;
+    fn FPDFAvail_GetFirstPageNum(doc: FPDF_DOCUMENT) -> c_int;
+    fn FPDFAvail_IsPageAvail(
+        avail: FPDF_AVAIL,
+        page_index: c_int,
+        hints: *mut FX_DOWNLOADHINTS,
+    ) -> c_int;
+    fn FPDFAvail_IsFormAvail(avail: FPDF_AVAIL, hints: *mut FX_DOWNLOADHINTS) -> c_int;
+    fn FPDFAvail_IsLinearized(avail: FPDF_AVAIL) -> c_int;
+    fn FPDF_ClosePage(page: FPDF_PAGE);
+    fn FPDF_CloseDocument(document: FPDF_DOCUMENT);
+    fn FPDF_DeviceToPage(
+        page: FPDF_PAGE,
+        start_x: c_int,
+        start_y: c_int,
+        size_x: c_int,
+        size_y: c_int,
+        rotate: c_int,
+        device_x: c_int,
+        device_y: c_int,
+        page_x: *mut c_double,
+        page_y: *mut c_double,
+    ) -> FPDF_BOOL;
+    fn FPDF_PageToDevice(
+        page: FPDF_PAGE,
+        start_x: c_int,
+        start_y: c_int,
+        size_x: c_int,
+        size_y: c_int,
+        rotate: c_int,
+        page_x: c_double,
+        page_y: c_double,
+        device_x: *mut c_int,
+        device_y: *mut c_int,
+    ) -> FPDF_BOOL;
+    fn FPDF_GetFileVersion(doc: FPDF_DOCUMENT, fileVersion: *mut c_int) -> FPDF_BOOL;
+    fn FPDF_DocumentHasValidCrossReferenceTable(document: FPDF_DOCUMENT) -> FPDF_BOOL;
+    fn FPDF_GetTrailerEnds(
+        document: FPDF_DOCUMENT,
+        buffer: *mut c_uint,
+        length: c_ulong,
+    ) -> c_ulong;
+    fn FPDF_GetDocPermissions(document: FPDF_DOCUMENT) -> c_ulong;
+    #[cfg(any(
+        feature = "pdfium_future",
+        feature = "pdfium_6996",
+        feature = "pdfium_6721",
+        feature = "pdfium_6666",
+        feature = "pdfium_6611",
+        feature = "pdfium_6569",
+        feature = "pdfium_6569",
+        feature = "pdfium_6555",
+        feature = "pdfium_6490",
+        feature = "pdfium_6406",
+        feature = "pdfium_6337",
+        feature = "pdfium_6295",
+    ))]
+    fn FPDF_GetDocUserPermissions(document: FPDF_DOCUMENT) -> c_ulong;
+    fn FPDF_GetSecurityHandlerRevision(document: FPDF_DOCUMENT) -> c_int;
+    fn FPDF_GetPageCount(document: FPDF_DOCUMENT) -> c_int;
+    fn FPDF_LoadPage(document: FPDF_DOCUMENT, page_index: c_int) -> FPDF_PAGE;
+    fn FPDF_RenderPageBitmapWithColorScheme_Start(
+        bitmap: FPDF_BITMAP,
+        page: FPDF_PAGE,
+        start_x: c_int,
+        start_y: c_int,
+        size_x: c_int,
+        size_y: c_int,
+        rotate: c_int,
+        flags: c_int,
+        color_scheme: *const FPDF_COLORSCHEME,
+        pause: *mut IFSDK_PAUSE,
+    ) -> c_int;
+    fn FPDF_RenderPageBitmap_Start(
+        bitmap: FPDF_BITMAP,
+        page: FPDF_PAGE,
+        start_x: c_int,
+        start_y: c_int,
+        size_x: c_int,
+        size_y: c_int,
+        rotate: c_int,
+        flags: c_int,
+        pause: *mut IFSDK_PAUSE,
+    ) -> c_int;
+    fn FPDF_RenderPage_Continue(page: FPDF_PAGE, pause: *mut IFSDK_PAUSE) -> c_int;
+    fn FPDF_RenderPage_Close(page: FPDF_PAGE);
+    fn FPDF_ImportPagesByIndex(
+        dest_doc: FPDF_DOCUMENT,
+        src_doc: FPDF_DOCUMENT,
+        page_indices: *const c_int,
+        length: c_ulong,
+        index: c_int,
+    ) -> FPDF_BOOL;
+    fn FPDF_ImportPages(
+        dest_doc: FPDF_DOCUMENT,
+        src_doc: FPDF_DOCUMENT,
+        pagerange: &str,
+        index: c_int,
+    ) -> FPDF_BOOL;
+    fn FPDF_ImportNPagesToOne(
+        src_doc: FPDF_DOCUMENT,
+        output_width: c_float,
+        output_height: c_float,
+        num_pages_on_x_axis: size_t,
+        num_pages_on_y_axis: size_t,
+    ) -> FPDF_DOCUMENT;
+    fn FPDF_NewXObjectFromPage(
+        dest_doc: FPDF_DOCUMENT,
+        src_doc: FPDF_DOCUMENT,
+        src_page_index: c_int,
+    ) -> FPDF_XOBJECT;
+    fn FPDF_CloseXObject(xobject: FPDF_XOBJECT);
+    fn FPDF_NewFormObjectFromXObject(xobject: FPDF_XOBJECT) -> FPDF_PAGEOBJECT;
+    fn FPDF_CopyViewerPreferences(dest_doc: FPDF_DOCUMENT, src_doc: FPDF_DOCUMENT) -> FPDF_BOOL;
+    fn FPDF_GetPageWidthF(page: FPDF_PAGE) -> c_float;
+    fn FPDF_GetPageWidth(page: FPDF_PAGE) -> f64;
+    fn FPDF_GetPageHeightF(page: FPDF_PAGE) -> c_float;
+    fn FPDF_GetPageHeight(page: FPDF_PAGE) -> f64;
+    fn FPDFText_GetCharIndexFromTextIndex(text_page: FPDF_TEXTPAGE, nTextIndex: c_int) -> c_int;
+    fn FPDFText_GetTextIndexFromCharIndex(text_page: FPDF_TEXTPAGE, nCharIndex: c_int) -> c_int;
+    fn FPDF_GetSignatureCount(document: FPDF_DOCUMENT) -> c_int;
+    fn FPDF_GetSignatureObject(document: FPDF_DOCUMENT, index: c_int) -> FPDF_SIGNATURE;
+    fn FPDFSignatureObj_GetContents(
+        signature: FPDF_SIGNATURE,
+        buffer: *mut c_void,
+        length: c_ulong,
+    ) -> c_ulong;
+    fn FPDFSignatureObj_GetByteRange(
+        signature: FPDF_SIGNATURE,
+        buffer: *mut c_int,
+        length: c_ulong,
+    ) -> c_ulong;
+    fn FPDFSignatureObj_GetSubFilter(
+        signature: FPDF_SIGNATURE,
+        buffer: *mut c_char,
+        length: c_ulong,
+    ) -> c_ulong;
+    fn FPDFSignatureObj_GetReason(
+        signature: FPDF_SIGNATURE,
+        buffer: *mut c_void,
+        length: c_ulong,
+    ) -> c_ulong;
+    fn FPDFSignatureObj_GetTime(
+        signature: FPDF_SIGNATURE,
+        buffer: *mut c_char,
+        length: c_ulong,
+    ) -> c_ulong;
+    fn FPDFSignatureObj_GetDocMDPPermission(signature: FPDF_SIGNATURE) -> c_uint;
+    fn FPDF_StructTree_GetForPage(page: FPDF_PAGE) -> FPDF_STRUCTTREE;
+    fn FPDF_StructTree_Close(struct_tree: FPDF_STRUCTTREE);
+    fn FPDF_StructTree_CountChildren(struct_tree: FPDF_STRUCTTREE) -> c_int;
+    fn FPDF_StructTree_GetChildAtIndex(
+        struct_tree: FPDF_STRUCTTREE,
+        index: c_int,
+    ) -> FPDF_STRUCTELEMENT;
+    fn FPDF_StructElement_GetAltText(
+        struct_element: FPDF_STRUCTELEMENT,
+        buffer: *mut c_void,
+        buflen: c_ulong,
+    ) -> c_ulong;
+    fn FPDF_StructElement_GetActualText(
+        struct_element: FPDF_STRUCTELEMENT,
+        buffer: *mut c_void,
+        buflen: c_ulong,
+    ) -> c_ulong;
+    fn FPDF_StructElement_GetID(
+        struct_element: FPDF_STRUCTELEMENT,
+        buffer: *mut c_void,
+        buflen: c_ulong,
+    ) -> c_ulong;
+    fn FPDF_StructElement_GetLang(
+        struct_element: FPDF_STRUCTELEMENT,
+        buffer: *mut c_void,
+        buflen: c_ulong,
+    ) -> c_ulong;
+    fn FPDF_StructElement_GetStringAttribute(
+        struct_element: FPDF_STRUCTELEMENT,
+        attr_name: &str,
+        buffer: *mut c_void,
+        buflen: c_ulong,
+    ) -> c_ulong;
+    fn FPDF_StructElement_GetMarkedContentID(struct_element: FPDF_STRUCTELEMENT) -> c_int;
+    fn FPDF_StructElement_GetType(
+        struct_element: FPDF_STRUCTELEMENT,
+        buffer: *mut c_void,
+        buflen: c_ulong,
+    ) -> c_ulong;
+    fn FPDF_StructElement_GetObjType(
+        struct_element: FPDF_STRUCTELEMENT,
+        buffer: *mut c_void,
+        buflen: c_ulong,
+    ) -> c_ulong;
+    fn FPDF_StructElement_GetTitle(
+        struct_element: FPDF_STRUCTELEMENT,
+        buffer: *mut c_void,
+        buflen: c_ulong,
+    ) -> c_ulong;
+    fn FPDF_StructElement_CountChildren(struct_element: FPDF_STRUCTELEMENT) -> c_int;
+    fn FPDF_StructElement_GetChildAtIndex(
+        struct_element: FPDF_STRUCTELEMENT,
+        index: c_int,
+    ) -> FPDF_STRUCTELEMENT;
+    #[cfg(any(
+        feature = "pdfium_future",
+        feature = "pdfium_6996",
+        feature = "pdfium_6721",
+        feature = "pdfium_6666",
+        feature = "pdfium_6611",
+        feature = "pdfium_6569",
+        feature = "pdfium_6555",
+        feature = "pdfium_6490",
+        feature = "pdfium_6406",
+        feature = "pdfium_6337",
+        feature = "pdfium_6295",
+        feature = "pdfium_6259",
+        feature = "pdfium_6164",
+        feature = "pdfium_6124",
+        feature = "pdfium_6110",
+        feature = "pdfium_6084",
+    ))]
+    fn FPDF_StructElement_GetChildMarkedContentID(
+        struct_element: FPDF_STRUCTELEMENT,
+        index: c_int,
+    ) -> c_int;
+    fn FPDF_StructElement_GetParent(struct_element: FPDF_STRUCTELEMENT) -> FPDF_STRUCTELEMENT;
+    fn FPDF_StructElement_GetAttributeCount(struct_element: FPDF_STRUCTELEMENT) -> c_int;
+    fn FPDF_StructElement_GetAttributeAtIndex(
+        struct_element: FPDF_STRUCTELEMENT,
+        index: c_int,
+    ) -> FPDF_STRUCTELEMENT_ATTR;
+    fn FPDF_StructElement_Attr_GetCount(struct_attribute: FPDF_STRUCTELEMENT_ATTR) -> c_int;
+    fn FPDF_StructElement_Attr_GetName(
+        struct_attribute: FPDF_STRUCTELEMENT_ATTR,
+        index: c_int,
+        buffer: *mut c_void,
+        buflen: c_ulong,
+        out_buflen: *mut c_ulong,
+    ) -> FPDF_BOOL;
+    #[cfg(any(
+        feature = "pdfium_future",
+        feature = "pdfium_6996",
+        feature = "pdfium_6721",
+        feature = "pdfium_6666",
+        feature = "pdfium_6611",
+        feature = "pdfium_6569",
+        feature = "pdfium_6555",
+        feature = "pdfium_6490",
+    ))]
+    fn FPDF_StructElement_Attr_GetValue(
+        struct_attribute: FPDF_STRUCTELEMENT_ATTR,
+        name: &str,
+    ) -> FPDF_STRUCTELEMENT_ATTR_VALUE;
+    #[cfg(any(
+        feature = "pdfium_6406",
+        feature = "pdfium_6337",
+        feature = "pdfium_6295",
+        feature = "pdfium_6259",
+        feature = "pdfium_6164",
+        feature = "pdfium_6124",
+        feature = "pdfium_6110",
+        feature = "pdfium_6084",
+        feature = "pdfium_6043",
+        feature = "pdfium_6015",
+        feature = "pdfium_5961",
+    ))]
+    fn FPDF_StructElement_Attr_GetType(
+        struct_attribute: FPDF_STRUCTELEMENT_ATTR,
+        name: &str,
+    ) -> FPDF_OBJECT_TYPE;
+    #[cfg(any(
+        feature = "pdfium_future",
+        feature = "pdfium_6996",
+        feature = "pdfium_6721",
+        feature = "pdfium_6666",
+        feature = "pdfium_6611",
+        feature = "pdfium_6569",
+        feature = "pdfium_6555",
+        feature = "pdfium_6490",
+    ))]
+    fn FPDF_StructElement_Attr_GetType(value: FPDF_STRUCTELEMENT_ATTR_VALUE) -> FPDF_OBJECT_TYPE;
+    #[cfg(any(
+        feature = "pdfium_6406",
+        feature = "pdfium_6337",
+        feature = "pdfium_6295",
+        feature = "pdfium_6259",
+        feature = "pdfium_6164",
+        feature = "pdfium_6124",
+        feature = "pdfium_6110",
+        feature = "pdfium_6084",
+        feature = "pdfium_6043",
+        feature = "pdfium_6015",
+        feature = "pdfium_5961",
+    ))]
+    fn FPDF_StructElement_Attr_GetBooleanValue(
+        struct_attribute: FPDF_STRUCTELEMENT_ATTR,
+        name: &str,
+        out_value: *mut FPDF_BOOL,
+    ) -> FPDF_BOOL;
+    #[cfg(any(
+        feature = "pdfium_future",
+        feature = "pdfium_6996",
+        feature = "pdfium_6721",
+        feature = "pdfium_6666",
+        feature = "pdfium_6611",
+        feature = "pdfium_6569",
+        feature = "pdfium_6555",
+        feature = "pdfium_6490",
+    ))]
+    fn FPDF_StructElement_Attr_GetBooleanValue(
+        value: FPDF_STRUCTELEMENT_ATTR_VALUE,
+        out_value: *mut FPDF_BOOL,
+    ) -> FPDF_BOOL;
+    #[cfg(any(
+        feature = "pdfium_6406",
+        feature = "pdfium_6337",
+        feature = "pdfium_6295",
+        feature = "pdfium_6259",
+        feature = "pdfium_6164",
+        feature = "pdfium_6124",
+        feature = "pdfium_6110",
+        feature = "pdfium_6084",
+        feature = "pdfium_6043",
+        feature = "pdfium_6015",
+        feature = "pdfium_5961",
+    ))]
+    fn FPDF_StructElement_Attr_GetNumberValue(
+        struct_attribute: FPDF_STRUCTELEMENT_ATTR,
+        name: &str,
+        out_value: *mut f32,
+    ) -> FPDF_BOOL;
+    #[cfg(any(
+        feature = "pdfium_future",
+        feature = "pdfium_6996",
+        feature = "pdfium_6721",
+        feature = "pdfium_6666",
+        feature = "pdfium_6611",
+        feature = "pdfium_6569",
+        feature = "pdfium_6555",
+        feature = "pdfium_6490",
+    ))]
+    fn FPDF_StructElement_Attr_GetNumberValue(
+        value: FPDF_STRUCTELEMENT_ATTR_VALUE,
+        out_value: *mut f32,
+    ) -> FPDF_BOOL;
+    #[cfg(any(
+        feature = "pdfium_6406",
+        feature = "pdfium_6337",
+        feature = "pdfium_6295",
+        feature = "pdfium_6259",
+        feature = "pdfium_6164",
+        feature = "pdfium_6124",
+        feature = "pdfium_6110",
+        feature = "pdfium_6084",
+        feature = "pdfium_6043",
+        feature = "pdfium_6015",
+        feature = "pdfium_5961",
+    ))]
+    fn FPDF_StructElement_Attr_GetStringValue(
+        struct_attribute: FPDF_STRUCTELEMENT_ATTR,
+        name: &str,
+        buffer: *mut c_void,
+        buflen: c_ulong,
+        out_buflen: *mut c_ulong,
+    ) -> FPDF_BOOL;
+    #[cfg(any(
+        feature = "pdfium_future",
+        feature = "pdfium_6996",
+        feature = "pdfium_6721",
+        feature = "pdfium_6666",
+        feature = "pdfium_6611",
+        feature = "pdfium_6569",
+        feature = "pdfium_6555",
+        feature = "pdfium_6490",
+    ))]
+    fn FPDF_StructElement_Attr_GetStringValue(
+        value: FPDF_STRUCTELEMENT_ATTR_VALUE,
+        buffer: *mut c_void,
+        buflen: c_ulong,
+        out_buflen: *mut c_ulong,
+    ) -> FPDF_BOOL;
+    #[cfg(any(
+        feature = "pdfium_6406",
+        feature = "pdfium_6337",
+        feature = "pdfium_6295",
+        feature = "pdfium_6259",
+        feature = "pdfium_6164",
+        feature = "pdfium_6124",
+        feature = "pdfium_6110",
+        feature = "pdfium_6084",
+        feature = "pdfium_6043",
+        feature = "pdfium_6015",
+        feature = "pdfium_5961",
+    ))]
+    fn FPDF_StructElement_Attr_GetBlobValue(
+        struct_attribute: FPDF_STRUCTELEMENT_ATTR,
+        name: &str,
+        buffer: *mut c_void,
+        buflen: c_ulong,
+        out_buflen: *mut c_ulong,
+    ) -> FPDF_BOOL;
+    #[cfg(any(
+        feature = "pdfium_future",
+        feature = "pdfium_6996",
+        feature = "pdfium_6721",
+        feature = "pdfium_6666",
+        feature = "pdfium_6611",
+        feature = "pdfium_6569",
+        feature = "pdfium_6555",
+        feature = "pdfium_6490",
+    ))]
+    fn FPDF_StructElement_Attr_GetBlobValue(
+        value: FPDF_STRUCTELEMENT_ATTR_VALUE,
+        buffer: *mut c_void,
+        buflen: c_ulong,
+        out_buflen: *mut c_ulong,
+    ) -> FPDF_BOOL;
+    #[cfg(any(
+        feature = "pdfium_future",
+        feature = "pdfium_6996",
+        feature = "pdfium_6721",
+        feature = "pdfium_6666",
+        feature = "pdfium_6611",
+        feature = "pdfium_6569",
+        feature = "pdfium_6555",
+        feature = "pdfium_6490",
+    ))]
+    fn FPDF_StructElement_Attr_CountChildren(value: FPDF_STRUCTELEMENT_ATTR_VALUE) -> c_int;
+    #[cfg(any(
+        feature = "pdfium_future",
+        feature = "pdfium_6996",
+        feature = "pdfium_6721",
+        feature = "pdfium_6666",
+        feature = "pdfium_6611",
+        feature = "pdfium_6569",
+        feature = "pdfium_6555",
+        feature = "pdfium_6490",
+    ))]
+    fn FPDF_StructElement_Attr_GetChildAtIndex(
+        value: FPDF_STRUCTELEMENT_ATTR_VALUE,
+        index: c_int,
+    ) -> FPDF_STRUCTELEMENT_ATTR_VALUE;
+    fn FPDF_StructElement_GetMarkedContentIdCount(struct_element: FPDF_STRUCTELEMENT) -> c_int;
+    fn FPDF_StructElement_GetMarkedContentIdAtIndex(
+        struct_element: FPDF_STRUCTELEMENT,
+        index: c_int,
+    ) -> c_int;
+    fn FPDFPage_New(
+        document: FPDF_DOCUMENT,
+        page_index: c_int,
+        width: c_double,
+        height: c_double,
+    ) -> FPDF_PAGE;
+    fn FPDFPage_Delete(document: FPDF_DOCUMENT, page_index: c_int);
+    #[cfg(any(
+        feature = "pdfium_future",
+        feature = "pdfium_6996",
+        feature = "pdfium_6721",
+        feature = "pdfium_6666",
+        feature = "pdfium_6611",
+        feature = "pdfium_6569",
+        feature = "pdfium_6555",
+        feature = "pdfium_6490",
+        feature = "pdfium_6406",
+        feature = "pdfium_6337",
+        feature = "pdfium_6295",
+        feature = "pdfium_6259",
+        feature = "pdfium_6164",
+        feature = "pdfium_6124",
+        feature = "pdfium_6110",
+        feature = "pdfium_6084",
+        feature = "pdfium_6043",
+    ))]
+    fn FPDF_MovePages(
+        document: FPDF_DOCUMENT,
+        page_indices: *const c_int,
+        page_indices_len: c_ulong,
+        dest_page_index: c_int,
+    ) -> FPDF_BOOL;
+    fn FPDFPage_GetRotation(page: FPDF_PAGE) -> c_int;
+    fn FPDFPage_SetRotation(page: FPDF_PAGE, rotate: c_int);
+    fn FPDF_GetPageBoundingBox(page: FPDF_PAGE, rect: *mut FS_RECTF) -> FPDF_BOOL;
+    fn FPDF_GetPageSizeByIndexF(
+        document: FPDF_DOCUMENT,
+        page_index: c_int,
+        size: *mut FS_SIZEF,
+    ) -> FPDF_BOOL;
+    fn FPDF_GetPageSizeByIndex(
+        document: FPDF_DOCUMENT,
+        page_index: c_int,
+        width: *mut f64,
+        height: *mut f64,
+    ) -> c_int;
+    fn FPDFPage_GetMediaBox(
+        page: FPDF_PAGE,
+        left: *mut c_float,
+        bottom: *mut c_float,
+        right: *mut c_float,
+        top: *mut c_float,
+    ) -> FPDF_BOOL;
+    fn FPDFPage_GetCropBox(
+        page: FPDF_PAGE,
+        left: *mut c_float,
+        bottom: *mut c_float,
+        right: *mut c_float,
+        top: *mut c_float,
+    ) -> FPDF_BOOL;
+    fn FPDFPage_GetBleedBox(
+        page: FPDF_PAGE,
+        left: *mut c_float,
+        bottom: *mut c_float,
+        right: *mut c_float,
+        top: *mut c_float,
+    ) -> FPDF_BOOL;
+    fn FPDFPage_GetTrimBox(
+        page: FPDF_PAGE,
+        left: *mut c_float,
+        bottom: *mut c_float,
+        right: *mut c_float,
+        top: *mut c_float,
+    ) -> FPDF_BOOL;
+    fn FPDFPage_GetArtBox(
+        page: FPDF_PAGE,
+        left: *mut c_float,
+        bottom: *mut c_float,
+        right: *mut c_float,
+        top: *mut c_float,
+    ) -> FPDF_BOOL;
+    fn FPDFPage_SetMediaBox(
+        page: FPDF_PAGE,
+        left: c_float,
+        bottom: c_float,
+        right: c_float,
+        top: c_float,
+    );
+    fn FPDFPage_SetCropBox(
+        page: FPDF_PAGE,
+        left: c_float,
+        bottom: c_float,
+        right: c_float,
+        top: c_float,
+    );
+    fn FPDFPage_SetBleedBox(
+        page: FPDF_PAGE,
+        left: c_float,
+        bottom: c_float,
+        right: c_float,
+        top: c_float,
+    );
+    fn FPDFPage_SetTrimBox(
+        page: FPDF_PAGE,
+        left: c_float,
+        bottom: c_float,
+        right: c_float,
+        top: c_float,
+    );
+    fn FPDFPage_SetArtBox(
+        page: FPDF_PAGE,
+        left: c_float,
+        bottom: c_float,
+        right: c_float,
+        top: c_float,
+    );
+    fn FPDFPage_TransFormWithClip(
+        page: FPDF_PAGE,
+        matrix: *const FS_MATRIX,
+        clipRect: *const FS_RECTF,
+    ) -> FPDF_BOOL;
+    fn FPDFPageObj_TransformClipPath(
+        page_object: FPDF_PAGEOBJECT,
+        a: f64,
+        b: f64,
+        c: f64,
+        d: f64,
+        e: f64,
+        f: f64,
+    );
+    fn FPDFPageObj_GetClipPath(page_object: FPDF_PAGEOBJECT) -> FPDF_CLIPPATH;
+    fn FPDFClipPath_CountPaths(clip_path: FPDF_CLIPPATH) -> c_int;
+    fn FPDFClipPath_CountPathSegments(clip_path: FPDF_CLIPPATH, path_index: c_int) -> c_int;
+    fn FPDFClipPath_GetPathSegment(
+        clip_path: FPDF_CLIPPATH,
+        path_index: c_int,
+        segment_index: c_int,
+    ) -> FPDF_PATHSEGMENT;
+    fn FPDF_CreateClipPath(left: f32, bottom: f32, right: f32, top: f32) -> FPDF_CLIPPATH;
+    fn FPDF_DestroyClipPath(clipPath: FPDF_CLIPPATH);
+    fn FPDFPage_InsertClipPath(page: FPDF_PAGE, clipPath: FPDF_CLIPPATH);
+    fn FPDFPage_HasTransparency(page: FPDF_PAGE) -> FPDF_BOOL;
+    fn FPDFPage_GenerateContent(page: FPDF_PAGE) -> FPDF_BOOL;
+    fn FPDFPage_TransformAnnots(page: FPDF_PAGE, a: f64, b: f64, c: f64, d: f64, e: f64, f: f64);
+    fn FPDFBitmap_Create(width: c_int, height: c_int, alpha: c_int) -> FPDF_BITMAP;
+    fn FPDFBitmap_CreateEx(
+        width: c_int,
+        height: c_int,
+        format: c_int,
+        first_scan: *mut c_void,
+        stride: c_int,
+    ) -> FPDF_BITMAP;
+    fn FPDFBitmap_GetFormat(bitmap: FPDF_BITMAP) -> c_int;
+    #[cfg(any(
+        feature = "pdfium_6611",
+        feature = "pdfium_6569",
+        feature = "pdfium_6555",
+        feature = "pdfium_6490",
+        feature = "pdfium_6406",
+        feature = "pdfium_6337",
+        feature = "pdfium_6295",
+        feature = "pdfium_6259",
+        feature = "pdfium_6164",
+        feature = "pdfium_6124",
+        feature = "pdfium_6110",
+        feature = "pdfium_6084",
+        feature = "pdfium_6043",
+        feature = "pdfium_6015",
+        feature = "pdfium_5961",
+    ))]
+    fn FPDFBitmap_FillRect(
+        bitmap: FPDF_BITMAP,
+        left: c_int,
+        top: c_int,
+        width: c_int,
+        height: c_int,
+        color: FPDF_DWORD,
+    );
+    #[cfg(any(
+        feature = "pdfium_future",
+        feature = "pdfium_6996",
+        feature = "pdfium_6721",
+        feature = "pdfium_6666",
+    ))]
+    fn FPDFBitmap_FillRect(
+        bitmap: FPDF_BITMAP,
+        left: c_int,
+        top: c_int,
+        width: c_int,
+        height: c_int,
+        color: FPDF_DWORD,
+    ) -> FPDF_BOOL;
+    #[cfg(not(target_arch = "wasm32"))]
+    fn FPDFBitmap_GetBuffer(bitmap: FPDF_BITMAP) -> *mut c_void;
+    #[cfg(target_arch = "wasm32")]
+    fn FPDFBitmap_GetBuffer(bitmap: FPDF_BITMAP) -> *const c_void;
+    #[cfg(target_arch = "wasm32")]
+    fn FPDFBitmap_SetBuffer(bitmap: FPDF_BITMAP, buffer: &[u8]) -> bool;
+    #[cfg(target_arch = "wasm32")]
+    fn FPDFBitmap_GetBuffer_as_array(bitmap: FPDF_BITMAP) -> js_sys::Uint8Array;
+    fn FPDFBitmap_GetWidth(bitmap: FPDF_BITMAP) -> c_int;
+    fn FPDFBitmap_GetHeight(bitmap: FPDF_BITMAP) -> c_int;
+    fn FPDFBitmap_GetStride(bitmap: FPDF_BITMAP) -> c_int;
+    fn FPDFBitmap_Destroy(bitmap: FPDF_BITMAP);
+    #[cfg(not(target_arch = "wasm32"))]
+    #[cfg(target_os = "windows")]
+    #[cfg(feature = "pdfium_use_win32")]
+    fn FPDF_RenderPage(
+        dc: windows::Win32::Graphics::Gdi::HDC,
+        page: FPDF_PAGE,
+        start_x: c_int,
+        start_y: c_int,
+        size_x: c_int,
+        size_y: c_int,
+        rotate: c_int,
+        flags: c_int,
+    );
+    fn FPDF_RenderPageBitmap(
+        bitmap: FPDF_BITMAP,
+        page: FPDF_PAGE,
+        start_x: c_int,
+        start_y: c_int,
+        size_x: c_int,
+        size_y: c_int,
+        rotate: c_int,
+        flags: c_int,
+    );
+    fn FPDF_RenderPageBitmapWithMatrix(
+        bitmap: FPDF_BITMAP,
+        page: FPDF_PAGE,
+        matrix: *const FS_MATRIX,
+        clipping: *const FS_RECTF,
+        flags: c_int,
+    );
+    #[cfg(feature = "pdfium_use_skia")]
+    fn FPDF_RenderPageSkia(canvas: FPDF_SKIA_CANVAS, page: FPDF_PAGE, size_x: c_int, size_y: c_int);
+    fn FPDFAnnot_IsSupportedSubtype(subtype: FPDF_ANNOTATION_SUBTYPE) -> FPDF_BOOL;
+    fn FPDFPage_CreateAnnot(page: FPDF_PAGE, subtype: FPDF_ANNOTATION_SUBTYPE) -> FPDF_ANNOTATION;
+    fn FPDFPage_GetAnnotCount(page: FPDF_PAGE) -> c_int;
+    fn FPDFPage_GetAnnot(page: FPDF_PAGE, index: c_int) -> FPDF_ANNOTATION;
+    fn FPDFPage_GetAnnotIndex(page: FPDF_PAGE, annot: FPDF_ANNOTATION) -> c_int;
+    fn FPDFPage_CloseAnnot(annot: FPDF_ANNOTATION);
+    fn FPDFPage_RemoveAnnot(page: FPDF_PAGE, index: c_int) -> FPDF_BOOL;
+    fn FPDFAnnot_GetSubtype(annot: FPDF_ANNOTATION) -> FPDF_ANNOTATION_SUBTYPE;
+    fn FPDFAnnot_IsObjectSupportedSubtype(subtype: FPDF_ANNOTATION_SUBTYPE) -> FPDF_BOOL;
+    fn FPDFAnnot_UpdateObject(annot: FPDF_ANNOTATION, obj: FPDF_PAGEOBJECT) -> FPDF_BOOL;
+    fn FPDFAnnot_AddInkStroke(
+        annot: FPDF_ANNOTATION,
+        points: *const FS_POINTF,
+        point_count: size_t,
+    ) -> c_int;
+    fn FPDFAnnot_RemoveInkList(annot: FPDF_ANNOTATION) -> FPDF_BOOL;
+    fn FPDFAnnot_AppendObject(annot: FPDF_ANNOTATION, obj: FPDF_PAGEOBJECT) -> FPDF_BOOL;
+    fn FPDFAnnot_GetObjectCount(annot: FPDF_ANNOTATION) -> c_int;
+    fn FPDFAnnot_GetObject(annot: FPDF_ANNOTATION, index: c_int) -> FPDF_PAGEOBJECT;
+    fn FPDFAnnot_RemoveObject(annot: FPDF_ANNOTATION, index: c_int) -> FPDF_BOOL;
+    fn FPDFAnnot_SetColor(
+        annot: FPDF_ANNOTATION,
+        color_type: FPDFANNOT_COLORTYPE,
+        R: c_uint,
+        G: c_uint,
+        B: c_uint,
+        A: c_uint,
+    ) -> FPDF_BOOL;
+    fn FPDFAnnot_GetColor(
+        annot: FPDF_ANNOTATION,
+        color_type: FPDFANNOT_COLORTYPE,
+        R: *mut c_uint,
+        G: *mut c_uint,
+        B: *mut c_uint,
+        A: *mut c_uint,
+    ) -> FPDF_BOOL;
+    fn FPDFAnnot_HasAttachmentPoints(annot: FPDF_ANNOTATION) -> FPDF_BOOL;
+    fn FPDFAnnot_SetAttachmentPoints(
+        annot: FPDF_ANNOTATION,
+        quad_index: size_t,
+        quad_points: *const FS_QUADPOINTSF,
+    ) -> FPDF_BOOL;
+    fn FPDFAnnot_AppendAttachmentPoints(
+        annot: FPDF_ANNOTATION,
+        quad_points: *const FS_QUADPOINTSF,
+    ) -> FPDF_BOOL;
+    fn FPDFAnnot_CountAttachmentPoints(annot: FPDF_ANNOTATION) -> size_t;
+    fn FPDFAnnot_GetAttachmentPoints(
+        annot: FPDF_ANNOTATION,
+        quad_index: size_t,
+        quad_points: *mut FS_QUADPOINTSF,
+    ) -> FPDF_BOOL;
+    fn FPDFAnnot_SetRect(annot: FPDF_ANNOTATION, rect: *const FS_RECTF) -> FPDF_BOOL;
+    fn FPDFAnnot_GetRect(annot: FPDF_ANNOTATION, rect: *mut FS_RECTF) -> FPDF_BOOL;
+    fn FPDFAnnot_GetVertices(
+        annot: FPDF_ANNOTATION,
+        buffer: *mut FS_POINTF,
+        length: c_ulong,
+    ) -> c_ulong;
+    fn FPDFAnnot_GetInkListCount(annot: FPDF_ANNOTATION) -> c_ulong;
+    fn FPDFAnnot_GetInkListPath(
+        annot: FPDF_ANNOTATION,
+        path_index: c_ulong,
+        buffer: *mut FS_POINTF,
+        length: c_ulong,
+    ) -> c_ulong;
+    fn FPDFAnnot_GetLine(
+        annot: FPDF_ANNOTATION,
+        start: *mut FS_POINTF,
+        end: *mut FS_POINTF,
+    ) -> FPDF_BOOL;
+    fn FPDFAnnot_SetBorder(
+        annot: FPDF_ANNOTATION,
+        horizontal_radius: c_float,
+        vertical_radius: c_float,
+        border_width: c_float,
+    ) -> FPDF_BOOL;
+    fn FPDFAnnot_GetBorder(
+        annot: FPDF_ANNOTATION,
+        horizontal_radius: *mut c_float,
+        vertical_radius: *mut c_float,
+        border_width: *mut c_float,
+    ) -> FPDF_BOOL;
+    fn FPDFAnnot_GetFormAdditionalActionJavaScript(
+        hHandle: FPDF_FORMHANDLE,
+        annot: FPDF_ANNOTATION,
+        event: c_int,
+        buffer: *mut FPDF_WCHAR,
+        buflen: c_ulong,
+    ) -> c_ulong;
+    fn FPDFAnnot_GetFormFieldAlternateName(
+        hHandle: FPDF_FORMHANDLE,
+        annot: FPDF_ANNOTATION,
+        buffer: *mut FPDF_WCHAR,
+        buflen: c_ulong,
+    ) -> c_ulong;
+    fn FPDFAnnot_HasKey(annot: FPDF_ANNOTATION, key: &str) -> FPDF_BOOL;
+    fn FPDFAnnot_GetValueType(annot: FPDF_ANNOTATION, key: &str) -> FPDF_OBJECT_TYPE;
+    fn FPDFAnnot_SetStringValue(
+        annot: FPDF_ANNOTATION,
+        key: &str,
+        value: FPDF_WIDESTRING,
+    ) -> FPDF_BOOL;
+    fn FPDFAnnot_GetStringValue(
+        annot: FPDF_ANNOTATION,
+        key: &str,
+        buffer: *mut FPDF_WCHAR,
+        buflen: c_ulong,
+    ) -> c_ulong;
+    fn FPDFAnnot_GetNumberValue(
+        annot: FPDF_ANNOTATION,
+        key: &str,
+        value: *mut c_float,
+    ) -> FPDF_BOOL;
+    fn FPDFAnnot_SetAP(
+        annot: FPDF_ANNOTATION,
+        appearanceMode: FPDF_ANNOT_APPEARANCEMODE,
+        value: FPDF_WIDESTRING,
+    ) -> FPDF_BOOL;
+    fn FPDFAnnot_GetAP(
+        annot: FPDF_ANNOTATION,
+        appearanceMode: FPDF_ANNOT_APPEARANCEMODE,
+        buffer: *mut FPDF_WCHAR,
+        buflen: c_ulong,
+    ) -> c_ulong;
+    fn FPDFAnnot_GetLinkedAnnot(annot: FPDF_ANNOTATION, key: &str) -> FPDF_ANNOTATION;
+    fn FPDFAnnot_GetFlags(annot: FPDF_ANNOTATION) -> c_int;
+    fn FPDFAnnot_SetFlags(annot: FPDF_ANNOTATION, flags: c_int) -> FPDF_BOOL;
+    fn FPDFAnnot_GetFormFieldFlags(hHandle: FPDF_FORMHANDLE, annot: FPDF_ANNOTATION) -> c_int;
+    fn FPDFAnnot_GetFormFieldAtPoint(
+        hHandle: FPDF_FORMHANDLE,
+        page: FPDF_PAGE,
+        point: *const FS_POINTF,
+    ) -> FPDF_ANNOTATION;
+    fn FPDFAnnot_GetFormFieldName(
+        hHandle: FPDF_FORMHANDLE,
+        annot: FPDF_ANNOTATION,
+        buffer: *mut FPDF_WCHAR,
+        buflen: c_ulong,
+    ) -> c_ulong;
+    fn FPDFAnnot_GetFormFieldType(hHandle: FPDF_FORMHANDLE, annot: FPDF_ANNOTATION) -> c_int;
+    fn FPDFAnnot_GetFormFieldValue(
+        hHandle: FPDF_FORMHANDLE,
+        annot: FPDF_ANNOTATION,
+        buffer: *mut FPDF_WCHAR,
+        buflen: c_ulong,
+    ) -> c_ulong;
+    fn FPDFAnnot_GetOptionCount(hHandle: FPDF_FORMHANDLE, annot: FPDF_ANNOTATION) -> c_int;
+    fn FPDFAnnot_GetOptionLabel(
+        hHandle: FPDF_FORMHANDLE,
+        annot: FPDF_ANNOTATION,
+        index: c_int,
+        buffer: *mut FPDF_WCHAR,
+        buflen: c_ulong,
+    ) -> c_ulong;
+    fn FPDFAnnot_IsOptionSelected(
+        hHandle: FPDF_FORMHANDLE,
+        annot: FPDF_ANNOTATION,
+        index: c_int,
+    ) -> FPDF_BOOL;
+    fn FPDFAnnot_GetFontSize(
+        hHandle: FPDF_FORMHANDLE,
+        annot: FPDF_ANNOTATION,
+        value: *mut c_float,
+    ) -> FPDF_BOOL;
+    #[cfg(any(
+        feature = "pdfium_future",
+        feature = "pdfium_6996",
+        feature = "pdfium_6721",
+        feature = "pdfium_6666",
+        feature = "pdfium_6611",
+        feature = "pdfium_6569",
+        feature = "pdfium_6555",
+    ))]
+    fn FPDFAnnot_GetFontColor(
+        hHandle: FPDF_FORMHANDLE,
+        annot: FPDF_ANNOTATION,
+        R: *mut c_uint,
+        G: *mut c_uint,
+        B: *mut c_uint,
+    ) -> FPDF_BOOL;
+    fn FPDFAnnot_IsChecked(hHandle: FPDF_FORMHANDLE, annot: FPDF_ANNOTATION) -> FPDF_BOOL;
+    fn FPDFAnnot_SetFocusableSubtypes(
+        hHandle: FPDF_FORMHANDLE,
+        subtypes: *const FPDF_ANNOTATION_SUBTYPE,
+        count: size_t,
+    ) -> FPDF_BOOL;
+    fn FPDFAnnot_GetFocusableSubtypesCount(hHandle: FPDF_FORMHANDLE) -> c_int;
+    fn FPDFAnnot_GetFocusableSubtypes(
+        hHandle: FPDF_FORMHANDLE,
+        subtypes: *mut FPDF_ANNOTATION_SUBTYPE,
+        count: size_t,
+    ) -> FPDF_BOOL;
+    fn FPDFAnnot_GetLink(annot: FPDF_ANNOTATION) -> FPDF_LINK;
+    fn FPDFAnnot_GetFormControlCount(hHandle: FPDF_FORMHANDLE, annot: FPDF_ANNOTATION) -> c_int;
+    fn FPDFAnnot_GetFormControlIndex(hHandle: FPDF_FORMHANDLE, annot: FPDF_ANNOTATION) -> c_int;
+    fn FPDFAnnot_GetFormFieldExportValue(
+        hHandle: FPDF_FORMHANDLE,
+        annot: FPDF_ANNOTATION,
+        buffer: *mut FPDF_WCHAR,
+        buflen: c_ulong,
+    ) -> c_ulong;
+    fn FPDFAnnot_SetURI(annot: FPDF_ANNOTATION, uri: &str) -> FPDF_BOOL;
+    #[cfg(any(
+        feature = "pdfium_future",
+        feature = "pdfium_6996",
+        feature = "pdfium_6721",
+        feature = "pdfium_6666",
+        feature = "pdfium_6611",
+        feature = "pdfium_6569",
+        feature = "pdfium_6555",
+        feature = "pdfium_6490",
+        feature = "pdfium_6406",
+        feature = "pdfium_6337",
+    ))]
+    fn FPDFAnnot_GetFileAttachment(annot: FPDF_ANNOTATION) -> FPDF_ATTACHMENT;
+    #[cfg(any(
+        feature = "pdfium_future",
+        feature = "pdfium_6996",
+        feature = "pdfium_6721",
+        feature = "pdfium_6666",
+        feature = "pdfium_6611",
+        feature = "pdfium_6569",
+        feature = "pdfium_6555",
+        feature = "pdfium_6490",
+        feature = "pdfium_6406",
+        feature = "pdfium_6337",
+    ))]
+    fn FPDFAnnot_AddFileAttachment(
+        annot: FPDF_ANNOTATION,
+        name: FPDF_WIDESTRING,
+    ) -> FPDF_ATTACHMENT;
+    fn FPDFDOC_InitFormFillEnvironment(
+        document: FPDF_DOCUMENT,
+        form_info: *mut FPDF_FORMFILLINFO,
+    ) -> FPDF_FORMHANDLE;
+    fn FPDFDOC_ExitFormFillEnvironment(hHandle: FPDF_FORMHANDLE);
+    fn FORM_OnAfterLoadPage(page: FPDF_PAGE, hHandle: FPDF_FORMHANDLE);
+    fn FORM_OnBeforeClosePage(page: FPDF_PAGE, hHandle: FPDF_FORMHANDLE);
+    fn FPDFDoc_GetPageMode(document: FPDF_DOCUMENT) -> c_int;
+    fn FPDFPage_Flatten(page: FPDF_PAGE, nFlag: c_int) -> c_int;
+    fn FORM_DoDocumentJSAction(hHandle: FPDF_FORMHANDLE);
+    fn FORM_DoDocumentOpenAction(hHandle: FPDF_FORMHANDLE);
+    fn FORM_DoDocumentAAction(hHandle: FPDF_FORMHANDLE, aaType: c_int);
+    fn FORM_DoPageAAction(page: FPDF_PAGE, hHandle: FPDF_FORMHANDLE, aaType: c_int);
+    fn FORM_OnMouseMove(
+        hHandle: FPDF_FORMHANDLE,
+        page: FPDF_PAGE,
+        modifier: c_int,
+        page_x: f64,
+        page_y: f64,
+    ) -> FPDF_BOOL;
+    fn FORM_OnMouseWheel(
+        hHandle: FPDF_FORMHANDLE,
+        page: FPDF_PAGE,
+        modifier: c_int,
+        page_coord: *const FS_POINTF,
+        delta_x: c_int,
+        delta_y: c_int,
+    ) -> FPDF_BOOL;
+    fn FORM_OnFocus(
+        hHandle: FPDF_FORMHANDLE,
+        page: FPDF_PAGE,
+        modifier: c_int,
+        page_x: f64,
+        page_y: f64,
+    ) -> FPDF_BOOL;
+    fn FORM_OnLButtonDown(
+        hHandle: FPDF_FORMHANDLE,
+        page: FPDF_PAGE,
+        modifier: c_int,
+        page_x: f64,
+        page_y: f64,
+    ) -> FPDF_BOOL;
+    fn FORM_OnRButtonDown(
+        hHandle: FPDF_FORMHANDLE,
+        page: FPDF_PAGE,
+        modifier: c_int,
+        page_x: f64,
+        page_y: f64,
+    ) -> FPDF_BOOL;
+    fn FORM_OnLButtonUp(
+        hHandle: FPDF_FORMHANDLE,
+        page: FPDF_PAGE,
+        modifier: c_int,
+        page_x: f64,
+        page_y: f64,
+    ) -> FPDF_BOOL;
+    fn FORM_OnRButtonUp(
+        hHandle: FPDF_FORMHANDLE,
+        page: FPDF_PAGE,
+        modifier: c_int,
+        page_x: f64,
+        page_y: f64,
+    ) -> FPDF_BOOL;
+    fn FORM_OnLButtonDoubleClick(
+        hHandle: FPDF_FORMHANDLE,
+        page: FPDF_PAGE,
+        modifier: c_int,
+        page_x: f64,
+        page_y: f64,
+    ) -> FPDF_BOOL;
+    fn FORM_OnKeyDown(
+        hHandle: FPDF_FORMHANDLE,
+        page: FPDF_PAGE,
+        nKeyCode: c_int,
+        modifier: c_int,
+    ) -> FPDF_BOOL;
+    fn FORM_OnKeyUp(
+        hHandle: FPDF_FORMHANDLE,
+        page: FPDF_PAGE,
+        nKeyCode: c_int,
+        modifier: c_int,
+    ) -> FPDF_BOOL;
+    fn FORM_OnChar(
+        hHandle: FPDF_FORMHANDLE,
+        page: FPDF_PAGE,
+        nChar: c_int,
+        modifier: c_int,
+    ) -> FPDF_BOOL;
+    fn FORM_GetFocusedText(
+        hHandle: FPDF_FORMHANDLE,
+        page: FPDF_PAGE,
+        buffer: *mut c_void,
+        buflen: c_ulong,
+    ) -> c_ulong;
+    fn FORM_GetSelectedText(
+        hHandle: FPDF_FORMHANDLE,
+        page: FPDF_PAGE,
+        buffer: *mut c_void,
+        buflen: c_ulong,
+    ) -> c_ulong;
+    fn FORM_ReplaceAndKeepSelection(
+        hHandle: FPDF_FORMHANDLE,
+        page: FPDF_PAGE,
+        wsText: FPDF_WIDESTRING,
+    );
+    fn FORM_ReplaceSelection(hHandle: FPDF_FORMHANDLE, page: FPDF_PAGE, wsText: FPDF_WIDESTRING);
+    fn FORM_SelectAllText(hHandle: FPDF_FORMHANDLE, page: FPDF_PAGE) -> FPDF_BOOL;
+    fn FORM_CanUndo(hHandle: FPDF_FORMHANDLE, page: FPDF_PAGE) -> FPDF_BOOL;
+    fn FORM_CanRedo(hHandle: FPDF_FORMHANDLE, page: FPDF_PAGE) -> FPDF_BOOL;
+    fn FORM_Undo(hHandle: FPDF_FORMHANDLE, page: FPDF_PAGE) -> FPDF_BOOL;
+    fn FORM_Redo(hHandle: FPDF_FORMHANDLE, page: FPDF_PAGE) -> FPDF_BOOL;
+    fn FORM_ForceToKillFocus(hHandle: FPDF_FORMHANDLE) -> FPDF_BOOL;
+    fn FORM_GetFocusedAnnot(
+        handle: FPDF_FORMHANDLE,
+        page_index: *mut c_int,
+        annot: *mut FPDF_ANNOTATION,
+    ) -> FPDF_BOOL;
+    fn FORM_SetFocusedAnnot(handle: FPDF_FORMHANDLE, annot: FPDF_ANNOTATION) -> FPDF_BOOL;
+    fn FPDFPage_HasFormFieldAtPoint(
+        hHandle: FPDF_FORMHANDLE,
+        page: FPDF_PAGE,
+        page_x: f64,
+        page_y: f64,
+    ) -> c_int;
+    fn FPDFPage_FormFieldZOrderAtPoint(
+        hHandle: FPDF_FORMHANDLE,
+        page: FPDF_PAGE,
+        page_x: f64,
+        page_y: f64,
+    ) -> c_int;
+    fn FPDF_SetFormFieldHighlightColor(
+        handle: FPDF_FORMHANDLE,
+        field_type: c_int,
+        color: FPDF_DWORD,
+    );
+    fn FPDF_SetFormFieldHighlightAlpha(handle: FPDF_FORMHANDLE, alpha: c_uchar);
+    fn FPDF_RemoveFormFieldHighlight(hHandle: FPDF_FORMHANDLE);
+    fn FPDF_FFLDraw(
+        handle: FPDF_FORMHANDLE,
+        bitmap: FPDF_BITMAP,
+        page: FPDF_PAGE,
+        start_x: c_int,
+        start_y: c_int,
+        size_x: c_int,
+        size_y: c_int,
+        rotate: c_int,
+        flags: c_int,
+    );
+    #[cfg(feature = "pdfium_use_skia")]
+    fn FPDF_FFLDrawSkia(
+        hHandle: FPDF_FORMHANDLE,
+        canvas: FPDF_SKIA_CANVAS,
+        page: FPDF_PAGE,
+        start_x: c_int,
+        start_y: c_int,
+        size_x: c_int,
+        size_y: c_int,
+        rotate: c_int,
+        flags: c_int,
+    );
+    fn FPDF_GetFormType(document: FPDF_DOCUMENT) -> c_int;
+    fn FORM_SetIndexSelected(
+        hHandle: FPDF_FORMHANDLE,
+        page: FPDF_PAGE,
+        index: c_int,
+        selected: FPDF_BOOL,
+    ) -> FPDF_BOOL;
+    fn FORM_IsIndexSelected(hHandle: FPDF_FORMHANDLE, page: FPDF_PAGE, index: c_int) -> FPDF_BOOL;
+    fn FPDF_LoadXFA(document: FPDF_DOCUMENT) -> FPDF_BOOL;
+    fn FPDFDoc_GetJavaScriptActionCount(document: FPDF_DOCUMENT) -> c_int;
+    fn FPDFDoc_GetJavaScriptAction(document: FPDF_DOCUMENT, index: c_int) -> FPDF_JAVASCRIPT_ACTION;
+    fn FPDFDoc_CloseJavaScriptAction(javascript: FPDF_JAVASCRIPT_ACTION);
+    fn FPDFJavaScriptAction_GetName(
+        javascript: FPDF_JAVASCRIPT_ACTION,
+        buffer: *mut FPDF_WCHAR,
+        buflen: c_ulong,
+    ) -> c_ulong;
+    fn FPDFJavaScriptAction_GetScript(
+        javascript: FPDF_JAVASCRIPT_ACTION,
+        buffer: *mut FPDF_WCHAR,
+        buflen: c_ulong,
+    ) -> c_ulong;
+    fn FPDF_GetDefaultTTFMap() -> *const FPDF_CharsetFontMap;
+    #[cfg(any(
+        feature = "pdfium_future",
+        feature = "pdfium_6996",
+        feature = "pdfium_6721",
+        feature = "pdfium_6666",
+        feature = "pdfium_6611",
+        feature = "pdfium_6569",
+    ))]
+    fn FPDF_GetDefaultTTFMapCount() -> usize;
+    #[cfg(any(
+        feature = "pdfium_future",
+        feature = "pdfium_6996",
+        feature = "pdfium_6721",
+        feature = "pdfium_6666",
+        feature = "pdfium_6611",
+        feature = "pdfium_6569",
+    ))]
+    fn FPDF_GetDefaultTTFMapEntry(index: usize) -> *const FPDF_CharsetFontMap;
+    fn FPDF_AddInstalledFont(mapper: *mut c_void, face: &str, charset: c_int);
+    fn FPDF_SetSystemFontInfo(pFontInfo: *mut FPDF_SYSFONTINFO);
+    fn FPDF_GetDefaultSystemFontInfo() -> *mut FPDF_SYSFONTINFO;
+    fn FPDF_FreeDefaultSystemFontInfo(pFontInfo: *mut FPDF_SYSFONTINFO);
+    fn FPDFBookmark_GetFirstChild(
+        document: FPDF_DOCUMENT,
+        bookmark: FPDF_BOOKMARK,
+    ) -> FPDF_BOOKMARK;
+    fn FPDFBookmark_GetNextSibling(
+        document: FPDF_DOCUMENT,
+        bookmark: FPDF_BOOKMARK,
+    ) -> FPDF_BOOKMARK;
+    fn FPDFBookmark_GetTitle(
+        bookmark: FPDF_BOOKMARK,
+        buffer: *mut c_void,
+        buflen: c_ulong,
+    ) -> c_ulong;
+    fn FPDFBookmark_GetCount(bookmark: FPDF_BOOKMARK) -> c_int;
+    fn FPDFBookmark_Find(document: FPDF_DOCUMENT, title: FPDF_WIDESTRING) -> FPDF_BOOKMARK;
+    fn FPDFBookmark_GetDest(document: FPDF_DOCUMENT, bookmark: FPDF_BOOKMARK) -> FPDF_DEST;
+    fn FPDFBookmark_GetAction(bookmark: FPDF_BOOKMARK) -> FPDF_ACTION;
+    fn FPDFAction_GetType(action: FPDF_ACTION) -> c_ulong;
+    fn FPDFAction_GetDest(document: FPDF_DOCUMENT, action: FPDF_ACTION) -> FPDF_DEST;
+    fn FPDFAction_GetFilePath(action: FPDF_ACTION, buffer: *mut c_void, buflen: c_ulong) -> c_ulong;
+    fn FPDFAction_GetURIPath(
+        document: FPDF_DOCUMENT,
+        action: FPDF_ACTION,
+        buffer: *mut c_void,
+        buflen: c_ulong,
+    ) -> c_ulong;
+    fn FPDFDest_GetDestPageIndex(document: FPDF_DOCUMENT, dest: FPDF_DEST) -> c_int;
+    fn FPDFDest_GetView(
+        dest: FPDF_DEST,
+        pNumParams: *mut c_ulong,
+        pParams: *mut FS_FLOAT,
+    ) -> c_ulong;
+    fn FPDFDest_GetLocationInPage(
+        dest: FPDF_DEST,
+        hasXVal: *mut FPDF_BOOL,
+        hasYVal: *mut FPDF_BOOL,
+        hasZoomVal: *mut FPDF_BOOL,
+        x: *mut FS_FLOAT,
+        y: *mut FS_FLOAT,
+        zoom: *mut FS_FLOAT,
+    ) -> FPDF_BOOL;
+    fn FPDFLink_GetLinkAtPoint(page: FPDF_PAGE, x: c_double, y: c_double) -> FPDF_LINK;
+    fn FPDFLink_GetLinkZOrderAtPoint(page: FPDF_PAGE, x: c_double, y: c_double) -> c_int;
+    fn FPDFLink_GetDest(document: FPDF_DOCUMENT, link: FPDF_LINK) -> FPDF_DEST;
+    fn FPDFLink_GetAction(link: FPDF_LINK) -> FPDF_ACTION;
+    fn FPDFLink_Enumerate(
+        page: FPDF_PAGE,
+        start_pos: *mut c_int,
+        link_annot: *mut FPDF_LINK,
+    ) -> FPDF_BOOL;
+    fn FPDFLink_GetAnnot(page: FPDF_PAGE, link_annot: FPDF_LINK) -> FPDF_ANNOTATION;
+    fn FPDFLink_GetAnnotRect(link_annot: FPDF_LINK, rect: *mut FS_RECTF) -> FPDF_BOOL;
+    fn FPDFLink_CountQuadPoints(link_annot: FPDF_LINK) -> c_int;
+    fn FPDFLink_GetQuadPoints(
+        link_annot: FPDF_LINK,
+        quad_index: c_int,
+        quad_points: *mut FS_QUADPOINTSF,
+    ) -> FPDF_BOOL;
+    fn FPDF_GetPageAAction(page: FPDF_PAGE, aa_type: c_int) -> FPDF_ACTION;
+    fn FPDF_GetFileIdentifier(
+        document: FPDF_DOCUMENT,
+        id_type: FPDF_FILEIDTYPE,
+        buffer: *mut c_void,
+        buflen: c_ulong,
+    ) -> c_ulong;
+    fn FPDF_GetMetaText(
+        document: FPDF_DOCUMENT,
+        tag: &str,
+        buffer: *mut c_void,
+        buflen: c_ulong,
+    ) -> c_ulong;
+    fn FPDF_GetPageLabel(
+        document: FPDF_DOCUMENT,
+        page_index: c_int,
+        buffer: *mut c_void,
+        buflen: c_ulong,
+    ) -> c_ulong;
+    #[cfg(feature = "pdfium_enable_xfa")]
+    fn FPDF_GetXFAPacketCount(document: FPDF_DOCUMENT) -> c_int;
+    #[cfg(feature = "pdfium_enable_xfa")]
+    fn FPDF_GetXFAPacketName(
+        document: FPDF_DOCUMENT,
+        index: c_int,
+        buffer: *mut c_void,
+        buflen: c_ulong,
+    ) -> c_ulong;
+    #[cfg(feature = "pdfium_enable_xfa")]
+    fn FPDF_GetXFAPacketContent(
+        document: FPDF_DOCUMENT,
+        index: c_int,
+        buffer: *mut c_void,
+        buflen: c_ulong,
+        out_buflen: *mut c_ulong,
+    ) -> FPDF_BOOL;
+    #[cfg(feature = "pdfium_enable_v8")]
+    #[cfg(not(target_arch = "wasm32"))] // pdfium_enable_v8 feature not supported on WASM
+    fn FPDF_GetRecommendedV8Flags() -> *const c_char;
+    #[cfg(feature = "pdfium_enable_v8")]
+    #[cfg(not(target_arch = "wasm32"))] // pdfium_enable_v8 feature not supported on WASM
+    fn FPDF_GetArrayBufferAllocatorSharedInstance() -> *mut c_void;
+    #[cfg(feature = "pdfium_enable_xfa")]
+    fn FPDF_BStr_Init(bstr: *mut FPDF_BSTR) -> FPDF_RESULT;
+    #[cfg(feature = "pdfium_enable_xfa")]
+    fn FPDF_BStr_Set(bstr: *mut FPDF_BSTR, cstr: *const c_char, length: c_int) -> FPDF_RESULT;
+    #[cfg(feature = "pdfium_enable_xfa")]
+    fn FPDF_BStr_Clear(bstr: *mut FPDF_BSTR) -> FPDF_RESULT;
+    fn FPDFText_LoadPage(page: FPDF_PAGE) -> FPDF_TEXTPAGE;
+    fn FPDFText_ClosePage(text_page: FPDF_TEXTPAGE);
+    fn FPDFText_CountChars(text_page: FPDF_TEXTPAGE) -> c_int;
+    fn FPDFText_GetUnicode(text_page: FPDF_TEXTPAGE, index: c_int) -> c_uint;
+    #[cfg(any(
+        feature = "pdfium_future",
+        feature = "pdfium_6996",
+        feature = "pdfium_6721",
+        feature = "pdfium_6666",
+        feature = "pdfium_6611",
+    ))]
+    fn FPDFText_GetTextObject(text_page: FPDF_TEXTPAGE, index: c_int) -> FPDF_PAGEOBJECT;
+    fn FPDFText_IsGenerated(text_page: FPDF_TEXTPAGE, index: c_int) -> c_int;
+    #[cfg(any(
+        feature = "pdfium_future",
+        feature = "pdfium_6996",
+        feature = "pdfium_6721",
+        feature = "pdfium_6666",
+        feature = "pdfium_6611",
+        feature = "pdfium_6569",
+        feature = "pdfium_6555",
+        feature = "pdfium_6490",
+        feature = "pdfium_6406",
+        feature = "pdfium_6337",
+        feature = "pdfium_6295",
+        feature = "pdfium_6259",
+        feature = "pdfium_6164",
+        feature = "pdfium_6124",
+        feature = "pdfium_6110",
+        feature = "pdfium_6084",
+        feature = "pdfium_6043",
+        feature = "pdfium_6015",
+    ))]
+    fn FPDFText_IsHyphen(text_page: FPDF_TEXTPAGE, index: c_int) -> c_int;
+    fn FPDFText_HasUnicodeMapError(text_page: FPDF_TEXTPAGE, index: c_int) -> c_int;
+    fn FPDFText_GetFontSize(text_page: FPDF_TEXTPAGE, index: c_int) -> c_double;
+    fn FPDFText_GetFontInfo(
+        text_page: FPDF_TEXTPAGE,
+        index: c_int,
+        buffer: *mut c_void,
+        buflen: c_ulong,
+        flags: *mut c_int,
+    ) -> c_ulong;
+    fn FPDFText_GetFontWeight(text_page: FPDF_TEXTPAGE, index: c_int) -> c_int;
+    #[cfg(any(
+        feature = "pdfium_6569",
+        feature = "pdfium_6555",
+        feature = "pdfium_6490",
+        feature = "pdfium_6406",
+        feature = "pdfium_6337",
+        feature = "pdfium_6295",
+        feature = "pdfium_6259",
+        feature = "pdfium_6164",
+        feature = "pdfium_6124",
+        feature = "pdfium_6110",
+        feature = "pdfium_6084",
+        feature = "pdfium_6043",
+        feature = "pdfium_6015",
+        feature = "pdfium_5961",
+    ))]
+    fn FPDFText_GetTextRenderMode(text_page: FPDF_TEXTPAGE, index: c_int) -> FPDF_TEXT_RENDERMODE;
+    fn FPDFText_GetFillColor(
+        text_page: FPDF_TEXTPAGE,
+        index: c_int,
+        R: *mut c_uint,
+        G: *mut c_uint,
+        B: *mut c_uint,
+        A: *mut c_uint,
+    ) -> FPDF_BOOL;
+    fn FPDFText_GetStrokeColor(
+        text_page: FPDF_TEXTPAGE,
+        index: c_int,
+        R: *mut c_uint,
+        G: *mut c_uint,
+        B: *mut c_uint,
+        A: *mut c_uint,
+    ) -> FPDF_BOOL;
+    fn FPDFText_GetCharAngle(text_page: FPDF_TEXTPAGE, index: c_int) -> c_float;
+    fn FPDFText_GetCharBox(
+        text_page: FPDF_TEXTPAGE,
+        index: c_int,
+        left: *mut c_double,
+        right: *mut c_double,
+        bottom: *mut c_double,
+        top: *mut c_double,
+    ) -> FPDF_BOOL;
+    fn FPDFText_GetLooseCharBox(
+        text_page: FPDF_TEXTPAGE,
+        index: c_int,
+        rect: *mut FS_RECTF,
+    ) -> FPDF_BOOL;
+    fn FPDFText_GetMatrix(
+        text_page: FPDF_TEXTPAGE,
+        index: c_int,
+        matrix: *mut FS_MATRIX,
+    ) -> FPDF_BOOL;
+    fn FPDFText_GetCharOrigin(
+        text_page: FPDF_TEXTPAGE,
+        index: c_int,
+        x: *mut c_double,
+        y: *mut c_double,
+    ) -> FPDF_BOOL;
+    fn FPDFText_GetCharIndexAtPos(
+        text_page: FPDF_TEXTPAGE,
+        x: c_double,
+        y: c_double,
+        xTolerance: c_double,
+        yTolerance: c_double,
+    ) -> c_int;
+    fn FPDFText_GetText(
+        text_page: FPDF_TEXTPAGE,
+        start_index: c_int,
+        count: c_int,
+        result: *mut c_ushort,
+    ) -> c_int;
+    fn FPDFText_CountRects(text_page: FPDF_TEXTPAGE, start_index: c_int, count: c_int) -> c_int;
+    fn FPDFText_GetRect(
+        text_page: FPDF_TEXTPAGE,
+        rect_index: c_int,
+        left: *mut c_double,
+        top: *mut c_double,
+        right: *mut c_double,
+        bottom: *mut c_double,
+    ) -> FPDF_BOOL;
+    fn FPDFText_GetBoundedText(
+        text_page: FPDF_TEXTPAGE,
+        left: c_double,
+        top: c_double,
+        right: c_double,
+        bottom: c_double,
+        buffer: *mut c_ushort,
+        buflen: c_int,
+    ) -> c_int;
+    fn FPDFText_FindStart(
+        text_page: FPDF_TEXTPAGE,
+        findwhat: FPDF_WIDESTRING,
+        flags: c_ulong,
+        start_index: c_int,
+    ) -> FPDF_SCHHANDLE;
+    fn FPDFText_FindNext(handle: FPDF_SCHHANDLE) -> FPDF_BOOL;
+    fn FPDFText_FindPrev(handle: FPDF_SCHHANDLE) -> FPDF_BOOL;
+    fn FPDFText_GetSchResultIndex(handle: FPDF_SCHHANDLE) -> c_int;
+    fn FPDFText_GetSchCount(handle: FPDF_SCHHANDLE) -> c_int;
+    fn FPDFText_FindClose(handle: FPDF_SCHHANDLE);
+    fn FPDFLink_LoadWebLinks(text_page: FPDF_TEXTPAGE) -> FPDF_PAGELINK;
+    fn FPDFLink_CountWebLinks(link_page: FPDF_PAGELINK) -> c_int;
+    fn FPDFLink_GetURL(
+        link_page: FPDF_PAGELINK,
+        link_index: c_int,
+        buffer: *mut c_ushort,
+        buflen: c_int,
+    ) -> c_int;
+    fn FPDFLink_CountRects(link_page: FPDF_PAGELINK, link_index: c_int) -> c_int;
+    fn FPDFLink_GetRect(
+        link_page: FPDF_PAGELINK,
+        link_index: c_int,
+        rect_index: c_int,
+        left: *mut c_double,
+        top: *mut c_double,
+        right: *mut c_double,
+        bottom: *mut c_double,
+    ) -> FPDF_BOOL;
+    fn FPDFLink_GetTextRange(
+        link_page: FPDF_PAGELINK,
+        link_index: c_int,
+        start_char_index: *mut c_int,
+        char_count: *mut c_int,
+    ) -> FPDF_BOOL;
+    fn FPDFLink_CloseWebLinks(link_page: FPDF_PAGELINK);
+    fn FPDFPage_GetDecodedThumbnailData(
+        page: FPDF_PAGE,
+        buffer: *mut c_void,
+        buflen: c_ulong,
+    ) -> c_ulong;
+    fn FPDFPage_GetRawThumbnailData(
+        page: FPDF_PAGE,
+        buffer: *mut c_void,
+        buflen: c_ulong,
+    ) -> c_ulong;
+    fn FPDFPage_GetThumbnailAsBitmap(page: FPDF_PAGE) -> FPDF_BITMAP;
+    fn FPDFFormObj_CountObjects(form_object: FPDF_PAGEOBJECT) -> c_int;
+    fn FPDFFormObj_GetObject(form_object: FPDF_PAGEOBJECT, index: c_ulong) -> FPDF_PAGEOBJECT;
+    fn FPDFPageObj_CreateTextObj(
+        document: FPDF_DOCUMENT,
+        font: FPDF_FONT,
+        font_size: c_float,
+    ) -> FPDF_PAGEOBJECT;
+    fn FPDFTextObj_GetTextRenderMode(text: FPDF_PAGEOBJECT) -> FPDF_TEXT_RENDERMODE;
+    fn FPDFTextObj_SetTextRenderMode(
+        text: FPDF_PAGEOBJECT,
+        render_mode: FPDF_TEXT_RENDERMODE,
+    ) -> FPDF_BOOL;
+    fn FPDFTextObj_GetText(
+        text_object: FPDF_PAGEOBJECT,
+        text_page: FPDF_TEXTPAGE,
+        buffer: *mut FPDF_WCHAR,
+        length: c_ulong,
+    ) -> c_ulong;
+    fn FPDFTextObj_GetRenderedBitmap(
+        document: FPDF_DOCUMENT,
+        page: FPDF_PAGE,
+        text_object: FPDF_PAGEOBJECT,
+        scale: f32,
+    ) -> FPDF_BITMAP;
+    fn FPDFTextObj_GetFont(text: FPDF_PAGEOBJECT) -> FPDF_FONT;
+    fn FPDFTextObj_GetFontSize(text: FPDF_PAGEOBJECT, size: *mut c_float) -> FPDF_BOOL;
+    fn FPDFFont_Close(font: FPDF_FONT);
+    fn FPDFPath_MoveTo(path: FPDF_PAGEOBJECT, x: c_float, y: c_float) -> FPDF_BOOL;
+    fn FPDFPath_LineTo(path: FPDF_PAGEOBJECT, x: c_float, y: c_float) -> FPDF_BOOL;
+    fn FPDFPath_BezierTo(
+        path: FPDF_PAGEOBJECT,
+        x1: c_float,
+        y1: c_float,
+        x2: c_float,
+        y2: c_float,
+        x3: c_float,
+        y3: c_float,
+    ) -> FPDF_BOOL;
+    fn FPDFPath_Close(path: FPDF_PAGEOBJECT) -> FPDF_BOOL;
+    fn FPDFPath_SetDrawMode(path: FPDF_PAGEOBJECT, fillmode: c_int, stroke: FPDF_BOOL) -> FPDF_BOOL;
+    fn FPDFPath_GetDrawMode(
+        path: FPDF_PAGEOBJECT,
+        fillmode: *mut c_int,
+        stroke: *mut FPDF_BOOL,
+    ) -> FPDF_BOOL;
+    fn FPDFPageObj_NewTextObj(
+        document: FPDF_DOCUMENT,
+        font: &str,
+        font_size: c_float,
+    ) -> FPDF_PAGEOBJECT;
+    fn FPDFText_SetText(text_object: FPDF_PAGEOBJECT, text: FPDF_WIDESTRING) -> FPDF_BOOL;
+    fn FPDFText_SetCharcodes(
+        text_object: FPDF_PAGEOBJECT,
+        charcodes: *const c_uint,
+        count: size_t,
+    ) -> FPDF_BOOL;
+    fn FPDFText_LoadFont(
+        document: FPDF_DOCUMENT,
+        data: *const c_uchar,
+        size: c_uint,
+        font_type: c_int,
+        cid: FPDF_BOOL,
+    ) -> FPDF_FONT;
+    fn FPDFText_LoadStandardFont(document: FPDF_DOCUMENT, font: &str) -> FPDF_FONT;
+    #[cfg(any(
+        feature = "pdfium_future",
+        feature = "pdfium_6996",
+        feature = "pdfium_6721",
+        feature = "pdfium_6666",
+        feature = "pdfium_6611",
+        feature = "pdfium_6569",
+        feature = "pdfium_6555",
+        feature = "pdfium_6490",
+        feature = "pdfium_6406",
+        feature = "pdfium_6337",
+        feature = "pdfium_6295",
+    ))]
+    fn FPDFText_LoadCidType2Font(
+        document: FPDF_DOCUMENT,
+        font_data: *const u8,
+        font_data_size: u32,
+        to_unicode_cmap: &str,
+        cid_to_gid_map_data: *const u8,
+        cid_to_gid_map_data_size: u32,
+    ) -> FPDF_FONT;
+    fn FPDFPage_InsertObject(page: FPDF_PAGE, page_obj: FPDF_PAGEOBJECT);
+    fn FPDFPage_RemoveObject(page: FPDF_PAGE, page_obj: FPDF_PAGEOBJECT) -> FPDF_BOOL;
+    fn FPDFPage_CountObjects(page: FPDF_PAGE) -> c_int;
+    fn FPDFPage_GetObject(page: FPDF_PAGE, index: c_int) -> FPDF_PAGEOBJECT;
+    fn FPDFPageObj_Destroy(page_obj: FPDF_PAGEOBJECT);
+    fn FPDFPageObj_HasTransparency(page_object: FPDF_PAGEOBJECT) -> FPDF_BOOL;
+    fn FPDFPageObj_GetType(page_object: FPDF_PAGEOBJECT) -> c_int;
+    #[cfg(any(feature = "pdfium_future", feature = "pdfium_6996"))]
+    fn FPDFPageObj_GetIsActive(page_object: FPDF_PAGEOBJECT, active: *mut FPDF_BOOL) -> FPDF_BOOL;
+    #[cfg(any(feature = "pdfium_future", feature = "pdfium_6996"))]
+    fn FPDFPageObj_SetIsActive(page_object: FPDF_PAGEOBJECT, active: FPDF_BOOL) -> FPDF_BOOL;
+    fn FPDFPageObj_Transform(
+        page_object: FPDF_PAGEOBJECT,
+        a: c_double,
+        b: c_double,
+        c: c_double,
+        d: c_double,
+        e: c_double,
+        f: c_double,
+    );
+    #[cfg(any(
+        feature = "pdfium_future",
+        feature = "pdfium_6996",
+        feature = "pdfium_6721",
+        feature = "pdfium_6666",
+        feature = "pdfium_6611",
+    ))]
+    fn FPDFPageObj_TransformF(page_object: FPDF_PAGEOBJECT, matrix: *const FS_MATRIX) -> FPDF_BOOL;
+    fn FPDFPageObj_GetMatrix(page_object: FPDF_PAGEOBJECT, matrix: *mut FS_MATRIX) -> FPDF_BOOL;
+    fn FPDFPageObj_SetMatrix(path: FPDF_PAGEOBJECT, matrix: *const FS_MATRIX) -> FPDF_BOOL;
+    fn FPDFPageObj_NewImageObj(document: FPDF_DOCUMENT) -> FPDF_PAGEOBJECT;
+    #[cfg(any(
+        feature = "pdfium_future",
+        feature = "pdfium_6996",
+        feature = "pdfium_6721",
+        feature = "pdfium_6666",
+        feature = "pdfium_6611",
+    ))]
+    fn FPDFPageObj_GetMarkedContentID(page_object: FPDF_PAGEOBJECT) -> c_int;
+    fn FPDFPageObj_CountMarks(page_object: FPDF_PAGEOBJECT) -> c_int;
+    fn FPDFPageObj_GetMark(page_object: FPDF_PAGEOBJECT, index: c_ulong) -> FPDF_PAGEOBJECTMARK;
+    fn FPDFPageObj_AddMark(page_object: FPDF_PAGEOBJECT, name: &str) -> FPDF_PAGEOBJECTMARK;
+    fn FPDFPageObj_RemoveMark(page_object: FPDF_PAGEOBJECT, mark: FPDF_PAGEOBJECTMARK) -> FPDF_BOOL;
+    #[cfg(any(feature = "pdfium_future", feature = "pdfium_6996"))]
+    fn FPDFPageObjMark_GetName(
+        mark: FPDF_PAGEOBJECTMARK,
+        buffer: *mut FPDF_WCHAR,
+        buflen: c_ulong,
+        out_buflen: *mut c_ulong,
+    ) -> FPDF_BOOL;
+    #[cfg(any(
+        feature = "pdfium_6721",
+        feature = "pdfium_6666",
+        feature = "pdfium_6611",
+        feature = "pdfium_6569",
+        feature = "pdfium_6555",
+        feature = "pdfium_6490",
+        feature = "pdfium_6406",
+        feature = "pdfium_6337",
+        feature = "pdfium_6295",
+        feature = "pdfium_6259",
+        feature = "pdfium_6164",
+        feature = "pdfium_6124",
+        feature = "pdfium_6110",
+        feature = "pdfium_6084",
+        feature = "pdfium_6043",
+        feature = "pdfium_6015",
+        feature = "pdfium_5961",
+    ))]
+    fn FPDFPageObjMark_GetName(
+        mark: FPDF_PAGEOBJECTMARK,
+        buffer: *mut c_void,
+        buflen: c_ulong,
+        out_buflen: *mut c_ulong,
+    ) -> FPDF_BOOL;
+    fn FPDFPageObjMark_CountParams(mark: FPDF_PAGEOBJECTMARK) -> c_int;
+    #[cfg(any(feature = "pdfium_future", feature = "pdfium_6996"))]
+    fn FPDFPageObjMark_GetParamKey(
+        mark: FPDF_PAGEOBJECTMARK,
+        index: c_ulong,
+        buffer: *mut FPDF_WCHAR,
+        buflen: c_ulong,
+        out_buflen: *mut c_ulong,
+    ) -> FPDF_BOOL;
+    #[cfg(any(
+        feature = "pdfium_6721",
+        feature = "pdfium_6666",
+        feature = "pdfium_6611",
+        feature = "pdfium_6569",
+        feature = "pdfium_6555",
+        feature = "pdfium_6490",
+        feature = "pdfium_6406",
+        feature = "pdfium_6337",
+        feature = "pdfium_6295",
+        feature = "pdfium_6259",
+        feature = "pdfium_6164",
+        feature = "pdfium_6124",
+        feature = "pdfium_6110",
+        feature = "pdfium_6084",
+        feature = "pdfium_6043",
+        feature = "pdfium_6015",
+        feature = "pdfium_5961",
+    ))]
+    fn FPDFPageObjMark_GetParamKey(
+        mark: FPDF_PAGEOBJECTMARK,
+        index: c_ulong,
+        buffer: *mut c_void,
+        buflen: c_ulong,
+        out_buflen: *mut c_ulong,
+    ) -> FPDF_BOOL;
+    fn FPDFPageObjMark_GetParamValueType(mark: FPDF_PAGEOBJECTMARK, key: &str) -> FPDF_OBJECT_TYPE;
+    fn FPDFPageObjMark_GetParamIntValue(
+        mark: FPDF_PAGEOBJECTMARK,
+        key: &str,
+        out_value: *mut c_int,
+    ) -> FPDF_BOOL;
+    #[cfg(any(feature = "pdfium_future", feature = "pdfium_6996"))]
+    fn FPDFPageObjMark_GetParamStringValue(
+        mark: FPDF_PAGEOBJECTMARK,
+        key: &str,
+        buffer: *mut FPDF_WCHAR,
+        buflen: c_ulong,
+        out_buflen: *mut c_ulong,
+    ) -> FPDF_BOOL;
+    #[cfg(any(
+        feature = "pdfium_6721",
+        feature = "pdfium_6666",
+        feature = "pdfium_6611",
+        feature = "pdfium_6569",
+        feature = "pdfium_6555",
+        feature = "pdfium_6490",
+        feature = "pdfium_6406",
+        feature = "pdfium_6337",
+        feature = "pdfium_6295",
+        feature = "pdfium_6259",
+        feature = "pdfium_6164",
+        feature = "pdfium_6124",
+        feature = "pdfium_6110",
+        feature = "pdfium_6084",
+        feature = "pdfium_6043",
+        feature = "pdfium_6015",
+        feature = "pdfium_5961",
+    ))]
+    fn FPDFPageObjMark_GetParamStringValue(
+        mark: FPDF_PAGEOBJECTMARK,
+        key: &str,
+        buffer: *mut c_void,
+        buflen: c_ulong,
+        out_buflen: *mut c_ulong,
+    ) -> FPDF_BOOL;
+    #[cfg(any(feature = "pdfium_future", feature = "pdfium_6996"))]
+    fn FPDFPageObjMark_GetParamBlobValue(
+        mark: FPDF_PAGEOBJECTMARK,
+        key: &str,
+        buffer: *mut c_uchar,
+        buflen: c_ulong,
+        out_buflen: *mut c_ulong,
+    ) -> FPDF_BOOL;
+    #[cfg(any(
+        feature = "pdfium_6721",
+        feature = "pdfium_6666",
+        feature = "pdfium_6611",
+        feature = "pdfium_6569",
+        feature = "pdfium_6555",
+        feature = "pdfium_6490",
+        feature = "pdfium_6406",
+        feature = "pdfium_6337",
+        feature = "pdfium_6295",
+        feature = "pdfium_6259",
+        feature = "pdfium_6164",
+        feature = "pdfium_6124",
+        feature = "pdfium_6110",
+        feature = "pdfium_6084",
+        feature = "pdfium_6043",
+        feature = "pdfium_6015",
+        feature = "pdfium_5961",
+    ))]
+    fn FPDFPageObjMark_GetParamBlobValue(
+        mark: FPDF_PAGEOBJECTMARK,
+        key: &str,
+        buffer: *mut c_void,
+        buflen: c_ulong,
+        out_buflen: *mut c_ulong,
+    ) -> FPDF_BOOL;
+    fn FPDFPageObjMark_SetIntParam(
+        document: FPDF_DOCUMENT,
+        page_object: FPDF_PAGEOBJECT,
+        mark: FPDF_PAGEOBJECTMARK,
+        key: &str,
+        value: c_int,
+    ) -> FPDF_BOOL;
+    fn FPDFPageObjMark_SetStringParam(
+        document: FPDF_DOCUMENT,
+        page_object: FPDF_PAGEOBJECT,
+        mark: FPDF_PAGEOBJECTMARK,
+        key: &str,
+        value: &str,
+    ) -> FPDF_BOOL;
+    #[cfg(any(feature = "pdfium_future", feature = "pdfium_6996"))]
+    fn FPDFPageObjMark_SetBlobParam(
+        document: FPDF_DOCUMENT,
+        page_object: FPDF_PAGEOBJECT,
+        mark: FPDF_PAGEOBJECTMARK,
+        key: &str,
+        value: *const c_uchar,
+        value_len: c_ulong,
+    ) -> FPDF_BOOL;
+    #[cfg(any(
+        feature = "pdfium_6721",
+        feature = "pdfium_6666",
+        feature = "pdfium_6611",
+        feature = "pdfium_6569",
+        feature = "pdfium_6555",
+        feature = "pdfium_6490",
+        feature = "pdfium_6406",
+        feature = "pdfium_6337",
+        feature = "pdfium_6295",
+        feature = "pdfium_6259",
+        feature = "pdfium_6164",
+        feature = "pdfium_6124",
+        feature = "pdfium_6110",
+        feature = "pdfium_6084",
+        feature = "pdfium_6043",
+        feature = "pdfium_6015",
+        feature = "pdfium_5961",
+    ))]
+    fn FPDFPageObjMark_SetBlobParam(
+        document: FPDF_DOCUMENT,
+        page_object: FPDF_PAGEOBJECT,
+        mark: FPDF_PAGEOBJECTMARK,
+        key: &str,
+        value: *mut c_void,
+        value_len: c_ulong,
+    ) -> FPDF_BOOL;
+    fn FPDFPageObjMark_RemoveParam(
+        page_object: FPDF_PAGEOBJECT,
+        mark: FPDF_PAGEOBJECTMARK,
+        key: &str,
+    ) -> FPDF_BOOL;
+    fn FPDFImageObj_LoadJpegFile(
+        pages: *mut FPDF_PAGE,
+        count: c_int,
+        image_object: FPDF_PAGEOBJECT,
+        file_access: *mut FPDF_FILEACCESS,
+    ) -> FPDF_BOOL;
+    fn FPDFImageObj_LoadJpegFileInline(
+        pages: *mut FPDF_PAGE,
+        count: c_int,
+        image_object: FPDF_PAGEOBJECT,
+        file_access: *mut FPDF_FILEACCESS,
+    ) -> FPDF_BOOL;
+    fn FPDFImageObj_SetMatrix(
+        image_object: FPDF_PAGEOBJECT,
+        a: c_double,
+        b: c_double,
+        c: c_double,
+        d: c_double,
+        e: c_double,
+        f: c_double,
+    ) -> FPDF_BOOL;
+    fn FPDFImageObj_SetBitmap(
+        pages: *mut FPDF_PAGE,
+        count: c_int,
+        image_object: FPDF_PAGEOBJECT,
+        bitmap: FPDF_BITMAP,
+    ) -> FPDF_BOOL;
+    fn FPDFImageObj_GetBitmap(image_object: FPDF_PAGEOBJECT) -> FPDF_BITMAP;
+    fn FPDFImageObj_GetRenderedBitmap(
+        document: FPDF_DOCUMENT,
+        page: FPDF_PAGE,
+        image_object: FPDF_PAGEOBJECT,
+    ) -> FPDF_BITMAP;
+    fn FPDFImageObj_GetImageDataDecoded(
+        image_object: FPDF_PAGEOBJECT,
+        buffer: *mut c_void,
+        buflen: c_ulong,
+    ) -> c_ulong;
+    fn FPDFImageObj_GetImageDataRaw(
+        image_object: FPDF_PAGEOBJECT,
+        buffer: *mut c_void,
+        buflen: c_ulong,
+    ) -> c_ulong;
+    fn FPDFImageObj_GetImageFilterCount(image_object: FPDF_PAGEOBJECT) -> c_int;
+    fn FPDFImageObj_GetImageFilter(
+        image_object: FPDF_PAGEOBJECT,
+        index: c_int,
+        buffer: *mut c_void,
+        buflen: c_ulong,
+    ) -> c_ulong;
+    fn FPDFImageObj_GetImageMetadata(
+        image_object: FPDF_PAGEOBJECT,
+        page: FPDF_PAGE,
+        metadata: *mut FPDF_IMAGEOBJ_METADATA,
+    ) -> FPDF_BOOL;
+    fn FPDFImageObj_GetImagePixelSize(
+        image_object: FPDF_PAGEOBJECT,
+        width: *mut c_uint,
+        height: *mut c_uint,
+    ) -> FPDF_BOOL;
+    #[cfg(any(feature = "pdfium_future", feature = "pdfium_6996"))]
+    fn FPDFImageObj_GetIccProfileDataDecoded(
+        image_object: FPDF_PAGEOBJECT,
+        page: FPDF_PAGE,
+        buffer: *mut u8,
+        buflen: usize,
+        out_buflen: *mut usize,
+    ) -> FPDF_BOOL;
+    fn FPDFPageObj_CreateNewPath(x: c_float, y: c_float) -> FPDF_PAGEOBJECT;
+    fn FPDFPageObj_CreateNewRect(x: c_float, y: c_float, w: c_float, h: c_float) -> FPDF_PAGEOBJECT;
+    fn FPDFPageObj_GetBounds(
+        page_object: FPDF_PAGEOBJECT,
+        left: *mut c_float,
+        bottom: *mut c_float,
+        right: *mut c_float,
+        top: *mut c_float,
+    ) -> FPDF_BOOL;
+    fn FPDFPageObj_GetRotatedBounds(
+        page_object: FPDF_PAGEOBJECT,
+        quad_points: *mut FS_QUADPOINTSF,
+    ) -> FPDF_BOOL;
+    fn FPDFPageObj_SetBlendMode(page_object: FPDF_PAGEOBJECT, blend_mode: &str);
+    fn FPDFPageObj_SetStrokeColor(
+        page_object: FPDF_PAGEOBJECT,
+        R: c_uint,
+        G: c_uint,
+        B: c_uint,
+        A: c_uint,
+    ) -> FPDF_BOOL;
+    fn FPDFPageObj_GetStrokeColor(
+        page_object: FPDF_PAGEOBJECT,
+        R: *mut c_uint,
+        G: *mut c_uint,
+        B: *mut c_uint,
+        A: *mut c_uint,
+    ) -> FPDF_BOOL;
+    fn FPDFPageObj_SetStrokeWidth(page_object: FPDF_PAGEOBJECT, width: c_float) -> FPDF_BOOL;
+    fn FPDFPageObj_GetStrokeWidth(page_object: FPDF_PAGEOBJECT, width: *mut c_float) -> FPDF_BOOL;
+    fn FPDFPageObj_GetLineJoin(page_object: FPDF_PAGEOBJECT) -> c_int;
+    fn FPDFPageObj_SetLineJoin(page_object: FPDF_PAGEOBJECT, line_join: c_int) -> FPDF_BOOL;
+    fn FPDFPageObj_GetLineCap(page_object: FPDF_PAGEOBJECT) -> c_int;
+    fn FPDFPageObj_SetLineCap(page_object: FPDF_PAGEOBJECT, line_cap: c_int) -> FPDF_BOOL;
+    fn FPDFPageObj_SetFillColor(
+        page_object: FPDF_PAGEOBJECT,
+        R: c_uint,
+        G: c_uint,
+        B: c_uint,
+        A: c_uint,
+    ) -> FPDF_BOOL;
+    fn FPDFPageObj_GetFillColor(
+        page_object: FPDF_PAGEOBJECT,
+        R: *mut c_uint,
+        G: *mut c_uint,
+        B: *mut c_uint,
+        A: *mut c_uint,
+    ) -> FPDF_BOOL;
+    fn FPDFPageObj_GetDashPhase(page_object: FPDF_PAGEOBJECT, phase: *mut c_float) -> FPDF_BOOL;
+    fn FPDFPageObj_SetDashPhase(page_object: FPDF_PAGEOBJECT, phase: c_float) -> FPDF_BOOL;
+    fn FPDFPageObj_GetDashCount(page_object: FPDF_PAGEOBJECT) -> c_int;
+    fn FPDFPageObj_GetDashArray(
+        page_object: FPDF_PAGEOBJECT,
+        dash_array: *mut c_float,
+        dash_count: size_t,
+    ) -> FPDF_BOOL;
+    fn FPDFPageObj_SetDashArray(
+        page_object: FPDF_PAGEOBJECT,
+        dash_array: *const c_float,
+        dash_count: size_t,
+        phase: c_float,
+    ) -> FPDF_BOOL;
+    fn FPDFPath_CountSegments(path: FPDF_PAGEOBJECT) -> c_int;
+    fn FPDFPath_GetPathSegment(path: FPDF_PAGEOBJECT, index: c_int) -> FPDF_PATHSEGMENT;
+    fn FPDFPathSegment_GetPoint(
+        segment: FPDF_PATHSEGMENT,
+        x: *mut c_float,
+        y: *mut c_float,
+    ) -> FPDF_BOOL;
+    fn FPDFPathSegment_GetType(segment: FPDF_PATHSEGMENT) -> c_int;
+    fn FPDFPathSegment_GetClose(segment: FPDF_PATHSEGMENT) -> FPDF_BOOL;
+    #[cfg(any(
+        feature = "pdfium_future",
+        feature = "pdfium_6996",
+        feature = "pdfium_6721",
+        feature = "pdfium_6666",
+    ))]
+    fn FPDFFont_GetBaseFontName(font: FPDF_FONT, buffer: *mut c_char, length: size_t) -> size_t;
+    #[cfg(any(
+        feature = "pdfium_future",
+        feature = "pdfium_6996",
+        feature = "pdfium_6721",
+        feature = "pdfium_6666",
+    ))]
+    fn FPDFFont_GetFamilyName(font: FPDF_FONT, buffer: *mut c_char, length: size_t) -> size_t;
+    #[cfg(feature = "pdfium_6611")]
+    fn FPDFFont_GetFamilyName(font: FPDF_FONT, buffer: *mut c_char, length: c_ulong) -> c_ulong;
+    #[cfg(any(
+        feature = "pdfium_6569",
+        feature = "pdfium_6555",
+        feature = "pdfium_6490",
+        feature = "pdfium_6406",
+        feature = "pdfium_6337",
+        feature = "pdfium_6295",
+        feature = "pdfium_6259",
+        feature = "pdfium_6164",
+        feature = "pdfium_6124",
+        feature = "pdfium_6110",
+        feature = "pdfium_6084",
+        feature = "pdfium_6043",
+        feature = "pdfium_6015",
+        feature = "pdfium_5961",
+    ))]
+    fn FPDFFont_GetFontName(font: FPDF_FONT, buffer: *mut c_char, length: c_ulong) -> c_ulong;
+    fn FPDFFont_GetFontData(
+        font: FPDF_FONT,
+        buffer: *mut u8,
+        buflen: size_t,
+        out_buflen: *mut size_t,
+    ) -> FPDF_BOOL;
+    fn FPDFFont_GetIsEmbedded(font: FPDF_FONT) -> c_int;
+    fn FPDFFont_GetFlags(font: FPDF_FONT) -> c_int;
+    fn FPDFFont_GetWeight(font: FPDF_FONT) -> c_int;
+    fn FPDFFont_GetItalicAngle(font: FPDF_FONT, angle: *mut c_int) -> FPDF_BOOL;
+    fn FPDFFont_GetAscent(font: FPDF_FONT, font_size: c_float, ascent: *mut c_float) -> FPDF_BOOL;
+    fn FPDFFont_GetDescent(font: FPDF_FONT, font_size: c_float, descent: *mut c_float) -> FPDF_BOOL;
+    fn FPDFFont_GetGlyphWidth(
+        font: FPDF_FONT,
+        glyph: c_uint,
+        font_size: c_float,
+        width: *mut c_float,
+    ) -> FPDF_BOOL;
+    fn FPDFFont_GetGlyphPath(font: FPDF_FONT, glyph: c_uint, font_size: c_float) -> FPDF_GLYPHPATH;
+    fn FPDFGlyphPath_CountGlyphSegments(glyphpath: FPDF_GLYPHPATH) -> c_int;
+    fn FPDFGlyphPath_GetGlyphPathSegment(
+        glyphpath: FPDF_GLYPHPATH,
+        index: c_int,
+    ) -> FPDF_PATHSEGMENT;
+    fn FPDF_VIEWERREF_GetPrintScaling(document: FPDF_DOCUMENT) -> FPDF_BOOL;
+    fn FPDF_VIEWERREF_GetNumCopies(document: FPDF_DOCUMENT) -> c_int;
+    fn FPDF_VIEWERREF_GetPrintPageRange(document: FPDF_DOCUMENT) -> FPDF_PAGERANGE;
+    fn FPDF_VIEWERREF_GetPrintPageRangeCount(pagerange: FPDF_PAGERANGE) -> size_t;
+    fn FPDF_VIEWERREF_GetPrintPageRangeElement(pagerange: FPDF_PAGERANGE, index: size_t) -> c_int;
+    fn FPDF_VIEWERREF_GetDuplex(document: FPDF_DOCUMENT) -> FPDF_DUPLEXTYPE;
+    fn FPDF_VIEWERREF_GetName(
+        document: FPDF_DOCUMENT,
+        key: &str,
+        buffer: *mut c_char,
+        length: c_ulong,
+    ) -> c_ulong;
+    fn FPDF_CountNamedDests(document: FPDF_DOCUMENT) -> FPDF_DWORD;
+    fn FPDF_GetNamedDestByName(document: FPDF_DOCUMENT, name: &str) -> FPDF_DEST;
+    fn FPDF_GetNamedDest(
+        document: FPDF_DOCUMENT,
+        index: c_int,
+        buffer: *mut c_void,
+        buflen: *mut c_long,
+    ) -> FPDF_DEST;
+    fn FPDFDoc_GetAttachmentCount(document: FPDF_DOCUMENT) -> c_int;
+    fn FPDFDoc_AddAttachment(document: FPDF_DOCUMENT, name: FPDF_WIDESTRING) -> FPDF_ATTACHMENT;
+    fn FPDFDoc_GetAttachment(document: FPDF_DOCUMENT, index: c_int) -> FPDF_ATTACHMENT;
+    fn FPDFDoc_DeleteAttachment(document: FPDF_DOCUMENT, index: c_int) -> FPDF_BOOL;
+    fn FPDFAttachment_GetName(
+        attachment: FPDF_ATTACHMENT,
+        buffer: *mut FPDF_WCHAR,
+        buflen: c_ulong,
+    ) -> c_ulong;
+    fn FPDFAttachment_HasKey(attachment: FPDF_ATTACHMENT, key: &str) -> FPDF_BOOL;
+    fn FPDFAttachment_GetValueType(attachment: FPDF_ATTACHMENT, key: &str) -> FPDF_OBJECT_TYPE;
+    fn FPDFAttachment_SetStringValue(
+        attachment: FPDF_ATTACHMENT,
+        key: &str,
+        value: FPDF_WIDESTRING,
+    ) -> FPDF_BOOL;
+    fn FPDFAttachment_GetStringValue(
+        attachment: FPDF_ATTACHMENT,
+        key: &str,
+        buffer: *mut FPDF_WCHAR,
+        buflen: c_ulong,
+    ) -> c_ulong;
+    fn FPDFAttachment_SetFile(
+        attachment: FPDF_ATTACHMENT,
+        document: FPDF_DOCUMENT,
+        contents: *const c_void,
+        len: c_ulong,
+    ) -> FPDF_BOOL;
+    fn FPDFAttachment_GetFile(
+        attachment: FPDF_ATTACHMENT,
+        buffer: *mut c_void,
+        buflen: c_ulong,
+        out_buflen: *mut c_ulong,
+    ) -> FPDF_BOOL;
+    fn FPDFCatalog_IsTagged(document: FPDF_DOCUMENT) -> FPDF_BOOL;
+    #[cfg(any(
+        feature = "pdfium_future",
+        feature = "pdfium_6996",
+        feature = "pdfium_6721",
+        feature = "pdfium_6666",
+    ))]
+    fn FPDFCatalog_SetLanguage(document: FPDF_DOCUMENT, language: &str) -> FPDF_BOOL;
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::bindgen::{FPDF_BOOL, FPDF_DOCUMENT, FPDF_PAGEOBJECT, FPDF_PAGEOBJ_PATH};
+    use crate::prelude::*;
+    use std::os::raw::c_int;
+
+    #[test]
+    fn test_mock_load_and_render() -> Result<(), PdfiumError> {
+        let mock = MockPdfiumBindings::new();
+
+        mock.allow_library_lifecycle()
+            .allow_document_loading(2)
+            .allow_page_rendering();
+
+        {
+            let pdfium = Pdfium::new(Box::new(mock.clone()));
+
+            let document = pdfium.load_pdf_from_byte_slice(b"%PDF-1.7", None)?;
+
+            assert_eq!(document.pages().len(), 2);
+
+            let page = document.pages().get(1)?;
+
+            let bitmap = page.render_with_config(&PdfRenderConfig::new().set_target_width(100))?;
+
+            assert_eq!(bitmap.width(), 100);
+            assert!(bitmap
+                .as_rgba_bytes()
+                .chunks_exact(4)
+                .all(|pixel| pixel == [255, 255, 255, 255]));
+        }
+
+        assert_eq!(mock.call_count("FPDF_InitLibrary"), 1);
+        assert_eq!(mock.call_count("FPDF_LoadPage"), 1);
+        assert_eq!(mock.calls_to("FPDF_LoadPage")[0].arguments()[1], "1");
+        assert_eq!(mock.call_count("FPDF_RenderPageBitmap"), 1);
+        assert_eq!(mock.call_count("FPDF_ClosePage"), 1);
+        assert_eq!(mock.call_count("FPDF_CloseDocument"), 1);
+        assert_eq!(mock.call_count("FPDF_DestroyLibrary"), 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_mock_counts_calls() -> Result<(), PdfiumError> {
+        let mock = MockPdfiumBindings::new();
+
+        mock.allow_library_lifecycle().allow_document_loading(1);
+
+        let pdfium = Pdfium::new(Box::new(mock.clone()));
+
+        let document = pdfium.load_pdf_from_byte_slice(b"%PDF-1.7", None)?;
+
+        let mut page = document.pages().first()?;
+
+        // The page contains two path objects, both of which are removed successfully.
+
+        mock.returns_always("FPDFPage_CountObjects", 2 as c_int)
+            .returns_with("FPDFPage_GetObject", |call| {
+                (0x100 + call.numeric_argument::<usize>(1).unwrap_or(0) * 16) as FPDF_PAGEOBJECT
+            })
+            .returns_always("FPDFPageObj_GetType", FPDF_PAGEOBJ_PATH as c_int)
+            .returns("FPDFPage_RemoveObject", 1 as FPDF_BOOL)
+            .returns("FPDFPage_RemoveObject", 1 as FPDF_BOOL)
+            .returns_always("FPDFPage_GenerateContent", 1 as FPDF_BOOL)
+            .allow(&["FPDFPageObj_Destroy"]);
+
+        mock.clear_calls();
+
+        assert_eq!(page.objects_mut().remove_objects_if(|_| true)?, 2);
+        assert_eq!(mock.call_count("FPDFPage_RemoveObject"), 2);
+        assert_eq!(
+            mock.calls_to("FPDFPage_RemoveObject")
+                .iter()
+                .map(|call| call.arguments()[1].as_str())
+                .collect::<Vec<_>>(),
+            vec!["0x110", "0x100"]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    #[should_panic(expected = "Unexpected call to FPDF_GetPageCount(0x10)")]
+    fn test_mock_panics_on_unexpected_call() {
+        let mock = MockPdfiumBindings::new();
+
+        mock.returns("FPDF_GetPageCount", 1 as c_int);
+
+        assert_eq!(mock.FPDF_GetPageCount(0x10 as FPDF_DOCUMENT), 1);
+
+        // The queued value has been consumed.
+
+        mock.FPDF_GetPageCount(0x10 as FPDF_DOCUMENT);
+    }
+}
@@ -85,6 +85,7 @@ impl PdfiumLibraryBindings for StaticPdfiumBindings {
         }
     }
 
+    #[cfg(target_os = "windows")]
     #[cfg(feature = "pdfium_use_win32")]
     #[inline]
     #[allow(non_snake_case)]
@@ -1546,6 +1547,7 @@ impl PdfiumLibraryBindings for StaticPdfiumBindings {
         unsafe { crate::bindgen::FPDFBitmap_Destroy(bitmap) }
     }
 
+    #[cfg(target_os = "windows")]
     #[cfg(feature = "pdfium_use_win32")]
     #[inline]
     #[allow(non_snake_case)]
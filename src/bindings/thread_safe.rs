@@ -5,6 +5,17 @@
 // only way to guarantee thread safety. We acquire the lock on the first call to FPDF_InitLibrary(),
 // and release the lock on the last call to FPDF_DestroyLibrary().
 
+// When the sync crate feature is enabled, a Pdfium instance and the documents it loads can be
+// shared between threads, so holding the lock from FPDF_InitLibrary() to FPDF_DestroyLibrary()
+// is no longer sufficient. In that case we additionally acquire a reentrant lock around every
+// individual call to Pdfium, so that calls made from different threads are serialized. The lock
+// is reentrant because Pdfium may call back into Rust code while a call is in progress - for example,
+// when reading a document through a custom file access callback, or when checking whether
+// a progressive render should be paused - and that callback may itself call Pdfium.
+// The PdfiumSync and PdfDocumentSync wrappers hold the same lock for the duration of each
+// operation they perform, so that operations making several related calls to Pdfium cannot
+// be interleaved with calls made from other threads.
+
 use crate::bindgen::{
     size_t, FPDF_CharsetFontMap, FPDFANNOT_COLORTYPE, FPDF_ACTION, FPDF_ANNOTATION,
     FPDF_ANNOTATION_SUBTYPE, FPDF_ANNOT_APPEARANCEMODE, FPDF_ATTACHMENT, FPDF_AVAIL, FPDF_BITMAP,
@@ -45,9 +56,41 @@ use std::os::raw::{
 };
 use std::sync::{Mutex, MutexGuard};
 
+use std::thread::ThreadId;
+
+#[cfg(feature = "sync")]
+use parking_lot::{ReentrantMutex, ReentrantMutexGuard};
+
 static PDFIUM_THREAD_MARSHALL: Lazy<Mutex<PdfiumThreadMarshall>> =
     Lazy::new(|| Mutex::new(PdfiumThreadMarshall::new()));
 
+/// The thread that initialized the Pdfium instance currently holding the lock on the global
+/// [PdfiumThreadMarshall], if any.
+static PDFIUM_THREAD_MARSHALL_OWNER: Lazy<Mutex<Option<ThreadId>>> = Lazy::new(|| Mutex::new(None));
+
+/// Records the thread that initialized the Pdfium instance currently holding the lock on
+/// the global [PdfiumThreadMarshall], or that no instance holds the lock.
+fn set_thread_marshall_owner(owner: Option<ThreadId>) {
+    match PDFIUM_THREAD_MARSHALL_OWNER.lock() {
+        Ok(mut current) => *current = owner,
+        Err(poisoned) => *poisoned.into_inner() = owner,
+    }
+}
+
+/// Returns `true` if a live Pdfium instance was initialized on the currently running thread.
+/// Any attempt to initialize another Pdfium instance while that instance is alive will block
+/// until it is dropped, so code that waits on other threads initializing Pdfium must not run
+/// on the current thread.
+#[cfg(feature = "threaded_render")]
+pub(crate) fn is_pdfium_initialized_on_current_thread() -> bool {
+    let current = std::thread::current().id();
+
+    match PDFIUM_THREAD_MARSHALL_OWNER.lock() {
+        Ok(owner) => *owner == Some(current),
+        Err(poisoned) => *poisoned.into_inner() == Some(current),
+    }
+}
+
 struct PdfiumThreadMarshall {}
 
 impl PdfiumThreadMarshall {
@@ -83,6 +126,25 @@ impl Default for PdfiumThreadMarshall {
     }
 }
 
+/// A reentrant lock serializing calls to Pdfium made from different threads. A thread that
+/// already holds the lock can acquire it again without blocking.
+#[cfg(feature = "sync")]
+static PDFIUM_CALL_LOCK: Lazy<ReentrantMutex<()>> = Lazy::new(|| ReentrantMutex::new(()));
+
+/// Acquires the global reentrant lock over calls to Pdfium for the currently running thread,
+/// blocking until any other thread holding the lock releases it. The lock is released when
+/// the returned guard is dropped.
+///
+/// Every call made through [ThreadSafePdfiumBindings] acquires this lock. Operations that
+/// make several related calls to Pdfium, such as sizing a buffer and then filling it, or
+/// retrieving the error code left by a failed call, should hold the lock for the whole
+/// operation; `PdfiumSync` and `PdfDocumentSync` do this.
+#[cfg(feature = "sync")]
+#[inline]
+pub(crate) fn lock_pdfium() -> ReentrantMutexGuard<'static, ()> {
+    PDFIUM_CALL_LOCK.lock()
+}
+
 pub(crate) struct ThreadSafePdfiumBindings<T: PdfiumLibraryBindings> {
     bindings: T,
     lock: RefCell<Option<MutexGuard<'static, PdfiumThreadMarshall>>>,
@@ -100,6 +162,11 @@ impl<T: PdfiumLibraryBindings> ThreadSafePdfiumBindings<T> {
 
 #[allow(deprecated)]
 impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBindings<T> {
+    #[inline]
+    fn is_function_available(&self, function: &str) -> bool {
+        self.bindings.is_function_available(function)
+    }
+
     #[inline]
     #[allow(non_snake_case)]
     fn FPDF_InitLibraryWithConfig(&self, config: *const FPDF_LIBRARY_CONFIG) {
@@ -108,6 +175,16 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
 
         if self.lock.borrow().is_none() {
             self.lock.replace(Some(PdfiumThreadMarshall::lock()));
+
+            set_thread_marshall_owner(Some(std::thread::current().id()));
+
+            // The per-call lock must only be acquired once we hold the exclusive lock;
+            // otherwise, we would block other threads' calls to the existing instance
+            // of Pdfium while waiting for that instance to be destroyed.
+
+            #[cfg(feature = "sync")]
+            let _call = lock_pdfium();
+
             self.bindings.FPDF_InitLibraryWithConfig(config);
         }
     }
@@ -120,6 +197,16 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
 
         if self.lock.borrow().is_none() {
             self.lock.replace(Some(PdfiumThreadMarshall::lock()));
+
+            set_thread_marshall_owner(Some(std::thread::current().id()));
+
+            // The per-call lock must only be acquired once we hold the exclusive lock;
+            // otherwise, we would block other threads' calls to the existing instance
+            // of Pdfium while waiting for that instance to be destroyed.
+
+            #[cfg(feature = "sync")]
+            let _call = lock_pdfium();
+
             self.bindings.FPDF_InitLibrary();
         }
     }
@@ -127,37 +214,56 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
     #[inline]
     #[allow(non_snake_case)]
     fn FPDF_SetSandBoxPolicy(&self, policy: FPDF_DWORD, enable: FPDF_BOOL) {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDF_SetSandBoxPolicy(policy, enable);
     }
 
     #[inline]
     #[allow(non_snake_case)]
     fn FPDF_DestroyLibrary(&self) {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         // Release the exclusive lock we hold over access to Pdfium. Any other thread waiting
         // to use Pdfium will be able to continue.
 
         if self.lock.borrow().is_some() {
             self.bindings.FPDF_DestroyLibrary();
+
+            set_thread_marshall_owner(None);
+
             self.lock.replace(None);
         }
     }
 
+    #[cfg(target_os = "windows")]
     #[cfg(feature = "pdfium_use_win32")]
     #[inline]
     #[allow(non_snake_case)]
     fn FPDF_SetPrintMode(&self, mode: c_int) {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDF_SetPrintMode(mode);
     }
 
     #[inline]
     #[allow(non_snake_case)]
     fn FPDF_GetLastError(&self) -> c_ulong {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDF_GetLastError()
     }
 
     #[inline]
     #[allow(non_snake_case)]
     fn FPDF_CreateNewDocument(&self) -> FPDF_DOCUMENT {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDF_CreateNewDocument()
     }
 
@@ -165,12 +271,18 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
     #[inline]
     #[allow(non_snake_case)]
     fn FPDF_LoadDocument(&self, file_path: &str, password: Option<&str>) -> FPDF_DOCUMENT {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDF_LoadDocument(file_path, password)
     }
 
     #[inline]
     #[allow(non_snake_case)]
     fn FPDF_LoadMemDocument64(&self, data_buf: &[u8], password: Option<&str>) -> FPDF_DOCUMENT {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDF_LoadMemDocument64(data_buf, password)
     }
 
@@ -181,6 +293,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         pFileAccess: *mut FPDF_FILEACCESS,
         password: Option<&str>,
     ) -> FPDF_DOCUMENT {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDF_LoadCustomDocument(pFileAccess, password)
     }
 
@@ -192,6 +307,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         pFileWrite: *mut FPDF_FILEWRITE,
         flags: FPDF_DWORD,
     ) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDF_SaveAsCopy(document, pFileWrite, flags)
     }
 
@@ -204,6 +322,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         flags: FPDF_DWORD,
         fileVersion: c_int,
     ) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDF_SaveWithVersion(document, pFileWrite, flags, fileVersion)
     }
@@ -215,30 +336,45 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         file_avail: *mut FX_FILEAVAIL,
         file: *mut FPDF_FILEACCESS,
     ) -> FPDF_AVAIL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFAvail_Create(file_avail, file)
     }
 
     #[inline]
     #[allow(non_snake_case)]
     fn FPDFAvail_Destroy(&self, avail: FPDF_AVAIL) {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFAvail_Destroy(avail)
     }
 
     #[inline]
     #[allow(non_snake_case)]
     fn FPDFAvail_IsDocAvail(&self, avail: FPDF_AVAIL, hints: *mut FX_DOWNLOADHINTS) -> c_int {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFAvail_IsDocAvail(avail, hints)
     }
 
     #[inline]
     #[allow(non_snake_case)]
     fn FPDFAvail_GetDocument(&self, avail: FPDF_AVAIL, password: Option<&str>) -> FPDF_DOCUMENT {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFAvail_GetDocument(avail, password)
     }
 
     #[inline]
     #[allow(non_snake_case)]
     fn FPDFAvail_GetFirstPageNum(&self, doc: FPDF_DOCUMENT) -> c_int {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFAvail_GetFirstPageNum(doc)
     }
 
@@ -250,6 +386,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         page_index: c_int,
         hints: *mut FX_DOWNLOADHINTS,
     ) -> c_int {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDFAvail_IsPageAvail(avail, page_index, hints)
     }
@@ -257,18 +396,27 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
     #[inline]
     #[allow(non_snake_case)]
     fn FPDFAvail_IsFormAvail(&self, avail: FPDF_AVAIL, hints: *mut FX_DOWNLOADHINTS) -> c_int {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFAvail_IsFormAvail(avail, hints)
     }
 
     #[inline]
     #[allow(non_snake_case)]
     fn FPDFAvail_IsLinearized(&self, avail: FPDF_AVAIL) -> c_int {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFAvail_IsLinearized(avail)
     }
 
     #[inline]
     #[allow(non_snake_case)]
     fn FPDF_CloseDocument(&self, document: FPDF_DOCUMENT) {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDF_CloseDocument(document)
     }
 
@@ -287,6 +435,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         page_x: *mut c_double,
         page_y: *mut c_double,
     ) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDF_DeviceToPage(
             page, start_x, start_y, size_x, size_y, rotate, device_x, device_y, page_x, page_y,
         )
@@ -307,6 +458,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         device_x: *mut c_int,
         device_y: *mut c_int,
     ) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDF_PageToDevice(
             page, start_x, start_y, size_x, size_y, rotate, page_x, page_y, device_x, device_y,
         )
@@ -315,12 +469,18 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
     #[inline]
     #[allow(non_snake_case)]
     fn FPDF_GetFileVersion(&self, doc: FPDF_DOCUMENT, fileVersion: *mut c_int) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDF_GetFileVersion(doc, fileVersion)
     }
 
     #[inline]
     #[allow(non_snake_case)]
     fn FPDF_DocumentHasValidCrossReferenceTable(&self, document: FPDF_DOCUMENT) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDF_DocumentHasValidCrossReferenceTable(document)
     }
@@ -333,12 +493,18 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         buffer: *mut c_uint,
         length: c_ulong,
     ) -> c_ulong {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDF_GetTrailerEnds(document, buffer, length)
     }
 
     #[inline]
     #[allow(non_snake_case)]
     fn FPDF_GetDocPermissions(&self, document: FPDF_DOCUMENT) -> c_ulong {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDF_GetDocPermissions(document)
     }
 
@@ -359,30 +525,45 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
     #[inline]
     #[allow(non_snake_case)]
     fn FPDF_GetDocUserPermissions(&self, document: FPDF_DOCUMENT) -> c_ulong {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDF_GetDocUserPermissions(document)
     }
 
     #[inline]
     #[allow(non_snake_case)]
     fn FPDF_GetSecurityHandlerRevision(&self, document: FPDF_DOCUMENT) -> c_int {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDF_GetSecurityHandlerRevision(document)
     }
 
     #[inline]
     #[allow(non_snake_case)]
     fn FPDF_GetPageCount(&self, document: FPDF_DOCUMENT) -> c_int {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDF_GetPageCount(document)
     }
 
     #[inline]
     #[allow(non_snake_case)]
     fn FPDF_LoadPage(&self, document: FPDF_DOCUMENT, page_index: c_int) -> FPDF_PAGE {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDF_LoadPage(document, page_index)
     }
 
     #[inline]
     #[allow(non_snake_case)]
     fn FPDF_ClosePage(&self, page: FPDF_PAGE) {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDF_ClosePage(page)
     }
 
@@ -401,6 +582,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         color_scheme: *const FPDF_COLORSCHEME,
         pause: *mut IFSDK_PAUSE,
     ) -> c_int {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDF_RenderPageBitmapWithColorScheme_Start(
             bitmap,
             page,
@@ -429,6 +613,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         flags: c_int,
         pause: *mut IFSDK_PAUSE,
     ) -> c_int {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDF_RenderPageBitmap_Start(
             bitmap, page, start_x, start_y, size_x, size_y, rotate, flags, pause,
         )
@@ -437,12 +624,18 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
     #[inline]
     #[allow(non_snake_case)]
     fn FPDF_RenderPage_Continue(&self, page: FPDF_PAGE, pause: *mut IFSDK_PAUSE) -> c_int {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDF_RenderPage_Continue(page, pause)
     }
 
     #[inline]
     #[allow(non_snake_case)]
     fn FPDF_RenderPage_Close(&self, page: FPDF_PAGE) {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDF_RenderPage_Close(page)
     }
 
@@ -456,6 +649,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         length: c_ulong,
         index: c_int,
     ) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDF_ImportPagesByIndex(dest_doc, src_doc, page_indices, length, index)
     }
@@ -469,6 +665,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         pagerange: &str,
         index: c_int,
     ) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDF_ImportPages(dest_doc, src_doc, pagerange, index)
     }
@@ -481,6 +680,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         num_pages_on_x_axis: size_t,
         num_pages_on_y_axis: size_t,
     ) -> FPDF_DOCUMENT {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDF_ImportNPagesToOne(
             src_doc,
             output_width,
@@ -498,6 +700,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         src_doc: FPDF_DOCUMENT,
         src_page_index: c_int,
     ) -> FPDF_XOBJECT {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDF_NewXObjectFromPage(dest_doc, src_doc, src_page_index)
     }
@@ -505,12 +710,18 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
     #[inline]
     #[allow(non_snake_case)]
     fn FPDF_CloseXObject(&self, xobject: FPDF_XOBJECT) {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDF_CloseXObject(xobject);
     }
 
     #[inline]
     #[allow(non_snake_case)]
     fn FPDF_NewFormObjectFromXObject(&self, xobject: FPDF_XOBJECT) -> FPDF_PAGEOBJECT {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDF_NewFormObjectFromXObject(xobject)
     }
 
@@ -521,30 +732,45 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         dest_doc: FPDF_DOCUMENT,
         src_doc: FPDF_DOCUMENT,
     ) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDF_CopyViewerPreferences(dest_doc, src_doc)
     }
 
     #[inline]
     #[allow(non_snake_case)]
     fn FPDF_GetPageWidthF(&self, page: FPDF_PAGE) -> c_float {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDF_GetPageWidthF(page)
     }
 
     #[inline]
     #[allow(non_snake_case)]
     fn FPDF_GetPageWidth(&self, page: FPDF_PAGE) -> f64 {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDF_GetPageWidth(page)
     }
 
     #[inline]
     #[allow(non_snake_case)]
     fn FPDF_GetPageHeightF(&self, page: FPDF_PAGE) -> c_float {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDF_GetPageHeightF(page)
     }
 
     #[inline]
     #[allow(non_snake_case)]
     fn FPDF_GetPageHeight(&self, page: FPDF_PAGE) -> f64 {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDF_GetPageHeight(page)
     }
 
@@ -555,6 +781,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         text_page: FPDF_TEXTPAGE,
         nTextIndex: c_int,
     ) -> c_int {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDFText_GetCharIndexFromTextIndex(text_page, nTextIndex)
     }
@@ -566,6 +795,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         text_page: FPDF_TEXTPAGE,
         nCharIndex: c_int,
     ) -> c_int {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDFText_GetTextIndexFromCharIndex(text_page, nCharIndex)
     }
@@ -573,12 +805,18 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
     #[inline]
     #[allow(non_snake_case)]
     fn FPDF_GetSignatureCount(&self, document: FPDF_DOCUMENT) -> c_int {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDF_GetSignatureCount(document)
     }
 
     #[inline]
     #[allow(non_snake_case)]
     fn FPDF_GetSignatureObject(&self, document: FPDF_DOCUMENT, index: c_int) -> FPDF_SIGNATURE {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDF_GetSignatureObject(document, index)
     }
 
@@ -590,6 +828,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         buffer: *mut c_void,
         length: c_ulong,
     ) -> c_ulong {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDFSignatureObj_GetContents(signature, buffer, length)
     }
@@ -602,6 +843,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         buffer: *mut c_int,
         length: c_ulong,
     ) -> c_ulong {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDFSignatureObj_GetByteRange(signature, buffer, length)
     }
@@ -614,6 +858,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         buffer: *mut c_char,
         length: c_ulong,
     ) -> c_ulong {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDFSignatureObj_GetSubFilter(signature, buffer, length)
     }
@@ -626,6 +873,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         buffer: *mut c_void,
         length: c_ulong,
     ) -> c_ulong {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDFSignatureObj_GetReason(signature, buffer, length)
     }
@@ -638,6 +888,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         buffer: *mut c_char,
         length: c_ulong,
     ) -> c_ulong {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDFSignatureObj_GetTime(signature, buffer, length)
     }
@@ -645,6 +898,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
     #[inline]
     #[allow(non_snake_case)]
     fn FPDFSignatureObj_GetDocMDPPermission(&self, signature: FPDF_SIGNATURE) -> c_uint {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDFSignatureObj_GetDocMDPPermission(signature)
     }
@@ -652,18 +908,27 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
     #[inline]
     #[allow(non_snake_case)]
     fn FPDF_StructTree_GetForPage(&self, page: FPDF_PAGE) -> FPDF_STRUCTTREE {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDF_StructTree_GetForPage(page)
     }
 
     #[inline]
     #[allow(non_snake_case)]
     fn FPDF_StructTree_Close(&self, struct_tree: FPDF_STRUCTTREE) {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDF_StructTree_Close(struct_tree)
     }
 
     #[inline]
     #[allow(non_snake_case)]
     fn FPDF_StructTree_CountChildren(&self, struct_tree: FPDF_STRUCTTREE) -> c_int {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDF_StructTree_CountChildren(struct_tree)
     }
 
@@ -674,6 +939,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         struct_tree: FPDF_STRUCTTREE,
         index: c_int,
     ) -> FPDF_STRUCTELEMENT {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDF_StructTree_GetChildAtIndex(struct_tree, index)
     }
@@ -686,6 +954,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         buffer: *mut c_void,
         buflen: c_ulong,
     ) -> c_ulong {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDF_StructElement_GetAltText(struct_element, buffer, buflen)
     }
@@ -698,6 +969,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         buffer: *mut c_void,
         buflen: c_ulong,
     ) -> c_ulong {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDF_StructElement_GetActualText(struct_element, buffer, buflen)
     }
@@ -710,6 +984,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         buffer: *mut c_void,
         buflen: c_ulong,
     ) -> c_ulong {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDF_StructElement_GetID(struct_element, buffer, buflen)
     }
@@ -722,6 +999,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         buffer: *mut c_void,
         buflen: c_ulong,
     ) -> c_ulong {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDF_StructElement_GetLang(struct_element, buffer, buflen)
     }
@@ -735,6 +1015,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         buffer: *mut c_void,
         buflen: c_ulong,
     ) -> c_ulong {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDF_StructElement_GetStringAttribute(
             struct_element,
             attr_name,
@@ -746,6 +1029,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
     #[inline]
     #[allow(non_snake_case)]
     fn FPDF_StructElement_GetMarkedContentID(&self, struct_element: FPDF_STRUCTELEMENT) -> c_int {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDF_StructElement_GetMarkedContentID(struct_element)
     }
@@ -758,6 +1044,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         buffer: *mut c_void,
         buflen: c_ulong,
     ) -> c_ulong {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDF_StructElement_GetType(struct_element, buffer, buflen)
     }
@@ -770,6 +1059,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         buffer: *mut c_void,
         buflen: c_ulong,
     ) -> c_ulong {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDF_StructElement_GetObjType(struct_element, buffer, buflen)
     }
@@ -782,6 +1074,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         buffer: *mut c_void,
         buflen: c_ulong,
     ) -> c_ulong {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDF_StructElement_GetTitle(struct_element, buffer, buflen)
     }
@@ -789,6 +1084,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
     #[inline]
     #[allow(non_snake_case)]
     fn FPDF_StructElement_CountChildren(&self, struct_element: FPDF_STRUCTELEMENT) -> c_int {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDF_StructElement_CountChildren(struct_element)
     }
@@ -800,6 +1098,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         struct_element: FPDF_STRUCTELEMENT,
         index: c_int,
     ) -> FPDF_STRUCTELEMENT {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDF_StructElement_GetChildAtIndex(struct_element, index)
     }
@@ -829,6 +1130,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         struct_element: FPDF_STRUCTELEMENT,
         index: c_int,
     ) -> c_int {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDF_StructElement_GetChildMarkedContentID(struct_element, index)
     }
@@ -839,12 +1143,18 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         &self,
         struct_element: FPDF_STRUCTELEMENT,
     ) -> FPDF_STRUCTELEMENT {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDF_StructElement_GetParent(struct_element)
     }
 
     #[inline]
     #[allow(non_snake_case)]
     fn FPDF_StructElement_GetAttributeCount(&self, struct_element: FPDF_STRUCTELEMENT) -> c_int {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDF_StructElement_GetAttributeCount(struct_element)
     }
@@ -856,6 +1166,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         struct_element: FPDF_STRUCTELEMENT,
         index: c_int,
     ) -> FPDF_STRUCTELEMENT_ATTR {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDF_StructElement_GetAttributeAtIndex(struct_element, index)
     }
@@ -863,6 +1176,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
     #[inline]
     #[allow(non_snake_case)]
     fn FPDF_StructElement_Attr_GetCount(&self, struct_attribute: FPDF_STRUCTELEMENT_ATTR) -> c_int {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDF_StructElement_Attr_GetCount(struct_attribute)
     }
@@ -877,6 +1193,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         buflen: c_ulong,
         out_buflen: *mut c_ulong,
     ) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDF_StructElement_Attr_GetName(
             struct_attribute,
             index,
@@ -903,6 +1222,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         struct_attribute: FPDF_STRUCTELEMENT_ATTR,
         name: &str,
     ) -> FPDF_STRUCTELEMENT_ATTR_VALUE {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDF_StructElement_Attr_GetValue(struct_attribute, name)
     }
@@ -925,6 +1247,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         struct_attribute: FPDF_STRUCTELEMENT_ATTR,
         name: &str,
     ) -> FPDF_OBJECT_TYPE {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDF_StructElement_Attr_GetType(struct_attribute, name)
     }
@@ -945,6 +1270,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         &self,
         value: FPDF_STRUCTELEMENT_ATTR_VALUE,
     ) -> FPDF_OBJECT_TYPE {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDF_StructElement_Attr_GetType(value)
     }
 
@@ -967,6 +1295,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         name: &str,
         out_value: *mut FPDF_BOOL,
     ) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDF_StructElement_Attr_GetBooleanValue(struct_attribute, name, out_value)
     }
@@ -988,6 +1319,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         value: FPDF_STRUCTELEMENT_ATTR_VALUE,
         out_value: *mut FPDF_BOOL,
     ) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDF_StructElement_Attr_GetBooleanValue(value, out_value)
     }
@@ -1011,6 +1345,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         name: &str,
         out_value: *mut f32,
     ) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDF_StructElement_Attr_GetNumberValue(struct_attribute, name, out_value)
     }
@@ -1032,6 +1369,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         value: FPDF_STRUCTELEMENT_ATTR_VALUE,
         out_value: *mut f32,
     ) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDF_StructElement_Attr_GetNumberValue(value, out_value)
     }
@@ -1057,6 +1397,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         buflen: c_ulong,
         out_buflen: *mut c_ulong,
     ) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDF_StructElement_Attr_GetStringValue(
             struct_attribute,
             name,
@@ -1085,6 +1428,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         buflen: c_ulong,
         out_buflen: *mut c_ulong,
     ) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDF_StructElement_Attr_GetStringValue(value, buffer, buflen, out_buflen)
     }
@@ -1112,6 +1458,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         buflen: c_ulong,
         out_buflen: *mut c_ulong,
     ) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDF_StructElement_Attr_GetBlobValue(
             struct_attribute,
             name,
@@ -1140,6 +1489,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         buflen: c_ulong,
         out_buflen: *mut c_ulong,
     ) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDF_StructElement_Attr_GetBlobValue(value, buffer, buflen, out_buflen)
     }
@@ -1157,6 +1509,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
     #[inline]
     #[allow(non_snake_case)]
     fn FPDF_StructElement_Attr_CountChildren(&self, value: FPDF_STRUCTELEMENT_ATTR_VALUE) -> c_int {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDF_StructElement_Attr_CountChildren(value)
     }
 
@@ -1177,6 +1532,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         value: FPDF_STRUCTELEMENT_ATTR_VALUE,
         index: c_int,
     ) -> FPDF_STRUCTELEMENT_ATTR_VALUE {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDF_StructElement_Attr_GetChildAtIndex(value, index)
     }
@@ -1187,6 +1545,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         &self,
         struct_element: FPDF_STRUCTELEMENT,
     ) -> c_int {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDF_StructElement_GetMarkedContentIdCount(struct_element)
     }
@@ -1198,6 +1559,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         struct_element: FPDF_STRUCTELEMENT,
         index: c_int,
     ) -> c_int {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDF_StructElement_GetMarkedContentIdAtIndex(struct_element, index)
     }
@@ -1211,6 +1575,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         width: c_double,
         height: c_double,
     ) -> FPDF_PAGE {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDFPage_New(document, page_index, width, height)
     }
@@ -1218,6 +1585,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
     #[inline]
     #[allow(non_snake_case)]
     fn FPDFPage_Delete(&self, document: FPDF_DOCUMENT, page_index: c_int) {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFPage_Delete(document, page_index)
     }
 
@@ -1249,6 +1619,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         page_indices_len: c_ulong,
         dest_page_index: c_int,
     ) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDF_MovePages(document, page_indices, page_indices_len, dest_page_index)
     }
@@ -1256,18 +1629,27 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
     #[inline]
     #[allow(non_snake_case)]
     fn FPDFPage_GetRotation(&self, page: FPDF_PAGE) -> c_int {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFPage_GetRotation(page)
     }
 
     #[inline]
     #[allow(non_snake_case)]
     fn FPDFPage_SetRotation(&self, page: FPDF_PAGE, rotate: c_int) {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFPage_SetRotation(page, rotate)
     }
 
     #[inline]
     #[allow(non_snake_case)]
     fn FPDF_GetPageBoundingBox(&self, page: FPDF_PAGE, rect: *mut FS_RECTF) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDF_GetPageBoundingBox(page, rect)
     }
 
@@ -1279,6 +1661,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         page_index: c_int,
         size: *mut FS_SIZEF,
     ) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDF_GetPageSizeByIndexF(document, page_index, size)
     }
@@ -1292,6 +1677,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         width: *mut f64,
         height: *mut f64,
     ) -> c_int {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDF_GetPageSizeByIndex(document, page_index, width, height)
     }
@@ -1306,6 +1694,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         right: *mut c_float,
         top: *mut c_float,
     ) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDFPage_GetMediaBox(page, left, bottom, right, top)
     }
@@ -1320,6 +1711,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         right: *mut c_float,
         top: *mut c_float,
     ) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDFPage_GetCropBox(page, left, bottom, right, top)
     }
@@ -1334,6 +1728,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         right: *mut c_float,
         top: *mut c_float,
     ) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDFPage_GetBleedBox(page, left, bottom, right, top)
     }
@@ -1348,6 +1745,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         right: *mut c_float,
         top: *mut c_float,
     ) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDFPage_GetTrimBox(page, left, bottom, right, top)
     }
@@ -1362,6 +1762,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         right: *mut c_float,
         top: *mut c_float,
     ) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDFPage_GetArtBox(page, left, bottom, right, top)
     }
@@ -1376,6 +1779,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         right: c_float,
         top: c_float,
     ) {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDFPage_SetMediaBox(page, left, bottom, right, top)
     }
@@ -1390,6 +1796,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         right: c_float,
         top: c_float,
     ) {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDFPage_SetCropBox(page, left, bottom, right, top)
     }
@@ -1404,6 +1813,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         right: c_float,
         top: c_float,
     ) {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDFPage_SetBleedBox(page, left, bottom, right, top)
     }
@@ -1418,6 +1830,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         right: c_float,
         top: c_float,
     ) {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDFPage_SetTrimBox(page, left, bottom, right, top)
     }
@@ -1432,6 +1847,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         right: c_float,
         top: c_float,
     ) {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDFPage_SetArtBox(page, left, bottom, right, top)
     }
@@ -1444,6 +1862,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         matrix: *const FS_MATRIX,
         clipRect: *const FS_RECTF,
     ) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDFPage_TransFormWithClip(page, matrix, clipRect)
     }
@@ -1460,6 +1881,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         e: f64,
         f: f64,
     ) {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDFPageObj_TransformClipPath(page_object, a, b, c, d, e, f)
     }
@@ -1467,18 +1891,27 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
     #[inline]
     #[allow(non_snake_case)]
     fn FPDFPageObj_GetClipPath(&self, page_object: FPDF_PAGEOBJECT) -> FPDF_CLIPPATH {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFPageObj_GetClipPath(page_object)
     }
 
     #[inline]
     #[allow(non_snake_case)]
     fn FPDFClipPath_CountPaths(&self, clip_path: FPDF_CLIPPATH) -> c_int {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFClipPath_CountPaths(clip_path)
     }
 
     #[inline]
     #[allow(non_snake_case)]
     fn FPDFClipPath_CountPathSegments(&self, clip_path: FPDF_CLIPPATH, path_index: c_int) -> c_int {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDFClipPath_CountPathSegments(clip_path, path_index)
     }
@@ -1491,6 +1924,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         path_index: c_int,
         segment_index: c_int,
     ) -> FPDF_PATHSEGMENT {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDFClipPath_GetPathSegment(clip_path, path_index, segment_index)
     }
@@ -1498,30 +1934,45 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
     #[inline]
     #[allow(non_snake_case)]
     fn FPDF_CreateClipPath(&self, left: f32, bottom: f32, right: f32, top: f32) -> FPDF_CLIPPATH {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDF_CreateClipPath(left, bottom, right, top)
     }
 
     #[inline]
     #[allow(non_snake_case)]
     fn FPDF_DestroyClipPath(&self, clipPath: FPDF_CLIPPATH) {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDF_DestroyClipPath(clipPath)
     }
 
     #[inline]
     #[allow(non_snake_case)]
     fn FPDFPage_InsertClipPath(&self, page: FPDF_PAGE, clipPath: FPDF_CLIPPATH) {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFPage_InsertClipPath(page, clipPath)
     }
 
     #[inline]
     #[allow(non_snake_case)]
     fn FPDFPage_HasTransparency(&self, page: FPDF_PAGE) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFPage_HasTransparency(page)
     }
 
     #[inline]
     #[allow(non_snake_case)]
     fn FPDFPage_GenerateContent(&self, page: FPDF_PAGE) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFPage_GenerateContent(page)
     }
 
@@ -1536,6 +1987,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         e: f64,
         f: f64,
     ) {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDFPage_TransformAnnots(page, a, b, c, d, e, f)
     }
@@ -1543,6 +1997,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
     #[inline]
     #[allow(non_snake_case)]
     fn FPDFBitmap_Create(&self, width: c_int, height: c_int, alpha: c_int) -> FPDF_BITMAP {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFBitmap_Create(width, height, alpha)
     }
 
@@ -1556,6 +2013,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         first_scan: *mut c_void,
         stride: c_int,
     ) -> FPDF_BITMAP {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDFBitmap_CreateEx(width, height, format, first_scan, stride)
     }
@@ -1563,9 +2023,13 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
     #[inline]
     #[allow(non_snake_case)]
     fn FPDFBitmap_Destroy(&self, bitmap: FPDF_BITMAP) {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFBitmap_Destroy(bitmap)
     }
 
+    #[cfg(target_os = "windows")]
     #[cfg(feature = "pdfium_use_win32")]
     #[inline]
     #[allow(non_snake_case)]
@@ -1580,6 +2044,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         rotate: c_int,
         flags: c_int,
     ) {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDF_RenderPage(dc, page, start_x, start_y, size_x, size_y, rotate, flags);
     }
@@ -1587,6 +2054,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
     #[inline]
     #[allow(non_snake_case)]
     fn FPDFBitmap_GetFormat(&self, bitmap: FPDF_BITMAP) -> c_int {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFBitmap_GetFormat(bitmap)
     }
 
@@ -1618,6 +2088,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         height: c_int,
         color: FPDF_DWORD,
     ) {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDFBitmap_FillRect(bitmap, left, top, width, height, color);
     }
@@ -1639,6 +2112,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         height: c_int,
         color: FPDF_DWORD,
     ) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDFBitmap_FillRect(bitmap, left, top, width, height, color)
     }
@@ -1647,6 +2123,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
     #[allow(non_snake_case)]
     #[cfg(not(target_arch = "wasm32"))]
     fn FPDFBitmap_GetBuffer(&self, bitmap: FPDF_BITMAP) -> *mut c_void {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFBitmap_GetBuffer(bitmap)
     }
 
@@ -1655,12 +2134,18 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
     #[allow(non_snake_case)]
     #[cfg(target_arch = "wasm32")]
     fn FPDFBitmap_GetBuffer(&self, bitmap: FPDF_BITMAP) -> *const c_void {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFBitmap_GetBuffer(bitmap)
     }
 
     #[inline]
     #[allow(non_snake_case)]
     fn FPDFBitmap_SetBuffer(&self, bitmap: FPDF_BITMAP, buffer: &[u8]) -> bool {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFBitmap_SetBuffer(bitmap, buffer)
     }
 
@@ -1668,24 +2153,36 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
     #[allow(non_snake_case)]
     #[cfg(target_arch = "wasm32")]
     fn FPDFBitmap_GetBuffer_as_array(&self, bitmap: FPDF_BITMAP) -> js_sys::Uint8Array {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFBitmap_GetBuffer_as_array(bitmap)
     }
 
     #[inline]
     #[allow(non_snake_case)]
     fn FPDFBitmap_GetWidth(&self, bitmap: FPDF_BITMAP) -> c_int {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFBitmap_GetWidth(bitmap)
     }
 
     #[inline]
     #[allow(non_snake_case)]
     fn FPDFBitmap_GetHeight(&self, bitmap: FPDF_BITMAP) -> c_int {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFBitmap_GetHeight(bitmap)
     }
 
     #[inline]
     #[allow(non_snake_case)]
     fn FPDFBitmap_GetStride(&self, bitmap: FPDF_BITMAP) -> c_int {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFBitmap_GetStride(bitmap)
     }
 
@@ -1702,6 +2199,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         rotate: c_int,
         flags: c_int,
     ) {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDF_RenderPageBitmap(
             bitmap, page, start_x, start_y, size_x, size_y, rotate, flags,
         )
@@ -1717,6 +2217,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         clipping: *const FS_RECTF,
         flags: c_int,
     ) {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDF_RenderPageBitmapWithMatrix(bitmap, page, matrix, clipping, flags)
     }
@@ -1731,6 +2234,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         size_x: c_int,
         size_y: c_int,
     ) {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDF_RenderPageSkia(canvas, page, size_x, size_y);
     }
@@ -1738,6 +2244,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
     #[inline]
     #[allow(non_snake_case)]
     fn FPDFAnnot_IsSupportedSubtype(&self, subtype: FPDF_ANNOTATION_SUBTYPE) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFAnnot_IsSupportedSubtype(subtype)
     }
 
@@ -1748,54 +2257,81 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         page: FPDF_PAGE,
         subtype: FPDF_ANNOTATION_SUBTYPE,
     ) -> FPDF_ANNOTATION {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFPage_CreateAnnot(page, subtype)
     }
 
     #[inline]
     #[allow(non_snake_case)]
     fn FPDFPage_GetAnnotCount(&self, page: FPDF_PAGE) -> c_int {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFPage_GetAnnotCount(page)
     }
 
     #[inline]
     #[allow(non_snake_case)]
     fn FPDFPage_GetAnnot(&self, page: FPDF_PAGE, index: c_int) -> FPDF_ANNOTATION {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFPage_GetAnnot(page, index)
     }
 
     #[inline]
     #[allow(non_snake_case)]
     fn FPDFPage_GetAnnotIndex(&self, page: FPDF_PAGE, annot: FPDF_ANNOTATION) -> c_int {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFPage_GetAnnotIndex(page, annot)
     }
 
     #[inline]
     #[allow(non_snake_case)]
     fn FPDFPage_CloseAnnot(&self, annot: FPDF_ANNOTATION) {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFPage_CloseAnnot(annot)
     }
 
     #[inline]
     #[allow(non_snake_case)]
     fn FPDFPage_RemoveAnnot(&self, page: FPDF_PAGE, index: c_int) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFPage_RemoveAnnot(page, index)
     }
 
     #[inline]
     #[allow(non_snake_case)]
     fn FPDFAnnot_GetSubtype(&self, annot: FPDF_ANNOTATION) -> FPDF_ANNOTATION_SUBTYPE {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFAnnot_GetSubtype(annot)
     }
 
     #[inline]
     #[allow(non_snake_case)]
     fn FPDFAnnot_IsObjectSupportedSubtype(&self, subtype: FPDF_ANNOTATION_SUBTYPE) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFAnnot_IsObjectSupportedSubtype(subtype)
     }
 
     #[inline]
     #[allow(non_snake_case)]
     fn FPDFAnnot_UpdateObject(&self, annot: FPDF_ANNOTATION, obj: FPDF_PAGEOBJECT) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFAnnot_UpdateObject(annot, obj)
     }
 
@@ -1807,6 +2343,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         points: *const FS_POINTF,
         point_count: size_t,
     ) -> c_int {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDFAnnot_AddInkStroke(annot, points, point_count)
     }
@@ -1814,30 +2353,45 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
     #[inline]
     #[allow(non_snake_case)]
     fn FPDFAnnot_RemoveInkList(&self, annot: FPDF_ANNOTATION) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFAnnot_RemoveInkList(annot)
     }
 
     #[inline]
     #[allow(non_snake_case)]
     fn FPDFAnnot_AppendObject(&self, annot: FPDF_ANNOTATION, obj: FPDF_PAGEOBJECT) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFAnnot_AppendObject(annot, obj)
     }
 
     #[inline]
     #[allow(non_snake_case)]
     fn FPDFAnnot_GetObjectCount(&self, annot: FPDF_ANNOTATION) -> c_int {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFAnnot_GetObjectCount(annot)
     }
 
     #[inline]
     #[allow(non_snake_case)]
     fn FPDFAnnot_GetObject(&self, annot: FPDF_ANNOTATION, index: c_int) -> FPDF_PAGEOBJECT {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFAnnot_GetObject(annot, index)
     }
 
     #[inline]
     #[allow(non_snake_case)]
     fn FPDFAnnot_RemoveObject(&self, annot: FPDF_ANNOTATION, index: c_int) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFAnnot_RemoveObject(annot, index)
     }
 
@@ -1852,6 +2406,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         B: c_uint,
         A: c_uint,
     ) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDFAnnot_SetColor(annot, color_type, R, G, B, A)
     }
@@ -1867,6 +2424,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         B: *mut c_uint,
         A: *mut c_uint,
     ) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDFAnnot_GetColor(annot, color_type, R, G, B, A)
     }
@@ -1874,6 +2434,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
     #[inline]
     #[allow(non_snake_case)]
     fn FPDFAnnot_HasAttachmentPoints(&self, annot: FPDF_ANNOTATION) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFAnnot_HasAttachmentPoints(annot)
     }
 
@@ -1885,6 +2448,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         quad_index: size_t,
         quad_points: *const FS_QUADPOINTSF,
     ) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDFAnnot_SetAttachmentPoints(annot, quad_index, quad_points)
     }
@@ -1896,6 +2462,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         annot: FPDF_ANNOTATION,
         quad_points: *const FS_QUADPOINTSF,
     ) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDFAnnot_AppendAttachmentPoints(annot, quad_points)
     }
@@ -1903,6 +2472,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
     #[inline]
     #[allow(non_snake_case)]
     fn FPDFAnnot_CountAttachmentPoints(&self, annot: FPDF_ANNOTATION) -> size_t {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFAnnot_CountAttachmentPoints(annot)
     }
 
@@ -1914,6 +2486,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         quad_index: size_t,
         quad_points: *mut FS_QUADPOINTSF,
     ) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDFAnnot_GetAttachmentPoints(annot, quad_index, quad_points)
     }
@@ -1921,12 +2496,18 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
     #[inline]
     #[allow(non_snake_case)]
     fn FPDFAnnot_SetRect(&self, annot: FPDF_ANNOTATION, rect: *const FS_RECTF) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFAnnot_SetRect(annot, rect)
     }
 
     #[inline]
     #[allow(non_snake_case)]
     fn FPDFAnnot_GetRect(&self, annot: FPDF_ANNOTATION, rect: *mut FS_RECTF) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFAnnot_GetRect(annot, rect)
     }
 
@@ -1938,12 +2519,18 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         buffer: *mut FS_POINTF,
         length: c_ulong,
     ) -> c_ulong {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFAnnot_GetVertices(annot, buffer, length)
     }
 
     #[inline]
     #[allow(non_snake_case)]
     fn FPDFAnnot_GetInkListCount(&self, annot: FPDF_ANNOTATION) -> c_ulong {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFAnnot_GetInkListCount(annot)
     }
 
@@ -1956,6 +2543,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         buffer: *mut FS_POINTF,
         length: c_ulong,
     ) -> c_ulong {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDFAnnot_GetInkListPath(annot, path_index, buffer, length)
     }
@@ -1968,6 +2558,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         start: *mut FS_POINTF,
         end: *mut FS_POINTF,
     ) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFAnnot_GetLine(annot, start, end)
     }
 
@@ -1980,6 +2573,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         vertical_radius: c_float,
         border_width: c_float,
     ) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDFAnnot_SetBorder(annot, horizontal_radius, vertical_radius, border_width)
     }
@@ -1993,6 +2589,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         vertical_radius: *mut c_float,
         border_width: *mut c_float,
     ) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDFAnnot_GetBorder(annot, horizontal_radius, vertical_radius, border_width)
     }
@@ -2007,6 +2606,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         buffer: *mut FPDF_WCHAR,
         buflen: c_ulong,
     ) -> c_ulong {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDFAnnot_GetFormAdditionalActionJavaScript(hHandle, annot, event, buffer, buflen)
     }
@@ -2020,6 +2622,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         buffer: *mut FPDF_WCHAR,
         buflen: c_ulong,
     ) -> c_ulong {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDFAnnot_GetFormFieldAlternateName(hHandle, annot, buffer, buflen)
     }
@@ -2027,12 +2632,18 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
     #[inline]
     #[allow(non_snake_case)]
     fn FPDFAnnot_HasKey(&self, annot: FPDF_ANNOTATION, key: &str) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFAnnot_HasKey(annot, key)
     }
 
     #[inline]
     #[allow(non_snake_case)]
     fn FPDFAnnot_GetValueType(&self, annot: FPDF_ANNOTATION, key: &str) -> FPDF_OBJECT_TYPE {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFAnnot_GetValueType(annot, key)
     }
 
@@ -2044,6 +2655,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         key: &str,
         value: FPDF_WIDESTRING,
     ) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFAnnot_SetStringValue(annot, key, value)
     }
 
@@ -2056,6 +2670,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         buffer: *mut FPDF_WCHAR,
         buflen: c_ulong,
     ) -> c_ulong {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDFAnnot_GetStringValue(annot, key, buffer, buflen)
     }
@@ -2068,6 +2685,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         key: &str,
         value: *mut c_float,
     ) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFAnnot_GetNumberValue(annot, key, value)
     }
 
@@ -2079,6 +2699,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         appearanceMode: FPDF_ANNOT_APPEARANCEMODE,
         value: FPDF_WIDESTRING,
     ) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFAnnot_SetAP(annot, appearanceMode, value)
     }
 
@@ -2091,6 +2714,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         buffer: *mut FPDF_WCHAR,
         buflen: c_ulong,
     ) -> c_ulong {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDFAnnot_GetAP(annot, appearanceMode, buffer, buflen)
     }
@@ -2098,18 +2724,27 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
     #[inline]
     #[allow(non_snake_case)]
     fn FPDFAnnot_GetLinkedAnnot(&self, annot: FPDF_ANNOTATION, key: &str) -> FPDF_ANNOTATION {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFAnnot_GetLinkedAnnot(annot, key)
     }
 
     #[inline]
     #[allow(non_snake_case)]
     fn FPDFAnnot_GetFlags(&self, annot: FPDF_ANNOTATION) -> c_int {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFAnnot_GetFlags(annot)
     }
 
     #[inline]
     #[allow(non_snake_case)]
     fn FPDFAnnot_SetFlags(&self, annot: FPDF_ANNOTATION, flags: c_int) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFAnnot_SetFlags(annot, flags)
     }
 
@@ -2120,6 +2755,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         handle: FPDF_FORMHANDLE,
         annot: FPDF_ANNOTATION,
     ) -> c_int {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFAnnot_GetFormFieldFlags(handle, annot)
     }
 
@@ -2131,6 +2769,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         page: FPDF_PAGE,
         point: *const FS_POINTF,
     ) -> FPDF_ANNOTATION {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDFAnnot_GetFormFieldAtPoint(hHandle, page, point)
     }
@@ -2144,6 +2785,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         buffer: *mut FPDF_WCHAR,
         buflen: c_ulong,
     ) -> c_ulong {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDFAnnot_GetFormFieldName(hHandle, annot, buffer, buflen)
     }
@@ -2155,6 +2799,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         hHandle: FPDF_FORMHANDLE,
         annot: FPDF_ANNOTATION,
     ) -> c_int {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFAnnot_GetFormFieldType(hHandle, annot)
     }
 
@@ -2167,6 +2814,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         buffer: *mut FPDF_WCHAR,
         buflen: c_ulong,
     ) -> c_ulong {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDFAnnot_GetFormFieldValue(hHandle, annot, buffer, buflen)
     }
@@ -2174,6 +2824,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
     #[inline]
     #[allow(non_snake_case)]
     fn FPDFAnnot_GetOptionCount(&self, hHandle: FPDF_FORMHANDLE, annot: FPDF_ANNOTATION) -> c_int {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFAnnot_GetOptionCount(hHandle, annot)
     }
 
@@ -2187,6 +2840,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         buffer: *mut FPDF_WCHAR,
         buflen: c_ulong,
     ) -> c_ulong {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDFAnnot_GetOptionLabel(hHandle, annot, index, buffer, buflen)
     }
@@ -2199,6 +2855,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         annot: FPDF_ANNOTATION,
         index: c_int,
     ) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDFAnnot_IsOptionSelected(handle, annot, index)
     }
@@ -2211,6 +2870,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         annot: FPDF_ANNOTATION,
         value: *mut c_float,
     ) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFAnnot_GetFontSize(hHandle, annot, value)
     }
 
@@ -2233,6 +2895,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         G: *mut c_uint,
         B: *mut c_uint,
     ) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDFAnnot_GetFontColor(hHandle, annot, R, G, B)
     }
@@ -2240,6 +2905,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
     #[inline]
     #[allow(non_snake_case)]
     fn FPDFAnnot_IsChecked(&self, hHandle: FPDF_FORMHANDLE, annot: FPDF_ANNOTATION) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFAnnot_IsChecked(hHandle, annot)
     }
 
@@ -2251,6 +2919,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         subtypes: *const FPDF_ANNOTATION_SUBTYPE,
         count: size_t,
     ) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDFAnnot_SetFocusableSubtypes(hHandle, subtypes, count)
     }
@@ -2258,6 +2929,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
     #[inline]
     #[allow(non_snake_case)]
     fn FPDFAnnot_GetFocusableSubtypesCount(&self, hHandle: FPDF_FORMHANDLE) -> c_int {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFAnnot_GetFocusableSubtypesCount(hHandle)
     }
 
@@ -2269,6 +2943,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         subtypes: *mut FPDF_ANNOTATION_SUBTYPE,
         count: size_t,
     ) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDFAnnot_GetFocusableSubtypes(hHandle, subtypes, count)
     }
@@ -2276,6 +2953,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
     #[inline]
     #[allow(non_snake_case)]
     fn FPDFAnnot_GetLink(&self, annot: FPDF_ANNOTATION) -> FPDF_LINK {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFAnnot_GetLink(annot)
     }
 
@@ -2286,6 +2966,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         hHandle: FPDF_FORMHANDLE,
         annot: FPDF_ANNOTATION,
     ) -> c_int {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFAnnot_GetFormControlCount(hHandle, annot)
     }
 
@@ -2296,6 +2979,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         hHandle: FPDF_FORMHANDLE,
         annot: FPDF_ANNOTATION,
     ) -> c_int {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFAnnot_GetFormControlIndex(hHandle, annot)
     }
 
@@ -2308,6 +2994,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         buffer: *mut FPDF_WCHAR,
         buflen: c_ulong,
     ) -> c_ulong {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDFAnnot_GetFormFieldExportValue(hHandle, annot, buffer, buflen)
     }
@@ -2315,6 +3004,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
     #[inline]
     #[allow(non_snake_case)]
     fn FPDFAnnot_SetURI(&self, annot: FPDF_ANNOTATION, uri: &str) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFAnnot_SetURI(annot, uri)
     }
 
@@ -2333,6 +3025,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
     #[inline]
     #[allow(non_snake_case)]
     fn FPDFAnnot_GetFileAttachment(&self, annot: FPDF_ANNOTATION) -> FPDF_ATTACHMENT {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFAnnot_GetFileAttachment(annot)
     }
 
@@ -2355,6 +3050,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         annot: FPDF_ANNOTATION,
         name: FPDF_WIDESTRING,
     ) -> FPDF_ATTACHMENT {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFAnnot_AddFileAttachment(annot, name)
     }
 
@@ -2365,6 +3063,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         document: FPDF_DOCUMENT,
         form_info: *mut FPDF_FORMFILLINFO,
     ) -> FPDF_FORMHANDLE {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDFDOC_InitFormFillEnvironment(document, form_info)
     }
@@ -2372,52 +3073,79 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
     #[inline]
     #[allow(non_snake_case)]
     fn FPDFDOC_ExitFormFillEnvironment(&self, handle: FPDF_FORMHANDLE) {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFDOC_ExitFormFillEnvironment(handle)
     }
 
     #[allow(non_snake_case)]
     fn FORM_OnAfterLoadPage(&self, page: FPDF_PAGE, handle: FPDF_FORMHANDLE) {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FORM_OnAfterLoadPage(page, handle)
     }
 
     #[allow(non_snake_case)]
     fn FORM_OnBeforeClosePage(&self, page: FPDF_PAGE, handle: FPDF_FORMHANDLE) {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FORM_OnBeforeClosePage(page, handle)
     }
 
     #[inline]
     #[allow(non_snake_case)]
     fn FPDFDoc_GetPageMode(&self, document: FPDF_DOCUMENT) -> c_int {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFDoc_GetPageMode(document)
     }
 
     #[inline]
     #[allow(non_snake_case)]
     fn FPDFPage_Flatten(&self, page: FPDF_PAGE, nFlag: c_int) -> c_int {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFPage_Flatten(page, nFlag)
     }
 
     #[inline]
     #[allow(non_snake_case)]
     fn FORM_DoDocumentJSAction(&self, hHandle: FPDF_FORMHANDLE) {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FORM_DoDocumentJSAction(hHandle)
     }
 
     #[inline]
     #[allow(non_snake_case)]
     fn FORM_DoDocumentOpenAction(&self, hHandle: FPDF_FORMHANDLE) {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FORM_DoDocumentOpenAction(hHandle)
     }
 
     #[inline]
     #[allow(non_snake_case)]
     fn FORM_DoDocumentAAction(&self, hHandle: FPDF_FORMHANDLE, aaType: c_int) {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FORM_DoDocumentAAction(hHandle, aaType)
     }
 
     #[inline]
     #[allow(non_snake_case)]
     fn FORM_DoPageAAction(&self, page: FPDF_PAGE, hHandle: FPDF_FORMHANDLE, aaType: c_int) {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FORM_DoPageAAction(page, hHandle, aaType)
     }
 
@@ -2431,6 +3159,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         page_x: f64,
         page_y: f64,
     ) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FORM_OnMouseMove(hHandle, page, modifier, page_x, page_y)
     }
@@ -2446,6 +3177,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         delta_x: c_int,
         delta_y: c_int,
     ) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FORM_OnMouseWheel(hHandle, page, modifier, page_coord, delta_x, delta_y)
     }
@@ -2460,6 +3194,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         page_x: f64,
         page_y: f64,
     ) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FORM_OnFocus(hHandle, page, modifier, page_x, page_y)
     }
@@ -2474,6 +3211,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         page_x: f64,
         page_y: f64,
     ) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FORM_OnLButtonDown(hHandle, page, modifier, page_x, page_y)
     }
@@ -2488,6 +3228,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         page_x: f64,
         page_y: f64,
     ) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FORM_OnRButtonDown(hHandle, page, modifier, page_x, page_y)
     }
@@ -2502,6 +3245,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         page_x: f64,
         page_y: f64,
     ) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FORM_OnLButtonUp(hHandle, page, modifier, page_x, page_y)
     }
@@ -2516,6 +3262,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         page_x: f64,
         page_y: f64,
     ) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FORM_OnRButtonUp(hHandle, page, modifier, page_x, page_y)
     }
@@ -2530,6 +3279,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         page_x: f64,
         page_y: f64,
     ) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FORM_OnLButtonDoubleClick(hHandle, page, modifier, page_x, page_y)
     }
@@ -2543,6 +3295,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         nKeyCode: c_int,
         modifier: c_int,
     ) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FORM_OnKeyDown(hHandle, page, nKeyCode, modifier)
     }
@@ -2556,6 +3311,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         nKeyCode: c_int,
         modifier: c_int,
     ) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FORM_OnKeyUp(hHandle, page, nKeyCode, modifier)
     }
@@ -2569,6 +3327,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         nChar: c_int,
         modifier: c_int,
     ) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FORM_OnChar(hHandle, page, nChar, modifier)
     }
 
@@ -2581,6 +3342,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         buffer: *mut c_void,
         buflen: c_ulong,
     ) -> c_ulong {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FORM_GetFocusedText(hHandle, page, buffer, buflen)
     }
@@ -2594,6 +3358,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         buffer: *mut c_void,
         buflen: c_ulong,
     ) -> c_ulong {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FORM_GetSelectedText(hHandle, page, buffer, buflen)
     }
@@ -2606,6 +3373,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         page: FPDF_PAGE,
         wsText: FPDF_WIDESTRING,
     ) {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FORM_ReplaceAndKeepSelection(hHandle, page, wsText)
     }
@@ -2618,30 +3388,45 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         page: FPDF_PAGE,
         wsText: FPDF_WIDESTRING,
     ) {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FORM_ReplaceSelection(hHandle, page, wsText)
     }
 
     #[inline]
     #[allow(non_snake_case)]
     fn FORM_SelectAllText(&self, hHandle: FPDF_FORMHANDLE, page: FPDF_PAGE) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FORM_SelectAllText(hHandle, page)
     }
 
     #[inline]
     #[allow(non_snake_case)]
     fn FORM_CanUndo(&self, hHandle: FPDF_FORMHANDLE, page: FPDF_PAGE) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FORM_CanUndo(hHandle, page)
     }
 
     #[inline]
     #[allow(non_snake_case)]
     fn FORM_CanRedo(&self, hHandle: FPDF_FORMHANDLE, page: FPDF_PAGE) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FORM_CanRedo(hHandle, page)
     }
 
     #[inline]
     #[allow(non_snake_case)]
     fn FORM_Undo(&self, hHandle: FPDF_FORMHANDLE, page: FPDF_PAGE) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FORM_Undo(hHandle, page)
     }
 
@@ -2649,12 +3434,18 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
     #[doc = " Function: FORM_Redo\n       Make the current focused widget perform a redo operation.\n Parameters:\n       hHandle     -   Handle to the form fill module, as returned by\n                       FPDFDOC_InitFormFillEnvironment().\n       page        -   Handle to the page, as returned by FPDF_LoadPage().\n Return Value:\n       True if the redo operation succeeded."]
     #[allow(non_snake_case)]
     fn FORM_Redo(&self, hHandle: FPDF_FORMHANDLE, page: FPDF_PAGE) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FORM_Redo(hHandle, page)
     }
 
     #[inline]
     #[allow(non_snake_case)]
     fn FORM_ForceToKillFocus(&self, hHandle: FPDF_FORMHANDLE) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FORM_ForceToKillFocus(hHandle)
     }
 
@@ -2666,6 +3457,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         page_index: *mut c_int,
         annot: *mut FPDF_ANNOTATION,
     ) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FORM_GetFocusedAnnot(handle, page_index, annot)
     }
@@ -2673,6 +3467,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
     #[inline]
     #[allow(non_snake_case)]
     fn FORM_SetFocusedAnnot(&self, handle: FPDF_FORMHANDLE, annot: FPDF_ANNOTATION) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FORM_SetFocusedAnnot(handle, annot)
     }
 
@@ -2685,6 +3482,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         page_x: f64,
         page_y: f64,
     ) -> c_int {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDFPage_HasFormFieldAtPoint(hHandle, page, page_x, page_y)
     }
@@ -2698,6 +3498,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         page_x: f64,
         page_y: f64,
     ) -> c_int {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDFPage_FormFieldZOrderAtPoint(hHandle, page, page_x, page_y)
     }
@@ -2710,6 +3513,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         field_type: c_int,
         color: FPDF_DWORD,
     ) {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDF_SetFormFieldHighlightColor(handle, field_type, color)
     }
@@ -2717,12 +3523,18 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
     #[inline]
     #[allow(non_snake_case)]
     fn FPDF_SetFormFieldHighlightAlpha(&self, handle: FPDF_FORMHANDLE, alpha: c_uchar) {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDF_SetFormFieldHighlightAlpha(handle, alpha)
     }
 
     #[inline]
     #[allow(non_snake_case)]
     fn FPDF_RemoveFormFieldHighlight(&self, hHandle: FPDF_FORMHANDLE) {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDF_RemoveFormFieldHighlight(hHandle)
     }
 
@@ -2740,6 +3552,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         rotate: c_int,
         flags: c_int,
     ) {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDF_FFLDraw(
             handle, bitmap, page, start_x, start_y, size_x, size_y, rotate, flags,
         )
@@ -2760,6 +3575,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         rotate: c_int,
         flags: c_int,
     ) {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDF_FFLDrawSkia(
             hHandle, canvas, page, start_x, start_y, size_x, size_y, rotate, flags,
         );
@@ -2768,6 +3586,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
     #[inline]
     #[allow(non_snake_case)]
     fn FPDF_GetFormType(&self, document: FPDF_DOCUMENT) -> c_int {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDF_GetFormType(document)
     }
 
@@ -2780,6 +3601,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         index: c_int,
         selected: FPDF_BOOL,
     ) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FORM_SetIndexSelected(hHandle, page, index, selected)
     }
@@ -2792,18 +3616,27 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         page: FPDF_PAGE,
         index: c_int,
     ) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FORM_IsIndexSelected(hHandle, page, index)
     }
 
     #[inline]
     #[allow(non_snake_case)]
     fn FPDF_LoadXFA(&self, document: FPDF_DOCUMENT) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDF_LoadXFA(document)
     }
 
     #[inline]
     #[allow(non_snake_case)]
     fn FPDFDoc_GetJavaScriptActionCount(&self, document: FPDF_DOCUMENT) -> c_int {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFDoc_GetJavaScriptActionCount(document)
     }
 
@@ -2814,12 +3647,18 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         document: FPDF_DOCUMENT,
         index: c_int,
     ) -> FPDF_JAVASCRIPT_ACTION {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFDoc_GetJavaScriptAction(document, index)
     }
 
     #[inline]
     #[allow(non_snake_case)]
     fn FPDFDoc_CloseJavaScriptAction(&self, javascript: FPDF_JAVASCRIPT_ACTION) {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFDoc_CloseJavaScriptAction(javascript)
     }
 
@@ -2831,6 +3670,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         buffer: *mut FPDF_WCHAR,
         buflen: c_ulong,
     ) -> c_ulong {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDFJavaScriptAction_GetName(javascript, buffer, buflen)
     }
@@ -2843,6 +3685,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         buffer: *mut FPDF_WCHAR,
         buflen: c_ulong,
     ) -> c_ulong {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDFJavaScriptAction_GetScript(javascript, buffer, buflen)
     }
@@ -2850,6 +3695,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
     #[inline]
     #[allow(non_snake_case)]
     fn FPDF_GetDefaultTTFMap(&self) -> *const FPDF_CharsetFontMap {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDF_GetDefaultTTFMap()
     }
 
@@ -2864,6 +3712,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
     #[inline]
     #[allow(non_snake_case)]
     fn FPDF_GetDefaultTTFMapCount(&self) -> usize {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDF_GetDefaultTTFMapCount()
     }
 
@@ -2878,30 +3729,45 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
     #[inline]
     #[allow(non_snake_case)]
     fn FPDF_GetDefaultTTFMapEntry(&self, index: usize) -> *const FPDF_CharsetFontMap {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDF_GetDefaultTTFMapEntry(index)
     }
 
     #[inline]
     #[allow(non_snake_case)]
     fn FPDF_AddInstalledFont(&self, mapper: *mut c_void, face: &str, charset: c_int) {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDF_AddInstalledFont(mapper, face, charset)
     }
 
     #[inline]
     #[allow(non_snake_case)]
     fn FPDF_SetSystemFontInfo(&self, pFontInfo: *mut FPDF_SYSFONTINFO) {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDF_SetSystemFontInfo(pFontInfo)
     }
 
     #[inline]
     #[allow(non_snake_case)]
     fn FPDF_GetDefaultSystemFontInfo(&self) -> *mut FPDF_SYSFONTINFO {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDF_GetDefaultSystemFontInfo()
     }
 
     #[inline]
     #[allow(non_snake_case)]
     fn FPDF_FreeDefaultSystemFontInfo(&self, pFontInfo: *mut FPDF_SYSFONTINFO) {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDF_FreeDefaultSystemFontInfo(pFontInfo)
     }
 
@@ -2912,6 +3778,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         document: FPDF_DOCUMENT,
         bookmark: FPDF_BOOKMARK,
     ) -> FPDF_BOOKMARK {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFBookmark_GetFirstChild(document, bookmark)
     }
 
@@ -2922,6 +3791,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         document: FPDF_DOCUMENT,
         bookmark: FPDF_BOOKMARK,
     ) -> FPDF_BOOKMARK {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDFBookmark_GetNextSibling(document, bookmark)
     }
@@ -2934,6 +3806,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         buffer: *mut c_void,
         buflen: c_ulong,
     ) -> c_ulong {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDFBookmark_GetTitle(bookmark, buffer, buflen)
     }
@@ -2941,36 +3816,54 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
     #[inline]
     #[allow(non_snake_case)]
     fn FPDFBookmark_GetCount(&self, bookmark: FPDF_BOOKMARK) -> c_int {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFBookmark_GetCount(bookmark)
     }
 
     #[inline]
     #[allow(non_snake_case)]
     fn FPDFBookmark_Find(&self, document: FPDF_DOCUMENT, title: FPDF_WIDESTRING) -> FPDF_BOOKMARK {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFBookmark_Find(document, title)
     }
 
     #[inline]
     #[allow(non_snake_case)]
     fn FPDFBookmark_GetDest(&self, document: FPDF_DOCUMENT, bookmark: FPDF_BOOKMARK) -> FPDF_DEST {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFBookmark_GetDest(document, bookmark)
     }
 
     #[inline]
     #[allow(non_snake_case)]
     fn FPDFBookmark_GetAction(&self, bookmark: FPDF_BOOKMARK) -> FPDF_ACTION {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFBookmark_GetAction(bookmark)
     }
 
     #[inline]
     #[allow(non_snake_case)]
     fn FPDFAction_GetType(&self, action: FPDF_ACTION) -> c_ulong {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFAction_GetType(action)
     }
 
     #[inline]
     #[allow(non_snake_case)]
     fn FPDFAction_GetDest(&self, document: FPDF_DOCUMENT, action: FPDF_ACTION) -> FPDF_DEST {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFAction_GetDest(document, action)
     }
 
@@ -2982,6 +3875,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         buffer: *mut c_void,
         buflen: c_ulong,
     ) -> c_ulong {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFAction_GetFilePath(action, buffer, buflen)
     }
 
@@ -2994,6 +3890,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         buffer: *mut c_void,
         buflen: c_ulong,
     ) -> c_ulong {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDFAction_GetURIPath(document, action, buffer, buflen)
     }
@@ -3001,6 +3900,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
     #[inline]
     #[allow(non_snake_case)]
     fn FPDFDest_GetDestPageIndex(&self, document: FPDF_DOCUMENT, dest: FPDF_DEST) -> c_int {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFDest_GetDestPageIndex(document, dest)
     }
 
@@ -3012,6 +3914,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         pNumParams: *mut c_ulong,
         pParams: *mut FS_FLOAT,
     ) -> c_ulong {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFDest_GetView(dest, pNumParams, pParams)
     }
 
@@ -3027,6 +3932,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         y: *mut FS_FLOAT,
         zoom: *mut FS_FLOAT,
     ) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDFDest_GetLocationInPage(dest, hasXVal, hasYVal, hasZoomVal, x, y, zoom)
     }
@@ -3034,24 +3942,36 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
     #[inline]
     #[allow(non_snake_case)]
     fn FPDFLink_GetLinkAtPoint(&self, page: FPDF_PAGE, x: c_double, y: c_double) -> FPDF_LINK {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFLink_GetLinkAtPoint(page, x, y)
     }
 
     #[inline]
     #[allow(non_snake_case)]
     fn FPDFLink_GetLinkZOrderAtPoint(&self, page: FPDF_PAGE, x: c_double, y: c_double) -> c_int {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFLink_GetLinkZOrderAtPoint(page, x, y)
     }
 
     #[inline]
     #[allow(non_snake_case)]
     fn FPDFLink_GetDest(&self, document: FPDF_DOCUMENT, link: FPDF_LINK) -> FPDF_DEST {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFLink_GetDest(document, link)
     }
 
     #[inline]
     #[allow(non_snake_case)]
     fn FPDFLink_GetAction(&self, link: FPDF_LINK) -> FPDF_ACTION {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFLink_GetAction(link)
     }
 
@@ -3063,6 +3983,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         start_pos: *mut c_int,
         link_annot: *mut FPDF_LINK,
     ) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDFLink_Enumerate(page, start_pos, link_annot)
     }
@@ -3070,18 +3993,27 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
     #[inline]
     #[allow(non_snake_case)]
     fn FPDFLink_GetAnnot(&self, page: FPDF_PAGE, link_annot: FPDF_LINK) -> FPDF_ANNOTATION {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFLink_GetAnnot(page, link_annot)
     }
 
     #[inline]
     #[allow(non_snake_case)]
     fn FPDFLink_GetAnnotRect(&self, link_annot: FPDF_LINK, rect: *mut FS_RECTF) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFLink_GetAnnotRect(link_annot, rect)
     }
 
     #[inline]
     #[allow(non_snake_case)]
     fn FPDFLink_CountQuadPoints(&self, link_annot: FPDF_LINK) -> c_int {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFLink_CountQuadPoints(link_annot)
     }
 
@@ -3093,6 +4025,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         quad_index: c_int,
         quad_points: *mut FS_QUADPOINTSF,
     ) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDFLink_GetQuadPoints(link_annot, quad_index, quad_points)
     }
@@ -3100,6 +4035,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
     #[inline]
     #[allow(non_snake_case)]
     fn FPDF_GetPageAAction(&self, page: FPDF_PAGE, aa_type: c_int) -> FPDF_ACTION {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDF_GetPageAAction(page, aa_type)
     }
 
@@ -3112,6 +4050,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         buffer: *mut c_void,
         buflen: c_ulong,
     ) -> c_ulong {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDF_GetFileIdentifier(document, id_type, buffer, buflen)
     }
@@ -3125,6 +4066,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         buffer: *mut c_void,
         buflen: c_ulong,
     ) -> c_ulong {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDF_GetMetaText(document, tag, buffer, buflen)
     }
@@ -3138,6 +4082,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         buffer: *mut c_void,
         buflen: c_ulong,
     ) -> c_ulong {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDF_GetPageLabel(document, page_index, buffer, buflen)
     }
@@ -3146,6 +4093,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
     #[inline]
     #[allow(non_snake_case)]
     fn FPDF_GetXFAPacketCount(&self, document: FPDF_DOCUMENT) -> c_int {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDF_GetXFAPacketCount(document)
     }
 
@@ -3159,6 +4109,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         buffer: *mut c_void,
         buflen: c_ulong,
     ) -> c_ulong {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDF_GetXFAPacketName(document, index, buffer, buflen)
     }
@@ -3174,6 +4127,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         buflen: c_ulong,
         out_buflen: *mut c_ulong,
     ) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDF_GetXFAPacketContent(document, index, buffer, buflen, out_buflen)
     }
@@ -3182,6 +4138,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
     #[inline]
     #[allow(non_snake_case)]
     fn FPDF_GetRecommendedV8Flags(&self) -> *const c_char {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDF_GetRecommendedV8Flags()
     }
 
@@ -3189,6 +4148,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
     #[inline]
     #[allow(non_snake_case)]
     fn FPDF_GetArrayBufferAllocatorSharedInstance(&self) -> *mut c_void {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDF_GetArrayBufferAllocatorSharedInstance()
     }
 
@@ -3196,6 +4158,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
     #[inline]
     #[allow(non_snake_case)]
     fn FPDF_BStr_Init(&self, bstr: *mut FPDF_BSTR) -> FPDF_RESULT {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDF_BStr_Init(bstr)
     }
 
@@ -3208,6 +4173,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         cstr: *const c_char,
         length: c_int,
     ) -> FPDF_RESULT {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDF_BStr_Set(bstr, cstr, length)
     }
 
@@ -3215,30 +4183,45 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
     #[inline]
     #[allow(non_snake_case)]
     fn FPDF_BStr_Clear(&self, bstr: *mut FPDF_BSTR) -> FPDF_RESULT {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDF_BStr_Clear(bstr)
     }
 
     #[inline]
     #[allow(non_snake_case)]
     fn FPDFText_LoadPage(&self, page: FPDF_PAGE) -> FPDF_TEXTPAGE {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFText_LoadPage(page)
     }
 
     #[inline]
     #[allow(non_snake_case)]
     fn FPDFText_ClosePage(&self, text_page: FPDF_TEXTPAGE) {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFText_ClosePage(text_page)
     }
 
     #[inline]
     #[allow(non_snake_case)]
     fn FPDFText_CountChars(&self, text_page: FPDF_TEXTPAGE) -> c_int {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFText_CountChars(text_page)
     }
 
     #[inline]
     #[allow(non_snake_case)]
     fn FPDFText_GetUnicode(&self, text_page: FPDF_TEXTPAGE, index: c_int) -> c_uint {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFText_GetUnicode(text_page, index)
     }
 
@@ -3252,12 +4235,18 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
     #[inline]
     #[allow(non_snake_case)]
     fn FPDFText_GetTextObject(&self, text_page: FPDF_TEXTPAGE, index: c_int) -> FPDF_PAGEOBJECT {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFText_GetTextObject(text_page, index)
     }
 
     #[inline]
     #[allow(non_snake_case)]
     fn FPDFText_IsGenerated(&self, text_page: FPDF_TEXTPAGE, index: c_int) -> c_int {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFText_IsGenerated(text_page, index)
     }
 
@@ -3284,18 +4273,27 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
     #[inline]
     #[allow(non_snake_case)]
     fn FPDFText_IsHyphen(&self, text_page: FPDF_TEXTPAGE, index: c_int) -> c_int {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFText_IsHyphen(text_page, index)
     }
 
     #[inline]
     #[allow(non_snake_case)]
     fn FPDFText_HasUnicodeMapError(&self, text_page: FPDF_TEXTPAGE, index: c_int) -> c_int {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFText_HasUnicodeMapError(text_page, index)
     }
 
     #[inline]
     #[allow(non_snake_case)]
     fn FPDFText_GetFontSize(&self, text_page: FPDF_TEXTPAGE, index: c_int) -> c_double {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFText_GetFontSize(text_page, index)
     }
 
@@ -3309,6 +4307,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         buflen: c_ulong,
         flags: *mut c_int,
     ) -> c_ulong {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDFText_GetFontInfo(text_page, index, buffer, buflen, flags)
     }
@@ -3316,6 +4317,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
     #[inline]
     #[allow(non_snake_case)]
     fn FPDFText_GetFontWeight(&self, text_page: FPDF_TEXTPAGE, index: c_int) -> c_int {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFText_GetFontWeight(text_page, index)
     }
 
@@ -3342,6 +4346,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         text_page: FPDF_TEXTPAGE,
         index: c_int,
     ) -> FPDF_TEXT_RENDERMODE {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFText_GetTextRenderMode(text_page, index)
     }
 
@@ -3356,6 +4363,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         B: *mut c_uint,
         A: *mut c_uint,
     ) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDFText_GetFillColor(text_page, index, R, G, B, A)
     }
@@ -3371,6 +4381,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         B: *mut c_uint,
         A: *mut c_uint,
     ) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDFText_GetStrokeColor(text_page, index, R, G, B, A)
     }
@@ -3378,6 +4391,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
     #[inline]
     #[allow(non_snake_case)]
     fn FPDFText_GetCharAngle(&self, text_page: FPDF_TEXTPAGE, index: c_int) -> c_float {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFText_GetCharAngle(text_page, index)
     }
 
@@ -3392,6 +4408,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         bottom: *mut c_double,
         top: *mut c_double,
     ) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDFText_GetCharBox(text_page, index, left, right, bottom, top)
     }
@@ -3404,6 +4423,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         index: c_int,
         rect: *mut FS_RECTF,
     ) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDFText_GetLooseCharBox(text_page, index, rect)
     }
@@ -3416,6 +4438,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         index: c_int,
         matrix: *mut FS_MATRIX,
     ) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFText_GetMatrix(text_page, index, matrix)
     }
 
@@ -3428,6 +4453,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         x: *mut c_double,
         y: *mut c_double,
     ) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFText_GetCharOrigin(text_page, index, x, y)
     }
 
@@ -3441,6 +4469,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         xTolerance: c_double,
         yTolerance: c_double,
     ) -> c_int {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDFText_GetCharIndexAtPos(text_page, x, y, xTolerance, yTolerance)
     }
@@ -3454,6 +4485,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         count: c_int,
         result: *mut c_ushort,
     ) -> c_int {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDFText_GetText(text_page, start_index, count, result)
     }
@@ -3466,6 +4500,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         start_index: c_int,
         count: c_int,
     ) -> c_int {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDFText_CountRects(text_page, start_index, count)
     }
@@ -3481,6 +4518,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         right: *mut c_double,
         bottom: *mut c_double,
     ) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDFText_GetRect(text_page, rect_index, left, top, right, bottom)
     }
@@ -3497,6 +4537,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         buffer: *mut c_ushort,
         buflen: c_int,
     ) -> c_int {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDFText_GetBoundedText(text_page, left, top, right, bottom, buffer, buflen)
     }
@@ -3510,6 +4553,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         flags: c_ulong,
         start_index: c_int,
     ) -> FPDF_SCHHANDLE {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDFText_FindStart(text_page, findwhat, flags, start_index)
     }
@@ -3517,42 +4563,63 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
     #[inline]
     #[allow(non_snake_case)]
     fn FPDFText_FindNext(&self, handle: FPDF_SCHHANDLE) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFText_FindNext(handle)
     }
 
     #[inline]
     #[allow(non_snake_case)]
     fn FPDFText_FindPrev(&self, handle: FPDF_SCHHANDLE) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFText_FindPrev(handle)
     }
 
     #[inline]
     #[allow(non_snake_case)]
     fn FPDFText_GetSchResultIndex(&self, handle: FPDF_SCHHANDLE) -> c_int {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFText_GetSchResultIndex(handle)
     }
 
     #[inline]
     #[allow(non_snake_case)]
     fn FPDFText_GetSchCount(&self, handle: FPDF_SCHHANDLE) -> c_int {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFText_GetSchCount(handle)
     }
 
     #[inline]
     #[allow(non_snake_case)]
     fn FPDFText_FindClose(&self, handle: FPDF_SCHHANDLE) {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFText_FindClose(handle)
     }
 
     #[inline]
     #[allow(non_snake_case)]
     fn FPDFLink_LoadWebLinks(&self, text_page: FPDF_TEXTPAGE) -> FPDF_PAGELINK {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFLink_LoadWebLinks(text_page)
     }
 
     #[inline]
     #[allow(non_snake_case)]
     fn FPDFLink_CountWebLinks(&self, link_page: FPDF_PAGELINK) -> c_int {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFLink_CountWebLinks(link_page)
     }
 
@@ -3565,6 +4632,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         buffer: *mut c_ushort,
         buflen: c_int,
     ) -> c_int {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDFLink_GetURL(link_page, link_index, buffer, buflen)
     }
@@ -3572,6 +4642,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
     #[inline]
     #[allow(non_snake_case)]
     fn FPDFLink_CountRects(&self, link_page: FPDF_PAGELINK, link_index: c_int) -> c_int {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFLink_CountRects(link_page, link_index)
     }
 
@@ -3587,6 +4660,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         right: *mut c_double,
         bottom: *mut c_double,
     ) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDFLink_GetRect(link_page, link_index, rect_index, left, top, right, bottom)
     }
@@ -3600,6 +4676,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         start_char_index: *mut c_int,
         char_count: *mut c_int,
     ) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDFLink_GetTextRange(link_page, link_index, start_char_index, char_count)
     }
@@ -3607,6 +4686,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
     #[inline]
     #[allow(non_snake_case)]
     fn FPDFLink_CloseWebLinks(&self, link_page: FPDF_PAGELINK) {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFLink_CloseWebLinks(link_page)
     }
 
@@ -3618,6 +4700,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         buffer: *mut c_void,
         buflen: c_ulong,
     ) -> c_ulong {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDFPage_GetDecodedThumbnailData(page, buffer, buflen)
     }
@@ -3630,6 +4715,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         buffer: *mut c_void,
         buflen: c_ulong,
     ) -> c_ulong {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDFPage_GetRawThumbnailData(page, buffer, buflen)
     }
@@ -3637,12 +4725,18 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
     #[inline]
     #[allow(non_snake_case)]
     fn FPDFPage_GetThumbnailAsBitmap(&self, page: FPDF_PAGE) -> FPDF_BITMAP {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFPage_GetThumbnailAsBitmap(page)
     }
 
     #[inline]
     #[allow(non_snake_case)]
     fn FPDFFormObj_CountObjects(&self, form_object: FPDF_PAGEOBJECT) -> c_int {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFFormObj_CountObjects(form_object)
     }
 
@@ -3653,6 +4747,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         form_object: FPDF_PAGEOBJECT,
         index: c_ulong,
     ) -> FPDF_PAGEOBJECT {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFFormObj_GetObject(form_object, index)
     }
 
@@ -3664,6 +4761,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         font: FPDF_FONT,
         font_size: c_float,
     ) -> FPDF_PAGEOBJECT {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDFPageObj_CreateTextObj(document, font, font_size)
     }
@@ -3671,6 +4771,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
     #[inline]
     #[allow(non_snake_case)]
     fn FPDFTextObj_GetTextRenderMode(&self, text: FPDF_PAGEOBJECT) -> FPDF_TEXT_RENDERMODE {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFTextObj_GetTextRenderMode(text)
     }
 
@@ -3681,6 +4784,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         text: FPDF_PAGEOBJECT,
         render_mode: FPDF_TEXT_RENDERMODE,
     ) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDFTextObj_SetTextRenderMode(text, render_mode)
     }
@@ -3694,6 +4800,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         buffer: *mut FPDF_WCHAR,
         length: c_ulong,
     ) -> c_ulong {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDFTextObj_GetText(text_object, text_page, buffer, length)
     }
@@ -3707,6 +4816,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         text_object: FPDF_PAGEOBJECT,
         scale: f32,
     ) -> FPDF_BITMAP {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDFTextObj_GetRenderedBitmap(document, page, text_object, scale)
     }
@@ -3714,30 +4826,45 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
     #[inline]
     #[allow(non_snake_case)]
     fn FPDFTextObj_GetFont(&self, text: FPDF_PAGEOBJECT) -> FPDF_FONT {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFTextObj_GetFont(text)
     }
 
     #[inline]
     #[allow(non_snake_case)]
     fn FPDFTextObj_GetFontSize(&self, text: FPDF_PAGEOBJECT, size: *mut c_float) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFTextObj_GetFontSize(text, size)
     }
 
     #[inline]
     #[allow(non_snake_case)]
     fn FPDFFont_Close(&self, font: FPDF_FONT) {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFFont_Close(font)
     }
 
     #[inline]
     #[allow(non_snake_case)]
     fn FPDFPath_MoveTo(&self, path: FPDF_PAGEOBJECT, x: c_float, y: c_float) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFPath_MoveTo(path, x, y)
     }
 
     #[inline]
     #[allow(non_snake_case)]
     fn FPDFPath_LineTo(&self, path: FPDF_PAGEOBJECT, x: c_float, y: c_float) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFPath_LineTo(path, x, y)
     }
 
@@ -3753,6 +4880,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         x3: c_float,
         y3: c_float,
     ) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDFPath_BezierTo(path, x1, y1, x2, y2, x3, y3)
     }
@@ -3760,6 +4890,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
     #[inline]
     #[allow(non_snake_case)]
     fn FPDFPath_Close(&self, path: FPDF_PAGEOBJECT) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFPath_Close(path)
     }
 
@@ -3771,6 +4904,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         fillmode: c_int,
         stroke: FPDF_BOOL,
     ) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFPath_SetDrawMode(path, fillmode, stroke)
     }
 
@@ -3782,6 +4918,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         fillmode: *mut c_int,
         stroke: *mut FPDF_BOOL,
     ) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFPath_GetDrawMode(path, fillmode, stroke)
     }
 
@@ -3793,6 +4932,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         font: &str,
         font_size: c_float,
     ) -> FPDF_PAGEOBJECT {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDFPageObj_NewTextObj(document, font, font_size)
     }
@@ -3800,6 +4942,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
     #[inline]
     #[allow(non_snake_case)]
     fn FPDFText_SetText(&self, text_object: FPDF_PAGEOBJECT, text: FPDF_WIDESTRING) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFText_SetText(text_object, text)
     }
 
@@ -3811,6 +4956,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         charcodes: *const c_uint,
         count: size_t,
     ) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDFText_SetCharcodes(text_object, charcodes, count)
     }
@@ -3825,6 +4973,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         font_type: c_int,
         cid: FPDF_BOOL,
     ) -> FPDF_FONT {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDFText_LoadFont(document, data, size, font_type, cid)
     }
@@ -3832,6 +4983,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
     #[inline]
     #[allow(non_snake_case)]
     fn FPDFText_LoadStandardFont(&self, document: FPDF_DOCUMENT, font: &str) -> FPDF_FONT {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFText_LoadStandardFont(document, font)
     }
 
@@ -3859,6 +5013,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         cid_to_gid_map_data: *const u8,
         cid_to_gid_map_data_size: u32,
     ) -> FPDF_FONT {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFText_LoadCidType2Font(
             document,
             font_data,
@@ -3872,42 +5029,63 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
     #[inline]
     #[allow(non_snake_case)]
     fn FPDFPage_InsertObject(&self, page: FPDF_PAGE, page_obj: FPDF_PAGEOBJECT) {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFPage_InsertObject(page, page_obj)
     }
 
     #[inline]
     #[allow(non_snake_case)]
     fn FPDFPage_RemoveObject(&self, page: FPDF_PAGE, page_obj: FPDF_PAGEOBJECT) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFPage_RemoveObject(page, page_obj)
     }
 
     #[inline]
     #[allow(non_snake_case)]
     fn FPDFPage_CountObjects(&self, page: FPDF_PAGE) -> c_int {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFPage_CountObjects(page)
     }
 
     #[inline]
     #[allow(non_snake_case)]
     fn FPDFPage_GetObject(&self, page: FPDF_PAGE, index: c_int) -> FPDF_PAGEOBJECT {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFPage_GetObject(page, index)
     }
 
     #[inline]
     #[allow(non_snake_case)]
     fn FPDFPageObj_Destroy(&self, page_obj: FPDF_PAGEOBJECT) {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFPageObj_Destroy(page_obj)
     }
 
     #[inline]
     #[allow(non_snake_case)]
     fn FPDFPageObj_HasTransparency(&self, page_object: FPDF_PAGEOBJECT) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFPageObj_HasTransparency(page_object)
     }
 
     #[inline]
     #[allow(non_snake_case)]
     fn FPDFPageObj_GetType(&self, page_object: FPDF_PAGEOBJECT) -> c_int {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFPageObj_GetType(page_object)
     }
 
@@ -3919,6 +5097,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         page_object: FPDF_PAGEOBJECT,
         active: *mut FPDF_BOOL,
     ) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFPageObj_GetIsActive(page_object, active)
     }
 
@@ -3930,6 +5111,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         page_object: FPDF_PAGEOBJECT,
         active: FPDF_BOOL,
     ) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFPageObj_SetIsActive(page_object, active)
     }
 
@@ -3945,6 +5129,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         e: c_double,
         f: c_double,
     ) {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDFPageObj_Transform(page_object, a, b, c, d, e, f)
     }
@@ -3963,6 +5150,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         page_object: FPDF_PAGEOBJECT,
         matrix: *const FS_MATRIX,
     ) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFPageObj_TransformF(page_object, matrix)
     }
 
@@ -3973,18 +5163,27 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         page_object: FPDF_PAGEOBJECT,
         matrix: *mut FS_MATRIX,
     ) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFPageObj_GetMatrix(page_object, matrix)
     }
 
     #[inline]
     #[allow(non_snake_case)]
     fn FPDFPageObj_SetMatrix(&self, path: FPDF_PAGEOBJECT, matrix: *const FS_MATRIX) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFPageObj_SetMatrix(path, matrix)
     }
 
     #[inline]
     #[allow(non_snake_case)]
     fn FPDFPageObj_NewImageObj(&self, document: FPDF_DOCUMENT) -> FPDF_PAGEOBJECT {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFPageObj_NewImageObj(document)
     }
 
@@ -3998,12 +5197,18 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
     #[inline]
     #[allow(non_snake_case)]
     fn FPDFPageObj_GetMarkedContentID(&self, page_object: FPDF_PAGEOBJECT) -> c_int {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFPageObj_GetMarkedContentID(page_object)
     }
 
     #[inline]
     #[allow(non_snake_case)]
     fn FPDFPageObj_CountMarks(&self, page_object: FPDF_PAGEOBJECT) -> c_int {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFPageObj_CountMarks(page_object)
     }
 
@@ -4014,12 +5219,18 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         page_object: FPDF_PAGEOBJECT,
         index: c_ulong,
     ) -> FPDF_PAGEOBJECTMARK {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFPageObj_GetMark(page_object, index)
     }
 
     #[inline]
     #[allow(non_snake_case)]
     fn FPDFPageObj_AddMark(&self, page_object: FPDF_PAGEOBJECT, name: &str) -> FPDF_PAGEOBJECTMARK {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFPageObj_AddMark(page_object, name)
     }
 
@@ -4030,6 +5241,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         page_object: FPDF_PAGEOBJECT,
         mark: FPDF_PAGEOBJECTMARK,
     ) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFPageObj_RemoveMark(page_object, mark)
     }
 
@@ -4043,6 +5257,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         buflen: c_ulong,
         out_buflen: *mut c_ulong,
     ) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDFPageObjMark_GetName(mark, buffer, buflen, out_buflen)
     }
@@ -4075,6 +5292,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         buflen: c_ulong,
         out_buflen: *mut c_ulong,
     ) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDFPageObjMark_GetName(mark, buffer, buflen, out_buflen)
     }
@@ -4082,6 +5302,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
     #[inline]
     #[allow(non_snake_case)]
     fn FPDFPageObjMark_CountParams(&self, mark: FPDF_PAGEOBJECTMARK) -> c_int {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFPageObjMark_CountParams(mark)
     }
 
@@ -4096,6 +5319,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         buflen: c_ulong,
         out_buflen: *mut c_ulong,
     ) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDFPageObjMark_GetParamKey(mark, index, buffer, buflen, out_buflen)
     }
@@ -4129,6 +5355,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         buflen: c_ulong,
         out_buflen: *mut c_ulong,
     ) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDFPageObjMark_GetParamKey(mark, index, buffer, buflen, out_buflen)
     }
@@ -4140,6 +5369,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         mark: FPDF_PAGEOBJECTMARK,
         key: &str,
     ) -> FPDF_OBJECT_TYPE {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFPageObjMark_GetParamValueType(mark, key)
     }
 
@@ -4151,6 +5383,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         key: &str,
         out_value: *mut c_int,
     ) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDFPageObjMark_GetParamIntValue(mark, key, out_value)
     }
@@ -4166,6 +5401,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         buflen: c_ulong,
         out_buflen: *mut c_ulong,
     ) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDFPageObjMark_GetParamStringValue(mark, key, buffer, buflen, out_buflen)
     }
@@ -4199,6 +5437,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         buflen: c_ulong,
         out_buflen: *mut c_ulong,
     ) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDFPageObjMark_GetParamStringValue(mark, key, buffer, buflen, out_buflen)
     }
@@ -4214,6 +5455,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         buflen: c_ulong,
         out_buflen: *mut c_ulong,
     ) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDFPageObjMark_GetParamBlobValue(mark, key, buffer, buflen, out_buflen)
     }
@@ -4247,6 +5491,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         buflen: c_ulong,
         out_buflen: *mut c_ulong,
     ) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDFPageObjMark_GetParamBlobValue(mark, key, buffer, buflen, out_buflen)
     }
@@ -4261,6 +5508,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         key: &str,
         value: c_int,
     ) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDFPageObjMark_SetIntParam(document, page_object, mark, key, value)
     }
@@ -4275,6 +5525,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         key: &str,
         value: &str,
     ) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDFPageObjMark_SetStringParam(document, page_object, mark, key, value)
     }
@@ -4291,6 +5544,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         value: *const c_uchar,
         value_len: c_ulong,
     ) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFPageObjMark_SetBlobParam(
             document,
             page_object,
@@ -4331,6 +5587,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         value: *mut c_void,
         value_len: c_ulong,
     ) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFPageObjMark_SetBlobParam(
             document,
             page_object,
@@ -4349,6 +5608,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         mark: FPDF_PAGEOBJECTMARK,
         key: &str,
     ) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDFPageObjMark_RemoveParam(page_object, mark, key)
     }
@@ -4362,6 +5624,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         image_object: FPDF_PAGEOBJECT,
         file_access: *mut FPDF_FILEACCESS,
     ) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDFImageObj_LoadJpegFile(pages, count, image_object, file_access)
     }
@@ -4375,6 +5640,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         image_object: FPDF_PAGEOBJECT,
         file_access: *mut FPDF_FILEACCESS,
     ) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDFImageObj_LoadJpegFileInline(pages, count, image_object, file_access)
     }
@@ -4391,6 +5659,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         e: c_double,
         f: c_double,
     ) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         #[allow(deprecated)]
         self.bindings
             .FPDFImageObj_SetMatrix(image_object, a, b, c, d, e, f)
@@ -4405,6 +5676,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         image_object: FPDF_PAGEOBJECT,
         bitmap: FPDF_BITMAP,
     ) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDFImageObj_SetBitmap(pages, count, image_object, bitmap)
     }
@@ -4412,6 +5686,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
     #[inline]
     #[allow(non_snake_case)]
     fn FPDFImageObj_GetBitmap(&self, image_object: FPDF_PAGEOBJECT) -> FPDF_BITMAP {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFImageObj_GetBitmap(image_object)
     }
 
@@ -4423,6 +5700,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         page: FPDF_PAGE,
         image_object: FPDF_PAGEOBJECT,
     ) -> FPDF_BITMAP {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDFImageObj_GetRenderedBitmap(document, page, image_object)
     }
@@ -4435,6 +5715,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         buffer: *mut c_void,
         buflen: c_ulong,
     ) -> c_ulong {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDFImageObj_GetImageDataDecoded(image_object, buffer, buflen)
     }
@@ -4447,6 +5730,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         buffer: *mut c_void,
         buflen: c_ulong,
     ) -> c_ulong {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDFImageObj_GetImageDataRaw(image_object, buffer, buflen)
     }
@@ -4454,6 +5740,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
     #[inline]
     #[allow(non_snake_case)]
     fn FPDFImageObj_GetImageFilterCount(&self, image_object: FPDF_PAGEOBJECT) -> c_int {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFImageObj_GetImageFilterCount(image_object)
     }
 
@@ -4466,6 +5755,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         buffer: *mut c_void,
         buflen: c_ulong,
     ) -> c_ulong {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDFImageObj_GetImageFilter(image_object, index, buffer, buflen)
     }
@@ -4478,6 +5770,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         page: FPDF_PAGE,
         metadata: *mut FPDF_IMAGEOBJ_METADATA,
     ) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDFImageObj_GetImageMetadata(image_object, page, metadata)
     }
@@ -4490,6 +5785,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         width: *mut c_uint,
         height: *mut c_uint,
     ) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDFImageObj_GetImagePixelSize(image_object, width, height)
     }
@@ -4505,6 +5803,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         buflen: size_t,
         out_buflen: *mut size_t,
     ) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFImageObj_GetIccProfileDataDecoded(
             image_object,
             page,
@@ -4517,6 +5818,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
     #[inline]
     #[allow(non_snake_case)]
     fn FPDFPageObj_CreateNewPath(&self, x: c_float, y: c_float) -> FPDF_PAGEOBJECT {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFPageObj_CreateNewPath(x, y)
     }
 
@@ -4529,6 +5833,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         w: c_float,
         h: c_float,
     ) -> FPDF_PAGEOBJECT {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFPageObj_CreateNewRect(x, y, w, h)
     }
 
@@ -4542,6 +5849,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         right: *mut c_float,
         top: *mut c_float,
     ) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDFPageObj_GetBounds(page_object, left, bottom, right, top)
     }
@@ -4553,6 +5863,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         page_object: FPDF_PAGEOBJECT,
         quad_points: *mut FS_QUADPOINTSF,
     ) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDFPageObj_GetRotatedBounds(page_object, quad_points)
     }
@@ -4560,6 +5873,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
     #[inline]
     #[allow(non_snake_case)]
     fn FPDFPageObj_SetBlendMode(&self, page_object: FPDF_PAGEOBJECT, blend_mode: &str) {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDFPageObj_SetBlendMode(page_object, blend_mode)
     }
@@ -4574,6 +5890,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         B: c_uint,
         A: c_uint,
     ) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDFPageObj_SetStrokeColor(page_object, R, G, B, A)
     }
@@ -4588,6 +5907,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         B: *mut c_uint,
         A: *mut c_uint,
     ) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDFPageObj_GetStrokeColor(page_object, R, G, B, A)
     }
@@ -4599,6 +5921,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         page_object: FPDF_PAGEOBJECT,
         width: c_float,
     ) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFPageObj_SetStrokeWidth(page_object, width)
     }
 
@@ -4609,18 +5934,27 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         page_object: FPDF_PAGEOBJECT,
         width: *mut c_float,
     ) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFPageObj_GetStrokeWidth(page_object, width)
     }
 
     #[inline]
     #[allow(non_snake_case)]
     fn FPDFPageObj_GetLineJoin(&self, page_object: FPDF_PAGEOBJECT) -> c_int {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFPageObj_GetLineJoin(page_object)
     }
 
     #[inline]
     #[allow(non_snake_case)]
     fn FPDFPageObj_SetLineJoin(&self, page_object: FPDF_PAGEOBJECT, line_join: c_int) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDFPageObj_SetLineJoin(page_object, line_join)
     }
@@ -4628,12 +5962,18 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
     #[inline]
     #[allow(non_snake_case)]
     fn FPDFPageObj_GetLineCap(&self, page_object: FPDF_PAGEOBJECT) -> c_int {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFPageObj_GetLineCap(page_object)
     }
 
     #[inline]
     #[allow(non_snake_case)]
     fn FPDFPageObj_SetLineCap(&self, page_object: FPDF_PAGEOBJECT, line_cap: c_int) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFPageObj_SetLineCap(page_object, line_cap)
     }
 
@@ -4647,6 +5987,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         B: c_uint,
         A: c_uint,
     ) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDFPageObj_SetFillColor(page_object, R, G, B, A)
     }
@@ -4661,6 +6004,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         B: *mut c_uint,
         A: *mut c_uint,
     ) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDFPageObj_GetFillColor(page_object, R, G, B, A)
     }
@@ -4672,18 +6018,27 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         page_object: FPDF_PAGEOBJECT,
         phase: *mut c_float,
     ) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFPageObj_GetDashPhase(page_object, phase)
     }
 
     #[inline]
     #[allow(non_snake_case)]
     fn FPDFPageObj_SetDashPhase(&self, page_object: FPDF_PAGEOBJECT, phase: c_float) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFPageObj_SetDashPhase(page_object, phase)
     }
 
     #[inline]
     #[allow(non_snake_case)]
     fn FPDFPageObj_GetDashCount(&self, page_object: FPDF_PAGEOBJECT) -> c_int {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFPageObj_GetDashCount(page_object)
     }
 
@@ -4695,6 +6050,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         dash_array: *mut c_float,
         dash_count: size_t,
     ) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDFPageObj_GetDashArray(page_object, dash_array, dash_count)
     }
@@ -4708,6 +6066,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         dash_count: size_t,
         phase: c_float,
     ) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDFPageObj_SetDashArray(page_object, dash_array, dash_count, phase)
     }
@@ -4715,12 +6076,18 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
     #[inline]
     #[allow(non_snake_case)]
     fn FPDFPath_CountSegments(&self, path: FPDF_PAGEOBJECT) -> c_int {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFPath_CountSegments(path)
     }
 
     #[inline]
     #[allow(non_snake_case)]
     fn FPDFPath_GetPathSegment(&self, path: FPDF_PAGEOBJECT, index: c_int) -> FPDF_PATHSEGMENT {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFPath_GetPathSegment(path, index)
     }
 
@@ -4732,18 +6099,27 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         x: *mut c_float,
         y: *mut c_float,
     ) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFPathSegment_GetPoint(segment, x, y)
     }
 
     #[inline]
     #[allow(non_snake_case)]
     fn FPDFPathSegment_GetType(&self, segment: FPDF_PATHSEGMENT) -> c_int {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFPathSegment_GetType(segment)
     }
 
     #[inline]
     #[allow(non_snake_case)]
     fn FPDFPathSegment_GetClose(&self, segment: FPDF_PATHSEGMENT) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFPathSegment_GetClose(segment)
     }
 
@@ -4761,6 +6137,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         buffer: *mut c_char,
         length: usize,
     ) -> usize {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFFont_GetBaseFontName(font, buffer, length)
     }
 
@@ -4773,6 +6152,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
     #[inline]
     #[allow(non_snake_case)]
     fn FPDFFont_GetFamilyName(&self, font: FPDF_FONT, buffer: *mut c_char, length: usize) -> usize {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFFont_GetFamilyName(font, buffer, length)
     }
 
@@ -4785,6 +6167,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         buffer: *mut c_char,
         length: c_ulong,
     ) -> c_ulong {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFFont_GetFamilyName(font, buffer, length)
     }
 
@@ -4812,6 +6197,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         buffer: *mut c_char,
         length: c_ulong,
     ) -> c_ulong {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFFont_GetFontName(font, buffer, length)
     }
 
@@ -4824,6 +6212,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         buflen: usize,
         out_buflen: *mut usize,
     ) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDFFont_GetFontData(font, buffer, buflen, out_buflen)
     }
@@ -4831,24 +6222,36 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
     #[inline]
     #[allow(non_snake_case)]
     fn FPDFFont_GetIsEmbedded(&self, font: FPDF_FONT) -> c_int {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFFont_GetIsEmbedded(font)
     }
 
     #[inline]
     #[allow(non_snake_case)]
     fn FPDFFont_GetFlags(&self, font: FPDF_FONT) -> c_int {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFFont_GetFlags(font)
     }
 
     #[inline]
     #[allow(non_snake_case)]
     fn FPDFFont_GetWeight(&self, font: FPDF_FONT) -> c_int {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFFont_GetWeight(font)
     }
 
     #[inline]
     #[allow(non_snake_case)]
     fn FPDFFont_GetItalicAngle(&self, font: FPDF_FONT, angle: *mut c_int) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFFont_GetItalicAngle(font, angle)
     }
 
@@ -4860,6 +6263,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         font_size: c_float,
         ascent: *mut c_float,
     ) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFFont_GetAscent(font, font_size, ascent)
     }
 
@@ -4871,6 +6277,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         font_size: c_float,
         descent: *mut c_float,
     ) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFFont_GetDescent(font, font_size, descent)
     }
 
@@ -4883,6 +6292,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         font_size: c_float,
         width: *mut c_float,
     ) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDFFont_GetGlyphWidth(font, glyph, font_size, width)
     }
@@ -4895,12 +6307,18 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         glyph: c_uint,
         font_size: c_float,
     ) -> FPDF_GLYPHPATH {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFFont_GetGlyphPath(font, glyph, font_size)
     }
 
     #[inline]
     #[allow(non_snake_case)]
     fn FPDFGlyphPath_CountGlyphSegments(&self, glyphpath: FPDF_GLYPHPATH) -> c_int {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFGlyphPath_CountGlyphSegments(glyphpath)
     }
 
@@ -4911,6 +6329,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         glyphpath: FPDF_GLYPHPATH,
         index: c_int,
     ) -> FPDF_PATHSEGMENT {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDFGlyphPath_GetGlyphPathSegment(glyphpath, index)
     }
@@ -4918,24 +6339,36 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
     #[inline]
     #[allow(non_snake_case)]
     fn FPDF_VIEWERREF_GetPrintScaling(&self, document: FPDF_DOCUMENT) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDF_VIEWERREF_GetPrintScaling(document)
     }
 
     #[inline]
     #[allow(non_snake_case)]
     fn FPDF_VIEWERREF_GetNumCopies(&self, document: FPDF_DOCUMENT) -> c_int {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDF_VIEWERREF_GetNumCopies(document)
     }
 
     #[inline]
     #[allow(non_snake_case)]
     fn FPDF_VIEWERREF_GetPrintPageRange(&self, document: FPDF_DOCUMENT) -> FPDF_PAGERANGE {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDF_VIEWERREF_GetPrintPageRange(document)
     }
 
     #[inline]
     #[allow(non_snake_case)]
     fn FPDF_VIEWERREF_GetPrintPageRangeCount(&self, pagerange: FPDF_PAGERANGE) -> size_t {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDF_VIEWERREF_GetPrintPageRangeCount(pagerange)
     }
@@ -4947,6 +6380,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         pagerange: FPDF_PAGERANGE,
         index: size_t,
     ) -> c_int {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDF_VIEWERREF_GetPrintPageRangeElement(pagerange, index)
     }
@@ -4954,6 +6390,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
     #[inline]
     #[allow(non_snake_case)]
     fn FPDF_VIEWERREF_GetDuplex(&self, document: FPDF_DOCUMENT) -> FPDF_DUPLEXTYPE {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDF_VIEWERREF_GetDuplex(document)
     }
 
@@ -4966,6 +6405,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         buffer: *mut c_char,
         length: c_ulong,
     ) -> c_ulong {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDF_VIEWERREF_GetName(document, key, buffer, length)
     }
@@ -4973,12 +6415,18 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
     #[inline]
     #[allow(non_snake_case)]
     fn FPDF_CountNamedDests(&self, document: FPDF_DOCUMENT) -> FPDF_DWORD {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDF_CountNamedDests(document)
     }
 
     #[inline]
     #[allow(non_snake_case)]
     fn FPDF_GetNamedDestByName(&self, document: FPDF_DOCUMENT, name: &str) -> FPDF_DEST {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDF_GetNamedDestByName(document, name)
     }
 
@@ -4991,6 +6439,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         buffer: *mut c_void,
         buflen: *mut c_long,
     ) -> FPDF_DEST {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDF_GetNamedDest(document, index, buffer, buflen)
     }
@@ -4998,6 +6449,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
     #[inline]
     #[allow(non_snake_case)]
     fn FPDFDoc_GetAttachmentCount(&self, document: FPDF_DOCUMENT) -> c_int {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFDoc_GetAttachmentCount(document)
     }
 
@@ -5008,18 +6462,27 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         document: FPDF_DOCUMENT,
         name: FPDF_WIDESTRING,
     ) -> FPDF_ATTACHMENT {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFDoc_AddAttachment(document, name)
     }
 
     #[inline]
     #[allow(non_snake_case)]
     fn FPDFDoc_GetAttachment(&self, document: FPDF_DOCUMENT, index: c_int) -> FPDF_ATTACHMENT {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFDoc_GetAttachment(document, index)
     }
 
     #[inline]
     #[allow(non_snake_case)]
     fn FPDFDoc_DeleteAttachment(&self, document: FPDF_DOCUMENT, index: c_int) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFDoc_DeleteAttachment(document, index)
     }
 
@@ -5031,6 +6494,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         buffer: *mut FPDF_WCHAR,
         buflen: c_ulong,
     ) -> c_ulong {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDFAttachment_GetName(attachment, buffer, buflen)
     }
@@ -5038,6 +6504,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
     #[inline]
     #[allow(non_snake_case)]
     fn FPDFAttachment_HasKey(&self, attachment: FPDF_ATTACHMENT, key: &str) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFAttachment_HasKey(attachment, key)
     }
 
@@ -5048,6 +6517,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         attachment: FPDF_ATTACHMENT,
         key: &str,
     ) -> FPDF_OBJECT_TYPE {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFAttachment_GetValueType(attachment, key)
     }
 
@@ -5059,6 +6531,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         key: &str,
         value: FPDF_WIDESTRING,
     ) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDFAttachment_SetStringValue(attachment, key, value)
     }
@@ -5072,6 +6547,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         buffer: *mut FPDF_WCHAR,
         buflen: c_ulong,
     ) -> c_ulong {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDFAttachment_GetStringValue(attachment, key, buffer, buflen)
     }
@@ -5085,6 +6563,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         contents: *const c_void,
         len: c_ulong,
     ) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDFAttachment_SetFile(attachment, document, contents, len)
     }
@@ -5098,6 +6579,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
         buflen: c_ulong,
         out_buflen: *mut c_ulong,
     ) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings
             .FPDFAttachment_GetFile(attachment, buffer, buflen, out_buflen)
     }
@@ -5105,6 +6589,9 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
     #[inline]
     #[allow(non_snake_case)]
     fn FPDFCatalog_IsTagged(&self, document: FPDF_DOCUMENT) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFCatalog_IsTagged(document)
     }
 
@@ -5117,6 +6604,52 @@ impl<T: PdfiumLibraryBindings> PdfiumLibraryBindings for ThreadSafePdfiumBinding
     #[inline]
     #[allow(non_snake_case)]
     fn FPDFCatalog_SetLanguage(&self, document: FPDF_DOCUMENT, language: &str) -> FPDF_BOOL {
+        #[cfg(feature = "sync")]
+        let _call = lock_pdfium();
+
         self.bindings.FPDFCatalog_SetLanguage(document, language)
     }
 }
+
+#[cfg(test)]
+#[cfg(feature = "sync")]
+mod tests {
+    use super::lock_pdfium;
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::Arc;
+
+    #[test]
+    fn test_call_lock_is_reentrant() {
+        // Simulates Pdfium calling back into Rust code that itself calls Pdfium.
+
+        let outer = lock_pdfium();
+        let inner = lock_pdfium();
+
+        let acquired = Arc::new(AtomicBool::new(false));
+
+        let handle = {
+            let acquired = acquired.clone();
+
+            std::thread::spawn(move || {
+                let _call = lock_pdfium();
+
+                acquired.store(true, Ordering::SeqCst);
+            })
+        };
+
+        drop(inner);
+
+        std::thread::sleep(std::time::Duration::from_millis(50));
+
+        // The other thread must still be waiting, since this thread holds the lock
+        // at one level of reentrancy.
+
+        assert!(!acquired.load(Ordering::SeqCst));
+
+        drop(outer);
+
+        handle.join().unwrap();
+
+        assert!(acquired.load(Ordering::SeqCst));
+    }
+}
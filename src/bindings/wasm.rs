@@ -78,6 +78,8 @@ pub(crate) struct PdfiumRenderWasmState {
     debug: bool,
     file_access_callback_function_table_entry: usize,
     file_write_callback_function_table_entry: usize,
+    file_avail_callback_function_table_entry: usize,
+    download_hints_callback_function_table_entry: usize,
     state: HashMap<String, JsValue>,
 }
 
@@ -237,6 +239,9 @@ impl PdfiumRenderWasmState {
             })?.into();
 
         // Once we have the function table, we scan it for function signatures that take 4 arguments.
+        // We also look up two functions that take 3 arguments, to host the
+        // is_data_available_from_callback_wasm() and add_download_hint_from_callback_wasm()
+        // functions used when loading documents progressively.
 
         for index in 1..table.length() {
             if let Ok(function) = table.get(index) {
@@ -246,14 +251,20 @@ impl PdfiumRenderWasmState {
                     self.file_access_callback_function_table_entry =
                         self.file_write_callback_function_table_entry;
                     self.file_write_callback_function_table_entry = index as usize;
+                } else if function.length() == 3 {
+                    self.file_avail_callback_function_table_entry =
+                        self.download_hints_callback_function_table_entry;
+                    self.download_hints_callback_function_table_entry = index as usize;
                 }
             }
         }
 
         log::debug!(
-            "pdfium_render::PdfiumRenderWasmState::bind_to_pdfium(): found candidate patch function indices {}, {}",
+            "pdfium_render::PdfiumRenderWasmState::bind_to_pdfium(): found candidate patch function indices {}, {}, {}, {}",
             self.file_access_callback_function_table_entry,
             self.file_write_callback_function_table_entry,
+            self.file_avail_callback_function_table_entry,
+            self.download_hints_callback_function_table_entry,
         );
 
         self.wasm_table = Some(table);
@@ -852,6 +863,107 @@ impl PdfiumRenderWasmState {
         file_write_ptr
     }
 
+    /// Copies the given `FX_FILEAVAIL` struct into Pdfium's WASM heap, returning a pointer to
+    /// the copied struct at the destination location.
+    ///
+    /// Pdfium retains the pointer for the lifetime of the availability provider created from it,
+    /// so the copied struct must not be freed until the provider is destroyed.
+    fn copy_file_avail_to_pdfium(&mut self, file_avail: *mut FX_FILEAVAIL) -> usize {
+        // See comments in copy_file_access_to_pdfium() for an explanation of the approach used here.
+
+        let file_avail_with_callback = unsafe {
+            FX_FILEAVAIL {
+                version: (*file_avail).version,
+                IsDataAvail: Some(std::mem::transmute::<
+                    usize,
+                    unsafe extern "C" fn(
+                        pThis: *mut FX_FILEAVAIL,
+                        offset: usize,
+                        size: usize,
+                    ) -> FPDF_BOOL,
+                >(
+                    self.file_avail_callback_function_table_entry
+                )),
+            }
+        };
+
+        let file_avail_ptr = self.copy_struct_to_pdfium(&file_avail_with_callback);
+
+        // The callback function will receive a pointer to the _copied_ struct in Pdfium's
+        // memory heap. We save the memory location of the original so that our callback
+        // can look it up as required.
+
+        self.set(
+            format!("file_avail_{}", file_avail_ptr).as_str(),
+            JsValue::from_f64(file_avail as usize as f64),
+        );
+
+        file_avail_ptr
+    }
+
+    /// Copies the given `FX_DOWNLOADHINTS` struct into Pdfium's WASM heap, returning a pointer to
+    /// the copied struct at the destination location. The copied struct should be released
+    /// using [PdfiumRenderWasmState::release_download_hints()] once Pdfium has finished with it.
+    fn copy_download_hints_to_pdfium(&mut self, hints: *mut FX_DOWNLOADHINTS) -> usize {
+        // See comments in copy_file_access_to_pdfium() for an explanation of the approach used here.
+
+        let hints_with_callback = unsafe {
+            FX_DOWNLOADHINTS {
+                version: (*hints).version,
+                AddSegment: Some(std::mem::transmute::<
+                    usize,
+                    unsafe extern "C" fn(pThis: *mut FX_DOWNLOADHINTS, offset: usize, size: usize),
+                >(
+                    self.download_hints_callback_function_table_entry
+                )),
+            }
+        };
+
+        let hints_ptr = self.copy_struct_to_pdfium(&hints_with_callback);
+
+        self.set(
+            format!("download_hints_{}", hints_ptr).as_str(),
+            JsValue::from_f64(hints as usize as f64),
+        );
+
+        hints_ptr
+    }
+
+    /// Frees an `FX_DOWNLOADHINTS` struct previously copied into Pdfium's WASM heap using
+    /// [PdfiumRenderWasmState::copy_download_hints_to_pdfium()].
+    fn release_download_hints(&mut self, hints_ptr: usize) {
+        self.take(format!("download_hints_{}", hints_ptr).as_str());
+
+        self.free(hints_ptr);
+    }
+
+    /// Patches the function table in Pdfium's WASM module so that the callbacks used when loading
+    /// documents progressively can be invoked by Pdfium. Unlike the callback used when saving,
+    /// these patches are never undone, since Pdfium may read document data at any time
+    /// during the lifetime of a progressively loaded document.
+    fn patch_pdfium_function_table_for_availability(&mut self) -> Result<(), PdfiumError> {
+        for (entry, local_function_name) in [
+            (
+                self.file_access_callback_function_table_entry,
+                "read_block_from_callback_wasm",
+            ),
+            (
+                self.file_avail_callback_function_table_entry,
+                "is_data_available_from_callback_wasm",
+            ),
+            (
+                self.download_hints_callback_function_table_entry,
+                "add_download_hint_from_callback_wasm",
+            ),
+        ] {
+            if self.get(format!("function_{}", entry).as_str()).is_none() {
+                self.patch_pdfium_function_table(entry, local_function_name)?;
+            }
+        }
+
+        Ok(())
+    }
+
     /// Patches the function table in Pdfium's WASM module, replacing the pdfium_function_index entry
     /// with the local_function_index entry. This enables Pdfium to invoke a callback inside our local
     /// WASM module's function table. This is necessary to enable certain file handling functionality.
@@ -1007,6 +1119,8 @@ impl Default for PdfiumRenderWasmState {
             debug: false,
             file_access_callback_function_table_entry: 0, // These sentinel values will be replaced with actual values...
             file_write_callback_function_table_entry: 0, // ... during the first call to PdfiumRenderWasmState::bind_to_pdfium().
+            file_avail_callback_function_table_entry: 0,
+            download_hints_callback_function_table_entry: 0,
             state: HashMap::new(),
         }
     }
@@ -1174,6 +1288,79 @@ pub fn write_block_from_callback_wasm(
     }
 }
 
+/// A callback function that can be invoked by Pdfium's `FPDFAvail_Is*Avail()` family of
+/// functions, passing control to the `IsDataAvail` callback of the original `FX_FILEAVAIL`
+/// struct in our local WASM memory heap.
+#[wasm_bindgen]
+pub fn is_data_available_from_callback_wasm(
+    this: *mut c_void,
+    offset: usize,
+    size: usize,
+) -> FPDF_BOOL {
+    log::debug!(
+        "pdfium-render::is_data_available_from_callback_wasm(): entering with this = {:?}, offset = {:?}, size = {:?}",
+        this,
+        offset,
+        size
+    );
+
+    // Look up the memory location of the original FX_FILEAVAIL struct in our local memory heap.
+
+    let ptr = PdfiumRenderWasmState::lock()
+        .get(format!("file_avail_{}", this as usize).as_str())
+        .and_then(|value| value.as_f64());
+
+    match ptr {
+        Some(ptr) => {
+            let file_avail = ptr as usize as *mut FX_FILEAVAIL;
+
+            match unsafe { (*file_avail).IsDataAvail } {
+                Some(callback) => unsafe { callback(file_avail, offset, size) },
+                None => 0,
+            }
+        }
+        None => {
+            log::error!(
+                "pdfium-render::is_data_available_from_callback_wasm(): cannot retrieve callback pointer for Pdfium WASM heap address {}",
+                this as usize
+            );
+
+            0
+        }
+    }
+}
+
+/// A callback function that can be invoked by Pdfium's `FPDFAvail_Is*Avail()` family of
+/// functions, passing control to the `AddSegment` callback of the original `FX_DOWNLOADHINTS`
+/// struct in our local WASM memory heap.
+#[wasm_bindgen]
+pub fn add_download_hint_from_callback_wasm(this: *mut c_void, offset: usize, size: usize) {
+    log::debug!(
+        "pdfium-render::add_download_hint_from_callback_wasm(): entering with this = {:?}, offset = {:?}, size = {:?}",
+        this,
+        offset,
+        size
+    );
+
+    let ptr = PdfiumRenderWasmState::lock()
+        .get(format!("download_hints_{}", this as usize).as_str())
+        .and_then(|value| value.as_f64());
+
+    match ptr {
+        Some(ptr) => {
+            let hints = ptr as usize as *mut FX_DOWNLOADHINTS;
+
+            if let Some(callback) = unsafe { (*hints).AddSegment } {
+                unsafe { callback(hints, offset, size) }
+            }
+        }
+        None => log::error!(
+            "pdfium-render::add_download_hint_from_callback_wasm(): cannot retrieve callback pointer for Pdfium WASM heap address {}",
+            this as usize
+        ),
+    }
+}
+
 pub(crate) struct WasmPdfiumBindings {}
 
 impl WasmPdfiumBindings {
@@ -1562,6 +1749,14 @@ impl Default for WasmPdfiumBindings {
 }
 
 impl PdfiumLibraryBindings for WasmPdfiumBindings {
+    fn is_function_available(&self, function: &str) -> bool {
+        // Emscripten exports each Pdfium function with a leading underscore.
+
+        PdfiumRenderWasmState::lock()
+            .get_value_from_pdfium_wasm_module(&format!("_{}", function))
+            .is_ok()
+    }
+
     #[allow(non_snake_case)]
     fn FPDF_InitLibraryWithConfig(&self, config: *const FPDF_LIBRARY_CONFIG) {
         log::debug!("pdfium-render::PdfiumLibraryBindings::FPDF_InitLibraryWithConfig()");
@@ -1877,17 +2072,25 @@ impl PdfiumLibraryBindings for WasmPdfiumBindings {
     ) -> FPDF_AVAIL {
         log::debug!("pdfium-render::PdfiumLibraryBindings::FPDFAvail_Create()");
 
-        let state = PdfiumRenderWasmState::lock();
-
-        let out_file_avail_length = size_of::<FX_FILEAVAIL>();
+        let (out_file_avail_ptr, out_file_ptr) = {
+            let mut state = PdfiumRenderWasmState::lock_mut();
 
-        let out_file_avail_ptr = state.copy_struct_to_pdfium(file_avail);
+            if let Err(err) = state.patch_pdfium_function_table_for_availability() {
+                log::error!(
+                    "pdfium-render::PdfiumLibraryBindings::FPDFAvail_Create(): unable to patch Pdfium function table: {:#?}",
+                    err
+                );
 
-        let out_file_length = size_of::<FPDF_FILEACCESS>();
+                return std::ptr::null_mut();
+            }
 
-        let out_file_ptr = state.copy_struct_to_pdfium(file);
+            (
+                state.copy_file_avail_to_pdfium(file_avail),
+                state.copy_file_access_to_pdfium(file),
+            )
+        };
 
-        let result = state
+        let result = PdfiumRenderWasmState::lock()
             .call(
                 "FPDFAvail_Create",
                 JsFunctionArgumentType::Number,
@@ -1903,11 +2106,24 @@ impl PdfiumLibraryBindings for WasmPdfiumBindings {
             .as_f64()
             .unwrap() as usize as FPDF_AVAIL;
 
-        state.copy_struct_from_pdfium(out_file_avail_ptr, out_file_avail_length, file_avail);
-        state.copy_struct_from_pdfium(out_file_ptr, out_file_length, file);
+        // Pdfium retains both pointers for the lifetime of the availability provider,
+        // so the copied structs are freed when the provider is destroyed.
+
+        let mut state = PdfiumRenderWasmState::lock_mut();
 
-        state.free(out_file_avail_ptr);
-        state.free(out_file_ptr);
+        if result.is_null() {
+            state.take(format!("file_avail_{}", out_file_avail_ptr).as_str());
+            state.free(out_file_avail_ptr);
+            state.free(out_file_ptr);
+        } else {
+            state.set(
+                format!("avail_{}", result as usize).as_str(),
+                JsValue::from(Array::of2(
+                    &JsValue::from_f64(out_file_avail_ptr as f64),
+                    &JsValue::from_f64(out_file_ptr as f64),
+                )),
+            );
+        }
 
         result
     }
@@ -1925,6 +2141,23 @@ impl PdfiumLibraryBindings for WasmPdfiumBindings {
                 avail,
             )))),
         );
+
+        // Free the structs copied into Pdfium's WASM heap by FPDFAvail_Create().
+
+        let mut state = PdfiumRenderWasmState::lock_mut();
+
+        if let Some(ptrs) = state.take(format!("avail_{}", avail as usize).as_str()) {
+            let ptrs = Array::from(&ptrs);
+
+            if let Some(file_avail_ptr) = ptrs.get(0).as_f64() {
+                state.take(format!("file_avail_{}", file_avail_ptr as usize).as_str());
+                state.free(file_avail_ptr as usize);
+            }
+
+            if let Some(file_ptr) = ptrs.get(1).as_f64() {
+                state.free(file_ptr as usize);
+            }
+        }
     }
 
     #[inline]
@@ -1932,13 +2165,9 @@ impl PdfiumLibraryBindings for WasmPdfiumBindings {
     fn FPDFAvail_IsDocAvail(&self, avail: FPDF_AVAIL, hints: *mut FX_DOWNLOADHINTS) -> c_int {
         log::debug!("pdfium-render::PdfiumLibraryBindings::FPDFAvail_IsDocAvail()");
 
-        let state = PdfiumRenderWasmState::lock();
+        let out_hints_ptr = PdfiumRenderWasmState::lock_mut().copy_download_hints_to_pdfium(hints);
 
-        let out_hints_length = size_of::<FX_DOWNLOADHINTS>();
-
-        let out_hints_ptr = state.copy_struct_to_pdfium(hints);
-
-        let result = state
+        let result = PdfiumRenderWasmState::lock()
             .call(
                 "FPDFAvail_IsDocAvail",
                 JsFunctionArgumentType::Number,
@@ -1954,9 +2183,7 @@ impl PdfiumLibraryBindings for WasmPdfiumBindings {
             .as_f64()
             .unwrap() as c_int;
 
-        state.copy_struct_from_pdfium(out_hints_ptr, out_hints_length, hints);
-
-        state.free(out_hints_ptr);
+        PdfiumRenderWasmState::lock_mut().release_download_hints(out_hints_ptr);
 
         result
     }
@@ -2020,13 +2247,9 @@ impl PdfiumLibraryBindings for WasmPdfiumBindings {
     ) -> c_int {
         log::debug!("pdfium-render::PdfiumLibraryBindings::FPDFAvail_IsPageAvail()");
 
-        let state = PdfiumRenderWasmState::lock();
-
-        let out_hints_length = size_of::<FX_DOWNLOADHINTS>();
+        let out_hints_ptr = PdfiumRenderWasmState::lock_mut().copy_download_hints_to_pdfium(hints);
 
-        let out_hints_ptr = state.copy_struct_to_pdfium(hints);
-
-        let result = state
+        let result = PdfiumRenderWasmState::lock()
             .call(
                 "FPDFAvail_IsPageAvail",
                 JsFunctionArgumentType::Number,
@@ -2044,9 +2267,7 @@ impl PdfiumLibraryBindings for WasmPdfiumBindings {
             .as_f64()
             .unwrap() as c_int;
 
-        state.copy_struct_from_pdfium(out_hints_ptr, out_hints_length, hints);
-
-        state.free(out_hints_ptr);
+        PdfiumRenderWasmState::lock_mut().release_download_hints(out_hints_ptr);
 
         result
     }
@@ -2056,13 +2277,9 @@ impl PdfiumLibraryBindings for WasmPdfiumBindings {
     fn FPDFAvail_IsFormAvail(&self, avail: FPDF_AVAIL, hints: *mut FX_DOWNLOADHINTS) -> c_int {
         log::debug!("pdfium-render::PdfiumLibraryBindings::FPDFAvail_IsFormAvail()");
 
-        let state = PdfiumRenderWasmState::lock();
-
-        let out_hints_length = size_of::<FX_DOWNLOADHINTS>();
-
-        let out_hints_ptr = state.copy_struct_to_pdfium(hints);
+        let out_hints_ptr = PdfiumRenderWasmState::lock_mut().copy_download_hints_to_pdfium(hints);
 
-        let result = state
+        let result = PdfiumRenderWasmState::lock()
             .call(
                 "FPDFAvail_IsFormAvail",
                 JsFunctionArgumentType::Number,
@@ -2078,9 +2295,7 @@ impl PdfiumLibraryBindings for WasmPdfiumBindings {
             .as_f64()
             .unwrap() as c_int;
 
-        state.copy_struct_from_pdfium(out_hints_ptr, out_hints_length, hints);
-
-        state.free(out_hints_ptr);
+        PdfiumRenderWasmState::lock_mut().release_download_hints(out_hints_ptr);
 
         result
     }
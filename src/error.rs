@@ -62,6 +62,7 @@ pub enum PdfiumError {
     UnrecognizedPath,
     PageIndexOutOfBounds,
     LinkIndexOutOfBounds,
+    WebLinkIndexOutOfBounds,
     UnknownBitmapFormat,
     UnknownBitmapRotation,
     UnknownFormType,
@@ -69,6 +70,7 @@ pub enum PdfiumError {
     UnknownActionType,
     UnknownAppearanceMode,
     PageObjectIndexOutOfBounds,
+    PageObjectMarkIndexOutOfBounds,
     PageAnnotationIndexOutOfBounds,
     OwnershipNotAttachedToDocument,
     OwnershipNotAttachedToPage,
@@ -76,6 +78,9 @@ pub enum PdfiumError {
     OwnershipNotAttachedToAnnotation,
     FormFieldOptionIndexOutOfBounds,
     FormFieldAppearanceStreamUndefined,
+    FormFieldTypeDoesNotSupportOperation,
+    InkStrokeTooFewPoints,
+    AnnotationObjectsCannotBeReordered,
     PageFlattenFailure,
     PageMissingEmbeddedThumbnail,
     UnknownPdfPageObjectType,
@@ -87,18 +92,28 @@ pub enum PdfiumError {
     UnknownPdfSignatureModificationDetectionPermissionLevel,
     UnsupportedPdfPageObjectType,
     TextSegmentIndexOutOfBounds,
+    TextSearchTargetIsEmpty,
+    InvalidPdfDate,
+    InvalidPdfDocumentMetadataValue,
+    DocumentMetadataUpdateNotSupported,
+    IncrementalSaveRequiresLoadedDocument,
+    IncrementalSaveIncompatibleWithRemoveSecurity,
+    DocumentDataNotYetAvailable,
     CharIndexOutOfBounds,
     NoCharsInPageObject,
     NoCharsInAnnotation,
     NoCharsInRect,
     ImageObjectFilterIndexOutOfBounds,
+    ClipPathIndexOutOfBounds,
     ImageObjectFilterIndexInBoundsButFilterUndefined,
     UnknownPdfColorSpace,
     InvalidTransformationMatrix,
     SignatureIndexOutOfBounds,
+    StructElementIndexOutOfBounds,
     AttachmentIndexOutOfBounds,
     NoDataInAttachment,
     FontGlyphIndexOutOfBounds,
+    SystemFontNotFound,
     UnknownPathSegmentType,
     NoPagesInDocument,
     NoPageObjectsInCollection,
@@ -109,6 +124,7 @@ pub enum PdfiumError {
     PathObjectBezierControlPointsNotCopyable,
     PathObjectUnknownSegmentTypeNotCopyable,
     GroupContainsNonCopyablePageObjects,
+    PageContainsNonCopyablePageObjects,
     SourcePageIndexNotInCache,
     NoUriForAction,
     DestinationPageIndexNotAvailable,
@@ -142,6 +158,124 @@ pub enum PdfiumError {
     /// The setting cannot be returned because this `PdfPageGroupObject` is empty.
     EmptyPageObjectGroup,
 
+    /// The blend mode of a page object could not be determined. Pdfium does not provide
+    /// any way to read the blend mode of a page object directly; `pdfium-render` can only
+    /// determine the blend mode of a page object that contains no transparency.
+    BlendModeNotAvailable,
+
+    /// The data given to `PdfPageImageObject::set_image_from_jpeg_reader()` or
+    /// `PdfPageImageObject::set_image_from_jpeg_bytes()` does not begin with a JPEG
+    /// start-of-image marker, and so cannot be JPEG image data.
+    InvalidJpegImageData,
+
+    /// The target rectangle, size, or resolution given to `PdfPageImageObject::scale_to_fit()`,
+    /// `PdfPageImageObject::scale_to_physical_size()`, or `PdfPageImageObject::place_at_dpi()`
+    /// was empty, negative, or not finite.
+    InvalidImagePlacement,
+
+    /// A transparent background was requested by calling
+    /// `PdfRenderConfig::use_transparent_background()`, but the destination bitmap uses
+    /// a pixel format without an alpha channel. Only `PdfBitmapFormat::BGRA` bitmaps
+    /// can hold a transparent background.
+    TransparentBackgroundRequiresAlphaChannel,
+
+    /// Pdfium's progressive rendering functions do not support custom transformation matrices,
+    /// clipping, or sub-region rendering. Use `PdfPage::render_with_config()` instead, or
+    /// enable rendering of form data, which disregards any custom transformation.
+    ProgressiveRenderingDoesNotSupportTransformations,
+
+    /// Pdfium's color scheme rendering functions do not support custom transformation matrices,
+    /// clipping, or sub-region rendering. Remove the color scheme from the `PdfRenderConfig`,
+    /// or enable rendering of form data, which disregards any custom transformation.
+    RenderColorSchemeDoesNotSupportTransformations,
+
+    /// The requested operation cannot be performed, because the Pdfium library being used
+    /// does not provide any public function capable of performing it.
+    UnsupportedOperation,
+
+    /// A worker thread started by `Pdfium::render_pages_parallel()` terminated before
+    /// returning the page it was rendering, most likely because it panicked, or every worker
+    /// thread exited early because none of them could bind to Pdfium or open the document.
+    ParallelRenderWorkerTerminated,
+
+    /// `Pdfium::render_pages_parallel()` was called on a thread holding a live `Pdfium`
+    /// instance. Worker threads would wait forever to initialize their own instances,
+    /// so the instance must be dropped before rendering pages in parallel.
+    ParallelRenderPdfiumInUse,
+
+    /// A `PdfPageAnnotationsCursor` or `PdfPageObjectsCursor` was asked to delete the item at
+    /// its current position, but the cursor has not yet returned an item, or the item it
+    /// returned most recently has already been deleted.
+    CursorNotPositionedOnItem,
+
+    /// Encryption was requested using `PdfSaveOptions::encrypt()`, but the document data
+    /// saved by Pdfium could not be parsed, or uses a structure that cannot be encrypted,
+    /// such as a cross-reference stream or object stream compressed with an unsupported filter.
+    DocumentEncryptionNotSupported,
+
+    /// Encryption was requested using `PdfSaveOptions::encrypt()` in combination with an
+    /// incremental save. Encryption applies to every object in the document, so it cannot
+    /// be added by an incremental update.
+    IncrementalSaveIncompatibleWithEncryption,
+
+    /// Encryption was requested using `PdfSaveOptions::encrypt()`, but the random data
+    /// needed to generate encryption keys could not be obtained from the operating system.
+    EncryptionRandomSourceUnavailable,
+
+    /// The index given to `PdfJavaScriptActions::get()` is not a valid index
+    /// in the collection.
+    JavaScriptActionIndexOutOfBounds,
+
+    /// The index given to `PdfLayers::get()` is not a valid index in the collection.
+    LayerIndexOutOfBounds,
+
+    /// The optional content groups in a document could not be read, either because
+    /// the document is encrypted or because the document data saved by Pdfium could
+    /// not be parsed.
+    DocumentLayersNotReadable,
+
+    /// Layer visibility overrides were set using `PdfRenderConfig::set_layer_visibility()`,
+    /// but a page object belongs to optional content whose visibility is determined by
+    /// an optional content membership dictionary rather than by a single named layer.
+    /// Membership dictionaries are not yet supported.
+    LayerMembershipNotSupported,
+
+    /// Pdfium's device context rendering function does not support custom transformation
+    /// matrices, clipping, or sub-region rendering. Remove these from the `PdfRenderConfig`,
+    /// or enable rendering of form data, which disregards any custom transformation.
+    DeviceContextRenderingDoesNotSupportTransformations,
+
+    /// The SVG path data given to `PdfPathOutline::from_svg_path_data()` could not be parsed.
+    /// The byte offset of the problem within the path data is given.
+    SvgPathDataSyntaxError(usize),
+
+    /// The natural language declared in a document catalog could not be read, either because
+    /// the document is encrypted or because the document data saved by Pdfium could
+    /// not be parsed.
+    DocumentLanguageNotReadable,
+
+    /// The pixel format of a target `PdfBitmap` does not match the pixel format of the
+    /// source `PdfBitmap` or `PdfRenderConfig` whose pixels are to be drawn into it.
+    BitmapFormatMismatch,
+
+    /// A region given to a `PdfBitmap` or `PdfPage` function does not lie entirely within
+    /// the bounds of the target `PdfBitmap`.
+    BitmapRegionOutOfBounds,
+
+    /// A miter limit less than 1.0 was given to `PdfPageObjectCommon::set_miter_limit()`.
+    InvalidMiterLimit,
+
+    /// The miter limit of a page object could not be determined. Pdfium does not provide
+    /// any way to read the miter limit of a page object directly; `pdfium-render` can only
+    /// determine the miter limit of a page object whose miter limit was set using
+    /// `PdfPageObjectCommon::set_miter_limit()`.
+    MiterLimitNotAvailable,
+
+    /// The bookmarks created or changed using `PdfBookmarks` and `PdfBookmark` could not be
+    /// written when saving a document, because the outline in the data saved by Pdfium
+    /// could not be read or did not match the bookmarks reported by Pdfium.
+    DocumentOutlineUpdateNotSupported,
+
     /// A call to a internal Pdfium `FPDF_*` function returned a value indicating failure.
     ///
     /// For Pdfium functions that return enumerations, this means the function returned
@@ -160,6 +294,10 @@ pub enum PdfiumError {
     /// The browser's built-in `Window` object could not be retrieved.
     WebSysWindowObjectNotAvailable,
 
+    /// A 2D rendering context could not be retrieved from an HTML `<canvas>` element
+    /// or an `OffscreenCanvas`.
+    WebSysCanvasContextNotAvailable,
+
     #[cfg(target_arch = "wasm32")]
     /// A JsValue returned from a function call was set to JsValue::UNDEFINED instead of
     /// a valid value of the expected type.
@@ -173,6 +311,15 @@ pub enum PdfiumError {
     /// An invalid Response object was returned when attempting to use the browser's built-in `fetch()` API.
     WebSysInvalidResponseError,
 
+    #[cfg(target_arch = "wasm32")]
+    /// The server returned an unsuccessful HTTP status code when attempting to download
+    /// document data using the browser's built-in `fetch()` API. The status code is given.
+    WebSysFetchStatusError(u16),
+
+    #[cfg(target_arch = "wasm32")]
+    /// An error was returned when attempting to draw rendered pixel data onto a canvas.
+    WebSysCanvasError(JsValue),
+
     #[cfg(target_arch = "wasm32")]
     /// An error was returned when attempting to construct a `Blob` object from a byte buffer.
     JsSysErrorConstructingBlobFromBytes,
@@ -210,17 +357,151 @@ pub enum PdfiumError {
     /// `u16` size allowed by `pdfium-render`.
     ImageSizeOutOfBounds,
 
+    /// A user-friendly page range string, such as `"1,3,5-7"`, could not be parsed, or referred
+    /// to pages that do not exist. The contained message describes the problem.
+    InvalidPageRange(String),
+
     /// An I/O error occurred during a Pdfium file operation.
     IoError(std::io::Error),
 
     /// A wrapped internal library error from Pdfium's `FPDF_ERR_*` constant values.
     PdfiumLibraryInternalError(PdfiumInternalError),
+
+    /// A wrapped [PdfiumError], annotated with a [PdfiumErrorContext] describing the
+    /// Pdfium function call that failed. Use the [PdfiumError::without_context()] function
+    /// to retrieve the wrapped error.
+    WithContext(Box<PdfiumError>, PdfiumErrorContext),
+}
+
+impl PdfiumError {
+    /// Returns the [PdfiumErrorContext] describing the Pdfium function call that caused
+    /// this [PdfiumError], if available.
+    #[inline]
+    pub fn context(&self) -> Option<PdfiumErrorContext> {
+        match self {
+            PdfiumError::WithContext(_, context) => Some(context.clone()),
+            _ => None,
+        }
+    }
+
+    /// Returns this [PdfiumError] with any [PdfiumErrorContext] removed. This is useful
+    /// when matching against specific error values.
+    #[inline]
+    pub fn without_context(&self) -> &PdfiumError {
+        match self {
+            PdfiumError::WithContext(error, _) => error.without_context(),
+            _ => self,
+        }
+    }
+
+    /// Attaches the given human-readable note, such as "while setting fill color on text object",
+    /// to the [PdfiumErrorContext] of this [PdfiumError]. Errors without a [PdfiumErrorContext]
+    /// are returned unchanged.
+    pub fn with_note(self, note: impl Into<String>) -> Self {
+        match self {
+            PdfiumError::WithContext(error, context) => {
+                PdfiumError::WithContext(error, context.with_note(note))
+            }
+            _ => self,
+        }
+    }
+
+    /// Annotates this [PdfiumError] with the given [PdfiumErrorContext], replacing
+    /// any existing context.
+    #[inline]
+    pub(crate) fn with_context(self, context: PdfiumErrorContext) -> Self {
+        match self {
+            PdfiumError::WithContext(error, _) => PdfiumError::WithContext(error, context),
+            _ => PdfiumError::WithContext(Box::new(self), context),
+        }
+    }
 }
 
 impl Display for PdfiumError {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
-        write!(f, "{:#?}", self)
+        match self {
+            PdfiumError::WithContext(error, context) => write!(f, "{}: {}", context, error),
+            _ => write!(f, "{:#?}", self),
+        }
+    }
+}
+
+impl Error for PdfiumError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            PdfiumError::WithContext(error, _) => Some(error.as_ref()),
+            _ => None,
+        }
     }
 }
 
-impl Error for PdfiumError {}
+/// Describes the circumstances in which a [PdfiumError] occurred: the Pdfium `FPDF_*` function
+/// whose return value indicated failure, the value returned by Pdfium's `FPDF_GetLastError()`
+/// function immediately afterwards, and an optional human-readable note describing the operation
+/// being performed.
+///
+/// Note that Pdfium only sets its last error value when loading documents and pages,
+/// so for most other functions the last error value will be zero or will refer
+/// to an earlier failure.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PdfiumErrorContext {
+    function: &'static str,
+    last_error: u32,
+    note: Option<String>,
+}
+
+impl PdfiumErrorContext {
+    /// Creates a new [PdfiumErrorContext] for a failed call to the given Pdfium function.
+    #[inline]
+    pub fn new(function: &'static str, last_error: u32) -> Self {
+        PdfiumErrorContext {
+            function,
+            last_error,
+            note: None,
+        }
+    }
+
+    /// Returns this [PdfiumErrorContext] with the given human-readable note attached.
+    #[inline]
+    pub fn with_note(mut self, note: impl Into<String>) -> Self {
+        self.note = Some(note.into());
+
+        self
+    }
+
+    /// Returns the name of the Pdfium `FPDF_*` function whose return value indicated failure.
+    #[inline]
+    pub fn function(&self) -> &str {
+        self.function
+    }
+
+    /// Returns the value returned by Pdfium's `FPDF_GetLastError()` function immediately
+    /// after the failed function call, corresponding to one of Pdfium's `FPDF_ERR_*` constants.
+    #[inline]
+    pub fn last_error(&self) -> u32 {
+        self.last_error
+    }
+
+    /// Returns the human-readable note describing the operation being performed when
+    /// the failure occurred, if any.
+    #[inline]
+    pub fn note(&self) -> Option<&str> {
+        self.note.as_deref()
+    }
+}
+
+impl Display for PdfiumErrorContext {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(
+            f,
+            "{}() failed, FPDF_GetLastError() = {}",
+            self.function, self.last_error
+        )?;
+
+        if let Some(note) = self.note.as_ref() {
+            write!(f, ", {}", note)?;
+        }
+
+        Ok(())
+    }
+}
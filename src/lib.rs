@@ -72,6 +72,10 @@ mod bindings;
 mod error;
 mod pdf;
 mod pdfium;
+
+#[cfg(feature = "sync")]
+mod pdfium_sync;
+
 mod utils;
 
 /// A prelude for conveniently importing all public `pdfium-render` definitions at once.
@@ -92,16 +96,27 @@ pub mod prelude {
         pdf::bitmap::*,
         pdf::color::*,
         pdf::color_space::*,
+        pdf::date::*,
         pdf::destination::*,
+        pdf::document::accessibility::*,
         pdf::document::attachment::*,
         pdf::document::attachments::*,
+        pdf::document::availability::*,
         pdf::document::bookmark::*,
         pdf::document::bookmarks::*,
+        pdf::document::fields::*,
         pdf::document::fonts::*,
+        pdf::document::form::events::*,
         pdf::document::form::*,
+        pdf::document::image_page::*,
+        pdf::document::javascript_action::*,
+        pdf::document::javascript_actions::*,
+        pdf::document::layers::*,
         pdf::document::metadata::*,
+        pdf::document::nup::*,
         pdf::document::page::annotation::attachment_points::*,
         pdf::document::page::annotation::circle::*,
+        pdf::document::page::annotation::flags::*,
         pdf::document::page::annotation::free_text::*,
         pdf::document::page::annotation::highlight::*,
         pdf::document::page::annotation::ink::*,
@@ -123,6 +138,7 @@ pub mod prelude {
         },
         pdf::document::page::annotations::*,
         pdf::document::page::boundaries::*,
+        pdf::document::page::color_analysis::*,
         pdf::document::page::field::button::*,
         pdf::document::page::field::checkbox::*,
         pdf::document::page::field::combo::*,
@@ -134,9 +150,12 @@ pub mod prelude {
         pdf::document::page::field::text::*,
         pdf::document::page::field::unknown::*,
         pdf::document::page::field::{PdfFormField, PdfFormFieldCommon, PdfFormFieldType},
+        pdf::document::page::layout::*,
         pdf::document::page::links::*,
         pdf::document::page::object::group::*,
         pdf::document::page::object::image::*,
+        pdf::document::page::object::mark::*,
+        pdf::document::page::object::marks::*,
         pdf::document::page::object::path::*,
         pdf::document::page::object::shading::*,
         pdf::document::page::object::text::*,
@@ -148,28 +167,44 @@ pub mod prelude {
         },
         pdf::document::page::objects::common::*,
         pdf::document::page::objects::*,
+        pdf::document::page::overlay::*,
+        pdf::document::page::regeneration_guard::*,
         pdf::document::page::render_config::*,
+        pdf::document::page::render_job::*,
+        pdf::document::page::resize::*,
         pdf::document::page::size::*,
+        pdf::document::page::struct_tree::element::*,
+        pdf::document::page::struct_tree::*,
         pdf::document::page::text::char::*,
         pdf::document::page::text::chars::*,
+        pdf::document::page::text::lines::*,
         pdf::document::page::text::search::*,
         pdf::document::page::text::segment::*,
         pdf::document::page::text::segments::*,
+        pdf::document::page::text::web_links::*,
         pdf::document::page::text::*,
+        pdf::document::page::watermark::*,
         pdf::document::page::{
-            PdfBitmapRotation, PdfPage, PdfPageContentRegenerationStrategy, PdfPageOrientation,
-            PdfPageRenderRotation,
+            PdfBitmapRotation, PdfPage, PdfPageContentRegenerationStrategy, PdfPageFlattenMode,
+            PdfPageFlattenResult, PdfPageOrientation, PdfPageRenderRotation,
         },
         pdf::document::pages::*,
         pdf::document::permissions::*,
+        pdf::document::recovery::*,
         pdf::document::signature::*,
         pdf::document::signatures::*,
-        pdf::document::{PdfDocument, PdfDocumentVersion},
+        pdf::document::split::*,
+        pdf::document::viewer_preferences::*,
+        pdf::document::{
+            PdfDocument, PdfDocumentFileIdentifierType, PdfDocumentVersion, PdfSaveOptions,
+        },
         pdf::font::glyph::*,
         pdf::font::glyphs::*,
         pdf::font::*,
         pdf::link::*,
         pdf::matrix::*,
+        pdf::path::clip_path::*,
+        pdf::path::outline::*,
         pdf::path::segment::*,
         pdf::path::segments::*,
         pdf::points::*,
@@ -177,6 +212,27 @@ pub mod prelude {
         pdf::rect::*,
         pdfium::*,
     };
+
+    #[cfg(feature = "encryption")]
+    pub use crate::pdf::document::encryption::*;
+
+    #[cfg(feature = "export")]
+    pub use crate::pdf::document::export::*;
+
+    #[cfg(any(doc, target_arch = "wasm32"))]
+    pub use crate::pdf::document::fetch::*;
+
+    #[cfg(feature = "sync")]
+    pub use crate::pdfium_sync::*;
+
+    #[cfg(feature = "test_support")]
+    pub use crate::bindings::mock::*;
+
+    #[cfg(all(feature = "threaded_render", not(target_arch = "wasm32")))]
+    pub use crate::pdf::document::page::render_parallel::*;
+
+    #[cfg(any(doc, all(target_os = "windows", feature = "windows_print")))]
+    pub use crate::pdf::print::*;
 }
 
 #[cfg(test)]
@@ -268,6 +324,30 @@ mod tests {
         Pdfium::bind_to_statically_linked_library().unwrap();
     }
 
+    #[test]
+    #[cfg(feature = "static")]
+    fn test_static_bindings_render_page() -> Result<(), PdfiumError> {
+        // Checks that the statically linked Pdfium functions resolve correctly at link time
+        // by loading a document and rendering its first page.
+
+        let pdfium = Pdfium::new(Pdfium::bind_to_statically_linked_library()?);
+
+        let document = pdfium.load_pdf_from_file("./test/form-test.pdf", None)?;
+
+        let render_config = PdfRenderConfig::new()
+            .set_target_width(500)
+            .render_form_data(true);
+
+        let page = document.pages().first()?;
+
+        let bitmap = page.render_with_config(&render_config)?;
+
+        assert_eq!(bitmap.width(), 500);
+        assert!(bitmap.height() > 0);
+
+        Ok(())
+    }
+
     #[test]
     fn test_reader_lifetime() -> Result<(), PdfiumError> {
         // Confirms that a reader given to Pdfium::load_pdf_from_reader() does not need
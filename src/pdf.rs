@@ -3,6 +3,7 @@ pub mod appearance_mode;
 pub mod bitmap;
 pub mod color;
 pub mod color_space;
+pub mod date;
 pub mod destination;
 pub mod document;
 pub mod font;
@@ -10,6 +11,8 @@ pub mod link;
 pub mod matrix;
 pub mod path;
 pub mod points;
+#[cfg(any(doc, all(target_os = "windows", feature = "windows_print")))]
+pub mod print;
 pub mod quad_points;
 pub mod rect;
 mod transform; // Keep private so that internal macros are not exposed.
@@ -18,6 +18,13 @@ impl<'a> PdfActionLaunch<'a> {
     ) -> Self {
         PdfActionLaunch { handle, bindings }
     }
+
+    /// Returns the path of the file or application that should be launched when this
+    /// [PdfActionLaunch] is triggered, if any.
+    #[inline]
+    pub fn file_path(&self) -> Option<String> {
+        self.file_path_impl()
+    }
 }
 
 impl<'a> PdfActionPrivate<'a> for PdfActionLaunch<'a> {
@@ -32,8 +32,9 @@ impl<'a> PdfActionLocalDestination<'a> {
         let handle = self.bindings.FPDFAction_GetDest(self.document, self.handle);
 
         if handle.is_null() {
-            Err(PdfiumError::PdfiumLibraryInternalError(
-                PdfiumInternalError::Unknown,
+            Err(self.bindings.error_in(
+                "FPDFAction_GetDest",
+                PdfiumError::PdfiumLibraryInternalError(PdfiumInternalError::Unknown),
             ))
         } else {
             Ok(PdfDestination::from_pdfium(
@@ -10,14 +10,54 @@ pub(crate) mod internal {
     use crate::bindgen::FPDF_ACTION;
     use crate::bindings::PdfiumLibraryBindings;
     use crate::pdf::action::PdfActionCommon;
+    use crate::utils::mem::create_byte_buffer;
+    use std::os::raw::c_void;
 
     /// Internal crate-specific functionality common to all [PdfAction] actions.
     pub(crate) trait PdfActionPrivate<'a>: PdfActionCommon<'a> {
         /// Returns the internal `FPDF_ACTION` handle for this [PdfAction].
-        #[allow(dead_code)] // TODO: AJRC - 13/6/24 - remove once handle() function is in use.
         fn handle(&self) -> &FPDF_ACTION;
 
         /// Returns the [PdfiumLibraryBindings] used by this [PdfAction].
         fn bindings(&self) -> &dyn PdfiumLibraryBindings;
+
+        /// Returns the file path associated with this [PdfAction], if any. Pdfium only
+        /// reports file paths for launch and remote destination actions.
+        fn file_path_impl(&self) -> Option<String> {
+            // Retrieving the file path from Pdfium is a two-step operation. First, we call
+            // FPDFAction_GetFilePath() with a null buffer; this will retrieve the length of
+            // the path in bytes. If the length is zero, then there is no path associated
+            // with this action.
+
+            // If the length is non-zero, then we reserve a byte buffer of the given
+            // length and call FPDFAction_GetFilePath() again with a pointer to the buffer;
+            // this will write the path to the buffer as a null-terminated UTF-8 string.
+
+            let buffer_length =
+                self.bindings()
+                    .FPDFAction_GetFilePath(*self.handle(), std::ptr::null_mut(), 0);
+
+            if buffer_length == 0 {
+                return None;
+            }
+
+            let mut buffer = create_byte_buffer(buffer_length as usize);
+
+            let result = self.bindings().FPDFAction_GetFilePath(
+                *self.handle(),
+                buffer.as_mut_ptr() as *mut c_void,
+                buffer_length,
+            );
+
+            if result != buffer_length {
+                return None;
+            }
+
+            if let Some(terminator) = buffer.iter().position(|byte| *byte == 0) {
+                buffer.truncate(terminator);
+            }
+
+            Some(String::from_utf8_lossy(&buffer).into_owned())
+        }
     }
 }
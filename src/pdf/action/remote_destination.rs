@@ -18,6 +18,13 @@ impl<'a> PdfActionRemoteDestination<'a> {
     ) -> Self {
         PdfActionRemoteDestination { handle, bindings }
     }
+
+    /// Returns the path of the remote document containing the target of this
+    /// [PdfActionRemoteDestination], if any.
+    #[inline]
+    pub fn file_path(&self) -> Option<String> {
+        self.file_path_impl()
+    }
 }
 
 impl<'a> PdfActionPrivate<'a> for PdfActionRemoteDestination<'a> {
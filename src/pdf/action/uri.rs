@@ -6,7 +6,7 @@ use crate::bindings::PdfiumLibraryBindings;
 use crate::error::PdfiumError;
 use crate::pdf::action::private::internal::PdfActionPrivate;
 use crate::utils::mem::create_byte_buffer;
-use std::ffi::{c_void, CString};
+use std::ffi::c_void;
 
 pub struct PdfActionUri<'a> {
     handle: FPDF_ACTION,
@@ -37,7 +37,7 @@ impl<'a> PdfActionUri<'a> {
 
         // If the length is non-zero, then we reserve a byte buffer of the given
         // length and call FPDFAction_GetURIPath() again with a pointer to the buffer;
-        // this will write the path to the buffer as an array of 7-bit ASCII characters.
+        // this will write the path to the buffer as a null-terminated byte string.
 
         let buffer_length = self.bindings().FPDFAction_GetURIPath(
             self.document,
@@ -61,15 +61,28 @@ impl<'a> PdfActionUri<'a> {
             buffer_length,
         );
 
-        assert_eq!(result, buffer_length);
+        if result != buffer_length {
+            return Err(PdfiumError::NoUriForAction);
+        }
 
-        if let Ok(result) = CString::from_vec_with_nul(buffer) {
-            result
-                .into_string()
-                .map_err(PdfiumError::CStringConversionError)
-        } else {
-            Err(PdfiumError::NoUriForAction)
+        if let Some(terminator) = buffer.iter().position(|byte| *byte == 0) {
+            buffer.truncate(terminator);
         }
+
+        if buffer.is_empty() {
+            return Err(PdfiumError::NoUriForAction);
+        }
+
+        // The PDF specification requires URIs to be 7-bit ASCII, but Pdfium returns the
+        // raw bytes stored in the document, and some PDF generators store URIs in the
+        // local encoding of the generating system. Prefer UTF-8, falling back to
+        // Latin-1 (where every byte maps directly to the equivalent code point) so that
+        // no bytes are lost.
+
+        Ok(match String::from_utf8(buffer) {
+            Ok(uri) => uri,
+            Err(err) => err.into_bytes().into_iter().map(char::from).collect(),
+        })
     }
 }
 
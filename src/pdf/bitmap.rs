@@ -6,8 +6,12 @@ use crate::bindgen::{
 };
 use crate::bindings::PdfiumLibraryBindings;
 use crate::error::{PdfiumError, PdfiumInternalError};
+use crate::pdf::color::PdfColor;
 use crate::pdf::document::page::render_config::PdfPageRenderSettings;
-use crate::utils::pixels::{aligned_bgr_to_rgba, aligned_rgb_to_rgba, bgra_to_rgba};
+use crate::utils::pixels::{
+    aligned_bgr_to_rgba, aligned_bgra_to_rgba, aligned_rgb_to_rgba, strip_alignment,
+};
+use std::borrow::Cow;
 use std::os::raw::c_int;
 
 #[cfg(any(feature = "image_latest", feature = "image_025"))]
@@ -19,12 +23,18 @@ use image_024::{DynamicImage, GrayImage, RgbaImage};
 #[cfg(feature = "image_023")]
 use image_023::{DynamicImage, GrayImage, RgbaImage};
 
+#[cfg(feature = "image_api")]
+use crate::utils::pixels::aligned_gray_to_rgba;
+
 #[cfg(not(target_arch = "wasm32"))]
 use std::os::raw::c_void;
 
+#[cfg(doc)]
+use crate::pdf::document::page::PdfPage;
+
 #[cfg(target_arch = "wasm32")]
 use {
-    js_sys::Uint8Array,
+    js_sys::{Uint8Array, Uint8ClampedArray},
     wasm_bindgen::{Clamped, JsValue},
     web_sys::ImageData,
 };
@@ -142,14 +152,35 @@ impl<'a> PdfBitmap<'a> {
         );
 
         if handle.is_null() {
-            Err(PdfiumError::PdfiumLibraryInternalError(
-                PdfiumInternalError::Unknown,
+            Err(bindings.error_in(
+                "FPDFBitmap_CreateEx",
+                PdfiumError::PdfiumLibraryInternalError(PdfiumInternalError::Unknown),
             ))
         } else {
             Ok(Self::from_pdfium(handle, bindings))
         }
     }
 
+    /// Creates a new [PdfBitmap] capable of storing an image of the given pixel width and height
+    /// in the given pixel format, with every pixel initialized to [PdfColor::WHITE].
+    ///
+    /// Unlike [PdfBitmap::empty()], which leaves the contents of the new bitmap buffer undefined,
+    /// the returned bitmap can be used directly as a canvas onto which other images are drawn,
+    /// for example by rendering several pages into it using [PdfPage::render_into_bitmap_at()].
+    /// Use [PdfBitmap::fill_rect()] to initialize the bitmap to a different color.
+    pub fn create_empty(
+        width: Pixels,
+        height: Pixels,
+        format: PdfBitmapFormat,
+        bindings: &'a dyn PdfiumLibraryBindings,
+    ) -> Result<PdfBitmap<'a>, PdfiumError> {
+        let mut bitmap = Self::empty(width, height, format, bindings)?;
+
+        bitmap.fill_rect(0, 0, width, height, PdfColor::WHITE)?;
+
+        Ok(bitmap)
+    }
+
     /// Creates a new [PdfBitmap] that wraps the given byte buffer. The buffer must be capable
     /// of storing an image of the given pixel width and height in the given pixel format,
     /// otherwise a buffer overflow may occur during rendering.
@@ -177,8 +208,9 @@ impl<'a> PdfBitmap<'a> {
         );
 
         if handle.is_null() {
-            Err(PdfiumError::PdfiumLibraryInternalError(
-                PdfiumInternalError::Unknown,
+            Err(bindings.error_in(
+                "FPDFBitmap_CreateEx",
+                PdfiumError::PdfiumLibraryInternalError(PdfiumInternalError::Unknown),
             ))
         } else {
             Ok(Self::from_pdfium(handle, bindings))
@@ -224,6 +256,135 @@ impl<'a> PdfBitmap<'a> {
         PdfBitmapFormat::from_pdfium(self.bindings.FPDFBitmap_GetFormat(self.handle) as u32)
     }
 
+    /// Returns `true` if the given region lies entirely within the bounds of this [PdfBitmap].
+    #[inline]
+    pub(crate) fn contains_region(
+        &self,
+        x: Pixels,
+        y: Pixels,
+        width: Pixels,
+        height: Pixels,
+    ) -> bool {
+        x >= 0
+            && y >= 0
+            && width >= 0
+            && height >= 0
+            && x.checked_add(width)
+                .map(|right| right <= self.width())
+                .unwrap_or(false)
+            && y.checked_add(height)
+                .map(|bottom| bottom <= self.height())
+                .unwrap_or(false)
+    }
+
+    /// Sets every pixel in the given region of this [PdfBitmap] to the given color. The region's
+    /// top-left corner is given in pixels from the top-left corner of the bitmap.
+    ///
+    /// The alpha channel of the given color is only used if this bitmap has the
+    /// [PdfBitmapFormat::BGRA] pixel format. Existing pixels are replaced rather than composited
+    /// with the given color. Returns [PdfiumError::BitmapRegionOutOfBounds] if the region
+    /// does not lie entirely within the bounds of this bitmap.
+    pub fn fill_rect(
+        &mut self,
+        x: Pixels,
+        y: Pixels,
+        width: Pixels,
+        height: Pixels,
+        color: PdfColor,
+    ) -> Result<(), PdfiumError> {
+        if !self.contains_region(x, y, width, height) {
+            return Err(PdfiumError::BitmapRegionOutOfBounds);
+        }
+
+        let color = if self.was_byte_order_reversed_during_rendering {
+            // Match the swapped R and B channels of the existing pixel data.
+
+            PdfColor::new(color.blue(), color.green(), color.red(), color.alpha())
+        } else {
+            color
+        };
+
+        self.bindings.FPDFBitmap_FillRect(
+            self.handle,
+            x as c_int,
+            y as c_int,
+            width as c_int,
+            height as c_int,
+            color.as_pdfium_argb_color(),
+        );
+
+        Ok(())
+    }
+
+    /// Copies all the pixels in the given source [PdfBitmap] into this [PdfBitmap], placing
+    /// the top-left corner of the source bitmap at the given pixel position in this bitmap.
+    ///
+    /// Both bitmaps must have the same pixel format, otherwise
+    /// [PdfiumError::BitmapFormatMismatch] will be returned. If only one of the bitmaps was
+    /// rendered with `PdfRenderConfig::set_reverse_byte_order()` set to `true`, the R and B
+    /// channels of the copied pixels are swapped to match this bitmap. Returns
+    /// [PdfiumError::BitmapRegionOutOfBounds] if the source bitmap does not fit entirely within
+    /// the bounds of this bitmap at the given position.
+    pub fn copy_from(
+        &mut self,
+        source: &PdfBitmap,
+        x: Pixels,
+        y: Pixels,
+    ) -> Result<(), PdfiumError> {
+        let format = self.format()?;
+
+        if source.format()? != format {
+            return Err(PdfiumError::BitmapFormatMismatch);
+        }
+
+        if !self.contains_region(x, y, source.width(), source.height()) {
+            return Err(PdfiumError::BitmapRegionOutOfBounds);
+        }
+
+        let bytes_per_pixel = match format {
+            PdfBitmapFormat::Gray => 1,
+            PdfBitmapFormat::BGR => 3,
+            #[allow(deprecated)]
+            PdfBitmapFormat::BGRA | PdfBitmapFormat::BGRx | PdfBitmapFormat::BRGx => 4,
+        };
+
+        let do_swap_channels = bytes_per_pixel > 1
+            && source.was_byte_order_reversed_during_rendering
+                != self.was_byte_order_reversed_during_rendering;
+
+        let source_buffer = source.buffer();
+
+        let source_stride = source.stride();
+
+        let mut buffer = self.as_raw_bytes();
+
+        let stride = self.stride();
+
+        let row_length = source.width() as usize * bytes_per_pixel;
+
+        for row in 0..source.height() as usize {
+            let source_start = row * source_stride;
+
+            let start = (y as usize + row) * stride + x as usize * bytes_per_pixel;
+
+            let destination = &mut buffer[start..start + row_length];
+
+            destination.copy_from_slice(&source_buffer[source_start..source_start + row_length]);
+
+            if do_swap_channels {
+                for pixel in destination.chunks_exact_mut(bytes_per_pixel) {
+                    pixel.swap(0, 2);
+                }
+            }
+        }
+
+        if self.bindings.FPDFBitmap_SetBuffer(self.handle, &buffer) {
+            Ok(())
+        } else {
+            Err(PdfiumError::DataBufferLengthMismatch)
+        }
+    }
+
     // TODO: AJRC - 25/11/22 - remove deprecated PdfBitmap::as_bytes() function in 0.9.0
     // as part of tracking issue https://github.com/ajrcarey/pdfium-render/issues/36
     /// Returns an immutable reference to the bitmap buffer backing this [PdfBitmap].
@@ -247,16 +408,63 @@ impl<'a> PdfBitmap<'a> {
         self.bindings.FPDFBitmap_GetBuffer_as_vec(self.handle)
     }
 
+    /// Returns an immutable reference to the bitmap buffer backing this [PdfBitmap], without
+    /// copying any pixel data. This allows callers to copy pixel data directly into their
+    /// own buffers or drawing surfaces.
+    ///
+    /// Each scanline in the returned buffer occupies [PdfBitmap::stride()] bytes. The stride may
+    /// be larger than the width of the bitmap multiplied by the number of bytes per pixel
+    /// in the bitmap's [PdfBitmap::format()], in which case the remaining bytes at the end of each
+    /// scanline are alignment padding and do not contain pixel data.
+    ///
+    /// Unlike [PdfBitmap::as_rgba_bytes], this function does not attempt any color channel normalization.
+    ///
+    /// This function is not available when compiling to WASM. Use [PdfBitmap::as_array]
+    /// to avoid copying pixel data when compiling to WASM.
+    #[cfg(not(target_arch = "wasm32"))]
+    #[inline]
+    pub fn as_raw_slice(&self) -> &[u8] {
+        self.bindings.FPDFBitmap_GetBuffer_as_slice(self.handle)
+    }
+
+    /// Returns the length in bytes of a single scanline in the bitmap buffer backing this [PdfBitmap].
+    ///
+    /// The stride is always a multiple of four bytes. For pixel formats that use less than
+    /// four bytes per pixel, the stride may be larger than the width of the bitmap multiplied by
+    /// the number of bytes per pixel; the extra bytes at the end of each scanline are
+    /// alignment padding.
+    #[inline]
+    pub fn stride(&self) -> usize {
+        self.bindings.FPDFBitmap_GetStride(self.handle) as usize
+    }
+
+    /// Returns the bitmap buffer backing this [PdfBitmap], borrowing it directly where possible
+    /// so that callers can convert pixel data with a single copy.
+    #[inline]
+    fn buffer(&self) -> Cow<'_, [u8]> {
+        #[cfg(not(target_arch = "wasm32"))]
+        let buffer = Cow::Borrowed(self.as_raw_slice());
+
+        #[cfg(target_arch = "wasm32")]
+        let buffer = Cow::Owned(self.as_raw_bytes());
+
+        buffer
+    }
+
     /// Returns an owned copy of the bitmap buffer backing this [PdfBitmap], normalizing all
-    /// color channels into RGBA irrespective of the original pixel format.
+    /// color channels into RGBA irrespective of the original pixel format. Grayscale pixel data
+    /// is returned as a single channel, without any alignment padding.
+    ///
+    /// Pixel data is copied out of the bitmap buffer exactly once, with any color channel
+    /// conversion taking place during the copy.
     pub fn as_rgba_bytes(&self) -> Vec<u8> {
-        let bytes = self.as_raw_bytes();
+        let bytes = self.buffer();
 
         let format = self.format().unwrap_or_default();
 
         let width = self.width() as usize;
 
-        let stride = bytes.len() / self.height() as usize;
+        let stride = self.stride();
 
         if self.was_byte_order_reversed_during_rendering {
             // The R and B channels were swapped by Pdfium during rendering, as configured by
@@ -267,23 +475,45 @@ impl<'a> PdfBitmap<'a> {
                 PdfBitmapFormat::BGRA | PdfBitmapFormat::BGRx | PdfBitmapFormat::BRGx => {
                     // No color conversion necessary; data was already swapped from BGRx
                     // to four-channel RGB during rendering.
-                    bytes
+                    strip_alignment(&bytes, width * 4, stride)
                 }
-                PdfBitmapFormat::BGR => aligned_rgb_to_rgba(bytes.as_slice(), width, stride),
-                PdfBitmapFormat::Gray => bytes,
+                PdfBitmapFormat::BGR => aligned_rgb_to_rgba(&bytes, width, stride),
+                PdfBitmapFormat::Gray => strip_alignment(&bytes, width, stride),
             }
         } else {
             match format {
                 #[allow(deprecated)]
                 PdfBitmapFormat::BGRA | PdfBitmapFormat::BRGx | PdfBitmapFormat::BGRx => {
-                    bgra_to_rgba(bytes.as_slice())
+                    aligned_bgra_to_rgba(&bytes, width, stride)
                 }
-                PdfBitmapFormat::BGR => aligned_bgr_to_rgba(bytes.as_slice(), width, stride),
-                PdfBitmapFormat::Gray => bytes,
+                PdfBitmapFormat::BGR => aligned_bgr_to_rgba(&bytes, width, stride),
+                PdfBitmapFormat::Gray => strip_alignment(&bytes, width, stride),
             }
         }
     }
 
+    /// Consumes this [PdfBitmap], returning a new `Image::RgbaImage` created from the
+    /// bitmap buffer. Unlike [PdfBitmap::as_image], grayscale pixel data is expanded into
+    /// four-channel RGBA.
+    ///
+    /// Pixel data is copied out of the bitmap buffer exactly once, with any color channel
+    /// conversion taking place during the copy. The bitmap buffer is released as soon as the
+    /// image has been created.
+    ///
+    /// This function is only available when this crate's `image` feature is enabled.
+    #[cfg(feature = "image_api")]
+    pub fn into_rgba_image(self) -> Result<RgbaImage, PdfiumError> {
+        let bytes = match self.format().unwrap_or_default() {
+            PdfBitmapFormat::Gray => {
+                aligned_gray_to_rgba(&self.buffer(), self.width() as usize, self.stride())
+            }
+            _ => self.as_rgba_bytes(),
+        };
+
+        RgbaImage::from_raw(self.width() as u32, self.height() as u32, bytes)
+            .ok_or(PdfiumError::ImageError)
+    }
+
     /// Returns a new `Image::DynamicImage` created from the bitmap buffer backing this [PdfBitmap].
     ///
     /// This function is only available when this crate's `image` feature is enabled.
@@ -369,6 +599,46 @@ impl<'a> PdfBitmap<'a> {
         )
     }
 
+    /// Returns a new Javascript `ImageData` object that reads its pixel data directly from the
+    /// bitmap buffer backing this [PdfBitmap], through a `Uint8ClampedArray` view over Pdfium's
+    /// WASM memory, without copying it.
+    ///
+    /// A view can only be used if the bitmap buffer already holds pixel data in RGBA order,
+    /// i.e. if the bitmap uses the [PdfBitmapFormat::BGRA] pixel format and was rendered with
+    /// `PdfRenderConfig::set_reverse_byte_order()` set to `true`. Browsers also refuse to create an `ImageData` object over shared memory,
+    /// as used by multi-threaded builds of Pdfium. In all other cases, this function falls back
+    /// to [PdfBitmap::as_image_data()].
+    ///
+    /// The returned `ImageData` object must be consumed before this [PdfBitmap] is dropped and
+    /// before any further calls are made into Pdfium, since either may invalidate the view.
+    #[cfg(any(doc, target_arch = "wasm32"))]
+    pub(crate) fn as_image_data_view(&self) -> Result<ImageData, JsValue> {
+        let width = self.width() as u32;
+
+        let height = self.height() as u32;
+
+        if self.format().ok() == Some(PdfBitmapFormat::BGRA)
+            && self.was_byte_order_reversed_during_rendering
+            && self.stride() == width as usize * 4
+        {
+            let array = self.as_array();
+
+            let view = Uint8ClampedArray::new_with_byte_offset_and_length(
+                &array.buffer(),
+                array.byte_offset(),
+                array.length(),
+            );
+
+            if let Ok(image_data) =
+                ImageData::new_with_js_u8_clamped_array_and_sh(&view, width, height)
+            {
+                return Ok(image_data);
+            }
+        }
+
+        self.as_image_data()
+    }
+
     /// Estimates the maximum memory buffer size required for a [PdfBitmap] of the given dimensions.
     ///
     /// Certain platforms, architectures, and operating systems may limit the maximum size of a
@@ -435,4 +705,111 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_fill_rect_and_copy_from() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut destination =
+            PdfBitmap::create_empty(10, 10, PdfBitmapFormat::BGRA, pdfium.bindings())?;
+
+        assert!(destination
+            .as_raw_bytes()
+            .iter()
+            .all(|channel| *channel == 255));
+
+        let mut source = PdfBitmap::create_empty(4, 3, PdfBitmapFormat::BGRA, pdfium.bindings())?;
+
+        source.fill_rect(0, 0, 4, 3, PdfColor::new(255, 0, 0, 255))?;
+
+        assert!(matches!(
+            source.fill_rect(2, 2, 4, 4, PdfColor::BLACK),
+            Err(PdfiumError::BitmapRegionOutOfBounds)
+        ));
+
+        destination.copy_from(&source, 5, 6)?;
+
+        let rgba = destination.as_rgba_bytes();
+
+        let pixel_at = |x: usize, y: usize| &rgba[(y * 10 + x) * 4..(y * 10 + x) * 4 + 4];
+
+        assert_eq!(pixel_at(5, 6), [255, 0, 0, 255]);
+        assert_eq!(pixel_at(8, 8), [255, 0, 0, 255]);
+        assert_eq!(pixel_at(4, 6), [255, 255, 255, 255]);
+        assert_eq!(pixel_at(9, 9), [255, 255, 255, 255]);
+
+        assert!(matches!(
+            destination.copy_from(&source, 7, 0),
+            Err(PdfiumError::BitmapRegionOutOfBounds)
+        ));
+
+        let gray = PdfBitmap::create_empty(4, 3, PdfBitmapFormat::Gray, pdfium.bindings())?;
+
+        assert!(matches!(
+            destination.copy_from(&gray, 0, 0),
+            Err(PdfiumError::BitmapFormatMismatch)
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "image_api")]
+    fn test_render_gray_and_bgr_formats() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let document = pdfium.load_pdf_from_file("./test/export-test.pdf", None)?;
+
+        let page = document.pages().first()?;
+
+        // Choose an odd width so that three-channel and single-channel scanlines
+        // require alignment padding.
+
+        let config = PdfRenderConfig::new().set_target_width(201);
+
+        let rgba = page.render_with_config(&config)?.into_rgba_image()?;
+
+        for format in [PdfBitmapFormat::Gray, PdfBitmapFormat::BGR] {
+            let bitmap = page.render_with_config(
+                &PdfRenderConfig::new()
+                    .set_target_width(201)
+                    .set_format(format),
+            )?;
+
+            let bytes_per_pixel = if format == PdfBitmapFormat::Gray {
+                1
+            } else {
+                3
+            };
+
+            assert_eq!(bitmap.format()?, format);
+            assert_eq!(bitmap.stride() % 4, 0);
+            assert!(bitmap.stride() >= bitmap.width() as usize * bytes_per_pixel);
+            assert_eq!(
+                bitmap.as_raw_slice().len(),
+                bitmap.stride() * bitmap.height() as usize
+            );
+            assert_eq!(bitmap.as_raw_slice(), bitmap.as_raw_bytes().as_slice());
+
+            // Alignment padding should be removed from normalized pixel data. Grayscale
+            // pixel data remains single-channel.
+
+            let channels = if format == PdfBitmapFormat::Gray {
+                1
+            } else {
+                4
+            };
+
+            assert_eq!(
+                bitmap.as_rgba_bytes().len(),
+                bitmap.width() as usize * bitmap.height() as usize * channels
+            );
+
+            let image = bitmap.into_rgba_image()?;
+
+            assert_eq!(image.dimensions(), rgba.dimensions());
+        }
+
+        Ok(())
+    }
 }
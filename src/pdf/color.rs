@@ -10,7 +10,8 @@ use crate::error::PdfiumError;
 /// Note that when used as a form field highlight color, a solid color with no opacity
 /// will overprint any user data in the field. Use the [PdfColor::with_alpha()] function
 /// to apply an alpha channel value to an existing [PdfColor].
-#[derive(Debug, Copy, Clone)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct PdfColor {
     r: u8,
     g: u8,
@@ -266,6 +267,48 @@ impl PdfColor {
         }
     }
 
+    /// Constructs a new, fully opaque [PdfColor] approximating the given CMYK color, where each
+    /// of the cyan, magenta, yellow, and black components is in the range 0.0 to 1.0. Values
+    /// outside this range are clamped.
+    ///
+    /// The conversion is the simple device-dependent conversion described in Section 6.2.4
+    /// of The PDF Reference, Sixth Edition, on page 476, in which each RGB component is
+    /// calculated as `255 * (1 - C) * (1 - K)` (and likewise for magenta and green,
+    /// and yellow and blue), rounded to the nearest integer. The result is deterministic,
+    /// but is only an approximation of how the color would appear in print; no color
+    /// management is performed.
+    pub fn from_cmyk(cyan: f32, magenta: f32, yellow: f32, black: f32) -> Self {
+        let k = 1.0 - black.clamp(0.0, 1.0);
+
+        let component = |value: f32| (255.0 * (1.0 - value.clamp(0.0, 1.0)) * k).round() as u8;
+
+        Self::new(component(cyan), component(magenta), component(yellow), 255)
+    }
+
+    /// Returns the cyan, magenta, yellow, and black components of a CMYK color approximating
+    /// this [PdfColor], each in the range 0.0 to 1.0. The alpha channel value is ignored.
+    ///
+    /// This is the inverse of the conversion performed by [PdfColor::from_cmyk()], using
+    /// the maximum amount of black: the black component is calculated as
+    /// `1 - max(R, G, B) / 255`, and the remaining components are calculated from the
+    /// RGB components relative to the black component. Pure black is returned as
+    /// `(0.0, 0.0, 0.0, 1.0)`.
+    pub fn to_cmyk(&self) -> (f32, f32, f32, f32) {
+        let (r, g, b) = (
+            self.r as f32 / 255.0,
+            self.g as f32 / 255.0,
+            self.b as f32 / 255.0,
+        );
+
+        let max = r.max(g).max(b);
+
+        if max == 0.0 {
+            return (0.0, 0.0, 0.0, 1.0);
+        }
+
+        ((max - r) / max, (max - g) / max, (max - b) / max, 1.0 - max)
+    }
+
     /// Returns the result of averaging the RGB and alpha values of the two given [PdfColor] objects.
     #[inline]
     pub const fn mix(a: &PdfColor, b: &PdfColor) -> Self {
@@ -360,6 +403,15 @@ impl PdfColor {
         )
     }
 
+    /// Returns this color encoded as a 32-bit 0xAARRGGBB value in 8888 ARGB format,
+    /// as expected by Pdfium's `FPDF_COLORSCHEME` struct.
+    #[inline]
+    pub(crate) fn as_pdfium_argb_color(&self) -> FPDF_DWORD {
+        let (alpha, r, g, b) = self.color_components();
+
+        ((alpha << 24) | (r << 16) | (g << 8) | b) as FPDF_DWORD
+    }
+
     /// Returns the raw color components of this [PdfColor] in the order (alpha, R, G, B).
     #[inline]
     fn color_components(&self) -> (FPDF_DWORD, FPDF_DWORD, FPDF_DWORD, FPDF_DWORD) {
@@ -392,6 +444,41 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_cmyk_conversion() {
+        // (C, M, Y, K) values and their expected RGB equivalents.
+
+        let pairs = [
+            ((0.0, 0.0, 0.0, 0.0), PdfColor::WHITE),
+            ((0.0, 0.0, 0.0, 1.0), PdfColor::BLACK),
+            ((0.0, 1.0, 1.0, 0.0), PdfColor::RED),
+            ((1.0, 0.0, 1.0, 0.0), PdfColor::LIME),
+            ((1.0, 1.0, 0.0, 0.0), PdfColor::BLUE),
+            ((1.0, 0.0, 0.0, 0.0), PdfColor::CYAN),
+            ((0.0, 0.5, 1.0, 0.2), PdfColor::new(204, 102, 0, 255)),
+            ((0.0, 0.0, 0.0, 0.5), PdfColor::new(128, 128, 128, 255)),
+        ];
+
+        for ((c, m, y, k), rgb) in pairs {
+            assert_eq!(PdfColor::from_cmyk(c, m, y, k), rgb);
+
+            let (c2, m2, y2, k2) = rgb.to_cmyk();
+
+            assert!((c2 - c).abs() < 0.01);
+            assert!((m2 - m).abs() < 0.01);
+            assert!((y2 - y).abs() < 0.01);
+            assert!((k2 - k).abs() < 0.01);
+
+            // Round-tripping should always produce the same color.
+
+            assert_eq!(PdfColor::from_cmyk(c2, m2, y2, k2), rgb);
+        }
+
+        // Out of range values are clamped.
+
+        assert_eq!(PdfColor::from_cmyk(-1.0, 2.0, 0.0, 0.0), PdfColor::MAGENTA);
+    }
+
     #[test]
     fn test_to_hex() {
         assert_eq!(PdfColor::PURPLE.to_hex(), "800080");
@@ -0,0 +1,314 @@
+//! Defines the [PdfDate] struct, a calendar date and time expressed in the date format
+//! defined by the PDF specification.
+
+use crate::error::PdfiumError;
+use chrono::{DateTime, Datelike, Offset, TimeZone, Timelike};
+use std::fmt::{Display, Formatter};
+
+/// A calendar date and time, along with an offset from Universal Time (UT), expressed
+/// in the date format defined in Section 7.9.4 of the PDF Reference Manual, version 1.7,
+/// on page 95.
+///
+/// Dates are formatted as `D:YYYYMMDDHHmmSSOHH'mm'`, where `O` indicates the relationship
+/// of local time to Universal Time: `+` if local time is later than UT, `-` if local
+/// time is earlier than UT, and `Z` if local time is equal to UT.
+///
+/// A [PdfDate] can be constructed without any dependency on an external date handling crate,
+/// but can also be created from a `chrono::DateTime` value for convenience.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct PdfDate {
+    year: u16,
+    month: u8,
+    day: u8,
+    hour: u8,
+    minute: u8,
+    second: u8,
+    utc_offset_minutes: i16,
+}
+
+impl PdfDate {
+    /// Creates a new [PdfDate] from the given date and time components, and the given
+    /// offset of local time from Universal Time, expressed in minutes.
+    ///
+    /// Returns [PdfiumError::InvalidPdfDate] if any component lies outside its valid range.
+    pub fn new(
+        year: u16,
+        month: u8,
+        day: u8,
+        hour: u8,
+        minute: u8,
+        second: u8,
+        utc_offset_minutes: i16,
+    ) -> Result<Self, PdfiumError> {
+        if year > 9999
+            || !(1..=12).contains(&month)
+            || !(1..=31).contains(&day)
+            || hour > 23
+            || minute > 59
+            || second > 59
+            || utc_offset_minutes.abs() >= 24 * 60
+        {
+            return Err(PdfiumError::InvalidPdfDate);
+        }
+
+        Ok(PdfDate {
+            year,
+            month,
+            day,
+            hour,
+            minute,
+            second,
+            utc_offset_minutes,
+        })
+    }
+
+    /// Creates a new [PdfDate] from the given date and time components in Universal Time.
+    ///
+    /// Returns [PdfiumError::InvalidPdfDate] if any component lies outside its valid range.
+    #[inline]
+    pub fn new_utc(
+        year: u16,
+        month: u8,
+        day: u8,
+        hour: u8,
+        minute: u8,
+        second: u8,
+    ) -> Result<Self, PdfiumError> {
+        Self::new(year, month, day, hour, minute, second, 0)
+    }
+
+    /// Parses the given PDF date string into a new [PdfDate].
+    ///
+    /// The leading `D:` prefix is optional, as are all components following the year;
+    /// omitted components take their default values as defined by the PDF specification.
+    /// Returns [PdfiumError::InvalidPdfDate] if the string cannot be parsed.
+    pub fn from_pdf_string(date: &str) -> Result<Self, PdfiumError> {
+        let date = date.trim();
+
+        let date = date.strip_prefix("D:").unwrap_or(date);
+
+        let digits = date
+            .char_indices()
+            .find(|(_, c)| !c.is_ascii_digit())
+            .map(|(index, _)| index)
+            .unwrap_or(date.len());
+
+        let (timestamp, offset) = date.split_at(digits);
+
+        if timestamp.len() < 4 || timestamp.len() > 14 || timestamp.len() % 2 != 0 {
+            return Err(PdfiumError::InvalidPdfDate);
+        }
+
+        let component = |start: usize, default: u8| -> u8 {
+            timestamp
+                .get(start..start + 2)
+                .and_then(|value| value.parse().ok())
+                .unwrap_or(default)
+        };
+
+        let year = timestamp[0..4]
+            .parse()
+            .map_err(|_| PdfiumError::InvalidPdfDate)?;
+
+        let utc_offset_minutes = match offset.chars().next() {
+            None | Some('Z') => 0,
+            Some(sign @ '+') | Some(sign @ '-') => {
+                let offset = &offset[1..];
+
+                // Some producers omit the apostrophes separating the hours and minutes
+                // of the offset, e.g. +0530 rather than +05'30'.
+
+                let offset = if offset.len() == 4 && !offset.contains('\'') {
+                    format!("{}'{}", &offset[0..2], &offset[2..4])
+                } else {
+                    offset.to_string()
+                };
+
+                let parts = offset
+                    .split('\'')
+                    .filter(|part| !part.is_empty())
+                    .map(|part| part.parse::<i16>().map_err(|_| PdfiumError::InvalidPdfDate))
+                    .collect::<Result<Vec<_>, _>>()?;
+
+                let minutes =
+                    parts.first().copied().unwrap_or(0) * 60 + parts.get(1).copied().unwrap_or(0);
+
+                if sign == '-' {
+                    -minutes
+                } else {
+                    minutes
+                }
+            }
+            _ => return Err(PdfiumError::InvalidPdfDate),
+        };
+
+        Self::new(
+            year,
+            component(4, 1),
+            component(6, 1),
+            component(8, 0),
+            component(10, 0),
+            component(12, 0),
+            utc_offset_minutes,
+        )
+    }
+
+    /// Returns the year of this [PdfDate].
+    #[inline]
+    pub fn year(&self) -> u16 {
+        self.year
+    }
+
+    /// Returns the month of this [PdfDate], in the range 1 to 12.
+    #[inline]
+    pub fn month(&self) -> u8 {
+        self.month
+    }
+
+    /// Returns the day of the month of this [PdfDate], in the range 1 to 31.
+    #[inline]
+    pub fn day(&self) -> u8 {
+        self.day
+    }
+
+    /// Returns the hour of this [PdfDate], in the range 0 to 23.
+    #[inline]
+    pub fn hour(&self) -> u8 {
+        self.hour
+    }
+
+    /// Returns the minute of this [PdfDate], in the range 0 to 59.
+    #[inline]
+    pub fn minute(&self) -> u8 {
+        self.minute
+    }
+
+    /// Returns the second of this [PdfDate], in the range 0 to 59.
+    #[inline]
+    pub fn second(&self) -> u8 {
+        self.second
+    }
+
+    /// Returns the offset of local time from Universal Time for this [PdfDate], in minutes.
+    #[inline]
+    pub fn utc_offset_minutes(&self) -> i16 {
+        self.utc_offset_minutes
+    }
+
+    /// Formats this [PdfDate] as a PDF date string, in the format `D:YYYYMMDDHHmmSSOHH'mm'`.
+    pub fn to_pdf_string(&self) -> String {
+        let sign = match self.utc_offset_minutes {
+            0 => 'Z',
+            offset if offset > 0 => '+',
+            _ => '-',
+        };
+
+        let offset = self.utc_offset_minutes.abs();
+
+        format!(
+            "D:{:04}{:02}{:02}{:02}{:02}{:02}{}{:02}'{:02}'",
+            self.year,
+            self.month,
+            self.day,
+            self.hour,
+            self.minute,
+            self.second,
+            sign,
+            offset / 60,
+            offset % 60
+        )
+    }
+}
+
+impl Display for PdfDate {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.to_pdf_string().as_str())
+    }
+}
+
+impl<T: TimeZone> From<DateTime<T>> for PdfDate {
+    fn from(date: DateTime<T>) -> Self {
+        PdfDate {
+            year: date.year().clamp(0, 9999) as u16,
+            month: date.month() as u8,
+            day: date.day() as u8,
+            hour: date.hour() as u8,
+            minute: date.minute() as u8,
+            second: date.second() as u8,
+            utc_offset_minutes: (date.offset().fix().local_minus_utc() / 60) as i16,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use chrono::prelude::*;
+
+    #[test]
+    fn test_pdf_date_formatting() -> Result<(), PdfiumError> {
+        assert_eq!(
+            PdfDate::new(1998, 12, 23, 19, 52, 0, -8 * 60)?.to_pdf_string(),
+            "D:19981223195200-08'00'"
+        );
+        assert_eq!(
+            PdfDate::new_utc(2024, 2, 29, 7, 5, 9)?.to_pdf_string(),
+            "D:20240229070509Z00'00'"
+        );
+        assert_eq!(
+            PdfDate::new(2024, 1, 1, 0, 0, 0, 5 * 60 + 30)?.to_string(),
+            "D:20240101000000+05'30'"
+        );
+        assert!(PdfDate::new(2024, 13, 1, 0, 0, 0, 0).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_pdf_date_parsing() -> Result<(), PdfiumError> {
+        assert_eq!(
+            PdfDate::from_pdf_string("D:19981223195200-08'00'")?,
+            PdfDate::new(1998, 12, 23, 19, 52, 0, -8 * 60)?
+        );
+        assert_eq!(
+            PdfDate::from_pdf_string("D:2001")?,
+            PdfDate::new_utc(2001, 1, 1, 0, 0, 0)?
+        );
+        assert_eq!(
+            PdfDate::from_pdf_string("20240229070509Z")?,
+            PdfDate::new_utc(2024, 2, 29, 7, 5, 9)?
+        );
+        assert_eq!(
+            PdfDate::from_pdf_string("D:20240229070509+05'30")?,
+            PdfDate::new(2024, 2, 29, 7, 5, 9, 5 * 60 + 30)?
+        );
+        assert_eq!(
+            PdfDate::from_pdf_string("D:20240229070509+0530")?,
+            PdfDate::new(2024, 2, 29, 7, 5, 9, 5 * 60 + 30)?
+        );
+        assert_eq!(
+            PdfDate::from_pdf_string("D:20240229070509")?,
+            PdfDate::new_utc(2024, 2, 29, 7, 5, 9)?
+        );
+        assert!(PdfDate::from_pdf_string("D:19").is_err());
+        assert!(PdfDate::from_pdf_string("yesterday").is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_pdf_date_from_chrono() -> Result<(), PdfiumError> {
+        let date = FixedOffset::east_opt(-5 * 3600)
+            .unwrap()
+            .with_ymd_and_hms(2023, 6, 15, 12, 30, 45)
+            .unwrap();
+
+        assert_eq!(
+            PdfDate::from(date),
+            PdfDate::new(2023, 6, 15, 12, 30, 45, -5 * 60)?
+        );
+
+        Ok(())
+    }
+}
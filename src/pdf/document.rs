@@ -1,36 +1,94 @@
 //! Defines the [PdfDocument] struct, the entry point to all Pdfium functionality
 //! related to a single PDF file.
 
+pub mod accessibility;
 pub mod attachment;
 pub mod attachments;
+pub mod availability;
 pub mod bookmark;
 pub mod bookmarks;
+#[cfg(feature = "encryption")]
+pub mod encryption;
+#[cfg(feature = "export")]
+pub mod export;
+#[cfg(any(doc, target_arch = "wasm32"))]
+pub mod fetch;
+pub mod fields;
 pub mod fonts;
 pub mod form;
+pub mod image_page;
+pub mod javascript_action;
+pub mod javascript_actions;
+pub mod layers;
 pub mod metadata;
+pub mod nup;
 pub mod page;
 pub mod pages;
 pub mod permissions;
+pub mod recovery;
 pub mod signature;
 pub mod signatures;
+pub mod split;
+pub mod viewer_preferences;
 
-use crate::bindgen::FPDF_DOCUMENT;
+mod raw;
+
+use crate::bindgen::{
+    FPDF_DOCUMENT, FPDF_DWORD, FPDF_FILEIDTYPE, FPDF_FILEIDTYPE_FILEIDTYPE_CHANGING,
+    FPDF_FILEIDTYPE_FILEIDTYPE_PERMANENT, FPDF_INCREMENTAL, FPDF_NO_INCREMENTAL,
+    FPDF_REMOVE_SECURITY,
+};
 use crate::bindings::PdfiumLibraryBindings;
 use crate::error::PdfiumError;
 use crate::error::PdfiumInternalError;
+use crate::pdf::document::accessibility::{
+    has_embedded_fonts_for_all_text, has_images_missing_alt_text, PdfAccessibilitySummary,
+};
 use crate::pdf::document::attachments::PdfAttachments;
+use crate::pdf::document::availability::PdfDocumentAvailability;
 use crate::pdf::document::bookmarks::PdfBookmarks;
+#[cfg(feature = "encryption")]
+use crate::pdf::document::encryption::{encrypt_document, PdfEncryptionOptions};
+#[cfg(feature = "export")]
+use crate::pdf::document::export::{PdfExport, PdfExportFormat, PdfExportOptions};
 use crate::pdf::document::fonts::PdfFonts;
 use crate::pdf::document::form::PdfForm;
+use crate::pdf::document::javascript_actions::PdfJavaScriptActions;
+use crate::pdf::document::layers::PdfLayers;
 use crate::pdf::document::metadata::PdfMetadata;
-use crate::pdf::document::pages::PdfPages;
+use crate::pdf::document::nup::PdfNupLayout;
+use crate::pdf::document::page::annotation::link::{
+    add_resolved_page_destinations_to_update, forget_page_destinations_to_resolve,
+    has_page_destinations_to_resolve,
+};
+use crate::pdf::document::page::object::miter_limit::{
+    forget_miter_limits_to_write, has_miter_limits_to_write,
+};
+use crate::pdf::document::page::object::opacity::{
+    add_graphics_states_to_update, forget_opacity_marks, has_opacity_marks,
+};
+use crate::pdf::document::page::overlay::{PdfPageOverlay, PdfPageOverlayPosition};
+use crate::pdf::document::page::size::PdfPagePaperSize;
+use crate::pdf::document::page::text::search::{PdfSearchOptions, PdfSearchResult};
+use crate::pdf::document::page::watermark::PdfWatermarkConfig;
+use crate::pdf::document::pages::{PdfPageIndex, PdfPages};
 use crate::pdf::document::permissions::PdfPermissions;
+use crate::pdf::document::raw::{
+    decode_stream, decode_text_string, get, parse_reference, set, to_dictionary_string,
+    PdfIncrementalUpdate, PdfRawDocument,
+};
+use crate::pdf::document::recovery::PdfLoadWarning;
 use crate::pdf::document::signatures::PdfSignatures;
+use crate::pdf::document::split::PdfDocumentSplitIterator;
+use crate::pdf::document::viewer_preferences::PdfViewerPreferences;
 use crate::utils::files::get_pdfium_file_writer_from_writer;
 use crate::utils::files::FpdfFileAccessExt;
+use crate::utils::mem::create_byte_buffer;
 use std::fmt::{Debug, Formatter};
 use std::io::Cursor;
 use std::io::Write;
+use std::ops::Range;
+use std::os::raw::{c_uint, c_void};
 
 #[cfg(not(target_arch = "wasm32"))]
 use std::fs::File;
@@ -41,6 +99,9 @@ use std::path::Path;
 #[cfg(target_arch = "wasm32")]
 use js_sys::{Array, Uint8Array};
 
+#[cfg(doc)]
+use crate::pdf::document::page::PdfPage;
+
 #[cfg(target_arch = "wasm32")]
 use wasm_bindgen::JsValue;
 
@@ -138,6 +199,159 @@ impl PdfDocumentVersion {
     }
 }
 
+/// The two halves of the file identifier in the `/ID` entry of a [PdfDocument]'s trailer.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum PdfDocumentFileIdentifierType {
+    /// The permanent identifier, set when the file was first created and intended
+    /// to remain the same across all subsequent revisions of the file.
+    Permanent,
+
+    /// The changing identifier, intended to be updated each time the file is modified.
+    Changing,
+}
+
+impl PdfDocumentFileIdentifierType {
+    #[inline]
+    pub(crate) fn as_pdfium(&self) -> FPDF_FILEIDTYPE {
+        match self {
+            PdfDocumentFileIdentifierType::Permanent => FPDF_FILEIDTYPE_FILEIDTYPE_PERMANENT,
+            PdfDocumentFileIdentifierType::Changing => FPDF_FILEIDTYPE_FILEIDTYPE_CHANGING,
+        }
+    }
+}
+
+/// Configures the options that should be applied when saving a [PdfDocument] using
+/// [PdfDocument::save_to_writer_with_options()] or [PdfDocument::save_to_bytes_with_options()].
+#[derive(Debug, Clone, PartialEq)]
+pub struct PdfSaveOptions {
+    incremental: bool,
+    remove_security: bool,
+    version: Option<PdfDocumentVersion>,
+    #[cfg(feature = "encryption")]
+    encryption: Option<PdfEncryptionOptions>,
+}
+
+impl PdfSaveOptions {
+    /// Creates a new [PdfSaveOptions] object with all settings initialized with their default values.
+    pub fn new() -> Self {
+        PdfSaveOptions {
+            incremental: false,
+            remove_security: false,
+            version: None,
+            #[cfg(feature = "encryption")]
+            encryption: None,
+        }
+    }
+
+    /// Controls whether the document should be saved as an incremental update, leaving the
+    /// original document data untouched and appending only the changed objects to it.
+    /// This preserves the validity of any existing digital signatures over the original data.
+    /// Only documents loaded from existing PDF data can be saved incrementally.
+    /// The default is `false`.
+    pub fn incremental(mut self, is_incremental: bool) -> Self {
+        self.incremental = is_incremental;
+
+        self
+    }
+
+    /// Controls whether the security handler of an encrypted document should be removed,
+    /// so that the document is saved without encryption. This cannot be combined with
+    /// an incremental save. The default is `false`.
+    pub fn remove_security(mut self, do_remove_security: bool) -> Self {
+        self.remove_security = do_remove_security;
+
+        self
+    }
+
+    /// Sets the file version that will be used when saving the document. If not set,
+    /// the version set using [PdfDocument::set_version()] will be used, if any.
+    pub fn version(mut self, version: PdfDocumentVersion) -> Self {
+        self.version = Some(version);
+
+        self
+    }
+
+    /// Encrypts the saved document with the passwords, permissions, and algorithm set in the
+    /// given [PdfEncryptionOptions]. Any existing security handler is replaced. This cannot be
+    /// combined with an incremental save.
+    ///
+    /// Pdfium does not support writing encrypted documents, so the document data saved by
+    /// Pdfium is encrypted by `pdfium-render` before it is passed to the writer.
+    #[cfg(feature = "encryption")]
+    pub fn encrypt(mut self, options: PdfEncryptionOptions) -> Self {
+        self.encryption = Some(options);
+
+        self
+    }
+
+    /// Returns `true` if the document should be saved as an incremental update.
+    #[inline]
+    pub fn is_incremental(&self) -> bool {
+        self.incremental
+    }
+
+    /// Returns `true` if the security handler of an encrypted document should be removed.
+    #[inline]
+    pub fn is_remove_security(&self) -> bool {
+        self.remove_security
+    }
+
+    /// Returns the file version that will be used when saving the document, if set.
+    #[inline]
+    pub fn get_version(&self) -> Option<PdfDocumentVersion> {
+        self.version
+    }
+
+    /// Returns the [PdfEncryptionOptions] that will be used to encrypt the saved document, if set.
+    #[cfg(feature = "encryption")]
+    #[inline]
+    pub fn get_encryption(&self) -> Option<&PdfEncryptionOptions> {
+        self.encryption.as_ref()
+    }
+
+    #[cfg(feature = "encryption")]
+    #[inline]
+    fn is_encrypted(&self) -> bool {
+        self.encryption.is_some()
+    }
+
+    #[cfg(not(feature = "encryption"))]
+    #[inline]
+    fn is_encrypted(&self) -> bool {
+        false
+    }
+
+    pub(crate) fn as_pdfium(&self) -> Result<FPDF_DWORD, PdfiumError> {
+        // The save flags defined in fpdf_save.h are mutually exclusive values,
+        // not bit flags that can be combined.
+
+        if self.is_encrypted() {
+            // Pdfium must write unencrypted data for us to encrypt, so any existing
+            // security handler is always removed.
+
+            return if self.incremental {
+                Err(PdfiumError::IncrementalSaveIncompatibleWithEncryption)
+            } else {
+                Ok(FPDF_REMOVE_SECURITY as FPDF_DWORD)
+            };
+        }
+
+        match (self.incremental, self.remove_security) {
+            (true, true) => Err(PdfiumError::IncrementalSaveIncompatibleWithRemoveSecurity),
+            (true, false) => Ok(FPDF_INCREMENTAL as FPDF_DWORD),
+            (false, true) => Ok(FPDF_REMOVE_SECURITY as FPDF_DWORD),
+            (false, false) => Ok(FPDF_NO_INCREMENTAL as FPDF_DWORD),
+        }
+    }
+}
+
+impl Default for PdfSaveOptions {
+    #[inline]
+    fn default() -> Self {
+        PdfSaveOptions::new()
+    }
+}
+
 /// An entry point to all the various object collections contained in a single PDF file.
 /// These collections include:
 /// * [PdfDocument::attachments()], an immutable collection of all the [PdfAttachments] in the document.
@@ -146,12 +360,17 @@ impl PdfDocumentVersion {
 /// * [PdfDocument::fonts()], an immutable collection of all the [PdfFonts] in the document.
 /// * [PdfDocument::fonts_mut()], a mutable collection of all the [PdfFonts] in the document.
 /// * [PdfDocument::form()], an immutable reference to the [PdfForm] embedded in the document, if any.
+/// * [PdfDocument::javascript_actions()], an immutable collection of all the document-level
+///   [PdfJavaScriptActions] in the document.
 /// * [PdfDocument::metadata()], an immutable collection of all the [PdfMetadata] tags in the document.
+/// * [PdfDocument::metadata_mut()], a mutable collection of all the [PdfMetadata] tags in the document.
 /// * [PdfDocument::pages()], an immutable collection of all the [PdfPages] in the document.
 /// * [PdfDocument::pages_mut()], a mutable collection of all the [PdfPages] in the document.
 /// * [PdfDocument::permissions()], settings relating to security handlers and document permissions
 ///   for the document.
 /// * [PdfDocument::signatures()], an immutable collection of all the [PdfSignatures] in the document.
+/// * [PdfDocument::viewer_preferences()], settings controlling how the document should be
+///   displayed and printed.
 pub struct PdfDocument<'a> {
     handle: FPDF_DOCUMENT,
     output_version: Option<PdfDocumentVersion>,
@@ -159,16 +378,22 @@ pub struct PdfDocument<'a> {
     bookmarks: PdfBookmarks<'a>,
     form: Option<PdfForm<'a>>,
     fonts: PdfFonts<'a>,
+    javascript_actions: PdfJavaScriptActions<'a>,
     metadata: PdfMetadata<'a>,
     pages: PdfPages<'a>,
     permissions: PdfPermissions<'a>,
     signatures: PdfSignatures<'a>,
+    viewer_preferences: PdfViewerPreferences<'a>,
+    xmp_metadata: Option<String>,
     bindings: &'a dyn PdfiumLibraryBindings,
     source_byte_buffer: Option<Vec<u8>>,
 
     #[cfg_attr(target_arch = "wasm32", allow(dead_code))]
     // This field is never used when compiling to WASM.
     file_access_reader: Option<Box<FpdfFileAccessExt<'a>>>,
+
+    availability: Option<PdfDocumentAvailability<'a>>,
+    load_warnings: Vec<PdfLoadWarning>,
 }
 
 impl<'a> PdfDocument<'a> {
@@ -189,13 +414,18 @@ impl<'a> PdfDocument<'a> {
             bookmarks: PdfBookmarks::from_pdfium(handle, bindings),
             form,
             fonts: PdfFonts::from_pdfium(handle, bindings),
+            javascript_actions: PdfJavaScriptActions::from_pdfium(handle, bindings),
             metadata: PdfMetadata::from_pdfium(handle, bindings),
             pages,
             permissions: PdfPermissions::from_pdfium(handle, bindings),
             signatures: PdfSignatures::from_pdfium(handle, bindings),
+            viewer_preferences: PdfViewerPreferences::from_pdfium(handle, bindings),
+            xmp_metadata: None,
             bindings,
             source_byte_buffer: None,
             file_access_reader: None,
+            availability: None,
+            load_warnings: Vec::new(),
         }
     }
 
@@ -227,6 +457,42 @@ impl<'a> PdfDocument<'a> {
         self.file_access_reader = Some(reader);
     }
 
+    /// Transfers ownership of the [PdfDocumentAvailability] provider this [PdfDocument] was
+    /// retrieved from, so that it will always be available for Pdfium to read data from as needed.
+    #[inline]
+    pub(crate) fn set_availability(&mut self, availability: PdfDocumentAvailability<'a>) {
+        self.availability = Some(availability);
+    }
+
+    /// Returns the [PdfDocumentAvailability] provider this [PdfDocument] was retrieved from,
+    /// if the document is being loaded progressively. The provider can be used to check
+    /// whether individual pages are available before accessing them.
+    #[inline]
+    pub fn availability(&self) -> Option<&PdfDocumentAvailability<'a>> {
+        self.availability.as_ref()
+    }
+
+    /// Records the repairs that were made in order to load this [PdfDocument].
+    #[inline]
+    pub(crate) fn set_load_warnings(&mut self, warnings: Vec<PdfLoadWarning>) {
+        self.load_warnings = warnings;
+    }
+
+    /// Returns `true` if this [PdfDocument] was damaged, and had to be repaired in order
+    /// to be loaded. Documents are only repaired when loaded using the
+    /// `Pdfium::load_pdf_from_bytes_with_recovery()` function.
+    #[inline]
+    pub fn was_repaired(&self) -> bool {
+        !self.load_warnings.is_empty()
+    }
+
+    /// Returns a description of each repair that was made in order to load this [PdfDocument].
+    /// The returned list is empty if the document was not repaired.
+    #[inline]
+    pub fn load_warnings(&self) -> &[PdfLoadWarning] {
+        self.load_warnings.as_slice()
+    }
+
     /// Returns the file version of this [PdfDocument].
     pub fn version(&self) -> PdfDocumentVersion {
         let mut version = 0;
@@ -243,6 +509,91 @@ impl<'a> PdfDocument<'a> {
         self.output_version = Some(version);
     }
 
+    /// Returns the given half of the file identifier in the `/ID` entry of this
+    /// [PdfDocument]'s trailer, as raw bytes.
+    ///
+    /// File identifiers are useful for caching and deduplicating documents: two files sharing
+    /// the same permanent identifier are usually revisions of the same original document.
+    ///
+    /// Returns `None` if the document has no file identifier. This is always the case for
+    /// documents created via a call to `Pdfium::create_new_pdf()`.
+    pub fn file_identifier(&self, kind: PdfDocumentFileIdentifierType) -> Option<Vec<u8>> {
+        // Retrieving the file identifier from Pdfium is a two-step operation. First, we call
+        // FPDF_GetFileIdentifier() with a null buffer; this will retrieve the length of
+        // the identifier in bytes, including a trailing nul terminator. If the length is zero,
+        // then the document has no file identifier.
+
+        // If the length is non-zero, then we reserve a byte buffer of the given length and
+        // call FPDF_GetFileIdentifier() again with a pointer to the buffer; this will write
+        // the identifier to the buffer.
+
+        let id_type = kind.as_pdfium();
+
+        let buffer_length =
+            self.bindings
+                .FPDF_GetFileIdentifier(self.handle, id_type, std::ptr::null_mut(), 0);
+
+        if buffer_length <= 1 {
+            // The identifier is either missing or empty.
+
+            return None;
+        }
+
+        let mut buffer = create_byte_buffer(buffer_length as usize);
+
+        let result = self.bindings.FPDF_GetFileIdentifier(
+            self.handle,
+            id_type,
+            buffer.as_mut_ptr() as *mut c_void,
+            buffer_length,
+        );
+
+        assert_eq!(result, buffer_length);
+
+        // The identifier is a byte string that may itself contain nul bytes, so only
+        // the trailing nul terminator is removed.
+
+        buffer.truncate(buffer_length as usize - 1);
+
+        Some(buffer)
+    }
+
+    /// Returns the byte offsets of the ends of all the trailers in this [PdfDocument].
+    ///
+    /// Each incremental update appended to a file adds a new trailer, so a document with more
+    /// than one trailer end has been modified since it was first written. Comparing the
+    /// trailer ends against the byte ranges covered by a signature can reveal changes made
+    /// after the document was signed.
+    ///
+    /// Returns an empty list for documents created via a call to `Pdfium::create_new_pdf()`.
+    pub fn trailer_ends(&self) -> Vec<u32> {
+        // Retrieving the trailer ends from Pdfium is a two-step operation. First, we call
+        // FPDF_GetTrailerEnds() with a null buffer; this will retrieve the number of
+        // trailer ends. If the number is zero, then there are no trailers.
+
+        // If the number is non-zero, then we reserve a buffer of the given length and call
+        // FPDF_GetTrailerEnds() again with a pointer to the buffer; this will write
+        // the byte offsets to the buffer.
+
+        let buffer_length = self
+            .bindings
+            .FPDF_GetTrailerEnds(self.handle, std::ptr::null_mut(), 0);
+
+        if buffer_length == 0 {
+            return Vec::new();
+        }
+
+        let mut buffer: Vec<c_uint> = vec![0; buffer_length as usize];
+
+        let result =
+            self.bindings
+                .FPDF_GetTrailerEnds(self.handle, buffer.as_mut_ptr(), buffer_length);
+
+        assert_eq!(result, buffer_length);
+
+        buffer
+    }
+
     /// Returns an immutable collection of all the [PdfAttachments] embedded in this [PdfDocument].
     #[inline]
     pub fn attachments(&self) -> &PdfAttachments {
@@ -261,12 +612,29 @@ impl<'a> PdfDocument<'a> {
         &self.bookmarks
     }
 
+    /// Returns a mutable collection of all the [PdfBookmarks] in this [PdfDocument].
+    #[inline]
+    pub fn bookmarks_mut(&mut self) -> &mut PdfBookmarks<'a> {
+        &mut self.bookmarks
+    }
+
     /// Returns an immutable reference to the [PdfForm] embedded in this [PdfDocument], if any.
     #[inline]
     pub fn form(&self) -> Option<&PdfForm> {
         self.form.as_ref()
     }
 
+    /// Returns an immutable collection of all the document-level [PdfJavaScriptActions]
+    /// in this [PdfDocument].
+    ///
+    /// Only named JavaScript actions in the document's JavaScript name tree are included.
+    /// JavaScript attached to the document's open action, to page actions, or to individual
+    /// annotations and form fields is not included.
+    #[inline]
+    pub fn javascript_actions(&self) -> &PdfJavaScriptActions<'a> {
+        &self.javascript_actions
+    }
+
     /// Returns an immutable collection of all the [PdfFonts] in this [PdfDocument].
     #[inline]
     pub fn fonts(&self) -> &PdfFonts {
@@ -285,6 +653,197 @@ impl<'a> PdfDocument<'a> {
         &self.metadata
     }
 
+    /// Returns a mutable collection of all the [PdfMetadata] tags in this [PdfDocument].
+    ///
+    /// Changes to metadata tags are written to the document when it is saved.
+    #[inline]
+    pub fn metadata_mut(&mut self) -> &mut PdfMetadata<'a> {
+        &mut self.metadata
+    }
+
+    /// Returns the XMP metadata packet referenced by the `/Metadata` entry in the catalog
+    /// of this [PdfDocument], if any, as raw XML.
+    ///
+    /// Pdfium does not expose the document catalog, so the packet is located by saving
+    /// the document to a temporary buffer and reading the metadata stream from the saved data.
+    /// Streams using the `FlateDecode` filter are decoded. Any malformed UTF-8 sequences
+    /// in the packet are replaced with `U+FFFD REPLACEMENT CHARACTER`.
+    ///
+    /// If the packet has been changed using [PdfDocument::set_xmp_metadata()], the
+    /// changed packet is returned.
+    pub fn xmp_metadata(&self) -> Option<String> {
+        if let Some(xml) = self.xmp_metadata.as_ref() {
+            return Some(xml.clone());
+        }
+
+        let mut cursor = Cursor::new(Vec::new());
+
+        self.save_to_writer_with_pdfium(&mut cursor, &PdfSaveOptions::new())
+            .ok()?;
+
+        let bytes = cursor.into_inner();
+
+        let document = PdfRawDocument::parse(bytes.as_slice())?;
+
+        let catalog = document.object_dictionary(document.root_object_number()?)?;
+
+        let (dictionary, data) =
+            document.object_stream(get(&catalog, "Metadata").and_then(parse_reference)?)?;
+
+        decode_stream(&dictionary, data).map(|xml| String::from_utf8_lossy(&xml).into_owned())
+    }
+
+    /// Creates or replaces the XMP metadata packet referenced by the `/Metadata` entry
+    /// in the catalog of this [PdfDocument].
+    ///
+    /// The new packet is written to the document when it is saved, as an incremental update
+    /// containing an uncompressed metadata stream and an updated document catalog.
+    /// Returns [PdfiumError::DocumentMetadataUpdateNotSupported] if the document is encrypted.
+    pub fn set_xmp_metadata(&mut self, xml: &str) -> Result<(), PdfiumError> {
+        if self.bindings.FPDF_GetSecurityHandlerRevision(self.handle) != -1 {
+            // Streams in an encrypted document must themselves be encrypted,
+            // which we cannot do without access to the document's security handler.
+
+            return Err(PdfiumError::DocumentMetadataUpdateNotSupported);
+        }
+
+        self.xmp_metadata = Some(xml.to_owned());
+
+        Ok(())
+    }
+
+    /// Returns the optional content groups, or layers, defined in the `/OCProperties`
+    /// dictionary of the catalog of this [PdfDocument]. Returns an empty collection if the
+    /// document does not use optional content.
+    ///
+    /// Pdfium does not expose the document catalog, so the layers are read by saving the
+    /// document to a temporary buffer and parsing the saved data. Returns
+    /// [PdfiumError::DocumentLayersNotReadable] if the document is encrypted.
+    pub fn layers(&self) -> Result<PdfLayers, PdfiumError> {
+        if self.bindings.FPDF_GetSecurityHandlerRevision(self.handle) != -1 {
+            // Strings in an encrypted document are themselves encrypted, so layer names
+            // cannot be read without access to the document's security handler.
+
+            return Err(PdfiumError::DocumentLayersNotReadable);
+        }
+
+        let mut cursor = Cursor::new(Vec::new());
+
+        self.save_to_writer_with_pdfium(&mut cursor, &PdfSaveOptions::new())?;
+
+        let bytes = cursor.into_inner();
+
+        let document = PdfRawDocument::parse(bytes.as_slice())
+            .ok_or(PdfiumError::DocumentLayersNotReadable)?;
+
+        PdfLayers::from_raw_document(&document)
+    }
+
+    /// Returns `true` if this [PdfDocument] is a tagged PDF, i.e. its catalog contains a
+    /// `/MarkInfo` dictionary with a `/Marked` entry of `true`. The logical structure of each
+    /// page of a tagged document can be retrieved using the [PdfPage::struct_tree()] function.
+    #[inline]
+    pub fn is_tagged(&self) -> bool {
+        self.bindings
+            .is_true(self.bindings.FPDFCatalog_IsTagged(self.handle))
+    }
+
+    /// Returns the natural language of the content of this [PdfDocument], as declared by the
+    /// `/Lang` entry in the document catalog, if any. The language is typically given as a
+    /// BCP 47 language tag such as `en-US`.
+    ///
+    /// Pdfium does not expose the document catalog, so the language is read by saving the
+    /// document to a temporary buffer and parsing the saved data. Returns
+    /// [PdfiumError::DocumentLanguageNotReadable] if the document is encrypted.
+    pub fn language(&self) -> Result<Option<String>, PdfiumError> {
+        if self.bindings.FPDF_GetSecurityHandlerRevision(self.handle) != -1 {
+            // Strings in an encrypted document are themselves encrypted, so the language
+            // cannot be read without access to the document's security handler.
+
+            return Err(PdfiumError::DocumentLanguageNotReadable);
+        }
+
+        let mut cursor = Cursor::new(Vec::new());
+
+        self.save_to_writer_with_pdfium(&mut cursor, &PdfSaveOptions::new())?;
+
+        let bytes = cursor.into_inner();
+
+        let document = PdfRawDocument::parse(bytes.as_slice())
+            .ok_or(PdfiumError::DocumentLanguageNotReadable)?;
+
+        let catalog = document
+            .root_object_number()
+            .and_then(|object_number| document.object_dictionary(object_number))
+            .ok_or(PdfiumError::DocumentLanguageNotReadable)?;
+
+        Ok(get(&catalog, "Lang")
+            .and_then(decode_text_string)
+            .filter(|language| !language.trim().is_empty()))
+    }
+
+    /// Returns the indices of the pages in this [PdfDocument] containing at least one image
+    /// that is neither described by alternative text in the page's logical structure
+    /// nor marked as a decorative artifact. Images inside form objects are included.
+    ///
+    /// Alternative text given for a structure element, such as a figure, also describes
+    /// all the content belonging to that element's descendants.
+    pub fn pages_with_images_missing_alt_text(&self) -> Result<Vec<PdfPageIndex>, PdfiumError> {
+        let mut result = Vec::new();
+
+        for (index, page) in self.pages().iter().enumerate() {
+            if has_images_missing_alt_text(&page)? {
+                result.push(index as PdfPageIndex);
+            }
+        }
+
+        Ok(result)
+    }
+
+    /// Returns `true` if the fonts used by every text object in this [PdfDocument],
+    /// including text objects inside form objects, are embedded in the document.
+    /// Returns `true` if the document contains no text.
+    pub fn has_embedded_fonts_for_all_text(&self) -> Result<bool, PdfiumError> {
+        for page in self.pages().iter() {
+            if !has_embedded_fonts_for_all_text(&page)? {
+                return Ok(false);
+            }
+        }
+
+        Ok(true)
+    }
+
+    /// Returns a [PdfAccessibilitySummary] summarizing the accessibility features of this
+    /// [PdfDocument], suitable for compliance reporting. Every page in the document is
+    /// inspected, so this may be slow for large documents.
+    #[inline]
+    pub fn accessibility_summary(&self) -> Result<PdfAccessibilitySummary, PdfiumError> {
+        PdfAccessibilitySummary::from_document(self)
+    }
+
+    /// Converts the content of this [PdfDocument] into a complete HTML document using the
+    /// given [PdfExportOptions]. Every page in the document is converted, so this may be slow
+    /// for large documents.
+    ///
+    /// This function is only available when this crate's `export` feature is enabled.
+    #[cfg(feature = "export")]
+    #[inline]
+    pub fn export_to_html(&self, options: &PdfExportOptions) -> Result<PdfExport, PdfiumError> {
+        PdfExport::from_document(self, options, PdfExportFormat::Html)
+    }
+
+    /// Converts the content of this [PdfDocument] into Markdown using the given
+    /// [PdfExportOptions]. Markdown output is always reflowed, irrespective of the layout
+    /// set in the options. Every page in the document is converted, so this may be slow
+    /// for large documents.
+    ///
+    /// This function is only available when this crate's `export` feature is enabled.
+    #[cfg(feature = "export")]
+    #[inline]
+    pub fn export_to_markdown(&self, options: &PdfExportOptions) -> Result<PdfExport, PdfiumError> {
+        PdfExport::from_document(self, options, PdfExportFormat::Markdown)
+    }
+
     /// Returns an immutable collection of all the [PdfPages] in this [PdfDocument].
     #[inline]
     pub fn pages(&self) -> &PdfPages<'a> {
@@ -297,6 +856,132 @@ impl<'a> PdfDocument<'a> {
         &mut self.pages
     }
 
+    /// Adds the watermark configured by the given [PdfWatermarkConfig] to every [PdfPage]
+    /// in this [PdfDocument]. Pages of different sizes and intrinsic rotations are handled
+    /// individually, so the watermark appears in the same position, upright relative to
+    /// the viewer, on every page.
+    ///
+    /// To apply a different watermark to each page, use the [PdfPages::watermark()] function.
+    pub fn watermark_all_pages(&self, config: &PdfWatermarkConfig) -> Result<(), PdfiumError> {
+        for mut page in self.pages().iter() {
+            page.add_watermark(config)?;
+        }
+
+        Ok(())
+    }
+
+    /// Stamps the page at the given index in the given template [PdfDocument] behind the
+    /// existing content of every [PdfPage] in this [PdfDocument], as with a letterhead or
+    /// stationery background. The template page is copied into this document just once,
+    /// together with all the fonts, images, and other resources it uses, and shared by all pages.
+    ///
+    /// To stamp a page onto individual pages, or in front of existing page content,
+    /// use the [PdfPage::apply_overlay()] function.
+    pub fn apply_letterhead(
+        &self,
+        template: &PdfDocument,
+        template_page_index: PdfPageIndex,
+    ) -> Result<(), PdfiumError> {
+        if template_page_index >= template.pages().len() {
+            return Err(PdfiumError::PageIndexOutOfBounds);
+        }
+
+        let overlay = PdfPageOverlay::new(
+            self.handle(),
+            template.handle(),
+            template_page_index,
+            self.bindings(),
+        )?;
+
+        for mut page in self.pages().iter() {
+            overlay.apply(&mut page, PdfPageOverlayPosition::Underlay, None)?;
+        }
+
+        Ok(())
+    }
+
+    /// Copies all the pages in the given source [PdfDocument], appending them to the end of
+    /// this [PdfDocument]. Pages are copied together with all the fonts, images, and other
+    /// resources they use.
+    ///
+    /// To copy only some pages, or to insert them elsewhere in this [PdfDocument], use the
+    /// [PdfPages::import_pages_from_document()] function.
+    #[inline]
+    pub fn append_document(&mut self, source: &PdfDocument) -> Result<(), PdfiumError> {
+        self.pages_mut().append(source)
+    }
+
+    /// Splits this [PdfDocument] into several new documents, one for each of the given
+    /// ranges of page indices, returning an iterator over the new documents. Pages are
+    /// copied together with all the fonts, images, and other resources they use.
+    ///
+    /// Each new document is only created when the iterator is advanced, so a large document
+    /// can be split while keeping memory usage roughly constant, as long as each new document
+    /// is dropped before the next is created.
+    ///
+    /// All ranges are checked before any document is created. [PdfiumError::InvalidPageRange]
+    /// will be returned if any range is empty or overlaps another range, and
+    /// [PdfiumError::PageIndexOutOfBounds] will be returned if any range extends beyond
+    /// the last page in this [PdfDocument].
+    #[inline]
+    pub fn split(
+        &self,
+        ranges: impl IntoIterator<Item = Range<PdfPageIndex>>,
+    ) -> Result<PdfDocumentSplitIterator<'a, '_>, PdfiumError> {
+        PdfDocumentSplitIterator::new(self, ranges)
+    }
+
+    /// Splits this [PdfDocument] into several new documents, each containing a single page,
+    /// returning an iterator over the new documents. Each new document is only created when
+    /// the iterator is advanced.
+    ///
+    /// To split this [PdfDocument] into documents containing more than one page,
+    /// use the [PdfDocument::split()] function.
+    #[inline]
+    pub fn split_into_single_pages(&self) -> PdfDocumentSplitIterator<'a, '_> {
+        PdfDocumentSplitIterator::new_unchecked(
+            self,
+            self.pages()
+                .as_range()
+                .map(|index| index..index + 1)
+                .collect(),
+        )
+    }
+
+    /// Creates a new [PdfDocument] by tiling the pages of the given source [PdfDocument]
+    /// into grids of the given number of rows and columns on pages of the given size,
+    /// the size of each tile shrinking or expanding as necessary to fit.
+    ///
+    /// Tiling is performed by Pdfium, so all page content is preserved. For control over margins,
+    /// gutters, and page order, use the [PdfDocument::create_nup_document()] function instead.
+    #[inline]
+    pub fn create_tiled_document(
+        source: &PdfDocument<'a>,
+        rows_per_page: u8,
+        columns_per_row: u8,
+        size: PdfPagePaperSize,
+    ) -> Result<PdfDocument<'a>, PdfiumError> {
+        source
+            .pages()
+            .tile_into_new_document(rows_per_page, columns_per_row, size)
+    }
+
+    /// Creates a new [PdfDocument] by imposing the pages of the given source [PdfDocument]
+    /// onto sheets of the given size, placing several source pages on each sheet according
+    /// to the given [PdfNupLayout]. This can be used to create two-up or four-up handouts,
+    /// or booklets.
+    ///
+    /// Unlike the [PdfPages::tile_into_new_document()] function, this function supports
+    /// margins and gutters, booklet page ordering, and source pages of different sizes.
+    #[inline]
+    pub fn create_nup_document(
+        source: &PdfDocument<'a>,
+        layout: PdfNupLayout,
+        sheet_size: PdfPagePaperSize,
+    ) -> Result<PdfDocument<'a>, PdfiumError> {
+        layout.impose(source, sheet_size)
+    }
+
     /// Returns an immutable collection of all the [PdfPermissions] applied to this [PdfDocument].
     #[inline]
     pub fn permissions(&self) -> &PdfPermissions {
@@ -309,18 +994,243 @@ impl<'a> PdfDocument<'a> {
         &self.signatures
     }
 
+    /// Returns the [PdfViewerPreferences] for this [PdfDocument], controlling how the document
+    /// should be displayed and printed.
+    #[inline]
+    pub fn viewer_preferences(&self) -> &PdfViewerPreferences<'a> {
+        &self.viewer_preferences
+    }
+
+    /// Searches the text of every page in this [PdfDocument] for the given text string,
+    /// returning each [PdfSearchResult] along with the index of the page on which it was found.
+    ///
+    /// Results are returned in page order, and in the direction `SearchForward` within each page.
+    /// Returns [PdfiumError::TextSearchTargetIsEmpty] if the given text string is empty.
+    pub fn search_all_pages(
+        &self,
+        text: &str,
+        options: &PdfSearchOptions,
+    ) -> Result<Vec<(PdfPageIndex, PdfSearchResult)>, PdfiumError> {
+        if text.is_empty() {
+            return Err(PdfiumError::TextSearchTargetIsEmpty);
+        }
+
+        let mut results = Vec::new();
+
+        for (index, page) in self.pages().iter().enumerate() {
+            let page_text = page.text()?;
+
+            let search = page_text.search(text, options)?;
+
+            results.extend(search.map(|result| (index as PdfPageIndex, result)));
+        }
+
+        Ok(results)
+    }
+
     /// Writes this [PdfDocument] to the given writer.
+    ///
+    /// If any metadata tags have been changed using [PdfDocument::metadata_mut()], the
+    /// XMP metadata packet has been changed using [PdfDocument::set_xmp_metadata()], or any
+    /// bookmarks have been created or changed using [PdfDocument::bookmarks_mut()], the
+    /// changes are written as an incremental update immediately following the document data
+    /// saved by Pdfium.
+    #[inline]
     pub fn save_to_writer<W: Write + 'static>(&self, writer: &mut W) -> Result<(), PdfiumError> {
-        // TODO: AJRC - 25/5/22 - investigate supporting the FPDF_INCREMENTAL, FPDF_NO_INCREMENTAL,
-        // and FPDF_REMOVE_SECURITY flags defined in fpdf_save.h. There's not a lot of information
-        // on what they actually do, however.
-        // Some small info at https://forum.patagames.com/posts/t155-PDF-SaveFlags.
+        self.save_to_writer_with_options(writer, &PdfSaveOptions::new())
+    }
+
+    /// Writes this [PdfDocument] to the given writer, using the given [PdfSaveOptions].
+    ///
+    /// Returns [PdfiumError::IncrementalSaveRequiresLoadedDocument] if an incremental save
+    /// is requested for a document that was created using `Pdfium::create_new_pdf()`
+    /// rather than loaded from existing PDF data.
+    ///
+    /// If encryption is requested using `PdfSaveOptions::encrypt()`, returns
+    /// [PdfiumError::IncrementalSaveIncompatibleWithEncryption] if an incremental save is
+    /// also requested, or [PdfiumError::DocumentEncryptionNotSupported] if the document data
+    /// saved by Pdfium could not be encrypted.
+    pub fn save_to_writer_with_options<W: Write + 'static>(
+        &self,
+        writer: &mut W,
+        options: &PdfSaveOptions,
+    ) -> Result<(), PdfiumError> {
+        if options.is_incremental() && self.version() == PdfDocumentVersion::Unset {
+            // Pdfium only reports a file version for documents that have a parser
+            // attached, i.e. documents loaded from existing data. An incremental save
+            // of any other document would produce an update with nothing to update.
+
+            return Err(PdfiumError::IncrementalSaveRequiresLoadedDocument);
+        }
+
+        #[cfg(feature = "encryption")]
+        {
+            if let Some(encryption) = options.get_encryption() {
+                // Check the options before saving, so a conflicting incremental save
+                // is reported without doing any work.
+
+                options.as_pdfium()?;
+
+                let mut cursor = Cursor::new(Vec::new());
 
-        let flags = 0;
+                self.save_to_writer_with_updates(&mut cursor, options)?;
+
+                let bytes = encrypt_document(cursor.get_ref().as_slice(), encryption)?;
+
+                return writer
+                    .write_all(bytes.as_slice())
+                    .map_err(PdfiumError::IoError);
+            }
+        }
+
+        self.save_to_writer_with_updates(writer, options)
+    }
+
+    /// Writes the document data held by Pdfium for this [PdfDocument] to the given writer,
+    /// followed by an incremental update containing any changes to the document metadata,
+    /// XMP metadata, bookmarks, link destinations, object opacity, or miter limits that Pdfium
+    /// cannot save itself.
+    fn save_to_writer_with_updates<W: Write + 'static>(
+        &self,
+        writer: &mut W,
+        options: &PdfSaveOptions,
+    ) -> Result<(), PdfiumError> {
+        let has_page_destinations = has_page_destinations_to_resolve(self.handle);
+
+        // The saved page content streams need only be scanned for content marks if an image
+        // or form object has been given an opacity, or a page object has been given a miter
+        // limit, since this document was opened.
+
+        let may_have_graphics_state_marks =
+            has_opacity_marks(self.handle) || has_miter_limits_to_write(self.handle);
+
+        if self.metadata.is_modified()
+            || self.xmp_metadata.is_some()
+            || self.bookmarks.is_modified()
+            || has_page_destinations
+            || may_have_graphics_state_marks
+        {
+            // Pdfium does not allow the document information dictionary, the document
+            // catalog, the document outline, link destinations, the opacity of image and
+            // form objects, or the miter limits of page objects to be modified, so we must
+            // save the document to a buffer and append the updated objects ourselves before
+            // passing the result to the writer.
+
+            let mut cursor = Cursor::new(Vec::new());
+
+            self.save_to_writer_with_pdfium(&mut cursor, options)?;
+
+            let mut bytes = cursor.into_inner();
+
+            // Link destinations, opacity, and miter limits are best-effort; if they cannot be written,
+            // for instance because the document is encrypted, then rather than failing
+            // the save, leave them unresolved.
+
+            let is_update_required = self.metadata.is_modified()
+                || self.xmp_metadata.is_some()
+                || self.bookmarks.is_modified();
+
+            let update = {
+                let document = match PdfRawDocument::parse(bytes.as_slice()) {
+                    Some(document) => document,
+                    None if !is_update_required => {
+                        return writer
+                            .write_all(bytes.as_slice())
+                            .map_err(PdfiumError::IoError);
+                    }
+                    None => return Err(PdfiumError::DocumentMetadataUpdateNotSupported),
+                };
+
+                let mut update = match PdfIncrementalUpdate::new(&document) {
+                    Ok(update) => update,
+                    Err(_) if !is_update_required => {
+                        return writer
+                            .write_all(bytes.as_slice())
+                            .map_err(PdfiumError::IoError);
+                    }
+                    Err(err) => return Err(err),
+                };
+
+                if has_page_destinations {
+                    add_resolved_page_destinations_to_update(&document, &mut update);
+                }
+
+                if may_have_graphics_state_marks {
+                    add_graphics_states_to_update(&document, &mut update);
+                }
+
+                if self.metadata.is_modified() {
+                    self.metadata.add_info_dictionary_to_update(&mut update);
+                }
+
+                // Both the outline and the XMP metadata packet are referenced from the
+                // document catalog, so the catalog is replaced at most once.
+
+                let mut catalog_entries = Vec::new();
+
+                if self.bookmarks.is_modified() {
+                    let outlines = self
+                        .bookmarks
+                        .add_outline_to_update(&document, &mut update)?;
+
+                    catalog_entries.push(("Outlines", format!("{} 0 R", outlines)));
+                }
+
+                if let Some(xml) = self.xmp_metadata.as_ref() {
+                    let mut stream = format!(
+                        "<< /Type /Metadata /Subtype /XML /Length {} >>\nstream\n",
+                        xml.len()
+                    )
+                    .into_bytes();
+
+                    stream.extend_from_slice(xml.as_bytes());
+                    stream.extend_from_slice(b"\nendstream");
+
+                    let metadata = update.add_object(stream);
+
+                    catalog_entries.push(("Metadata", format!("{} 0 R", metadata)));
+                }
+
+                if !catalog_entries.is_empty() {
+                    let root = document
+                        .root_object_number()
+                        .ok_or(PdfiumError::DocumentMetadataUpdateNotSupported)?;
+
+                    let mut catalog = document
+                        .object_dictionary(root)
+                        .ok_or(PdfiumError::DocumentMetadataUpdateNotSupported)?;
+
+                    for (key, value) in catalog_entries {
+                        set(&mut catalog, key, value);
+                    }
+
+                    update.replace_object(root, to_dictionary_string(&catalog).into_bytes());
+                }
+
+                update
+            };
+
+            update.append_to(&mut bytes);
+
+            writer
+                .write_all(bytes.as_slice())
+                .map_err(PdfiumError::IoError)
+        } else {
+            self.save_to_writer_with_pdfium(writer, options)
+        }
+    }
+
+    /// Writes the document data held by Pdfium for this [PdfDocument] to the given writer.
+    fn save_to_writer_with_pdfium<W: Write + 'static>(
+        &self,
+        writer: &mut W,
+        options: &PdfSaveOptions,
+    ) -> Result<(), PdfiumError> {
+        let flags = options.as_pdfium()?;
 
         let mut pdfium_file_writer = get_pdfium_file_writer_from_writer(writer);
 
-        let result = match self.output_version {
+        let result = match options.get_version().or(self.output_version) {
             Some(version) => self.bindings.FPDF_SaveWithVersion(
                 self.handle,
                 pdfium_file_writer.as_fpdf_file_write_mut_ptr(),
@@ -340,13 +1250,20 @@ impl<'a> PdfDocument<'a> {
             true => {
                 // Pdfium's return value indicated success. Flush the buffer.
 
-                pdfium_file_writer.flush().map_err(PdfiumError::IoError)
+                pdfium_file_writer.flush().map_err(PdfiumError::IoError)?;
+
+                if let Some(form) = self.form.as_ref() {
+                    form.mark_changes_saved();
+                }
+
+                Ok(())
             }
             false => {
                 // Pdfium's return value indicated failure.
 
-                Err(PdfiumError::PdfiumLibraryInternalError(
-                    PdfiumInternalError::Unknown,
+                Err(self.bindings.error_in(
+                    "FPDF_SaveAsCopy",
+                    PdfiumError::PdfiumLibraryInternalError(PdfiumInternalError::Unknown),
                 ))
             }
         }
@@ -374,6 +1291,19 @@ impl<'a> PdfDocument<'a> {
         Ok(cursor.into_inner())
     }
 
+    /// Writes this [PdfDocument] to a new byte buffer using the given [PdfSaveOptions],
+    /// returning the byte buffer.
+    pub fn save_to_bytes_with_options(
+        &self,
+        options: &PdfSaveOptions,
+    ) -> Result<Vec<u8>, PdfiumError> {
+        let mut cursor = Cursor::new(Vec::new());
+
+        self.save_to_writer_with_options(&mut cursor, options)?;
+
+        Ok(cursor.into_inner())
+    }
+
     /// Writes this [PdfDocument] to a new `Blob`, returning the `Blob`.
     ///
     /// This function is only available when compiling to WASM.
@@ -403,6 +1333,9 @@ impl<'a> Drop for PdfDocument<'a> {
         // avoiding a segmentation fault when using Pdfium builds compiled with V8/XFA support.
 
         self.form = None;
+        forget_page_destinations_to_resolve(self.handle);
+        forget_opacity_marks(self.handle);
+        forget_miter_limits_to_write(self.handle);
         self.bindings.FPDF_CloseDocument(self.handle);
     }
 }
@@ -421,3 +1354,220 @@ unsafe impl<'a> Sync for PdfDocument<'a> {}
 
 #[cfg(feature = "sync")]
 unsafe impl<'a> Send for PdfDocument<'a> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::utils::test::test_bind_to_pdfium;
+
+    #[test]
+    #[cfg(feature = "sync")]
+    fn test_render_pages_from_two_threads() -> Result<(), PdfiumError> {
+        // Checks that two threads sharing the same PdfDocument can take turns rendering
+        // its pages. Every call to Pdfium is serialized by the thread_safe bindings.
+
+        use std::sync::Mutex;
+
+        let pdfium = test_bind_to_pdfium();
+
+        let document = pdfium.load_pdf_from_file("./test/export-test.pdf", None)?;
+
+        let page_count = document.pages().len();
+
+        let render_config = PdfRenderConfig::new().set_target_width(400);
+
+        let rendered = Mutex::new(Vec::new());
+
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(2)
+            .build()
+            .unwrap();
+
+        pool.scope(|scope| {
+            for first_index in 0..2 {
+                let document = &document;
+                let render_config = &render_config;
+                let rendered = &rendered;
+
+                scope.spawn(move |_| {
+                    for _ in 0..3 {
+                        for index in (first_index..page_count).step_by(2) {
+                            let page = document.pages().get(index).unwrap();
+
+                            let bitmap = page.render_with_config(render_config).unwrap();
+
+                            rendered.lock().unwrap().push((index, bitmap.width()));
+                        }
+                    }
+                });
+            }
+        });
+
+        let rendered = rendered.into_inner().unwrap();
+
+        assert_eq!(rendered.len(), page_count as usize * 3);
+        assert!(rendered.iter().all(|(_, width)| *width == 400));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_incremental_save() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let options = PdfSaveOptions::new().incremental(true);
+
+        let original = {
+            let mut document = pdfium.create_new_pdf()?;
+
+            document
+                .pages_mut()
+                .create_page_at_start(PdfPagePaperSize::a4())?;
+
+            assert!(matches!(
+                document.save_to_bytes_with_options(&options),
+                Err(PdfiumError::IncrementalSaveRequiresLoadedDocument)
+            ));
+
+            document.save_to_bytes()?
+        };
+
+        let document = pdfium.load_pdf_from_byte_slice(original.as_slice(), None)?;
+
+        let saved = document.save_to_bytes_with_options(&options)?;
+
+        assert!(saved.starts_with(original.as_slice()));
+
+        assert!(matches!(
+            document.save_to_bytes_with_options(&options.remove_security(true)),
+            Err(PdfiumError::IncrementalSaveIncompatibleWithRemoveSecurity)
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_file_identifier() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let document = pdfium.load_pdf_from_file("./test/export-test.pdf", None)?;
+
+        assert_eq!(
+            document.file_identifier(PdfDocumentFileIdentifierType::Permanent),
+            Some(vec![
+                0x04, 0x4a, 0x1d, 0xd7, 0x24, 0x19, 0xde, 0x65, 0x21, 0x82, 0xa5, 0xec, 0xdb, 0x7b,
+                0x9d, 0x08
+            ])
+        );
+
+        assert_eq!(
+            document.file_identifier(PdfDocumentFileIdentifierType::Changing),
+            Some(vec![
+                0x94, 0x36, 0x4b, 0xcd, 0xe7, 0x5d, 0xe1, 0x1c, 0x8b, 0x4b, 0xa7, 0xee, 0x92, 0x0a,
+                0x06, 0xb6
+            ])
+        );
+
+        // A newly created document has no file identifier.
+
+        let document = pdfium.create_new_pdf()?;
+
+        assert_eq!(
+            document.file_identifier(PdfDocumentFileIdentifierType::Permanent),
+            None
+        );
+        assert_eq!(
+            document.file_identifier(PdfDocumentFileIdentifierType::Changing),
+            None
+        );
+        assert!(document.trailer_ends().is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_trailer_ends_after_incremental_updates() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let options = PdfSaveOptions::new().incremental(true);
+
+        let original = std::fs::read("./test/export-test.pdf").map_err(PdfiumError::IoError)?;
+
+        let original_trailer_count = pdfium
+            .load_pdf_from_byte_slice(original.as_slice(), None)?
+            .trailer_ends()
+            .len();
+
+        assert!(original_trailer_count >= 1);
+
+        // Apply two incremental updates, each of which should append a new trailer.
+
+        let mut bytes = original;
+
+        for _ in 0..2 {
+            let document = pdfium.load_pdf_from_byte_vec(bytes, None)?;
+
+            document
+                .pages()
+                .first()?
+                .objects_mut()
+                .create_path_object_rect(
+                    PdfRect::new_from_values(0.0, 0.0, 100.0, 100.0),
+                    None,
+                    None,
+                    Some(PdfColor::RED),
+                )?;
+
+            bytes = document.save_to_bytes_with_options(&options)?;
+        }
+
+        let document = pdfium.load_pdf_from_byte_slice(bytes.as_slice(), None)?;
+
+        let trailer_ends = document.trailer_ends();
+
+        assert_eq!(trailer_ends.len(), original_trailer_count + 2);
+        assert!(trailer_ends.len() > 1);
+        assert!(trailer_ends.windows(2).all(|pair| pair[0] < pair[1]));
+        assert!(*trailer_ends.last().unwrap() as usize <= bytes.len());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_xmp_metadata_round_trip() -> Result<(), PdfiumError> {
+        const PACKET: &str = "<?xpacket begin=\"\u{feff}\" id=\"W5M0MpCehiHzreSzNTczkc9d\"?>\
+            <x:xmpmeta xmlns:x=\"adobe:ns:meta/\"><rdf:RDF \
+            xmlns:rdf=\"http://www.w3.org/1999/02/22-rdf-syntax-ns#\"/></x:xmpmeta>\
+            <?xpacket end=\"w\"?>";
+
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        document
+            .pages_mut()
+            .create_page_at_start(PdfPagePaperSize::a4())?;
+
+        assert_eq!(document.xmp_metadata(), None);
+
+        document.set_xmp_metadata(PACKET)?;
+
+        let bytes = document.save_to_bytes()?;
+
+        assert!(bytes
+            .windows(PACKET.len())
+            .any(|window| window == PACKET.as_bytes()));
+
+        // Re-saving a reloaded document without changes must preserve the packet exactly.
+
+        let document = pdfium.load_pdf_from_byte_vec(bytes, None)?;
+
+        assert_eq!(document.xmp_metadata().as_deref(), Some(PACKET));
+
+        let document = pdfium.load_pdf_from_byte_vec(document.save_to_bytes()?, None)?;
+
+        assert_eq!(document.xmp_metadata().as_deref(), Some(PACKET));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,220 @@
+//! Defines the [PdfAccessibilitySummary] struct, a summary of the accessibility features
+//! of a `PdfDocument` suitable for compliance reporting.
+
+use crate::error::PdfiumError;
+use crate::pdf::document::page::object::{PdfPageObject, PdfPageObjectCommon, PdfPageObjectType};
+use crate::pdf::document::page::objects::common::PdfPageObjectsCommon;
+use crate::pdf::document::page::PdfPage;
+use crate::pdf::document::pages::PdfPageIndex;
+use crate::pdf::document::PdfDocument;
+use std::collections::HashSet;
+
+/// A summary of the accessibility features of a [PdfDocument], as returned by the
+/// [PdfDocument::accessibility_summary()] function.
+///
+/// The summary is a quick indication of whether a document is likely to be usable with
+/// assistive technologies such as screen readers; it is not a full accessibility audit.
+/// Each part of the summary can also be retrieved individually from the [PdfDocument],
+/// so that slower checks can be skipped:
+///
+/// * [PdfDocument::is_tagged()]
+/// * [PdfDocument::language()]
+/// * [PdfDocument::pages_with_images_missing_alt_text()]
+/// * [PdfDocument::has_embedded_fonts_for_all_text()]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PdfAccessibilitySummary {
+    is_tagged: bool,
+    has_language: bool,
+    page_count: PdfPageIndex,
+    pages_with_images_missing_alt_text: Vec<PdfPageIndex>,
+    has_embedded_fonts_for_all_text: bool,
+}
+
+impl PdfAccessibilitySummary {
+    /// Summarizes the accessibility features of the given [PdfDocument].
+    pub(crate) fn from_document(document: &PdfDocument) -> Result<Self, PdfiumError> {
+        Ok(PdfAccessibilitySummary {
+            is_tagged: document.is_tagged(),
+            has_language: document.language()?.is_some(),
+            page_count: document.pages().len() as PdfPageIndex,
+            pages_with_images_missing_alt_text: document.pages_with_images_missing_alt_text()?,
+            has_embedded_fonts_for_all_text: document.has_embedded_fonts_for_all_text()?,
+        })
+    }
+
+    /// Returns `true` if the document claims to be tagged, i.e. its logical structure tree
+    /// is intended to describe all of its content.
+    #[inline]
+    pub fn is_tagged(&self) -> bool {
+        self.is_tagged
+    }
+
+    /// Returns `true` if the document declares the natural language of its content.
+    #[inline]
+    pub fn has_language(&self) -> bool {
+        self.has_language
+    }
+
+    /// Returns the number of pages in the document.
+    #[inline]
+    pub fn page_count(&self) -> PdfPageIndex {
+        self.page_count
+    }
+
+    /// Returns the indices of the pages containing at least one image that is neither
+    /// described by alternative text nor marked as a decorative artifact.
+    #[inline]
+    pub fn pages_with_images_missing_alt_text(&self) -> &[PdfPageIndex] {
+        self.pages_with_images_missing_alt_text.as_slice()
+    }
+
+    /// Returns `true` if the fonts used by every text object in the document are embedded
+    /// in the document.
+    #[inline]
+    pub fn has_embedded_fonts_for_all_text(&self) -> bool {
+        self.has_embedded_fonts_for_all_text
+    }
+}
+
+/// Returns `true` if the given [PdfPage] contains at least one image that is neither described
+/// by alternative text in the page's structure tree nor marked as a decorative artifact.
+///
+/// An image is described if it lies inside a marked content sequence belonging to a structure
+/// element, or a descendant of a structure element, that has alternative text.
+pub(crate) fn has_images_missing_alt_text(page: &PdfPage) -> Result<bool, PdfiumError> {
+    let tree = page.struct_tree()?;
+
+    let mut described = HashSet::new();
+
+    let mut stack = tree
+        .children()
+        .map(|element| (element, false))
+        .collect::<Vec<_>>();
+
+    while let Some((element, is_parent_described)) = stack.pop() {
+        let is_described = is_parent_described
+            || element
+                .alt_text()
+                .map(|alt_text| !alt_text.trim().is_empty())
+                .unwrap_or(false);
+
+        if is_described {
+            described.extend(element.marked_content_ids());
+        }
+
+        stack.extend(element.children().map(|child| (child, is_described)));
+    }
+
+    let mut is_missing = false;
+
+    for_each_object(page, |object| {
+        if object.object_type() == PdfPageObjectType::Image {
+            let marks = object.marks();
+
+            let is_artifact = marks.iter().any(|mark| mark.name() == "Artifact");
+
+            let is_described = marks
+                .iter()
+                .filter_map(|mark| mark.int_param("MCID"))
+                .any(|id| described.contains(&id));
+
+            if !is_artifact && !is_described {
+                is_missing = true;
+            }
+        }
+
+        Ok(())
+    })?;
+
+    Ok(is_missing)
+}
+
+/// Returns `true` if the fonts used by every text object on the given [PdfPage]
+/// are embedded in the containing document.
+pub(crate) fn has_embedded_fonts_for_all_text(page: &PdfPage) -> Result<bool, PdfiumError> {
+    let mut is_embedded = true;
+
+    for_each_object(page, |object| {
+        if let Some(text) = object.as_text_object() {
+            if !text.font().is_embedded()? {
+                is_embedded = false;
+            }
+        }
+
+        Ok(())
+    })?;
+
+    Ok(is_embedded)
+}
+
+/// Calls the given callback for every page object on the given [PdfPage],
+/// including the children of form objects.
+fn for_each_object(
+    page: &PdfPage,
+    mut callback: impl FnMut(&PdfPageObject) -> Result<(), PdfiumError>,
+) -> Result<(), PdfiumError> {
+    for object in page.objects().iter() {
+        if let PdfPageObject::XObjectForm(form) = &object {
+            for (child, _) in form.objects().iter_recursive() {
+                callback(&child)?;
+            }
+        } else {
+            callback(&object)?;
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::utils::test::test_bind_to_pdfium;
+
+    #[test]
+    fn test_accessibility_summary() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let tagged = pdfium.load_pdf_from_file("./test/accessibility-tagged-test.pdf", None)?;
+
+        assert!(tagged.is_tagged());
+        assert_eq!(tagged.language()?.as_deref(), Some("en-US"));
+
+        // The image on the first page is described by alternative text, and the image
+        // on the third page is a decorative artifact. The image on the second page
+        // belongs to a figure without alternative text.
+
+        assert_eq!(tagged.pages_with_images_missing_alt_text()?, vec![1]);
+
+        // The tagged document contains no text.
+
+        assert!(tagged.has_embedded_fonts_for_all_text()?);
+
+        let summary = tagged.accessibility_summary()?;
+
+        assert!(summary.is_tagged());
+        assert!(summary.has_language());
+        assert_eq!(summary.page_count(), 3);
+        assert_eq!(summary.pages_with_images_missing_alt_text(), &[1]);
+        assert!(summary.has_embedded_fonts_for_all_text());
+
+        let untagged = pdfium.load_pdf_from_file("./test/accessibility-untagged-test.pdf", None)?;
+
+        assert!(!untagged.is_tagged());
+        assert!(untagged.language()?.is_none());
+
+        // The untagged document uses the standard Helvetica font without embedding it.
+
+        let summary = untagged.accessibility_summary()?;
+
+        assert!(!summary.is_tagged());
+        assert!(!summary.has_language());
+        assert_eq!(summary.page_count(), 1);
+        assert_eq!(summary.pages_with_images_missing_alt_text(), &[0]);
+        assert!(!summary.has_embedded_fonts_for_all_text());
+
+        assert_ne!(summary, tagged.accessibility_summary()?);
+
+        Ok(())
+    }
+}
@@ -1,11 +1,15 @@
 //! Defines the [PdfAttachment] struct, exposing functionality related to a single
 //! attachment in a `PdfAttachments` collection.
 
-use crate::bindgen::{FPDF_ATTACHMENT, FPDF_WCHAR};
+use crate::bindgen::{
+    FPDF_ATTACHMENT, FPDF_DOCUMENT, FPDF_OBJECT_NAME, FPDF_OBJECT_STRING, FPDF_WCHAR,
+};
 use crate::bindings::PdfiumLibraryBindings;
-use crate::error::PdfiumError;
+use crate::error::{PdfiumError, PdfiumInternalError};
+use crate::utils::dates::date_time_to_pdf_string;
 use crate::utils::mem::create_byte_buffer;
 use crate::utils::utf16le::get_string_from_pdfium_utf16le_bytes;
+use chrono::prelude::*;
 use std::io::Write;
 use std::os::raw::{c_ulong, c_void};
 
@@ -32,6 +36,7 @@ use crate::pdf::document::PdfDocument;
 /// A single attached data file embedded in a [PdfDocument].
 pub struct PdfAttachment<'a> {
     handle: FPDF_ATTACHMENT,
+    document_handle: FPDF_DOCUMENT,
     bindings: &'a dyn PdfiumLibraryBindings,
 }
 
@@ -39,9 +44,14 @@ impl<'a> PdfAttachment<'a> {
     #[inline]
     pub(crate) fn from_pdfium(
         handle: FPDF_ATTACHMENT,
+        document_handle: FPDF_DOCUMENT,
         bindings: &'a dyn PdfiumLibraryBindings,
     ) -> Self {
-        PdfAttachment { handle, bindings }
+        PdfAttachment {
+            handle,
+            document_handle,
+            bindings,
+        }
     }
 
     /// Returns the [PdfiumLibraryBindings] used by this [PdfAttachment].
@@ -84,6 +94,157 @@ impl<'a> PdfAttachment<'a> {
         get_string_from_pdfium_utf16le_bytes(buffer).unwrap_or_default()
     }
 
+    /// Returns the string value associated with the given key in the parameters dictionary
+    /// of this [PdfAttachment], if any.
+    pub fn string_value(&self, key: &str) -> Option<String> {
+        if !self
+            .bindings()
+            .is_true(self.bindings().FPDFAttachment_HasKey(self.handle, key))
+        {
+            // The key does not exist.
+
+            return None;
+        }
+
+        let value_type = self
+            .bindings()
+            .FPDFAttachment_GetValueType(self.handle, key) as u32;
+
+        if value_type != FPDF_OBJECT_STRING && value_type != FPDF_OBJECT_NAME {
+            // The key exists, but the value associated with the key is not a string.
+
+            return None;
+        }
+
+        // Retrieving the string value from Pdfium is a two-step operation. First, we call
+        // FPDFAttachment_GetStringValue() with a null buffer; this will retrieve the length of
+        // the value in bytes, assuming the key exists. If the length is zero, then there
+        // is no such key, or the key's value is not a string.
+
+        // If the length is non-zero, then we reserve a byte buffer of the given
+        // length and call FPDFAttachment_GetStringValue() again with a pointer to the buffer;
+        // this will write the string value into the buffer.
+
+        let buffer_length = self.bindings().FPDFAttachment_GetStringValue(
+            self.handle,
+            key,
+            std::ptr::null_mut(),
+            0,
+        );
+
+        if buffer_length <= 2 {
+            // A buffer length of 2 indicates that the string value for the given key is
+            // an empty UTF16-LE string, so there is no point in retrieving it.
+
+            return None;
+        }
+
+        let mut buffer = create_byte_buffer(buffer_length as usize);
+
+        let result = self.bindings().FPDFAttachment_GetStringValue(
+            self.handle,
+            key,
+            buffer.as_mut_ptr() as *mut FPDF_WCHAR,
+            buffer_length,
+        );
+
+        assert_eq!(result, buffer_length);
+
+        Some(get_string_from_pdfium_utf16le_bytes(buffer).unwrap_or_default())
+    }
+
+    /// Sets the string value associated with the given key in the parameters dictionary
+    /// of this [PdfAttachment], overwriting any existing value.
+    pub fn set_string_value(&mut self, key: &str, value: &str) -> Result<(), PdfiumError> {
+        if self
+            .bindings()
+            .is_true(
+                self.bindings()
+                    .FPDFAttachment_SetStringValue_str(self.handle, key, value),
+            )
+        {
+            Ok(())
+        } else {
+            Err(self.bindings().error_in(
+                "FPDFAttachment_SetStringValue_str",
+                PdfiumError::PdfiumLibraryInternalError(PdfiumInternalError::Unknown),
+            ))
+        }
+    }
+
+    /// Returns the date and time when the file data in this [PdfAttachment] was created, if any.
+    ///
+    /// Pdfium sets this value automatically whenever the file data of an attachment is replaced.
+    #[inline]
+    pub fn creation_date(&self) -> Option<String> {
+        self.string_value("CreationDate")
+    }
+
+    /// Sets the date and time when the file data in this [PdfAttachment] was created.
+    #[inline]
+    pub fn set_creation_date(&mut self, date: DateTime<Utc>) -> Result<(), PdfiumError> {
+        self.set_string_value("CreationDate", &date_time_to_pdf_string(date))
+    }
+
+    /// Returns the date and time when the file data in this [PdfAttachment] was last
+    /// modified, if any.
+    #[inline]
+    pub fn modification_date(&self) -> Option<String> {
+        self.string_value("ModDate")
+    }
+
+    /// Sets the date and time when the file data in this [PdfAttachment] was last modified.
+    ///
+    /// Pdfium removes this value whenever the file data of an attachment is replaced,
+    /// so it should be set after calling [PdfAttachment::set_data()].
+    #[inline]
+    pub fn set_modification_date(&mut self, date: DateTime<Utc>) -> Result<(), PdfiumError> {
+        self.set_string_value("ModDate", &date_time_to_pdf_string(date))
+    }
+
+    /// Returns the MD5 checksum of the file data in this [PdfAttachment], if any,
+    /// as a hexadecimal string.
+    ///
+    /// Pdfium sets this value automatically whenever the file data of an attachment is replaced.
+    #[inline]
+    pub fn checksum(&self) -> Option<String> {
+        self.string_value("CheckSum")
+    }
+
+    /// Returns the file data embedded in this [PdfAttachment]. The returned buffer will be
+    /// empty if the attachment has no data, or if the data could not be read.
+    ///
+    /// To distinguish between an attachment with no data and an attachment whose data
+    /// could not be read, use the [PdfAttachment::save_to_bytes()] function.
+    #[inline]
+    pub fn data(&self) -> Vec<u8> {
+        self.save_to_bytes().unwrap_or_default()
+    }
+
+    /// Replaces the file data embedded in this [PdfAttachment] with the given bytes.
+    ///
+    /// Pdfium updates the creation date and checksum of the attachment and removes all
+    /// other entries from the attachment's parameters dictionary, including any
+    /// modification date.
+    pub fn set_data(&mut self, bytes: &[u8]) -> Result<(), PdfiumError> {
+        if self
+            .bindings()
+            .is_true(self.bindings().FPDFAttachment_SetFile(
+                self.handle,
+                self.document_handle,
+                bytes.as_ptr() as *const c_void,
+                bytes.len() as c_ulong,
+            ))
+        {
+            Ok(())
+        } else {
+            Err(self.bindings().error_in(
+                "FPDFAttachment_SetFile",
+                PdfiumError::PdfiumLibraryInternalError(PdfiumInternalError::Unknown),
+            ))
+        }
+    }
+
     /// Returns the size of this [PdfAttachment] in bytes.
     pub fn len(&self) -> usize {
         // Calling FPDFAttachment_GetFile() with a null buffer will retrieve the length of the
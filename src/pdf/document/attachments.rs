@@ -7,7 +7,7 @@ use crate::error::{PdfiumError, PdfiumInternalError};
 use crate::pdf::document::attachment::PdfAttachment;
 use std::io::Read;
 use std::ops::{Range, RangeInclusive};
-use std::os::raw::{c_int, c_ulong, c_void};
+use std::os::raw::c_int;
 
 #[cfg(not(target_arch = "wasm32"))]
 use {std::fs::File, std::path::Path};
@@ -97,11 +97,16 @@ impl<'a> PdfAttachments<'a> {
             .FPDFDoc_GetAttachment(self.document_handle, index as c_int);
 
         if handle.is_null() {
-            Err(PdfiumError::PdfiumLibraryInternalError(
-                PdfiumInternalError::Unknown,
+            Err(self.bindings().error_in(
+                "FPDFDoc_GetAttachment",
+                PdfiumError::PdfiumLibraryInternalError(PdfiumInternalError::Unknown),
             ))
         } else {
-            Ok(PdfAttachment::from_pdfium(handle, self.bindings()))
+            Ok(PdfAttachment::from_pdfium(
+                handle,
+                self.document_handle,
+                self.bindings(),
+            ))
         }
     }
 
@@ -121,32 +126,32 @@ impl<'a> PdfAttachments<'a> {
             .FPDFDoc_AddAttachment_str(self.document_handle, name);
 
         if handle.is_null() {
-            Err(PdfiumError::PdfiumLibraryInternalError(
-                PdfiumInternalError::Unknown,
+            Err(self.bindings().error_in(
+                "FPDFDoc_AddAttachment_str",
+                PdfiumError::PdfiumLibraryInternalError(PdfiumInternalError::Unknown),
             ))
         } else {
             // With the FPDF_ATTACHMENT correctly created, we can now apply the byte data to the attachment.
 
-            if self
-                .bindings()
-                .is_true(self.bindings().FPDFAttachment_SetFile(
-                    handle,
-                    self.document_handle,
-                    bytes.as_ptr() as *const c_void,
-                    bytes.len() as c_ulong,
-                ))
-            {
-                Ok(PdfAttachment::from_pdfium(handle, self.bindings))
-            } else {
-                // The return value from FPDFAttachment_SetFile() indicates failure.
-
-                Err(PdfiumError::PdfiumLibraryInternalError(
-                    PdfiumInternalError::Unknown,
-                ))
-            }
+            let mut attachment =
+                PdfAttachment::from_pdfium(handle, self.document_handle, self.bindings);
+
+            attachment.set_data(bytes)?;
+
+            Ok(attachment)
         }
     }
 
+    /// Attempts to add a new [PdfAttachment] to this collection, using the given name and the
+    /// data in the given byte buffer. An error will be returned if the given name is not
+    /// unique in the list of attachments already present in the containing PDF document.
+    ///
+    /// This is a shorter alias for the [PdfAttachments::create_attachment_from_bytes()] function.
+    #[inline]
+    pub fn create(&mut self, name: &str, data: &[u8]) -> Result<PdfAttachment, PdfiumError> {
+        self.create_attachment_from_bytes(name, data)
+    }
+
     /// Attempts to add a new [PdfAttachment] to this collection, using the given name and file path.
     /// Byte data from the given file path will be embedded directly into the containing document.
     /// An error will be returned if the given name is not unique in the list of attachments
@@ -275,12 +280,21 @@ impl<'a> PdfAttachments<'a> {
         ) {
             Ok(())
         } else {
-            Err(PdfiumError::PdfiumLibraryInternalError(
-                PdfiumInternalError::Unknown,
+            Err(self.bindings().error_in(
+                "FPDFDoc_DeleteAttachment",
+                PdfiumError::PdfiumLibraryInternalError(PdfiumInternalError::Unknown),
             ))
         }
     }
 
+    /// Deletes the attachment at the given index from this [PdfAttachments] collection.
+    ///
+    /// This is a shorter alias for the [PdfAttachments::delete_at_index()] function.
+    #[inline]
+    pub fn delete(&mut self, index: PdfAttachmentIndex) -> Result<(), PdfiumError> {
+        self.delete_at_index(index)
+    }
+
     /// Returns an iterator over all the attachments in this [PdfAttachments] collection.
     #[inline]
     pub fn iter(&self) -> PdfAttachmentsIterator {
@@ -315,3 +329,63 @@ impl<'a> Iterator for PdfAttachmentsIterator<'a> {
         next.ok()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::utils::test::test_bind_to_pdfium;
+
+    #[test]
+    fn test_attachments_round_trip() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let invoice = br#"<?xml version="1.0" encoding="UTF-8"?><rsm:CrossIndustryInvoice/>"#;
+
+        let bytes = {
+            let mut document = pdfium.create_new_pdf()?;
+
+            document
+                .pages_mut()
+                .create_page_at_start(PdfPagePaperSize::a4())?;
+
+            let attachments = document.attachments_mut();
+
+            let attachment = attachments.create("factur-x.xml", invoice)?;
+
+            assert_eq!(attachment.data(), invoice.to_vec());
+            assert!(attachment.checksum().is_some());
+
+            attachments.create("empty.txt", &[])?;
+            attachments.create("Rechnungsübersicht-請求書.xml", invoice)?;
+            attachments.create("to-be-deleted.txt", b"temporary")?;
+
+            assert_eq!(attachments.len(), 4);
+
+            attachments.delete(3)?;
+
+            assert_eq!(attachments.len(), 3);
+
+            document.save_to_bytes()?
+        };
+
+        let document = pdfium.load_pdf_from_byte_vec(bytes, None)?;
+
+        let attachments = document.attachments();
+
+        assert_eq!(
+            attachments
+                .iter()
+                .map(|attachment| attachment.name())
+                .collect::<Vec<_>>(),
+            vec!["factur-x.xml", "empty.txt", "Rechnungsübersicht-請求書.xml"]
+        );
+
+        assert_eq!(attachments.get(0)?.data(), invoice.to_vec());
+        assert!(attachments.get(1)?.data().is_empty());
+        assert!(attachments.get(1)?.is_empty());
+        assert_eq!(attachments.get(2)?.data(), invoice.to_vec());
+        assert!(attachments.get(0)?.creation_date().is_some());
+
+        Ok(())
+    }
+}
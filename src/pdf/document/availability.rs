@@ -0,0 +1,705 @@
+//! Defines the [PdfDocumentAvailability] struct, allowing a `PdfDocument` to be loaded
+//! progressively while its data is still being downloaded.
+
+use crate::bindgen::{
+    FPDF_AVAIL, FPDF_BOOL, FX_DOWNLOADHINTS, FX_FILEAVAIL, PDF_DATA_AVAIL, PDF_DATA_ERROR,
+    PDF_LINEARIZED, PDF_NOT_LINEARIZED,
+};
+use crate::bindings::PdfiumLibraryBindings;
+use crate::error::{PdfiumError, PdfiumInternalError};
+use crate::pdf::document::pages::PdfPageIndex;
+use crate::pdf::document::PdfDocument;
+use crate::pdfium::Pdfium;
+use crate::utils::files::{get_pdfium_file_accessor_from_reader, FpdfFileAccessExt};
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+use std::io::{Error, ErrorKind, Read, Seek, SeekFrom};
+use std::ops::Range;
+use std::os::raw::c_int;
+use std::panic::{self, AssertUnwindSafe};
+use std::rc::Rc;
+
+#[cfg(target_arch = "wasm32")]
+use crate::pdf::document::fetch::PdfRangeFetcher;
+
+/// A source of document data that may not yet be completely available, such as a document
+/// that is still being downloaded over a network connection.
+///
+/// Pdfium will call [PdfDataProvider::is_data_available()] before reading any range of
+/// bytes, and will only call [PdfDataProvider::read()] for ranges reported as available.
+pub trait PdfDataProvider {
+    /// Returns the total length of the document data, in bytes. The total length must be
+    /// known in advance, even if only a portion of the data is currently available.
+    fn content_length(&self) -> usize;
+
+    /// Returns `true` if every byte in the given range of document data is currently available.
+    fn is_data_available(&self, range: Range<usize>) -> bool;
+
+    /// Fills the given buffer with the bytes in the given range of document data.
+    /// The length of the buffer always matches the length of the range.
+    fn read(&mut self, range: Range<usize>, buffer: &mut [u8]) -> std::io::Result<()>;
+}
+
+type SharedPdfDataProvider<'a> = Rc<RefCell<Box<dyn PdfDataProvider + 'a>>>;
+
+/// Tracks the availability of the data in a document that is being loaded progressively,
+/// typically while it is being downloaded over a network connection.
+///
+/// Pdfium can begin loading a document, and rendering its first page, before the entire
+/// document has been downloaded, especially if the document is linearized. A typical
+/// progressive loading workflow is:
+/// * Create a [PdfDocumentAvailability] from a [PdfDataProvider] using
+///   `Pdfium::load_pdf_availability_from_provider()`.
+/// * Call [PdfDocumentAvailability::is_document_available()] whenever new data arrives.
+///   If the document is not yet available, fetch the byte ranges returned by
+///   [PdfDocumentAvailability::download_hints()] and try again.
+/// * Once the document is available, call [PdfDocumentAvailability::into_document()] to
+///   retrieve the [PdfDocument].
+/// * Before accessing each page, use [PdfDocument::availability()] to check that the
+///   page is available using [PdfDocumentAvailability::is_page_available()], fetching
+///   the byte ranges returned by [PdfDocumentAvailability::download_hints()] as necessary.
+pub struct PdfDocumentAvailability<'a> {
+    handle: FPDF_AVAIL,
+    download_hints: Box<FxDownloadHintsExt>,
+    bindings: &'a dyn PdfiumLibraryBindings,
+
+    // The file availability and file access structs are only used by Pdfium, but they must
+    // live for at least as long as the availability provider and any document created from it.
+    #[allow(dead_code)]
+    file_avail: Box<FxFileAvailExt<'a>>,
+
+    #[allow(dead_code)]
+    file_access: Box<FpdfFileAccessExt<'a>>,
+
+    #[cfg(target_arch = "wasm32")]
+    fetcher: Option<Rc<PdfRangeFetcher>>,
+}
+
+impl<'a> PdfDocumentAvailability<'a> {
+    pub(crate) fn from_provider(
+        provider: impl PdfDataProvider + 'a,
+        bindings: &'a dyn PdfiumLibraryBindings,
+    ) -> Result<Self, PdfiumError> {
+        let provider: SharedPdfDataProvider<'a> = Rc::new(RefCell::new(Box::new(provider)));
+
+        let mut file_access = get_pdfium_file_accessor_from_reader(PdfDataProviderReader {
+            provider: provider.clone(),
+            position: 0,
+        })?;
+
+        let mut file_avail = Box::new(FxFileAvailExt {
+            version: 1,
+            is_data_avail: Some(is_data_available_from_callback),
+            provider,
+        });
+
+        let handle = bindings.FPDFAvail_Create(
+            file_avail.as_fx_file_avail_mut_ptr(),
+            file_access.as_fpdf_file_access_mut_ptr(),
+        );
+
+        if handle.is_null() {
+            return Err(bindings.error_in(
+                "FPDFAvail_Create",
+                PdfiumError::PdfiumLibraryInternalError(PdfiumInternalError::Unknown),
+            ));
+        }
+
+        Ok(PdfDocumentAvailability {
+            handle,
+            download_hints: Box::new(FxDownloadHintsExt {
+                version: 1,
+                add_segment: Some(add_download_hint_from_callback),
+                hints: RefCell::new(Vec::new()),
+            }),
+            bindings,
+            file_avail,
+            file_access,
+            #[cfg(target_arch = "wasm32")]
+            fetcher: None,
+        })
+    }
+
+    /// Sets the [PdfRangeFetcher] used to download document data for this
+    /// [PdfDocumentAvailability] on demand.
+    #[cfg(target_arch = "wasm32")]
+    #[inline]
+    pub(crate) fn set_fetcher(&mut self, fetcher: Rc<PdfRangeFetcher>) {
+        self.fetcher = Some(fetcher);
+    }
+
+    /// Returns the [PdfiumLibraryBindings] used by this [PdfDocumentAvailability].
+    #[inline]
+    pub fn bindings(&self) -> &'a dyn PdfiumLibraryBindings {
+        self.bindings
+    }
+
+    /// Returns `true` if enough document data is available for the document to be loaded
+    /// using [PdfDocumentAvailability::into_document()].
+    ///
+    /// If the document is not yet available, the byte ranges Pdfium needs in order to
+    /// continue are made available through [PdfDocumentAvailability::download_hints()].
+    pub fn is_document_available(&self) -> Result<bool, PdfiumError> {
+        let result = self.bindings.FPDFAvail_IsDocAvail(
+            self.handle,
+            self.download_hints.as_fx_download_hints_mut_ptr(),
+        );
+
+        Self::availability_to_result(result)
+    }
+
+    /// Returns `true` if enough document data is available for the page at the given index
+    /// to be loaded.
+    ///
+    /// Page availability can only be checked once the document has been retrieved using
+    /// [PdfDocumentAvailability::into_document()]; use [PdfDocument::availability()] to access
+    /// this [PdfDocumentAvailability] from the document. If the page is not yet available,
+    /// the byte ranges Pdfium needs in order to continue are made available through
+    /// [PdfDocumentAvailability::download_hints()].
+    pub fn is_page_available(&self, index: PdfPageIndex) -> Result<bool, PdfiumError> {
+        let result = self.bindings.FPDFAvail_IsPageAvail(
+            self.handle,
+            index as c_int,
+            self.download_hints.as_fx_download_hints_mut_ptr(),
+        );
+
+        Self::availability_to_result(result)
+    }
+
+    /// Downloads the document data needed to load the page at the given index, if it is not
+    /// already available. Pages should be fetched before they are accessed.
+    ///
+    /// Data can only be fetched for documents opened using `Pdfium::load_pdf_from_fetch()`
+    /// from a server that supports HTTP `Range` requests; for any other document,
+    /// [PdfiumError::DocumentDataNotYetAvailable] is returned if the page is not available.
+    ///
+    /// This function is only available when compiling to WASM.
+    #[cfg(any(doc, target_arch = "wasm32"))]
+    pub async fn fetch_page(&self, index: PdfPageIndex) -> Result<(), PdfiumError> {
+        while !self.is_page_available(index)? {
+            let is_fetched = match self.fetcher.as_ref() {
+                Some(fetcher) => fetcher.fetch_hints(self.download_hints()).await?,
+                None => false,
+            };
+
+            if !is_fetched {
+                return Err(PdfiumError::DocumentDataNotYetAvailable);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Returns `Some(true)` if the document is linearized, i.e. structured so that its first
+    /// page can be displayed before the remainder of the document has been downloaded.
+    ///
+    /// Pdfium requires at least the first 1 kB of document data in order to determine
+    /// whether a document is linearized; `None` is returned if this cannot yet be determined.
+    pub fn is_linearized(&self) -> Option<bool> {
+        match self.bindings.FPDFAvail_IsLinearized(self.handle) as u32 {
+            PDF_LINEARIZED => Some(true),
+            PDF_NOT_LINEARIZED => Some(false),
+            _ => None,
+        }
+    }
+
+    /// Returns an iterator over the byte ranges of document data that Pdfium has requested
+    /// since this function was last called, so that the caller knows which data to fetch next.
+    ///
+    /// Ranges may overlap one another, and may include data that is already available.
+    pub fn download_hints(&self) -> impl Iterator<Item = Range<usize>> {
+        self.download_hints.hints.replace(Vec::new()).into_iter()
+    }
+
+    /// Consumes this [PdfDocumentAvailability], returning the [PdfDocument] whose data
+    /// it tracks.
+    ///
+    /// This function should only be called once [PdfDocumentAvailability::is_document_available()]
+    /// has returned `true`; otherwise, [PdfiumError::DocumentDataNotYetAvailable] is returned.
+    /// If the document is password protected, the given password will be used to unlock it.
+    pub fn into_document(self, password: Option<&str>) -> Result<PdfDocument<'a>, PdfiumError> {
+        if !self.is_document_available()? {
+            return Err(PdfiumError::DocumentDataNotYetAvailable);
+        }
+
+        let bindings = self.bindings;
+
+        Pdfium::pdfium_document_handle_to_result(
+            bindings.FPDFAvail_GetDocument(self.handle, password),
+            bindings,
+        )
+        .map(|mut document| {
+            // Give the newly-created document ownership of this availability provider, so that
+            // Pdfium can continue to read from it on an as-needed basis throughout the lifetime
+            // of the document, and so that page availability can continue to be checked.
+
+            document.set_availability(self);
+
+            document
+        })
+    }
+
+    fn availability_to_result(result: c_int) -> Result<bool, PdfiumError> {
+        if result == PDF_DATA_ERROR {
+            Err(PdfiumError::PdfiumLibraryInternalError(
+                PdfiumInternalError::Unknown,
+            ))
+        } else {
+            Ok(result as u32 == PDF_DATA_AVAIL)
+        }
+    }
+}
+
+impl<'a> Drop for PdfDocumentAvailability<'a> {
+    /// Closes this [PdfDocumentAvailability], releasing held memory.
+    #[inline]
+    fn drop(&mut self) {
+        self.bindings.FPDFAvail_Destroy(self.handle);
+    }
+}
+
+/// Adapts a [PdfDataProvider] to the `Read` and `Seek` traits, so that it can be used
+/// as an input source for Pdfium's file access callback function.
+struct PdfDataProviderReader<'a> {
+    provider: SharedPdfDataProvider<'a>,
+    position: usize,
+}
+
+impl<'a> Read for PdfDataProviderReader<'a> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let mut provider = self
+            .provider
+            .try_borrow_mut()
+            .map_err(|err| Error::new(ErrorKind::Other, err))?;
+
+        let end = (self.position + buf.len()).min(provider.content_length());
+
+        if end <= self.position {
+            return Ok(0);
+        }
+
+        let length = end - self.position;
+
+        provider.read(self.position..end, &mut buf[..length])?;
+
+        self.position = end;
+
+        Ok(length)
+    }
+}
+
+impl<'a> Seek for PdfDataProviderReader<'a> {
+    fn seek(&mut self, pos: SeekFrom) -> std::io::Result<u64> {
+        let content_length = self
+            .provider
+            .try_borrow()
+            .map_err(|err| Error::new(ErrorKind::Other, err))?
+            .content_length() as i64;
+
+        let position = match pos {
+            SeekFrom::Start(offset) => offset as i64,
+            SeekFrom::End(offset) => content_length + offset,
+            SeekFrom::Current(offset) => self.position as i64 + offset,
+        };
+
+        if position < 0 {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "invalid seek to a negative position",
+            ));
+        }
+
+        self.position = position as usize;
+
+        Ok(self.position as u64)
+    }
+}
+
+/// A [PdfDataProvider] serving document data from byte ranges that are downloaded on demand.
+/// Only the downloaded ranges are retained in memory; adjacent and overlapping ranges are
+/// merged as they are inserted. Cloned instances share the same downloaded data.
+#[cfg_attr(not(target_arch = "wasm32"), allow(dead_code))]
+// This struct is only used when compiling to WASM.
+#[derive(Clone)]
+pub(crate) struct PdfRangeBuffer {
+    content_length: usize,
+    ranges: Rc<RefCell<BTreeMap<usize, Vec<u8>>>>,
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), allow(dead_code))]
+impl PdfRangeBuffer {
+    /// Creates a new, empty [PdfRangeBuffer] for document data of the given total length.
+    pub(crate) fn new(content_length: usize) -> Self {
+        PdfRangeBuffer {
+            content_length,
+            ranges: Rc::new(RefCell::new(BTreeMap::new())),
+        }
+    }
+
+    /// Adds the given bytes, downloaded from the given starting offset, to this buffer.
+    /// Bytes beyond the end of the document data are discarded.
+    pub(crate) fn insert(&self, start: usize, mut bytes: Vec<u8>) {
+        let end = (start + bytes.len()).min(self.content_length);
+
+        if end <= start {
+            return;
+        }
+
+        bytes.truncate(end - start);
+
+        let mut ranges = self.ranges.borrow_mut();
+
+        // Find every existing range that overlaps or touches the new range.
+
+        let touching = ranges
+            .range(..=end)
+            .filter(|(existing_start, existing)| **existing_start + existing.len() >= start)
+            .map(|(existing_start, _)| *existing_start)
+            .collect::<Vec<_>>();
+
+        if touching.is_empty() {
+            ranges.insert(start, bytes);
+
+            return;
+        }
+
+        let merged_start = start.min(touching[0]);
+
+        let mut merged_end = end;
+
+        let existing = touching
+            .iter()
+            .filter_map(|existing_start| ranges.remove_entry(existing_start))
+            .collect::<Vec<_>>();
+
+        for (existing_start, existing) in existing.iter() {
+            merged_end = merged_end.max(existing_start + existing.len());
+        }
+
+        let mut merged = vec![0; merged_end - merged_start];
+
+        for (existing_start, existing) in existing.iter() {
+            let offset = existing_start - merged_start;
+
+            merged[offset..offset + existing.len()].copy_from_slice(existing);
+        }
+
+        merged[start - merged_start..end - merged_start].copy_from_slice(&bytes);
+
+        ranges.insert(merged_start, merged);
+    }
+
+    /// Returns the sub-ranges of the given range that have not yet been downloaded.
+    pub(crate) fn missing(&self, range: Range<usize>) -> Vec<Range<usize>> {
+        let end = range.end.min(self.content_length);
+
+        let mut position = range.start;
+
+        let mut result = Vec::new();
+
+        for (start, bytes) in self.ranges.borrow().range(..end) {
+            if position >= end {
+                break;
+            }
+
+            if start + bytes.len() <= position {
+                continue;
+            }
+
+            if *start > position {
+                result.push(position..*start);
+            }
+
+            position = position.max(start + bytes.len());
+        }
+
+        if position < end {
+            result.push(position..end);
+        }
+
+        result
+    }
+
+    /// Returns the ranges that should be downloaded in order to satisfy the given download
+    /// hints. Each hint is widened to whole chunks of the given size, ranges that have already
+    /// been downloaded are excluded, and the remaining ranges are merged where they touch.
+    pub(crate) fn ranges_to_fetch(
+        &self,
+        hints: impl IntoIterator<Item = Range<usize>>,
+        chunk_size: usize,
+    ) -> Vec<Range<usize>> {
+        let chunk_size = chunk_size.max(1);
+
+        let mut ranges = hints
+            .into_iter()
+            .filter(|hint| hint.start < hint.end)
+            .flat_map(|hint| {
+                let start = hint.start / chunk_size * chunk_size;
+
+                let end = ((hint.end + chunk_size - 1) / chunk_size * chunk_size)
+                    .min(self.content_length);
+
+                self.missing(start..end)
+            })
+            .collect::<Vec<_>>();
+
+        ranges.sort_by_key(|range| range.start);
+
+        let mut result: Vec<Range<usize>> = Vec::with_capacity(ranges.len());
+
+        for range in ranges {
+            match result.last_mut() {
+                Some(last) if range.start <= last.end => last.end = last.end.max(range.end),
+                _ => result.push(range),
+            }
+        }
+
+        result
+    }
+}
+
+impl PdfDataProvider for PdfRangeBuffer {
+    #[inline]
+    fn content_length(&self) -> usize {
+        self.content_length
+    }
+
+    #[inline]
+    fn is_data_available(&self, range: Range<usize>) -> bool {
+        self.missing(range).is_empty()
+    }
+
+    fn read(&mut self, range: Range<usize>, buffer: &mut [u8]) -> std::io::Result<()> {
+        // Since touching ranges are merged, an available range always lies within
+        // a single downloaded range.
+
+        let ranges = self.ranges.borrow();
+
+        match ranges.range(..=range.start).next_back() {
+            Some((start, bytes)) if start + bytes.len() >= range.end => {
+                buffer.copy_from_slice(&bytes[range.start - start..range.end - start]);
+
+                Ok(())
+            }
+            _ => Err(Error::new(
+                ErrorKind::UnexpectedEof,
+                "requested document data has not been downloaded",
+            )),
+        }
+    }
+}
+
+#[repr(C)]
+struct FxFileAvailExt<'a> {
+    // An extension of Pdfium's FX_FILEAVAIL struct that adds an extra field to carry the
+    // user-provided data provider.
+    version: c_int,
+    is_data_avail: Option<
+        unsafe extern "C" fn(this: *mut FxFileAvailExt, offset: usize, size: usize) -> FPDF_BOOL,
+    >,
+    provider: SharedPdfDataProvider<'a>,
+}
+
+impl<'a> FxFileAvailExt<'a> {
+    /// Returns an `FX_FILEAVAIL` pointer suitable for passing to `FPDFAvail_Create()`.
+    #[inline]
+    fn as_fx_file_avail_mut_ptr(&mut self) -> &mut FX_FILEAVAIL {
+        unsafe { &mut *(self as *mut FxFileAvailExt as *mut FX_FILEAVAIL) }
+    }
+}
+
+// The callback function invoked by Pdfium to check data availability. Any panic raised by
+// the data provider is treated as the data being unavailable, since unwinding across the
+// FFI boundary into Pdfium is undefined behaviour.
+extern "C" fn is_data_available_from_callback(
+    this: *mut FxFileAvailExt,
+    offset: usize,
+    size: usize,
+) -> FPDF_BOOL {
+    let result = panic::catch_unwind(AssertUnwindSafe(|| unsafe {
+        (*this)
+            .provider
+            .try_borrow()
+            .map(|provider| provider.is_data_available(offset..offset + size))
+            .unwrap_or(false)
+    }));
+
+    match result {
+        Ok(true) => 1,
+        _ => 0,
+    }
+}
+
+#[repr(C)]
+struct FxDownloadHintsExt {
+    // An extension of Pdfium's FX_DOWNLOADHINTS struct that adds an extra field to collect
+    // the byte ranges requested by Pdfium.
+    version: c_int,
+    add_segment:
+        Option<unsafe extern "C" fn(this: *mut FxDownloadHintsExt, offset: usize, size: usize)>,
+    hints: RefCell<Vec<Range<usize>>>,
+}
+
+impl FxDownloadHintsExt {
+    /// Returns an `FX_DOWNLOADHINTS` pointer suitable for passing to the `FPDFAvail_Is*Avail()`
+    /// family of functions. Pdfium only ever uses the pointer to call back into
+    /// `add_download_hint_from_callback()`, which mutates the hints via their `RefCell`.
+    #[inline]
+    fn as_fx_download_hints_mut_ptr(&self) -> *mut FX_DOWNLOADHINTS {
+        self as *const FxDownloadHintsExt as *mut FX_DOWNLOADHINTS
+    }
+}
+
+// The callback function invoked by Pdfium to request a byte range be downloaded.
+extern "C" fn add_download_hint_from_callback(
+    this: *mut FxDownloadHintsExt,
+    offset: usize,
+    size: usize,
+) {
+    unsafe {
+        if let Ok(mut hints) = (*this).hints.try_borrow_mut() {
+            hints.push(offset..offset + size);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PdfRangeBuffer;
+    use crate::prelude::*;
+    use crate::utils::test::test_bind_to_pdfium;
+    use std::cell::Cell;
+    use std::ops::Range;
+    use std::rc::Rc;
+
+    // A data provider simulating a download in progress, where only a growing prefix
+    // of the document data is available.
+    struct PartialDownload {
+        data: Vec<u8>,
+        available: Rc<Cell<usize>>,
+    }
+
+    impl PdfDataProvider for PartialDownload {
+        fn content_length(&self) -> usize {
+            self.data.len()
+        }
+
+        fn is_data_available(&self, range: Range<usize>) -> bool {
+            range.end <= self.available.get()
+        }
+
+        fn read(&mut self, range: Range<usize>, buffer: &mut [u8]) -> std::io::Result<()> {
+            buffer.copy_from_slice(&self.data[range]);
+
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_progressive_loading() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let data = std::fs::read("./test/text-test.pdf").map_err(PdfiumError::IoError)?;
+
+        let length = data.len();
+
+        let available = Rc::new(Cell::new(0));
+
+        let availability = pdfium.load_pdf_availability_from_provider(PartialDownload {
+            data,
+            available: available.clone(),
+        })?;
+
+        // Nothing has been downloaded yet.
+
+        assert!(!availability.is_document_available()?);
+        assert_eq!(availability.is_linearized(), None);
+        assert!(availability.download_hints().count() > 0);
+
+        // Simulate the download progressing in chunks until the document is available.
+
+        while !availability.is_document_available()? {
+            assert!(available.get() < length);
+
+            available.set((available.get() + 1024).min(length));
+        }
+
+        assert!(availability.is_linearized().is_some());
+
+        let document = availability.into_document(None)?;
+
+        available.set(length);
+
+        let availability = document.availability().unwrap();
+
+        for index in 0..document.pages().len() {
+            assert!(availability.is_page_available(index)?);
+        }
+
+        assert!(!document.pages().first()?.text()?.all().is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_range_buffer() -> Result<(), std::io::Error> {
+        let mut buffer = PdfRangeBuffer::new(100);
+
+        let data = (0..100).collect::<Vec<u8>>();
+
+        assert_eq!(buffer.missing(0..100), vec![0..100]);
+
+        buffer.insert(10, data[10..20].to_vec());
+        buffer.insert(40, data[40..50].to_vec());
+
+        assert!(buffer.is_data_available(12..18));
+        assert!(!buffer.is_data_available(15..25));
+        assert_eq!(buffer.missing(0..100), vec![0..10, 20..40, 50..100]);
+        assert_eq!(buffer.missing(15..45), vec![20..40]);
+
+        // Touching and overlapping ranges are merged.
+
+        buffer.insert(20, data[20..30].to_vec());
+        buffer.insert(25, data[25..45].to_vec());
+
+        assert_eq!(buffer.missing(0..100), vec![0..10, 50..100]);
+
+        let mut bytes = [0; 30];
+
+        buffer.read(15..45, &mut bytes)?;
+
+        assert_eq!(&bytes[..], &data[15..45]);
+        assert!(buffer.read(45..55, &mut bytes[..10]).is_err());
+
+        // Data beyond the end of the document is discarded.
+
+        buffer.insert(90, vec![0; 20]);
+
+        assert_eq!(buffer.missing(0..200), vec![0..10, 50..90]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_range_buffer_ranges_to_fetch() {
+        let buffer = PdfRangeBuffer::new(1000);
+
+        buffer.insert(100, vec![0; 100]);
+
+        // Hints are widened to whole chunks, already downloaded data is excluded,
+        // and touching ranges are merged.
+
+        assert_eq!(
+            buffer.ranges_to_fetch(vec![150..160, 220..230, 310..320, 990..995], 100),
+            vec![200..400, 900..1000]
+        );
+        assert!(buffer.ranges_to_fetch(Some(120..180), 100).is_empty());
+
+        // Empty hints are ignored.
+
+        assert_eq!(
+            buffer.ranges_to_fetch([50..60, 70..70], 1),
+            buffer.missing(50..60)
+        );
+    }
+}
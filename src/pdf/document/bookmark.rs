@@ -3,13 +3,21 @@
 
 use crate::bindgen::{FPDF_BOOKMARK, FPDF_DOCUMENT};
 use crate::bindings::PdfiumLibraryBindings;
+use crate::error::PdfiumError;
 use crate::pdf::action::PdfAction;
 use crate::pdf::destination::PdfDestination;
-use crate::pdf::document::bookmarks::PdfBookmarksIterator;
+use crate::pdf::document::bookmarks::{
+    check_destination, PdfBookmarkEdits, PdfBookmarkKey, PdfBookmarksIterator,
+};
+use crate::pdf::document::pages::PdfPageIndex;
+use crate::pdf::points::PdfPoints;
 use crate::utils::mem::create_byte_buffer;
 use crate::utils::utf16le::get_string_from_pdfium_utf16le_bytes;
+use std::cell::RefCell;
 use std::hash::{Hash, Hasher};
 use std::os::raw::c_void;
+use std::ptr::null_mut;
+use std::rc::Rc;
 
 #[cfg(doc)]
 use {
@@ -17,12 +25,76 @@ use {
     crate::pdf::document::PdfDocument,
 };
 
+/// The target page and view position of a [PdfBookmark] being created or updated using
+/// [PdfBookmarks::create_root_bookmark()], [PdfBookmark::add_child()], or
+/// [PdfBookmark::set_destination()].
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct PdfBookmarkDestination {
+    page_index: PdfPageIndex,
+    top: Option<PdfPoints>,
+    zoom: Option<f32>,
+}
+
+impl PdfBookmarkDestination {
+    /// Creates a new [PdfBookmarkDestination] targeting the page at the given index.
+    #[inline]
+    pub fn new(page_index: PdfPageIndex) -> Self {
+        PdfBookmarkDestination {
+            page_index,
+            top: None,
+            zoom: None,
+        }
+    }
+
+    /// Sets the vertical position on the target page, measured from the bottom of the page,
+    /// that should be scrolled into view when the bookmark is followed.
+    #[inline]
+    pub fn with_top(mut self, top: PdfPoints) -> Self {
+        self.top = Some(top);
+
+        self
+    }
+
+    /// Sets the zoom factor that should be applied when the bookmark is followed.
+    #[inline]
+    pub fn with_zoom(mut self, zoom: f32) -> Self {
+        self.zoom = Some(zoom);
+
+        self
+    }
+
+    /// Returns the index of the page targeted by this [PdfBookmarkDestination].
+    #[inline]
+    pub fn page_index(&self) -> PdfPageIndex {
+        self.page_index
+    }
+
+    /// Returns the vertical position on the target page, if any.
+    #[inline]
+    pub fn top(&self) -> Option<PdfPoints> {
+        self.top
+    }
+
+    /// Returns the zoom factor, if any.
+    #[inline]
+    pub fn zoom(&self) -> Option<f32> {
+        self.zoom
+    }
+}
+
 /// A single bookmark in a [PdfBookmarks] collection.
+///
+/// A bookmark created using [PdfBookmarks::create_root_bookmark()] or [PdfBookmark::add_child()]
+/// is only written to the document's outline when the containing [PdfDocument] is saved.
+/// Until then, it has no children, siblings, action, or destination as far as Pdfium is
+/// concerned, although its title can be retrieved and further children can be added to it.
 #[derive(Clone)]
 pub struct PdfBookmark<'a> {
     bookmark_handle: FPDF_BOOKMARK,
+    created_index: Option<usize>,
     parent: Option<FPDF_BOOKMARK>,
     document_handle: FPDF_DOCUMENT,
+    edits: Rc<RefCell<PdfBookmarkEdits>>,
     bindings: &'a dyn PdfiumLibraryBindings,
 }
 
@@ -44,7 +116,12 @@ impl<'a> PartialEq for PdfBookmark<'a> {
         // - The structure is allocated and retained by Pdfium for as long as the document is open,
         //   so the same bookmark will always give the same handle.
 
+        // Bookmarks that have been created but not yet saved have no handle, and are instead
+        // distinguished by their position in the containing document's list of created bookmarks.
+
         self.bookmark_handle == other.bookmark_handle
+            && self.created_index == other.created_index
+            && Rc::ptr_eq(&self.edits, &other.edits)
     }
 }
 
@@ -56,6 +133,7 @@ impl<'a> Hash for PdfBookmark<'a> {
         H: Hasher,
     {
         self.bookmark_handle.hash(state);
+        self.created_index.hash(state);
     }
 }
 
@@ -64,12 +142,33 @@ impl<'a> PdfBookmark<'a> {
         bookmark_handle: FPDF_BOOKMARK,
         parent: Option<FPDF_BOOKMARK>,
         document_handle: FPDF_DOCUMENT,
+        edits: Rc<RefCell<PdfBookmarkEdits>>,
         bindings: &'a dyn PdfiumLibraryBindings,
     ) -> Self {
         PdfBookmark {
             bookmark_handle,
+            created_index: None,
             parent,
             document_handle,
+            edits,
+            bindings,
+        }
+    }
+
+    /// Creates a [PdfBookmark] for the bookmark at the given index in the given list of
+    /// bookmarks created since the containing [PdfDocument] was opened.
+    pub(crate) fn from_created(
+        created_index: usize,
+        document_handle: FPDF_DOCUMENT,
+        edits: Rc<RefCell<PdfBookmarkEdits>>,
+        bindings: &'a dyn PdfiumLibraryBindings,
+    ) -> Self {
+        PdfBookmark {
+            bookmark_handle: null_mut(),
+            created_index: Some(created_index),
+            parent: None,
+            document_handle,
+            edits,
             bindings,
         }
     }
@@ -92,8 +191,29 @@ impl<'a> PdfBookmark<'a> {
         self.bindings
     }
 
+    /// Returns the key identifying this [PdfBookmark] in the containing [PdfDocument]'s
+    /// record of bookmark edits.
+    #[inline]
+    fn key(&self) -> PdfBookmarkKey {
+        match self.created_index {
+            Some(index) => PdfBookmarkKey::Created(index),
+            None => PdfBookmarkKey::Existing(self.bookmark_handle),
+        }
+    }
+
+    /// Returns `true` if this [PdfBookmark] has been created but not yet saved, and so
+    /// is not known to Pdfium.
+    #[inline]
+    fn is_created(&self) -> bool {
+        self.created_index.is_some()
+    }
+
     /// Returns the title of this [PdfBookmark], if any.
     pub fn title(&self) -> Option<String> {
+        if let Some(title) = self.edits.borrow().title(self.key()) {
+            return Some(title);
+        }
+
         // Retrieving the bookmark title from Pdfium is a two-step operation. First, we call
         // FPDFBookmark_GetTitle() with a null buffer; this will retrieve the length of
         // the bookmark title in bytes. If the length is zero, then there is no title.
@@ -132,6 +252,10 @@ impl<'a> PdfBookmark<'a> {
     /// of type [PdfActionType::GoToDestinationInSameDocument], but the PDF file format supports
     /// a variety of other actions.
     pub fn action(&self) -> Option<PdfAction<'a>> {
+        if self.is_created() {
+            return None;
+        }
+
         let handle = self.bindings.FPDFBookmark_GetAction(self.bookmark_handle);
 
         if handle.is_null() {
@@ -149,7 +273,15 @@ impl<'a> PdfBookmark<'a> {
     ///
     /// The destination specifies the page and region, if any, that will be the target
     /// of the action behaviour specified by [PdfBookmark::action()].
+    ///
+    /// Destinations set using [PdfBookmark::set_destination()] are only written to the
+    /// document when it is saved, and are not returned by this function until the saved
+    /// document is reloaded.
     pub fn destination(&self) -> Option<PdfDestination<'a>> {
+        if self.is_created() {
+            return None;
+        }
+
         let handle = self
             .bindings
             .FPDFBookmark_GetDest(self.document_handle, self.bookmark_handle);
@@ -169,13 +301,23 @@ impl<'a> PdfBookmark<'a> {
     #[inline]
     pub fn parent(&self) -> Option<PdfBookmark<'a>> {
         self.parent.map(|parent_handle| {
-            PdfBookmark::from_pdfium(parent_handle, None, self.document_handle, self.bindings)
+            PdfBookmark::from_pdfium(
+                parent_handle,
+                None,
+                self.document_handle,
+                self.edits.clone(),
+                self.bindings,
+            )
         })
     }
 
     /// Returns the number of direct children of this [PdfBookmark].
     #[inline]
     pub fn children_len(&self) -> usize {
+        if self.is_created() {
+            return 0;
+        }
+
         // If there are N child bookmarks, then FPDFBookmark_GetCount returns a
         // N if the bookmark tree should be displayed open by default, and -N if
         // the child tree should be displayed closed by deafult.
@@ -186,6 +328,10 @@ impl<'a> PdfBookmark<'a> {
 
     /// Returns the first child [PdfBookmark] of this [PdfBookmark], if any.
     pub fn first_child(&self) -> Option<PdfBookmark<'a>> {
+        if self.is_created() {
+            return None;
+        }
+
         let handle = self
             .bindings
             .FPDFBookmark_GetFirstChild(self.document_handle, self.bookmark_handle);
@@ -197,6 +343,7 @@ impl<'a> PdfBookmark<'a> {
                 handle,
                 Some(self.bookmark_handle),
                 self.document_handle,
+                self.edits.clone(),
                 self.bindings,
             ))
         }
@@ -204,6 +351,10 @@ impl<'a> PdfBookmark<'a> {
 
     /// Returns the next [PdfBookmark] at the same tree level as this [PdfBookmark], if any.
     pub fn next_sibling(&self) -> Option<PdfBookmark<'a>> {
+        if self.is_created() {
+            return None;
+        }
+
         let handle = self
             .bindings
             .FPDFBookmark_GetNextSibling(self.document_handle, self.bookmark_handle);
@@ -215,6 +366,7 @@ impl<'a> PdfBookmark<'a> {
                 handle,
                 self.parent,
                 self.document_handle,
+                self.edits.clone(),
                 self.bindings,
             ))
         }
@@ -234,6 +386,7 @@ impl<'a> PdfBookmark<'a> {
                         parent_handle,
                         None,
                         self.document_handle,
+                        self.edits.clone(),
                         self.bindings,
                     )
                     .first_child(),
@@ -242,6 +395,7 @@ impl<'a> PdfBookmark<'a> {
                     // the parent's direct children.
                     Some(self.clone()),
                     self.document_handle(),
+                    self.edits.clone(),
                     self.bindings(),
                 )
             }
@@ -257,6 +411,7 @@ impl<'a> PdfBookmark<'a> {
                     // the parent's direct children.
                     Some(self.clone()),
                     self.document_handle(),
+                    self.edits.clone(),
                     self.bindings(),
                 )
             }
@@ -274,6 +429,7 @@ impl<'a> PdfBookmark<'a> {
             false,
             None,
             self.document_handle(),
+            self.edits.clone(),
             self.bindings(),
         )
     }
@@ -288,9 +444,62 @@ impl<'a> PdfBookmark<'a> {
             true,
             None,
             self.document_handle(),
+            self.edits.clone(),
             self.bindings(),
         )
     }
+
+    /// Adds a new bookmark with the given title and destination as the last child of
+    /// this [PdfBookmark].
+    ///
+    /// The new bookmark is written to the document's outline when the document is saved.
+    pub fn add_child(
+        &mut self,
+        title: &str,
+        destination: PdfBookmarkDestination,
+    ) -> Result<PdfBookmark<'a>, PdfiumError> {
+        check_destination(self.document_handle, &destination, self.bindings)?;
+
+        let index = self
+            .edits
+            .borrow_mut()
+            .create_bookmark(self.key(), title, destination);
+
+        Ok(PdfBookmark::from_created(
+            index,
+            self.document_handle,
+            self.edits.clone(),
+            self.bindings,
+        ))
+    }
+
+    /// Sets the title of this [PdfBookmark].
+    ///
+    /// The new title is returned by [PdfBookmark::title()] immediately, and is written
+    /// to the document's outline when the document is saved.
+    #[inline]
+    pub fn set_title(&mut self, title: &str) -> Result<(), PdfiumError> {
+        self.edits.borrow_mut().set_title(self.key(), title);
+
+        Ok(())
+    }
+
+    /// Sets the destination of this [PdfBookmark], replacing any existing destination
+    /// or action.
+    ///
+    /// The new destination is written to the document's outline when the document is saved.
+    pub fn set_destination(
+        &mut self,
+        destination: PdfBookmarkDestination,
+    ) -> Result<(), PdfiumError> {
+        check_destination(self.document_handle, &destination, self.bindings)?;
+
+        self.edits
+            .borrow_mut()
+            .set_destination(self.key(), destination);
+
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -567,4 +776,121 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_create_bookmarks_round_trip() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        for _ in 0..3 {
+            document
+                .pages_mut()
+                .create_page_at_end(PdfPagePaperSize::a4())?;
+        }
+
+        let mut chapter1 = document.bookmarks_mut().create_root_bookmark(
+            "Chapter 1",
+            PdfBookmarkDestination::new(0).with_top(PdfPoints::new(800.0)),
+        )?;
+
+        chapter1.add_child("Section 1.1", PdfBookmarkDestination::new(0))?;
+        chapter1.add_child("Section 1.2", PdfBookmarkDestination::new(1))?;
+
+        let mut chapter2 = document
+            .bookmarks_mut()
+            .create_root_bookmark("Untitled", PdfBookmarkDestination::new(1))?;
+
+        let mut section = chapter2.add_child("Section 2.1", PdfBookmarkDestination::new(1))?;
+
+        chapter2.set_title("Chapter 2 – Überblick")?;
+        section.set_destination(PdfBookmarkDestination::new(2).with_zoom(2.0))?;
+
+        assert_eq!(chapter2.title().as_deref(), Some("Chapter 2 – Überblick"));
+        assert!(matches!(
+            chapter2.add_child("Missing", PdfBookmarkDestination::new(3)),
+            Err(PdfiumError::PageIndexOutOfBounds)
+        ));
+
+        let bytes = document.save_to_bytes()?;
+
+        let document = pdfium.load_pdf_from_byte_vec(bytes, None)?;
+
+        let outline = document
+            .bookmarks()
+            .iter()
+            .map(|bookmark| {
+                (
+                    bookmark.title().unwrap_or_default(),
+                    bookmark.parent().and_then(|parent| parent.title()),
+                    bookmark
+                        .destination()
+                        .and_then(|destination| destination.page_index().ok()),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            outline,
+            vec![
+                ("Chapter 1".to_owned(), None, Some(0)),
+                (
+                    "Section 1.1".to_owned(),
+                    Some("Chapter 1".to_owned()),
+                    Some(0)
+                ),
+                (
+                    "Section 1.2".to_owned(),
+                    Some("Chapter 1".to_owned()),
+                    Some(1)
+                ),
+                ("Chapter 2 – Überblick".to_owned(), None, Some(1)),
+                (
+                    "Section 2.1".to_owned(),
+                    Some("Chapter 2 – Überblick".to_owned()),
+                    Some(2)
+                ),
+            ]
+        );
+
+        let chapter1 = document.bookmarks().find_first_by_title("Chapter 1")?;
+
+        assert_eq!(chapter1.children_len(), 2);
+
+        // Changes to the bookmarks of a loaded document are written over the existing outline.
+
+        let mut document = pdfium.load_pdf_from_byte_vec(document.save_to_bytes()?, None)?;
+
+        let mut chapter1 = document.bookmarks().find_first_by_title("Chapter 1")?;
+
+        chapter1.set_title("Chapter One")?;
+        chapter1.add_child("Section 1.3", PdfBookmarkDestination::new(2))?;
+
+        document
+            .bookmarks_mut()
+            .create_root_bookmark("Appendix", PdfBookmarkDestination::new(2))?;
+
+        let document = pdfium.load_pdf_from_byte_vec(document.save_to_bytes()?, None)?;
+
+        let titles = document
+            .bookmarks()
+            .iter()
+            .map(|bookmark| bookmark.title().unwrap_or_default())
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            titles,
+            vec![
+                "Chapter One",
+                "Section 1.1",
+                "Section 1.2",
+                "Section 1.3",
+                "Chapter 2 – Überblick",
+                "Section 2.1",
+                "Appendix"
+            ]
+        );
+
+        Ok(())
+    }
 }
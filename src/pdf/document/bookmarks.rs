@@ -4,9 +4,16 @@
 use crate::bindgen::{FPDF_BOOKMARK, FPDF_DOCUMENT};
 use crate::bindings::PdfiumLibraryBindings;
 use crate::error::{PdfiumError, PdfiumInternalError};
-use crate::pdf::document::bookmark::PdfBookmark;
-use std::collections::HashSet;
+use crate::pdf::document::bookmark::{PdfBookmark, PdfBookmarkDestination};
+use crate::pdf::document::pages::PdfPageIndex;
+use crate::pdf::document::raw::{
+    encode_text_string, get, parse_reference, set, to_dictionary_string, PdfIncrementalUpdate,
+    PdfRawDictionary, PdfRawDocument,
+};
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet};
 use std::ptr::null_mut;
+use std::rc::Rc;
 
 /// The bookmarks contained within a single `PdfDocument`.
 ///
@@ -18,8 +25,16 @@ use std::ptr::null_mut;
 /// To search the tree for a bookmark with a specific title, use the [PdfBookmarks::find_first_by_title()]
 /// and [PdfBookmarks::find_all_by_title()] functions. To traverse the tree breadth-first, visiting
 /// every bookmark in the tree, create an iterator using the [PdfBookmarks::iter()] function.
+///
+/// New bookmarks can be added using the [PdfBookmarks::create_root_bookmark()] and
+/// [PdfBookmark::add_child()] functions. Pdfium provides no functions for editing a document's
+/// outline, so new bookmarks, and changes made to existing bookmarks using
+/// [PdfBookmark::set_title()] and [PdfBookmark::set_destination()], are written to the
+/// document's outline when the document is saved. New bookmarks are not visited when
+/// traversing the bookmark tree until the saved document is reloaded.
 pub struct PdfBookmarks<'a> {
     document_handle: FPDF_DOCUMENT,
+    edits: Rc<RefCell<PdfBookmarkEdits>>,
     bindings: &'a dyn PdfiumLibraryBindings,
 }
 
@@ -31,6 +46,7 @@ impl<'a> PdfBookmarks<'a> {
     ) -> Self {
         Self {
             document_handle,
+            edits: Rc::new(RefCell::new(PdfBookmarkEdits::default())),
             bindings,
         }
     }
@@ -61,6 +77,7 @@ impl<'a> PdfBookmarks<'a> {
                 bookmark_handle,
                 None,
                 self.document_handle,
+                self.edits.clone(),
                 self.bindings,
             ))
         }
@@ -78,14 +95,16 @@ impl<'a> PdfBookmarks<'a> {
             .FPDFBookmark_Find_str(self.document_handle, title);
 
         if handle.is_null() {
-            Err(PdfiumError::PdfiumLibraryInternalError(
-                PdfiumInternalError::Unknown,
+            Err(self.bindings.error_in(
+                "FPDFBookmark_Find_str",
+                PdfiumError::PdfiumLibraryInternalError(PdfiumInternalError::Unknown),
             ))
         } else {
             Ok(PdfBookmark::from_pdfium(
                 handle,
                 None,
                 self.document_handle,
+                self.edits.clone(),
                 self.bindings,
             ))
         }
@@ -107,6 +126,288 @@ impl<'a> PdfBookmarks<'a> {
             .collect()
     }
 
+    /// Creates a new top-level bookmark with the given title and destination, appending it
+    /// after any existing top-level bookmarks in the containing `PdfDocument`.
+    ///
+    /// The new bookmark is written to the document's outline when the document is saved.
+    pub fn create_root_bookmark(
+        &mut self,
+        title: &str,
+        destination: PdfBookmarkDestination,
+    ) -> Result<PdfBookmark<'a>, PdfiumError> {
+        check_destination(self.document_handle, &destination, self.bindings)?;
+
+        let index =
+            self.edits
+                .borrow_mut()
+                .create_bookmark(PdfBookmarkKey::Root, title, destination);
+
+        Ok(PdfBookmark::from_created(
+            index,
+            self.document_handle,
+            self.edits.clone(),
+            self.bindings,
+        ))
+    }
+
+    /// Returns `true` if any bookmarks have been created or changed since the containing
+    /// `PdfDocument` was opened.
+    #[inline]
+    pub(crate) fn is_modified(&self) -> bool {
+        self.edits.borrow().is_modified()
+    }
+
+    /// Adds a replacement for the outline in the given saved document, including all created
+    /// and changed bookmarks, to the given incremental update, returning the object number
+    /// of the outline dictionary so that it can be referenced from the document catalog.
+    pub(crate) fn add_outline_to_update(
+        &self,
+        document: &PdfRawDocument,
+        update: &mut PdfIncrementalUpdate,
+    ) -> Result<u32, PdfiumError> {
+        let edits = self.edits.borrow();
+
+        let pages = document
+            .page_object_numbers()
+            .ok_or(PdfiumError::DocumentOutlineUpdateNotSupported)?;
+
+        let outlines = document
+            .root_object_number()
+            .and_then(|root| document.object_dictionary(root))
+            .and_then(|catalog| get(&catalog, "Outlines").and_then(parse_reference));
+
+        let dictionary = match outlines {
+            Some(outlines) => document
+                .object_dictionary(outlines)
+                .ok_or(PdfiumError::DocumentOutlineUpdateNotSupported)?,
+            None => PdfRawDictionary::new(),
+        };
+
+        let first = get(&dictionary, "First").and_then(parse_reference);
+
+        // The first node is always the outline dictionary itself.
+
+        let mut nodes = vec![PdfOutlineNode {
+            object_number: outlines,
+            dictionary,
+            is_open: true,
+            children: vec![],
+        }];
+
+        // Pdfium does not expose the object numbers of existing bookmarks, so we match each
+        // bookmark to its outline item by walking Pdfium's bookmark tree and the saved outline
+        // in step with one another.
+
+        let mut existing = HashMap::new();
+
+        self.read_outline_items(
+            document,
+            first,
+            null_mut(),
+            0,
+            &mut nodes,
+            &mut existing,
+            &mut HashSet::new(),
+        )?;
+
+        for (handle, title) in edits.titles.iter() {
+            if let Some(index) = existing.get(handle) {
+                set(
+                    &mut nodes[*index].dictionary,
+                    "Title",
+                    encode_text_string(title),
+                );
+            }
+        }
+
+        for (handle, destination) in edits.destinations.iter() {
+            if let Some(index) = existing.get(handle) {
+                let dictionary = &mut nodes[*index].dictionary;
+
+                // An outline item's destination is ignored if it also has an action.
+
+                dictionary.retain(|(key, _)| key != "A");
+
+                set(
+                    dictionary,
+                    "Dest",
+                    to_destination_string(destination, pages.as_slice())?,
+                );
+            }
+        }
+
+        let mut created = Vec::with_capacity(edits.created.len());
+
+        for bookmark in edits.created.iter() {
+            let parent = match bookmark.parent {
+                PdfBookmarkKey::Root => 0,
+                PdfBookmarkKey::Existing(handle) => *existing
+                    .get(&(handle as usize))
+                    .ok_or(PdfiumError::DocumentOutlineUpdateNotSupported)?,
+                PdfBookmarkKey::Created(index) => created[index],
+            };
+
+            let index = nodes.len();
+
+            nodes.push(PdfOutlineNode {
+                object_number: None,
+                dictionary: vec![
+                    ("Title".to_owned(), encode_text_string(&bookmark.title)),
+                    (
+                        "Dest".to_owned(),
+                        to_destination_string(&bookmark.destination, pages.as_slice())?,
+                    ),
+                ],
+                is_open: true,
+                children: vec![],
+            });
+
+            nodes[parent].children.push(index);
+
+            created.push(index);
+        }
+
+        let object_numbers = nodes
+            .iter()
+            .map(|node| {
+                node.object_number
+                    .unwrap_or_else(|| update.reserve_object_number())
+            })
+            .collect::<Vec<_>>();
+
+        // Each outline item links to its parent and its adjacent siblings.
+
+        let mut links = vec![(None, None, None); nodes.len()];
+
+        for (index, node) in nodes.iter().enumerate() {
+            for (position, child) in node.children.iter().enumerate() {
+                links[*child] = (
+                    Some(index),
+                    position
+                        .checked_sub(1)
+                        .map(|previous| node.children[previous]),
+                    node.children.get(position + 1).copied(),
+                );
+            }
+        }
+
+        for (index, node) in nodes.iter().enumerate() {
+            let mut dictionary = node.dictionary.clone();
+
+            dictionary.retain(|(key, _)| {
+                !matches!(
+                    key.as_str(),
+                    "Parent" | "Prev" | "Next" | "First" | "Last" | "Count"
+                )
+            });
+
+            if index == 0 {
+                set(&mut dictionary, "Type", "/Outlines".to_owned());
+            }
+
+            let (parent, previous, next) = links[index];
+
+            let references = [
+                ("Parent", parent),
+                ("Prev", previous),
+                ("Next", next),
+                ("First", node.children.first().copied()),
+                ("Last", node.children.last().copied()),
+            ];
+
+            for (key, reference) in references.iter() {
+                if let Some(reference) = reference {
+                    set(
+                        &mut dictionary,
+                        key,
+                        format!("{} 0 R", object_numbers[*reference]),
+                    );
+                }
+            }
+
+            if !node.children.is_empty() {
+                // The count of an open item is the number of its visible descendants;
+                // a closed item's count is negated.
+
+                let count = count_visible_descendants(nodes.as_slice(), index) as i64;
+
+                set(
+                    &mut dictionary,
+                    "Count",
+                    (if node.is_open { count } else { -count }).to_string(),
+                );
+            }
+
+            update.replace_object(
+                object_numbers[index],
+                to_dictionary_string(&dictionary).into_bytes(),
+            );
+        }
+
+        Ok(object_numbers[0])
+    }
+
+    /// Reads the outline items starting with the given item in the given saved document into
+    /// the given list of nodes as children of the given parent node, matching each item to
+    /// the corresponding child of the given bookmark in Pdfium's bookmark tree.
+    #[allow(clippy::too_many_arguments)]
+    fn read_outline_items(
+        &self,
+        document: &PdfRawDocument,
+        first: Option<u32>,
+        parent_handle: FPDF_BOOKMARK,
+        parent: usize,
+        nodes: &mut Vec<PdfOutlineNode>,
+        existing: &mut HashMap<usize, usize>,
+        visited: &mut HashSet<u32>,
+    ) -> Result<(), PdfiumError> {
+        let mut object_number = first;
+
+        let mut handle = self
+            .bindings
+            .FPDFBookmark_GetFirstChild(self.document_handle, parent_handle);
+
+        loop {
+            match object_number {
+                None if handle.is_null() => return Ok(()),
+                Some(item) if !handle.is_null() && visited.insert(item) => {
+                    let dictionary = document
+                        .object_dictionary(item)
+                        .ok_or(PdfiumError::DocumentOutlineUpdateNotSupported)?;
+
+                    let first = get(&dictionary, "First").and_then(parse_reference);
+
+                    object_number = get(&dictionary, "Next").and_then(parse_reference);
+
+                    let index = nodes.len();
+
+                    nodes.push(PdfOutlineNode {
+                        object_number: Some(item),
+                        is_open: get(&dictionary, "Count")
+                            .and_then(|count| count.parse::<i64>().ok())
+                            .map(|count| count >= 0)
+                            .unwrap_or(true),
+                        dictionary,
+                        children: vec![],
+                    });
+
+                    nodes[parent].children.push(index);
+
+                    existing.insert(handle as usize, index);
+
+                    self.read_outline_items(
+                        document, first, handle, index, nodes, existing, visited,
+                    )?;
+
+                    handle = self
+                        .bindings
+                        .FPDFBookmark_GetNextSibling(self.document_handle, handle);
+                }
+                _ => return Err(PdfiumError::DocumentOutlineUpdateNotSupported),
+            }
+        }
+    }
+
     /// Returns a depth-first prefix-order iterator over all the [PdfBookmark]
     /// objects in the containing `PdfDocument`, starting from the top-level
     /// root bookmark.
@@ -117,6 +418,7 @@ impl<'a> PdfBookmarks<'a> {
             true,
             None,
             self.document_handle(),
+            self.edits.clone(),
             self.bindings(),
         )
     }
@@ -136,6 +438,7 @@ pub struct PdfBookmarksIterator<'a> {
     // descendants will be explored). May be NULL.
     skip_sibling: FPDF_BOOKMARK,
     document_handle: FPDF_DOCUMENT,
+    edits: Rc<RefCell<PdfBookmarkEdits>>,
     bindings: &'a dyn PdfiumLibraryBindings,
 }
 
@@ -145,10 +448,12 @@ impl<'a> PdfBookmarksIterator<'a> {
         include_descendants: bool,
         skip_sibling: Option<PdfBookmark<'a>>,
         document_handle: FPDF_DOCUMENT,
+        edits: Rc<RefCell<PdfBookmarkEdits>>,
         bindings: &'a dyn PdfiumLibraryBindings,
     ) -> Self {
         let mut result = PdfBookmarksIterator {
             document_handle,
+            edits,
             include_descendants,
             pending_stack: Vec::with_capacity(20),
             visited: HashSet::new(),
@@ -238,6 +543,7 @@ impl<'a> Iterator for PdfBookmarksIterator<'a> {
                     node,
                     parent,
                     self.document_handle,
+                    self.edits.clone(),
                     self.bindings,
                 ));
             }
@@ -247,3 +553,161 @@ impl<'a> Iterator for PdfBookmarksIterator<'a> {
         None
     }
 }
+
+/// Bookmarks created, and changes made to existing bookmarks, since a `PdfDocument`
+/// was opened. Existing bookmarks are keyed by their raw `FPDF_BOOKMARK` handles.
+#[derive(Default)]
+pub(crate) struct PdfBookmarkEdits {
+    created: Vec<PdfCreatedBookmark>,
+    titles: HashMap<usize, String>,
+    destinations: HashMap<usize, PdfBookmarkDestination>,
+}
+
+impl PdfBookmarkEdits {
+    /// Returns `true` if any bookmarks have been created or changed.
+    #[inline]
+    fn is_modified(&self) -> bool {
+        !self.created.is_empty() || !self.titles.is_empty() || !self.destinations.is_empty()
+    }
+
+    /// Records a new bookmark with the given parent, title, and destination,
+    /// returning its index in the list of created bookmarks.
+    pub(crate) fn create_bookmark(
+        &mut self,
+        parent: PdfBookmarkKey,
+        title: &str,
+        destination: PdfBookmarkDestination,
+    ) -> usize {
+        self.created.push(PdfCreatedBookmark {
+            parent,
+            title: title.to_owned(),
+            destination,
+        });
+
+        self.created.len() - 1
+    }
+
+    /// Returns the title set for the given bookmark, if any.
+    pub(crate) fn title(&self, bookmark: PdfBookmarkKey) -> Option<String> {
+        match bookmark {
+            PdfBookmarkKey::Root => None,
+            PdfBookmarkKey::Existing(handle) => self.titles.get(&(handle as usize)).cloned(),
+            PdfBookmarkKey::Created(index) => {
+                self.created.get(index).map(|created| created.title.clone())
+            }
+        }
+    }
+
+    /// Sets the title of the given bookmark.
+    pub(crate) fn set_title(&mut self, bookmark: PdfBookmarkKey, title: &str) {
+        match bookmark {
+            PdfBookmarkKey::Root => {}
+            PdfBookmarkKey::Existing(handle) => {
+                self.titles.insert(handle as usize, title.to_owned());
+            }
+            PdfBookmarkKey::Created(index) => {
+                if let Some(created) = self.created.get_mut(index) {
+                    created.title = title.to_owned();
+                }
+            }
+        }
+    }
+
+    /// Sets the destination of the given bookmark.
+    pub(crate) fn set_destination(
+        &mut self,
+        bookmark: PdfBookmarkKey,
+        destination: PdfBookmarkDestination,
+    ) {
+        match bookmark {
+            PdfBookmarkKey::Root => {}
+            PdfBookmarkKey::Existing(handle) => {
+                self.destinations.insert(handle as usize, destination);
+            }
+            PdfBookmarkKey::Created(index) => {
+                if let Some(created) = self.created.get_mut(index) {
+                    created.destination = destination;
+                }
+            }
+        }
+    }
+}
+
+/// Identifies a node in the bookmark tree: either the outline root, an existing bookmark,
+/// or a bookmark in the list of created bookmarks.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub(crate) enum PdfBookmarkKey {
+    Root,
+    Existing(FPDF_BOOKMARK),
+    Created(usize),
+}
+
+/// A bookmark created since a `PdfDocument` was opened.
+struct PdfCreatedBookmark {
+    parent: PdfBookmarkKey,
+    title: String,
+    destination: PdfBookmarkDestination,
+}
+
+/// A single node in an outline being rewritten: either the outline dictionary itself,
+/// an existing outline item, or a new outline item.
+struct PdfOutlineNode {
+    object_number: Option<u32>,
+    dictionary: PdfRawDictionary,
+    is_open: bool,
+    children: Vec<usize>,
+}
+
+/// Returns the number of descendants of the given node that are visible when the node
+/// is open, counting the descendants of open children.
+fn count_visible_descendants(nodes: &[PdfOutlineNode], index: usize) -> usize {
+    nodes[index]
+        .children
+        .iter()
+        .map(|child| {
+            1 + if nodes[*child].is_open {
+                count_visible_descendants(nodes, *child)
+            } else {
+                0
+            }
+        })
+        .sum()
+}
+
+/// Returns [PdfiumError::PageIndexOutOfBounds] if the given destination targets a page
+/// that does not exist in the given document.
+pub(crate) fn check_destination(
+    document_handle: FPDF_DOCUMENT,
+    destination: &PdfBookmarkDestination,
+    bindings: &dyn PdfiumLibraryBindings,
+) -> Result<(), PdfiumError> {
+    if destination.page_index() >= bindings.FPDF_GetPageCount(document_handle) as PdfPageIndex {
+        Err(PdfiumError::PageIndexOutOfBounds)
+    } else {
+        Ok(())
+    }
+}
+
+/// Returns an explicit destination array for the given destination, referencing the target
+/// page in the given list of page object numbers.
+fn to_destination_string(
+    destination: &PdfBookmarkDestination,
+    pages: &[u32],
+) -> Result<String, PdfiumError> {
+    let page = pages
+        .get(destination.page_index() as usize)
+        .ok_or(PdfiumError::PageIndexOutOfBounds)?;
+
+    Ok(format!(
+        "[{} 0 R /XYZ null {} {}]",
+        page,
+        destination
+            .top()
+            .map(|top| top.value.to_string())
+            .unwrap_or_else(|| "null".to_owned()),
+        destination
+            .zoom()
+            .map(|zoom| zoom.to_string())
+            .unwrap_or_else(|| "null".to_owned()),
+    ))
+}
@@ -0,0 +1,759 @@
+//! Defines the [PdfEncryptionOptions] struct, a builder-based approach to configuring the
+//! password protection of a `PdfDocument` when it is saved.
+//!
+//! Pdfium can open encrypted documents, but it cannot write them. Encryption is therefore
+//! applied to the document data saved by Pdfium: every string and stream in the saved data
+//! is encrypted using revision 6 of the standard security handler described in Section 7.6.4
+//! of ISO 32000-2, and the document is rewritten with a new cross-reference table and an
+//! `/Encrypt` dictionary. All cryptographic primitives are provided by the RustCrypto crates.
+
+use crate::error::PdfiumError;
+use crate::pdf::document::permissions::PdfPermissionFlags;
+use crate::pdf::document::raw::{
+    find, get, parse_reference, read_value, set, skip_whitespace, to_dictionary_string, to_hex,
+    PdfRawDocument, PdfRawObjectLocation, PdfRawValue,
+};
+use aes::cipher::block_padding::{NoPadding, Pkcs7};
+use aes::cipher::{BlockEncrypt, BlockEncryptMut, KeyInit, KeyIvInit};
+use aes::{Aes128, Aes256};
+use sha2::{Digest, Sha256, Sha384, Sha512};
+use std::fmt::{Debug, Formatter};
+
+#[cfg(doc)]
+use crate::pdf::document::{PdfDocument, PdfSaveOptions};
+
+/// The algorithm used to encrypt the strings and streams in a password-protected [PdfDocument].
+///
+/// The RC4 and 128-bit AES algorithms of earlier revisions of the standard security handler
+/// are not offered, since both depend on the insecure RC4 cipher to protect their passwords.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum PdfEncryptionAlgorithm {
+    /// AES encryption with a 256-bit key, using revision 6 of the standard security handler.
+    /// Requires PDF version 2.0 or later, although it is also supported by Adobe Acrobat 9
+    /// and later when opening earlier versions.
+    Aes256,
+}
+
+impl PdfEncryptionAlgorithm {
+    /// Returns the minimum (major, minor) PDF version supporting this algorithm.
+    #[inline]
+    fn minimum_version(&self) -> (u8, u8) {
+        match self {
+            PdfEncryptionAlgorithm::Aes256 => (2, 0),
+        }
+    }
+}
+
+/// Configures the password protection applied to a [PdfDocument] saved using
+/// [PdfSaveOptions::encrypt()].
+///
+/// A user who opens the document with the user password may perform only the operations
+/// allowed by the permission flags. A user who opens the document with the owner password
+/// may perform any operation. If the user password is empty, the document can be opened
+/// without a password, but the permission flags still apply. If the owner password is empty,
+/// the user password is used as the owner password.
+///
+/// Only the first 127 bytes of the UTF-8 encoding of each password are used.
+///
+/// By default, all operations are permitted and [PdfEncryptionAlgorithm::Aes256] is used.
+#[derive(Clone, PartialEq, Eq)]
+pub struct PdfEncryptionOptions {
+    user_password: String,
+    owner_password: String,
+    permissions: PdfPermissionFlags,
+    algorithm: PdfEncryptionAlgorithm,
+}
+
+impl PdfEncryptionOptions {
+    /// Creates a new [PdfEncryptionOptions] object with the given user and owner passwords
+    /// and all other settings at their default values.
+    #[inline]
+    pub fn new(user_password: &str, owner_password: &str) -> Self {
+        PdfEncryptionOptions {
+            user_password: user_password.to_string(),
+            owner_password: owner_password.to_string(),
+            permissions: PdfPermissionFlags::all(),
+            algorithm: PdfEncryptionAlgorithm::Aes256,
+        }
+    }
+
+    /// Sets the operations that may be performed by a user who opens the document
+    /// with the user password.
+    #[inline]
+    pub fn set_permissions(mut self, permissions: PdfPermissionFlags) -> Self {
+        self.permissions = permissions;
+
+        self
+    }
+
+    /// Sets the [PdfEncryptionAlgorithm] used to encrypt the document. The file version of
+    /// the saved document is raised to the minimum version supporting the algorithm,
+    /// if necessary.
+    #[inline]
+    pub fn set_algorithm(mut self, algorithm: PdfEncryptionAlgorithm) -> Self {
+        self.algorithm = algorithm;
+
+        self
+    }
+
+    /// Returns the user password set in this [PdfEncryptionOptions] object.
+    #[inline]
+    pub fn user_password(&self) -> &str {
+        self.user_password.as_str()
+    }
+
+    /// Returns the owner password set in this [PdfEncryptionOptions] object.
+    #[inline]
+    pub fn owner_password(&self) -> &str {
+        self.owner_password.as_str()
+    }
+
+    /// Returns the [PdfPermissionFlags] set in this [PdfEncryptionOptions] object.
+    #[inline]
+    pub fn permissions(&self) -> PdfPermissionFlags {
+        self.permissions
+    }
+
+    /// Returns the [PdfEncryptionAlgorithm] set in this [PdfEncryptionOptions] object.
+    #[inline]
+    pub fn algorithm(&self) -> PdfEncryptionAlgorithm {
+        self.algorithm
+    }
+}
+
+impl Debug for PdfEncryptionOptions {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        // Passwords are deliberately omitted, so they do not end up in logs.
+
+        f.debug_struct("PdfEncryptionOptions")
+            .field("permissions", &self.permissions)
+            .field("algorithm", &self.algorithm)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Encrypts the given unencrypted document data using the given [PdfEncryptionOptions],
+/// returning a complete new file.
+///
+/// Every object in use is rewritten in ascending object number order with its strings and
+/// stream data encrypted, followed by a new `/Encrypt` dictionary and a single
+/// cross-reference table. Any previous incremental updates are consolidated, and objects
+/// held in compressed object streams are written out as uncompressed objects.
+pub(crate) fn encrypt_document(
+    bytes: &[u8],
+    options: &PdfEncryptionOptions,
+) -> Result<Vec<u8>, PdfiumError> {
+    let document =
+        PdfRawDocument::parse(bytes).ok_or(PdfiumError::DocumentEncryptionNotSupported)?;
+
+    let mut trailer = document.trailer().clone();
+
+    if get(&trailer, "Encrypt").is_some() {
+        return Err(PdfiumError::DocumentEncryptionNotSupported);
+    }
+
+    let objects = document
+        .object_locations()
+        .ok_or(PdfiumError::DocumentEncryptionNotSupported)?;
+
+    // An encrypted document must have a file identifier. Reuse the existing identifier
+    // if there is one; otherwise, generate a new one.
+
+    let id_entry = match get(&trailer, "ID").and_then(|id| PdfRawValue::parse(id.as_bytes())) {
+        Some(PdfRawValue::Array(elements)) => match elements.first() {
+            Some(PdfRawValue::String(_)) => get(&trailer, "ID").unwrap_or_default().to_string(),
+            _ => return Err(PdfiumError::DocumentEncryptionNotSupported),
+        },
+        _ => {
+            let id = random_bytes(16)?;
+
+            format!("[<{}> <{}>]", to_hex(&id), to_hex(&id))
+        }
+    };
+
+    let (handler, encrypt_dictionary) = PdfSecurityHandler::new(options)?;
+
+    let mut result = Vec::with_capacity(bytes.len() + bytes.len() / 8 + 1024);
+
+    let (major, minor) = header_version(bytes)
+        .unwrap_or((1, 0))
+        .max(options.algorithm().minimum_version());
+
+    result.extend_from_slice(format!("%PDF-{}.{}\n", major, minor).as_bytes());
+    result.extend_from_slice(b"%\xE2\xE3\xCF\xD3\n");
+
+    let mut xref = Vec::with_capacity(objects.len() + 1);
+
+    for (object_number, generation, location) in objects.iter() {
+        let object = match location {
+            PdfRawObjectLocation::Offset(offset) => read_object(&document, bytes, *offset),
+            PdfRawObjectLocation::Compressed { stream, index } => document
+                .compressed_object(*stream, *index)
+                .and_then(|(data, position)| read_value(data.as_slice(), position))
+                .map(|(value, _)| (value, None)),
+            PdfRawObjectLocation::Free => continue,
+        }
+        .ok_or(PdfiumError::DocumentEncryptionNotSupported)?;
+
+        let (mut value, stream) = object;
+
+        match value.get_name("/Type") {
+            // Cross-reference streams are replaced by our own cross-reference table, and the
+            // objects held in object streams are written out individually.
+            Some(b"/XRef") | Some(b"/ObjStm") => continue,
+            _ => {}
+        }
+
+        encrypt_strings(&mut value, &handler)?;
+
+        let stream = match stream {
+            Some(data) => {
+                let data = handler.encrypt(data)?;
+
+                value.set(
+                    "/Length",
+                    PdfRawValue::Token(data.len().to_string().into_bytes()),
+                );
+
+                Some(data)
+            }
+            None => None,
+        };
+
+        xref.push((*object_number, *generation, result.len()));
+
+        result.extend_from_slice(format!("{} {} obj\n", object_number, generation).as_bytes());
+
+        value.write_to(&mut result);
+
+        if let Some(data) = stream {
+            result.extend_from_slice(b"\nstream\n");
+            result.extend_from_slice(data.as_slice());
+            result.extend_from_slice(b"\nendstream");
+        }
+
+        result.extend_from_slice(b"\nendobj\n");
+    }
+
+    let size = get(&trailer, "Size")
+        .and_then(|size| size.parse::<u32>().ok())
+        .unwrap_or(0);
+
+    let encrypt_object_number = objects
+        .last()
+        .map(|(object_number, _, _)| object_number + 1)
+        .unwrap_or(1)
+        .max(size);
+
+    xref.push((encrypt_object_number, 0, result.len()));
+
+    result.extend_from_slice(
+        format!(
+            "{} 0 obj\n{}\nendobj\n",
+            encrypt_object_number, encrypt_dictionary
+        )
+        .as_bytes(),
+    );
+
+    // Write a single cross-reference table covering every object number up to and including
+    // the /Encrypt dictionary. Unused object numbers are marked as free, and are never reused.
+
+    let xref_offset = result.len();
+
+    result.extend_from_slice(format!("xref\n0 {}\n", encrypt_object_number + 1).as_bytes());
+    result.extend_from_slice(b"0000000000 65535 f\r\n");
+
+    let mut entries = xref.iter().peekable();
+
+    for object_number in 1..=encrypt_object_number {
+        match entries.peek() {
+            Some((entry_number, generation, offset)) if *entry_number == object_number => {
+                result.extend_from_slice(
+                    format!("{:010} {:05} n\r\n", offset, generation).as_bytes(),
+                );
+
+                entries.next();
+            }
+            _ => result.extend_from_slice(b"0000000000 65535 f\r\n"),
+        }
+    }
+
+    trailer.retain(|(key, _)| !matches!(key.as_str(), "Prev" | "XRefStm" | "Encrypt" | "ID"));
+
+    set(
+        &mut trailer,
+        "Size",
+        (encrypt_object_number + 1).to_string(),
+    );
+    set(
+        &mut trailer,
+        "Encrypt",
+        format!("{} 0 R", encrypt_object_number),
+    );
+    set(&mut trailer, "ID", id_entry);
+
+    result.extend_from_slice(
+        format!(
+            "trailer\n{}\nstartxref\n{}\n%%EOF\n",
+            to_dictionary_string(&trailer),
+            xref_offset
+        )
+        .as_bytes(),
+    );
+
+    Ok(result)
+}
+
+/// Returns the (major, minor) version in the `%PDF-` header of the given document data.
+fn header_version(bytes: &[u8]) -> Option<(u8, u8)> {
+    let version = bytes.strip_prefix(b"%PDF-")?;
+
+    match version {
+        [major, b'.', minor, ..] if major.is_ascii_digit() && minor.is_ascii_digit() => {
+            Some((major - b'0', minor - b'0'))
+        }
+        _ => None,
+    }
+}
+
+/// Reads the value of the indirect object at the given offset, along with its raw stream data
+/// if the object is a stream. Indirect stream lengths are resolved using the given document.
+fn read_object<'a>(
+    document: &PdfRawDocument,
+    bytes: &'a [u8],
+    offset: usize,
+) -> Option<(PdfRawValue, Option<&'a [u8]>)> {
+    let start = find(bytes.get(offset..)?, b"obj")? + offset + b"obj".len();
+
+    let (value, end) = read_value(bytes, start)?;
+
+    let position = skip_whitespace(bytes, end);
+
+    if !bytes.get(position..)?.starts_with(b"stream") {
+        return Some((value, None));
+    }
+
+    // The stream keyword is followed by either CRLF or LF; a lone CR is tolerated.
+
+    let mut data_start = position + b"stream".len();
+
+    if bytes.get(data_start) == Some(&b'\r') {
+        data_start += 1;
+    }
+
+    if bytes.get(data_start) == Some(&b'\n') {
+        data_start += 1;
+    }
+
+    let length = match value.get("/Length") {
+        Some(PdfRawValue::Token(token)) => resolve_length(document, token),
+        _ => None,
+    };
+
+    let data = match length {
+        Some(length)
+            if bytes
+                .get(data_start + length..)
+                .map(|rest| rest[skip_whitespace(rest, 0)..].starts_with(b"endstream"))
+                .unwrap_or(false) =>
+        {
+            &bytes[data_start..data_start + length]
+        }
+        _ => {
+            // The stream length is missing or incorrect. Fall back to searching for the end
+            // of the stream, discarding the end-of-line marker preceding the keyword.
+
+            let end = data_start + find(&bytes[data_start..], b"endstream")?;
+
+            let mut data = &bytes[data_start..end];
+
+            if let Some(rest) = data.strip_suffix(b"\n") {
+                data = rest;
+            }
+
+            if let Some(rest) = data.strip_suffix(b"\r") {
+                data = rest;
+            }
+
+            data
+        }
+    };
+
+    Some((value, Some(data)))
+}
+
+/// Resolves a stream `/Length` value, which may be either a direct integer
+/// or an indirect reference to an integer object.
+fn resolve_length(document: &PdfRawDocument, token: &[u8]) -> Option<usize> {
+    let token = std::str::from_utf8(token).ok()?;
+
+    match parse_reference(token) {
+        Some(object_number) => document.object_unsigned(object_number),
+        None => token.parse().ok(),
+    }
+}
+
+/// Encrypts every string in the given value in place.
+fn encrypt_strings(
+    value: &mut PdfRawValue,
+    handler: &PdfSecurityHandler,
+) -> Result<(), PdfiumError> {
+    match value {
+        PdfRawValue::String(data) => {
+            *data = handler.encrypt(data.as_slice())?;
+        }
+        PdfRawValue::Array(elements) => {
+            for element in elements.iter_mut() {
+                encrypt_strings(element, handler)?;
+            }
+        }
+        PdfRawValue::Dictionary(entries) => {
+            for (_, value) in entries.iter_mut() {
+                encrypt_strings(value, handler)?;
+            }
+        }
+        PdfRawValue::Token(_) => {}
+    }
+
+    Ok(())
+}
+
+/// The file encryption key of the standard security handler for a document being encrypted.
+struct PdfSecurityHandler {
+    key: Vec<u8>,
+}
+
+impl PdfSecurityHandler {
+    /// Creates a new [PdfSecurityHandler] for the given options, returning it together with
+    /// the `/Encrypt` dictionary that must be written to the document.
+    fn new(options: &PdfEncryptionOptions) -> Result<(Self, String), PdfiumError> {
+        // Bits 7, 8, and 13 to 32 of the permissions value are reserved and must be set.
+
+        let permissions = (options.permissions().bits() | 0xfffff0c0) as i32;
+
+        let user_password = options.user_password().as_bytes();
+
+        let owner_password = if options.owner_password().is_empty() {
+            user_password
+        } else {
+            options.owner_password().as_bytes()
+        };
+
+        let user_password = &user_password[..user_password.len().min(127)];
+
+        let owner_password = &owner_password[..owner_password.len().min(127)];
+
+        let key = random_bytes(32)?;
+
+        // Algorithm 8 of ISO 32000-2: the /U and /UE entries.
+
+        let salts = random_bytes(16)?;
+
+        let mut user = hash_r6(user_password, &salts[..8], &[]);
+
+        user.extend_from_slice(salts.as_slice());
+
+        let user_encrypted_key = aes256_cbc_without_padding(
+            hash_r6(user_password, &salts[8..], &[]).as_slice(),
+            key.as_slice(),
+        );
+
+        // Algorithm 9 of ISO 32000-2: the /O and /OE entries.
+
+        let salts = random_bytes(16)?;
+
+        let mut owner = hash_r6(owner_password, &salts[..8], user.as_slice());
+
+        owner.extend_from_slice(salts.as_slice());
+
+        let owner_encrypted_key = aes256_cbc_without_padding(
+            hash_r6(owner_password, &salts[8..], user.as_slice()).as_slice(),
+            key.as_slice(),
+        );
+
+        // Algorithm 10 of ISO 32000-2: the /Perms entry.
+
+        let mut perms = [0u8; 16];
+
+        perms[..4].copy_from_slice(&permissions.to_le_bytes());
+        perms[4..8].copy_from_slice(&[0xff; 4]);
+        perms[8..12].copy_from_slice(b"Tadb");
+        perms[12..].copy_from_slice(random_bytes(4)?.as_slice());
+
+        let mut block = aes::Block::clone_from_slice(&perms);
+
+        Aes256::new_from_slice(key.as_slice())
+            .map_err(|_| PdfiumError::DocumentEncryptionNotSupported)?
+            .encrypt_block(&mut block);
+
+        let dictionary = format!(
+            "<< /Filter /Standard /V 5 /R 6 /Length 256 \
+            /CF << /StdCF << /Type /CryptFilter /CFM /AESV3 /AuthEvent /DocOpen /Length 32 >> >> \
+            /StmF /StdCF /StrF /StdCF /O <{}> /U <{}> /OE <{}> /UE <{}> /P {} /Perms <{}> \
+            /EncryptMetadata true >>",
+            to_hex(&owner),
+            to_hex(&user),
+            to_hex(&owner_encrypted_key),
+            to_hex(&user_encrypted_key),
+            permissions,
+            to_hex(block.as_slice())
+        );
+
+        Ok((PdfSecurityHandler { key }, dictionary))
+    }
+
+    /// Encrypts the given string or stream data. Revision 6 uses the file encryption key
+    /// for every object, with a random initialization vector prepended to the encrypted data.
+    fn encrypt(&self, data: &[u8]) -> Result<Vec<u8>, PdfiumError> {
+        let iv = random_bytes(16)?;
+
+        let mut result = iv.clone();
+
+        result.extend(
+            cbc::Encryptor::<Aes256>::new_from_slices(self.key.as_slice(), iv.as_slice())
+                .map_err(|_| PdfiumError::DocumentEncryptionNotSupported)?
+                .encrypt_padded_vec_mut::<Pkcs7>(data),
+        );
+
+        Ok(result)
+    }
+}
+
+/// Computes the password hash for revision 6 of the standard security handler,
+/// as described in algorithm 2.B of ISO 32000-2.
+fn hash_r6(password: &[u8], salt: &[u8], user: &[u8]) -> Vec<u8> {
+    let mut hasher = Sha256::new();
+
+    hasher.update(password);
+    hasher.update(salt);
+    hasher.update(user);
+
+    let mut k = hasher.finalize().to_vec();
+
+    let mut round = 0;
+
+    loop {
+        let k1 = [password, k.as_slice(), user].concat().repeat(64);
+
+        let e = cbc::Encryptor::<Aes128>::new_from_slices(&k[..16], &k[16..32])
+            .expect("key and IV are 16 bytes")
+            .encrypt_padded_vec_mut::<NoPadding>(k1.as_slice());
+
+        k = match e[..16].iter().map(|byte| *byte as u32).sum::<u32>() % 3 {
+            0 => Sha256::digest(e.as_slice()).to_vec(),
+            1 => Sha384::digest(e.as_slice()).to_vec(),
+            _ => Sha512::digest(e.as_slice()).to_vec(),
+        };
+
+        round += 1;
+
+        if round >= 64 && (*e.last().unwrap() as u32) <= round - 32 {
+            break;
+        }
+    }
+
+    k.truncate(32);
+
+    k
+}
+
+/// Encrypts the given 32-byte file encryption key using AES-256 in CBC mode with the given key,
+/// a zero initialization vector, and no padding.
+fn aes256_cbc_without_padding(key: &[u8], data: &[u8]) -> Vec<u8> {
+    cbc::Encryptor::<Aes256>::new_from_slices(key, &[0; 16])
+        .expect("key is 32 bytes and IV is 16 bytes")
+        .encrypt_padded_vec_mut::<NoPadding>(data)
+}
+
+/// Returns the given number of random bytes from the operating system's random number generator.
+fn random_bytes(count: usize) -> Result<Vec<u8>, PdfiumError> {
+    let mut result = vec![0; count];
+
+    getrandom::getrandom(result.as_mut_slice())
+        .map_err(|_| PdfiumError::EncryptionRandomSourceUnavailable)?;
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+    use crate::utils::test::test_bind_to_pdfium;
+
+    #[test]
+    fn test_encrypt_raw_document() -> Result<(), PdfiumError> {
+        let source = b"%PDF-1.7\n\
+            1 0 obj\n<< /Type /Catalog /Pages 2 0 R >>\nendobj\n\
+            2 0 obj\n<< /Type /Pages /Kids [] /Count 0 >>\nendobj\n\
+            3 0 obj\n<< /Title (Secret title) >>\nendobj\n\
+            4 0 obj\n<< /Length 11 >>\nstream\nSecret data\nendstream\nendobj\n";
+
+        let xref_offset = source.len();
+
+        let mut bytes = source.to_vec();
+
+        bytes.extend_from_slice(b"xref\n0 5\n0000000000 65535 f\r\n");
+
+        for object in ["1 0 obj", "2 0 obj", "3 0 obj", "4 0 obj"] {
+            let offset = find(source, object.as_bytes()).unwrap();
+
+            bytes.extend_from_slice(format!("{:010} 00000 n\r\n", offset).as_bytes());
+        }
+
+        bytes.extend_from_slice(
+            format!(
+                "trailer\n<< /Size 5 /Root 1 0 R /Info 3 0 R >>\nstartxref\n{}\n%%EOF\n",
+                xref_offset
+            )
+            .as_bytes(),
+        );
+
+        let encrypted = encrypt_document(
+            bytes.as_slice(),
+            &PdfEncryptionOptions::new("user", "owner"),
+        )?;
+
+        assert!(find(encrypted.as_slice(), b"Secret").is_none());
+
+        let document = PdfRawDocument::parse(encrypted.as_slice()).unwrap();
+
+        assert_eq!(get(document.trailer(), "Encrypt").unwrap(), "5 0 R");
+        assert_eq!(get(document.trailer(), "Size").unwrap(), "6");
+        assert_eq!(document.root_object_number(), Some(1));
+        assert_eq!(header_version(encrypted.as_slice()), Some((2, 0)));
+
+        let (dictionary, data) = document.object_stream(4).unwrap();
+
+        assert_eq!(get(&dictionary, "Length").unwrap(), &data.len().to_string());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_encrypt_raw_document_with_object_streams() -> Result<(), PdfiumError> {
+        // Objects 1 and 2 are held in the compressed object stream 3, and are listed
+        // in the cross-reference stream 5 rather than in a cross-reference table.
+
+        let contents: &[u8] = b"1 0 2 34 << /Type /Catalog /Pages 4 0 R >> (Secret title)";
+
+        let mut bytes = format!(
+            "%PDF-1.7\n3 0 obj\n<< /Type /ObjStm /N 2 /First 9 /Length {} >>\nstream\n",
+            contents.len()
+        )
+        .into_bytes();
+
+        bytes.extend_from_slice(contents);
+        bytes.extend_from_slice(b"\nendstream\nendobj\n");
+
+        let pages_offset = bytes.len();
+
+        bytes.extend_from_slice(b"4 0 obj\n<< /Type /Pages /Kids [] /Count 0 >>\nendobj\n");
+
+        let xref_offset = bytes.len();
+
+        let mut entries = Vec::new();
+
+        for (kind, second, third) in [
+            (2u8, 3, 0u8),
+            (2, 3, 1),
+            (1, 9, 0),
+            (1, pages_offset as u16, 0),
+            (1, xref_offset as u16, 0),
+        ] {
+            entries.push(kind);
+            entries.extend_from_slice(&u16::to_be_bytes(second));
+            entries.push(third);
+        }
+
+        bytes.extend_from_slice(
+            format!(
+                "5 0 obj\n<< /Type /XRef /Size 6 /Index [1 5] /W [1 2 1] /Root 1 0 R \
+                /Info 2 0 R /Length {} >>\nstream\n",
+                entries.len()
+            )
+            .as_bytes(),
+        );
+        bytes.extend_from_slice(entries.as_slice());
+        bytes.extend_from_slice(
+            format!("\nendstream\nendobj\nstartxref\n{}\n%%EOF\n", xref_offset).as_bytes(),
+        );
+
+        let encrypted = encrypt_document(
+            bytes.as_slice(),
+            &PdfEncryptionOptions::new("user", "owner"),
+        )?;
+
+        assert!(find(encrypted.as_slice(), b"Secret").is_none());
+        assert!(find(encrypted.as_slice(), b"/ObjStm").is_none());
+        assert!(find(encrypted.as_slice(), b"/XRef").is_none());
+
+        let document = PdfRawDocument::parse(encrypted.as_slice()).unwrap();
+
+        assert_eq!(get(document.trailer(), "Encrypt").unwrap(), "6 0 R");
+        assert_eq!(get(document.trailer(), "Info").unwrap(), "2 0 R");
+        assert_eq!(get(document.trailer(), "W"), None);
+        assert_eq!(
+            get(&document.object_dictionary(1).unwrap(), "Pages"),
+            Some("4 0 R")
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_save_encrypted_document() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        let font = document.fonts_mut().helvetica();
+
+        document
+            .pages_mut()
+            .create_page_at_start(PdfPagePaperSize::a4())?
+            .objects_mut()
+            .create_text_object(
+                PdfPoints::new(72.0),
+                PdfPoints::new(720.0),
+                "Top secret",
+                font,
+                PdfPoints::new(24.0),
+            )?;
+
+        let options = PdfSaveOptions::new().encrypt(
+            PdfEncryptionOptions::new("user", "owner")
+                .set_permissions(PdfPermissionFlags::PRINT | PdfPermissionFlags::COPY),
+        );
+
+        let bytes = document.save_to_bytes_with_options(&options)?;
+
+        assert!(pdfium
+            .load_pdf_from_byte_slice(bytes.as_slice(), None)
+            .is_err());
+
+        for password in ["user", "owner"] {
+            let document = pdfium.load_pdf_from_byte_slice(bytes.as_slice(), Some(password))?;
+
+            assert!(document.permissions().is_encrypted()?);
+
+            assert_eq!(document.pages().first()?.text()?.all().trim(), "Top secret");
+        }
+
+        let document = pdfium.load_pdf_from_byte_slice(bytes.as_slice(), Some("user"))?;
+
+        let permissions = document.permissions();
+
+        assert!(permissions.can_print()?);
+        assert!(permissions.can_copy()?);
+        assert!(!permissions.can_modify()?);
+        assert!(!permissions.can_annotate()?);
+
+        assert!(matches!(
+            document.save_to_bytes_with_options(&options.incremental(true)),
+            Err(PdfiumError::IncrementalSaveIncompatibleWithEncryption)
+        ));
+
+        Ok(())
+    }
+}
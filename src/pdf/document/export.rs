@@ -0,0 +1,1417 @@
+//! Defines the [PdfExportOptions] struct, a builder-based approach to configuring how
+//! `PdfDocument::export_to_html()` and `PdfDocument::export_to_markdown()` convert the
+//! content of a document into HTML or Markdown, and the [PdfExport] struct holding the
+//! converted content.
+//!
+//! This module is only available when this crate's `export` feature is enabled.
+
+use crate::error::PdfiumError;
+use crate::pdf::document::metadata::PdfDocumentMetadataTagType;
+use crate::pdf::document::page::object::{PdfPageObject, PdfPageObjectCommon};
+use crate::pdf::document::page::objects::common::PdfPageObjectsCommon;
+use crate::pdf::document::page::PdfPage;
+use crate::pdf::document::pages::PdfPageIndex;
+use crate::pdf::document::PdfDocument;
+use crate::pdf::matrix::PdfMatrix;
+use crate::pdf::points::PdfPoints;
+use crate::pdf::rect::PdfRect;
+use std::cmp::Ordering;
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::Path;
+
+#[cfg(doc)]
+use crate::pdf::document::page::text::PdfPageText;
+
+/// Controls how images are included in the output of [PdfDocument::export_to_html()]
+/// and [PdfDocument::export_to_markdown()].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum PdfExportImageHandling {
+    /// Images are not included in the output.
+    Omit,
+
+    /// Each image is encoded as a PNG file and referenced from the output by a relative path.
+    /// The encoded files are returned by [PdfExport::images()]; use
+    /// [PdfExport::save_images_to_directory()] to write them alongside the output.
+    Reference,
+
+    /// Each image is encoded as a PNG file and embedded directly in the output as a
+    /// `data:` URI, so the output is self-contained.
+    Embed,
+}
+
+/// Controls whether the output of [PdfDocument::export_to_html()] reproduces the layout
+/// of each page or reflows its content.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum PdfExportLayout {
+    /// Lines of text are joined into headings and paragraphs, and emitted together with
+    /// images in reading order. The output adapts to the width of the reader's display.
+    Reflowed,
+
+    /// Every line of text and every image is placed at its original position on the page
+    /// using absolutely positioned CSS. Markdown cannot express positioned content,
+    /// so Markdown output is always reflowed.
+    Positioned,
+}
+
+/// Configures how [PdfDocument::export_to_html()] and [PdfDocument::export_to_markdown()]
+/// convert the content of a document.
+///
+/// Text is grouped into lines in reading order using [PdfPageText::lines()]. When a document
+/// is tagged, headings are taken from the heading elements of its structure tree. Otherwise,
+/// headings are inferred by clustering the font sizes used in the document: the size used by
+/// the most text is taken to be the body text size, and lines set in a sufficiently larger
+/// size become headings, with the largest size becoming the top level heading.
+///
+/// Link annotations become hyperlinks. Links to pages within the document point to an
+/// anchor at the start of the target page. Pages are separated by horizontal rules.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PdfExportOptions {
+    image_handling: PdfExportImageHandling,
+    image_directory: String,
+    heading_size_ratio: f32,
+    heading_levels: u8,
+    use_structure_tree: bool,
+    include_outline: bool,
+    layout: PdfExportLayout,
+}
+
+impl PdfExportOptions {
+    /// The ratio between the font size of a line and the body text size at or above which
+    /// the line is considered to be a heading, if no ratio is set.
+    pub const DEFAULT_HEADING_SIZE_RATIO: f32 = 1.2;
+
+    /// Creates a new [PdfExportOptions] object with all settings at their default values.
+    #[inline]
+    pub fn new() -> Self {
+        PdfExportOptions {
+            image_handling: PdfExportImageHandling::Embed,
+            image_directory: "images".to_owned(),
+            heading_size_ratio: Self::DEFAULT_HEADING_SIZE_RATIO,
+            heading_levels: 3,
+            use_structure_tree: true,
+            include_outline: false,
+            layout: PdfExportLayout::Reflowed,
+        }
+    }
+
+    /// Controls how images are included in the output. The default is
+    /// [PdfExportImageHandling::Embed].
+    #[inline]
+    pub fn set_image_handling(mut self, image_handling: PdfExportImageHandling) -> Self {
+        self.image_handling = image_handling;
+
+        self
+    }
+
+    /// Sets the relative directory used in the paths of images referenced from the output
+    /// when using [PdfExportImageHandling::Reference]. The default is `images`. An empty
+    /// directory places images alongside the output.
+    #[inline]
+    pub fn set_image_directory(mut self, directory: &str) -> Self {
+        self.image_directory = directory.trim_end_matches('/').to_owned();
+
+        self
+    }
+
+    /// Sets the ratio between the font size of a line and the body text size at or above
+    /// which the line is considered to be a heading when headings are inferred from font sizes.
+    /// The default is [PdfExportOptions::DEFAULT_HEADING_SIZE_RATIO]. Ratios below `1.0`
+    /// are increased to `1.0`.
+    #[inline]
+    pub fn set_heading_size_ratio(mut self, ratio: f32) -> Self {
+        self.heading_size_ratio = ratio.max(1.0);
+
+        self
+    }
+
+    /// Sets the number of heading levels assigned when headings are inferred from font sizes.
+    /// Each distinct heading font size is assigned its own level, from largest to smallest;
+    /// any sizes smaller than the given number of levels allows for share the lowest level.
+    /// The default is `3`. The number of levels is clamped between `1` and `6`.
+    #[inline]
+    pub fn set_heading_levels(mut self, levels: u8) -> Self {
+        self.heading_levels = levels.clamp(1, 6);
+
+        self
+    }
+
+    /// Controls whether headings are taken from the structure tree of a tagged document.
+    /// If `false`, headings are always inferred from font sizes. The default is `true`.
+    #[inline]
+    pub fn use_structure_tree(mut self, use_structure_tree: bool) -> Self {
+        self.use_structure_tree = use_structure_tree;
+
+        self
+    }
+
+    /// Controls whether the document's bookmarks are included at the start of the output
+    /// as a nested list of links to the pages they point to. The default is `false`.
+    #[inline]
+    pub fn include_outline(mut self, include_outline: bool) -> Self {
+        self.include_outline = include_outline;
+
+        self
+    }
+
+    /// Controls whether HTML output reproduces the layout of each page or reflows its content.
+    /// The default is [PdfExportLayout::Reflowed].
+    #[inline]
+    pub fn set_layout(mut self, layout: PdfExportLayout) -> Self {
+        self.layout = layout;
+
+        self
+    }
+
+    /// Returns how images are included in the output.
+    #[inline]
+    pub fn image_handling(&self) -> PdfExportImageHandling {
+        self.image_handling
+    }
+
+    /// Returns the relative directory used in the paths of referenced images.
+    #[inline]
+    pub fn image_directory(&self) -> &str {
+        self.image_directory.as_str()
+    }
+
+    /// Returns the ratio between the font size of a line and the body text size at or above
+    /// which the line is considered to be a heading.
+    #[inline]
+    pub fn heading_size_ratio(&self) -> f32 {
+        self.heading_size_ratio
+    }
+
+    /// Returns the number of heading levels assigned when headings are inferred from font sizes.
+    #[inline]
+    pub fn heading_levels(&self) -> u8 {
+        self.heading_levels
+    }
+
+    /// Returns `true` if headings are taken from the structure tree of a tagged document.
+    #[inline]
+    pub fn is_using_structure_tree(&self) -> bool {
+        self.use_structure_tree
+    }
+
+    /// Returns `true` if the document's bookmarks are included at the start of the output.
+    #[inline]
+    pub fn is_including_outline(&self) -> bool {
+        self.include_outline
+    }
+
+    /// Returns whether HTML output reproduces the layout of each page or reflows its content.
+    #[inline]
+    pub fn layout(&self) -> PdfExportLayout {
+        self.layout
+    }
+}
+
+impl Default for PdfExportOptions {
+    #[inline]
+    fn default() -> Self {
+        PdfExportOptions::new()
+    }
+}
+
+/// A single image referenced from the output of an export, encoded as a PNG file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PdfExportImage {
+    page_index: PdfPageIndex,
+    path: String,
+    data: Vec<u8>,
+}
+
+impl PdfExportImage {
+    /// Returns the index of the page containing this [PdfExportImage].
+    #[inline]
+    pub fn page_index(&self) -> PdfPageIndex {
+        self.page_index
+    }
+
+    /// Returns the relative path by which this [PdfExportImage] is referenced from the output.
+    #[inline]
+    pub fn path(&self) -> &str {
+        self.path.as_str()
+    }
+
+    /// Returns the PNG-encoded data of this [PdfExportImage].
+    #[inline]
+    pub fn data(&self) -> &[u8] {
+        self.data.as_slice()
+    }
+}
+
+/// The content of a [PdfDocument] converted into HTML or Markdown, as returned by the
+/// [PdfDocument::export_to_html()] and [PdfDocument::export_to_markdown()] functions.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PdfExport {
+    content: String,
+    images: Vec<PdfExportImage>,
+}
+
+impl PdfExport {
+    /// Converts the content of the given [PdfDocument] into the given format.
+    pub(crate) fn from_document(
+        document: &PdfDocument,
+        options: &PdfExportOptions,
+        format: PdfExportFormat,
+    ) -> Result<Self, PdfiumError> {
+        let use_structure_tree = options.is_using_structure_tree() && document.is_tagged();
+
+        let mut images = Vec::new();
+
+        let mut pages = Vec::new();
+
+        for (index, page) in document.pages().iter().enumerate() {
+            pages.push(ExportPage::from_page(
+                index as PdfPageIndex,
+                &page,
+                options,
+                use_structure_tree,
+                &mut images,
+            )?);
+        }
+
+        if !use_structure_tree {
+            infer_heading_levels(&mut pages, options);
+        }
+
+        let outline = if options.is_including_outline() {
+            outline(document)
+        } else {
+            Vec::new()
+        };
+
+        // Only pages that are the target of a link need an anchor.
+
+        let targets = pages
+            .iter()
+            .flat_map(|page| page.lines.iter())
+            .flat_map(|line| line.spans.iter())
+            .filter_map(|span| span.link.as_deref())
+            .chain(outline.iter().map(|entry| entry.link.as_str()))
+            .filter(|link| link.starts_with("#page-"))
+            .map(|link| link.to_owned())
+            .collect::<HashSet<_>>();
+
+        let content = match format {
+            PdfExportFormat::Html => write_html(document, &pages, &outline, options),
+            PdfExportFormat::Markdown => write_markdown(&pages, &outline, &targets),
+        };
+
+        Ok(PdfExport { content, images })
+    }
+
+    /// Returns the converted content.
+    #[inline]
+    pub fn content(&self) -> &str {
+        self.content.as_str()
+    }
+
+    /// Consumes this [PdfExport], returning the converted content.
+    #[inline]
+    pub fn into_content(self) -> String {
+        self.content
+    }
+
+    /// Returns the images referenced from the converted content. Images are only returned
+    /// when using [PdfExportImageHandling::Reference].
+    #[inline]
+    pub fn images(&self) -> &[PdfExportImage] {
+        self.images.as_slice()
+    }
+
+    /// Writes the images referenced from the converted content into the given directory,
+    /// creating any subdirectories needed by their relative paths. The directory should be
+    /// the directory the converted content itself is saved in.
+    pub fn save_images_to_directory(&self, directory: impl AsRef<Path>) -> Result<(), PdfiumError> {
+        for image in self.images.iter() {
+            let path = directory.as_ref().join(image.path());
+
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent).map_err(PdfiumError::IoError)?;
+            }
+
+            fs::write(path, image.data()).map_err(PdfiumError::IoError)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// The output formats supported by [PdfExport].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub(crate) enum PdfExportFormat {
+    Html,
+    Markdown,
+}
+
+/// A run of words in a line of text sharing the same link target, if any.
+#[derive(Debug, Clone, PartialEq)]
+struct ExportSpan {
+    text: String,
+    link: Option<String>,
+}
+
+/// A single line of text on a page.
+#[derive(Debug, Clone, PartialEq)]
+struct ExportLine {
+    spans: Vec<ExportSpan>,
+    bounds: PdfRect,
+    baseline: PdfPoints,
+    font_size: f32,
+    heading: Option<u8>,
+}
+
+impl ExportLine {
+    /// Returns the number of characters in this [ExportLine].
+    fn len(&self) -> usize {
+        self.spans
+            .iter()
+            .map(|span| span.text.chars().count())
+            .sum()
+    }
+}
+
+/// A single image on a page.
+#[derive(Debug, Clone, PartialEq)]
+struct ExportImage {
+    bounds: PdfRect,
+    source: String,
+    alt_text: Option<String>,
+}
+
+/// A heading, paragraph, or image in reflowed output.
+enum ExportBlock<'a> {
+    Heading(u8, Vec<ExportSpan>),
+    Paragraph(Vec<ExportSpan>),
+    Image(&'a ExportImage),
+}
+
+/// The lines of text and images on a single page.
+struct ExportPage {
+    index: PdfPageIndex,
+    width: PdfPoints,
+    height: PdfPoints,
+    lines: Vec<ExportLine>,
+    images: Vec<ExportImage>,
+}
+
+impl ExportPage {
+    fn from_page(
+        index: PdfPageIndex,
+        page: &PdfPage,
+        options: &PdfExportOptions,
+        use_structure_tree: bool,
+        exported_images: &mut Vec<PdfExportImage>,
+    ) -> Result<Self, PdfiumError> {
+        let structure = if use_structure_tree {
+            PageStructure::from_page(page)?
+        } else {
+            PageStructure::default()
+        };
+
+        // Locate the headings and images on the page. Form objects are expanded,
+        // so content placed inside them is found as well.
+
+        let mut headings = Vec::new();
+
+        let mut images = Vec::new();
+
+        let mut visit = |object: &PdfPageObject, matrix: PdfMatrix| -> Result<(), PdfiumError> {
+            let marked_content_ids = object
+                .marks()
+                .iter()
+                .filter_map(|mark| mark.int_param("MCID"))
+                .collect::<Vec<_>>();
+
+            if object.as_text_object().is_some() {
+                if let Some(level) = marked_content_ids
+                    .iter()
+                    .find_map(|id| structure.headings.get(id))
+                {
+                    headings.push((object.bounds()?.transform(matrix).to_rect(), *level));
+                }
+            } else if let Some(image) = object.as_image_object() {
+                if options.image_handling() == PdfExportImageHandling::Omit {
+                    return Ok(());
+                }
+
+                // Images whose pixel data Pdfium cannot decode are left out of the output.
+
+                let bitmap = match image.get_raw_bitmap() {
+                    Ok(bitmap) => bitmap,
+                    Err(_) => return Ok(()),
+                };
+
+                let data = match encode_png(
+                    bitmap.width() as u32,
+                    bitmap.height() as u32,
+                    &bitmap.as_rgba_bytes(),
+                ) {
+                    Some(data) => data,
+                    None => return Ok(()),
+                };
+
+                let source = match options.image_handling() {
+                    PdfExportImageHandling::Reference => {
+                        let name = format!("page-{}-image-{}.png", index + 1, images.len() + 1);
+
+                        let path = if options.image_directory().is_empty() {
+                            name
+                        } else {
+                            format!("{}/{}", options.image_directory(), name)
+                        };
+
+                        exported_images.push(PdfExportImage {
+                            page_index: index,
+                            path: path.clone(),
+                            data,
+                        });
+
+                        path
+                    }
+                    _ => format!("data:image/png;base64,{}", encode_base64(&data)),
+                };
+
+                images.push(ExportImage {
+                    bounds: object.bounds()?.transform(matrix).to_rect(),
+                    source,
+                    alt_text: marked_content_ids
+                        .iter()
+                        .find_map(|id| structure.alt_texts.get(id))
+                        .cloned(),
+                });
+            }
+
+            Ok(())
+        };
+
+        for object in page.objects().iter() {
+            if let PdfPageObject::XObjectForm(form) = &object {
+                for (child, matrix) in form.objects().iter_recursive() {
+                    visit(&child, matrix)?;
+                }
+            } else {
+                visit(&object, PdfMatrix::IDENTITY)?;
+            }
+        }
+
+        images.sort_by(|a, b| {
+            b.bounds
+                .top()
+                .value
+                .partial_cmp(&a.bounds.top().value)
+                .unwrap_or(Ordering::Equal)
+        });
+
+        let links = links(page);
+
+        let text = page.text()?;
+
+        let chars = text.chars();
+
+        let lines = text
+            .lines()
+            .into_iter()
+            .map(|line| {
+                // Take the font size of the line from the largest of the first characters
+                // of its words.
+
+                let font_size = line
+                    .words()
+                    .iter()
+                    .filter_map(|word| word.char_ranges().first())
+                    .filter_map(|range| chars.get(range.start).ok())
+                    .map(|char| char.scaled_font_size().value)
+                    .fold(0.0, f32::max);
+
+                let (x, y) = line.bounds().center();
+
+                let heading = headings
+                    .iter()
+                    .find(|(bounds, _)| bounds.contains(x, y))
+                    .map(|(_, level)| *level);
+
+                let mut spans: Vec<ExportSpan> = Vec::new();
+
+                for word in line.words() {
+                    let (x, y) = word.bounds().center();
+
+                    let link = links
+                        .iter()
+                        .find(|(bounds, _)| bounds.contains(x, y))
+                        .map(|(_, link)| link.clone());
+
+                    push_span(&mut spans, word.text(), link);
+                }
+
+                ExportLine {
+                    spans,
+                    bounds: line.bounds(),
+                    baseline: line.baseline(),
+                    font_size,
+                    heading,
+                }
+            })
+            .filter(|line| !line.spans.is_empty())
+            .collect();
+
+        Ok(ExportPage {
+            index,
+            width: page.width(),
+            height: page.height(),
+            lines,
+            images,
+        })
+    }
+
+    /// Returns the anchor identifying this [ExportPage].
+    fn anchor(&self) -> String {
+        page_anchor(self.index)
+    }
+
+    /// Joins the lines of text on this [ExportPage] into headings and paragraphs,
+    /// interleaving images by their vertical position.
+    fn blocks(&self) -> Vec<ExportBlock<'_>> {
+        let mut blocks = Vec::new();
+
+        let mut images = self.images.iter().peekable();
+
+        let mut previous: Option<&ExportLine> = None;
+
+        for line in self.lines.iter() {
+            while let Some(image) = images.peek() {
+                if image.bounds.top() > line.bounds.top() {
+                    blocks.push(ExportBlock::Image(image));
+                    images.next();
+                    previous = None;
+                } else {
+                    break;
+                }
+            }
+
+            // A line continues the current block if it shares the block's heading level
+            // and font size, and lies directly below the previous line.
+
+            let is_continuation = previous
+                .map(|previous| {
+                    let leading = previous.baseline.value - line.baseline.value;
+
+                    previous.heading == line.heading
+                        && size_cluster(previous.font_size) == size_cluster(line.font_size)
+                        && leading > 0.0
+                        && leading <= line.font_size * 1.5
+                })
+                .unwrap_or(false);
+
+            match blocks.last_mut() {
+                Some(ExportBlock::Heading(_, spans)) | Some(ExportBlock::Paragraph(spans))
+                    if is_continuation =>
+                {
+                    for span in line.spans.iter() {
+                        push_span(spans, &span.text, span.link.clone());
+                    }
+                }
+                _ => {
+                    let spans = line.spans.clone();
+
+                    blocks.push(match line.heading {
+                        Some(level) => ExportBlock::Heading(level, spans),
+                        None => ExportBlock::Paragraph(spans),
+                    });
+                }
+            }
+
+            previous = Some(line);
+        }
+
+        blocks.extend(images.map(ExportBlock::Image));
+
+        blocks
+    }
+}
+
+/// The heading levels and alternative text assigned to marked content by the structure tree
+/// of a single page.
+#[derive(Default)]
+struct PageStructure {
+    headings: HashMap<i32, u8>,
+    alt_texts: HashMap<i32, String>,
+}
+
+impl PageStructure {
+    fn from_page(page: &PdfPage) -> Result<Self, PdfiumError> {
+        let tree = page.struct_tree()?;
+
+        let mut structure = PageStructure::default();
+
+        let mut stack = tree
+            .children()
+            .map(|element| (element, None, None))
+            .collect::<Vec<_>>();
+
+        while let Some((element, parent_level, parent_alt_text)) = stack.pop() {
+            let level = parent_level.or_else(|| match element.element_type().as_deref() {
+                Some("Title") | Some("H") => Some(1),
+                Some(element_type) if element_type.starts_with('H') => element_type[1..]
+                    .parse::<u8>()
+                    .ok()
+                    .filter(|level| (1..=6).contains(level)),
+                _ => None,
+            });
+
+            let alt_text = parent_alt_text.or_else(|| {
+                element
+                    .alt_text()
+                    .filter(|alt_text| !alt_text.trim().is_empty())
+            });
+
+            for id in element.marked_content_ids() {
+                if let Some(level) = level {
+                    structure.headings.insert(id, level);
+                }
+
+                if let Some(alt_text) = alt_text.as_ref() {
+                    structure.alt_texts.insert(id, alt_text.clone());
+                }
+            }
+
+            stack.extend(
+                element
+                    .children()
+                    .map(|child| (child, level, alt_text.clone())),
+            );
+        }
+
+        Ok(structure)
+    }
+}
+
+/// A single entry in the outline of a document.
+struct OutlineEntry {
+    depth: usize,
+    title: String,
+    link: String,
+}
+
+/// Returns the bookmarks of the given [PdfDocument] that point to pages in the document,
+/// in depth-first order.
+fn outline(document: &PdfDocument) -> Vec<OutlineEntry> {
+    let mut entries = Vec::new();
+
+    let mut stack = document
+        .bookmarks()
+        .root()
+        .map(|bookmark| vec![(bookmark, 0)])
+        .unwrap_or_default();
+
+    while let Some((bookmark, depth)) = stack.pop() {
+        if let Some(sibling) = bookmark.next_sibling() {
+            stack.push((sibling, depth));
+        }
+
+        if let Some(child) = bookmark.first_child() {
+            stack.push((child, depth + 1));
+        }
+
+        let page_index = bookmark
+            .destination()
+            .and_then(|destination| destination.page_index().ok());
+
+        if let (Some(title), Some(page_index)) = (bookmark.title(), page_index) {
+            entries.push(OutlineEntry {
+                depth,
+                title,
+                link: page_anchor_link(page_index),
+            });
+        }
+    }
+
+    entries
+}
+
+/// Returns the bounds and targets of the links on the given [PdfPage]. Links to web
+/// resources target their URI; links to pages within the document target the anchor
+/// of the destination page.
+fn links(page: &PdfPage) -> Vec<(PdfRect, String)> {
+    page.links()
+        .iter()
+        .filter_map(|link| {
+            let bounds = link.bounds().ok()?;
+
+            let uri = link
+                .action()
+                .and_then(|action| action.as_uri_action().and_then(|action| action.uri().ok()));
+
+            let target = match uri {
+                Some(uri) => uri,
+                None => page_anchor_link(link.destination()?.page_index().ok()?),
+            };
+
+            Some((bounds, target))
+        })
+        .collect()
+}
+
+/// Adds the given word to the given spans, extending the last span if it has the same
+/// link target.
+fn push_span(spans: &mut Vec<ExportSpan>, text: &str, link: Option<String>) {
+    match spans.last_mut() {
+        Some(span) if span.link == link => {
+            span.text.push(' ');
+            span.text.push_str(text);
+        }
+        _ => spans.push(ExportSpan {
+            text: text.to_owned(),
+            link,
+        }),
+    }
+}
+
+/// Returns the cluster of similar font sizes that the given font size belongs to.
+/// Font sizes are clustered to the nearest half point.
+#[inline]
+fn size_cluster(font_size: f32) -> i32 {
+    (font_size * 2.0).round() as i32
+}
+
+/// Assigns heading levels to the lines on the given pages by clustering their font sizes.
+fn infer_heading_levels(pages: &mut [ExportPage], options: &PdfExportOptions) {
+    let mut lengths = HashMap::new();
+
+    for line in pages.iter().flat_map(|page| page.lines.iter()) {
+        *lengths.entry(size_cluster(line.font_size)).or_insert(0) += line.len();
+    }
+
+    // The body text size is the size used by the most characters. If two sizes are
+    // used equally, the smaller is preferred.
+
+    let body = match lengths
+        .iter()
+        .max_by(|a, b| a.1.cmp(b.1).then(b.0.cmp(a.0)))
+        .map(|(cluster, _)| *cluster)
+    {
+        Some(body) => body,
+        None => return,
+    };
+
+    let mut heading_sizes = lengths
+        .keys()
+        .copied()
+        .filter(|cluster| {
+            *cluster != body && *cluster as f32 >= body as f32 * options.heading_size_ratio()
+        })
+        .collect::<Vec<_>>();
+
+    heading_sizes.sort_unstable_by(|a, b| b.cmp(a));
+
+    for line in pages.iter_mut().flat_map(|page| page.lines.iter_mut()) {
+        let cluster = size_cluster(line.font_size);
+
+        line.heading = heading_sizes
+            .iter()
+            .position(|size| *size == cluster)
+            .map(|position| (position as u8 + 1).min(options.heading_levels()));
+    }
+}
+
+/// Returns the anchor identifying the page at the given index.
+#[inline]
+fn page_anchor(index: PdfPageIndex) -> String {
+    format!("page-{}", index + 1)
+}
+
+/// Returns a link to the anchor identifying the page at the given index.
+#[inline]
+fn page_anchor_link(index: PdfPageIndex) -> String {
+    format!("#{}", page_anchor(index))
+}
+
+/// Formats the given number of points for use in CSS, with at most two decimal places.
+fn format_points(points: PdfPoints) -> String {
+    let formatted = format!("{:.2}", points.value);
+
+    formatted
+        .trim_end_matches('0')
+        .trim_end_matches('.')
+        .to_owned()
+}
+
+/// Escapes the given text for inclusion in HTML content or attribute values.
+fn escape_html(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+
+    for char in text.chars() {
+        match char {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            _ => escaped.push(char),
+        }
+    }
+
+    escaped
+}
+
+/// Escapes the given text for inclusion in Markdown content.
+fn escape_markdown(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+
+    for char in text.chars() {
+        if matches!(char, '\\' | '`' | '*' | '_' | '[' | ']' | '<' | '>' | '#') {
+            escaped.push('\\');
+        }
+
+        escaped.push(char);
+    }
+
+    escaped
+}
+
+/// Escapes the given link target for inclusion in a Markdown link.
+fn escape_markdown_link(link: &str) -> String {
+    link.replace(' ', "%20")
+        .replace('(', "%28")
+        .replace(')', "%29")
+}
+
+/// Writes the given spans as HTML.
+fn html_spans(spans: &[ExportSpan]) -> String {
+    spans
+        .iter()
+        .map(|span| match span.link.as_ref() {
+            Some(link) => format!(
+                "<a href=\"{}\">{}</a>",
+                escape_html(link),
+                escape_html(&span.text)
+            ),
+            None => escape_html(&span.text),
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Writes the given spans as Markdown.
+fn markdown_spans(spans: &[ExportSpan]) -> String {
+    spans
+        .iter()
+        .map(|span| match span.link.as_ref() {
+            Some(link) => format!(
+                "[{}]({})",
+                escape_markdown(&span.text),
+                escape_markdown_link(link)
+            ),
+            None => escape_markdown(&span.text),
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Writes the given image as an HTML `img` element with the given additional attributes.
+fn html_image(image: &ExportImage, attributes: &str) -> String {
+    format!(
+        "<img src=\"{}\" alt=\"{}\"{}>",
+        escape_html(&image.source),
+        escape_html(image.alt_text.as_deref().unwrap_or("")),
+        attributes
+    )
+}
+
+/// Writes the given pages as a complete HTML document.
+fn write_html(
+    document: &PdfDocument,
+    pages: &[ExportPage],
+    outline: &[OutlineEntry],
+    options: &PdfExportOptions,
+) -> String {
+    let mut html = vec!["<!DOCTYPE html>".to_owned()];
+
+    match document.language().ok().flatten() {
+        Some(language) => html.push(format!("<html lang=\"{}\">", escape_html(&language))),
+        None => html.push("<html>".to_owned()),
+    }
+
+    html.push("<head>".to_owned());
+    html.push("<meta charset=\"utf-8\">".to_owned());
+
+    if let Some(title) = document
+        .metadata()
+        .get(PdfDocumentMetadataTagType::Title)
+        .filter(|title| !title.value().is_empty())
+    {
+        html.push(format!("<title>{}</title>", escape_html(title.value())));
+    }
+
+    let is_positioned = options.layout() == PdfExportLayout::Positioned;
+
+    if is_positioned {
+        html.push("<style>".to_owned());
+        html.push(".page { position: relative; overflow: hidden; }".to_owned());
+        html.push(
+            ".page > * { position: absolute; margin: 0; white-space: pre; line-height: 1; }"
+                .to_owned(),
+        );
+        html.push("</style>".to_owned());
+    }
+
+    html.push("</head>".to_owned());
+    html.push("<body>".to_owned());
+
+    if !outline.is_empty() {
+        html.push("<nav>".to_owned());
+
+        html.push("<ul>".to_owned());
+
+        let mut depth = 0;
+
+        for (index, entry) in outline.iter().enumerate() {
+            // Each nested list only ever opens one level deeper than its parent.
+
+            let entry_depth = if index == 0 {
+                0
+            } else {
+                entry.depth.min(depth + 1)
+            };
+
+            if index > 0 {
+                if entry_depth > depth {
+                    html.push("<ul>".to_owned());
+                } else {
+                    html.push("</li>".to_owned());
+
+                    for _ in entry_depth..depth {
+                        html.push("</ul>".to_owned());
+                        html.push("</li>".to_owned());
+                    }
+                }
+            }
+
+            depth = entry_depth;
+
+            html.push(format!(
+                "<li><a href=\"{}\">{}</a>",
+                escape_html(&entry.link),
+                escape_html(&entry.title)
+            ));
+        }
+
+        html.push("</li>".to_owned());
+
+        for _ in 0..depth {
+            html.push("</ul>".to_owned());
+            html.push("</li>".to_owned());
+        }
+
+        html.push("</ul>".to_owned());
+        html.push("</nav>".to_owned());
+    }
+
+    for (index, page) in pages.iter().enumerate() {
+        if index > 0 {
+            html.push("<hr>".to_owned());
+        }
+
+        if is_positioned {
+            html.push(format!(
+                "<section id=\"{}\" class=\"page\" style=\"width: {}pt; height: {}pt;\">",
+                page.anchor(),
+                format_points(page.width),
+                format_points(page.height)
+            ));
+
+            // CSS positions are measured from the top of the page, whereas page
+            // coordinates are measured from the bottom.
+
+            let position = |bounds: &PdfRect| {
+                format!(
+                    "left: {}pt; top: {}pt;",
+                    format_points(bounds.left()),
+                    format_points(page.height - bounds.top())
+                )
+            };
+
+            for line in page.lines.iter() {
+                let style = format!(
+                    " style=\"{} font-size: {}pt;\"",
+                    position(&line.bounds),
+                    format_points(PdfPoints::new(line.font_size))
+                );
+
+                match line.heading {
+                    Some(level) => html.push(format!(
+                        "<h{}{}>{}</h{}>",
+                        level,
+                        style,
+                        html_spans(&line.spans),
+                        level
+                    )),
+                    None => html.push(format!("<div{}>{}</div>", style, html_spans(&line.spans))),
+                }
+            }
+
+            for image in page.images.iter() {
+                html.push(html_image(
+                    image,
+                    &format!(
+                        " style=\"{} width: {}pt; height: {}pt;\"",
+                        position(&image.bounds),
+                        format_points(image.bounds.width()),
+                        format_points(image.bounds.height())
+                    ),
+                ));
+            }
+        } else {
+            html.push(format!("<section id=\"{}\">", page.anchor()));
+
+            for block in page.blocks() {
+                html.push(match block {
+                    ExportBlock::Heading(level, spans) => {
+                        format!("<h{}>{}</h{}>", level, html_spans(&spans), level)
+                    }
+                    ExportBlock::Paragraph(spans) => format!("<p>{}</p>", html_spans(&spans)),
+                    ExportBlock::Image(image) => html_image(image, ""),
+                });
+            }
+        }
+
+        html.push("</section>".to_owned());
+    }
+
+    html.push("</body>".to_owned());
+    html.push("</html>".to_owned());
+
+    html.join("\n") + "\n"
+}
+
+/// Writes the given pages as Markdown. Anchors are only written for the pages in the
+/// given set of link targets.
+fn write_markdown(
+    pages: &[ExportPage],
+    outline: &[OutlineEntry],
+    targets: &HashSet<String>,
+) -> String {
+    let mut markdown = Vec::new();
+
+    if !outline.is_empty() {
+        markdown.push(
+            outline
+                .iter()
+                .map(|entry| {
+                    format!(
+                        "{}- [{}]({})",
+                        "  ".repeat(entry.depth),
+                        escape_markdown(&entry.title),
+                        entry.link
+                    )
+                })
+                .collect::<Vec<_>>()
+                .join("\n"),
+        );
+    }
+
+    for (index, page) in pages.iter().enumerate() {
+        if index > 0 {
+            markdown.push("---".to_owned());
+        }
+
+        if targets.contains(&format!("#{}", page.anchor())) {
+            markdown.push(format!("<a id=\"{}\"></a>", page.anchor()));
+        }
+
+        for block in page.blocks() {
+            markdown.push(match block {
+                ExportBlock::Heading(level, spans) => {
+                    format!("{} {}", "#".repeat(level as usize), markdown_spans(&spans))
+                }
+                ExportBlock::Paragraph(spans) => markdown_spans(&spans),
+                ExportBlock::Image(image) => format!(
+                    "![{}]({})",
+                    escape_markdown(image.alt_text.as_deref().unwrap_or("")),
+                    escape_markdown_link(&image.source)
+                ),
+            });
+        }
+    }
+
+    markdown.join("\n\n") + "\n"
+}
+
+/// Encodes the given pixel data as an 8-bit PNG image. The pixel data must either be
+/// four-channel RGBA or single-channel grayscale, without any row alignment padding.
+/// Returns `None` if the length of the pixel data does not match the given dimensions.
+fn encode_png(width: u32, height: u32, pixels: &[u8]) -> Option<Vec<u8>> {
+    let pixel_count = width as usize * height as usize;
+
+    if pixel_count == 0 {
+        return None;
+    }
+
+    let (color_type, channels) = if pixels.len() == pixel_count * 4 {
+        (6, 4)
+    } else if pixels.len() == pixel_count {
+        (0, 1)
+    } else {
+        return None;
+    };
+
+    // Each row of pixel data is preceded by a filter type byte. No filtering is applied.
+
+    let row_length = width as usize * channels;
+
+    let mut filtered = Vec::with_capacity(pixels.len() + height as usize);
+
+    for row in pixels.chunks_exact(row_length) {
+        filtered.push(0);
+        filtered.extend_from_slice(row);
+    }
+
+    let mut header = Vec::with_capacity(13);
+
+    header.extend_from_slice(&width.to_be_bytes());
+    header.extend_from_slice(&height.to_be_bytes());
+    header.extend_from_slice(&[8, color_type, 0, 0, 0]);
+
+    let mut png = vec![0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A];
+
+    write_png_chunk(&mut png, b"IHDR", &header);
+    write_png_chunk(
+        &mut png,
+        b"IDAT",
+        &miniz_oxide::deflate::compress_to_vec_zlib(&filtered, 6),
+    );
+    write_png_chunk(&mut png, b"IEND", &[]);
+
+    Some(png)
+}
+
+/// Appends a PNG chunk with the given type and data to the given buffer.
+fn write_png_chunk(png: &mut Vec<u8>, chunk_type: &[u8; 4], data: &[u8]) {
+    png.extend_from_slice(&(data.len() as u32).to_be_bytes());
+    png.extend_from_slice(chunk_type);
+    png.extend_from_slice(data);
+
+    let crc = crc32(chunk_type.iter().chain(data.iter()).copied());
+
+    png.extend_from_slice(&crc.to_be_bytes());
+}
+
+/// Returns the CRC-32 checksum of the given bytes, as used by PNG chunks.
+fn crc32(bytes: impl Iterator<Item = u8>) -> u32 {
+    let mut crc = 0xFFFF_FFFFu32;
+
+    for byte in bytes {
+        crc ^= byte as u32;
+
+        for _ in 0..8 {
+            crc = if crc & 1 == 1 {
+                (crc >> 1) ^ 0xEDB8_8320
+            } else {
+                crc >> 1
+            };
+        }
+    }
+
+    !crc
+}
+
+/// Encodes the given bytes using standard, padded Base64.
+fn encode_base64(bytes: &[u8]) -> String {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+    let mut encoded = String::with_capacity((bytes.len() + 2) / 3 * 4);
+
+    for chunk in bytes.chunks(3) {
+        let value = (chunk[0] as u32) << 16
+            | (*chunk.get(1).unwrap_or(&0) as u32) << 8
+            | *chunk.get(2).unwrap_or(&0) as u32;
+
+        for index in 0..4 {
+            if index <= chunk.len() {
+                encoded.push(ALPHABET[(value >> (18 - index * 6)) as usize & 0x3F] as char);
+            } else {
+                encoded.push('=');
+            }
+        }
+    }
+
+    encoded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{crc32, encode_base64, encode_png};
+    use crate::prelude::*;
+    use crate::utils::test::test_bind_to_pdfium;
+    use std::fs;
+
+    #[test]
+    fn test_encode_base64() {
+        assert_eq!(encode_base64(b""), "");
+        assert_eq!(encode_base64(b"f"), "Zg==");
+        assert_eq!(encode_base64(b"fo"), "Zm8=");
+        assert_eq!(encode_base64(b"foo"), "Zm9v");
+        assert_eq!(encode_base64(b"foob"), "Zm9vYg==");
+        assert_eq!(encode_base64(b"fooba"), "Zm9vYmE=");
+        assert_eq!(encode_base64(b"foobar"), "Zm9vYmFy");
+        assert_eq!(encode_base64(&[0xFB, 0xFF]), "+/8=");
+    }
+
+    #[test]
+    fn test_encode_png() {
+        // The CRC of an empty IEND chunk is fixed.
+
+        assert_eq!(crc32(b"IEND".iter().copied()), 0xAE42_6082);
+
+        let png = encode_png(2, 1, &[255, 0, 0, 255, 0, 0, 255, 255]).unwrap();
+
+        assert_eq!(
+            &png[0..8],
+            &[0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A]
+        );
+        assert_eq!(&png[12..16], b"IHDR");
+        assert_eq!(&png[16..20], &2u32.to_be_bytes());
+        assert_eq!(&png[20..24], &1u32.to_be_bytes());
+        assert_eq!(png[25], 6);
+        assert_eq!(
+            &png[png.len() - 12..],
+            &[0, 0, 0, 0, b'I', b'E', b'N', b'D', 0xAE, 0x42, 0x60, 0x82]
+        );
+
+        // The image data decompresses to one filtered row.
+
+        let idat_length = u32::from_be_bytes([png[33], png[34], png[35], png[36]]) as usize;
+
+        assert_eq!(&png[37..41], b"IDAT");
+        assert_eq!(
+            miniz_oxide::inflate::decompress_to_vec_zlib(&png[41..41 + idat_length]).unwrap(),
+            vec![0, 255, 0, 0, 255, 0, 0, 255, 255]
+        );
+
+        let gray = encode_png(2, 2, &[0, 64, 128, 255]).unwrap();
+
+        assert_eq!(gray[25], 0);
+
+        assert!(encode_png(2, 2, &[0, 0, 0]).is_none());
+        assert!(encode_png(0, 0, &[]).is_none());
+    }
+
+    #[test]
+    fn test_export_golden_files() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let options = PdfExportOptions::new().set_image_handling(PdfExportImageHandling::Reference);
+
+        for name in ["export-tagged-test", "export-untagged-test"] {
+            let document = pdfium.load_pdf_from_file(&format!("./test/{}.pdf", name), None)?;
+
+            let html = document.export_to_html(&options)?;
+
+            assert_eq!(
+                html.content(),
+                fs::read_to_string(format!("./test/{}.html", name)).unwrap()
+            );
+
+            let markdown = document.export_to_markdown(&options)?;
+
+            assert_eq!(
+                markdown.content(),
+                fs::read_to_string(format!("./test/{}.md", name)).unwrap()
+            );
+
+            // Each fixture contains a single image on its first page.
+
+            assert_eq!(markdown.images().len(), 1);
+            assert_eq!(markdown.images()[0].page_index(), 0);
+            assert_eq!(markdown.images()[0].path(), "images/page-1-image-1.png");
+            assert_eq!(&markdown.images()[0].data()[1..4], b"PNG");
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_export_options() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let document = pdfium.load_pdf_from_file("./test/export-untagged-test.pdf", None)?;
+
+        // Embedded images are written as data URIs, and are not returned separately.
+
+        let embedded = document.export_to_markdown(&PdfExportOptions::new())?;
+
+        assert!(embedded
+            .content()
+            .contains("![](data:image/png;base64,iVBORw0KGgo"));
+        assert!(embedded.images().is_empty());
+
+        let omitted = document.export_to_markdown(
+            &PdfExportOptions::new().set_image_handling(PdfExportImageHandling::Omit),
+        )?;
+
+        assert!(!omitted.content().contains("!["));
+
+        // Raising the heading threshold above the size of the second page's heading
+        // turns it into body text.
+
+        let markdown = document.export_to_markdown(
+            &PdfExportOptions::new()
+                .set_image_handling(PdfExportImageHandling::Omit)
+                .set_heading_size_ratio(1.6),
+        )?;
+
+        assert!(markdown.content().contains("# Export Test\n"));
+        assert!(markdown.content().contains("\nSecond Page\n"));
+
+        // Positioned output places every line and image absolutely.
+
+        let positioned = document.export_to_html(
+            &PdfExportOptions::new()
+                .set_image_handling(PdfExportImageHandling::Reference)
+                .set_layout(PdfExportLayout::Positioned),
+        )?;
+
+        assert!(positioned.content().contains(
+            "<section id=\"page-1\" class=\"page\" style=\"width: 300pt; height: 300pt;\">"
+        ));
+        assert!(positioned.content().contains("<h1 style=\"left: "));
+        assert!(positioned.content().contains(
+            "<img src=\"images/page-1-image-1.png\" alt=\"\" style=\"left: 20pt; top: 220pt; width: 60pt; height: 60pt;\">"
+        ));
+        assert!(positioned.content().contains("<hr>"));
+
+        // The outline links to the pages the document's bookmarks point to.
+
+        let outlined = document.export_to_markdown(
+            &PdfExportOptions::new()
+                .set_image_handling(PdfExportImageHandling::Omit)
+                .include_outline(true),
+        )?;
+
+        assert!(outlined.content().starts_with(
+            "- [Export Test](#page-1)\n  - [Second Page](#page-2)\n\n<a id=\"page-1\"></a>\n\n"
+        ));
+        assert!(outlined
+            .content()
+            .contains("---\n\n<a id=\"page-2\"></a>\n\n## Second Page\n"));
+
+        // Ignoring the structure tree of a tagged document infers headings from font sizes.
+
+        let tagged = pdfium.load_pdf_from_file("./test/export-tagged-test.pdf", None)?;
+
+        let inferred = tagged.export_to_markdown(
+            &PdfExportOptions::new()
+                .set_image_handling(PdfExportImageHandling::Omit)
+                .use_structure_tree(false),
+        )?;
+
+        assert_eq!(
+            inferred.content(),
+            "Tagged Heading\n\n# Large paragraph\n\nSubsection\n\nBody text.\n"
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,298 @@
+//! Defines the [PdfFetchOptions] struct, a builder-based approach to configuring how
+//! `Pdfium::load_pdf_from_fetch()` downloads document data over the network.
+
+#[cfg(target_arch = "wasm32")]
+use {
+    crate::error::PdfiumError,
+    crate::pdf::document::availability::{PdfDataProvider, PdfRangeBuffer},
+    js_sys::{ArrayBuffer, Uint8Array},
+    std::ops::Range,
+    wasm_bindgen::JsCast,
+    wasm_bindgen_futures::JsFuture,
+    web_sys::{window, Headers, RequestInit, Response},
+};
+
+#[cfg(doc)]
+use crate::{pdf::document::availability::PdfDocumentAvailability, pdfium::Pdfium};
+
+/// Configures how [Pdfium::load_pdf_from_fetch()] downloads document data.
+///
+/// By default, document data is downloaded in chunks of [PdfFetchOptions::DEFAULT_CHUNK_SIZE]
+/// bytes using HTTP `Range` requests, so that the first page of a large document can be
+/// displayed before the remainder of the document has been downloaded. Only the chunks
+/// Pdfium asks for are downloaded. If the server does not support range requests,
+/// the entire document is downloaded instead.
+///
+/// Data for pages other than the first is downloaded on request; use
+/// [PdfDocumentAvailability::fetch_page()] to download the data for a page before accessing it.
+///
+/// This struct is only available when compiling to WASM.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PdfFetchOptions {
+    password: Option<String>,
+    chunk_size: usize,
+    use_range_requests: bool,
+}
+
+impl PdfFetchOptions {
+    /// The number of bytes requested in each range request if no chunk size is set.
+    pub const DEFAULT_CHUNK_SIZE: usize = 64 * 1024;
+
+    /// The smallest chunk size that can be set. Pdfium needs at least the first 1 kB of
+    /// document data in order to determine whether a document is linearized.
+    pub const MINIMUM_CHUNK_SIZE: usize = 1024;
+
+    /// Creates a new [PdfFetchOptions] object with all settings at their default values.
+    #[inline]
+    pub fn new() -> Self {
+        PdfFetchOptions {
+            password: None,
+            chunk_size: Self::DEFAULT_CHUNK_SIZE,
+            use_range_requests: true,
+        }
+    }
+
+    /// Unlocks a password protected document using the given password.
+    #[inline]
+    pub fn set_password(mut self, password: &str) -> Self {
+        self.password = Some(password.to_owned());
+
+        self
+    }
+
+    /// Downloads document data in chunks of the given number of bytes. Larger chunks mean
+    /// fewer round trips to the server, at the expense of downloading data that may not be
+    /// needed. Chunk sizes smaller than [PdfFetchOptions::MINIMUM_CHUNK_SIZE] are increased
+    /// to that size.
+    #[inline]
+    pub fn set_chunk_size(mut self, chunk_size: usize) -> Self {
+        self.chunk_size = chunk_size.max(Self::MINIMUM_CHUNK_SIZE);
+
+        self
+    }
+
+    /// Controls whether document data is downloaded on demand using HTTP `Range` requests.
+    /// If `false`, the entire document is downloaded before it is opened. The default is `true`.
+    #[inline]
+    pub fn use_range_requests(mut self, use_range_requests: bool) -> Self {
+        self.use_range_requests = use_range_requests;
+
+        self
+    }
+
+    /// Returns the password used to unlock the document, if any.
+    #[inline]
+    pub fn password(&self) -> Option<&str> {
+        self.password.as_deref()
+    }
+
+    /// Returns the number of bytes requested in each range request.
+    #[inline]
+    pub fn chunk_size(&self) -> usize {
+        self.chunk_size
+    }
+
+    /// Returns `true` if document data is downloaded on demand using HTTP `Range` requests.
+    #[inline]
+    pub fn is_using_range_requests(&self) -> bool {
+        self.use_range_requests
+    }
+}
+
+impl Default for PdfFetchOptions {
+    #[inline]
+    fn default() -> Self {
+        PdfFetchOptions::new()
+    }
+}
+
+impl<'a> From<Option<&'a str>> for PdfFetchOptions {
+    /// Creates a new [PdfFetchOptions] object with default settings and the given password,
+    /// if any.
+    #[inline]
+    fn from(password: Option<&'a str>) -> Self {
+        match password {
+            Some(password) => PdfFetchOptions::new().set_password(password),
+            None => PdfFetchOptions::new(),
+        }
+    }
+}
+
+/// The result of the first request made by a [PdfRangeFetcher].
+#[cfg(target_arch = "wasm32")]
+pub(crate) enum PdfFetchResult {
+    /// The server supports range requests. Further chunks can be downloaded on demand.
+    Ranged(PdfRangeFetcher),
+
+    /// The server returned the entire document.
+    Complete(Vec<u8>),
+}
+
+/// Downloads chunks of document data from a URL into a [PdfRangeBuffer] using
+/// HTTP `Range` requests.
+#[cfg(target_arch = "wasm32")]
+pub(crate) struct PdfRangeFetcher {
+    url: String,
+    chunk_size: usize,
+    buffer: PdfRangeBuffer,
+}
+
+#[cfg(target_arch = "wasm32")]
+impl PdfRangeFetcher {
+    /// Requests the first chunk of document data from the given URL. If the server responds
+    /// with partial content, the total length of the document is taken from the response's
+    /// `Content-Range` header and a [PdfRangeFetcher] is returned for downloading further
+    /// chunks. Otherwise, the server has ignored the range request and returned the entire
+    /// document, or range requests are disabled, and the complete document data is returned.
+    pub(crate) async fn connect(
+        url: &str,
+        options: &PdfFetchOptions,
+    ) -> Result<PdfFetchResult, PdfiumError> {
+        if !options.is_using_range_requests() {
+            let response = Self::fetch(url, None).await?;
+
+            return Ok(PdfFetchResult::Complete(Self::body(&response).await?));
+        }
+
+        let response = Self::fetch(url, Some(0..options.chunk_size())).await?;
+
+        // The Content-Range header is only readable for cross-origin requests if the server
+        // exposes it using the Access-Control-Expose-Headers header. If the total length
+        // is unknown, we fall back to downloading the entire document.
+
+        let content_length = if response.status() == 206 {
+            response
+                .headers()
+                .get("Content-Range")
+                .ok()
+                .flatten()
+                .and_then(|header| Self::parse_content_range_length(&header))
+        } else {
+            None
+        };
+
+        match content_length {
+            Some(content_length) => {
+                let buffer = PdfRangeBuffer::new(content_length);
+
+                buffer.insert(0, Self::body(&response).await?);
+
+                Ok(PdfFetchResult::Ranged(PdfRangeFetcher {
+                    url: url.to_owned(),
+                    chunk_size: options.chunk_size(),
+                    buffer,
+                }))
+            }
+            None if response.status() == 200 => {
+                Ok(PdfFetchResult::Complete(Self::body(&response).await?))
+            }
+            None => {
+                let response = Self::fetch(url, None).await?;
+
+                Ok(PdfFetchResult::Complete(Self::body(&response).await?))
+            }
+        }
+    }
+
+    /// Returns the [PdfRangeBuffer] holding the document data downloaded by this fetcher.
+    #[inline]
+    pub(crate) fn buffer(&self) -> &PdfRangeBuffer {
+        &self.buffer
+    }
+
+    /// Downloads the document data needed to satisfy the given download hints, returning
+    /// `false` if there was nothing left to download.
+    ///
+    /// If Pdfium provided no hints, the first chunk of document data that has not yet been
+    /// downloaded is requested instead.
+    pub(crate) async fn fetch_hints(
+        &self,
+        hints: impl IntoIterator<Item = Range<usize>>,
+    ) -> Result<bool, PdfiumError> {
+        let mut ranges = self.buffer.ranges_to_fetch(hints, self.chunk_size);
+
+        if ranges.is_empty() {
+            ranges = self
+                .buffer
+                .missing(0..self.buffer.content_length())
+                .into_iter()
+                .take(1)
+                .map(|range| range.start..range.end.min(range.start + self.chunk_size))
+                .collect();
+        }
+
+        if ranges.is_empty() {
+            return Ok(false);
+        }
+
+        for range in ranges {
+            let response = Self::fetch(&self.url, Some(range.clone())).await?;
+
+            if response.status() != 206 {
+                // The server has stopped honouring range requests.
+
+                return Err(PdfiumError::WebSysFetchStatusError(response.status()));
+            }
+
+            self.buffer
+                .insert(range.start, Self::body(&response).await?);
+        }
+
+        Ok(true)
+    }
+
+    /// Requests the given range of bytes from the given URL, or the entire resource
+    /// if no range is given.
+    async fn fetch(url: &str, range: Option<Range<usize>>) -> Result<Response, PdfiumError> {
+        let window = window().ok_or(PdfiumError::WebSysWindowObjectNotAvailable)?;
+
+        let init = RequestInit::new();
+
+        init.set_method("GET");
+
+        if let Some(range) = range {
+            let headers = Headers::new().map_err(PdfiumError::WebSysFetchError)?;
+
+            headers
+                .set(
+                    "Range",
+                    format!("bytes={}-{}", range.start, range.end.saturating_sub(1)).as_str(),
+                )
+                .map_err(PdfiumError::WebSysFetchError)?;
+
+            init.set_headers(&headers.into());
+        }
+
+        let response: Response = JsFuture::from(window.fetch_with_str_and_init(url, &init))
+            .await
+            .map_err(PdfiumError::WebSysFetchError)?
+            .dyn_into()
+            .map_err(|_| PdfiumError::WebSysInvalidResponseError)?;
+
+        if response.ok() {
+            Ok(response)
+        } else {
+            Err(PdfiumError::WebSysFetchStatusError(response.status()))
+        }
+    }
+
+    /// Returns the body of the given response.
+    async fn body(response: &Response) -> Result<Vec<u8>, PdfiumError> {
+        let array_buffer: ArrayBuffer = JsFuture::from(
+            response
+                .array_buffer()
+                .map_err(PdfiumError::WebSysFetchError)?,
+        )
+        .await
+        .map_err(PdfiumError::WebSysFetchError)?
+        .into();
+
+        Ok(Uint8Array::new(&array_buffer).to_vec())
+    }
+
+    /// Returns the complete length of a resource from a `Content-Range` header value
+    /// such as `bytes 0-1023/146515`.
+    fn parse_content_range_length(header: &str) -> Option<usize> {
+        header.rsplit('/').next()?.trim().parse().ok()
+    }
+}
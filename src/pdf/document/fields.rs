@@ -0,0 +1,488 @@
+//! Defines the [PdfFormFields] struct, a collection of all the form field widgets
+//! on every page of a `PdfDocument`.
+
+use crate::bindgen::{FPDF_FORMHANDLE, FPDF_WIDESTRING};
+use crate::bindings::PdfiumLibraryBindings;
+use crate::error::{PdfiumError, PdfiumInternalError};
+use crate::pdf::document::page::annotation::PdfPageAnnotation;
+use crate::pdf::document::page::annotations::PdfPageAnnotationIndex;
+use crate::pdf::document::page::field::options::{PdfFormFieldOptionIndex, PdfFormFieldOptions};
+use crate::pdf::document::page::field::private::internal::PdfFormFieldPrivate;
+use crate::pdf::document::page::field::{PdfFormField, PdfFormFieldCommon, PdfFormFieldType};
+use crate::pdf::document::page::PdfPage;
+use crate::pdf::document::pages::{PdfPageIndex, PdfPages};
+use crate::utils::utf16le::get_pdfium_utf16le_words_from_str;
+use std::os::raw::c_int;
+use std::rc::Rc;
+
+#[cfg(doc)]
+use {crate::pdf::document::form::PdfForm, crate::pdf::document::PdfDocument};
+
+// The character code Pdfium's form fill module interprets as a press of the space bar,
+// toggling the state of a focused checkbox or radio button.
+const SPACE_CHAR_CODE: c_int = 0x20;
+
+/// The collection of all form field widgets on every page of a [PdfDocument],
+/// as returned by [PdfForm::fields()].
+///
+/// Unlike the form field objects available by unwrapping individual page annotations,
+/// the [PdfFormFieldWidget] objects yielded by this collection update field values through
+/// Pdfium's form fill module. This means Pdfium regenerates the appearance stream of each
+/// widget as its value changes, so the updated values are visible in other PDF viewers
+/// once the document is saved, and are retained if the page is later flattened.
+pub struct PdfFormFields<'a> {
+    form_handle: FPDF_FORMHANDLE,
+    pages: &'a PdfPages<'a>,
+    bindings: &'a dyn PdfiumLibraryBindings,
+}
+
+impl<'a> PdfFormFields<'a> {
+    #[inline]
+    pub(crate) fn from_pdfium(
+        form_handle: FPDF_FORMHANDLE,
+        pages: &'a PdfPages<'a>,
+        bindings: &'a dyn PdfiumLibraryBindings,
+    ) -> Self {
+        PdfFormFields {
+            form_handle,
+            pages,
+            bindings,
+        }
+    }
+
+    /// Returns the [PdfiumLibraryBindings] used by this [PdfFormFields] collection.
+    #[inline]
+    pub fn bindings(&self) -> &'a dyn PdfiumLibraryBindings {
+        self.bindings
+    }
+
+    /// Returns the first [PdfFormFieldWidget] in this [PdfFormFields] collection with the
+    /// given field name, if any.
+    pub fn get(&self, name: &str) -> Option<PdfFormFieldWidget<'a>> {
+        self.iter()
+            .find(|widget| widget.name().as_deref() == Some(name))
+    }
+
+    /// Returns an iterator over all the form field widgets in this [PdfFormFields] collection,
+    /// in page order.
+    #[inline]
+    pub fn iter(&self) -> PdfFormFieldsIterator<'a> {
+        PdfFormFieldsIterator::new(self.form_handle, self.pages, self.bindings)
+    }
+}
+
+/// A [PdfPage] that has been registered with Pdfium's form fill module. Pdfium requires
+/// that form fill operations only be performed on pages registered in this way.
+struct PdfFormFieldsPage<'a> {
+    page: PdfPage<'a>,
+    form_handle: FPDF_FORMHANDLE,
+    bindings: &'a dyn PdfiumLibraryBindings,
+}
+
+impl<'a> PdfFormFieldsPage<'a> {
+    fn new(
+        page: PdfPage<'a>,
+        form_handle: FPDF_FORMHANDLE,
+        bindings: &'a dyn PdfiumLibraryBindings,
+    ) -> Self {
+        bindings.FORM_OnAfterLoadPage(page.page_handle(), form_handle);
+
+        PdfFormFieldsPage {
+            page,
+            form_handle,
+            bindings,
+        }
+    }
+}
+
+impl<'a> Drop for PdfFormFieldsPage<'a> {
+    #[inline]
+    fn drop(&mut self) {
+        self.bindings
+            .FORM_OnBeforeClosePage(self.page.page_handle(), self.form_handle);
+    }
+}
+
+/// A single form field widget in a [PdfFormFields] collection.
+///
+/// Values set using this widget are entered through Pdfium's form fill module, exactly
+/// as if a user had typed into or clicked on the widget in an interactive viewer.
+/// Pdfium regenerates the widget's appearance stream to reflect each change.
+pub struct PdfFormFieldWidget<'a> {
+    // The annotation must be dropped before the page that contains it,
+    // so it is declared first.
+    annotation: PdfPageAnnotation<'a>,
+    page: Rc<PdfFormFieldsPage<'a>>,
+    page_index: PdfPageIndex,
+}
+
+impl<'a> PdfFormFieldWidget<'a> {
+    /// Returns the index of the page containing this [PdfFormFieldWidget].
+    #[inline]
+    pub fn page_index(&self) -> PdfPageIndex {
+        self.page_index
+    }
+
+    /// Returns the [PdfPageAnnotation] wrapping this [PdfFormFieldWidget].
+    #[inline]
+    pub fn annotation(&self) -> &PdfPageAnnotation<'a> {
+        &self.annotation
+    }
+
+    /// Returns the underlying [PdfFormField] of this [PdfFormFieldWidget].
+    #[inline]
+    pub fn field(&self) -> &PdfFormField<'_> {
+        // The iterator only yields widgets for annotations containing a form field.
+
+        self.annotation.as_form_field().unwrap()
+    }
+
+    /// Returns the name of this [PdfFormFieldWidget], if any.
+    #[inline]
+    pub fn name(&self) -> Option<String> {
+        self.field().name()
+    }
+
+    /// Returns the [PdfFormFieldType] of this [PdfFormFieldWidget].
+    #[inline]
+    pub fn field_type(&self) -> PdfFormFieldType {
+        self.field().field_type()
+    }
+
+    /// Returns the value of this [PdfFormFieldWidget], if any.
+    ///
+    /// For text fields, this is the text entered into the field. For combo box and
+    /// list box fields, this is the label of the first selected option. For checkboxes
+    /// and radio buttons, this is the value of the widget's control group.
+    pub fn value(&self) -> Option<String> {
+        match self.field() {
+            PdfFormField::Text(field) => field.value(),
+            PdfFormField::ComboBox(field) => field.value(),
+            PdfFormField::ListBox(field) => field.value(),
+            PdfFormField::Checkbox(field) => field.group_value(),
+            PdfFormField::RadioButton(field) => field.group_value(),
+            PdfFormField::PushButton(_) | PdfFormField::Signature(_) | PdfFormField::Unknown(_) => {
+                None
+            }
+        }
+    }
+
+    /// Sets the value of this [PdfFormFieldWidget] by replacing any existing text in the field
+    /// with the given value.
+    ///
+    /// Only text fields and editable combo box fields accept text input. Returns
+    /// [PdfiumError::FormFieldTypeDoesNotSupportOperation] for any other type of field.
+    pub fn set_value(&mut self, value: &str) -> Result<(), PdfiumError> {
+        match self.field_type() {
+            PdfFormFieldType::Text | PdfFormFieldType::ComboBox => {}
+            _ => return Err(PdfiumError::FormFieldTypeDoesNotSupportOperation),
+        }
+
+        self.focus()?;
+
+        let bindings = self.bindings();
+
+        let page_handle = self.page.page.page_handle();
+
+        bindings.FORM_SelectAllText(self.page.form_handle, page_handle);
+
+        let value = get_pdfium_utf16le_words_from_str(value);
+
+        bindings.FORM_ReplaceSelection(
+            self.page.form_handle,
+            page_handle,
+            value.as_ptr() as FPDF_WIDESTRING,
+        );
+
+        self.kill_focus()
+    }
+
+    /// Returns `true` if this [PdfFormFieldWidget] is a checked checkbox or radio button.
+    ///
+    /// Returns [PdfiumError::FormFieldTypeDoesNotSupportOperation] for any other type of field.
+    pub fn is_checked(&self) -> Result<bool, PdfiumError> {
+        match self.field() {
+            PdfFormField::Checkbox(field) => field.is_checked(),
+            PdfFormField::RadioButton(field) => field.is_checked(),
+            _ => Err(PdfiumError::FormFieldTypeDoesNotSupportOperation),
+        }
+    }
+
+    /// Checks or clears this [PdfFormFieldWidget], which must be a checkbox or radio button.
+    ///
+    /// Checking a radio button clears all other radio buttons in its control group. A radio
+    /// button cannot be cleared directly; check a different radio button in the same control
+    /// group instead. Returns [PdfiumError::FormFieldTypeDoesNotSupportOperation] if this
+    /// widget is not a checkbox or radio button, or if an attempt is made to clear
+    /// a radio button.
+    pub fn set_checked(&mut self, is_checked: bool) -> Result<(), PdfiumError> {
+        if self.is_checked()? == is_checked {
+            return Ok(());
+        }
+
+        if self.field_type() == PdfFormFieldType::RadioButton && !is_checked {
+            return Err(PdfiumError::FormFieldTypeDoesNotSupportOperation);
+        }
+
+        self.focus()?;
+
+        self.bindings().FORM_OnChar(
+            self.page.form_handle,
+            self.page.page.page_handle(),
+            SPACE_CHAR_CODE,
+            0,
+        );
+
+        self.kill_focus()?;
+
+        if self.is_checked()? == is_checked {
+            Ok(())
+        } else {
+            Err(PdfiumError::PdfiumLibraryInternalError(
+                PdfiumInternalError::Unknown,
+            ))
+        }
+    }
+
+    /// Returns the collection of selectable options in this [PdfFormFieldWidget], which
+    /// must be a combo box or list box.
+    ///
+    /// Returns [PdfiumError::FormFieldTypeDoesNotSupportOperation] for any other type of field.
+    pub fn options(&self) -> Result<&PdfFormFieldOptions<'_>, PdfiumError> {
+        match self.field() {
+            PdfFormField::ComboBox(field) => Ok(field.options()),
+            PdfFormField::ListBox(field) => Ok(field.options()),
+            _ => Err(PdfiumError::FormFieldTypeDoesNotSupportOperation),
+        }
+    }
+
+    /// Returns the indices of all currently selected options in this [PdfFormFieldWidget],
+    /// which must be a combo box or list box.
+    ///
+    /// Returns [PdfiumError::FormFieldTypeDoesNotSupportOperation] for any other type of field.
+    pub fn selected_indices(&self) -> Result<Vec<PdfFormFieldOptionIndex>, PdfiumError> {
+        Ok(self
+            .options()?
+            .iter()
+            .filter(|option| option.is_set())
+            .map(|option| option.index())
+            .collect())
+    }
+
+    /// Selects or deselects the option at the given index in this [PdfFormFieldWidget],
+    /// which must be a combo box or list box. Selecting an option in a combo box or
+    /// a single-selection list box deselects any previously selected option.
+    ///
+    /// Returns [PdfiumError::FormFieldTypeDoesNotSupportOperation] for any other type of field.
+    pub fn set_option_selected(
+        &mut self,
+        index: PdfFormFieldOptionIndex,
+        is_selected: bool,
+    ) -> Result<(), PdfiumError> {
+        if index >= self.options()?.len() {
+            return Err(PdfiumError::FormFieldOptionIndexOutOfBounds);
+        }
+
+        self.focus()?;
+
+        let bindings = self.bindings();
+
+        let result = bindings.to_result(bindings.FORM_SetIndexSelected(
+            self.page.form_handle,
+            self.page.page.page_handle(),
+            index as c_int,
+            bindings.bool_to_pdfium(is_selected),
+        ));
+
+        self.kill_focus().and(result)
+    }
+
+    /// Gives this [PdfFormFieldWidget] the input focus within Pdfium's form fill module.
+    #[inline]
+    fn focus(&self) -> Result<(), PdfiumError> {
+        self.bindings().to_result(
+            self.bindings()
+                .FORM_SetFocusedAnnot(self.page.form_handle, *self.field().annotation_handle()),
+        )
+    }
+
+    /// Removes the input focus from this [PdfFormFieldWidget], committing any value changes
+    /// and regenerating the widget's appearance stream.
+    #[inline]
+    fn kill_focus(&self) -> Result<(), PdfiumError> {
+        self.bindings()
+            .to_result(self.bindings().FORM_ForceToKillFocus(self.page.form_handle))
+    }
+
+    #[inline]
+    fn bindings(&self) -> &'a dyn PdfiumLibraryBindings {
+        self.page.bindings
+    }
+}
+
+/// An iterator over all the [PdfFormFieldWidget] objects in a [PdfFormFields] collection.
+pub struct PdfFormFieldsIterator<'a> {
+    form_handle: FPDF_FORMHANDLE,
+    pages: &'a PdfPages<'a>,
+    bindings: &'a dyn PdfiumLibraryBindings,
+    next_page_index: PdfPageIndex,
+    page: Option<(PdfPageIndex, Rc<PdfFormFieldsPage<'a>>)>,
+    next_annotation_index: PdfPageAnnotationIndex,
+}
+
+impl<'a> PdfFormFieldsIterator<'a> {
+    #[inline]
+    pub(crate) fn new(
+        form_handle: FPDF_FORMHANDLE,
+        pages: &'a PdfPages<'a>,
+        bindings: &'a dyn PdfiumLibraryBindings,
+    ) -> Self {
+        PdfFormFieldsIterator {
+            form_handle,
+            pages,
+            bindings,
+            next_page_index: 0,
+            page: None,
+            next_annotation_index: 0,
+        }
+    }
+}
+
+impl<'a> Iterator for PdfFormFieldsIterator<'a> {
+    type Item = PdfFormFieldWidget<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if self.page.is_none() {
+                if self.next_page_index >= self.pages.len() {
+                    return None;
+                }
+
+                let page_index = self.next_page_index;
+
+                self.next_page_index += 1;
+
+                if let Ok(page) = self.pages.get(page_index) {
+                    self.page = Some((
+                        page_index,
+                        Rc::new(PdfFormFieldsPage::new(
+                            page,
+                            self.form_handle,
+                            self.bindings,
+                        )),
+                    ));
+                    self.next_annotation_index = 0;
+                }
+
+                continue;
+            }
+
+            let (page_index, page) = self.page.as_ref().unwrap();
+
+            let annotations = page.page.annotations();
+
+            if self.next_annotation_index >= annotations.len() {
+                self.page = None;
+
+                continue;
+            }
+
+            let annotation = annotations.get(self.next_annotation_index);
+
+            self.next_annotation_index += 1;
+
+            if let Ok(annotation) = annotation {
+                if annotation.as_form_field().is_some() {
+                    return Some(PdfFormFieldWidget {
+                        annotation,
+                        page: page.clone(),
+                        page_index: *page_index,
+                    });
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::utils::test::test_bind_to_pdfium;
+
+    #[test]
+    fn test_fill_and_flatten_round_trip() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let document = pdfium.load_pdf_from_file("./test/form-test.pdf", None)?;
+
+        let form = document.form().unwrap();
+
+        let fields = form.fields(document.pages());
+
+        let mut text = fields
+            .iter()
+            .find(|widget| widget.field_type() == PdfFormFieldType::Text)
+            .unwrap();
+
+        let text_name = text.name().unwrap();
+
+        text.set_value("pdfium-render")?;
+
+        assert_eq!(text.value().as_deref(), Some("pdfium-render"));
+
+        assert!(text.field().appearance_stream().is_some());
+
+        let text_page_index = text.page_index();
+
+        drop(text);
+
+        let mut checkbox = fields
+            .iter()
+            .find(|widget| widget.field_type() == PdfFormFieldType::Checkbox)
+            .unwrap();
+
+        let checkbox_name = checkbox.name().unwrap();
+
+        let was_checked = checkbox.is_checked()?;
+
+        checkbox.set_checked(!was_checked)?;
+
+        assert_eq!(checkbox.is_checked()?, !was_checked);
+
+        drop(checkbox);
+
+        let bytes = document.save_to_bytes()?;
+
+        let document = pdfium.load_pdf_from_byte_vec(bytes, None)?;
+
+        let form = document.form().unwrap();
+
+        let fields = form.fields(document.pages());
+
+        assert_eq!(
+            fields.get(&text_name).unwrap().value().as_deref(),
+            Some("pdfium-render")
+        );
+
+        assert_eq!(
+            fields.get(&checkbox_name).unwrap().is_checked()?,
+            !was_checked
+        );
+
+        let mut page = document.pages().get(text_page_index)?;
+
+        assert_eq!(
+            page.flatten(PdfPageFlattenMode::Print)?,
+            PdfPageFlattenResult::Success
+        );
+
+        assert!(page
+            .annotations()
+            .iter()
+            .all(|annotation| annotation.as_form_field().is_none()));
+
+        assert!(page.text()?.all().contains("pdfium-render"));
+
+        Ok(())
+    }
+}
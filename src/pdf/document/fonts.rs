@@ -9,6 +9,12 @@ use std::collections::HashMap;
 use std::io::Read;
 use std::os::raw::{c_int, c_uint};
 
+#[cfg(not(target_arch = "wasm32"))]
+mod system;
+
+#[cfg(not(target_arch = "wasm32"))]
+use crate::pdf::document::fonts::system::{find_system_font, SystemFontFileType};
+
 #[cfg(not(target_arch = "wasm32"))]
 use std::fs::File;
 
@@ -556,8 +562,9 @@ impl<'a> PdfFonts<'a> {
         );
 
         if handle.is_null() {
-            Err(PdfiumError::PdfiumLibraryInternalError(
-                PdfiumInternalError::Unknown,
+            Err(self.bindings.error_in(
+                "FPDFText_LoadFont",
+                PdfiumError::PdfiumLibraryInternalError(PdfiumInternalError::Unknown),
             ))
         } else {
             let font = PdfFont::from_pdfium(handle, self.bindings, None, true);
@@ -570,9 +577,116 @@ impl<'a> PdfFonts<'a> {
         }
     }
 
+    /// Attempts to locate an installed font with the given name on the local system, then
+    /// load and embed it, returning a reusable [PdfFontToken] if the font was successfully loaded.
+    ///
+    /// Font files are located by comparing their file names against the given font name,
+    /// ignoring case, whitespace, and punctuation, so that "DejaVu Sans" matches `DejaVuSans.ttf`.
+    /// The standard font directories are searched on Windows, macOS, and Linux; on Linux and other
+    /// Unix-like systems, fontconfig is also consulted if no matching file name is found.
+    /// TrueType, OpenType, and Type 1 font files are supported.
+    ///
+    /// Set the `is_cid_font` parameter to `true` if the given font is keyed by
+    /// 16-bit character ID (CID), indicating that it supports an extended glyphset of
+    /// 65,535 glyphs. This is typically the case with fonts that support Asian character sets
+    /// or right-to-left languages.
+    ///
+    /// [PdfiumError::SystemFontNotFound] will be returned if no matching font file can be found.
+    ///
+    /// This function is not available when compiling to WASM.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn load_from_system(
+        &mut self,
+        name: &str,
+        is_cid_font: bool,
+    ) -> Result<PdfFontToken, PdfiumError> {
+        match find_system_font(name) {
+            Some((path, SystemFontFileType::TrueType)) => {
+                self.load_true_type_from_file(&path, is_cid_font)
+            }
+            Some((path, SystemFontFileType::Type1)) => {
+                self.load_type1_from_file(&path, is_cid_font)
+            }
+            None => Err(PdfiumError::SystemFontNotFound),
+        }
+    }
+
     /// Returns a reference to the [PdfFont] associated with the given [PdfFontToken], if any.
     #[inline]
     pub fn get(&self, token: PdfFontToken) -> Option<&PdfFont> {
         self.fonts.get(&token)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::utils::test::test_bind_to_pdfium;
+
+    #[test]
+    fn test_standard_fonts_are_not_embedded() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let bytes = {
+            let mut document = pdfium.create_new_pdf()?;
+
+            let mut page = document
+                .pages_mut()
+                .create_page_at_start(PdfPagePaperSize::a4())?;
+
+            let font = document
+                .fonts_mut()
+                .new_built_in(PdfFontBuiltin::CourierBold);
+
+            page.objects_mut().create_text_object(
+                PdfPoints::new(100.0),
+                PdfPoints::new(700.0),
+                "Standard font",
+                font,
+                PdfPoints::new(24.0),
+            )?;
+
+            document.save_to_bytes()?
+        };
+
+        let document = pdfium.load_pdf_from_byte_vec(bytes, None)?;
+
+        let page = document.pages().first()?;
+
+        let object = page.objects().first()?;
+
+        let text = object.as_text_object().unwrap();
+
+        assert_eq!(text.text(), "Standard font");
+        assert!(!text.font().is_embedded()?);
+
+        #[cfg(any(
+            feature = "pdfium_future",
+            feature = "pdfium_6996",
+            feature = "pdfium_6721",
+            feature = "pdfium_6666"
+        ))]
+        {
+            assert_eq!(text.font().base_name(), "Courier-Bold");
+            assert!(!text.font().is_subset());
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_load_missing_system_font() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        assert!(matches!(
+            document
+                .fonts_mut()
+                .load_from_system("No Such Font Family 12345", false),
+            Err(PdfiumError::SystemFontNotFound)
+        ));
+
+        Ok(())
+    }
+}
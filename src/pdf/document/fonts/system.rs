@@ -0,0 +1,218 @@
+//! Locates font files installed on the local system by font name, without depending on
+//! any platform font APIs.
+
+use std::env;
+use std::path::{Path, PathBuf};
+
+#[cfg(all(unix, not(target_os = "macos")))]
+use std::process::Command;
+
+/// The kind of font data contained in a font file, as determined by its file extension.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub(crate) enum SystemFontFileType {
+    TrueType,
+    Type1,
+}
+
+impl SystemFontFileType {
+    fn from_path(path: &Path) -> Option<Self> {
+        match path.extension()?.to_str()?.to_ascii_lowercase().as_str() {
+            "ttf" | "otf" | "ttc" => Some(SystemFontFileType::TrueType),
+            "pfb" | "pfa" => Some(SystemFontFileType::Type1),
+            _ => None,
+        }
+    }
+}
+
+/// The maximum depth of subdirectories searched beneath each font directory.
+const MAXIMUM_SEARCH_DEPTH: usize = 4;
+
+/// Returns the path and type of an installed font file matching the given font name, if any.
+///
+/// Font files are matched by comparing their file names to the given font name, ignoring case,
+/// whitespace, and punctuation, so that "DejaVu Sans" matches `DejaVuSans.ttf`. On Linux and
+/// other Unix-like systems, fontconfig is consulted if no matching file name is found.
+pub(crate) fn find_system_font(name: &str) -> Option<(PathBuf, SystemFontFileType)> {
+    find_font_in_directories(name, &system_font_directories())
+        .or_else(|| find_with_fontconfig(name))
+}
+
+/// Searches the given directories, and their subdirectories, for a font file matching
+/// the given font name.
+pub(crate) fn find_font_in_directories(
+    name: &str,
+    directories: &[PathBuf],
+) -> Option<(PathBuf, SystemFontFileType)> {
+    let name = normalize(name);
+
+    if name.is_empty() {
+        return None;
+    }
+
+    directories
+        .iter()
+        .find_map(|directory| search_directory(&name, directory, 0))
+}
+
+fn search_directory(
+    name: &str,
+    directory: &Path,
+    depth: usize,
+) -> Option<(PathBuf, SystemFontFileType)> {
+    let mut subdirectories = Vec::new();
+
+    for entry in std::fs::read_dir(directory).ok()?.flatten() {
+        let path = entry.path();
+
+        if path.is_dir() {
+            subdirectories.push(path);
+        } else if let Some(file_type) = SystemFontFileType::from_path(&path) {
+            if path
+                .file_stem()
+                .and_then(|stem| stem.to_str())
+                .map(normalize)
+                .as_deref()
+                == Some(name)
+            {
+                return Some((path, file_type));
+            }
+        }
+    }
+
+    if depth < MAXIMUM_SEARCH_DEPTH {
+        subdirectories
+            .iter()
+            .find_map(|subdirectory| search_directory(name, subdirectory, depth + 1))
+    } else {
+        None
+    }
+}
+
+/// Lowercases the given font or file name and removes everything other than letters and digits.
+fn normalize(name: &str) -> String {
+    name.chars()
+        .filter(|c| c.is_alphanumeric())
+        .flat_map(|c| c.to_lowercase())
+        .collect()
+}
+
+#[cfg(target_os = "windows")]
+fn system_font_directories() -> Vec<PathBuf> {
+    let mut directories = Vec::new();
+
+    let windows = env::var_os("WINDIR")
+        .or_else(|| env::var_os("SystemRoot"))
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from("C:\\Windows"));
+
+    directories.push(windows.join("Fonts"));
+
+    // Fonts installed for the current user only.
+
+    if let Some(local) = env::var_os("LOCALAPPDATA") {
+        directories.push(PathBuf::from(local).join("Microsoft\\Windows\\Fonts"));
+    }
+
+    directories
+}
+
+#[cfg(target_os = "macos")]
+fn system_font_directories() -> Vec<PathBuf> {
+    let mut directories = Vec::new();
+
+    if let Some(home) = env::var_os("HOME") {
+        directories.push(PathBuf::from(home).join("Library/Fonts"));
+    }
+
+    directories.push(PathBuf::from("/Library/Fonts"));
+    directories.push(PathBuf::from("/System/Library/Fonts"));
+
+    directories
+}
+
+#[cfg(not(any(target_os = "windows", target_os = "macos")))]
+fn system_font_directories() -> Vec<PathBuf> {
+    let mut directories = Vec::new();
+
+    if let Some(home) = env::var_os("HOME") {
+        let home = PathBuf::from(home);
+
+        directories.push(home.join(".local/share/fonts"));
+        directories.push(home.join(".fonts"));
+    }
+
+    directories.push(PathBuf::from("/usr/local/share/fonts"));
+    directories.push(PathBuf::from("/usr/share/fonts"));
+
+    directories
+}
+
+/// Asks fontconfig for the file containing the given font. fontconfig always returns its
+/// best substitute, so the result is only accepted if one of the family names of the
+/// returned font matches the given font name.
+#[cfg(all(unix, not(target_os = "macos")))]
+fn find_with_fontconfig(name: &str) -> Option<(PathBuf, SystemFontFileType)> {
+    let output = Command::new("fc-match")
+        .arg("--format=%{family}\n%{file}")
+        .arg(name)
+        .output()
+        .ok()?;
+
+    if !output.status.success() {
+        return None;
+    }
+
+    let output = String::from_utf8(output.stdout).ok()?;
+
+    let (families, file) = output.split_once('\n')?;
+
+    let name = normalize(name);
+
+    if families.split(',').any(|family| normalize(family) == name) {
+        let path = PathBuf::from(file.trim());
+
+        SystemFontFileType::from_path(&path).map(|file_type| (path, file_type))
+    } else {
+        None
+    }
+}
+
+#[cfg(not(all(unix, not(target_os = "macos"))))]
+fn find_with_fontconfig(_name: &str) -> Option<(PathBuf, SystemFontFileType)> {
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_font_in_directories() -> std::io::Result<()> {
+        let root = env::temp_dir().join(format!("pdfium-render-fonts-{}", std::process::id()));
+
+        let nested = root.join("truetype/dejavu");
+
+        std::fs::create_dir_all(&nested)?;
+        std::fs::write(nested.join("DejaVuSans-Bold.ttf"), [])?;
+        std::fs::write(root.join("n019003l.pfb"), [])?;
+        std::fs::write(root.join("Readme.txt"), [])?;
+
+        let directories = vec![root.clone()];
+
+        assert_eq!(
+            find_font_in_directories("DejaVu Sans Bold", &directories),
+            Some((
+                nested.join("DejaVuSans-Bold.ttf"),
+                SystemFontFileType::TrueType
+            ))
+        );
+        assert_eq!(
+            find_font_in_directories("N019003L", &directories),
+            Some((root.join("n019003l.pfb"), SystemFontFileType::Type1))
+        );
+        assert_eq!(find_font_in_directories("Readme", &directories), None);
+        assert_eq!(find_font_in_directories("", &directories), None);
+
+        std::fs::remove_dir_all(&root)
+    }
+}
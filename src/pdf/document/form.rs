@@ -1,20 +1,30 @@
 //! Defines the [PdfForm] struct, exposing functionality related to a form
 //! embedded in a `PdfDocument`.
 
+pub mod events;
+
 use crate::bindgen::{
     FORMTYPE_ACRO_FORM, FORMTYPE_NONE, FORMTYPE_XFA_FOREGROUND, FORMTYPE_XFA_FULL, FPDF_DOCUMENT,
-    FPDF_FORMFILLINFO, FPDF_FORMHANDLE,
+    FPDF_FORMFILLINFO, FPDF_FORMHANDLE, FPDF_PAGE, FXCT_ARROW, FXCT_HAND, FXCT_HBEAM, FXCT_NESW,
+    FXCT_NWSE, FXCT_VBEAM,
 };
 use crate::bindings::PdfiumLibraryBindings;
 use crate::error::PdfiumError;
+use crate::pdf::document::fields::PdfFormFields;
+use crate::pdf::document::form::events::PdfFormEventHandler;
 use crate::pdf::document::page::field::PdfFormFieldCommon;
 use crate::pdf::document::page::field::PdfFormFieldType;
-use crate::pdf::document::pages::PdfPages;
+use crate::pdf::document::page::index_cache::PdfPageIndexCache;
+use crate::pdf::document::pages::{PdfPageIndex, PdfPages};
+use crate::pdf::rect::PdfRect;
+use std::cell::{Cell, RefCell};
 use std::collections::HashMap;
-use std::ops::DerefMut;
-use std::pin::Pin;
+use std::os::raw::c_int;
 use std::ptr::null_mut;
 
+#[cfg(not(target_arch = "wasm32"))]
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
 #[cfg(doc)]
 use crate::pdf::document::PdfDocument;
 
@@ -54,6 +64,45 @@ impl PdfFormType {
     }
 }
 
+/// The shape of the mouse pointer requested by Pdfium's form fill module as the pointer
+/// moves over the form field widgets in a [PdfForm], as passed to the handler set with
+/// [PdfForm::set_cursor_handler()].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum PdfFormCursor {
+    /// The standard arrow pointer.
+    Arrow = FXCT_ARROW as isize,
+
+    /// A diagonal resize pointer, pointing north-east and south-west.
+    ResizeNorthEastSouthWest = FXCT_NESW as isize,
+
+    /// A diagonal resize pointer, pointing north-west and south-east.
+    ResizeNorthWestSouthEast = FXCT_NWSE as isize,
+
+    /// A vertical text insertion beam, shown over text fields.
+    VerticalBeam = FXCT_VBEAM as isize,
+
+    /// A horizontal text insertion beam, shown over text fields containing vertical text.
+    HorizontalBeam = FXCT_HBEAM as isize,
+
+    /// A pointing hand, shown over push buttons, checkboxes, and radio buttons.
+    Hand = FXCT_HAND as isize,
+}
+
+impl PdfFormCursor {
+    #[inline]
+    pub(crate) fn from_pdfium(cursor: c_int) -> Option<PdfFormCursor> {
+        match cursor as u32 {
+            FXCT_ARROW => Some(PdfFormCursor::Arrow),
+            FXCT_NESW => Some(PdfFormCursor::ResizeNorthEastSouthWest),
+            FXCT_NWSE => Some(PdfFormCursor::ResizeNorthWestSouthEast),
+            FXCT_VBEAM => Some(PdfFormCursor::VerticalBeam),
+            FXCT_HBEAM => Some(PdfFormCursor::HorizontalBeam),
+            FXCT_HAND => Some(PdfFormCursor::Hand),
+            _ => None,
+        }
+    }
+}
+
 /// The [PdfForm] embedded inside a [PdfDocument].
 ///
 /// Form fields in Pdfium are exposed as page annotations of type `PdfPageAnnotationType::Widget`
@@ -73,14 +122,13 @@ impl PdfFormType {
 /// ```
 ///
 /// Alternatively, use the [PdfForm::field_values()] function to eagerly retrieve the values of all
-/// fields in the document as a map of (field name, field value) pairs.
+/// fields in the document as a map of (field name, field value) pairs, or the [PdfForm::fields()]
+/// function to fill in field values in a way that other PDF viewers will display.
 pub struct PdfForm<'a> {
     form_handle: FPDF_FORMHANDLE,
     document_handle: FPDF_DOCUMENT,
 
-    #[allow(dead_code)]
-    // The form_fill_info field is not currently used, but we expect it to be in future
-    form_fill_info: Pin<Box<FPDF_FORMFILLINFO>>,
+    form_fill_info: *mut PdfFormFillInfo,
     bindings: &'a dyn PdfiumLibraryBindings,
 }
 
@@ -97,56 +145,70 @@ impl<'a> PdfForm<'a> {
         // FPDFDOC_InitFormFillEnvironment() function. This function takes a large
         // struct, FPDF_FORMFILLINFO, which Pdfium uses to store a variety of form
         // configuration information - mostly callback functions that should be called
-        // when the user interacts with a form field widget. Since pdfium-render has
-        // no concept of interactivity, we can leave all these set to None.
-
-        // We allocate the FPDF_FORMFILLINFO struct on the heap and pin its pointer location
-        // so Rust will not move it around. Pdfium retains the pointer location
-        // when we call FPDFDOC_InitFormFillEnvironment() and expects the pointer
-        // location to still be valid when we later call FPDFDOC_ExitFormFillEnvironment()
-        // during drop(); if we don't pin the struct's location it may move, and the
-        // call to FPDFDOC_ExitFormFillEnvironment() will segfault.
-
-        let mut form_fill_info = Box::pin(FPDF_FORMFILLINFO {
-            version: 2,
-            Release: None,
-            FFI_Invalidate: None,
-            FFI_OutputSelectedRect: None,
-            FFI_SetCursor: None,
-            FFI_SetTimer: None,
-            FFI_KillTimer: None,
-            FFI_GetLocalTime: None,
-            FFI_OnChange: None,
-            FFI_GetPage: None,
-            FFI_GetCurrentPage: None,
-            FFI_GetRotation: None,
-            FFI_ExecuteNamedAction: None,
-            FFI_SetTextFieldFocus: None,
-            FFI_DoURIAction: None,
-            FFI_DoGoToAction: None,
-            m_pJsPlatform: null_mut(),
-            xfa_disabled: 0,
-            FFI_DisplayCaret: None,
-            FFI_GetCurrentPageIndex: None,
-            FFI_SetCurrentPage: None,
-            FFI_GotoURL: None,
-            FFI_GetPageViewRect: None,
-            FFI_PageEvent: None,
-            FFI_PopupMenu: None,
-            FFI_OpenFile: None,
-            FFI_EmailTo: None,
-            FFI_UploadTo: None,
-            FFI_GetPlatform: None,
-            FFI_GetLanguage: None,
-            FFI_DownloadFromURL: None,
-            FFI_PostRequestURL: None,
-            FFI_PutRequestURL: None,
-            FFI_OnFocusChange: None,
-            FFI_DoURIActionWithKeyboardModifier: None,
-        });
-
-        let form_handle =
-            bindings.FPDFDOC_InitFormFillEnvironment(document_handle, form_fill_info.deref_mut());
+        // when the user interacts with a form field widget. We only need a handful of
+        // these callbacks to support forwarding user input events to Pdfium using a
+        // PdfFormEventHandler; the remainder can be left set to None.
+
+        // We allocate the FPDF_FORMFILLINFO struct on the heap as part of a larger
+        // PdfFormFillInfo struct, and leak the allocation into a raw pointer so Rust will
+        // not move it around or assume exclusive access to it. Pdfium retains the pointer
+        // location when we call FPDFDOC_InitFormFillEnvironment(), passes it back to us
+        // in every callback, and expects the pointer location to still be valid when we
+        // later call FPDFDOC_ExitFormFillEnvironment() during drop(); if the struct's location
+        // were to move, the call to FPDFDOC_ExitFormFillEnvironment() would segfault.
+        // The allocation is reclaimed in drop(), once Pdfium has released the pointer.
+
+        let form_fill_info = Box::into_raw(Box::new(PdfFormFillInfo {
+            info: FPDF_FORMFILLINFO {
+                version: 2,
+                Release: None,
+                FFI_Invalidate: PdfFormFillInfo::invalidate_callback(),
+                FFI_OutputSelectedRect: None,
+                FFI_SetCursor: PdfFormFillInfo::cursor_callback(),
+                FFI_SetTimer: None,
+                FFI_KillTimer: None,
+                FFI_GetLocalTime: None,
+                FFI_OnChange: PdfFormFillInfo::change_callback(),
+                FFI_GetPage: None,
+                FFI_GetCurrentPage: None,
+                FFI_GetRotation: None,
+                FFI_ExecuteNamedAction: None,
+                FFI_SetTextFieldFocus: None,
+                FFI_DoURIAction: None,
+                FFI_DoGoToAction: None,
+                m_pJsPlatform: null_mut(),
+                xfa_disabled: 0,
+                FFI_DisplayCaret: None,
+                FFI_GetCurrentPageIndex: None,
+                FFI_SetCurrentPage: None,
+                FFI_GotoURL: None,
+                FFI_GetPageViewRect: None,
+                FFI_PageEvent: None,
+                FFI_PopupMenu: None,
+                FFI_OpenFile: None,
+                FFI_EmailTo: None,
+                FFI_UploadTo: None,
+                FFI_GetPlatform: None,
+                FFI_GetLanguage: None,
+                FFI_DownloadFromURL: None,
+                FFI_PostRequestURL: None,
+                FFI_PutRequestURL: None,
+                FFI_OnFocusChange: None,
+                FFI_DoURIActionWithKeyboardModifier: None,
+            },
+            invalidated_rects: RefCell::new(Vec::new()),
+            has_unsaved_changes: Cell::new(false),
+            cursor_handler: RefCell::new(None),
+            change_handler: RefCell::new(None),
+        }));
+
+        // Pdfium receives a pointer to the first field of the PdfFormFillInfo struct,
+        // which has the same address as the struct itself.
+
+        let form_handle = bindings.FPDFDOC_InitFormFillEnvironment(
+            document_handle,
+            form_fill_info as *mut FPDF_FORMFILLINFO,
+        );
 
         if !form_handle.is_null() {
             // There is a form embedded in this document, and we retrieved a valid handle to it.
@@ -168,7 +230,10 @@ impl<'a> PdfForm<'a> {
                 None
             }
         } else {
-            // There is no form embedded in this document.
+            // There is no form embedded in this document. Pdfium has not retained
+            // the pointer, so we can reclaim the allocation immediately.
+
+            drop(unsafe { Box::from_raw(form_fill_info) });
 
             None
         }
@@ -186,6 +251,15 @@ impl<'a> PdfForm<'a> {
         self.bindings
     }
 
+    /// Returns the [PdfFormFillInfo] shared with Pdfium's form fill module.
+    #[inline]
+    fn form_fill_info(&self) -> &PdfFormFillInfo {
+        // Safety: the allocation remains valid until this PdfForm is dropped, and Pdfium
+        // never mutates it. All state updated by Pdfium callbacks uses interior mutability.
+
+        unsafe { &*self.form_fill_info }
+    }
+
     /// Returns the [PdfFormType] of this [PdfForm].
     #[inline]
     pub fn form_type(&self) -> PdfFormType {
@@ -193,6 +267,125 @@ impl<'a> PdfForm<'a> {
             .unwrap()
     }
 
+    /// Returns a new [PdfFormEventHandler] for forwarding keyboard and mouse events from an
+    /// interactive viewer to the form field widgets in this [PdfForm].
+    #[inline]
+    pub fn event_handler(&self) -> PdfFormEventHandler<'_, 'a> {
+        PdfFormEventHandler::new(self)
+    }
+
+    /// Returns `true` if the value of any form field in this [PdfForm] has been changed
+    /// through Pdfium's form fill module since the containing [PdfDocument] was opened
+    /// or last saved.
+    ///
+    /// Changes are reported by Pdfium's form fill module, so changes made by a
+    /// [PdfFormEventHandler] or through the [PdfFormFields] collection are detected, but
+    /// changes made directly to individual `PdfFormField` objects are not. Change detection
+    /// is not available when compiling to WASM; this function always returns `false`.
+    #[inline]
+    pub fn has_unsaved_changes(&self) -> bool {
+        self.form_fill_info().has_unsaved_changes.get()
+    }
+
+    /// Clears the flag returned by [PdfForm::has_unsaved_changes()]. Called once the
+    /// containing [PdfDocument] has been saved.
+    #[inline]
+    pub(crate) fn mark_changes_saved(&self) {
+        self.form_fill_info().has_unsaved_changes.set(false);
+    }
+
+    /// Removes and returns all the rectangles on the page with the given handle that Pdfium
+    /// has reported as needing to be re-rendered since the last call to this function.
+    pub(crate) fn take_invalidated_rects(&self, page: FPDF_PAGE) -> Vec<PdfRect> {
+        let mut invalidated_rects = self.form_fill_info().invalidated_rects.borrow_mut();
+
+        let mut result = Vec::new();
+
+        invalidated_rects.retain(|(invalidated_page, rect)| {
+            if *invalidated_page == page {
+                result.push(*rect);
+
+                false
+            } else {
+                true
+            }
+        });
+
+        result
+    }
+
+    /// Removes and returns all the rectangles, measured in page coordinates, that Pdfium has
+    /// reported as needing to be re-rendered since the last call to this function, together
+    /// with the index of the page containing each rectangle. The returned rectangles may overlap.
+    ///
+    /// Pdfium reports invalidated rectangles whenever the appearance of a form field widget
+    /// changes, whether because of an event forwarded by a [PdfFormEventHandler] or because
+    /// of a JavaScript action. Each rectangle is returned only once, either by this function or
+    /// by [PdfFormEventHandler::take_invalidated_rects()]. Rectangles on pages that are no
+    /// longer open are discarded.
+    ///
+    /// Pdfium's invalidation callbacks are not available when compiling to WASM; this
+    /// function always returns an empty list.
+    pub fn take_pending_invalidations(&self) -> Vec<(PdfPageIndex, PdfRect)> {
+        self.form_fill_info()
+            .invalidated_rects
+            .borrow_mut()
+            .drain(..)
+            .filter_map(|(page, rect)| {
+                PdfPageIndexCache::get_index_for_page(self.document_handle, page)
+                    .map(|index| (index, rect))
+            })
+            .collect()
+    }
+
+    /// Sets a handler that Pdfium's form fill module will call whenever it requests a change
+    /// to the shape of the mouse pointer, typically as the pointer moves over form field widgets
+    /// in response to events forwarded by a [PdfFormEventHandler]. Replaces any existing handler.
+    ///
+    /// The handler is called from inside Pdfium, and must not call back into Pdfium.
+    /// Any panic raised by the handler is caught and discarded, since unwinding across
+    /// the boundary into Pdfium is not possible.
+    ///
+    /// Pdfium's callbacks are not available when compiling to WASM; the handler is never called.
+    pub fn set_cursor_handler(&self, handler: impl FnMut(PdfFormCursor) + 'static) {
+        *self.form_fill_info().cursor_handler.borrow_mut() = Some(Box::new(handler));
+    }
+
+    /// Removes any handler set with [PdfForm::set_cursor_handler()].
+    #[inline]
+    pub fn clear_cursor_handler(&self) {
+        *self.form_fill_info().cursor_handler.borrow_mut() = None;
+    }
+
+    /// Sets a handler that Pdfium's form fill module will call whenever the value of any form
+    /// field in this [PdfForm] changes, whether because of an event forwarded by a
+    /// [PdfFormEventHandler], a value set through the [PdfFormFields] collection, or a
+    /// JavaScript action. Replaces any existing handler.
+    ///
+    /// The handler is called from inside Pdfium, and must not call back into Pdfium.
+    /// Any panic raised by the handler is caught and discarded, since unwinding across
+    /// the boundary into Pdfium is not possible.
+    ///
+    /// Pdfium's callbacks are not available when compiling to WASM; the handler is never called.
+    pub fn set_change_handler(&self, handler: impl FnMut() + 'static) {
+        *self.form_fill_info().change_handler.borrow_mut() = Some(Box::new(handler));
+    }
+
+    /// Removes any handler set with [PdfForm::set_change_handler()].
+    #[inline]
+    pub fn clear_change_handler(&self) {
+        *self.form_fill_info().change_handler.borrow_mut() = None;
+    }
+
+    /// Returns the [PdfFormFields] collection of all form field widgets on every page of
+    /// the given [PdfPages] collection. Values set using the widgets in the returned
+    /// collection are entered through Pdfium's form fill module, so Pdfium regenerates
+    /// the appearance stream of each widget to reflect its updated value.
+    #[inline]
+    pub fn fields(&self, pages: &'a PdfPages<'a>) -> PdfFormFields<'a> {
+        PdfFormFields::from_pdfium(self.form_handle, pages, self.bindings)
+    }
+
     /// Captures a string representation of the value of every form field on every page of
     /// the given [PdfPages] collection, returning a map of (field name, field value) pairs.
     ///
@@ -277,5 +470,132 @@ impl<'a> Drop for PdfForm<'a> {
     fn drop(&mut self) {
         self.bindings
             .FPDFDOC_ExitFormFillEnvironment(self.form_handle);
+
+        // Pdfium no longer retains a pointer to the PdfFormFillInfo struct,
+        // so it is now safe to reclaim it.
+
+        drop(unsafe { Box::from_raw(self.form_fill_info) });
+    }
+}
+
+/// The FPDF_FORMFILLINFO struct passed to Pdfium's form fill module, together with the
+/// state updated by the callback functions Pdfium invokes through it.
+// The struct is #[repr(C)] and the FPDF_FORMFILLINFO struct is its first field, so the
+// pointer Pdfium passes to each callback function can be cast back to a PdfFormFillInfo.
+#[repr(C)]
+struct PdfFormFillInfo {
+    info: FPDF_FORMFILLINFO,
+    invalidated_rects: RefCell<Vec<(FPDF_PAGE, PdfRect)>>,
+    has_unsaved_changes: Cell<bool>,
+    cursor_handler: RefCell<Option<PdfFormCursorHandler>>,
+    change_handler: RefCell<Option<PdfFormChangeHandler>>,
+}
+
+/// A handler set with [PdfForm::set_cursor_handler()].
+type PdfFormCursorHandler = Box<dyn FnMut(PdfFormCursor)>;
+
+/// A handler set with [PdfForm::set_change_handler()].
+type PdfFormChangeHandler = Box<dyn FnMut()>;
+
+impl PdfFormFillInfo {
+    /// Returns the PdfFormFillInfo containing the given FPDF_FORMFILLINFO pointer.
+    ///
+    /// Safety: the pointer must be one passed by Pdfium to a callback function,
+    /// i.e. the pointer given to FPDFDOC_InitFormFillEnvironment().
+    #[cfg(not(target_arch = "wasm32"))]
+    #[inline]
+    unsafe fn from_pdfium<'b>(info: *mut FPDF_FORMFILLINFO) -> Option<&'b PdfFormFillInfo> {
+        (info as *const PdfFormFillInfo).as_ref()
+    }
+
+    // Function pointers cannot be passed across the boundary between our WASM module
+    // and Pdfium's WASM module, so callbacks are only registered on native targets.
+
+    #[cfg(not(target_arch = "wasm32"))]
+    fn invalidate_callback(
+    ) -> Option<unsafe extern "C" fn(*mut FPDF_FORMFILLINFO, FPDF_PAGE, f64, f64, f64, f64)> {
+        Some(Self::on_invalidate)
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    fn invalidate_callback(
+    ) -> Option<unsafe extern "C" fn(*mut FPDF_FORMFILLINFO, FPDF_PAGE, f64, f64, f64, f64)> {
+        None
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    fn cursor_callback() -> Option<unsafe extern "C" fn(*mut FPDF_FORMFILLINFO, c_int)> {
+        Some(Self::on_set_cursor)
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    fn cursor_callback() -> Option<unsafe extern "C" fn(*mut FPDF_FORMFILLINFO, c_int)> {
+        None
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    fn change_callback() -> Option<unsafe extern "C" fn(*mut FPDF_FORMFILLINFO)> {
+        Some(Self::on_change)
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    fn change_callback() -> Option<unsafe extern "C" fn(*mut FPDF_FORMFILLINFO)> {
+        None
+    }
+
+    /// Called by Pdfium when the given rectangle on the given page needs to be re-rendered.
+    #[cfg(not(target_arch = "wasm32"))]
+    unsafe extern "C" fn on_invalidate(
+        info: *mut FPDF_FORMFILLINFO,
+        page: FPDF_PAGE,
+        left: f64,
+        top: f64,
+        right: f64,
+        bottom: f64,
+    ) {
+        // Panicking across the FFI boundary is undefined behaviour, so we take care
+        // never to panic here.
+
+        if let Some(info) = Self::from_pdfium(info) {
+            if let Ok(mut invalidated_rects) = info.invalidated_rects.try_borrow_mut() {
+                invalidated_rects.push((
+                    page,
+                    PdfRect::new_from_values(
+                        bottom.min(top) as f32,
+                        left.min(right) as f32,
+                        top.max(bottom) as f32,
+                        right.max(left) as f32,
+                    ),
+                ));
+            }
+        }
+    }
+
+    /// Called by Pdfium when the value of any form field in the document changes.
+    #[cfg(not(target_arch = "wasm32"))]
+    unsafe extern "C" fn on_change(info: *mut FPDF_FORMFILLINFO) {
+        if let Some(info) = Self::from_pdfium(info) {
+            info.has_unsaved_changes.set(true);
+
+            if let Ok(mut handler) = info.change_handler.try_borrow_mut() {
+                if let Some(handler) = handler.as_mut() {
+                    let _ = catch_unwind(AssertUnwindSafe(handler));
+                }
+            }
+        }
+    }
+
+    /// Called by Pdfium when the shape of the mouse pointer should change.
+    #[cfg(not(target_arch = "wasm32"))]
+    unsafe extern "C" fn on_set_cursor(info: *mut FPDF_FORMFILLINFO, cursor: c_int) {
+        if let (Some(info), Some(cursor)) =
+            (Self::from_pdfium(info), PdfFormCursor::from_pdfium(cursor))
+        {
+            if let Ok(mut handler) = info.cursor_handler.try_borrow_mut() {
+                if let Some(handler) = handler.as_mut() {
+                    let _ = catch_unwind(AssertUnwindSafe(|| handler(cursor)));
+                }
+            }
+        }
     }
 }
@@ -0,0 +1,589 @@
+//! Defines the [PdfFormEventHandler] struct, forwarding keyboard and mouse events from an
+//! interactive viewer to the form field widgets in a `PdfForm`.
+
+use crate::bindgen::{
+    FWL_EVENTFLAG_FWL_EVENTFLAG_AltKey, FWL_EVENTFLAG_FWL_EVENTFLAG_AutoRepeat,
+    FWL_EVENTFLAG_FWL_EVENTFLAG_ControlKey, FWL_EVENTFLAG_FWL_EVENTFLAG_KeyPad,
+    FWL_EVENTFLAG_FWL_EVENTFLAG_LeftButtonDown, FWL_EVENTFLAG_FWL_EVENTFLAG_MetaKey,
+    FWL_EVENTFLAG_FWL_EVENTFLAG_MiddleButtonDown, FWL_EVENTFLAG_FWL_EVENTFLAG_RightButtonDown,
+    FWL_EVENTFLAG_FWL_EVENTFLAG_ShiftKey, FWL_VKEYCODE_FWL_VKEY_Back, FWL_VKEYCODE_FWL_VKEY_Delete,
+    FWL_VKEYCODE_FWL_VKEY_Down, FWL_VKEYCODE_FWL_VKEY_End, FWL_VKEYCODE_FWL_VKEY_Escape,
+    FWL_VKEYCODE_FWL_VKEY_Home, FWL_VKEYCODE_FWL_VKEY_Insert, FWL_VKEYCODE_FWL_VKEY_Left,
+    FWL_VKEYCODE_FWL_VKEY_Next, FWL_VKEYCODE_FWL_VKEY_Prior, FWL_VKEYCODE_FWL_VKEY_Return,
+    FWL_VKEYCODE_FWL_VKEY_Right, FWL_VKEYCODE_FWL_VKEY_Space, FWL_VKEYCODE_FWL_VKEY_Tab,
+    FWL_VKEYCODE_FWL_VKEY_Up, FPDF_PAGE,
+};
+use crate::bindings::PdfiumLibraryBindings;
+use crate::error::PdfiumError;
+use crate::pdf::document::form::PdfForm;
+use crate::pdf::document::page::PdfPage;
+use crate::pdf::points::PdfPoints;
+use crate::pdf::rect::PdfRect;
+use bitflags::bitflags;
+use std::cell::RefCell;
+use std::os::raw::c_int;
+
+bitflags! {
+    /// The modifier keys and mouse buttons held down during a keyboard or mouse event
+    /// forwarded to a [PdfFormEventHandler].
+    #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+    pub struct PdfFormEventModifiers: u32 {
+        /// The shift key is held down.
+        const SHIFT = FWL_EVENTFLAG_FWL_EVENTFLAG_ShiftKey;
+
+        /// The control key is held down.
+        const CONTROL = FWL_EVENTFLAG_FWL_EVENTFLAG_ControlKey;
+
+        /// The alt key is held down.
+        const ALT = FWL_EVENTFLAG_FWL_EVENTFLAG_AltKey;
+
+        /// The meta key (the command key on macOS, or the Windows key on Windows)
+        /// is held down.
+        const META = FWL_EVENTFLAG_FWL_EVENTFLAG_MetaKey;
+
+        /// The key event originated from the numeric keypad.
+        const KEYPAD = FWL_EVENTFLAG_FWL_EVENTFLAG_KeyPad;
+
+        /// The key event was generated by the key being held down.
+        const AUTO_REPEAT = FWL_EVENTFLAG_FWL_EVENTFLAG_AutoRepeat;
+
+        /// The left mouse button is held down.
+        const LEFT_BUTTON_DOWN = FWL_EVENTFLAG_FWL_EVENTFLAG_LeftButtonDown;
+
+        /// The middle mouse button is held down.
+        const MIDDLE_BUTTON_DOWN = FWL_EVENTFLAG_FWL_EVENTFLAG_MiddleButtonDown;
+
+        /// The right mouse button is held down.
+        const RIGHT_BUTTON_DOWN = FWL_EVENTFLAG_FWL_EVENTFLAG_RightButtonDown;
+    }
+}
+
+impl PdfFormEventModifiers {
+    #[inline]
+    pub(crate) fn as_pdfium(&self) -> c_int {
+        self.bits() as c_int
+    }
+}
+
+/// A non-character key forwarded to a [PdfFormEventHandler]. Keys that produce characters
+/// should be forwarded using [PdfFormEventHandler::on_char()] instead; Pdfium uses key
+/// events only for navigation and editing.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum PdfFormKey {
+    Backspace,
+    Tab,
+    Return,
+    Escape,
+    Space,
+    PageUp,
+    PageDown,
+    End,
+    Home,
+    Left,
+    Up,
+    Right,
+    Down,
+    Insert,
+    Delete,
+
+    /// Any other key, identified by its virtual key code as defined in Pdfium's
+    /// `fpdf_fwlevent.h` header file.
+    Other(u32),
+}
+
+impl PdfFormKey {
+    #[inline]
+    pub(crate) fn as_pdfium(&self) -> c_int {
+        (match self {
+            PdfFormKey::Backspace => FWL_VKEYCODE_FWL_VKEY_Back,
+            PdfFormKey::Tab => FWL_VKEYCODE_FWL_VKEY_Tab,
+            PdfFormKey::Return => FWL_VKEYCODE_FWL_VKEY_Return,
+            PdfFormKey::Escape => FWL_VKEYCODE_FWL_VKEY_Escape,
+            PdfFormKey::Space => FWL_VKEYCODE_FWL_VKEY_Space,
+            PdfFormKey::PageUp => FWL_VKEYCODE_FWL_VKEY_Prior,
+            PdfFormKey::PageDown => FWL_VKEYCODE_FWL_VKEY_Next,
+            PdfFormKey::End => FWL_VKEYCODE_FWL_VKEY_End,
+            PdfFormKey::Home => FWL_VKEYCODE_FWL_VKEY_Home,
+            PdfFormKey::Left => FWL_VKEYCODE_FWL_VKEY_Left,
+            PdfFormKey::Up => FWL_VKEYCODE_FWL_VKEY_Up,
+            PdfFormKey::Right => FWL_VKEYCODE_FWL_VKEY_Right,
+            PdfFormKey::Down => FWL_VKEYCODE_FWL_VKEY_Down,
+            PdfFormKey::Insert => FWL_VKEYCODE_FWL_VKEY_Insert,
+            PdfFormKey::Delete => FWL_VKEYCODE_FWL_VKEY_Delete,
+            PdfFormKey::Other(code) => *code,
+        }) as c_int
+    }
+}
+
+/// Forwards keyboard and mouse events from an interactive viewer to the form field widgets
+/// in a `PdfForm`, as returned by [PdfForm::event_handler()].
+///
+/// Events are entered through Pdfium's form fill module, exactly as if a user had clicked
+/// on and typed into the form field widgets in a PDF viewer. Pdfium updates field values
+/// and regenerates the appearance streams of the affected widgets as it processes each event.
+/// Positions are given in page coordinates; use `PdfRenderConfig::pixel_to_page()` to convert
+/// a pointer position in a rendered bitmap to a position on the page.
+///
+/// After forwarding an event, call [PdfFormEventHandler::take_invalidated_rects()] to retrieve
+/// the areas of the page Pdfium reports as needing to be re-rendered to reflect the event.
+///
+/// Each page passed to this [PdfFormEventHandler] is registered with Pdfium's form fill module
+/// the first time an event is forwarded to it, and remains registered until this
+/// [PdfFormEventHandler] is dropped. Pages must therefore outlive this [PdfFormEventHandler].
+pub struct PdfFormEventHandler<'b, 'a> {
+    form: &'b PdfForm<'a>,
+    registered_pages: RefCell<Vec<&'b PdfPage<'a>>>,
+}
+
+impl<'b, 'a> PdfFormEventHandler<'b, 'a> {
+    #[inline]
+    pub(crate) fn new(form: &'b PdfForm<'a>) -> Self {
+        PdfFormEventHandler {
+            form,
+            registered_pages: RefCell::new(Vec::new()),
+        }
+    }
+
+    /// Returns the [PdfiumLibraryBindings] used by this [PdfFormEventHandler].
+    #[inline]
+    pub fn bindings(&self) -> &'a dyn PdfiumLibraryBindings {
+        self.form.bindings()
+    }
+
+    /// Forwards a mouse movement to the given position on the given page.
+    ///
+    /// Returns `true` if Pdfium handled the event.
+    pub fn on_mouse_move(
+        &self,
+        page: &'b PdfPage<'a>,
+        x: PdfPoints,
+        y: PdfPoints,
+        modifiers: PdfFormEventModifiers,
+    ) -> bool {
+        let page = self.register(page);
+
+        self.bindings().is_true(self.bindings().FORM_OnMouseMove(
+            self.form.handle(),
+            page,
+            modifiers.as_pdfium(),
+            x.value as f64,
+            y.value as f64,
+        ))
+    }
+
+    /// Forwards a press of the left mouse button at the given position on the given page.
+    /// Pressing the mouse button over a form field widget gives it the input focus.
+    ///
+    /// Returns `true` if Pdfium handled the event.
+    pub fn on_mouse_down(
+        &self,
+        page: &'b PdfPage<'a>,
+        x: PdfPoints,
+        y: PdfPoints,
+        modifiers: PdfFormEventModifiers,
+    ) -> bool {
+        let page = self.register(page);
+
+        self.bindings().is_true(self.bindings().FORM_OnLButtonDown(
+            self.form.handle(),
+            page,
+            modifiers.as_pdfium(),
+            x.value as f64,
+            y.value as f64,
+        ))
+    }
+
+    /// Forwards a release of the left mouse button at the given position on the given page.
+    /// Releasing the mouse button over a checkbox or radio button toggles its state.
+    ///
+    /// Returns `true` if Pdfium handled the event.
+    pub fn on_mouse_up(
+        &self,
+        page: &'b PdfPage<'a>,
+        x: PdfPoints,
+        y: PdfPoints,
+        modifiers: PdfFormEventModifiers,
+    ) -> bool {
+        let page = self.register(page);
+
+        self.bindings().is_true(self.bindings().FORM_OnLButtonUp(
+            self.form.handle(),
+            page,
+            modifiers.as_pdfium(),
+            x.value as f64,
+            y.value as f64,
+        ))
+    }
+
+    /// Forwards a double click of the left mouse button at the given position on the given page.
+    /// Double clicking in a text field selects the word under the pointer.
+    ///
+    /// Returns `true` if Pdfium handled the event.
+    pub fn on_double_click(
+        &self,
+        page: &'b PdfPage<'a>,
+        x: PdfPoints,
+        y: PdfPoints,
+        modifiers: PdfFormEventModifiers,
+    ) -> bool {
+        let page = self.register(page);
+
+        self.bindings()
+            .is_true(self.bindings().FORM_OnLButtonDoubleClick(
+                self.form.handle(),
+                page,
+                modifiers.as_pdfium(),
+                x.value as f64,
+                y.value as f64,
+            ))
+    }
+
+    /// Forwards a typed character to the form field widget on the given page that currently
+    /// has the input focus, if any.
+    ///
+    /// Returns `true` if Pdfium handled the event.
+    pub fn on_char(
+        &self,
+        page: &'b PdfPage<'a>,
+        char: char,
+        modifiers: PdfFormEventModifiers,
+    ) -> bool {
+        let page = self.register(page);
+
+        // Pdfium expects characters as UTF-16 code units. Characters outside the
+        // Basic Multilingual Plane are forwarded as a surrogate pair.
+
+        let mut buffer = [0; 2];
+
+        let mut result = true;
+
+        for code_unit in char.encode_utf16(&mut buffer) {
+            result &= self.bindings().is_true(self.bindings().FORM_OnChar(
+                self.form.handle(),
+                page,
+                *code_unit as c_int,
+                modifiers.as_pdfium(),
+            ));
+        }
+
+        result
+    }
+
+    /// Forwards a key press to the form field widget on the given page that currently
+    /// has the input focus, if any.
+    ///
+    /// Returns `true` if Pdfium handled the event.
+    pub fn on_key_down(
+        &self,
+        page: &'b PdfPage<'a>,
+        key: PdfFormKey,
+        modifiers: PdfFormEventModifiers,
+    ) -> bool {
+        let page = self.register(page);
+
+        self.bindings().is_true(self.bindings().FORM_OnKeyDown(
+            self.form.handle(),
+            page,
+            key.as_pdfium(),
+            modifiers.as_pdfium(),
+        ))
+    }
+
+    /// Forwards a key release to the form field widget on the given page that currently
+    /// has the input focus, if any.
+    ///
+    /// Returns `true` if Pdfium handled the event. Pdfium currently ignores key releases,
+    /// so this function always returns `false`, but it is included for the sake of symmetry.
+    pub fn on_key_up(
+        &self,
+        page: &'b PdfPage<'a>,
+        key: PdfFormKey,
+        modifiers: PdfFormEventModifiers,
+    ) -> bool {
+        let page = self.register(page);
+
+        self.bindings().is_true(self.bindings().FORM_OnKeyUp(
+            self.form.handle(),
+            page,
+            key.as_pdfium(),
+            modifiers.as_pdfium(),
+        ))
+    }
+
+    /// Gives the input focus to the form field widget at the given position on the given page,
+    /// without otherwise interacting with it. If there is no form field widget at the given
+    /// position, the input focus is removed from any widget that currently has it.
+    ///
+    /// Returns `true` if there is a form field widget at the given position and it now
+    /// has the input focus.
+    pub fn on_focus(&self, page: &'b PdfPage<'a>, x: PdfPoints, y: PdfPoints) -> bool {
+        let page = self.register(page);
+
+        self.bindings().is_true(self.bindings().FORM_OnFocus(
+            self.form.handle(),
+            page,
+            0,
+            x.value as f64,
+            y.value as f64,
+        ))
+    }
+
+    /// Removes the input focus from the form field widget that currently has it, if any,
+    /// committing any changes to the widget's value.
+    pub fn kill_focus(&self) -> Result<(), PdfiumError> {
+        self.bindings()
+            .to_result(self.bindings().FORM_ForceToKillFocus(self.form.handle()))
+    }
+
+    /// Returns `true` if the value of any form field in the containing `PdfForm` has been
+    /// changed since the containing `PdfDocument` was opened or last saved.
+    ///
+    /// See [PdfForm::has_unsaved_changes()] for more information.
+    #[inline]
+    pub fn has_unsaved_changes(&self) -> bool {
+        self.form.has_unsaved_changes()
+    }
+
+    /// Removes and returns all the rectangles on the given page, measured in page coordinates,
+    /// that Pdfium has reported as needing to be re-rendered since the last call to this function.
+    /// The returned rectangles may overlap.
+    ///
+    /// Rectangles on all pages can be retrieved at once using
+    /// [PdfForm::take_pending_invalidations()]. Each rectangle is returned only once, by
+    /// whichever of the two functions is called first.
+    ///
+    /// Pdfium's invalidation callbacks are not available when compiling to WASM; this
+    /// function always returns an empty list.
+    #[inline]
+    pub fn take_invalidated_rects(&self, page: &PdfPage) -> Vec<PdfRect> {
+        self.form.take_invalidated_rects(page.page_handle())
+    }
+
+    /// Registers the given page with Pdfium's form fill module, if it is not already
+    /// registered, returning its page handle.
+    fn register(&self, page: &'b PdfPage<'a>) -> FPDF_PAGE {
+        let handle = page.page_handle();
+
+        let mut registered_pages = self.registered_pages.borrow_mut();
+
+        if !registered_pages
+            .iter()
+            .any(|registered| registered.page_handle() == handle)
+        {
+            self.bindings()
+                .FORM_OnAfterLoadPage(handle, self.form.handle());
+
+            registered_pages.push(page);
+        }
+
+        handle
+    }
+}
+
+impl<'b, 'a> Drop for PdfFormEventHandler<'b, 'a> {
+    /// Unregisters all pages registered with Pdfium's form fill module by this
+    /// [PdfFormEventHandler].
+    #[inline]
+    fn drop(&mut self) {
+        for page in self.registered_pages.borrow().iter() {
+            self.bindings()
+                .FORM_OnBeforeClosePage(page.page_handle(), self.form.handle());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::utils::test::test_bind_to_pdfium;
+    use std::cell::{Cell, RefCell};
+    use std::rc::Rc;
+
+    #[test]
+    fn test_type_into_text_field() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let document = pdfium.load_pdf_from_file("./test/form-test.pdf", None)?;
+
+        let form = document.form().unwrap();
+
+        let pages = document.pages();
+
+        let (page_index, annotation_index) = pages
+            .iter()
+            .enumerate()
+            .find_map(|(page_index, page)| {
+                page.annotations()
+                    .iter()
+                    .position(|annotation| {
+                        annotation
+                            .as_form_field()
+                            .map(|field| field.field_type() == PdfFormFieldType::Text)
+                            .unwrap_or(false)
+                    })
+                    .map(|annotation_index| (page_index, annotation_index))
+            })
+            .unwrap();
+
+        let page = pages.get(page_index as PdfPageIndex)?;
+
+        let bounds = page
+            .annotations()
+            .get(annotation_index as PdfPageAnnotationIndex)?
+            .bounds()?;
+
+        let x = (bounds.left() + bounds.right()) / 2.0;
+        let y = (bounds.top() + bounds.bottom()) / 2.0;
+
+        let handler = form.event_handler();
+
+        assert!(!handler.has_unsaved_changes());
+
+        let none = PdfFormEventModifiers::empty();
+
+        // Click into the field, clear any existing text, and type a new value.
+
+        handler.on_mouse_down(&page, x, y, none);
+        handler.on_mouse_up(&page, x, y, none);
+
+        handler.on_key_down(&page, PdfFormKey::End, none);
+
+        let existing = page
+            .annotations()
+            .get(annotation_index as PdfPageAnnotationIndex)?
+            .as_form_field()
+            .and_then(|field| field.as_text_field())
+            .and_then(|field| field.value())
+            .unwrap_or_default();
+
+        for _ in existing.chars() {
+            handler.on_key_down(&page, PdfFormKey::Backspace, none);
+        }
+
+        for char in "pdfium-render".chars() {
+            assert!(handler.on_char(&page, char, none));
+        }
+
+        handler.kill_focus()?;
+
+        assert!(handler.has_unsaved_changes());
+        assert!(!handler.take_invalidated_rects(&page).is_empty());
+        assert!(handler.take_invalidated_rects(&page).is_empty());
+
+        assert_eq!(
+            page.annotations()
+                .get(annotation_index as PdfPageAnnotationIndex)?
+                .as_form_field()
+                .and_then(|field| field.as_text_field())
+                .and_then(|field| field.value())
+                .as_deref(),
+            Some("pdfium-render")
+        );
+
+        drop(handler);
+
+        document.save_to_bytes()?;
+
+        assert!(!form.has_unsaved_changes());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_toggle_checkbox() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let document = pdfium.load_pdf_from_file("./test/form-test.pdf", None)?;
+
+        let form = document.form().unwrap();
+
+        let pages = document.pages();
+
+        let (page_index, annotation_index) = pages
+            .iter()
+            .enumerate()
+            .find_map(|(page_index, page)| {
+                page.annotations()
+                    .iter()
+                    .position(|annotation| {
+                        annotation
+                            .as_form_field()
+                            .map(|field| field.field_type() == PdfFormFieldType::Checkbox)
+                            .unwrap_or(false)
+                    })
+                    .map(|annotation_index| (page_index, annotation_index))
+            })
+            .unwrap();
+
+        let page = pages.get(page_index as PdfPageIndex)?;
+
+        let is_checked = || -> Result<bool, PdfiumError> {
+            page.annotations()
+                .get(annotation_index as PdfPageAnnotationIndex)?
+                .as_form_field()
+                .and_then(|field| field.as_checkbox_field())
+                .unwrap()
+                .is_checked()
+        };
+
+        let bounds = page
+            .annotations()
+            .get(annotation_index as PdfPageAnnotationIndex)?
+            .bounds()?;
+
+        let x = (bounds.left() + bounds.right()) / 2.0;
+        let y = (bounds.top() + bounds.bottom()) / 2.0;
+
+        let changes = Rc::new(Cell::new(0));
+
+        form.set_change_handler({
+            let changes = changes.clone();
+
+            move || changes.set(changes.get() + 1)
+        });
+
+        let cursors = Rc::new(RefCell::new(Vec::new()));
+
+        form.set_cursor_handler({
+            let cursors = cursors.clone();
+
+            move |cursor| cursors.borrow_mut().push(cursor)
+        });
+
+        let was_checked = is_checked()?;
+
+        let handler = form.event_handler();
+
+        let none = PdfFormEventModifiers::empty();
+
+        // Click on the checkbox to toggle it.
+
+        handler.on_mouse_move(&page, x, y, none);
+        handler.on_mouse_down(&page, x, y, none);
+        handler.on_mouse_up(&page, x, y, none);
+
+        assert_eq!(is_checked()?, !was_checked);
+        assert!(changes.get() > 0);
+        assert!(!cursors.borrow().is_empty());
+
+        // Pdfium reports that the checkbox must be re-rendered.
+
+        let invalidations = form.take_pending_invalidations();
+
+        assert!(invalidations.iter().any(|(index, rect)| {
+            *index == page_index as PdfPageIndex
+                && rect.left() <= bounds.left() + PdfPoints::new(1.0)
+                && rect.right() >= bounds.right() - PdfPoints::new(1.0)
+                && rect.bottom() <= bounds.bottom() + PdfPoints::new(1.0)
+                && rect.top() >= bounds.top() - PdfPoints::new(1.0)
+        }));
+
+        assert!(form.take_pending_invalidations().is_empty());
+        assert!(handler.take_invalidated_rects(&page).is_empty());
+
+        form.clear_change_handler();
+        form.clear_cursor_handler();
+
+        Ok(())
+    }
+}
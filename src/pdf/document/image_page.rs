@@ -0,0 +1,136 @@
+//! Defines the [PdfImagePageOptions] struct, a builder-based approach to configuring the
+//! creation of pages containing a single image, for instance when converting scanned images
+//! into a `PdfDocument`.
+
+use crate::pdf::document::page::size::PdfPagePaperSize;
+use crate::pdf::document::page::PdfPageRenderRotation;
+use crate::pdf::points::PdfPoints;
+
+#[cfg(doc)]
+use {
+    crate::pdf::document::page::object::image::PdfPageImageObject,
+    crate::pdf::document::pages::PdfPages, crate::pdfium::Pdfium,
+};
+
+/// Controls the size of a page created by [PdfPages::create_page_from_image()].
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum PdfImagePageSize {
+    /// The page is created with the given paper size. The image is scaled to fit inside
+    /// the page margins, preserving its aspect ratio, and centered on the page.
+    PaperSize(PdfPagePaperSize),
+
+    /// The page is sized so that the image is printed at the given resolution, in dots
+    /// per inch, with the page margins added around it. For example, a scan measuring
+    /// 2550 x 3300 pixels at 300 dpi produces a page measuring 8.5 x 11 inches, or
+    /// 612 x 792 points, when no margins are set.
+    Dpi(f32),
+}
+
+/// Configures the creation of a page containing a single image using the
+/// [PdfPages::create_page_from_image()] or [Pdfium::create_pdf_from_images()] functions.
+///
+/// By default, each page is sized to match its image at 72 dots per inch, with no margins
+/// and no rotation, and each image is embedded losslessly.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PdfImagePageOptions {
+    size: PdfImagePageSize,
+    margin: PdfPoints,
+    rotation: PdfPageRenderRotation,
+    jpeg_data: Option<Vec<u8>>,
+}
+
+impl PdfImagePageOptions {
+    /// The resolution used to size pages if no paper size or resolution is set.
+    pub const DEFAULT_DPI: f32 = 72.0;
+
+    /// Creates a new [PdfImagePageOptions] object with all settings at their default values.
+    #[inline]
+    pub fn new() -> Self {
+        PdfImagePageOptions {
+            size: PdfImagePageSize::Dpi(Self::DEFAULT_DPI),
+            margin: PdfPoints::ZERO,
+            rotation: PdfPageRenderRotation::None,
+            jpeg_data: None,
+        }
+    }
+
+    /// Creates pages with the given [PdfPagePaperSize]. The image is scaled to fit inside
+    /// the page margins, preserving its aspect ratio, and centered on the page.
+    #[inline]
+    pub fn set_paper_size(mut self, size: PdfPagePaperSize) -> Self {
+        self.size = PdfImagePageSize::PaperSize(size);
+
+        self
+    }
+
+    /// Creates pages sized so that the image is printed at the given resolution,
+    /// in dots per inch, with the page margins added around it.
+    #[inline]
+    pub fn set_dpi(mut self, dpi: f32) -> Self {
+        self.size = PdfImagePageSize::Dpi(dpi);
+
+        self
+    }
+
+    /// Sets the margin between the edges of each page and the image on that page.
+    #[inline]
+    pub fn set_margin(mut self, margin: PdfPoints) -> Self {
+        self.margin = margin;
+
+        self
+    }
+
+    /// Sets the intrinsic rotation of each page, as returned by `PdfPage::rotation()`.
+    /// The page content is not changed; viewers display the rotated page upright.
+    #[inline]
+    pub fn set_rotation(mut self, rotation: PdfPageRenderRotation) -> Self {
+        self.rotation = rotation;
+
+        self
+    }
+
+    /// Embeds the given JPEG data into the page as-is, using the `DCTDecode` filter,
+    /// rather than losslessly encoding the pixel data of the image passed to
+    /// [PdfPages::create_page_from_image()]. This avoids recompressing images that
+    /// were originally loaded from JPEG files. The JPEG data must encode the same image.
+    ///
+    /// This setting only applies to a single image, so it is ignored by
+    /// [Pdfium::create_pdf_from_images()].
+    #[inline]
+    pub fn pass_through_jpeg(mut self, data: Vec<u8>) -> Self {
+        self.jpeg_data = Some(data);
+
+        self
+    }
+
+    /// Returns the [PdfImagePageSize] setting of this [PdfImagePageOptions] object.
+    #[inline]
+    pub fn size(&self) -> PdfImagePageSize {
+        self.size
+    }
+
+    /// Returns the margin setting of this [PdfImagePageOptions] object.
+    #[inline]
+    pub fn margin(&self) -> PdfPoints {
+        self.margin
+    }
+
+    /// Returns the page rotation setting of this [PdfImagePageOptions] object.
+    #[inline]
+    pub fn rotation(&self) -> PdfPageRenderRotation {
+        self.rotation
+    }
+
+    /// Returns the JPEG data that will be embedded as-is, if any.
+    #[inline]
+    pub fn jpeg_data(&self) -> Option<&[u8]> {
+        self.jpeg_data.as_deref()
+    }
+}
+
+impl Default for PdfImagePageOptions {
+    #[inline]
+    fn default() -> Self {
+        PdfImagePageOptions::new()
+    }
+}
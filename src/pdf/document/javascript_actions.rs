@@ -0,0 +1,183 @@
+//! Defines the [PdfJavaScriptActions] struct, a collection of all the document-level
+//! `PdfJavaScriptAction` objects in a `PdfDocument`.
+
+use crate::bindgen::FPDF_DOCUMENT;
+use crate::bindings::PdfiumLibraryBindings;
+use crate::error::{PdfiumError, PdfiumInternalError};
+use crate::pdf::document::javascript_action::PdfJavaScriptAction;
+use std::ops::{Range, RangeInclusive};
+use std::os::raw::c_int;
+
+/// The zero-based index of a single [PdfJavaScriptAction] inside its containing [PdfJavaScriptActions] collection.
+pub type PdfJavaScriptActionIndex = u16;
+
+/// The collection of document-level [PdfJavaScriptAction] objects inside a `PdfDocument`.
+pub struct PdfJavaScriptActions<'a> {
+    document_handle: FPDF_DOCUMENT,
+    bindings: &'a dyn PdfiumLibraryBindings,
+}
+
+impl<'a> PdfJavaScriptActions<'a> {
+    #[inline]
+    pub(crate) fn from_pdfium(
+        document_handle: FPDF_DOCUMENT,
+        bindings: &'a dyn PdfiumLibraryBindings,
+    ) -> Self {
+        PdfJavaScriptActions {
+            document_handle,
+            bindings,
+        }
+    }
+
+    /// Returns the [PdfiumLibraryBindings] used by this [PdfJavaScriptActions] collection.
+    #[inline]
+    pub fn bindings(&self) -> &'a dyn PdfiumLibraryBindings {
+        self.bindings
+    }
+
+    /// Returns the number of actions in this [PdfJavaScriptActions] collection.
+    pub fn len(&self) -> PdfJavaScriptActionIndex {
+        // Pdfium returns -1 if the document cannot be queried; we treat this the same
+        // as a document containing no JavaScript actions.
+
+        self.bindings()
+            .FPDFDoc_GetJavaScriptActionCount(self.document_handle)
+            .max(0) as PdfJavaScriptActionIndex
+    }
+
+    /// Returns `true` if this [PdfJavaScriptActions] collection is empty.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns a Range from `0..(number of actions)` for this [PdfJavaScriptActions] collection.
+    #[inline]
+    pub fn as_range(&self) -> Range<PdfJavaScriptActionIndex> {
+        0..self.len()
+    }
+
+    /// Returns an inclusive Range from `0..=(number of actions - 1)`
+    /// for this [PdfJavaScriptActions] collection.
+    #[inline]
+    pub fn as_range_inclusive(&self) -> RangeInclusive<PdfJavaScriptActionIndex> {
+        if self.is_empty() {
+            0..=0
+        } else {
+            0..=(self.len() - 1)
+        }
+    }
+
+    /// Returns a single [PdfJavaScriptAction] from this [PdfJavaScriptActions] collection.
+    pub fn get(
+        &self,
+        index: PdfJavaScriptActionIndex,
+    ) -> Result<PdfJavaScriptAction<'a>, PdfiumError> {
+        if index >= self.len() {
+            return Err(PdfiumError::JavaScriptActionIndexOutOfBounds);
+        }
+
+        let handle = self
+            .bindings()
+            .FPDFDoc_GetJavaScriptAction(self.document_handle, index as c_int);
+
+        if handle.is_null() {
+            Err(self.bindings().error_in(
+                "FPDFDoc_GetJavaScriptAction",
+                PdfiumError::PdfiumLibraryInternalError(PdfiumInternalError::Unknown),
+            ))
+        } else {
+            Ok(PdfJavaScriptAction::from_pdfium(handle, self.bindings()))
+        }
+    }
+
+    /// Returns an iterator over all the actions in this [PdfJavaScriptActions] collection.
+    #[inline]
+    pub fn iter(&self) -> PdfJavaScriptActionsIterator<'_> {
+        PdfJavaScriptActionsIterator::new(self)
+    }
+}
+
+/// An iterator over all the [PdfJavaScriptAction] objects in a [PdfJavaScriptActions] collection.
+pub struct PdfJavaScriptActionsIterator<'a> {
+    actions: &'a PdfJavaScriptActions<'a>,
+    next_index: PdfJavaScriptActionIndex,
+}
+
+impl<'a> PdfJavaScriptActionsIterator<'a> {
+    #[inline]
+    pub(crate) fn new(actions: &'a PdfJavaScriptActions<'a>) -> Self {
+        PdfJavaScriptActionsIterator {
+            actions,
+            next_index: 0,
+        }
+    }
+}
+
+impl<'a> Iterator for PdfJavaScriptActionsIterator<'a> {
+    type Item = PdfJavaScriptAction<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let next = self.actions.get(self.next_index);
+
+        self.next_index += 1;
+
+        next.ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::utils::test::test_bind_to_pdfium;
+
+    #[test]
+    fn test_javascript_actions() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let document = pdfium.load_pdf_from_file("./test/javascript-test.pdf", None)?;
+
+        let actions = document.javascript_actions();
+
+        assert_eq!(actions.len(), 2);
+
+        let greeting = actions.get(0)?;
+
+        assert_eq!(greeting.name(), "Greeting");
+        assert_eq!(greeting.script(), "app.alert('Hello from pdfium-render');");
+
+        // Scripts longer than 64 KB must be retrieved in full.
+
+        let expected = (0..4000)
+            .map(|index| format!("var value{:05} = {}; ", index, index))
+            .collect::<String>();
+
+        assert!(expected.len() > 65536);
+
+        let large = actions
+            .iter()
+            .find(|action| action.name() == "Large")
+            .unwrap();
+
+        assert_eq!(large.script(), expected);
+
+        assert!(matches!(
+            actions.get(2),
+            Err(PdfiumError::JavaScriptActionIndexOutOfBounds)
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_no_javascript_actions() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let document = pdfium.create_new_pdf()?;
+
+        assert!(document.javascript_actions().is_empty());
+        assert_eq!(document.javascript_actions().iter().count(), 0);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,338 @@
+//! Defines the [PdfLayers] struct, exposing the optional content groups (layers)
+//! defined in a `PdfDocument`.
+
+use crate::error::PdfiumError;
+use crate::pdf::document::raw::{
+    decode_text_string, get, parse_dictionary, parse_names, parse_reference, parse_references,
+    PdfRawDictionary, PdfRawDocument,
+};
+use std::slice::Iter;
+
+#[cfg(doc)]
+use {
+    crate::pdf::document::page::render_config::PdfRenderConfig, crate::pdf::document::PdfDocument,
+};
+
+/// The index of a single [PdfLayer] within its containing [PdfLayers] collection.
+pub type PdfLayerIndex = usize;
+
+/// The intended use of a single [PdfLayer], as specified by the `/Intent` entry
+/// of its optional content group dictionary.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum PdfLayerIntent {
+    /// The layer is intended for interactive use by document consumers.
+    View,
+
+    /// The layer is intended to represent a document designer's structural organization
+    /// of artwork.
+    Design,
+
+    /// An intent not defined by The PDF Reference. The name of the intent is given.
+    Other(String),
+}
+
+impl PdfLayerIntent {
+    #[inline]
+    pub(crate) fn from_name(name: &str) -> Self {
+        match name {
+            "View" => PdfLayerIntent::View,
+            "Design" => PdfLayerIntent::Design,
+            _ => PdfLayerIntent::Other(name.to_owned()),
+        }
+    }
+}
+
+/// A single optional content group, more commonly known as a layer, in a [PdfDocument].
+/// Page content belonging to a layer can be shown or hidden as a unit.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PdfLayer {
+    name: String,
+    is_visible_by_default: bool,
+    intent: Vec<PdfLayerIntent>,
+}
+
+impl PdfLayer {
+    /// Returns the name of this [PdfLayer], as displayed in the layers panel of a viewer.
+    /// Layer names need not be unique.
+    #[inline]
+    pub fn name(&self) -> &str {
+        self.name.as_str()
+    }
+
+    /// Returns `true` if this [PdfLayer] is visible when the document is first opened,
+    /// according to the document's default optional content configuration.
+    #[inline]
+    pub fn is_visible_by_default(&self) -> bool {
+        self.is_visible_by_default
+    }
+
+    /// Returns the intended uses of this [PdfLayer]. A layer that does not specify an intent
+    /// is intended for viewing.
+    #[inline]
+    pub fn intent(&self) -> &[PdfLayerIntent] {
+        self.intent.as_slice()
+    }
+}
+
+/// The optional content groups, or layers, defined in the `/OCProperties` dictionary
+/// of a [PdfDocument]'s catalog, in the order they are listed there.
+///
+/// Pdfium offers no API for reading optional content groups, so the layers are read directly
+/// from the document's catalog when this collection is created. Changes to the document
+/// made afterwards are not reflected in an existing [PdfLayers] collection.
+///
+/// Use [PdfRenderConfig::set_layer_visibility()] to hide layers when rendering pages.
+#[derive(Clone, Debug, Default)]
+pub struct PdfLayers {
+    layers: Vec<PdfLayer>,
+}
+
+impl PdfLayers {
+    /// Reads the optional content groups listed in the catalog of the given saved document.
+    pub(crate) fn from_raw_document(document: &PdfRawDocument) -> Result<Self, PdfiumError> {
+        let catalog = document
+            .root_object_number()
+            .and_then(|object_number| document.object_dictionary(object_number))
+            .ok_or(PdfiumError::DocumentLayersNotReadable)?;
+
+        let properties = match get(&catalog, "OCProperties")
+            .and_then(|value| Self::resolve_dictionary(document, value))
+        {
+            Some(properties) => properties,
+            None => {
+                // The document does not use optional content.
+
+                return Ok(PdfLayers::default());
+            }
+        };
+
+        let configuration = get(&properties, "D")
+            .and_then(|value| Self::resolve_dictionary(document, value))
+            .unwrap_or_default();
+
+        // Groups are visible by default unless the default configuration says otherwise.
+        // A base state of /Unchanged is treated as /ON for the default configuration.
+
+        let is_base_state_off = get(&configuration, "BaseState") == Some("/OFF");
+
+        let on = get(&configuration, "ON")
+            .map(parse_references)
+            .unwrap_or_default();
+
+        let off = get(&configuration, "OFF")
+            .map(parse_references)
+            .unwrap_or_default();
+
+        let layers = get(&properties, "OCGs")
+            .map(parse_references)
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|object_number| {
+                let group = document.object_dictionary(object_number)?;
+
+                let intent = match get(&group, "Intent").map(parse_names) {
+                    Some(names) if !names.is_empty() => names
+                        .iter()
+                        .map(|name| PdfLayerIntent::from_name(name))
+                        .collect(),
+                    _ => vec![PdfLayerIntent::View],
+                };
+
+                Some(PdfLayer {
+                    name: get(&group, "Name")
+                        .and_then(decode_text_string)
+                        .unwrap_or_default(),
+                    is_visible_by_default: if is_base_state_off {
+                        on.contains(&object_number)
+                    } else {
+                        !off.contains(&object_number)
+                    },
+                    intent,
+                })
+            })
+            .collect();
+
+        Ok(PdfLayers { layers })
+    }
+
+    /// Returns the entries of the given serialized dictionary, following the given value
+    /// if it is an indirect reference.
+    fn resolve_dictionary(document: &PdfRawDocument, value: &str) -> Option<PdfRawDictionary> {
+        match parse_reference(value) {
+            Some(object_number) => document.object_dictionary(object_number),
+            None => parse_dictionary(value),
+        }
+    }
+
+    /// Returns the number of layers in this [PdfLayers] collection.
+    #[inline]
+    pub fn len(&self) -> PdfLayerIndex {
+        self.layers.len()
+    }
+
+    /// Returns `true` if this [PdfLayers] collection is empty.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.layers.is_empty()
+    }
+
+    /// Returns a single [PdfLayer] from this [PdfLayers] collection.
+    pub fn get(&self, index: PdfLayerIndex) -> Result<&PdfLayer, PdfiumError> {
+        self.layers
+            .get(index)
+            .ok_or(PdfiumError::LayerIndexOutOfBounds)
+    }
+
+    /// Returns the first [PdfLayer] in this [PdfLayers] collection with the given name, if any.
+    pub fn find(&self, name: &str) -> Option<&PdfLayer> {
+        self.layers.iter().find(|layer| layer.name() == name)
+    }
+
+    /// Returns an iterator over all the layers in this [PdfLayers] collection.
+    #[inline]
+    pub fn iter(&self) -> Iter<'_, PdfLayer> {
+        self.layers.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::utils::test::test_bind_to_pdfium;
+
+    #[test]
+    fn test_list_layers() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let document = pdfium.load_pdf_from_file("./test/layers-test.pdf", None)?;
+
+        let layers = document.layers()?;
+
+        assert_eq!(layers.len(), 3);
+
+        let names = layers.iter().map(|layer| layer.name()).collect::<Vec<_>>();
+
+        assert_eq!(names, vec!["Layer A", "Layer B", "Construction Lines"]);
+        assert!(layers.get(0)?.is_visible_by_default());
+        assert!(layers.get(1)?.is_visible_by_default());
+        assert!(!layers.get(2)?.is_visible_by_default());
+        assert_eq!(layers.get(0)?.intent(), &[PdfLayerIntent::View]);
+        assert_eq!(
+            layers.find("Construction Lines").unwrap().intent(),
+            &[PdfLayerIntent::View, PdfLayerIntent::Design]
+        );
+        assert!(layers.find("Layer C").is_none());
+        assert!(layers.get(3).is_err());
+
+        // A document without optional content has no layers.
+
+        assert!(pdfium.create_new_pdf()?.layers()?.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(any(feature = "pdfium_future", feature = "pdfium_6996"))]
+    fn test_render_with_hidden_layers() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        if !pdfium
+            .bindings()
+            .is_function_available("FPDFPageObj_SetIsActive")
+        {
+            // The bound Pdfium library is too old to support page object active states.
+
+            return Ok(());
+        }
+
+        let document = pdfium.load_pdf_from_file("./test/layers-test.pdf", None)?;
+
+        let page = document.pages().first()?;
+
+        // Returns the RGB color of the pixel at the given page coordinates. The page is
+        // rendered at a scale of one pixel per point.
+
+        fn colors_at(
+            page: &PdfPage,
+            config: PdfRenderConfig,
+            points: &[(usize, usize)],
+        ) -> Result<Vec<[u8; 3]>, PdfiumError> {
+            let bitmap = page.render_with_config(&config.set_target_width(612))?;
+
+            let bytes = bitmap.as_rgba_bytes();
+
+            Ok(points
+                .iter()
+                .map(|(x, y)| {
+                    let offset = ((bitmap.height() as usize - y) * bitmap.width() as usize + x) * 4;
+
+                    [bytes[offset], bytes[offset + 1], bytes[offset + 2]]
+                })
+                .collect())
+        }
+
+        const RED: [u8; 3] = [255, 0, 0];
+        const BLUE: [u8; 3] = [0, 0, 255];
+        const BLACK: [u8; 3] = [0, 0, 0];
+        const WHITE: [u8; 3] = [255, 255, 255];
+
+        // Layer A, Layer B, Layer B inside a form object, the default-off
+        // construction lines, and unlayered content.
+
+        let points = [(150, 600), (450, 600), (450, 200), (150, 200), (300, 375)];
+
+        assert_eq!(
+            colors_at(&page, PdfRenderConfig::new(), &points)?,
+            vec![RED, BLUE, BLUE, WHITE, BLACK]
+        );
+        assert_eq!(
+            colors_at(
+                &page,
+                PdfRenderConfig::new().set_layer_visibility([("Layer A", false)]),
+                &points
+            )?,
+            vec![WHITE, BLUE, BLUE, WHITE, BLACK]
+        );
+        assert_eq!(
+            colors_at(
+                &page,
+                PdfRenderConfig::new().set_layer_visibility([("Layer B", false)]),
+                &points
+            )?,
+            vec![RED, WHITE, WHITE, WHITE, BLACK]
+        );
+
+        // Later overrides take precedence over earlier ones.
+
+        assert_eq!(
+            colors_at(
+                &page,
+                PdfRenderConfig::new()
+                    .set_layer_visibility([("Layer A", false), ("Layer A", true)]),
+                &points
+            )?,
+            vec![RED, BLUE, BLUE, WHITE, BLACK]
+        );
+
+        // Hidden objects are restored after rendering.
+
+        assert_eq!(
+            colors_at(&page, PdfRenderConfig::new(), &points)?,
+            vec![RED, BLUE, BLUE, WHITE, BLACK]
+        );
+
+        // Content on the second page belongs to an optional content membership dictionary.
+
+        let page = document.pages().get(1)?;
+
+        assert!(page.render_with_config(&PdfRenderConfig::new()).is_ok());
+        assert!(matches!(
+            page.render_with_config(
+                &PdfRenderConfig::new().set_layer_visibility([("Layer A", false)])
+            ),
+            Err(PdfiumError::LayerMembershipNotSupported)
+        ));
+
+        Ok(())
+    }
+}
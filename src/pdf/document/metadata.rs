@@ -2,6 +2,9 @@
 
 use crate::bindgen::FPDF_DOCUMENT;
 use crate::bindings::PdfiumLibraryBindings;
+use crate::error::PdfiumError;
+use crate::pdf::date::PdfDate;
+use crate::pdf::document::raw::{encode_text_string, PdfIncrementalUpdate};
 use crate::utils::mem::create_byte_buffer;
 use crate::utils::utf16le::get_string_from_pdfium_utf16le_bytes;
 use std::os::raw::c_void;
@@ -21,6 +24,47 @@ pub enum PdfDocumentMetadataTagType {
     Producer,
     CreationDate,
     ModificationDate,
+
+    /// Indicates whether the document has been modified to include trapping information.
+    /// Valid values are `True`, `False`, and `Unknown`.
+    Trapped,
+}
+
+impl PdfDocumentMetadataTagType {
+    /// All metadata tag types, in the order in which they are written to the
+    /// document information dictionary.
+    const ALL: [PdfDocumentMetadataTagType; 9] = [
+        PdfDocumentMetadataTagType::Title,
+        PdfDocumentMetadataTagType::Author,
+        PdfDocumentMetadataTagType::Subject,
+        PdfDocumentMetadataTagType::Keywords,
+        PdfDocumentMetadataTagType::Creator,
+        PdfDocumentMetadataTagType::Producer,
+        PdfDocumentMetadataTagType::CreationDate,
+        PdfDocumentMetadataTagType::ModificationDate,
+        PdfDocumentMetadataTagType::Trapped,
+    ];
+
+    /// Returns the key used for this metadata tag type in the document information dictionary.
+    #[inline]
+    pub(crate) fn as_pdf_key(&self) -> &'static str {
+        match self {
+            PdfDocumentMetadataTagType::Title => "Title",
+            PdfDocumentMetadataTagType::Author => "Author",
+            PdfDocumentMetadataTagType::Subject => "Subject",
+            PdfDocumentMetadataTagType::Keywords => "Keywords",
+            PdfDocumentMetadataTagType::Creator => "Creator",
+            PdfDocumentMetadataTagType::Producer => "Producer",
+            PdfDocumentMetadataTagType::CreationDate => "CreationDate",
+            PdfDocumentMetadataTagType::ModificationDate => "ModDate",
+            PdfDocumentMetadataTagType::Trapped => "Trapped",
+        }
+    }
+
+    #[inline]
+    fn sort_order(&self) -> usize {
+        Self::ALL.iter().position(|tag| tag == self).unwrap_or(0)
+    }
 }
 
 /// A single metadata tag in a [PdfDocument].
@@ -50,10 +94,17 @@ impl PdfDocumentMetadataTag {
 }
 
 /// A collection of all the metadata tags in a [PdfDocument].
+///
+/// Pdfium does not provide any way to modify the document information dictionary directly.
+/// Changes made to metadata tags are therefore held in this collection and written out
+/// as an incremental update to the document information dictionary whenever the containing
+/// [PdfDocument] is saved. Only the standard metadata tags listed in
+/// [PdfDocumentMetadataTagType] are carried across into the updated dictionary.
 pub struct PdfMetadata<'a> {
     document_handle: FPDF_DOCUMENT,
     bindings: &'a dyn PdfiumLibraryBindings,
     tags: Vec<PdfDocumentMetadataTag>,
+    is_modified: bool,
 }
 
 impl<'a> PdfMetadata<'a> {
@@ -65,38 +116,13 @@ impl<'a> PdfMetadata<'a> {
             document_handle,
             bindings,
             tags: vec![],
+            is_modified: false,
         };
 
-        if let Some(tag) = result.get(PdfDocumentMetadataTagType::Title) {
-            result.tags.push(tag);
-        }
-
-        if let Some(tag) = result.get(PdfDocumentMetadataTagType::Author) {
-            result.tags.push(tag);
-        }
-
-        if let Some(tag) = result.get(PdfDocumentMetadataTagType::Subject) {
-            result.tags.push(tag);
-        }
-
-        if let Some(tag) = result.get(PdfDocumentMetadataTagType::Keywords) {
-            result.tags.push(tag);
-        }
-
-        if let Some(tag) = result.get(PdfDocumentMetadataTagType::Creator) {
-            result.tags.push(tag);
-        }
-
-        if let Some(tag) = result.get(PdfDocumentMetadataTagType::Producer) {
-            result.tags.push(tag);
-        }
-
-        if let Some(tag) = result.get(PdfDocumentMetadataTagType::CreationDate) {
-            result.tags.push(tag);
-        }
-
-        if let Some(tag) = result.get(PdfDocumentMetadataTagType::ModificationDate) {
-            result.tags.push(tag);
+        for tag in PdfDocumentMetadataTagType::ALL.iter() {
+            if let Some(value) = result.get_raw_metadata_tag(tag.as_pdf_key()) {
+                result.tags.push(PdfDocumentMetadataTag::new(*tag, value));
+            }
         }
 
         result
@@ -122,20 +148,131 @@ impl<'a> PdfMetadata<'a> {
 
     /// Returns one metadata tag from this [PdfMetadata] collection, if it is defined.
     pub fn get(&self, tag: PdfDocumentMetadataTagType) -> Option<PdfDocumentMetadataTag> {
-        let result = match tag {
-            PdfDocumentMetadataTagType::Title => self.get_raw_metadata_tag("Title"),
-            PdfDocumentMetadataTagType::Author => self.get_raw_metadata_tag("Author"),
-            PdfDocumentMetadataTagType::Subject => self.get_raw_metadata_tag("Subject"),
-            PdfDocumentMetadataTagType::Keywords => self.get_raw_metadata_tag("Keywords"),
-            PdfDocumentMetadataTagType::Creator => self.get_raw_metadata_tag("Creator"),
-            PdfDocumentMetadataTagType::Producer => self.get_raw_metadata_tag("Producer"),
-            PdfDocumentMetadataTagType::CreationDate => self.get_raw_metadata_tag("CreationDate"),
-            PdfDocumentMetadataTagType::ModificationDate => {
-                self.get_raw_metadata_tag("ModificationDate")
-            }
+        self.tags
+            .iter()
+            .find(|candidate| candidate.tag_type() == tag)
+            .cloned()
+    }
+
+    /// Sets the value of the given metadata tag in this [PdfMetadata] collection,
+    /// replacing any existing value.
+    ///
+    /// Values for the [PdfDocumentMetadataTagType::CreationDate] and
+    /// [PdfDocumentMetadataTagType::ModificationDate] tags must be valid PDF date strings;
+    /// consider using the [PdfMetadata::set_creation_date()] and
+    /// [PdfMetadata::set_modification_date()] functions instead. Values for the
+    /// [PdfDocumentMetadataTagType::Trapped] tag must be one of `True`, `False`, or `Unknown`.
+    ///
+    /// The change will be written to the document information dictionary when the
+    /// containing [PdfDocument] is saved.
+    pub fn set(&mut self, tag: PdfDocumentMetadataTagType, value: &str) -> Result<(), PdfiumError> {
+        let value = match tag {
+            PdfDocumentMetadataTagType::CreationDate
+            | PdfDocumentMetadataTagType::ModificationDate => PdfDate::from_pdf_string(value)
+                .map_err(|_| PdfiumError::InvalidPdfDocumentMetadataValue)?
+                .to_pdf_string(),
+            PdfDocumentMetadataTagType::Trapped => match value {
+                "True" | "False" | "Unknown" => value.to_owned(),
+                _ => return Err(PdfiumError::InvalidPdfDocumentMetadataValue),
+            },
+            _ => value.to_owned(),
         };
 
-        result.map(|value| PdfDocumentMetadataTag::new(tag, value))
+        self.tags.retain(|candidate| candidate.tag_type() != tag);
+        self.tags.push(PdfDocumentMetadataTag::new(tag, value));
+        self.tags.sort_by_key(|tag| tag.tag_type().sort_order());
+        self.is_modified = true;
+
+        Ok(())
+    }
+
+    /// Removes the given metadata tag from this [PdfMetadata] collection, returning `true`
+    /// if the tag was present.
+    ///
+    /// The change will be written to the document information dictionary when the
+    /// containing [PdfDocument] is saved.
+    pub fn remove(&mut self, tag: PdfDocumentMetadataTagType) -> bool {
+        let len = self.tags.len();
+
+        self.tags.retain(|candidate| candidate.tag_type() != tag);
+
+        let was_removed = self.tags.len() != len;
+
+        self.is_modified |= was_removed;
+
+        was_removed
+    }
+
+    /// Returns the date and time at which the containing [PdfDocument] was created, if defined.
+    #[inline]
+    pub fn creation_date(&self) -> Option<PdfDate> {
+        self.get(PdfDocumentMetadataTagType::CreationDate)
+            .and_then(|tag| PdfDate::from_pdf_string(tag.value()).ok())
+    }
+
+    /// Sets the date and time at which the containing [PdfDocument] was created.
+    #[inline]
+    pub fn set_creation_date(&mut self, date: PdfDate) -> Result<(), PdfiumError> {
+        self.set(
+            PdfDocumentMetadataTagType::CreationDate,
+            date.to_pdf_string().as_str(),
+        )
+    }
+
+    /// Returns the date and time at which the containing [PdfDocument] was most recently
+    /// modified, if defined.
+    #[inline]
+    pub fn modification_date(&self) -> Option<PdfDate> {
+        self.get(PdfDocumentMetadataTagType::ModificationDate)
+            .and_then(|tag| PdfDate::from_pdf_string(tag.value()).ok())
+    }
+
+    /// Sets the date and time at which the containing [PdfDocument] was most recently modified.
+    #[inline]
+    pub fn set_modification_date(&mut self, date: PdfDate) -> Result<(), PdfiumError> {
+        self.set(
+            PdfDocumentMetadataTagType::ModificationDate,
+            date.to_pdf_string().as_str(),
+        )
+    }
+
+    /// Returns `true` if any metadata tags in this [PdfMetadata] collection have been changed
+    /// since the containing [PdfDocument] was loaded.
+    #[inline]
+    pub fn is_modified(&self) -> bool {
+        self.is_modified
+    }
+
+    /// Returns the metadata tags in this [PdfMetadata] collection serialized as a
+    /// PDF document information dictionary, as defined in Section 14.3.3 of the
+    /// PDF Reference Manual, version 1.7, on page 549.
+    pub fn to_info_dictionary(&self) -> String {
+        let mut result = String::from("<<");
+
+        for tag in self.tags.iter() {
+            result.push_str(" /");
+            result.push_str(tag.tag_type().as_pdf_key());
+            result.push(' ');
+
+            if tag.tag_type() == PdfDocumentMetadataTagType::Trapped {
+                result.push('/');
+                result.push_str(tag.value());
+            } else {
+                result.push_str(encode_text_string(tag.value()).as_str());
+            }
+        }
+
+        result.push_str(" >>");
+
+        result
+    }
+
+    /// Adds a new document information dictionary containing the tags in this [PdfMetadata]
+    /// collection to the given incremental update, and references it from the update's trailer.
+    pub(crate) fn add_info_dictionary_to_update(&self, update: &mut PdfIncrementalUpdate) {
+        let object_number = update.add_object(self.to_info_dictionary().into_bytes());
+
+        update.set_trailer_entry("Info", format!("{} 0 R", object_number));
     }
 
     #[inline]
@@ -178,3 +315,74 @@ impl<'a> PdfMetadata<'a> {
         self.tags.iter()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::pdf::document::raw::{get, PdfRawDocument};
+    use crate::prelude::*;
+    use crate::utils::test::test_bind_to_pdfium;
+
+    #[test]
+    fn test_set_metadata_save_and_reload() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let created = PdfDate::new(2024, 3, 14, 15, 9, 26, 60)?;
+
+        let bytes = {
+            let mut document = pdfium.create_new_pdf()?;
+
+            document
+                .pages_mut()
+                .create_page_at_start(PdfPagePaperSize::a4())?;
+
+            let metadata = document.metadata_mut();
+
+            metadata.set(PdfDocumentMetadataTagType::Title, "Quarterly Report")?;
+            metadata.set(PdfDocumentMetadataTagType::Author, "Zoë Example")?;
+            metadata.set(PdfDocumentMetadataTagType::Trapped, "False")?;
+            metadata.set_creation_date(created)?;
+
+            assert!(metadata
+                .set(PdfDocumentMetadataTagType::Trapped, "Maybe")
+                .is_err());
+
+            document.save_to_bytes()?
+        };
+
+        // External tools read the document information dictionary referenced by the
+        // trailer of the final incremental update.
+
+        let raw = PdfRawDocument::parse(bytes.as_slice()).unwrap();
+
+        assert!(get(raw.trailer(), "Info").is_some());
+        assert!(bytes
+            .windows(b"/Title (Quarterly Report)".len())
+            .any(|window| window == b"/Title (Quarterly Report)"));
+
+        let document = pdfium.load_pdf_from_byte_vec(bytes, None)?;
+
+        let metadata = document.metadata();
+
+        assert_eq!(
+            metadata
+                .get(PdfDocumentMetadataTagType::Title)
+                .map(|tag| tag.value().to_owned()),
+            Some("Quarterly Report".to_owned())
+        );
+        assert_eq!(
+            metadata
+                .get(PdfDocumentMetadataTagType::Author)
+                .map(|tag| tag.value().to_owned()),
+            Some("Zoë Example".to_owned())
+        );
+        assert_eq!(
+            metadata
+                .get(PdfDocumentMetadataTagType::Trapped)
+                .map(|tag| tag.value().to_owned()),
+            Some("False".to_owned())
+        );
+        assert_eq!(metadata.creation_date(), Some(created));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,443 @@
+//! Defines the [PdfNupLayout] struct, a builder-based approach to configuring the imposition
+//! of several pages from one `PdfDocument` onto each sheet of a new document.
+
+use crate::error::{PdfiumError, PdfiumInternalError};
+use crate::pdf::bitmap::Pixels;
+use crate::pdf::document::page::object::image::PdfPageImageObject;
+use crate::pdf::document::page::object::private::internal::PdfPageObjectPrivate;
+use crate::pdf::document::page::object::PdfPageObjectCommon;
+use crate::pdf::document::page::objects::common::PdfPageObjectsCommon;
+use crate::pdf::document::page::render_config::PdfRenderConfig;
+use crate::pdf::document::page::size::PdfPagePaperSize;
+use crate::pdf::document::page::{PdfPage, PdfPageRenderRotation};
+use crate::pdf::document::pages::PdfPageIndex;
+use crate::pdf::document::{PdfDocument, PdfDocumentVersion};
+use crate::pdf::points::PdfPoints;
+use crate::pdf::rect::PdfRect;
+
+/// The order in which source pages are placed into the slots on each sheet
+/// of an N-up document.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum PdfNupPageOrder {
+    /// Source pages are placed in their original order, filling the slots on each sheet
+    /// from left to right, then from top to bottom.
+    Sequential,
+
+    /// Source pages are placed in booklet imposition order, so that the printed sheets
+    /// can be folded in half and nested to form a booklet. The number of source pages is
+    /// padded with blank slots to a multiple of four, then pages are placed in the order
+    /// n, 1, 2, n - 1, n - 2, 3, 4, n - 3, and so on.
+    ///
+    /// This order is intended for use with a two-up layout, where each pair of sheets
+    /// forms the front and back of a single printed sheet.
+    Booklet,
+}
+
+/// Controls how a source page containing page objects that cannot be copied is placed
+/// onto a sheet of an N-up document.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum PdfNupFallback {
+    /// Stop creating the N-up document, returning [PdfiumError::PageContainsNonCopyablePageObjects].
+    Fail,
+
+    /// Render the source page to a bitmap at the given resolution, in dots per inch of the
+    /// scaled page as placed on the sheet, and place the rendered bitmap onto the sheet as
+    /// a single image object.
+    RenderToImage { dpi: f32 },
+}
+
+/// Configures the imposition of several source pages onto each sheet of a new document,
+/// for instance to create two-up or four-up handouts, using the
+/// [PdfDocument::create_nup_document()] function.
+///
+/// Each sheet is divided into a grid of equally sized slots, separated by an optional gutter
+/// and surrounded by an optional margin. Each source page is scaled to fit its slot,
+/// preserving its aspect ratio, and centered within the slot. Any intrinsic rotation of
+/// a source page is taken into account, so pages appear on each sheet as they would
+/// appear in a viewer.
+///
+/// Page objects are copied onto each sheet using the [PdfPageObjectCommon::try_copy()]
+/// function, and are subject to the same restrictions; in particular, the fonts used by
+/// copied text objects are not embedded into the new document. Annotations are not copied.
+/// Page objects extending outside the visible area of a source page are not clipped,
+/// and may extend beyond their slot. A source page containing page objects that cannot
+/// be copied is handled according to the [PdfNupFallback] setting.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct PdfNupLayout {
+    rows: u8,
+    columns: u8,
+    margin: PdfPoints,
+    gutter: PdfPoints,
+    page_order: PdfNupPageOrder,
+    fallback: PdfNupFallback,
+}
+
+impl PdfNupLayout {
+    /// Creates a new [PdfNupLayout] that places source pages into a grid of the given number
+    /// of rows and columns on each sheet. By default, the layout uses no margin or gutter,
+    /// places source pages in their original order, and fails if a source page contains
+    /// page objects that cannot be copied.
+    pub fn new(rows: u8, columns: u8) -> Self {
+        PdfNupLayout {
+            rows: rows.max(1),
+            columns: columns.max(1),
+            margin: PdfPoints::ZERO,
+            gutter: PdfPoints::ZERO,
+            page_order: PdfNupPageOrder::Sequential,
+            fallback: PdfNupFallback::Fail,
+        }
+    }
+
+    /// Creates a new [PdfNupLayout] that places two source pages side by side on each sheet.
+    #[inline]
+    pub fn two_up() -> Self {
+        Self::new(1, 2)
+    }
+
+    /// Creates a new [PdfNupLayout] that places four source pages in a two by two grid
+    /// on each sheet.
+    #[inline]
+    pub fn four_up() -> Self {
+        Self::new(2, 2)
+    }
+
+    /// Sets the margin between the edges of each sheet and the slots on that sheet.
+    #[inline]
+    pub fn set_margin(mut self, margin: PdfPoints) -> Self {
+        self.margin = margin;
+
+        self
+    }
+
+    /// Sets the gutter separating adjacent slots on each sheet.
+    #[inline]
+    pub fn set_gutter(mut self, gutter: PdfPoints) -> Self {
+        self.gutter = gutter;
+
+        self
+    }
+
+    /// Sets the order in which source pages are placed into slots.
+    #[inline]
+    pub fn set_page_order(mut self, page_order: PdfNupPageOrder) -> Self {
+        self.page_order = page_order;
+
+        self
+    }
+
+    /// Sets how source pages containing page objects that cannot be copied are handled.
+    #[inline]
+    pub fn set_fallback(mut self, fallback: PdfNupFallback) -> Self {
+        self.fallback = fallback;
+
+        self
+    }
+
+    /// Returns the number of source pages placed onto each sheet by this [PdfNupLayout].
+    #[inline]
+    pub fn pages_per_sheet(&self) -> usize {
+        self.rows as usize * self.columns as usize
+    }
+
+    /// Returns the sequence of source page indices to place into consecutive slots for
+    /// a source document with the given number of pages. Blank slots are represented by `None`.
+    pub(crate) fn page_sequence(&self, page_count: PdfPageIndex) -> Vec<Option<PdfPageIndex>> {
+        let page_count = page_count as usize;
+
+        let page = |index: usize| {
+            if index < page_count {
+                Some(index as PdfPageIndex)
+            } else {
+                None
+            }
+        };
+
+        match self.page_order {
+            PdfNupPageOrder::Sequential => (0..page_count).map(page).collect(),
+            PdfNupPageOrder::Booklet => {
+                let padded_count = (page_count + 3) / 4 * 4;
+
+                let mut sequence = Vec::with_capacity(padded_count);
+
+                for sheet in 0..padded_count / 4 {
+                    sequence.push(page(padded_count - 1 - 2 * sheet));
+                    sequence.push(page(2 * sheet));
+                    sequence.push(page(2 * sheet + 1));
+                    sequence.push(page(padded_count - 2 - 2 * sheet));
+                }
+
+                sequence
+            }
+        }
+    }
+
+    /// Creates a new [PdfDocument] containing the pages of the given source document
+    /// imposed according to this [PdfNupLayout] onto sheets of the given size.
+    pub(crate) fn impose<'a>(
+        &self,
+        source: &PdfDocument<'a>,
+        sheet_size: PdfPagePaperSize,
+    ) -> Result<PdfDocument<'a>, PdfiumError> {
+        let bindings = source.bindings();
+
+        let handle = bindings.FPDF_CreateNewDocument();
+
+        if handle.is_null() {
+            return Err(bindings.error_in(
+                "FPDF_CreateNewDocument",
+                PdfiumError::PdfiumLibraryInternalError(PdfiumInternalError::Unknown),
+            ));
+        }
+
+        let mut document = PdfDocument::from_pdfium(handle, bindings);
+
+        document.set_version(PdfDocumentVersion::DEFAULT_VERSION);
+
+        let (rows, columns) = (self.rows as f32, self.columns as f32);
+
+        let sheet_height = sheet_size.height().value;
+
+        let slot_width = ((sheet_size.width().value
+            - self.margin.value * 2.0
+            - self.gutter.value * (columns - 1.0))
+            / columns)
+            .max(0.0);
+
+        let slot_height =
+            ((sheet_height - self.margin.value * 2.0 - self.gutter.value * (rows - 1.0)) / rows)
+                .max(0.0);
+
+        let sequence = self.page_sequence(source.pages().len());
+
+        for slots in sequence.chunks(self.pages_per_sheet()) {
+            let mut sheet = document.pages_mut().create_page_at_end(sheet_size)?;
+
+            for (slot, index) in slots.iter().enumerate() {
+                if let Some(index) = index {
+                    let column = (slot % self.columns as usize) as f32;
+
+                    let row = (slot / self.columns as usize) as f32;
+
+                    let left = self.margin.value + column * (slot_width + self.gutter.value);
+
+                    let top =
+                        sheet_height - self.margin.value - row * (slot_height + self.gutter.value);
+
+                    self.place_page(
+                        &source.pages().get(*index)?,
+                        &mut sheet,
+                        PdfRect::new_from_values(top - slot_height, left, top, left + slot_width),
+                    )?;
+                }
+            }
+        }
+
+        Ok(document)
+    }
+
+    /// Scales the given source page to fit the given slot on the given sheet, preserving its
+    /// aspect ratio, and places it centered in the slot.
+    fn place_page(
+        &self,
+        page: &PdfPage,
+        sheet: &mut PdfPage,
+        slot: PdfRect,
+    ) -> Result<(), PdfiumError> {
+        let bounds = page
+            .boundaries()
+            .crop()
+            .or_else(|_| page.boundaries().media())?
+            .bounds;
+
+        let rotation = page.rotation().unwrap_or(PdfPageRenderRotation::None);
+
+        let (page_width, page_height) = match rotation {
+            PdfPageRenderRotation::Degrees90 | PdfPageRenderRotation::Degrees270 => {
+                (bounds.height().value, bounds.width().value)
+            }
+            _ => (bounds.width().value, bounds.height().value),
+        };
+
+        if page_width <= 0.0 || page_height <= 0.0 {
+            return Ok(());
+        }
+
+        let scale = (slot.width().value / page_width).min(slot.height().value / page_height);
+
+        let x = slot.left().value + (slot.width().value - page_width * scale) / 2.0;
+
+        let y = slot.bottom().value + (slot.height().value - page_height * scale) / 2.0;
+
+        if page.objects().iter().all(|object| object.is_copyable()) {
+            // Map page space into the space of the page as displayed by a viewer,
+            // with its origin at the bottom left corner of the displayed page.
+
+            let (left, bottom, right, top) = (
+                bounds.left().value,
+                bounds.bottom().value,
+                bounds.right().value,
+                bounds.top().value,
+            );
+
+            let (a, b, c, d, e, f) = match rotation {
+                PdfPageRenderRotation::None => (1.0, 0.0, 0.0, 1.0, -left, -bottom),
+                PdfPageRenderRotation::Degrees90 => (0.0, -1.0, 1.0, 0.0, -bottom, right),
+                PdfPageRenderRotation::Degrees180 => (-1.0, 0.0, 0.0, -1.0, right, top),
+                PdfPageRenderRotation::Degrees270 => (0.0, 1.0, -1.0, 0.0, top, -left),
+            };
+
+            for object in page.objects().iter() {
+                let copy = object.try_copy_impl(sheet.document_handle(), sheet.bindings())?;
+
+                sheet.objects_mut().add_object(copy)?.transform(
+                    a * scale,
+                    b * scale,
+                    c * scale,
+                    d * scale,
+                    e * scale + x,
+                    f * scale + y,
+                )?;
+            }
+
+            Ok(())
+        } else {
+            match self.fallback {
+                PdfNupFallback::Fail => Err(PdfiumError::PageContainsNonCopyablePageObjects),
+                PdfNupFallback::RenderToImage { dpi } => {
+                    let (width, height) = (page_width * scale, page_height * scale);
+
+                    let pixels = |points: f32| ((points / 72.0 * dpi).round() as Pixels).max(1);
+
+                    let bitmap = page.render_with_config(
+                        &PdfRenderConfig::new().set_target_size(pixels(width), pixels(height)),
+                    )?;
+
+                    let mut object = PdfPageImageObject::new_from_handle(
+                        sheet.document_handle(),
+                        sheet.bindings(),
+                    )?;
+
+                    object.set_bitmap(&bitmap)?;
+                    object.scale(width, height)?;
+                    object.translate(PdfPoints::new(x), PdfPoints::new(y))?;
+
+                    sheet.objects_mut().add_image_object(object)?;
+
+                    Ok(())
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::utils::test::test_bind_to_pdfium;
+
+    #[test]
+    fn test_booklet_page_sequence() {
+        let layout = PdfNupLayout::two_up().set_page_order(PdfNupPageOrder::Booklet);
+
+        assert_eq!(
+            layout.page_sequence(8),
+            vec![
+                Some(7),
+                Some(0),
+                Some(1),
+                Some(6),
+                Some(5),
+                Some(2),
+                Some(3),
+                Some(4)
+            ]
+        );
+
+        // Page counts that are not a multiple of four are padded with blank slots.
+
+        assert_eq!(
+            layout.page_sequence(3),
+            vec![None, Some(0), Some(1), Some(2)]
+        );
+    }
+
+    #[test]
+    fn test_two_up_sheet_matches_composited_source_pages() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut source = pdfium.create_new_pdf()?;
+
+        for (rect, color) in [
+            (
+                PdfRect::new_from_values(100.0, 100.0, 400.0, 300.0),
+                PdfColor::RED,
+            ),
+            (
+                PdfRect::new_from_values(500.0, 200.0, 800.0, 500.0),
+                PdfColor::BLUE,
+            ),
+        ] {
+            source
+                .pages_mut()
+                .create_page_at_end(PdfPagePaperSize::a4())?
+                .objects_mut()
+                .create_path_object_rect(rect, None, None, Some(color))?;
+        }
+
+        // A4 pages fit exactly into the two halves of an A4 landscape sheet.
+
+        let sheet_size = PdfPagePaperSize::a4().landscape();
+
+        let document =
+            PdfDocument::create_nup_document(&source, PdfNupLayout::two_up(), sheet_size)?;
+
+        assert_eq!(document.pages().len(), 1);
+
+        let (sheet_width, sheet_height) = (
+            sheet_size.width().value.round() as Pixels,
+            sheet_size.height().value.round() as Pixels,
+        );
+
+        let sheet = document
+            .pages()
+            .get(0)?
+            .render_with_config(&PdfRenderConfig::new().set_target_size(sheet_width, sheet_height))?
+            .as_rgba_bytes();
+
+        // Composite renders of the two source pages side by side.
+
+        let slot_width = sheet_width / 2;
+
+        let mut composite = vec![255; sheet.len()];
+
+        for (slot, page) in source.pages().iter().enumerate() {
+            let bitmap = page.render_with_config(
+                &PdfRenderConfig::new().set_target_size(slot_width, sheet_height),
+            )?;
+
+            let pixels = bitmap.as_rgba_bytes();
+
+            for y in 0..sheet_height as usize {
+                for x in 0..slot_width as usize {
+                    let from = (y * slot_width as usize + x) * 4;
+
+                    let to = (y * sheet_width as usize + slot * slot_width as usize + x) * 4;
+
+                    composite[to..to + 4].copy_from_slice(&pixels[from..from + 4]);
+                }
+            }
+        }
+
+        // Allow for small differences along the anti-aliased edges of each rectangle.
+
+        let differing = sheet
+            .chunks(4)
+            .zip(composite.chunks(4))
+            .filter(|(a, b)| a.iter().zip(b.iter()).any(|(a, b)| a.abs_diff(*b) > 64))
+            .count();
+
+        assert!(differing < sheet.len() / 4 / 100);
+
+        Ok(())
+    }
+}
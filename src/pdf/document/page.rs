@@ -4,51 +4,120 @@
 pub mod annotation;
 pub mod annotations;
 pub mod boundaries;
+pub mod color_analysis;
 pub mod field;
 pub(crate) mod index_cache;
+pub mod layout;
 pub mod links;
 pub mod object;
 pub mod objects;
+pub mod overlay;
+pub mod regeneration_guard;
 pub mod render_config;
+pub mod render_job;
+pub mod resize;
 pub mod size;
+pub mod struct_tree;
 pub mod text;
+pub mod watermark;
 
 #[cfg(feature = "paragraph")]
 pub mod paragraph;
 
+#[cfg(all(feature = "threaded_render", not(target_arch = "wasm32")))]
+pub mod render_parallel;
+
 #[cfg(feature = "flatten")]
 mod flatten; // Keep internal flatten operation private.
 
 use object::ownership::PdfPageObjectOwnership;
 
 use crate::bindgen::{
-    FLATTEN_FAIL, FLATTEN_NOTHINGTODO, FLATTEN_SUCCESS, FLAT_PRINT, FPDF_DOCUMENT, FPDF_FORMHANDLE,
-    FPDF_PAGE,
+    FLATTEN_FAIL, FLATTEN_NOTHINGTODO, FLATTEN_SUCCESS, FLAT_NORMALDISPLAY, FLAT_PRINT,
+    FPDF_DOCUMENT, FPDF_FORMHANDLE, FPDF_PAGE, FPDF_PAGEOBJECT, FS_RECTF,
 };
 use crate::bindings::PdfiumLibraryBindings;
 use crate::create_transform_setters;
 use crate::error::{PdfiumError, PdfiumInternalError};
 use crate::pdf::bitmap::{PdfBitmap, PdfBitmapFormat, Pixels};
+use crate::pdf::document::page::annotation::private::internal::PdfPageAnnotationPrivate;
+use crate::pdf::document::page::annotation::PdfPageAnnotationCommon;
 use crate::pdf::document::page::annotations::PdfPageAnnotations;
 use crate::pdf::document::page::boundaries::PdfPageBoundaries;
+use crate::pdf::document::page::color_analysis::{
+    PdfPageColorAnalysis, PdfPageColorAnalysisOptions,
+};
+use crate::pdf::document::page::field::PdfFormFieldType;
 use crate::pdf::document::page::index_cache::PdfPageIndexCache;
+use crate::pdf::document::page::layout::PdfPageLayout;
 use crate::pdf::document::page::links::PdfPageLinks;
+use crate::pdf::document::page::object::PdfPageObject;
 use crate::pdf::document::page::objects::common::PdfPageObjectsCommon;
 use crate::pdf::document::page::objects::PdfPageObjects;
+use crate::pdf::document::page::overlay::{PdfPageOverlay, PdfPageOverlayPosition};
+use crate::pdf::document::page::regeneration_guard::PdfPageContentRegenerationGuard;
 use crate::pdf::document::page::render_config::{PdfPageRenderSettings, PdfRenderConfig};
+use crate::pdf::document::page::render_job::PdfProgressiveRenderJob;
+use crate::pdf::document::page::resize::PdfPageResizeMode;
 use crate::pdf::document::page::size::PdfPagePaperSize;
-use crate::pdf::document::page::text::PdfPageText;
+use crate::pdf::document::page::struct_tree::PdfStructTree;
+use crate::pdf::document::page::text::{PdfPageText, PdfPageTextHandle, PdfPageTextPage};
+use crate::pdf::document::page::watermark::PdfWatermarkConfig;
 use crate::pdf::font::PdfFont;
 use crate::pdf::matrix::{PdfMatrix, PdfMatrixValue};
+use crate::pdf::path::clip_path::PdfClipPath;
 use crate::pdf::points::PdfPoints;
 use crate::pdf::rect::PdfRect;
+use crate::utils::mem::create_byte_buffer;
+use std::cell::RefCell;
 use std::collections::{hash_map::Entry, HashMap};
 use std::f32::consts::{FRAC_PI_2, PI};
-use std::os::raw::{c_double, c_int};
+use std::os::raw::{c_int, c_ulong, c_void};
+use std::sync::Arc;
+
+#[cfg(any(feature = "pdfium_future", feature = "pdfium_6996"))]
+use crate::pdf::document::page::object::{
+    private::internal::PdfPageObjectPrivate, PdfPageObjectCommon,
+};
+
+#[cfg(all(target_os = "windows", feature = "windows_print"))]
+use {crate::bindgen::FPDF_REVERSE_BYTE_ORDER, windows::Win32::Graphics::Gdi::HDC};
+
+#[cfg(target_arch = "wasm32")]
+use {
+    crate::bindgen::FPDF_REVERSE_BYTE_ORDER,
+    wasm_bindgen::JsCast,
+    web_sys::{
+        CanvasRenderingContext2d, HtmlCanvasElement, OffscreenCanvas,
+        OffscreenCanvasRenderingContext2d,
+    },
+};
+
+#[cfg(doc)]
+use crate::pdf::document::page::color_analysis::PdfPageColorAnalysisMode;
+
+#[cfg(doc)]
+use crate::pdf::document::page::text::chars::PdfPageTextChars;
 
 #[cfg(doc)]
 use crate::pdf::document::PdfDocument;
 
+#[cfg(doc)]
+use crate::{pdf::document::page::render_config::PdfRenderColorScheme, pdfium::Pdfium};
+
+// The following dummy declarations are used only when running cargo doc.
+// They allow documentation of WASM-specific and Windows-specific functionality
+// to be included in documentation generated on other targets.
+
+#[cfg(doc)]
+struct HtmlCanvasElement;
+
+#[cfg(doc)]
+struct OffscreenCanvas;
+
+#[cfg(doc)]
+struct HDC;
+
 /// The orientation of a [PdfPage].
 #[derive(Copy, Clone, Debug, PartialEq)]
 pub enum PdfPageOrientation {
@@ -167,6 +236,57 @@ pub enum PdfPageContentRegenerationStrategy {
     Manual,
 }
 
+/// The intended use of the page content generated when annotations and form fields are
+/// flattened into a [PdfPage] by the [PdfPage::flatten()] function.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum PdfPageFlattenMode {
+    /// Flattens annotations and form fields as they appear when the page is displayed on screen.
+    NormalDisplay,
+
+    /// Flattens annotations and form fields as they appear when the page is printed.
+    Print,
+}
+
+impl PdfPageFlattenMode {
+    #[inline]
+    // The custom flatten operation used with the flatten crate feature does not call Pdfium.
+    #[cfg_attr(feature = "flatten", allow(dead_code))]
+    pub(crate) fn as_pdfium(&self) -> c_int {
+        (match self {
+            PdfPageFlattenMode::NormalDisplay => FLAT_NORMALDISPLAY,
+            PdfPageFlattenMode::Print => FLAT_PRINT,
+        }) as c_int
+    }
+}
+
+/// The outcome of flattening a [PdfPage] using the [PdfPage::flatten()] function,
+/// as reported by Pdfium.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum PdfPageFlattenResult {
+    /// Annotations and form fields were flattened into the page content.
+    Success,
+
+    /// The page contained no annotations or form fields that could be flattened.
+    NothingToDo,
+
+    /// Pdfium was unable to flatten the page.
+    Fail,
+}
+
+impl PdfPageFlattenResult {
+    #[inline]
+    // The custom flatten operation used with the flatten crate feature does not call Pdfium.
+    #[cfg_attr(feature = "flatten", allow(dead_code))]
+    pub(crate) fn from_pdfium(value: c_int) -> Result<Self, PdfiumError> {
+        match value as u32 {
+            FLATTEN_SUCCESS => Ok(PdfPageFlattenResult::Success),
+            FLATTEN_NOTHINGTODO => Ok(PdfPageFlattenResult::NothingToDo),
+            FLATTEN_FAIL => Ok(PdfPageFlattenResult::Fail),
+            _ => Err(PdfiumError::PageFlattenFailure),
+        }
+    }
+}
+
 /// A single page in a `PdfDocument`.
 ///
 /// In addition to its own intrinsic properties, a [PdfPage] serves as the entry point
@@ -186,10 +306,12 @@ pub struct PdfPage<'a> {
     label: Option<String>,
     regeneration_strategy: PdfPageContentRegenerationStrategy,
     is_content_regeneration_required: bool,
+    deferred_content_regeneration_depth: u32,
     annotations: PdfPageAnnotations<'a>,
     boundaries: PdfPageBoundaries<'a>,
     links: PdfPageLinks<'a>,
     objects: PdfPageObjects<'a>,
+    text_page: RefCell<Option<(u64, Arc<PdfPageTextPage>)>>,
     bindings: &'a dyn PdfiumLibraryBindings,
 }
 
@@ -214,6 +336,7 @@ impl<'a> PdfPage<'a> {
             label,
             regeneration_strategy: PdfPageContentRegenerationStrategy::Manual,
             is_content_regeneration_required: false,
+            deferred_content_regeneration_depth: 0,
             annotations: PdfPageAnnotations::from_pdfium(
                 document_handle,
                 page_handle,
@@ -223,6 +346,7 @@ impl<'a> PdfPage<'a> {
             boundaries: PdfPageBoundaries::from_pdfium(page_handle, bindings),
             links: PdfPageLinks::from_pdfium(page_handle, document_handle, bindings),
             objects: PdfPageObjects::from_pdfium(document_handle, page_handle, bindings),
+            text_page: RefCell::new(None),
             bindings,
         };
 
@@ -322,10 +446,18 @@ impl<'a> PdfPage<'a> {
             .is_true(self.bindings.FPDFPage_HasTransparency(self.page_handle))
     }
 
-    /// Returns the paper size of this [PdfPage].
+    /// Returns the paper size of this [PdfPage], taking any intrinsic page rotation into account.
+    ///
+    /// The page's dimensions are matched to the closest standard paper size within
+    /// [PdfPagePaperSize::DEFAULT_MATCH_TOLERANCE] using [PdfPagePaperSize::closest_match()].
+    /// If no standard paper size is that close, [PdfPagePaperSize::Custom] is returned.
     #[inline]
     pub fn paper_size(&self) -> PdfPagePaperSize {
-        PdfPagePaperSize::from_points(self.width(), self.height())
+        let (width, height) = (self.width(), self.height());
+
+        PdfPagePaperSize::closest_match(width, height, PdfPagePaperSize::DEFAULT_MATCH_TOLERANCE)
+            .map(|(size, _)| size)
+            .unwrap_or(PdfPagePaperSize::Custom(width, height))
     }
 
     /// Returns `true` if this [PdfPage] contains an embedded thumbnail.
@@ -380,16 +512,160 @@ impl<'a> PdfPage<'a> {
         }
     }
 
+    /// Returns the raw, still-encoded image data of the embedded thumbnail for this [PdfPage],
+    /// if any. The data is returned exactly as stored in the document, without any stream
+    /// filters being applied; for a DCT-encoded thumbnail this is a complete JPEG file that
+    /// can be passed directly to an image decoder.
+    ///
+    /// Returns [PdfiumError::PageMissingEmbeddedThumbnail] if this page does not contain an
+    /// embedded thumbnail.
+    #[inline]
+    pub fn raw_thumbnail_data(&self) -> Result<Vec<u8>, PdfiumError> {
+        self.get_thumbnail_data(|bindings, page_handle, buffer, buffer_length| {
+            bindings.FPDFPage_GetRawThumbnailData(page_handle, buffer, buffer_length)
+        })
+    }
+
+    /// Returns the image data of the embedded thumbnail for this [PdfPage], if any, after
+    /// all stream filters have been applied to decode it.
+    ///
+    /// Returns [PdfiumError::PageMissingEmbeddedThumbnail] if this page does not contain an
+    /// embedded thumbnail.
+    #[inline]
+    pub fn decoded_thumbnail_data(&self) -> Result<Vec<u8>, PdfiumError> {
+        self.get_thumbnail_data(|bindings, page_handle, buffer, buffer_length| {
+            bindings.FPDFPage_GetDecodedThumbnailData(page_handle, buffer, buffer_length)
+        })
+    }
+
+    fn get_thumbnail_data(
+        &self,
+        get_data: impl Fn(&dyn PdfiumLibraryBindings, FPDF_PAGE, *mut c_void, c_ulong) -> c_ulong,
+    ) -> Result<Vec<u8>, PdfiumError> {
+        // Retrieving the thumbnail data from Pdfium is a two-step operation. First, we call
+        // the retrieval function with a null buffer; this will retrieve the length of
+        // the thumbnail data in bytes. If the length is zero, then there is no thumbnail.
+
+        // If the length is non-zero, then we reserve a byte buffer of the given
+        // length and call the retrieval function again with a pointer to the buffer;
+        // this will write the thumbnail data to the buffer.
+
+        let buffer_length = get_data(self.bindings, self.page_handle, std::ptr::null_mut(), 0);
+
+        if buffer_length == 0 {
+            return Err(PdfiumError::PageMissingEmbeddedThumbnail);
+        }
+
+        let mut buffer = create_byte_buffer(buffer_length as usize);
+
+        let result = get_data(
+            self.bindings,
+            self.page_handle,
+            buffer.as_mut_ptr() as *mut c_void,
+            buffer_length,
+        );
+
+        if result == buffer_length {
+            Ok(buffer)
+        } else {
+            Err(PdfiumError::PdfiumFunctionReturnValueIndicatedFailure)
+        }
+    }
+
+    /// Returns a thumbnail for this [PdfPage]. If this page contains an embedded thumbnail,
+    /// then the embedded thumbnail is returned as-is; otherwise, a new thumbnail with a
+    /// maximum width and height of the given pixel size is rendered using the settings
+    /// provided by [PdfRenderConfig::thumbnail()].
+    ///
+    /// The dimensions of an embedded thumbnail are chosen by the PDF generator that created
+    /// the document, and may not match the given pixel size.
+    pub fn thumbnail(&self, size: Pixels) -> Result<PdfBitmap, PdfiumError> {
+        match self.embedded_thumbnail() {
+            Err(PdfiumError::PageMissingEmbeddedThumbnail) => {
+                self.render_with_config(&PdfRenderConfig::new().thumbnail(size))
+            }
+            result => result,
+        }
+    }
+
     /// Returns the collection of text boxes contained within this [PdfPage].
+    ///
+    /// Pdfium's text page for this [PdfPage] is loaded the first time this function is called,
+    /// and shared by all [PdfPageText] and [PdfPageTextChars] objects subsequently created
+    /// from this page. The text page is reloaded automatically if the page's content changes.
+    #[inline]
     pub fn text(&self) -> Result<PdfPageText, PdfiumError> {
-        let text_handle = self.bindings().FPDFText_LoadPage(self.page_handle);
+        Ok(PdfPageText::from_pdfium(
+            self.text_page()?,
+            self,
+            self.bindings,
+        ))
+    }
+
+    /// Returns a shared handle to the text page for this [PdfPage], loading the text page
+    /// if it has not yet been loaded or if the page's content has changed since it was loaded.
+    pub(crate) fn text_page(&self) -> Result<PdfPageTextHandle<'a>, PdfiumError> {
+        let revision = PdfPageIndexCache::get_content_revision_for_page(self.page_handle);
+
+        if let Some((cached_revision, text_page)) = self.text_page.borrow().as_ref() {
+            if *cached_revision == revision {
+                return Ok(text_page.to_handle(self.bindings));
+            }
+        }
+
+        self.discard_cached_text_page();
+
+        let text_page = PdfPageTextHandle::load(self.page_handle, self.bindings)?;
+
+        self.text_page
+            .replace(Some((revision, text_page.clone().into_text_page())));
+
+        Ok(text_page)
+    }
 
-        if text_handle.is_null() {
-            Err(PdfiumError::PdfiumLibraryInternalError(
-                PdfiumInternalError::Unknown,
+    /// Releases this [PdfPage]'s reference to its cached text page, if any. The text page is
+    /// closed once all [PdfPageText] and [PdfPageTextChars] objects using it have been dropped.
+    pub(crate) fn discard_cached_text_page(&self) {
+        if let Some((_, text_page)) = self.text_page.take() {
+            text_page.release(self.bindings);
+        }
+    }
+
+    /// Returns the positioned text runs, images, and paths on this [PdfPage] as a
+    /// [PdfPageLayout], suitable for exporting to a machine-readable format.
+    /// When the `serde` crate feature is enabled, the returned [PdfPageLayout] can be
+    /// serialized using `serde`.
+    #[inline]
+    pub fn extract_layout(&self) -> Result<PdfPageLayout, PdfiumError> {
+        PdfPageLayout::from_page(self)
+    }
+
+    /// Classifies the content of this [PdfPage] as monochrome, grayscale, or color
+    /// using the given options, for instance to estimate the cost of printing the page.
+    ///
+    /// Depending on the analysis mode set in the given options, the page is classified
+    /// by inspecting the colors of its page objects, by rendering the page at a low resolution,
+    /// or by a combination of both. See [PdfPageColorAnalysisMode] for details.
+    #[inline]
+    pub fn analyze_color(
+        &self,
+        options: &PdfPageColorAnalysisOptions,
+    ) -> Result<PdfPageColorAnalysis, PdfiumError> {
+        PdfPageColorAnalysis::from_page(self, options)
+    }
+
+    /// Returns the logical structure tree of this [PdfPage]. The structure tree of a page
+    /// that is not tagged will be empty.
+    pub fn struct_tree(&self) -> Result<PdfStructTree<'_>, PdfiumError> {
+        let handle = self.bindings().FPDF_StructTree_GetForPage(self.page_handle);
+
+        if handle.is_null() {
+            Err(self.bindings().error_in(
+                "FPDF_StructTree_GetForPage",
+                PdfiumError::PdfiumLibraryInternalError(PdfiumInternalError::Unknown),
             ))
         } else {
-            Ok(PdfPageText::from_pdfium(text_handle, self, self.bindings))
+            Ok(PdfStructTree::from_pdfium(handle, self.bindings))
         }
     }
 
@@ -437,6 +713,79 @@ impl<'a> PdfPage<'a> {
         &mut self.objects
     }
 
+    /// Adds the watermark configured by the given [PdfWatermarkConfig] to this [PdfPage].
+    ///
+    /// The watermark is drawn upright relative to the viewer, taking into account any
+    /// intrinsic rotation of this page. To add the same watermark to every page in a document,
+    /// use the `PdfDocument::watermark_all_pages()` function.
+    ///
+    /// If this [PdfPage] has a content regeneration strategy of
+    /// `PdfPageContentRegenerationStrategy::AutomaticOnEveryChange` then content regeneration
+    /// will be triggered on the page.
+    #[inline]
+    pub fn add_watermark(&mut self, config: &PdfWatermarkConfig) -> Result<(), PdfiumError> {
+        config.apply(self)
+    }
+
+    /// Stamps the content of the given source [PdfPage] onto this [PdfPage], either in front of
+    /// or behind the existing content of this page, returning the newly created form object.
+    /// The source page may belong to a different document.
+    ///
+    /// The source page is copied into this page's document as a form XObject, together with
+    /// all the fonts, images, and other resources it uses, so even page objects that cannot be
+    /// copied individually are reproduced faithfully. The bottom left corner of the source
+    /// page's media box is placed at the origin of this page; use the given transformation
+    /// matrix, if any, to scale or position the source page differently. The intrinsic rotation
+    /// of the source page is not applied. Uncommitted changes to the source page are not
+    /// included; see [PdfPage::regenerate_content()].
+    ///
+    /// To stamp the same page behind every page in a document, use the
+    /// `PdfDocument::apply_letterhead()` function.
+    ///
+    /// If this [PdfPage] has a content regeneration strategy of
+    /// `PdfPageContentRegenerationStrategy::AutomaticOnEveryChange` then content regeneration
+    /// will be triggered on the page.
+    pub fn apply_overlay(
+        &mut self,
+        source_page: &PdfPage,
+        position: PdfPageOverlayPosition,
+        transform: Option<PdfMatrix>,
+    ) -> Result<PdfPageObject<'a>, PdfiumError> {
+        let source_page_index = PdfPageIndexCache::get_index_for_page(
+            source_page.document_handle(),
+            source_page.page_handle(),
+        )
+        .ok_or(PdfiumError::SourcePageIndexNotInCache)?;
+
+        PdfPageOverlay::new(
+            self.document_handle(),
+            source_page.document_handle(),
+            source_page_index,
+            self.bindings,
+        )?
+        .apply(self, position, transform)
+    }
+
+    /// Changes the size of this [PdfPage] to the given paper size, scaling or repositioning
+    /// all existing page objects and annotations according to the given [PdfPageResizeMode].
+    /// The media box and crop box of the page are both set to the new size; any art box,
+    /// bleed box, or trim box is transformed along with the page content.
+    ///
+    /// The given size describes the page as it is displayed by a viewer, after any intrinsic
+    /// rotation of this page has been applied. Page content is always transformed in
+    /// unrotated page space, so the intrinsic rotation of this page is preserved.
+    ///
+    /// Pdfium will reload this page after its content has been transformed. Any
+    /// `PdfPageObject` references held before resizing should be considered invalid.
+    #[inline]
+    pub fn resize(
+        &mut self,
+        size: PdfPagePaperSize,
+        mode: PdfPageResizeMode,
+    ) -> Result<(), PdfiumError> {
+        mode.apply(self, size)
+    }
+
     /// Returns a list of all the distinct [PdfFont] instances used by the page text objects
     /// on this [PdfPage], if any.
     pub fn fonts(&self) -> Vec<PdfFont> {
@@ -461,6 +810,55 @@ impl<'a> PdfPage<'a> {
             .collect()
     }
 
+    /// Returns the type of the interactive form field at the given position on this [PdfPage],
+    /// if any. If several form fields overlap at the given position, the type of the top-most
+    /// form field is returned.
+    ///
+    /// This is useful for hit-testing pointer events in an interactive viewer. Use
+    /// [PdfRenderConfig::pixel_to_page()] to convert a pointer position in a rendered bitmap
+    /// to a position on this page.
+    ///
+    /// Returns `None` if the document containing this page has no form, or if there is no
+    /// form field at the given position.
+    pub fn form_field_at_point(&self, x: PdfPoints, y: PdfPoints) -> Option<PdfFormFieldType> {
+        let form_handle = self.form_handle?;
+
+        let result = self.bindings.FPDFPage_HasFormFieldAtPoint(
+            form_handle,
+            self.page_handle,
+            x.value as f64,
+            y.value as f64,
+        );
+
+        if result < 0 {
+            None
+        } else {
+            PdfFormFieldType::from_pdfium(result).ok()
+        }
+    }
+
+    /// Returns the z-order of the top-most interactive form field at the given position on
+    /// this [PdfPage], if any. Higher z-order values are closer to the front of the page.
+    ///
+    /// Returns `None` if the document containing this page has no form, or if there is no
+    /// form field at the given position.
+    pub fn form_field_z_order_at_point(&self, x: PdfPoints, y: PdfPoints) -> Option<i32> {
+        let form_handle = self.form_handle?;
+
+        let result = self.bindings.FPDFPage_FormFieldZOrderAtPoint(
+            form_handle,
+            self.page_handle,
+            x.value as f64,
+            y.value as f64,
+        );
+
+        if result < 0 {
+            None
+        } else {
+            Some(result)
+        }
+    }
+
     /// Converts from a bitmap coordinate system, measured in [Pixels] and with constraints
     /// and dimensions determined by the given [PdfRenderConfig] object, to the equivalent
     /// position on this page, measured in [PdfPoints].
@@ -470,27 +868,7 @@ impl<'a> PdfPage<'a> {
         y: Pixels,
         config: &PdfRenderConfig,
     ) -> Result<(PdfPoints, PdfPoints), PdfiumError> {
-        let mut page_x: c_double = 0.0;
-        let mut page_y: c_double = 0.0;
-
-        let settings = config.apply_to_page(self);
-
-        if self.bindings.is_true(self.bindings.FPDF_DeviceToPage(
-            self.page_handle,
-            settings.clipping.left as c_int,
-            settings.clipping.top as c_int,
-            (settings.clipping.right - settings.clipping.left) as c_int,
-            (settings.clipping.bottom - settings.clipping.top) as c_int,
-            settings.rotate,
-            x as c_int,
-            y as c_int,
-            &mut page_x,
-            &mut page_y,
-        )) {
-            Ok((PdfPoints::new(page_x as f32), PdfPoints::new(page_y as f32)))
-        } else {
-            Err(PdfiumError::CoordinateConversionFunctionIndicatedError)
-        }
+        config.pixel_to_page(self, x as f32, y as f32)
     }
 
     /// Converts from the page coordinate system, measured in [PdfPoints], to the equivalent position
@@ -502,27 +880,9 @@ impl<'a> PdfPage<'a> {
         y: PdfPoints,
         config: &PdfRenderConfig,
     ) -> Result<(Pixels, Pixels), PdfiumError> {
-        let mut device_x: c_int = 0;
-        let mut device_y: c_int = 0;
-
-        let settings = config.apply_to_page(self);
+        let (x, y) = config.page_to_pixel(self, x, y)?;
 
-        if self.bindings.is_true(self.bindings.FPDF_PageToDevice(
-            self.page_handle,
-            settings.clipping.left as c_int,
-            settings.clipping.top as c_int,
-            (settings.clipping.right - settings.clipping.left) as c_int,
-            (settings.clipping.bottom - settings.clipping.top) as c_int,
-            settings.rotate,
-            x.value.into(),
-            y.value.into(),
-            &mut device_x,
-            &mut device_y,
-        )) {
-            Ok((device_x as Pixels, device_y as Pixels))
-        } else {
-            Err(PdfiumError::CoordinateConversionFunctionIndicatedError)
-        }
+        Ok((x.round() as Pixels, y.round() as Pixels))
     }
 
     /// Renders this [PdfPage] into a [PdfBitmap] with the given pixel dimensions and page rotation.
@@ -581,6 +941,42 @@ impl<'a> PdfPage<'a> {
         Ok(bitmap)
     }
 
+    /// Starts progressively rendering this [PdfPage] into a new [PdfBitmap] using pixel
+    /// dimensions, page rotation settings, and rendering options configured in the given
+    /// [PdfRenderConfig].
+    ///
+    /// The given `should_pause` callback is invoked periodically during rendering; returning
+    /// `true` from the callback pauses rendering and returns control to the caller. The returned
+    /// [PdfProgressiveRenderJob] can be used to resume rendering by calling
+    /// [PdfProgressiveRenderJob::continue_rendering()], or to abandon rendering by calling
+    /// [PdfProgressiveRenderJob::cancel()]. Once rendering is complete, the bitmap is
+    /// identical to the bitmap returned by [PdfPage::render_with_config()].
+    ///
+    /// Pdfium does not support progressive rendering with custom transformation matrices,
+    /// clipping, or sub-regions. If the given [PdfRenderConfig] disables rendering of form data
+    /// and specifies any of these, then [PdfiumError::ProgressiveRenderingDoesNotSupportTransformations]
+    /// will be returned.
+    pub fn render_progressively<'b, F>(
+        &'b self,
+        config: &PdfRenderConfig,
+        should_pause: F,
+    ) -> Result<PdfProgressiveRenderJob<'b, 'a>, PdfiumError>
+    where
+        F: FnMut() -> bool + 'b,
+    {
+        let settings = config.apply_to_page(self);
+
+        let bitmap = PdfBitmap::empty(
+            settings.width as Pixels,
+            settings.height as Pixels,
+            PdfBitmapFormat::from_pdfium(settings.format as u32)
+                .unwrap_or_else(|_| PdfBitmapFormat::default()),
+            self.bindings,
+        )?;
+
+        PdfProgressiveRenderJob::start(self, bitmap, settings, should_pause)
+    }
+
     /// Renders this [PdfPage] into the given [PdfBitmap] using the given pixel dimensions
     /// and page rotation.
     ///
@@ -622,6 +1018,205 @@ impl<'a> PdfPage<'a> {
         self.render_into_bitmap_with_settings(bitmap, config.apply_to_page(self))
     }
 
+    /// Renders this [PdfPage] into the region of the given [PdfBitmap] with its top-left corner
+    /// at the given pixel position and the given pixel width and height, using the page rotation
+    /// settings and rendering options configured in the given [PdfRenderConfig]. Pixels in the
+    /// bitmap outside the region are left unchanged, so several pages can be rendered into
+    /// a single shared bitmap, for example to create a contact sheet of page thumbnails.
+    ///
+    /// The page is scaled to the largest size that fits within the region while maintaining
+    /// its aspect ratio, and centered within the region; any target size, scale factors,
+    /// and maximum size constraints in the given [PdfRenderConfig] are disregarded. If the
+    /// [PdfRenderConfig] clears the bitmap before rendering, the whole region is cleared.
+    ///
+    /// Returns [PdfiumError::BitmapFormatMismatch] if the pixel format of the given bitmap does
+    /// not match the pixel format set in the given [PdfRenderConfig], or
+    /// [PdfiumError::BitmapRegionOutOfBounds] if the region does not lie entirely within the
+    /// bounds of the given bitmap. In either case, the bitmap is left unchanged.
+    pub fn render_into_bitmap_at(
+        &self,
+        bitmap: &mut PdfBitmap,
+        x: Pixels,
+        y: Pixels,
+        width: Pixels,
+        height: Pixels,
+        config: &PdfRenderConfig,
+    ) -> Result<(), PdfiumError> {
+        let mut settings = config.clone().fit_within(width, height).apply_to_page(self);
+
+        if PdfBitmapFormat::from_pdfium(settings.format as u32)? != bitmap.format()? {
+            return Err(PdfiumError::BitmapFormatMismatch);
+        }
+
+        if !bitmap.contains_region(x, y, width, height) {
+            return Err(PdfiumError::BitmapRegionOutOfBounds);
+        }
+
+        settings.offset_x = x + (width - settings.width) / 2;
+        settings.offset_y = y + (height - settings.height) / 2;
+
+        // Clear the whole region, rather than just the area covered by the rendered page,
+        // once the remaining settings have been validated.
+
+        let do_clear_region = settings.do_clear_bitmap_before_rendering;
+
+        settings.do_clear_bitmap_before_rendering = false;
+
+        self.prepare_bitmap_for_rendering(bitmap, &settings)?;
+
+        if do_clear_region {
+            self.bindings().FPDFBitmap_FillRect(
+                *bitmap.handle(),
+                x,
+                y,
+                width,
+                height,
+                settings.clear_color,
+            );
+        }
+
+        self.render_into_bitmap_with_settings(bitmap, settings)
+    }
+
+    /// Renders this [PdfPage] directly into the given HTML `<canvas>` element using pixel
+    /// dimensions, page rotation settings, and rendering options configured in the given
+    /// [PdfRenderConfig]. The canvas is resized to match the dimensions of the rendered page.
+    ///
+    /// Pdfium renders the page with its `FPDF_REVERSE_BYTE_ORDER` flag set, so that pixel data
+    /// is produced in the RGBA order expected by the canvas, and the canvas reads the pixel data
+    /// through a view over Pdfium's WASM memory. The only copy of the pixel data is the one made
+    /// by the canvas itself. Since canvas pixel data always has four channels, any pixel format
+    /// set in the given [PdfRenderConfig] is disregarded.
+    ///
+    /// This function is only available when compiling to WASM.
+    #[cfg(any(doc, target_arch = "wasm32"))]
+    pub fn render_into_canvas(
+        &self,
+        canvas: &HtmlCanvasElement,
+        config: &PdfRenderConfig,
+    ) -> Result<(), PdfiumError> {
+        let context = canvas
+            .get_context("2d")
+            .ok()
+            .flatten()
+            .and_then(|context| context.dyn_into::<CanvasRenderingContext2d>().ok())
+            .ok_or(PdfiumError::WebSysCanvasContextNotAvailable)?;
+
+        let bitmap = self.render_for_canvas(config)?;
+
+        canvas.set_width(bitmap.width() as u32);
+        canvas.set_height(bitmap.height() as u32);
+
+        bitmap
+            .as_image_data_view()
+            .and_then(|image_data| context.put_image_data(&image_data, 0.0, 0.0))
+            .map_err(PdfiumError::WebSysCanvasError)
+    }
+
+    /// Renders this [PdfPage] directly into the given `OffscreenCanvas` using pixel
+    /// dimensions, page rotation settings, and rendering options configured in the given
+    /// [PdfRenderConfig]. The canvas is resized to match the dimensions of the rendered page.
+    ///
+    /// Pixel data is transferred to the canvas in the same way as
+    /// [PdfPage::render_into_canvas()], making this function suitable for rendering pages
+    /// inside a web worker.
+    ///
+    /// This function is only available when compiling to WASM.
+    #[cfg(any(doc, target_arch = "wasm32"))]
+    pub fn render_into_offscreen_canvas(
+        &self,
+        canvas: &OffscreenCanvas,
+        config: &PdfRenderConfig,
+    ) -> Result<(), PdfiumError> {
+        let context = canvas
+            .get_context("2d")
+            .ok()
+            .flatten()
+            .and_then(|context| context.dyn_into::<OffscreenCanvasRenderingContext2d>().ok())
+            .ok_or(PdfiumError::WebSysCanvasContextNotAvailable)?;
+
+        let bitmap = self.render_for_canvas(config)?;
+
+        canvas.set_width(bitmap.width() as u32);
+        canvas.set_height(bitmap.height() as u32);
+
+        bitmap
+            .as_image_data_view()
+            .and_then(|image_data| context.put_image_data(&image_data, 0.0, 0.0))
+            .map_err(PdfiumError::WebSysCanvasError)
+    }
+
+    /// Renders this [PdfPage] directly into the given Windows device context, such as a printer
+    /// or enhanced metafile device context, using pixel dimensions, page rotation settings,
+    /// and rendering flags configured in the given [PdfRenderConfig]. The page is drawn with
+    /// its top-left corner at the origin of the device context.
+    ///
+    /// Rendering to a printer device context preserves vector output rather than rasterizing
+    /// the page into a bitmap first. Use [PdfRenderConfig::use_print_quality()] to render
+    /// using Pdfium's print rendering path, [PdfRenderConfig::render_annotations()] to control
+    /// whether annotations are drawn, and [Pdfium::set_print_mode()] to select PostScript output
+    /// for PostScript printers.
+    ///
+    /// Pdfium cannot render user-supplied form data into a device context; to include form
+    /// data, first flatten the page using [PdfPage::flatten()]. Any [PdfRenderColorScheme] set in
+    /// the given [PdfRenderConfig] is disregarded. Returns
+    /// [PdfiumError::DeviceContextRenderingDoesNotSupportTransformations] if the configuration
+    /// includes a custom transformation, clipping, or sub-region and rendering of form data
+    /// is disabled.
+    ///
+    /// This function is only available on Windows when this crate's `windows_print`
+    /// feature is enabled.
+    #[cfg(any(doc, all(target_os = "windows", feature = "windows_print")))]
+    pub fn render_to_hdc(&self, hdc: HDC, config: &PdfRenderConfig) -> Result<(), PdfiumError> {
+        let settings = config.apply_to_page(self);
+
+        if !settings.do_render_form_data && settings.has_custom_transformation {
+            return Err(PdfiumError::DeviceContextRenderingDoesNotSupportTransformations);
+        }
+
+        let deactivated_objects = self.deactivate_objects_in_layers(&settings.hidden_layers)?;
+
+        // The FPDF_REVERSE_BYTE_ORDER flag only applies when rendering into a bitmap.
+
+        self.bindings.FPDF_RenderPage(
+            hdc,
+            self.page_handle,
+            0,
+            0,
+            settings.width,
+            settings.height,
+            settings.rotate,
+            settings.render_flags & !(FPDF_REVERSE_BYTE_ORDER as c_int),
+        );
+
+        self.reactivate_objects(deactivated_objects);
+
+        Ok(())
+    }
+
+    /// Renders this [PdfPage] into a new [PdfBitmap] suitable for transferring to a canvas,
+    /// using the [PdfBitmapFormat::BGRA] pixel format with the byte order reversed so that
+    /// the bitmap buffer holds RGBA pixel data.
+    #[cfg(any(doc, target_arch = "wasm32"))]
+    fn render_for_canvas(&self, config: &PdfRenderConfig) -> Result<PdfBitmap, PdfiumError> {
+        let mut settings = config.apply_to_page(self);
+
+        settings.format = PdfBitmapFormat::BGRA.as_pdfium() as c_int;
+        settings.render_flags |= FPDF_REVERSE_BYTE_ORDER as c_int;
+        settings.is_reversed_byte_order_flag_set = true;
+
+        let mut bitmap = PdfBitmap::empty(
+            settings.width as Pixels,
+            settings.height as Pixels,
+            PdfBitmapFormat::BGRA,
+            self.bindings,
+        )?;
+
+        self.render_into_bitmap_with_settings(&mut bitmap, settings)?;
+
+        Ok(bitmap)
+    }
+
     /// Renders this [PdfPage] into the given [PdfBitmap] using the given [PdfRenderSettings].
     /// The size of the buffer backing the given bitmap must be sufficiently large to hold
     /// the rendered image or an error will be returned.
@@ -630,82 +1225,274 @@ impl<'a> PdfPage<'a> {
         bitmap: &mut PdfBitmap,
         settings: PdfPageRenderSettings,
     ) -> Result<(), PdfiumError> {
+        self.prepare_bitmap_for_rendering(bitmap, &settings)?;
+
+        let deactivated_objects = self.deactivate_objects_in_layers(&settings.hidden_layers)?;
+
         let bitmap_handle = *bitmap.handle();
 
-        if settings.do_clear_bitmap_before_rendering {
-            // Clear the bitmap buffer by setting every pixel to a known color.
+        if let Some(color_scheme) = settings.color_scheme.as_ref() {
+            // Pdfium only supports rendering with a color scheme via its progressive rendering
+            // functions. Passing a null pause callback renders the page to completion in a
+            // single step. Custom transformation matrices cannot be applied.
 
-            self.bindings().FPDFBitmap_FillRect(
+            self.bindings.FPDF_RenderPageBitmapWithColorScheme_Start(
                 bitmap_handle,
-                0,
-                0,
+                self.page_handle,
+                settings.offset_x,
+                settings.offset_y,
                 settings.width,
                 settings.height,
-                settings.clear_color,
+                settings.rotate,
+                settings.render_flags,
+                color_scheme,
+                std::ptr::null_mut(),
             );
-        }
 
-        if settings.do_render_form_data {
+            self.bindings.FPDF_RenderPage_Close(self.page_handle);
+
+            if settings.do_render_form_data {
+                self.render_form_data_into_bitmap(bitmap, &settings);
+            }
+        } else if settings.do_render_form_data {
             // Render the PDF page into the bitmap buffer, ignoring any custom transformation matrix.
             // (Custom transforms cannot be applied to the rendering of form fields.)
 
             self.bindings.FPDF_RenderPageBitmap(
                 bitmap_handle,
                 self.page_handle,
-                0,
-                0,
+                settings.offset_x,
+                settings.offset_y,
                 settings.width,
                 settings.height,
                 settings.rotate,
                 settings.render_flags,
             );
 
-            if let Some(form_handle) = self.form_handle {
-                // Render user-supplied form data, if any, as an overlay on top of the page.
+            self.render_form_data_into_bitmap(bitmap, &settings);
+        } else {
+            // Render the PDF page into the bitmap buffer, applying any custom transformation matrix.
 
-                if let Some(form_field_highlight) = settings.form_field_highlight.as_ref() {
-                    for (form_field_type, (color, alpha)) in form_field_highlight.iter() {
-                        self.bindings.FPDF_SetFormFieldHighlightColor(
-                            form_handle,
-                            *form_field_type,
-                            *color,
-                        );
+            let (matrix, clipping) = if settings.offset_x != 0 || settings.offset_y != 0 {
+                // Shift the rendered page and its clipping rectangle to the requested
+                // position in the bitmap.
 
-                        self.bindings
-                            .FPDF_SetFormFieldHighlightAlpha(form_handle, *alpha);
-                    }
-                }
+                let matrix = PdfMatrix::from_pdfium(settings.matrix).translate(
+                    PdfPoints::new(settings.offset_x as f32),
+                    PdfPoints::new(settings.offset_y as f32),
+                )?;
 
-                self.bindings.FPDF_FFLDraw(
-                    form_handle,
-                    bitmap_handle,
-                    self.page_handle,
-                    0,
-                    0,
-                    settings.width,
-                    settings.height,
-                    settings.rotate,
-                    settings.render_flags,
-                );
-            }
-        } else {
-            // Render the PDF page into the bitmap buffer, applying any custom transformation matrix.
+                let clipping = FS_RECTF {
+                    left: settings.clipping.left + settings.offset_x as f32,
+                    top: settings.clipping.top + settings.offset_y as f32,
+                    right: settings.clipping.right + settings.offset_x as f32,
+                    bottom: settings.clipping.bottom + settings.offset_y as f32,
+                };
+
+                (matrix.as_pdfium(), clipping)
+            } else {
+                (settings.matrix, settings.clipping)
+            };
 
             self.bindings.FPDF_RenderPageBitmapWithMatrix(
                 bitmap_handle,
                 self.page_handle,
-                &settings.matrix,
-                &settings.clipping,
+                &matrix,
+                &clipping,
                 settings.render_flags,
             );
         }
 
+        self.reactivate_objects(deactivated_objects);
+
         bitmap.set_byte_order_from_render_settings(&settings);
 
         Ok(())
     }
 
-    // TODO: AJRC - 29/7/22 - remove deprecated PdfPage::get_bitmap_*() functions in 0.9.0
+    /// Deactivates every page object on this [PdfPage], including objects nested inside
+    /// form objects, that is marked as belonging to one of the given named layers, returning
+    /// the handles of the deactivated objects so they can later be passed to
+    /// [PdfPage::reactivate_objects()].
+    ///
+    /// The page's content is not regenerated, so deactivation affects only rendering.
+    #[cfg(any(feature = "pdfium_future", feature = "pdfium_6996"))]
+    fn deactivate_objects_in_layers(
+        &self,
+        layers: &[String],
+    ) -> Result<Vec<FPDF_PAGEOBJECT>, PdfiumError> {
+        if layers.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        if !self
+            .bindings
+            .is_function_available("FPDFPageObj_SetIsActive")
+        {
+            return Err(PdfiumError::UnsupportedOperation);
+        }
+
+        // Returns the handles of all objects in the given iterator that belong to one of
+        // the given layers, descending into form objects that do not themselves belong
+        // to a layer.
+
+        fn find_objects_in_layers<'b>(
+            objects: impl Iterator<Item = PdfPageObject<'b>>,
+            layers: &[String],
+            result: &mut Vec<FPDF_PAGEOBJECT>,
+        ) -> Result<(), PdfiumError> {
+            for object in objects {
+                let mut is_in_layer = false;
+
+                for mark in object.marks().iter() {
+                    if mark.name() != "OC" {
+                        continue;
+                    }
+
+                    // The properties of an /OC marked content sequence are either a single
+                    // optional content group, or an optional content membership dictionary
+                    // whose visibility depends on several groups. Only the former carries a name.
+
+                    match mark.string_param("Name") {
+                        Some(name) => {
+                            if layers.contains(&name) {
+                                is_in_layer = true;
+                            }
+                        }
+                        None => return Err(PdfiumError::LayerMembershipNotSupported),
+                    }
+                }
+
+                if is_in_layer {
+                    result.push(object.object_handle());
+                } else if let Some(form) = object.as_x_object_form_object() {
+                    find_objects_in_layers(form.iter(), layers, result)?;
+                }
+            }
+
+            Ok(())
+        }
+
+        let mut result = Vec::new();
+
+        find_objects_in_layers(self.objects().iter(), layers, &mut result)?;
+
+        for (index, handle) in result.iter().enumerate() {
+            if !self.bindings.is_true(
+                self.bindings
+                    .FPDFPageObj_SetIsActive(*handle, self.bindings.FALSE()),
+            ) {
+                self.reactivate_objects(result[..index].to_vec());
+
+                return Err(self.bindings.error_in(
+                    "FPDFPageObj_SetIsActive",
+                    PdfiumError::PdfiumFunctionReturnValueIndicatedFailure,
+                ));
+            }
+        }
+
+        Ok(result)
+    }
+
+    #[cfg(not(any(feature = "pdfium_future", feature = "pdfium_6996")))]
+    #[inline]
+    fn deactivate_objects_in_layers(
+        &self,
+        layers: &[String],
+    ) -> Result<Vec<FPDF_PAGEOBJECT>, PdfiumError> {
+        if layers.is_empty() {
+            Ok(Vec::new())
+        } else {
+            Err(PdfiumError::UnsupportedOperation)
+        }
+    }
+
+    /// Reactivates the given page objects previously deactivated by
+    /// [PdfPage::deactivate_objects_in_layers()].
+    #[cfg(any(feature = "pdfium_future", feature = "pdfium_6996"))]
+    fn reactivate_objects(&self, objects: Vec<FPDF_PAGEOBJECT>) {
+        for handle in objects {
+            self.bindings
+                .FPDFPageObj_SetIsActive(handle, self.bindings.TRUE());
+        }
+    }
+
+    #[cfg(not(any(feature = "pdfium_future", feature = "pdfium_6996")))]
+    #[inline]
+    fn reactivate_objects(&self, _objects: Vec<FPDF_PAGEOBJECT>) {}
+
+    /// Checks that the given [PdfBitmap] is compatible with the given [PdfPageRenderSettings],
+    /// then clears the bitmap buffer if the settings require it.
+    pub(crate) fn prepare_bitmap_for_rendering(
+        &self,
+        bitmap: &mut PdfBitmap,
+        settings: &PdfPageRenderSettings,
+    ) -> Result<(), PdfiumError> {
+        if settings.do_use_transparent_background
+            && bitmap.format().ok() != Some(PdfBitmapFormat::BGRA)
+        {
+            return Err(PdfiumError::TransparentBackgroundRequiresAlphaChannel);
+        }
+
+        if settings.color_scheme.is_some()
+            && !settings.do_render_form_data
+            && settings.has_custom_transformation
+        {
+            return Err(PdfiumError::RenderColorSchemeDoesNotSupportTransformations);
+        }
+
+        if settings.do_clear_bitmap_before_rendering {
+            // Clear the bitmap buffer by setting every pixel to a known color.
+
+            self.bindings().FPDFBitmap_FillRect(
+                *bitmap.handle(),
+                settings.offset_x,
+                settings.offset_y,
+                settings.width,
+                settings.height,
+                settings.clear_color,
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Renders user-supplied form data, if any, into the given [PdfBitmap] as an overlay
+    /// on top of the already-rendered page.
+    pub(crate) fn render_form_data_into_bitmap(
+        &self,
+        bitmap: &PdfBitmap,
+        settings: &PdfPageRenderSettings,
+    ) {
+        if let Some(form_handle) = self.form_handle {
+            if let Some(form_field_highlight) = settings.form_field_highlight.as_ref() {
+                for (form_field_type, (color, alpha)) in form_field_highlight.iter() {
+                    self.bindings.FPDF_SetFormFieldHighlightColor(
+                        form_handle,
+                        *form_field_type,
+                        *color,
+                    );
+
+                    self.bindings
+                        .FPDF_SetFormFieldHighlightAlpha(form_handle, *alpha);
+                }
+            }
+
+            self.bindings.FPDF_FFLDraw(
+                form_handle,
+                *bitmap.handle(),
+                self.page_handle,
+                settings.offset_x,
+                settings.offset_y,
+                settings.width,
+                settings.height,
+                settings.rotate,
+                settings.render_flags,
+            );
+        }
+    }
+
+    // TODO: AJRC - 29/7/22 - remove deprecated PdfPage::get_bitmap_*() functions in 0.9.0
     // as part of tracking issue https://github.com/ajrcarey/pdfium-render/issues/36
     /// Renders this [PdfPage] into a new [PdfBitmap] using pixel dimensions, rotation settings,
     /// and rendering options configured in the given [PdfRenderConfig].
@@ -803,6 +1590,9 @@ impl<'a> PdfPage<'a> {
 
     /// Applies the given transformation, expressed as a [PdfMatrix], to this [PdfPage],
     /// restricting the effects of the transformation matrix to the given clipping rectangle.
+    ///
+    /// Only the page content is transformed; annotations on this [PdfPage] are left unchanged.
+    /// To transform both the page content and its annotations, use [PdfPage::transform_all()].
     pub fn apply_matrix_with_clip(
         &mut self,
         matrix: PdfMatrix,
@@ -823,19 +1613,90 @@ impl<'a> PdfPage<'a> {
             self.reload_in_place();
             Ok(())
         } else {
-            Err(PdfiumError::PdfiumLibraryInternalError(
-                PdfiumInternalError::Unknown,
+            Err(self.bindings().error_in(
+                "FPDFPage_TransFormWithClip",
+                PdfiumError::PdfiumLibraryInternalError(PdfiumInternalError::Unknown),
             ))
         }
     }
 
+    /// Applies the given transformation, expressed as a [PdfMatrix], to the entire content
+    /// stream of this [PdfPage] in a single operation, optionally restricting the effects of
+    /// the transformation to the given clipping rectangle. The bounds and attachment points
+    /// of every annotation on this [PdfPage] are transformed by the same matrix, so that
+    /// annotations remain aligned with the transformed content.
+    ///
+    /// Unlike transforming each page object individually, this function applies to all
+    /// page content irrespective of the number or type of page objects on the page,
+    /// including content that `pdfium-render` does not otherwise support editing.
+    ///
+    /// Any staged changes to this [PdfPage] are committed before the transformation is applied.
+    /// The page is then reloaded, so any page objects or annotations retrieved from
+    /// this [PdfPage] beforehand should be considered invalid.
+    ///
+    /// The [PdfPage::translate()], [PdfPage::scale()], [PdfPage::rotate_clockwise_degrees()],
+    /// and related transformation functions all use this function internally.
+    pub fn transform_all(
+        &mut self,
+        matrix: PdfMatrix,
+        clip: Option<PdfRect>,
+    ) -> Result<(), PdfiumError> {
+        if self.content_regeneration_strategy() != PdfPageContentRegenerationStrategy::Manual {
+            // Pdfium wraps the existing content stream in the transformation, so any
+            // staged changes must be written to the content stream first.
+
+            self.regenerate_content()?;
+        }
+
+        self.apply_matrix_with_clip(matrix, clip.unwrap_or(PdfRect::MAX))?;
+
+        // Pdfium does not transform annotations along with the page content.
+
+        for mut annotation in self.annotations_mut().iter() {
+            if let Ok(rect) = annotation.bounds() {
+                annotation.set_bounds(rect.transform(matrix))?;
+            }
+
+            if annotation.has_attachment_points() {
+                let attachment_points = annotation.attachment_points_mut_impl();
+
+                for index in 0..attachment_points.len() {
+                    let quad_points = attachment_points.get(index)?.transform(matrix);
+
+                    attachment_points.set_attachment_point_at_index(index, quad_points)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Clips all the content on this [PdfPage] to the given [PdfClipPath]. Content outside
+    /// the clip path will no longer be rendered.
+    ///
+    /// Pdfium inserts the clip path into the page's content stream ahead of the existing
+    /// page content, so the clip path applies to all existing page objects. Page objects
+    /// added to this [PdfPage] afterwards may not be clipped. The given [PdfClipPath] is
+    /// not consumed, and can be inserted into other pages.
+    pub fn insert_clip_path(&mut self, clip_path: &PdfClipPath) -> Result<(), PdfiumError> {
+        self.bindings()
+            .FPDFPage_InsertClipPath(self.page_handle, clip_path.handle());
+
+        // As with FPDFPage_TransFormWithClip(), we must reload the page in order for
+        // the clip path to take effect.
+
+        self.reload_in_place();
+
+        Ok(())
+    }
+
     create_transform_setters!(
         &mut Self,
         Result<(), PdfiumError>,
         "each object on this [PdfPage]",
         "each object on this [PdfPage].",
         "each object on this [PdfPage],",
-        "",
+        "Annotations on this [PdfPage] are transformed along with the page content.",
         pub(self)
     ); // pub(self) visibility for the generated reset_matrix() function will effectively make it
        // private. This is what we want, since Pdfium does not expose a function to directly set
@@ -851,7 +1712,7 @@ impl<'a> PdfPage<'a> {
         e: PdfMatrixValue,
         f: PdfMatrixValue,
     ) -> Result<(), PdfiumError> {
-        self.transform_with_clip(a, b, c, d, e, f, PdfRect::MAX)
+        self.transform_all(PdfMatrix::new(a, b, c, d, e, f), None)
     }
 
     // The reset_matrix() function created by the create_transform_setters!() macro
@@ -861,42 +1722,53 @@ impl<'a> PdfPage<'a> {
         unreachable!();
     }
 
-    /// Flattens all annotations and form fields on this [PdfPage] into the page contents.
+    /// Flattens all annotations and form fields on this [PdfPage] into the page contents,
+    /// using the appearance of each annotation and form field appropriate to the given
+    /// [PdfPageFlattenMode].
+    ///
+    /// This custom flatten operation, enabled by the `flatten` crate feature, is still
+    /// under development and is not yet functional.
     #[cfg(feature = "flatten")]
     // Use a custom-written flatten operation, rather than Pdfium's built-in flatten. See:
     // https://github.com/ajrcarey/pdfium-render/issues/140
-    pub fn flatten(&mut self) -> Result<(), PdfiumError> {
-        flatten_page(self.handle())
+    pub fn flatten(
+        &mut self,
+        mode: PdfPageFlattenMode,
+    ) -> Result<PdfPageFlattenResult, PdfiumError> {
+        flatten::flatten(self.page_handle, mode)
     }
 
-    /// Flattens all annotations and form fields on this [PdfPage] into the page contents.
+    /// Flattens all annotations and form fields on this [PdfPage] into the page contents,
+    /// using the appearance of each annotation and form field appropriate to the given
+    /// [PdfPageFlattenMode].
+    ///
+    /// The annotations, boundaries, links, and objects collections of this [PdfPage] are
+    /// refreshed after a successful flatten, so they immediately reflect the new page content.
     #[cfg(not(feature = "flatten"))]
     // Use Pdfium's built-in flatten. This has some problems; see:
     // https://github.com/ajrcarey/pdfium-render/issues/140
-    pub fn flatten(&mut self) -> Result<(), PdfiumError> {
-        // TODO: AJRC - 28/5/22 - consider allowing the caller to set the FLAT_NORMALDISPLAY or FLAT_PRINT flag.
-        let flag = FLAT_PRINT;
+    pub fn flatten(
+        &mut self,
+        mode: PdfPageFlattenMode,
+    ) -> Result<PdfPageFlattenResult, PdfiumError> {
+        let result = PdfPageFlattenResult::from_pdfium(
+            self.bindings()
+                .FPDFPage_Flatten(self.page_handle, mode.as_pdfium()),
+        )?;
 
-        match self
-            .bindings()
-            .FPDFPage_Flatten(self.page_handle, flag as c_int) as u32
-        {
-            FLATTEN_SUCCESS => {
-                self.regenerate_content()?;
+        if result == PdfPageFlattenResult::Success {
+            self.regenerate_content()?;
 
-                // As noted at https://bugs.chromium.org/p/pdfium/issues/detail?id=2055,
-                // FPDFPage_Flatten() updates the underlying dictionaries and content streams for
-                // the page, but does not update the FPDF_Page structure. We must reload the
-                // page for the effects of the flatten operation to be visible. For more information, see:
-                // https://github.com/ajrcarey/pdfium-render/issues/140
+            // As noted at https://bugs.chromium.org/p/pdfium/issues/detail?id=2055,
+            // FPDFPage_Flatten() updates the underlying dictionaries and content streams for
+            // the page, but does not update the FPDF_Page structure. We must reload the
+            // page for the effects of the flatten operation to be visible. For more information, see:
+            // https://github.com/ajrcarey/pdfium-render/issues/140
 
-                self.reload_in_place();
-                Ok(())
-            }
-            FLATTEN_NOTHINGTODO => Ok(()),
-            FLATTEN_FAIL => Err(PdfiumError::PageFlattenFailure),
-            _ => Err(PdfiumError::PageFlattenFailure),
+            self.reload_in_place();
         }
+
+        Ok(result)
     }
 
     /// Deletes this [PdfPage] from its containing `PdfPages` collection, consuming this [PdfPage].
@@ -952,6 +1824,107 @@ impl<'a> PdfPage<'a> {
     ) {
         self.regeneration_strategy = strategy;
 
+        self.cache_content_regeneration_strategy();
+    }
+
+    /// Suppresses content regeneration for this [PdfPage] until the returned guard is
+    /// committed or dropped, at which point the page's content is regenerated exactly once
+    /// if this page's content regeneration strategy is
+    /// [PdfPageContentRegenerationStrategy::AutomaticOnEveryChange].
+    ///
+    /// Regenerating a page's content after every change is inefficient when many changes
+    /// are made at once. Deferring content regeneration while staging a batch of changes
+    /// avoids this cost without needing to change the page's content regeneration strategy.
+    /// The returned guard dereferences to this [PdfPage], so changes can be made through
+    /// the guard. Guards can be nested; only the outermost guard regenerates content.
+    ///
+    /// See also the [PdfPage::with_batched_edits()] function.
+    #[inline]
+    pub fn defer_content_regeneration(&mut self) -> PdfPageContentRegenerationGuard<'_, 'a> {
+        PdfPageContentRegenerationGuard::new(self)
+    }
+
+    /// Calls the given closure with content regeneration for this [PdfPage] suppressed,
+    /// then regenerates the page's content exactly once if this page's content regeneration
+    /// strategy is [PdfPageContentRegenerationStrategy::AutomaticOnEveryChange].
+    ///
+    /// Content regeneration takes place even if the closure returns an error, so that
+    /// any changes made before the error are not lost.
+    ///
+    /// See also the [PdfPage::defer_content_regeneration()] function.
+    pub fn with_batched_edits<T>(
+        &mut self,
+        edits: impl FnOnce(&mut PdfPage<'a>) -> Result<T, PdfiumError>,
+    ) -> Result<T, PdfiumError> {
+        let mut guard = self.defer_content_regeneration();
+
+        let result = edits(&mut guard);
+
+        let committed = guard.commit();
+
+        let result = result?;
+
+        committed.map(|()| result)
+    }
+
+    /// Returns `true` if content regeneration for this [PdfPage] is currently suppressed
+    /// by a guard returned from the [PdfPage::defer_content_regeneration()] function.
+    #[inline]
+    pub fn is_content_regeneration_deferred(&self) -> bool {
+        self.deferred_content_regeneration_depth > 0
+    }
+
+    /// Starts a batch of edits during which content regeneration is suppressed.
+    pub(crate) fn begin_deferred_content_regeneration(&mut self) {
+        self.deferred_content_regeneration_depth += 1;
+
+        if self.deferred_content_regeneration_depth == 1 {
+            self.cache_content_regeneration_strategy();
+        }
+    }
+
+    /// Ends a batch of edits started by [PdfPage::begin_deferred_content_regeneration()].
+    /// If this ends the outermost batch, the page's content regeneration strategy is
+    /// reinstated and the page's content is regenerated as that strategy requires.
+    pub(crate) fn end_deferred_content_regeneration(&mut self) -> Result<(), PdfiumError> {
+        self.deferred_content_regeneration_depth =
+            self.deferred_content_regeneration_depth.saturating_sub(1);
+
+        if self.is_content_regeneration_deferred() {
+            return Ok(());
+        }
+
+        self.cache_content_regeneration_strategy();
+
+        match self.regeneration_strategy {
+            PdfPageContentRegenerationStrategy::AutomaticOnEveryChange => {
+                self.regenerate_content_immut()
+            }
+            PdfPageContentRegenerationStrategy::AutomaticOnDrop => {
+                self.is_content_regeneration_required = true;
+
+                Ok(())
+            }
+            PdfPageContentRegenerationStrategy::Manual => Ok(()),
+        }
+    }
+
+    /// Returns the content regeneration strategy currently in effect for this [PdfPage].
+    /// This is [PdfPageContentRegenerationStrategy::Manual] while content regeneration is
+    /// deferred, irrespective of the page's configured content regeneration strategy.
+    #[inline]
+    fn effective_content_regeneration_strategy(&self) -> PdfPageContentRegenerationStrategy {
+        if self.is_content_regeneration_deferred() {
+            PdfPageContentRegenerationStrategy::Manual
+        } else {
+            self.regeneration_strategy
+        }
+    }
+
+    /// Updates the page index cache with the content regeneration strategy currently in effect
+    /// for this [PdfPage]. Mutations to page objects and annotations consult the cache
+    /// to determine whether content regeneration should take place.
+    fn cache_content_regeneration_strategy(&self) {
         if let Some(index) =
             PdfPageIndexCache::get_index_for_page(self.document_handle(), self.page_handle())
         {
@@ -959,7 +1932,7 @@ impl<'a> PdfPage<'a> {
                 self.document_handle(),
                 self.page_handle(),
                 index,
-                strategy,
+                self.effective_content_regeneration_strategy(),
             );
         }
     }
@@ -1001,15 +1974,55 @@ impl<'a> PdfPage<'a> {
         page: FPDF_PAGE,
         bindings: &dyn PdfiumLibraryBindings,
     ) -> Result<(), PdfiumError> {
+        PdfPageIndexCache::increment_content_revision_for_page(page);
+
         if bindings.is_true(bindings.FPDFPage_GenerateContent(page)) {
             Ok(())
         } else {
-            Err(PdfiumError::PdfiumLibraryInternalError(
-                PdfiumInternalError::Unknown,
+            Err(bindings.error_in(
+                "FPDFPage_GenerateContent",
+                PdfiumError::PdfiumLibraryInternalError(PdfiumInternalError::Unknown),
             ))
         }
     }
 
+    /// Commits any staged but unsaved changes to the page containing a page object with
+    /// the given ownership hierarchy, taking the page's currently set content regeneration
+    /// strategy into account. Changes are only committed immediately if the page's
+    /// content regeneration strategy is [PdfPageContentRegenerationStrategy::AutomaticOnEveryChange].
+    ///
+    /// Page objects that are not attached to a page require no content regeneration.
+    pub(crate) fn regenerate_content_for_ownership(
+        ownership: &PdfPageObjectOwnership,
+        bindings: &dyn PdfiumLibraryBindings,
+    ) -> Result<(), PdfiumError> {
+        let (document_handle, page_handle) = match ownership {
+            PdfPageObjectOwnership::Page(ownership) => {
+                (ownership.document_handle(), ownership.page_handle())
+            }
+            PdfPageObjectOwnership::AttachedAnnotation(ownership) => {
+                (ownership.document_handle(), ownership.page_handle())
+            }
+            _ => return Ok(()),
+        };
+
+        // The page's objects have changed, even if its content is not regenerated immediately,
+        // so any cached text page no longer reflects the page's content.
+
+        PdfPageIndexCache::increment_content_revision_for_page(page_handle);
+
+        match PdfPageIndexCache::get_content_regeneration_strategy_for_page(
+            document_handle,
+            page_handle,
+        ) {
+            Some(PdfPageContentRegenerationStrategy::AutomaticOnEveryChange) => {
+                Self::regenerate_content_immut_for_handle(page_handle, bindings)
+            }
+            Some(_) => Ok(()),
+            None => Err(PdfiumError::SourcePageIndexNotInCache),
+        }
+    }
+
     /// Reloads the page transparently to any caller, forcing a refresh of all page data structures.
     /// This will replace this page's `FPDF_PAGE` handle. The page index cache will be updated.
     fn reload_in_place(&mut self) {
@@ -1026,8 +2039,24 @@ impl<'a> PdfPage<'a> {
                 self.document_handle,
                 self.page_handle,
                 page_index,
-                self.content_regeneration_strategy(),
+                self.effective_content_regeneration_strategy(),
             );
+
+            // The child collections of this page retain the page handle that was current
+            // when they were created. That handle is no longer valid, so we must rebind
+            // each collection to the reloaded page.
+
+            self.annotations = PdfPageAnnotations::from_pdfium(
+                self.document_handle,
+                self.page_handle,
+                self.form_handle,
+                self.bindings,
+            );
+            self.boundaries = PdfPageBoundaries::from_pdfium(self.page_handle, self.bindings);
+            self.links =
+                PdfPageLinks::from_pdfium(self.page_handle, self.document_handle, self.bindings);
+            self.objects =
+                PdfPageObjects::from_pdfium(self.document_handle, self.page_handle, self.bindings);
         }
     }
 
@@ -1044,6 +2073,8 @@ impl<'a> PdfPage<'a> {
             debug_assert!(result.is_ok());
         }
 
+        self.discard_cached_text_page();
+
         self.bindings.FPDF_ClosePage(self.page_handle);
 
         PdfPageIndexCache::remove_index_for_page(self.document_handle, self.page_handle);
@@ -1058,6 +2089,9 @@ impl<'a> Drop for PdfPage<'a> {
     }
 }
 
+#[cfg(feature = "sync")]
+unsafe impl<'a> Send for PdfPage<'a> {}
+
 #[cfg(test)]
 mod tests {
     use crate::prelude::*;
@@ -1117,4 +2151,781 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_render_sub_regions_match_full_render() -> Result<(), PdfiumError> {
+        // Renders a page in four tiles and checks that the stitched tiles are identical
+        // to a render of the whole page.
+
+        let pdfium = test_bind_to_pdfium();
+
+        let document = pdfium.load_pdf_from_file("./test/export-test.pdf", None)?;
+
+        let page = document.pages().get(0)?;
+
+        let render_config = || {
+            PdfRenderConfig::new()
+                .set_target_width(800)
+                .rotate(PdfPageRenderRotation::Degrees90, true)
+                .render_form_data(false)
+        };
+
+        let full = page
+            .render_with_config(&render_config())?
+            .as_image()
+            .into_rgba8();
+
+        let (width, height) = full.dimensions();
+
+        let (tile_width, tile_height) = (width / 2 + 1, height / 2 + 1);
+
+        for (left, top) in [
+            (0, 0),
+            (tile_width, 0),
+            (0, tile_height),
+            (tile_width, tile_height),
+        ] {
+            // Tiles in the right-hand column and bottom row extend past the edge of the page,
+            // and should be clamped.
+
+            let tile = page
+                .render_with_config(&render_config().render_sub_region(
+                    left as Pixels,
+                    top as Pixels,
+                    tile_width as Pixels,
+                    tile_height as Pixels,
+                ))?
+                .as_image()
+                .into_rgba8();
+
+            let (expected_width, expected_height) =
+                (tile_width.min(width - left), tile_height.min(height - top));
+
+            assert_eq!(tile.dimensions(), (expected_width, expected_height));
+
+            assert_eq!(
+                tile.as_raw(),
+                full.view(left, top, expected_width, expected_height)
+                    .to_image()
+                    .as_raw()
+            );
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_render_into_bitmap_at_builds_contact_sheet() -> Result<(), PdfiumError> {
+        // Renders several pages into cells of a single shared bitmap and checks that
+        // each cell containing a page differs from the sheet's background color, while
+        // all other pixels are untouched.
+
+        let pdfium = test_bind_to_pdfium();
+
+        let document = pdfium.load_pdf_from_file("./test/export-test.pdf", None)?;
+
+        const COLUMNS: Pixels = 4;
+        const ROWS: Pixels = 3;
+        const CELL: Pixels = 100;
+
+        let background = PdfColor::new(0, 128, 0, 255);
+
+        let mut sheet = PdfBitmap::create_empty(
+            COLUMNS * CELL,
+            ROWS * CELL,
+            PdfBitmapFormat::BGRA,
+            pdfium.bindings(),
+        )?;
+
+        sheet.fill_rect(0, 0, sheet.width(), sheet.height(), background)?;
+
+        let config = PdfRenderConfig::new().set_format(PdfBitmapFormat::BGRA);
+
+        // Rendering fails up front if the pixel format or region is invalid.
+
+        assert!(matches!(
+            document.pages().first()?.render_into_bitmap_at(
+                &mut sheet,
+                0,
+                0,
+                CELL,
+                CELL,
+                &PdfRenderConfig::new().set_format(PdfBitmapFormat::BGR),
+            ),
+            Err(PdfiumError::BitmapFormatMismatch)
+        ));
+
+        assert!(matches!(
+            document.pages().first()?.render_into_bitmap_at(
+                &mut sheet,
+                COLUMNS * CELL - CELL / 2,
+                0,
+                CELL,
+                CELL,
+                &config,
+            ),
+            Err(PdfiumError::BitmapRegionOutOfBounds)
+        ));
+
+        let page_count = document.pages().len().min((COLUMNS * ROWS) as PdfPageIndex) as Pixels;
+
+        for (index, page) in document
+            .pages()
+            .iter()
+            .take(page_count as usize)
+            .enumerate()
+        {
+            let index = index as Pixels;
+
+            page.render_into_bitmap_at(
+                &mut sheet,
+                (index % COLUMNS) * CELL,
+                (index / COLUMNS) * CELL,
+                CELL,
+                CELL,
+                &config,
+            )?;
+        }
+
+        let image = sheet.as_image().into_rgba8();
+
+        let is_background = |pixel: &image_025::Rgba<u8>| pixel.0 == [0, 128, 0, 255];
+
+        for index in 0..COLUMNS * ROWS {
+            let (left, top) = ((index % COLUMNS) * CELL, (index / COLUMNS) * CELL);
+
+            let cell = image.view(left as u32, top as u32, CELL as u32, CELL as u32);
+
+            let background_pixels = cell.pixels().filter(|(_, _, pixel)| is_background(pixel));
+
+            if index < page_count {
+                // The page is cleared to white before rendering, so at least the page's
+                // own area differs from the background.
+
+                assert!(background_pixels.count() < (CELL * CELL) as usize);
+            } else {
+                assert_eq!(background_pixels.count(), (CELL * CELL) as usize);
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_render_with_transparent_background() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        let mut page = document
+            .pages_mut()
+            .create_page_at_start(PdfPagePaperSize::new_custom(
+                PdfPoints::new(200.0),
+                PdfPoints::new(200.0),
+            ))?;
+
+        let render_config = || {
+            PdfRenderConfig::new()
+                .set_target_width(200)
+                .use_transparent_background()
+        };
+
+        // An empty page should render as fully transparent.
+
+        let image = page
+            .render_with_config(&render_config())?
+            .as_image()
+            .into_rgba8();
+
+        assert!(image.pixels().all(|pixel| pixel.0[3] == 0));
+
+        // After adding an opaque rectangle, only the area covered by the rectangle
+        // should be opaque.
+
+        page.objects_mut().create_path_object_rect(
+            PdfRect::new_from_values(0.0, 0.0, 100.0, 200.0),
+            None,
+            None,
+            Some(PdfColor::RED),
+        )?;
+
+        let image = page
+            .render_with_config(&render_config())?
+            .as_image()
+            .into_rgba8();
+
+        assert_eq!(image.get_pixel(50, 100).0, [255, 0, 0, 255]);
+        assert_eq!(image.get_pixel(150, 100).0[3], 0);
+
+        // Requesting a pixel format without an alpha channel should fail.
+
+        assert!(matches!(
+            page.render_with_config(&render_config().set_format(PdfBitmapFormat::BGR)),
+            Err(PdfiumError::TransparentBackgroundRequiresAlphaChannel)
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_render_with_page_rotation() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        let mut page = document
+            .pages_mut()
+            .create_page_at_start(PdfPagePaperSize::new_custom(
+                PdfPoints::new(300.0),
+                PdfPoints::new(200.0),
+            ))?;
+
+        // Mark the top-left corner of the unrotated landscape page.
+
+        page.objects_mut().create_path_object_rect(
+            PdfRect::new_from_values(160.0, 0.0, 200.0, 60.0),
+            None,
+            None,
+            Some(PdfColor::RED),
+        )?;
+
+        for rotation in [
+            PdfPageRenderRotation::None,
+            PdfPageRenderRotation::Degrees90,
+            PdfPageRenderRotation::Degrees180,
+            PdfPageRenderRotation::Degrees270,
+        ] {
+            page.set_rotation(rotation);
+
+            assert_eq!(page.rotation()?, rotation);
+
+            // By default, the page is rendered upright, so the marker moves with the rotation.
+
+            let image = page
+                .render_with_config(&PdfRenderConfig::new())?
+                .as_image()
+                .into_rgba8();
+
+            let (width, height) = image.dimensions();
+
+            let (expected_dimensions, marker) = match rotation {
+                PdfPageRenderRotation::None => ((300, 200), (10, 10)),
+                PdfPageRenderRotation::Degrees90 => ((200, 300), (190, 10)),
+                PdfPageRenderRotation::Degrees180 => ((300, 200), (290, 190)),
+                PdfPageRenderRotation::Degrees270 => ((200, 300), (10, 290)),
+            };
+
+            assert_eq!((width, height), expected_dimensions);
+            assert_eq!(image.get_pixel(marker.0, marker.1).0, [255, 0, 0, 255]);
+            assert_eq!(
+                image.get_pixel(width / 2, height / 2).0,
+                [255, 255, 255, 255]
+            );
+
+            // The marker's rotated bounds should match its position in the upright render.
+
+            let bounds = PdfRect::new_from_values(160.0, 0.0, 200.0, 60.0).to_rotated_space(
+                rotation,
+                PdfPoints::new(300.0),
+                PdfPoints::new(200.0),
+            );
+
+            assert!(bounds.contains(
+                PdfPoints::new(marker.0 as f32),
+                PdfPoints::new((height - marker.1) as f32)
+            ));
+
+            // Without the page rotation, the page is always rendered in its unrotated
+            // landscape orientation, whether or not form data is rendered.
+
+            for do_render_form_data in [true, false] {
+                let image = page
+                    .render_with_config(
+                        &PdfRenderConfig::new()
+                            .apply_page_rotation(false)
+                            .render_form_data(do_render_form_data),
+                    )?
+                    .as_image()
+                    .into_rgba8();
+
+                assert_eq!(image.dimensions(), (300, 200));
+                assert_eq!(image.get_pixel(10, 10).0, [255, 0, 0, 255]);
+                assert_eq!(image.get_pixel(290, 190).0, [255, 255, 255, 255]);
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_pixel_to_page_round_trip_with_rotation() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        let mut page = document
+            .pages_mut()
+            .create_page_at_start(PdfPagePaperSize::new_custom(
+                PdfPoints::new(300.0),
+                PdfPoints::new(200.0),
+            ))?;
+
+        // Mark the top-left corner of the page.
+
+        page.objects_mut().create_path_object_rect(
+            PdfRect::new_from_values(160.0, 0.0, 200.0, 60.0),
+            None,
+            None,
+            Some(PdfColor::RED),
+        )?;
+
+        let (marker_x, marker_y) = (PdfPoints::new(30.0), PdfPoints::new(180.0));
+
+        // Both of Pdfium's rendering pipelines should yield the same conversions.
+
+        for do_render_form_data in [true, false] {
+            let config = PdfRenderConfig::new()
+                .scale_page_by_factor(2.0)
+                .rotate(PdfPageRenderRotation::Degrees90, false)
+                .render_form_data(do_render_form_data);
+
+            let image = page.render_with_config(&config)?.as_image().into_rgba8();
+
+            assert_eq!(image.dimensions(), (400, 600));
+
+            // Rotating the page clockwise moves its top-left corner to the top-right corner
+            // of the bitmap. A naive conversion that ignores the rotation would place the
+            // marker at (60, 40) instead.
+
+            let (x, y) = config.page_to_pixel(&page, marker_x, marker_y)?;
+
+            assert!((x - 360.0).abs() < 0.1);
+            assert!((y - 60.0).abs() < 0.1);
+            assert_eq!(image.get_pixel(x as u32, y as u32).0, [255, 0, 0, 255]);
+
+            let (page_x, page_y) = config.pixel_to_page(&page, x, y)?;
+
+            assert!((page_x - marker_x).value.abs() < 0.1);
+            assert!((page_y - marker_y).value.abs() < 0.1);
+
+            assert_eq!(
+                page.points_to_pixels(marker_x, marker_y, &config)?,
+                (360, 60)
+            );
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_form_field_at_point() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let document = pdfium.load_pdf_from_file("test/form-test.pdf", None)?;
+
+        let page = document.pages().first()?;
+
+        let (field_type, bounds) = page
+            .annotations()
+            .iter()
+            .find_map(|annotation| {
+                annotation
+                    .as_form_field()
+                    .map(|field| field.field_type())
+                    .zip(annotation.bounds().ok())
+            })
+            .unwrap();
+
+        let center_x = (bounds.left() + bounds.right()) / 2.0;
+        let center_y = (bounds.top() + bounds.bottom()) / 2.0;
+
+        assert_eq!(
+            page.form_field_at_point(center_x, center_y),
+            Some(field_type)
+        );
+        assert!(page
+            .form_field_z_order_at_point(center_x, center_y)
+            .is_some());
+
+        let outside = PdfPoints::new(-100.0);
+
+        assert_eq!(page.form_field_at_point(outside, outside), None);
+        assert_eq!(page.form_field_z_order_at_point(outside, outside), None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_render_with_color_scheme() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        let mut page = document
+            .pages_mut()
+            .create_page_at_start(PdfPagePaperSize::new_custom(
+                PdfPoints::new(200.0),
+                PdfPoints::new(200.0),
+            ))?;
+
+        page.objects_mut().create_path_object_rect(
+            PdfRect::new_from_values(0.0, 0.0, 200.0, 200.0),
+            None,
+            None,
+            Some(PdfColor::RED),
+        )?;
+
+        let color_scheme = PdfRenderColorScheme::new(
+            PdfColor::BLUE,
+            PdfColor::BLUE,
+            PdfColor::GREEN,
+            PdfColor::GREEN,
+        );
+
+        // The path fill color should be remapped, both with and without Pdfium's
+        // reversed byte order flag set.
+
+        for do_reverse_byte_order in [false, true] {
+            let image = page
+                .render_with_config(
+                    &PdfRenderConfig::new()
+                        .set_target_width(200)
+                        .set_reverse_byte_order(do_reverse_byte_order)
+                        .set_color_scheme(color_scheme),
+                )?
+                .as_image()
+                .into_rgba8();
+
+            assert_eq!(image.get_pixel(100, 100).0, [0, 0, 255, 255]);
+        }
+
+        // Rendering without a color scheme should be unaffected.
+
+        let image = page
+            .render_with_config(&PdfRenderConfig::new().set_target_width(200))?
+            .as_image()
+            .into_rgba8();
+
+        assert_eq!(image.get_pixel(100, 100).0, [255, 0, 0, 255]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_thumbnail_falls_back_to_rendering() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        let page = document
+            .pages_mut()
+            .create_page_at_start(PdfPagePaperSize::a4())?;
+
+        // A newly created page never contains an embedded thumbnail.
+
+        assert!(!page.has_embedded_thumbnail());
+        assert!(matches!(
+            page.embedded_thumbnail(),
+            Err(PdfiumError::PageMissingEmbeddedThumbnail)
+        ));
+        assert!(matches!(
+            page.raw_thumbnail_data(),
+            Err(PdfiumError::PageMissingEmbeddedThumbnail)
+        ));
+        assert!(matches!(
+            page.decoded_thumbnail_data(),
+            Err(PdfiumError::PageMissingEmbeddedThumbnail)
+        ));
+
+        let thumbnail = page.thumbnail(128)?;
+
+        assert_eq!(thumbnail.height(), 128);
+        assert!(thumbnail.width() <= 128);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_flatten_retains_filled_field_values() -> Result<(), PdfiumError> {
+        fn delete_annotations(page: &mut PdfPage) -> Result<(), PdfiumError> {
+            while let Ok(annotation) = page.annotations().get(0) {
+                page.annotations_mut().delete_annotation(annotation)?;
+            }
+
+            Ok(())
+        }
+
+        let pdfium = test_bind_to_pdfium();
+
+        let render_config = PdfRenderConfig::new()
+            .set_target_width(1000)
+            .render_form_data(false)
+            .render_annotations(false);
+
+        // Render the page containing the first text field with all annotations removed,
+        // so the field is not visible.
+
+        let document = pdfium.load_pdf_from_file("./test/form-test.pdf", None)?;
+
+        let page_index = document
+            .form()
+            .unwrap()
+            .fields(document.pages())
+            .iter()
+            .find(|widget| widget.field_type() == PdfFormFieldType::Text)
+            .unwrap()
+            .page_index();
+
+        let mut page = document.pages().get(page_index)?;
+
+        delete_annotations(&mut page)?;
+
+        let unfilled = page.render_with_config(&render_config)?.as_raw_bytes();
+
+        // Fill the field, flatten, remove any remaining annotations, then render again.
+        // The filled value should now be part of the page content.
+
+        let document = pdfium.load_pdf_from_file("./test/form-test.pdf", None)?;
+
+        document
+            .form()
+            .unwrap()
+            .fields(document.pages())
+            .iter()
+            .find(|widget| widget.field_type() == PdfFormFieldType::Text)
+            .unwrap()
+            .set_value("pdfium-render")?;
+
+        let mut page = document.pages().get(page_index)?;
+
+        let annotations_before_flatten = page.annotations().len();
+
+        let objects_before_flatten = page.objects().len();
+
+        assert!(annotations_before_flatten > 0);
+
+        assert_eq!(
+            page.flatten(PdfPageFlattenMode::Print)?,
+            PdfPageFlattenResult::Success
+        );
+
+        // The page's child collections must reflect the flattened content.
+
+        assert!(page.annotations().len() < annotations_before_flatten);
+        assert!(page.objects().len() > objects_before_flatten);
+
+        delete_annotations(&mut page)?;
+
+        assert_eq!(page.annotations().len(), 0);
+
+        let filled = page.render_with_config(&render_config)?.as_raw_bytes();
+
+        assert_ne!(unfilled, filled);
+
+        assert_eq!(
+            page.flatten(PdfPageFlattenMode::Print)?,
+            PdfPageFlattenResult::NothingToDo
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_transform_all_rotates_content_about_center() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let document = pdfium.load_pdf_from_file("./test/text-test.pdf", None)?;
+
+        let mut page = document.pages().get(0)?;
+
+        let bounds = page.boundaries().media()?.bounds;
+
+        let (center_x, center_y) = (
+            (bounds.left() + bounds.right()).value / 2.0,
+            (bounds.bottom() + bounds.top()).value / 2.0,
+        );
+
+        let annotation_rect = PdfRect::new_from_values(100.0, 100.0, 150.0, 200.0);
+
+        page.annotations_mut()
+            .create_square_annotation()?
+            .set_bounds(annotation_rect)?;
+
+        let origins = |page: &PdfPage| -> Result<Vec<(PdfPoints, PdfPoints)>, PdfiumError> {
+            let text = page.text()?;
+
+            let chars = text.chars();
+
+            (0..chars.len().min(50))
+                .map(|index| chars.get(index)?.origin())
+                .collect()
+        };
+
+        let before = origins(&page)?;
+
+        assert!(!before.is_empty());
+
+        // Rotating 180 degrees about the center of the page maps (x, y) to
+        // (2 * center_x - x, 2 * center_y - y).
+
+        page.transform_all(
+            PdfMatrix::new(-1.0, 0.0, 0.0, -1.0, 2.0 * center_x, 2.0 * center_y),
+            None,
+        )?;
+
+        let after = origins(&page)?;
+
+        assert_eq!(before.len(), after.len());
+
+        for ((before_x, before_y), (after_x, after_y)) in before.iter().zip(after.iter()) {
+            assert!((after_x.value - (2.0 * center_x - before_x.value)).abs() < 0.5);
+            assert!((after_y.value - (2.0 * center_y - before_y.value)).abs() < 0.5);
+        }
+
+        let rotated_rect = page.annotations().iter().last().unwrap().bounds()?;
+
+        assert!((rotated_rect.left().value - (2.0 * center_x - 200.0)).abs() < 0.01);
+        assert!((rotated_rect.bottom().value - (2.0 * center_y - 150.0)).abs() < 0.01);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_batched_edits_render_identically_to_unbatched_edits() -> Result<(), PdfiumError> {
+        fn add_grid(page: &mut PdfPage) -> Result<(), PdfiumError> {
+            for row in 0..20 {
+                for column in 0..20 {
+                    let mut object = page.objects_mut().create_path_object_rect(
+                        PdfRect::new_from_values(
+                            row as f32 * 10.0,
+                            column as f32 * 10.0,
+                            row as f32 * 10.0 + 8.0,
+                            column as f32 * 10.0 + 8.0,
+                        ),
+                        None,
+                        None,
+                        Some(PdfColor::new(row * 12, column * 12, 128, 255)),
+                    )?;
+
+                    if (row + column) % 3 == 0 {
+                        object.set_fill_color(PdfColor::BLACK)?;
+                        object.translate(PdfPoints::new(1.0), PdfPoints::new(1.0))?;
+                    }
+                }
+            }
+
+            Ok(())
+        }
+
+        fn render_saved(pdfium: &Pdfium, document: &PdfDocument) -> Result<Vec<u8>, PdfiumError> {
+            let saved = pdfium.load_pdf_from_byte_vec(document.save_to_bytes()?, None)?;
+
+            let page = saved.pages().first()?;
+
+            let bitmap = page.render_with_config(&PdfRenderConfig::new().set_target_width(200))?;
+
+            Ok(bitmap.as_rgba_bytes())
+        }
+
+        let pdfium = test_bind_to_pdfium();
+
+        let size = PdfPagePaperSize::new_custom(PdfPoints::new(200.0), PdfPoints::new(200.0));
+
+        let mut unbatched = pdfium.create_new_pdf()?;
+
+        add_grid(&mut unbatched.pages_mut().create_page_at_start(size)?)?;
+
+        let mut batched = pdfium.create_new_pdf()?;
+
+        {
+            let mut page = batched.pages_mut().create_page_at_start(size)?;
+
+            let mut outer = page.defer_content_regeneration();
+
+            assert!(outer.is_content_regeneration_deferred());
+
+            // Nested batches must not end the outer batch.
+
+            outer.with_batched_edits(add_grid)?;
+
+            assert!(outer.is_content_regeneration_deferred());
+            assert_eq!(
+                outer.content_regeneration_strategy(),
+                PdfPageContentRegenerationStrategy::AutomaticOnEveryChange
+            );
+
+            outer.commit()?;
+
+            assert!(!page.is_content_regeneration_deferred());
+        }
+
+        assert_eq!(
+            render_saved(&pdfium, &batched)?,
+            render_saved(&pdfium, &unbatched)?
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(all(target_os = "windows", feature = "windows_print"))]
+    fn test_render_to_enhanced_metafile() -> Result<(), PdfiumError> {
+        use windows::core::PCWSTR;
+        use windows::Win32::Graphics::Gdi::{
+            CloseEnhMetaFile, CreateEnhMetaFileW, DeleteEnhMetaFile, GetEnhMetaFileHeader,
+            ENHMETAHEADER, HDC,
+        };
+
+        // Returns the number of records written to an in-memory enhanced metafile
+        // by the given drawing function.
+
+        fn count_records(
+            draw: impl FnOnce(HDC) -> Result<(), PdfiumError>,
+        ) -> Result<u32, PdfiumError> {
+            let dc = unsafe { CreateEnhMetaFileW(None, PCWSTR::null(), None, PCWSTR::null()) };
+
+            assert!(!dc.is_invalid());
+
+            let result = draw(dc);
+
+            let metafile = unsafe { CloseEnhMetaFile(dc) };
+
+            let mut header = ENHMETAHEADER::default();
+
+            unsafe {
+                GetEnhMetaFileHeader(
+                    metafile,
+                    std::mem::size_of::<ENHMETAHEADER>() as u32,
+                    Some(&mut header as *mut ENHMETAHEADER),
+                );
+
+                let _ = DeleteEnhMetaFile(Some(metafile));
+            }
+
+            result.map(|_| header.nRecords)
+        }
+
+        let pdfium = test_bind_to_pdfium();
+
+        let document = pdfium.load_pdf_from_file("./test/export-test.pdf", None)?;
+
+        let page = document.pages().first()?;
+
+        pdfium.set_print_mode(PdfPrintMode::Emf);
+
+        let empty = count_records(|_| Ok(()))?;
+
+        let rendered = count_records(|dc| {
+            page.render_to_hdc(
+                dc,
+                &PdfRenderConfig::new()
+                    .set_target_width(1000)
+                    .use_print_quality(true),
+            )
+        })?;
+
+        assert!(rendered > empty);
+
+        Ok(())
+    }
 }
@@ -2,6 +2,7 @@
 
 pub mod attachment_points;
 pub mod circle;
+pub mod flags;
 pub mod free_text;
 pub mod highlight;
 pub mod ink;
@@ -33,8 +34,10 @@ use crate::bindgen::{
 use crate::bindings::PdfiumLibraryBindings;
 use crate::error::PdfiumError;
 use crate::pdf::color::PdfColor;
+use crate::pdf::date::PdfDate;
 use crate::pdf::document::page::annotation::attachment_points::PdfPageAnnotationAttachmentPoints;
 use crate::pdf::document::page::annotation::circle::PdfPageCircleAnnotation;
+use crate::pdf::document::page::annotation::flags::PdfPageAnnotationFlags;
 use crate::pdf::document::page::annotation::free_text::PdfPageFreeTextAnnotation;
 use crate::pdf::document::page::annotation::highlight::PdfPageHighlightAnnotation;
 use crate::pdf::document::page::annotation::ink::PdfPageInkAnnotation;
@@ -53,6 +56,8 @@ use crate::pdf::document::page::annotation::unsupported::PdfPageUnsupportedAnnot
 use crate::pdf::document::page::annotation::widget::PdfPageWidgetAnnotation;
 use crate::pdf::document::page::annotation::xfa_widget::PdfPageXfaWidgetAnnotation;
 use crate::pdf::document::page::field::PdfFormField;
+use crate::pdf::document::page::object::ownership::PdfPageObjectOwnership;
+use crate::pdf::document::page::objects::private::internal::PdfPageObjectsPrivate;
 use crate::pdf::points::PdfPoints;
 use crate::pdf::rect::PdfRect;
 use chrono::prelude::*;
@@ -869,6 +874,50 @@ impl<'a> PdfPageAnnotation<'a> {
             _ => None,
         }
     }
+
+    /// Returns the pop-up annotation linked to this [PdfPageAnnotation] through the `/Popup`
+    /// entry in its annotation dictionary, if any. Pop-up annotations display the text
+    /// of their parent markup annotation in a pop-up window.
+    #[inline]
+    pub fn popup(&self) -> Option<PdfPageAnnotation<'_>> {
+        self.linked_annotation("Popup")
+    }
+
+    /// Returns the annotation this [PdfPageAnnotation] is a reply to, as given by
+    /// the `/IRT` entry in its annotation dictionary, if any.
+    #[inline]
+    pub fn in_reply_to(&self) -> Option<PdfPageAnnotation<'_>> {
+        self.linked_annotation("IRT")
+    }
+
+    /// Returns the annotation referenced by the given key in the annotation dictionary
+    /// of this [PdfPageAnnotation], if any.
+    fn linked_annotation(&self, key: &str) -> Option<PdfPageAnnotation<'_>> {
+        let handle = self.bindings().FPDFAnnot_GetLinkedAnnot(self.handle(), key);
+
+        if handle.is_null() {
+            return None;
+        }
+
+        match self.objects_impl().ownership() {
+            PdfPageObjectOwnership::AttachedAnnotation(ownership) => {
+                Some(PdfPageAnnotation::from_pdfium(
+                    ownership.document_handle(),
+                    ownership.page_handle(),
+                    handle,
+                    None,
+                    self.bindings(),
+                ))
+            }
+            _ => {
+                // Annotations are always attached to a page, so this should be unreachable.
+
+                self.bindings().FPDFPage_CloseAnnot(handle);
+
+                None
+            }
+        }
+    }
 }
 
 /// Functionality common to all [PdfPageAnnotation] objects, regardless of their [PdfPageAnnotationType].
@@ -926,8 +975,17 @@ pub trait PdfPageAnnotationCommon {
     fn set_contents(&mut self, contents: &str) -> Result<(), PdfiumError>;
 
     /// Returns the name of the creator of this [PdfPageAnnotation], if any.
+    ///
+    /// This is equivalent to the [PdfPageAnnotationCommon::author()] function.
     fn creator(&self) -> Option<String>;
 
+    /// Returns the author of this [PdfPageAnnotation], if any. By convention, this is
+    /// the name of the user who added the annotation.
+    fn author(&self) -> Option<String>;
+
+    /// Sets the author of this [PdfPageAnnotation].
+    fn set_author(&mut self, author: &str) -> Result<(), PdfiumError>;
+
     /// Returns the date and time when this [PdfPageAnnotation] was originally created, if any.
     fn creation_date(&self) -> Option<String>;
 
@@ -940,6 +998,32 @@ pub trait PdfPageAnnotationCommon {
     /// Sets the date and time when this [PdfPageAnnotation] was last modified.
     fn set_modification_date(&mut self, date: DateTime<Utc>) -> Result<(), PdfiumError>;
 
+    /// Returns the date and time when this [PdfPageAnnotation] was originally created,
+    /// parsed into a [PdfDate], if any. Returns `None` if the annotation has no creation date
+    /// or if its creation date cannot be parsed.
+    fn creation_pdf_date(&self) -> Option<PdfDate>;
+
+    /// Sets the date and time when this [PdfPageAnnotation] was originally created.
+    fn set_creation_pdf_date(&mut self, date: PdfDate) -> Result<(), PdfiumError>;
+
+    /// Returns the date and time when this [PdfPageAnnotation] was last modified,
+    /// parsed into a [PdfDate], if any. Returns `None` if the annotation has no modification
+    /// date or if its modification date cannot be parsed.
+    fn modification_pdf_date(&self) -> Option<PdfDate>;
+
+    /// Sets the date and time when this [PdfPageAnnotation] was last modified.
+    ///
+    /// Note that changing any other property of the annotation that is stored as a string,
+    /// such as its author or contents, automatically updates the modification date to
+    /// the current date and time.
+    fn set_modification_pdf_date(&mut self, date: PdfDate) -> Result<(), PdfiumError>;
+
+    /// Returns the flags set on this [PdfPageAnnotation].
+    fn flags(&self) -> PdfPageAnnotationFlags;
+
+    /// Replaces the flags set on this [PdfPageAnnotation] with the given flags.
+    fn set_flags(&mut self, flags: PdfPageAnnotationFlags) -> Result<(), PdfiumError>;
+
     /// Returns the color of any filled paths in this [PdfPageAnnotation].
     fn fill_color(&self) -> Result<PdfColor, PdfiumError>;
 
@@ -1044,6 +1128,16 @@ where
         self.creator_impl()
     }
 
+    #[inline]
+    fn author(&self) -> Option<String> {
+        self.creator_impl()
+    }
+
+    #[inline]
+    fn set_author(&mut self, author: &str) -> Result<(), PdfiumError> {
+        self.set_creator(author)
+    }
+
     #[inline]
     fn creation_date(&self) -> Option<String> {
         self.creation_date_impl()
@@ -1064,6 +1158,36 @@ where
         self.set_modification_date_impl(date)
     }
 
+    #[inline]
+    fn creation_pdf_date(&self) -> Option<PdfDate> {
+        self.creation_pdf_date_impl()
+    }
+
+    #[inline]
+    fn set_creation_pdf_date(&mut self, date: PdfDate) -> Result<(), PdfiumError> {
+        self.set_creation_pdf_date_impl(date)
+    }
+
+    #[inline]
+    fn modification_pdf_date(&self) -> Option<PdfDate> {
+        self.modification_pdf_date_impl()
+    }
+
+    #[inline]
+    fn set_modification_pdf_date(&mut self, date: PdfDate) -> Result<(), PdfiumError> {
+        self.set_modification_pdf_date_impl(date)
+    }
+
+    #[inline]
+    fn flags(&self) -> PdfPageAnnotationFlags {
+        self.flags_impl()
+    }
+
+    #[inline]
+    fn set_flags(&mut self, flags: PdfPageAnnotationFlags) -> Result<(), PdfiumError> {
+        self.set_flags_impl(flags)
+    }
+
     #[inline]
     fn fill_color(&self) -> Result<PdfColor, PdfiumError> {
         self.fill_color_impl()
@@ -1125,6 +1249,14 @@ impl<'a> PdfPageAnnotationPrivate<'a> for PdfPageAnnotation<'a> {
     fn attachment_points_mut_impl(&mut self) -> &mut PdfPageAnnotationAttachmentPoints<'a> {
         self.unwrap_as_trait_mut().attachment_points_mut_impl()
     }
+
+    #[inline]
+    fn set_stroke_color_impl(&mut self, stroke_color: PdfColor) -> Result<(), PdfiumError> {
+        // Some annotation types customise how their stroke color is set.
+
+        self.unwrap_as_trait_mut()
+            .set_stroke_color_impl(stroke_color)
+    }
 }
 
 impl<'a> Drop for PdfPageAnnotation<'a> {
@@ -93,8 +93,9 @@ impl<'a> PdfPageAnnotationAttachmentPoints<'a> {
         {
             Ok(PdfQuadPoints::from_pdfium(result))
         } else {
-            Err(PdfiumError::PdfiumLibraryInternalError(
-                PdfiumInternalError::Unknown,
+            Err(self.bindings.error_in(
+                "FPDFAnnot_GetAttachmentPoints",
+                PdfiumError::PdfiumLibraryInternalError(PdfiumInternalError::Unknown),
             ))
         }
     }
@@ -137,8 +138,9 @@ impl<'a> PdfPageAnnotationAttachmentPoints<'a> {
         {
             Ok(())
         } else {
-            Err(PdfiumError::PdfiumLibraryInternalError(
-                PdfiumInternalError::Unknown,
+            Err(self.bindings.error_in(
+                "FPDFAnnot_AppendAttachmentPoints",
+                PdfiumError::PdfiumLibraryInternalError(PdfiumInternalError::Unknown),
             ))
         }
     }
@@ -160,8 +162,9 @@ impl<'a> PdfPageAnnotationAttachmentPoints<'a> {
         {
             Ok(())
         } else {
-            Err(PdfiumError::PdfiumLibraryInternalError(
-                PdfiumInternalError::Unknown,
+            Err(self.bindings.error_in(
+                "FPDFAnnot_SetAttachmentPoints",
+                PdfiumError::PdfiumLibraryInternalError(PdfiumInternalError::Unknown),
             ))
         }
     }
@@ -0,0 +1,51 @@
+//! Defines the [PdfPageAnnotationFlags] bitflags, specifying how a single `PdfPageAnnotation`
+//! should be presented and whether it may be changed.
+
+use crate::bindgen::{
+    FPDF_ANNOT_FLAG_HIDDEN, FPDF_ANNOT_FLAG_INVISIBLE, FPDF_ANNOT_FLAG_LOCKED,
+    FPDF_ANNOT_FLAG_NOROTATE, FPDF_ANNOT_FLAG_NOVIEW, FPDF_ANNOT_FLAG_NOZOOM,
+    FPDF_ANNOT_FLAG_PRINT, FPDF_ANNOT_FLAG_READONLY, FPDF_ANNOT_FLAG_TOGGLENOVIEW,
+};
+use bitflags::bitflags;
+
+bitflags! {
+    /// The flags set on a single `PdfPageAnnotation`, as defined in table 8.16 of the PDF Reference,
+    /// version 1.7, on page 608.
+    #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+    pub struct PdfPageAnnotationFlags: u32 {
+        /// Do not display the annotation if it does not belong to one of the standard
+        /// annotation types and no annotation handler is available.
+        const INVISIBLE = FPDF_ANNOT_FLAG_INVISIBLE;
+
+        /// Do not display or print the annotation, or allow it to interact with the user.
+        const HIDDEN = FPDF_ANNOT_FLAG_HIDDEN;
+
+        /// Print the annotation when the page is printed.
+        const PRINT = FPDF_ANNOT_FLAG_PRINT;
+
+        /// Do not scale the annotation's appearance to match the magnification of the page.
+        const NO_ZOOM = FPDF_ANNOT_FLAG_NOZOOM;
+
+        /// Do not rotate the annotation's appearance to match the rotation of the page.
+        const NO_ROTATE = FPDF_ANNOT_FLAG_NOROTATE;
+
+        /// Do not display the annotation on the screen or allow it to interact with the user.
+        /// The annotation may still be printed, depending on the [PdfPageAnnotationFlags::PRINT] flag.
+        const NO_VIEW = FPDF_ANNOT_FLAG_NOVIEW;
+
+        /// Do not allow the annotation to interact with the user.
+        const READ_ONLY = FPDF_ANNOT_FLAG_READONLY;
+
+        /// Do not allow the annotation to be deleted or its properties to be modified by the user.
+        /// The annotation's contents may still be changed.
+        const LOCKED = FPDF_ANNOT_FLAG_LOCKED;
+
+        /// Invert the interpretation of the [PdfPageAnnotationFlags::NO_VIEW] flag for
+        /// certain events.
+        const TOGGLE_NO_VIEW = FPDF_ANNOT_FLAG_TOGGLENOVIEW;
+
+        /// Do not allow the annotation's contents to be modified by the user.
+        /// The annotation may still be deleted and its other properties changed.
+        const LOCKED_CONTENTS = 512;
+    }
+}
@@ -3,9 +3,15 @@
 
 use crate::bindgen::{FPDF_ANNOTATION, FPDF_DOCUMENT, FPDF_PAGE};
 use crate::bindings::PdfiumLibraryBindings;
+use crate::error::PdfiumError;
+use crate::pdf::appearance_mode::PdfAppearanceMode;
+use crate::pdf::color::PdfColor;
+use crate::pdf::document::fonts::PdfFontBuiltin;
 use crate::pdf::document::page::annotation::attachment_points::PdfPageAnnotationAttachmentPoints;
 use crate::pdf::document::page::annotation::objects::PdfPageAnnotationObjects;
 use crate::pdf::document::page::annotation::private::internal::PdfPageAnnotationPrivate;
+use crate::pdf::document::page::annotation::PdfPageAnnotationCommon;
+use crate::pdf::points::PdfPoints;
 
 /// A single `PdfPageAnnotation` of type `PdfPageAnnotationType::FreeText`.
 pub struct PdfPageFreeTextAnnotation<'a> {
@@ -37,6 +43,99 @@ impl<'a> PdfPageFreeTextAnnotation<'a> {
             bindings,
         }
     }
+
+    /// Returns the default appearance string of this [PdfPageFreeTextAnnotation], if any.
+    /// The default appearance string specifies the font, font size, and color used
+    /// by viewers when regenerating the appearance of the annotation's text.
+    #[inline]
+    pub fn default_appearance(&self) -> Option<String> {
+        self.get_string_value("DA")
+    }
+
+    /// Sets the text of this [PdfPageFreeTextAnnotation] to the given value, updating its
+    /// `/Contents` and `/DA` entries to match and generating a normal appearance stream that
+    /// draws the text inside the annotation's current bounds using the given built-in font,
+    /// font size, and color.
+    ///
+    /// Lines in the given text are laid out top-to-bottom from the top-left corner of the
+    /// annotation's bounds. Text is not wrapped; lines that are wider than the bounds will be
+    /// clipped. Characters outside the Latin-1 range are replaced with `?` in the generated
+    /// appearance stream, although they are retained in the annotation's `/Contents`.
+    ///
+    /// The font is referenced by its standard PostScript name; all conforming viewers
+    /// are required to provide the 14 built-in PDF fonts.
+    pub fn set_text(
+        &mut self,
+        text: &str,
+        font: PdfFontBuiltin,
+        font_size: PdfPoints,
+        color: PdfColor,
+    ) -> Result<(), PdfiumError> {
+        let bounds = self.bounds()?;
+
+        let font_name = font.to_pdf_font_name();
+
+        let size = font_size.value;
+
+        let (red, green, blue) = (
+            color.red() as f32 / 255.0,
+            color.green() as f32 / 255.0,
+            color.blue() as f32 / 255.0,
+        );
+
+        let default_appearance =
+            format!("/{} {} Tf {} {} {} rg", font_name, size, red, green, blue);
+
+        let mut appearance = format!(
+            "BT {} {} TL {} {} Td",
+            default_appearance,
+            size * 1.2,
+            bounds.left().value + 2.0,
+            bounds.top().value - 2.0 - size,
+        );
+
+        for (index, line) in text.lines().enumerate() {
+            if index > 0 {
+                appearance.push_str(" T*");
+            }
+
+            appearance.push_str(" (");
+            appearance.push_str(&escape_pdf_string(line));
+            appearance.push_str(") Tj");
+        }
+
+        appearance.push_str(" ET");
+
+        self.set_contents(text)?;
+        self.set_string_value("DA", &default_appearance)?;
+
+        self.bindings.to_result(self.bindings.FPDFAnnot_SetAP_str(
+            self.handle,
+            PdfAppearanceMode::Normal as i32,
+            &appearance,
+        ))
+    }
+}
+
+/// Escapes the given text for inclusion in a literal string in a PDF content stream.
+/// Characters in the Latin-1 range above ASCII are written as octal escapes; characters
+/// outside the Latin-1 range are replaced with `?`.
+fn escape_pdf_string(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+
+    for c in text.chars() {
+        match c {
+            '(' | ')' | '\\' => {
+                result.push('\\');
+                result.push(c);
+            }
+            ' '..='~' => result.push(c),
+            '\u{80}'..='\u{ff}' => result.push_str(&format!("\\{:03o}", c as u32)),
+            _ => result.push('?'),
+        }
+    }
+
+    result
 }
 
 impl<'a> PdfPageAnnotationPrivate<'a> for PdfPageFreeTextAnnotation<'a> {
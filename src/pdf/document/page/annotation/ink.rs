@@ -1,13 +1,34 @@
 //! Defines the [PdfPageInkAnnotation] struct, exposing functionality related to a single
 //! user annotation of type `PdfPageAnnotationType::Ink`.
 
-use crate::bindgen::{FPDF_ANNOTATION, FPDF_DOCUMENT, FPDF_PAGE};
+use crate::bindgen::{
+    size_t, FPDFANNOT_COLORTYPE_FPDFANNOT_COLORTYPE_Color, FPDF_ANNOTATION, FPDF_DOCUMENT,
+    FPDF_PAGE, FS_POINTF,
+};
 use crate::bindings::PdfiumLibraryBindings;
+use crate::error::{PdfiumError, PdfiumInternalError};
+use crate::pdf::appearance_mode::PdfAppearanceMode;
+use crate::pdf::color::PdfColor;
 use crate::pdf::document::page::annotation::attachment_points::PdfPageAnnotationAttachmentPoints;
 use crate::pdf::document::page::annotation::objects::PdfPageAnnotationObjects;
 use crate::pdf::document::page::annotation::private::internal::PdfPageAnnotationPrivate;
+use crate::pdf::points::PdfPoints;
+use crate::pdf::rect::PdfRect;
+use std::os::raw::{c_float, c_uint, c_ulong};
+
+/// The zero-based index of a single stroke in the ink list of a [PdfPageInkAnnotation].
+pub type PdfPageInkAnnotationStrokeIndex = usize;
 
 /// A single `PdfPageAnnotation` of type `PdfPageAnnotationType::Ink`.
+///
+/// An ink annotation represents one or more freehand strokes, each a path of connected
+/// points. Strokes are stored in the annotation's ink list; Pdfium generates the annotation's
+/// appearance stream from the ink list, stroke color, and stroke width when the page is
+/// rendered or the document is reloaded.
+///
+/// Adding or clearing strokes, or changing the stroke color or stroke width, discards the
+/// annotation's existing appearance stream so that it will be regenerated. This includes
+/// any page objects previously added using the [PdfPageInkAnnotation::objects_mut()] function.
 pub struct PdfPageInkAnnotation<'a> {
     handle: FPDF_ANNOTATION,
     objects: PdfPageAnnotationObjects<'a>,
@@ -43,6 +64,165 @@ impl<'a> PdfPageInkAnnotation<'a> {
     pub fn objects_mut(&mut self) -> &mut PdfPageAnnotationObjects<'a> {
         &mut self.objects
     }
+
+    /// Returns all the strokes in the ink list of this [PdfPageInkAnnotation]. Each stroke
+    /// is a path of connected (x, y) points, expressed in page coordinates.
+    pub fn strokes(&self) -> Vec<Vec<(PdfPoints, PdfPoints)>> {
+        let count = self.bindings.FPDFAnnot_GetInkListCount(self.handle);
+
+        (0..count)
+            .map(|index| {
+                // Retrieving the points in a stroke is a two-step operation. First, we call
+                // FPDFAnnot_GetInkListPath() with a null buffer; this will retrieve the number
+                // of points in the stroke. We then reserve a buffer of the given length
+                // and call FPDFAnnot_GetInkListPath() again to fill the buffer.
+
+                let len = self.bindings.FPDFAnnot_GetInkListPath(
+                    self.handle,
+                    index,
+                    std::ptr::null_mut(),
+                    0,
+                );
+
+                let mut buffer = vec![FS_POINTF { x: 0.0, y: 0.0 }; len as usize];
+
+                self.bindings.FPDFAnnot_GetInkListPath(
+                    self.handle,
+                    index,
+                    buffer.as_mut_ptr(),
+                    len as c_ulong,
+                );
+
+                buffer
+                    .iter()
+                    .map(|point| (PdfPoints::new(point.x), PdfPoints::new(point.y)))
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Adds a new stroke, a path connecting the given (x, y) points, to the ink list of
+    /// this [PdfPageInkAnnotation], returning the index of the new stroke. The bounds
+    /// of the annotation are expanded to fit the new stroke.
+    ///
+    /// Returns [PdfiumError::InkStrokeTooFewPoints] if fewer than two points are given.
+    pub fn add_stroke(
+        &mut self,
+        points: &[(PdfPoints, PdfPoints)],
+    ) -> Result<PdfPageInkAnnotationStrokeIndex, PdfiumError> {
+        if points.len() < 2 {
+            return Err(PdfiumError::InkStrokeTooFewPoints);
+        }
+
+        let points = points
+            .iter()
+            .map(|(x, y)| FS_POINTF {
+                x: x.value,
+                y: y.value,
+            })
+            .collect::<Vec<_>>();
+
+        let index = self.bindings.FPDFAnnot_AddInkStroke(
+            self.handle,
+            points.as_ptr(),
+            points.len() as size_t,
+        );
+
+        if index < 0 {
+            return Err(self.bindings.error_in(
+                "FPDFAnnot_AddInkStroke",
+                PdfiumError::PdfiumLibraryInternalError(PdfiumInternalError::Unknown),
+            ));
+        }
+
+        self.update_bounds()?;
+        self.clear_appearance_stream()?;
+
+        Ok(index as PdfPageInkAnnotationStrokeIndex)
+    }
+
+    /// Removes all strokes from the ink list of this [PdfPageInkAnnotation].
+    pub fn clear_strokes(&mut self) -> Result<(), PdfiumError> {
+        self.bindings
+            .to_result(self.bindings.FPDFAnnot_RemoveInkList(self.handle))?;
+
+        self.clear_appearance_stream()
+    }
+
+    /// Returns the width of the strokes in this [PdfPageInkAnnotation]. If no width has
+    /// been set, the default width of 1 point is returned.
+    pub fn stroke_width(&self) -> PdfPoints {
+        let mut horizontal_radius: c_float = 0.0;
+
+        let mut vertical_radius: c_float = 0.0;
+
+        let mut border_width: c_float = 0.0;
+
+        if self.bindings.is_true(self.bindings.FPDFAnnot_GetBorder(
+            self.handle,
+            &mut horizontal_radius,
+            &mut vertical_radius,
+            &mut border_width,
+        )) {
+            PdfPoints::new(border_width)
+        } else {
+            PdfPoints::new(1.0)
+        }
+    }
+
+    /// Sets the width of the strokes in this [PdfPageInkAnnotation].
+    pub fn set_stroke_width(&mut self, width: PdfPoints) -> Result<(), PdfiumError> {
+        self.bindings.to_result(self.bindings.FPDFAnnot_SetBorder(
+            self.handle,
+            0.0,
+            0.0,
+            width.value,
+        ))?;
+
+        self.update_bounds()?;
+        self.clear_appearance_stream()
+    }
+
+    /// Sets the bounds of this [PdfPageInkAnnotation] so that they enclose every stroke
+    /// in its ink list, allowing for the stroke width.
+    fn update_bounds(&mut self) -> Result<(), PdfiumError> {
+        let padding = self.stroke_width().value / 2.0 + 1.0;
+
+        let bounds = self.strokes().iter().flatten().fold(
+            None,
+            |bounds: Option<(f32, f32, f32, f32)>, (x, y)| {
+                let (x, y) = (x.value, y.value);
+
+                Some(match bounds {
+                    Some((bottom, left, top, right)) => {
+                        (bottom.min(y), left.min(x), top.max(y), right.max(x))
+                    }
+                    None => (y, x, y, x),
+                })
+            },
+        );
+
+        match bounds {
+            Some((bottom, left, top, right)) => self.set_bounds_impl(PdfRect::new_from_values(
+                bottom - padding,
+                left - padding,
+                top + padding,
+                right + padding,
+            )),
+            None => Ok(()),
+        }
+    }
+
+    /// Removes the normal appearance stream of this [PdfPageInkAnnotation], so that Pdfium
+    /// regenerates it from the annotation's ink list, stroke color, and stroke width.
+    #[inline]
+    fn clear_appearance_stream(&mut self) -> Result<(), PdfiumError> {
+        self.bindings.to_result(self.bindings.FPDFAnnot_SetAP(
+            self.handle,
+            PdfAppearanceMode::Normal as i32,
+            std::ptr::null(),
+        ))
+    }
 }
 
 impl<'a> PdfPageAnnotationPrivate<'a> for PdfPageInkAnnotation<'a> {
@@ -75,4 +255,101 @@ impl<'a> PdfPageAnnotationPrivate<'a> for PdfPageInkAnnotation<'a> {
     fn attachment_points_mut_impl(&mut self) -> &mut PdfPageAnnotationAttachmentPoints<'a> {
         &mut self.attachment_points
     }
+
+    fn set_stroke_color_impl(&mut self, stroke_color: PdfColor) -> Result<(), PdfiumError> {
+        // Pdfium will not set the color of an annotation that has an appearance stream,
+        // so we remove the appearance stream first. It will be regenerated using the new color.
+
+        self.clear_appearance_stream()?;
+
+        self.bindings.to_result(self.bindings.FPDFAnnot_SetColor(
+            self.handle,
+            FPDFANNOT_COLORTYPE_FPDFANNOT_COLORTYPE_Color,
+            stroke_color.red() as c_uint,
+            stroke_color.green() as c_uint,
+            stroke_color.blue() as c_uint,
+            stroke_color.alpha() as c_uint,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::utils::test::test_bind_to_pdfium;
+
+    #[test]
+    fn test_ink_strokes_round_trip() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let stroke_1 = vec![
+            (PdfPoints::new(100.0), PdfPoints::new(100.0)),
+            (PdfPoints::new(300.0), PdfPoints::new(100.0)),
+        ];
+
+        let stroke_2 = vec![
+            (PdfPoints::new(100.0), PdfPoints::new(400.0)),
+            (PdfPoints::new(200.0), PdfPoints::new(500.0)),
+            (PdfPoints::new(300.0), PdfPoints::new(400.0)),
+        ];
+
+        let bytes = {
+            let mut document = pdfium.create_new_pdf()?;
+
+            let mut page = document
+                .pages_mut()
+                .create_page_at_end(PdfPagePaperSize::a4())?;
+
+            let mut annotation = page.annotations_mut().create_ink_annotation()?;
+
+            assert!(annotation
+                .add_stroke(&[(PdfPoints::new(10.0), PdfPoints::new(10.0))])
+                .is_err());
+
+            assert_eq!(annotation.add_stroke(&stroke_1)?, 0);
+            assert_eq!(annotation.add_stroke(&stroke_2)?, 1);
+
+            annotation.set_stroke_color(PdfColor::RED)?;
+            annotation.set_stroke_width(PdfPoints::new(6.0))?;
+
+            document.save_to_bytes()?
+        };
+
+        let document = pdfium.load_pdf_from_byte_vec(bytes, None)?;
+
+        let page = document.pages().get(0)?;
+
+        let annotation = page.annotations().get(0)?;
+
+        let strokes = annotation.as_ink_annotation().unwrap().strokes();
+
+        assert_eq!(strokes, vec![stroke_1, stroke_2]);
+
+        // Render the page at 1 pixel per point and confirm that pixels along the
+        // first stroke are no longer the white page background.
+
+        let width = page.width().value as i32;
+
+        let height = page.height().value as i32;
+
+        let bitmap = page.render_with_config(
+            &PdfRenderConfig::new()
+                .set_target_width(width)
+                .render_annotations(true),
+        )?;
+
+        let pixels = bitmap.as_rgba_bytes();
+
+        let bitmap_width = bitmap.width() as i32;
+
+        for x in [150, 200, 250] {
+            let y = height - 100;
+
+            let offset = ((y * bitmap_width + x) * 4) as usize;
+
+            assert_ne!(&pixels[offset..offset + 3], &[255, 255, 255]);
+        }
+
+        Ok(())
+    }
 }
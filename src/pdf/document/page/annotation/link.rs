@@ -9,7 +9,45 @@ use crate::pdf::document::page::annotation::objects::PdfPageAnnotationObjects;
 use crate::pdf::document::page::annotation::private::internal::PdfPageAnnotationPrivate;
 use crate::pdf::document::page::objects::private::internal::PdfPageObjectsPrivate;
 use crate::pdf::document::page::PdfPageObjectOwnership;
+use crate::pdf::document::pages::PdfPageIndex;
+use crate::pdf::document::raw::{
+    get, set, to_dictionary_string, PdfIncrementalUpdate, PdfRawDocument,
+};
 use crate::pdf::link::PdfLink;
+use crate::pdf::points::PdfPoints;
+use once_cell::sync::Lazy;
+use std::collections::HashSet;
+use std::sync::Mutex;
+
+/// The prefix of the placeholder `/Dest` string written into a link annotation targeting
+/// a page destination. Pdfium offers no API for writing an explicit destination array into
+/// an annotation dictionary, so the placeholder is replaced with the correct array as an
+/// incremental update when the containing document is saved.
+const PAGE_DESTINATION_PLACEHOLDER_PREFIX: &str = "pdfium-render-page-destination:";
+
+/// The raw `FPDF_DOCUMENT` handles of all open documents containing link annotations with
+/// page destination placeholders that must be resolved when the document is saved.
+static DOCUMENTS_WITH_PAGE_DESTINATIONS: Lazy<Mutex<HashSet<usize>>> =
+    Lazy::new(|| Mutex::new(HashSet::new()));
+
+/// The target of a single [PdfPageLinkAnnotation]: either a location on a page in the
+/// same document, or an external URI.
+#[derive(Debug, Clone, PartialEq)]
+pub enum PdfLinkTarget {
+    /// A location on the page with the given index in the same document. If `location`
+    /// is provided, the viewer scrolls so that the given point is at the top left of the
+    /// window; if `zoom` is provided, the viewer applies the given magnification factor,
+    /// where 1.0 is 100%. Omitted values leave the viewer's current position or magnification
+    /// unchanged.
+    PageDestination {
+        page_index: PdfPageIndex,
+        location: Option<(PdfPoints, PdfPoints)>,
+        zoom: Option<f32>,
+    },
+
+    /// An external URI, typically a web address.
+    Uri(String),
+}
 
 /// A single `PdfPageAnnotation` of type `PdfPageAnnotationType::Link`.
 pub struct PdfPageLinkAnnotation<'a> {
@@ -47,8 +85,9 @@ impl<'a> PdfPageLinkAnnotation<'a> {
         let handle = self.bindings.FPDFAnnot_GetLink(self.handle);
 
         if handle.is_null() {
-            Err(PdfiumError::PdfiumLibraryInternalError(
-                PdfiumInternalError::Unknown,
+            Err(self.bindings.error_in(
+                "FPDFAnnot_GetLink",
+                PdfiumError::PdfiumLibraryInternalError(PdfiumInternalError::Unknown),
             ))
         } else {
             let document_handle = match self.objects.ownership() {
@@ -82,12 +121,82 @@ impl<'a> PdfPageLinkAnnotation<'a> {
         {
             Ok(())
         } else {
-            Err(PdfiumError::PdfiumLibraryInternalError(
-                PdfiumInternalError::Unknown,
+            Err(self.bindings().error_in(
+                "FPDFAnnot_SetURI",
+                PdfiumError::PdfiumLibraryInternalError(PdfiumInternalError::Unknown),
             ))
         }
     }
 
+    /// Sets the target of this [PdfPageLinkAnnotation] to the given [PdfLinkTarget].
+    ///
+    /// Pdfium does not support writing page destinations into annotations directly.
+    /// A page destination is instead recorded as a placeholder that is replaced with the
+    /// correct destination when the containing `PdfDocument` is saved; until then, the
+    /// destination will not be visible to the [PdfPageLinkAnnotation::link()] function.
+    /// Page destinations cannot be written into encrypted documents.
+    ///
+    /// Returns [PdfiumError::PageIndexOutOfBounds] if the given target is a page destination
+    /// for a page index that does not exist in the containing document.
+    ///
+    /// The target of a link annotation should be set only once; Pdfium offers no way to remove
+    /// a previously set target, so viewers may ignore a page destination set after a URI.
+    pub fn set_target(&mut self, target: PdfLinkTarget) -> Result<(), PdfiumError> {
+        match target {
+            PdfLinkTarget::Uri(uri) => self.set_link(&uri),
+            PdfLinkTarget::PageDestination {
+                page_index,
+                location,
+                zoom,
+            } => {
+                let document_handle = match self.objects.ownership() {
+                    PdfPageObjectOwnership::AttachedAnnotation(ownership) => {
+                        ownership.document_handle()
+                    }
+                    _ => return Err(PdfiumError::OwnershipNotAttachedToDocument),
+                };
+
+                if page_index as i32 >= self.bindings.FPDF_GetPageCount(document_handle) {
+                    return Err(PdfiumError::PageIndexOutOfBounds);
+                }
+
+                let value = |value: Option<f32>| {
+                    value
+                        .map(|value| value.to_string())
+                        .unwrap_or_else(|| "null".to_string())
+                };
+
+                let placeholder = format!(
+                    "{}{} {} {} {}",
+                    PAGE_DESTINATION_PLACEHOLDER_PREFIX,
+                    page_index,
+                    value(location.map(|(x, _)| x.value)),
+                    value(location.map(|(_, y)| y.value)),
+                    value(zoom),
+                );
+
+                self.set_string_value("Dest", &placeholder)?;
+
+                DOCUMENTS_WITH_PAGE_DESTINATIONS
+                    .lock()
+                    .unwrap()
+                    .insert(document_handle as usize);
+
+                Ok(())
+            }
+        }
+    }
+
+    /// Hides the border of this [PdfPageLinkAnnotation]. By default, viewers draw a
+    /// visible border around link annotations that do not specify otherwise.
+    #[inline]
+    pub(crate) fn hide_border(&mut self) -> Result<(), PdfiumError> {
+        self.bindings.to_result(
+            self.bindings
+                .FPDFAnnot_SetBorder(self.handle, 0.0, 0.0, 0.0),
+        )
+    }
+
     /// Returns a mutable collection of all the attachment points in this [PdfPageLinkAnnotation].
     #[inline]
     pub fn attachment_points_mut(&mut self) -> &mut PdfPageAnnotationAttachmentPoints<'a> {
@@ -126,3 +235,69 @@ impl<'a> PdfPageAnnotationPrivate<'a> for PdfPageLinkAnnotation<'a> {
         &mut self.attachment_points
     }
 }
+
+/// Returns `true` if the given document contains link annotations with page destination
+/// placeholders that must be resolved when the document is saved.
+#[inline]
+pub(crate) fn has_page_destinations_to_resolve(document: FPDF_DOCUMENT) -> bool {
+    DOCUMENTS_WITH_PAGE_DESTINATIONS
+        .lock()
+        .unwrap()
+        .contains(&(document as usize))
+}
+
+/// Stops tracking page destination placeholders for the given document.
+/// Called when the document is closed.
+#[inline]
+pub(crate) fn forget_page_destinations_to_resolve(document: FPDF_DOCUMENT) {
+    DOCUMENTS_WITH_PAGE_DESTINATIONS
+        .lock()
+        .unwrap()
+        .remove(&(document as usize));
+}
+
+/// Adds replacements for all link annotations in the given saved document containing page
+/// destination placeholders to the given incremental update, with each placeholder replaced
+/// by an explicit destination array referencing the target page.
+pub(crate) fn add_resolved_page_destinations_to_update(
+    document: &PdfRawDocument,
+    update: &mut PdfIncrementalUpdate,
+) {
+    let pages = match document.page_object_numbers() {
+        Some(pages) => pages,
+        None => return,
+    };
+
+    for object_number in document.objects_containing(PAGE_DESTINATION_PLACEHOLDER_PREFIX.as_bytes())
+    {
+        let mut dictionary = match document.object_dictionary(object_number) {
+            Some(dictionary) => dictionary,
+            None => continue,
+        };
+
+        let destination = get(&dictionary, "Dest")
+            .map(|value| value.trim_start_matches('(').trim_end_matches(')'))
+            .and_then(|value| value.strip_prefix(PAGE_DESTINATION_PLACEHOLDER_PREFIX))
+            .and_then(|value| {
+                let tokens = value.split_ascii_whitespace().collect::<Vec<_>>();
+
+                match tokens.as_slice() {
+                    [page_index, left, top, zoom] => {
+                        let page = pages.get(page_index.parse::<usize>().ok()?)?;
+
+                        Some(format!("[{} 0 R /XYZ {} {} {}]", page, left, top, zoom))
+                    }
+                    _ => None,
+                }
+            });
+
+        if let Some(destination) = destination {
+            set(&mut dictionary, "Dest", destination);
+
+            update.replace_object(
+                object_number,
+                to_dictionary_string(&dictionary).into_bytes(),
+            );
+        }
+    }
+}
@@ -1,7 +1,7 @@
 //! Defines the [PdfPageAnnotationObjects] struct, exposing functionality related to the
 //! page objects contained within a single `PdfPageAnnotation`.
 
-use crate::bindgen::{FPDF_ANNOTATION, FPDF_DOCUMENT, FPDF_PAGE};
+use crate::bindgen::{FPDF_ANNOTATION, FPDF_DOCUMENT, FPDF_PAGE, FS_RECTF};
 use crate::bindings::PdfiumLibraryBindings;
 use crate::error::{PdfiumError, PdfiumInternalError};
 use crate::pdf::document::page::object::ownership::PdfPageObjectOwnership;
@@ -58,6 +58,128 @@ impl<'a> PdfPageAnnotationObjects<'a> {
     pub(crate) fn annotation_handle(&self) -> FPDF_ANNOTATION {
         self.annotation_handle
     }
+
+    /// Returns `true` if the `PdfPageAnnotation` containing this [PdfPageAnnotationObjects]
+    /// collection has an appearance stream.
+    ///
+    /// Viewers other than Pdfium generally draw an annotation's page objects only
+    /// from its appearance stream, so an annotation containing page objects but lacking an
+    /// appearance stream may not be visible outside of `pdfium-render`.
+    #[inline]
+    pub fn has_appearance_stream(&self) -> bool {
+        self.bindings
+            .is_true(self.bindings.FPDFAnnot_HasKey(self.annotation_handle, "AP"))
+    }
+
+    /// Rebuilds the appearance stream of the `PdfPageAnnotation` containing this
+    /// [PdfPageAnnotationObjects] collection from the page objects currently in the collection.
+    ///
+    /// The appearance stream is rebuilt automatically whenever a page object is added to or
+    /// removed from this collection, so it is only necessary to call this function after
+    /// changing the properties of page objects already in the collection. If any page object
+    /// extends beyond the annotation's bounds, the bounds are enlarged to contain it so that
+    /// the object is not clipped by the appearance stream's bounding box.
+    pub fn regenerate_appearance(&mut self) -> Result<(), PdfiumError> {
+        Self::regenerate_appearance_for_annotation(self.annotation_handle, self.bindings)?;
+        self.regenerate_content_after_mutation()
+    }
+
+    /// Rebuilds the appearance stream of the annotation with the given `FPDF_ANNOTATION` handle
+    /// from the page objects it contains, enlarging the annotation's bounds if necessary so that
+    /// every page object lies within the appearance stream's bounding box.
+    pub(crate) fn regenerate_appearance_for_annotation(
+        annotation_handle: FPDF_ANNOTATION,
+        bindings: &dyn PdfiumLibraryBindings,
+    ) -> Result<(), PdfiumError> {
+        let count = bindings.FPDFAnnot_GetObjectCount(annotation_handle);
+
+        if count <= 0 {
+            // Pdfium empties the appearance stream when the last object is removed,
+            // so there is nothing to rebuild.
+
+            return Ok(());
+        }
+
+        let mut rect = FS_RECTF {
+            left: 0_f32,
+            bottom: 0_f32,
+            right: 0_f32,
+            top: 0_f32,
+        };
+
+        if !bindings.is_true(bindings.FPDFAnnot_GetRect(annotation_handle, &mut rect)) {
+            return Err(bindings.error_in(
+                "FPDFAnnot_GetRect",
+                PdfiumError::PdfiumLibraryInternalError(PdfiumInternalError::Unknown),
+            ));
+        }
+
+        let mut required = rect;
+
+        let mut first_object_handle = None;
+
+        for index in 0..count {
+            let object_handle = bindings.FPDFAnnot_GetObject(annotation_handle, index);
+
+            if object_handle.is_null() {
+                continue;
+            }
+
+            first_object_handle.get_or_insert(object_handle);
+
+            let mut left = 0_f32;
+            let mut bottom = 0_f32;
+            let mut right = 0_f32;
+            let mut top = 0_f32;
+
+            if bindings.is_true(bindings.FPDFPageObj_GetBounds(
+                object_handle,
+                &mut left,
+                &mut bottom,
+                &mut right,
+                &mut top,
+            )) {
+                required.left = required.left.min(left);
+                required.bottom = required.bottom.min(bottom);
+                required.right = required.right.max(right);
+                required.top = required.top.max(top);
+            }
+        }
+
+        // Pdfium maps an appearance stream's bounding box onto the annotation's bounds;
+        // setting the bounds also updates the bounding box, keeping the two in step.
+
+        if (required.left < rect.left
+            || required.bottom < rect.bottom
+            || required.right > rect.right
+            || required.top > rect.top)
+            && !bindings.is_true(bindings.FPDFAnnot_SetRect(annotation_handle, &required))
+        {
+            return Err(bindings.error_in(
+                "FPDFAnnot_SetRect",
+                PdfiumError::PdfiumLibraryInternalError(PdfiumInternalError::Unknown),
+            ));
+        }
+
+        // Updating any single object causes Pdfium to regenerate the content of the entire
+        // appearance stream from all the objects in the annotation.
+
+        match first_object_handle {
+            Some(object_handle) => {
+                if bindings
+                    .is_true(bindings.FPDFAnnot_UpdateObject(annotation_handle, object_handle))
+                {
+                    Ok(())
+                } else {
+                    Err(bindings.error_in(
+                        "FPDFAnnot_UpdateObject",
+                        PdfiumError::PdfiumLibraryInternalError(PdfiumInternalError::Unknown),
+                    ))
+                }
+            }
+            None => Ok(()),
+        }
+    }
 }
 
 impl<'a> PdfPageObjectsPrivate<'a> for PdfPageAnnotationObjects<'a> {
@@ -87,8 +209,9 @@ impl<'a> PdfPageObjectsPrivate<'a> for PdfPageAnnotationObjects<'a> {
             .FPDFAnnot_GetObject(self.annotation_handle(), index as c_int);
 
         if object_handle.is_null() {
-            Err(PdfiumError::PdfiumLibraryInternalError(
-                PdfiumInternalError::Unknown,
+            Err(self.bindings().error_in(
+                "FPDFAnnot_GetObject",
+                PdfiumError::PdfiumLibraryInternalError(PdfiumInternalError::Unknown),
             ))
         } else {
             Ok(PdfPageObject::from_pdfium(
@@ -119,4 +242,141 @@ impl<'a> PdfPageObjectsPrivate<'a> for PdfPageAnnotationObjects<'a> {
     ) -> Result<PdfPageObject<'a>, PdfiumError> {
         object.remove_object_from_annotation().map(|_| object)
     }
+
+    fn remove_objects_if_impl(
+        &mut self,
+        predicate: &dyn Fn(&PdfPageObject) -> bool,
+    ) -> Result<usize, PdfiumError> {
+        let mut removed = 0;
+
+        // Walk the collection backwards so that removing an object does not shift the
+        // index positions of the objects we have yet to visit.
+
+        for index in (0..self.len()).rev() {
+            let mut object = self.get(index)?;
+
+            if predicate(&object) {
+                if self.bindings.is_true(
+                    self.bindings
+                        .FPDFAnnot_RemoveObject(self.annotation_handle, index as c_int),
+                ) {
+                    // The removed object is no longer owned by the annotation, so its memory
+                    // will be released when it is dropped.
+
+                    object.set_ownership(PdfPageObjectOwnership::unowned());
+
+                    removed += 1;
+                } else {
+                    return Err(self.bindings.error_in(
+                        "FPDFAnnot_RemoveObject",
+                        PdfiumError::PdfiumLibraryInternalError(PdfiumInternalError::Unknown),
+                    ));
+                }
+            }
+        }
+
+        if removed > 0 {
+            Self::regenerate_appearance_for_annotation(self.annotation_handle, self.bindings)?;
+            self.regenerate_content_after_mutation()?;
+        }
+
+        Ok(removed)
+    }
+
+    #[inline]
+    fn move_object_impl(
+        &mut self,
+        _from: PdfPageObjectIndex,
+        _to: PdfPageObjectIndex,
+    ) -> Result<(), PdfiumError> {
+        // Pdfium destroys objects removed from an annotation, so they cannot be re-appended
+        // in a different order.
+
+        Err(PdfiumError::AnnotationObjectsCannotBeReordered)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::utils::test::test_bind_to_pdfium;
+
+    #[test]
+    fn test_appearance_stream_survives_save_and_reload() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        let mut page = document
+            .pages_mut()
+            .create_page_at_start(PdfPagePaperSize::a4())?;
+
+        let mut annotation = page
+            .annotations_mut()
+            .create_stamp_annotation_at(PdfRect::new_from_values(100.0, 100.0, 200.0, 200.0))?;
+
+        // The second object extends beyond the annotation's bounds, so the bounds should be
+        // enlarged to contain it.
+
+        for rect in [
+            PdfRect::new_from_values(110.0, 110.0, 150.0, 150.0),
+            PdfRect::new_from_values(160.0, 160.0, 240.0, 260.0),
+        ] {
+            let object = PdfPagePathObject::new_rect(
+                &document,
+                rect,
+                Some(PdfColor::BLUE),
+                Some(PdfPoints::new(2.0)),
+                Some(PdfColor::RED),
+            )?;
+
+            annotation.objects_mut().add_path_object(object)?;
+        }
+
+        assert!(annotation.objects().has_appearance_stream());
+
+        let bounds = annotation.bounds()?;
+
+        assert!(bounds.right().value >= 240.0);
+        assert!(bounds.top().value >= 260.0);
+
+        let render_config = PdfRenderConfig::new()
+            .set_target_width(300)
+            .render_annotations(true);
+
+        let before = page.render_with_config(&render_config)?.as_raw_bytes();
+
+        let bytes = document.save_to_bytes()?;
+
+        drop(page);
+
+        let document = pdfium.load_pdf_from_byte_vec(bytes, None)?;
+
+        let mut page = document.pages().get(0)?;
+
+        let after = page.render_with_config(&render_config)?.as_raw_bytes();
+
+        assert_eq!(before, after);
+
+        let mut annotation = page.annotations_mut().get(0)?;
+
+        assert!(annotation.objects().has_appearance_stream());
+        assert_eq!(annotation.objects().len(), 2);
+
+        // Removing an object should rebuild the appearance stream without it.
+
+        annotation
+            .as_stamp_annotation_mut()
+            .unwrap()
+            .objects_mut()
+            .remove_object_at_index(1)?;
+
+        assert_eq!(annotation.objects().len(), 1);
+        assert_ne!(
+            page.render_with_config(&render_config)?.as_raw_bytes(),
+            after
+        );
+
+        Ok(())
+    }
 }
@@ -15,7 +15,9 @@ pub(crate) mod internal {
     use crate::bindings::PdfiumLibraryBindings;
     use crate::error::{PdfiumError, PdfiumInternalError};
     use crate::pdf::color::PdfColor;
+    use crate::pdf::date::PdfDate;
     use crate::pdf::document::page::annotation::attachment_points::PdfPageAnnotationAttachmentPoints;
+    use crate::pdf::document::page::annotation::flags::PdfPageAnnotationFlags;
     use crate::pdf::document::page::annotation::objects::PdfPageAnnotationObjects;
     use crate::pdf::document::page::annotation::{PdfPageAnnotationCommon, PdfPageAnnotationType};
     use crate::pdf::points::PdfPoints;
@@ -24,7 +26,7 @@ pub(crate) mod internal {
     use crate::utils::mem::create_byte_buffer;
     use crate::utils::utf16le::get_string_from_pdfium_utf16le_bytes;
     use chrono::{DateTime, Utc};
-    use std::os::raw::c_uint;
+    use std::os::raw::{c_int, c_uint};
 
     /// Internal crate-specific functionality common to all [PdfPageAnnotation] objects.
     pub trait PdfPageAnnotationPrivate<'a>: PdfPageAnnotationCommon {
@@ -123,8 +125,9 @@ pub(crate) mod internal {
             {
                 Ok(())
             } else {
-                Err(PdfiumError::PdfiumLibraryInternalError(
-                    PdfiumInternalError::Unknown,
+                Err(self.bindings().error_in(
+                    "FPDFAnnot_SetStringValue_str",
+                    PdfiumError::PdfiumLibraryInternalError(PdfiumInternalError::Unknown),
                 ))
             }
         }
@@ -159,8 +162,9 @@ pub(crate) mod internal {
             ) {
                 self.set_string_value("M", &date_time_to_pdf_string(Utc::now()))
             } else {
-                Err(PdfiumError::PdfiumLibraryInternalError(
-                    PdfiumInternalError::Unknown,
+                Err(self.bindings().error_in(
+                    "FPDFAnnot_SetRect",
+                    PdfiumError::PdfiumLibraryInternalError(PdfiumInternalError::Unknown),
                 ))
             }
         }
@@ -258,6 +262,49 @@ pub(crate) mod internal {
             self.set_string_value("M", &date_time_to_pdf_string(date))
         }
 
+        /// Internal implementation of [PdfPageAnnotationCommon::creation_pdf_date()].
+        #[inline]
+        fn creation_pdf_date_impl(&self) -> Option<PdfDate> {
+            self.creation_date_impl()
+                .and_then(|date| PdfDate::from_pdf_string(&date).ok())
+        }
+
+        /// Internal implementation of [PdfPageAnnotationCommon::set_creation_pdf_date()].
+        #[inline]
+        fn set_creation_pdf_date_impl(&mut self, date: PdfDate) -> Result<(), PdfiumError> {
+            self.set_string_value("CreationDate", &date.to_pdf_string())
+        }
+
+        /// Internal implementation of [PdfPageAnnotationCommon::modification_pdf_date()].
+        #[inline]
+        fn modification_pdf_date_impl(&self) -> Option<PdfDate> {
+            self.modification_date_impl()
+                .and_then(|date| PdfDate::from_pdf_string(&date).ok())
+        }
+
+        /// Internal implementation of [PdfPageAnnotationCommon::set_modification_pdf_date()].
+        #[inline]
+        fn set_modification_pdf_date_impl(&mut self, date: PdfDate) -> Result<(), PdfiumError> {
+            self.set_string_value("M", &date.to_pdf_string())
+        }
+
+        /// Internal implementation of [PdfPageAnnotationCommon::flags()].
+        #[inline]
+        fn flags_impl(&self) -> PdfPageAnnotationFlags {
+            PdfPageAnnotationFlags::from_bits_retain(
+                self.bindings().FPDFAnnot_GetFlags(self.handle()) as u32,
+            )
+        }
+
+        /// Internal implementation of [PdfPageAnnotationCommon::set_flags()].
+        #[inline]
+        fn set_flags_impl(&mut self, flags: PdfPageAnnotationFlags) -> Result<(), PdfiumError> {
+            self.bindings().to_result(
+                self.bindings()
+                    .FPDFAnnot_SetFlags(self.handle(), flags.bits() as c_int),
+            )
+        }
+
         /// Internal implementation of [PdfPageAnnotationCommon::is_markup_annotation()].
         #[inline]
         fn is_markup_annotation_impl(&self) -> bool {
@@ -314,8 +361,9 @@ pub(crate) mod internal {
                 {
                     Ok(PdfColor::new(r as u8, g as u8, b as u8, a as u8))
                 } else {
-                    Err(PdfiumError::PdfiumLibraryInternalError(
-                        PdfiumInternalError::Unknown,
+                    Err(self.bindings().error_in(
+                        "FPDFPageObj_GetFillColor",
+                        PdfiumError::PdfiumLibraryInternalError(PdfiumInternalError::Unknown),
                     ))
                 }
             }
@@ -351,8 +399,9 @@ pub(crate) mod internal {
                 {
                     Ok(())
                 } else {
-                    Err(PdfiumError::PdfiumLibraryInternalError(
-                        PdfiumInternalError::Unknown,
+                    Err(self.bindings().error_in(
+                        "FPDFPageObj_SetFillColor",
+                        PdfiumError::PdfiumLibraryInternalError(PdfiumInternalError::Unknown),
                     ))
                 }
             }
@@ -396,8 +445,9 @@ pub(crate) mod internal {
                 {
                     Ok(PdfColor::new(r as u8, g as u8, b as u8, a as u8))
                 } else {
-                    Err(PdfiumError::PdfiumLibraryInternalError(
-                        PdfiumInternalError::Unknown,
+                    Err(self.bindings().error_in(
+                        "FPDFPageObj_GetStrokeColor",
+                        PdfiumError::PdfiumLibraryInternalError(PdfiumInternalError::Unknown),
                     ))
                 }
             }
@@ -433,8 +483,9 @@ pub(crate) mod internal {
                 {
                     Ok(())
                 } else {
-                    Err(PdfiumError::PdfiumLibraryInternalError(
-                        PdfiumInternalError::Unknown,
+                    Err(self.bindings().error_in(
+                        "FPDFPageObj_SetStrokeColor",
+                        PdfiumError::PdfiumLibraryInternalError(PdfiumInternalError::Unknown),
                     ))
                 }
             }
@@ -5,10 +5,12 @@ use crate::bindgen::{FPDF_ANNOTATION, FPDF_DOCUMENT, FPDF_FORMHANDLE, FPDF_PAGE}
 use crate::bindings::PdfiumLibraryBindings;
 use crate::error::{PdfiumError, PdfiumInternalError};
 use crate::pdf::color::PdfColor;
+use crate::pdf::document::fonts::PdfFontBuiltin;
+use crate::pdf::document::page::annotation::flags::PdfPageAnnotationFlags;
 use crate::pdf::document::page::annotation::free_text::PdfPageFreeTextAnnotation;
 use crate::pdf::document::page::annotation::highlight::PdfPageHighlightAnnotation;
 use crate::pdf::document::page::annotation::ink::PdfPageInkAnnotation;
-use crate::pdf::document::page::annotation::link::PdfPageLinkAnnotation;
+use crate::pdf::document::page::annotation::link::{PdfLinkTarget, PdfPageLinkAnnotation};
 use crate::pdf::document::page::annotation::popup::PdfPagePopupAnnotation;
 use crate::pdf::document::page::annotation::private::internal::PdfPageAnnotationPrivate;
 use crate::pdf::document::page::annotation::square::PdfPageSquareAnnotation;
@@ -21,8 +23,12 @@ use crate::pdf::document::page::annotation::{
     PdfPageAnnotation, PdfPageAnnotationCommon, PdfPageAnnotationType,
 };
 use crate::pdf::document::page::object::{PdfPageObject, PdfPageObjectCommon};
+use crate::pdf::document::page::text::chars::PdfPageTextCharIndex;
+use crate::pdf::document::page::text::PdfPageText;
 use crate::pdf::document::page::{PdfPage, PdfPageContentRegenerationStrategy, PdfPageIndexCache};
+use crate::pdf::points::PdfPoints;
 use crate::pdf::quad_points::PdfQuadPoints;
+use crate::pdf::rect::PdfRect;
 use chrono::prelude::*;
 use std::ops::Range;
 use std::os::raw::c_int;
@@ -104,8 +110,9 @@ impl<'a> PdfPageAnnotations<'a> {
             .FPDFPage_GetAnnot(self.page_handle, index as c_int);
 
         if annotation_handle.is_null() {
-            Err(PdfiumError::PdfiumLibraryInternalError(
-                PdfiumInternalError::Unknown,
+            Err(self.bindings().error_in(
+                "FPDFPage_GetAnnot",
+                PdfiumError::PdfiumLibraryInternalError(PdfiumInternalError::Unknown),
             ))
         } else {
             Ok(PdfPageAnnotation::from_pdfium(
@@ -165,8 +172,9 @@ impl<'a> PdfPageAnnotations<'a> {
             .FPDFPage_CreateAnnot(self.page_handle(), annotation_type.as_pdfium());
 
         if handle.is_null() {
-            Err(PdfiumError::PdfiumLibraryInternalError(
-                PdfiumInternalError::Unknown,
+            Err(self.bindings().error_in(
+                "FPDFPage_CreateAnnot",
+                PdfiumError::PdfiumLibraryInternalError(PdfiumInternalError::Unknown),
             ))
         } else {
             let mut annotation = constructor(
@@ -224,6 +232,37 @@ impl<'a> PdfPageAnnotations<'a> {
         Ok(annotation)
     }
 
+    /// Creates a new [PdfPageFreeTextAnnotation] in this [PdfPageAnnotations] collection,
+    /// positioned at the given bounds and containing the given text drawn in the given
+    /// built-in font, font size, and color, returning the newly created annotation.
+    ///
+    /// The annotation's `/Contents` and `/DA` entries are set consistently with one another,
+    /// and a normal appearance stream is generated so that the annotation renders in viewers
+    /// that do not regenerate free text appearances themselves, including Pdfium.
+    /// See [PdfPageFreeTextAnnotation::set_text()] for details of how the text is laid out.
+    ///
+    /// If the containing `PdfPage` has a content regeneration strategy of
+    /// `PdfPageContentRegenerationStrategy::AutomaticOnEveryChange` then content regeneration
+    /// will be triggered on the page.
+    pub fn create_free_text_annotation_at(
+        &mut self,
+        bounds: PdfRect,
+        text: &str,
+        font: PdfFontBuiltin,
+        font_size: PdfPoints,
+        color: PdfColor,
+    ) -> Result<PdfPageFreeTextAnnotation<'a>, PdfiumError> {
+        let mut annotation = self.create_annotation(
+            PdfPageAnnotationType::FreeText,
+            PdfPageFreeTextAnnotation::from_pdfium,
+        )?;
+
+        annotation.set_bounds(bounds)?;
+        annotation.set_text(text, font, font_size, color)?;
+
+        Ok(annotation)
+    }
+
     /// Creates a new [PdfPageHighlightAnnotation] annotation in this [PdfPageAnnotations] collection,
     /// returning the newly created annotation.
     ///
@@ -274,6 +313,33 @@ impl<'a> PdfPageAnnotations<'a> {
         Ok(annotation)
     }
 
+    /// Creates a new [PdfPageLinkAnnotation] covering the given bounds in this
+    /// [PdfPageAnnotations] collection, linking to the given [PdfLinkTarget], and returning
+    /// the newly created annotation. The annotation's border is hidden.
+    ///
+    /// See [PdfPageLinkAnnotation::set_target()] for details of how page destinations
+    /// are written.
+    ///
+    /// If the containing `PdfPage` has a content regeneration strategy of
+    /// `PdfPageContentRegenerationStrategy::AutomaticOnEveryChange` then content regeneration
+    /// will be triggered on the page.
+    pub fn create_link_annotation_at(
+        &mut self,
+        bounds: PdfRect,
+        target: PdfLinkTarget,
+    ) -> Result<PdfPageLinkAnnotation<'a>, PdfiumError> {
+        let mut annotation = self.create_annotation(
+            PdfPageAnnotationType::Link,
+            PdfPageLinkAnnotation::from_pdfium,
+        )?;
+
+        annotation.set_bounds(bounds)?;
+        annotation.hide_border()?;
+        annotation.set_target(target)?;
+
+        Ok(annotation)
+    }
+
     /// Creates a new [PdfPagePopupAnnotation] annotation in this [PdfPageAnnotations] collection,
     /// returning the newly created annotation.
     ///
@@ -332,6 +398,28 @@ impl<'a> PdfPageAnnotations<'a> {
         )
     }
 
+    /// Creates a new [PdfPageStampAnnotation] annotation positioned at the given bounds in this
+    /// [PdfPageAnnotations] collection, returning the newly created annotation.
+    ///
+    /// Image and text objects added to the returned annotation's `objects_mut()` collection
+    /// will be included in the annotation's generated appearance stream, so the stamp
+    /// renders in any viewer. Since Pdfium clips the generated appearance stream to the
+    /// annotation's bounds, objects should be positioned within the given bounds.
+    ///
+    /// If the containing `PdfPage` has a content regeneration strategy of
+    /// `PdfPageContentRegenerationStrategy::AutomaticOnEveryChange` then content regeneration
+    /// will be triggered on the page.
+    pub fn create_stamp_annotation_at(
+        &mut self,
+        bounds: PdfRect,
+    ) -> Result<PdfPageStampAnnotation<'a>, PdfiumError> {
+        let mut annotation = self.create_stamp_annotation()?;
+
+        annotation.set_bounds(bounds)?;
+
+        Ok(annotation)
+    }
+
     /// Creates a new [PdfPageStrikeoutAnnotation] annotation in this [PdfPageAnnotations] collection,
     /// returning the newly created annotation.
     ///
@@ -555,8 +643,137 @@ impl<'a> PdfPageAnnotations<'a> {
         Ok(annotation)
     }
 
+    /// Creates a new [PdfPageHighlightAnnotation] annotation covering the given range of
+    /// characters on the containing `PdfPage`, coloring it with the given [PdfColor].
+    ///
+    /// The annotation will contain one attachment point for each bounding rectangle of the
+    /// character range reported by `PdfPageText::rects_for_range()`, so a range of text that
+    /// spans multiple lines will be highlighted line by line rather than as a single block.
+    ///
+    /// Returns [PdfiumError::CharIndexOutOfBounds] if the given range does not cover any
+    /// characters on the page.
+    ///
+    /// If the containing `PdfPage` has a content regeneration strategy of
+    /// `PdfPageContentRegenerationStrategy::AutomaticOnEveryChange` then content regeneration
+    /// will be triggered on the page.
+    pub fn create_highlight_annotation_over_text_range(
+        &mut self,
+        start: PdfPageTextCharIndex,
+        count: PdfPageTextCharIndex,
+        color: PdfColor,
+    ) -> Result<PdfPageHighlightAnnotation<'a>, PdfiumError> {
+        // We cannot borrow the page's PdfPageText object while this collection is borrowed
+        // mutably, so we load the text page directly.
+
+        let text_page_handle = self.bindings().FPDFText_LoadPage(self.page_handle());
+
+        if text_page_handle.is_null() {
+            return Err(self.bindings().error_in(
+                "FPDFText_LoadPage",
+                PdfiumError::PdfiumLibraryInternalError(PdfiumInternalError::Unknown),
+            ));
+        }
+
+        let rects = PdfPageText::rects_for_range_for_handle(
+            text_page_handle,
+            start,
+            count,
+            self.bindings(),
+        );
+
+        self.bindings().FPDFText_ClosePage(text_page_handle);
+
+        let bounds = rects
+            .iter()
+            .copied()
+            .reduce(|bounds, rect| {
+                PdfRect::new_from_values(
+                    bounds.bottom().value.min(rect.bottom().value),
+                    bounds.left().value.min(rect.left().value),
+                    bounds.top().value.max(rect.top().value),
+                    bounds.right().value.max(rect.right().value),
+                )
+            })
+            .ok_or(PdfiumError::CharIndexOutOfBounds)?;
+
+        let mut annotation = self.create_highlight_annotation()?;
+
+        annotation.set_bounds(bounds)?;
+        annotation.set_stroke_color(color)?;
+
+        for rect in rects.iter() {
+            // Conforming readers expect text markup quadrilaterals to be ordered
+            // top-left, top-right, bottom-left, bottom-right.
+
+            annotation
+                .attachment_points_mut()
+                .create_attachment_point_at_end(PdfQuadPoints::new(
+                    rect.left(),
+                    rect.top(),
+                    rect.right(),
+                    rect.top(),
+                    rect.left(),
+                    rect.bottom(),
+                    rect.right(),
+                    rect.bottom(),
+                ))?;
+        }
+
+        Ok(annotation)
+    }
+
+    /// Returns the index of the given [PdfPageAnnotation] in this [PdfPageAnnotations] collection,
+    /// or `None` if the annotation is not part of this collection.
+    ///
+    /// Newly created annotations are always added to the end of the collection, so the index
+    /// of an annotation returned by any of the `create_*()` functions is `len() - 1` until
+    /// another annotation is created or deleted.
+    #[inline]
+    pub fn index_of(&self, annotation: &PdfPageAnnotation) -> Option<PdfPageAnnotationIndex> {
+        let index = self
+            .bindings()
+            .FPDFPage_GetAnnotIndex(self.page_handle(), annotation.handle());
+
+        if index < 0 {
+            None
+        } else {
+            Some(index as PdfPageAnnotationIndex)
+        }
+    }
+
+    /// Returns the index of the top-most visible annotation in this [PdfPageAnnotations]
+    /// collection whose bounding rectangle contains the given position on the containing
+    /// `PdfPage`, or `None` if there is no such annotation.
+    ///
+    /// Annotations later in the collection are drawn on top of annotations earlier in the
+    /// collection, so the collection is searched from last to first. Annotations flagged as
+    /// [PdfPageAnnotationFlags::HIDDEN] or [PdfPageAnnotationFlags::NO_VIEW] are skipped.
+    ///
+    /// This is useful for hit-testing pointer events in an interactive viewer. Use
+    /// `PdfRenderConfig::pixel_to_page()` to convert a pointer position in a rendered bitmap
+    /// to a position on the containing page.
+    pub fn annotation_at_point(
+        &self,
+        x: PdfPoints,
+        y: PdfPoints,
+    ) -> Option<PdfPageAnnotationIndex> {
+        (0..self.len()).rev().find(|index| {
+            self.get(*index)
+                .map(|annotation| {
+                    !annotation.flags().intersects(
+                        PdfPageAnnotationFlags::HIDDEN | PdfPageAnnotationFlags::NO_VIEW,
+                    ) && annotation
+                        .bounds()
+                        .map(|bounds| bounds.contains(x, y))
+                        .unwrap_or(false)
+                })
+                .unwrap_or(false)
+        })
+    }
+
     /// Removes the given [PdfPageAnnotation] from this [PdfPageAnnotations] collection,
-    /// consuming the [PdfPageAnnotation].
+    /// consuming the [PdfPageAnnotation] and returning the index it was removed from.
+    /// The index of every following annotation in the collection decreases by one.
     ///
     /// If the containing `PdfPage` has a content regeneration strategy of
     /// `PdfPageContentRegenerationStrategy::AutomaticOnEveryChange` then content regeneration
@@ -564,18 +781,34 @@ impl<'a> PdfPageAnnotations<'a> {
     pub fn delete_annotation(
         &mut self,
         annotation: PdfPageAnnotation<'a>,
-    ) -> Result<(), PdfiumError> {
+    ) -> Result<PdfPageAnnotationIndex, PdfiumError> {
         let index = self
-            .bindings()
-            .FPDFPage_GetAnnotIndex(self.page_handle(), annotation.handle());
+            .index_of(&annotation)
+            .ok_or(PdfiumError::PageAnnotationIndexOutOfBounds)?;
 
-        if index == -1 {
+        self.delete_annotation_at_index(index).map(|()| index)
+    }
+
+    /// Removes the [PdfPageAnnotation] at the given index from this [PdfPageAnnotations]
+    /// collection. The index of every following annotation in the collection decreases by one.
+    ///
+    /// Any [PdfPageAnnotation] previously retrieved for the removed annotation remains valid,
+    /// but is no longer attached to the page.
+    ///
+    /// If the containing `PdfPage` has a content regeneration strategy of
+    /// `PdfPageContentRegenerationStrategy::AutomaticOnEveryChange` then content regeneration
+    /// will be triggered on the page.
+    pub fn delete_annotation_at_index(
+        &mut self,
+        index: PdfPageAnnotationIndex,
+    ) -> Result<(), PdfiumError> {
+        if index >= self.len() {
             return Err(PdfiumError::PageAnnotationIndexOutOfBounds);
         }
 
         if self.bindings().is_true(
             self.bindings()
-                .FPDFPage_RemoveAnnot(self.page_handle(), index),
+                .FPDFPage_RemoveAnnot(self.page_handle(), index as c_int),
         ) {
             if let Some(content_regeneration_strategy) =
                 PdfPageIndexCache::get_content_regeneration_strategy_for_page(
@@ -597,11 +830,19 @@ impl<'a> PdfPageAnnotations<'a> {
                 Err(PdfiumError::SourcePageIndexNotInCache)
             }
         } else {
-            Err(PdfiumError::PdfiumLibraryInternalError(
-                PdfiumInternalError::Unknown,
+            Err(self.bindings().error_in(
+                "FPDFPage_RemoveAnnot",
+                PdfiumError::PdfiumLibraryInternalError(PdfiumInternalError::Unknown),
             ))
         }
     }
+
+    /// Returns a [PdfPageAnnotationsCursor] over all the annotations in this
+    /// [PdfPageAnnotations] collection, allowing annotations to be deleted during iteration.
+    #[inline]
+    pub fn cursor(&mut self) -> PdfPageAnnotationsCursor<'_, 'a> {
+        PdfPageAnnotationsCursor::new(self)
+    }
 }
 
 /// An iterator over all the [PdfPageAnnotation] objects in a [PdfPageAnnotations] collection.
@@ -631,3 +872,450 @@ impl<'a> Iterator for PdfPageAnnotationsIterator<'a> {
         next.ok()
     }
 }
+
+/// A cursor over all the [PdfPageAnnotation] objects in a [PdfPageAnnotations] collection
+/// that allows annotations to be deleted during iteration.
+///
+/// Annotations are retrieved from Pdfium by index as the cursor advances, so annotations
+/// created while the cursor is in use are visited once the cursor reaches the end of the
+/// original collection. Calling [PdfPageAnnotationsCursor::delete_at_cursor()] removes the
+/// annotation most recently returned by the cursor and adjusts the cursor's position so that
+/// every remaining annotation is still visited exactly once.
+///
+/// The cursor holds a mutable borrow of its [PdfPageAnnotations] collection, so no other
+/// iterator over the collection can be used at the same time. Any [PdfPageAnnotation] returned
+/// by the cursor remains valid after it is deleted, but is no longer attached to the page.
+pub struct PdfPageAnnotationsCursor<'b, 'a> {
+    annotations: &'b mut PdfPageAnnotations<'a>,
+    current_index: Option<PdfPageAnnotationIndex>,
+    next_index: PdfPageAnnotationIndex,
+}
+
+impl<'b, 'a> PdfPageAnnotationsCursor<'b, 'a> {
+    #[inline]
+    pub(crate) fn new(annotations: &'b mut PdfPageAnnotations<'a>) -> Self {
+        PdfPageAnnotationsCursor {
+            annotations,
+            current_index: None,
+            next_index: 0,
+        }
+    }
+
+    /// Returns the index of the annotation most recently returned by this cursor,
+    /// or `None` if the cursor has not yet returned an annotation or the annotation
+    /// has been deleted.
+    #[inline]
+    pub fn index(&self) -> Option<PdfPageAnnotationIndex> {
+        self.current_index
+    }
+
+    /// Deletes the annotation most recently returned by this cursor from the containing
+    /// [PdfPageAnnotations] collection, returning the index it was removed from. The next
+    /// annotation returned by the cursor will be the annotation that followed the deleted one.
+    ///
+    /// Returns [PdfiumError::CursorNotPositionedOnItem] if the cursor has not yet returned
+    /// an annotation, or if the annotation has already been deleted.
+    pub fn delete_at_cursor(&mut self) -> Result<PdfPageAnnotationIndex, PdfiumError> {
+        let index = self
+            .current_index
+            .ok_or(PdfiumError::CursorNotPositionedOnItem)?;
+
+        self.annotations.delete_annotation_at_index(index)?;
+
+        self.current_index = None;
+        self.next_index = index;
+
+        Ok(index)
+    }
+}
+
+impl<'b, 'a> Iterator for PdfPageAnnotationsCursor<'b, 'a> {
+    type Item = PdfPageAnnotation<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let next = self.annotations.get(self.next_index).ok();
+
+        self.current_index = next.as_ref().map(|_| self.next_index);
+
+        if next.is_some() {
+            self.next_index += 1;
+        }
+
+        next
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::utils::test::test_bind_to_pdfium;
+
+    #[test]
+    fn test_cursor_delete_during_iteration() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        let mut page = document
+            .pages_mut()
+            .create_page_at_start(PdfPagePaperSize::a4())?;
+
+        for contents in ["first", "second", "third"] {
+            page.annotations_mut()
+                .create_square_annotation()?
+                .set_contents(contents)?;
+
+            assert_eq!(
+                page.annotations()
+                    .index_of(&page.annotations().last()?)
+                    .map(|index| index + 1),
+                Some(page.annotations().len())
+            );
+        }
+
+        let mut visited = Vec::new();
+
+        let mut cursor = page.annotations_mut().cursor();
+
+        while let Some(annotation) = cursor.next() {
+            let contents = annotation.contents().unwrap_or_default();
+
+            if contents == "second" {
+                assert_eq!(cursor.delete_at_cursor()?, 1);
+                assert!(matches!(
+                    cursor.delete_at_cursor(),
+                    Err(PdfiumError::CursorNotPositionedOnItem)
+                ));
+            }
+
+            visited.push(contents);
+        }
+
+        assert_eq!(visited, vec!["first", "second", "third"]);
+
+        assert_eq!(
+            page.annotations()
+                .iter()
+                .map(|annotation| annotation.contents().unwrap_or_default())
+                .collect::<Vec<_>>(),
+            vec!["first", "third"]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_annotation_at_point() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        let mut page = document
+            .pages_mut()
+            .create_page_at_start(PdfPagePaperSize::a4())?;
+
+        for (contents, bounds) in [
+            (
+                "lower",
+                PdfRect::new_from_values(100.0, 100.0, 300.0, 300.0),
+            ),
+            (
+                "upper",
+                PdfRect::new_from_values(200.0, 200.0, 400.0, 400.0),
+            ),
+            (
+                "hidden",
+                PdfRect::new_from_values(250.0, 250.0, 350.0, 350.0),
+            ),
+        ] {
+            let mut annotation = page.annotations_mut().create_square_annotation()?;
+
+            annotation.set_contents(contents)?;
+            annotation.set_bounds(bounds)?;
+
+            if contents == "hidden" {
+                annotation.set_flags(PdfPageAnnotationFlags::HIDDEN)?;
+            }
+        }
+
+        let annotations = page.annotations();
+
+        let at =
+            |x: f32, y: f32| annotations.annotation_at_point(PdfPoints::new(x), PdfPoints::new(y));
+
+        // Only the lower annotation covers this point.
+
+        assert_eq!(at(150.0, 150.0), Some(0));
+
+        // Both visible annotations overlap here; the one added last is top-most. The hidden
+        // annotation, although added after both, is skipped.
+
+        assert_eq!(at(275.0, 275.0), Some(1));
+
+        assert_eq!(at(500.0, 500.0), None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_multi_line_highlight_round_trip() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let document = pdfium.load_pdf_from_file("./test/text-test.pdf", None)?;
+
+        let mut page = document.pages().get(0)?;
+
+        // Find a range of characters spanning at least two lines.
+
+        let (start, count, rects) = {
+            let text = page.text()?;
+
+            let count = (text.len() as PdfPageTextCharIndex).min(200);
+
+            (0, count, text.rects_for_range(0, count))
+        };
+
+        assert!(rects.len() > 1);
+
+        let annotation = page
+            .annotations_mut()
+            .create_highlight_annotation_over_text_range(start, count, PdfColor::YELLOW)?;
+
+        assert_eq!(annotation.attachment_points().len(), rects.len());
+
+        let bytes = document.save_to_bytes()?;
+
+        drop(page);
+
+        let document = pdfium.load_pdf_from_byte_vec(bytes, None)?;
+
+        let page = document.pages().get(0)?;
+
+        let annotation = page
+            .annotations()
+            .iter()
+            .find(|annotation| annotation.annotation_type() == PdfPageAnnotationType::Highlight)
+            .unwrap();
+
+        let attachment_points = annotation.attachment_points();
+
+        assert_eq!(attachment_points.len(), rects.len());
+
+        for (quad_points, rect) in attachment_points.iter().zip(rects.iter()) {
+            assert!((quad_points.left().value - rect.left().value).abs() < 0.01);
+            assert!((quad_points.right().value - rect.right().value).abs() < 0.01);
+            assert!((quad_points.top().value - rect.top().value).abs() < 0.01);
+            assert!((quad_points.bottom().value - rect.bottom().value).abs() < 0.01);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_annotation_metadata_round_trip() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        let mut page = document
+            .pages_mut()
+            .create_page_at_start(PdfPagePaperSize::a4())?;
+
+        let created = PdfDate::new(2024, 2, 29, 7, 5, 9, 5 * 60 + 30)?;
+
+        let modified = PdfDate::new_utc(2024, 3, 1, 12, 0, 0)?;
+
+        let mut annotation = page.annotations_mut().create_highlight_annotation()?;
+
+        annotation.set_bounds(PdfRect::new_from_values(100.0, 100.0, 120.0, 200.0))?;
+        annotation.set_author("Reviewer")?;
+        annotation.set_flags(PdfPageAnnotationFlags::PRINT | PdfPageAnnotationFlags::LOCKED)?;
+        annotation.set_creation_pdf_date(created)?;
+
+        // Setting any other string value updates the modification date, so the
+        // modification date must be set last.
+
+        annotation.set_modification_pdf_date(modified)?;
+
+        let bytes = document.save_to_bytes()?;
+
+        drop(page);
+
+        let document = pdfium.load_pdf_from_byte_vec(bytes, None)?;
+
+        let page = document.pages().get(0)?;
+
+        let annotation = page.annotations().get(0)?;
+
+        assert_eq!(
+            annotation.annotation_type(),
+            PdfPageAnnotationType::Highlight
+        );
+        assert_eq!(annotation.author(), Some("Reviewer".to_string()));
+        assert_eq!(annotation.creation_pdf_date(), Some(created));
+        assert_eq!(annotation.modification_pdf_date(), Some(modified));
+        assert_eq!(
+            annotation.flags(),
+            PdfPageAnnotationFlags::PRINT | PdfPageAnnotationFlags::LOCKED
+        );
+        assert!(annotation.popup().is_none());
+        assert!(annotation.in_reply_to().is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_link_annotation_targets_round_trip() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        for _ in 0..2 {
+            document
+                .pages_mut()
+                .create_page_at_end(PdfPagePaperSize::a4())?;
+        }
+
+        let mut page = document.pages().get(0)?;
+
+        page.annotations_mut().create_link_annotation_at(
+            PdfRect::new_from_values(700.0, 50.0, 720.0, 150.0),
+            PdfLinkTarget::PageDestination {
+                page_index: 1,
+                location: Some((PdfPoints::new(0.0), PdfPoints::new(800.0))),
+                zoom: None,
+            },
+        )?;
+
+        page.annotations_mut().create_link_annotation_at(
+            PdfRect::new_from_values(650.0, 50.0, 670.0, 150.0),
+            PdfLinkTarget::Uri("https://example.com/".to_string()),
+        )?;
+
+        assert!(matches!(
+            page.annotations_mut().create_link_annotation_at(
+                PdfRect::new_from_values(600.0, 50.0, 620.0, 150.0),
+                PdfLinkTarget::PageDestination {
+                    page_index: 2,
+                    location: None,
+                    zoom: None,
+                },
+            ),
+            Err(PdfiumError::PageIndexOutOfBounds)
+        ));
+
+        let bytes = document.save_to_bytes()?;
+
+        drop(page);
+
+        let document = pdfium.load_pdf_from_byte_vec(bytes, None)?;
+
+        let page = document.pages().get(0)?;
+
+        let links = page.links();
+
+        let destination = links
+            .iter()
+            .find_map(|link| link.destination())
+            .expect("page destination");
+
+        assert_eq!(destination.page_index()?, 1);
+
+        assert!(links.iter().any(|link| link
+            .action()
+            .and_then(|action| action.as_uri_action().and_then(|uri| uri.uri().ok()))
+            == Some("https://example.com/".to_string())));
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "image_api")]
+    fn test_stamp_and_free_text_annotations_render() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        let mut page = document
+            .pages_mut()
+            .create_page_at_start(PdfPagePaperSize::a4())?;
+
+        // Build a solid red image to place inside the stamp.
+
+        let mut image =
+            PdfBitmap::empty(32, 32, PdfBitmapFormat::BGRA, pdfium.bindings())?.as_image();
+
+        for pixel in image.as_mut_rgba8().unwrap().pixels_mut() {
+            pixel.0 = [255, 0, 0, 255];
+        }
+
+        let stamp_bounds = PdfRect::new_from_values(100.0, 100.0, 200.0, 200.0);
+
+        let mut object = PdfPageImageObject::new_with_size(
+            &document,
+            &image,
+            PdfPoints::new(100.0),
+            PdfPoints::new(100.0),
+        )?;
+
+        object.translate(PdfPoints::new(100.0), PdfPoints::new(100.0))?;
+
+        page.annotations_mut()
+            .create_stamp_annotation_at(stamp_bounds)?
+            .objects_mut()
+            .add_image_object(object)?;
+
+        let free_text = page.annotations_mut().create_free_text_annotation_at(
+            PdfRect::new_from_values(400.0, 100.0, 500.0, 300.0),
+            "Approved (final)",
+            PdfFontBuiltin::HelveticaBold,
+            PdfPoints::new(24.0),
+            PdfColor::BLUE,
+        )?;
+
+        assert_eq!(free_text.contents(), Some("Approved (final)".to_string()));
+        assert_eq!(
+            free_text.default_appearance(),
+            Some("/Helvetica-Bold 24 Tf 0 0 1 rg".to_string())
+        );
+
+        let bytes = document.save_to_bytes()?;
+
+        drop(page);
+
+        let document = pdfium.load_pdf_from_byte_vec(bytes, None)?;
+
+        let page = document.pages().get(0)?;
+
+        assert_eq!(page.annotations().len(), 2);
+
+        // Render at 1 pixel per point and sample the centre of the stamp, then look
+        // for non-white pixels inside the free text annotation.
+
+        let bitmap = page.render_with_config(
+            &PdfRenderConfig::new()
+                .set_target_width(page.width().value as Pixels)
+                .render_annotations(true),
+        )?;
+
+        let pixels = bitmap.as_rgba_bytes();
+
+        let width = bitmap.width() as usize;
+
+        let height = page.height().value;
+
+        let offset_of = |x: f32, y: f32| ((height - y) as usize * width + x as usize) * 4;
+
+        let offset = offset_of(150.0, 150.0);
+
+        assert_eq!(&pixels[offset..offset + 3], &[255, 0, 0]);
+
+        assert!((400..500).any(|x| (210..290).any(|y| {
+            let offset = offset_of(x as f32, y as f32);
+
+            pixels[offset..offset + 3] != [255, 255, 255]
+        })));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,610 @@
+//! Defines the [PdfPageColorAnalysis] struct, classifying the content of a single `PdfPage`
+//! as monochrome, grayscale, or color, as returned by the `PdfPage::analyze_color()` function.
+
+use crate::error::PdfiumError;
+use crate::pdf::bitmap::PdfBitmapFormat;
+use crate::pdf::color::PdfColor;
+use crate::pdf::color_space::PdfColorSpace;
+use crate::pdf::document::page::object::text::PdfPageTextRenderMode;
+use crate::pdf::document::page::object::{PdfPageObject, PdfPageObjectCommon};
+use crate::pdf::document::page::objects::common::{PdfPageObjectIndex, PdfPageObjectsCommon};
+use crate::pdf::document::page::render_config::PdfRenderConfig;
+use crate::pdf::document::page::PdfPage;
+
+/// The strategy used by `PdfPage::analyze_color()` to classify the content of a page.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum PdfPageColorAnalysisMode {
+    /// Classifies the page solely by inspecting the fill and stroke colors of the page's
+    /// path and text objects and the color space metadata of the page's image objects.
+    /// The page is never rendered, so analysis is fast.
+    ///
+    /// Image objects in color spaces capable of representing color, shading objects,
+    /// and unsupported objects are conservatively assumed to contain color, even if
+    /// the colors they actually display are all shades of gray.
+    InspectObjects,
+
+    /// Classifies the page by inspecting its objects, falling back to rendering the page
+    /// at a low resolution only if inspection finds objects whose colors cannot be determined
+    /// without rendering, such as images and shadings. This is accurate for all pages,
+    /// and fast for pages containing only text and vector paths.
+    Hybrid,
+
+    /// Classifies the page by rendering it at a low resolution, irrespective of
+    /// the objects it contains. Ink coverage estimates are always available in this mode.
+    Render,
+}
+
+/// Configures the options used by `PdfPage::analyze_color()` when classifying the content
+/// of a page.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct PdfPageColorAnalysisOptions {
+    mode: PdfPageColorAnalysisMode,
+    tolerance: u8,
+    render_resolution: f32,
+}
+
+impl PdfPageColorAnalysisOptions {
+    /// The default tolerance, in 8-bit color channel units, within which colors are
+    /// considered to be gray.
+    pub const DEFAULT_TOLERANCE: u8 = 8;
+
+    /// The default resolution, in dots per inch, at which pages are rendered during analysis.
+    pub const DEFAULT_RENDER_RESOLUTION: f32 = 72.0;
+
+    /// Creates a new [PdfPageColorAnalysisOptions] object using the
+    /// [PdfPageColorAnalysisMode::Hybrid] analysis mode and the default tolerance
+    /// and render resolution.
+    #[inline]
+    pub fn new() -> Self {
+        PdfPageColorAnalysisOptions {
+            mode: PdfPageColorAnalysisMode::Hybrid,
+            tolerance: Self::DEFAULT_TOLERANCE,
+            render_resolution: Self::DEFAULT_RENDER_RESOLUTION,
+        }
+    }
+
+    /// Sets the strategy used to classify the content of the page.
+    #[inline]
+    pub fn mode(mut self, mode: PdfPageColorAnalysisMode) -> Self {
+        self.mode = mode;
+
+        self
+    }
+
+    /// Sets the maximum difference, in 8-bit color channel units, between the red, green,
+    /// and blue channels of a color for the color to be considered a shade of gray. The same
+    /// tolerance determines how close to pure black or pure white a gray must be
+    /// for it to be considered monochrome.
+    #[inline]
+    pub fn tolerance(mut self, tolerance: u8) -> Self {
+        self.tolerance = tolerance;
+
+        self
+    }
+
+    /// Sets the resolution, in dots per inch, at which the page will be rendered
+    /// if rendering is required.
+    #[inline]
+    pub fn render_resolution(mut self, dpi: f32) -> Self {
+        self.render_resolution = dpi;
+
+        self
+    }
+
+    /// Returns the strategy used to classify the content of the page.
+    #[inline]
+    pub fn get_mode(&self) -> PdfPageColorAnalysisMode {
+        self.mode
+    }
+
+    /// Returns the maximum difference, in 8-bit color channel units, between the red, green,
+    /// and blue channels of a color for the color to be considered a shade of gray.
+    #[inline]
+    pub fn get_tolerance(&self) -> u8 {
+        self.tolerance
+    }
+
+    /// Returns the resolution, in dots per inch, at which the page will be rendered
+    /// if rendering is required.
+    #[inline]
+    pub fn get_render_resolution(&self) -> f32 {
+        self.render_resolution
+    }
+}
+
+impl Default for PdfPageColorAnalysisOptions {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// The estimated proportion of a page covered by each of the four process colorants
+/// used in printing.
+///
+/// Each value is in the range `0.0..=1.0`, where 0.0 indicates that the colorant is not used
+/// at all and 1.0 indicates that the colorant covers the entire page at full strength.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct PdfPageInkCoverage {
+    cyan: f32,
+    magenta: f32,
+    yellow: f32,
+    black: f32,
+}
+
+impl PdfPageInkCoverage {
+    /// Returns the estimated coverage of the cyan colorant.
+    #[inline]
+    pub fn cyan(&self) -> f32 {
+        self.cyan
+    }
+
+    /// Returns the estimated coverage of the magenta colorant.
+    #[inline]
+    pub fn magenta(&self) -> f32 {
+        self.magenta
+    }
+
+    /// Returns the estimated coverage of the yellow colorant.
+    #[inline]
+    pub fn yellow(&self) -> f32 {
+        self.yellow
+    }
+
+    /// Returns the estimated coverage of the black colorant.
+    #[inline]
+    pub fn black(&self) -> f32 {
+        self.black
+    }
+}
+
+/// The result of classifying the content of a single `PdfPage` as monochrome, grayscale,
+/// or color, as returned by the `PdfPage::analyze_color()` function.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PdfPageColorAnalysis {
+    is_grayscale: bool,
+    is_monochrome: bool,
+    ink_coverage: Option<PdfPageInkCoverage>,
+    color_object_indices: Vec<PdfPageObjectIndex>,
+}
+
+impl PdfPageColorAnalysis {
+    /// Analyzes the content of the given [PdfPage] using the given options.
+    pub(crate) fn from_page(
+        page: &PdfPage,
+        options: &PdfPageColorAnalysisOptions,
+    ) -> Result<Self, PdfiumError> {
+        let tolerance = options.get_tolerance();
+
+        let mut is_grayscale = true;
+
+        let mut is_monochrome = true;
+
+        let mut is_inconclusive = false;
+
+        let mut color_object_indices = Vec::new();
+
+        let mut uncertain_object_indices = Vec::new();
+
+        for (index, object) in page.objects().iter().enumerate() {
+            let classification = if let PdfPageObject::XObjectForm(form) = &object {
+                form.objects()
+                    .iter_recursive()
+                    .map(|(child, _)| PdfObjectColorClass::from_object(&child, tolerance))
+                    .fold(PdfObjectColorClass::Empty, PdfObjectColorClass::combine)
+            } else {
+                PdfObjectColorClass::from_object(&object, tolerance)
+            };
+
+            match classification {
+                PdfObjectColorClass::Empty => {}
+                PdfObjectColorClass::Monochrome => {}
+                PdfObjectColorClass::Gray => is_monochrome = false,
+                PdfObjectColorClass::Color => {
+                    is_grayscale = false;
+                    is_monochrome = false;
+                    color_object_indices.push(index);
+                }
+                PdfObjectColorClass::Uncertain => {
+                    is_grayscale = false;
+                    is_monochrome = false;
+                    is_inconclusive = true;
+                    color_object_indices.push(index);
+                    uncertain_object_indices.push(index);
+                }
+            }
+        }
+
+        let do_render = match options.get_mode() {
+            PdfPageColorAnalysisMode::InspectObjects => false,
+            PdfPageColorAnalysisMode::Hybrid => is_inconclusive,
+            PdfPageColorAnalysisMode::Render => true,
+        };
+
+        let mut ink_coverage = None;
+
+        if do_render {
+            let rendered = RenderedColorAnalysis::from_page(page, options)?;
+
+            is_grayscale = rendered.is_grayscale;
+            is_monochrome = rendered.is_monochrome;
+            ink_coverage = Some(rendered.ink_coverage);
+
+            if is_grayscale {
+                // Objects that might have contained color evidently did not.
+
+                color_object_indices.retain(|index| !uncertain_object_indices.contains(index));
+            }
+        }
+
+        Ok(PdfPageColorAnalysis {
+            is_grayscale,
+            is_monochrome,
+            ink_coverage,
+            color_object_indices,
+        })
+    }
+
+    /// Returns `true` if the page contains only black, white, and shades of gray.
+    ///
+    /// When the page is analyzed without rendering, pages containing objects whose colors
+    /// cannot be determined without rendering are never considered grayscale.
+    #[inline]
+    pub fn is_grayscale(&self) -> bool {
+        self.is_grayscale
+    }
+
+    /// Returns `true` if the page contains only pure black and pure white, without any
+    /// intermediate shades of gray. Every monochrome page is also a grayscale page.
+    ///
+    /// Anti-aliasing is disabled when the page is rendered during analysis, so the edges
+    /// of black text and vector paths do not introduce intermediate shades of gray.
+    #[inline]
+    pub fn is_monochrome(&self) -> bool {
+        self.is_monochrome
+    }
+
+    /// Returns `true` if the page contains any color other than black, white, and shades of gray.
+    #[inline]
+    pub fn is_color(&self) -> bool {
+        !self.is_grayscale
+    }
+
+    /// Returns `true` if the page was rendered during analysis.
+    #[inline]
+    pub fn is_rendered(&self) -> bool {
+        self.ink_coverage.is_some()
+    }
+
+    /// Returns the estimated coverage of each printing colorant across the page, if the page
+    /// was rendered during analysis. Coverage is estimated by converting each rendered pixel
+    /// to its CMYK equivalent and averaging the results across the entire page.
+    ///
+    /// Returns `None` if the page was not rendered during analysis. Use the
+    /// [PdfPageColorAnalysisMode::Render] analysis mode to ensure ink coverage is always available.
+    #[inline]
+    pub fn ink_coverage(&self) -> Option<PdfPageInkCoverage> {
+        self.ink_coverage
+    }
+
+    /// Returns the indices of the top-level page objects that contribute color to the page,
+    /// in ascending order. Form objects are included if any of their child objects
+    /// contribute color.
+    ///
+    /// Objects whose colors cannot be determined without rendering, such as images in color
+    /// color spaces and shadings, are included unless the page was rendered during analysis
+    /// and found to be grayscale.
+    #[inline]
+    pub fn color_object_indices(&self) -> &[PdfPageObjectIndex] {
+        self.color_object_indices.as_slice()
+    }
+}
+
+/// The classification of the colors used by a single page object.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+enum PdfObjectColorClass {
+    /// The object does not paint anything.
+    Empty,
+
+    /// The object paints only pure black and pure white.
+    Monochrome,
+
+    /// The object paints only black, white, and shades of gray.
+    Gray,
+
+    /// The object paints at least one color.
+    Color,
+
+    /// The colors painted by the object cannot be determined without rendering.
+    Uncertain,
+}
+
+impl PdfObjectColorClass {
+    fn from_object(object: &PdfPageObject, tolerance: u8) -> Self {
+        let classify = |color: Result<PdfColor, PdfiumError>| match color {
+            Ok(color) => Self::from_color(&color, tolerance),
+            Err(_) => PdfObjectColorClass::Uncertain,
+        };
+
+        match object {
+            PdfPageObject::Path(path) => match path.draw_mode() {
+                Ok(draw_mode) => {
+                    let fill = if draw_mode.fill.is_some() {
+                        classify(path.fill_color())
+                    } else {
+                        PdfObjectColorClass::Empty
+                    };
+
+                    let stroke = if draw_mode.stroke {
+                        classify(path.stroke_color())
+                    } else {
+                        PdfObjectColorClass::Empty
+                    };
+
+                    fill.combine(stroke)
+                }
+                Err(_) => PdfObjectColorClass::Uncertain,
+            },
+            PdfPageObject::Text(text) => {
+                let (is_filled, is_stroked) = match text.render_mode() {
+                    PdfPageTextRenderMode::Invisible | PdfPageTextRenderMode::InvisibleClipping => {
+                        (false, false)
+                    }
+                    PdfPageTextRenderMode::StrokedUnfilled
+                    | PdfPageTextRenderMode::StrokedUnfilledClipping => (false, true),
+                    PdfPageTextRenderMode::FilledThenStroked
+                    | PdfPageTextRenderMode::FilledThenStrokedClipping => (true, true),
+                    _ => (true, false),
+                };
+
+                let fill = if is_filled {
+                    classify(text.fill_color())
+                } else {
+                    PdfObjectColorClass::Empty
+                };
+
+                let stroke = if is_stroked {
+                    classify(text.stroke_color())
+                } else {
+                    PdfObjectColorClass::Empty
+                };
+
+                fill.combine(stroke)
+            }
+            PdfPageObject::Image(image) => match image.metadata() {
+                Ok(metadata) => match metadata.color_space() {
+                    PdfColorSpace::DeviceGray | PdfColorSpace::CalibratedCIEGray => {
+                        if metadata.bits_per_pixel() == 1 {
+                            PdfObjectColorClass::Monochrome
+                        } else {
+                            PdfObjectColorClass::Gray
+                        }
+                    }
+                    _ => PdfObjectColorClass::Uncertain,
+                },
+                Err(_) => PdfObjectColorClass::Uncertain,
+            },
+            PdfPageObject::XObjectForm(_) => {
+                // The children of form objects are classified individually.
+
+                PdfObjectColorClass::Empty
+            }
+            PdfPageObject::Shading(_) | PdfPageObject::Unsupported(_) => {
+                PdfObjectColorClass::Uncertain
+            }
+        }
+    }
+
+    fn from_color(color: &PdfColor, tolerance: u8) -> Self {
+        if color.alpha() == 0 {
+            PdfObjectColorClass::Empty
+        } else {
+            classify_rgb(color.red(), color.green(), color.blue(), tolerance)
+        }
+    }
+
+    /// Returns the classification of an object painting the colors of both this
+    /// classification and the given classification.
+    #[inline]
+    fn combine(self, other: Self) -> Self {
+        self.max(other)
+    }
+}
+
+/// Classifies the given RGB color as monochrome, gray, or color, within the given tolerance.
+fn classify_rgb(red: u8, green: u8, blue: u8, tolerance: u8) -> PdfObjectColorClass {
+    let max = red.max(green).max(blue);
+
+    let min = red.min(green).min(blue);
+
+    if max - min > tolerance {
+        PdfObjectColorClass::Color
+    } else if max <= tolerance || min >= 255 - tolerance {
+        PdfObjectColorClass::Monochrome
+    } else {
+        PdfObjectColorClass::Gray
+    }
+}
+
+/// The result of classifying the pixels of a low-resolution rendering of a page.
+struct RenderedColorAnalysis {
+    is_grayscale: bool,
+    is_monochrome: bool,
+    ink_coverage: PdfPageInkCoverage,
+}
+
+impl RenderedColorAnalysis {
+    fn from_page(
+        page: &PdfPage,
+        options: &PdfPageColorAnalysisOptions,
+    ) -> Result<Self, PdfiumError> {
+        // Anti-aliasing would introduce intermediate shades of gray along the edges
+        // of black text and paths, so it is disabled.
+
+        let config = PdfRenderConfig::new()
+            .scale_page_by_factor(options.get_render_resolution() / 72.0)
+            .set_format(PdfBitmapFormat::BGRA)
+            .set_text_smoothing(false)
+            .set_path_smoothing(false)
+            .set_image_smoothing(false);
+
+        let bytes = page.render_with_config(&config)?.as_rgba_bytes();
+
+        let tolerance = options.get_tolerance();
+
+        let mut is_grayscale = true;
+
+        let mut is_monochrome = true;
+
+        let mut totals = (0.0, 0.0, 0.0, 0.0);
+
+        for pixel in bytes.chunks_exact(4) {
+            match classify_rgb(pixel[0], pixel[1], pixel[2], tolerance) {
+                PdfObjectColorClass::Color => {
+                    is_grayscale = false;
+                    is_monochrome = false;
+                }
+                PdfObjectColorClass::Gray => is_monochrome = false,
+                _ => {}
+            }
+
+            let (c, m, y, k) = PdfColor::new(pixel[0], pixel[1], pixel[2], 255).to_cmyk();
+
+            totals.0 += c as f64;
+            totals.1 += m as f64;
+            totals.2 += y as f64;
+            totals.3 += k as f64;
+        }
+
+        let count = (bytes.len() / 4).max(1) as f64;
+
+        Ok(RenderedColorAnalysis {
+            is_grayscale,
+            is_monochrome,
+            ink_coverage: PdfPageInkCoverage {
+                cyan: (totals.0 / count) as f32,
+                magenta: (totals.1 / count) as f32,
+                yellow: (totals.2 / count) as f32,
+                black: (totals.3 / count) as f32,
+            },
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::utils::test::test_bind_to_pdfium;
+    use image_025::{DynamicImage, Rgba, RgbaImage};
+
+    #[test]
+    fn test_color_image_on_gray_page() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        let mut page = document
+            .pages_mut()
+            .create_page_at_start(PdfPagePaperSize::new_custom(
+                PdfPoints::new(200.0),
+                PdfPoints::new(200.0),
+            ))?;
+
+        page.objects_mut().create_path_object_rect(
+            PdfRect::new_from_values(10.0, 10.0, 90.0, 90.0),
+            None,
+            None,
+            Some(PdfColor::BLACK),
+        )?;
+
+        // A page containing only black and white is monochrome, and can be classified
+        // without rendering.
+
+        let analysis = page.analyze_color(&PdfPageColorAnalysisOptions::new())?;
+
+        assert!(analysis.is_grayscale());
+        assert!(analysis.is_monochrome());
+        assert!(!analysis.is_rendered());
+        assert!(analysis.color_object_indices().is_empty());
+
+        page.objects_mut().create_path_object_rect(
+            PdfRect::new_from_values(110.0, 10.0, 190.0, 90.0),
+            Some(PdfColor::BLACK),
+            Some(PdfPoints::new(2.0)),
+            Some(PdfColor::new(128, 128, 128, 255)),
+        )?;
+
+        let analysis = page.analyze_color(&PdfPageColorAnalysisOptions::new())?;
+
+        assert!(analysis.is_grayscale());
+        assert!(!analysis.is_monochrome());
+        assert!(!analysis.is_rendered());
+
+        // Adding a color image makes the page a color page. The image's colors can only be
+        // determined by rendering, so the hybrid mode renders the page.
+
+        let image = DynamicImage::ImageRgba8(RgbaImage::from_pixel(10, 10, Rgba([255, 0, 0, 255])));
+
+        let mut object = PdfPageImageObject::new(&document, &image)?;
+
+        object.scale(80.0, 80.0)?;
+        object.translate(PdfPoints::new(60.0), PdfPoints::new(110.0))?;
+
+        page.objects_mut().add_image_object(object)?;
+
+        let inspected = page.analyze_color(
+            &PdfPageColorAnalysisOptions::new().mode(PdfPageColorAnalysisMode::InspectObjects),
+        )?;
+
+        assert!(!inspected.is_grayscale());
+        assert!(!inspected.is_rendered());
+        assert_eq!(inspected.ink_coverage(), None);
+        assert_eq!(inspected.color_object_indices(), &[2]);
+
+        let analysis = page.analyze_color(&PdfPageColorAnalysisOptions::new())?;
+
+        assert!(analysis.is_color());
+        assert!(!analysis.is_monochrome());
+        assert!(analysis.is_rendered());
+        assert_eq!(analysis.color_object_indices(), &[2]);
+
+        // The red image covers 16% of the page; the black rectangle a further 16%.
+
+        let coverage = analysis.ink_coverage().unwrap();
+
+        assert!(coverage.cyan() < 0.01);
+        assert!((coverage.magenta() - 0.16).abs() < 0.02);
+        assert!((coverage.yellow() - 0.16).abs() < 0.02);
+        assert!(coverage.black() > 0.16);
+
+        // Replacing the color image with a gray image in an RGB color space returns the page
+        // to grayscale, but this can only be detected by rendering.
+
+        page.objects_mut().remove_object_at_index(2)?;
+
+        let image =
+            DynamicImage::ImageRgba8(RgbaImage::from_pixel(10, 10, Rgba([64, 64, 64, 255])));
+
+        let mut object = PdfPageImageObject::new(&document, &image)?;
+
+        object.scale(80.0, 80.0)?;
+        object.translate(PdfPoints::new(60.0), PdfPoints::new(110.0))?;
+
+        page.objects_mut().add_image_object(object)?;
+
+        let inspected = page.analyze_color(
+            &PdfPageColorAnalysisOptions::new().mode(PdfPageColorAnalysisMode::InspectObjects),
+        )?;
+
+        assert!(!inspected.is_grayscale());
+        assert_eq!(inspected.color_object_indices(), &[2]);
+
+        let analysis = page.analyze_color(&PdfPageColorAnalysisOptions::new())?;
+
+        assert!(analysis.is_grayscale());
+        assert!(analysis.is_rendered());
+        assert!(analysis.color_object_indices().is_empty());
+
+        Ok(())
+    }
+}
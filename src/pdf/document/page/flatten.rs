@@ -1,6 +1,10 @@
 use crate::bindgen::FPDF_PAGE;
 use crate::error::PdfiumError;
+use crate::pdf::document::page::{PdfPageFlattenMode, PdfPageFlattenResult};
 
-fn flatten(_page_handle: FPDF_PAGE) -> Result<(), PdfiumError> {
+pub(super) fn flatten(
+    _page_handle: FPDF_PAGE,
+    _mode: PdfPageFlattenMode,
+) -> Result<PdfPageFlattenResult, PdfiumError> {
     unimplemented!()
 }
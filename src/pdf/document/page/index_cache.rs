@@ -21,6 +21,7 @@ pub(crate) struct PdfPageIndexCache {
     pages_by_index: HashMap<(FPDF_DOCUMENT, FPDF_PAGE), PdfPageCachedProperties>,
     indices_by_page: HashMap<(FPDF_DOCUMENT, PdfPageIndex), FPDF_PAGE>,
     documents_by_maximum_index: HashMap<FPDF_DOCUMENT, PdfPageIndex>,
+    content_revisions_by_page: HashMap<FPDF_PAGE, u64>,
 }
 
 impl PdfPageIndexCache {
@@ -30,6 +31,7 @@ impl PdfPageIndexCache {
             pages_by_index: HashMap::new(),
             indices_by_page: HashMap::new(),
             documents_by_maximum_index: HashMap::new(),
+            content_revisions_by_page: HashMap::new(),
         }
     }
 
@@ -209,6 +211,74 @@ impl PdfPageIndexCache {
         }
     }
 
+    /// Adjusts all cached [PdfPageIndex] values for the given document as necessary to accommodate
+    /// moving a single page from one index position to another.
+    #[cfg(any(
+        feature = "pdfium_future",
+        feature = "pdfium_6996",
+        feature = "pdfium_6721",
+        feature = "pdfium_6666",
+        feature = "pdfium_6611",
+        feature = "pdfium_6569",
+        feature = "pdfium_6555",
+        feature = "pdfium_6490",
+        feature = "pdfium_6406",
+        feature = "pdfium_6337",
+        feature = "pdfium_6295",
+        feature = "pdfium_6259",
+        feature = "pdfium_6164",
+        feature = "pdfium_6124",
+        feature = "pdfium_6110",
+        feature = "pdfium_6084",
+        feature = "pdfium_6043",
+    ))]
+    fn move_page(&mut self, document: FPDF_DOCUMENT, from: PdfPageIndex, to: PdfPageIndex) {
+        if from == to {
+            return;
+        }
+
+        let moved = self
+            .pages_by_index
+            .iter()
+            .filter(|((cached_document, _), _)| *cached_document == document)
+            .map(|((_, page), props)| (*page, props.index))
+            .collect::<Vec<_>>();
+
+        for (_, index) in moved.iter() {
+            self.indices_by_page.remove(&(document, *index));
+        }
+
+        for (page, index) in moved {
+            let index = if index == from {
+                to
+            } else if from < to && index > from && index <= to {
+                index - 1
+            } else if from > to && index >= to && index < from {
+                index + 1
+            } else {
+                index
+            };
+
+            if let Some(props) = self.pages_by_index.get_mut(&(document, page)) {
+                props.index = index;
+            }
+
+            self.indices_by_page.insert((document, index), page);
+        }
+
+        // Moving a page does not change the number of pages in the document, but the moved
+        // page may now sit beyond the maximum index we were previously tracking.
+
+        let maximum = self
+            .documents_by_maximum_index
+            .get(&document)
+            .copied()
+            .unwrap_or(0)
+            .max(to);
+
+        self.documents_by_maximum_index.insert(document, maximum);
+    }
+
     #[inline]
     fn lock() -> MutexGuard<'static, PdfPageIndexCache> {
         PAGE_INDEX_CACHE.lock().unwrap()
@@ -259,7 +329,31 @@ impl PdfPageIndexCache {
     /// Removes the cached [PdfPageIndex] value for the given raw document and page handles.
     #[inline]
     pub(crate) fn remove_index_for_page(document: FPDF_DOCUMENT, page: FPDF_PAGE) {
-        Self::lock().remove(document, page);
+        let mut cache = Self::lock();
+
+        cache.remove(document, page);
+        cache.content_revisions_by_page.remove(&page);
+    }
+
+    /// Returns the number of times the content of the page with the given raw page handle
+    /// has changed since the page was loaded.
+    #[inline]
+    pub(crate) fn get_content_revision_for_page(page: FPDF_PAGE) -> u64 {
+        Self::lock()
+            .content_revisions_by_page
+            .get(&page)
+            .copied()
+            .unwrap_or(0)
+    }
+
+    /// Records that the content of the page with the given raw page handle has changed,
+    /// invalidating any data derived from the page's previous content.
+    #[inline]
+    pub(crate) fn increment_content_revision_for_page(page: FPDF_PAGE) {
+        *Self::lock()
+            .content_revisions_by_page
+            .entry(page)
+            .or_insert(0) += 1;
     }
 
     /// Adjusts all cached [PdfPageIndex] values for the given document as necessary to accommodate
@@ -273,6 +367,36 @@ impl PdfPageIndexCache {
         Self::lock().insert(document, index, count);
     }
 
+    /// Adjusts all cached [PdfPageIndex] values for the given document as necessary to accommodate
+    /// moving a single page from one index position to another.
+    #[inline]
+    #[cfg(any(
+        feature = "pdfium_future",
+        feature = "pdfium_6996",
+        feature = "pdfium_6721",
+        feature = "pdfium_6666",
+        feature = "pdfium_6611",
+        feature = "pdfium_6569",
+        feature = "pdfium_6555",
+        feature = "pdfium_6490",
+        feature = "pdfium_6406",
+        feature = "pdfium_6337",
+        feature = "pdfium_6295",
+        feature = "pdfium_6259",
+        feature = "pdfium_6164",
+        feature = "pdfium_6124",
+        feature = "pdfium_6110",
+        feature = "pdfium_6084",
+        feature = "pdfium_6043",
+    ))]
+    pub(crate) fn move_page_to_index(
+        document: FPDF_DOCUMENT,
+        from: PdfPageIndex,
+        to: PdfPageIndex,
+    ) {
+        Self::lock().move_page(document, from, to);
+    }
+
     /// Adjusts all cached [PdfPageIndex] values for the given document as necessary to accommodate
     /// a deletion of the given number of pages at the given index position.
     #[inline]
@@ -0,0 +1,381 @@
+//! Defines the [PdfPageLayout] struct, a machine-readable description of the positioned
+//! text, images, and paths on a single `PdfPage`, as returned by the
+//! `PdfPage::extract_layout()` function.
+//!
+//! When the `serde` crate feature is enabled, all types in this module implement
+//! `serde::Serialize`, making it straightforward to export page layouts to JSON or any other
+//! format supported by `serde`.
+
+use crate::error::PdfiumError;
+use crate::pdf::bitmap::Pixels;
+use crate::pdf::color::PdfColor;
+use crate::pdf::document::page::object::image::PdfPageImageObject;
+use crate::pdf::document::page::object::text::PdfPageTextObject;
+use crate::pdf::document::page::object::{PdfPageObject, PdfPageObjectCommon};
+use crate::pdf::document::page::objects::common::PdfPageObjectsCommon;
+use crate::pdf::document::page::text::PdfPageText;
+use crate::pdf::document::page::{PdfPage, PdfPageRenderRotation};
+use crate::pdf::matrix::PdfMatrix;
+use crate::pdf::points::PdfPoints;
+use crate::pdf::rect::PdfRect;
+
+/// The positioned text runs, images, and paths on a single `PdfPage`, listed in the order
+/// in which they appear in the page's content stream.
+///
+/// All positions and dimensions are expressed in page points, in the unrotated coordinate
+/// space of the page. Use the [PdfPageLayout::rotation()] function to determine how the page
+/// is rotated when displayed.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct PdfPageLayout {
+    schema_version: u32,
+    width: PdfPoints,
+    height: PdfPoints,
+    #[cfg_attr(feature = "serde", serde(serialize_with = "serialize_rotation"))]
+    rotation: PdfPageRenderRotation,
+    elements: Vec<PdfPageLayoutElement>,
+}
+
+impl PdfPageLayout {
+    /// The version of the structure of a serialized [PdfPageLayout]. The version will be
+    /// incremented whenever a field is renamed or removed, or its meaning changes, so that
+    /// downstream parsers can detect layouts they do not understand.
+    pub const SCHEMA_VERSION: u32 = 1;
+
+    /// Extracts the layout of the given [PdfPage].
+    pub(crate) fn from_page(page: &PdfPage) -> Result<Self, PdfiumError> {
+        // Retrieving the text of a text object requires a PdfPageText. Loading the
+        // PdfPageText once and reusing it for every object avoids re-analyzing the
+        // page text for each text object.
+
+        let text = page.text()?;
+
+        let mut elements = Vec::new();
+
+        for object in page.objects().iter() {
+            if let PdfPageObject::XObjectForm(form) = &object {
+                for (child, matrix) in form.objects().iter_recursive() {
+                    if let Some(element) = PdfPageLayoutElement::from_object(&child, matrix, &text)?
+                    {
+                        elements.push(element);
+                    }
+                }
+            } else if let Some(element) =
+                PdfPageLayoutElement::from_object(&object, PdfMatrix::IDENTITY, &text)?
+            {
+                elements.push(element);
+            }
+        }
+
+        Ok(PdfPageLayout {
+            schema_version: Self::SCHEMA_VERSION,
+            width: page.width(),
+            height: page.height(),
+            rotation: page.rotation().unwrap_or(PdfPageRenderRotation::None),
+            elements,
+        })
+    }
+
+    /// Returns the version of the structure of this [PdfPageLayout]. This is always
+    /// [PdfPageLayout::SCHEMA_VERSION].
+    #[inline]
+    pub fn schema_version(&self) -> u32 {
+        self.schema_version
+    }
+
+    /// Returns the width of the page described by this [PdfPageLayout].
+    #[inline]
+    pub fn width(&self) -> PdfPoints {
+        self.width
+    }
+
+    /// Returns the height of the page described by this [PdfPageLayout].
+    #[inline]
+    pub fn height(&self) -> PdfPoints {
+        self.height
+    }
+
+    /// Returns the rotation applied to the page described by this [PdfPageLayout]
+    /// when it is displayed. When serialized, the rotation is given in degrees.
+    #[inline]
+    pub fn rotation(&self) -> PdfPageRenderRotation {
+        self.rotation
+    }
+
+    /// Returns the text runs, images, and paths in this [PdfPageLayout], in content
+    /// stream order.
+    #[inline]
+    pub fn elements(&self) -> &[PdfPageLayoutElement] {
+        self.elements.as_slice()
+    }
+}
+
+/// A single positioned element in a [PdfPageLayout].
+///
+/// When serialized, each element includes a `type` field containing one of `text`,
+/// `image`, or `path`.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize),
+    serde(tag = "type", rename_all = "snake_case")
+)]
+pub enum PdfPageLayoutElement {
+    Text(PdfPageLayoutTextRun),
+    Image(PdfPageLayoutImage),
+    Path(PdfPageLayoutPath),
+}
+
+impl PdfPageLayoutElement {
+    /// Creates a [PdfPageLayoutElement] from the given [PdfPageObject], applying the given
+    /// matrix to map the object's position into page space. Returns `None` for objects that
+    /// are not represented in a [PdfPageLayout].
+    fn from_object(
+        object: &PdfPageObject,
+        matrix: PdfMatrix,
+        text: &PdfPageText,
+    ) -> Result<Option<Self>, PdfiumError> {
+        let bounds = object.bounds()?.transform(matrix).to_rect();
+
+        Ok(match object {
+            PdfPageObject::Text(object) => Some(PdfPageLayoutElement::Text(
+                PdfPageLayoutTextRun::from_object(object, bounds, matrix, text)?,
+            )),
+            PdfPageObject::Image(object) => Some(PdfPageLayoutElement::Image(
+                PdfPageLayoutImage::from_object(object, bounds)?,
+            )),
+            PdfPageObject::Path(_) => {
+                Some(PdfPageLayoutElement::Path(PdfPageLayoutPath { bounds }))
+            }
+            _ => None,
+        })
+    }
+
+    /// Returns the bounding box of this [PdfPageLayoutElement].
+    #[inline]
+    pub fn bounds(&self) -> PdfRect {
+        match self {
+            PdfPageLayoutElement::Text(run) => run.bounds(),
+            PdfPageLayoutElement::Image(image) => image.bounds(),
+            PdfPageLayoutElement::Path(path) => path.bounds(),
+        }
+    }
+}
+
+/// A run of text sharing a single font, size, and color in a [PdfPageLayout].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct PdfPageLayoutTextRun {
+    text: String,
+    font_name: String,
+    font_size: PdfPoints,
+    color: Option<PdfColor>,
+    bounds: PdfRect,
+    baseline: PdfPoints,
+}
+
+impl PdfPageLayoutTextRun {
+    fn from_object(
+        object: &PdfPageTextObject,
+        bounds: PdfRect,
+        matrix: PdfMatrix,
+        text: &PdfPageText,
+    ) -> Result<Self, PdfiumError> {
+        // The origin of a text object lies on its baseline.
+
+        let origin = object.matrix()?;
+
+        let (_, baseline) =
+            matrix.apply_to_points(PdfPoints::new(origin.e()), PdfPoints::new(origin.f()));
+
+        Ok(PdfPageLayoutTextRun {
+            text: text.for_object(object),
+            font_name: object.font().family(),
+            font_size: object.scaled_font_size(),
+            color: object.fill_color().ok(),
+            bounds,
+            baseline,
+        })
+    }
+
+    /// Returns the text in this [PdfPageLayoutTextRun].
+    #[inline]
+    pub fn text(&self) -> &str {
+        self.text.as_str()
+    }
+
+    /// Returns the family name of the font used to render this [PdfPageLayoutTextRun].
+    #[inline]
+    pub fn font_name(&self) -> &str {
+        self.font_name.as_str()
+    }
+
+    /// Returns the effective font size of this [PdfPageLayoutTextRun], taking into account
+    /// any scaling applied to the text object containing it.
+    #[inline]
+    pub fn font_size(&self) -> PdfPoints {
+        self.font_size
+    }
+
+    /// Returns the fill color of this [PdfPageLayoutTextRun], if Pdfium was able to
+    /// determine it.
+    #[inline]
+    pub fn color(&self) -> Option<PdfColor> {
+        self.color
+    }
+
+    /// Returns the bounding box of this [PdfPageLayoutTextRun].
+    #[inline]
+    pub fn bounds(&self) -> PdfRect {
+        self.bounds
+    }
+
+    /// Returns the vertical position of the baseline of this [PdfPageLayoutTextRun].
+    #[inline]
+    pub fn baseline(&self) -> PdfPoints {
+        self.baseline
+    }
+}
+
+/// A placed image in a [PdfPageLayout].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct PdfPageLayoutImage {
+    bounds: PdfRect,
+    width: Pixels,
+    height: Pixels,
+    horizontal_dpi: f32,
+    vertical_dpi: f32,
+}
+
+impl PdfPageLayoutImage {
+    fn from_object(object: &PdfPageImageObject, bounds: PdfRect) -> Result<Self, PdfiumError> {
+        let metadata = object.metadata()?;
+
+        Ok(PdfPageLayoutImage {
+            bounds,
+            width: metadata.width(),
+            height: metadata.height(),
+            horizontal_dpi: metadata.horizontal_dpi(),
+            vertical_dpi: metadata.vertical_dpi(),
+        })
+    }
+
+    /// Returns the bounding box of this [PdfPageLayoutImage].
+    #[inline]
+    pub fn bounds(&self) -> PdfRect {
+        self.bounds
+    }
+
+    /// Returns the intrinsic width of the image, in pixels.
+    #[inline]
+    pub fn width(&self) -> Pixels {
+        self.width
+    }
+
+    /// Returns the intrinsic height of the image, in pixels.
+    #[inline]
+    pub fn height(&self) -> Pixels {
+        self.height
+    }
+
+    /// Returns the effective horizontal resolution of the image as placed on the page,
+    /// in dots per inch.
+    #[inline]
+    pub fn horizontal_dpi(&self) -> f32 {
+        self.horizontal_dpi
+    }
+
+    /// Returns the effective vertical resolution of the image as placed on the page,
+    /// in dots per inch.
+    #[inline]
+    pub fn vertical_dpi(&self) -> f32 {
+        self.vertical_dpi
+    }
+}
+
+/// A path in a [PdfPageLayout].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct PdfPageLayoutPath {
+    bounds: PdfRect,
+}
+
+impl PdfPageLayoutPath {
+    /// Returns the bounding box of this [PdfPageLayoutPath].
+    #[inline]
+    pub fn bounds(&self) -> PdfRect {
+        self.bounds
+    }
+}
+
+#[cfg(feature = "serde")]
+fn serialize_rotation<S: serde::Serializer>(
+    rotation: &PdfPageRenderRotation,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.serialize_f32(rotation.as_degrees())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::utils::test::test_bind_to_pdfium;
+
+    #[test]
+    fn test_extract_layout() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        let font = document.fonts_mut().helvetica();
+
+        let mut page = document
+            .pages_mut()
+            .create_page_at_end(PdfPagePaperSize::a4())?;
+
+        page.objects_mut().create_text_object(
+            PdfPoints::new(100.0),
+            PdfPoints::new(700.0),
+            "Layout",
+            font,
+            PdfPoints::new(20.0),
+        )?;
+
+        page.objects_mut().create_path_object_rect(
+            PdfRect::new_from_values(100.0, 100.0, 200.0, 300.0),
+            None,
+            None,
+            Some(PdfColor::BLACK),
+        )?;
+
+        page.set_rotation(PdfPageRenderRotation::Degrees90);
+
+        let layout = page.extract_layout()?;
+
+        assert_eq!(layout.schema_version(), PdfPageLayout::SCHEMA_VERSION);
+        assert_eq!(layout.width(), page.width());
+        assert_eq!(layout.rotation(), PdfPageRenderRotation::Degrees90);
+        assert_eq!(layout.elements().len(), 2);
+
+        match &layout.elements()[0] {
+            PdfPageLayoutElement::Text(run) => {
+                assert_eq!(run.text(), "Layout");
+                assert_eq!(run.font_name(), "Helvetica");
+                assert_eq!(run.font_size(), PdfPoints::new(20.0));
+                assert!((run.baseline().value - 700.0).abs() < 0.01);
+            }
+            element => panic!("Expected text run, found {:?}", element),
+        }
+
+        match &layout.elements()[1] {
+            PdfPageLayoutElement::Path(path) => {
+                assert!((path.bounds().left().value - 100.0).abs() < 1.0);
+                assert!((path.bounds().top().value - 200.0).abs() < 1.0);
+            }
+            element => panic!("Expected path, found {:?}", element),
+        }
+
+        Ok(())
+    }
+}
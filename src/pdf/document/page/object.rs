@@ -2,6 +2,10 @@
 
 pub(crate) mod group;
 pub(crate) mod image;
+pub(crate) mod mark;
+pub(crate) mod marks;
+pub(crate) mod miter_limit;
+pub(crate) mod opacity;
 pub(crate) mod ownership;
 pub(crate) mod path;
 pub(crate) mod private; // Keep private so that the PdfPageObjectPrivate trait is not exposed.
@@ -18,9 +22,13 @@ use crate::bindgen::{
 };
 use crate::bindings::PdfiumLibraryBindings;
 use crate::error::PdfiumError;
+use crate::pdf::bitmap::{PdfBitmap, Pixels};
 use crate::pdf::color::PdfColor;
 use crate::pdf::document::page::annotation::objects::PdfPageAnnotationObjects;
 use crate::pdf::document::page::object::image::PdfPageImageObject;
+use crate::pdf::document::page::object::marks::PdfPageObjectMarks;
+use crate::pdf::document::page::object::miter_limit::{record_miter_limit, recorded_miter_limit};
+use crate::pdf::document::page::object::opacity::update_opacity_mark;
 use crate::pdf::document::page::object::path::PdfPagePathObject;
 use crate::pdf::document::page::object::private::internal::PdfPageObjectPrivate;
 use crate::pdf::document::page::object::shading::PdfPageShadingObject;
@@ -31,6 +39,7 @@ use crate::pdf::document::page::objects::PdfPageObjects;
 use crate::pdf::document::page::PdfPageObjectOwnership;
 use crate::pdf::document::PdfDocument;
 use crate::pdf::matrix::{PdfMatrix, PdfMatrixValue};
+use crate::pdf::path::clip_path::PdfClipPath;
 use crate::pdf::points::PdfPoints;
 use crate::pdf::quad_points::PdfQuadPoints;
 use crate::pdf::rect::PdfRect;
@@ -207,6 +216,11 @@ pub enum PdfPageObjectLineJoin {
     /// The outer edges of the strokes for the two path segments are extended
     /// until they meet at an angle, as in a picture frame. If the segments meet at too
     /// sharp an angle, a bevel join is used instead.
+    ///
+    /// The angle at which a bevel join is substituted is determined by the miter limit
+    /// set in the page object's graphics state. The miter limit can be read and changed using
+    /// the [PdfPageObjectCommon::miter_limit()] and [PdfPageObjectCommon::set_miter_limit()]
+    /// functions.
     Miter = FPDF_LINEJOIN_MITER as isize,
 
     /// An arc of a circle with a diameter equal to the line width is drawn
@@ -532,6 +546,23 @@ pub trait PdfPageObjectCommon<'a> {
     /// Returns `true` if this [PdfPageObject] contains transparency.
     fn has_transparency(&self) -> bool;
 
+    /// Returns `true` if this [PdfPageObject] is active. All page objects are active unless
+    /// deactivated by a call to [PdfPageObjectCommon::set_active()].
+    ///
+    /// Returns [PdfiumError::UnsupportedOperation] if the bound Pdfium library does not
+    /// support page object active states.
+    fn is_active(&self) -> Result<bool, PdfiumError>;
+
+    /// Sets whether this [PdfPageObject] is active. An inactive page object is treated by Pdfium
+    /// as if it were not part of its containing page: it is not rendered, and it is omitted
+    /// from the page's content stream when the page's content is regenerated, but it is not
+    /// deleted and so can later be made active again. This allows page objects to be hidden
+    /// non-destructively.
+    ///
+    /// Returns [PdfiumError::UnsupportedOperation] if the bound Pdfium library does not
+    /// support page object active states.
+    fn set_active(&mut self, active: bool) -> Result<(), PdfiumError>;
+
     /// Returns the bounding box of this [PdfPageObject] as a quadrilateral.
     ///
     /// For text objects, the bottom of the bounding box is set to the font baseline. Any characters
@@ -540,6 +571,17 @@ pub trait PdfPageObjectCommon<'a> {
     /// any glyphs, use the [PdfPageTextObject::descent()] function.
     fn bounds(&self) -> Result<PdfQuadPoints, PdfiumError>;
 
+    /// Returns the tight fitting bounds of this [PdfPageObject] as a quadrilateral that
+    /// follows any rotation or skew applied by this object's transformation matrix.
+    ///
+    /// Tight fitting bounds are retrieved directly from Pdfium for text and image objects
+    /// where the bound Pdfium library supports it. For all other objects, the bounds of the
+    /// object without its transformation matrix applied are transformed by that matrix.
+    /// Either way, the returned quadrilateral can be considerably smaller than the
+    /// axis-aligned rectangle returned by [PdfQuadPoints::to_axis_aligned_rect()] for
+    /// objects that are rotated or skewed.
+    fn quad_bounds(&self) -> Result<PdfQuadPoints, PdfiumError>;
+
     /// Returns the width of this [PdfPageObject].
     #[inline]
     fn width(&self) -> Result<PdfPoints, PdfiumError> {
@@ -569,15 +611,54 @@ pub trait PdfPageObjectCommon<'a> {
             .unwrap_or(false)
     }
 
+    /// Returns `true` if the tight fitting bounds of this [PdfPageObject] lie at least
+    /// partially within the given rectangle.
+    ///
+    /// Unlike [PdfPageObjectCommon::does_overlap_rect()], which tests the axis-aligned
+    /// bounding box of this [PdfPageObject], this function tests the quadrilateral returned
+    /// by [PdfPageObjectCommon::quad_bounds()]. It will not report an overlap with a rectangle
+    /// that only intersects the empty corners of the axis-aligned bounding box of a rotated object.
+    #[inline]
+    fn does_overlap_rect_precise(&self, rect: &PdfRect) -> bool {
+        self.quad_bounds()
+            .map(|bounds| bounds.does_overlap_rect(rect))
+            .unwrap_or(false)
+    }
+
     /// Transforms this [PdfPageObject] by applying the transformation matrix read from the given [PdfPageObject].
     ///
     /// Any translation, rotation, scaling, or skewing transformations currently applied to the
     /// given [PdfPageObject] will be immediately applied to this [PdfPageObject].
     fn transform_from(&mut self, other: &PdfPageObject) -> Result<(), PdfiumError>;
 
-    /// Sets the blend mode that will be applied when painting this [PdfPageObject].
+    /// Returns the [PdfClipPath] attached to this [PdfPageObject], if any.
     ///
-    /// Note that Pdfium does not currently expose a function to read the currently set blend mode.
+    /// The clip path restricts the region of the page in which this [PdfPageObject] is painted.
+    /// Pdfium does not provide a way to attach a new clip path to an individual page object;
+    /// to clip all the content on a page, use the `PdfPage::insert_clip_path()` function.
+    fn clip_path(&self) -> Option<PdfClipPath>;
+
+    /// Transforms the [PdfClipPath] attached to this [PdfPageObject], if any, by applying
+    /// the given [PdfMatrix]. The transformation is not applied to the page object itself.
+    fn transform_clip_path(&mut self, matrix: PdfMatrix) -> Result<(), PdfiumError>;
+
+    /// Returns the collection of content marks attached to this [PdfPageObject].
+    ///
+    /// Content marks associate a page object with a marked-content sequence in the page's
+    /// content stream, and can be used to tag page objects so they can be identified later.
+    fn marks(&self) -> PdfPageObjectMarks;
+
+    /// Returns the blend mode that will be applied when painting this [PdfPageObject].
+    ///
+    /// Pdfium does not currently expose a function to read the `/BM` entry from a page object's
+    /// graphics state dictionary. Pdfium reports a page object as containing transparency
+    /// whenever a blend mode other than [PdfPageObjectBlendMode::Normal] is set, so if this
+    /// [PdfPageObject] contains no transparency, [PdfPageObjectBlendMode::Normal] is returned.
+    /// Otherwise, the blend mode cannot be determined and [PdfiumError::BlendModeNotAvailable]
+    /// is returned.
+    fn blend_mode(&self) -> Result<PdfPageObjectBlendMode, PdfiumError>;
+
+    /// Sets the blend mode that will be applied when painting this [PdfPageObject].
     fn set_blend_mode(&mut self, blend_mode: PdfPageObjectBlendMode) -> Result<(), PdfiumError>;
 
     /// Returns the color of any filled paths in this [PdfPageObject].
@@ -595,6 +676,39 @@ pub trait PdfPageObjectCommon<'a> {
     /// the object's stroke mode must be set in order for strokes to actually be visible.
     fn set_stroke_color(&mut self, stroke_color: PdfColor) -> Result<(), PdfiumError>;
 
+    /// Returns the fill and stroke opacity of this [PdfPageObject], in that order, as values
+    /// between 0.0 (fully transparent) and 1.0 (fully opaque).
+    ///
+    /// An object with no fill or stroke color set is fully opaque.
+    fn opacity(&self) -> (f32, f32);
+
+    /// Returns the fill opacity of this [PdfPageObject], as a value between 0.0
+    /// (fully transparent) and 1.0 (fully opaque).
+    fn fill_opacity(&self) -> f32;
+
+    /// Returns the stroke opacity of this [PdfPageObject], as a value between 0.0
+    /// (fully transparent) and 1.0 (fully opaque).
+    fn stroke_opacity(&self) -> f32;
+
+    /// Sets the fill and stroke opacity of this [PdfPageObject] to the given values between
+    /// 0.0 (fully transparent) and 1.0 (fully opaque). Values outside this range are clamped.
+    ///
+    /// Opacity is written into the object's graphics state as the `/ca` and `/CA` entries
+    /// of an `ExtGState` dictionary, and applies to the whole object, including image and
+    /// form objects. The colors of the object are otherwise unchanged. Setting the alpha value
+    /// of a fill or stroke color sets the same graphics state entry, so the most recent change
+    /// takes precedence.
+    ///
+    /// Pdfium does not write the opacity of image and form objects into page content,
+    /// so `pdfium-render` tags such objects with a content mark and writes the opacity
+    /// as an incremental update when the containing document is saved. Image and form
+    /// objects must be attached to a page for their opacity to be saved.
+    ///
+    /// Each object is painted with its own opacity, so where two semi-transparent objects
+    /// overlap, the overlap appears darker than either object. To fade several objects
+    /// uniformly, use `PdfPageGroupObject::set_group_opacity()`.
+    fn set_opacity(&mut self, fill_alpha: f32, stroke_alpha: f32) -> Result<(), PdfiumError>;
+
     /// Returns the width of any stroked lines in this [PdfPageObject].
     fn stroke_width(&self) -> Result<PdfPoints, PdfiumError>;
 
@@ -611,12 +725,41 @@ pub trait PdfPageObjectCommon<'a> {
 
     /// Returns the line join style that will be used when painting stroked path segments
     /// in this [PdfPageObject].
+    ///
+    /// The miter limit that controls when [PdfPageObjectLineJoin::Miter] joins are replaced
+    /// by bevel joins is returned by [PdfPageObjectCommon::miter_limit()].
     fn line_join(&self) -> Result<PdfPageObjectLineJoin, PdfiumError>;
 
     /// Sets the line join style that will be used when painting stroked path segments
     /// in this [PdfPageObject].
+    ///
+    /// To avoid spikes where [PdfPageObjectLineJoin::Miter] joins meet at sharp angles,
+    /// lower the miter limit using [PdfPageObjectCommon::set_miter_limit()].
     fn set_line_join(&mut self, line_join: PdfPageObjectLineJoin) -> Result<(), PdfiumError>;
 
+    /// Returns the miter limit that will be used when painting stroked path segments
+    /// in this [PdfPageObject].
+    ///
+    /// The miter limit is the maximum ratio of the length of a [PdfPageObjectLineJoin::Miter]
+    /// join to the stroke width; where two segments meet at too sharp an angle for the
+    /// join to fit within this limit, a bevel join is used instead.
+    ///
+    /// Pdfium does not currently expose a function to read the miter limit from a page object's
+    /// graphics state, so this function returns the miter limit set by
+    /// [PdfPageObjectCommon::set_miter_limit()]. If no miter limit has been set since this
+    /// [PdfPageObject] was loaded, [PdfiumError::MiterLimitNotAvailable] is returned.
+    fn miter_limit(&self) -> Result<f32, PdfiumError>;
+
+    /// Sets the miter limit that will be used when painting stroked path segments
+    /// in this [PdfPageObject]. The miter limit must be at least 1.0.
+    ///
+    /// Pdfium does not currently expose a function to set the miter limit in a page object's
+    /// graphics state, so the miter limit is recorded in a content mark attached to this
+    /// [PdfPageObject]. When the containing document is saved, the mark is replaced by the
+    /// miter limit itself. The miter limit will not be applied when rendering this
+    /// [PdfPageObject] until the document has been saved and reloaded.
+    fn set_miter_limit(&mut self, limit: f32) -> Result<(), PdfiumError>;
+
     /// Returns the line cap style that will be used when painting stroked path segments
     /// in this [PdfPageObject].
     fn line_cap(&self) -> Result<PdfPageObjectLineCap, PdfiumError>;
@@ -708,9 +851,8 @@ pub trait PdfPageObjectCommon<'a> {
     ///   to an image object, but currently provides no way to set a new object's image filters.
     ///   As a result, it is not possible to copy an image object that has any image filters applied.
     ///
-    /// Pdfium currently allows setting the blend mode for a page object, but provides no way
-    /// to retrieve an object's current blend mode. As a result, the blend mode setting of the
-    /// original object will not be transferred to the copy.
+    /// The blend mode of the original object will be transferred to the copy if it can be
+    /// determined by [PdfPageObjectCommon::blend_mode()].
     fn is_copyable(&self) -> bool;
 
     /// Attempts to copy this [PdfPageObject] by creating a new page object and copying across
@@ -728,14 +870,48 @@ pub trait PdfPageObjectCommon<'a> {
     ///   to an image object, but currently provides no way to set a new object's image filters.
     ///   As a result, it is not possible to copy an image object that has any image filters applied.
     ///
-    /// Pdfium currently allows setting the blend mode for a page object, but provides no way
-    /// to retrieve an object's current blend mode. As a result, the blend mode setting of the
-    /// original object will not be transferred to the copy.
+    /// The blend mode of the original object will be transferred to the copy if it can be
+    /// determined by [PdfPageObjectCommon::blend_mode()].
     ///
     /// The returned page object will be detached from any existing `PdfPage`. Its lifetime
     /// will be bound to the lifetime of the given destination [PdfDocument].
     fn try_copy<'b>(&self, document: &'b PdfDocument<'b>)
         -> Result<PdfPageObject<'b>, PdfiumError>;
+
+    /// Renders this [PdfPageObject] in isolation into a new [PdfBitmap] with the given
+    /// pixel dimensions. The bitmap is tightly cropped to the bounds of this object; no other
+    /// content from the containing page is rendered. Any area of the bitmap not covered by this
+    /// object is filled with the given background color, or left fully transparent if no
+    /// background color is given.
+    ///
+    /// Image objects are rendered taking into account any image filters, image mask,
+    /// and object transforms applied to the object. All other object types are rendered by
+    /// copying the object into a temporary document, so the restrictions described in
+    /// [PdfPageObjectCommon::try_copy()] apply; an error is returned if this object
+    /// cannot be copied.
+    ///
+    /// It is the responsibility of the caller to ensure the given pixel width and height
+    /// correctly maintain the aspect ratio of the object's bounds.
+    fn render(
+        &self,
+        width: Pixels,
+        height: Pixels,
+        background: Option<PdfColor>,
+    ) -> Result<PdfBitmap<'_>, PdfiumError> {
+        self.render_with_padding(width, height, PdfPoints::ZERO, background)
+    }
+
+    /// Renders this [PdfPageObject] in isolation into a new [PdfBitmap] with the given
+    /// pixel dimensions, surrounding the object's bounds with the given padding on all sides.
+    ///
+    /// See [PdfPageObjectCommon::render()] for more information.
+    fn render_with_padding(
+        &self,
+        width: Pixels,
+        height: Pixels,
+        padding: PdfPoints,
+        background: Option<PdfColor>,
+    ) -> Result<PdfBitmap<'_>, PdfiumError>;
 }
 
 // Blanket implementation for all PdfPageObject types.
@@ -749,16 +925,129 @@ where
         self.has_transparency_impl()
     }
 
+    #[cfg(any(feature = "pdfium_future", feature = "pdfium_6996"))]
+    fn is_active(&self) -> Result<bool, PdfiumError> {
+        if !self
+            .bindings()
+            .is_function_available("FPDFPageObj_GetIsActive")
+        {
+            return Err(PdfiumError::UnsupportedOperation);
+        }
+
+        let mut active = self.bindings().FALSE();
+
+        if self.bindings().is_true(
+            self.bindings()
+                .FPDFPageObj_GetIsActive(self.object_handle(), &mut active),
+        ) {
+            Ok(self.bindings().is_true(active))
+        } else {
+            Err(self.bindings().error_in(
+                "FPDFPageObj_GetIsActive",
+                PdfiumError::PdfiumFunctionReturnValueIndicatedFailure,
+            ))
+        }
+    }
+
+    #[cfg(not(any(feature = "pdfium_future", feature = "pdfium_6996")))]
+    #[inline]
+    fn is_active(&self) -> Result<bool, PdfiumError> {
+        Err(PdfiumError::UnsupportedOperation)
+    }
+
+    #[cfg(any(feature = "pdfium_future", feature = "pdfium_6996"))]
+    fn set_active(&mut self, active: bool) -> Result<(), PdfiumError> {
+        if !self
+            .bindings()
+            .is_function_available("FPDFPageObj_SetIsActive")
+        {
+            return Err(PdfiumError::UnsupportedOperation);
+        }
+
+        if self.bindings().is_true(
+            self.bindings().FPDFPageObj_SetIsActive(
+                self.object_handle(),
+                self.bindings().bool_to_pdfium(active),
+            ),
+        ) {
+            // Inactive objects are omitted from the page's content stream, so the content
+            // stream must be regenerated for the change to be saved.
+
+            self.regenerate_content_after_mutation()
+        } else {
+            Err(self.bindings().error_in(
+                "FPDFPageObj_SetIsActive",
+                PdfiumError::PdfiumFunctionReturnValueIndicatedFailure,
+            ))
+        }
+    }
+
+    #[cfg(not(any(feature = "pdfium_future", feature = "pdfium_6996")))]
+    #[inline]
+    fn set_active(&mut self, _active: bool) -> Result<(), PdfiumError> {
+        Err(PdfiumError::UnsupportedOperation)
+    }
+
     #[inline]
     fn bounds(&self) -> Result<PdfQuadPoints, PdfiumError> {
         self.bounds_impl()
     }
 
+    #[inline]
+    fn quad_bounds(&self) -> Result<PdfQuadPoints, PdfiumError> {
+        self.quad_bounds_impl()
+    }
+
     #[inline]
     fn transform_from(&mut self, other: &PdfPageObject) -> Result<(), PdfiumError> {
         self.reset_matrix_impl(other.matrix()?)
     }
 
+    #[inline]
+    fn clip_path(&self) -> Option<PdfClipPath> {
+        let handle = self
+            .bindings()
+            .FPDFPageObj_GetClipPath(self.object_handle());
+
+        if handle.is_null() {
+            None
+        } else {
+            Some(PdfClipPath::from_pdfium(handle, self.bindings()))
+        }
+    }
+
+    #[inline]
+    fn transform_clip_path(&mut self, matrix: PdfMatrix) -> Result<(), PdfiumError> {
+        self.bindings().FPDFPageObj_TransformClipPath(
+            self.object_handle(),
+            matrix.a() as f64,
+            matrix.b() as f64,
+            matrix.c() as f64,
+            matrix.d() as f64,
+            matrix.e() as f64,
+            matrix.f() as f64,
+        );
+
+        self.regenerate_content_after_mutation()
+    }
+
+    #[inline]
+    fn marks(&self) -> PdfPageObjectMarks {
+        PdfPageObjectMarks::from_pdfium(self.object_handle(), *self.ownership(), self.bindings())
+    }
+
+    fn blend_mode(&self) -> Result<PdfPageObjectBlendMode, PdfiumError> {
+        // FPDFPageObj_HasTransparency() returns true if the page object's graphics state
+        // sets any blend mode other than Normal, or sets a fill or stroke alpha value or
+        // a soft mask. If none of these are set, then the blend mode must be Normal.
+
+        if self.has_transparency() {
+            Err(PdfiumError::BlendModeNotAvailable)
+        } else {
+            Ok(PdfPageObjectBlendMode::Normal)
+        }
+    }
+
     #[inline]
     fn set_blend_mode(&mut self, blend_mode: PdfPageObjectBlendMode) -> Result<(), PdfiumError> {
         self.bindings()
@@ -798,7 +1087,10 @@ where
                     .map_err(PdfiumError::UnableToConvertPdfiumColorValueToRustu8)?,
             ))
         } else {
-            Err(PdfiumError::PdfiumFunctionReturnValueIndicatedFailure)
+            Err(self.bindings().error_in(
+                "FPDFPageObj_GetFillColor",
+                PdfiumError::PdfiumFunctionReturnValueIndicatedFailure,
+            ))
         }
     }
 
@@ -816,7 +1108,13 @@ where
         {
             Ok(())
         } else {
-            Err(PdfiumError::PdfiumFunctionReturnValueIndicatedFailure)
+            Err(self
+                .bindings()
+                .error_in(
+                    "FPDFPageObj_SetFillColor",
+                    PdfiumError::PdfiumFunctionReturnValueIndicatedFailure,
+                )
+                .with_note("while setting fill color on page object"))
         }
     }
 
@@ -851,7 +1149,10 @@ where
                     .map_err(PdfiumError::UnableToConvertPdfiumColorValueToRustu8)?,
             ))
         } else {
-            Err(PdfiumError::PdfiumFunctionReturnValueIndicatedFailure)
+            Err(self.bindings().error_in(
+                "FPDFPageObj_GetStrokeColor",
+                PdfiumError::PdfiumFunctionReturnValueIndicatedFailure,
+            ))
         }
     }
 
@@ -869,10 +1170,48 @@ where
         {
             Ok(())
         } else {
-            Err(PdfiumError::PdfiumFunctionReturnValueIndicatedFailure)
+            Err(self
+                .bindings()
+                .error_in(
+                    "FPDFPageObj_SetStrokeColor",
+                    PdfiumError::PdfiumFunctionReturnValueIndicatedFailure,
+                )
+                .with_note("while setting stroke color on page object"))
         }
     }
 
+    #[inline]
+    fn opacity(&self) -> (f32, f32) {
+        (self.fill_opacity(), self.stroke_opacity())
+    }
+
+    #[inline]
+    fn fill_opacity(&self) -> f32 {
+        self.fill_color()
+            .map_or(1.0, |color| color.alpha() as f32 / 255.0)
+    }
+
+    #[inline]
+    fn stroke_opacity(&self) -> f32 {
+        self.stroke_color()
+            .map_or(1.0, |color| color.alpha() as f32 / 255.0)
+    }
+
+    fn set_opacity(&mut self, fill_alpha: f32, stroke_alpha: f32) -> Result<(), PdfiumError> {
+        let to_alpha = |value: f32| (value.clamp(0.0, 1.0) * 255.0).round() as u8;
+
+        let fill_color = self.fill_color().unwrap_or(PdfColor::BLACK);
+
+        let stroke_color = self.stroke_color().unwrap_or(PdfColor::BLACK);
+
+        self.set_fill_color(fill_color.with_alpha(to_alpha(fill_alpha)))?;
+        self.set_stroke_color(stroke_color.with_alpha(to_alpha(stroke_alpha)))?;
+
+        update_opacity_mark(self.object_handle(), self.ownership(), self.bindings())?;
+
+        self.regenerate_content_after_mutation()
+    }
+
     #[inline]
     fn stroke_width(&self) -> Result<PdfPoints, PdfiumError> {
         let mut width = 0.0;
@@ -883,7 +1222,10 @@ where
         ) {
             Ok(PdfPoints::new(width))
         } else {
-            Err(PdfiumError::PdfiumFunctionReturnValueIndicatedFailure)
+            Err(self.bindings().error_in(
+                "FPDFPageObj_GetStrokeWidth",
+                PdfiumError::PdfiumFunctionReturnValueIndicatedFailure,
+            ))
         }
     }
 
@@ -895,7 +1237,10 @@ where
         ) {
             Ok(())
         } else {
-            Err(PdfiumError::PdfiumFunctionReturnValueIndicatedFailure)
+            Err(self.bindings().error_in(
+                "FPDFPageObj_SetStrokeWidth",
+                PdfiumError::PdfiumFunctionReturnValueIndicatedFailure,
+            ))
         }
     }
 
@@ -905,7 +1250,12 @@ where
             self.bindings()
                 .FPDFPageObj_GetLineJoin(self.object_handle()),
         )
-        .ok_or(PdfiumError::PdfiumFunctionReturnValueIndicatedFailure)
+        .ok_or_else(|| {
+            self.bindings().error_in(
+                "FPDFPageObj_GetLineJoin",
+                PdfiumError::PdfiumFunctionReturnValueIndicatedFailure,
+            )
+        })
     }
 
     #[inline]
@@ -916,8 +1266,32 @@ where
         ) {
             Ok(())
         } else {
-            Err(PdfiumError::PdfiumFunctionReturnValueIndicatedFailure)
+            Err(self.bindings().error_in(
+                "FPDFPageObj_SetLineJoin",
+                PdfiumError::PdfiumFunctionReturnValueIndicatedFailure,
+            ))
+        }
+    }
+
+    #[inline]
+    fn miter_limit(&self) -> Result<f32, PdfiumError> {
+        recorded_miter_limit(self.object_handle(), self.ownership(), self.bindings())
+            .ok_or(PdfiumError::MiterLimitNotAvailable)
+    }
+
+    fn set_miter_limit(&mut self, limit: f32) -> Result<(), PdfiumError> {
+        // The PDF specification requires the miter limit to be at least 1.
+
+        if !limit.is_finite() || limit < 1.0 {
+            return Err(PdfiumError::InvalidMiterLimit);
         }
+
+        record_miter_limit(
+            self.object_handle(),
+            self.ownership(),
+            self.bindings(),
+            limit,
+        )
     }
 
     #[inline]
@@ -925,7 +1299,12 @@ where
         PdfPageObjectLineCap::from_pdfium(
             self.bindings().FPDFPageObj_GetLineCap(self.object_handle()),
         )
-        .ok_or(PdfiumError::PdfiumFunctionReturnValueIndicatedFailure)
+        .ok_or_else(|| {
+            self.bindings().error_in(
+                "FPDFPageObj_GetLineCap",
+                PdfiumError::PdfiumFunctionReturnValueIndicatedFailure,
+            )
+        })
     }
 
     #[inline]
@@ -936,7 +1315,10 @@ where
         ) {
             Ok(())
         } else {
-            Err(PdfiumError::PdfiumFunctionReturnValueIndicatedFailure)
+            Err(self.bindings().error_in(
+                "FPDFPageObj_SetLineCap",
+                PdfiumError::PdfiumFunctionReturnValueIndicatedFailure,
+            ))
         }
     }
 
@@ -950,7 +1332,10 @@ where
         ) {
             Ok(PdfPoints::new(phase))
         } else {
-            Err(PdfiumError::PdfiumFunctionReturnValueIndicatedFailure)
+            Err(self.bindings().error_in(
+                "FPDFPageObj_GetDashPhase",
+                PdfiumError::PdfiumFunctionReturnValueIndicatedFailure,
+            ))
         }
     }
 
@@ -962,7 +1347,10 @@ where
         ) {
             Ok(())
         } else {
-            Err(PdfiumError::PdfiumFunctionReturnValueIndicatedFailure)
+            Err(self.bindings().error_in(
+                "FPDFPageObj_SetDashPhase",
+                PdfiumError::PdfiumFunctionReturnValueIndicatedFailure,
+            ))
         }
     }
 
@@ -987,7 +1375,10 @@ where
                 .map(|dash| PdfPoints::new(*dash))
                 .collect())
         } else {
-            Err(PdfiumError::PdfiumFunctionReturnValueIndicatedFailure)
+            Err(self.bindings().error_in(
+                "FPDFPageObj_GetDashArray",
+                PdfiumError::PdfiumFunctionReturnValueIndicatedFailure,
+            ))
         }
     }
 
@@ -1005,7 +1396,10 @@ where
         {
             Ok(())
         } else {
-            Err(PdfiumError::PdfiumFunctionReturnValueIndicatedFailure)
+            Err(self.bindings().error_in(
+                "FPDFPageObj_SetDashArray",
+                PdfiumError::PdfiumFunctionReturnValueIndicatedFailure,
+            ))
         }
     }
 
@@ -1021,6 +1415,17 @@ where
     ) -> Result<PdfPageObject<'b>, PdfiumError> {
         self.try_copy_impl(document.handle(), document.bindings())
     }
+
+    #[inline]
+    fn render_with_padding(
+        &self,
+        width: Pixels,
+        height: Pixels,
+        padding: PdfPoints,
+        background: Option<PdfColor>,
+    ) -> Result<PdfBitmap<'_>, PdfiumError> {
+        self.render_impl(width, height, padding, background)
+    }
 }
 
 impl<'a> PdfPageObjectPrivate<'a> for PdfPageObject<'a> {
@@ -1081,6 +1486,16 @@ impl<'a> PdfPageObjectPrivate<'a> for PdfPageObject<'a> {
     ) -> Result<PdfPageObject<'b>, PdfiumError> {
         self.unwrap_as_trait().try_copy_impl(document, bindings)
     }
+
+    #[inline]
+    fn copy_for_render_impl<'b>(
+        &self,
+        document: FPDF_DOCUMENT,
+        bindings: &'b dyn PdfiumLibraryBindings,
+    ) -> Result<PdfPageObject<'b>, PdfiumError> {
+        self.unwrap_as_trait()
+            .copy_for_render_impl(document, bindings)
+    }
 }
 
 impl<'a> From<PdfPageXObjectFormObject<'a>> for PdfPageObject<'a> {
@@ -1147,9 +1562,39 @@ impl<'a> Drop for PdfPageObject<'a> {
 
 #[cfg(test)]
 mod tests {
+    use crate::pdf::document::page::object::ownership::PdfPageObjectOwnership;
     use crate::prelude::*;
     use crate::utils::test::test_bind_to_pdfium;
 
+    #[test]
+    fn test_error_context_names_failed_function() {
+        let pdfium = test_bind_to_pdfium();
+
+        // A page object with a null handle is invalid, so Pdfium will refuse to set its fill color.
+
+        let mut object = PdfPagePathObject::from_pdfium(
+            std::ptr::null_mut(),
+            PdfPageObjectOwnership::unowned(),
+            pdfium.bindings(),
+        );
+
+        let error = object.set_fill_color(PdfColor::RED).unwrap_err();
+
+        let context = error.context().unwrap();
+
+        assert_eq!(context.function(), "FPDFPageObj_SetFillColor");
+        assert_eq!(
+            context.note(),
+            Some("while setting fill color on page object")
+        );
+        assert!(matches!(
+            error.without_context(),
+            PdfiumError::PdfiumFunctionReturnValueIndicatedFailure
+        ));
+        assert!(error.to_string().contains("FPDFPageObj_SetFillColor"));
+        assert!(std::error::Error::source(&error).is_some());
+    }
+
     #[test]
     fn test_apply_matrix() -> Result<(), PdfiumError> {
         let pdfium = test_bind_to_pdfium();
@@ -1228,6 +1673,64 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_blend_mode_of_opaque_object_is_normal() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        let mut page = document
+            .pages_mut()
+            .create_page_at_start(PdfPagePaperSize::a4())?;
+
+        let object = page.objects_mut().create_path_object_rect(
+            PdfRect::new_from_values(100.0, 100.0, 200.0, 200.0),
+            None,
+            None,
+            Some(PdfColor::RED),
+        )?;
+
+        assert!(!object.has_transparency());
+        assert_eq!(object.blend_mode()?, PdfPageObjectBlendMode::Normal);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_blend_mode_of_blended_object_is_not_available() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        let mut page = document
+            .pages_mut()
+            .create_page_at_start(PdfPagePaperSize::a4())?;
+
+        let mut object = page.objects_mut().create_path_object_rect(
+            PdfRect::new_from_values(100.0, 100.0, 200.0, 200.0),
+            None,
+            None,
+            Some(PdfColor::RED),
+        )?;
+
+        object.set_blend_mode(PdfPageObjectBlendMode::Multiply)?;
+
+        assert!(object.has_transparency());
+        assert!(object.marks().is_empty());
+        assert!(matches!(
+            object.blend_mode(),
+            Err(PdfiumError::BlendModeNotAvailable)
+        ));
+
+        let bytes = document.save_to_bytes()?;
+
+        assert!(!bytes
+            .windows(b"PdfiumRender".len())
+            .any(|window| window == b"PdfiumRender"));
+
+        Ok(())
+    }
+
     #[test]
     fn test_transform_captured_in_content_regeneration() -> Result<(), PdfiumError> {
         // The purpose of the test is to confirm that object transformations are correctly
@@ -1281,4 +1784,204 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_render_path_object() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        let mut page = document
+            .pages_mut()
+            .create_page_at_start(PdfPagePaperSize::a4())?;
+
+        // A 100 x 50 point rectangle, filled but not stroked, so its bounds are exact.
+
+        let object = page.objects_mut().create_path_object_rect(
+            PdfRect::new_from_values(100.0, 100.0, 150.0, 200.0),
+            None,
+            None,
+            Some(PdfColor::RED),
+        )?;
+
+        let pixel = |bytes: &[u8], width: usize, x: usize, y: usize| {
+            let offset = (y * width + x) * 4;
+
+            [
+                bytes[offset],
+                bytes[offset + 1],
+                bytes[offset + 2],
+                bytes[offset + 3],
+            ]
+        };
+
+        // Rendered without padding, the object should fill the entire bitmap.
+
+        let bitmap = object.render(200, 100, Some(PdfColor::WHITE))?;
+
+        assert_eq!(bitmap.width(), 200);
+        assert_eq!(bitmap.height(), 100);
+
+        let bytes = bitmap.as_rgba_bytes();
+
+        assert_eq!(pixel(&bytes, 200, 2, 2), [255, 0, 0, 255]);
+        assert_eq!(pixel(&bytes, 200, 100, 50), [255, 0, 0, 255]);
+        assert_eq!(pixel(&bytes, 200, 197, 97), [255, 0, 0, 255]);
+
+        // Rendered with 10 points of padding at two pixels per point, the object should
+        // occupy a 200 x 100 pixel region surrounded by a 20 pixel transparent border.
+
+        let bitmap = object.render_with_padding(240, 140, PdfPoints::new(10.0), None)?;
+
+        let bytes = bitmap.as_rgba_bytes();
+
+        assert_eq!(pixel(&bytes, 240, 5, 5)[3], 0);
+        assert_eq!(pixel(&bytes, 240, 234, 134)[3], 0);
+        assert_eq!(pixel(&bytes, 240, 120, 70), [255, 0, 0, 255]);
+        assert_eq!(
+            bytes
+                .chunks_exact(4)
+                .filter(|pixel| pixel[3] == 255)
+                .count(),
+            200 * 100
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(any(feature = "pdfium_future", feature = "pdfium_6996"))]
+    fn test_set_active() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        if !pdfium
+            .bindings()
+            .is_function_available("FPDFPageObj_SetIsActive")
+        {
+            // The bound Pdfium library is too old to support page object active states.
+
+            return Ok(());
+        }
+
+        // Returns the red channel of the pixel at the center of the test rectangle.
+
+        fn red_at_center(page: &PdfPage) -> Result<u8, PdfiumError> {
+            let bitmap = page.render_with_config(
+                &PdfRenderConfig::new().set_target_width(page.width().value as i32),
+            )?;
+
+            let (x, y) = (200, bitmap.height() as usize - 200);
+
+            Ok(bitmap.as_rgba_bytes()[(y * bitmap.width() as usize + x) * 4])
+        }
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        let mut page = document
+            .pages_mut()
+            .create_page_at_start(PdfPagePaperSize::a4())?;
+
+        let mut object = page.objects_mut().create_path_object_rect(
+            PdfRect::new_from_values(100.0, 100.0, 300.0, 300.0),
+            None,
+            None,
+            Some(PdfColor::BLUE),
+        )?;
+
+        assert!(object.is_active()?);
+        assert_eq!(red_at_center(&page)?, 0);
+
+        object.set_active(false)?;
+
+        assert!(!object.is_active()?);
+        assert_eq!(red_at_center(&page)?, 255);
+
+        // The inactive object must be omitted from the saved document.
+
+        let saved = pdfium.load_pdf_from_byte_vec(document.save_to_bytes()?, None)?;
+
+        assert_eq!(red_at_center(&saved.pages().first()?)?, 255);
+
+        // Making the object active again must restore it.
+
+        let page = document.pages().first()?;
+
+        let mut object = page.objects().get(0)?;
+
+        object.set_active(true)?;
+
+        assert!(object.is_active()?);
+        assert_eq!(red_at_center(&page)?, 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_quad_bounds_of_rotated_objects() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        let mut page = document
+            .pages_mut()
+            .create_page_at_start(PdfPagePaperSize::a4())?;
+
+        let font = document.fonts_mut().helvetica();
+
+        // A long, thin text object rotated by 45 degrees occupies only a small fraction of
+        // its axis-aligned bounding box.
+
+        let mut text = page.objects_mut().create_text_object(
+            PdfPoints::ZERO,
+            PdfPoints::ZERO,
+            "A long and very thin line of text, rotated by forty five degrees",
+            font,
+            PdfPoints::new(10.0),
+        )?;
+
+        text.rotate_counter_clockwise_degrees(45.0)?;
+        text.translate(PdfPoints::new(200.0), PdfPoints::new(200.0))?;
+
+        let quad = text.quad_bounds()?;
+        let rect = quad.to_axis_aligned_rect();
+
+        assert!(quad.area() * 5.0 < rect.width().value * rect.height().value);
+
+        // The top left corner of the axis-aligned bounding box lies well away from the text.
+
+        let corner = PdfRect::new_from_values(
+            rect.top().value - 10.0,
+            rect.left().value,
+            rect.top().value,
+            rect.left().value + 10.0,
+        );
+
+        assert!(text.does_overlap_rect(&corner));
+        assert!(!text.does_overlap_rect_precise(&corner));
+        assert!(text.does_overlap_rect_precise(&rect));
+
+        // Path objects do not support tight fitting bounds directly in Pdfium, so their
+        // unrotated bounds are transformed by the object's matrix instead. Doing so
+        // must not change the object's matrix.
+
+        let mut path = page.objects_mut().create_path_object_rect(
+            PdfRect::new_from_values(0.0, 0.0, 5.0, 300.0),
+            None,
+            None,
+            Some(PdfColor::BLUE),
+        )?;
+
+        path.rotate_clockwise_degrees(45.0)?;
+
+        let matrix = path.matrix()?;
+
+        let quad = path.quad_bounds()?;
+        let rect = quad.to_axis_aligned_rect();
+
+        assert_eq!(path.matrix()?, matrix);
+        assert!((quad.area() - 1500.0).abs() < 1.0);
+        assert!(quad.area() * 5.0 < rect.width().value * rect.height().value);
+
+        Ok(())
+    }
 }
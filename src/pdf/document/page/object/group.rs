@@ -14,6 +14,8 @@ use crate::pdf::document::page::object::{
     PdfPageObjectLineJoin,
 };
 use crate::pdf::document::page::objects::common::{PdfPageObjectIndex, PdfPageObjectsCommon};
+use crate::pdf::document::page::objects::PdfPageObjects;
+use crate::pdf::document::page::overlay::PdfPageOverlay;
 use crate::pdf::document::page::{
     PdfPage, PdfPageContentRegenerationStrategy, PdfPageObjectOwnership,
 };
@@ -191,49 +193,13 @@ impl<'a> PdfPageGroupObject<'a> {
 
     /// Adds all the given [PdfPageObject] objects to this group.
     pub fn append(&mut self, objects: &mut [PdfPageObject<'a>]) -> Result<(), PdfiumError> {
-        // Hold off regenerating page content until all objects have been processed.
-
-        let content_regeneration_strategy =
-            PdfPageIndexCache::get_content_regeneration_strategy_for_page(
-                self.document_handle(),
-                self.page_handle(),
-            )
-            .unwrap_or(PdfPageContentRegenerationStrategy::AutomaticOnEveryChange);
-
-        let page_index =
-            PdfPageIndexCache::get_index_for_page(self.document_handle(), self.page_handle());
-
-        if let Some(page_index) = page_index {
-            PdfPageIndexCache::cache_props_for_page(
-                self.document_handle(),
-                self.page_handle(),
-                page_index,
-                PdfPageContentRegenerationStrategy::Manual,
-            );
-        }
-
-        for object in objects.iter_mut() {
-            self.push(object)?;
-        }
-
-        // Regenerate page content now, if necessary.
-
-        if let Some(page_index) = page_index {
-            PdfPageIndexCache::cache_props_for_page(
-                self.document_handle(),
-                self.page_handle(),
-                page_index,
-                content_regeneration_strategy,
-            );
-        }
-
-        if content_regeneration_strategy
-            == PdfPageContentRegenerationStrategy::AutomaticOnEveryChange
-        {
-            PdfPage::regenerate_content_immut_for_handle(self.page_handle(), self.bindings())?;
-        }
+        self.with_deferred_content_regeneration(|group| {
+            for object in objects.iter_mut() {
+                group.push(object)?;
+            }
 
-        Ok(())
+            Ok(())
+        })
     }
 
     /// Removes every [PdfPageObject] in this group from the group's containing [PdfPage]
@@ -643,6 +609,61 @@ impl<'a> PdfPageGroupObject<'a> {
         self.apply_to_each(|object| object.set_blend_mode(blend_mode))
     }
 
+    /// Replaces every [PdfPageObject] in this group with a single form object painting the
+    /// same objects as a transparency group with the given opacity, between 0.0 (fully
+    /// transparent) and 1.0 (fully opaque). Consumes this group, returning the new form object.
+    ///
+    /// Setting the opacity of each object individually using [PdfPageObjectCommon::set_opacity()]
+    /// darkens areas where semi-transparent objects overlap. The objects in a transparency group
+    /// are instead composited together before the opacity is applied, so the group fades
+    /// uniformly, as if it were a single object.
+    ///
+    /// The new form object takes the place of the lowest object in this group in the page's
+    /// z-order. Any objects not in this group that were painted between objects in this group
+    /// will now be painted in front of the whole group.
+    pub fn set_group_opacity(mut self, opacity: f32) -> Result<PdfPageObject<'a>, PdfiumError> {
+        if self.is_empty() {
+            return Err(PdfiumError::EmptyPageObjectGroup);
+        }
+
+        // Copy the objects in this group onto a new page in an in-memory document, then
+        // create a form XObject from that page in this group's document. The new page has
+        // the same size as the source page, so the form object needs no transformation.
+
+        let cache = Pdfium::pdfium_document_handle_to_result(
+            self.bindings.FPDF_CreateNewDocument(),
+            self.bindings,
+        )?;
+
+        self.copy_onto_new_page_at_end(&cache)?;
+
+        let form = PdfPageOverlay::new(self.document_handle, cache.handle(), 0, self.bindings)?
+            .create_object()?;
+
+        let index = (0..self.bindings.FPDFPage_CountObjects(self.page_handle))
+            .find(|index| {
+                self.object_handles
+                    .contains(&self.bindings.FPDFPage_GetObject(self.page_handle, *index))
+            })
+            .unwrap_or(0) as PdfPageObjectIndex;
+
+        // Replace the objects in this group with the form object.
+
+        self.apply_to_each(|object| object.remove_object_from_page())?;
+        self.object_handles.clear();
+
+        let mut objects =
+            PdfPageObjects::from_pdfium(self.document_handle, self.page_handle, self.bindings);
+
+        let mut form = objects.add_object(form)?;
+
+        form.set_opacity(opacity, opacity)?;
+
+        objects.move_to_index(objects.len() - 1, index)?;
+
+        Ok(form)
+    }
+
     /// Sets the color of any filled paths in every [PdfPageObject] in this group.
     #[inline]
     pub fn set_fill_color(&mut self, fill_color: PdfColor) -> Result<(), PdfiumError> {
@@ -708,23 +729,81 @@ impl<'a> PdfPageGroupObject<'a> {
     }
 
     /// Applies the given closure to each [PdfPageObject] in this group.
+    ///
+    /// Content regeneration of the containing [PdfPage] is deferred until the closure
+    /// has been applied to every object, so that page content is regenerated at most once
+    /// no matter how many objects are in this group.
     #[inline]
     pub(crate) fn apply_to_each<F, T>(&mut self, f: F) -> Result<(), PdfiumError>
     where
         F: Fn(&mut PdfPageObject<'a>) -> Result<T, PdfiumError>,
     {
-        let mut error = None;
+        self.with_deferred_content_regeneration(|group| {
+            let mut error = None;
 
-        self.object_handles.iter().for_each(|handle| {
-            if let Err(err) = f(&mut self.get_object_from_handle(handle)) {
-                error = Some(err)
+            group.object_handles.iter().for_each(|handle| {
+                if let Err(err) = f(&mut group.get_object_from_handle(handle)) {
+                    error = Some(err)
+                }
+            });
+
+            match error {
+                Some(err) => Err(err),
+                None => Ok(()),
             }
-        });
+        })
+    }
+
+    /// Calls the given closure with automatic content regeneration of the containing [PdfPage]
+    /// suspended, then regenerates page content once if the page's content regeneration
+    /// strategy is `PdfPageContentRegenerationStrategy::AutomaticOnEveryChange`.
+    fn with_deferred_content_regeneration<F, T>(&mut self, f: F) -> Result<T, PdfiumError>
+    where
+        F: FnOnce(&mut Self) -> Result<T, PdfiumError>,
+    {
+        // Hold off regenerating page content until all objects have been processed.
+
+        let content_regeneration_strategy =
+            PdfPageIndexCache::get_content_regeneration_strategy_for_page(
+                self.document_handle(),
+                self.page_handle(),
+            )
+            .unwrap_or(PdfPageContentRegenerationStrategy::AutomaticOnEveryChange);
+
+        let page_index =
+            PdfPageIndexCache::get_index_for_page(self.document_handle(), self.page_handle());
+
+        if let Some(page_index) = page_index {
+            PdfPageIndexCache::cache_props_for_page(
+                self.document_handle(),
+                self.page_handle(),
+                page_index,
+                PdfPageContentRegenerationStrategy::Manual,
+            );
+        }
+
+        let result = f(self);
+
+        // Restore the page's content regeneration strategy, even if the closure failed.
+
+        if let Some(page_index) = page_index {
+            PdfPageIndexCache::cache_props_for_page(
+                self.document_handle(),
+                self.page_handle(),
+                page_index,
+                content_regeneration_strategy,
+            );
+        }
 
-        match error {
-            Some(err) => Err(err),
-            None => Ok(()),
+        // Regenerate page content now, if necessary.
+
+        if content_regeneration_strategy
+            == PdfPageContentRegenerationStrategy::AutomaticOnEveryChange
+        {
+            PdfPage::regenerate_content_immut_for_handle(self.page_handle(), self.bindings())?;
         }
+
+        result
     }
 
     /// Calls the given closure on each [PdfPageObject] in this group.
@@ -923,4 +1002,47 @@ mod test {
 
         Ok(())
     }
+
+    #[test]
+    fn test_group_broadcast_setters_defer_content_regeneration() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        let mut page = document
+            .pages_mut()
+            .create_page_at_start(PdfPagePaperSize::a4())?;
+
+        for index in 0..100 {
+            let offset = index as f32 * 5.0;
+
+            page.objects_mut().create_path_object_rect(
+                PdfRect::new_from_values(offset, offset, offset + 10.0, offset + 10.0),
+                None,
+                None,
+                Some(PdfColor::RED),
+            )?;
+        }
+
+        let mut group = PdfPageGroupObject::new(&page, |_| true)?;
+
+        group.set_fill_color(PdfColor::GREEN)?;
+        group.translate(PdfPoints::new(10.0), PdfPoints::new(10.0))?;
+
+        // Every object in the group should have been updated, and the page's content
+        // regeneration strategy should have been restored once the group operations completed.
+
+        assert!(group
+            .iter()
+            .all(|object| object.fill_color().unwrap().to_hex() == PdfColor::GREEN.to_hex()));
+
+        assert_eq!(group.bounds()?.bottom().value, 10.0);
+
+        assert_eq!(
+            page.content_regeneration_strategy(),
+            PdfPageContentRegenerationStrategy::AutomaticOnEveryChange
+        );
+
+        Ok(())
+    }
 }
@@ -6,41 +6,62 @@ use crate::bindgen::{
 };
 use crate::bindings::PdfiumLibraryBindings;
 use crate::error::{PdfiumError, PdfiumInternalError};
-use crate::pdf::bitmap::PdfBitmap;
 use crate::pdf::bitmap::Pixels;
+use crate::pdf::bitmap::{PdfBitmap, PdfBitmapFormat};
 use crate::pdf::color_space::PdfColorSpace;
 use crate::pdf::document::page::object::private::internal::PdfPageObjectPrivate;
-use crate::pdf::document::page::object::{PdfPageObject, PdfPageObjectOwnership};
+use crate::pdf::document::page::object::{
+    PdfPageObject, PdfPageObjectCommon, PdfPageObjectOwnership,
+};
 use crate::pdf::document::PdfDocument;
 use crate::pdf::matrix::{PdfMatrix, PdfMatrixValue};
 use crate::pdf::points::PdfPoints;
+use crate::pdf::rect::PdfRect;
+use crate::utils::files::get_pdfium_file_accessor_from_reader;
 use crate::utils::mem::create_byte_buffer;
 use crate::{create_transform_getters, create_transform_setters};
 use std::convert::TryInto;
+use std::io::{Cursor, Read, Seek, SeekFrom};
 use std::ops::{Range, RangeInclusive};
-use std::os::raw::{c_int, c_void};
+use std::os::raw::{c_int, c_ulong, c_void};
 
 #[cfg(any(feature = "image_latest", feature = "image_025"))]
 use {
-    crate::pdf::bitmap::PdfBitmapFormat,
     crate::utils::pixels::{aligned_bgr_to_rgba, bgra_to_rgba, rgba_to_bgra},
     image_025::{DynamicImage, EncodableLayout, GrayImage, RgbaImage},
 };
 
 #[cfg(feature = "image_024")]
 use {
-    crate::pdf::bitmap::PdfBitmapFormat,
     crate::utils::pixels::{aligned_bgr_to_rgba, bgra_to_rgba, rgba_to_bgra},
     image_024::{DynamicImage, EncodableLayout, GrayImage, RgbaImage},
 };
 
 #[cfg(feature = "image_023")]
 use {
-    crate::pdf::bitmap::PdfBitmapFormat,
     crate::utils::pixels::{aligned_bgr_to_rgba, bgra_to_rgba, rgba_to_bgra},
     image_023::{DynamicImage, EncodableLayout, GenericImageView, GrayImage, RgbaImage},
 };
 
+/// The strategy used by [PdfPageImageObject::scale_to_fit()] to size a [PdfPageImageObject]
+/// within a target rectangle.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum PdfPageImageObjectScaleMode {
+    /// Scales the image uniformly so that it fits entirely inside the target rectangle,
+    /// centering it along the axis with spare room. The aspect ratio of the image is
+    /// preserved; the uncovered parts of the target rectangle are left empty.
+    Fit,
+
+    /// Scales the image uniformly so that it covers the entire target rectangle, centering it
+    /// along the axis that overflows. The aspect ratio of the image is preserved; parts of the
+    /// image will extend beyond the target rectangle unless the object is clipped.
+    Fill,
+
+    /// Scales the image independently along each axis so that it exactly matches the target
+    /// rectangle. The aspect ratio of the image is not preserved.
+    Stretch,
+}
+
 /// A single `PdfPageObject` of type `PdfPageObjectType::Image`. The page object defines a single
 /// bitmapped image.
 ///
@@ -121,8 +142,9 @@ impl<'a> PdfPageImageObject<'a> {
         let handle = bindings.FPDFPageObj_NewImageObj(document);
 
         if handle.is_null() {
-            Err(PdfiumError::PdfiumLibraryInternalError(
-                PdfiumInternalError::Unknown,
+            Err(bindings.error_in(
+                "FPDFPageObj_NewImageObj",
+                PdfiumError::PdfiumLibraryInternalError(PdfiumInternalError::Unknown),
             ))
         } else {
             Ok(PdfPageImageObject {
@@ -405,8 +427,9 @@ impl<'a> PdfPageImageObject<'a> {
             // to the caller.
 
             self.reset_matrix_impl(original_matrix)?;
-            return Err(PdfiumError::PdfiumLibraryInternalError(
-                PdfiumInternalError::Unknown,
+            return Err(self.bindings.error_in(
+                "FPDFImageObj_GetRenderedBitmap",
+                PdfiumError::PdfiumLibraryInternalError(PdfiumInternalError::Unknown),
             ));
         }
 
@@ -546,6 +569,11 @@ impl<'a> PdfPageImageObject<'a> {
 
     /// Applies the byte data in the given `Image::DynamicImage` to this [PdfPageImageObject].
     ///
+    /// If the given image has an alpha channel containing any pixels that are not fully opaque,
+    /// the alpha channel is written to the document as a separate soft mask image, so that
+    /// the transparency of the image is preserved when it is rendered. Images that are fully
+    /// opaque are written without a soft mask.
+    ///
     /// This function is only available when this crate's `image` feature is enabled.
     #[cfg(feature = "image_api")]
     pub fn set_image(&mut self, image: &DynamicImage) -> Result<(), PdfiumError> {
@@ -559,26 +587,48 @@ impl<'a> PdfPageImageObject<'a> {
             .try_into()
             .map_err(|_| PdfiumError::ImageSizeOutOfBounds)?;
 
-        let bitmap = PdfBitmap::empty(width, height, PdfBitmapFormat::BGRA, self.bindings)?;
+        let converted;
 
-        let buffer = if let Some(image) = image.as_rgba8() {
+        let rgba = if let Some(image) = image.as_rgba8() {
             // The given image is already in RGBA format.
 
-            rgba_to_bgra(image.as_bytes())
+            image
         } else {
             // The image must be converted to RGBA first.
 
-            let image = image.to_rgba8();
+            converted = image.to_rgba8();
+
+            &converted
+        };
+
+        // Pdfium writes the alpha channel of a BGRA bitmap to the document as a separate
+        // soft mask image. We only want to pay for the soft mask if the image actually
+        // contains transparent pixels; otherwise, we use the BGRx format, which Pdfium
+        // writes without a soft mask.
+
+        let is_transparent = image.color().has_alpha()
+            && rgba
+                .as_bytes()
+                .chunks_exact(4)
+                .any(|pixel| pixel[3] < u8::MAX);
 
-            rgba_to_bgra(image.as_bytes())
+        let format = if is_transparent {
+            PdfBitmapFormat::BGRA
+        } else {
+            PdfBitmapFormat::BGRx
         };
 
+        let bitmap = PdfBitmap::empty(width, height, format, self.bindings)?;
+
+        let buffer = rgba_to_bgra(rgba.as_bytes());
+
         if !self
             .bindings
             .FPDFBitmap_SetBuffer(*bitmap.handle(), buffer.as_slice())
         {
-            return Err(PdfiumError::PdfiumLibraryInternalError(
-                PdfiumInternalError::Unknown,
+            return Err(self.bindings.error_in(
+                "FPDFBitmap_SetBuffer",
+                PdfiumError::PdfiumLibraryInternalError(PdfiumInternalError::Unknown),
             ));
         }
 
@@ -586,6 +636,9 @@ impl<'a> PdfPageImageObject<'a> {
     }
 
     /// Applies the byte data in the given [PdfBitmap] to this [PdfPageImageObject].
+    ///
+    /// If the given bitmap uses the [PdfBitmapFormat::BGRA] pixel format, its alpha channel
+    /// is written to the document as a separate soft mask image.
     pub fn set_bitmap(&mut self, bitmap: &PdfBitmap) -> Result<(), PdfiumError> {
         if self
             .bindings
@@ -598,12 +651,175 @@ impl<'a> PdfPageImageObject<'a> {
         {
             Ok(())
         } else {
-            Err(PdfiumError::PdfiumLibraryInternalError(
-                PdfiumInternalError::Unknown,
+            Err(self.bindings().error_in(
+                "FPDFImageObj_SetBitmap",
+                PdfiumError::PdfiumLibraryInternalError(PdfiumInternalError::Unknown),
+            ))
+        }
+    }
+
+    /// Returns `true` if the image assigned to this [PdfPageImageObject] is drawn with
+    /// transparency, for instance because it has a soft mask image.
+    ///
+    /// Pdfium does not provide direct access to the soft mask of an image, so the transparency
+    /// is determined by rendering the image at its native size. Any other kind of image mask
+    /// that makes part of the image transparent will also be detected.
+    #[inline]
+    pub fn has_soft_mask(&self, document: &PdfDocument) -> Result<bool, PdfiumError> {
+        Ok(self.get_alpha_channel(document)?.is_some())
+    }
+
+    /// Returns a new [PdfBitmap] in the [PdfBitmapFormat::Gray] pixel format containing
+    /// the transparency of the image assigned to this [PdfPageImageObject], with fully
+    /// opaque pixels set to 255 and fully transparent pixels set to 0. Returns `None`
+    /// if the image is drawn without any transparency.
+    ///
+    /// Pdfium does not provide direct access to the soft mask of an image, so the transparency
+    /// is determined by rendering the image at its native size. Any other kind of image mask
+    /// that makes part of the image transparent will also be included in the returned bitmap.
+    pub fn soft_mask_bitmap(
+        &self,
+        document: &PdfDocument,
+    ) -> Result<Option<PdfBitmap<'a>>, PdfiumError> {
+        let (width, height, alpha) = match self.get_alpha_channel(document)? {
+            Some(result) => result,
+            None => return Ok(None),
+        };
+
+        let mask = PdfBitmap::empty(width, height, PdfBitmapFormat::Gray, self.bindings)?;
+
+        // Each scanline in the mask may be padded to a multiple of four bytes.
+
+        let stride = mask.stride();
+
+        let mut buffer = vec![0; stride * height as usize];
+
+        for (source, destination) in alpha
+            .chunks_exact(width as usize)
+            .zip(buffer.chunks_exact_mut(stride))
+        {
+            destination[..source.len()].copy_from_slice(source);
+        }
+
+        if self.bindings.FPDFBitmap_SetBuffer(*mask.handle(), &buffer) {
+            Ok(Some(mask))
+        } else {
+            Err(self.bindings.error_in(
+                "FPDFBitmap_SetBuffer",
+                PdfiumError::PdfiumLibraryInternalError(PdfiumInternalError::Unknown),
+            ))
+        }
+    }
+
+    /// Renders the image assigned to this [PdfPageImageObject] at its native size, returning
+    /// the width, height, and unpadded alpha channel of the rendered image, or `None` if
+    /// every rendered pixel is fully opaque.
+    fn get_alpha_channel(
+        &self,
+        document: &PdfDocument,
+    ) -> Result<Option<(Pixels, Pixels, Vec<u8>)>, PdfiumError> {
+        let rendered = self.get_processed_bitmap(document)?;
+
+        if rendered.format()? != PdfBitmapFormat::BGRA {
+            return Ok(None);
+        }
+
+        let width = rendered.width();
+        let height = rendered.height();
+        let stride = rendered.stride();
+
+        let alpha = rendered
+            .as_raw_bytes()
+            .chunks_exact(stride)
+            .take(height as usize)
+            .flat_map(|scanline| {
+                scanline[..width as usize * 4]
+                    .chunks_exact(4)
+                    .map(|pixel| pixel[3])
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        if alpha.iter().all(|value| *value == u8::MAX) {
+            Ok(None)
+        } else {
+            Ok(Some((width, height, alpha)))
+        }
+    }
+
+    /// Applies the JPEG image data read from the given reader to this [PdfPageImageObject].
+    ///
+    /// The JPEG data is embedded into the document as-is using the `DCTDecode` filter, without
+    /// being decoded and re-encoded. This is considerably faster than applying a decoded image
+    /// using [PdfPageImageObject::set_image()], and avoids both any loss of image quality and
+    /// any increase in output file size caused by re-encoding. The JPEG data is copied into
+    /// the document, so the reader can be safely dropped once this function returns.
+    ///
+    /// [PdfiumError::InvalidJpegImageData] will be returned if the data does not begin with
+    /// a JPEG start-of-image marker.
+    pub fn set_image_from_jpeg_reader<R: Read + Seek>(
+        &mut self,
+        mut reader: R,
+    ) -> Result<(), PdfiumError> {
+        // Pdfium does not reliably validate the data it is given before attempting to decode it,
+        // so we confirm the data at least begins with a JPEG start-of-image marker.
+
+        let mut marker = [0; 2];
+
+        reader
+            .seek(SeekFrom::Start(0))
+            .and_then(|_| reader.read_exact(&mut marker))
+            .map_err(|_| PdfiumError::InvalidJpegImageData)?;
+
+        if marker != [0xFF, 0xD8] {
+            return Err(PdfiumError::InvalidJpegImageData);
+        }
+
+        let mut reader = get_pdfium_file_accessor_from_reader(reader)?;
+
+        // If this object is attached to a page, we pass the page to Pdfium so that it
+        // can clear any cached copy of the object's previous image.
+
+        let mut pages = match self.ownership() {
+            PdfPageObjectOwnership::Page(ownership) => vec![ownership.page_handle()],
+            PdfPageObjectOwnership::AttachedAnnotation(ownership) => {
+                vec![ownership.page_handle()]
+            }
+            _ => vec![],
+        };
+
+        if self
+            .bindings()
+            .is_true(self.bindings().FPDFImageObj_LoadJpegFileInline(
+                if pages.is_empty() {
+                    std::ptr::null_mut()
+                } else {
+                    pages.as_mut_ptr()
+                },
+                pages.len() as c_int,
+                self.object_handle(),
+                reader.as_fpdf_file_access_mut_ptr(),
+            ))
+        {
+            Ok(())
+        } else {
+            Err(self.bindings().error_in(
+                "FPDFImageObj_LoadJpegFileInline",
+                PdfiumError::PdfiumLibraryInternalError(PdfiumInternalError::Unknown),
             ))
         }
     }
 
+    /// Applies the given JPEG image data to this [PdfPageImageObject].
+    ///
+    /// The JPEG data is embedded into the document as-is using the `DCTDecode` filter, without
+    /// being decoded and re-encoded. See [PdfPageImageObject::set_image_from_jpeg_reader()]
+    /// for more information.
+    #[inline]
+    pub fn set_image_from_jpeg_bytes(&mut self, bytes: &[u8]) -> Result<(), PdfiumError> {
+        self.set_image_from_jpeg_reader(Cursor::new(bytes))
+    }
+
     pub(crate) fn get_raw_metadata(&self) -> Result<FPDF_IMAGEOBJ_METADATA, PdfiumError> {
         let mut metadata = FPDF_IMAGEOBJ_METADATA {
             width: 0,
@@ -633,12 +849,47 @@ impl<'a> PdfPageImageObject<'a> {
         if self.bindings().is_true(result) {
             Ok(metadata)
         } else {
-            Err(PdfiumError::PdfiumLibraryInternalError(
-                PdfiumInternalError::Unknown,
+            Err(self.bindings().error_in(
+                "FPDFImageObj_GetImageMetadata",
+                PdfiumError::PdfiumLibraryInternalError(PdfiumInternalError::Unknown),
             ))
         }
     }
 
+    /// Returns the [PdfPageImageObjectMetadata] for the image assigned to this
+    /// [PdfPageImageObject], including its pixel dimensions, effective resolution,
+    /// bits per pixel, and color space.
+    ///
+    /// Pdfium can only determine the bits per pixel and color space of an image that is
+    /// attached to a page, so [PdfiumError::OwnershipNotAttachedToPage] will be returned
+    /// if this object has not been attached to a `PdfPage`. Use the
+    /// [PdfPageImageObject::horizontal_dpi()] and [PdfPageImageObject::vertical_dpi()]
+    /// functions to retrieve the resolution of a detached object.
+    pub fn metadata(&self) -> Result<PdfPageImageObjectMetadata, PdfiumError> {
+        match self.ownership() {
+            PdfPageObjectOwnership::Page(_) | PdfPageObjectOwnership::AttachedAnnotation(_) => {}
+            _ => return Err(PdfiumError::OwnershipNotAttachedToPage),
+        }
+
+        let metadata = self.get_raw_metadata()?;
+
+        Ok(PdfPageImageObjectMetadata {
+            width: metadata
+                .width
+                .try_into()
+                .map_err(|_| PdfiumError::ImageSizeOutOfBounds)?,
+            height: metadata
+                .height
+                .try_into()
+                .map_err(|_| PdfiumError::ImageSizeOutOfBounds)?,
+            horizontal_dpi: metadata.horizontal_dpi,
+            vertical_dpi: metadata.vertical_dpi,
+            bits_per_pixel: metadata.bits_per_pixel as u8,
+            color_space: PdfColorSpace::from_pdfium(metadata.colorspace as u32)
+                .unwrap_or(PdfColorSpace::Unknown),
+        })
+    }
+
     /// Returns the horizontal dots per inch resolution of the image assigned to this
     /// [PdfPageImageObject], based on the intrinsic resolution of the assigned image
     /// and the dimensions of this object.
@@ -681,6 +932,242 @@ impl<'a> PdfPageImageObject<'a> {
         PdfPageImageObjectFilters::new(self)
     }
 
+    /// Returns the raw image data stream of the image assigned to this [PdfPageImageObject],
+    /// exactly as it is stored in the document. No image filters are applied, so the returned
+    /// data will still be encoded using any filters listed by [PdfPageImageObject::filters()].
+    ///
+    /// For an image using only the `DCTDecode` filter, the returned data is a complete JPEG
+    /// file that can be written directly to disk.
+    #[inline]
+    pub fn raw_image_data(&self) -> Result<Vec<u8>, PdfiumError> {
+        self.get_image_data(|bindings, object_handle, buffer, buflen| {
+            bindings.FPDFImageObj_GetImageDataRaw(object_handle, buffer, buflen)
+        })
+    }
+
+    /// Returns the image data stream of the image assigned to this [PdfPageImageObject],
+    /// after decoding it using any filters listed by [PdfPageImageObject::filters()].
+    ///
+    /// Note that Pdfium only applies the filters needed to decompress the data; image filters
+    /// such as `DCTDecode` and `JPXDecode` are not applied, so the image data for images using
+    /// those filters will still be encoded.
+    #[inline]
+    pub fn decoded_image_data(&self) -> Result<Vec<u8>, PdfiumError> {
+        self.get_image_data(|bindings, object_handle, buffer, buflen| {
+            bindings.FPDFImageObj_GetImageDataDecoded(object_handle, buffer, buflen)
+        })
+    }
+
+    /// Retrieves image data from Pdfium using the given image data retrieval function.
+    fn get_image_data(
+        &self,
+        get_data: impl Fn(&dyn PdfiumLibraryBindings, FPDF_PAGEOBJECT, *mut c_void, c_ulong) -> c_ulong,
+    ) -> Result<Vec<u8>, PdfiumError> {
+        // Retrieving the image data from Pdfium is a two-step operation. First, we call
+        // the retrieval function with a null buffer; this will retrieve the length of
+        // the image data in bytes. If the length is zero, then there is no image data.
+
+        // If the length is non-zero, then we reserve a byte buffer of the given
+        // length and call the retrieval function again with a pointer to the buffer;
+        // this will write the image data to the buffer.
+
+        let buffer_length = get_data(
+            self.bindings(),
+            self.object_handle(),
+            std::ptr::null_mut(),
+            0,
+        );
+
+        if buffer_length == 0 {
+            return Err(PdfiumError::PdfiumFunctionReturnValueIndicatedFailure);
+        }
+
+        let mut buffer = create_byte_buffer(buffer_length as usize);
+
+        let result = get_data(
+            self.bindings(),
+            self.object_handle(),
+            buffer.as_mut_ptr() as *mut c_void,
+            buffer_length,
+        );
+
+        if result == buffer_length {
+            Ok(buffer)
+        } else {
+            Err(PdfiumError::PdfiumFunctionReturnValueIndicatedFailure)
+        }
+    }
+
+    /// Scales and moves this [PdfPageImageObject] so that it occupies the given rectangle
+    /// on the page, using the given [PdfPageImageObjectScaleMode] to decide how the aspect
+    /// ratio of the image should be treated. Returns the resulting bounds of this object
+    /// on the page.
+    ///
+    /// The placement is applied on top of any transformations already applied to this object,
+    /// so an object that has been rotated remains rotated. For the purposes of preserving
+    /// the aspect ratio, the shape of the image is taken from its pixel dimensions,
+    /// not from the current size of this object.
+    pub fn scale_to_fit(
+        &mut self,
+        rect: PdfRect,
+        mode: PdfPageImageObjectScaleMode,
+    ) -> Result<PdfRect, PdfiumError> {
+        if !Self::is_valid_placement_size(rect.width(), rect.height()) {
+            return Err(PdfiumError::InvalidImagePlacement);
+        }
+
+        let target = match mode {
+            PdfPageImageObjectScaleMode::Stretch => rect,
+            PdfPageImageObjectScaleMode::Fit | PdfPageImageObjectScaleMode::Fill => {
+                let natural = self.get_natural_bounds()?;
+
+                let horizontal_scale = rect.width().value / natural.width().value;
+                let vertical_scale = rect.height().value / natural.height().value;
+
+                let scale = if mode == PdfPageImageObjectScaleMode::Fit {
+                    horizontal_scale.min(vertical_scale)
+                } else {
+                    horizontal_scale.max(vertical_scale)
+                };
+
+                let width = natural.width().value * scale;
+                let height = natural.height().value * scale;
+
+                let left = rect.left().value + (rect.width().value - width) / 2.0;
+                let bottom = rect.bottom().value + (rect.height().value - height) / 2.0;
+
+                PdfRect::new_from_values(bottom, left, bottom + height, left + width)
+            }
+        };
+
+        self.move_bounds_to(target)
+    }
+
+    /// Scales this [PdfPageImageObject] so that it occupies exactly the given width and height
+    /// on the page, keeping the bottom left corner of its bounds in place. Returns the resulting
+    /// bounds of this object on the page.
+    ///
+    /// The aspect ratio of the image is not preserved. Use [PdfPageImageObject::scale_to_fit()]
+    /// to size the image while preserving its aspect ratio.
+    pub fn scale_to_physical_size(
+        &mut self,
+        width: PdfPoints,
+        height: PdfPoints,
+    ) -> Result<PdfRect, PdfiumError> {
+        if !Self::is_valid_placement_size(width, height) {
+            return Err(PdfiumError::InvalidImagePlacement);
+        }
+
+        let bounds = self.get_current_bounds()?;
+
+        self.move_bounds_to(PdfRect::new(
+            bounds.bottom(),
+            bounds.left(),
+            bounds.bottom() + height,
+            bounds.left() + width,
+        ))
+    }
+
+    /// Scales and moves this [PdfPageImageObject] so that the image is printed at the given
+    /// resolution, in dots per inch, with the bottom left corner of its bounds at the given
+    /// page co-ordinates. The size of this object is calculated from the pixel dimensions of
+    /// the image; for example, an image 600 pixels wide placed at 300 dpi will be 2 inches,
+    /// or 144 points, wide. Returns the resulting bounds of this object on the page.
+    pub fn place_at_dpi(
+        &mut self,
+        x: PdfPoints,
+        y: PdfPoints,
+        dpi: f32,
+    ) -> Result<PdfRect, PdfiumError> {
+        if !(dpi.is_finite() && dpi > 0.0) {
+            return Err(PdfiumError::InvalidImagePlacement);
+        }
+
+        let natural = self.get_natural_bounds()?;
+
+        let points_per_pixel = 72.0 / dpi;
+
+        self.move_bounds_to(PdfRect::new(
+            y,
+            x,
+            y + natural.height() * points_per_pixel,
+            x + natural.width() * points_per_pixel,
+        ))
+    }
+
+    #[inline]
+    fn is_valid_placement_size(width: PdfPoints, height: PdfPoints) -> bool {
+        width.value.is_finite()
+            && height.value.is_finite()
+            && width.value > 0.0
+            && height.value > 0.0
+    }
+
+    /// Returns the bounds of this [PdfPageImageObject] calculated from its transformation matrix.
+    /// An image is always drawn into the unit square of its own coordinate space, so its bounds
+    /// are the unit square mapped onto the page.
+    fn get_current_bounds(&self) -> Result<PdfRect, PdfiumError> {
+        Ok(PdfRect::new_from_values(0.0, 0.0, 1.0, 1.0).transform(self.matrix()?))
+    }
+
+    /// Returns the bounds of the image assigned to this [PdfPageImageObject] at a scale of one
+    /// point per pixel, with any rotation or skew in the current transformation matrix applied.
+    fn get_natural_bounds(&self) -> Result<PdfRect, PdfiumError> {
+        let (width, height) = self.get_current_width_and_height_from_metadata()?;
+
+        if width <= 0 || height <= 0 {
+            return Err(PdfiumError::ImageSizeOutOfBounds);
+        }
+
+        // Remove the scale, but not the orientation, from each axis of the current matrix.
+
+        let matrix = self.matrix()?;
+
+        let horizontal_length = matrix.a().hypot(matrix.b());
+        let vertical_length = matrix.c().hypot(matrix.d());
+
+        if horizontal_length == 0.0 || vertical_length == 0.0 {
+            return Err(PdfiumError::InvalidTransformationMatrix);
+        }
+
+        Ok(
+            PdfRect::new_from_values(0.0, 0.0, height as f32, width as f32).transform(
+                PdfMatrix::new(
+                    matrix.a() / horizontal_length,
+                    matrix.b() / horizontal_length,
+                    matrix.c() / vertical_length,
+                    matrix.d() / vertical_length,
+                    0.0,
+                    0.0,
+                ),
+            ),
+        )
+    }
+
+    /// Applies a scale and translation to this [PdfPageImageObject] that moves its current
+    /// bounds onto the given target rectangle, returning the resulting bounds.
+    fn move_bounds_to(&mut self, target: PdfRect) -> Result<PdfRect, PdfiumError> {
+        let current = self.get_current_bounds()?;
+
+        if current.width().value <= 0.0 || current.height().value <= 0.0 {
+            return Err(PdfiumError::InvalidTransformationMatrix);
+        }
+
+        let horizontal_scale = target.width().value / current.width().value;
+        let vertical_scale = target.height().value / current.height().value;
+
+        self.transform(
+            horizontal_scale,
+            0.0,
+            0.0,
+            vertical_scale,
+            target.left().value - current.left().value * horizontal_scale,
+            target.bottom().value - current.bottom().value * vertical_scale,
+        )?;
+
+        self.get_current_bounds()
+    }
+
     create_transform_setters!(
         &mut Self,
         Result<(), PdfiumError>,
@@ -745,10 +1232,121 @@ impl<'a> PdfPageObjectPrivate<'a> for PdfPageImageObject<'a> {
         let mut copy = PdfPageImageObject::new_from_handle(document, bindings)?;
 
         copy.set_bitmap(&self.get_raw_bitmap()?)?;
+
+        if let Ok(blend_mode) = self.blend_mode() {
+            copy.set_blend_mode(blend_mode)?;
+        }
         copy.reset_matrix(self.matrix()?)?;
 
         Ok(PdfPageObject::Image(copy))
     }
+
+    fn copy_for_render_impl<'b>(
+        &self,
+        document: FPDF_DOCUMENT,
+        bindings: &'b dyn PdfiumLibraryBindings,
+    ) -> Result<PdfPageObject<'b>, PdfiumError> {
+        // Pdfium can only render an image object that belongs to a document. Detached
+        // image objects are copied instead.
+
+        let source_document = match self.ownership().document_handle() {
+            Some(source_document) => source_document,
+            None => return self.try_copy_impl(document, bindings),
+        };
+
+        let page_handle = match self.ownership() {
+            PdfPageObjectOwnership::Page(ownership) => ownership.page_handle(),
+            PdfPageObjectOwnership::AttachedAnnotation(ownership) => ownership.page_handle(),
+            _ => std::ptr::null_mut::<fpdf_page_t__>(),
+        };
+
+        // The rendered bitmap takes into account any image filters, image mask, and
+        // object transforms, so it can be placed directly over the bounds of this object.
+
+        let bitmap_handle = self.bindings().FPDFImageObj_GetRenderedBitmap(
+            source_document,
+            page_handle,
+            self.object_handle(),
+        );
+
+        if bitmap_handle.is_null() {
+            // Pdfium may fail to render an image object whose transformation matrix flips
+            // the image. Fall back to copying the object.
+
+            return self.try_copy_impl(document, bindings);
+        }
+
+        let bitmap = PdfBitmap::from_pdfium(bitmap_handle, self.bindings());
+
+        let bounds = self.bounds()?.to_rect();
+
+        let mut copy = PdfPageImageObject::new_from_handle(document, bindings)?;
+
+        copy.set_bitmap(&bitmap)?;
+        copy.reset_matrix(PdfMatrix::new(
+            bounds.width().value,
+            0.0,
+            0.0,
+            bounds.height().value,
+            bounds.left().value,
+            bounds.bottom().value,
+        ))?;
+
+        Ok(PdfPageObject::Image(copy))
+    }
+}
+
+/// Metadata describing the image assigned to a [PdfPageImageObject], as returned by
+/// the [PdfPageImageObject::metadata()] function.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct PdfPageImageObjectMetadata {
+    width: Pixels,
+    height: Pixels,
+    horizontal_dpi: f32,
+    vertical_dpi: f32,
+    bits_per_pixel: u8,
+    color_space: PdfColorSpace,
+}
+
+impl PdfPageImageObjectMetadata {
+    /// Returns the intrinsic width of the image, in pixels.
+    #[inline]
+    pub fn width(&self) -> Pixels {
+        self.width
+    }
+
+    /// Returns the intrinsic height of the image, in pixels.
+    #[inline]
+    pub fn height(&self) -> Pixels {
+        self.height
+    }
+
+    /// Returns the horizontal dots per inch resolution of the image, based on the intrinsic
+    /// width of the image and the width of the containing [PdfPageImageObject] on the page.
+    #[inline]
+    pub fn horizontal_dpi(&self) -> f32 {
+        self.horizontal_dpi
+    }
+
+    /// Returns the vertical dots per inch resolution of the image, based on the intrinsic
+    /// height of the image and the height of the containing [PdfPageImageObject] on the page.
+    #[inline]
+    pub fn vertical_dpi(&self) -> f32 {
+        self.vertical_dpi
+    }
+
+    /// Returns the number of bits used to represent a single pixel of the image.
+    #[inline]
+    pub fn bits_per_pixel(&self) -> u8 {
+        self.bits_per_pixel
+    }
+
+    /// Returns the color space of the image. [PdfColorSpace::Unknown] will be returned
+    /// if Pdfium could not determine the color space.
+    #[inline]
+    pub fn color_space(&self) -> PdfColorSpace {
+        self.color_space
+    }
 }
 
 /// The zero-based index of a single [PdfPageImageObjectFilter] inside its containing
@@ -968,6 +1566,356 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_set_image_from_jpeg_bytes_rejects_non_jpeg_data() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let document = pdfium.create_new_pdf()?;
+
+        let mut object =
+            PdfPageImageObject::new_from_handle(document.handle(), document.bindings())?;
+
+        assert!(matches!(
+            object.set_image_from_jpeg_bytes(&[0x89, b'P', b'N', b'G']),
+            Err(PdfiumError::InvalidJpegImageData)
+        ));
+
+        assert!(matches!(
+            object.set_image_from_jpeg_bytes(&[]),
+            Err(PdfiumError::InvalidJpegImageData)
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(any(feature = "image_latest", feature = "image_025"))]
+    fn test_set_image_from_jpeg_bytes_embeds_dct_stream() -> Result<(), PdfiumError> {
+        use image_025::{DynamicImage, ImageFormat, Rgb, RgbImage};
+        use std::io::Cursor;
+
+        let pdfium = test_bind_to_pdfium();
+
+        let mut jpeg = Vec::new();
+
+        DynamicImage::ImageRgb8(RgbImage::from_pixel(16, 16, Rgb([255, 0, 0])))
+            .write_to(&mut Cursor::new(&mut jpeg), ImageFormat::Jpeg)
+            .unwrap();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        let mut page = document
+            .pages_mut()
+            .create_page_at_start(PdfPagePaperSize::a4())?;
+
+        let mut object =
+            PdfPageImageObject::new_from_handle(document.handle(), document.bindings())?;
+
+        object.set_image_from_jpeg_bytes(&jpeg)?;
+
+        let object = page.objects_mut().add_image_object(object)?;
+
+        let object = object.as_image_object().unwrap();
+
+        assert_eq!(object.filters().len(), 1);
+        assert_eq!(object.filters().get(0)?.name(), "DCTDecode");
+        assert_eq!(object.get_raw_image()?.width(), 16);
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(any(feature = "image_latest", feature = "image_025"))]
+    fn test_raw_image_data_returns_original_jpeg() -> Result<(), PdfiumError> {
+        use image_025::{DynamicImage, ImageFormat, Rgb, RgbImage};
+        use std::io::Cursor;
+
+        let pdfium = test_bind_to_pdfium();
+
+        let mut jpeg = Vec::new();
+
+        DynamicImage::ImageRgb8(RgbImage::from_pixel(16, 16, Rgb([0, 0, 255])))
+            .write_to(&mut Cursor::new(&mut jpeg), ImageFormat::Jpeg)
+            .unwrap();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        let mut page = document
+            .pages_mut()
+            .create_page_at_start(PdfPagePaperSize::a4())?;
+
+        let mut object =
+            PdfPageImageObject::new_from_handle(document.handle(), document.bindings())?;
+
+        object.set_image_from_jpeg_bytes(&jpeg)?;
+
+        let object = page.objects_mut().add_image_object(object)?;
+
+        let object = object.as_image_object().unwrap();
+
+        // The raw DCTDecode stream should be byte-for-byte identical to the original JPEG.
+        // Pdfium does not apply the DCTDecode filter when decoding image data, so the
+        // decoded image data should be identical as well.
+
+        assert_eq!(object.raw_image_data()?, jpeg);
+        assert_eq!(object.decoded_image_data()?, jpeg);
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(any(feature = "image_latest", feature = "image_025"))]
+    fn test_metadata() -> Result<(), PdfiumError> {
+        use image_025::{DynamicImage, ImageFormat, Rgb, RgbImage};
+        use std::io::Cursor;
+
+        let pdfium = test_bind_to_pdfium();
+
+        let mut jpeg = Vec::new();
+
+        DynamicImage::ImageRgb8(RgbImage::from_pixel(300, 150, Rgb([0, 255, 0])))
+            .write_to(&mut Cursor::new(&mut jpeg), ImageFormat::Jpeg)
+            .unwrap();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        let mut page = document
+            .pages_mut()
+            .create_page_at_start(PdfPagePaperSize::a4())?;
+
+        let mut object =
+            PdfPageImageObject::new_from_handle(document.handle(), document.bindings())?;
+
+        object.set_image_from_jpeg_bytes(&jpeg)?;
+
+        // Metadata is not available for a detached object.
+
+        assert!(matches!(
+            object.metadata(),
+            Err(PdfiumError::OwnershipNotAttachedToPage)
+        ));
+
+        // Scale the object to 4 x 2 inches, giving an effective resolution of 75 DPI.
+
+        object.scale(288.0, 144.0)?;
+
+        let object = page.objects_mut().add_image_object(object)?;
+
+        let metadata = object.as_image_object().unwrap().metadata()?;
+
+        assert_eq!(metadata.width(), 300);
+        assert_eq!(metadata.height(), 150);
+        assert_eq!(metadata.horizontal_dpi(), 75.0);
+        assert_eq!(metadata.vertical_dpi(), 75.0);
+        assert_eq!(metadata.bits_per_pixel(), 24);
+        assert_eq!(metadata.color_space(), PdfColorSpace::DeviceRGB);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_scale_to_fit_letterboxes_image() -> Result<(), PdfiumError> {
+        use image_025::{DynamicImage, GenericImageView, Rgba, RgbaImage};
+
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        let mut page = document
+            .pages_mut()
+            .create_page_at_end(PdfPagePaperSize::new_custom(
+                PdfPoints::new(200.0),
+                PdfPoints::new(200.0),
+            ))?;
+
+        // A 2:1 image placed into a square target should be letterboxed, leaving empty
+        // bands above and below the image.
+
+        let image =
+            DynamicImage::ImageRgba8(RgbaImage::from_pixel(200, 100, Rgba([255, 0, 0, 255])));
+
+        let mut object = PdfPageImageObject::new(&document, &image)?;
+
+        let bounds = object.scale_to_fit(
+            PdfRect::new_from_values(50.0, 50.0, 150.0, 150.0),
+            PdfPageImageObjectScaleMode::Fit,
+        )?;
+
+        assert_rect_near(bounds, PdfRect::new_from_values(75.0, 50.0, 125.0, 150.0));
+
+        page.objects_mut().add_image_object(object)?;
+
+        let rendered = page
+            .render_with_config(&PdfRenderConfig::new().set_target_width(200))?
+            .as_image();
+
+        // Page co-ordinates have their origin at the bottom of the page, but image
+        // co-ordinates have their origin at the top.
+
+        let is_red = |x: u32, y: u32| {
+            let pixel = rendered.get_pixel(x, 200 - y);
+
+            pixel[0] > 200 && pixel[1] < 50 && pixel[2] < 50
+        };
+
+        assert!(is_red(100, 100));
+        assert!(is_red(52, 77));
+        assert!(is_red(147, 122));
+        assert!(!is_red(100, 72));
+        assert!(!is_red(100, 128));
+        assert!(!is_red(47, 100));
+        assert!(!is_red(152, 100));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_image_placement_modes() -> Result<(), PdfiumError> {
+        use image_025::{DynamicImage, Rgba, RgbaImage};
+
+        let pdfium = test_bind_to_pdfium();
+
+        let document = pdfium.create_new_pdf()?;
+
+        let image =
+            DynamicImage::ImageRgba8(RgbaImage::from_pixel(600, 300, Rgba([0, 0, 255, 255])));
+
+        let target = PdfRect::new_from_values(0.0, 0.0, 100.0, 100.0);
+
+        let mut object = PdfPageImageObject::new(&document, &image)?;
+
+        assert_rect_near(
+            object.scale_to_fit(target, PdfPageImageObjectScaleMode::Fill)?,
+            PdfRect::new_from_values(0.0, -50.0, 100.0, 150.0),
+        );
+
+        assert_rect_near(
+            object.scale_to_fit(target, PdfPageImageObjectScaleMode::Stretch)?,
+            target,
+        );
+
+        // Resizing keeps the bottom left corner of the object in place.
+
+        object.translate(PdfPoints::new(10.0), PdfPoints::new(20.0))?;
+
+        assert_rect_near(
+            object.scale_to_physical_size(PdfPoints::new(100.0), PdfPoints::new(80.0))?,
+            PdfRect::new_from_values(20.0, 10.0, 100.0, 110.0),
+        );
+
+        // 600 x 300 pixels at 300 dpi is 2 x 1 inches, or 144 x 72 points.
+
+        assert_rect_near(
+            object.place_at_dpi(PdfPoints::new(36.0), PdfPoints::new(72.0), 300.0)?,
+            PdfRect::new_from_values(72.0, 36.0, 144.0, 180.0),
+        );
+
+        assert!(matches!(
+            object.place_at_dpi(PdfPoints::ZERO, PdfPoints::ZERO, 0.0),
+            Err(PdfiumError::InvalidImagePlacement)
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_set_image_preserves_transparency() -> Result<(), PdfiumError> {
+        use image_025::{DynamicImage, GenericImageView, Rgba, RgbaImage};
+
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        let mut page = document
+            .pages_mut()
+            .create_page_at_end(PdfPagePaperSize::new_custom(
+                PdfPoints::new(100.0),
+                PdfPoints::new(100.0),
+            ))?;
+
+        page.objects_mut().create_path_object_rect(
+            PdfRect::new_from_values(0.0, 0.0, 100.0, 100.0),
+            None,
+            None,
+            Some(PdfColor::new(0, 0, 255, 255)),
+        )?;
+
+        // A half-transparent red image should blend with the blue rectangle beneath it,
+        // rather than being drawn as an opaque box.
+
+        let transparent =
+            DynamicImage::ImageRgba8(RgbaImage::from_pixel(50, 50, Rgba([255, 0, 0, 128])));
+
+        let object = page.objects_mut().create_image_object(
+            PdfPoints::new(25.0),
+            PdfPoints::new(25.0),
+            &transparent,
+            Some(PdfPoints::new(50.0)),
+            Some(PdfPoints::new(50.0)),
+        )?;
+
+        let image_object = object.as_image_object().unwrap();
+
+        assert!(image_object.has_soft_mask(&document)?);
+
+        let mask = image_object.soft_mask_bitmap(&document)?.unwrap();
+
+        assert_eq!(mask.format()?, PdfBitmapFormat::Gray);
+        assert!(mask.as_raw_bytes()[..mask.width() as usize]
+            .iter()
+            .all(|alpha| (*alpha as i32 - 128).abs() <= 2));
+
+        let rendered = page
+            .render_with_config(&PdfRenderConfig::new().set_target_width(100))?
+            .as_image();
+
+        let blended = rendered.get_pixel(50, 50);
+
+        assert!((blended[0] as i32 - 128).abs() <= 4);
+        assert!(blended[1] < 4);
+        assert!((blended[2] as i32 - 127).abs() <= 4);
+
+        // Areas outside the image should be untouched.
+
+        assert_eq!(rendered.get_pixel(10, 10), Rgba([0, 0, 255, 255]));
+
+        // Images that are fully opaque should not be given a soft mask.
+
+        let opaque =
+            DynamicImage::ImageRgba8(RgbaImage::from_pixel(50, 50, Rgba([255, 0, 0, 255])));
+
+        let object = page.objects_mut().create_image_object(
+            PdfPoints::new(25.0),
+            PdfPoints::new(25.0),
+            &opaque,
+            Some(PdfPoints::new(50.0)),
+            Some(PdfPoints::new(50.0)),
+        )?;
+
+        assert!(!object.as_image_object().unwrap().has_soft_mask(&document)?);
+        assert!(object
+            .as_image_object()
+            .unwrap()
+            .soft_mask_bitmap(&document)?
+            .is_none());
+
+        Ok(())
+    }
+
+    fn assert_rect_near(actual: PdfRect, expected: PdfRect) {
+        let is_near = |a: PdfPoints, b: PdfPoints| (a.value - b.value).abs() < 0.01;
+
+        assert!(
+            is_near(actual.bottom(), expected.bottom())
+                && is_near(actual.left(), expected.left())
+                && is_near(actual.top(), expected.top())
+                && is_near(actual.right(), expected.right()),
+            "expected {:?}, got {:?}",
+            expected,
+            actual
+        );
+    }
+
     fn compare_equality_of_byte_arrays(a: &[u8], b: &[u8]) -> bool {
         if a.len() != b.len() {
             return false;
@@ -0,0 +1,369 @@
+//! Defines the [PdfPageObjectMark] struct, exposing functionality related to a single
+//! content mark attached to a `PdfPageObject`.
+
+use crate::bindgen::{
+    FPDF_BOOL, FPDF_DOCUMENT, FPDF_OBJECT_NUMBER, FPDF_PAGEOBJECT, FPDF_PAGEOBJECTMARK,
+};
+use crate::bindings::PdfiumLibraryBindings;
+use crate::error::{PdfiumError, PdfiumInternalError};
+use crate::pdf::document::page::object::ownership::PdfPageObjectOwnership;
+use crate::pdf::document::page::PdfPage;
+use crate::utils::mem::create_byte_buffer;
+use crate::utils::utf16le::get_string_from_pdfium_utf16le_bytes;
+use std::os::raw::{c_int, c_ulong};
+
+#[cfg(doc)]
+use crate::pdf::document::page::object::PdfPageObject;
+
+/// The zero-based index of a single key/value parameter inside a [PdfPageObjectMark].
+pub type PdfPageObjectMarkParamIndex = usize;
+
+/// A single content mark attached to a [PdfPageObject].
+///
+/// Content marks identify a page object as belonging to a marked-content sequence in
+/// the page's content stream. Marked content is used to associate page objects with
+/// the logical structure of a tagged PDF document, and can also be used by applications
+/// to tag page objects for their own purposes, so that those objects can be found again later.
+///
+/// Each content mark has a name (its tag) and may optionally carry a dictionary of key/value
+/// parameters. Pdfium supports reading and writing integer, string, and blob parameter values.
+pub struct PdfPageObjectMark<'a> {
+    mark_handle: FPDF_PAGEOBJECTMARK,
+    object_handle: FPDF_PAGEOBJECT,
+    ownership: PdfPageObjectOwnership,
+    bindings: &'a dyn PdfiumLibraryBindings,
+}
+
+impl<'a> PdfPageObjectMark<'a> {
+    #[inline]
+    pub(crate) fn from_pdfium(
+        mark_handle: FPDF_PAGEOBJECTMARK,
+        object_handle: FPDF_PAGEOBJECT,
+        ownership: PdfPageObjectOwnership,
+        bindings: &'a dyn PdfiumLibraryBindings,
+    ) -> Self {
+        PdfPageObjectMark {
+            mark_handle,
+            object_handle,
+            ownership,
+            bindings,
+        }
+    }
+
+    /// Returns the internal `FPDF_PAGEOBJECTMARK` handle for this [PdfPageObjectMark].
+    #[inline]
+    pub(crate) fn mark_handle(&self) -> FPDF_PAGEOBJECTMARK {
+        self.mark_handle
+    }
+
+    /// Returns the [PdfiumLibraryBindings] used by this [PdfPageObjectMark].
+    #[inline]
+    pub fn bindings(&self) -> &'a dyn PdfiumLibraryBindings {
+        self.bindings
+    }
+
+    /// Returns the name (tag) of this [PdfPageObjectMark].
+    pub fn name(&self) -> String {
+        // Retrieving the mark name from Pdfium is a two-step operation. First, we call
+        // FPDFPageObjMark_GetName() with a null buffer; this will retrieve the length of
+        // the name in bytes. If the length is zero, then there is no name associated
+        // with this mark.
+
+        // If the length is non-zero, then we reserve a byte buffer of the given
+        // length and call FPDFPageObjMark_GetName() again with a pointer to the buffer;
+        // this will write the name to the buffer in UTF16-LE format.
+
+        let mut out_buflen: c_ulong = 0;
+
+        if !self.bindings.is_true(self.bindings.FPDFPageObjMark_GetName(
+            self.mark_handle,
+            std::ptr::null_mut(),
+            0,
+            &mut out_buflen,
+        )) || out_buflen == 0
+        {
+            return String::new();
+        }
+
+        let mut buffer = create_byte_buffer(out_buflen as usize);
+
+        if self.bindings.is_true(self.bindings.FPDFPageObjMark_GetName(
+            self.mark_handle,
+            buffer.as_mut_ptr() as *mut _,
+            out_buflen,
+            &mut out_buflen,
+        )) {
+            get_string_from_pdfium_utf16le_bytes(buffer).unwrap_or_default()
+        } else {
+            String::new()
+        }
+    }
+
+    /// Returns the number of key/value parameters in this [PdfPageObjectMark].
+    pub fn params_len(&self) -> PdfPageObjectMarkParamIndex {
+        let count = self.bindings.FPDFPageObjMark_CountParams(self.mark_handle);
+
+        if count < 0 {
+            0
+        } else {
+            count as PdfPageObjectMarkParamIndex
+        }
+    }
+
+    /// Returns `true` if this [PdfPageObjectMark] has no key/value parameters.
+    #[inline]
+    pub fn has_no_params(&self) -> bool {
+        self.params_len() == 0
+    }
+
+    /// Returns the key of the parameter at the given index in this [PdfPageObjectMark],
+    /// if any.
+    pub fn param_key(&self, index: PdfPageObjectMarkParamIndex) -> Option<String> {
+        if index >= self.params_len() {
+            return None;
+        }
+
+        let mut out_buflen: c_ulong = 0;
+
+        if !self
+            .bindings
+            .is_true(self.bindings.FPDFPageObjMark_GetParamKey(
+                self.mark_handle,
+                index as c_ulong,
+                std::ptr::null_mut(),
+                0,
+                &mut out_buflen,
+            ))
+            || out_buflen == 0
+        {
+            return None;
+        }
+
+        let mut buffer = create_byte_buffer(out_buflen as usize);
+
+        if self
+            .bindings
+            .is_true(self.bindings.FPDFPageObjMark_GetParamKey(
+                self.mark_handle,
+                index as c_ulong,
+                buffer.as_mut_ptr() as *mut _,
+                out_buflen,
+                &mut out_buflen,
+            ))
+        {
+            get_string_from_pdfium_utf16le_bytes(buffer)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the keys of all the key/value parameters in this [PdfPageObjectMark].
+    pub fn param_keys(&self) -> Vec<String> {
+        (0..self.params_len())
+            .filter_map(|index| self.param_key(index))
+            .collect()
+    }
+
+    /// Returns the integer value of the parameter with the given key in this
+    /// [PdfPageObjectMark], if any. `None` will be returned if no parameter with the given key
+    /// exists, or if the parameter's value is not a number.
+    pub fn int_param(&self, key: &str) -> Option<i32> {
+        if self
+            .bindings
+            .FPDFPageObjMark_GetParamValueType(self.mark_handle, key) as u32
+            != FPDF_OBJECT_NUMBER
+        {
+            return None;
+        }
+
+        let mut value: c_int = 0;
+
+        if self
+            .bindings
+            .is_true(self.bindings.FPDFPageObjMark_GetParamIntValue(
+                self.mark_handle,
+                key,
+                &mut value,
+            ))
+        {
+            Some(value)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the string value of the parameter with the given key in this
+    /// [PdfPageObjectMark], if any. `None` will be returned if no parameter with the given key
+    /// exists, or if the parameter's value is not a string.
+    pub fn string_param(&self, key: &str) -> Option<String> {
+        let mut out_buflen: c_ulong = 0;
+
+        if !self
+            .bindings
+            .is_true(self.bindings.FPDFPageObjMark_GetParamStringValue(
+                self.mark_handle,
+                key,
+                std::ptr::null_mut(),
+                0,
+                &mut out_buflen,
+            ))
+        {
+            return None;
+        }
+
+        if out_buflen <= 2 {
+            // A buffer length of 2 indicates that the string value for the given key is
+            // an empty UTF16-LE string, so there is no point in retrieving it.
+
+            return Some(String::new());
+        }
+
+        let mut buffer = create_byte_buffer(out_buflen as usize);
+
+        if self
+            .bindings
+            .is_true(self.bindings.FPDFPageObjMark_GetParamStringValue(
+                self.mark_handle,
+                key,
+                buffer.as_mut_ptr() as *mut _,
+                out_buflen,
+                &mut out_buflen,
+            ))
+        {
+            get_string_from_pdfium_utf16le_bytes(buffer)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the raw byte value of the parameter with the given key in this
+    /// [PdfPageObjectMark], if any. `None` will be returned if no parameter with the given key
+    /// exists, or if the parameter's value is not a string or blob.
+    pub fn blob_param(&self, key: &str) -> Option<Vec<u8>> {
+        let mut out_buflen: c_ulong = 0;
+
+        if !self
+            .bindings
+            .is_true(self.bindings.FPDFPageObjMark_GetParamBlobValue(
+                self.mark_handle,
+                key,
+                std::ptr::null_mut(),
+                0,
+                &mut out_buflen,
+            ))
+        {
+            return None;
+        }
+
+        if out_buflen == 0 {
+            return Some(Vec::new());
+        }
+
+        let mut buffer = create_byte_buffer(out_buflen as usize);
+
+        if self
+            .bindings
+            .is_true(self.bindings.FPDFPageObjMark_GetParamBlobValue(
+                self.mark_handle,
+                key,
+                buffer.as_mut_ptr() as *mut _,
+                out_buflen,
+                &mut out_buflen,
+            ))
+        {
+            buffer.truncate(out_buflen as usize);
+
+            Some(buffer)
+        } else {
+            None
+        }
+    }
+
+    /// Sets the parameter with the given key in this [PdfPageObjectMark] to the given
+    /// integer value, replacing any existing value.
+    ///
+    /// Pdfium requires the page object containing this mark to be attached to a page or
+    /// annotation before mark parameters can be set.
+    pub fn set_int_param(&mut self, key: &str, value: i32) -> Result<(), PdfiumError> {
+        let document_handle = self.document_handle()?;
+
+        let result = self.bindings.FPDFPageObjMark_SetIntParam(
+            document_handle,
+            self.object_handle,
+            self.mark_handle,
+            key,
+            value,
+        );
+
+        self.result_after_mutation(result)
+    }
+
+    /// Sets the parameter with the given key in this [PdfPageObjectMark] to the given
+    /// string value, replacing any existing value.
+    ///
+    /// Pdfium requires the page object containing this mark to be attached to a page or
+    /// annotation before mark parameters can be set.
+    pub fn set_string_param(&mut self, key: &str, value: &str) -> Result<(), PdfiumError> {
+        let document_handle = self.document_handle()?;
+
+        let result = self.bindings.FPDFPageObjMark_SetStringParam(
+            document_handle,
+            self.object_handle,
+            self.mark_handle,
+            key,
+            value,
+        );
+
+        self.result_after_mutation(result)
+    }
+
+    /// Sets the parameter with the given key in this [PdfPageObjectMark] to the given
+    /// raw byte value, replacing any existing value.
+    ///
+    /// Pdfium requires the page object containing this mark to be attached to a page or
+    /// annotation before mark parameters can be set.
+    pub fn set_blob_param(&mut self, key: &str, value: &[u8]) -> Result<(), PdfiumError> {
+        let document_handle = self.document_handle()?;
+
+        let result = self.bindings.FPDFPageObjMark_SetBlobParam(
+            document_handle,
+            self.object_handle,
+            self.mark_handle,
+            key,
+            value.as_ptr() as *mut _,
+            value.len() as c_ulong,
+        );
+
+        self.result_after_mutation(result)
+    }
+
+    /// Removes the parameter with the given key from this [PdfPageObjectMark].
+    pub fn remove_param(&mut self, key: &str) -> Result<(), PdfiumError> {
+        let result =
+            self.bindings
+                .FPDFPageObjMark_RemoveParam(self.object_handle, self.mark_handle, key);
+
+        self.result_after_mutation(result)
+    }
+
+    /// Returns the internal `FPDF_DOCUMENT` handle of the document containing the page object
+    /// this [PdfPageObjectMark] is attached to.
+    #[inline]
+    fn document_handle(&self) -> Result<FPDF_DOCUMENT, PdfiumError> {
+        self.ownership
+            .document_handle()
+            .ok_or(PdfiumError::OwnershipNotAttachedToDocument)
+    }
+
+    /// Converts the given result from a Pdfium mutation function into a Rust `Result`,
+    /// triggering content regeneration of the containing page if the mutation succeeded.
+    fn result_after_mutation(&self, result: FPDF_BOOL) -> Result<(), PdfiumError> {
+        if self.bindings.is_true(result) {
+            PdfPage::regenerate_content_for_ownership(&self.ownership, self.bindings)
+        } else {
+            Err(PdfiumError::PdfiumLibraryInternalError(
+                PdfiumInternalError::Unknown,
+            ))
+        }
+    }
+}
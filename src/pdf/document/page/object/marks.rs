@@ -0,0 +1,249 @@
+//! Defines the [PdfPageObjectMarks] struct, a collection of all the `PdfPageObjectMark`
+//! content marks attached to a `PdfPageObject`.
+
+use crate::bindgen::FPDF_PAGEOBJECT;
+use crate::bindings::PdfiumLibraryBindings;
+use crate::error::{PdfiumError, PdfiumInternalError};
+use crate::pdf::document::page::object::mark::PdfPageObjectMark;
+use crate::pdf::document::page::object::ownership::PdfPageObjectOwnership;
+use crate::pdf::document::page::PdfPage;
+use std::ops::{Range, RangeInclusive};
+use std::os::raw::c_ulong;
+
+#[cfg(doc)]
+use crate::pdf::document::page::object::PdfPageObject;
+
+/// The zero-based index of a single [PdfPageObjectMark] inside its containing
+/// [PdfPageObjectMarks] collection.
+pub type PdfPageObjectMarkIndex = usize;
+
+/// The collection of [PdfPageObjectMark] content marks attached to a [PdfPageObject].
+pub struct PdfPageObjectMarks<'a> {
+    object_handle: FPDF_PAGEOBJECT,
+    ownership: PdfPageObjectOwnership,
+    bindings: &'a dyn PdfiumLibraryBindings,
+}
+
+impl<'a> PdfPageObjectMarks<'a> {
+    #[inline]
+    pub(crate) fn from_pdfium(
+        object_handle: FPDF_PAGEOBJECT,
+        ownership: PdfPageObjectOwnership,
+        bindings: &'a dyn PdfiumLibraryBindings,
+    ) -> Self {
+        PdfPageObjectMarks {
+            object_handle,
+            ownership,
+            bindings,
+        }
+    }
+
+    /// Returns the [PdfiumLibraryBindings] used by this [PdfPageObjectMarks] collection.
+    #[inline]
+    pub fn bindings(&self) -> &'a dyn PdfiumLibraryBindings {
+        self.bindings
+    }
+
+    /// Returns the number of content marks in this [PdfPageObjectMarks] collection.
+    pub fn len(&self) -> PdfPageObjectMarkIndex {
+        let count = self.bindings.FPDFPageObj_CountMarks(self.object_handle);
+
+        if count < 0 {
+            0
+        } else {
+            count as PdfPageObjectMarkIndex
+        }
+    }
+
+    /// Returns `true` if this [PdfPageObjectMarks] collection is empty.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns a Range from `0..(number of marks)` for this [PdfPageObjectMarks] collection.
+    #[inline]
+    pub fn as_range(&self) -> Range<PdfPageObjectMarkIndex> {
+        0..self.len()
+    }
+
+    /// Returns an inclusive Range from `0..=(number of marks - 1)` for this
+    /// [PdfPageObjectMarks] collection.
+    #[inline]
+    pub fn as_range_inclusive(&self) -> RangeInclusive<PdfPageObjectMarkIndex> {
+        if self.is_empty() {
+            0..=0
+        } else {
+            0..=(self.len() - 1)
+        }
+    }
+
+    /// Returns a single [PdfPageObjectMark] from this [PdfPageObjectMarks] collection.
+    pub fn get(&self, index: PdfPageObjectMarkIndex) -> Result<PdfPageObjectMark<'a>, PdfiumError> {
+        if index >= self.len() {
+            return Err(PdfiumError::PageObjectMarkIndexOutOfBounds);
+        }
+
+        let handle = self
+            .bindings
+            .FPDFPageObj_GetMark(self.object_handle, index as c_ulong);
+
+        if handle.is_null() {
+            Err(self.bindings.error_in(
+                "FPDFPageObj_GetMark",
+                PdfiumError::PdfiumLibraryInternalError(PdfiumInternalError::Unknown),
+            ))
+        } else {
+            Ok(PdfPageObjectMark::from_pdfium(
+                handle,
+                self.object_handle,
+                self.ownership,
+                self.bindings,
+            ))
+        }
+    }
+
+    /// Returns the first [PdfPageObjectMark] in this [PdfPageObjectMarks] collection
+    /// with the given name, if any.
+    pub fn find(&self, name: &str) -> Option<PdfPageObjectMark<'a>> {
+        self.as_range()
+            .filter_map(|index| self.get(index).ok())
+            .find(|mark| mark.name() == name)
+    }
+
+    /// Adds a new [PdfPageObjectMark] with the given name to this [PdfPageObjectMarks]
+    /// collection, returning the newly created mark.
+    pub fn add_mark(&mut self, name: &str) -> Result<PdfPageObjectMark<'a>, PdfiumError> {
+        let handle = self.bindings.FPDFPageObj_AddMark(self.object_handle, name);
+
+        if handle.is_null() {
+            Err(self.bindings.error_in(
+                "FPDFPageObj_AddMark",
+                PdfiumError::PdfiumLibraryInternalError(PdfiumInternalError::Unknown),
+            ))
+        } else {
+            PdfPage::regenerate_content_for_ownership(&self.ownership, self.bindings)?;
+
+            Ok(PdfPageObjectMark::from_pdfium(
+                handle,
+                self.object_handle,
+                self.ownership,
+                self.bindings,
+            ))
+        }
+    }
+
+    /// Removes the given [PdfPageObjectMark] from this [PdfPageObjectMarks] collection.
+    pub fn remove_mark(&mut self, mark: PdfPageObjectMark<'a>) -> Result<(), PdfiumError> {
+        if self.bindings.is_true(
+            self.bindings
+                .FPDFPageObj_RemoveMark(self.object_handle, mark.mark_handle()),
+        ) {
+            PdfPage::regenerate_content_for_ownership(&self.ownership, self.bindings)
+        } else {
+            Err(self.bindings.error_in(
+                "FPDFPageObj_RemoveMark",
+                PdfiumError::PdfiumLibraryInternalError(PdfiumInternalError::Unknown),
+            ))
+        }
+    }
+
+    /// Removes the [PdfPageObjectMark] at the given index from this [PdfPageObjectMarks]
+    /// collection.
+    #[inline]
+    pub fn remove_mark_at_index(
+        &mut self,
+        index: PdfPageObjectMarkIndex,
+    ) -> Result<(), PdfiumError> {
+        self.remove_mark(self.get(index)?)
+    }
+
+    /// Returns an iterator over all the content marks in this [PdfPageObjectMarks] collection.
+    #[inline]
+    pub fn iter(&self) -> PdfPageObjectMarksIterator {
+        PdfPageObjectMarksIterator::new(self)
+    }
+}
+
+/// An iterator over all the [PdfPageObjectMark] objects in a [PdfPageObjectMarks] collection.
+pub struct PdfPageObjectMarksIterator<'a> {
+    marks: &'a PdfPageObjectMarks<'a>,
+    next_index: PdfPageObjectMarkIndex,
+}
+
+impl<'a> PdfPageObjectMarksIterator<'a> {
+    #[inline]
+    pub(crate) fn new(marks: &'a PdfPageObjectMarks<'a>) -> Self {
+        PdfPageObjectMarksIterator {
+            marks,
+            next_index: 0,
+        }
+    }
+}
+
+impl<'a> Iterator for PdfPageObjectMarksIterator<'a> {
+    type Item = PdfPageObjectMark<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let next = self.marks.get(self.next_index);
+
+        self.next_index += 1;
+
+        next.ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::utils::test::test_bind_to_pdfium;
+
+    #[test]
+    fn test_mark_round_trip() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let bytes = {
+            let mut document = pdfium.create_new_pdf()?;
+
+            let mut page = document
+                .pages_mut()
+                .create_page_at_start(PdfPagePaperSize::a4())?;
+
+            let object = page.objects_mut().create_path_object_rect(
+                PdfRect::new_from_values(100.0, 100.0, 200.0, 200.0),
+                None,
+                None,
+                Some(PdfColor::RED),
+            )?;
+
+            let mut mark = object.marks().add_mark("Watermark")?;
+
+            mark.set_int_param("Version", 2)?;
+            mark.set_string_param("Producer", "pdfium-render")?;
+
+            drop(page);
+
+            document.save_to_bytes()?
+        };
+
+        let document = pdfium.load_pdf_from_byte_vec(bytes, None)?;
+
+        let object = document.pages().first()?.objects().first()?;
+
+        let marks = object.marks();
+
+        assert_eq!(marks.len(), 1);
+
+        let mark = marks.find("Watermark").unwrap();
+
+        assert_eq!(mark.params_len(), 2);
+        assert_eq!(mark.int_param("Version"), Some(2));
+        assert_eq!(
+            mark.string_param("Producer"),
+            Some("pdfium-render".to_string())
+        );
+        assert_eq!(mark.int_param("Producer"), None);
+
+        Ok(())
+    }
+}
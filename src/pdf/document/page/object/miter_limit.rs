@@ -0,0 +1,268 @@
+//! Records the miter limit of page objects and writes it into saved documents.
+//!
+//! Pdfium parses the miter limit set by the `M` operator or the `/ML` entry of an `ExtGState`
+//! graphics state when loading page content, but provides no function to read or change it,
+//! and does not write it when regenerating page content. To support it, we tag page objects
+//! with a content mark whose name records the miter limit, then when the containing document
+//! is saved, replace the mark with an `M` operator at the start of each marked object.
+
+use crate::bindgen::{FPDF_DOCUMENT, FPDF_PAGEOBJECT};
+use crate::bindings::PdfiumLibraryBindings;
+use crate::error::PdfiumError;
+use crate::pdf::document::page::object::marks::PdfPageObjectMarks;
+use crate::pdf::document::page::object::opacity::{find_end_of_marked_content, find_object_start};
+use crate::pdf::document::page::object::ownership::PdfPageObjectOwnership;
+use crate::pdf::document::raw::{find, is_delimiter, is_whitespace};
+use once_cell::sync::Lazy;
+use std::collections::HashSet;
+use std::ops::Range;
+use std::sync::Mutex;
+
+/// The prefix of the name of the content mark recording the miter limit of a page object.
+/// The miter limit follows the prefix, for example `PdfiumRenderMiterLimit2.5`.
+const MITER_LIMIT_MARK_PREFIX: &str = "PdfiumRenderMiterLimit";
+
+/// The raw `FPDF_DOCUMENT` handles of all open documents containing page objects whose
+/// miter limit has been set and must be written when the document is saved.
+static DOCUMENTS_WITH_MITER_LIMITS: Lazy<Mutex<HashSet<usize>>> =
+    Lazy::new(|| Mutex::new(HashSet::new()));
+
+/// Returns the miter limit recorded in the content mark on the given page object, if any.
+pub(crate) fn recorded_miter_limit(
+    object_handle: FPDF_PAGEOBJECT,
+    ownership: &PdfPageObjectOwnership,
+    bindings: &dyn PdfiumLibraryBindings,
+) -> Option<f32> {
+    let marks = PdfPageObjectMarks::from_pdfium(object_handle, *ownership, bindings);
+
+    marks.iter().find_map(|mark| {
+        mark.name()
+            .strip_prefix(MITER_LIMIT_MARK_PREFIX)
+            .and_then(|limit| limit.parse::<f32>().ok())
+    })
+}
+
+/// Replaces any content mark recording the miter limit of the given page object with
+/// a new mark recording the given miter limit.
+pub(crate) fn record_miter_limit(
+    object_handle: FPDF_PAGEOBJECT,
+    ownership: &PdfPageObjectOwnership,
+    bindings: &dyn PdfiumLibraryBindings,
+    limit: f32,
+) -> Result<(), PdfiumError> {
+    let mut marks = PdfPageObjectMarks::from_pdfium(object_handle, *ownership, bindings);
+
+    loop {
+        let mark = marks
+            .as_range()
+            .filter_map(|index| marks.get(index).ok())
+            .find(|mark| mark.name().starts_with(MITER_LIMIT_MARK_PREFIX));
+
+        match mark {
+            Some(mark) => marks.remove_mark(mark)?,
+            None => break,
+        }
+    }
+
+    marks.add_mark(format!("{}{}", MITER_LIMIT_MARK_PREFIX, to_number_string(limit)).as_str())?;
+
+    if let Some(document_handle) = ownership.document_handle() {
+        DOCUMENTS_WITH_MITER_LIMITS
+            .lock()
+            .unwrap()
+            .insert(document_handle as usize);
+    }
+
+    Ok(())
+}
+
+/// Returns `true` if the miter limit of any page object in the given document has been set
+/// since the document was opened.
+#[inline]
+pub(crate) fn has_miter_limits_to_write(document: FPDF_DOCUMENT) -> bool {
+    DOCUMENTS_WITH_MITER_LIMITS
+        .lock()
+        .unwrap()
+        .contains(&(document as usize))
+}
+
+/// Stops tracking miter limits for the given document. Called when the document is closed.
+#[inline]
+pub(crate) fn forget_miter_limits_to_write(document: FPDF_DOCUMENT) {
+    DOCUMENTS_WITH_MITER_LIMITS
+        .lock()
+        .unwrap()
+        .remove(&(document as usize));
+}
+
+/// Returns the edits to the given decoded page content stream that set the miter limit
+/// of each page object tagged with a miter limit mark and remove the mark itself, as pairs
+/// of the range to replace and the replacement text.
+///
+/// Pdfium writes each marked page object as a marked content sequence of the form
+/// `/PdfiumRenderMiterLimit2 BMC q ... Q EMC`, so the miter limit can be set immediately
+/// after the `q` operator, where it is restored by the object's own `Q` operator.
+pub(crate) fn find_miter_limit_edits(content: &[u8]) -> Vec<(Range<usize>, String)> {
+    let mut result = Vec::new();
+
+    let needle = format!("/{}", MITER_LIMIT_MARK_PREFIX);
+
+    let mut start = 0;
+
+    while let Some(offset) = find(&content[start..], needle.as_bytes()) {
+        let name_start = start + offset;
+
+        let name_end = name_start + needle.len();
+
+        let mut value_end = name_end;
+
+        while value_end < content.len()
+            && !is_whitespace(content[value_end])
+            && !is_delimiter(content[value_end])
+        {
+            value_end += 1;
+        }
+
+        start = value_end;
+
+        let limit = match std::str::from_utf8(&content[name_end..value_end])
+            .ok()
+            .and_then(|limit| limit.parse::<f32>().ok())
+        {
+            Some(limit) => limit,
+            None => continue,
+        };
+
+        let mut operator_start = value_end;
+
+        while operator_start < content.len() && is_whitespace(content[operator_start]) {
+            operator_start += 1;
+        }
+
+        if !content[operator_start..].starts_with(b"BMC") {
+            continue;
+        }
+
+        let operator_end = operator_start + b"BMC".len();
+
+        let position = match find_object_start(content, operator_end) {
+            Some(position) => position,
+            None => continue,
+        };
+
+        result.push((name_start..operator_end, String::new()));
+        result.push((
+            position..position,
+            format!(" {} M", to_number_string(limit)),
+        ));
+
+        if let Some(end_mark) = find_end_of_marked_content(content, operator_end) {
+            result.push((end_mark, String::new()));
+        }
+
+        start = position;
+    }
+
+    result
+}
+
+/// Formats the given miter limit as a PDF number.
+fn to_number_string(limit: f32) -> String {
+    let value = format!("{:.4}", limit);
+
+    value.trim_end_matches('0').trim_end_matches('.').to_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+    use crate::utils::test::test_bind_to_pdfium;
+
+    #[test]
+    fn test_set_miter_limit() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        let mut page = document
+            .pages_mut()
+            .create_page_at_start(PdfPagePaperSize::a4())?;
+
+        let mut object = page.objects_mut().create_path_object_rect(
+            PdfRect::new_from_values(100.0, 100.0, 200.0, 200.0),
+            Some(PdfColor::BLACK),
+            Some(PdfPoints::new(5.0)),
+            None,
+        )?;
+
+        assert!(matches!(
+            object.miter_limit(),
+            Err(PdfiumError::MiterLimitNotAvailable)
+        ));
+
+        assert!(object.set_miter_limit(0.5).is_err());
+        assert!(object.set_miter_limit(f32::NAN).is_err());
+
+        object.set_miter_limit(4.0)?;
+        object.set_miter_limit(1.5)?;
+
+        assert_eq!(object.miter_limit()?, 1.5);
+
+        let bytes = document.save_to_bytes()?;
+
+        let reloaded = pdfium.load_pdf_from_byte_vec(bytes, None)?;
+
+        let object = reloaded.pages().first()?.objects().first()?;
+
+        // The mark is replaced by the miter limit itself, which Pdfium cannot read back.
+
+        assert!(object.marks().is_empty());
+        assert!(matches!(
+            object.miter_limit(),
+            Err(PdfiumError::MiterLimitNotAvailable)
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_find_miter_limit_edits() {
+        let content = b"/PdfiumRenderMiterLimit1.5 BMC\n\
+            /Span BMC\n\
+            q 0 0 0 RG 5 w 100 100 100 100 re S Q\n\
+            EMC\n\
+            EMC\n";
+
+        let mut edits = find_miter_limit_edits(content);
+
+        assert_eq!(edits.len(), 3);
+
+        edits.sort_by_key(|(range, _)| range.start);
+
+        let mut edited = content.to_vec();
+
+        for (range, text) in edits.into_iter().rev() {
+            edited.splice(range, text.into_bytes());
+        }
+
+        assert_eq!(
+            edited,
+            b"\n\
+            /Span BMC\n\
+            q 1.5 M 0 0 0 RG 5 w 100 100 100 100 re S Q\n\
+            EMC\n\
+            \n"
+            .to_vec()
+        );
+
+        assert!(find_miter_limit_edits(edited.as_slice()).is_empty());
+    }
+
+    #[test]
+    fn test_to_number_string() {
+        assert_eq!(to_number_string(10.0), "10");
+        assert_eq!(to_number_string(1.5), "1.5");
+        assert_eq!(to_number_string(1.23456), "1.2346");
+    }
+}
@@ -0,0 +1,805 @@
+//! Writes the fill and stroke opacity of image and form objects into saved documents.
+//!
+//! Pdfium applies a page object's fill and stroke alpha values when rendering, but when
+//! regenerating page content it only writes an `ExtGState` graphics state carrying those
+//! values for path and text objects; the opacity of image and form objects is silently
+//! dropped. To preserve it, we tag such objects with a content mark recording their alpha
+//! values, then rewrite the marked content as an incremental update when the containing
+//! document is saved, inserting an `ExtGState` with the appropriate `/ca` and `/CA` entries
+//! and removing the mark itself.
+//! Form XObjects painted with an opacity are also made transparency groups, so that their
+//! content fades uniformly rather than compounding wherever it overlaps.
+
+use crate::bindgen::{FPDF_DOCUMENT, FPDF_PAGEOBJECT, FPDF_PAGEOBJ_FORM, FPDF_PAGEOBJ_IMAGE};
+use crate::bindings::PdfiumLibraryBindings;
+use crate::error::PdfiumError;
+use crate::pdf::document::page::object::marks::PdfPageObjectMarks;
+use crate::pdf::document::page::object::miter_limit::find_miter_limit_edits;
+use crate::pdf::document::page::object::ownership::PdfPageObjectOwnership;
+use crate::pdf::document::raw::{
+    decode_stream, find, get, is_delimiter, is_whitespace, parse_dictionary, parse_reference,
+    parse_references, set, to_dictionary_string, PdfIncrementalUpdate, PdfRawDictionary,
+    PdfRawDocument,
+};
+use once_cell::sync::Lazy;
+use std::collections::{HashMap, HashSet};
+use std::ops::Range;
+use std::sync::Mutex;
+
+/// The name of the content mark recording the fill and stroke alpha values of an image
+/// or form object, as integer parameters `ca` and `CA` in the range 0 - 255.
+const OPACITY_MARK_NAME: &str = "PdfiumRenderOpacity";
+
+/// The raw `FPDF_PAGEOBJECT` handles of all page objects currently carrying an opacity mark,
+/// keyed by the raw `FPDF_DOCUMENT` handle of the open document containing them. Documents
+/// with no marked objects have no entry, so their saved content need not be rewritten.
+static OPACITY_MARKED_OBJECTS: Lazy<Mutex<HashMap<usize, HashSet<usize>>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// Updates the opacity mark on the given page object to match its current fill and stroke
+/// alpha values. Only image and form objects attached to a page are marked; Pdfium already
+/// writes the opacity of all other objects itself.
+pub(crate) fn update_opacity_mark(
+    object_handle: FPDF_PAGEOBJECT,
+    ownership: &PdfPageObjectOwnership,
+    bindings: &dyn PdfiumLibraryBindings,
+) -> Result<(), PdfiumError> {
+    let object_type = bindings.FPDFPageObj_GetType(object_handle) as u32;
+
+    if object_type != FPDF_PAGEOBJ_IMAGE && object_type != FPDF_PAGEOBJ_FORM {
+        return Ok(());
+    }
+
+    let document_handle = match ownership {
+        PdfPageObjectOwnership::Page(ownership) => ownership.document_handle(),
+        _ => return Ok(()),
+    };
+
+    let (fill_alpha, stroke_alpha) = alpha_values(object_handle, bindings);
+
+    let mut marks = PdfPageObjectMarks::from_pdfium(object_handle, *ownership, bindings);
+
+    let has_mark = marks.find(OPACITY_MARK_NAME).is_some();
+
+    if !has_mark && fill_alpha == 255 && stroke_alpha == 255 {
+        return Ok(());
+    }
+
+    while let Some(mark) = marks.find(OPACITY_MARK_NAME) {
+        marks.remove_mark(mark)?;
+    }
+
+    if fill_alpha < 255 || stroke_alpha < 255 {
+        let mut mark = marks.add_mark(OPACITY_MARK_NAME)?;
+
+        mark.set_int_param("ca", fill_alpha as i32)?;
+        mark.set_int_param("CA", stroke_alpha as i32)?;
+
+        note_opacity_mark(document_handle, object_handle);
+    } else {
+        forget_opacity_mark(document_handle, object_handle);
+    }
+
+    Ok(())
+}
+
+/// Records that the given page object in the given document carries an opacity mark.
+#[inline]
+fn note_opacity_mark(document: FPDF_DOCUMENT, object: FPDF_PAGEOBJECT) {
+    OPACITY_MARKED_OBJECTS
+        .lock()
+        .unwrap()
+        .entry(document as usize)
+        .or_default()
+        .insert(object as usize);
+}
+
+/// Records that the given page object in the given document no longer carries
+/// an opacity mark.
+fn forget_opacity_mark(document: FPDF_DOCUMENT, object: FPDF_PAGEOBJECT) {
+    let mut documents = OPACITY_MARKED_OBJECTS.lock().unwrap();
+
+    if let Some(objects) = documents.get_mut(&(document as usize)) {
+        objects.remove(&(object as usize));
+
+        if objects.is_empty() {
+            documents.remove(&(document as usize));
+        }
+    }
+}
+
+/// Returns `true` if any page object in the given document has been tagged with an
+/// opacity mark that must be written out when the document is saved.
+#[inline]
+pub(crate) fn has_opacity_marks(document: FPDF_DOCUMENT) -> bool {
+    OPACITY_MARKED_OBJECTS
+        .lock()
+        .unwrap()
+        .contains_key(&(document as usize))
+}
+
+/// Stops tracking opacity marks for the given document. Called when the document is closed.
+#[inline]
+pub(crate) fn forget_opacity_marks(document: FPDF_DOCUMENT) {
+    OPACITY_MARKED_OBJECTS
+        .lock()
+        .unwrap()
+        .remove(&(document as usize));
+}
+
+/// Returns the fill and stroke alpha values of the given page object. Objects without
+/// a color state are fully opaque.
+fn alpha_values(object_handle: FPDF_PAGEOBJECT, bindings: &dyn PdfiumLibraryBindings) -> (u8, u8) {
+    let mut r = 0;
+    let mut g = 0;
+    let mut b = 0;
+    let mut fill_alpha = 255;
+    let mut stroke_alpha = 255;
+
+    if !bindings.is_true(bindings.FPDFPageObj_GetFillColor(
+        object_handle,
+        &mut r,
+        &mut g,
+        &mut b,
+        &mut fill_alpha,
+    )) {
+        fill_alpha = 255;
+    }
+
+    if !bindings.is_true(bindings.FPDFPageObj_GetStrokeColor(
+        object_handle,
+        &mut r,
+        &mut g,
+        &mut b,
+        &mut stroke_alpha,
+    )) {
+        stroke_alpha = 255;
+    }
+
+    (fill_alpha.min(255) as u8, stroke_alpha.min(255) as u8)
+}
+
+/// Adds replacements for all page content streams in the given saved document containing
+/// opacity marks to the given incremental update, with each marked object painted inside
+/// an `ExtGState` setting its fill and stroke alpha values and its opacity mark removed.
+/// Objects tagged with miter limit marks have their miter limits set in the same pass.
+pub(crate) fn add_graphics_states_to_update(
+    document: &PdfRawDocument,
+    update: &mut PdfIncrementalUpdate,
+) {
+    let pages = match document.page_object_numbers() {
+        Some(pages) => pages,
+        None => return,
+    };
+
+    // Graphics state dictionaries are shared between all pages using the same alpha values.
+
+    let mut graphics_states = HashMap::new();
+
+    for page_object_number in pages {
+        let mut page = match document.object_dictionary(page_object_number) {
+            Some(page) => page,
+            None => continue,
+        };
+
+        let contents = match get(&page, "Contents") {
+            Some(contents) => parse_references(contents),
+            None => continue,
+        };
+
+        let mut page_graphics_states = Vec::new();
+
+        let mut forms = Vec::new();
+
+        for stream_object_number in contents {
+            let content = match document
+                .object_stream(stream_object_number)
+                .and_then(|(dictionary, data)| decode_stream(&dictionary, data))
+            {
+                Some(content) => content,
+                None => continue,
+            };
+
+            let mut edits = find_miter_limit_edits(content.as_slice());
+
+            for marked in find_marked_objects(content.as_slice()) {
+                let name = format!("{}{}-{}", OPACITY_MARK_NAME, marked.ca, marked.stroke_ca);
+
+                let object_number = *graphics_states
+                    .entry((marked.ca, marked.stroke_ca))
+                    .or_insert_with(|| {
+                        update.add_object(
+                            format!(
+                                "<< /Type /ExtGState /ca {} /CA {} >>",
+                                to_alpha_string(marked.ca),
+                                to_alpha_string(marked.stroke_ca)
+                            )
+                            .into_bytes(),
+                        )
+                    });
+
+                if !page_graphics_states
+                    .iter()
+                    .any(|(candidate, _)| *candidate == name)
+                {
+                    page_graphics_states.push((name.clone(), object_number));
+                }
+
+                edits.push((marked.mark, String::new()));
+                edits.push((marked.position..marked.position, format!(" /{} gs", name)));
+
+                if let Some(end_mark) = marked.end_mark {
+                    edits.push((end_mark, String::new()));
+                }
+
+                if let Some(xobject) = marked.xobject {
+                    forms.push(xobject);
+                }
+            }
+
+            if edits.is_empty() {
+                continue;
+            }
+
+            let mut content = content;
+
+            edits.sort_by_key(|(range, _)| range.start);
+
+            for (range, text) in edits.into_iter().rev() {
+                content.splice(range, text.into_bytes());
+            }
+
+            let compressed = miniz_oxide::deflate::compress_to_vec_zlib(content.as_slice(), 6);
+
+            let mut stream = format!(
+                "<< /Length {} /Filter /FlateDecode >>\nstream\n",
+                compressed.len()
+            )
+            .into_bytes();
+
+            stream.extend(compressed);
+            stream.extend_from_slice(b"\nendstream");
+
+            update.replace_object(stream_object_number, stream);
+        }
+
+        if page_graphics_states.is_empty() {
+            continue;
+        }
+
+        let (resources_object_number, mut resources) = match get(&page, "Resources") {
+            Some(resources) => match resolve_dictionary(document, resources) {
+                Some(resources) => resources,
+                None => continue,
+            },
+            None => (None, vec![]),
+        };
+
+        add_transparency_groups_to_update(document, &resources, forms.as_slice(), update);
+
+        let (graphics_states_object_number, mut page_resources) = match get(&resources, "ExtGState")
+        {
+            Some(value) => match resolve_dictionary(document, value) {
+                Some(graphics_states) => graphics_states,
+                None => continue,
+            },
+            None => (None, vec![]),
+        };
+
+        for (name, object_number) in page_graphics_states {
+            set(
+                &mut page_resources,
+                name.as_str(),
+                format!("{} 0 R", object_number),
+            );
+        }
+
+        match graphics_states_object_number {
+            Some(object_number) => update.replace_object(
+                object_number,
+                to_dictionary_string(&page_resources).into_bytes(),
+            ),
+            None => {
+                set(
+                    &mut resources,
+                    "ExtGState",
+                    to_dictionary_string(&page_resources),
+                );
+
+                match resources_object_number {
+                    Some(object_number) => update.replace_object(
+                        object_number,
+                        to_dictionary_string(&resources).into_bytes(),
+                    ),
+                    None => {
+                        set(&mut page, "Resources", to_dictionary_string(&resources));
+
+                        update.replace_object(
+                            page_object_number,
+                            to_dictionary_string(&page).into_bytes(),
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Adds replacements for the given form XObjects in the given page resources dictionary
+/// to the given incremental update, with each form made a transparency group.
+fn add_transparency_groups_to_update(
+    document: &PdfRawDocument,
+    resources: &PdfRawDictionary,
+    names: &[String],
+    update: &mut PdfIncrementalUpdate,
+) {
+    let xobjects =
+        match get(resources, "XObject").and_then(|value| resolve_dictionary(document, value)) {
+            Some((_, xobjects)) => xobjects,
+            None => return,
+        };
+
+    let mut written = HashSet::new();
+
+    for name in names {
+        let object_number = match get(&xobjects, name.as_str()).and_then(parse_reference) {
+            Some(object_number) => object_number,
+            None => continue,
+        };
+
+        if !written.insert(object_number) {
+            continue;
+        }
+
+        if let Some((mut dictionary, data)) = document.object_stream(object_number) {
+            if get(&dictionary, "Subtype") != Some("/Form") || get(&dictionary, "Group").is_some() {
+                continue;
+            }
+
+            set(
+                &mut dictionary,
+                "Group",
+                "<< /Type /Group /S /Transparency >>".to_owned(),
+            );
+
+            let mut stream = to_dictionary_string(&dictionary).into_bytes();
+
+            stream.extend_from_slice(b"\nstream\n");
+            stream.extend_from_slice(data);
+            stream.extend_from_slice(b"\nendstream");
+
+            update.replace_object(object_number, stream);
+        }
+    }
+}
+
+/// A single image or form object tagged with an opacity mark in a page content stream.
+struct MarkedObject {
+    /// The range of the mark name, properties, and `BDC` operator beginning the mark.
+    mark: Range<usize>,
+
+    /// The range of the `EMC` operator ending the mark, if it could be found.
+    end_mark: Option<Range<usize>>,
+
+    /// The position immediately following the `q` operator that begins the object.
+    position: usize,
+
+    /// The fill alpha value of the object, in the range 0 - 255.
+    ca: u8,
+
+    /// The stroke alpha value of the object, in the range 0 - 255.
+    stroke_ca: u8,
+
+    /// The resource name of the XObject painted by the object, without its leading `/`.
+    xobject: Option<String>,
+}
+
+/// Returns all image and form objects tagged with an opacity mark in the given decoded
+/// page content stream.
+///
+/// Pdfium writes each marked image or form object as a marked content sequence of the form
+/// `/PdfiumRenderOpacity <</ca 128 /CA 255>> BDC q a b c d e f cm /Name Do Q EMC`, so
+/// the graphics state can be set immediately after the `q` operator, where it is restored
+/// by the object's own `Q` operator, and the mark itself can be removed.
+fn find_marked_objects(content: &[u8]) -> Vec<MarkedObject> {
+    let mut result = Vec::new();
+
+    let needle = format!("/{}", OPACITY_MARK_NAME);
+
+    let mut start = 0;
+
+    while let Some(offset) = find(&content[start..], needle.as_bytes()) {
+        let name_start = start + offset;
+
+        let name_end = name_start + needle.len();
+
+        start = name_end;
+
+        let operator = match find(&content[name_end..], b"BDC") {
+            Some(offset) => name_end + offset,
+            None => break,
+        };
+
+        let properties = match std::str::from_utf8(&content[name_end..operator])
+            .ok()
+            .and_then(parse_dictionary)
+        {
+            Some(properties) => properties,
+            None => continue,
+        };
+
+        let alpha = |key: &str| -> u8 {
+            get(&properties, key)
+                .and_then(|value| value.parse::<u32>().ok())
+                .map_or(255, |value| value.min(255) as u8)
+        };
+
+        let position = match find_object_start(content, operator + b"BDC".len()) {
+            Some(position) => position,
+            None => continue,
+        };
+
+        // The name of the painted XObject is the operand of the first Do operator
+        // following the start of the object.
+
+        let xobject = find(&content[position..], b" Do").and_then(|offset| {
+            let operand = &content[position..position + offset];
+
+            let name_start = operand.iter().rposition(|c| *c == b'/')?;
+
+            std::str::from_utf8(&operand[name_start + 1..])
+                .ok()
+                .map(|name| name.trim().to_owned())
+        });
+
+        result.push(MarkedObject {
+            mark: name_start..operator + b"BDC".len(),
+            end_mark: find_end_of_marked_content(content, position),
+            position,
+            ca: alpha("ca"),
+            stroke_ca: alpha("CA"),
+            xobject,
+        });
+
+        start = position;
+    }
+
+    result
+}
+
+/// Returns the position immediately following the `q` operator that begins the page object
+/// painted inside the marked content sequence whose opening operator ends at the given position
+/// in the given decoded page content stream, skipping over the opening operators of any
+/// marked content sequences nested inside it.
+pub(crate) fn find_object_start(content: &[u8], position: usize) -> Option<usize> {
+    let mut position = position;
+
+    loop {
+        while position < content.len() && is_whitespace(content[position]) {
+            position += 1;
+        }
+
+        match content.get(position)? {
+            b'q' => {
+                return if content
+                    .get(position + 1)
+                    .map_or(false, |c| is_whitespace(*c))
+                {
+                    Some(position + 1)
+                } else {
+                    None
+                };
+            }
+            b'/' => {
+                // A nested mark, either of the form /Name BMC or /Name <<...>> BDC.
+
+                position += 1;
+
+                while position < content.len()
+                    && !is_whitespace(content[position])
+                    && !is_delimiter(content[position])
+                {
+                    position += 1;
+                }
+
+                while position < content.len() && is_whitespace(content[position]) {
+                    position += 1;
+                }
+
+                if content[position..].starts_with(b"<<") {
+                    position += find_end_of_dictionary(&content[position..])?;
+
+                    while position < content.len() && is_whitespace(content[position]) {
+                        position += 1;
+                    }
+
+                    if !content[position..].starts_with(b"BDC") {
+                        return None;
+                    }
+                } else if !content[position..].starts_with(b"BMC") {
+                    return None;
+                }
+
+                position += 3;
+            }
+            _ => return None,
+        }
+    }
+}
+
+/// Returns the length of the dictionary at the start of the given bytes, including its
+/// enclosing `<<` and `>>` delimiters.
+fn find_end_of_dictionary(content: &[u8]) -> Option<usize> {
+    let mut depth = 0;
+
+    let mut position = 0;
+
+    while position < content.len() {
+        if content[position..].starts_with(b"<<") {
+            depth += 1;
+            position += 2;
+        } else if content[position..].starts_with(b">>") {
+            depth -= 1;
+            position += 2;
+
+            if depth == 0 {
+                return Some(position);
+            }
+        } else {
+            position += 1;
+        }
+    }
+
+    None
+}
+
+/// Returns the range of the `EMC` operator ending the marked content sequence containing
+/// the given position in the given decoded page content stream, skipping over any nested
+/// marked content sequences.
+pub(crate) fn find_end_of_marked_content(content: &[u8], position: usize) -> Option<Range<usize>> {
+    let mut depth = 1;
+
+    let mut start = position;
+
+    while start < content.len() {
+        if is_whitespace(content[start]) || is_delimiter(content[start]) {
+            start += 1;
+
+            continue;
+        }
+
+        let mut end = start;
+
+        while end < content.len() && !is_whitespace(content[end]) && !is_delimiter(content[end]) {
+            end += 1;
+        }
+
+        match &content[start..end] {
+            b"BDC" | b"BMC" => depth += 1,
+            b"EMC" => {
+                depth -= 1;
+
+                if depth == 0 {
+                    return Some(start..end);
+                }
+            }
+            _ => {}
+        }
+
+        start = end;
+    }
+
+    None
+}
+
+/// Returns the object number, if any, and the entries of the dictionary given either
+/// directly or as an indirect reference.
+fn resolve_dictionary(
+    document: &PdfRawDocument,
+    value: &str,
+) -> Option<(Option<u32>, PdfRawDictionary)> {
+    match parse_reference(value) {
+        Some(object_number) => Some((
+            Some(object_number),
+            document.object_dictionary(object_number)?,
+        )),
+        None => Some((None, parse_dictionary(value)?)),
+    }
+}
+
+/// Formats the given alpha value in the range 0 - 255 as a PDF number in the range 0.0 - 1.0.
+fn to_alpha_string(alpha: u8) -> String {
+    let value = format!("{:.4}", alpha as f32 / 255.0);
+
+    value.trim_end_matches('0').trim_end_matches('.').to_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+    use crate::utils::test::test_bind_to_pdfium;
+
+    /// Renders the given 200 x 200 point page at one pixel per point, returning a function
+    /// that returns the RGB color of the pixel at the given position in page coordinates.
+    fn render(page: &PdfPage) -> Result<impl Fn(usize, usize) -> [u8; 3], PdfiumError> {
+        let bytes = page
+            .render_with_config(&PdfRenderConfig::new().set_target_width(200))?
+            .as_rgba_bytes();
+
+        Ok(move |x: usize, y: usize| {
+            let offset = ((200 - y) * 200 + x) * 4;
+
+            [bytes[offset], bytes[offset + 1], bytes[offset + 2]]
+        })
+    }
+
+    /// Creates two overlapping red squares on the given page with the given opacity.
+    fn create_squares(page: &mut PdfPage, opacity: f32) -> Result<(), PdfiumError> {
+        for (left, bottom) in [(20.0, 20.0), (80.0, 80.0)] {
+            let mut square = page.objects_mut().create_path_object_rect(
+                PdfRect::new_from_values(bottom, left, bottom + 100.0, left + 100.0),
+                None,
+                None,
+                Some(PdfColor::RED),
+            )?;
+
+            square.set_opacity(opacity, opacity)?;
+
+            assert!((square.fill_opacity() - opacity).abs() < 0.01);
+            assert!((square.stroke_opacity() - opacity).abs() < 0.01);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_set_opacity() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        let mut page = document
+            .pages_mut()
+            .create_page_at_start(PdfPagePaperSize::Custom(
+                PdfPoints::new(200.0),
+                PdfPoints::new(200.0),
+            ))?;
+
+        create_squares(&mut page, 0.5)?;
+
+        // Each square is painted separately, so the overlap is darker than either square.
+
+        let pixel_at = render(&page)?;
+
+        let single = pixel_at(50, 50);
+
+        let overlap = pixel_at(100, 100);
+
+        assert!((single[1] as i16 - 127).abs() <= 2);
+        assert!(overlap[1] < single[1] - 32);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_set_group_opacity() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        let mut page = document
+            .pages_mut()
+            .create_page_at_start(PdfPagePaperSize::Custom(
+                PdfPoints::new(200.0),
+                PdfPoints::new(200.0),
+            ))?;
+
+        create_squares(&mut page, 1.0)?;
+
+        let group = page.objects().create_group(|_| true)?;
+
+        let form = group.set_group_opacity(0.5)?;
+
+        assert_eq!(form.object_type(), PdfPageObjectType::XObjectForm);
+        assert_eq!(page.objects().len(), 1);
+
+        // The squares are composited together before the opacity is applied, so the overlap
+        // is no darker than either square.
+
+        let pixel_at = render(&page)?;
+
+        let single = pixel_at(50, 50);
+
+        assert!((single[1] as i16 - 127).abs() <= 2);
+        assert!((pixel_at(100, 100)[1] as i16 - single[1] as i16).abs() <= 2);
+        assert_eq!(pixel_at(190, 10), [255, 255, 255]);
+
+        // The group opacity survives a save and reload.
+
+        let reloaded = pdfium.load_pdf_from_byte_vec(document.save_to_bytes()?, None)?;
+
+        let page = reloaded.pages().first()?;
+
+        let pixel_at = render(&page)?;
+
+        assert!((pixel_at(50, 50)[1] as i16 - 127).abs() <= 2);
+        assert!((pixel_at(100, 100)[1] as i16 - 127).abs() <= 2);
+
+        // The private opacity mark is not written into the saved document.
+
+        assert!(page
+            .objects()
+            .iter()
+            .all(|object| object.marks().find(OPACITY_MARK_NAME).is_none()));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_set_group_opacity_in_encrypted_document() -> Result<(), PdfiumError> {
+        // The saved document retains its /Encrypt entry, so the incremental update writing
+        // the group opacity is refused. The document should still be saved without it.
+
+        let pdfium = test_bind_to_pdfium();
+
+        let document = pdfium.load_pdf_from_file("./test/permissions-r3-test.pdf", None)?;
+
+        let mut page = document.pages().first()?;
+
+        create_squares(&mut page, 1.0)?;
+
+        let group = page.objects().create_group(|object| {
+            object.object_type() == PdfPageObjectType::Path
+                && object.fill_color().ok() == Some(PdfColor::RED)
+        })?;
+
+        let form = group.set_group_opacity(0.5)?;
+
+        assert!(form.marks().find(OPACITY_MARK_NAME).is_some());
+
+        let bytes = document.save_to_bytes()?;
+
+        assert!(find(bytes.as_slice(), b"/Encrypt").is_some());
+        assert!(find(bytes.as_slice(), b"%%EOF")
+            .map(|offset| find(&bytes[offset + 1..], b"%%EOF").is_none())
+            .unwrap_or(false));
+
+        let reloaded = pdfium.load_pdf_from_byte_vec(bytes, None)?;
+
+        assert_eq!(reloaded.pages().len(), document.pages().len());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_find_marked_objects() {
+        let content = b"q 1 0 0 1 0 0 cm 0 0 10 10 re f Q\n\
+            /PdfiumRenderOpacity <</ca 128/CA 64>> BDC\n\
+            q 100 0 0 100 10 10 cm /FXX1 Do Q\n\
+            EMC\n";
+
+        let marked = find_marked_objects(content);
+
+        assert_eq!(marked.len(), 1);
+        assert_eq!(marked[0].ca, 128);
+        assert_eq!(marked[0].stroke_ca, 64);
+        assert_eq!(marked[0].xobject.as_deref(), Some("FXX1"));
+        assert_eq!(&content[marked[0].position - 1..marked[0].position], b"q");
+        assert!(content[marked[0].mark.clone()].starts_with(b"/PdfiumRenderOpacity"));
+        assert!(content[marked[0].mark.clone()].ends_with(b"BDC"));
+        assert_eq!(
+            marked[0].end_mark.clone().map(|range| &content[range]),
+            Some(&b"EMC"[..])
+        );
+
+        assert!(find_marked_objects(b"q 0 0 10 10 re f Q").is_empty());
+    }
+
+    #[test]
+    fn test_to_alpha_string() {
+        assert_eq!(to_alpha_string(0), "0");
+        assert_eq!(to_alpha_string(255), "1");
+        assert_eq!(to_alpha_string(128), "0.502");
+    }
+}
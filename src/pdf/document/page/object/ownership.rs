@@ -73,6 +73,10 @@ pub(crate) enum PdfPageObjectOwnership {
     /// The object is currently owned by an object container attached to a [PdfAnnotation]
     /// that is not currently attached to any [PdfPage].
     UnattachedAnnotation(PdfPageObjectOwnedByUnattachedAnnotation),
+
+    /// The object is a child of a form object that is not currently owned by any
+    /// object container. The memory allocated to the object is owned by the form object.
+    DetachedFormObject,
 }
 
 impl PdfPageObjectOwnership {
@@ -109,9 +113,30 @@ impl PdfPageObjectOwnership {
         })
     }
 
+    pub fn owned_by_detached_form_object() -> Self {
+        Self::DetachedFormObject
+    }
+
+    /// Returns the internal `FPDF_DOCUMENT` handle of the document containing the object
+    /// container that owns the [PdfPageObject] holding this [PdfObjectOwnership] instance,
+    /// if any.
+    pub fn document_handle(&self) -> Option<FPDF_DOCUMENT> {
+        match self {
+            PdfPageObjectOwnership::Unowned => None,
+            PdfPageObjectOwnership::Page(ownership) => Some(ownership.document_handle()),
+            PdfPageObjectOwnership::AttachedAnnotation(ownership) => {
+                Some(ownership.document_handle())
+            }
+            PdfPageObjectOwnership::UnattachedAnnotation(ownership) => {
+                Some(ownership.document_handle())
+            }
+            PdfPageObjectOwnership::DetachedFormObject => None,
+        }
+    }
+
     /// Returns `true` if the memory allocated to the [PdfPageObject] holding
     /// this [PdfObjectOwnership] instance is owned by an object container attached to
-    /// either a [PdfPage] or a [PdfAnnotation].
+    /// either a [PdfPage] or a [PdfAnnotation], or by a parent form object.
     pub fn is_owned(&self) -> bool {
         match self {
             PdfPageObjectOwnership::Unowned => false,
@@ -14,6 +14,10 @@ use crate::pdf::document::page::object::{
 };
 use crate::pdf::document::PdfDocument;
 use crate::pdf::matrix::{PdfMatrix, PdfMatrixValue};
+use crate::pdf::path::outline::{
+    polylines_contain_point, polylines_distance_to_point, PdfPathCommand, PdfPathOrientation,
+    PdfPathOutline,
+};
 use crate::pdf::path::segment::{PdfPathSegment, PdfPathSegmentType};
 use crate::pdf::path::segments::{PdfPathSegmentIndex, PdfPathSegments, PdfPathSegmentsIterator};
 use crate::pdf::points::PdfPoints;
@@ -96,6 +100,52 @@ impl Default for PdfPathFillMode {
     }
 }
 
+/// Determines whether a [PdfPagePathObject] is filled, stroked, both, or neither when rendered.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct PdfPathDrawMode {
+    /// The method used to determine the path region to fill, or `None` if the path
+    /// should not be filled.
+    pub fill: Option<PdfPathFillMode>,
+
+    /// `true` if the path should be stroked.
+    pub stroke: bool,
+}
+
+impl PdfPathDrawMode {
+    /// Creates a new [PdfPathDrawMode] with the given fill and stroke settings.
+    ///
+    /// A fill mode of `Some(PdfPathFillMode::None)` is equivalent to a fill mode of `None`.
+    #[inline]
+    pub fn new(fill: Option<PdfPathFillMode>, stroke: bool) -> Self {
+        PdfPathDrawMode {
+            fill: fill.filter(|mode| *mode != PdfPathFillMode::None),
+            stroke,
+        }
+    }
+
+    /// Returns `true` if a path using this [PdfPathDrawMode] will be neither filled nor stroked.
+    #[inline]
+    pub fn is_invisible(&self) -> bool {
+        self.fill.is_none() && !self.stroke
+    }
+}
+
+/// The parts of a [PdfPagePathObject] considered when testing whether a point lies on the path
+/// using the [PdfPagePathObject::contains_point()] function.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum PdfPathHitTestMode {
+    /// Only the filled region of the path is considered, as determined by the path's
+    /// [PdfPathFillMode].
+    Fill,
+
+    /// Only the stroked outline of the path is considered, as determined by the path's
+    /// stroke width.
+    Stroke,
+
+    /// Both the filled region and the stroked outline of the path are considered.
+    Either,
+}
+
 /// A single `PdfPageObject` of type `PdfPageObjectType::Path`. The page object defines a path.
 ///
 /// Paths define shapes, trajectories, and regions of all sorts. They are used to draw
@@ -152,6 +202,10 @@ pub struct PdfPagePathObject<'a> {
 }
 
 impl<'a> PdfPagePathObject<'a> {
+    /// The maximum distance, in points, between a Bézier curve and the straight line segments
+    /// used to approximate it when hit testing.
+    const HIT_TEST_FLATTENING_TOLERANCE: f32 = 0.1;
+
     #[inline]
     pub(crate) fn from_pdfium(
         object_handle: FPDF_PAGEOBJECT,
@@ -213,8 +267,9 @@ impl<'a> PdfPagePathObject<'a> {
         let handle = bindings.FPDFPageObj_CreateNewPath(x.value, y.value);
 
         if handle.is_null() {
-            Err(PdfiumError::PdfiumLibraryInternalError(
-                PdfiumInternalError::Unknown,
+            Err(bindings.error_in(
+                "FPDFPageObj_CreateNewPath",
+                PdfiumError::PdfiumLibraryInternalError(PdfiumInternalError::Unknown),
             ))
         } else {
             let mut result = PdfPagePathObject {
@@ -227,7 +282,7 @@ impl<'a> PdfPagePathObject<'a> {
 
             result.move_to(x, y)?;
 
-            let do_stroke = if let Some(stroke_color) = stroke_color {
+            let stroke = if let Some(stroke_color) = stroke_color {
                 if let Some(stroke_width) = stroke_width {
                     result.set_stroke_color(stroke_color)?;
                     result.set_stroke_width(stroke_width)?;
@@ -240,15 +295,15 @@ impl<'a> PdfPagePathObject<'a> {
                 false
             };
 
-            let fill_mode = if let Some(fill_color) = fill_color {
+            let fill = if let Some(fill_color) = fill_color {
                 result.set_fill_color(fill_color)?;
 
-                PdfPathFillMode::default()
+                Some(PdfPathFillMode::default())
             } else {
-                PdfPathFillMode::None
+                None
             };
 
-            result.set_fill_and_stroke_mode(fill_mode, do_stroke)?;
+            result.set_draw_mode(fill, stroke)?;
 
             Ok(result)
         }
@@ -617,6 +672,127 @@ impl<'a> PdfPagePathObject<'a> {
         )
     }
 
+    pub(crate) fn from_svg_path_data_from_bindings(
+        bindings: &'a dyn PdfiumLibraryBindings,
+        data: &str,
+        fill_color: Option<PdfColor>,
+        stroke: Option<(PdfColor, PdfPoints)>,
+        orientation: PdfPathOrientation,
+    ) -> Result<Self, PdfiumError> {
+        let outline = PdfPathOutline::from_svg_path_data(data, orientation)?;
+
+        let mut commands = outline.commands();
+
+        // Path data always begins with a move-to command, so an outline without one
+        // can only result from empty path data.
+
+        let (start_x, start_y) = match commands.next() {
+            Some(PdfPathCommand::MoveTo { x, y }) => (x, y),
+            _ => return Err(PdfiumError::SvgPathDataSyntaxError(data.len())),
+        };
+
+        let (stroke_color, stroke_width) = match stroke {
+            Some((color, width)) => (Some(color), Some(width)),
+            None => (None, None),
+        };
+
+        let mut result = Self::new_from_bindings(
+            bindings,
+            PdfPoints::new(start_x),
+            PdfPoints::new(start_y),
+            stroke_color,
+            stroke_width,
+            fill_color,
+        )?;
+
+        let (mut current_x, mut current_y) = (start_x, start_y);
+
+        for command in commands {
+            match command {
+                PdfPathCommand::MoveTo { x, y } => {
+                    result.move_to(PdfPoints::new(x), PdfPoints::new(y))?;
+
+                    (current_x, current_y) = (x, y);
+                }
+                PdfPathCommand::LineTo { x, y } => {
+                    result.line_to(PdfPoints::new(x), PdfPoints::new(y))?;
+
+                    (current_x, current_y) = (x, y);
+                }
+                PdfPathCommand::QuadTo { x1, y1, x, y } => {
+                    // Pdfium does not support quadratic Bézier curves, so promote the curve
+                    // to the equivalent cubic Bézier curve.
+
+                    result.bezier_to(
+                        PdfPoints::new(x),
+                        PdfPoints::new(y),
+                        PdfPoints::new(current_x + 2.0 / 3.0 * (x1 - current_x)),
+                        PdfPoints::new(current_y + 2.0 / 3.0 * (y1 - current_y)),
+                        PdfPoints::new(x + 2.0 / 3.0 * (x1 - x)),
+                        PdfPoints::new(y + 2.0 / 3.0 * (y1 - y)),
+                    )?;
+
+                    (current_x, current_y) = (x, y);
+                }
+                PdfPathCommand::CubicTo {
+                    x1,
+                    y1,
+                    x2,
+                    y2,
+                    x,
+                    y,
+                } => {
+                    result.bezier_to(
+                        PdfPoints::new(x),
+                        PdfPoints::new(y),
+                        PdfPoints::new(x1),
+                        PdfPoints::new(y1),
+                        PdfPoints::new(x2),
+                        PdfPoints::new(y2),
+                    )?;
+
+                    (current_x, current_y) = (x, y);
+                }
+                PdfPathCommand::Close => result.close_path()?,
+            }
+        }
+
+        Ok(result)
+    }
+
+    /// Creates a new [PdfPagePathObject] from the given SVG path data, as used in the `d`
+    /// attribute of an SVG `<path>` element. The returned page object will not be rendered
+    /// until it is added to a `PdfPage` using the `PdfPageObjects::add_path_object()` function.
+    ///
+    /// All path commands in the SVG path mini-language are supported, in both their absolute
+    /// and relative forms. Quadratic Bézier curves are promoted to cubic Bézier curves, and
+    /// elliptical arcs are approximated using cubic Bézier curves. Coordinates are interpreted
+    /// according to the given orientation: use [PdfPathOrientation::PdfYUp] for path data
+    /// expressed in PDF points, or [PdfPathOrientation::SvgYDown] to flip path data
+    /// expressed in SVG's downward-pointing coordinate space.
+    ///
+    /// The new path will be filled with the given fill color, if any, and stroked with the
+    /// given stroke color and width, if any.
+    ///
+    /// Returns [PdfiumError::SvgPathDataSyntaxError] containing the byte offset of the problem
+    /// if the given path data cannot be parsed.
+    #[inline]
+    pub fn from_svg_path_data(
+        document: &PdfDocument<'a>,
+        data: &str,
+        fill_color: Option<PdfColor>,
+        stroke: Option<(PdfColor, PdfPoints)>,
+        orientation: PdfPathOrientation,
+    ) -> Result<Self, PdfiumError> {
+        Self::from_svg_path_data_from_bindings(
+            document.bindings(),
+            data,
+            fill_color,
+            stroke,
+            orientation,
+        )
+    }
+
     /// Begins a new sub-path in this [PdfPagePathObject] by moving the current point to the
     /// given coordinates, omitting any connecting line segment.
     pub fn move_to(&mut self, x: PdfPoints, y: PdfPoints) -> Result<(), PdfiumError> {
@@ -630,8 +806,9 @@ impl<'a> PdfPagePathObject<'a> {
 
             Ok(())
         } else {
-            Err(PdfiumError::PdfiumLibraryInternalError(
-                PdfiumInternalError::Unknown,
+            Err(self.bindings().error_in(
+                "FPDFPath_MoveTo",
+                PdfiumError::PdfiumLibraryInternalError(PdfiumInternalError::Unknown),
             ))
         }
     }
@@ -649,8 +826,9 @@ impl<'a> PdfPagePathObject<'a> {
 
             Ok(())
         } else {
-            Err(PdfiumError::PdfiumLibraryInternalError(
-                PdfiumInternalError::Unknown,
+            Err(self.bindings().error_in(
+                "FPDFPath_LineTo",
+                PdfiumError::PdfiumLibraryInternalError(PdfiumInternalError::Unknown),
             ))
         }
     }
@@ -681,8 +859,9 @@ impl<'a> PdfPagePathObject<'a> {
 
             Ok(())
         } else {
-            Err(PdfiumError::PdfiumLibraryInternalError(
-                PdfiumInternalError::Unknown,
+            Err(self.bindings().error_in(
+                "FPDFPath_BezierTo",
+                PdfiumError::PdfiumLibraryInternalError(PdfiumInternalError::Unknown),
             ))
         }
     }
@@ -796,58 +975,73 @@ impl<'a> PdfPagePathObject<'a> {
         {
             Ok(())
         } else {
-            Err(PdfiumError::PdfiumLibraryInternalError(
-                PdfiumInternalError::Unknown,
+            Err(self.bindings().error_in(
+                "FPDFPath_Close",
+                PdfiumError::PdfiumLibraryInternalError(PdfiumInternalError::Unknown),
             ))
         }
     }
 
-    /// Returns the method used to determine which sub-paths of any path in this [PdfPagePathObject]
-    /// should be filled.
-    pub fn fill_mode(&self) -> Result<PdfPathFillMode, PdfiumError> {
+    /// Returns the [PdfPathDrawMode] of this [PdfPagePathObject], indicating whether the
+    /// path will be filled, stroked, both, or neither.
+    pub fn draw_mode(&self) -> Result<PdfPathDrawMode, PdfiumError> {
         let mut raw_fill_mode: c_int = 0;
 
-        let mut _raw_stroke: FPDF_BOOL = self.bindings().FALSE();
-
-        if self.bindings().is_true(self.bindings.FPDFPath_GetDrawMode(
-            self.object_handle(),
-            &mut raw_fill_mode,
-            &mut _raw_stroke,
-        )) {
-            PdfPathFillMode::from_pdfium(raw_fill_mode)
-        } else {
-            Err(PdfiumError::PdfiumLibraryInternalError(
-                PdfiumInternalError::Unknown,
-            ))
-        }
-    }
-
-    /// Returns `true` if this [PdfPagePathObject] will be stroked, regardless of the path's
-    /// stroke settings.
-    ///
-    /// Even if this path is set to be stroked, the stroke must be configured with a visible color
-    /// and a non-zero width in order to actually be visible.
-    pub fn is_stroked(&self) -> Result<bool, PdfiumError> {
-        let mut _raw_fill_mode: c_int = 0;
-
         let mut raw_stroke: FPDF_BOOL = self.bindings().FALSE();
 
         if self
             .bindings()
             .is_true(self.bindings().FPDFPath_GetDrawMode(
                 self.object_handle(),
-                &mut _raw_fill_mode,
+                &mut raw_fill_mode,
                 &mut raw_stroke,
             ))
         {
-            Ok(self.bindings().is_true(raw_stroke))
+            Ok(PdfPathDrawMode::new(
+                Some(PdfPathFillMode::from_pdfium(raw_fill_mode)?),
+                self.bindings().is_true(raw_stroke),
+            ))
         } else {
-            Err(PdfiumError::PdfiumLibraryInternalError(
-                PdfiumInternalError::Unknown,
+            Err(self.bindings().error_in(
+                "FPDFPath_GetDrawMode",
+                PdfiumError::PdfiumLibraryInternalError(PdfiumInternalError::Unknown),
             ))
         }
     }
 
+    /// Sets whether this [PdfPagePathObject] should be filled, and if so, the method used
+    /// to determine the path region to fill, and whether it should be stroked.
+    ///
+    /// A path that is neither filled nor stroked is valid, but will not be visible.
+    /// Even if this path is set to be stroked, the stroke must be configured with
+    /// a visible color and a non-zero width in order to actually be visible.
+    #[inline]
+    pub fn set_draw_mode(
+        &mut self,
+        fill: Option<PdfPathFillMode>,
+        stroke: bool,
+    ) -> Result<(), PdfiumError> {
+        self.set_fill_and_stroke_mode(fill.unwrap_or(PdfPathFillMode::None), stroke)
+    }
+
+    /// Returns the method used to determine which sub-paths of any path in this [PdfPagePathObject]
+    /// should be filled.
+    #[inline]
+    pub fn fill_mode(&self) -> Result<PdfPathFillMode, PdfiumError> {
+        self.draw_mode()
+            .map(|draw_mode| draw_mode.fill.unwrap_or(PdfPathFillMode::None))
+    }
+
+    /// Returns `true` if this [PdfPagePathObject] will be stroked, regardless of the path's
+    /// stroke settings.
+    ///
+    /// Even if this path is set to be stroked, the stroke must be configured with a visible color
+    /// and a non-zero width in order to actually be visible.
+    #[inline]
+    pub fn is_stroked(&self) -> Result<bool, PdfiumError> {
+        self.draw_mode().map(|draw_mode| draw_mode.stroke)
+    }
+
     /// Sets the method used to determine which sub-paths of any path in this [PdfPagePathObject]
     /// should be filled, and whether or not any path in this [PdfPagePathObject] should be stroked.
     ///
@@ -868,18 +1062,116 @@ impl<'a> PdfPagePathObject<'a> {
         {
             Ok(())
         } else {
-            Err(PdfiumError::PdfiumLibraryInternalError(
-                PdfiumInternalError::Unknown,
+            Err(self.bindings().error_in(
+                "FPDFPath_SetDrawMode",
+                PdfiumError::PdfiumLibraryInternalError(PdfiumInternalError::Unknown),
             ))
         }
     }
 
+    /// Sets the fill color of this [PdfPagePathObject] to the given CMYK color in the
+    /// `DeviceCMYK` color space, where each component is in the range 0.0 to 1.0.
+    ///
+    /// Pdfium does not currently provide any public function for setting a page object's
+    /// color in any color space other than `DeviceRGB`, so this function always returns
+    /// [PdfiumError::UnsupportedOperation]. It will never silently convert the given color
+    /// to RGB. To set an RGB approximation of a CMYK color instead, pass the result of
+    /// [PdfColor::from_cmyk()] to the [PdfPageObjectCommon::set_fill_color()] function.
+    ///
+    /// Note also that Pdfium converts colors to RGB when retrieving them, so the
+    /// [PdfPageObjectCommon::fill_color()] function returns an RGB approximation of a fill color
+    /// set in any other color space. The original color space of a path's fill color is not
+    /// available through Pdfium's public API.
+    #[inline]
+    pub fn set_fill_color_cmyk(
+        &mut self,
+        _cyan: f32,
+        _magenta: f32,
+        _yellow: f32,
+        _black: f32,
+    ) -> Result<(), PdfiumError> {
+        Err(PdfiumError::UnsupportedOperation)
+    }
+
+    /// Sets the stroke color of this [PdfPagePathObject] to the given CMYK color in the
+    /// `DeviceCMYK` color space, where each component is in the range 0.0 to 1.0.
+    ///
+    /// As for the [PdfPagePathObject::set_fill_color_cmyk()] function, this function always
+    /// returns [PdfiumError::UnsupportedOperation], since Pdfium does not provide any public
+    /// function for setting a page object's color in any color space other than `DeviceRGB`.
+    /// To set an RGB approximation of a CMYK color instead, pass the result of
+    /// [PdfColor::from_cmyk()] to the [PdfPageObjectCommon::set_stroke_color()] function.
+    #[inline]
+    pub fn set_stroke_color_cmyk(
+        &mut self,
+        _cyan: f32,
+        _magenta: f32,
+        _yellow: f32,
+        _black: f32,
+    ) -> Result<(), PdfiumError> {
+        Err(PdfiumError::UnsupportedOperation)
+    }
+
     /// Returns the collection of path segments currently defined by this [PdfPagePathObject].
     #[inline]
     pub fn segments(&self) -> PdfPagePathObjectSegments {
         PdfPagePathObjectSegments::from_pdfium(self.object_handle(), self.bindings())
     }
 
+    /// Returns `true` if the given point, expressed in page coordinates, lies on the parts of
+    /// this [PdfPagePathObject] selected by the given [PdfPathHitTestMode].
+    ///
+    /// A point lies on the filled region of the path if it is inside the region according to
+    /// the path's current [PdfPathFillMode]. A point lies on the stroked outline of the path if
+    /// it is within half the path's stroke width of any path segment. Regions that are not
+    /// painted are never hit: a path with a fill mode of [PdfPathFillMode::None] has no filled
+    /// region, and a path that is not stroked has no stroked outline.
+    ///
+    /// The current transformation matrix of this [PdfPagePathObject] is applied to the
+    /// path's segments before testing. Bézier curves are approximated by straight line segments.
+    pub fn contains_point(
+        &self,
+        x: PdfPoints,
+        y: PdfPoints,
+        mode: PdfPathHitTestMode,
+    ) -> Result<bool, PdfiumError> {
+        let matrix = self.matrix()?;
+
+        let polylines = PdfPathOutline::from_segments(&self.segments().transform(matrix))
+            .flatten(Self::HIT_TEST_FLATTENING_TOLERANCE);
+
+        let point = (x.value, y.value);
+
+        let is_in_fill = || -> Result<bool, PdfiumError> {
+            Ok(match self.fill_mode()? {
+                PdfPathFillMode::None => false,
+                PdfPathFillMode::EvenOdd => polylines_contain_point(&polylines, point, true),
+                PdfPathFillMode::Winding => polylines_contain_point(&polylines, point, false),
+            })
+        };
+
+        let is_on_stroke = || -> Result<bool, PdfiumError> {
+            if !self.is_stroked()? {
+                return Ok(false);
+            }
+
+            // The stroke width is expressed in the path's own coordinate space, so it must
+            // be scaled by the object's transformation matrix.
+
+            let half_width = self.stroke_width()?.value * matrix.determinant().abs().sqrt() / 2.0;
+
+            Ok(polylines_distance_to_point(&polylines, point)
+                .map(|distance| distance <= half_width)
+                .unwrap_or(false))
+        };
+
+        match mode {
+            PdfPathHitTestMode::Fill => is_in_fill(),
+            PdfPathHitTestMode::Stroke => is_on_stroke(),
+            PdfPathHitTestMode::Either => Ok(is_in_fill()? || is_on_stroke()?),
+        }
+    }
+
     create_transform_setters!(
         &mut Self,
         Result<(), PdfiumError>,
@@ -948,13 +1240,23 @@ impl<'a> PdfPageObjectPrivate<'a> for PdfPagePathObject<'a> {
             None,
         )?;
 
-        copy.set_fill_and_stroke_mode(self.fill_mode()?, self.is_stroked()?)?;
+        let draw_mode = self.draw_mode()?;
+
+        copy.set_draw_mode(draw_mode.fill, draw_mode.stroke)?;
         copy.set_fill_color(self.fill_color()?)?;
         copy.set_stroke_color(self.stroke_color()?)?;
         copy.set_stroke_width(self.stroke_width()?)?;
         copy.set_line_join(self.line_join()?)?;
         copy.set_line_cap(self.line_cap()?)?;
 
+        if let Ok(limit) = self.miter_limit() {
+            copy.set_miter_limit(limit)?;
+        }
+
+        if let Ok(blend_mode) = self.blend_mode() {
+            copy.set_blend_mode(blend_mode)?;
+        }
+
         for segment in self.segments().iter() {
             if segment.segment_type() == PdfPathSegmentType::Unknown {
                 return Err(PdfiumError::PathObjectUnknownSegmentTypeNotCopyable);
@@ -1047,8 +1349,9 @@ impl<'a> PdfPathSegments<'a> for PdfPagePathObjectSegments<'a> {
             .FPDFPath_GetPathSegment(self.handle, index as c_int);
 
         if handle.is_null() {
-            Err(PdfiumError::PdfiumLibraryInternalError(
-                PdfiumInternalError::Unknown,
+            Err(self.bindings().error_in(
+                "FPDFPath_GetPathSegment",
+                PdfiumError::PdfiumLibraryInternalError(PdfiumInternalError::Unknown),
             ))
         } else {
             Ok(PdfPathSegment::from_pdfium(
@@ -1064,3 +1367,225 @@ impl<'a> PdfPathSegments<'a> for PdfPagePathObjectSegments<'a> {
         PdfPathSegmentsIterator::new(self)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::pdf::document::page::object::private::internal::PdfPageObjectPrivate;
+    use crate::prelude::*;
+    use crate::utils::test::test_bind_to_pdfium;
+
+    #[test]
+    fn test_contains_point_respects_fill_mode() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        let mut page = document
+            .pages_mut()
+            .create_page_at_start(PdfPagePaperSize::a4())?;
+
+        // A donut: an outer square and an inner square, both drawn in the same direction.
+        // The non-zero winding rule fills the hole; the even-odd rule leaves it empty.
+
+        let mut path = PdfPagePathObject::new(
+            &document,
+            PdfPoints::new(100.0),
+            PdfPoints::new(100.0),
+            Some(PdfColor::BLACK),
+            Some(PdfPoints::new(4.0)),
+            Some(PdfColor::RED),
+        )?;
+
+        path.line_to(PdfPoints::new(300.0), PdfPoints::new(100.0))?;
+        path.line_to(PdfPoints::new(300.0), PdfPoints::new(300.0))?;
+        path.line_to(PdfPoints::new(100.0), PdfPoints::new(300.0))?;
+        path.close_path()?;
+        path.move_to(PdfPoints::new(150.0), PdfPoints::new(150.0))?;
+        path.line_to(PdfPoints::new(250.0), PdfPoints::new(150.0))?;
+        path.line_to(PdfPoints::new(250.0), PdfPoints::new(250.0))?;
+        path.line_to(PdfPoints::new(150.0), PdfPoints::new(250.0))?;
+        path.close_path()?;
+
+        // Move the donut, so the hit test must take the object's matrix into account.
+
+        path.translate(PdfPoints::new(50.0), PdfPoints::new(50.0))?;
+
+        let mut object = page.objects_mut().add_path_object(path)?;
+
+        let path = object.as_path_object_mut().unwrap();
+
+        let hole = (PdfPoints::new(250.0), PdfPoints::new(250.0));
+
+        let ring = (PdfPoints::new(175.0), PdfPoints::new(175.0));
+
+        let outside = (PdfPoints::new(125.0), PdfPoints::new(125.0));
+
+        let inner_edge = (PdfPoints::new(201.5), PdfPoints::new(250.0));
+
+        assert_eq!(path.fill_mode()?, PdfPathFillMode::Winding);
+        assert!(path.contains_point(hole.0, hole.1, PdfPathHitTestMode::Fill)?);
+        assert!(path.contains_point(ring.0, ring.1, PdfPathHitTestMode::Fill)?);
+        assert!(!path.contains_point(outside.0, outside.1, PdfPathHitTestMode::Fill)?);
+
+        path.set_fill_and_stroke_mode(PdfPathFillMode::EvenOdd, true)?;
+
+        assert_eq!(path.fill_mode()?, PdfPathFillMode::EvenOdd);
+        assert!(!path.contains_point(hole.0, hole.1, PdfPathHitTestMode::Fill)?);
+        assert!(path.contains_point(ring.0, ring.1, PdfPathHitTestMode::Fill)?);
+        assert!(!path.contains_point(outside.0, outside.1, PdfPathHitTestMode::Fill)?);
+
+        // The inner edge of the donut lies within half the stroke width of the given point.
+
+        assert!(!path.contains_point(hole.0, hole.1, PdfPathHitTestMode::Stroke)?);
+        assert!(path.contains_point(inner_edge.0, inner_edge.1, PdfPathHitTestMode::Stroke)?);
+        assert!(path.contains_point(inner_edge.0, inner_edge.1, PdfPathHitTestMode::Either)?);
+        assert!(!path.contains_point(hole.0, hole.1, PdfPathHitTestMode::Either)?);
+
+        path.set_fill_and_stroke_mode(PdfPathFillMode::EvenOdd, false)?;
+
+        assert!(!path.contains_point(inner_edge.0, inner_edge.1, PdfPathHitTestMode::Stroke)?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_from_svg_path_data_renders_heart() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        let mut page = document
+            .pages_mut()
+            .create_page_at_start(PdfPagePaperSize::new_custom(
+                PdfPoints::new(200.0),
+                PdfPoints::new(200.0),
+            ))?;
+
+        // Flipping the path data to SVG's y-down orientation means that pixel coordinates
+        // in the rendered image match coordinates in the path data.
+
+        let heart = PdfPagePathObject::from_svg_path_data(
+            &document,
+            "M 100,30 C 100,27 95,15 75,15 C 45,15 45,52.5 45,52.5 C 45,70 65,92 100,110 \
+                C 135,92 155,70 155,52.5 C 155,52.5 155,15 125,15 C 110,15 100,27 100,30 Z",
+            Some(PdfColor::RED),
+            None,
+            PdfPathOrientation::SvgYDown { height: 200.0 },
+        )?;
+
+        page.objects_mut().add_path_object(heart)?;
+
+        let image = page
+            .render_with_config(&PdfRenderConfig::new().set_target_width(200))?
+            .as_image()
+            .into_rgba8();
+
+        for (x, y) in [(100, 60), (75, 40), (125, 40), (100, 100)] {
+            assert_eq!(image.get_pixel(x, y).0, [255, 0, 0, 255]);
+        }
+
+        // The notch at the top of the heart, and the areas beside and below it,
+        // should be left empty.
+
+        for (x, y) in [(100, 20), (20, 100), (180, 100), (100, 125)] {
+            assert_eq!(image.get_pixel(x, y).0, [255, 255, 255, 255]);
+        }
+
+        assert!(matches!(
+            PdfPagePathObject::from_svg_path_data(
+                &document,
+                "M 10 10 L 20 x",
+                None,
+                Some((PdfColor::BLACK, PdfPoints::new(1.0))),
+                PdfPathOrientation::PdfYUp,
+            ),
+            Err(PdfiumError::SvgPathDataSyntaxError(13))
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_draw_mode_round_trip() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let document = pdfium.create_new_pdf()?;
+
+        // A path created with only stroke settings should be stroked but not filled.
+
+        let mut path = PdfPagePathObject::new(
+            &document,
+            PdfPoints::new(100.0),
+            PdfPoints::new(100.0),
+            Some(PdfColor::BLACK),
+            Some(PdfPoints::new(2.0)),
+            None,
+        )?;
+
+        path.line_to(PdfPoints::new(200.0), PdfPoints::new(200.0))?;
+
+        assert_eq!(path.draw_mode()?, PdfPathDrawMode::new(None, true));
+
+        for fill in [
+            None,
+            Some(PdfPathFillMode::EvenOdd),
+            Some(PdfPathFillMode::Winding),
+        ] {
+            for stroke in [false, true] {
+                path.set_draw_mode(fill, stroke)?;
+
+                let draw_mode = path.draw_mode()?;
+
+                assert_eq!(draw_mode.fill, fill);
+                assert_eq!(draw_mode.stroke, stroke);
+                assert_eq!(draw_mode.is_invisible(), fill.is_none() && !stroke);
+            }
+        }
+
+        // Copying an unfilled, unstroked path must not change its draw mode.
+
+        path.set_draw_mode(None, false)?;
+
+        let copy = path.try_copy_impl(document.handle(), document.bindings())?;
+
+        assert_eq!(
+            copy.as_path_object().unwrap().draw_mode()?,
+            PdfPathDrawMode::new(None, false)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_set_cmyk_color_is_not_converted() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let document = pdfium.create_new_pdf()?;
+
+        let mut path = PdfPagePathObject::new_rect(
+            &document,
+            PdfRect::new_from_values(100.0, 100.0, 200.0, 200.0),
+            None,
+            None,
+            Some(PdfColor::BLUE),
+        )?;
+
+        // Pdfium cannot set CMYK colors, so the existing RGB fill color must be left unchanged.
+
+        assert!(matches!(
+            path.set_fill_color_cmyk(0.0, 1.0, 1.0, 0.0),
+            Err(PdfiumError::UnsupportedOperation)
+        ));
+        assert!(matches!(
+            path.set_stroke_color_cmyk(0.0, 1.0, 1.0, 0.0),
+            Err(PdfiumError::UnsupportedOperation)
+        ));
+        assert_eq!(path.fill_color()?, PdfColor::BLUE);
+
+        path.set_fill_color(PdfColor::from_cmyk(0.0, 1.0, 1.0, 0.0))?;
+
+        assert_eq!(path.fill_color()?, PdfColor::RED);
+
+        Ok(())
+    }
+}
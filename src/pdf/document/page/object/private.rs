@@ -12,16 +12,22 @@ pub(crate) mod internal {
     };
     use crate::bindings::PdfiumLibraryBindings;
     use crate::error::{PdfiumError, PdfiumInternalError};
+    use crate::pdf::bitmap::{PdfBitmap, PdfBitmapFormat, Pixels};
+    use crate::pdf::color::PdfColor;
     use crate::pdf::document::page::annotation::objects::PdfPageAnnotationObjects;
+    use crate::pdf::document::page::object::opacity::update_opacity_mark;
     use crate::pdf::document::page::object::{
         PdfPageObject, PdfPageObjectCommon, PdfPageObjectOwnership, PdfPageObjectType,
     };
+    use crate::pdf::document::page::objects::common::PdfPageObjectsCommon;
     use crate::pdf::document::page::objects::private::internal::PdfPageObjectsPrivate;
     use crate::pdf::document::page::objects::PdfPageObjects;
-    use crate::pdf::document::page::{
-        PdfPage, PdfPageContentRegenerationStrategy, PdfPageIndexCache,
-    };
+    use crate::pdf::document::page::render_config::PdfRenderConfig;
+    use crate::pdf::document::page::size::PdfPagePaperSize;
+    use crate::pdf::document::page::PdfPage;
+    use crate::pdf::document::PdfDocument;
     use crate::pdf::matrix::{PdfMatrix, PdfMatrixValue};
+    use crate::pdf::points::PdfPoints;
     use crate::pdf::quad_points::PdfQuadPoints;
     use crate::pdf::rect::PdfRect;
     use std::os::raw::c_double;
@@ -62,6 +68,11 @@ pub(crate) mod internal {
                 page_handle,
             ));
 
+            // The opacity of image and form objects can only be recorded for saving
+            // once the object is attached to a page.
+
+            update_opacity_mark(self.object_handle(), self.ownership(), self.bindings())?;
+
             self.regenerate_content_after_mutation()
         }
 
@@ -76,8 +87,9 @@ pub(crate) mod internal {
                         self.set_ownership(PdfPageObjectOwnership::unowned());
                         self.regenerate_content_after_mutation()
                     } else {
-                        Err(PdfiumError::PdfiumLibraryInternalError(
-                            PdfiumInternalError::Unknown,
+                        Err(self.bindings().error_in(
+                            "FPDFPage_RemoveObject",
+                            PdfiumError::PdfiumLibraryInternalError(PdfiumInternalError::Unknown),
                         ))
                     }
                 }
@@ -104,10 +116,15 @@ pub(crate) mod internal {
                             ownership.page_handle(),
                             ownership.annotation_handle(),
                         ));
+                        PdfPageAnnotationObjects::regenerate_appearance_for_annotation(
+                            ownership.annotation_handle(),
+                            self.bindings(),
+                        )?;
                         self.regenerate_content_after_mutation()
                     } else {
-                        Err(PdfiumError::PdfiumLibraryInternalError(
-                            PdfiumInternalError::Unknown,
+                        Err(self.bindings().error_in(
+                            "FPDFAnnot_AppendObject",
+                            PdfiumError::PdfiumLibraryInternalError(PdfiumInternalError::Unknown),
                         ))
                     }
                 }
@@ -123,10 +140,15 @@ pub(crate) mod internal {
                             ownership.document_handle(),
                             ownership.annotation_handle(),
                         ));
+                        PdfPageAnnotationObjects::regenerate_appearance_for_annotation(
+                            ownership.annotation_handle(),
+                            self.bindings(),
+                        )?;
                         self.regenerate_content_after_mutation()
                     } else {
-                        Err(PdfiumError::PdfiumLibraryInternalError(
-                            PdfiumInternalError::Unknown,
+                        Err(self.bindings().error_in(
+                            "FPDFAnnot_AppendObject",
+                            PdfiumError::PdfiumLibraryInternalError(PdfiumInternalError::Unknown),
                         ))
                     }
                 }
@@ -140,18 +162,25 @@ pub(crate) mod internal {
         fn remove_object_from_annotation(&mut self) -> Result<(), PdfiumError> {
             match self.ownership() {
                 PdfPageObjectOwnership::AttachedAnnotation(ownership) => {
-                    if let Some(index) =
-                        self.get_index_for_annotation(ownership.annotation_handle())
-                    {
+                    let annotation_handle = ownership.annotation_handle();
+
+                    if let Some(index) = self.get_index_for_annotation(annotation_handle) {
                         if self.bindings().is_true(
                             self.bindings()
-                                .FPDFAnnot_RemoveObject(ownership.annotation_handle(), index),
+                                .FPDFAnnot_RemoveObject(annotation_handle, index),
                         ) {
                             self.set_ownership(PdfPageObjectOwnership::unowned());
+                            PdfPageAnnotationObjects::regenerate_appearance_for_annotation(
+                                annotation_handle,
+                                self.bindings(),
+                            )?;
                             self.regenerate_content_after_mutation()
                         } else {
-                            Err(PdfiumError::PdfiumLibraryInternalError(
-                                PdfiumInternalError::Unknown,
+                            Err(self.bindings().error_in(
+                                "FPDFAnnot_RemoveObject",
+                                PdfiumError::PdfiumLibraryInternalError(
+                                    PdfiumInternalError::Unknown,
+                                ),
                             ))
                         }
                     } else {
@@ -159,18 +188,25 @@ pub(crate) mod internal {
                     }
                 }
                 PdfPageObjectOwnership::UnattachedAnnotation(ownership) => {
-                    if let Some(index) =
-                        self.get_index_for_annotation(ownership.annotation_handle())
-                    {
+                    let annotation_handle = ownership.annotation_handle();
+
+                    if let Some(index) = self.get_index_for_annotation(annotation_handle) {
                         if self.bindings().is_true(
                             self.bindings()
-                                .FPDFAnnot_RemoveObject(ownership.annotation_handle(), index),
+                                .FPDFAnnot_RemoveObject(annotation_handle, index),
                         ) {
                             self.set_ownership(PdfPageObjectOwnership::unowned());
+                            PdfPageAnnotationObjects::regenerate_appearance_for_annotation(
+                                annotation_handle,
+                                self.bindings(),
+                            )?;
                             self.regenerate_content_after_mutation()
                         } else {
-                            Err(PdfiumError::PdfiumLibraryInternalError(
-                                PdfiumInternalError::Unknown,
+                            Err(self.bindings().error_in(
+                                "FPDFAnnot_RemoveObject",
+                                PdfiumError::PdfiumLibraryInternalError(
+                                    PdfiumInternalError::Unknown,
+                                ),
                             ))
                         }
                     } else {
@@ -210,53 +246,109 @@ pub(crate) mod internal {
 
         /// Internal implementation of [PdfPageObjectCommon::bounds()].
         fn bounds_impl(&self) -> Result<PdfQuadPoints, PdfiumError> {
-            match PdfPageObjectType::from_pdfium(
-                self.bindings().FPDFPageObj_GetType(self.object_handle()) as u32,
-            ) {
-                Ok(PdfPageObjectType::Text) | Ok(PdfPageObjectType::Image) => {
-                    // Text and image page objects support tight fitting bounds via the
-                    // FPDFPageObject_GetRotatedBounds() function.
+            if self.supports_rotated_bounds() {
+                self.rotated_bounds_impl()
+            } else {
+                self.axis_aligned_bounds_impl()
+                    .map(|bounds| bounds.to_quad_points())
+            }
+        }
 
-                    let mut points = PdfQuadPoints::ZERO.as_pdfium();
+        /// Internal implementation of [PdfPageObjectCommon::quad_bounds()].
+        fn quad_bounds_impl(&self) -> Result<PdfQuadPoints, PdfiumError> {
+            if self.supports_rotated_bounds() {
+                return self.rotated_bounds_impl();
+            }
 
-                    let result = self
-                        .bindings()
-                        .FPDFPageObj_GetRotatedBounds(self.object_handle(), &mut points);
+            // Measure the bounds of this page object without its transformation matrix applied,
+            // then transform those bounds by the matrix. The matrix is set directly rather than
+            // through reset_matrix_impl(), since the original matrix is restored immediately
+            // afterwards and there is no need to regenerate the page's content stream.
 
-                    PdfQuadPoints::from_pdfium_as_result(result, points, self.bindings())
-                }
-                _ => {
-                    // All other page objects support the FPDFPageObj_GetBounds() function.
-
-                    let mut left = 0.0;
-
-                    let mut bottom = 0.0;
-
-                    let mut right = 0.0;
-
-                    let mut top = 0.0;
-
-                    let result = self.bindings().FPDFPageObj_GetBounds(
-                        self.object_handle(),
-                        &mut left,
-                        &mut bottom,
-                        &mut right,
-                        &mut top,
-                    );
-
-                    PdfRect::from_pdfium_as_result(
-                        result,
-                        FS_RECTF {
-                            left,
-                            top,
-                            right,
-                            bottom,
-                        },
-                        self.bindings(),
-                    )
-                    .map(|r| r.to_quad_points())
-                }
+            let matrix = self.get_matrix_impl()?;
+
+            if !self.bindings().is_true(
+                self.bindings()
+                    .FPDFPageObj_SetMatrix(self.object_handle(), &PdfMatrix::IDENTITY.as_pdfium()),
+            ) {
+                return Err(self.bindings().error_in(
+                    "FPDFPageObj_SetMatrix",
+                    PdfiumError::PdfiumLibraryInternalError(PdfiumInternalError::Unknown),
+                ));
+            }
+
+            let bounds = self.axis_aligned_bounds_impl();
+
+            if !self.bindings().is_true(
+                self.bindings()
+                    .FPDFPageObj_SetMatrix(self.object_handle(), &matrix.as_pdfium()),
+            ) {
+                return Err(self.bindings().error_in(
+                    "FPDFPageObj_SetMatrix",
+                    PdfiumError::PdfiumLibraryInternalError(PdfiumInternalError::Unknown),
+                ));
             }
+
+            Ok(bounds?.to_quad_points().transform(matrix))
+        }
+
+        /// Returns `true` if this page object supports tight fitting bounds via the
+        /// FPDFPageObj_GetRotatedBounds() function.
+        fn supports_rotated_bounds(&self) -> bool {
+            // Only text and image page objects support tight fitting bounds, and
+            // FPDFPageObj_GetRotatedBounds() may be missing from older builds of Pdfium.
+
+            matches!(
+                PdfPageObjectType::from_pdfium(
+                    self.bindings().FPDFPageObj_GetType(self.object_handle()) as u32,
+                ),
+                Ok(PdfPageObjectType::Text) | Ok(PdfPageObjectType::Image)
+            ) && self
+                .bindings()
+                .is_function_available("FPDFPageObj_GetRotatedBounds")
+        }
+
+        /// Returns the tight fitting bounds of this page object, as reported by the
+        /// FPDFPageObj_GetRotatedBounds() function.
+        fn rotated_bounds_impl(&self) -> Result<PdfQuadPoints, PdfiumError> {
+            let mut points = PdfQuadPoints::ZERO.as_pdfium();
+
+            let result = self
+                .bindings()
+                .FPDFPageObj_GetRotatedBounds(self.object_handle(), &mut points);
+
+            PdfQuadPoints::from_pdfium_as_result(result, points, self.bindings())
+        }
+
+        /// Returns the axis-aligned bounds of this page object, as reported by the
+        /// FPDFPageObj_GetBounds() function.
+        fn axis_aligned_bounds_impl(&self) -> Result<PdfRect, PdfiumError> {
+            let mut left = 0.0;
+
+            let mut bottom = 0.0;
+
+            let mut right = 0.0;
+
+            let mut top = 0.0;
+
+            let result = self.bindings().FPDFPageObj_GetBounds(
+                self.object_handle(),
+                &mut left,
+                &mut bottom,
+                &mut right,
+                &mut top,
+            );
+
+            PdfRect::from_pdfium_as_result(
+                result,
+                FS_RECTF {
+                    left,
+                    top,
+                    right,
+                    bottom,
+                },
+                self.bindings(),
+            )
         }
 
         /// Internal implementation of [PdfPageObjectCommon::transform()].
@@ -300,8 +392,9 @@ pub(crate) mod internal {
             ) {
                 Ok(PdfMatrix::from_pdfium(matrix))
             } else {
-                Err(PdfiumError::PdfiumLibraryInternalError(
-                    PdfiumInternalError::Unknown,
+                Err(self.bindings().error_in(
+                    "FPDFPageObj_GetMatrix",
+                    PdfiumError::PdfiumLibraryInternalError(PdfiumInternalError::Unknown),
                 ))
             }
         }
@@ -315,8 +408,9 @@ pub(crate) mod internal {
             ) {
                 self.regenerate_content_after_mutation()
             } else {
-                Err(PdfiumError::PdfiumLibraryInternalError(
-                    PdfiumInternalError::Unknown,
+                Err(self.bindings().error_in(
+                    "FPDFPageObj_SetMatrix",
+                    PdfiumError::PdfiumLibraryInternalError(PdfiumInternalError::Unknown),
                 ))
             }
         }
@@ -324,39 +418,9 @@ pub(crate) mod internal {
         /// Regenerate the containing page's content stream to reflect a change to the objects
         /// within the page objects container. The page's content regeneration strategy is
         /// taken into account.
+        #[inline]
         fn regenerate_content_after_mutation(&self) -> Result<(), PdfiumError> {
-            let (document_handle, page_handle) = match self.ownership() {
-                PdfPageObjectOwnership::Page(ownership) => (
-                    Some(ownership.document_handle()),
-                    Some(ownership.page_handle()),
-                ),
-                PdfPageObjectOwnership::AttachedAnnotation(ownership) => (
-                    Some(ownership.document_handle()),
-                    Some(ownership.page_handle()),
-                ),
-                _ => (None, None),
-            };
-
-            if let (Some(document_handle), Some(page_handle)) = (document_handle, page_handle) {
-                if let Some(content_regeneration_strategy) =
-                    PdfPageIndexCache::get_content_regeneration_strategy_for_page(
-                        document_handle,
-                        page_handle,
-                    )
-                {
-                    if content_regeneration_strategy
-                        == PdfPageContentRegenerationStrategy::AutomaticOnEveryChange
-                    {
-                        PdfPage::regenerate_content_immut_for_handle(page_handle, self.bindings())
-                    } else {
-                        Ok(())
-                    }
-                } else {
-                    Err(PdfiumError::SourcePageIndexNotInCache)
-                }
-            } else {
-                Ok(())
-            }
+            PdfPage::regenerate_content_for_ownership(self.ownership(), self.bindings())
         }
 
         /// Returns `true` if this [PdfPageObject] can be successfully cloned by calling its
@@ -370,6 +434,75 @@ pub(crate) mod internal {
             document_handle: FPDF_DOCUMENT,
             bindings: &'b dyn PdfiumLibraryBindings,
         ) -> Result<PdfPageObject<'b>, PdfiumError>;
+
+        /// Creates a copy of this [PdfPageObject] in the document with the given handle,
+        /// suitable for rendering this object in isolation. The copy must have the same
+        /// appearance and bounds as this [PdfPageObject].
+        #[inline]
+        fn copy_for_render_impl<'b>(
+            &self,
+            document_handle: FPDF_DOCUMENT,
+            bindings: &'b dyn PdfiumLibraryBindings,
+        ) -> Result<PdfPageObject<'b>, PdfiumError> {
+            self.try_copy_impl(document_handle, bindings)
+        }
+
+        /// Internal implementation of [PdfPageObjectCommon::render_with_padding()].
+        fn render_impl(
+            &self,
+            width: Pixels,
+            height: Pixels,
+            padding: PdfPoints,
+            background: Option<PdfColor>,
+        ) -> Result<PdfBitmap<'_>, PdfiumError> {
+            let bounds = self.bounds_impl()?.to_rect();
+
+            let bindings = self.bindings();
+
+            // Copy this object into a temporary single-page document sized to the object's
+            // bounds, then render that page.
+
+            let handle = bindings.FPDF_CreateNewDocument();
+
+            if handle.is_null() {
+                return Err(bindings.error_in(
+                    "FPDF_CreateNewDocument",
+                    PdfiumError::PdfiumLibraryInternalError(PdfiumInternalError::Unknown),
+                ));
+            }
+
+            let mut document = PdfDocument::from_pdfium(handle, bindings);
+
+            let mut page =
+                document
+                    .pages_mut()
+                    .create_page_at_end(PdfPagePaperSize::new_custom(
+                        bounds.width() + padding * 2.0,
+                        bounds.height() + padding * 2.0,
+                    ))?;
+
+            let mut copy = self.copy_for_render_impl(document.handle(), bindings)?;
+
+            copy.translate(padding - bounds.left(), padding - bounds.bottom())?;
+
+            page.objects_mut().add_object(copy)?;
+
+            let config = PdfRenderConfig::new()
+                .set_target_size(width, height)
+                .render_annotations(false)
+                .render_form_data(false);
+
+            let config = match background {
+                Some(color) => config.set_clear_color(color),
+                None => config.use_transparent_background(),
+            };
+
+            let mut bitmap = PdfBitmap::empty(width, height, PdfBitmapFormat::BGRA, bindings)?;
+
+            page.render_into_bitmap_with_config(&mut bitmap, &config)?;
+
+            Ok(bitmap)
+        }
     }
 }
 
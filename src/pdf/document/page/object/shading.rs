@@ -1,13 +1,73 @@
 //! Defines the [PdfPageShadingObject] struct, exposing functionality related to a single
 //! page object of type `PdfPageObjectType::Shading`.
 
-use crate::bindgen::{FPDF_DOCUMENT, FPDF_PAGEOBJECT};
+use crate::bindgen::{FPDF_DOCUMENT, FPDF_PAGEOBJECT, FS_RECTF};
 use crate::bindings::PdfiumLibraryBindings;
-use crate::error::PdfiumError;
+use crate::error::{PdfiumError, PdfiumInternalError};
+use crate::pdf::bitmap::{PdfBitmap, PdfBitmapFormat, Pixels};
+use crate::pdf::color::PdfColor;
 use crate::pdf::document::page::object::private::internal::PdfPageObjectPrivate;
 use crate::pdf::document::page::object::{PdfPageObject, PdfPageObjectOwnership};
+use crate::pdf::matrix::PdfMatrix;
+use crate::pdf::points::PdfPoints;
+use std::os::raw::c_int;
+
+/// The type of a shading, as described in Section 8.7.4.5 of the PDF Reference Manual,
+/// version 1.7, on page 182.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum PdfShadingType {
+    /// Colors are defined by a mathematical function of each point in the shaded area.
+    FunctionBased,
+
+    /// Colors vary along a line between two points, and are constant perpendicular to it.
+    Axial,
+
+    /// Colors vary between two circles.
+    Radial,
+
+    /// Colors are interpolated across a mesh of triangles, each vertex having its own color.
+    FreeFormTriangleMesh,
+
+    /// Colors are interpolated across a mesh of triangles whose vertices form a regular lattice.
+    LatticeFormTriangleMesh,
+
+    /// Colors are interpolated across a mesh of patches bounded by Coons cubic curves.
+    CoonsPatchMesh,
+
+    /// Colors are interpolated across a mesh of tensor-product patches.
+    TensorProductPatchMesh,
+}
+
+/// The geometry of an axial shading, expressed in page coordinates.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct PdfAxialShadingCoords {
+    pub start_x: PdfPoints,
+    pub start_y: PdfPoints,
+    pub end_x: PdfPoints,
+    pub end_y: PdfPoints,
+}
+
+/// The geometry of a radial shading, expressed in page coordinates.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct PdfRadialShadingCoords {
+    pub start_x: PdfPoints,
+    pub start_y: PdfPoints,
+    pub start_radius: PdfPoints,
+    pub end_x: PdfPoints,
+    pub end_y: PdfPoints,
+    pub end_radius: PdfPoints,
+}
 
 /// A single `PdfPageObject` of type `PdfPageObjectType::Shading`.
+///
+/// Pdfium does not expose the shading dictionary underlying a shading object. The
+/// [PdfPageShadingObject::shading_type()], [PdfPageShadingObject::axial_coords()],
+/// [PdfPageShadingObject::radial_coords()], and [PdfPageShadingObject::sample_colors()]
+/// functions instead analyze a small rendering of the page region covered by the object.
+/// The results are therefore approximate, and will be disturbed by any other page objects
+/// that overlap the shading. Only axial and radial shadings can be recognized; for all other
+/// shadings, including function-based and mesh shadings, these functions return
+/// `PdfiumError::UnsupportedOperation`.
 pub struct PdfPageShadingObject<'a> {
     object_handle: FPDF_PAGEOBJECT,
     ownership: PdfPageObjectOwnership,
@@ -15,6 +75,9 @@ pub struct PdfPageShadingObject<'a> {
 }
 
 impl<'a> PdfPageShadingObject<'a> {
+    /// The length, in pixels, of the longest side of the rendering used to analyze a shading.
+    const ANALYSIS_RESOLUTION: f32 = 128.0;
+
     pub(crate) fn from_pdfium(
         object_handle: FPDF_PAGEOBJECT,
         ownership: PdfPageObjectOwnership,
@@ -26,6 +89,475 @@ impl<'a> PdfPageShadingObject<'a> {
             bindings,
         }
     }
+
+    /// Returns the [PdfShadingType] of this [PdfPageShadingObject].
+    ///
+    /// Only [PdfShadingType::Axial] and [PdfShadingType::Radial] shadings can currently be
+    /// recognized. `PdfiumError::UnsupportedOperation` is returned for all other shadings.
+    /// This object must be attached to a page.
+    pub fn shading_type(&self) -> Result<PdfShadingType, PdfiumError> {
+        Ok(match self.analyze()?.geometry {
+            ShadingGeometry::Axial { .. } => PdfShadingType::Axial,
+            ShadingGeometry::Radial { .. } => PdfShadingType::Radial,
+        })
+    }
+
+    /// Returns the start and end points of the axis of this [PdfPageShadingObject] in
+    /// page coordinates, with the object's transformation matrix already applied.
+    ///
+    /// The points mark the extent of the color transition visible on the page; the direction
+    /// of the axis cannot be recovered, so the start and end points may be reversed relative
+    /// to the underlying shading dictionary. `PdfiumError::UnsupportedOperation` is returned
+    /// if this object is not an axial shading.
+    pub fn axial_coords(&self) -> Result<PdfAxialShadingCoords, PdfiumError> {
+        let analysis = self.analyze()?;
+
+        match analysis.geometry {
+            ShadingGeometry::Axial { start, end } => {
+                let (start_x, start_y) = analysis.to_page(start);
+                let (end_x, end_y) = analysis.to_page(end);
+
+                Ok(PdfAxialShadingCoords {
+                    start_x,
+                    start_y,
+                    end_x,
+                    end_y,
+                })
+            }
+            _ => Err(PdfiumError::UnsupportedOperation),
+        }
+    }
+
+    /// Returns the start and end circles of this [PdfPageShadingObject] in page coordinates,
+    /// with the object's transformation matrix already applied.
+    ///
+    /// The circles are assumed to be concentric, and mark the innermost and outermost extent
+    /// of the color transition visible on the page. `PdfiumError::UnsupportedOperation` is
+    /// returned if this object is not a radial shading.
+    pub fn radial_coords(&self) -> Result<PdfRadialShadingCoords, PdfiumError> {
+        let analysis = self.analyze()?;
+
+        match analysis.geometry {
+            ShadingGeometry::Radial {
+                center,
+                start_radius,
+                end_radius,
+            } => {
+                let (x, y) = analysis.to_page(center);
+
+                Ok(PdfRadialShadingCoords {
+                    start_x: x,
+                    start_y: y,
+                    start_radius: PdfPoints::new(start_radius / analysis.scale),
+                    end_x: x,
+                    end_y: y,
+                    end_radius: PdfPoints::new(end_radius / analysis.scale),
+                })
+            }
+            _ => Err(PdfiumError::UnsupportedOperation),
+        }
+    }
+
+    /// Returns the given number of colors, sampled at evenly spaced positions from the start
+    /// to the end of the color transition of this [PdfPageShadingObject]. For an axial
+    /// shading, colors are sampled along its axis; for a radial shading, colors are sampled
+    /// along a radius from the start circle to the end circle.
+    ///
+    /// Colors are taken from a rendering of the page, so they reflect the shading's colors
+    /// after conversion to RGB. `PdfiumError::UnsupportedOperation` is returned if this object
+    /// is neither an axial nor a radial shading.
+    pub fn sample_colors(&self, count: usize) -> Result<Vec<PdfColor>, PdfiumError> {
+        let analysis = self.analyze()?;
+
+        let (start, end) = match analysis.geometry {
+            ShadingGeometry::Axial { start, end } => (start, end),
+            ShadingGeometry::Radial {
+                center,
+                start_radius,
+                end_radius,
+            } => {
+                let (dx, dy) = analysis.direction_to_farthest_gradient_pixel(center);
+
+                (
+                    (center.0 + dx * start_radius, center.1 + dy * start_radius),
+                    (center.0 + dx * end_radius, center.1 + dy * end_radius),
+                )
+            }
+        };
+
+        Ok((0..count)
+            .map(|index| {
+                let t = if count > 1 {
+                    index as f32 / (count - 1) as f32
+                } else {
+                    0.0
+                };
+
+                analysis.color_at((
+                    start.0 + (end.0 - start.0) * t,
+                    start.1 + (end.1 - start.1) * t,
+                ))
+            })
+            .collect())
+    }
+
+    /// Renders the page region covered by this [PdfPageShadingObject] and analyzes the
+    /// rendered pixels to determine the shading geometry.
+    fn analyze(&self) -> Result<ShadingAnalysis, PdfiumError> {
+        let page_handle = match &self.ownership {
+            PdfPageObjectOwnership::Page(ownership) => ownership.page_handle(),
+            _ => return Err(PdfiumError::OwnershipNotAttachedToPage),
+        };
+
+        let bindings = self.bindings();
+
+        let mut page_box = FS_RECTF {
+            left: 0.0,
+            top: 0.0,
+            right: 0.0,
+            bottom: 0.0,
+        };
+
+        if !bindings.is_true(bindings.FPDF_GetPageBoundingBox(page_handle, &mut page_box)) {
+            return Err(bindings.error_in(
+                "FPDF_GetPageBoundingBox",
+                PdfiumError::PdfiumLibraryInternalError(PdfiumInternalError::Unknown),
+            ));
+        }
+
+        // Shadings painted with the sh operator fill the entire clipping region, so the object
+        // bounds may extend beyond the page. Only the visible region is analyzed.
+
+        let bounds = self.bounds_impl()?.to_rect();
+
+        let left = bounds.left().value.max(page_box.left);
+        let right = bounds.right().value.min(page_box.right);
+        let bottom = bounds.bottom().value.max(page_box.bottom);
+        let top = bounds.top().value.min(page_box.top);
+
+        if right - left <= 0.0 || top - bottom <= 0.0 {
+            return Err(PdfiumError::UnsupportedOperation);
+        }
+
+        let scale = Self::ANALYSIS_RESOLUTION / (right - left).max(top - bottom);
+
+        let width = ((right - left) * scale).ceil().max(1.0) as Pixels;
+        let height = ((top - bottom) * scale).ceil().max(1.0) as Pixels;
+
+        // Pdfium applies our matrix after its own display matrix, which maps the page
+        // bounding box to device space taking the page's intrinsic rotation into account.
+        // We undo the display matrix so we can map page space directly onto the bitmap.
+
+        let (l, b, r, t) = (page_box.left, page_box.bottom, page_box.right, page_box.top);
+
+        let display = match bindings.FPDFPage_GetRotation(page_handle) {
+            1 => PdfMatrix::new(0.0, 1.0, 1.0, 0.0, -b, -l),
+            2 => PdfMatrix::new(-1.0, 0.0, 0.0, 1.0, r, -b),
+            3 => PdfMatrix::new(0.0, -1.0, -1.0, 0.0, t, r),
+            _ => PdfMatrix::new(1.0, 0.0, 0.0, -1.0, -l, t),
+        };
+
+        let target = PdfMatrix::new(scale, 0.0, 0.0, -scale, -left * scale, top * scale);
+
+        // Each display matrix is a reflection or rotation, so it can always be inverted.
+
+        let matrix = display
+            .invert()
+            .ok_or(PdfiumError::InvalidTransformationMatrix)?
+            .multiply(target);
+
+        let bitmap = PdfBitmap::empty(width, height, PdfBitmapFormat::BGRA, bindings)?;
+
+        bindings.FPDFBitmap_FillRect(*bitmap.handle(), 0, 0, width as c_int, height as c_int, 0);
+
+        let clipping = FS_RECTF {
+            left: 0.0,
+            top: 0.0,
+            right: width as f32,
+            bottom: height as f32,
+        };
+
+        bindings.FPDF_RenderPageBitmapWithMatrix(
+            *bitmap.handle(),
+            page_handle,
+            &matrix.as_pdfium(),
+            &clipping,
+            0,
+        );
+
+        let pixels = bitmap.as_rgba_bytes();
+
+        let geometry = ShadingGeometry::from_pixels(&pixels, width as usize, height as usize)
+            .ok_or(PdfiumError::UnsupportedOperation)?;
+
+        Ok(ShadingAnalysis {
+            geometry,
+            pixels,
+            width: width as usize,
+            height: height as usize,
+            left,
+            top,
+            scale,
+        })
+    }
+}
+
+/// The geometry of a shading, expressed in bitmap pixel coordinates.
+#[derive(Debug, Copy, Clone, PartialEq)]
+enum ShadingGeometry {
+    Axial {
+        start: (f32, f32),
+        end: (f32, f32),
+    },
+    Radial {
+        center: (f32, f32),
+        start_radius: f32,
+        end_radius: f32,
+    },
+}
+
+impl ShadingGeometry {
+    /// The minimum degree to which color gradients must agree in direction, or in
+    /// alignment with a common center, for a shading to be recognized.
+    const COHERENCE_THRESHOLD: f32 = 0.9;
+
+    /// The minimum magnitude of the color gradient at a pixel, in color channel units
+    /// per pixel, for the pixel to be considered part of a color transition.
+    const GRADIENT_THRESHOLD: f32 = 0.5;
+
+    /// Recognizes an axial or radial shading in the given RGBA pixel data by examining
+    /// the direction of the color gradient at each fully opaque pixel. Returns `None`
+    /// if no color transition is found, or if the gradients fit neither shape.
+    fn from_pixels(pixels: &[u8], width: usize, height: usize) -> Option<Self> {
+        let opaque = |x: usize, y: usize| pixels[(y * width + x) * 4 + 3] == 255;
+
+        let channel = |x: usize, y: usize, c: usize| pixels[(y * width + x) * 4 + c] as f32;
+
+        // Each gradient pixel records its center and the dominant direction of its
+        // color gradient, taken across all three color channels.
+
+        let mut gradients = Vec::new();
+
+        let (mut jxx, mut jxy, mut jyy) = (0.0, 0.0, 0.0);
+
+        for y in 1..height.saturating_sub(1) {
+            for x in 1..width.saturating_sub(1) {
+                if !(opaque(x, y)
+                    && opaque(x - 1, y)
+                    && opaque(x + 1, y)
+                    && opaque(x, y - 1)
+                    && opaque(x, y + 1))
+                {
+                    continue;
+                }
+
+                let (mut pxx, mut pxy, mut pyy): (f32, f32, f32) = (0.0, 0.0, 0.0);
+
+                for c in 0..3 {
+                    let gx = (channel(x + 1, y, c) - channel(x - 1, y, c)) / 2.0;
+                    let gy = (channel(x, y + 1, c) - channel(x, y - 1, c)) / 2.0;
+
+                    pxx += gx * gx;
+                    pxy += gx * gy;
+                    pyy += gy * gy;
+                }
+
+                if (pxx + pyy).sqrt() < Self::GRADIENT_THRESHOLD {
+                    continue;
+                }
+
+                jxx += pxx;
+                jxy += pxy;
+                jyy += pyy;
+
+                let angle = 0.5 * (2.0 * pxy).atan2(pxx - pyy);
+
+                gradients.push(((x as f32 + 0.5, y as f32 + 0.5), (angle.cos(), angle.sin())));
+            }
+        }
+
+        if gradients.is_empty() {
+            return None;
+        }
+
+        let coherence = ((jxx - jyy) * (jxx - jyy) + 4.0 * jxy * jxy).sqrt() / (jxx + jyy);
+
+        if coherence > Self::COHERENCE_THRESHOLD {
+            // All gradients point the same way, so this is an axial shading. The axis runs
+            // through the centroid of the color transition, along the dominant direction.
+
+            let angle = 0.5 * (2.0 * jxy).atan2(jxx - jyy);
+
+            let direction = (angle.cos(), angle.sin());
+
+            let count = gradients.len() as f32;
+
+            let centroid = gradients.iter().fold((0.0, 0.0), |sum, ((x, y), _)| {
+                (sum.0 + x / count, sum.1 + y / count)
+            });
+
+            let (min, max) = gradients.iter().fold(
+                (f32::MAX, f32::MIN),
+                |(min, max): (f32, f32), ((x, y), _)| {
+                    let t = (x - centroid.0) * direction.0 + (y - centroid.1) * direction.1;
+
+                    (min.min(t), max.max(t))
+                },
+            );
+
+            return Some(ShadingGeometry::Axial {
+                start: (
+                    centroid.0 + direction.0 * min,
+                    centroid.1 + direction.1 * min,
+                ),
+                end: (
+                    centroid.0 + direction.0 * max,
+                    centroid.1 + direction.1 * max,
+                ),
+            });
+        }
+
+        // Find the point that lies closest to all the lines running through each gradient
+        // pixel in its gradient direction. For a radial shading, all these lines meet at
+        // the center of the circles.
+
+        let (mut a11, mut a12, mut a22, mut b1, mut b2) = (0.0, 0.0, 0.0, 0.0, 0.0);
+
+        for ((x, y), (nx, ny)) in gradients.iter() {
+            let (p11, p12, p22) = (1.0 - nx * nx, -nx * ny, 1.0 - ny * ny);
+
+            a11 += p11;
+            a12 += p12;
+            a22 += p22;
+            b1 += p11 * x + p12 * y;
+            b2 += p12 * x + p22 * y;
+        }
+
+        let determinant: f32 = a11 * a22 - a12 * a12;
+
+        if determinant.abs() < f32::EPSILON {
+            return None;
+        }
+
+        let center = (
+            (a22 * b1 - a12 * b2) / determinant,
+            (a11 * b2 - a12 * b1) / determinant,
+        );
+
+        let mut alignment = 0.0;
+
+        let (mut start_radius, mut end_radius) = (f32::MAX, 0.0_f32);
+
+        for ((x, y), (nx, ny)) in gradients.iter() {
+            let (dx, dy) = (x - center.0, y - center.1);
+
+            let distance = (dx * dx + dy * dy).sqrt();
+
+            if distance > 0.0 {
+                alignment += ((dx * nx + dy * ny) / distance).abs();
+            }
+
+            start_radius = start_radius.min(distance);
+            end_radius = end_radius.max(distance);
+        }
+
+        if alignment / (gradients.len() as f32) > Self::COHERENCE_THRESHOLD {
+            Some(ShadingGeometry::Radial {
+                center,
+                start_radius,
+                end_radius,
+            })
+        } else {
+            None
+        }
+    }
+}
+
+/// The result of analyzing a rendering of a shading object.
+struct ShadingAnalysis {
+    geometry: ShadingGeometry,
+    pixels: Vec<u8>,
+    width: usize,
+    height: usize,
+    left: f32,
+    top: f32,
+    scale: f32,
+}
+
+impl ShadingAnalysis {
+    /// Converts the given bitmap pixel coordinates into page coordinates.
+    fn to_page(&self, (x, y): (f32, f32)) -> (PdfPoints, PdfPoints) {
+        (
+            PdfPoints::new(self.left + x / self.scale),
+            PdfPoints::new(self.top - y / self.scale),
+        )
+    }
+
+    /// Returns the unit vector pointing from the given position towards the most distant
+    /// pixel at which the color changes.
+    fn direction_to_farthest_gradient_pixel(&self, (cx, cy): (f32, f32)) -> (f32, f32) {
+        let mut farthest = (1.0, 0.0);
+
+        let mut farthest_distance = 0.0;
+
+        for y in 0..self.height {
+            for x in 0..self.width {
+                if !self.is_opaque(x, y) {
+                    continue;
+                }
+
+                let (dx, dy) = (x as f32 + 0.5 - cx, y as f32 + 0.5 - cy);
+
+                let distance = (dx * dx + dy * dy).sqrt();
+
+                if distance > farthest_distance {
+                    farthest_distance = distance;
+                    farthest = (dx / distance, dy / distance);
+                }
+            }
+        }
+
+        farthest
+    }
+
+    /// Returns the color of the opaque pixel nearest to the given position.
+    fn color_at(&self, (x, y): (f32, f32)) -> PdfColor {
+        let clamp = |value: f32, size: usize| (value.max(0.0) as usize).min(size - 1);
+
+        let (mut nearest_x, mut nearest_y) = (clamp(x, self.width), clamp(y, self.height));
+
+        if !self.is_opaque(nearest_x, nearest_y) {
+            let mut nearest_distance = f32::MAX;
+
+            for py in 0..self.height {
+                for px in 0..self.width {
+                    let (dx, dy) = (px as f32 + 0.5 - x, py as f32 + 0.5 - y);
+
+                    let distance = dx * dx + dy * dy;
+
+                    if self.is_opaque(px, py) && distance < nearest_distance {
+                        nearest_distance = distance;
+                        nearest_x = px;
+                        nearest_y = py;
+                    }
+                }
+            }
+        }
+
+        let offset = (nearest_y * self.width + nearest_x) * 4;
+
+        PdfColor::new(
+            self.pixels[offset],
+            self.pixels[offset + 1],
+            self.pixels[offset + 2],
+            self.pixels[offset + 3],
+        )
+    }
+
+    #[inline]
+    fn is_opaque(&self, x: usize, y: usize) -> bool {
+        self.pixels[(y * self.width + x) * 4 + 3] == 255
+    }
 }
 
 impl<'a> PdfPageObjectPrivate<'a> for PdfPageShadingObject<'a> {
@@ -63,3 +595,136 @@ impl<'a> PdfPageObjectPrivate<'a> for PdfPageShadingObject<'a> {
         Err(PdfiumError::UnsupportedPdfPageObjectType)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::ShadingGeometry;
+    use crate::prelude::*;
+    use crate::utils::test::test_bind_to_pdfium;
+
+    #[test]
+    fn test_shading_geometry_from_pixels() {
+        let (width, height) = (64, 48);
+
+        let pixels = |color: &dyn Fn(f32, f32) -> u8| {
+            let mut pixels = Vec::with_capacity(width * height * 4);
+
+            for y in 0..height {
+                for x in 0..width {
+                    let value = color(x as f32 + 0.5, y as f32 + 0.5);
+
+                    pixels.extend_from_slice(&[value, 0, 255 - value, 255]);
+                }
+            }
+
+            pixels
+        };
+
+        // A horizontal transition from x = 10 to x = 50, flat beyond either end.
+
+        let axial = pixels(&|x, _| ((x - 10.0) / 40.0 * 255.0).clamp(0.0, 255.0) as u8);
+
+        match ShadingGeometry::from_pixels(&axial, width, height) {
+            Some(ShadingGeometry::Axial { start, end }) => {
+                let (left, right) = if start.0 < end.0 {
+                    (start, end)
+                } else {
+                    (end, start)
+                };
+
+                assert!((left.0 - 10.0).abs() < 2.0);
+                assert!((right.0 - 50.0).abs() < 2.0);
+                assert!((left.1 - right.1).abs() < 0.01);
+            }
+            other => panic!("expected axial geometry, found {:?}", other),
+        }
+
+        // Concentric circles centered at (30, 20), with radii from 5 to 18.
+
+        let radial = pixels(&|x, y| {
+            let distance = ((x - 30.0).powi(2) + (y - 20.0).powi(2)).sqrt();
+
+            ((distance - 5.0) / 13.0 * 255.0).clamp(0.0, 255.0) as u8
+        });
+
+        match ShadingGeometry::from_pixels(&radial, width, height) {
+            Some(ShadingGeometry::Radial {
+                center,
+                start_radius,
+                end_radius,
+            }) => {
+                assert!((center.0 - 30.0).abs() < 1.0);
+                assert!((center.1 - 20.0).abs() < 1.0);
+                assert!((start_radius - 5.0).abs() < 2.0);
+                assert!((end_radius - 18.0).abs() < 2.0);
+            }
+            other => panic!("expected radial geometry, found {:?}", other),
+        }
+
+        // A flat color contains no transition at all.
+
+        assert_eq!(
+            ShadingGeometry::from_pixels(&pixels(&|_, _| 128), width, height),
+            None
+        );
+    }
+
+    #[test]
+    fn test_axial_shading_details() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let document = pdfium.load_pdf_from_file("./test/shading-test.pdf", None)?;
+
+        let page = document.pages().get(0)?;
+
+        let object = page
+            .objects()
+            .iter()
+            .find(|object| object.object_type() == PdfPageObjectType::Shading)
+            .unwrap();
+
+        let shading = object.as_shading_object().unwrap();
+
+        assert_eq!(shading.shading_type()?, PdfShadingType::Axial);
+        assert!(matches!(
+            shading.radial_coords(),
+            Err(PdfiumError::UnsupportedOperation)
+        ));
+
+        // The fixture's shading runs from (20, 50) to (180, 50).
+
+        let coords = shading.axial_coords()?;
+
+        let (left, right) = if coords.start_x < coords.end_x {
+            (
+                (coords.start_x, coords.start_y),
+                (coords.end_x, coords.end_y),
+            )
+        } else {
+            (
+                (coords.end_x, coords.end_y),
+                (coords.start_x, coords.start_y),
+            )
+        };
+
+        assert!((left.0.value - 20.0).abs() < 5.0);
+        assert!((left.1.value - 50.0).abs() < 5.0);
+        assert!((right.0.value - 180.0).abs() < 5.0);
+        assert!((right.1.value - 50.0).abs() < 5.0);
+
+        // The shading blends from red to blue.
+
+        let colors = shading.sample_colors(2)?;
+
+        assert_eq!(colors.len(), 2);
+        assert_ne!(colors[0], colors[1]);
+
+        let is_red = |color: &PdfColor| color.red() > 200 && color.blue() < 55;
+        let is_blue = |color: &PdfColor| color.blue() > 200 && color.red() < 55;
+
+        assert!(colors.iter().any(is_red));
+        assert!(colors.iter().any(is_blue));
+
+        Ok(())
+    }
+}
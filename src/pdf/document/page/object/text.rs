@@ -2,7 +2,7 @@
 //! page object defining a piece of formatted text.
 
 use crate::bindgen::{
-    FPDF_DOCUMENT, FPDF_FONT, FPDF_PAGEOBJECT, FPDF_TEXT_RENDERMODE,
+    FPDF_DOCUMENT, FPDF_FONT, FPDF_PAGE, FPDF_PAGEOBJECT, FPDF_TEXT_RENDERMODE,
     FPDF_TEXT_RENDERMODE_FPDF_TEXTRENDERMODE_CLIP, FPDF_TEXT_RENDERMODE_FPDF_TEXTRENDERMODE_FILL,
     FPDF_TEXT_RENDERMODE_FPDF_TEXTRENDERMODE_FILL_CLIP,
     FPDF_TEXT_RENDERMODE_FPDF_TEXTRENDERMODE_FILL_STROKE,
@@ -14,6 +14,7 @@ use crate::bindgen::{
 };
 use crate::bindings::PdfiumLibraryBindings;
 use crate::error::{PdfiumError, PdfiumInternalError};
+use crate::pdf::bitmap::PdfBitmap;
 use crate::pdf::document::fonts::ToPdfFontToken;
 use crate::pdf::document::page::object::private::internal::PdfPageObjectPrivate;
 use crate::pdf::document::page::object::{
@@ -25,8 +26,8 @@ use crate::pdf::document::PdfDocument;
 use crate::pdf::font::PdfFont;
 use crate::pdf::matrix::{PdfMatrix, PdfMatrixValue};
 use crate::pdf::points::PdfPoints;
-use crate::utils::mem::create_byte_buffer;
-use crate::utils::utf16le::get_string_from_pdfium_utf16le_bytes;
+use crate::utils::mem::create_sized_buffer;
+use crate::utils::utf16le::get_string_from_pdfium_utf16le_words;
 use crate::{create_transform_getters, create_transform_setters};
 
 /// The text rendering modes supported by the PDF standard, as listed in table 5.3
@@ -95,8 +96,6 @@ impl PdfPageTextRenderMode {
     }
 
     #[inline]
-    #[allow(dead_code)]
-    // The as_pdfium() function is not currently used, but we expect it to be in future
     pub(crate) fn as_pdfium(&self) -> FPDF_TEXT_RENDERMODE {
         match self {
             PdfPageTextRenderMode::Unknown => FPDF_TEXT_RENDERMODE_FPDF_TEXTRENDERMODE_UNKNOWN,
@@ -124,6 +123,23 @@ impl PdfPageTextRenderMode {
     }
 }
 
+/// Controls how [PdfPageTextObject::replace_text_preserving_layout()] handles replacement
+/// text that is wider than the text it replaces.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum PdfTextFitMode {
+    /// The replacement text is set at the existing font size, even if it is wider than
+    /// the original text and so overflows the space the original text occupied.
+    None,
+
+    /// The font size is reduced until the width of the replacement text is no greater
+    /// than the width of the original text.
+    ShrinkToFit,
+
+    /// Characters are removed from the end of the replacement text until its width at
+    /// the existing font size is no greater than the width of the original text.
+    Truncate,
+}
+
 /// A single `PdfPageObject` of type `PdfPageObjectType::Text`. The page object defines a single
 /// piece of formatted text.
 ///
@@ -198,8 +214,9 @@ impl<'a> PdfPageTextObject<'a> {
         let handle = bindings.FPDFPageObj_CreateTextObj(document, font, font_size.value);
 
         if handle.is_null() {
-            Err(PdfiumError::PdfiumLibraryInternalError(
-                PdfiumInternalError::Unknown,
+            Err(bindings.error_in(
+                "FPDFPageObj_CreateTextObj",
+                PdfiumError::PdfiumLibraryInternalError(PdfiumInternalError::Unknown),
             ))
         } else {
             let mut result = PdfPageTextObject {
@@ -223,6 +240,20 @@ impl<'a> PdfPageTextObject<'a> {
         .unwrap_or(PdfPageTextRenderMode::Unknown)
     }
 
+    /// Returns `true` if the text contained within this [PdfPageTextObject] is neither filled
+    /// nor stroked, i.e. if its text rendering mode is either [PdfPageTextRenderMode::Invisible]
+    /// or [PdfPageTextRenderMode::InvisibleClipping].
+    ///
+    /// Invisible text does not appear when the page is rendered, but can still be extracted
+    /// and searched. It is commonly used to overlay recognized text on scanned images.
+    #[inline]
+    pub fn is_invisible(&self) -> bool {
+        matches!(
+            self.render_mode(),
+            PdfPageTextRenderMode::Invisible | PdfPageTextRenderMode::InvisibleClipping
+        )
+    }
+
     /// Returns the effective size of the text when rendered, taking into account both the
     /// font size specified in this text object as well as any vertical scale factor applied
     /// to the text object's transformation matrix.
@@ -254,6 +285,10 @@ impl<'a> PdfPageTextObject<'a> {
     }
 
     /// Returns the [PdfFont] used to render the text contained within this [PdfPageTextObject].
+    ///
+    /// The returned [PdfFont] does not take ownership of the underlying Pdfium font, which
+    /// belongs to the document containing this [PdfPageTextObject]; the font is not released
+    /// when the returned [PdfFont] is dropped.
     pub fn font(&self) -> PdfFont {
         PdfFont::from_pdfium(
             self.bindings().FPDFTextObj_GetFont(self.object_handle),
@@ -316,12 +351,18 @@ impl<'a> PdfPageTextObject<'a> {
                     return String::new();
                 }
 
-                let mut buffer = create_byte_buffer(buffer_length as usize);
+                // The buffer length is given in bytes. We allocate a buffer of 16-bit
+                // code units rather than bytes so that the buffer is correctly aligned
+                // for Pdfium's FPDF_WCHAR type.
+
+                let mut buffer = create_sized_buffer::<FPDF_WCHAR>(
+                    (buffer_length as usize + 1) / std::mem::size_of::<FPDF_WCHAR>(),
+                );
 
                 let result = self.bindings().FPDFTextObj_GetText(
                     self.object_handle(),
                     text_handle,
-                    buffer.as_mut_ptr() as *mut FPDF_WCHAR,
+                    buffer.as_mut_ptr(),
                     buffer_length,
                 );
 
@@ -329,7 +370,7 @@ impl<'a> PdfPageTextObject<'a> {
 
                 self.bindings.FPDFText_ClosePage(text_handle);
 
-                get_string_from_pdfium_utf16le_bytes(buffer).unwrap_or_default()
+                get_string_from_pdfium_utf16le_words(&buffer).unwrap_or_default()
             } else {
                 // The PdfPage containing this page object does not have an associated
                 // FPDF_TEXTPAGE object.
@@ -347,6 +388,13 @@ impl<'a> PdfPageTextObject<'a> {
     ///
     /// A single space will be used if the given text is empty, in order to avoid
     /// unexpected behaviour from Pdfium when dealing with an empty string.
+    ///
+    /// Any valid Rust string, including characters outside the Basic Multilingual Plane such as
+    /// emoji, is passed to Pdfium without loss. Note, however, that Pdfium encodes the text
+    /// using the font assigned to this [PdfPageTextObject]; characters that cannot be
+    /// represented by that font will not be returned by [PdfPageTextObject::text()]. To use
+    /// non-Latin scripts, load a font that covers those scripts using one of the
+    /// `PdfFonts::load_true_type_*()` functions, setting `is_cid_font` to `true`.
     pub fn set_text(&mut self, text: impl ToString) -> Result<(), PdfiumError> {
         let text = text.to_string();
 
@@ -358,8 +406,9 @@ impl<'a> PdfPageTextObject<'a> {
         ) {
             Ok(())
         } else {
-            Err(PdfiumError::PdfiumLibraryInternalError(
-                PdfiumInternalError::Unknown,
+            Err(self.bindings().error_in(
+                "FPDFText_SetText_str",
+                PdfiumError::PdfiumLibraryInternalError(PdfiumInternalError::Unknown),
             ))
         }
     }
@@ -375,19 +424,265 @@ impl<'a> PdfPageTextObject<'a> {
         ) {
             Ok(())
         } else {
-            Err(PdfiumError::PdfiumLibraryInternalError(
-                PdfiumInternalError::Unknown,
+            Err(self.bindings().error_in(
+                "FPDFTextObj_SetTextRenderMode",
+                PdfiumError::PdfiumLibraryInternalError(PdfiumInternalError::Unknown),
             ))
         }
     }
 
+    /// Replaces the text contained within this [PdfPageTextObject] while keeping the object's
+    /// position and styling intact, using the given [PdfTextFitMode] to handle replacement
+    /// text that is wider than the existing text. This is useful for filling in placeholder
+    /// text in templates.
+    ///
+    /// The widths of the existing and replacement text are measured using the metrics of
+    /// this object's [PdfFont]. The object's transformation matrix, text rendering mode,
+    /// colors, and font are never changed.
+    ///
+    /// Pdfium does not support changing the font size of an existing text object, so when
+    /// [PdfTextFitMode::ShrinkToFit] needs to reduce the font size, this function replaces
+    /// the underlying Pdfium text object with a new one in the same position in the page's
+    /// object stack. Any other references to the original object, such as index positions
+    /// in a previously retrieved `PdfPageText` object, should be considered invalid.
+    ///
+    /// This [PdfPageTextObject] must be attached to a page, since the existing text can
+    /// only be retrieved from an attached object.
+    pub fn replace_text_preserving_layout(
+        &mut self,
+        new_text: &str,
+        fit: PdfTextFitMode,
+    ) -> Result<(), PdfiumError> {
+        let (document_handle, page_handle) = match self.ownership() {
+            PdfPageObjectOwnership::Page(ownership) => {
+                (ownership.document_handle(), ownership.page_handle())
+            }
+            _ => return Err(PdfiumError::OwnershipNotAttachedToPage),
+        };
+
+        let font_handle = self.font().handle();
+
+        let font_size = self.unscaled_font_size();
+
+        // Measure the text while the font is borrowed, then apply the result afterwards.
+
+        let (text, new_font_size) = {
+            let font = self.font();
+
+            let original_width = font.text_width_at_font_size(&self.text(), font_size);
+
+            match fit {
+                PdfTextFitMode::None => (new_text.to_string(), None),
+                PdfTextFitMode::Truncate => {
+                    let mut text = new_text.to_string();
+
+                    while !text.is_empty()
+                        && font.text_width_at_font_size(&text, font_size) > original_width
+                    {
+                        text.pop();
+                    }
+
+                    (text, None)
+                }
+                PdfTextFitMode::ShrinkToFit => {
+                    let new_width = font.text_width_at_font_size(new_text, font_size);
+
+                    if new_width <= original_width {
+                        (new_text.to_string(), None)
+                    } else {
+                        // Glyph widths scale linearly with the font size, so the target size
+                        // can be calculated directly. Rounding errors may leave the result
+                        // fractionally too wide, in which case we nudge the size down until
+                        // the text fits.
+
+                        let mut new_font_size = PdfPoints::new(
+                            font_size.value * original_width.value / new_width.value,
+                        );
+
+                        while new_font_size.value > 0.0
+                            && font.text_width_at_font_size(new_text, new_font_size)
+                                > original_width
+                        {
+                            new_font_size = PdfPoints::new(new_font_size.value * 0.999);
+                        }
+
+                        (new_text.to_string(), Some(new_font_size))
+                    }
+                }
+            }
+        };
+
+        match new_font_size {
+            None => self.set_text(text),
+            Some(new_font_size) => {
+                let replacement = PdfPageTextObject::new_from_handles(
+                    document_handle,
+                    text,
+                    font_handle,
+                    new_font_size,
+                    self.bindings,
+                )?;
+
+                self.replace_object_on_page(replacement, page_handle)
+            }
+        }
+    }
+
+    /// Sets the font used to render the text contained within this [PdfPageTextObject],
+    /// keeping the object's text, font size, transformation matrix, and styling intact.
+    ///
+    /// Glyph widths differ from font to font, so the width of the text will usually change
+    /// when the font is changed. The text is anchored at its origin, so wider text will extend
+    /// further to the right (for left-to-right scripts) and may overlap neighbouring content.
+    /// Characters that cannot be represented by the new font will not be rendered.
+    ///
+    /// Pdfium does not support changing the font of an existing text object, so this function
+    /// replaces the underlying Pdfium text object with a new one in the same position in the
+    /// page's object stack. Any other references to the original object, such as index positions
+    /// in a previously retrieved `PdfPageText` object, should be considered invalid.
+    ///
+    /// This [PdfPageTextObject] must be attached to a page, since the existing text can
+    /// only be retrieved from an attached object.
+    pub fn set_font(&mut self, font: impl ToPdfFontToken) -> Result<(), PdfiumError> {
+        let (document_handle, page_handle) = match self.ownership() {
+            PdfPageObjectOwnership::Page(ownership) => {
+                (ownership.document_handle(), ownership.page_handle())
+            }
+            _ => return Err(PdfiumError::OwnershipNotAttachedToPage),
+        };
+
+        let replacement = PdfPageTextObject::new_from_handles(
+            document_handle,
+            self.text(),
+            font.token().handle(),
+            self.unscaled_font_size(),
+            self.bindings,
+        )?;
+
+        self.replace_object_on_page(replacement, page_handle)
+    }
+
+    /// Copies the styling and transformation matrix of this [PdfPageTextObject]
+    /// to the given text object.
+    fn copy_style_to(&self, target: &mut PdfPageTextObject) -> Result<(), PdfiumError> {
+        target.set_fill_color(self.fill_color()?)?;
+        target.set_stroke_color(self.stroke_color()?)?;
+        target.set_stroke_width(self.stroke_width()?)?;
+        target.set_line_join(self.line_join()?)?;
+        target.set_line_cap(self.line_cap()?)?;
+
+        if let Ok(limit) = self.miter_limit() {
+            target.set_miter_limit(limit)?;
+        }
+
+        match self.render_mode() {
+            PdfPageTextRenderMode::Unknown => {}
+            render_mode => target.set_render_mode(render_mode)?,
+        }
+
+        if let Ok(blend_mode) = self.blend_mode() {
+            target.set_blend_mode(blend_mode)?;
+        }
+
+        target.reset_matrix(self.matrix()?)
+    }
+
+    /// Swaps the Pdfium text object wrapped by this [PdfPageTextObject] for the given detached
+    /// replacement, inserting the replacement at the same index position on the containing page.
+    fn replace_object_on_page(
+        &mut self,
+        mut replacement: PdfPageTextObject<'a>,
+        page_handle: FPDF_PAGE,
+    ) -> Result<(), PdfiumError> {
+        self.copy_style_to(&mut replacement)?;
+
+        // Pdfium can only append objects to the end of a page, so we detach this object and
+        // every object above it, then re-append them with the replacement taking the place
+        // of this object. Pdfium does not free the memory of removed objects.
+
+        let count = self.bindings.FPDFPage_CountObjects(page_handle);
+
+        let position = (0..count)
+            .find(|index| {
+                self.bindings.FPDFPage_GetObject(page_handle, *index) == self.object_handle
+            })
+            .ok_or(PdfiumError::OwnershipNotAttachedToPage)?;
+
+        let mut handles = (position..count)
+            .map(|index| self.bindings.FPDFPage_GetObject(page_handle, index))
+            .collect::<Vec<_>>();
+
+        for handle in handles.iter() {
+            if !self
+                .bindings
+                .is_true(self.bindings.FPDFPage_RemoveObject(page_handle, *handle))
+            {
+                return Err(self.bindings.error_in(
+                    "FPDFPage_RemoveObject",
+                    PdfiumError::PdfiumLibraryInternalError(PdfiumInternalError::Unknown),
+                ));
+            }
+        }
+
+        handles[0] = replacement.object_handle;
+
+        for handle in handles {
+            self.bindings.FPDFPage_InsertObject(page_handle, handle);
+        }
+
+        // The original object is no longer owned by the page, so we must release it ourselves.
+
+        self.bindings.FPDFPageObj_Destroy(self.object_handle);
+
+        self.object_handle = replacement.object_handle;
+
+        self.regenerate_content_after_mutation()
+    }
+
     /// Returns a collection of the characters contained within this [PdfPageTextObject],
     /// using character retrieval functionality provided by the given [PdfPageText] object.
+    ///
+    /// See [PdfPageText::chars_for_object()] for details of how characters are matched
+    /// to this object.
     #[inline]
     pub fn chars(&self, text: &'a PdfPageText<'a>) -> Result<PdfPageTextChars<'a>, PdfiumError> {
         text.chars_for_object(self)
     }
 
+    /// Renders this [PdfPageTextObject] into a new [PdfBitmap], scaled by the given factor.
+    /// A scale factor of `1.0` renders the text at one pixel per point. The bitmap is sized
+    /// to fit the rendered text exactly.
+    ///
+    /// This [PdfPageTextObject] must be attached to a document.
+    pub fn rendered_bitmap(&self, scale: f32) -> Result<PdfBitmap<'a>, PdfiumError> {
+        let document_handle = self
+            .ownership()
+            .document_handle()
+            .ok_or(PdfiumError::OwnershipNotAttachedToDocument)?;
+
+        let page_handle = match self.ownership() {
+            PdfPageObjectOwnership::Page(ownership) => ownership.page_handle(),
+            PdfPageObjectOwnership::AttachedAnnotation(ownership) => ownership.page_handle(),
+            _ => std::ptr::null_mut(),
+        };
+
+        let handle = self.bindings.FPDFTextObj_GetRenderedBitmap(
+            document_handle,
+            page_handle,
+            self.object_handle(),
+            scale,
+        );
+
+        if handle.is_null() {
+            Err(self.bindings.error_in(
+                "FPDFTextObj_GetRenderedBitmap",
+                PdfiumError::PdfiumLibraryInternalError(PdfiumInternalError::Unknown),
+            ))
+        } else {
+            Ok(PdfBitmap::from_pdfium(handle, self.bindings))
+        }
+    }
+
     /// Returns `true` if any of the characters contained within this [PdfPageTextObject] have a
     /// glyph shape that descends below the font baseline.
     ///
@@ -480,13 +775,380 @@ impl<'a> PdfPageObjectPrivate<'a> for PdfPageTextObject<'a> {
             bindings,
         )?;
 
-        copy.set_fill_color(self.fill_color()?)?;
-        copy.set_stroke_color(self.stroke_color()?)?;
-        copy.set_stroke_width(self.stroke_width()?)?;
-        copy.set_line_join(self.line_join()?)?;
-        copy.set_line_cap(self.line_cap()?)?;
-        copy.reset_matrix(self.matrix()?)?;
+        self.copy_style_to(&mut copy)?;
 
         Ok(PdfPageObject::Text(copy))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::utils::test::test_bind_to_pdfium;
+
+    #[test]
+    fn test_set_text_round_trip() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        let font = document.fonts_mut().helvetica();
+
+        let mut page = document
+            .pages_mut()
+            .create_page_at_start(PdfPagePaperSize::a4())?;
+
+        let mut object = page.objects_mut().create_text_object(
+            PdfPoints::new(100.0),
+            PdfPoints::new(700.0),
+            "placeholder",
+            font,
+            PdfPoints::new(12.0),
+        )?;
+
+        let text_object = object.as_text_object_mut().unwrap();
+
+        for sample in [
+            "Hello, world!",
+            "Grüße aus Köln",
+            "Café crème, naïve façade",
+        ] {
+            text_object.set_text(sample)?;
+
+            assert_eq!(text_object.text(), sample);
+        }
+
+        // Helvetica can only encode Latin text. Scripts outside the built-in fonts' encoding
+        // need an embedded font with glyphs for them; test/unicode-test.ttf is a minimal
+        // TrueType font covering the code points of the samples below.
+
+        let unicode_font = document
+            .fonts_mut()
+            .load_true_type_from_file("./test/unicode-test.ttf", true)?;
+
+        let mut object = page.objects_mut().create_text_object(
+            PdfPoints::new(100.0),
+            PdfPoints::new(600.0),
+            "placeholder",
+            unicode_font,
+            PdfPoints::new(12.0),
+        )?;
+
+        let text_object = object.as_text_object_mut().unwrap();
+
+        for sample in [
+            "Hello, world!",
+            "مرحبا بالعالم",
+            "नमस्ते दुनिया",
+            "你好，世界",
+            "こんにちは",
+            "안녕하세요",
+            "👋🌍 👍🏽",
+        ] {
+            text_object.set_text(sample)?;
+
+            assert_eq!(text_object.text(), sample);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "image_api")]
+    fn test_invisible_text_over_image() -> Result<(), PdfiumError> {
+        use crate::pdf::document::page::object::private::internal::PdfPageObjectPrivate;
+
+        let pdfium = test_bind_to_pdfium();
+
+        // Simulate a scanned page by rendering an existing page into an image.
+
+        let image = pdfium
+            .load_pdf_from_file("./test/path-test.pdf", None)?
+            .pages()
+            .get(0)?
+            .render_with_config(&PdfRenderConfig::new().set_target_width(400))?
+            .as_image();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        let font = document.fonts_mut().helvetica();
+
+        let mut page = document
+            .pages_mut()
+            .create_page_at_end(PdfPagePaperSize::a4())?;
+
+        let (width, height) = (page.width(), page.height());
+
+        page.objects_mut().create_image_object(
+            PdfPoints::ZERO,
+            PdfPoints::ZERO,
+            &image,
+            Some(width),
+            Some(height),
+        )?;
+
+        let config = PdfRenderConfig::new().set_target_width(400);
+
+        let before = page.render_with_config(&config)?.as_rgba_bytes();
+
+        let mut object = page.objects_mut().create_text_object(
+            PdfPoints::new(100.0),
+            PdfPoints::new(400.0),
+            "Recognized text",
+            font,
+            PdfPoints::new(36.0),
+        )?;
+
+        let text_object = object.as_text_object_mut().unwrap();
+
+        assert!(!text_object.is_invisible());
+
+        text_object.set_render_mode(PdfPageTextRenderMode::Invisible)?;
+
+        assert_eq!(text_object.render_mode(), PdfPageTextRenderMode::Invisible);
+        assert!(text_object.is_invisible());
+
+        // The invisible text must not change the rendered page...
+
+        let after = page.render_with_config(&config)?.as_rgba_bytes();
+
+        assert!(before == after);
+
+        // ... but must still be extractable.
+
+        assert!(page.text()?.all().contains("Recognized text"));
+
+        // Copies of the text object must also be invisible.
+
+        let copy = object.try_copy_impl(document.handle(), document.bindings())?;
+
+        assert!(copy.as_text_object().unwrap().is_invisible());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_chars_for_repeated_text() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        let font = document.fonts_mut().helvetica();
+
+        let mut page = document
+            .pages_mut()
+            .create_page_at_end(PdfPagePaperSize::a4())?;
+
+        // The same string appears twice on the page, so characters must be matched
+        // to each object by position rather than by content.
+
+        for y in [700.0, 500.0] {
+            page.objects_mut().create_text_object(
+                PdfPoints::new(100.0),
+                PdfPoints::new(y),
+                "Repeated",
+                font,
+                PdfPoints::new(24.0),
+            )?;
+        }
+
+        let text = page.text()?;
+
+        for object in page.objects().iter() {
+            let object = object.as_text_object().unwrap();
+
+            let bounds = object.bounds()?.to_rect();
+
+            let chars = object.chars(&text)?;
+
+            assert_eq!(chars.len(), "Repeated".len());
+
+            for char in chars.iter() {
+                let char_bounds = char.loose_bounds()?;
+
+                assert!(char_bounds.bottom() >= bounds.bottom() - PdfPoints::new(1.0));
+                assert!(char_bounds.top() <= bounds.top() + PdfPoints::new(1.0));
+            }
+
+            let bitmap = object.rendered_bitmap(2.0)?;
+
+            assert!(bitmap.width() as f32 >= bounds.width().value * 1.5);
+            assert!(bitmap.height() > 0);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_replace_text_preserving_layout() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        let font = document.fonts_mut().helvetica();
+
+        let mut page = document
+            .pages_mut()
+            .create_page_at_end(PdfPagePaperSize::a4())?;
+
+        // Place the placeholder beneath another object, so we can check that the
+        // placeholder's position in the page's object stack is retained.
+
+        for text in ["{{NAME}}", "Footer"] {
+            let mut object = page.objects_mut().create_text_object(
+                PdfPoints::new(100.0),
+                PdfPoints::new(700.0),
+                text,
+                font,
+                PdfPoints::new(24.0),
+            )?;
+
+            object.set_fill_color(PdfColor::RED)?;
+        }
+
+        let mut object = page.objects().get(0)?;
+
+        let text_object = object.as_text_object_mut().unwrap();
+
+        text_object.set_render_mode(PdfPageTextRenderMode::FilledThenStroked)?;
+
+        let original_bounds = text_object.bounds()?.to_rect();
+
+        let original_matrix = text_object.matrix()?;
+
+        text_object.replace_text_preserving_layout(
+            "Dr. Bartholomew Montgomery-Smythe",
+            PdfTextFitMode::ShrinkToFit,
+        )?;
+
+        let bounds = text_object.bounds()?.to_rect();
+
+        assert_eq!(text_object.text(), "Dr. Bartholomew Montgomery-Smythe");
+        assert!(text_object.unscaled_font_size() < PdfPoints::new(24.0));
+        assert!(bounds.width() <= original_bounds.width());
+        assert!((bounds.left() - original_bounds.left()).value.abs() < 1.0);
+        assert_eq!(text_object.matrix()?, original_matrix);
+        assert_eq!(
+            text_object.render_mode(),
+            PdfPageTextRenderMode::FilledThenStroked
+        );
+        assert_eq!(text_object.fill_color()?, PdfColor::RED);
+
+        assert_eq!(page.objects().len(), 2);
+        assert_eq!(
+            page.objects().get(0)?.as_text_object().unwrap().text(),
+            "Dr. Bartholomew Montgomery-Smythe"
+        );
+        assert_eq!(
+            page.objects().get(1)?.as_text_object().unwrap().text(),
+            "Footer"
+        );
+
+        // Truncation keeps the font size, removing characters until the text fits.
+
+        let mut object = page.objects().get(1)?;
+
+        let text_object = object.as_text_object_mut().unwrap();
+
+        text_object.replace_text_preserving_layout("Footnotes", PdfTextFitMode::Truncate)?;
+
+        assert_eq!(text_object.text(), "Footn");
+        assert_eq!(text_object.unscaled_font_size(), PdfPoints::new(24.0));
+
+        // Replacement text is allowed to overflow if no fit mode is set.
+
+        text_object.replace_text_preserving_layout("Footnotes", PdfTextFitMode::None)?;
+
+        assert_eq!(text_object.text(), "Footnotes");
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(any(
+        feature = "pdfium_future",
+        feature = "pdfium_6996",
+        feature = "pdfium_6721",
+        feature = "pdfium_6666"
+    ))]
+    fn test_font_of_existing_text_object() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        // Every text object in the test document uses the same embedded subset of Arial.
+
+        let document = pdfium.load_pdf_from_file("./test/segments-test.pdf", None)?;
+
+        let page = document.pages().first()?;
+
+        let mut count = 0;
+
+        for object in page.objects().iter() {
+            if let Some(object) = object.as_text_object() {
+                let font = object.font();
+
+                assert_eq!(font.base_name(), "UUHJEU+ArialMT");
+                assert!(font.is_subset());
+                assert!(font.is_embedded()?);
+
+                count += 1;
+            }
+        }
+
+        assert!(count > 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_set_font() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        let mut page = document
+            .pages_mut()
+            .create_page_at_start(PdfPagePaperSize::a4())?;
+
+        let helvetica = document.fonts_mut().helvetica();
+
+        let courier = document.fonts_mut().courier();
+
+        let mut object = page.objects_mut().create_text_object(
+            PdfPoints::new(100.0),
+            PdfPoints::new(200.0),
+            "Font substitution",
+            helvetica,
+            PdfPoints::new(18.0),
+        )?;
+
+        object.rotate_clockwise_degrees(30.0)?;
+
+        let object = object.as_text_object_mut().unwrap();
+
+        let matrix = object.matrix()?;
+
+        assert_eq!(object.font().handle(), helvetica.handle());
+
+        object.set_font(courier)?;
+
+        assert_eq!(object.font().handle(), courier.handle());
+        assert_eq!(object.text(), "Font substitution");
+        assert_eq!(object.unscaled_font_size(), PdfPoints::new(18.0));
+        assert_eq!(object.matrix()?, matrix);
+
+        // The replacement object must take the original object's place on the page.
+
+        assert_eq!(page.objects().len(), 1);
+        assert_eq!(
+            page.objects()
+                .first()?
+                .as_text_object()
+                .unwrap()
+                .font()
+                .handle(),
+            courier.handle()
+        );
+
+        Ok(())
+    }
+}
@@ -1,13 +1,14 @@
 //! Defines the [PdfPageXObjectFormObject] struct, exposing functionality related to a single
 //! page object of type `PdfPageObjectType::XObjectForm`.
 
-use crate::bindgen::{FPDF_DOCUMENT, FPDF_PAGEOBJECT};
+use crate::bindgen::{FPDF_DOCUMENT, FPDF_PAGEOBJECT, FS_MATRIX};
 use crate::bindings::PdfiumLibraryBindings;
 use crate::error::{PdfiumError, PdfiumInternalError};
 use crate::pdf::document::page::object::private::internal::PdfPageObjectPrivate;
 use crate::pdf::document::page::object::{PdfPageObject, PdfPageObjectOwnership};
 use crate::pdf::document::page::objects::common::{PdfPageObjectIndex, PdfPageObjectsIterator};
 use crate::pdf::document::page::objects::private::internal::PdfPageObjectsPrivate;
+use crate::pdf::matrix::PdfMatrix;
 use std::ops::{Range, RangeInclusive};
 use std::os::raw::c_ulong;
 
@@ -98,6 +99,29 @@ impl<'a> PdfPageXObjectFormObject<'a> {
     pub fn iter(&'a self) -> PdfPageObjectsIterator<'a> {
         self.iter_impl()
     }
+
+    /// Returns a read-only collection of the child [PdfPageObject] objects in this
+    /// [PdfPageXObjectFormObject].
+    ///
+    /// The bounds and transformation matrices of child objects are expressed in the coordinate
+    /// space of this [PdfPageXObjectFormObject], rather than in page space. Use the
+    /// [PdfPageXObjectFormObjects::matrix()] function to retrieve the transformation matrix
+    /// that maps child coordinates into the coordinate space containing this object.
+    #[inline]
+    pub fn objects(&self) -> PdfPageXObjectFormObjects<'a> {
+        PdfPageXObjectFormObjects::from_pdfium(self.object_handle, self.ownership, self.bindings)
+    }
+
+    /// Returns the ownership that should be given to a child object of the form object
+    /// with the given ownership. Child objects are owned by the form object, so they
+    /// must never be destroyed when dropped.
+    fn child_ownership(ownership: &PdfPageObjectOwnership) -> PdfPageObjectOwnership {
+        if ownership.is_owned() {
+            *ownership
+        } else {
+            PdfPageObjectOwnership::owned_by_detached_form_object()
+        }
+    }
 }
 
 impl<'a> PdfPageObjectPrivate<'a> for PdfPageXObjectFormObject<'a> {
@@ -162,13 +186,14 @@ impl<'a> PdfPageObjectsPrivate<'a> for PdfPageXObjectFormObject<'a> {
             .FPDFFormObj_GetObject(self.object_handle, index as c_ulong);
 
         if object_handle.is_null() {
-            Err(PdfiumError::PdfiumLibraryInternalError(
-                PdfiumInternalError::Unknown,
+            Err(self.bindings.error_in(
+                "FPDFFormObj_GetObject",
+                PdfiumError::PdfiumLibraryInternalError(PdfiumInternalError::Unknown),
             ))
         } else {
             Ok(PdfPageObject::from_pdfium(
                 object_handle,
-                PdfPageObjectPrivate::ownership(self).clone(),
+                Self::child_ownership(PdfPageObjectPrivate::ownership(self)),
                 PdfPageObjectsPrivate::bindings(self),
             ))
         }
@@ -179,8 +204,8 @@ impl<'a> PdfPageObjectsPrivate<'a> for PdfPageXObjectFormObject<'a> {
         PdfPageObjectsIterator::new(self)
     }
 
-    // The child objects collection is read-only, so add_object_impl() and remove_object_impl()
-    // are necessarily incomplete.
+    // The child objects collection is read-only, so add_object_impl(), remove_object_impl(),
+    // remove_objects_if_impl(), and move_object_impl() are necessarily incomplete.
 
     fn add_object_impl(
         &mut self,
@@ -195,4 +220,435 @@ impl<'a> PdfPageObjectsPrivate<'a> for PdfPageXObjectFormObject<'a> {
     ) -> Result<PdfPageObject<'a>, PdfiumError> {
         unimplemented!()
     }
+
+    #[inline]
+    fn remove_objects_if_impl(
+        &mut self,
+        _predicate: &dyn Fn(&PdfPageObject) -> bool,
+    ) -> Result<usize, PdfiumError> {
+        Err(PdfiumError::UnsupportedOperation)
+    }
+
+    #[inline]
+    fn move_object_impl(
+        &mut self,
+        _from: PdfPageObjectIndex,
+        _to: PdfPageObjectIndex,
+    ) -> Result<(), PdfiumError> {
+        Err(PdfiumError::UnsupportedOperation)
+    }
+}
+
+/// A read-only collection of the child [PdfPageObject] objects contained within
+/// a single [PdfPageXObjectFormObject].
+///
+/// Child objects are owned by their containing form object; they cannot be added to or removed
+/// from the collection. Their bounds and transformation matrices are expressed in the coordinate
+/// space of the form object. To map them into the coordinate space containing the form object,
+/// apply the matrix returned by [PdfPageXObjectFormObjects::matrix()], or use the
+/// [PdfPageXObjectFormObjects::iter_recursive()] function, which also descends into any
+/// nested form objects.
+pub struct PdfPageXObjectFormObjects<'a> {
+    form_object_handle: FPDF_PAGEOBJECT,
+    ownership: PdfPageObjectOwnership,
+    bindings: &'a dyn PdfiumLibraryBindings,
+}
+
+impl<'a> PdfPageXObjectFormObjects<'a> {
+    #[inline]
+    pub(crate) fn from_pdfium(
+        form_object_handle: FPDF_PAGEOBJECT,
+        ownership: PdfPageObjectOwnership,
+        bindings: &'a dyn PdfiumLibraryBindings,
+    ) -> Self {
+        PdfPageXObjectFormObjects {
+            form_object_handle,
+            ownership,
+            bindings,
+        }
+    }
+
+    /// Returns the [PdfiumLibraryBindings] used by this [PdfPageXObjectFormObjects] collection.
+    #[inline]
+    pub fn bindings(&self) -> &'a dyn PdfiumLibraryBindings {
+        self.bindings
+    }
+
+    /// Returns the total number of child page objects in this [PdfPageXObjectFormObjects] collection.
+    #[inline]
+    pub fn len(&self) -> PdfPageObjectIndex {
+        self.bindings
+            .FPDFFormObj_CountObjects(self.form_object_handle)
+            .max(0) as PdfPageObjectIndex
+    }
+
+    /// Returns `true` if this [PdfPageXObjectFormObjects] collection is empty.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns a Range from `0..(number of objects)` for this [PdfPageXObjectFormObjects] collection.
+    #[inline]
+    pub fn as_range(&self) -> Range<PdfPageObjectIndex> {
+        0..self.len()
+    }
+
+    /// Returns an inclusive Range from `0..=(number of objects - 1)` for this
+    /// [PdfPageXObjectFormObjects] collection.
+    #[inline]
+    pub fn as_range_inclusive(&self) -> RangeInclusive<PdfPageObjectIndex> {
+        if self.is_empty() {
+            0..=0
+        } else {
+            0..=(self.len() - 1)
+        }
+    }
+
+    /// Returns a single child [PdfPageObject] from this [PdfPageXObjectFormObjects] collection.
+    pub fn get(&self, index: PdfPageObjectIndex) -> Result<PdfPageObject<'a>, PdfiumError> {
+        if index >= self.len() {
+            return Err(PdfiumError::PageObjectIndexOutOfBounds);
+        }
+
+        let object_handle = self
+            .bindings
+            .FPDFFormObj_GetObject(self.form_object_handle, index as c_ulong);
+
+        if object_handle.is_null() {
+            Err(self.bindings.error_in(
+                "FPDFFormObj_GetObject",
+                PdfiumError::PdfiumLibraryInternalError(PdfiumInternalError::Unknown),
+            ))
+        } else {
+            Ok(PdfPageObject::from_pdfium(
+                object_handle,
+                PdfPageXObjectFormObject::child_ownership(&self.ownership),
+                self.bindings,
+            ))
+        }
+    }
+
+    /// Returns the first child [PdfPageObject] in this [PdfPageXObjectFormObjects] collection.
+    #[inline]
+    pub fn first(&self) -> Result<PdfPageObject<'a>, PdfiumError> {
+        if !self.is_empty() {
+            self.get(0)
+        } else {
+            Err(PdfiumError::NoPageObjectsInCollection)
+        }
+    }
+
+    /// Returns the last child [PdfPageObject] in this [PdfPageXObjectFormObjects] collection.
+    #[inline]
+    pub fn last(&self) -> Result<PdfPageObject<'a>, PdfiumError> {
+        if !self.is_empty() {
+            self.get(self.len() - 1)
+        } else {
+            Err(PdfiumError::NoPageObjectsInCollection)
+        }
+    }
+
+    /// Returns the transformation matrix of the form object containing this
+    /// [PdfPageXObjectFormObjects] collection. The matrix maps the coordinates of child objects
+    /// into the coordinate space containing the form object; for a form object placed directly
+    /// on a page, this is page space.
+    pub fn matrix(&self) -> Result<PdfMatrix, PdfiumError> {
+        let mut matrix = FS_MATRIX {
+            a: 0.0,
+            b: 0.0,
+            c: 0.0,
+            d: 0.0,
+            e: 0.0,
+            f: 0.0,
+        };
+
+        if self.bindings.is_true(
+            self.bindings
+                .FPDFPageObj_GetMatrix(self.form_object_handle, &mut matrix),
+        ) {
+            Ok(PdfMatrix::from_pdfium(matrix))
+        } else {
+            Err(self.bindings.error_in(
+                "FPDFPageObj_GetMatrix",
+                PdfiumError::PdfiumLibraryInternalError(PdfiumInternalError::Unknown),
+            ))
+        }
+    }
+
+    /// Returns an iterator over the child [PdfPageObject] objects in this
+    /// [PdfPageXObjectFormObjects] collection.
+    #[inline]
+    pub fn iter(&self) -> PdfPageXObjectFormObjectsIterator<'a> {
+        PdfPageXObjectFormObjectsIterator::new(PdfPageXObjectFormObjects::from_pdfium(
+            self.form_object_handle,
+            self.ownership,
+            self.bindings,
+        ))
+    }
+
+    /// Returns a depth-first iterator over the child [PdfPageObject] objects in this
+    /// [PdfPageXObjectFormObjects] collection, descending into any nested form objects.
+    /// Nested form objects are themselves returned before their children.
+    ///
+    /// Each object is returned alongside the cumulative transformation matrix that maps
+    /// the object's coordinates into the coordinate space containing the outermost form object.
+    /// Apply this matrix to the object's bounds to obtain its bounds in page space, for instance
+    /// by calling `object.bounds()?.transform(matrix)`.
+    #[inline]
+    pub fn iter_recursive(&self) -> PdfPageXObjectFormObjectsRecursiveIterator<'a> {
+        PdfPageXObjectFormObjectsRecursiveIterator::new(PdfPageXObjectFormObjects::from_pdfium(
+            self.form_object_handle,
+            self.ownership,
+            self.bindings,
+        ))
+    }
+}
+
+/// An iterator over the child [PdfPageObject] objects in a [PdfPageXObjectFormObjects] collection.
+pub struct PdfPageXObjectFormObjectsIterator<'a> {
+    objects: PdfPageXObjectFormObjects<'a>,
+    next_index: PdfPageObjectIndex,
+}
+
+impl<'a> PdfPageXObjectFormObjectsIterator<'a> {
+    #[inline]
+    pub(crate) fn new(objects: PdfPageXObjectFormObjects<'a>) -> Self {
+        PdfPageXObjectFormObjectsIterator {
+            objects,
+            next_index: 0,
+        }
+    }
+}
+
+impl<'a> Iterator for PdfPageXObjectFormObjectsIterator<'a> {
+    type Item = PdfPageObject<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let next = self.objects.get(self.next_index);
+
+        self.next_index += 1;
+
+        next.ok()
+    }
+}
+
+/// A depth-first iterator over the child [PdfPageObject] objects in a [PdfPageXObjectFormObjects]
+/// collection and any nested form objects, returning each object alongside the cumulative
+/// transformation matrix that maps the object's coordinates into the coordinate space
+/// containing the outermost form object.
+pub struct PdfPageXObjectFormObjectsRecursiveIterator<'a> {
+    // Each entry holds a collection of child objects, the index of the next child to return,
+    // and the matrix mapping the coordinates of those children into the outermost coordinate space.
+    stack: Vec<(PdfPageXObjectFormObjects<'a>, PdfPageObjectIndex, PdfMatrix)>,
+}
+
+impl<'a> PdfPageXObjectFormObjectsRecursiveIterator<'a> {
+    pub(crate) fn new(objects: PdfPageXObjectFormObjects<'a>) -> Self {
+        let mut stack = Vec::new();
+
+        if let Ok(matrix) = objects.matrix() {
+            stack.push((objects, 0, matrix));
+        }
+
+        PdfPageXObjectFormObjectsRecursiveIterator { stack }
+    }
+}
+
+impl<'a> Iterator for PdfPageXObjectFormObjectsRecursiveIterator<'a> {
+    type Item = (PdfPageObject<'a>, PdfMatrix);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let (objects, next_index, matrix) = self.stack.last_mut()?;
+
+            if *next_index >= objects.len() {
+                self.stack.pop();
+
+                continue;
+            }
+
+            let index = *next_index;
+
+            *next_index += 1;
+
+            let matrix = *matrix;
+
+            if let Ok(object) = objects.get(index) {
+                if let PdfPageObject::XObjectForm(form) = &object {
+                    let children = form.objects();
+
+                    if let Ok(form_matrix) = children.matrix() {
+                        // The form's own matrix is applied first, followed by the matrices
+                        // of any containing form objects.
+
+                        self.stack.push((children, 0, form_matrix.multiply(matrix)));
+                    }
+                }
+
+                return Some((object, matrix));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PdfPageObjectOwnership;
+    use crate::prelude::*;
+    use crate::utils::test::test_bind_to_pdfium;
+
+    /// Adds a new form object containing the contents of the given source page to the
+    /// given destination page.
+    fn add_form_object_from_page<'a>(
+        destination: &PdfDocument<'a>,
+        page: &mut PdfPage<'a>,
+        source: &PdfDocument<'a>,
+        source_page_index: PdfPageIndex,
+    ) -> Result<(), PdfiumError> {
+        let bindings = destination.bindings();
+
+        let xobject = bindings.FPDF_NewXObjectFromPage(
+            destination.handle(),
+            source.handle(),
+            source_page_index as i32,
+        );
+
+        let form_object = bindings.FPDF_NewFormObjectFromXObject(xobject);
+
+        bindings.FPDF_CloseXObject(xobject);
+
+        page.objects_mut().add_object(PdfPageObject::from_pdfium(
+            form_object,
+            PdfPageObjectOwnership::unowned(),
+            bindings,
+        ))?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_x_object_form_object_children() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        // Create a source page containing two text objects, then wrap it inside a
+        // form object on a second page that also contains a text object of its own. Finally,
+        // wrap that second page inside a translated form object on a third page.
+
+        let mut inner = pdfium.create_new_pdf()?;
+
+        let font = inner.fonts_mut().helvetica();
+
+        let mut page = inner
+            .pages_mut()
+            .create_page_at_end(PdfPagePaperSize::a4())?;
+
+        for (index, text) in ["Hello", "World"].iter().enumerate() {
+            page.objects_mut().create_text_object(
+                PdfPoints::new(100.0),
+                PdfPoints::new(100.0 + index as f32 * 50.0),
+                *text,
+                font,
+                PdfPoints::new(12.0),
+            )?;
+        }
+
+        let mut middle = pdfium.create_new_pdf()?;
+
+        let font = middle.fonts_mut().helvetica();
+
+        let mut page = middle
+            .pages_mut()
+            .create_page_at_end(PdfPagePaperSize::a4())?;
+
+        add_form_object_from_page(&middle, &mut page, &inner, 0)?;
+
+        page.objects_mut().create_text_object(
+            PdfPoints::new(300.0),
+            PdfPoints::new(300.0),
+            "Outer",
+            font,
+            PdfPoints::new(12.0),
+        )?;
+
+        let mut outer = pdfium.create_new_pdf()?;
+
+        let mut page = outer
+            .pages_mut()
+            .create_page_at_end(PdfPagePaperSize::a4())?;
+
+        add_form_object_from_page(&outer, &mut page, &middle, 0)?;
+
+        page.objects_mut()
+            .get(0)?
+            .translate(PdfPoints::new(10.0), PdfPoints::new(20.0))?;
+
+        let object = page.objects().get(0)?;
+
+        let form = object.as_x_object_form_object().unwrap();
+
+        let objects = form.objects();
+
+        assert_eq!(objects.len(), 2);
+        assert_eq!(
+            objects
+                .iter()
+                .filter(|object| object.object_type() == PdfPageObjectType::XObjectForm)
+                .count(),
+            1
+        );
+
+        // Descending recursively should find all three text objects, with bounds
+        // reported in page space.
+
+        let mut texts = Vec::new();
+
+        for (object, matrix) in objects.iter_recursive() {
+            if let Some(text) = object.as_text_object() {
+                let bounds = object.bounds()?.transform(matrix).to_rect();
+
+                texts.push((text.text(), bounds.left().value, bounds.bottom().value));
+            }
+        }
+
+        assert_eq!(texts.len(), 3);
+
+        texts.sort_by(|a, b| a.0.cmp(&b.0));
+
+        assert_eq!(texts[0].0, "Hello");
+        assert_eq!(texts[1].0, "Outer");
+        assert_eq!(texts[2].0, "World");
+
+        // Each text object should have been moved by the translation of the outermost form.
+
+        assert!((texts[0].1 - 110.0).abs() < 2.0);
+        assert!((texts[0].2 - 120.0).abs() < 5.0);
+        assert!((texts[1].1 - 310.0).abs() < 2.0);
+        assert!((texts[2].2 - 170.0).abs() < 5.0);
+
+        // The children of a form object cannot be removed.
+
+        let mut object = page.objects().get(0)?;
+
+        let form = object.as_x_object_form_object_mut().unwrap();
+
+        assert!(matches!(
+            form.remove_objects_if(|_| true),
+            Err(PdfiumError::UnsupportedOperation)
+        ));
+        assert_eq!(form.len(), 2);
+
+        // Nor can they be reordered.
+
+        assert!(matches!(
+            form.move_to_front(0),
+            Err(PdfiumError::UnsupportedOperation)
+        ));
+        assert!(matches!(
+            form.move_to_back(1),
+            Err(PdfiumError::UnsupportedOperation)
+        ));
+
+        Ok(())
+    }
 }
@@ -110,8 +110,9 @@ impl<'a> PdfPageObjectsPrivate<'a> for PdfPageObjects<'a> {
             .FPDFPage_GetObject(self.page_handle, index as c_int);
 
         if object_handle.is_null() {
-            Err(PdfiumError::PdfiumLibraryInternalError(
-                PdfiumInternalError::Unknown,
+            Err(self.bindings.error_in(
+                "FPDFPage_GetObject",
+                PdfiumError::PdfiumLibraryInternalError(PdfiumInternalError::Unknown),
             ))
         } else {
             Ok(PdfPageObject::from_pdfium(
@@ -142,4 +143,249 @@ impl<'a> PdfPageObjectsPrivate<'a> for PdfPageObjects<'a> {
     ) -> Result<PdfPageObject<'a>, PdfiumError> {
         object.remove_object_from_page().map(|_| object)
     }
+
+    fn remove_objects_if_impl(
+        &mut self,
+        predicate: &dyn Fn(&PdfPageObject) -> bool,
+    ) -> Result<usize, PdfiumError> {
+        let mut removed = 0;
+
+        // Walk the collection backwards so that removing an object does not shift the
+        // index positions of the objects we have yet to visit.
+
+        for index in (0..self.len()).rev() {
+            let mut object = self.get(index)?;
+
+            if predicate(&object) {
+                if self.bindings.is_true(
+                    self.bindings
+                        .FPDFPage_RemoveObject(self.page_handle, object.object_handle()),
+                ) {
+                    // The removed object is no longer owned by the page, so its memory
+                    // will be released when it is dropped.
+
+                    object.set_ownership(PdfPageObjectOwnership::unowned());
+
+                    removed += 1;
+                } else {
+                    return Err(self.bindings.error_in(
+                        "FPDFPage_RemoveObject",
+                        PdfiumError::PdfiumLibraryInternalError(PdfiumInternalError::Unknown),
+                    ));
+                }
+            }
+        }
+
+        if removed > 0 {
+            self.regenerate_content_after_mutation()?;
+        }
+
+        Ok(removed)
+    }
+
+    fn move_object_impl(
+        &mut self,
+        from: PdfPageObjectIndex,
+        to: PdfPageObjectIndex,
+    ) -> Result<(), PdfiumError> {
+        // Pdfium can only append objects to the end of a page, so we detach every object
+        // from the lower of the two index positions onwards and then re-append them in
+        // their new order. Pdfium does not free the memory of removed objects, and we never
+        // wrap the raw handles in PdfPageObject values here, so the memory ownership of
+        // every object remains with the page throughout.
+
+        let start = from.min(to);
+
+        let mut handles = Vec::with_capacity(self.len() - start);
+
+        for index in start..self.len() {
+            let handle = self
+                .bindings
+                .FPDFPage_GetObject(self.page_handle, index as c_int);
+
+            if handle.is_null() {
+                return Err(self.bindings.error_in(
+                    "FPDFPage_GetObject",
+                    PdfiumError::PdfiumLibraryInternalError(PdfiumInternalError::Unknown),
+                ));
+            }
+
+            handles.push(handle);
+        }
+
+        for handle in handles.iter() {
+            if !self.bindings.is_true(
+                self.bindings
+                    .FPDFPage_RemoveObject(self.page_handle, *handle),
+            ) {
+                return Err(self.bindings.error_in(
+                    "FPDFPage_RemoveObject",
+                    PdfiumError::PdfiumLibraryInternalError(PdfiumInternalError::Unknown),
+                ));
+            }
+        }
+
+        let handle = handles.remove(from - start);
+
+        handles.insert(to - start, handle);
+
+        for handle in handles {
+            self.bindings
+                .FPDFPage_InsertObject(self.page_handle, handle);
+        }
+
+        self.regenerate_content_after_mutation()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::utils::test::test_bind_to_pdfium;
+
+    #[test]
+    fn test_remove_objects_if() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        let mut page = document
+            .pages_mut()
+            .create_page_at_start(PdfPagePaperSize::a4())?;
+
+        let font = document.fonts_mut().helvetica();
+
+        for text in ["Keep me", "DRAFT", "Keep me too", "DRAFT"] {
+            page.objects_mut().create_text_object(
+                PdfPoints::new(100.0),
+                PdfPoints::new(100.0),
+                text,
+                font,
+                PdfPoints::new(12.0),
+            )?;
+        }
+
+        page.objects_mut().create_path_object_rect(
+            PdfRect::new_from_values(100.0, 100.0, 200.0, 200.0),
+            None,
+            None,
+            Some(PdfColor::RED),
+        )?;
+
+        let removed = page.objects_mut().remove_objects_if(|object| {
+            object
+                .as_text_object()
+                .map(|object| object.text() == "DRAFT")
+                .unwrap_or(false)
+        })?;
+
+        assert_eq!(removed, 2);
+        assert_eq!(page.objects().len(), 3);
+        assert!(page
+            .objects()
+            .iter()
+            .filter_map(|object| object.as_text_object().map(|object| object.text()))
+            .all(|text| text.starts_with("Keep me")));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_cursor_remove_during_iteration() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        let mut page = document
+            .pages_mut()
+            .create_page_at_start(PdfPagePaperSize::a4())?;
+
+        let font = document.fonts_mut().helvetica();
+
+        for text in ["first", "second", "third"] {
+            page.objects_mut().create_text_object(
+                PdfPoints::new(100.0),
+                PdfPoints::new(100.0),
+                text,
+                font,
+                PdfPoints::new(12.0),
+            )?;
+        }
+
+        let mut visited = Vec::new();
+
+        let mut cursor = page.objects_mut().cursor();
+
+        while let Some(object) = cursor.next() {
+            let text = object.as_text_object().map(|object| object.text());
+
+            if text.as_deref() == Some("second") {
+                let removed = cursor.remove_at_cursor()?;
+
+                assert_eq!(removed.as_text_object().map(|object| object.text()), text);
+            }
+
+            visited.extend(text);
+        }
+
+        assert_eq!(visited, vec!["first", "second", "third"]);
+        assert_eq!(page.objects().len(), 2);
+        assert_eq!(page.objects().index_of(&page.objects().last()?), Some(1));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_move_to_back_changes_stacking_order() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        let mut page = document
+            .pages_mut()
+            .create_page_at_start(PdfPagePaperSize::a4())?;
+
+        page.objects_mut().create_path_object_rect(
+            PdfRect::new_from_values(100.0, 100.0, 200.0, 200.0),
+            None,
+            None,
+            Some(PdfColor::BLUE),
+        )?;
+
+        page.objects_mut().create_path_object_rect(
+            PdfRect::new_from_values(50.0, 50.0, 250.0, 250.0),
+            None,
+            None,
+            Some(PdfColor::RED),
+        )?;
+
+        let sample = |page: &PdfPage| -> Result<Vec<u8>, PdfiumError> {
+            let bitmap = page.render_with_config(
+                &PdfRenderConfig::new().set_target_width(page.width().value as Pixels),
+            )?;
+
+            let offset =
+                ((page.height().value - 150.0) as usize * bitmap.width() as usize + 150) * 4;
+
+            Ok(bitmap.as_rgba_bytes()[offset..offset + 3].to_vec())
+        };
+
+        assert_eq!(sample(&page)?, vec![255, 0, 0]);
+
+        page.objects_mut().move_to_back(1)?;
+
+        assert_eq!(page.objects().len(), 2);
+        assert_eq!(sample(&page)?, vec![0, 0, 255]);
+
+        page.objects_mut().move_to_front(0)?;
+
+        assert_eq!(sample(&page)?, vec![255, 0, 0]);
+
+        assert!(matches!(
+            page.objects_mut().move_to_index(0, 2),
+            Err(PdfiumError::PageObjectIndexOutOfBounds)
+        ));
+
+        Ok(())
+    }
 }
@@ -6,6 +6,7 @@ use crate::pdf::color::PdfColor;
 use crate::pdf::document::fonts::ToPdfFontToken;
 use crate::pdf::document::page::object::image::PdfPageImageObject;
 use crate::pdf::document::page::object::path::PdfPagePathObject;
+use crate::pdf::document::page::object::private::internal::PdfPageObjectPrivate;
 use crate::pdf::document::page::object::text::PdfPageTextObject;
 use crate::pdf::document::page::object::{PdfPageObject, PdfPageObjectCommon};
 use crate::pdf::document::page::objects::private::internal::PdfPageObjectsPrivate;
@@ -80,9 +81,21 @@ pub trait PdfPageObjectsCommon<'a> {
         }
     }
 
+    /// Returns the index of the given [PdfPageObject] in this page objects collection,
+    /// or `None` if the object is not part of this collection.
+    ///
+    /// Newly created objects are always added to the end of the collection, so the index
+    /// of an object returned by any of the `add_*()` or `create_*()` functions is `len() - 1`
+    /// until another object is added, removed, or moved.
+    fn index_of(&self, object: &PdfPageObject<'a>) -> Option<PdfPageObjectIndex>;
+
     /// Returns an iterator over all the [PdfPageObject] objects in this page objects collection.
     fn iter(&'a self) -> PdfPageObjectsIterator<'a>;
 
+    /// Returns a [PdfPageObjectsCursor] over all the [PdfPageObject] objects in this
+    /// page objects collection, allowing objects to be removed during iteration.
+    fn cursor(&mut self) -> PdfPageObjectsCursor<'_, 'a>;
+
     /// Returns the smallest bounding box that contains all the [PdfPageObject] objects in this
     /// page objects collection.
     fn bounds(&'a self) -> PdfRect {
@@ -350,6 +363,58 @@ pub trait PdfPageObjectsCommon<'a> {
         &mut self,
         index: PdfPageObjectIndex,
     ) -> Result<PdfPageObject<'a>, PdfiumError>;
+
+    /// Removes every [PdfPageObject] in this page objects collection that matches the given
+    /// predicate function, returning the number of page objects removed. The memory owned
+    /// by each removed object will be freed.
+    ///
+    /// The collection is walked from back to front, so removing an object does not affect
+    /// the index positions of any objects still to be tested. The predicate function receives
+    /// a fully typed [PdfPageObject], so functions such as [PdfPageObject::as_text_object()]
+    /// can be used inside the predicate.
+    ///
+    /// If the containing `PdfPage` has a content regeneration strategy of
+    /// `PdfPageContentRegenerationStrategy::AutomaticOnEveryChange` then content regeneration
+    /// will be triggered on the page once, after all matching objects have been removed.
+    fn remove_objects_if<F>(&mut self, predicate: F) -> Result<usize, PdfiumError>
+    where
+        F: Fn(&PdfPageObject) -> bool;
+
+    /// Moves the [PdfPageObject] at the given index to the end of this page objects collection,
+    /// so that it is drawn after, and therefore in front of, all other objects.
+    ///
+    /// If the containing `PdfPage` has a content regeneration strategy of
+    /// `PdfPageContentRegenerationStrategy::AutomaticOnEveryChange` then content regeneration
+    /// will be triggered on the page.
+    fn move_to_front(&mut self, index: PdfPageObjectIndex) -> Result<(), PdfiumError>;
+
+    /// Moves the [PdfPageObject] at the given index to the start of this page objects collection,
+    /// so that it is drawn before, and therefore behind, all other objects.
+    ///
+    /// If the containing `PdfPage` has a content regeneration strategy of
+    /// `PdfPageContentRegenerationStrategy::AutomaticOnEveryChange` then content regeneration
+    /// will be triggered on the page.
+    fn move_to_back(&mut self, index: PdfPageObjectIndex) -> Result<(), PdfiumError>;
+
+    /// Moves the [PdfPageObject] at the given `from` index so that it is positioned at
+    /// the given `to` index, shifting the objects in between by one position.
+    /// Objects later in the collection are drawn in front of objects earlier in the collection.
+    ///
+    /// Any existing [PdfPageObject] values retrieved from this collection remain valid,
+    /// although their index positions may change.
+    ///
+    /// Pdfium offers no way to detach an object from an annotation without destroying it,
+    /// so objects inside a `PdfPageAnnotation` cannot be reordered; attempting to do so returns
+    /// [PdfiumError::AnnotationObjectsCannotBeReordered].
+    ///
+    /// If the containing `PdfPage` has a content regeneration strategy of
+    /// `PdfPageContentRegenerationStrategy::AutomaticOnEveryChange` then content regeneration
+    /// will be triggered on the page.
+    fn move_to_index(
+        &mut self,
+        from: PdfPageObjectIndex,
+        to: PdfPageObjectIndex,
+    ) -> Result<(), PdfiumError>;
 }
 
 // Blanket implementation for all PdfPageObjects collection types.
@@ -368,11 +433,24 @@ where
         self.get_impl(index)
     }
 
+    fn index_of(&self, object: &PdfPageObject<'a>) -> Option<PdfPageObjectIndex> {
+        (0..self.len()).find(|index| {
+            self.get(*index)
+                .map(|candidate| candidate.object_handle() == object.object_handle())
+                .unwrap_or(false)
+        })
+    }
+
     #[inline]
     fn iter(&'a self) -> PdfPageObjectsIterator<'a> {
         self.iter_impl()
     }
 
+    #[inline]
+    fn cursor(&mut self) -> PdfPageObjectsCursor<'_, 'a> {
+        PdfPageObjectsCursor::new(self)
+    }
+
     #[inline]
     fn add_object(&mut self, object: PdfPageObject<'a>) -> Result<PdfPageObject<'a>, PdfiumError> {
         self.add_object_impl(object)
@@ -658,6 +736,40 @@ where
             ))
         }
     }
+
+    #[inline]
+    fn remove_objects_if<F>(&mut self, predicate: F) -> Result<usize, PdfiumError>
+    where
+        F: Fn(&PdfPageObject) -> bool,
+    {
+        self.remove_objects_if_impl(&predicate)
+    }
+
+    #[inline]
+    fn move_to_front(&mut self, index: PdfPageObjectIndex) -> Result<(), PdfiumError> {
+        self.move_to_index(index, self.len().saturating_sub(1))
+    }
+
+    #[inline]
+    fn move_to_back(&mut self, index: PdfPageObjectIndex) -> Result<(), PdfiumError> {
+        self.move_to_index(index, 0)
+    }
+
+    fn move_to_index(
+        &mut self,
+        from: PdfPageObjectIndex,
+        to: PdfPageObjectIndex,
+    ) -> Result<(), PdfiumError> {
+        if from >= self.len() || to >= self.len() {
+            return Err(PdfiumError::PageObjectIndexOutOfBounds);
+        }
+
+        if from == to {
+            return Ok(());
+        }
+
+        self.move_object_impl(from, to)
+    }
 }
 
 /// An iterator over all the [PdfPageObject] objects in a page objects collection.
@@ -687,3 +799,84 @@ impl<'a> Iterator for PdfPageObjectsIterator<'a> {
         next.ok()
     }
 }
+
+/// A cursor over all the [PdfPageObject] objects in a page objects collection that allows
+/// objects to be removed during iteration.
+///
+/// Objects are retrieved from Pdfium by index as the cursor advances, so objects added while
+/// the cursor is in use are visited once the cursor reaches the end of the original collection.
+/// Calling [PdfPageObjectsCursor::remove_at_cursor()] removes the object most recently returned
+/// by the cursor and adjusts the cursor's position so that every remaining object is still
+/// visited exactly once.
+///
+/// The cursor holds a mutable borrow of its page objects collection, so no other iterator over
+/// the collection can be used at the same time. A [PdfPageObject] returned by the cursor must
+/// not be used after it has been removed; use the object returned by
+/// [PdfPageObjectsCursor::remove_at_cursor()] instead.
+pub struct PdfPageObjectsCursor<'b, 'a> {
+    objects: &'b mut dyn PdfPageObjectsPrivate<'a>,
+    current_index: Option<PdfPageObjectIndex>,
+    next_index: PdfPageObjectIndex,
+}
+
+impl<'b, 'a> PdfPageObjectsCursor<'b, 'a> {
+    #[inline]
+    pub(crate) fn new(objects: &'b mut dyn PdfPageObjectsPrivate<'a>) -> Self {
+        PdfPageObjectsCursor {
+            objects,
+            current_index: None,
+            next_index: 0,
+        }
+    }
+
+    /// Returns the index of the object most recently returned by this cursor,
+    /// or `None` if the cursor has not yet returned an object or the object
+    /// has been removed.
+    #[inline]
+    pub fn index(&self) -> Option<PdfPageObjectIndex> {
+        self.current_index
+    }
+
+    /// Removes the object most recently returned by this cursor from the containing page
+    /// objects collection, returning the removed object. The object's memory ownership
+    /// will be removed from the containing `PdfPage`; it can be added back into a page objects
+    /// collection or discarded, at which point the memory owned by the object will be freed.
+    /// The next object returned by the cursor will be the object that followed the removed one.
+    ///
+    /// Returns [PdfiumError::CursorNotPositionedOnItem] if the cursor has not yet returned
+    /// an object, or if the object has already been removed.
+    ///
+    /// If the containing `PdfPage` has a content regeneration strategy of
+    /// `PdfPageContentRegenerationStrategy::AutomaticOnEveryChange` then content regeneration
+    /// will be triggered on the page.
+    pub fn remove_at_cursor(&mut self) -> Result<PdfPageObject<'a>, PdfiumError> {
+        let index = self
+            .current_index
+            .ok_or(PdfiumError::CursorNotPositionedOnItem)?;
+
+        let object = self.objects.get_impl(index)?;
+
+        let object = self.objects.remove_object_impl(object)?;
+
+        self.current_index = None;
+        self.next_index = index;
+
+        Ok(object)
+    }
+}
+
+impl<'b, 'a> Iterator for PdfPageObjectsCursor<'b, 'a> {
+    type Item = PdfPageObject<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let next = self.objects.get_impl(self.next_index).ok();
+
+        self.current_index = next.as_ref().map(|_| self.next_index);
+
+        if next.is_some() {
+            self.next_index += 1;
+        }
+
+        next
+    }
+}
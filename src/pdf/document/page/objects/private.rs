@@ -11,7 +11,7 @@ pub(crate) mod internal {
     use crate::error::PdfiumError;
     use crate::pdf::document::page::object::PdfPageObject;
     use crate::pdf::document::page::objects::common::{PdfPageObjectIndex, PdfPageObjectsIterator};
-    use crate::pdf::document::page::PdfPageObjectOwnership;
+    use crate::pdf::document::page::{PdfPage, PdfPageObjectOwnership};
 
     /// Internal crate-specific functionality common to all [PdfPageObjects] collections.
     pub(crate) trait PdfPageObjectsPrivate<'a> {
@@ -41,5 +41,27 @@ pub(crate) mod internal {
             &mut self,
             object: PdfPageObject<'a>,
         ) -> Result<PdfPageObject<'a>, PdfiumError>;
+
+        /// Internal implementation of [PdfPageObjectsCommon::remove_objects_if()].
+        fn remove_objects_if_impl(
+            &mut self,
+            predicate: &dyn Fn(&PdfPageObject) -> bool,
+        ) -> Result<usize, PdfiumError>;
+
+        /// Internal implementation of [PdfPageObjectsCommon::move_to_index()].
+        /// The given indices have already been validated.
+        fn move_object_impl(
+            &mut self,
+            from: PdfPageObjectIndex,
+            to: PdfPageObjectIndex,
+        ) -> Result<(), PdfiumError>;
+
+        /// Regenerate the containing page's content stream to reflect a change to the objects
+        /// within this page objects collection. The page's content regeneration strategy is
+        /// taken into account.
+        #[inline]
+        fn regenerate_content_after_mutation(&self) -> Result<(), PdfiumError> {
+            PdfPage::regenerate_content_for_ownership(self.ownership(), self.bindings())
+        }
     }
 }
@@ -0,0 +1,339 @@
+//! Defines the [PdfPageOverlayPosition] enum, controlling whether the content of one `PdfPage`
+//! is stamped in front of or behind the existing content of another `PdfPage`.
+
+use crate::bindgen::{FPDF_DOCUMENT, FPDF_XOBJECT};
+use crate::bindings::PdfiumLibraryBindings;
+use crate::error::{PdfiumError, PdfiumInternalError};
+use crate::pdf::document::page::object::ownership::PdfPageObjectOwnership;
+use crate::pdf::document::page::object::PdfPageObject;
+use crate::pdf::document::page::objects::common::PdfPageObjectsCommon;
+use crate::pdf::document::page::PdfPage;
+use crate::pdf::document::pages::PdfPageIndex;
+use crate::pdf::matrix::PdfMatrix;
+use std::os::raw::c_int;
+
+#[cfg(doc)]
+use crate::pdf::document::PdfDocument;
+
+/// Controls whether the content of a source page is drawn in front of or behind the
+/// existing content of a target [PdfPage] when using the [PdfPage::apply_overlay()] function.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum PdfPageOverlayPosition {
+    /// The source page is drawn in front of all existing content on the target page.
+    Overlay,
+
+    /// The source page is drawn behind all existing content on the target page,
+    /// in the manner of a letterhead or stationery background.
+    Underlay,
+}
+
+/// A form XObject holding the content of a single source page, from which any number
+/// of form objects can be stamped onto the pages of a destination document.
+///
+/// Pdfium copies the source page together with all the fonts, images, and other resources
+/// it uses into the destination document when the XObject is created, so the source page
+/// can be stamped onto many pages while its resources are only copied once.
+pub(crate) struct PdfPageOverlay<'a> {
+    xobject: FPDF_XOBJECT,
+    bindings: &'a dyn PdfiumLibraryBindings,
+}
+
+impl<'a> PdfPageOverlay<'a> {
+    /// Creates a new [PdfPageOverlay] in the given destination document from the page
+    /// at the given index in the given source document.
+    pub(crate) fn new(
+        destination: FPDF_DOCUMENT,
+        source: FPDF_DOCUMENT,
+        source_page_index: PdfPageIndex,
+        bindings: &'a dyn PdfiumLibraryBindings,
+    ) -> Result<Self, PdfiumError> {
+        let xobject =
+            bindings.FPDF_NewXObjectFromPage(destination, source, source_page_index as c_int);
+
+        if xobject.is_null() {
+            Err(bindings.error_in(
+                "FPDF_NewXObjectFromPage",
+                PdfiumError::PdfiumLibraryInternalError(PdfiumInternalError::Unknown),
+            ))
+        } else {
+            Ok(PdfPageOverlay { xobject, bindings })
+        }
+    }
+
+    /// Creates a new form object painting the source page, not yet attached to any page.
+    pub(crate) fn create_object(&self) -> Result<PdfPageObject<'a>, PdfiumError> {
+        let object_handle = self.bindings.FPDF_NewFormObjectFromXObject(self.xobject);
+
+        if object_handle.is_null() {
+            Err(self.bindings.error_in(
+                "FPDF_NewFormObjectFromXObject",
+                PdfiumError::PdfiumLibraryInternalError(PdfiumInternalError::Unknown),
+            ))
+        } else {
+            Ok(PdfPageObject::from_pdfium(
+                object_handle,
+                PdfPageObjectOwnership::unowned(),
+                self.bindings,
+            ))
+        }
+    }
+
+    /// Stamps the source page onto the given [PdfPage] at the given position, applying
+    /// the given transformation matrix, if any, returning the newly created form object.
+    pub(crate) fn apply(
+        &self,
+        page: &mut PdfPage<'a>,
+        position: PdfPageOverlayPosition,
+        transform: Option<PdfMatrix>,
+    ) -> Result<PdfPageObject<'a>, PdfiumError> {
+        let mut object = self.create_object()?;
+
+        if let Some(transform) = transform {
+            object.apply_matrix(transform)?;
+        }
+
+        let object = page.objects_mut().add_object(object)?;
+
+        if position == PdfPageOverlayPosition::Underlay {
+            // The new form object was added in front of all existing page objects.
+            // Move it behind them.
+
+            let index = page.objects().len() - 1;
+
+            page.objects_mut().move_to_back(index)?;
+        }
+
+        Ok(object)
+    }
+}
+
+impl<'a> Drop for PdfPageOverlay<'a> {
+    /// Closes this [PdfPageOverlay], releasing held memory. Form objects already created
+    /// from this [PdfPageOverlay] are not affected.
+    #[inline]
+    fn drop(&mut self) {
+        self.bindings.FPDF_CloseXObject(self.xobject);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::utils::test::test_bind_to_pdfium;
+
+    /// Renders the given US Letter page at one pixel per point, returning the rendered
+    /// RGBA pixel data.
+    fn render(page: &PdfPage) -> Result<Vec<u8>, PdfiumError> {
+        let bitmap = page.render_with_config(&PdfRenderConfig::new().set_target_width(612))?;
+
+        assert_eq!(bitmap.height(), 792);
+
+        Ok(bitmap.as_rgba_bytes())
+    }
+
+    /// Returns `true` if the given RGBA pixel is white.
+    fn is_white(pixel: &[u8]) -> bool {
+        pixel[0..3] == [255, 255, 255]
+    }
+
+    /// Creates a new document containing a single US Letter page with a filled rectangle
+    /// of the given color.
+    fn create_document_with_rect<'a>(
+        pdfium: &'a Pdfium,
+        rect: PdfRect,
+        color: PdfColor,
+    ) -> Result<PdfDocument<'a>, PdfiumError> {
+        let mut document = pdfium.create_new_pdf()?;
+
+        document
+            .pages_mut()
+            .create_page_at_start(PdfPagePaperSize::from_inches(8.5, 11.0))?
+            .objects_mut()
+            .create_path_object_rect(rect, None, None, Some(color))?;
+
+        Ok(document)
+    }
+
+    /// Composites the given RGBA renders, drawing the non-white pixels of the front render
+    /// over the back render.
+    fn composite(back: &[u8], front: &[u8]) -> Vec<u8> {
+        back.chunks_exact(4)
+            .zip(front.chunks_exact(4))
+            .flat_map(|(back, front)| {
+                if is_white(front) {
+                    back.to_vec()
+                } else {
+                    front.to_vec()
+                }
+            })
+            .collect()
+    }
+
+    /// Returns the number of pixels that differ between the given RGBA renders.
+    fn count_differences(a: &[u8], b: &[u8]) -> usize {
+        a.chunks_exact(4)
+            .zip(b.chunks_exact(4))
+            .filter(|(a, b)| {
+                a.iter()
+                    .zip(b.iter())
+                    .any(|(a, b)| (*a as i16 - *b as i16).abs() > 2)
+            })
+            .count()
+    }
+
+    #[test]
+    fn test_apply_overlay_and_underlay() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let template = create_document_with_rect(
+            &pdfium,
+            PdfRect::new_from_values(100.0, 100.0, 400.0, 300.0),
+            PdfColor::RED,
+        )?;
+
+        let template_page = template.pages().first()?;
+
+        let template_render = render(&template_page)?;
+
+        for position in [
+            PdfPageOverlayPosition::Underlay,
+            PdfPageOverlayPosition::Overlay,
+        ] {
+            let content = create_document_with_rect(
+                &pdfium,
+                PdfRect::new_from_values(200.0, 200.0, 500.0, 400.0),
+                PdfColor::BLUE,
+            )?;
+
+            let mut page = content.pages().first()?;
+
+            let content_render = render(&page)?;
+
+            let object = page.apply_overlay(&template_page, position, None)?;
+
+            assert_eq!(object.object_type(), PdfPageObjectType::XObjectForm);
+            assert_eq!(page.objects().len(), 2);
+
+            let expected = match position {
+                PdfPageOverlayPosition::Underlay => {
+                    assert_eq!(
+                        page.objects().get(0)?.object_type(),
+                        PdfPageObjectType::XObjectForm
+                    );
+
+                    composite(&template_render, &content_render)
+                }
+                PdfPageOverlayPosition::Overlay => {
+                    assert_eq!(
+                        page.objects().get(1)?.object_type(),
+                        PdfPageObjectType::XObjectForm
+                    );
+
+                    composite(&content_render, &template_render)
+                }
+            };
+
+            let actual = render(&page)?;
+
+            assert_eq!(actual.len(), expected.len());
+            assert_eq!(count_differences(&actual, &expected), 0);
+
+            // The overlaid page survives a save and reload.
+
+            let bytes = content.save_to_bytes()?;
+
+            let reloaded = pdfium.load_pdf_from_byte_vec(bytes, None)?;
+
+            let reloaded_render = render(&reloaded.pages().first()?)?;
+
+            assert_eq!(count_differences(&reloaded_render, &expected), 0);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_apply_overlay_with_transform() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let template = create_document_with_rect(
+            &pdfium,
+            PdfRect::new_from_values(0.0, 0.0, 100.0, 100.0),
+            PdfColor::RED,
+        )?;
+
+        let mut content = pdfium.create_new_pdf()?;
+
+        let mut page = content
+            .pages_mut()
+            .create_page_at_start(PdfPagePaperSize::from_inches(8.5, 11.0))?;
+
+        page.apply_overlay(
+            &template.pages().first()?,
+            PdfPageOverlayPosition::Overlay,
+            Some(PdfMatrix::IDENTITY.translate(PdfPoints::new(200.0), PdfPoints::new(300.0))?),
+        )?;
+
+        let bytes = render(&page)?;
+
+        let pixel_at = |x: usize, y: usize| {
+            let offset = ((792 - y) * 612 + x) * 4;
+
+            [bytes[offset], bytes[offset + 1], bytes[offset + 2]]
+        };
+
+        // The rectangle has moved from the bottom left corner of the page.
+
+        assert_eq!(pixel_at(50, 50), [255, 255, 255]);
+        assert_eq!(pixel_at(250, 350), [255, 0, 0]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_apply_letterhead() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let template = create_document_with_rect(
+            &pdfium,
+            PdfRect::new_from_values(700.0, 50.0, 750.0, 250.0),
+            PdfColor::RED,
+        )?;
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        for _ in 0..3 {
+            document
+                .pages_mut()
+                .create_page_at_end(PdfPagePaperSize::from_inches(8.5, 11.0))?
+                .objects_mut()
+                .create_path_object_rect(
+                    PdfRect::new_from_values(100.0, 100.0, 600.0, 500.0),
+                    None,
+                    None,
+                    Some(PdfColor::BLUE),
+                )?;
+        }
+
+        document.apply_letterhead(&template, 0)?;
+
+        for page in document.pages().iter() {
+            assert_eq!(page.objects().len(), 2);
+            assert_eq!(
+                page.objects().get(0)?.object_type(),
+                PdfPageObjectType::XObjectForm
+            );
+            assert_eq!(
+                page.objects().get(1)?.object_type(),
+                PdfPageObjectType::Path
+            );
+        }
+
+        assert!(matches!(
+            document.apply_letterhead(&template, 1),
+            Err(PdfiumError::PageIndexOutOfBounds)
+        ));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,78 @@
+//! Defines the [PdfPageContentRegenerationGuard] struct, deferring content regeneration
+//! of a single [PdfPage] until a batch of edits is complete.
+
+use crate::error::PdfiumError;
+use crate::pdf::document::page::PdfPage;
+use std::ops::{Deref, DerefMut};
+
+#[cfg(doc)]
+use crate::pdf::document::page::PdfPageContentRegenerationStrategy;
+
+/// A guard that suppresses content regeneration for a single [PdfPage] for as long as
+/// the guard is in scope.
+///
+/// While the guard is held, edits to the page and to its annotations and page objects
+/// do not trigger content regeneration, irrespective of the page's content regeneration
+/// strategy. When the guard is committed or dropped, the page's content is regenerated
+/// exactly once if the page's content regeneration strategy is
+/// [PdfPageContentRegenerationStrategy::AutomaticOnEveryChange].
+///
+/// The guard dereferences to the [PdfPage] it was created from, so edits can be made
+/// through the guard itself. Guards can be nested; content regeneration only takes place
+/// when the outermost guard is committed or dropped.
+///
+/// Create a guard by calling the [PdfPage::defer_content_regeneration()] function.
+pub struct PdfPageContentRegenerationGuard<'b, 'a> {
+    page: &'b mut PdfPage<'a>,
+    is_committed: bool,
+}
+
+impl<'b, 'a> PdfPageContentRegenerationGuard<'b, 'a> {
+    #[inline]
+    pub(crate) fn new(page: &'b mut PdfPage<'a>) -> Self {
+        page.begin_deferred_content_regeneration();
+
+        PdfPageContentRegenerationGuard {
+            page,
+            is_committed: false,
+        }
+    }
+
+    /// Ends this batch of edits, regenerating the page's content if necessary.
+    ///
+    /// Dropping the guard has the same effect, but any error raised during content regeneration
+    /// is then ignored. Call this function instead to be notified of such errors.
+    pub fn commit(mut self) -> Result<(), PdfiumError> {
+        self.is_committed = true;
+
+        self.page.end_deferred_content_regeneration()
+    }
+}
+
+impl<'b, 'a> Deref for PdfPageContentRegenerationGuard<'b, 'a> {
+    type Target = PdfPage<'a>;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        self.page
+    }
+}
+
+impl<'b, 'a> DerefMut for PdfPageContentRegenerationGuard<'b, 'a> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.page
+    }
+}
+
+impl<'b, 'a> Drop for PdfPageContentRegenerationGuard<'b, 'a> {
+    /// Ends this batch of edits, regenerating the page's content if necessary.
+    #[inline]
+    fn drop(&mut self) {
+        if !self.is_committed {
+            let result = self.page.end_deferred_content_regeneration();
+
+            debug_assert!(result.is_ok());
+        }
+    }
+}
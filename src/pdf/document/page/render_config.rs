@@ -2,10 +2,10 @@
 //! the rendering of [PdfBitmap] objects from one or more [PdfPage] objects.
 
 use crate::bindgen::{
-    FPDF_ANNOT, FPDF_CONVERT_FILL_TO_STROKE, FPDF_DWORD, FPDF_GRAYSCALE, FPDF_LCD_TEXT,
-    FPDF_NO_NATIVETEXT, FPDF_PRINTING, FPDF_RENDER_FORCEHALFTONE, FPDF_RENDER_LIMITEDIMAGECACHE,
-    FPDF_RENDER_NO_SMOOTHIMAGE, FPDF_RENDER_NO_SMOOTHPATH, FPDF_RENDER_NO_SMOOTHTEXT,
-    FPDF_REVERSE_BYTE_ORDER, FS_MATRIX, FS_RECTF,
+    FPDF_ANNOT, FPDF_COLORSCHEME, FPDF_CONVERT_FILL_TO_STROKE, FPDF_DWORD, FPDF_GRAYSCALE,
+    FPDF_LCD_TEXT, FPDF_NO_NATIVETEXT, FPDF_PRINTING, FPDF_RENDER_FORCEHALFTONE,
+    FPDF_RENDER_LIMITEDIMAGECACHE, FPDF_RENDER_NO_SMOOTHIMAGE, FPDF_RENDER_NO_SMOOTHPATH,
+    FPDF_RENDER_NO_SMOOTHTEXT, FPDF_REVERSE_BYTE_ORDER, FS_MATRIX, FS_RECTF,
 };
 use crate::create_transform_setters;
 use crate::error::PdfiumError;
@@ -19,7 +19,7 @@ use crate::pdf::points::PdfPoints;
 use std::os::raw::c_int;
 
 #[cfg(doc)]
-use crate::pdf::bitmap::PdfBitmap;
+use {crate::pdf::bitmap::PdfBitmap, crate::pdf::document::PdfDocument};
 
 // TODO: AJRC - 29/7/22 - remove deprecated PdfBitmapConfig struct in 0.9.0 as part of tracking issue
 // https://github.com/ajrcarey/pdfium-render/issues/36
@@ -56,6 +56,56 @@ impl PdfBitmapConfig {
     }
 }
 
+/// A set of replacement colors that Pdfium should use in place of the colors specified
+/// by a page's content when rendering that page, for example to implement a forced
+/// dark mode. A color scheme can be applied during rendering by calling the
+/// [PdfRenderConfig::set_color_scheme()] function.
+///
+/// Pdfium remaps the fill and stroke colors of path objects and text objects only.
+/// Image objects are always rendered with their original colors.
+#[derive(Debug, Copy, Clone)]
+pub struct PdfRenderColorScheme {
+    /// The color used to fill all path objects.
+    pub path_fill_color: PdfColor,
+
+    /// The color used to stroke all path objects.
+    pub path_stroke_color: PdfColor,
+
+    /// The color used to fill all text objects.
+    pub text_fill_color: PdfColor,
+
+    /// The color used to stroke all text objects.
+    pub text_stroke_color: PdfColor,
+}
+
+impl PdfRenderColorScheme {
+    /// Creates a new [PdfRenderColorScheme] from the given replacement colors.
+    #[inline]
+    pub fn new(
+        path_fill_color: PdfColor,
+        path_stroke_color: PdfColor,
+        text_fill_color: PdfColor,
+        text_stroke_color: PdfColor,
+    ) -> Self {
+        PdfRenderColorScheme {
+            path_fill_color,
+            path_stroke_color,
+            text_fill_color,
+            text_stroke_color,
+        }
+    }
+
+    #[inline]
+    pub(crate) fn as_pdfium(&self) -> FPDF_COLORSCHEME {
+        FPDF_COLORSCHEME {
+            path_fill_color: self.path_fill_color.as_pdfium_argb_color(),
+            path_stroke_color: self.path_stroke_color.as_pdfium_argb_color(),
+            text_fill_color: self.text_fill_color.as_pdfium_argb_color(),
+            text_stroke_color: self.text_stroke_color.as_pdfium_argb_color(),
+        }
+    }
+}
+
 /// Configures the scaling, rotation, and rendering settings that should be applied to
 /// a [PdfPage] to create a [PdfBitmap] for that page. [PdfRenderConfig] can accommodate pages of
 /// different sizes while correctly maintaining each page's aspect ratio, automatically
@@ -69,6 +119,7 @@ impl PdfBitmapConfig {
 /// automatically disables rendering of form data. If you must render form data while simultaneously
 /// applying transformations, consider using the [PdfPage::flatten()] function to flatten the
 /// form elements and form data into the containing page.
+#[derive(Clone)]
 pub struct PdfRenderConfig {
     target_width: Option<Pixels>,
     target_height: Option<Pixels>,
@@ -80,13 +131,18 @@ pub struct PdfRenderConfig {
     portrait_rotation_do_rotate_constraints: bool,
     landscape_rotation: PdfPageRenderRotation,
     landscape_rotation_do_rotate_constraints: bool,
+    do_apply_page_rotation: bool,
     format: PdfBitmapFormat,
     do_clear_bitmap_before_rendering: bool,
     clear_color: PdfColor,
+    do_use_transparent_background: bool,
+    color_scheme: Option<PdfRenderColorScheme>,
     do_render_form_data: bool,
     form_field_highlight: Option<Vec<(PdfFormFieldType, PdfColor)>>,
     transformation_matrix: PdfMatrix,
     clip_rect: Option<(Pixels, Pixels, Pixels, Pixels)>,
+    sub_region: Option<(Pixels, Pixels, Pixels, Pixels)>,
+    layer_visibility: Vec<(String, bool)>,
 
     // The fields below set Pdfium's page rendering flags. Coverage for the
     // FPDF_DEBUG_INFO and FPDF_NO_CATCH flags is omitted since they are obsolete.
@@ -118,13 +174,18 @@ impl PdfRenderConfig {
             portrait_rotation_do_rotate_constraints: false,
             landscape_rotation: PdfPageRenderRotation::None,
             landscape_rotation_do_rotate_constraints: false,
+            do_apply_page_rotation: true,
             format: PdfBitmapFormat::default(),
             do_clear_bitmap_before_rendering: true,
             clear_color: PdfColor::WHITE,
+            do_use_transparent_background: false,
+            color_scheme: None,
             do_render_form_data: true,
             form_field_highlight: None,
             transformation_matrix: PdfMatrix::IDENTITY,
             clip_rect: None,
+            sub_region: None,
+            layer_visibility: Vec::new(),
             do_set_flag_render_annotations: true,
             do_set_flag_use_lcd_text_rendering: false,
             do_set_flag_no_native_text: false,
@@ -350,6 +411,22 @@ impl PdfRenderConfig {
         self
     }
 
+    /// Controls whether the intrinsic rotation of the [PdfPage], as returned by
+    /// [PdfPage::rotation()], should be applied during rendering. The default is `true`,
+    /// so the page is always rendered upright, as it would be displayed by a PDF viewer;
+    /// the pixel width and height of the rendered bitmap are swapped for pages with an
+    /// intrinsic rotation of 90 or 270 degrees.
+    ///
+    /// If set to `false`, the page is rendered as if it had no intrinsic rotation. Any rotation
+    /// set by [PdfRenderConfig::rotate()], [PdfRenderConfig::rotate_if_portrait()], or
+    /// [PdfRenderConfig::rotate_if_landscape()] is still applied, relative to the unrotated page.
+    #[inline]
+    pub fn apply_page_rotation(mut self, do_apply: bool) -> Self {
+        self.do_apply_page_rotation = do_apply;
+
+        self
+    }
+
     /// Sets the pixel format that will be used during rendering of the [PdfPage].
     /// The default is [PdfBitmapFormat::BGRA].
     #[inline]
@@ -379,6 +456,72 @@ impl PdfRenderConfig {
         self
     }
 
+    /// Renders the [PdfPage] onto a fully transparent background, rather than onto a
+    /// solid background color. Any area of the page not covered by page content will
+    /// have an alpha value of zero in the destination bitmap.
+    ///
+    /// This sets the pixel format to [PdfBitmapFormat::BGRA] and clears the destination bitmap
+    /// to a fully transparent color before rendering. Since only [PdfBitmapFormat::BGRA]
+    /// bitmaps carry an alpha channel, rendering will fail with
+    /// [PdfiumError::TransparentBackgroundRequiresAlphaChannel] if the pixel format is later
+    /// changed by calling [PdfRenderConfig::set_format()], or if a bitmap with a different
+    /// pixel format is passed to [PdfPage::render_into_bitmap_with_config()].
+    #[inline]
+    pub fn use_transparent_background(mut self) -> Self {
+        self.format = PdfBitmapFormat::BGRA;
+        self.do_clear_bitmap_before_rendering = true;
+        self.clear_color = PdfColor::new(0, 0, 0, 0);
+        self.do_use_transparent_background = true;
+
+        self
+    }
+
+    /// Sets a [PdfRenderColorScheme] that Pdfium should use to replace the fill and stroke
+    /// colors of path and text objects during rendering of the [PdfPage]. The default is
+    /// no color scheme, in which case all objects are rendered using their original colors.
+    ///
+    /// Image objects are not affected by the color scheme. Pdfium's color scheme rendering
+    /// does not support custom transformation matrices, clipping, or sub-regions; rendering
+    /// will fail with [PdfiumError::RenderColorSchemeDoesNotSupportTransformations] if both
+    /// a color scheme and any of these are set while rendering of form data is disabled.
+    #[inline]
+    pub fn set_color_scheme(mut self, color_scheme: PdfRenderColorScheme) -> Self {
+        self.color_scheme = Some(color_scheme);
+
+        self
+    }
+
+    /// Overrides the default visibility of the given named layers (optional content groups)
+    /// during rendering of the [PdfPage], replacing any overrides set previously. The default
+    /// is no overrides, in which case each layer is shown or hidden according to the document's
+    /// default optional content configuration. The layers in a document can be listed using
+    /// the [PdfDocument::layers()] function.
+    ///
+    /// Pdfium offers no API for changing the visibility of a layer, so layers are hidden by
+    /// temporarily deactivating every page object that is marked as belonging to a hidden layer,
+    /// then reactivating those objects once rendering is complete. As a consequence:
+    /// * Layers that are hidden by default cannot be made visible; overriding a layer's
+    ///   visibility to `true` simply cancels any earlier override hiding that layer.
+    /// * Only page content is affected. Annotations and form fields, and form XObjects
+    ///   whose `/OC` entry is set in the XObject's own dictionary, are not hidden.
+    /// * Rendering fails with [PdfiumError::LayerMembershipNotSupported] if an override
+    ///   hides at least one layer and the page contains content whose visibility is determined
+    ///   by an optional content membership dictionary rather than by a single layer.
+    /// * Rendering fails with [PdfiumError::UnsupportedOperation] if an override hides at least
+    ///   one layer and the bound Pdfium library does not support deactivating page objects.
+    /// * Overrides are ignored by [PdfPage::render_progressively()].
+    pub fn set_layer_visibility<N: ToString>(
+        mut self,
+        overrides: impl IntoIterator<Item = (N, bool)>,
+    ) -> Self {
+        self.layer_visibility = overrides
+            .into_iter()
+            .map(|(name, is_visible)| (name.to_string(), is_visible))
+            .collect();
+
+        self
+    }
+
     /// Controls whether form data widgets and user-supplied form data should be included
     /// during rendering of the [PdfPage]. The default is `true`.
     ///
@@ -652,13 +795,149 @@ impl PdfRenderConfig {
         self
     }
 
+    /// Restricts rendering output to the rectangular sub-region of the rendered page with the
+    /// given top-left pixel coordinates and pixel dimensions. The rendered [PdfBitmap] will have
+    /// the dimensions of the sub-region, rather than the dimensions of the whole page.
+    ///
+    /// This is useful for rendering a single tile of a page displayed at high zoom, or for
+    /// re-rendering only the small portion of a page affected by an edit. The pixel coordinates
+    /// are relative to the whole page as it would be rendered by this [PdfRenderConfig],
+    /// after all scaling and rotation settings have been applied. Any sub-region that extends
+    /// past the edges of the rendered page is clamped to the page.
+    ///
+    /// If a clipping rectangle has also been set using [PdfRenderConfig::clip()], then its pixel
+    /// coordinates are likewise relative to the whole page.
+    ///
+    /// Pdfium's rendering pipeline supports _either_ rendering with form data _or_ rendering a
+    /// sub-region of a page, but not both at the same time. Setting a sub-region automatically
+    /// disables rendering of form data. If you must render form data while simultaneously
+    /// rendering a sub-region, consider using the [PdfPage::flatten()] function to flatten the
+    /// form elements and form data into the containing page.
+    #[inline]
+    pub fn render_sub_region(
+        mut self,
+        left: Pixels,
+        top: Pixels,
+        width: Pixels,
+        height: Pixels,
+    ) -> Self {
+        self.sub_region = Some((left, top, width, height));
+        self.do_render_form_data = false;
+
+        self
+    }
+
+    /// Converts the given position in a bitmap rendered from the given [PdfPage] using this
+    /// [PdfRenderConfig], measured in (possibly fractional) pixels from the top-left corner
+    /// of the bitmap, to the equivalent position on the page, measured in [PdfPoints] from
+    /// the bottom-left corner of the page.
+    ///
+    /// The conversion accounts for all scaling, rotation, transformation, and sub-region settings
+    /// in this [PdfRenderConfig], as well as any intrinsic rotation of the page itself,
+    /// and for the fact that the y axis of a bitmap points downwards while the y axis of a page
+    /// points upwards. This is the inverse of [PdfRenderConfig::page_to_pixel()].
+    pub fn pixel_to_page(
+        &self,
+        page: &PdfPage,
+        x: f32,
+        y: f32,
+    ) -> Result<(PdfPoints, PdfPoints), PdfiumError> {
+        Ok(self
+            .page_to_pixel_matrix(page)?
+            .invert()
+            .ok_or(PdfiumError::CoordinateConversionFunctionIndicatedError)?
+            .transform_point(PdfPoints::new(x), PdfPoints::new(y)))
+    }
+
+    /// Converts the given position on the given [PdfPage], measured in [PdfPoints] from the
+    /// bottom-left corner of the page, to the equivalent position in a bitmap rendered from
+    /// the page using this [PdfRenderConfig], measured in (possibly fractional) pixels from
+    /// the top-left corner of the bitmap.
+    ///
+    /// The conversion accounts for all scaling, rotation, transformation, and sub-region settings
+    /// in this [PdfRenderConfig], as well as any intrinsic rotation of the page itself,
+    /// and for the fact that the y axis of a bitmap points downwards while the y axis of a page
+    /// points upwards. This is the inverse of [PdfRenderConfig::pixel_to_page()].
+    pub fn page_to_pixel(
+        &self,
+        page: &PdfPage,
+        x: PdfPoints,
+        y: PdfPoints,
+    ) -> Result<(f32, f32), PdfiumError> {
+        let (x, y) = self.page_to_pixel_matrix(page)?.transform_point(x, y);
+
+        Ok((x.value, y.value))
+    }
+
+    /// Returns the [PdfMatrix] that maps page coordinates on the given [PdfPage] onto
+    /// bitmap coordinates when the page is rendered using this [PdfRenderConfig].
+    pub(crate) fn page_to_pixel_matrix(&self, page: &PdfPage) -> Result<PdfMatrix, PdfiumError> {
+        let settings = self.apply_to_page(page);
+
+        // The transformation applied by Pdfium depends on which of its rendering functions
+        // is used; see PdfPage::render_into_bitmap_with_settings().
+
+        if settings.color_scheme.is_some() || settings.do_render_form_data {
+            display_matrix(page, settings.width, settings.height, settings.rotate)
+        } else {
+            // FPDF_RenderPageBitmapWithMatrix() first maps the page onto a device area
+            // the size of the page itself, truncated to whole points, and then applies
+            // the transformation matrix.
+
+            Ok(display_matrix(
+                page,
+                page.width().value as c_int,
+                page.height().value as c_int,
+                0,
+            )?
+            .multiply(PdfMatrix::from_pdfium(settings.matrix)))
+        }
+    }
+
+    /// Replaces any target size, scale factors, and maximum size constraints in this
+    /// [PdfRenderConfig] so that a rendered page is scaled to the largest size that fits
+    /// within the given pixel width and height while maintaining the page's aspect ratio.
+    pub(crate) fn fit_within(mut self, width: Pixels, height: Pixels) -> Self {
+        // Scaling the page's unrotated width to the larger of the two dimensions guarantees
+        // that the maximum size constraints will scale the page back down, whether or not
+        // the page is rotated during rendering.
+
+        self.target_width = Some(width.max(height));
+        self.target_height = None;
+        self.scale_width_factor = None;
+        self.scale_height_factor = None;
+        self.maximum_width = Some(width);
+        self.maximum_height = Some(height);
+
+        self
+    }
+
     /// Computes the pixel dimensions and rotation settings for the given [PdfPage]
     /// based on the configuration of this [PdfRenderConfig].
     #[inline]
     pub(crate) fn apply_to_page(&self, page: &PdfPage) -> PdfPageRenderSettings {
-        let source_width = page.width();
+        // Pdfium always applies a page's intrinsic rotation when rendering, and reports
+        // the page's width and height after that rotation has been applied. If the intrinsic
+        // rotation should not be applied, we must reverse it.
+
+        let displayed_width = page.width();
+
+        let displayed_height = page.height();
+
+        let page_rotation = if self.do_apply_page_rotation {
+            PdfPageRenderRotation::None
+        } else {
+            page.rotation().unwrap_or(PdfPageRenderRotation::None)
+        };
 
-        let source_height = page.height();
+        let (source_width, source_height) = match page_rotation {
+            PdfPageRenderRotation::Degrees90 | PdfPageRenderRotation::Degrees270 => {
+                (displayed_height, displayed_width)
+            }
+            PdfPageRenderRotation::None | PdfPageRenderRotation::Degrees180 => {
+                (displayed_width, displayed_height)
+            }
+        };
 
         let source_orientation =
             PdfPageOrientation::from_width_and_height(source_width, source_height);
@@ -816,6 +1095,26 @@ impl PdfRenderConfig {
 
         let output_height = (source_height.value * height_scale).round() as c_int;
 
+        // If only a sub-region of the page is to be rendered, clamp the sub-region
+        // to the bounds of the whole rendered page.
+
+        let sub_region = self.sub_region.map(|(left, top, width, height)| {
+            let clamped_left = left.clamp(0, output_width);
+
+            let clamped_top = top.clamp(0, output_height);
+
+            let clamped_right = left.saturating_add(width).clamp(clamped_left, output_width);
+
+            let clamped_bottom = top.saturating_add(height).clamp(clamped_top, output_height);
+
+            (
+                clamped_left,
+                clamped_top,
+                clamped_right - clamped_left,
+                clamped_bottom - clamped_top,
+            )
+        });
+
         // Pages can be rendered either _with_ transformation matrices and clipping
         // but _without_ form data, or _with_ form data but _without_ transformation matrices
         // and clipping. We need to be prepared for either option. If rendering of form data
@@ -823,6 +1122,13 @@ impl PdfRenderConfig {
         // 90-degree rotation need to be applied to the transformation matrix now.
 
         let transformation_matrix = if !self.do_render_form_data {
+            let base_matrix = if page_rotation != PdfPageRenderRotation::None {
+                reverse_page_rotation_matrix(page_rotation, displayed_width, displayed_height)
+                    .multiply(self.transformation_matrix)
+            } else {
+                self.transformation_matrix
+            };
+
             let result = if target_rotation != PdfPageRenderRotation::None {
                 // Translate the origin to the center of the page before rotating.
 
@@ -833,27 +1139,45 @@ impl PdfRenderConfig {
                     PdfPageRenderRotation::Degrees270 => (-source_height, PdfPoints::ZERO),
                 };
 
-                self.transformation_matrix
-                    .translate(delta_x, delta_y)
-                    .and_then(|result| {
-                        result.rotate_clockwise_degrees(target_rotation.as_degrees())
-                    })
+                base_matrix.translate(delta_x, delta_y).and_then(|result| {
+                    result.rotate_clockwise_degrees(target_rotation.as_degrees())
+                })
             } else {
-                Ok(self.transformation_matrix)
+                Ok(base_matrix)
             };
 
-            result.and_then(|result| result.scale(width_scale, height_scale))
+            let result = result.and_then(|result| result.scale(width_scale, height_scale));
+
+            if let Some((left, top, _, _)) = sub_region {
+                // Shift the rendered page so the top-left corner of the sub-region
+                // lies at the origin of the output bitmap.
+
+                result.and_then(|result| {
+                    result.translate(PdfPoints::new(-left as f32), PdfPoints::new(-top as f32))
+                })
+            } else {
+                result
+            }
         } else {
             Ok(self.transformation_matrix)
         };
 
+        let (offset_x, offset_y, output_width, output_height) = match sub_region {
+            Some((left, top, width, height)) => (left, top, width, height),
+            None => (0, 0, output_width, output_height),
+        };
+
         PdfPageRenderSettings {
             width: output_width,
             height: output_height,
+            offset_x: 0,
+            offset_y: 0,
             format: self.format.as_pdfium() as c_int,
-            rotate: target_rotation.as_pdfium(),
+            rotate: (target_rotation.as_pdfium() - page_rotation.as_pdfium()).rem_euclid(4),
             do_clear_bitmap_before_rendering: self.do_clear_bitmap_before_rendering,
             clear_color: self.clear_color.as_pdfium_color(),
+            do_use_transparent_background: self.do_use_transparent_background,
+            color_scheme: self.color_scheme.map(|scheme| scheme.as_pdfium()),
             do_render_form_data: self.do_render_form_data,
             form_field_highlight: if !self.do_render_form_data
                 || self.form_field_highlight.is_none()
@@ -877,12 +1201,29 @@ impl PdfRenderConfig {
             matrix: transformation_matrix
                 .unwrap_or(PdfMatrix::IDENTITY)
                 .as_pdfium(),
+            hidden_layers: self
+                .layer_visibility
+                .iter()
+                .enumerate()
+                .filter(|(index, (name, is_visible))| {
+                    // Later overrides of the same layer take precedence over earlier ones.
+
+                    !is_visible
+                        && !self.layer_visibility[index + 1..]
+                            .iter()
+                            .any(|(later, _)| later == name)
+                })
+                .map(|(_, (name, _))| name.clone())
+                .collect(),
+            has_custom_transformation: self.transformation_matrix != PdfMatrix::IDENTITY
+                || self.clip_rect.is_some()
+                || sub_region.is_some(),
             clipping: if let Some((left, top, right, bottom)) = self.clip_rect {
                 FS_RECTF {
-                    left: left as f32,
-                    top: top as f32,
-                    right: right as f32,
-                    bottom: bottom as f32,
+                    left: (left - offset_x) as f32,
+                    top: (top - offset_y) as f32,
+                    right: (right - offset_x) as f32,
+                    bottom: (bottom - offset_y) as f32,
                 }
             } else {
                 FS_RECTF {
@@ -905,19 +1246,107 @@ impl Default for PdfRenderConfig {
     }
 }
 
+/// Returns a [PdfMatrix] that reverses the given intrinsic page rotation, mapping the device
+/// coordinates of a page displayed upright at the given width and height back onto the device
+/// coordinates of the same page displayed without any rotation.
+fn reverse_page_rotation_matrix(
+    rotation: PdfPageRenderRotation,
+    displayed_width: PdfPoints,
+    displayed_height: PdfPoints,
+) -> PdfMatrix {
+    match rotation {
+        PdfPageRenderRotation::None => PdfMatrix::IDENTITY,
+        PdfPageRenderRotation::Degrees90 => {
+            PdfMatrix::new(0.0, -1.0, 1.0, 0.0, 0.0, displayed_width.value)
+        }
+        PdfPageRenderRotation::Degrees180 => PdfMatrix::new(
+            -1.0,
+            0.0,
+            0.0,
+            -1.0,
+            displayed_width.value,
+            displayed_height.value,
+        ),
+        PdfPageRenderRotation::Degrees270 => {
+            PdfMatrix::new(0.0, 1.0, -1.0, 0.0, displayed_height.value, 0.0)
+        }
+    }
+}
+
+/// Returns the [PdfMatrix] used by Pdfium to map page coordinates on the given [PdfPage]
+/// onto a device area with the given pixel dimensions and rotation.
+///
+/// Pdfium does not expose this matrix directly, and FPDF_PageToDevice() only returns whole pixel
+/// coordinates, so we probe FPDF_PageToDevice() at a magnified device size and scale the result
+/// back down. This retains sub-pixel accuracy.
+fn display_matrix(
+    page: &PdfPage,
+    width: c_int,
+    height: c_int,
+    rotate: c_int,
+) -> Result<PdfMatrix, PdfiumError> {
+    const PROBE_DISTANCE: f64 = 100.0;
+
+    let magnification = ((1 << 24) / width.max(height).max(1)).clamp(1, 4096);
+
+    let to_device = |x: f64, y: f64| {
+        let mut device_x: c_int = 0;
+        let mut device_y: c_int = 0;
+
+        if page.bindings().is_true(page.bindings().FPDF_PageToDevice(
+            page.page_handle(),
+            0,
+            0,
+            width * magnification,
+            height * magnification,
+            rotate,
+            x,
+            y,
+            &mut device_x,
+            &mut device_y,
+        )) {
+            Ok((
+                device_x as f64 / magnification as f64,
+                device_y as f64 / magnification as f64,
+            ))
+        } else {
+            Err(PdfiumError::CoordinateConversionFunctionIndicatedError)
+        }
+    };
+
+    let (origin_x, origin_y) = to_device(0.0, 0.0)?;
+    let (x_axis_x, x_axis_y) = to_device(PROBE_DISTANCE, 0.0)?;
+    let (y_axis_x, y_axis_y) = to_device(0.0, PROBE_DISTANCE)?;
+
+    Ok(PdfMatrix::new(
+        ((x_axis_x - origin_x) / PROBE_DISTANCE) as PdfMatrixValue,
+        ((x_axis_y - origin_y) / PROBE_DISTANCE) as PdfMatrixValue,
+        ((y_axis_x - origin_x) / PROBE_DISTANCE) as PdfMatrixValue,
+        ((y_axis_y - origin_y) / PROBE_DISTANCE) as PdfMatrixValue,
+        origin_x as PdfMatrixValue,
+        origin_y as PdfMatrixValue,
+    ))
+}
+
 /// Finalized rendering settings, ready to be passed to a Pdfium rendering function.
 /// Generated by calling [PdfRenderConfig::apply_to_page()].
 #[derive(Debug, Clone)]
 pub(crate) struct PdfPageRenderSettings {
     pub(crate) width: c_int,
     pub(crate) height: c_int,
+    pub(crate) offset_x: c_int,
+    pub(crate) offset_y: c_int,
     pub(crate) format: c_int,
     pub(crate) rotate: c_int,
     pub(crate) do_clear_bitmap_before_rendering: bool,
     pub(crate) clear_color: FPDF_DWORD,
+    pub(crate) do_use_transparent_background: bool,
+    pub(crate) color_scheme: Option<FPDF_COLORSCHEME>,
     pub(crate) do_render_form_data: bool,
     pub(crate) form_field_highlight: Option<Vec<(c_int, (FPDF_DWORD, u8))>>,
     pub(crate) matrix: FS_MATRIX,
+    pub(crate) has_custom_transformation: bool,
+    pub(crate) hidden_layers: Vec<String>,
     pub(crate) clipping: FS_RECTF,
     pub(crate) render_flags: c_int,
     pub(crate) is_reversed_byte_order_flag_set: bool,
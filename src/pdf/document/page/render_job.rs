@@ -0,0 +1,387 @@
+//! Defines the [PdfProgressiveRenderJob] struct, exposing functionality related to rendering
+//! a single `PdfPage` progressively, with the ability to pause and cancel rendering.
+
+use crate::bindgen::{FPDF_BOOL, FPDF_RENDER_DONE, FPDF_RENDER_TOBECONTINUED, IFSDK_PAUSE};
+use crate::error::{PdfiumError, PdfiumInternalError};
+use crate::pdf::bitmap::PdfBitmap;
+use crate::pdf::document::page::render_config::PdfPageRenderSettings;
+use crate::pdf::document::page::PdfPage;
+use std::any::Any;
+use std::os::raw::c_int;
+use std::panic::{catch_unwind, resume_unwind, AssertUnwindSafe};
+
+/// The status of a [PdfProgressiveRenderJob].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum PdfProgressiveRenderStatus {
+    /// Rendering was paused at the request of the pause callback. Call
+    /// [PdfProgressiveRenderJob::continue_rendering()] to resume rendering.
+    Paused,
+
+    /// Rendering is complete. The rendered bitmap can be retrieved by calling
+    /// [PdfProgressiveRenderJob::bitmap()] or [PdfProgressiveRenderJob::into_bitmap()].
+    Complete,
+}
+
+/// The Rust state made available to the pause callback invoked by Pdfium.
+struct PdfProgressiveRenderPauseState<'c> {
+    should_pause: Box<dyn FnMut() -> bool + 'c>,
+
+    // Any panic raised inside the user-provided callback is caught before it can unwind
+    // across the FFI boundary into Pdfium, and is resumed once control returns to Rust.
+    panic: Option<Box<dyn Any + Send + 'static>>,
+}
+
+// The callback function invoked by Pdfium.
+unsafe extern "C" fn need_to_pause_now_from_callback(pause: *mut IFSDK_PAUSE) -> FPDF_BOOL {
+    let state = &mut *((*pause).user as *mut PdfProgressiveRenderPauseState);
+
+    if state.panic.is_some() {
+        // The callback has already panicked; ask Pdfium to pause so the panic can be
+        // resumed as soon as possible.
+
+        return 1;
+    }
+
+    match catch_unwind(AssertUnwindSafe(|| (state.should_pause)())) {
+        Ok(true) => 1,
+        Ok(false) => 0,
+        Err(panic) => {
+            state.panic = Some(panic);
+
+            1
+        }
+    }
+}
+
+/// A progressive rendering operation of a single [PdfPage] into a [PdfBitmap], created by
+/// calling the [PdfPage::render_progressively()] function.
+///
+/// Progressive rendering allows a long-running render of a complex page to be paused and
+/// resumed, or abandoned entirely. Pdfium periodically invokes the pause callback supplied to
+/// [PdfPage::render_progressively()]; if the callback returns `true`, Pdfium pauses rendering
+/// and control returns to the caller, who can then either call
+/// [PdfProgressiveRenderJob::continue_rendering()] to resume rendering from where it left off,
+/// or [PdfProgressiveRenderJob::cancel()] to abandon rendering altogether.
+///
+/// The pause callback is invoked from inside Pdfium's rendering pipeline. It must not attempt
+/// to call back into Pdfium.
+///
+/// When compiling to WASM, Pdfium cannot invoke callbacks defined in `pdfium-render`'s
+/// WASM module during rendering, so the pause callback is never invoked and rendering always
+/// runs to completion in a single step.
+pub struct PdfProgressiveRenderJob<'b, 'a> {
+    page: &'b PdfPage<'a>,
+    bitmap: Option<PdfBitmap<'a>>,
+    settings: PdfPageRenderSettings,
+    pause: Box<IFSDK_PAUSE>,
+    pause_state: Box<PdfProgressiveRenderPauseState<'b>>,
+    status: PdfProgressiveRenderStatus,
+    is_rendering_open: bool,
+}
+
+impl<'b, 'a> PdfProgressiveRenderJob<'b, 'a> {
+    /// Starts progressively rendering the given [PdfPage] into the given [PdfBitmap]
+    /// using the given [PdfPageRenderSettings], invoking the given callback periodically
+    /// to determine whether rendering should be paused.
+    pub(crate) fn start<F>(
+        page: &'b PdfPage<'a>,
+        mut bitmap: PdfBitmap<'a>,
+        settings: PdfPageRenderSettings,
+        should_pause: F,
+    ) -> Result<Self, PdfiumError>
+    where
+        F: FnMut() -> bool + 'b,
+    {
+        if !settings.do_render_form_data && settings.has_custom_transformation {
+            return Err(PdfiumError::ProgressiveRenderingDoesNotSupportTransformations);
+        }
+
+        page.prepare_bitmap_for_rendering(&mut bitmap, &settings)?;
+
+        let mut pause_state = Box::new(PdfProgressiveRenderPauseState {
+            should_pause: Box::new(should_pause),
+            panic: None,
+        });
+
+        // Both the IFSDK_PAUSE struct and the state it points to are boxed, so their addresses
+        // remain stable for as long as Pdfium might invoke the callback.
+
+        let pause = Box::new(IFSDK_PAUSE {
+            version: 1,
+            NeedToPauseNow: Some(need_to_pause_now_from_callback),
+            user: pause_state.as_mut() as *mut PdfProgressiveRenderPauseState as *mut _,
+        });
+
+        let mut result = PdfProgressiveRenderJob {
+            page,
+            bitmap: Some(bitmap),
+            settings,
+            pause,
+            pause_state,
+            status: PdfProgressiveRenderStatus::Paused,
+            is_rendering_open: true,
+        };
+
+        let pause_ptr = result.pause_ptr();
+
+        let bitmap_handle = *result.bitmap.as_ref().unwrap().handle();
+
+        let render_status = match result.settings.color_scheme.as_ref() {
+            Some(color_scheme) => page.bindings().FPDF_RenderPageBitmapWithColorScheme_Start(
+                bitmap_handle,
+                page.page_handle(),
+                0,
+                0,
+                result.settings.width,
+                result.settings.height,
+                result.settings.rotate,
+                result.settings.render_flags,
+                color_scheme,
+                pause_ptr,
+            ),
+            None => page.bindings().FPDF_RenderPageBitmap_Start(
+                bitmap_handle,
+                page.page_handle(),
+                0,
+                0,
+                result.settings.width,
+                result.settings.height,
+                result.settings.rotate,
+                result.settings.render_flags,
+                pause_ptr,
+            ),
+        };
+
+        result.update_status(render_status)?;
+
+        Ok(result)
+    }
+
+    /// Returns the current [PdfProgressiveRenderStatus] of this [PdfProgressiveRenderJob].
+    #[inline]
+    pub fn status(&self) -> PdfProgressiveRenderStatus {
+        self.status
+    }
+
+    /// Returns `true` if rendering of this [PdfProgressiveRenderJob] is complete.
+    #[inline]
+    pub fn is_complete(&self) -> bool {
+        self.status == PdfProgressiveRenderStatus::Complete
+    }
+
+    /// Resumes rendering of a paused [PdfProgressiveRenderJob], continuing until either
+    /// the pause callback requests another pause or rendering is complete. Calling this
+    /// function on a job that has already completed has no effect.
+    pub fn continue_rendering(&mut self) -> Result<PdfProgressiveRenderStatus, PdfiumError> {
+        if self.is_complete() {
+            return Ok(self.status);
+        }
+
+        let pause_ptr = self.pause_ptr();
+
+        let render_status = self
+            .page
+            .bindings()
+            .FPDF_RenderPage_Continue(self.page.page_handle(), pause_ptr);
+
+        self.update_status(render_status)?;
+
+        Ok(self.status)
+    }
+
+    /// Resumes rendering of this [PdfProgressiveRenderJob], continuing until rendering
+    /// is complete, but still invoking the pause callback each time it requests a pause.
+    /// Returns the completed [PdfBitmap].
+    pub fn render_to_completion(mut self) -> Result<PdfBitmap<'a>, PdfiumError> {
+        while !self.is_complete() {
+            self.continue_rendering()?;
+        }
+
+        self.into_bitmap()
+            .ok_or(PdfiumError::PdfiumLibraryInternalError(
+                PdfiumInternalError::Unknown,
+            ))
+    }
+
+    /// Abandons rendering of this [PdfProgressiveRenderJob], releasing all resources
+    /// Pdfium allocated during rendering. Any partially rendered bitmap is discarded.
+    #[inline]
+    pub fn cancel(self) {
+        // Dropping the job closes the rendering operation.
+    }
+
+    /// Returns the rendered [PdfBitmap], if rendering of this [PdfProgressiveRenderJob]
+    /// is complete.
+    #[inline]
+    pub fn bitmap(&self) -> Option<&PdfBitmap<'a>> {
+        if self.is_complete() {
+            self.bitmap.as_ref()
+        } else {
+            None
+        }
+    }
+
+    /// Consumes this [PdfProgressiveRenderJob], returning the rendered [PdfBitmap]
+    /// if rendering is complete.
+    #[inline]
+    pub fn into_bitmap(mut self) -> Option<PdfBitmap<'a>> {
+        if self.is_complete() {
+            self.bitmap.take()
+        } else {
+            None
+        }
+    }
+
+    /// Returns a pointer to the `IFSDK_PAUSE` struct to pass to Pdfium.
+    #[inline]
+    fn pause_ptr(&mut self) -> *mut IFSDK_PAUSE {
+        if cfg!(target_arch = "wasm32") {
+            // Pdfium's WASM module cannot call back into our WASM module during rendering.
+            // Passing a null pause pointer instructs Pdfium to render to completion.
+
+            std::ptr::null_mut()
+        } else {
+            self.pause.as_mut() as *mut IFSDK_PAUSE
+        }
+    }
+
+    /// Updates the status of this [PdfProgressiveRenderJob] from the given Pdfium
+    /// rendering status, finalizing the rendered bitmap if rendering is complete.
+    fn update_status(&mut self, render_status: c_int) -> Result<(), PdfiumError> {
+        if let Some(panic) = self.pause_state.panic.take() {
+            self.close();
+
+            resume_unwind(panic);
+        }
+
+        match render_status as u32 {
+            FPDF_RENDER_TOBECONTINUED => {
+                self.status = PdfProgressiveRenderStatus::Paused;
+
+                Ok(())
+            }
+            FPDF_RENDER_DONE => {
+                self.close();
+
+                if let Some(bitmap) = self.bitmap.as_mut() {
+                    // Render user-supplied form data, if any, as an overlay on top of
+                    // the completed page, just as a blocking render would.
+
+                    if self.settings.do_render_form_data {
+                        self.page
+                            .render_form_data_into_bitmap(bitmap, &self.settings);
+                    }
+
+                    bitmap.set_byte_order_from_render_settings(&self.settings);
+                }
+
+                self.status = PdfProgressiveRenderStatus::Complete;
+
+                Ok(())
+            }
+            _ => {
+                // FPDF_RENDER_FAILED, or an unexpected status.
+
+                self.close();
+
+                Err(PdfiumError::PdfiumLibraryInternalError(
+                    PdfiumInternalError::Unknown,
+                ))
+            }
+        }
+    }
+
+    /// Releases the resources Pdfium allocated during progressive rendering.
+    #[inline]
+    fn close(&mut self) {
+        if self.is_rendering_open {
+            self.page
+                .bindings()
+                .FPDF_RenderPage_Close(self.page.page_handle());
+
+            self.is_rendering_open = false;
+        }
+    }
+}
+
+impl<'b, 'a> Drop for PdfProgressiveRenderJob<'b, 'a> {
+    /// Closes this [PdfProgressiveRenderJob], releasing held memory.
+    #[inline]
+    fn drop(&mut self) {
+        self.close();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::utils::test::test_bind_to_pdfium;
+    use std::cell::Cell;
+
+    #[test]
+    fn test_progressive_render_matches_blocking_render() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let document = pdfium.load_pdf_from_file("./test/export-test.pdf", None)?;
+
+        let page = document.pages().get(0)?;
+
+        let render_config = PdfRenderConfig::new().set_target_width(800);
+
+        let expected = page.render_with_config(&render_config)?.as_raw_bytes();
+
+        // Request a pause at every opportunity, so rendering proceeds in as many steps
+        // as possible.
+
+        let pause_count = Cell::new(0);
+
+        let mut job = page.render_progressively(&render_config, || {
+            pause_count.set(pause_count.get() + 1);
+
+            true
+        })?;
+
+        while job.continue_rendering()? == PdfProgressiveRenderStatus::Paused {}
+
+        assert!(job.is_complete());
+        assert!(pause_count.get() > 0);
+
+        let bitmap = job.into_bitmap().unwrap();
+
+        assert_eq!(bitmap.as_raw_bytes(), expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_progressive_render_cancel() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let document = pdfium.load_pdf_from_file("./test/export-test.pdf", None)?;
+
+        let page = document.pages().get(0)?;
+
+        let render_config = PdfRenderConfig::new().set_target_width(800);
+
+        let job = page.render_progressively(&render_config, || true)?;
+
+        if !job.is_complete() {
+            assert!(job.bitmap().is_none());
+        }
+
+        job.cancel();
+
+        // The page should still be renderable after a cancelled progressive render.
+
+        let bitmap = page
+            .render_progressively(&render_config, || false)?
+            .render_to_completion()?;
+
+        assert_eq!(
+            bitmap.as_raw_bytes(),
+            page.render_with_config(&render_config)?.as_raw_bytes()
+        );
+
+        Ok(())
+    }
+}
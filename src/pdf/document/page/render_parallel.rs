@@ -0,0 +1,564 @@
+//! Defines the [PdfParallelRenderIterator] struct, exposing functionality related to rendering
+//! many pages of a single document on a pool of worker threads, with each worker's Pdfium
+//! instance and document confined to the thread that created them.
+
+use crate::bindings::thread_safe::is_pdfium_initialized_on_current_thread;
+use crate::bindings::PdfiumLibraryBindings;
+use crate::error::PdfiumError;
+use crate::pdf::bitmap::{PdfBitmapFormat, Pixels};
+use crate::pdf::document::page::render_config::PdfRenderConfig;
+use crate::pdf::document::pages::PdfPageIndex;
+use crate::pdf::document::PdfDocument;
+use crate::pdfium::Pdfium;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::mpsc::{sync_channel, Receiver, SyncSender};
+use std::sync::Arc;
+use std::thread;
+
+#[cfg(any(feature = "image_latest", feature = "image_025"))]
+use image_025::{DynamicImage, GrayImage, RgbaImage};
+
+#[cfg(feature = "image_024")]
+use image_024::{DynamicImage, GrayImage, RgbaImage};
+
+#[cfg(feature = "image_023")]
+use image_023::{DynamicImage, GrayImage, RgbaImage};
+
+/// The document to be rendered by [Pdfium::render_pages_parallel()]. Each worker thread
+/// opens its own handle to the document from this source.
+#[derive(Clone, Debug)]
+pub enum PdfParallelRenderSource {
+    /// The document will be loaded from the file at the given path.
+    File(PathBuf),
+
+    /// The document will be loaded from the given bytes, shared between all worker threads.
+    Bytes(Arc<Vec<u8>>),
+}
+
+impl From<PathBuf> for PdfParallelRenderSource {
+    #[inline]
+    fn from(path: PathBuf) -> Self {
+        PdfParallelRenderSource::File(path)
+    }
+}
+
+impl From<&Path> for PdfParallelRenderSource {
+    #[inline]
+    fn from(path: &Path) -> Self {
+        PdfParallelRenderSource::File(path.to_path_buf())
+    }
+}
+
+impl From<&str> for PdfParallelRenderSource {
+    #[inline]
+    fn from(path: &str) -> Self {
+        PdfParallelRenderSource::File(PathBuf::from(path))
+    }
+}
+
+impl From<String> for PdfParallelRenderSource {
+    #[inline]
+    fn from(path: String) -> Self {
+        PdfParallelRenderSource::File(PathBuf::from(path))
+    }
+}
+
+impl From<Vec<u8>> for PdfParallelRenderSource {
+    #[inline]
+    fn from(bytes: Vec<u8>) -> Self {
+        PdfParallelRenderSource::Bytes(Arc::new(bytes))
+    }
+}
+
+impl From<&[u8]> for PdfParallelRenderSource {
+    #[inline]
+    fn from(bytes: &[u8]) -> Self {
+        PdfParallelRenderSource::Bytes(Arc::new(bytes.to_vec()))
+    }
+}
+
+impl From<Arc<Vec<u8>>> for PdfParallelRenderSource {
+    #[inline]
+    fn from(bytes: Arc<Vec<u8>>) -> Self {
+        PdfParallelRenderSource::Bytes(bytes)
+    }
+}
+
+impl PdfParallelRenderSource {
+    /// Opens a handle to the document from this source using the given [Pdfium] instance.
+    fn load<'a>(&'a self, pdfium: &'a Pdfium) -> Result<PdfDocument<'a>, PdfiumError> {
+        match self {
+            PdfParallelRenderSource::File(path) => pdfium.load_pdf_from_file(path, None),
+            PdfParallelRenderSource::Bytes(bytes) => {
+                pdfium.load_pdf_from_byte_slice(bytes.as_slice(), None)
+            }
+        }
+    }
+}
+
+/// A function called once by each worker thread started by [Pdfium::render_pages_parallel_with_bindings()]
+/// to bind to the Pdfium library that worker will use.
+pub type PdfParallelRenderBindingsFactory =
+    dyn Fn() -> Result<Box<dyn PdfiumLibraryBindings>, PdfiumError> + Send + Sync;
+
+/// A single page rendered by [Pdfium::render_pages_parallel()].
+///
+/// Unlike a `PdfBitmap`, a [PdfRenderedPage] owns a copy of its pixel data and holds no
+/// Pdfium handles, so it can be freely sent between threads.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PdfRenderedPage {
+    index: PdfPageIndex,
+    width: Pixels,
+    height: Pixels,
+    format: PdfBitmapFormat,
+    bytes: Vec<u8>,
+}
+
+impl PdfRenderedPage {
+    /// Returns the zero-based index of the rendered page in its containing document.
+    #[inline]
+    pub fn index(&self) -> PdfPageIndex {
+        self.index
+    }
+
+    /// Returns the width of the rendered image.
+    #[inline]
+    pub fn width(&self) -> Pixels {
+        self.width
+    }
+
+    /// Returns the height of the rendered image.
+    #[inline]
+    pub fn height(&self) -> Pixels {
+        self.height
+    }
+
+    /// Returns the pixel format of the bitmap the page was rendered into.
+    #[inline]
+    pub fn format(&self) -> PdfBitmapFormat {
+        self.format
+    }
+
+    /// Returns the rendered pixel data, normalized as described by `PdfBitmap::as_rgba_bytes()`.
+    #[inline]
+    pub fn as_rgba_bytes(&self) -> &[u8] {
+        self.bytes.as_slice()
+    }
+
+    /// Consumes this [PdfRenderedPage], returning its rendered pixel data, normalized as
+    /// described by `PdfBitmap::as_rgba_bytes()`.
+    #[inline]
+    pub fn into_rgba_bytes(self) -> Vec<u8> {
+        self.bytes
+    }
+
+    /// Consumes this [PdfRenderedPage], returning a new `Image::DynamicImage` created from
+    /// its rendered pixel data.
+    ///
+    /// This function is only available when this crate's `image` feature is enabled.
+    #[cfg(feature = "image_api")]
+    pub fn into_image(self) -> Result<DynamicImage, PdfiumError> {
+        let width = self.width as u32;
+
+        let height = self.height as u32;
+
+        match self.format {
+            PdfBitmapFormat::Gray => {
+                GrayImage::from_raw(width, height, self.bytes).map(DynamicImage::ImageLuma8)
+            }
+            _ => RgbaImage::from_raw(width, height, self.bytes).map(DynamicImage::ImageRgba8),
+        }
+        .ok_or(PdfiumError::ImageError)
+    }
+}
+
+/// The state shared between a [PdfParallelRenderIterator] and its worker threads.
+struct PdfParallelRenderState {
+    bindings: Arc<PdfParallelRenderBindingsFactory>,
+    source: PdfParallelRenderSource,
+    config: PdfRenderConfig,
+    page_indices: Vec<PdfPageIndex>,
+    next_position: AtomicUsize,
+    is_cancelled: AtomicBool,
+}
+
+/// An iterator over the pages rendered by [Pdfium::render_pages_parallel()], returning
+/// each [PdfRenderedPage] in the order the page indices were originally given.
+///
+/// Pages are rendered by a pool of worker threads. Each worker creates its own [Pdfium] instance
+/// before claiming any pages, and then renders one page at a time, opening its own handle to
+/// the source document for each page, until every page has been claimed. No Pdfium handles ever
+/// leave the worker thread that created them; only owned [PdfRenderedPage] values are passed
+/// back to the iterator.
+///
+/// Dropping the iterator stops the workers from starting any further pages.
+pub struct PdfParallelRenderIterator {
+    state: Arc<PdfParallelRenderState>,
+    receiver: Receiver<(usize, Result<PdfRenderedPage, PdfiumError>)>,
+    pending: BTreeMap<usize, Result<PdfRenderedPage, PdfiumError>>,
+    next_position: usize,
+}
+
+impl PdfParallelRenderIterator {
+    pub(crate) fn new(
+        bindings: Arc<PdfParallelRenderBindingsFactory>,
+        source: PdfParallelRenderSource,
+        page_indices: Vec<PdfPageIndex>,
+        config: PdfRenderConfig,
+        worker_count: usize,
+    ) -> Result<Self, PdfiumError> {
+        if is_pdfium_initialized_on_current_thread() {
+            // The workers could never initialize Pdfium while this thread's instance
+            // is alive, so the iterator would wait forever for the first page.
+
+            return Err(PdfiumError::ParallelRenderPdfiumInUse);
+        }
+
+        let worker_count = worker_count.max(1).min(page_indices.len().max(1));
+
+        let state = Arc::new(PdfParallelRenderState {
+            bindings,
+            source,
+            config,
+            page_indices,
+            next_position: AtomicUsize::new(0),
+            is_cancelled: AtomicBool::new(false),
+        });
+
+        // Bounding the channel stops workers from racing ahead of a slow consumer.
+
+        let (sender, receiver) = sync_channel(worker_count);
+
+        for index in 0..worker_count {
+            let state = state.clone();
+
+            let sender = sender.clone();
+
+            thread::Builder::new()
+                .name(format!("pdfium-render-worker-{}", index))
+                .spawn(move || Self::run_worker(state, sender))
+                .map_err(PdfiumError::IoError)?;
+        }
+
+        Ok(PdfParallelRenderIterator {
+            state,
+            receiver,
+            pending: BTreeMap::new(),
+            next_position: 0,
+        })
+    }
+
+    /// Binds to Pdfium, then claims and renders pages until every page has been claimed,
+    /// the iterator is dropped, or the render is cancelled.
+    fn run_worker(
+        state: Arc<PdfParallelRenderState>,
+        sender: SyncSender<(usize, Result<PdfRenderedPage, PdfiumError>)>,
+    ) {
+        if state.is_cancelled.load(Ordering::SeqCst) {
+            return;
+        }
+
+        // Pdfium must be initialized before any page is claimed. When the thread_safe crate
+        // feature serializes access to Pdfium, initialization blocks until no other Pdfium
+        // instance is alive; a worker blocked here must not hold a claimed page, otherwise
+        // the iterator could wait forever for a page that is never rendered.
+
+        let pdfium = match (state.bindings)() {
+            Ok(bindings) => Pdfium::new(bindings),
+            Err(err) => return Self::report_worker_error(&state, &sender, err),
+        };
+
+        while let Some((position, index)) = Self::claim_page(&state) {
+            // The document cannot be kept open between pages, since Pdfium may be released
+            // and reinitialized before the next page is rendered.
+
+            let result = match state.source.load(&pdfium) {
+                Ok(document) => Self::render_page(&document, &state.config, index),
+                Err(err) => {
+                    // If the iterator has been dropped, there is no-one to report the error to.
+
+                    let _ = sender.send((position, Err(err)));
+
+                    return;
+                }
+            };
+
+            // If this worker holds the global lock taken by the thread_safe crate feature,
+            // release it while sending the rendered page, so that the other workers can take
+            // their turn rendering a page rather than waiting until this worker has rendered
+            // every page.
+
+            let is_releasing_pdfium = is_pdfium_initialized_on_current_thread();
+
+            if is_releasing_pdfium {
+                pdfium.bindings().FPDF_DestroyLibrary();
+
+                thread::yield_now();
+            }
+
+            let is_sent = sender.send((position, result)).is_ok();
+
+            if is_releasing_pdfium {
+                pdfium.bindings().FPDF_InitLibrary();
+            }
+
+            if !is_sent {
+                // The iterator has been dropped.
+
+                break;
+            }
+        }
+    }
+
+    /// Claims the next page to be rendered, returning its position in the list of page
+    /// indices and its page index, or `None` if every page has been claimed or the render
+    /// has been cancelled.
+    fn claim_page(state: &PdfParallelRenderState) -> Option<(usize, PdfPageIndex)> {
+        if state.is_cancelled.load(Ordering::SeqCst) {
+            return None;
+        }
+
+        let position = state.next_position.fetch_add(1, Ordering::SeqCst);
+
+        state
+            .page_indices
+            .get(position)
+            .map(|index| (position, *index))
+    }
+
+    /// Reports the given error, which prevented a worker from rendering any pages, as the
+    /// result of the next unclaimed page. The worker then exits, leaving any remaining pages
+    /// to the other workers.
+    fn report_worker_error(
+        state: &PdfParallelRenderState,
+        sender: &SyncSender<(usize, Result<PdfRenderedPage, PdfiumError>)>,
+        err: PdfiumError,
+    ) {
+        if let Some((position, _)) = Self::claim_page(state) {
+            // If the iterator has been dropped, there is no-one to report the error to.
+
+            let _ = sender.send((position, Err(err)));
+        }
+    }
+
+    /// Renders a single page of the given document on the current thread.
+    fn render_page(
+        document: &PdfDocument,
+        config: &PdfRenderConfig,
+        index: PdfPageIndex,
+    ) -> Result<PdfRenderedPage, PdfiumError> {
+        let page = document.pages().get(index)?;
+
+        let bitmap = page.render_with_config(config)?;
+
+        Ok(PdfRenderedPage {
+            index,
+            width: bitmap.width(),
+            height: bitmap.height(),
+            format: bitmap.format()?,
+            bytes: bitmap.as_rgba_bytes(),
+        })
+    }
+}
+
+impl Iterator for PdfParallelRenderIterator {
+    type Item = Result<PdfRenderedPage, PdfiumError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.next_position >= self.state.page_indices.len() {
+            return None;
+        }
+
+        loop {
+            if let Some(result) = self.pending.remove(&self.next_position) {
+                self.next_position += 1;
+
+                return Some(result);
+            }
+
+            match self.receiver.recv() {
+                Ok((position, result)) => {
+                    self.pending.insert(position, result);
+                }
+                Err(_) => {
+                    // Every worker has exited without returning the next page, so either
+                    // the worker that claimed it terminated abnormally, or every worker
+                    // exited early after failing to bind to Pdfium or open the document.
+
+                    self.next_position += 1;
+
+                    return Some(Err(PdfiumError::ParallelRenderWorkerTerminated));
+                }
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.state.page_indices.len() - self.next_position;
+
+        (remaining, Some(remaining))
+    }
+}
+
+impl ExactSizeIterator for PdfParallelRenderIterator {}
+
+impl Drop for PdfParallelRenderIterator {
+    /// Stops the worker threads from starting any further pages. Workers finish rendering
+    /// any pages already in progress and then exit; they are not joined.
+    #[inline]
+    fn drop(&mut self) {
+        self.state.is_cancelled.store(true, Ordering::SeqCst);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::utils::test::test_bind_to_pdfium;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    #[test]
+    fn test_parallel_render_matches_serial_render() -> Result<(), PdfiumError> {
+        let path = "./test/export-test.pdf";
+
+        let config = || PdfRenderConfig::new().set_target_width(500);
+
+        // Render each page serially first. The Pdfium instance must be dropped before
+        // rendering in parallel, so that the worker threads can initialize Pdfium.
+
+        let serial = {
+            let pdfium = test_bind_to_pdfium();
+
+            let document = pdfium.load_pdf_from_file(path, None)?;
+
+            document
+                .pages()
+                .iter()
+                .map(|page| {
+                    page.render_with_config(&config())
+                        .map(|bitmap| bitmap.as_rgba_bytes())
+                })
+                .collect::<Result<Vec<_>, _>>()?
+        };
+
+        // Render every page three times over, in an interleaved order, from four workers.
+
+        let page_count = serial.len() as PdfPageIndex;
+
+        let page_indices = (0..3)
+            .flat_map(|_| (0..page_count).rev())
+            .collect::<Vec<_>>();
+
+        let rendered = Pdfium::render_pages_parallel(
+            std::fs::read(path).map_err(PdfiumError::IoError)?,
+            page_indices.clone(),
+            config(),
+            4,
+        )?;
+
+        assert_eq!(rendered.len(), page_indices.len());
+
+        for (expected_index, page) in page_indices.into_iter().zip(rendered) {
+            let page = page?;
+
+            assert_eq!(page.index(), expected_index);
+            assert_eq!(
+                page.as_rgba_bytes(),
+                serial[expected_index as usize].as_slice()
+            );
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_parallel_render_reports_errors_in_order() -> Result<(), PdfiumError> {
+        let results = Pdfium::render_pages_parallel(
+            "./test/export-test.pdf",
+            vec![0, PdfPageIndex::MAX, 0],
+            PdfRenderConfig::new().set_target_width(100),
+            2,
+        )?
+        .collect::<Vec<_>>();
+
+        assert_eq!(results.len(), 3);
+        assert!(results[0].is_ok());
+        assert!(results[1].is_err());
+        assert!(results[2].is_ok());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_parallel_render_binds_once_per_worker() -> Result<(), PdfiumError> {
+        let bind_count = Arc::new(AtomicUsize::new(0));
+
+        let factory_bind_count = bind_count.clone();
+
+        let results = Pdfium::render_pages_parallel_with_bindings(
+            move || {
+                factory_bind_count.fetch_add(1, Ordering::SeqCst);
+
+                Pdfium::bind_to_library(Pdfium::pdfium_platform_library_name_at_path("./"))
+                    .or_else(|_| Pdfium::bind_to_system_library())
+            },
+            "./test/export-test.pdf",
+            vec![0; 12],
+            PdfRenderConfig::new().set_target_width(100),
+            3,
+        )?
+        .collect::<Vec<_>>();
+
+        assert_eq!(results.len(), 12);
+        assert!(results.iter().all(|result| result.is_ok()));
+        assert!(bind_count.load(Ordering::SeqCst) <= 3);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_parallel_render_reports_binding_errors() -> Result<(), PdfiumError> {
+        let results = Pdfium::render_pages_parallel_with_bindings(
+            || Err(PdfiumError::UnrecognizedPath),
+            "./test/export-test.pdf",
+            vec![0, 1, 2],
+            PdfRenderConfig::new(),
+            2,
+        )?
+        .collect::<Vec<_>>();
+
+        // Each worker reports its binding error in place of one page, then exits,
+        // leaving no worker to render the final page.
+
+        assert_eq!(results.len(), 3);
+        assert!(matches!(results[0], Err(PdfiumError::UnrecognizedPath)));
+        assert!(matches!(results[1], Err(PdfiumError::UnrecognizedPath)));
+        assert!(matches!(
+            results[2],
+            Err(PdfiumError::ParallelRenderWorkerTerminated)
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_parallel_render_fails_while_pdfium_is_held() {
+        let pdfium = test_bind_to_pdfium();
+
+        assert!(matches!(
+            Pdfium::render_pages_parallel(
+                "./test/export-test.pdf",
+                vec![0],
+                PdfRenderConfig::new(),
+                2,
+            ),
+            Err(PdfiumError::ParallelRenderPdfiumInUse)
+        ));
+
+        drop(pdfium);
+    }
+}
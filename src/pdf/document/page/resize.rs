@@ -0,0 +1,298 @@
+//! Defines the [PdfPageResizeMode] enum, controlling how existing page content is scaled or
+//! repositioned when a `PdfPage` is resized using the `PdfPage::resize()` function.
+
+use crate::error::PdfiumError;
+use crate::pdf::document::page::size::PdfPagePaperSize;
+use crate::pdf::document::page::{PdfPage, PdfPageRenderRotation};
+use crate::pdf::matrix::PdfMatrix;
+use crate::pdf::points::PdfPoints;
+use crate::pdf::rect::PdfRect;
+
+/// A position on a page, used to pin existing page content in place when resizing a page
+/// using [PdfPageResizeMode::KeepContentPosition].
+///
+/// Anchors are always expressed relative to the page as it is displayed by a viewer,
+/// after any intrinsic page rotation has been applied.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum PdfPageAnchor {
+    TopLeft,
+    Top,
+    TopRight,
+    Left,
+    Center,
+    Right,
+    BottomLeft,
+    Bottom,
+    BottomRight,
+}
+
+impl PdfPageAnchor {
+    /// Returns the horizontal and vertical position of this [PdfPageAnchor] as fractions
+    /// of the width and height of the displayed page, measured from its bottom left corner.
+    fn as_fractions(&self) -> (f32, f32) {
+        match self {
+            PdfPageAnchor::TopLeft => (0.0, 1.0),
+            PdfPageAnchor::Top => (0.5, 1.0),
+            PdfPageAnchor::TopRight => (1.0, 1.0),
+            PdfPageAnchor::Left => (0.0, 0.5),
+            PdfPageAnchor::Center => (0.5, 0.5),
+            PdfPageAnchor::Right => (1.0, 0.5),
+            PdfPageAnchor::BottomLeft => (0.0, 0.0),
+            PdfPageAnchor::Bottom => (0.5, 0.0),
+            PdfPageAnchor::BottomRight => (1.0, 0.0),
+        }
+    }
+}
+
+/// Controls how existing page content is transformed when a [PdfPage] is resized
+/// using the [PdfPage::resize()] function.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum PdfPageResizeMode {
+    /// Scales the page content uniformly so that it fits within the new page size,
+    /// preserving its aspect ratio, and centers it on the new page.
+    ScaleContentToFit,
+
+    /// Scales the page content independently in each direction so that it exactly fills
+    /// the new page size. The aspect ratio of the page content will not be preserved
+    /// if the aspect ratio of the new page size differs from that of the existing page.
+    ScaleContentAnisotropic,
+
+    /// Leaves the page content at its existing size, keeping it fixed relative to
+    /// the given anchor position. For instance, [PdfPageAnchor::TopLeft] keeps the top left
+    /// corner of the existing content at the top left corner of the resized page.
+    /// Content falling outside the new page size will be clipped.
+    KeepContentPosition(PdfPageAnchor),
+}
+
+impl PdfPageResizeMode {
+    /// Resizes the given [PdfPage] to the given size, transforming all page objects,
+    /// annotations, and page boundary boxes according to this [PdfPageResizeMode].
+    pub(crate) fn apply(
+        &self,
+        page: &mut PdfPage,
+        size: PdfPagePaperSize,
+    ) -> Result<(), PdfiumError> {
+        let bounds = page
+            .boundaries()
+            .crop()
+            .or_else(|_| page.boundaries().media())?
+            .bounds;
+
+        let page_rotation = page.rotation().unwrap_or(PdfPageRenderRotation::None);
+
+        // The given size describes the page as it is displayed. We work in unrotated
+        // page space, so the target dimensions must be swapped for pages displayed
+        // in landscape orientation.
+
+        let (target_width, target_height) = match page_rotation {
+            PdfPageRenderRotation::Degrees90 | PdfPageRenderRotation::Degrees270 => {
+                (size.height(), size.width())
+            }
+            _ => (size.width(), size.height()),
+        };
+
+        let matrix = self.matrix(&bounds, target_width, target_height, page_rotation);
+
+        let target = PdfRect::new(
+            PdfPoints::ZERO,
+            PdfPoints::ZERO,
+            target_height,
+            target_width,
+        );
+
+        page.transform_all(matrix, Some(target))?;
+
+        // Boundary boxes are not part of the page content, so they must be
+        // transformed separately.
+
+        let boundaries = page.boundaries_mut();
+
+        if let Ok(art) = boundaries.art() {
+            boundaries.set_art(art.bounds.transform(matrix))?;
+        }
+
+        if let Ok(bleed) = boundaries.bleed() {
+            boundaries.set_bleed(bleed.bounds.transform(matrix))?;
+        }
+
+        if let Ok(trim) = boundaries.trim() {
+            boundaries.set_trim(trim.bounds.transform(matrix))?;
+        }
+
+        boundaries.set_media(target)?;
+        boundaries.set_crop(target)
+    }
+
+    /// Returns the transformation matrix that maps the existing page content, lying within
+    /// the given bounds, onto a page with the given unrotated dimensions.
+    fn matrix(
+        &self,
+        bounds: &PdfRect,
+        target_width: PdfPoints,
+        target_height: PdfPoints,
+        page_rotation: PdfPageRenderRotation,
+    ) -> PdfMatrix {
+        let (left, bottom) = (bounds.left().value, bounds.bottom().value);
+
+        let (source_width, source_height) = (
+            bounds.width().value.max(f32::EPSILON),
+            bounds.height().value.max(f32::EPSILON),
+        );
+
+        let (target_width, target_height) = (target_width.value, target_height.value);
+
+        match self {
+            PdfPageResizeMode::ScaleContentToFit => {
+                let scale = (target_width / source_width).min(target_height / source_height);
+
+                PdfMatrix::new(
+                    scale,
+                    0.0,
+                    0.0,
+                    scale,
+                    (target_width - source_width * scale) / 2.0 - left * scale,
+                    (target_height - source_height * scale) / 2.0 - bottom * scale,
+                )
+            }
+            PdfPageResizeMode::ScaleContentAnisotropic => {
+                let (scale_x, scale_y) =
+                    (target_width / source_width, target_height / source_height);
+
+                PdfMatrix::new(
+                    scale_x,
+                    0.0,
+                    0.0,
+                    scale_y,
+                    -left * scale_x,
+                    -bottom * scale_y,
+                )
+            }
+            PdfPageResizeMode::KeepContentPosition(anchor) => {
+                // Map the anchor position on the displayed page back into page space.
+
+                let (u, v) = anchor.as_fractions();
+
+                let (x, y) = match page_rotation {
+                    PdfPageRenderRotation::None => (u, v),
+                    PdfPageRenderRotation::Degrees90 => (1.0 - v, u),
+                    PdfPageRenderRotation::Degrees180 => (1.0 - u, 1.0 - v),
+                    PdfPageRenderRotation::Degrees270 => (v, 1.0 - u),
+                };
+
+                PdfMatrix::new(
+                    1.0,
+                    0.0,
+                    0.0,
+                    1.0,
+                    x * (target_width - source_width) - left,
+                    y * (target_height - source_height) - bottom,
+                )
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::utils::test::test_bind_to_pdfium;
+
+    #[test]
+    fn test_resize_scales_text() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let document = pdfium.load_pdf_from_file("./test/text-test.pdf", None)?;
+
+        let mut page = document.pages().get(0)?;
+
+        let bounds = page.boundaries().media()?.bounds;
+
+        let char_bounds = |page: &PdfPage| -> Result<Vec<PdfRect>, PdfiumError> {
+            let text = page.text()?;
+
+            let chars = text.chars();
+
+            (0..chars.len().min(50))
+                .map(|index| chars.get(index)?.loose_bounds())
+                .collect()
+        };
+
+        let before = char_bounds(&page)?;
+
+        assert!(!before.is_empty());
+
+        let (scale_x, scale_y) = (2.0, 1.5);
+
+        page.resize(
+            PdfPagePaperSize::new_custom(bounds.width() * scale_x, bounds.height() * scale_y),
+            PdfPageResizeMode::ScaleContentAnisotropic,
+        )?;
+
+        let resized = page.boundaries().media()?.bounds;
+
+        assert!((resized.width().value - bounds.width().value * scale_x).abs() < 0.01);
+        assert!((resized.height().value - bounds.height().value * scale_y).abs() < 0.01);
+
+        let after = char_bounds(&page)?;
+
+        assert_eq!(before.len(), after.len());
+
+        for (before, after) in before.iter().zip(after.iter()) {
+            let expected_left = (before.left() - bounds.left()).value * scale_x;
+
+            let expected_bottom = (before.bottom() - bounds.bottom()).value * scale_y;
+
+            assert!((after.left().value - expected_left).abs() < 0.5);
+            assert!((after.bottom().value - expected_bottom).abs() < 0.5);
+            assert!((after.width().value - before.width().value * scale_x).abs() < 0.5);
+            assert!((after.height().value - before.height().value * scale_y).abs() < 0.5);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_resize_rotated_page() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        let mut page = document
+            .pages_mut()
+            .create_page_at_end(PdfPagePaperSize::new_custom(
+                PdfPoints::new(200.0),
+                PdfPoints::new(100.0),
+            ))?;
+
+        page.objects_mut().create_path_object_rect(
+            PdfRect::new_from_values(0.0, 0.0, 10.0, 10.0),
+            None,
+            None,
+            Some(PdfColor::BLACK),
+        )?;
+
+        page.set_rotation(PdfPageRenderRotation::Degrees90);
+
+        // The page is displayed as 100 x 200 points. The bottom right corner of the displayed
+        // page is the top right corner of the unrotated page, so enlarging the page to
+        // 150 x 300 points while keeping that corner fixed should move the content by
+        // 100 points horizontally and 50 points vertically in unrotated page space.
+
+        page.resize(
+            PdfPagePaperSize::new_custom(PdfPoints::new(150.0), PdfPoints::new(300.0)),
+            PdfPageResizeMode::KeepContentPosition(PdfPageAnchor::BottomRight),
+        )?;
+
+        let media = page.boundaries().media()?.bounds;
+
+        assert_eq!(media.width(), PdfPoints::new(300.0));
+        assert_eq!(media.height(), PdfPoints::new(150.0));
+
+        let object_bounds = page.objects().get(0)?.bounds()?.to_rect();
+
+        assert!((object_bounds.left().value - 100.0).abs() < 0.01);
+        assert!((object_bounds.bottom().value - 50.0).abs() < 0.01);
+
+        Ok(())
+    }
+}
@@ -1,5 +1,6 @@
 //! Defines the [PdfPagePaperSize] enum, a set of common ANSI and ISO paper sizes.
 
+use crate::pdf::document::page::PdfPageOrientation;
 use crate::pdf::points::PdfPoints;
 use crate::pdf::rect::PdfRect;
 
@@ -165,9 +166,86 @@ pub enum PdfPagePaperStandardSize {
 
     /// North American architectural E size, 762 x 1067 mm / 30.0 x 42.0 in
     ArchE,
+
+    /// ISO DL envelope size, 110 x 220 mm / 4.33 x 8.66 in
+    EnvelopeDL,
+
+    /// ISO C6/C5 envelope size, 114 x 229 mm / 4.49 x 9.02 in
+    EnvelopeC6C5,
+
+    /// US No. 9 envelope size, 98 x 225 mm / 3.875 x 8.875 in
+    EnvelopeUS9,
+
+    /// US No. 10 envelope size, 105 x 241 mm / 4.125 x 9.5 in
+    EnvelopeUS10,
+
+    /// US Monarch envelope size, 98 x 191 mm / 3.875 x 7.5 in
+    EnvelopeMonarch,
 }
 
 impl PdfPagePaperStandardSize {
+    /// The standard paper sizes considered by [PdfPagePaperSize::closest_match()].
+    /// [PdfPagePaperStandardSize::A4R] is omitted, since it is identical to
+    /// [PdfPagePaperStandardSize::A4] in landscape orientation.
+    const CLASSIFIABLE: [PdfPagePaperStandardSize; 56] = [
+        PdfPagePaperStandardSize::USLetterAnsiA,
+        PdfPagePaperStandardSize::USHalfLetter,
+        PdfPagePaperStandardSize::USGovernmentLetter,
+        PdfPagePaperStandardSize::USLegal,
+        PdfPagePaperStandardSize::USJuniorLegal,
+        PdfPagePaperStandardSize::USGovernmentLegal,
+        PdfPagePaperStandardSize::USLedgerTabloidAnsiB,
+        PdfPagePaperStandardSize::A0x4,
+        PdfPagePaperStandardSize::A0x2,
+        PdfPagePaperStandardSize::A0,
+        PdfPagePaperStandardSize::A1,
+        PdfPagePaperStandardSize::A2,
+        PdfPagePaperStandardSize::A3,
+        PdfPagePaperStandardSize::A4,
+        PdfPagePaperStandardSize::A5,
+        PdfPagePaperStandardSize::A6,
+        PdfPagePaperStandardSize::A7,
+        PdfPagePaperStandardSize::A8,
+        PdfPagePaperStandardSize::A9,
+        PdfPagePaperStandardSize::A10,
+        PdfPagePaperStandardSize::B0,
+        PdfPagePaperStandardSize::B1,
+        PdfPagePaperStandardSize::B2,
+        PdfPagePaperStandardSize::B3,
+        PdfPagePaperStandardSize::B4,
+        PdfPagePaperStandardSize::B5,
+        PdfPagePaperStandardSize::B6,
+        PdfPagePaperStandardSize::B7,
+        PdfPagePaperStandardSize::B8,
+        PdfPagePaperStandardSize::B9,
+        PdfPagePaperStandardSize::B10,
+        PdfPagePaperStandardSize::C0,
+        PdfPagePaperStandardSize::C1,
+        PdfPagePaperStandardSize::C2,
+        PdfPagePaperStandardSize::C3,
+        PdfPagePaperStandardSize::C4,
+        PdfPagePaperStandardSize::C5,
+        PdfPagePaperStandardSize::C6,
+        PdfPagePaperStandardSize::C7,
+        PdfPagePaperStandardSize::C8,
+        PdfPagePaperStandardSize::C9,
+        PdfPagePaperStandardSize::C10,
+        PdfPagePaperStandardSize::AnsiBPlus,
+        PdfPagePaperStandardSize::AnsiC,
+        PdfPagePaperStandardSize::AnsiD,
+        PdfPagePaperStandardSize::AnsiE,
+        PdfPagePaperStandardSize::ArchA,
+        PdfPagePaperStandardSize::ArchB,
+        PdfPagePaperStandardSize::ArchC,
+        PdfPagePaperStandardSize::ArchD,
+        PdfPagePaperStandardSize::ArchE,
+        PdfPagePaperStandardSize::EnvelopeDL,
+        PdfPagePaperStandardSize::EnvelopeC6C5,
+        PdfPagePaperStandardSize::EnvelopeUS9,
+        PdfPagePaperStandardSize::EnvelopeUS10,
+        PdfPagePaperStandardSize::EnvelopeMonarch,
+    ];
+
     /// Returns the [PdfPagePaperStandardSize] variant, if any, that exactly matches the
     /// given dimensions in millimeters.
     pub fn from_mm_dimensions(width: u32, height: u32) -> Option<PdfPagePaperStandardSize> {
@@ -224,124 +302,143 @@ impl PdfPagePaperStandardSize {
             (457, 610) => Some(PdfPagePaperStandardSize::ArchC),
             (610, 914) => Some(PdfPagePaperStandardSize::ArchD),
             (762, 1067) => Some(PdfPagePaperStandardSize::ArchE),
+            (110, 220) => Some(PdfPagePaperStandardSize::EnvelopeDL),
+            (114, 229) => Some(PdfPagePaperStandardSize::EnvelopeC6C5),
+            (98, 225) => Some(PdfPagePaperStandardSize::EnvelopeUS9),
+            (105, 241) => Some(PdfPagePaperStandardSize::EnvelopeUS10),
+            (98, 191) => Some(PdfPagePaperStandardSize::EnvelopeMonarch),
             _ => None,
         }
     }
 
     /// Returns the width of this [PdfPagePaperStandardSize] in portrait orientation.
+    /// Sizes defined in inches, such as US Letter, are returned exactly; ISO sizes are
+    /// defined in whole millimeters.
     pub fn width(&self) -> PdfPoints {
-        PdfPoints::from_mm(match self {
-            PdfPagePaperStandardSize::USLetterAnsiA => 216.0,
-            PdfPagePaperStandardSize::USHalfLetter => 140.0,
-            PdfPagePaperStandardSize::USGovernmentLetter => 203.0,
-            PdfPagePaperStandardSize::USLegal => 216.0,
-            PdfPagePaperStandardSize::USJuniorLegal => 127.0,
-            PdfPagePaperStandardSize::USGovernmentLegal => 216.0,
-            PdfPagePaperStandardSize::USLedgerTabloidAnsiB => 279.0,
-            PdfPagePaperStandardSize::A0x4 => 1682.0,
-            PdfPagePaperStandardSize::A0x2 => 1189.0,
-            PdfPagePaperStandardSize::A0 => 841.0,
-            PdfPagePaperStandardSize::A1 => 594.0,
-            PdfPagePaperStandardSize::A2 => 420.0,
-            PdfPagePaperStandardSize::A3 => 297.0,
-            PdfPagePaperStandardSize::A4 => 210.0,
-            PdfPagePaperStandardSize::A4R => 297.0,
-            PdfPagePaperStandardSize::A5 => 148.0,
-            PdfPagePaperStandardSize::A6 => 105.0,
-            PdfPagePaperStandardSize::A7 => 74.0,
-            PdfPagePaperStandardSize::A8 => 52.0,
-            PdfPagePaperStandardSize::A9 => 37.0,
-            PdfPagePaperStandardSize::A10 => 26.0,
-            PdfPagePaperStandardSize::B0 => 1000.0,
-            PdfPagePaperStandardSize::B1 => 707.0,
-            PdfPagePaperStandardSize::B2 => 500.0,
-            PdfPagePaperStandardSize::B3 => 353.0,
-            PdfPagePaperStandardSize::B4 => 250.0,
-            PdfPagePaperStandardSize::B5 => 176.0,
-            PdfPagePaperStandardSize::B6 => 125.0,
-            PdfPagePaperStandardSize::B7 => 88.0,
-            PdfPagePaperStandardSize::B8 => 62.0,
-            PdfPagePaperStandardSize::B9 => 44.0,
-            PdfPagePaperStandardSize::B10 => 31.0,
-            PdfPagePaperStandardSize::C0 => 917.0,
-            PdfPagePaperStandardSize::C1 => 648.0,
-            PdfPagePaperStandardSize::C2 => 458.0,
-            PdfPagePaperStandardSize::C3 => 324.0,
-            PdfPagePaperStandardSize::C4 => 229.0,
-            PdfPagePaperStandardSize::C5 => 162.0,
-            PdfPagePaperStandardSize::C6 => 114.0,
-            PdfPagePaperStandardSize::C7 => 81.0,
-            PdfPagePaperStandardSize::C8 => 57.0,
-            PdfPagePaperStandardSize::C9 => 40.0,
-            PdfPagePaperStandardSize::C10 => 28.0,
-            PdfPagePaperStandardSize::AnsiBPlus => 330.0,
-            PdfPagePaperStandardSize::AnsiC => 432.0,
-            PdfPagePaperStandardSize::AnsiD => 559.0,
-            PdfPagePaperStandardSize::AnsiE => 864.0,
-            PdfPagePaperStandardSize::ArchA => 229.0,
-            PdfPagePaperStandardSize::ArchB => 305.0,
-            PdfPagePaperStandardSize::ArchC => 457.0,
-            PdfPagePaperStandardSize::ArchD => 610.0,
-            PdfPagePaperStandardSize::ArchE => 762.0,
-        })
+        match self {
+            PdfPagePaperStandardSize::USLetterAnsiA => PdfPoints::from_inches(8.5),
+            PdfPagePaperStandardSize::USHalfLetter => PdfPoints::from_inches(5.5),
+            PdfPagePaperStandardSize::USGovernmentLetter => PdfPoints::from_inches(8.0),
+            PdfPagePaperStandardSize::USLegal => PdfPoints::from_inches(8.5),
+            PdfPagePaperStandardSize::USJuniorLegal => PdfPoints::from_inches(5.0),
+            PdfPagePaperStandardSize::USGovernmentLegal => PdfPoints::from_inches(8.5),
+            PdfPagePaperStandardSize::USLedgerTabloidAnsiB => PdfPoints::from_inches(11.0),
+            PdfPagePaperStandardSize::A0x4 => PdfPoints::from_mm(1682.0),
+            PdfPagePaperStandardSize::A0x2 => PdfPoints::from_mm(1189.0),
+            PdfPagePaperStandardSize::A0 => PdfPoints::from_mm(841.0),
+            PdfPagePaperStandardSize::A1 => PdfPoints::from_mm(594.0),
+            PdfPagePaperStandardSize::A2 => PdfPoints::from_mm(420.0),
+            PdfPagePaperStandardSize::A3 => PdfPoints::from_mm(297.0),
+            PdfPagePaperStandardSize::A4 => PdfPoints::from_mm(210.0),
+            PdfPagePaperStandardSize::A4R => PdfPoints::from_mm(297.0),
+            PdfPagePaperStandardSize::A5 => PdfPoints::from_mm(148.0),
+            PdfPagePaperStandardSize::A6 => PdfPoints::from_mm(105.0),
+            PdfPagePaperStandardSize::A7 => PdfPoints::from_mm(74.0),
+            PdfPagePaperStandardSize::A8 => PdfPoints::from_mm(52.0),
+            PdfPagePaperStandardSize::A9 => PdfPoints::from_mm(37.0),
+            PdfPagePaperStandardSize::A10 => PdfPoints::from_mm(26.0),
+            PdfPagePaperStandardSize::B0 => PdfPoints::from_mm(1000.0),
+            PdfPagePaperStandardSize::B1 => PdfPoints::from_mm(707.0),
+            PdfPagePaperStandardSize::B2 => PdfPoints::from_mm(500.0),
+            PdfPagePaperStandardSize::B3 => PdfPoints::from_mm(353.0),
+            PdfPagePaperStandardSize::B4 => PdfPoints::from_mm(250.0),
+            PdfPagePaperStandardSize::B5 => PdfPoints::from_mm(176.0),
+            PdfPagePaperStandardSize::B6 => PdfPoints::from_mm(125.0),
+            PdfPagePaperStandardSize::B7 => PdfPoints::from_mm(88.0),
+            PdfPagePaperStandardSize::B8 => PdfPoints::from_mm(62.0),
+            PdfPagePaperStandardSize::B9 => PdfPoints::from_mm(44.0),
+            PdfPagePaperStandardSize::B10 => PdfPoints::from_mm(31.0),
+            PdfPagePaperStandardSize::C0 => PdfPoints::from_mm(917.0),
+            PdfPagePaperStandardSize::C1 => PdfPoints::from_mm(648.0),
+            PdfPagePaperStandardSize::C2 => PdfPoints::from_mm(458.0),
+            PdfPagePaperStandardSize::C3 => PdfPoints::from_mm(324.0),
+            PdfPagePaperStandardSize::C4 => PdfPoints::from_mm(229.0),
+            PdfPagePaperStandardSize::C5 => PdfPoints::from_mm(162.0),
+            PdfPagePaperStandardSize::C6 => PdfPoints::from_mm(114.0),
+            PdfPagePaperStandardSize::C7 => PdfPoints::from_mm(81.0),
+            PdfPagePaperStandardSize::C8 => PdfPoints::from_mm(57.0),
+            PdfPagePaperStandardSize::C9 => PdfPoints::from_mm(40.0),
+            PdfPagePaperStandardSize::C10 => PdfPoints::from_mm(28.0),
+            PdfPagePaperStandardSize::AnsiBPlus => PdfPoints::from_inches(13.0),
+            PdfPagePaperStandardSize::AnsiC => PdfPoints::from_inches(17.0),
+            PdfPagePaperStandardSize::AnsiD => PdfPoints::from_inches(22.0),
+            PdfPagePaperStandardSize::AnsiE => PdfPoints::from_inches(34.0),
+            PdfPagePaperStandardSize::ArchA => PdfPoints::from_inches(9.0),
+            PdfPagePaperStandardSize::ArchB => PdfPoints::from_inches(12.0),
+            PdfPagePaperStandardSize::ArchC => PdfPoints::from_inches(18.0),
+            PdfPagePaperStandardSize::ArchD => PdfPoints::from_inches(24.0),
+            PdfPagePaperStandardSize::ArchE => PdfPoints::from_inches(30.0),
+            PdfPagePaperStandardSize::EnvelopeDL => PdfPoints::from_mm(110.0),
+            PdfPagePaperStandardSize::EnvelopeC6C5 => PdfPoints::from_mm(114.0),
+            PdfPagePaperStandardSize::EnvelopeUS9 => PdfPoints::from_inches(3.875),
+            PdfPagePaperStandardSize::EnvelopeUS10 => PdfPoints::from_inches(4.125),
+            PdfPagePaperStandardSize::EnvelopeMonarch => PdfPoints::from_inches(3.875),
+        }
     }
 
     /// Returns the height of this [PdfPagePaperStandardSize] in portrait orientation.
+    /// Sizes defined in inches, such as US Letter, are returned exactly; ISO sizes are
+    /// defined in whole millimeters.
     pub fn height(&self) -> PdfPoints {
-        PdfPoints::from_mm(match self {
-            PdfPagePaperStandardSize::USLetterAnsiA => 279.0,
-            PdfPagePaperStandardSize::USHalfLetter => 216.0,
-            PdfPagePaperStandardSize::USGovernmentLetter => 254.0,
-            PdfPagePaperStandardSize::USLegal => 356.0,
-            PdfPagePaperStandardSize::USJuniorLegal => 203.0,
-            PdfPagePaperStandardSize::USGovernmentLegal => 330.0,
-            PdfPagePaperStandardSize::USLedgerTabloidAnsiB => 432.0,
-            PdfPagePaperStandardSize::A0x4 => 2378.0,
-            PdfPagePaperStandardSize::A0x2 => 1682.0,
-            PdfPagePaperStandardSize::A0 => 1189.0,
-            PdfPagePaperStandardSize::A1 => 841.0,
-            PdfPagePaperStandardSize::A2 => 594.0,
-            PdfPagePaperStandardSize::A3 => 420.0,
-            PdfPagePaperStandardSize::A4 => 297.0,
-            PdfPagePaperStandardSize::A4R => 210.0,
-            PdfPagePaperStandardSize::A5 => 210.0,
-            PdfPagePaperStandardSize::A6 => 148.0,
-            PdfPagePaperStandardSize::A7 => 105.0,
-            PdfPagePaperStandardSize::A8 => 74.0,
-            PdfPagePaperStandardSize::A9 => 52.0,
-            PdfPagePaperStandardSize::A10 => 37.0,
-            PdfPagePaperStandardSize::B0 => 1414.0,
-            PdfPagePaperStandardSize::B1 => 1000.0,
-            PdfPagePaperStandardSize::B2 => 707.0,
-            PdfPagePaperStandardSize::B3 => 500.0,
-            PdfPagePaperStandardSize::B4 => 353.0,
-            PdfPagePaperStandardSize::B5 => 250.0,
-            PdfPagePaperStandardSize::B6 => 176.0,
-            PdfPagePaperStandardSize::B7 => 125.0,
-            PdfPagePaperStandardSize::B8 => 88.0,
-            PdfPagePaperStandardSize::B9 => 62.0,
-            PdfPagePaperStandardSize::B10 => 44.0,
-            PdfPagePaperStandardSize::C0 => 1297.0,
-            PdfPagePaperStandardSize::C1 => 917.0,
-            PdfPagePaperStandardSize::C2 => 648.0,
-            PdfPagePaperStandardSize::C3 => 458.0,
-            PdfPagePaperStandardSize::C4 => 324.0,
-            PdfPagePaperStandardSize::C5 => 229.0,
-            PdfPagePaperStandardSize::C6 => 162.0,
-            PdfPagePaperStandardSize::C7 => 114.0,
-            PdfPagePaperStandardSize::C8 => 81.0,
-            PdfPagePaperStandardSize::C9 => 57.0,
-            PdfPagePaperStandardSize::C10 => 40.0,
-            PdfPagePaperStandardSize::AnsiBPlus => 483.0,
-            PdfPagePaperStandardSize::AnsiC => 559.0,
-            PdfPagePaperStandardSize::AnsiD => 864.0,
-            PdfPagePaperStandardSize::AnsiE => 1118.0,
-            PdfPagePaperStandardSize::ArchA => 305.0,
-            PdfPagePaperStandardSize::ArchB => 457.0,
-            PdfPagePaperStandardSize::ArchC => 610.0,
-            PdfPagePaperStandardSize::ArchD => 914.0,
-            PdfPagePaperStandardSize::ArchE => 1067.0,
-        })
+        match self {
+            PdfPagePaperStandardSize::USLetterAnsiA => PdfPoints::from_inches(11.0),
+            PdfPagePaperStandardSize::USHalfLetter => PdfPoints::from_inches(8.5),
+            PdfPagePaperStandardSize::USGovernmentLetter => PdfPoints::from_inches(10.0),
+            PdfPagePaperStandardSize::USLegal => PdfPoints::from_inches(14.0),
+            PdfPagePaperStandardSize::USJuniorLegal => PdfPoints::from_inches(8.0),
+            PdfPagePaperStandardSize::USGovernmentLegal => PdfPoints::from_inches(13.0),
+            PdfPagePaperStandardSize::USLedgerTabloidAnsiB => PdfPoints::from_inches(17.0),
+            PdfPagePaperStandardSize::A0x4 => PdfPoints::from_mm(2378.0),
+            PdfPagePaperStandardSize::A0x2 => PdfPoints::from_mm(1682.0),
+            PdfPagePaperStandardSize::A0 => PdfPoints::from_mm(1189.0),
+            PdfPagePaperStandardSize::A1 => PdfPoints::from_mm(841.0),
+            PdfPagePaperStandardSize::A2 => PdfPoints::from_mm(594.0),
+            PdfPagePaperStandardSize::A3 => PdfPoints::from_mm(420.0),
+            PdfPagePaperStandardSize::A4 => PdfPoints::from_mm(297.0),
+            PdfPagePaperStandardSize::A4R => PdfPoints::from_mm(210.0),
+            PdfPagePaperStandardSize::A5 => PdfPoints::from_mm(210.0),
+            PdfPagePaperStandardSize::A6 => PdfPoints::from_mm(148.0),
+            PdfPagePaperStandardSize::A7 => PdfPoints::from_mm(105.0),
+            PdfPagePaperStandardSize::A8 => PdfPoints::from_mm(74.0),
+            PdfPagePaperStandardSize::A9 => PdfPoints::from_mm(52.0),
+            PdfPagePaperStandardSize::A10 => PdfPoints::from_mm(37.0),
+            PdfPagePaperStandardSize::B0 => PdfPoints::from_mm(1414.0),
+            PdfPagePaperStandardSize::B1 => PdfPoints::from_mm(1000.0),
+            PdfPagePaperStandardSize::B2 => PdfPoints::from_mm(707.0),
+            PdfPagePaperStandardSize::B3 => PdfPoints::from_mm(500.0),
+            PdfPagePaperStandardSize::B4 => PdfPoints::from_mm(353.0),
+            PdfPagePaperStandardSize::B5 => PdfPoints::from_mm(250.0),
+            PdfPagePaperStandardSize::B6 => PdfPoints::from_mm(176.0),
+            PdfPagePaperStandardSize::B7 => PdfPoints::from_mm(125.0),
+            PdfPagePaperStandardSize::B8 => PdfPoints::from_mm(88.0),
+            PdfPagePaperStandardSize::B9 => PdfPoints::from_mm(62.0),
+            PdfPagePaperStandardSize::B10 => PdfPoints::from_mm(44.0),
+            PdfPagePaperStandardSize::C0 => PdfPoints::from_mm(1297.0),
+            PdfPagePaperStandardSize::C1 => PdfPoints::from_mm(917.0),
+            PdfPagePaperStandardSize::C2 => PdfPoints::from_mm(648.0),
+            PdfPagePaperStandardSize::C3 => PdfPoints::from_mm(458.0),
+            PdfPagePaperStandardSize::C4 => PdfPoints::from_mm(324.0),
+            PdfPagePaperStandardSize::C5 => PdfPoints::from_mm(229.0),
+            PdfPagePaperStandardSize::C6 => PdfPoints::from_mm(162.0),
+            PdfPagePaperStandardSize::C7 => PdfPoints::from_mm(114.0),
+            PdfPagePaperStandardSize::C8 => PdfPoints::from_mm(81.0),
+            PdfPagePaperStandardSize::C9 => PdfPoints::from_mm(57.0),
+            PdfPagePaperStandardSize::C10 => PdfPoints::from_mm(40.0),
+            PdfPagePaperStandardSize::AnsiBPlus => PdfPoints::from_inches(19.0),
+            PdfPagePaperStandardSize::AnsiC => PdfPoints::from_inches(22.0),
+            PdfPagePaperStandardSize::AnsiD => PdfPoints::from_inches(34.0),
+            PdfPagePaperStandardSize::AnsiE => PdfPoints::from_inches(44.0),
+            PdfPagePaperStandardSize::ArchA => PdfPoints::from_inches(12.0),
+            PdfPagePaperStandardSize::ArchB => PdfPoints::from_inches(18.0),
+            PdfPagePaperStandardSize::ArchC => PdfPoints::from_inches(24.0),
+            PdfPagePaperStandardSize::ArchD => PdfPoints::from_inches(36.0),
+            PdfPagePaperStandardSize::ArchE => PdfPoints::from_inches(42.0),
+            PdfPagePaperStandardSize::EnvelopeDL => PdfPoints::from_mm(220.0),
+            PdfPagePaperStandardSize::EnvelopeC6C5 => PdfPoints::from_mm(229.0),
+            PdfPagePaperStandardSize::EnvelopeUS9 => PdfPoints::from_inches(8.875),
+            PdfPagePaperStandardSize::EnvelopeUS10 => PdfPoints::from_inches(9.5),
+            PdfPagePaperStandardSize::EnvelopeMonarch => PdfPoints::from_inches(7.5),
+        }
     }
 }
 
@@ -359,6 +456,10 @@ pub enum PdfPagePaperSize {
 }
 
 impl PdfPagePaperSize {
+    /// The tolerance used by [PdfPage::paper_size()] when matching the dimensions of a page
+    /// to a standard paper size using [PdfPagePaperSize::closest_match()].
+    pub const DEFAULT_MATCH_TOLERANCE: PdfPoints = PdfPoints::new(1.0);
+
     /// Returns the [PdfPagePaperSize] matching the given dimensions,
     /// or [PdfPagePaperSize::Custom] if no match can be made.
     #[inline]
@@ -408,6 +509,62 @@ impl PdfPagePaperSize {
         Self::from_points(PdfPoints::from_mm(width), PdfPoints::from_mm(height))
     }
 
+    /// Creates a new custom [PdfPagePaperSize] from the given dimensions in millimeters.
+    /// Unlike [PdfPagePaperSize::from_mm()], no attempt is made to match the given dimensions
+    /// to a standard paper size.
+    #[inline]
+    pub fn custom_mm(width: f32, height: f32) -> Self {
+        Self::new_custom(PdfPoints::from_mm(width), PdfPoints::from_mm(height))
+    }
+
+    /// Returns the standard paper size, in either portrait or landscape orientation, whose
+    /// dimensions most closely match the given width and height, so long as neither dimension
+    /// differs from the given dimensions by more than the given tolerance. Returns `None`
+    /// if no standard paper size is within the given tolerance.
+    ///
+    /// Page dimensions in real-world documents rarely match standard paper sizes exactly;
+    /// for example, documents produced by scanners often have dimensions a fraction of a point
+    /// larger or smaller than the paper that was scanned. A tolerance of
+    /// [PdfPagePaperSize::DEFAULT_MATCH_TOLERANCE] is a reasonable choice for most documents.
+    ///
+    /// ISO A, B, and C series sizes, US and ANSI sizes, North American architectural sizes,
+    /// and common envelope sizes are considered.
+    pub fn closest_match(
+        width: PdfPoints,
+        height: PdfPoints,
+        tolerance: PdfPoints,
+    ) -> Option<(PdfPagePaperSize, PdfPageOrientation)> {
+        let tolerance = tolerance.abs().value;
+
+        let mut result: Option<(PdfPagePaperSize, PdfPageOrientation, f32)> = None;
+
+        for size in PdfPagePaperStandardSize::CLASSIFIABLE.iter() {
+            for (candidate, orientation) in [
+                (
+                    PdfPagePaperSize::Portrait(*size),
+                    PdfPageOrientation::Portrait,
+                ),
+                (
+                    PdfPagePaperSize::Landscape(*size),
+                    PdfPageOrientation::Landscape,
+                ),
+            ] {
+                let difference = (candidate.width() - width)
+                    .abs()
+                    .value
+                    .max((candidate.height() - height).abs().value);
+
+                if difference <= tolerance
+                    && result.map_or(true, |(_, _, closest)| difference < closest)
+                {
+                    result = Some((candidate, orientation, difference));
+                }
+            }
+        }
+
+        result.map(|(size, orientation, _)| (size, orientation))
+    }
+
     /// Creates a new portrait [PdfPagePaperSize] from a standard [PdfPagePaperStandardSize].
     #[inline]
     pub fn new_portrait(size: PdfPagePaperStandardSize) -> Self {
@@ -513,6 +670,12 @@ impl PdfPagePaperSize {
         }
     }
 
+    /// Returns the width and height of this [PdfPagePaperSize].
+    #[inline]
+    pub fn dimensions(&self) -> (PdfPoints, PdfPoints) {
+        (self.width(), self.height())
+    }
+
     /// Returns the dimensions of this [PdfPagePaperSize] as a [PdfRect].
     #[inline]
     pub fn as_rect(&self) -> PdfRect {
@@ -524,3 +687,225 @@ impl PdfPagePaperSize {
         )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::utils::test::test_bind_to_pdfium;
+
+    #[test]
+    fn test_closest_match() {
+        let tolerance = PdfPagePaperSize::DEFAULT_MATCH_TOLERANCE;
+
+        // Exact sizes.
+
+        assert_eq!(
+            PdfPagePaperSize::closest_match(
+                PdfPoints::new(612.0),
+                PdfPoints::new(792.0),
+                tolerance
+            ),
+            Some((
+                PdfPagePaperSize::Portrait(PdfPagePaperStandardSize::USLetterAnsiA),
+                PdfPageOrientation::Portrait
+            ))
+        );
+
+        assert_eq!(
+            PdfPagePaperSize::closest_match(
+                PdfPoints::new(792.0),
+                PdfPoints::new(612.0),
+                tolerance
+            ),
+            Some((
+                PdfPagePaperSize::Landscape(PdfPagePaperStandardSize::USLetterAnsiA),
+                PdfPageOrientation::Landscape
+            ))
+        );
+
+        // Scanned pages are often a fraction of a point larger or smaller than the paper.
+
+        assert_eq!(
+            PdfPagePaperSize::closest_match(
+                PdfPoints::new(595.2),
+                PdfPoints::new(841.92),
+                tolerance
+            ),
+            Some((
+                PdfPagePaperSize::Portrait(PdfPagePaperStandardSize::A4),
+                PdfPageOrientation::Portrait
+            ))
+        );
+
+        assert_eq!(
+            PdfPagePaperSize::closest_match(
+                PdfPoints::new(1008.4),
+                PdfPoints::new(611.6),
+                tolerance
+            ),
+            Some((
+                PdfPagePaperSize::Landscape(PdfPagePaperStandardSize::USLegal),
+                PdfPageOrientation::Landscape
+            ))
+        );
+
+        // A4 landscape is reported as such, rather than as A4R.
+
+        assert_eq!(
+            PdfPagePaperSize::closest_match(
+                PdfPoints::new(842.0),
+                PdfPoints::new(595.0),
+                tolerance
+            ),
+            Some((
+                PdfPagePaperSize::Landscape(PdfPagePaperStandardSize::A4),
+                PdfPageOrientation::Landscape
+            ))
+        );
+
+        // Envelopes.
+
+        assert_eq!(
+            PdfPagePaperSize::closest_match(
+                PdfPoints::new(684.0),
+                PdfPoints::new(297.0),
+                tolerance
+            ),
+            Some((
+                PdfPagePaperSize::Landscape(PdfPagePaperStandardSize::EnvelopeUS10),
+                PdfPageOrientation::Landscape
+            ))
+        );
+
+        assert_eq!(
+            PdfPagePaperSize::closest_match(
+                PdfPoints::from_mm(110.0),
+                PdfPoints::from_mm(220.0),
+                tolerance
+            ),
+            Some((
+                PdfPagePaperSize::Portrait(PdfPagePaperStandardSize::EnvelopeDL),
+                PdfPageOrientation::Portrait
+            ))
+        );
+
+        // Sizes outside the tolerance are not matched.
+
+        assert_eq!(
+            PdfPagePaperSize::closest_match(
+                PdfPoints::new(614.0),
+                PdfPoints::new(792.0),
+                tolerance
+            ),
+            None
+        );
+
+        assert_eq!(
+            PdfPagePaperSize::closest_match(
+                PdfPoints::new(614.0),
+                PdfPoints::new(792.0),
+                PdfPoints::new(3.0)
+            )
+            .map(|(size, _)| size),
+            Some(PdfPagePaperSize::Portrait(
+                PdfPagePaperStandardSize::USLetterAnsiA
+            ))
+        );
+
+        assert_eq!(
+            PdfPagePaperSize::closest_match(
+                PdfPoints::new(224.0),
+                PdfPoints::new(792.0),
+                tolerance
+            ),
+            None
+        );
+    }
+
+    #[test]
+    fn test_custom_sizes_and_orientation() {
+        let size = PdfPagePaperSize::custom_mm(300.0, 100.0);
+
+        assert!(matches!(size, PdfPagePaperSize::Custom(_, _)));
+        assert!((size.width().to_mm() - 300.0).abs() < 0.01);
+        assert!((size.height().to_mm() - 100.0).abs() < 0.01);
+
+        let (width, height) = size.portrait().dimensions();
+
+        assert_eq!((width, height), (size.height(), size.width()));
+        assert_eq!(size.landscape(), size);
+
+        // Custom sizes are never matched to standard sizes, even if they are identical.
+
+        assert!(matches!(
+            PdfPagePaperSize::custom_mm(210.0, 297.0),
+            PdfPagePaperSize::Custom(_, _)
+        ));
+
+        let letter = PdfPagePaperSize::new_portrait(PdfPagePaperStandardSize::USLetterAnsiA);
+
+        assert_eq!(
+            letter.dimensions(),
+            (PdfPoints::new(612.0), PdfPoints::new(792.0))
+        );
+        assert_eq!(
+            letter.landscape().dimensions(),
+            (PdfPoints::new(792.0), PdfPoints::new(612.0))
+        );
+        assert_eq!(letter.landscape().portrait(), letter);
+    }
+
+    #[test]
+    fn test_page_paper_size() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        let mut page = document
+            .pages_mut()
+            .create_page_at_end(PdfPagePaperSize::new_portrait(
+                PdfPagePaperStandardSize::USLetterAnsiA,
+            ))?;
+
+        assert_eq!(
+            page.paper_size(),
+            PdfPagePaperSize::Portrait(PdfPagePaperStandardSize::USLetterAnsiA)
+        );
+
+        // An intrinsic page rotation changes the orientation of the page.
+
+        page.set_rotation(PdfPageRenderRotation::Degrees90);
+
+        assert_eq!(
+            page.paper_size(),
+            PdfPagePaperSize::Landscape(PdfPagePaperStandardSize::USLetterAnsiA)
+        );
+
+        // A scanned A4 page, slightly smaller than A4.
+
+        let page = document
+            .pages_mut()
+            .create_page_at_end(PdfPagePaperSize::new_custom(
+                PdfPoints::new(594.96),
+                PdfPoints::new(841.68),
+            ))?;
+
+        assert_eq!(
+            page.paper_size(),
+            PdfPagePaperSize::Portrait(PdfPagePaperStandardSize::A4)
+        );
+
+        // A narrow strip that is not a standard paper size.
+
+        let page = document
+            .pages_mut()
+            .create_page_at_end(PdfPagePaperSize::new_custom(
+                PdfPoints::new(224.0),
+                PdfPoints::new(792.0),
+            ))?;
+
+        assert!(matches!(page.paper_size(), PdfPagePaperSize::Custom(_, _)));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,148 @@
+//! Defines the [PdfStructTree] struct, exposing functionality related to the logical
+//! structure tree of a single tagged `PdfPage`.
+
+pub mod element;
+
+use crate::bindgen::FPDF_STRUCTTREE;
+use crate::bindings::PdfiumLibraryBindings;
+use crate::error::PdfiumError;
+use crate::pdf::document::page::struct_tree::element::{
+    PdfStructElement, PdfStructElementIndex, PdfStructElementsIterator,
+    PdfStructElementsRecursiveIterator,
+};
+use std::os::raw::c_int;
+
+/// The logical structure tree of a single tagged `PdfPage`, as described in Section 10.6
+/// of the PDF Reference Manual, version 1.7, on page 856.
+///
+/// The structure tree describes the logical organization of the page content, for example
+/// headings, paragraphs, tables, and figures, and is used by accessibility tools to present
+/// the content in a meaningful order. Pages that are not tagged have an empty structure tree.
+///
+/// Each [PdfStructElement] in the tree is only valid for as long as the [PdfStructTree]
+/// containing it remains in scope.
+pub struct PdfStructTree<'a> {
+    handle: FPDF_STRUCTTREE,
+    bindings: &'a dyn PdfiumLibraryBindings,
+}
+
+impl<'a> PdfStructTree<'a> {
+    #[inline]
+    pub(crate) fn from_pdfium(
+        handle: FPDF_STRUCTTREE,
+        bindings: &'a dyn PdfiumLibraryBindings,
+    ) -> Self {
+        PdfStructTree { handle, bindings }
+    }
+
+    /// Returns the [PdfiumLibraryBindings] used by this [PdfStructTree].
+    #[inline]
+    pub fn bindings(&self) -> &'a dyn PdfiumLibraryBindings {
+        self.bindings
+    }
+
+    /// Returns the number of top-level elements in this [PdfStructTree].
+    #[inline]
+    pub fn len(&self) -> PdfStructElementIndex {
+        self.bindings
+            .FPDF_StructTree_CountChildren(self.handle)
+            .max(0) as PdfStructElementIndex
+    }
+
+    /// Returns `true` if this [PdfStructTree] contains no elements.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the top-level [PdfStructElement] at the given index in this [PdfStructTree].
+    pub fn get(&self, index: PdfStructElementIndex) -> Result<PdfStructElement<'_>, PdfiumError> {
+        if index >= self.len() {
+            return Err(PdfiumError::StructElementIndexOutOfBounds);
+        }
+
+        let handle = self
+            .bindings
+            .FPDF_StructTree_GetChildAtIndex(self.handle, index as c_int);
+
+        if handle.is_null() {
+            Err(PdfiumError::StructElementIndexOutOfBounds)
+        } else {
+            Ok(PdfStructElement::from_pdfium(handle, self.bindings))
+        }
+    }
+
+    /// Returns an iterator over the top-level elements in this [PdfStructTree].
+    #[inline]
+    pub fn children(&self) -> PdfStructElementsIterator<'_> {
+        PdfStructElementsIterator::for_tree(self.handle, self.bindings)
+    }
+
+    /// Returns an iterator over every element in this [PdfStructTree], visiting each element
+    /// before its children in depth-first order.
+    #[inline]
+    pub fn descendants(&self) -> PdfStructElementsRecursiveIterator<'_> {
+        PdfStructElementsRecursiveIterator::new(self.children())
+    }
+}
+
+impl<'a> Drop for PdfStructTree<'a> {
+    /// Closes this [PdfStructTree], releasing held memory.
+    #[inline]
+    fn drop(&mut self) {
+        self.bindings.FPDF_StructTree_Close(self.handle);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::utils::test::test_bind_to_pdfium;
+
+    #[test]
+    fn test_struct_tree() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let document = pdfium.load_pdf_from_file("./test/struct-tree-test.pdf", None)?;
+
+        let page = document.pages().get(0)?;
+
+        let tree = page.struct_tree()?;
+
+        assert_eq!(tree.len(), 1);
+
+        let root = tree.get(0)?;
+
+        assert_eq!(root.element_type().as_deref(), Some("Document"));
+        assert_eq!(root.len(), 2);
+        assert!(root.parent().is_none());
+
+        let types = tree
+            .descendants()
+            .map(|element| element.element_type().unwrap_or_default())
+            .collect::<Vec<_>>();
+
+        assert_eq!(types, vec!["Document", "H1", "Figure"]);
+
+        let heading = root.get(0)?;
+
+        assert_eq!(heading.title().as_deref(), Some("Heading"));
+        assert_eq!(heading.lang().as_deref(), Some("en-GB"));
+        assert_eq!(heading.marked_content_ids(), vec![0]);
+
+        let figure = tree
+            .descendants()
+            .find(|element| element.element_type().as_deref() == Some("Figure"))
+            .unwrap();
+
+        assert_eq!(figure.alt_text().as_deref(), Some("A red square"));
+        assert_eq!(figure.id().as_deref(), Some("figure-1"));
+        assert_eq!(figure.marked_content_ids(), vec![1]);
+        assert_eq!(
+            figure.parent().and_then(|parent| parent.element_type()),
+            Some("Document".to_string())
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,292 @@
+//! Defines the [PdfStructElement] struct, exposing functionality related to a single
+//! element in a `PdfStructTree`.
+
+use crate::bindgen::{FPDF_STRUCTELEMENT, FPDF_STRUCTTREE};
+use crate::bindings::PdfiumLibraryBindings;
+use crate::error::PdfiumError;
+use crate::utils::mem::create_byte_buffer;
+use crate::utils::utf16le::get_string_from_pdfium_utf16le_bytes;
+use std::os::raw::{c_int, c_ulong, c_void};
+
+#[cfg(doc)]
+use crate::pdf::document::page::struct_tree::PdfStructTree;
+
+pub type PdfStructElementIndex = usize;
+
+/// A single element in a [PdfStructTree], such as a heading, paragraph, table, or figure.
+pub struct PdfStructElement<'a> {
+    handle: FPDF_STRUCTELEMENT,
+    bindings: &'a dyn PdfiumLibraryBindings,
+}
+
+impl<'a> PdfStructElement<'a> {
+    #[inline]
+    pub(crate) fn from_pdfium(
+        handle: FPDF_STRUCTELEMENT,
+        bindings: &'a dyn PdfiumLibraryBindings,
+    ) -> Self {
+        PdfStructElement { handle, bindings }
+    }
+
+    /// Returns the [PdfiumLibraryBindings] used by this [PdfStructElement].
+    #[inline]
+    pub fn bindings(&self) -> &'a dyn PdfiumLibraryBindings {
+        self.bindings
+    }
+
+    /// Returns the structure type of this [PdfStructElement], for example `H1`, `P`,
+    /// `Table`, or `Figure`.
+    pub fn element_type(&self) -> Option<String> {
+        self.get_string_value(|buffer, length| {
+            self.bindings
+                .FPDF_StructElement_GetType(self.handle, buffer, length)
+        })
+    }
+
+    /// Returns the object type of this [PdfStructElement], usually `StructElem`.
+    pub fn object_type(&self) -> Option<String> {
+        self.get_string_value(|buffer, length| {
+            self.bindings
+                .FPDF_StructElement_GetObjType(self.handle, buffer, length)
+        })
+    }
+
+    /// Returns the title of this [PdfStructElement], if any.
+    pub fn title(&self) -> Option<String> {
+        self.get_string_value(|buffer, length| {
+            self.bindings
+                .FPDF_StructElement_GetTitle(self.handle, buffer, length)
+        })
+    }
+
+    /// Returns the alternate description of this [PdfStructElement], if any. Alternate
+    /// descriptions are typically provided for figures and formulas, so that their meaning
+    /// can be conveyed to users who cannot see them.
+    pub fn alt_text(&self) -> Option<String> {
+        self.get_string_value(|buffer, length| {
+            self.bindings
+                .FPDF_StructElement_GetAltText(self.handle, buffer, length)
+        })
+    }
+
+    /// Returns the replacement text for the content of this [PdfStructElement], if any.
+    pub fn actual_text(&self) -> Option<String> {
+        self.get_string_value(|buffer, length| {
+            self.bindings
+                .FPDF_StructElement_GetActualText(self.handle, buffer, length)
+        })
+    }
+
+    /// Returns the unique identifier of this [PdfStructElement], if any.
+    pub fn id(&self) -> Option<String> {
+        self.get_string_value(|buffer, length| {
+            self.bindings
+                .FPDF_StructElement_GetID(self.handle, buffer, length)
+        })
+    }
+
+    /// Returns the natural language of the content of this [PdfStructElement], if any,
+    /// as a language identifier such as `en-US`.
+    pub fn lang(&self) -> Option<String> {
+        self.get_string_value(|buffer, length| {
+            self.bindings
+                .FPDF_StructElement_GetLang(self.handle, buffer, length)
+        })
+    }
+
+    /// Returns the marked content identifiers associated with this [PdfStructElement].
+    ///
+    /// Each identifier matches the `MCID` parameter of a marked content sequence in the page
+    /// content stream, and can be correlated with the `PdfPageObjectMark` of each page object
+    /// painted inside that sequence.
+    pub fn marked_content_ids(&self) -> Vec<i32> {
+        let count = self
+            .bindings
+            .FPDF_StructElement_GetMarkedContentIdCount(self.handle);
+
+        (0..count.max(0))
+            .map(|index| {
+                self.bindings
+                    .FPDF_StructElement_GetMarkedContentIdAtIndex(self.handle, index)
+            })
+            .filter(|id| *id >= 0)
+            .collect()
+    }
+
+    /// Returns the parent of this [PdfStructElement], or `None` if this element is
+    /// a top-level element in its containing [PdfStructTree].
+    pub fn parent(&self) -> Option<PdfStructElement<'a>> {
+        let handle = self.bindings.FPDF_StructElement_GetParent(self.handle);
+
+        if handle.is_null() {
+            None
+        } else {
+            Some(PdfStructElement::from_pdfium(handle, self.bindings))
+        }
+    }
+
+    /// Returns the number of children of this [PdfStructElement]. Children that are not
+    /// themselves structure elements, such as marked content identifiers, are included
+    /// in the count.
+    #[inline]
+    pub fn len(&self) -> PdfStructElementIndex {
+        self.bindings
+            .FPDF_StructElement_CountChildren(self.handle)
+            .max(0) as PdfStructElementIndex
+    }
+
+    /// Returns `true` if this [PdfStructElement] has no children.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the child [PdfStructElement] at the given index. An error is returned
+    /// if the child at the given index is not itself a structure element.
+    pub fn get(&self, index: PdfStructElementIndex) -> Result<PdfStructElement<'a>, PdfiumError> {
+        if index >= self.len() {
+            return Err(PdfiumError::StructElementIndexOutOfBounds);
+        }
+
+        let handle = self
+            .bindings
+            .FPDF_StructElement_GetChildAtIndex(self.handle, index as c_int);
+
+        if handle.is_null() {
+            Err(PdfiumError::StructElementIndexOutOfBounds)
+        } else {
+            Ok(PdfStructElement::from_pdfium(handle, self.bindings))
+        }
+    }
+
+    /// Returns an iterator over the children of this [PdfStructElement] that are themselves
+    /// structure elements.
+    #[inline]
+    pub fn children(&self) -> PdfStructElementsIterator<'a> {
+        PdfStructElementsIterator {
+            parent: PdfStructElementsParent::Element(self.handle),
+            len: self.len(),
+            next_index: 0,
+            bindings: self.bindings,
+        }
+    }
+
+    /// Returns an iterator over every descendant of this [PdfStructElement], visiting
+    /// each element before its children in depth-first order.
+    #[inline]
+    pub fn descendants(&self) -> PdfStructElementsRecursiveIterator<'a> {
+        PdfStructElementsRecursiveIterator::new(self.children())
+    }
+
+    /// Retrieves a string value using the given Pdfium string getter, following Pdfium's
+    /// convention of returning the required buffer length when called with an empty buffer.
+    fn get_string_value(&self, getter: impl Fn(*mut c_void, c_ulong) -> c_ulong) -> Option<String> {
+        // The first call to the getter with an empty buffer returns the length of the buffer
+        // needed to hold the string value, including a trailing null. A length of 2 or less
+        // indicates an empty UTF16-LE string.
+
+        let buffer_length = getter(std::ptr::null_mut(), 0);
+
+        if buffer_length <= 2 {
+            return None;
+        }
+
+        let mut buffer = create_byte_buffer(buffer_length as usize);
+
+        let result = getter(buffer.as_mut_ptr() as *mut c_void, buffer_length);
+
+        assert_eq!(result, buffer_length);
+
+        get_string_from_pdfium_utf16le_bytes(buffer)
+    }
+}
+
+/// The container whose children are visited by a [PdfStructElementsIterator].
+enum PdfStructElementsParent {
+    Tree(FPDF_STRUCTTREE),
+    Element(FPDF_STRUCTELEMENT),
+}
+
+/// An iterator over the child elements of a [PdfStructTree] or a [PdfStructElement].
+/// Children that are not themselves structure elements are skipped.
+pub struct PdfStructElementsIterator<'a> {
+    parent: PdfStructElementsParent,
+    len: PdfStructElementIndex,
+    next_index: PdfStructElementIndex,
+    bindings: &'a dyn PdfiumLibraryBindings,
+}
+
+impl<'a> PdfStructElementsIterator<'a> {
+    #[inline]
+    pub(crate) fn for_tree(tree: FPDF_STRUCTTREE, bindings: &'a dyn PdfiumLibraryBindings) -> Self {
+        PdfStructElementsIterator {
+            parent: PdfStructElementsParent::Tree(tree),
+            len: bindings.FPDF_StructTree_CountChildren(tree).max(0) as PdfStructElementIndex,
+            next_index: 0,
+            bindings,
+        }
+    }
+}
+
+impl<'a> Iterator for PdfStructElementsIterator<'a> {
+    type Item = PdfStructElement<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.next_index < self.len {
+            let index = self.next_index as c_int;
+
+            self.next_index += 1;
+
+            let handle = match self.parent {
+                PdfStructElementsParent::Tree(tree) => {
+                    self.bindings.FPDF_StructTree_GetChildAtIndex(tree, index)
+                }
+                PdfStructElementsParent::Element(element) => self
+                    .bindings
+                    .FPDF_StructElement_GetChildAtIndex(element, index),
+            };
+
+            if !handle.is_null() {
+                return Some(PdfStructElement::from_pdfium(handle, self.bindings));
+            }
+        }
+
+        None
+    }
+}
+
+/// A depth-first iterator over a [PdfStructTree] or a [PdfStructElement] and all
+/// their descendants.
+pub struct PdfStructElementsRecursiveIterator<'a> {
+    stack: Vec<PdfStructElementsIterator<'a>>,
+}
+
+impl<'a> PdfStructElementsRecursiveIterator<'a> {
+    #[inline]
+    pub(crate) fn new(children: PdfStructElementsIterator<'a>) -> Self {
+        PdfStructElementsRecursiveIterator {
+            stack: vec![children],
+        }
+    }
+}
+
+impl<'a> Iterator for PdfStructElementsRecursiveIterator<'a> {
+    type Item = PdfStructElement<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(children) = self.stack.last_mut() {
+            match children.next() {
+                Some(element) => {
+                    self.stack.push(element.children());
+
+                    return Some(element);
+                }
+                None => {
+                    self.stack.pop();
+                }
+            }
+        }
+
+        None
+    }
+}
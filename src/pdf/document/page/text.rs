@@ -3,13 +3,15 @@
 
 pub mod char;
 pub mod chars;
+pub mod lines;
 pub mod search;
 pub mod segment;
 pub mod segments;
+pub mod web_links;
 
-use crate::bindgen::{FPDF_TEXTPAGE, FPDF_WCHAR, FPDF_WIDESTRING};
+use crate::bindgen::{FPDF_PAGE, FPDF_TEXTPAGE, FPDF_WCHAR};
 use crate::bindings::PdfiumLibraryBindings;
-use crate::error::PdfiumError;
+use crate::error::{PdfiumError, PdfiumInternalError};
 use crate::pdf::document::page::annotation::PdfPageAnnotation;
 use crate::pdf::document::page::annotation::PdfPageAnnotationCommon;
 use crate::pdf::document::page::object::private::internal::PdfPageObjectPrivate;
@@ -17,20 +19,29 @@ use crate::pdf::document::page::object::text::PdfPageTextObject;
 use crate::pdf::document::page::object::PdfPageObjectCommon;
 use crate::pdf::document::page::objects::common::PdfPageObjectsCommon;
 use crate::pdf::document::page::text::chars::{PdfPageTextCharIndex, PdfPageTextChars};
+use crate::pdf::document::page::text::lines::{
+    segment, segmentation_chars, PdfPageTextLine, PdfTextSegmentationConfig,
+};
 use crate::pdf::document::page::text::search::{PdfPageTextSearch, PdfSearchOptions};
 use crate::pdf::document::page::text::segments::PdfPageTextSegments;
+use crate::pdf::document::page::text::web_links::PdfPageWebLinks;
 use crate::pdf::document::page::{PdfPage, PdfPageContentRegenerationStrategy, PdfPageIndexCache};
 use crate::pdf::document::pages::PdfPageIndex;
 use crate::pdf::points::PdfPoints;
 use crate::pdf::rect::PdfRect;
 use crate::utils::mem::{create_byte_buffer, create_sized_buffer};
 use crate::utils::utf16le::{
-    get_pdfium_utf16le_bytes_from_str, get_string_from_pdfium_utf16le_bytes,
+    get_pdfium_utf16le_words_from_str, get_string_from_pdfium_utf16le_bytes,
 };
 use bytemuck::cast_slice;
 use std::fmt::{Display, Formatter};
 use std::os::raw::{c_double, c_int};
 use std::ptr::null_mut;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+#[cfg(doc)]
+use crate::pdf::document::page::text::char::PdfPageTextChar;
 
 /// The collection of Unicode characters visible on a single [PdfPage].
 ///
@@ -46,19 +57,19 @@ use std::ptr::null_mut;
 ///
 /// [PdfPageText] implements both the [ToString] and the [Display] traits.
 pub struct PdfPageText<'a> {
-    text_page_handle: FPDF_TEXTPAGE,
+    text_page: PdfPageTextHandle<'a>,
     page: &'a PdfPage<'a>,
     bindings: &'a dyn PdfiumLibraryBindings,
 }
 
 impl<'a> PdfPageText<'a> {
     pub(crate) fn from_pdfium(
-        text_page_handle: FPDF_TEXTPAGE,
+        text_page: PdfPageTextHandle<'a>,
         page: &'a PdfPage<'a>,
         bindings: &'a dyn PdfiumLibraryBindings,
     ) -> Self {
         PdfPageText {
-            text_page_handle,
+            text_page,
             page,
             bindings,
         }
@@ -67,7 +78,7 @@ impl<'a> PdfPageText<'a> {
     /// Returns the internal `FPDF_TEXTPAGE` handle for this [PdfPageText].
     #[inline]
     pub(crate) fn text_page_handle(&self) -> FPDF_TEXTPAGE {
-        self.text_page_handle
+        self.text_page.handle()
     }
 
     /// Returns the [PdfiumLibraryBindings] used by this [PdfPageText].
@@ -108,13 +119,92 @@ impl<'a> PdfPageText<'a> {
         PdfPageTextSegments::new(self, start as i32, count as i32, self.bindings())
     }
 
+    /// Groups the characters in this [PdfPageText] collection into lines and words using
+    /// the default [PdfTextSegmentationConfig] settings.
+    ///
+    /// Lines are returned in reading order: text is divided into columns, and lines are
+    /// returned column by column from left to right, and from top to bottom within each column.
+    #[inline]
+    pub fn lines(&self) -> Vec<PdfPageTextLine> {
+        self.lines_with_config(&PdfTextSegmentationConfig::default())
+    }
+
+    /// Groups the characters in this [PdfPageText] collection into lines and words using
+    /// the given [PdfTextSegmentationConfig] settings.
+    ///
+    /// Lines are returned in reading order: text is divided into columns, and lines are
+    /// returned column by column from left to right, and from top to bottom within each column.
+    /// The text of words written from right to left is kept in logical order.
+    #[inline]
+    pub fn lines_with_config(&self, config: &PdfTextSegmentationConfig) -> Vec<PdfPageTextLine> {
+        segment(&segmentation_chars(self), config)
+    }
+
+    /// Returns the bounding rectangles of the given range of characters in the containing
+    /// [PdfPage].
+    ///
+    /// Pdfium merges adjacent characters into a single rectangle if they are on the same line
+    /// and share the same font settings, so the returned rectangles can be used to highlight
+    /// or overlay a run of text. Rectangles are always axis-aligned; to retrieve the rotated
+    /// bounds of an individual character, use the [PdfPageTextChar::tight_quad_points] function.
+    #[inline]
+    pub fn rects_for_range(
+        &self,
+        start: PdfPageTextCharIndex,
+        count: PdfPageTextCharIndex,
+    ) -> Vec<PdfRect> {
+        Self::rects_for_range_for_handle(self.text_page_handle(), start, count, self.bindings())
+    }
+
+    /// Returns the bounding rectangles of the given range of characters in the text page
+    /// with the given `FPDF_TEXTPAGE` handle.
+    pub(crate) fn rects_for_range_for_handle(
+        text_page_handle: FPDF_TEXTPAGE,
+        start: PdfPageTextCharIndex,
+        count: PdfPageTextCharIndex,
+        bindings: &dyn PdfiumLibraryBindings,
+    ) -> Vec<PdfRect> {
+        let rect_count =
+            bindings.FPDFText_CountRects(text_page_handle, start as c_int, count as c_int);
+
+        let mut rects = Vec::with_capacity(rect_count.max(0) as usize);
+
+        for index in 0..rect_count {
+            let mut left = 0.0;
+
+            let mut top = 0.0;
+
+            let mut right = 0.0;
+
+            let mut bottom = 0.0;
+
+            if bindings.is_true(bindings.FPDFText_GetRect(
+                text_page_handle,
+                index,
+                &mut left,
+                &mut top,
+                &mut right,
+                &mut bottom,
+            )) {
+                rects.push(PdfRect::new_from_values(
+                    bottom as f32,
+                    left as f32,
+                    top as f32,
+                    right as f32,
+                ));
+            }
+        }
+
+        rects
+    }
+
     /// Returns a collection of all the `PdfPageTextChar` characters in the containing [PdfPage].
     #[inline]
-    pub fn chars(&self) -> PdfPageTextChars {
+    pub fn chars(&self) -> PdfPageTextChars<'a> {
         PdfPageTextChars::new(
             self.page.document_handle(),
             self.page.page_handle(),
-            self.text_page_handle(),
+            self.text_page.clone(),
             0,
             self.len(),
             self.bindings(),
@@ -123,13 +213,34 @@ impl<'a> PdfPageText<'a> {
 
     /// Returns a collection of all the `PdfPageTextChar` characters in the given [PdfPageTextObject].
     ///
-    /// The return result will be empty if the given [PdfPageTextObject] is not attached to the
-    /// containing [PdfPage].
+    /// When using Pdfium version 6611 or later, characters are matched to the given object by
+    /// its handle, so the correct characters are returned even if the same text appears in
+    /// several text objects on the page. For earlier versions of Pdfium, or if the given
+    /// [PdfPageTextObject] is not attached to the containing [PdfPage], characters are located
+    /// by copying the object onto a temporary page.
     #[inline]
     pub fn chars_for_object(
         &self,
         object: &PdfPageTextObject,
-    ) -> Result<PdfPageTextChars, PdfiumError> {
+    ) -> Result<PdfPageTextChars<'a>, PdfiumError> {
+        #[cfg(any(
+            feature = "pdfium_future",
+            feature = "pdfium_6996",
+            feature = "pdfium_6721",
+            feature = "pdfium_6666",
+            feature = "pdfium_6611"
+        ))]
+        if let Some((start, end)) = self.char_range_for_object_handle(object.object_handle()) {
+            return Ok(PdfPageTextChars::new(
+                self.page.document_handle(),
+                self.page.page_handle(),
+                self.text_page.clone(),
+                start as i32,
+                (end - start + 1) as i32,
+                self.bindings,
+            ));
+        }
+
         // To avoid any possibility of returning the wrong characters in the event
         // of overlapping text objects, we create a new page, create a copy of the target
         // text object on the new page, and return the PdfPageTextChars object _for the
@@ -220,13 +331,39 @@ impl<'a> PdfPageText<'a> {
         // ... and use raw handles and indices to create a new PdfPageTextChars instance
         // that isn't bound to the lifetime of the current object.
 
-        Ok(PdfPageTextChars::new_with_owned_page(
+        PdfPageTextChars::new_with_owned_page(
             document_handle,
             page_index,
             start_index as i32,
             end_index as i32 + 1,
             self.bindings(),
-        ))
+        )
+    }
+
+    /// Returns the indices of the first and last characters in this [PdfPageText] collection
+    /// that belong to the page object with the given handle, if any.
+    #[cfg(any(
+        feature = "pdfium_future",
+        feature = "pdfium_6996",
+        feature = "pdfium_6721",
+        feature = "pdfium_6666",
+        feature = "pdfium_6611"
+    ))]
+    fn char_range_for_object_handle(
+        &self,
+        object_handle: crate::bindgen::FPDF_PAGEOBJECT,
+    ) -> Option<(PdfPageTextCharIndex, PdfPageTextCharIndex)> {
+        let mut matching = (0..self.len()).filter(|index| {
+            self.bindings()
+                .FPDFText_GetTextObject(self.text_page_handle(), *index)
+                == object_handle
+        });
+
+        let start = matching.next()?;
+
+        let end = matching.next_back().unwrap_or(start);
+
+        Some((start as PdfPageTextCharIndex, end as PdfPageTextCharIndex))
     }
 
     /// Returns a collection of all the `PdfPageTextChar` characters in the given [PdfPageAnnotation].
@@ -237,15 +374,19 @@ impl<'a> PdfPageText<'a> {
     pub fn chars_for_annotation(
         &self,
         annotation: &PdfPageAnnotation,
-    ) -> Result<PdfPageTextChars, PdfiumError> {
+    ) -> Result<PdfPageTextChars<'a>, PdfiumError> {
         self.chars_inside_rect(annotation.bounds()?)
             .map_err(|_| PdfiumError::NoCharsInAnnotation)
     }
 
     /// Returns a collection of all the `PdfPageTextChar` characters that lie within the bounds of
     /// the given [PdfRect] in the containing [PdfPage].
+    ///
+    /// The returned collection is the contiguous run of characters between the characters
+    /// nearest the left and right edges of the given rectangle. To retrieve exactly those
+    /// characters that overlap the rectangle, use the [PdfPageText::chars_in_rect()] function.
     #[inline]
-    pub fn chars_inside_rect(&self, rect: PdfRect) -> Result<PdfPageTextChars, PdfiumError> {
+    pub fn chars_inside_rect(&self, rect: PdfRect) -> Result<PdfPageTextChars<'a>, PdfiumError> {
         let tolerance_x = rect.width() / 2.0;
         let tolerance_y = rect.height() / 2.0;
         let center_height = rect.bottom() + tolerance_y;
@@ -259,7 +400,7 @@ impl<'a> PdfPageText<'a> {
             (Some(start), Some(end)) => Ok(PdfPageTextChars::new(
                 self.page.document_handle(),
                 self.page.page_handle(),
-                self.text_page_handle(),
+                self.text_page.clone(),
                 start.index() as i32,
                 end.index().saturating_sub(start.index()) as i32 + 1,
                 self.bindings,
@@ -268,6 +409,52 @@ impl<'a> PdfPageText<'a> {
         }
     }
 
+    /// Returns a collection of all the `PdfPageTextChar` characters that lie at least partially
+    /// within the bounds of the given [PdfRect] in the containing [PdfPage].
+    ///
+    /// Characters are included using the same rule as [PdfPageText::text_in_rect()]:
+    /// a character is included if its bounding box shares any area with the given rectangle.
+    /// The characters in the returned collection need not be contiguous; for instance, the
+    /// characters in a single column of a table will be returned without the characters
+    /// in neighbouring columns, even though Pdfium may define them in row order.
+    ///
+    /// Unlike [PdfPageText::text_in_rect()], the returned collection does not include the
+    /// spaces and line breaks Pdfium inserts between words and lines. [PdfiumError::NoCharsInRect]
+    /// is returned if no characters lie within the given rectangle.
+    pub fn chars_in_rect(&self, rect: PdfRect) -> Result<PdfPageTextChars<'a>, PdfiumError> {
+        let indices = (0..self.len())
+            .filter(|index| {
+                let mut left = 0.0;
+                let mut bottom = 0.0;
+                let mut right = 0.0;
+                let mut top = 0.0;
+
+                self.bindings().is_true(self.bindings().FPDFText_GetCharBox(
+                    self.text_page_handle(),
+                    *index,
+                    &mut left,
+                    &mut right,
+                    &mut bottom,
+                    &mut top,
+                )) && PdfRect::new_from_values(bottom as f32, left as f32, top as f32, right as f32)
+                    .intersect(&rect)
+                    .is_some()
+            })
+            .collect::<Vec<_>>();
+
+        if indices.is_empty() {
+            Err(PdfiumError::NoCharsInRect)
+        } else {
+            Ok(PdfPageTextChars::new_from_indices(
+                self.page.document_handle(),
+                self.page.page_handle(),
+                self.text_page.clone(),
+                indices,
+                self.bindings,
+            ))
+        }
+    }
+
     /// Returns the character near to the given x and y positions on the containing [PdfPage],
     /// if any. The returned character will be no further from the given positions than the given
     /// tolerance values.
@@ -299,17 +486,38 @@ impl<'a> PdfPageText<'a> {
     /// and the order in which they appear visually during rendering (and thus the order in
     /// which they are read by a user) may not necessarily match.
     pub fn all(&self) -> String {
-        self.inside_rect(self.page.page_size())
+        self.text_in_rect(self.page.page_size())
     }
 
     /// Returns all characters that lie within the bounds of the given [PdfRect] in the
     /// containing [PdfPage], in the order in which they are defined in the document,
     /// concatenated into a single string.
+    #[deprecated(
+        since = "0.8.30",
+        note = "This function has been renamed. Use the PdfPageText::text_in_rect() function instead."
+    )]
+    #[doc(hidden)]
+    #[inline]
+    pub fn inside_rect(&self, rect: PdfRect) -> String {
+        self.text_in_rect(rect)
+    }
+
+    /// Returns all characters that lie at least partially within the bounds of the given
+    /// [PdfRect] in the containing [PdfPage], in the order in which they are defined in the
+    /// document, concatenated into a single string.
+    ///
+    /// A character is included if its bounding box, as returned by
+    /// [PdfPageTextChar::tight_bounds()], shares any area with the given rectangle, so a
+    /// rectangle that clips a character part-way through its glyph still includes the whole
+    /// character. Pdfium inserts spaces and line breaks between included characters where
+    /// it detects word and line boundaries. The characters themselves, together with their
+    /// positions, can be retrieved using the [PdfPageText::chars_in_rect()] function, which
+    /// applies the same inclusion rule.
     ///
     /// In complex custom layouts, the order in which characters are defined in the document
     /// and the order in which they appear visually during rendering (and thus the order in
     /// which they are read by a user) may not necessarily match.
-    pub fn inside_rect(&self, rect: PdfRect) -> String {
+    pub fn text_in_rect(&self, rect: PdfRect) -> String {
         // Retrieving the bounded text from Pdfium is a two-step operation. First, we call
         // FPDFText_GetBoundedText() with a null buffer; this will retrieve the length of
         // the bounded text in _characters_ (not _bytes_!). If the length is zero, then there is
@@ -361,6 +569,36 @@ impl<'a> PdfPageText<'a> {
             .unwrap_or_default()
     }
 
+    /// Returns the given range of characters in this [PdfPageText] object, in the order in
+    /// which they are defined in the document, concatenated into a single string.
+    pub fn for_range(&self, start: PdfPageTextCharIndex, count: PdfPageTextCharIndex) -> String {
+        if count == 0 {
+            return String::new();
+        }
+
+        // FPDFText_GetText() writes the requested characters in UTF16-LE format, followed by
+        // a null terminator, so the buffer must be sized to allow for one more character than
+        // was requested.
+
+        let mut buffer = create_sized_buffer::<u16>(count + 1);
+
+        let result = self.bindings().FPDFText_GetText(
+            self.text_page_handle(),
+            start as c_int,
+            count as c_int,
+            buffer.as_mut_ptr(),
+        );
+
+        if result <= 0 {
+            return String::new();
+        }
+
+        buffer.truncate(result as usize);
+
+        get_string_from_pdfium_utf16le_bytes(cast_slice(buffer.as_slice()).to_vec())
+            .unwrap_or_default()
+    }
+
     /// Returns all characters assigned to the given [PdfPageTextObject] in this [PdfPageText] object,
     /// concatenated into a single string.
     pub fn for_object(&self, object: &PdfPageTextObject) -> String {
@@ -411,35 +649,77 @@ impl<'a> PdfPageText<'a> {
     pub fn for_annotation(&self, annotation: &PdfPageAnnotation) -> Result<String, PdfiumError> {
         let bounds = annotation.bounds()?;
 
-        Ok(self.inside_rect(bounds))
+        Ok(self.text_in_rect(bounds))
+    }
+
+    /// Detects all the web links in this [PdfPageText], returning a new [PdfPageWebLinks]
+    /// collection containing the detected links.
+    ///
+    /// Pdfium detects web links by examining the text on the page for strings that look
+    /// like URLs or email addresses, irrespective of whether or not those strings are
+    /// also marked as clickable links in the document.
+    pub fn detect_web_links(&self) -> Result<PdfPageWebLinks, PdfiumError> {
+        let handle = self
+            .bindings()
+            .FPDFLink_LoadWebLinks(self.text_page_handle());
+
+        if handle.is_null() {
+            Err(self.bindings().error_in(
+                "FPDFLink_LoadWebLinks",
+                PdfiumError::PdfiumFunctionReturnValueIndicatedFailure,
+            ))
+        } else {
+            Ok(PdfPageWebLinks::from_pdfium(handle, self, self.bindings()))
+        }
     }
 
     /// Starts a search for the given text string, returning a new [PdfPageTextSearch]
     /// object that can be used to step through the search results.
+    ///
+    /// Returns [PdfiumError::TextSearchTargetIsEmpty] if the given text string is empty.
     #[inline]
-    pub fn search(&self, text: &str, options: &PdfSearchOptions) -> PdfPageTextSearch {
+    pub fn search(
+        &self,
+        text: &str,
+        options: &PdfSearchOptions,
+    ) -> Result<PdfPageTextSearch, PdfiumError> {
         self.search_from(text, options, 0)
     }
 
     /// Starts a search for the given test string from the given character position,
     /// returning a new [PdfPageTextSearch] object that can be used to step through
     /// the search results.
+    ///
+    /// Returns [PdfiumError::TextSearchTargetIsEmpty] if the given text string is empty.
     pub fn search_from(
         &self,
         text: &str,
         options: &PdfSearchOptions,
         index: PdfPageTextCharIndex,
-    ) -> PdfPageTextSearch {
-        PdfPageTextSearch::from_pdfium(
-            self.bindings().FPDFText_FindStart(
-                self.text_page_handle(),
-                get_pdfium_utf16le_bytes_from_str(text).as_ptr() as FPDF_WIDESTRING,
-                options.as_pdfium(),
-                index as c_int,
-            ),
-            self,
-            self.bindings(),
-        )
+    ) -> Result<PdfPageTextSearch, PdfiumError> {
+        if text.is_empty() {
+            return Err(PdfiumError::TextSearchTargetIsEmpty);
+        }
+
+        let handle = self.bindings().FPDFText_FindStart(
+            self.text_page_handle(),
+            get_pdfium_utf16le_words_from_str(text).as_ptr(),
+            options.as_pdfium(),
+            index as c_int,
+        );
+
+        if handle.is_null() {
+            Err(self.bindings().error_in(
+                "FPDFText_FindStart",
+                PdfiumError::PdfiumFunctionReturnValueIndicatedFailure,
+            ))
+        } else {
+            Ok(PdfPageTextSearch::from_pdfium(
+                handle,
+                self,
+                self.bindings(),
+            ))
+        }
     }
 }
 
@@ -450,11 +730,118 @@ impl<'a> Display for PdfPageText<'a> {
     }
 }
 
-impl<'a> Drop for PdfPageText<'a> {
-    /// Closes the [PdfPageText] collection, releasing held memory.
+/// A reference-counted `FPDF_TEXTPAGE` handle, shared between a [PdfPage] and any
+/// [PdfPageText] and [PdfPageTextChars] objects created from it. The text page is closed
+/// when the last [PdfPageTextHandle] referencing it is dropped.
+pub(crate) struct PdfPageTextHandle<'a> {
+    text_page: Arc<PdfPageTextPage>,
+    bindings: &'a dyn PdfiumLibraryBindings,
+}
+
+impl<'a> PdfPageTextHandle<'a> {
+    /// Loads the text page for the page with the given `FPDF_PAGE` handle.
+    pub(crate) fn load(
+        page_handle: FPDF_PAGE,
+        bindings: &'a dyn PdfiumLibraryBindings,
+    ) -> Result<Self, PdfiumError> {
+        let handle = bindings.FPDFText_LoadPage(page_handle);
+
+        if handle.is_null() {
+            Err(bindings.error_in(
+                "FPDFText_LoadPage",
+                PdfiumError::PdfiumLibraryInternalError(PdfiumInternalError::Unknown),
+            ))
+        } else {
+            // The reference count is atomic so that a PdfPage sharing this text page can be
+            // sent to another thread when the "sync" crate feature is enabled.
+
+            #[allow(clippy::arc_with_non_send_sync)]
+            let text_page = Arc::new(PdfPageTextPage {
+                handle,
+                references: AtomicUsize::new(1),
+            });
+
+            Ok(PdfPageTextHandle {
+                text_page,
+                bindings,
+            })
+        }
+    }
+
+    /// Consumes this [PdfPageTextHandle], returning its reference to the text page without
+    /// releasing it. The reference is released by [PdfPageTextPage::release()].
+    #[inline]
+    pub(crate) fn into_text_page(self) -> Arc<PdfPageTextPage> {
+        let text_page = self.text_page.clone();
+
+        std::mem::forget(self);
+
+        text_page
+    }
+
+    /// Returns the internal `FPDF_TEXTPAGE` handle.
+    #[inline]
+    pub(crate) fn handle(&self) -> FPDF_TEXTPAGE {
+        self.text_page.handle
+    }
+}
+
+impl<'a> Clone for PdfPageTextHandle<'a> {
+    #[inline]
+    fn clone(&self) -> Self {
+        self.text_page.references.fetch_add(1, Ordering::Relaxed);
+
+        PdfPageTextHandle {
+            text_page: self.text_page.clone(),
+            bindings: self.bindings,
+        }
+    }
+}
+
+impl<'a> Drop for PdfPageTextHandle<'a> {
+    /// Releases this reference to the text page, closing the text page if no other
+    /// references to it remain.
     #[inline]
     fn drop(&mut self) {
-        self.bindings().FPDFText_ClosePage(self.text_page_handle());
+        if self.text_page.references.fetch_sub(1, Ordering::AcqRel) == 1 {
+            self.bindings.FPDFText_ClosePage(self.text_page.handle);
+        }
+    }
+}
+
+/// An open `FPDF_TEXTPAGE` handle, together with the number of references to it that have
+/// not yet been released. A [PdfPage] caches one such reference, obtained from
+/// [PdfPageTextHandle::into_text_page()], so that it can hold the text page without
+/// borrowing the Pdfium bindings.
+pub(crate) struct PdfPageTextPage {
+    handle: FPDF_TEXTPAGE,
+    references: AtomicUsize,
+}
+
+impl PdfPageTextPage {
+    /// Returns a new [PdfPageTextHandle] holding an additional reference to this text page.
+    #[inline]
+    pub(crate) fn to_handle<'a>(
+        self: &Arc<Self>,
+        bindings: &'a dyn PdfiumLibraryBindings,
+    ) -> PdfPageTextHandle<'a> {
+        self.references.fetch_add(1, Ordering::Relaxed);
+
+        PdfPageTextHandle {
+            text_page: self.clone(),
+            bindings,
+        }
+    }
+
+    /// Releases a reference to this text page previously obtained from
+    /// [PdfPageTextHandle::into_text_page()], closing the text page if no other references
+    /// to it remain.
+    #[inline]
+    pub(crate) fn release(self: Arc<Self>, bindings: &dyn PdfiumLibraryBindings) {
+        drop(PdfPageTextHandle {
+            text_page: self,
+            bindings,
+        });
     }
 }
 
@@ -522,6 +909,298 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_text_and_chars_in_table_cells() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        // Create a new document containing a three by three table, with the contents of
+        // each cell positioned well inside the cell's bounds.
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        let mut page = document
+            .pages_mut()
+            .create_page_at_start(PdfPagePaperSize::a4())?;
+
+        let font = document.fonts_mut().helvetica();
+
+        let cell = |row: usize, column: usize| {
+            let left = 72.0 + column as f32 * 150.0;
+            let bottom = 700.0 - row as f32 * 40.0;
+
+            PdfRect::new_from_values(bottom, left, bottom + 40.0, left + 150.0)
+        };
+
+        for row in 0..3 {
+            for column in 0..3 {
+                let bounds = cell(row, column);
+
+                page.objects_mut().create_text_object(
+                    bounds.left() + PdfPoints::new(10.0),
+                    bounds.bottom() + PdfPoints::new(14.0),
+                    format!("R{}C{}", row, column),
+                    font,
+                    PdfPoints::new(12.0),
+                )?;
+            }
+        }
+
+        let text = page.text()?;
+
+        let collect = |chars: &PdfPageTextChars| {
+            chars
+                .iter()
+                .filter_map(|char| char.unicode_char())
+                .filter(|char| !char.is_whitespace())
+                .collect::<String>()
+        };
+
+        for row in 0..3 {
+            for column in 0..3 {
+                let expected = format!("R{}C{}", row, column);
+
+                assert_eq!(text.text_in_rect(cell(row, column)).trim(), expected);
+                assert_eq!(collect(&text.chars_in_rect(cell(row, column))?), expected);
+            }
+        }
+
+        // The characters in a single column are not contiguous in the page's text,
+        // but should still be returned without the characters in neighbouring columns.
+
+        let column = cell(0, 1).union(&cell(2, 1));
+
+        let chars = text.chars_in_rect(column)?;
+
+        assert_eq!(collect(&chars), "R0C1R1C1R2C1");
+        assert_eq!(chars.len(), 12);
+
+        // A rectangle that clips a character part-way through its glyph includes the whole
+        // character, both in the text returned by Pdfium and in the returned characters.
+
+        let chars = text.chars_in_rect(cell(0, 0))?;
+
+        let first = chars.iter().next().unwrap();
+
+        assert_eq!(first.unicode_char(), Some('R'));
+
+        let bounds = first.tight_bounds()?;
+
+        let (center_x, _) = bounds.center();
+
+        let clip = PdfRect::new(
+            bounds.bottom(),
+            center_x,
+            bounds.top(),
+            bounds.right() + PdfPoints::new(0.1),
+        );
+
+        assert_eq!(text.text_in_rect(clip).trim(), "R");
+        assert_eq!(collect(&text.chars_in_rect(clip)?), "R");
+
+        // An empty cell contains no characters.
+
+        let empty = cell(3, 0);
+
+        assert_eq!(text.text_in_rect(empty), "");
+        assert!(matches!(
+            text.chars_in_rect(empty),
+            Err(PdfiumError::NoCharsInRect)
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_rotated_text_quad_points_and_rects() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        // Create a new document containing a single text object rotated 90 degrees
+        // counter-clockwise, so that it reads from the bottom of the page to the top.
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        let mut page = document
+            .pages_mut()
+            .create_page_at_start(PdfPagePaperSize::a4())?;
+
+        let font = document.fonts_mut().helvetica();
+
+        let mut object = page.objects_mut().create_text_object(
+            PdfPoints::ZERO,
+            PdfPoints::ZERO,
+            "ROTATED",
+            font,
+            PdfPoints::new(24.0),
+        )?;
+
+        object.rotate_counter_clockwise_degrees(90.0)?;
+        object.translate(PdfPoints::new(300.0), PdfPoints::new(400.0))?;
+
+        let text = page.text()?;
+
+        assert_eq!(text.all().trim(), "ROTATED");
+
+        for char in text.chars().iter() {
+            assert!((char.angle_degrees()? - 90.0).abs() < 1.0);
+
+            let tight = char.tight_bounds()?;
+
+            let quad = char.tight_quad_points()?;
+
+            // The quad should occupy the same area as the axis-aligned bounds...
+
+            assert!((quad.left().value - tight.left().value).abs() < 0.1);
+            assert!((quad.right().value - tight.right().value).abs() < 0.1);
+            assert!((quad.bottom().value - tight.bottom().value).abs() < 0.1);
+            assert!((quad.top().value - tight.top().value).abs() < 0.1);
+
+            // ... but its bottom edge should follow the rotated baseline, running up the page.
+
+            assert!((quad.x2.value - quad.x1.value).abs() < 0.1);
+            assert!(quad.y2.value > quad.y1.value);
+
+            // The baseline sits to the right of the glyph after a counter-clockwise rotation.
+
+            let (origin_x, origin_y) = char.origin()?;
+
+            assert!(origin_x.value >= tight.right().value - 0.1);
+            assert!(origin_y.value >= tight.bottom().value - 0.1);
+
+            let loose = char.loose_bounds()?;
+
+            assert!(loose.width().value >= tight.width().value - 0.1);
+            assert!(loose.height().value >= tight.height().value - 0.1);
+        }
+
+        let rects = text.rects_for_range(0, text.len() as PdfPageTextCharIndex);
+
+        assert!(!rects.is_empty());
+
+        for rect in rects.iter() {
+            assert!(rect.height().value > rect.width().value);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_group_chars_into_styled_runs() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        let mut page = document
+            .pages_mut()
+            .create_page_at_start(PdfPagePaperSize::a4())?;
+
+        let regular = document.fonts_mut().helvetica();
+
+        let bold_oblique = document.fonts_mut().helvetica_bold_oblique();
+
+        page.objects_mut().create_text_object(
+            PdfPoints::new(72.0),
+            PdfPoints::new(720.0),
+            "plain",
+            regular,
+            PdfPoints::new(12.0),
+        )?;
+
+        let mut emphasis = page.objects_mut().create_text_object(
+            PdfPoints::new(144.0),
+            PdfPoints::new(720.0),
+            "loud",
+            bold_oblique,
+            PdfPoints::new(12.0),
+        )?;
+
+        emphasis.set_fill_color(PdfColor::RED)?;
+
+        let text = page.text()?;
+
+        // Group consecutive characters with identical font information into styled runs.
+
+        let mut runs: Vec<(String, String, PdfFontDescriptorFlags, PdfColor)> = Vec::new();
+
+        for char in text.chars().iter() {
+            let character = match char.unicode_char() {
+                Some(character) if !character.is_whitespace() => character,
+                _ => continue,
+            };
+
+            assert_eq!(
+                char.font_name().as_bytes(),
+                char.font_name_bytes().as_slice()
+            );
+
+            let style = (char.font_name(), char.font_flags(), char.fill_color()?);
+
+            match runs.last_mut() {
+                Some(run) if (run.1.clone(), run.2, run.3) == style => run.0.push(character),
+                _ => runs.push((character.to_string(), style.0, style.1, style.2)),
+            }
+        }
+
+        assert_eq!(runs.len(), 2);
+        assert_eq!(runs[0].0, "plain");
+        assert_eq!(runs[1].0, "loud");
+        assert!(runs[0].1.contains("Helvetica"));
+        assert!(runs[1].1.contains("Helvetica-BoldOblique"));
+        assert_eq!(runs[1].3, PdfColor::RED);
+
+        Ok(())
+    }
+
+    #[test]
+    #[cfg(feature = "test_support")]
+    fn test_text_page_is_shared_and_invalidated() -> Result<(), PdfiumError> {
+        use crate::bindgen::FPDF_BOOL;
+
+        let mock = MockPdfiumBindings::new();
+
+        mock.allow_library_lifecycle()
+            .allow_document_loading(1)
+            .allow_text_extraction(5)
+            .returns_always("FPDFPage_GenerateContent", 1 as FPDF_BOOL);
+
+        let pdfium = Pdfium::new(Box::new(mock.clone()));
+
+        let document = pdfium.load_pdf_from_byte_slice(b"%PDF-1.7", None)?;
+
+        {
+            let mut page = document.pages().first()?;
+
+            // Repeated calls to text() share a single text page...
+
+            assert_eq!(page.text()?.len(), 5);
+            assert!(!page.text()?.is_empty());
+
+            // ... even if the characters outlive the PdfPageText they were created from.
+
+            let chars = page.text()?.chars();
+
+            assert_eq!(chars.len(), 5);
+            assert_eq!(mock.call_count("FPDFText_LoadPage"), 1);
+
+            drop(chars);
+
+            assert_eq!(mock.call_count("FPDFText_ClosePage"), 0);
+
+            // Regenerating the page's content invalidates the cached text page.
+
+            page.regenerate_content()?;
+
+            assert_eq!(page.text()?.len(), 5);
+            assert_eq!(mock.call_count("FPDFText_LoadPage"), 2);
+            assert_eq!(mock.call_count("FPDFText_ClosePage"), 1);
+        }
+
+        // Dropping the page closes its cached text page.
+
+        assert_eq!(mock.call_count("FPDFText_ClosePage"), 2);
+
+        Ok(())
+    }
+
     fn test_one_overlapping_text_object_results(
         object: &PdfPageObject,
         page_text: &PdfPageText,
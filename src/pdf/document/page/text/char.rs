@@ -7,8 +7,9 @@ use crate::error::{PdfiumError, PdfiumInternalError};
 use crate::pdf::color::PdfColor;
 use crate::pdf::document::page::object::text::PdfPageTextRenderMode;
 use crate::pdf::document::page::text::chars::PdfPageTextCharIndex;
-use crate::pdf::font::{FpdfFontDescriptorFlags, PdfFontWeight};
+use crate::pdf::font::{PdfFontDescriptorFlags, PdfFontWeight};
 use crate::pdf::points::PdfPoints;
+use crate::pdf::quad_points::PdfQuadPoints;
 use crate::pdf::rect::PdfRect;
 use crate::utils::mem::create_byte_buffer;
 use std::convert::TryInto;
@@ -142,8 +143,9 @@ impl<'a> PdfPageTextChar<'a> {
         )
     }
 
-    /// Returns the font name and raw font descriptor flags for the font applied to this character.
-    fn font(&self) -> (Option<String>, FpdfFontDescriptorFlags) {
+    /// Returns the raw font name bytes and the font descriptor flags for the font applied
+    /// to this character.
+    fn font(&self) -> (Vec<u8>, PdfFontDescriptorFlags) {
         // Retrieving the font name from Pdfium is a two-step operation. First, we call
         // FPDFText_GetFontInfo() with a null buffer; this will retrieve the length of
         // the font name in bytes. If the length is zero, then there is no font name.
@@ -151,7 +153,8 @@ impl<'a> PdfPageTextChar<'a> {
         // If the length is non-zero, then we reserve a byte buffer of the given
         // length and call FPDFText_GetFontInfo() again with a pointer to the buffer;
         // this will write the font name into the buffer. Unlike most text handling in
-        // Pdfium, font names are returned in UTF-8 format.
+        // Pdfium, font names are returned as raw bytes taken directly from the font dictionary;
+        // they are usually, but not necessarily, valid UTF-8.
 
         let mut flags = 0;
 
@@ -166,10 +169,7 @@ impl<'a> PdfPageTextChar<'a> {
         if buffer_length == 0 {
             // The font name is not present.
 
-            return (
-                None,
-                FpdfFontDescriptorFlags::from_bits_truncate(flags as u32),
-            );
+            return (Vec::new(), PdfFontDescriptorFlags::from_pdfium(flags));
         }
 
         let mut buffer = create_byte_buffer(buffer_length as usize);
@@ -184,20 +184,40 @@ impl<'a> PdfPageTextChar<'a> {
 
         assert_eq!(result, buffer_length);
 
-        (
-            String::from_utf8(buffer)
-                // Trim any trailing nulls. All strings returned from Pdfium are generally terminated
-                // by one null byte.
-                .map(|str| str.trim_end_matches(char::from(0)).to_owned())
-                .ok(),
-            FpdfFontDescriptorFlags::from_bits_truncate(flags as u32),
-        )
+        // Trim any trailing nulls. All strings returned from Pdfium are generally terminated
+        // by one null byte.
+
+        while buffer.last() == Some(&0) {
+            buffer.pop();
+        }
+
+        (buffer, PdfFontDescriptorFlags::from_pdfium(flags))
     }
 
     /// Returns the name of the font applied to this character.
+    ///
+    /// Font names are not guaranteed to be valid UTF-8; any invalid byte sequences are
+    /// replaced with the Unicode replacement character. Use the
+    /// [PdfPageTextChar::font_name_bytes] function to retrieve the font name exactly as it
+    /// is recorded in the document.
     #[inline]
     pub fn font_name(&self) -> String {
-        self.font().0.unwrap_or_default()
+        String::from_utf8_lossy(&self.font().0).into_owned()
+    }
+
+    /// Returns the raw bytes of the name of the font applied to this character, exactly
+    /// as recorded in the document, without any trailing null terminator.
+    #[inline]
+    pub fn font_name_bytes(&self) -> Vec<u8> {
+        self.font().0
+    }
+
+    /// Returns the font descriptor flags for the font applied to this character.
+    ///
+    /// Pdfium may not reliably return the correct value of these flags for built-in fonts.
+    #[inline]
+    pub fn font_flags(&self) -> PdfFontDescriptorFlags {
+        self.font().1
     }
 
     /// Returns the weight of the font applied to this character.
@@ -211,18 +231,11 @@ impl<'a> PdfPageTextChar<'a> {
         )
     }
 
-    /// Returns the raw font descriptor bitflags for the font applied to this character.
-    #[inline]
-    fn font_flags_bits(&self) -> FpdfFontDescriptorFlags {
-        self.font().1
-    }
-
     /// Returns `true` if all the glyphs in the font applied to this character have the same width.
     ///
     /// Pdfium may not reliably return the correct value of this flag for built-in fonts.
     pub fn font_is_fixed_pitch(&self) -> bool {
-        self.font_flags_bits()
-            .contains(FpdfFontDescriptorFlags::FIXED_PITCH_BIT_1)
+        self.font_flags().is_fixed_pitch()
     }
 
     /// Returns `true` if the glyphs in the font applied to this character have variable widths.
@@ -239,8 +252,7 @@ impl<'a> PdfPageTextChar<'a> {
     ///
     /// Pdfium may not reliably return the correct value of this flag for built-in fonts.
     pub fn font_is_serif(&self) -> bool {
-        self.font_flags_bits()
-            .contains(FpdfFontDescriptorFlags::SERIF_BIT_2)
+        self.font_flags().is_serif()
     }
 
     /// Returns `true` if no glyphs in the font applied to this character have serifs -
@@ -264,8 +276,7 @@ impl<'a> PdfPageTextChar<'a> {
     pub fn font_is_symbolic(&self) -> bool {
         // This flag bit and the non-symbolic flag bit cannot both be set or both be clear.
 
-        self.font_flags_bits()
-            .contains(FpdfFontDescriptorFlags::SYMBOLIC_BIT_3)
+        self.font_flags().is_symbolic()
     }
 
     /// Returns `true` if the font applied to this character does not contain glyphs outside the
@@ -279,8 +290,7 @@ impl<'a> PdfPageTextChar<'a> {
     pub fn font_is_non_symbolic(&self) -> bool {
         // This flag bit and the symbolic flag bit cannot both be set or both be clear.
 
-        self.font_flags_bits()
-            .contains(FpdfFontDescriptorFlags::NON_SYMBOLIC_BIT_6)
+        self.font_flags().is_non_symbolic()
     }
 
     /// Returns `true` if the glyphs in the font applied to this character are designed to resemble
@@ -288,8 +298,7 @@ impl<'a> PdfPageTextChar<'a> {
     ///
     /// Pdfium may not reliably return the correct value of this flag for built-in fonts.
     pub fn font_is_cursive(&self) -> bool {
-        self.font_flags_bits()
-            .contains(FpdfFontDescriptorFlags::SCRIPT_BIT_4)
+        self.font_flags().is_cursive()
     }
 
     /// Returns `true` if the glyphs in the font applied to this character include dominant
@@ -297,16 +306,14 @@ impl<'a> PdfPageTextChar<'a> {
     ///
     /// Pdfium may not reliably return the correct value of this flag for built-in fonts.
     pub fn font_is_italic(&self) -> bool {
-        self.font_flags_bits()
-            .contains(FpdfFontDescriptorFlags::ITALIC_BIT_7)
+        self.font_flags().is_italic()
     }
 
     /// Returns `true` if the font applied to this character contains no lowercase letters by design.
     ///
     /// Pdfium may not reliably return the correct value of this flag for built-in fonts.
     pub fn font_is_all_caps(&self) -> bool {
-        self.font_flags_bits()
-            .contains(FpdfFontDescriptorFlags::ALL_CAP_BIT_17)
+        self.font_flags().is_all_caps()
     }
 
     /// Returns `true` if the lowercase letters in the font applied to this character have the
@@ -315,8 +322,7 @@ impl<'a> PdfPageTextChar<'a> {
     ///
     /// Pdfium may not reliably return the correct value of this flag for built-in fonts.
     pub fn font_is_small_caps(&self) -> bool {
-        self.font_flags_bits()
-            .contains(FpdfFontDescriptorFlags::SMALL_CAP_BIT_18)
+        self.font_flags().is_small_caps()
     }
 
     /// Returns `true` if bold glyphs in the font applied to this character are painted with
@@ -330,8 +336,7 @@ impl<'a> PdfPageTextChar<'a> {
     ///
     /// Pdfium may not reliably return the correct value of this flag for built-in fonts.
     pub fn font_is_bold_reenforced(&self) -> bool {
-        self.font_flags_bits()
-            .contains(FpdfFontDescriptorFlags::FORCE_BOLD_BIT_19)
+        self.font_flags().is_bold_reenforced()
     }
 
     #[cfg(any(
@@ -348,8 +353,9 @@ impl<'a> PdfPageTextChar<'a> {
             .FPDFText_GetTextObject(self.text_page_handle(), self.index);
 
         if object_handle.is_null() {
-            Err(PdfiumError::PdfiumLibraryInternalError(
-                PdfiumInternalError::Unknown,
+            Err(self.bindings().error_in(
+                "FPDFText_GetTextObject",
+                PdfiumError::PdfiumLibraryInternalError(PdfiumInternalError::Unknown),
             ))
         } else {
             Ok(PdfPageTextObject::from_pdfium(
@@ -426,7 +432,10 @@ impl<'a> PdfPageTextChar<'a> {
                     .map_err(PdfiumError::UnableToConvertPdfiumColorValueToRustu8)?,
             ))
         } else {
-            Err(PdfiumError::PdfiumFunctionReturnValueIndicatedFailure)
+            Err(self.bindings.error_in(
+                "FPDFText_GetFillColor",
+                PdfiumError::PdfiumFunctionReturnValueIndicatedFailure,
+            ))
         }
     }
 
@@ -462,7 +471,10 @@ impl<'a> PdfPageTextChar<'a> {
                     .map_err(PdfiumError::UnableToConvertPdfiumColorValueToRustu8)?,
             ))
         } else {
-            Err(PdfiumError::PdfiumFunctionReturnValueIndicatedFailure)
+            Err(self.bindings.error_in(
+                "FPDFText_GetStrokeColor",
+                PdfiumError::PdfiumFunctionReturnValueIndicatedFailure,
+            ))
         }
     }
 
@@ -480,7 +492,10 @@ impl<'a> PdfPageTextChar<'a> {
             .FPDFText_GetCharAngle(self.text_page_handle, self.index);
 
         if result == -1.0 {
-            Err(PdfiumError::PdfiumFunctionReturnValueIndicatedFailure)
+            Err(self.bindings.error_in(
+                "FPDFText_GetCharAngle",
+                PdfiumError::PdfiumFunctionReturnValueIndicatedFailure,
+            ))
         } else {
             Ok(result)
         }
@@ -521,6 +536,70 @@ impl<'a> PdfPageTextChar<'a> {
         )
     }
 
+    /// Returns a precise bounding quadrilateral for this character, taking both the character's
+    /// specific shape and its rotation angle into account.
+    ///
+    /// The bounding box returned by [PdfPageTextChar::tight_bounds] is always axis-aligned,
+    /// so for rotated characters it covers more of the page than the glyph itself. This function
+    /// instead returns the four corners of the glyph's rotated bounding box, ordered
+    /// counter-clockwise starting from the bottom left corner of the glyph in its own
+    /// (unrotated) orientation. For unrotated characters the result is equivalent to
+    /// [PdfPageTextChar::tight_bounds].
+    pub fn tight_quad_points(&self) -> Result<PdfQuadPoints, PdfiumError> {
+        let bounds = self.tight_bounds()?;
+
+        let angle = self.angle_radians()?;
+
+        let (sin, cos) = angle.sin_cos();
+
+        let (abs_sin, abs_cos) = (sin.abs(), cos.abs());
+
+        let outer_width = bounds.width().value;
+
+        let outer_height = bounds.height().value;
+
+        // The axis-aligned bounding box of a w x h rectangle rotated by the character angle
+        // has dimensions (w|cos| + h|sin|) x (w|sin| + h|cos|). Solve for w and h, falling back
+        // to a square glyph box when the system is degenerate (i.e. at or near 45 degrees).
+
+        let determinant = abs_cos * abs_cos - abs_sin * abs_sin;
+
+        let (width, height) = if determinant.abs() > 0.01 {
+            (
+                ((outer_width * abs_cos - outer_height * abs_sin) / determinant).max(0.0),
+                ((outer_height * abs_cos - outer_width * abs_sin) / determinant).max(0.0),
+            )
+        } else {
+            let side = outer_width / (abs_cos + abs_sin);
+
+            (side, side)
+        };
+
+        let center_x = bounds.left().value + outer_width / 2.0;
+
+        let center_y = bounds.bottom().value + outer_height / 2.0;
+
+        let corner = |along: f32, across: f32| {
+            let u = along * width / 2.0;
+
+            let v = across * height / 2.0;
+
+            (center_x + u * cos - v * sin, center_y + u * sin + v * cos)
+        };
+
+        let (x1, y1) = corner(-1.0, -1.0);
+
+        let (x2, y2) = corner(1.0, -1.0);
+
+        let (x3, y3) = corner(1.0, 1.0);
+
+        let (x4, y4) = corner(-1.0, 1.0);
+
+        Ok(PdfQuadPoints::new_from_values(
+            x1, y1, x2, y2, x3, y3, x4, y4,
+        ))
+    }
+
     /// Returns a loose bounding box for this character, containing the entire glyph bounds.
     ///
     /// To return a tight bounding box that takes this character's specific shape into
@@ -560,8 +639,9 @@ impl<'a> PdfPageTextChar<'a> {
         )) {
             Ok(matrix)
         } else {
-            Err(PdfiumError::PdfiumLibraryInternalError(
-                PdfiumInternalError::Unknown,
+            Err(self.bindings().error_in(
+                "FPDFText_GetMatrix",
+                PdfiumError::PdfiumLibraryInternalError(PdfiumInternalError::Unknown),
             ))
         }
     }
@@ -724,8 +804,9 @@ impl<'a> PdfPageTextChar<'a> {
         {
             Ok((PdfPoints::new(x as f32), PdfPoints::new(y as f32)))
         } else {
-            Err(PdfiumError::PdfiumLibraryInternalError(
-                PdfiumInternalError::Unknown,
+            Err(self.bindings().error_in(
+                "FPDFText_GetCharOrigin",
+                PdfiumError::PdfiumLibraryInternalError(PdfiumInternalError::Unknown),
             ))
         }
     }
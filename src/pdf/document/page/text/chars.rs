@@ -5,6 +5,7 @@ use crate::bindgen::{FPDF_DOCUMENT, FPDF_PAGE, FPDF_TEXTPAGE};
 use crate::bindings::PdfiumLibraryBindings;
 use crate::error::PdfiumError;
 use crate::pdf::document::page::text::char::PdfPageTextChar;
+use crate::pdf::document::page::text::PdfPageTextHandle;
 use crate::pdf::document::page::{
     PdfPage, PdfPageContentRegenerationStrategy, PdfPageIndexCache, PdfPageText,
 };
@@ -12,6 +13,7 @@ use crate::pdf::document::pages::PdfPageIndex;
 use crate::pdf::points::PdfPoints;
 use std::ops::Range;
 use std::os::raw::c_int;
+use std::ptr::null_mut;
 
 /// The zero-based index of a single [PdfPageTextChar] inside its containing [PdfPageTextChars] collection.
 pub type PdfPageTextCharIndex = usize;
@@ -21,10 +23,11 @@ pub type PdfPageTextCharIndex = usize;
 pub struct PdfPageTextChars<'a> {
     document_handle: FPDF_DOCUMENT,
     page_handle: FPDF_PAGE,
-    text_page_handle: FPDF_TEXTPAGE,
+    text_page: Option<PdfPageTextHandle<'a>>,
     source_page: Option<PdfPage<'a>>,
     start: i32,
     len: i32,
+    indices: Option<Vec<i32>>,
     bindings: &'a dyn PdfiumLibraryBindings,
 }
 
@@ -33,7 +36,7 @@ impl<'a> PdfPageTextChars<'a> {
     pub(crate) fn new(
         document_handle: FPDF_DOCUMENT,
         page_handle: FPDF_PAGE,
-        text_page_handle: FPDF_TEXTPAGE,
+        text_page: PdfPageTextHandle<'a>,
         start: i32,
         len: i32,
         bindings: &'a dyn PdfiumLibraryBindings,
@@ -41,10 +44,37 @@ impl<'a> PdfPageTextChars<'a> {
         PdfPageTextChars {
             document_handle,
             page_handle,
-            text_page_handle,
+            text_page: Some(text_page),
             source_page: None,
             start,
             len,
+            indices: None,
+            bindings,
+        }
+    }
+
+    /// Creates a new [PdfPageTextChars] instance containing only the characters with the
+    /// given indices, which must be sorted in ascending order. Unlike a collection created
+    /// by [PdfPageTextChars::new()], the characters need not be contiguous.
+    pub(crate) fn new_from_indices(
+        document_handle: FPDF_DOCUMENT,
+        page_handle: FPDF_PAGE,
+        text_page: PdfPageTextHandle<'a>,
+        indices: Vec<i32>,
+        bindings: &'a dyn PdfiumLibraryBindings,
+    ) -> Self {
+        let start = indices.first().copied().unwrap_or(0);
+
+        let len = indices.last().map(|last| last - start + 1).unwrap_or(0);
+
+        PdfPageTextChars {
+            document_handle,
+            page_handle,
+            text_page: Some(text_page),
+            source_page: None,
+            start,
+            len,
+            indices: Some(indices),
             bindings,
         }
     }
@@ -52,14 +82,15 @@ impl<'a> PdfPageTextChars<'a> {
     /// Creates a new [PdfPageTextChars] instance for the given character range
     /// by loading the text page for the given page index in the given document handle.
     /// The newly created [PdfPageTextChars] instance will take ownership of both the page
-    /// and its text page, disposing of both when the [PdfPageTextChars] instance leaves scope.
+    /// and its text page, deleting the page from the document when the [PdfPageTextChars]
+    /// instance leaves scope.
     pub(crate) fn new_with_owned_page(
         document_handle: FPDF_DOCUMENT,
         page_index: c_int,
         start: i32,
         len: i32,
         bindings: &'a dyn PdfiumLibraryBindings,
-    ) -> Self {
+    ) -> Result<Self, PdfiumError> {
         let page_handle = bindings.FPDF_LoadPage(document_handle, page_index);
 
         PdfPageIndexCache::cache_props_for_page(
@@ -71,17 +102,25 @@ impl<'a> PdfPageTextChars<'a> {
 
         let page = PdfPage::from_pdfium(document_handle, page_handle, None, None, bindings);
 
-        let text_page_handle = bindings.FPDFText_LoadPage(page.page_handle());
+        let text_page = match page.text_page() {
+            Ok(text_page) => text_page,
+            Err(err) => {
+                page.delete()?;
 
-        PdfPageTextChars {
+                return Err(err);
+            }
+        };
+
+        Ok(PdfPageTextChars {
             document_handle,
             page_handle,
-            text_page_handle,
+            text_page: Some(text_page),
             source_page: Some(page),
             start,
             len,
+            indices: None,
             bindings,
-        }
+        })
     }
 
     /// Returns the internal `FPDF_DOCUMENT` handle of the [PdfDocument] containing this
@@ -101,7 +140,10 @@ impl<'a> PdfPageTextChars<'a> {
     /// Returns the internal `FPDF_TEXTPAGE` handle for this [PdfPageTextChars] collection.
     #[inline]
     pub(crate) fn text_page_handle(&self) -> FPDF_TEXTPAGE {
-        self.text_page_handle
+        self.text_page
+            .as_ref()
+            .map(|text_page| text_page.handle())
+            .unwrap_or(null_mut())
     }
 
     /// Returns the [PdfiumLibraryBindings] used by this [PdfPageTextChars] collection.
@@ -120,7 +162,10 @@ impl<'a> PdfPageTextChars<'a> {
     /// Returns the number of individual characters in this [PdfPageTextChars] collection.
     #[inline]
     pub fn len(&self) -> PdfPageTextCharIndex {
-        self.len as PdfPageTextCharIndex
+        match self.indices.as_ref() {
+            Some(indices) => indices.len(),
+            None => self.len as PdfPageTextCharIndex,
+        }
     }
 
     /// Returns the index in the containing [PdfPage] of the last character in this
@@ -147,7 +192,14 @@ impl<'a> PdfPageTextChars<'a> {
     pub fn get(&self, index: PdfPageTextCharIndex) -> Result<PdfPageTextChar, PdfiumError> {
         let index = index as i32;
 
-        if index < self.start || index >= self.start + self.len {
+        if index < self.start
+            || index >= self.start + self.len
+            || self
+                .indices
+                .as_ref()
+                .map(|indices| indices.binary_search(&index).is_err())
+                .unwrap_or(false)
+        {
             Err(PdfiumError::CharIndexOutOfBounds)
         } else {
             Ok(PdfPageTextChar::from_pdfium(
@@ -195,17 +247,40 @@ impl<'a> PdfPageTextChars<'a> {
     pub fn iter(&self) -> PdfPageTextCharsIterator {
         PdfPageTextCharsIterator::new(self)
     }
+
+    /// Returns the index in the containing [PdfPage] of the character at the given
+    /// zero-based position in this [PdfPageTextChars] collection, if any.
+    #[inline]
+    fn index_at_position(&self, position: usize) -> Option<PdfPageTextCharIndex> {
+        match self.indices.as_ref() {
+            Some(indices) => indices
+                .get(position)
+                .map(|index| *index as PdfPageTextCharIndex),
+            None => {
+                if position < self.len() {
+                    Some(self.first_char_index() + position)
+                } else {
+                    None
+                }
+            }
+        }
+    }
 }
 
 impl<'a> Drop for PdfPageTextChars<'a> {
     /// Closes this [PdfPageTextChars] object, releasing held memory.
     #[inline]
     fn drop(&mut self) {
+        // Release this collection's reference to the text page before closing the page
+        // the text page was loaded from.
+
+        self.text_page = None;
+
         if let Some(page) = self.source_page.take() {
             // This PdfPageTextChars instance had ownership over the page and text page
             // to which it was bound. Release those resources now.
 
-            self.bindings().FPDFText_ClosePage(self.text_page_handle());
+            page.discard_cached_text_page();
             assert!(page.delete().is_ok());
         }
     }
@@ -214,7 +289,7 @@ impl<'a> Drop for PdfPageTextChars<'a> {
 /// An iterator over all the [PdfPageTextChar] objects in a [PdfPageTextChars] collection.
 pub struct PdfPageTextCharsIterator<'a> {
     chars: &'a PdfPageTextChars<'a>,
-    next_index: PdfPageTextCharIndex,
+    next_position: usize,
 }
 
 impl<'a> PdfPageTextCharsIterator<'a> {
@@ -222,7 +297,7 @@ impl<'a> PdfPageTextCharsIterator<'a> {
     pub(crate) fn new(chars: &'a PdfPageTextChars<'a>) -> Self {
         PdfPageTextCharsIterator {
             chars,
-            next_index: chars.first_char_index(),
+            next_position: 0,
         }
     }
 }
@@ -231,10 +306,13 @@ impl<'a> Iterator for PdfPageTextCharsIterator<'a> {
     type Item = PdfPageTextChar<'a>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        let next = self.chars.get(self.next_index);
+        let next = self
+            .chars
+            .index_at_position(self.next_position)
+            .and_then(|index| self.chars.get(index).ok());
 
-        self.next_index += 1;
+        self.next_position += 1;
 
-        next.ok()
+        next
     }
 }
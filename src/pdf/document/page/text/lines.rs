@@ -0,0 +1,736 @@
+//! Defines the [PdfPageTextLine] and [PdfPageTextWord] structs, grouping the characters
+//! in a `PdfPageText` collection into lines and words, and the [PdfTextSegmentationConfig]
+//! struct controlling how that grouping is performed.
+
+use crate::pdf::document::page::text::chars::PdfPageTextCharIndex;
+use crate::pdf::document::page::text::PdfPageText;
+use crate::pdf::points::PdfPoints;
+use crate::pdf::rect::PdfRect;
+use std::ops::Range;
+
+/// Configures the heuristics used when grouping the characters in a `PdfPageText` collection
+/// into lines and words using the `PdfPageText::lines_with_config()` function.
+///
+/// All distances are expressed as fractions of an em, i.e. as multiples of the font size
+/// of the characters being compared, so that the same settings work for text of any size.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PdfTextSegmentationConfig {
+    word_gap_threshold: f32,
+    baseline_tolerance: f32,
+    column_gap_threshold: f32,
+    join_hyphenated_words: bool,
+}
+
+impl PdfTextSegmentationConfig {
+    /// Creates a new [PdfTextSegmentationConfig] object with all settings initialized with
+    /// their default values.
+    pub fn new() -> Self {
+        PdfTextSegmentationConfig {
+            word_gap_threshold: 0.2,
+            baseline_tolerance: 0.4,
+            column_gap_threshold: 1.5,
+            join_hyphenated_words: false,
+        }
+    }
+
+    /// Sets the smallest horizontal gap between two adjacent characters, in ems, that
+    /// separates them into different words even when no whitespace character lies between
+    /// them. The default is `0.2`.
+    pub fn set_word_gap_threshold(mut self, ems: f32) -> Self {
+        self.word_gap_threshold = ems;
+
+        self
+    }
+
+    /// Sets the largest vertical distance between the baselines of two characters, in ems,
+    /// for the characters to be considered part of the same line. The default is `0.4`.
+    pub fn set_baseline_tolerance(mut self, ems: f32) -> Self {
+        self.baseline_tolerance = ems;
+
+        self
+    }
+
+    /// Sets the smallest horizontal gap between two runs of characters sharing a baseline,
+    /// in ems, that separates them into different lines. Runs separated by such a gap are
+    /// assumed to belong to different columns. The default is `1.5`.
+    pub fn set_column_gap_threshold(mut self, ems: f32) -> Self {
+        self.column_gap_threshold = ems;
+
+        self
+    }
+
+    /// Controls whether a word broken across two lines with a hyphen should be joined back
+    /// together. When set, the hyphen is removed from the text of the joined word, and the
+    /// joined word is placed on the first of the two lines. The default is `false`.
+    pub fn join_hyphenated_words(mut self, do_join: bool) -> Self {
+        self.join_hyphenated_words = do_join;
+
+        self
+    }
+}
+
+impl Default for PdfTextSegmentationConfig {
+    #[inline]
+    fn default() -> Self {
+        PdfTextSegmentationConfig::new()
+    }
+}
+
+/// A single word in a [PdfPageTextLine].
+#[derive(Debug, Clone, PartialEq)]
+pub struct PdfPageTextWord {
+    text: String,
+    bounds: PdfRect,
+    char_ranges: Vec<Range<PdfPageTextCharIndex>>,
+    is_right_to_left: bool,
+}
+
+impl PdfPageTextWord {
+    /// Returns the text of this [PdfPageTextWord], in logical order.
+    #[inline]
+    pub fn text(&self) -> &str {
+        self.text.as_str()
+    }
+
+    /// Returns the bounding box of this [PdfPageTextWord]. The bounding box of a word joined
+    /// across two lines covers both parts of the word.
+    #[inline]
+    pub fn bounds(&self) -> PdfRect {
+        self.bounds
+    }
+
+    /// Returns the ranges of indices of the characters in the containing `PdfPageText`
+    /// that make up this [PdfPageTextWord]. A word usually consists of a single range
+    /// of characters, but a word joined across two lines consists of two.
+    #[inline]
+    pub fn char_ranges(&self) -> &[Range<PdfPageTextCharIndex>] {
+        self.char_ranges.as_slice()
+    }
+
+    /// Returns `true` if the characters in this [PdfPageTextWord] are written from right
+    /// to left, as in Arabic and Hebrew text.
+    #[inline]
+    pub fn is_right_to_left(&self) -> bool {
+        self.is_right_to_left
+    }
+}
+
+/// A single line of text in a `PdfPageText` collection, consisting of one or more
+/// [PdfPageTextWord] words sharing a common baseline.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PdfPageTextLine {
+    words: Vec<PdfPageTextWord>,
+    bounds: PdfRect,
+    baseline: PdfPoints,
+}
+
+impl PdfPageTextLine {
+    /// Returns the words in this [PdfPageTextLine], in logical order.
+    #[inline]
+    pub fn words(&self) -> &[PdfPageTextWord] {
+        self.words.as_slice()
+    }
+
+    /// Returns the text of this [PdfPageTextLine], with its words separated by single spaces.
+    pub fn text(&self) -> String {
+        self.words
+            .iter()
+            .map(|word| word.text())
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    /// Returns the bounding box of this [PdfPageTextLine].
+    #[inline]
+    pub fn bounds(&self) -> PdfRect {
+        self.bounds
+    }
+
+    /// Returns the vertical position of the baseline of this [PdfPageTextLine].
+    #[inline]
+    pub fn baseline(&self) -> PdfPoints {
+        self.baseline
+    }
+}
+
+/// The position and size of a single character, as used during segmentation.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct SegmentationChar {
+    pub(crate) index: PdfPageTextCharIndex,
+    pub(crate) char: char,
+    pub(crate) left: f32,
+    pub(crate) right: f32,
+    pub(crate) bottom: f32,
+    pub(crate) top: f32,
+    pub(crate) baseline: f32,
+    pub(crate) font_size: f32,
+}
+
+impl SegmentationChar {
+    #[inline]
+    fn is_right_to_left(&self) -> bool {
+        matches!(
+            self.char as u32,
+            0x0590..=0x08FF | 0xFB1D..=0xFDFF | 0xFE70..=0xFEFF
+        )
+    }
+
+    /// Returns the horizontal distance between this character and the given character.
+    /// The distance is negative if the two characters overlap.
+    #[inline]
+    fn gap_to(&self, other: &SegmentationChar) -> f32 {
+        (other.left - self.right).max(self.left - other.right)
+    }
+}
+
+/// A contiguous run of characters sharing a baseline, split into words.
+struct Fragment {
+    words: Vec<Vec<SegmentationChar>>,
+    left: f32,
+    right: f32,
+    baseline: f32,
+    font_size: f32,
+}
+
+impl Fragment {
+    fn new(char: SegmentationChar) -> Self {
+        Fragment {
+            words: vec![vec![char]],
+            left: char.left,
+            right: char.right,
+            baseline: char.baseline,
+            font_size: char.font_size,
+        }
+    }
+
+    fn push(&mut self, char: SegmentationChar, is_new_word: bool) {
+        if is_new_word {
+            self.words.push(vec![char]);
+        } else if let Some(word) = self.words.last_mut() {
+            word.push(char);
+        }
+
+        self.left = self.left.min(char.left);
+        self.right = self.right.max(char.right);
+        self.font_size = self.font_size.max(char.font_size);
+    }
+
+    #[inline]
+    fn last_char(&self) -> Option<&SegmentationChar> {
+        self.words.last().and_then(|word| word.last())
+    }
+}
+
+/// A line under construction, consisting of fragments sharing a baseline.
+struct LineBuilder {
+    fragments: Vec<Fragment>,
+    left: f32,
+    right: f32,
+    baseline: f32,
+    font_size: f32,
+}
+
+/// Returns the position and size of each character in the given [PdfPageText] collection,
+/// in content order.
+pub(crate) fn segmentation_chars(text: &PdfPageText) -> Vec<SegmentationChar> {
+    text.chars()
+        .iter()
+        .filter_map(|char| {
+            let unicode = char.unicode_char()?;
+
+            if unicode.is_whitespace() {
+                // The bounds of whitespace characters generated by Pdfium are unreliable.
+                // Only their position in content order is needed, to separate words.
+
+                return Some(SegmentationChar {
+                    index: char.index(),
+                    char: unicode,
+                    left: 0.0,
+                    right: 0.0,
+                    bottom: 0.0,
+                    top: 0.0,
+                    baseline: 0.0,
+                    font_size: 0.0,
+                });
+            }
+
+            let bounds = char.loose_bounds().ok()?;
+
+            let baseline = char
+                .origin_y()
+                .map(|y| y.value)
+                .unwrap_or(bounds.bottom().value);
+
+            let font_size = match char.scaled_font_size().value {
+                size if size > 0.0 => size,
+                _ => bounds.height().value,
+            };
+
+            Some(SegmentationChar {
+                index: char.index(),
+                char: unicode,
+                left: bounds.left().value,
+                right: bounds.right().value,
+                bottom: bounds.bottom().value,
+                top: bounds.top().value,
+                baseline,
+                font_size,
+            })
+        })
+        .collect()
+}
+
+/// Groups the given characters, given in content order, into lines and words.
+pub(crate) fn segment(
+    chars: &[SegmentationChar],
+    config: &PdfTextSegmentationConfig,
+) -> Vec<PdfPageTextLine> {
+    // First, split the characters into fragments: runs of characters, consecutive in content
+    // order, that share a baseline and are not separated by a gap wide enough to indicate
+    // a column break.
+
+    let mut fragments: Vec<Fragment> = Vec::new();
+
+    let mut is_after_whitespace = false;
+
+    for char in chars.iter() {
+        if char.char.is_whitespace() {
+            is_after_whitespace = true;
+
+            continue;
+        }
+
+        let fragment = fragments.last_mut().filter(|fragment| {
+            fragment.last_char().map_or(false, |previous| {
+                let em = previous.font_size.max(char.font_size).max(1.0);
+
+                (char.baseline - previous.baseline).abs() <= config.baseline_tolerance * em
+                    && previous.gap_to(char) <= config.column_gap_threshold * em
+            })
+        });
+
+        match fragment {
+            Some(fragment) => {
+                let previous = fragment.last_char().copied().unwrap();
+
+                let em = previous.font_size.max(char.font_size).max(1.0);
+
+                let is_new_word = is_after_whitespace
+                    || previous.gap_to(char) > config.word_gap_threshold * em
+                    || previous.is_right_to_left() != char.is_right_to_left();
+
+                fragment.push(*char, is_new_word);
+            }
+            None => fragments.push(Fragment::new(*char)),
+        }
+
+        is_after_whitespace = false;
+    }
+
+    // Next, merge fragments that share a baseline and lie close together into lines.
+    // Fragments are not necessarily consecutive in content order; a line may be drawn
+    // in several pieces.
+
+    fragments.sort_by(|a, b| {
+        b.baseline
+            .partial_cmp(&a.baseline)
+            .unwrap_or(std::cmp::Ordering::Equal)
+            .then(
+                a.left
+                    .partial_cmp(&b.left)
+                    .unwrap_or(std::cmp::Ordering::Equal),
+            )
+    });
+
+    let mut lines: Vec<LineBuilder> = Vec::new();
+
+    for fragment in fragments {
+        let line = lines.iter_mut().find(|line| {
+            let em = line.font_size.max(fragment.font_size).max(1.0);
+
+            let gap = (fragment.left - line.right).max(line.left - fragment.right);
+
+            (fragment.baseline - line.baseline).abs() <= config.baseline_tolerance * em
+                && gap <= config.column_gap_threshold * em
+        });
+
+        match line {
+            Some(line) => {
+                line.left = line.left.min(fragment.left);
+                line.right = line.right.max(fragment.right);
+                line.font_size = line.font_size.max(fragment.font_size);
+                line.fragments.push(fragment);
+            }
+            None => lines.push(LineBuilder {
+                left: fragment.left,
+                right: fragment.right,
+                baseline: fragment.baseline,
+                font_size: fragment.font_size,
+                fragments: vec![fragment],
+            }),
+        }
+    }
+
+    // Next, cluster lines into columns by their horizontal extents, so that lines in
+    // side-by-side columns are not interleaved. Narrow lines are assigned first so that
+    // a wide line spanning several columns, such as a heading, does not merge them.
+
+    let mut by_width = (0..lines.len()).collect::<Vec<_>>();
+
+    by_width.sort_by(|a, b| {
+        (lines[*a].right - lines[*a].left)
+            .partial_cmp(&(lines[*b].right - lines[*b].left))
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+
+    let mut columns: Vec<(f32, f32, Vec<usize>)> = Vec::new();
+
+    for index in by_width {
+        let line = &lines[index];
+
+        let overlap =
+            |column: &(f32, f32, Vec<usize>)| line.right.min(column.1) - line.left.max(column.0);
+
+        let overlapping = columns
+            .iter()
+            .filter(|column| overlap(column) > 0.0)
+            .count();
+
+        let best = columns
+            .iter_mut()
+            .filter(|column| overlap(column) > 0.0)
+            .max_by(|a, b| {
+                overlap(a)
+                    .partial_cmp(&overlap(b))
+                    .unwrap_or(std::cmp::Ordering::Equal)
+            });
+
+        match best {
+            Some(column) => {
+                if overlapping == 1 {
+                    column.0 = column.0.min(line.left);
+                    column.1 = column.1.max(line.right);
+                }
+
+                column.2.push(index);
+            }
+            None => columns.push((line.left, line.right, vec![index])),
+        }
+    }
+
+    columns.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(std::cmp::Ordering::Equal));
+
+    let mut lines = lines.into_iter().map(Some).collect::<Vec<_>>();
+
+    let mut result = Vec::new();
+
+    for (_, _, mut indices) in columns {
+        indices.sort_by(|a, b| {
+            let (a, b) = (lines[*a].as_ref().unwrap(), lines[*b].as_ref().unwrap());
+
+            b.baseline
+                .partial_cmp(&a.baseline)
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+
+        let mut column = indices
+            .into_iter()
+            .filter_map(|index| lines[index].take())
+            .map(|line| build_line(line, config))
+            .collect::<Vec<_>>();
+
+        if config.join_hyphenated_words {
+            join_hyphenated_words(&mut column);
+        }
+
+        result.extend(column.into_iter().filter(|line| !line.words.is_empty()));
+    }
+
+    result
+}
+
+/// Converts the given line under construction into a [PdfPageTextLine].
+fn build_line(mut line: LineBuilder, config: &PdfTextSegmentationConfig) -> PdfPageTextLine {
+    // Fragments are ordered by their horizontal position, in the direction of writing
+    // of the majority of characters in the line.
+
+    let (right_to_left, total) = line
+        .fragments
+        .iter()
+        .flat_map(|fragment| fragment.words.iter().flatten())
+        .fold((0, 0), |(rtl, total), char| {
+            (rtl + char.is_right_to_left() as usize, total + 1)
+        });
+
+    let is_right_to_left = right_to_left * 2 > total;
+
+    if is_right_to_left {
+        line.fragments.sort_by(|a, b| {
+            b.right
+                .partial_cmp(&a.right)
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+    } else {
+        line.fragments.sort_by(|a, b| {
+            a.left
+                .partial_cmp(&b.left)
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+    }
+
+    let mut words: Vec<Vec<SegmentationChar>> = Vec::new();
+
+    for fragment in line.fragments {
+        for (index, word) in fragment.words.into_iter().enumerate() {
+            // A word may be split across two adjacent fragments.
+
+            let previous = words.last_mut().filter(|previous| {
+                index == 0
+                    && previous.last().map_or(false, |last| {
+                        let em = last.font_size.max(word[0].font_size).max(1.0);
+
+                        last.gap_to(&word[0]) <= config.word_gap_threshold * em
+                    })
+            });
+
+            match previous {
+                Some(previous) => previous.extend(word),
+                None => words.push(word),
+            }
+        }
+    }
+
+    let words = words
+        .iter()
+        .map(|chars| build_word(chars))
+        .collect::<Vec<_>>();
+
+    PdfPageTextLine {
+        bounds: union(words.iter().map(|word| word.bounds)),
+        words,
+        baseline: PdfPoints::new(line.baseline),
+    }
+}
+
+/// Converts the given characters, in logical order, into a [PdfPageTextWord].
+fn build_word(chars: &[SegmentationChar]) -> PdfPageTextWord {
+    let mut char_ranges: Vec<Range<PdfPageTextCharIndex>> = Vec::new();
+
+    for char in chars.iter() {
+        match char_ranges.last_mut() {
+            Some(range) if range.end == char.index => range.end += 1,
+            _ => char_ranges.push(char.index..char.index + 1),
+        }
+    }
+
+    PdfPageTextWord {
+        text: chars.iter().map(|char| char.char).collect(),
+        bounds: union(
+            chars
+                .iter()
+                .map(|char| PdfRect::new_from_values(char.bottom, char.left, char.top, char.right)),
+        ),
+        char_ranges,
+        is_right_to_left: chars.iter().any(|char| char.is_right_to_left()),
+    }
+}
+
+/// Joins words broken across consecutive lines in the given column with a hyphen.
+fn join_hyphenated_words(lines: &mut [PdfPageTextLine]) {
+    for index in 1..lines.len() {
+        let (previous, next) = lines.split_at_mut(index);
+
+        let (previous, next) = (&mut previous[index - 1], &mut next[0]);
+
+        let is_hyphenated = previous.words.last().map_or(false, |word| {
+            word.text.chars().count() > 1 && word.text.ends_with(&['-', '\u{00AD}', '\u{2010}'][..])
+        });
+
+        if !is_hyphenated || next.words.is_empty() {
+            continue;
+        }
+
+        let continuation = next.words.remove(0);
+
+        if let Some(word) = previous.words.last_mut() {
+            word.text.pop();
+            word.text.push_str(continuation.text());
+            word.bounds = union([word.bounds, continuation.bounds].iter().copied());
+            word.char_ranges.extend(continuation.char_ranges);
+        }
+
+        previous.bounds = union(previous.words.iter().map(|word| word.bounds));
+
+        if !next.words.is_empty() {
+            next.bounds = union(next.words.iter().map(|word| word.bounds));
+        }
+    }
+}
+
+/// Returns the smallest rectangle enclosing all the given rectangles.
+fn union(rects: impl Iterator<Item = PdfRect>) -> PdfRect {
+    rects
+        .map(|rect| {
+            (
+                rect.bottom().value,
+                rect.left().value,
+                rect.top().value,
+                rect.right().value,
+            )
+        })
+        .reduce(|a, b| (a.0.min(b.0), a.1.min(b.1), a.2.max(b.2), a.3.max(b.3)))
+        .map(|(bottom, left, top, right)| PdfRect::new_from_values(bottom, left, top, right))
+        .unwrap_or(PdfRect::ZERO)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{segment, SegmentationChar};
+    use crate::prelude::*;
+    use crate::utils::test::test_bind_to_pdfium;
+
+    /// Lays out the given text as a run of characters, each 6 points wide and 10 points high,
+    /// starting at the given position. Characters are laid out leftwards if `rtl` is set.
+    fn run(
+        text: &str,
+        x: f32,
+        baseline: f32,
+        first_index: usize,
+        rtl: bool,
+    ) -> Vec<SegmentationChar> {
+        text.chars()
+            .enumerate()
+            .map(|(offset, char)| {
+                let left = if rtl {
+                    x - 6.0 * (offset + 1) as f32
+                } else {
+                    x + 6.0 * offset as f32
+                };
+
+                SegmentationChar {
+                    index: first_index + offset,
+                    char,
+                    left,
+                    right: left + 6.0,
+                    bottom: baseline - 2.0,
+                    top: baseline + 8.0,
+                    baseline,
+                    font_size: 10.0,
+                }
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_segment_right_to_left_and_hyphenation() {
+        // A Hebrew line, laid out leftwards from x = 200, must keep its logical order.
+
+        let chars = run("שלום עולם", 200.0, 700.0, 0, true);
+
+        let lines = segment(&chars, &PdfTextSegmentationConfig::new());
+
+        assert_eq!(lines.len(), 1);
+        assert_eq!(lines[0].text(), "שלום עולם");
+        assert!(lines[0].words().iter().all(|word| word.is_right_to_left()));
+        assert_eq!(lines[0].words()[1].char_ranges().len(), 1);
+        assert_eq!(lines[0].words()[1].char_ranges()[0], 5..9);
+
+        // A hyphenated line break is only joined when requested.
+
+        let mut chars = run("segmen-", 100.0, 700.0, 0, false);
+
+        chars.extend(run("tation works", 100.0, 688.0, 7, false));
+
+        let lines = segment(&chars, &PdfTextSegmentationConfig::new());
+
+        assert_eq!(
+            lines.iter().map(|line| line.text()).collect::<Vec<_>>(),
+            vec!["segmen-", "tation works"]
+        );
+
+        let lines = segment(
+            &chars,
+            &PdfTextSegmentationConfig::new().join_hyphenated_words(true),
+        );
+
+        assert_eq!(
+            lines.iter().map(|line| line.text()).collect::<Vec<_>>(),
+            vec!["segmentation", "works"]
+        );
+        assert_eq!(lines[0].words()[0].char_ranges(), &[0..7, 7..13]);
+    }
+
+    #[test]
+    fn test_lines_in_two_columns() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        let font = document.fonts_mut().helvetica();
+
+        let mut page = document
+            .pages_mut()
+            .create_page_at_end(PdfPagePaperSize::a4())?;
+
+        // Lines in the two columns share baselines, so sorting lines by their vertical
+        // position alone would interleave the columns. The lines are added row by row to
+        // make the content order interleaved as well.
+
+        let rows = [
+            ("Left column one", "Right column one"),
+            ("Left column two", "Right column two"),
+            ("Left column three", "Right column three"),
+        ];
+
+        for (index, (left, right)) in rows.iter().enumerate() {
+            let y = PdfPoints::new(700.0 - 14.0 * index as f32);
+
+            page.objects_mut().create_text_object(
+                PdfPoints::new(50.0),
+                y,
+                left,
+                font,
+                PdfPoints::new(12.0),
+            )?;
+
+            page.objects_mut().create_text_object(
+                PdfPoints::new(320.0),
+                y,
+                right,
+                font,
+                PdfPoints::new(12.0),
+            )?;
+        }
+
+        let text = page.text()?;
+
+        let lines = text.lines();
+
+        assert_eq!(
+            lines.iter().map(|line| line.text()).collect::<Vec<_>>(),
+            vec![
+                "Left column one",
+                "Left column two",
+                "Left column three",
+                "Right column one",
+                "Right column two",
+                "Right column three",
+            ]
+        );
+
+        for line in lines.iter() {
+            assert_eq!(line.words().len(), 3);
+
+            for word in line.words() {
+                assert!(line.bounds().contains(
+                    word.bounds().left() + PdfPoints::new(0.1),
+                    word.bounds().bottom() + PdfPoints::new(0.1)
+                ));
+            }
+        }
+
+        assert!(lines[0].bounds().right() < lines[3].bounds().left());
+
+        Ok(())
+    }
+}
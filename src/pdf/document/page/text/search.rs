@@ -1,21 +1,23 @@
 //! Defines the [PdfPageTextSearch] struct, exposing functionality related to searching
 //! the collection of Unicode characters visible in a single [PdfPage].
 
-use crate::bindgen::{FPDF_MATCHCASE, FPDF_MATCHWHOLEWORD, FPDF_SCHHANDLE};
+use crate::bindgen::{FPDF_CONSECUTIVE, FPDF_MATCHCASE, FPDF_MATCHWHOLEWORD, FPDF_SCHHANDLE};
 use crate::bindings::PdfiumLibraryBindings;
 use crate::pdf::document::page::text::chars::PdfPageTextCharIndex;
 use crate::pdf::document::page::text::segments::PdfPageTextSegments;
 use crate::pdf::document::page::text::PdfPageText;
+use crate::pdf::rect::PdfRect;
 use std::os::raw::c_ulong;
 
 #[cfg(doc)]
-use crate::pdf::document::page::PdfPage;
+use crate::pdf::document::{page::PdfPage, PdfDocument};
 
 /// Configures the search options that should be applied when creating a new [PdfPageTextSearch] object.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct PdfSearchOptions {
     match_case: bool,
     match_whole_word: bool,
+    consecutive: bool,
 }
 
 impl PdfSearchOptions {
@@ -24,6 +26,7 @@ impl PdfSearchOptions {
         PdfSearchOptions {
             match_case: false,
             match_whole_word: false,
+            consecutive: false,
         }
     }
 
@@ -43,6 +46,17 @@ impl PdfSearchOptions {
         self
     }
 
+    /// Controls whether the search should return overlapping results. When set, the search
+    /// for the next result begins at the character immediately following the start of the
+    /// previous result rather than at the character following the end of the previous result.
+    /// For example, searching for "aa" in "aaa" yields two results rather than one.
+    /// The default is `false`.
+    pub fn consecutive(mut self, do_search_consecutively: bool) -> Self {
+        self.consecutive = do_search_consecutively;
+
+        self
+    }
+
     pub(crate) fn as_pdfium(&self) -> c_ulong {
         let mut flag = 0;
 
@@ -52,6 +66,9 @@ impl PdfSearchOptions {
         if self.match_whole_word {
             flag |= FPDF_MATCHWHOLEWORD;
         }
+        if self.consecutive {
+            flag |= FPDF_CONSECUTIVE;
+        }
 
         flag as c_ulong
     }
@@ -71,8 +88,70 @@ pub enum PdfSearchDirection {
     SearchBackward,
 }
 
+/// A single result yielded by a [PdfPageTextSearch] object, identifying the position
+/// of one occurrence of the search target within the text of a single [PdfPage].
+///
+/// A [PdfSearchResult] captures all its information at the time it is yielded,
+/// so it can be retained after the [PdfPageTextSearch] and [PdfPageText] objects
+/// that created it have been dropped.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PdfSearchResult {
+    char_index: PdfPageTextCharIndex,
+    char_count: PdfPageTextCharIndex,
+    text: String,
+    rects: Vec<PdfRect>,
+}
+
+impl PdfSearchResult {
+    pub(crate) fn from_pdfium(
+        text_page: &PdfPageText,
+        char_index: PdfPageTextCharIndex,
+        char_count: PdfPageTextCharIndex,
+    ) -> Self {
+        PdfSearchResult {
+            char_index,
+            char_count,
+            text: text_page.for_range(char_index, char_count),
+            rects: text_page.rects_for_range(char_index, char_count),
+        }
+    }
+
+    /// Returns the index of the first character of this [PdfSearchResult] within
+    /// the containing [PdfPageText].
+    #[inline]
+    pub fn char_index(&self) -> PdfPageTextCharIndex {
+        self.char_index
+    }
+
+    /// Returns the number of characters in this [PdfSearchResult].
+    #[inline]
+    pub fn char_count(&self) -> PdfPageTextCharIndex {
+        self.char_count
+    }
+
+    /// Returns the text matched by this [PdfSearchResult]. Depending on the [PdfSearchOptions]
+    /// used, this may differ in case from the search target.
+    #[inline]
+    pub fn text(&self) -> &str {
+        self.text.as_str()
+    }
+
+    /// Returns the bounding rectangles of the characters matched by this [PdfSearchResult].
+    ///
+    /// A result that wraps across more than one line, or that spans text with differing
+    /// font settings, will be covered by more than one rectangle.
+    #[inline]
+    pub fn rects(&self) -> &[PdfRect] {
+        self.rects.as_slice()
+    }
+}
+
 /// Yields the results of searching for a given string within the collection of Unicode characters
 /// visible on a single [PdfPage].
+///
+/// [PdfPageTextSearch] implements the [Iterator] trait, yielding each [PdfSearchResult] in the
+/// direction [PdfSearchDirection::SearchForward]. Alternatively, use the [PdfPageTextSearch::iter()]
+/// function to iterate over the [PdfPageTextSegments] covered by each result in either direction.
 pub struct PdfPageTextSearch<'a> {
     search_handle: FPDF_SCHHANDLE,
     text_page: &'a PdfPageText<'a>,
@@ -121,6 +200,24 @@ impl<'a> PdfPageTextSearch<'a> {
     /// Returns the next search result yielded by this [PdfPageTextSearch] object
     /// in the given direction.
     pub fn get_next_result(&self, direction: PdfSearchDirection) -> Option<PdfPageTextSegments> {
+        self.find_next_range(direction)
+            .map(|(start_index, count)| self.text_page.segments_subset(start_index, count))
+    }
+
+    /// Returns the next [PdfSearchResult] yielded by this [PdfPageTextSearch] object
+    /// in the given direction.
+    pub fn get_next_search_result(&self, direction: PdfSearchDirection) -> Option<PdfSearchResult> {
+        self.find_next_range(direction).map(|(start_index, count)| {
+            PdfSearchResult::from_pdfium(self.text_page, start_index, count)
+        })
+    }
+
+    /// Advances the search in the given direction, returning the starting character index
+    /// and character count of the next result, if any.
+    fn find_next_range(
+        &self,
+        direction: PdfSearchDirection,
+    ) -> Option<(PdfPageTextCharIndex, PdfPageTextCharIndex)> {
         let has_next = if direction == PdfSearchDirection::SearchForward {
             self.bindings().FPDFText_FindNext(self.search_handle()) != 0
         } else {
@@ -133,10 +230,10 @@ impl<'a> PdfPageTextSearch<'a> {
                 .FPDFText_GetSchResultIndex(self.search_handle());
             let count = self.bindings().FPDFText_GetSchCount(self.search_handle());
 
-            return Some(self.text_page.segments_subset(
+            Some((
                 start_index as PdfPageTextCharIndex,
                 count as PdfPageTextCharIndex,
-            ));
+            ))
         } else {
             None
         }
@@ -150,6 +247,15 @@ impl<'a> PdfPageTextSearch<'a> {
     }
 }
 
+impl<'a> Iterator for PdfPageTextSearch<'a> {
+    type Item = PdfSearchResult;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.get_next_search_result(PdfSearchDirection::SearchForward)
+    }
+}
+
 impl<'a> Drop for PdfPageTextSearch<'a> {
     /// Closes this [PdfPageTextSearch] object, releasing held memory.
     #[inline]
@@ -177,3 +283,113 @@ impl<'a> Iterator for PdfPageTextSearchIterator<'a> {
         self.search.get_next_result(self.direction)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::utils::test::test_bind_to_pdfium;
+
+    #[test]
+    fn test_search_multi_byte_characters() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        let mut page = document
+            .pages_mut()
+            .create_page_at_start(PdfPagePaperSize::a4())?;
+
+        let font = document.fonts_mut().helvetica();
+
+        page.objects_mut().create_text_object(
+            PdfPoints::new(72.0),
+            PdfPoints::new(720.0),
+            "A naïve approach, but naïve NAÏVE.",
+            font,
+            PdfPoints::new(12.0),
+        )?;
+
+        let text = page.text()?;
+
+        let results = text
+            .search("naïve", &PdfSearchOptions::new().match_case(true))?
+            .collect::<Vec<_>>();
+
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].char_index(), 2);
+        assert_eq!(results[0].char_count(), 5);
+        assert_eq!(results[0].text(), "naïve");
+        assert_eq!(results[1].char_index(), 22);
+        assert!(!results[0].rects().is_empty());
+
+        let results = text
+            .search("naïve", &PdfSearchOptions::new())?
+            .collect::<Vec<_>>();
+
+        assert_eq!(results.len(), 3);
+        assert_eq!(results[2].text(), "NAÏVE");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_search_empty_target_is_error() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        document
+            .pages_mut()
+            .create_page_at_start(PdfPagePaperSize::a4())?;
+
+        let page = document.pages().first()?;
+
+        let text = page.text()?;
+
+        assert!(matches!(
+            text.search("", &PdfSearchOptions::new()),
+            Err(PdfiumError::TextSearchTargetIsEmpty)
+        ));
+        assert!(matches!(
+            document.search_all_pages("", &PdfSearchOptions::new()),
+            Err(PdfiumError::TextSearchTargetIsEmpty)
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_search_all_pages() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        let font = document.fonts_mut().helvetica();
+
+        for content in ["first page", "nothing here", "page three, last page"] {
+            let mut page = document
+                .pages_mut()
+                .create_page_at_end(PdfPagePaperSize::a4())?;
+
+            page.objects_mut().create_text_object(
+                PdfPoints::new(72.0),
+                PdfPoints::new(720.0),
+                content,
+                font,
+                PdfPoints::new(12.0),
+            )?;
+        }
+
+        let results = document.search_all_pages("page", &PdfSearchOptions::new())?;
+
+        assert_eq!(
+            results
+                .iter()
+                .map(|(index, result)| (*index, result.char_index()))
+                .collect::<Vec<_>>(),
+            vec![(0, 6), (2, 0), (2, 17)]
+        );
+
+        Ok(())
+    }
+}
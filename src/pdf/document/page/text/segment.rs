@@ -67,7 +67,7 @@ impl<'a> PdfPageTextSegment<'a> {
     /// which they are read by a user) may not necessarily match.
     #[inline]
     pub fn text(&self) -> String {
-        self.text.inside_rect(self.bounds)
+        self.text.text_in_rect(self.bounds)
     }
 
     /// Returns a collection of all the [PdfPageTextChar] characters that lie within the bounds of
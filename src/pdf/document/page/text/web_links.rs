@@ -0,0 +1,308 @@
+//! Defines the [PdfPageWebLinks] struct, a collection of all the web links detected
+//! in the text of a single `PdfPage`, and the [PdfPageWebLink] struct, exposing functionality
+//! related to a single detected web link.
+
+use crate::bindgen::FPDF_PAGELINK;
+use crate::bindings::PdfiumLibraryBindings;
+use crate::error::PdfiumError;
+use crate::pdf::document::page::text::chars::PdfPageTextCharIndex;
+use crate::pdf::document::page::text::PdfPageText;
+use crate::pdf::rect::PdfRect;
+use crate::utils::mem::create_sized_buffer;
+use crate::utils::utf16le::get_string_from_pdfium_utf16le_words;
+use std::ops::{Range, RangeInclusive};
+use std::os::raw::{c_double, c_int};
+
+#[cfg(doc)]
+use crate::pdf::document::page::PdfPage;
+
+/// The zero-based index of a single [PdfPageWebLink] inside its containing
+/// [PdfPageWebLinks] collection.
+pub type PdfPageWebLinkIndex = usize;
+
+/// The collection of web links detected by Pdfium in the text of a single [PdfPage].
+///
+/// Unlike the links returned by [PdfPage::links()], web links are not annotations or
+/// otherwise marked as links in the document; they are URLs that appear as plain text
+/// and are detected by Pdfium by examining the page's text layer.
+pub struct PdfPageWebLinks<'a> {
+    handle: FPDF_PAGELINK,
+    #[allow(dead_code)]
+    // The web links handle is only valid for as long as the text page it was loaded from.
+    text_page: &'a PdfPageText<'a>,
+    bindings: &'a dyn PdfiumLibraryBindings,
+}
+
+impl<'a> PdfPageWebLinks<'a> {
+    pub(crate) fn from_pdfium(
+        handle: FPDF_PAGELINK,
+        text_page: &'a PdfPageText<'a>,
+        bindings: &'a dyn PdfiumLibraryBindings,
+    ) -> Self {
+        PdfPageWebLinks {
+            handle,
+            text_page,
+            bindings,
+        }
+    }
+
+    /// Returns the internal `FPDF_PAGELINK` handle for this [PdfPageWebLinks] collection.
+    #[inline]
+    pub(crate) fn handle(&self) -> FPDF_PAGELINK {
+        self.handle
+    }
+
+    /// Returns the [PdfiumLibraryBindings] used by this [PdfPageWebLinks] collection.
+    #[inline]
+    pub fn bindings(&self) -> &'a dyn PdfiumLibraryBindings {
+        self.bindings
+    }
+
+    /// Returns the number of web links in this [PdfPageWebLinks] collection.
+    #[inline]
+    pub fn len(&self) -> PdfPageWebLinkIndex {
+        self.bindings.FPDFLink_CountWebLinks(self.handle).max(0) as PdfPageWebLinkIndex
+    }
+
+    /// Returns `true` if this [PdfPageWebLinks] collection is empty.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns a Range from `0..(number of web links)` for this [PdfPageWebLinks] collection.
+    #[inline]
+    pub fn as_range(&self) -> Range<PdfPageWebLinkIndex> {
+        0..self.len()
+    }
+
+    /// Returns an inclusive Range from `0..=(number of web links - 1)` for this
+    /// [PdfPageWebLinks] collection.
+    #[inline]
+    pub fn as_range_inclusive(&self) -> RangeInclusive<PdfPageWebLinkIndex> {
+        if self.is_empty() {
+            0..=0
+        } else {
+            0..=(self.len() - 1)
+        }
+    }
+
+    /// Returns a single [PdfPageWebLink] from this [PdfPageWebLinks] collection.
+    pub fn get(&self, index: PdfPageWebLinkIndex) -> Result<PdfPageWebLink, PdfiumError> {
+        if index >= self.len() {
+            return Err(PdfiumError::WebLinkIndexOutOfBounds);
+        }
+
+        Ok(PdfPageWebLink {
+            links: self,
+            index: index as c_int,
+        })
+    }
+
+    /// Returns an iterator over all the web links in this [PdfPageWebLinks] collection.
+    #[inline]
+    pub fn iter(&self) -> PdfPageWebLinksIterator {
+        PdfPageWebLinksIterator::new(self)
+    }
+}
+
+impl<'a> Drop for PdfPageWebLinks<'a> {
+    /// Closes this [PdfPageWebLinks] collection, releasing held memory.
+    #[inline]
+    fn drop(&mut self) {
+        self.bindings.FPDFLink_CloseWebLinks(self.handle);
+    }
+}
+
+/// A single web link detected by Pdfium in the text of a [PdfPage].
+pub struct PdfPageWebLink<'a> {
+    links: &'a PdfPageWebLinks<'a>,
+    index: c_int,
+}
+
+impl<'a> PdfPageWebLink<'a> {
+    /// Returns the URL of this [PdfPageWebLink].
+    pub fn url(&self) -> String {
+        // Retrieving the URL from Pdfium is a two-step operation. First, we call
+        // FPDFLink_GetURL() with a null buffer; this will retrieve the length of
+        // the URL in characters, including the trailing null terminator.
+
+        // If the length is non-zero, then we reserve a buffer of the given length
+        // and call FPDFLink_GetURL() again with a pointer to the buffer; this will
+        // write the URL to the buffer in UTF16-LE format.
+
+        let bindings = self.links.bindings();
+
+        let chars_count =
+            bindings.FPDFLink_GetURL(self.links.handle(), self.index, std::ptr::null_mut(), 0);
+
+        if chars_count <= 0 {
+            return String::new();
+        }
+
+        let mut buffer = create_sized_buffer(chars_count as usize);
+
+        let result = bindings.FPDFLink_GetURL(
+            self.links.handle(),
+            self.index,
+            buffer.as_mut_ptr(),
+            chars_count,
+        );
+
+        if result <= 0 {
+            return String::new();
+        }
+
+        buffer.truncate(result as usize);
+
+        get_string_from_pdfium_utf16le_words(&buffer).unwrap_or_default()
+    }
+
+    /// Returns the bounding rectangles of the text making up this [PdfPageWebLink].
+    /// A web link that wraps across several lines of text will have one rectangle per line.
+    pub fn rectangles(&self) -> Vec<PdfRect> {
+        let bindings = self.links.bindings();
+
+        let count = bindings.FPDFLink_CountRects(self.links.handle(), self.index);
+
+        let mut result = Vec::with_capacity(count.max(0) as usize);
+
+        for rect_index in 0..count {
+            let mut left: c_double = 0.0;
+            let mut top: c_double = 0.0;
+            let mut right: c_double = 0.0;
+            let mut bottom: c_double = 0.0;
+
+            if bindings.is_true(bindings.FPDFLink_GetRect(
+                self.links.handle(),
+                self.index,
+                rect_index,
+                &mut left,
+                &mut top,
+                &mut right,
+                &mut bottom,
+            )) {
+                result.push(PdfRect::new_from_values(
+                    bottom as f32,
+                    left as f32,
+                    top as f32,
+                    right as f32,
+                ));
+            }
+        }
+
+        result
+    }
+
+    /// Returns the range of character indices in the containing `PdfPageText` occupied by
+    /// this [PdfPageWebLink]. The start of the range is the index of the first character
+    /// in the link, and the length of the range is the number of characters in the link.
+    pub fn text_range(&self) -> Result<Range<PdfPageTextCharIndex>, PdfiumError> {
+        let bindings = self.links.bindings();
+
+        let mut start: c_int = 0;
+
+        let mut count: c_int = 0;
+
+        if bindings.is_true(bindings.FPDFLink_GetTextRange(
+            self.links.handle(),
+            self.index,
+            &mut start,
+            &mut count,
+        )) {
+            let start = start.max(0) as PdfPageTextCharIndex;
+
+            Ok(start..start + count.max(0) as PdfPageTextCharIndex)
+        } else {
+            Err(bindings.error_in(
+                "FPDFLink_GetTextRange",
+                PdfiumError::PdfiumFunctionReturnValueIndicatedFailure,
+            ))
+        }
+    }
+}
+
+/// An iterator over all the [PdfPageWebLink] objects in a [PdfPageWebLinks] collection.
+pub struct PdfPageWebLinksIterator<'a> {
+    links: &'a PdfPageWebLinks<'a>,
+    next_index: PdfPageWebLinkIndex,
+}
+
+impl<'a> PdfPageWebLinksIterator<'a> {
+    #[inline]
+    pub(crate) fn new(links: &'a PdfPageWebLinks<'a>) -> Self {
+        PdfPageWebLinksIterator {
+            links,
+            next_index: 0,
+        }
+    }
+}
+
+impl<'a> Iterator for PdfPageWebLinksIterator<'a> {
+    type Item = PdfPageWebLink<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let next = self.links.get(self.next_index);
+
+        self.next_index += 1;
+
+        next.ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::utils::test::test_bind_to_pdfium;
+
+    #[test]
+    fn test_detect_web_link_wrapped_across_lines() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        let mut page = document
+            .pages_mut()
+            .create_page_at_start(PdfPagePaperSize::a4())?;
+
+        let font = document.fonts_mut().helvetica();
+
+        // Place a URL on the page, with the URL wrapping onto a second line.
+
+        page.objects_mut().create_text_object(
+            PdfPoints::new(72.0),
+            PdfPoints::new(720.0),
+            "See https://www.example.com/a/rather/long/",
+            font,
+            PdfPoints::new(12.0),
+        )?;
+
+        page.objects_mut().create_text_object(
+            PdfPoints::new(72.0),
+            PdfPoints::new(706.0),
+            "path/to/index.html for details.",
+            font,
+            PdfPoints::new(12.0),
+        )?;
+
+        let text = page.text()?;
+
+        let links = text.detect_web_links()?;
+
+        assert_eq!(links.len(), 1);
+
+        let link = links.get(0)?;
+
+        assert_eq!(
+            link.url(),
+            "https://www.example.com/a/rather/long/path/to/index.html"
+        );
+        assert_eq!(link.rectangles().len(), 2);
+
+        let range = link.text_range()?;
+
+        assert!(range.len() >= link.url().len());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,436 @@
+//! Defines the [PdfWatermarkConfig] struct, a builder-based approach to configuring
+//! a text or image watermark stamped across one or more `PdfPage` objects.
+
+use crate::error::PdfiumError;
+use crate::pdf::color::PdfColor;
+use crate::pdf::document::fonts::{PdfFontToken, ToPdfFontToken};
+use crate::pdf::document::page::object::{PdfPageObject, PdfPageObjectCommon};
+use crate::pdf::document::page::objects::common::PdfPageObjectsCommon;
+use crate::pdf::document::page::{PdfPage, PdfPageRenderRotation};
+use crate::pdf::points::PdfPoints;
+use crate::pdf::rect::PdfRect;
+
+#[cfg(any(feature = "image_latest", feature = "image_025"))]
+use image_025::DynamicImage;
+
+#[cfg(feature = "image_024")]
+use image_024::DynamicImage;
+
+#[cfg(feature = "image_023")]
+use image_023::{DynamicImage, GenericImageView};
+
+#[cfg(doc)]
+use crate::pdf::document::PdfDocument;
+
+/// The content of a watermark configured by a [PdfWatermarkConfig].
+#[derive(Clone)]
+enum PdfWatermarkContent {
+    Text {
+        text: String,
+        font: PdfFontToken,
+        font_size: PdfPoints,
+        color: PdfColor,
+    },
+    #[cfg(feature = "image_api")]
+    Image {
+        image: DynamicImage,
+        width: PdfPoints,
+    },
+}
+
+/// The placement of a watermark on each page.
+///
+/// All positions are expressed relative to the page as it is displayed by a viewer,
+/// after any intrinsic page rotation has been applied, with the origin at the bottom
+/// left corner of the displayed page.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum PdfWatermarkPlacement {
+    /// A single watermark, centered on the page.
+    Center,
+
+    /// Repeated watermarks covering the whole page, separated horizontally and vertically
+    /// by the given gaps.
+    Tile { x_gap: PdfPoints, y_gap: PdfPoints },
+
+    /// A single watermark centered on the given position.
+    Custom(PdfPoints, PdfPoints),
+}
+
+/// Configures a text or image watermark that can be added to a single [PdfPage]
+/// using the [PdfPage::add_watermark()] function, or to every page in a document
+/// using the [PdfDocument::watermark_all_pages()] function.
+///
+/// Watermarks are always drawn upright relative to the viewer, taking into account
+/// any intrinsic rotation of each page.
+#[derive(Clone)]
+pub struct PdfWatermarkConfig {
+    content: PdfWatermarkContent,
+    opacity: f32,
+    rotation_degrees: f32,
+    placement: PdfWatermarkPlacement,
+    is_behind_content: bool,
+}
+
+impl PdfWatermarkConfig {
+    /// Creates a new [PdfWatermarkConfig] object that stamps the given text, using the given
+    /// font, font size, and color. By default, the watermark is fully opaque, unrotated,
+    /// centered on the page, and placed in front of existing page content.
+    pub fn new_text(
+        text: impl ToString,
+        font: impl ToPdfFontToken,
+        font_size: PdfPoints,
+        color: PdfColor,
+    ) -> Self {
+        Self::new(PdfWatermarkContent::Text {
+            text: text.to_string(),
+            font: font.token(),
+            font_size,
+            color,
+        })
+    }
+
+    /// Creates a new [PdfWatermarkConfig] object that stamps the given image, scaled to
+    /// the given width with its aspect ratio preserved. By default, the watermark is fully
+    /// opaque, unrotated, centered on the page, and placed in front of existing page content.
+    ///
+    /// This function is only available when this crate's `image` feature is enabled.
+    #[cfg(feature = "image_api")]
+    pub fn new_image(image: DynamicImage, width: PdfPoints) -> Self {
+        Self::new(PdfWatermarkContent::Image { image, width })
+    }
+
+    #[inline]
+    fn new(content: PdfWatermarkContent) -> Self {
+        PdfWatermarkConfig {
+            content,
+            opacity: 1.0,
+            rotation_degrees: 0.0,
+            placement: PdfWatermarkPlacement::Center,
+            is_behind_content: false,
+        }
+    }
+
+    /// Sets the opacity of the watermark, from 0.0 (fully transparent) to 1.0 (fully opaque).
+    /// Values outside this range are clamped.
+    #[inline]
+    pub fn set_opacity(mut self, opacity: f32) -> Self {
+        self.opacity = opacity.clamp(0.0, 1.0);
+
+        self
+    }
+
+    /// Sets the counter-clockwise rotation of the watermark, in degrees, as seen by the viewer.
+    #[inline]
+    pub fn set_rotation_degrees(mut self, degrees: f32) -> Self {
+        self.rotation_degrees = degrees;
+
+        self
+    }
+
+    /// Sets the placement of the watermark on each page.
+    #[inline]
+    pub fn set_placement(mut self, placement: PdfWatermarkPlacement) -> Self {
+        self.placement = placement;
+
+        self
+    }
+
+    /// Controls whether the watermark is placed behind existing page content rather than
+    /// in front of it. The default is `false`.
+    #[inline]
+    pub fn place_behind_content(mut self, is_behind_content: bool) -> Self {
+        self.is_behind_content = is_behind_content;
+
+        self
+    }
+
+    /// Adds the watermark configured by this [PdfWatermarkConfig] to the given page.
+    pub(crate) fn apply(&self, page: &mut PdfPage) -> Result<(), PdfiumError> {
+        let bounds = page
+            .boundaries()
+            .crop()
+            .or_else(|_| page.boundaries().media())?
+            .bounds;
+
+        let page_rotation = page.rotation().unwrap_or(PdfPageRenderRotation::None);
+
+        // The page rotation is applied clockwise by the viewer, so rotating the watermark
+        // counter-clockwise by the same amount keeps it upright.
+
+        let rotation_degrees = self.rotation_degrees + page_rotation.as_degrees();
+
+        let (display_width, display_height) = match page_rotation {
+            PdfPageRenderRotation::Degrees90 | PdfPageRenderRotation::Degrees270 => {
+                (bounds.height(), bounds.width())
+            }
+            _ => (bounds.width(), bounds.height()),
+        };
+
+        #[cfg(feature = "image_api")]
+        let image = match &self.content {
+            PdfWatermarkContent::Image { image, .. } => Some(self.image_with_opacity(image)),
+            _ => None,
+        };
+
+        let count_before = page.objects().len();
+
+        let mut centers = Vec::new();
+
+        match self.placement {
+            PdfWatermarkPlacement::Center => {
+                centers.push((display_width / 2.0, display_height / 2.0));
+            }
+            PdfWatermarkPlacement::Custom(x, y) => centers.push((x, y)),
+            PdfWatermarkPlacement::Tile { x_gap, y_gap } => {
+                // Tiles are spaced according to the size of the rotated watermark, so we
+                // create the first tile before deciding how many more are needed.
+
+                let object = self.create_object(
+                    page,
+                    #[cfg(feature = "image_api")]
+                    image.as_ref(),
+                )?;
+
+                let object_bounds = object.bounds()?.to_rect();
+
+                let (width, height) = (object_bounds.width().value, object_bounds.height().value);
+
+                let radians = self.rotation_degrees.to_radians();
+
+                let tile_width = (width * radians.cos()).abs() + (height * radians.sin()).abs();
+
+                let tile_height = (width * radians.sin()).abs() + (height * radians.cos()).abs();
+
+                let step_x = (tile_width + x_gap.value).max(1.0);
+
+                let step_y = (tile_height + y_gap.value).max(1.0);
+
+                let mut y = tile_height / 2.0;
+
+                while y - tile_height / 2.0 < display_height.value {
+                    let mut x = tile_width / 2.0;
+
+                    while x - tile_width / 2.0 < display_width.value {
+                        centers.push((PdfPoints::new(x), PdfPoints::new(y)));
+
+                        x += step_x;
+                    }
+
+                    y += step_y;
+                }
+
+                self.position_object(object, centers[0], rotation_degrees, &bounds, page_rotation)?;
+
+                centers.remove(0);
+            }
+        }
+
+        for center in centers {
+            let object = self.create_object(
+                page,
+                #[cfg(feature = "image_api")]
+                image.as_ref(),
+            )?;
+
+            self.position_object(object, center, rotation_degrees, &bounds, page_rotation)?;
+        }
+
+        if self.is_behind_content {
+            let count_after = page.objects().len();
+
+            let added = count_after - count_before;
+
+            for index in 0..added {
+                page.objects_mut()
+                    .move_to_index(count_before + index, index)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Creates a new, unpositioned page object for this watermark on the given page.
+    fn create_object<'a>(
+        &self,
+        page: &mut PdfPage<'a>,
+        #[cfg(feature = "image_api")] image: Option<&DynamicImage>,
+    ) -> Result<PdfPageObject<'a>, PdfiumError> {
+        match &self.content {
+            PdfWatermarkContent::Text {
+                text,
+                font,
+                font_size,
+                color,
+            } => {
+                let mut object = page.objects_mut().create_text_object(
+                    PdfPoints::ZERO,
+                    PdfPoints::ZERO,
+                    text,
+                    *font,
+                    *font_size,
+                )?;
+
+                object.set_fill_color(
+                    color.with_alpha((color.alpha() as f32 * self.opacity).round() as u8),
+                )?;
+
+                Ok(object)
+            }
+            #[cfg(feature = "image_api")]
+            PdfWatermarkContent::Image { width, .. } => {
+                // The image has already had its opacity applied.
+
+                let image = image.ok_or(PdfiumError::PdfiumLibraryInternalError(
+                    crate::error::PdfiumInternalError::Unknown,
+                ))?;
+
+                let height = *width * (image.height() as f32 / image.width().max(1) as f32);
+
+                page.objects_mut().create_image_object(
+                    PdfPoints::ZERO,
+                    PdfPoints::ZERO,
+                    image,
+                    Some(*width),
+                    Some(height),
+                )
+            }
+        }
+    }
+
+    /// Rotates the given page object around its own center, then moves it so that its center
+    /// lies at the given position relative to the displayed page.
+    fn position_object(
+        &self,
+        mut object: PdfPageObject,
+        (x, y): (PdfPoints, PdfPoints),
+        rotation_degrees: f32,
+        bounds: &PdfRect,
+        page_rotation: PdfPageRenderRotation,
+    ) -> Result<(), PdfiumError> {
+        let object_bounds = object.bounds()?.to_rect();
+
+        object.translate(
+            -(object_bounds.left() + object_bounds.right()) / 2.0,
+            -(object_bounds.bottom() + object_bounds.top()) / 2.0,
+        )?;
+
+        object.rotate_counter_clockwise_degrees(rotation_degrees)?;
+
+        // Map the position on the displayed page back into page space.
+
+        let (left, bottom, right, top) =
+            (bounds.left(), bounds.bottom(), bounds.right(), bounds.top());
+
+        let (page_x, page_y) = match page_rotation {
+            PdfPageRenderRotation::None => (left + x, bottom + y),
+            PdfPageRenderRotation::Degrees90 => (right - y, bottom + x),
+            PdfPageRenderRotation::Degrees180 => (right - x, top - y),
+            PdfPageRenderRotation::Degrees270 => (left + y, top - x),
+        };
+
+        object.translate(page_x, page_y)
+    }
+
+    /// Returns a copy of the given image with the opacity of this watermark applied
+    /// to its alpha channel.
+    #[cfg(feature = "image_api")]
+    fn image_with_opacity(&self, image: &DynamicImage) -> DynamicImage {
+        if self.opacity >= 1.0 {
+            return image.clone();
+        }
+
+        let mut rgba = image.to_rgba8();
+
+        for pixel in rgba.pixels_mut() {
+            pixel.0[3] = (pixel.0[3] as f32 * self.opacity).round() as u8;
+        }
+
+        DynamicImage::ImageRgba8(rgba)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::utils::test::test_bind_to_pdfium;
+
+    #[test]
+    fn test_center_watermark_is_centered_on_every_page() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        document
+            .pages_mut()
+            .create_page_at_end(PdfPagePaperSize::a4())?;
+
+        document
+            .pages_mut()
+            .create_page_at_end(PdfPagePaperSize::a3().landscape())?
+            .set_rotation(PdfPageRenderRotation::Degrees90);
+
+        let font = document.fonts_mut().helvetica();
+
+        let config =
+            PdfWatermarkConfig::new_text("DRAFT", font, PdfPoints::new(48.0), PdfColor::RED)
+                .set_opacity(0.5)
+                .set_rotation_degrees(45.0);
+
+        document.watermark_all_pages(&config)?;
+
+        for page in document.pages().iter() {
+            let page_bounds = page.boundaries().media()?.bounds;
+
+            let object = page.objects().last()?;
+
+            let object_bounds = object.bounds()?.to_rect();
+
+            let page_mid_x = (page_bounds.left() + page_bounds.right()).value / 2.0;
+
+            let page_mid_y = (page_bounds.bottom() + page_bounds.top()).value / 2.0;
+
+            let object_mid_x = (object_bounds.left() + object_bounds.right()).value / 2.0;
+
+            let object_mid_y = (object_bounds.bottom() + object_bounds.top()).value / 2.0;
+
+            assert!((page_mid_x - object_mid_x).abs() < 1.0);
+            assert!((page_mid_y - object_mid_y).abs() < 1.0);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_watermark_behind_content() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        let mut page = document
+            .pages_mut()
+            .create_page_at_start(PdfPagePaperSize::a4())?;
+
+        let font = document.fonts_mut().times_roman();
+
+        page.objects_mut().create_text_object(
+            PdfPoints::new(100.0),
+            PdfPoints::new(100.0),
+            "Existing content",
+            font,
+            PdfPoints::new(12.0),
+        )?;
+
+        page.add_watermark(
+            &PdfWatermarkConfig::new_text("DRAFT", font, PdfPoints::new(48.0), PdfColor::GREY_50)
+                .place_behind_content(true),
+        )?;
+
+        assert_eq!(page.objects().len(), 2);
+        assert_eq!(
+            page.objects().get(1)?.as_text_object().unwrap().text(),
+            "Existing content"
+        );
+
+        Ok(())
+    }
+}
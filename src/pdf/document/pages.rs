@@ -20,9 +20,173 @@ use crate::utils::utf16le::get_string_from_pdfium_utf16le_bytes;
 use std::ops::{Range, RangeInclusive};
 use std::os::raw::{c_double, c_int, c_void};
 
+#[cfg(any(
+    feature = "pdfium_future",
+    feature = "pdfium_6996",
+    feature = "pdfium_6721",
+    feature = "pdfium_6666",
+    feature = "pdfium_6611",
+    feature = "pdfium_6569",
+    feature = "pdfium_6555",
+    feature = "pdfium_6490",
+    feature = "pdfium_6406",
+    feature = "pdfium_6337",
+    feature = "pdfium_6295",
+    feature = "pdfium_6259",
+    feature = "pdfium_6164",
+    feature = "pdfium_6124",
+    feature = "pdfium_6110",
+    feature = "pdfium_6084",
+    feature = "pdfium_6043",
+))]
+use std::os::raw::c_ulong;
+
+#[cfg(feature = "image_api")]
+use {
+    crate::pdf::document::image_page::{PdfImagePageOptions, PdfImagePageSize},
+    crate::pdf::document::page::object::image::{PdfPageImageObject, PdfPageImageObjectScaleMode},
+    crate::pdf::document::page::objects::common::PdfPageObjectsCommon,
+};
+
+#[cfg(any(feature = "image_latest", feature = "image_025"))]
+use image_025::DynamicImage;
+
+#[cfg(feature = "image_024")]
+use image_024::DynamicImage;
+
+#[cfg(feature = "image_023")]
+use image_023::DynamicImage;
+
+#[cfg(doc)]
+use crate::pdf::document::page::object::PdfPageObjectCommon;
+
 /// The zero-based index of a single [PdfPage] inside its containing [PdfPages] collection.
 pub type PdfPageIndex = u16;
 
+/// A selection of pages in a [PdfDocument], given either as a user-friendly page range string
+/// such as `"1,3,5-7"`, in which pages are numbered starting at one, or as a collection of
+/// zero-based [PdfPageIndex] values.
+pub trait ToPdfPageIndices {
+    /// Returns the zero-based indices of the selected pages, in order, validating them
+    /// against the given number of pages in the document containing them.
+    fn to_page_indices(&self, page_count: PdfPageIndex) -> Result<Vec<PdfPageIndex>, PdfiumError>;
+}
+
+impl ToPdfPageIndices for &str {
+    #[inline]
+    fn to_page_indices(&self, page_count: PdfPageIndex) -> Result<Vec<PdfPageIndex>, PdfiumError> {
+        parse_page_range(self, page_count)
+    }
+}
+
+impl ToPdfPageIndices for String {
+    #[inline]
+    fn to_page_indices(&self, page_count: PdfPageIndex) -> Result<Vec<PdfPageIndex>, PdfiumError> {
+        parse_page_range(self, page_count)
+    }
+}
+
+impl ToPdfPageIndices for &[PdfPageIndex] {
+    #[inline]
+    fn to_page_indices(&self, page_count: PdfPageIndex) -> Result<Vec<PdfPageIndex>, PdfiumError> {
+        validate_page_indices(self.iter().copied(), page_count)
+    }
+}
+
+impl ToPdfPageIndices for Vec<PdfPageIndex> {
+    #[inline]
+    fn to_page_indices(&self, page_count: PdfPageIndex) -> Result<Vec<PdfPageIndex>, PdfiumError> {
+        validate_page_indices(self.iter().copied(), page_count)
+    }
+}
+
+impl ToPdfPageIndices for Range<PdfPageIndex> {
+    #[inline]
+    fn to_page_indices(&self, page_count: PdfPageIndex) -> Result<Vec<PdfPageIndex>, PdfiumError> {
+        validate_page_indices(self.clone(), page_count)
+    }
+}
+
+impl ToPdfPageIndices for RangeInclusive<PdfPageIndex> {
+    #[inline]
+    fn to_page_indices(&self, page_count: PdfPageIndex) -> Result<Vec<PdfPageIndex>, PdfiumError> {
+        validate_page_indices(self.clone(), page_count)
+    }
+}
+
+/// Collects the given zero-based page indices, returning [PdfiumError::PageIndexOutOfBounds]
+/// if any index is not less than the given page count.
+fn validate_page_indices(
+    indices: impl Iterator<Item = PdfPageIndex>,
+    page_count: PdfPageIndex,
+) -> Result<Vec<PdfPageIndex>, PdfiumError> {
+    indices
+        .map(|index| {
+            if index < page_count {
+                Ok(index)
+            } else {
+                Err(PdfiumError::PageIndexOutOfBounds)
+            }
+        })
+        .collect()
+}
+
+/// Parses a user-friendly page range string, such as `"1,3,5-7"`, into a list of zero-based
+/// page indices, validating each page number against the given page count.
+pub(crate) fn parse_page_range(
+    range: &str,
+    page_count: PdfPageIndex,
+) -> Result<Vec<PdfPageIndex>, PdfiumError> {
+    let invalid = |reason: String| {
+        Err(PdfiumError::InvalidPageRange(format!(
+            "{} in page range \"{}\"",
+            reason, range
+        )))
+    };
+
+    if range.trim().is_empty() {
+        return invalid("no pages given".to_string());
+    }
+
+    let mut indices = Vec::new();
+
+    for entry in range.split(',').map(|entry| entry.trim()) {
+        let (first, last) = match entry.split_once('-') {
+            Some((first, last)) => (first.trim(), last.trim()),
+            None => (entry, entry),
+        };
+
+        let mut numbers = [0; 2];
+
+        for (number, text) in numbers.iter_mut().zip([first, last]) {
+            *number = match text.parse::<u32>() {
+                Ok(0) => return invalid("page numbers start at 1, but found page 0".to_string()),
+                Ok(value) if value > page_count as u32 => {
+                    return invalid(format!(
+                        "page {} is out of range for a document with {} pages",
+                        value, page_count
+                    ))
+                }
+                Ok(value) => value as PdfPageIndex,
+                Err(_) => return invalid(format!("\"{}\" is not a valid page number", text)),
+            };
+        }
+
+        let [first, last] = numbers;
+
+        if first > last {
+            return invalid(format!(
+                "the range {}-{} is in descending order",
+                first, last
+            ));
+        }
+
+        indices.extend(first - 1..last);
+    }
+
+    Ok(indices)
+}
+
 /// A hint to a PDF document reader (such as Adobe Acrobat) as to how the creator intended
 /// the [PdfPage] objects in a [PdfDocument] to be displayed to the viewer when the document is opened.
 #[derive(Debug, Copy, Clone)]
@@ -177,8 +341,9 @@ impl<'a> PdfPages<'a> {
                 PdfPoints::new(size.width),
             ))
         } else {
-            Err(PdfiumError::PdfiumLibraryInternalError(
-                PdfiumInternalError::Unknown,
+            Err(self.bindings.error_in(
+                "FPDF_GetPageSizeByIndexF",
+                PdfiumError::PdfiumLibraryInternalError(PdfiumInternalError::Unknown),
             ))
         }
     }
@@ -265,6 +430,85 @@ impl<'a> PdfPages<'a> {
         result
     }
 
+    /// Creates a new [PdfPage] containing only the given image, configured by the given
+    /// [PdfImagePageOptions], and adds it to the end of this [PdfPages] collection.
+    ///
+    /// This is a convenient way of converting scanned images into a document. The size of the
+    /// page is either taken from the options, in which case the image is scaled to fit inside
+    /// the page margins while preserving its aspect ratio, or calculated from the pixel
+    /// dimensions of the image at the resolution given in the options.
+    ///
+    /// This function is only available when this crate's `image` feature is enabled.
+    #[cfg(feature = "image_api")]
+    #[inline]
+    pub fn create_page_from_image(
+        &mut self,
+        image: &DynamicImage,
+        options: &PdfImagePageOptions,
+    ) -> Result<PdfPage<'a>, PdfiumError> {
+        self.create_page_from_image_impl(image, options, options.jpeg_data())
+    }
+
+    #[cfg(feature = "image_api")]
+    pub(crate) fn create_page_from_image_impl(
+        &mut self,
+        image: &DynamicImage,
+        options: &PdfImagePageOptions,
+        jpeg_data: Option<&[u8]>,
+    ) -> Result<PdfPage<'a>, PdfiumError> {
+        let mut object = PdfPageImageObject::new_from_handle(self.document_handle, self.bindings)?;
+
+        match jpeg_data {
+            Some(data) => object.set_image_from_jpeg_bytes(data)?,
+            None => object.set_image(image)?,
+        }
+
+        // Take the pixel dimensions from the embedded image, rather than the given image,
+        // so that pass-through JPEG data is measured correctly.
+
+        let (width, height) = object.get_current_width_and_height_from_metadata()?;
+
+        let margin = options.margin();
+
+        let size = match options.size() {
+            PdfImagePageSize::PaperSize(size) => size,
+            PdfImagePageSize::Dpi(dpi) => {
+                if !(dpi.is_finite() && dpi > 0.0) {
+                    return Err(PdfiumError::InvalidImagePlacement);
+                }
+
+                PdfPagePaperSize::Custom(
+                    PdfPoints::new(width as f32 * 72.0 / dpi) + margin * 2.0,
+                    PdfPoints::new(height as f32 * 72.0 / dpi) + margin * 2.0,
+                )
+            }
+        };
+
+        let content = PdfRect::new(
+            margin,
+            margin,
+            size.height() - margin,
+            size.width() - margin,
+        );
+
+        // Check the content area before creating the page, so we do not leave behind
+        // an empty page if the margins are too large.
+
+        if content.width().value <= 0.0 || content.height().value <= 0.0 {
+            return Err(PdfiumError::InvalidImagePlacement);
+        }
+
+        object.scale_to_fit(content, PdfPageImageObjectScaleMode::Fit)?;
+
+        let mut page = self.create_page_at_end(size)?;
+
+        page.objects_mut().add_image_object(object)?;
+
+        page.set_rotation(options.rotation());
+
+        Ok(page)
+    }
+
     // TODO: AJRC - 5/2/23 - remove deprecated PdfPages::delete_page_range() function in 0.9.0
     // as part of tracking issue: https://github.com/ajrcarey/pdfium-render/issues/36
     // TODO: AJRC - 5/2/23 - if PdfDocument::pages() returned a &PdfPages reference (rather than an
@@ -380,8 +624,9 @@ impl<'a> PdfPages<'a> {
 
             Ok(())
         } else {
-            Err(PdfiumError::PdfiumLibraryInternalError(
-                PdfiumInternalError::Unknown,
+            Err(bindings.error_in(
+                "FPDF_ImportPages",
+                PdfiumError::PdfiumLibraryInternalError(PdfiumInternalError::Unknown),
             ))
         }
     }
@@ -414,14 +659,68 @@ impl<'a> PdfPages<'a> {
         destination_page_index: PdfPageIndex,
         bindings: &dyn PdfiumLibraryBindings,
     ) -> Result<(), PdfiumError> {
-        let no_of_pages_to_import = source_page_range.len() as PdfPageIndex;
+        Self::copy_page_indices_between_documents(
+            source,
+            &source_page_range.collect::<Vec<_>>(),
+            destination,
+            destination_page_index,
+            bindings,
+        )
+    }
+
+    /// Copies the pages with the given indices from the given source [PdfDocument],
+    /// inserting the pages sequentially starting at the given destination page index
+    /// in this [PdfPages] collection.
+    ///
+    /// Pages are copied by Pdfium together with all the fonts, images, and other resources
+    /// they use, so no content is lost; this differs from copying individual page objects
+    /// using the [PdfPageObjectCommon::try_copy()] function.
+    ///
+    /// The pages to copy can be given either as a user-friendly page range string,
+    /// for example \"1,3,5-7\", in which pages are indexed starting at one, or as a range,
+    /// slice, or vector of zero-based page indices. [PdfiumError::InvalidPageRange] will be
+    /// returned if a page range string cannot be parsed or refers to pages that do not exist
+    /// in the source document; [PdfiumError::PageIndexOutOfBounds] will be returned if
+    /// a zero-based page index is out of bounds.
+    pub fn import_pages_from_document(
+        &mut self,
+        source: &PdfDocument,
+        pages: impl ToPdfPageIndices,
+        destination_page_index: PdfPageIndex,
+    ) -> Result<(), PdfiumError> {
+        if destination_page_index > self.len() {
+            return Err(PdfiumError::PageIndexOutOfBounds);
+        }
+
+        Self::copy_page_indices_between_documents(
+            source.handle(),
+            &pages.to_page_indices(source.pages().len())?,
+            self.document_handle,
+            destination_page_index,
+            self.bindings(),
+        )
+    }
+
+    /// Copies the pages with the given indices from one raw document handle to another,
+    /// inserting the pages sequentially starting at the given destination page index.
+    pub(crate) fn copy_page_indices_between_documents(
+        source: FPDF_DOCUMENT,
+        source_page_indices: &[PdfPageIndex],
+        destination: FPDF_DOCUMENT,
+        destination_page_index: PdfPageIndex,
+        bindings: &dyn PdfiumLibraryBindings,
+    ) -> Result<(), PdfiumError> {
+        if source_page_indices.is_empty() {
+            return Ok(());
+        }
 
         if bindings.is_true(
             bindings.FPDF_ImportPagesByIndex_vec(
                 destination,
                 source,
-                source_page_range
-                    .map(|index| index as c_int)
+                source_page_indices
+                    .iter()
+                    .map(|index| *index as c_int)
                     .collect::<Vec<_>>(),
                 destination_page_index as c_int,
             ),
@@ -429,13 +728,14 @@ impl<'a> PdfPages<'a> {
             PdfPageIndexCache::insert_pages_at_index(
                 destination,
                 destination_page_index,
-                no_of_pages_to_import,
+                source_page_indices.len() as PdfPageIndex,
             );
 
             Ok(())
         } else {
-            Err(PdfiumError::PdfiumLibraryInternalError(
-                PdfiumInternalError::Unknown,
+            Err(bindings.error_in(
+                "FPDF_ImportPagesByIndex_vec",
+                PdfiumError::PdfiumLibraryInternalError(PdfiumInternalError::Unknown),
             ))
         }
     }
@@ -445,7 +745,8 @@ impl<'a> PdfPages<'a> {
     ///
     /// For finer control over which pages are imported, and where they should be inserted,
     /// use one of the [PdfPages::copy_page_from_document()], [PdfPages::copy_pages_from_document()],
-    ///  or [PdfPages::copy_page_range_from_document()] functions.
+    /// [PdfPages::copy_page_range_from_document()], or [PdfPages::import_pages_from_document()]
+    /// functions.
     #[inline]
     pub fn append(&mut self, document: &PdfDocument) -> Result<(), PdfiumError> {
         self.copy_page_range_from_document(
@@ -455,6 +756,146 @@ impl<'a> PdfPages<'a> {
         )
     }
 
+    /// Moves the page at the given source index to the given destination index in this
+    /// [PdfPages] collection, shuffling the pages in between up or down as necessary.
+    ///
+    /// Pages are moved within the document's page tree, not copied, so any [PdfPage]
+    /// already loaded from this collection remains valid and reports its new index.
+    /// Bookmarks and link destinations that refer to a moved page by its page object will
+    /// continue to point to that page; destinations that refer to a page by its index
+    /// are not rewritten.
+    ///
+    /// This function is not available when compiling to older versions of Pdfium.
+    #[cfg(any(
+        feature = "pdfium_future",
+        feature = "pdfium_6996",
+        feature = "pdfium_6721",
+        feature = "pdfium_6666",
+        feature = "pdfium_6611",
+        feature = "pdfium_6569",
+        feature = "pdfium_6555",
+        feature = "pdfium_6490",
+        feature = "pdfium_6406",
+        feature = "pdfium_6337",
+        feature = "pdfium_6295",
+        feature = "pdfium_6259",
+        feature = "pdfium_6164",
+        feature = "pdfium_6124",
+        feature = "pdfium_6110",
+        feature = "pdfium_6084",
+        feature = "pdfium_6043",
+    ))]
+    pub fn move_page(&mut self, from: PdfPageIndex, to: PdfPageIndex) -> Result<(), PdfiumError> {
+        let len = self.len();
+
+        if from >= len || to >= len {
+            return Err(PdfiumError::PageIndexOutOfBounds);
+        }
+
+        if from == to {
+            return Ok(());
+        }
+
+        let page_indices = [from as c_int];
+
+        if self.bindings.is_true(self.bindings.FPDF_MovePages(
+            self.document_handle,
+            page_indices.as_ptr(),
+            page_indices.len() as c_ulong,
+            to as c_int,
+        )) {
+            PdfPageIndexCache::move_page_to_index(self.document_handle, from, to);
+
+            Ok(())
+        } else {
+            Err(self.bindings.error_in(
+                "FPDF_MovePages",
+                PdfiumError::PdfiumLibraryInternalError(PdfiumInternalError::Unknown),
+            ))
+        }
+    }
+
+    /// Swaps the positions of the pages at the two given indices in this [PdfPages] collection.
+    ///
+    /// As with [PdfPages::move_page()], pages are moved rather than copied, so any [PdfPage]
+    /// already loaded from this collection remains valid, and destinations that refer to
+    /// a page by its index are not rewritten.
+    ///
+    /// This function is not available when compiling to older versions of Pdfium.
+    #[cfg(any(
+        feature = "pdfium_future",
+        feature = "pdfium_6996",
+        feature = "pdfium_6721",
+        feature = "pdfium_6666",
+        feature = "pdfium_6611",
+        feature = "pdfium_6569",
+        feature = "pdfium_6555",
+        feature = "pdfium_6490",
+        feature = "pdfium_6406",
+        feature = "pdfium_6337",
+        feature = "pdfium_6295",
+        feature = "pdfium_6259",
+        feature = "pdfium_6164",
+        feature = "pdfium_6124",
+        feature = "pdfium_6110",
+        feature = "pdfium_6084",
+        feature = "pdfium_6043",
+    ))]
+    pub fn swap(&mut self, a: PdfPageIndex, b: PdfPageIndex) -> Result<(), PdfiumError> {
+        let len = self.len();
+
+        if a >= len || b >= len {
+            return Err(PdfiumError::PageIndexOutOfBounds);
+        }
+
+        let (first, second) = if a < b { (a, b) } else { (b, a) };
+
+        if first == second {
+            return Ok(());
+        }
+
+        // Moving the later page in front of the earlier page shuffles the earlier page down
+        // by one position; moving it from there to the later page's original position
+        // completes the swap.
+
+        self.move_page(second, first)?;
+
+        if second - first > 1 {
+            self.move_page(first + 1, second)?;
+        }
+
+        Ok(())
+    }
+
+    /// Duplicates the page at the given index, inserting the copy at the given destination
+    /// index in this [PdfPages] collection, and returns the newly created copy.
+    ///
+    /// The page is copied by Pdfium together with all the fonts, images, and other resources
+    /// it uses, so the copy renders identically to the original. The destination index
+    /// may be equal to the number of pages in the collection, in which case the copy is
+    /// appended to the end of the collection.
+    pub fn duplicate(
+        &mut self,
+        index: PdfPageIndex,
+        insert_at: PdfPageIndex,
+    ) -> Result<PdfPage<'a>, PdfiumError> {
+        let len = self.len();
+
+        if index >= len || insert_at > len {
+            return Err(PdfiumError::PageIndexOutOfBounds);
+        }
+
+        Self::copy_page_indices_between_documents(
+            self.document_handle,
+            &[index],
+            self.document_handle,
+            insert_at,
+            self.bindings(),
+        )?;
+
+        self.get(insert_at)
+    }
+
     /// Creates a new [PdfDocument] by copying the pages in this [PdfPages] collection
     /// into tiled grids, the size of each tile shrinking or expanding as necessary to fit
     /// the given [PdfPagePaperSize].
@@ -471,7 +912,7 @@ impl<'a> PdfPages<'a> {
         rows_per_page: u8,
         columns_per_row: u8,
         size: PdfPagePaperSize,
-    ) -> Result<PdfDocument, PdfiumError> {
+    ) -> Result<PdfDocument<'a>, PdfiumError> {
         let handle = self.bindings.FPDF_ImportNPagesToOne(
             self.document_handle,
             size.width().value,
@@ -481,8 +922,9 @@ impl<'a> PdfPages<'a> {
         );
 
         if handle.is_null() {
-            Err(PdfiumError::PdfiumLibraryInternalError(
-                PdfiumInternalError::Unknown,
+            Err(self.bindings.error_in(
+                "FPDF_ImportNPagesToOne",
+                PdfiumError::PdfiumLibraryInternalError(PdfiumInternalError::Unknown),
             ))
         } else {
             Ok(PdfDocument::from_pdfium(handle, self.bindings))
@@ -508,41 +950,7 @@ impl<'a> PdfPages<'a> {
             // (Pdfium does not currently include an FPDF_SetPageLabel() function, so the label
             // _will_ be an immutable property of the PdfPage for its entire lifetime.)
 
-            let label = {
-                // Retrieving the label text from Pdfium is a two-step operation. First, we call
-                // FPDF_GetPageLabel() with a null buffer; this will retrieve the length of
-                // the label text in bytes. If the length is zero, then there is no such tag.
-
-                // If the length is non-zero, then we reserve a byte buffer of the given
-                // length and call FPDF_GetPageLabel() again with a pointer to the buffer;
-                // this will write the label text to the buffer in UTF16LE format.
-
-                let buffer_length = self.bindings.FPDF_GetPageLabel(
-                    self.document_handle,
-                    index as c_int,
-                    std::ptr::null_mut(),
-                    0,
-                );
-
-                if buffer_length == 0 {
-                    // The label is not present.
-
-                    None
-                } else {
-                    let mut buffer = create_byte_buffer(buffer_length as usize);
-
-                    let result = self.bindings.FPDF_GetPageLabel(
-                        self.document_handle,
-                        index as c_int,
-                        buffer.as_mut_ptr() as *mut c_void,
-                        buffer_length,
-                    );
-
-                    debug_assert_eq!(result, buffer_length);
-
-                    get_string_from_pdfium_utf16le_bytes(buffer)
-                }
-            };
+            let label = self.label_for_index(index);
 
             Ok(PdfPage::from_pdfium(
                 self.document_handle,
@@ -554,6 +962,59 @@ impl<'a> PdfPages<'a> {
         }
     }
 
+    /// Returns the label assigned to the page at the given index in this [PdfPages] collection,
+    /// if any, without loading the page into memory.
+    ///
+    /// Page labels are defined in the document's `/PageLabels` number tree, and allow pages
+    /// to be numbered differently to their index, for instance using roman numerals for the
+    /// front matter of a book. `None` is returned if the document does not define a label
+    /// for the page, or if the index is out of bounds.
+    pub fn label_for_index(&self, index: PdfPageIndex) -> Option<String> {
+        // Retrieving the label text from Pdfium is a two-step operation. First, we call
+        // FPDF_GetPageLabel() with a null buffer; this will retrieve the length of
+        // the label text in bytes. If the length is zero, then there is no such tag.
+
+        // If the length is non-zero, then we reserve a byte buffer of the given
+        // length and call FPDF_GetPageLabel() again with a pointer to the buffer;
+        // this will write the label text to the buffer in UTF16LE format.
+
+        let buffer_length = self.bindings.FPDF_GetPageLabel(
+            self.document_handle,
+            index as c_int,
+            std::ptr::null_mut(),
+            0,
+        );
+
+        if buffer_length == 0 {
+            // The label is not present.
+
+            None
+        } else {
+            let mut buffer = create_byte_buffer(buffer_length as usize);
+
+            let result = self.bindings.FPDF_GetPageLabel(
+                self.document_handle,
+                index as c_int,
+                buffer.as_mut_ptr() as *mut c_void,
+                buffer_length,
+            );
+
+            debug_assert_eq!(result, buffer_length);
+
+            get_string_from_pdfium_utf16le_bytes(buffer)
+        }
+    }
+
+    /// Returns the index of the first page in this [PdfPages] collection with the given label,
+    /// if any.
+    ///
+    /// Pdfium does not provide a reverse lookup from labels to pages, so this function
+    /// retrieves the label of every page in turn until a match is found.
+    pub fn index_for_label(&self, label: &str) -> Option<PdfPageIndex> {
+        self.as_range()
+            .find(|index| self.label_for_index(*index).as_deref() == Some(label))
+    }
+
     /// Returns the [PdfPageMode] setting embedded in the containing [PdfDocument].
     pub fn page_mode(&self) -> PdfPageMode {
         PdfPageMode::from_pdfium(self.bindings.FPDFDoc_GetPageMode(self.document_handle))
@@ -666,6 +1127,142 @@ mod tests {
     use crate::prelude::*;
     use crate::utils::test::test_bind_to_pdfium;
 
+    #[test]
+    fn test_create_page_from_image_at_dpi() -> Result<(), PdfiumError> {
+        use image_025::{DynamicImage, GrayImage, Luma};
+
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        // A US Letter scan at 300 dpi measures 2550 x 3300 pixels and should produce
+        // a page measuring 612 x 792 points.
+
+        let scan = DynamicImage::ImageLuma8(GrayImage::from_pixel(2550, 3300, Luma([255])));
+
+        let page = document
+            .pages_mut()
+            .create_page_from_image(&scan, &PdfImagePageOptions::new().set_dpi(300.0))?;
+
+        assert_eq!(page.width(), PdfPoints::new(612.0));
+        assert_eq!(page.height(), PdfPoints::new(792.0));
+
+        let bounds = page.objects().first()?.bounds()?.to_rect();
+
+        assert!((bounds.left().value).abs() < 0.01);
+        assert!((bounds.bottom().value).abs() < 0.01);
+        assert!((bounds.right().value - 612.0).abs() < 0.01);
+        assert!((bounds.top().value - 792.0).abs() < 0.01);
+
+        // Margins are added around the image, and the rotation is applied to the page.
+
+        let page = document.pages_mut().create_page_from_image(
+            &scan,
+            &PdfImagePageOptions::new()
+                .set_dpi(300.0)
+                .set_margin(PdfPoints::new(36.0))
+                .set_rotation(PdfPageRenderRotation::Degrees90),
+        )?;
+
+        assert_eq!(page.width(), PdfPoints::new(684.0));
+        assert_eq!(page.height(), PdfPoints::new(864.0));
+        assert_eq!(page.rotation()?, PdfPageRenderRotation::Degrees90);
+
+        assert!(matches!(
+            document.pages_mut().create_page_from_image(
+                &scan,
+                &PdfImagePageOptions::new()
+                    .set_paper_size(PdfPagePaperSize::a4())
+                    .set_margin(PdfPoints::new(1000.0)),
+            ),
+            Err(PdfiumError::InvalidImagePlacement)
+        ));
+
+        assert_eq!(document.pages().len(), 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_create_page_from_image_with_paper_size() -> Result<(), PdfiumError> {
+        use image_025::{DynamicImage, Rgb, RgbImage};
+
+        let pdfium = test_bind_to_pdfium();
+
+        // A landscape image placed on a portrait page is scaled to the width of the page
+        // and centered vertically.
+
+        let images = vec![
+            DynamicImage::ImageRgb8(RgbImage::from_pixel(200, 100, Rgb([255, 0, 0]))),
+            DynamicImage::ImageRgb8(RgbImage::from_pixel(100, 100, Rgb([0, 255, 0]))),
+        ];
+
+        let document = pdfium.create_pdf_from_images(
+            &images,
+            &PdfImagePageOptions::new()
+                .set_paper_size(PdfPagePaperSize::new_custom(
+                    PdfPoints::new(300.0),
+                    PdfPoints::new(400.0),
+                ))
+                .set_margin(PdfPoints::new(50.0)),
+        )?;
+
+        assert_eq!(document.pages().len(), 2);
+
+        let page = document.pages().first()?;
+
+        assert_eq!(page.width(), PdfPoints::new(300.0));
+        assert_eq!(page.height(), PdfPoints::new(400.0));
+
+        let bounds = page.objects().first()?.bounds()?.to_rect();
+
+        assert!((bounds.left().value - 50.0).abs() < 0.01);
+        assert!((bounds.right().value - 250.0).abs() < 0.01);
+        assert!((bounds.bottom().value - 150.0).abs() < 0.01);
+        assert!((bounds.top().value - 250.0).abs() < 0.01);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_create_page_from_image_passes_through_jpeg() -> Result<(), PdfiumError> {
+        use image_025::{DynamicImage, ImageFormat, Rgb, RgbImage};
+        use std::io::Cursor;
+
+        let pdfium = test_bind_to_pdfium();
+
+        let image = DynamicImage::ImageRgb8(RgbImage::from_pixel(300, 150, Rgb([0, 0, 255])));
+
+        let mut jpeg = Vec::new();
+
+        image
+            .write_to(&mut Cursor::new(&mut jpeg), ImageFormat::Jpeg)
+            .unwrap();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        let page = document.pages_mut().create_page_from_image(
+            &image,
+            &PdfImagePageOptions::new()
+                .set_dpi(150.0)
+                .pass_through_jpeg(jpeg.clone()),
+        )?;
+
+        assert_eq!(page.width(), PdfPoints::new(144.0));
+        assert_eq!(page.height(), PdfPoints::new(72.0));
+
+        let object = page.objects().first()?;
+
+        let object = object.as_image_object().unwrap();
+
+        // The embedded image data should be byte-for-byte identical to the original JPEG.
+
+        assert_eq!(object.raw_image_data()?, jpeg);
+        assert_eq!(object.filters().get(0)?.name(), "DCTDecode");
+
+        Ok(())
+    }
+
     #[test]
     fn test_page_size() -> Result<(), PdfiumError> {
         // Tests the dimensions of each page in a sample file.
@@ -706,6 +1303,216 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_parse_page_range() {
+        use crate::pdf::document::pages::parse_page_range;
+
+        assert_eq!(parse_page_range("1,3,5-7", 8).unwrap(), vec![0, 2, 4, 5, 6]);
+        assert_eq!(parse_page_range(" 2 - 3 , 1 ", 3).unwrap(), vec![1, 2, 0]);
+
+        for (range, expected) in [
+            ("", "no pages given in page range \"\""),
+            (
+                "0",
+                "page numbers start at 1, but found page 0 in page range \"0\"",
+            ),
+            (
+                "1,9",
+                "page 9 is out of range for a document with 8 pages in page range \"1,9\"",
+            ),
+            (
+                "1,,2",
+                "\"\" is not a valid page number in page range \"1,,2\"",
+            ),
+            (
+                "x-2",
+                "\"x\" is not a valid page number in page range \"x-2\"",
+            ),
+            (
+                "5-3",
+                "the range 5-3 is in descending order in page range \"5-3\"",
+            ),
+        ] {
+            match parse_page_range(range, 8) {
+                Err(PdfiumError::InvalidPageRange(message)) => assert_eq!(message, expected),
+                result => panic!("unexpected result for {:?}: {:?}", range, result),
+            }
+        }
+    }
+
+    #[test]
+    fn test_import_pages_preserves_text_and_fonts() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let first = pdfium.load_pdf_from_file("./test/text-test.pdf", None)?;
+
+        let second = pdfium.load_pdf_from_file("./test/export-test.pdf", None)?;
+
+        let mut merged = pdfium.create_new_pdf()?;
+
+        merged.append_document(&first)?;
+        merged
+            .pages_mut()
+            .import_pages_from_document(&second, "1", 0)?;
+
+        assert_eq!(merged.pages().len(), first.pages().len() + 1);
+
+        assert!(matches!(
+            merged
+                .pages_mut()
+                .import_pages_from_document(&second, vec![second.pages().len()], 0),
+            Err(PdfiumError::PageIndexOutOfBounds)
+        ));
+
+        let sources = std::iter::once(second.pages().get(0)?).chain(first.pages().iter());
+
+        let config = PdfRenderConfig::new().set_target_width(400);
+
+        for (source, copy) in sources.zip(merged.pages().iter()) {
+            assert_eq!(source.text()?.all(), copy.text()?.all());
+
+            // Text using the fonts embedded in the source documents should render identically.
+
+            assert_eq!(
+                source.render_with_config(&config)?.as_rgba_bytes(),
+                copy.render_with_config(&config)?.as_rgba_bytes()
+            );
+        }
+
+        Ok(())
+    }
+
+    #[cfg(any(
+        feature = "pdfium_future",
+        feature = "pdfium_6996",
+        feature = "pdfium_6721",
+        feature = "pdfium_6666",
+        feature = "pdfium_6611",
+        feature = "pdfium_6569",
+        feature = "pdfium_6555",
+        feature = "pdfium_6490",
+        feature = "pdfium_6406",
+        feature = "pdfium_6337",
+        feature = "pdfium_6295",
+        feature = "pdfium_6259",
+        feature = "pdfium_6164",
+        feature = "pdfium_6124",
+        feature = "pdfium_6110",
+        feature = "pdfium_6084",
+        feature = "pdfium_6043",
+    ))]
+    fn create_numbered_pages<'a>(
+        pdfium: &'a Pdfium,
+        count: u16,
+    ) -> Result<PdfDocument<'a>, PdfiumError> {
+        let mut document = pdfium.create_new_pdf()?;
+
+        let font = document.fonts_mut().helvetica();
+
+        for index in 0..count {
+            let mut page = document
+                .pages_mut()
+                .create_page_at_end(PdfPagePaperSize::a4())?;
+
+            page.objects_mut().create_text_object(
+                PdfPoints::new(100.0),
+                PdfPoints::new(700.0),
+                format!("Page {}", index),
+                font,
+                PdfPoints::new(24.0),
+            )?;
+        }
+
+        Ok(document)
+    }
+
+    #[test]
+    #[cfg(any(
+        feature = "pdfium_future",
+        feature = "pdfium_6996",
+        feature = "pdfium_6721",
+        feature = "pdfium_6666",
+        feature = "pdfium_6611",
+        feature = "pdfium_6569",
+        feature = "pdfium_6555",
+        feature = "pdfium_6490",
+        feature = "pdfium_6406",
+        feature = "pdfium_6337",
+        feature = "pdfium_6295",
+        feature = "pdfium_6259",
+        feature = "pdfium_6164",
+        feature = "pdfium_6124",
+        feature = "pdfium_6110",
+        feature = "pdfium_6084",
+        feature = "pdfium_6043",
+    ))]
+    fn test_move_pages_into_reverse_order() -> Result<(), PdfiumError> {
+        use crate::pdf::document::page::index_cache::PdfPageIndexCache;
+
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = create_numbered_pages(&pdfium, 5)?;
+
+        // A page loaded before the move should report its new index afterwards.
+
+        let first = document.pages().get(0)?;
+
+        for index in 0..4 {
+            document.pages_mut().move_page(4, index)?;
+        }
+
+        assert_eq!(
+            PdfPageIndexCache::get_index_for_page(document.handle(), first.page_handle()),
+            Some(4)
+        );
+
+        for (index, page) in document.pages().iter().enumerate() {
+            assert_eq!(page.text()?.all(), format!("Page {}", 4 - index));
+        }
+
+        document.pages_mut().swap(0, 4)?;
+        document.pages_mut().swap(3, 1)?;
+
+        let expected = [0, 1, 2, 3, 4];
+
+        for (index, page) in document.pages().iter().enumerate() {
+            assert_eq!(page.text()?.all(), format!("Page {}", expected[index]));
+        }
+
+        assert!(matches!(
+            document.pages_mut().move_page(0, 5),
+            Err(PdfiumError::PageIndexOutOfBounds)
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_duplicate_page() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.load_pdf_from_file("./test/export-test.pdf", None)?;
+
+        let len = document.pages().len();
+
+        let copy = document.pages_mut().duplicate(0, len)?;
+
+        assert_eq!(document.pages().len(), len + 1);
+
+        let config = PdfRenderConfig::new().set_target_width(400);
+
+        assert_eq!(
+            document
+                .pages()
+                .get(0)?
+                .render_with_config(&config)?
+                .as_rgba_bytes(),
+            copy.render_with_config(&config)?.as_rgba_bytes()
+        );
+
+        Ok(())
+    }
+
     const fn expected_page_0_size() -> PdfRect {
         PdfRect::new_from_values(0.0, 0.0, 841.8898, 595.30396)
     }
@@ -725,4 +1532,71 @@ mod tests {
     const fn expected_page_4_size() -> PdfRect {
         expected_page_0_size()
     }
+
+    #[test]
+    fn test_page_labels() -> Result<(), PdfiumError> {
+        use crate::pdf::document::raw::{
+            get, parse_reference, set, to_dictionary_string, PdfIncrementalUpdate, PdfRawDocument,
+        };
+
+        let pdfium = test_bind_to_pdfium();
+
+        let mut bytes = {
+            let mut document = pdfium.create_new_pdf()?;
+
+            for _ in 0..8 {
+                document
+                    .pages_mut()
+                    .create_page_at_end(PdfPagePaperSize::a4())?;
+            }
+
+            document.save_to_bytes()?
+        };
+
+        // Pdfium cannot create page labels, so we add a /PageLabels number tree to the
+        // document catalog ourselves: lowercase roman numerals for the first five pages,
+        // then arabic numerals starting again from 1.
+
+        let update = {
+            let raw = PdfRawDocument::parse(bytes.as_slice()).unwrap();
+
+            let root = get(raw.trailer(), "Root")
+                .and_then(parse_reference)
+                .unwrap();
+
+            let mut catalog = raw.object_dictionary(root).unwrap();
+
+            set(
+                &mut catalog,
+                "PageLabels",
+                "<< /Nums [0 << /S /r >> 5 << /S /D >>] >>".to_owned(),
+            );
+
+            let mut update = PdfIncrementalUpdate::new(&raw)?;
+
+            update.replace_object(root, to_dictionary_string(&catalog).into_bytes());
+
+            update
+        };
+
+        update.append_to(&mut bytes);
+
+        let document = pdfium.load_pdf_from_byte_vec(bytes, None)?;
+
+        let pages = document.pages();
+
+        assert_eq!(pages.label_for_index(0).as_deref(), Some("i"));
+        assert_eq!(pages.label_for_index(3).as_deref(), Some("iv"));
+        assert_eq!(pages.label_for_index(5).as_deref(), Some("1"));
+        assert_eq!(pages.get(3)?.label(), Some("iv"));
+        assert_eq!(pages.index_for_label("iv"), Some(3));
+        assert_eq!(pages.index_for_label("3"), Some(7));
+        assert_eq!(pages.index_for_label("ix"), None);
+
+        let document = pdfium.create_new_pdf()?;
+
+        assert_eq!(document.pages().label_for_index(0), None);
+
+        Ok(())
+    }
 }
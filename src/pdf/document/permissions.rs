@@ -27,6 +27,45 @@ bitflags! {
     }
 }
 
+bitflags! {
+    /// The operations a user may perform on a [PdfDocument] opened with its user password,
+    /// as defined in table 3.20 of the PDF Reference, version 1.7, on page 124.
+    /// A user who opens the document with its owner password may perform any operation.
+    #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+    pub struct PdfPermissionFlags: u32 {
+        /// Print the document, possibly at degraded quality unless
+        /// [PdfPermissionFlags::PRINT_HIGH_QUALITY] is also set.
+        const PRINT = FpdfPermissions::CAN_PRINT_BIT_3.bits();
+
+        /// Modify the contents of the document by operations other than those controlled by
+        /// [PdfPermissionFlags::ANNOTATE], [PdfPermissionFlags::FILL_FORMS],
+        /// and [PdfPermissionFlags::ASSEMBLE].
+        const MODIFY = FpdfPermissions::CAN_MODIFY_BIT_4.bits();
+
+        /// Copy or otherwise extract text and graphics from the document.
+        const COPY = FpdfPermissions::CAN_EXTRACT_TEXT_AND_GRAPHICS_BIT_5.bits();
+
+        /// Add or modify annotations, and fill in form fields. If [PdfPermissionFlags::MODIFY]
+        /// is also set, create or modify form fields.
+        const ANNOTATE = FpdfPermissions::CAN_ANNOTATE_AND_FORM_FILL_BIT_6.bits();
+
+        /// Fill in existing form fields, including signature fields, even if
+        /// [PdfPermissionFlags::ANNOTATE] is not set.
+        const FILL_FORMS = FpdfPermissions::V3_CAN_FORM_FILL_BIT_9.bits();
+
+        /// Extract text and graphics in support of accessibility to users with disabilities.
+        const COPY_FOR_ACCESSIBILITY = FpdfPermissions::V3_CAN_EXTRACT_TEXT_AND_GRAPHICS_BIT_10.bits();
+
+        /// Assemble the document: insert, rotate, or delete pages, and create bookmarks or
+        /// thumbnail images, even if [PdfPermissionFlags::MODIFY] is not set.
+        const ASSEMBLE = FpdfPermissions::V3_CAN_ASSEMBLE_DOCUMENT_BIT_11.bits();
+
+        /// Print the document to a representation from which a faithful digital copy of
+        /// the original content could be recovered.
+        const PRINT_HIGH_QUALITY = FpdfPermissions::V3_CAN_PRINT_HIGH_QUALITY_BIT_12.bits();
+    }
+}
+
 /// The revision of the standard security handler for a single [PdfDocument].
 #[derive(Copy, Clone, Debug, PartialEq)]
 pub enum PdfSecurityHandlerRevision {
@@ -34,6 +73,8 @@ pub enum PdfSecurityHandlerRevision {
     Revision2,
     Revision3,
     Revision4,
+    Revision5,
+    Revision6,
 }
 
 impl PdfSecurityHandlerRevision {
@@ -43,6 +84,8 @@ impl PdfSecurityHandlerRevision {
             2 => Some(PdfSecurityHandlerRevision::Revision2),
             3 => Some(PdfSecurityHandlerRevision::Revision3),
             4 => Some(PdfSecurityHandlerRevision::Revision4),
+            5 => Some(PdfSecurityHandlerRevision::Revision5),
+            6 => Some(PdfSecurityHandlerRevision::Revision6),
             _ => None,
         }
     }
@@ -84,8 +127,19 @@ impl<'a> PdfPermissions<'a> {
         )
     }
 
+    /// Returns the [PdfPermissionFlags] set for the containing [PdfDocument]. Unlike the
+    /// `can_*()` functions, the flags are returned as stored, regardless of the revision of
+    /// the security handler. If the document is not encrypted, all flags are set.
+    pub fn permission_flags(&self) -> Result<PdfPermissionFlags, PdfiumError> {
+        Ok(match self.security_handler_revision()? {
+            PdfSecurityHandlerRevision::Unprotected => PdfPermissionFlags::all(),
+            _ => PdfPermissionFlags::from_bits_truncate(self.get_permissions_bits().bits()),
+        })
+    }
+
     /// Returns the revision of the standard security handler used by the containing [PdfDocument].
-    /// As of PDF version 1.7, possible revision numbers are 2, 3, or 4.
+    /// As of PDF version 1.7, possible revision numbers are 2, 3, or 4. PDF version 2.0 adds
+    /// revision 6, and revision 5 is used by some earlier AES-256 encrypted documents.
     pub fn security_handler_revision(&self) -> Result<PdfSecurityHandlerRevision, PdfiumError> {
         PdfSecurityHandlerRevision::from_pdfium(
             self.bindings()
@@ -94,6 +148,27 @@ impl<'a> PdfPermissions<'a> {
         .ok_or(PdfiumError::UnknownPdfSecurityHandlerRevision)
     }
 
+    /// Returns `true` if the containing [PdfDocument] is encrypted using a security handler.
+    #[inline]
+    pub fn is_encrypted(&self) -> Result<bool, PdfiumError> {
+        Ok(self.security_handler_revision()? != PdfSecurityHandlerRevision::Unprotected)
+    }
+
+    /// Returns `true` if the containing [PdfDocument] can be printed, at any quality.
+    ///
+    /// Use the [PdfPermissions::can_print_high_quality()] function to determine whether the
+    /// document can be printed at full quality.
+    pub fn can_print(&self) -> Result<bool, PdfiumError> {
+        let permissions = self.get_permissions_bits();
+
+        let result = match self.security_handler_revision()? {
+            PdfSecurityHandlerRevision::Unprotected => true,
+            _ => permissions.contains(FpdfPermissions::CAN_PRINT_BIT_3),
+        };
+
+        Ok(result)
+    }
+
     /// Returns `true` if the containing [PdfDocument] can be printed to a representation
     /// from which a faithful digital copy of the original content could be recovered.
     pub fn can_print_high_quality(&self) -> Result<bool, PdfiumError> {
@@ -104,7 +179,10 @@ impl<'a> PdfPermissions<'a> {
             PdfSecurityHandlerRevision::Revision2 => {
                 permissions.contains(FpdfPermissions::CAN_PRINT_BIT_3)
             }
-            PdfSecurityHandlerRevision::Revision3 | PdfSecurityHandlerRevision::Revision4 => {
+            PdfSecurityHandlerRevision::Revision3
+            | PdfSecurityHandlerRevision::Revision4
+            | PdfSecurityHandlerRevision::Revision5
+            | PdfSecurityHandlerRevision::Revision6 => {
                 permissions.contains(FpdfPermissions::CAN_PRINT_BIT_3)
                     && permissions.contains(FpdfPermissions::V3_CAN_PRINT_HIGH_QUALITY_BIT_12)
             }
@@ -123,7 +201,10 @@ impl<'a> PdfPermissions<'a> {
             PdfSecurityHandlerRevision::Unprotected | PdfSecurityHandlerRevision::Revision2 => {
                 false
             }
-            PdfSecurityHandlerRevision::Revision3 | PdfSecurityHandlerRevision::Revision4 => {
+            PdfSecurityHandlerRevision::Revision3
+            | PdfSecurityHandlerRevision::Revision4
+            | PdfSecurityHandlerRevision::Revision5
+            | PdfSecurityHandlerRevision::Revision6 => {
                 permissions.contains(FpdfPermissions::CAN_PRINT_BIT_3)
                     && !permissions.contains(FpdfPermissions::V3_CAN_PRINT_HIGH_QUALITY_BIT_12)
             }
@@ -132,19 +213,38 @@ impl<'a> PdfPermissions<'a> {
         Ok(result)
     }
 
-    /// Returns `true` if the containing [PdfDocument] can be _assembled_; that is, the
-    /// document can have pages inserted, rotated, or deleted, can have bookmarks created,
-    /// or can have thumbnail page images created.
-    pub fn can_assemble_document(&self) -> Result<bool, PdfiumError> {
+    /// Returns `true` if the containing [PdfDocument] permits text and graphics to be copied
+    /// or otherwise extracted for any purpose.
+    pub fn can_copy(&self) -> Result<bool, PdfiumError> {
+        let permissions = self.get_permissions_bits();
+
+        let result = match self.security_handler_revision()? {
+            PdfSecurityHandlerRevision::Unprotected => true,
+            _ => permissions.contains(FpdfPermissions::CAN_EXTRACT_TEXT_AND_GRAPHICS_BIT_5),
+        };
+
+        Ok(result)
+    }
+
+    /// Returns `true` if the containing [PdfDocument] permits text and graphics to be extracted
+    /// in support of accessibility to users with disabilities or for other purposes.
+    ///
+    /// Security handler revision 2 does not distinguish extraction for accessibility from
+    /// general extraction, so for documents using that revision this function returns
+    /// the same value as [PdfPermissions::can_copy()].
+    pub fn can_copy_for_accessibility(&self) -> Result<bool, PdfiumError> {
         let permissions = self.get_permissions_bits();
 
         let result = match self.security_handler_revision()? {
             PdfSecurityHandlerRevision::Unprotected => true,
             PdfSecurityHandlerRevision::Revision2 => {
-                permissions.contains(FpdfPermissions::CAN_MODIFY_BIT_4)
+                permissions.contains(FpdfPermissions::CAN_EXTRACT_TEXT_AND_GRAPHICS_BIT_5)
             }
-            PdfSecurityHandlerRevision::Revision3 | PdfSecurityHandlerRevision::Revision4 => {
-                permissions.contains(FpdfPermissions::V3_CAN_ASSEMBLE_DOCUMENT_BIT_11)
+            PdfSecurityHandlerRevision::Revision3
+            | PdfSecurityHandlerRevision::Revision4
+            | PdfSecurityHandlerRevision::Revision5
+            | PdfSecurityHandlerRevision::Revision6 => {
+                permissions.contains(FpdfPermissions::V3_CAN_EXTRACT_TEXT_AND_GRAPHICS_BIT_10)
             }
         };
 
@@ -156,7 +256,7 @@ impl<'a> PdfPermissions<'a> {
     ///
     /// For security handler revisions 3 and later, general document modification can be disabled
     /// while still allowing modification of annotations and interactive form fields.
-    pub fn can_modify_document_content(&self) -> Result<bool, PdfiumError> {
+    pub fn can_modify(&self) -> Result<bool, PdfiumError> {
         let permissions = self.get_permissions_bits();
 
         let result = match self.security_handler_revision()? {
@@ -167,6 +267,98 @@ impl<'a> PdfPermissions<'a> {
         Ok(result)
     }
 
+    /// Returns `true` if the containing [PdfDocument] allows the addition or modification
+    /// of annotations.
+    pub fn can_annotate(&self) -> Result<bool, PdfiumError> {
+        let permissions = self.get_permissions_bits();
+
+        let result = match self.security_handler_revision()? {
+            PdfSecurityHandlerRevision::Unprotected => true,
+            _ => permissions.contains(FpdfPermissions::CAN_ANNOTATE_AND_FORM_FILL_BIT_6),
+        };
+
+        Ok(result)
+    }
+
+    /// Returns `true` if the containing [PdfDocument] permits existing form fields,
+    /// including signature fields, to be filled in by a user.
+    ///
+    /// For security handler revisions 3 and later, form filling can be permitted even if
+    /// the addition or modification of annotations is not.
+    pub fn can_fill_forms(&self) -> Result<bool, PdfiumError> {
+        let permissions = self.get_permissions_bits();
+
+        let result = match self.security_handler_revision()? {
+            PdfSecurityHandlerRevision::Unprotected => true,
+            PdfSecurityHandlerRevision::Revision2 => {
+                permissions.contains(FpdfPermissions::CAN_ANNOTATE_AND_FORM_FILL_BIT_6)
+            }
+            PdfSecurityHandlerRevision::Revision3
+            | PdfSecurityHandlerRevision::Revision4
+            | PdfSecurityHandlerRevision::Revision5
+            | PdfSecurityHandlerRevision::Revision6 => {
+                permissions.contains(FpdfPermissions::CAN_ANNOTATE_AND_FORM_FILL_BIT_6)
+                    || permissions.contains(FpdfPermissions::V3_CAN_FORM_FILL_BIT_9)
+            }
+        };
+
+        Ok(result)
+    }
+
+    /// Returns `true` if the containing [PdfDocument] can be _assembled_; that is, the
+    /// document can have pages inserted, rotated, or deleted, can have bookmarks created,
+    /// or can have thumbnail page images created.
+    pub fn can_assemble(&self) -> Result<bool, PdfiumError> {
+        let permissions = self.get_permissions_bits();
+
+        let result = match self.security_handler_revision()? {
+            PdfSecurityHandlerRevision::Unprotected => true,
+            PdfSecurityHandlerRevision::Revision2 => {
+                permissions.contains(FpdfPermissions::CAN_MODIFY_BIT_4)
+            }
+            PdfSecurityHandlerRevision::Revision3
+            | PdfSecurityHandlerRevision::Revision4
+            | PdfSecurityHandlerRevision::Revision5
+            | PdfSecurityHandlerRevision::Revision6 => {
+                permissions.contains(FpdfPermissions::V3_CAN_ASSEMBLE_DOCUMENT_BIT_11)
+            }
+        };
+
+        Ok(result)
+    }
+
+    // TODO: AJRC - 15/10/26 - remove deprecated PdfPermissions::can_assemble_document() function in 0.9.0
+    // as part of tracking issue https://github.com/ajrcarey/pdfium-render/issues/36
+    /// Returns `true` if the containing [PdfDocument] can be _assembled_; that is, the
+    /// document can have pages inserted, rotated, or deleted, can have bookmarks created,
+    /// or can have thumbnail page images created.
+    #[deprecated(
+        since = "0.8.30",
+        note = "This function has been renamed. Use the PdfPermissions::can_assemble() function instead."
+    )]
+    #[doc(hidden)]
+    #[inline]
+    pub fn can_assemble_document(&self) -> Result<bool, PdfiumError> {
+        self.can_assemble()
+    }
+
+    // TODO: AJRC - 15/10/26 - remove deprecated PdfPermissions::can_modify_document_content() function in 0.9.0
+    // as part of tracking issue https://github.com/ajrcarey/pdfium-render/issues/36
+    /// Returns `true` if the containing [PdfDocument] allows general modification of
+    /// the document contents.
+    ///
+    /// For security handler revisions 3 and later, general document modification can be disabled
+    /// while still allowing modification of annotations and interactive form fields.
+    #[deprecated(
+        since = "0.8.30",
+        note = "This function has been renamed. Use the PdfPermissions::can_modify() function instead."
+    )]
+    #[doc(hidden)]
+    #[inline]
+    pub fn can_modify_document_content(&self) -> Result<bool, PdfiumError> {
+        self.can_modify()
+    }
+
     /// Returns `true` if the containing [PdfDocument] permits text and graphics to be extracted.
     pub fn can_extract_text_and_graphics(&self) -> Result<bool, PdfiumError> {
         let permissions = self.get_permissions_bits();
@@ -177,7 +369,10 @@ impl<'a> PdfPermissions<'a> {
                 permissions.contains(FpdfPermissions::CAN_EXTRACT_TEXT_AND_GRAPHICS_BIT_5)
             }
             // TODO: AJRC - 27/5/22 - what operations are permitted by bit 10 but prevented by bit 5?
-            PdfSecurityHandlerRevision::Revision3 | PdfSecurityHandlerRevision::Revision4 => {
+            PdfSecurityHandlerRevision::Revision3
+            | PdfSecurityHandlerRevision::Revision4
+            | PdfSecurityHandlerRevision::Revision5
+            | PdfSecurityHandlerRevision::Revision6 => {
                 permissions.contains(FpdfPermissions::V3_CAN_EXTRACT_TEXT_AND_GRAPHICS_BIT_10)
             }
         };
@@ -195,7 +390,10 @@ impl<'a> PdfPermissions<'a> {
             PdfSecurityHandlerRevision::Revision2 => {
                 permissions.contains(FpdfPermissions::CAN_ANNOTATE_AND_FORM_FILL_BIT_6)
             }
-            PdfSecurityHandlerRevision::Revision3 | PdfSecurityHandlerRevision::Revision4 => {
+            PdfSecurityHandlerRevision::Revision3
+            | PdfSecurityHandlerRevision::Revision4
+            | PdfSecurityHandlerRevision::Revision5
+            | PdfSecurityHandlerRevision::Revision6 => {
                 permissions.contains(FpdfPermissions::V3_CAN_FORM_FILL_BIT_9)
             }
         };
@@ -219,16 +417,105 @@ impl<'a> PdfPermissions<'a> {
         Ok(result)
     }
 
+    // TODO: AJRC - 15/10/26 - remove deprecated PdfPermissions::can_add_or_modify_text_annotations() function in 0.9.0
+    // as part of tracking issue https://github.com/ajrcarey/pdfium-render/issues/36
     /// Returns `true` if the containing [PdfDocument] allows the addition or modification
     /// of text annotations.
+    #[deprecated(
+        since = "0.8.30",
+        note = "This function has been renamed. Use the PdfPermissions::can_annotate() function instead."
+    )]
+    #[doc(hidden)]
+    #[inline]
     pub fn can_add_or_modify_text_annotations(&self) -> Result<bool, PdfiumError> {
-        let permissions = self.get_permissions_bits();
+        self.can_annotate()
+    }
+}
 
-        let result = match self.security_handler_revision()? {
-            PdfSecurityHandlerRevision::Unprotected => true,
-            _ => permissions.contains(FpdfPermissions::CAN_ANNOTATE_AND_FORM_FILL_BIT_6),
-        };
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::utils::test::test_bind_to_pdfium;
+
+    #[test]
+    fn test_unencrypted_document_permissions() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let document = pdfium.load_pdf_from_file("./test/text-test.pdf", None)?;
+
+        let permissions = document.permissions();
+
+        assert_eq!(
+            permissions.security_handler_revision()?,
+            PdfSecurityHandlerRevision::Unprotected
+        );
+        assert!(!permissions.is_encrypted()?);
+        assert!(permissions.can_print()?);
+        assert!(permissions.can_print_high_quality()?);
+        assert!(permissions.can_copy()?);
+        assert!(permissions.can_copy_for_accessibility()?);
+        assert!(permissions.can_modify()?);
+        assert!(permissions.can_annotate()?);
+        assert!(permissions.can_fill_forms()?);
+        assert!(permissions.can_assemble()?);
+
+        Ok(())
+    }
 
-        Ok(result)
+    #[test]
+    fn test_revision_2_document_permissions() -> Result<(), PdfiumError> {
+        // The test document permits printing, but denies modification, copying, and
+        // annotation. Bits 9 to 12 are set, but must be ignored for revision 2 handlers.
+
+        let pdfium = test_bind_to_pdfium();
+
+        let document = pdfium.load_pdf_from_file("./test/permissions-r2-test.pdf", None)?;
+
+        let permissions = document.permissions();
+
+        assert_eq!(
+            permissions.security_handler_revision()?,
+            PdfSecurityHandlerRevision::Revision2
+        );
+        assert!(permissions.is_encrypted()?);
+        assert!(permissions.can_print()?);
+        assert!(permissions.can_print_high_quality()?);
+        assert!(!permissions.can_copy()?);
+        assert!(!permissions.can_copy_for_accessibility()?);
+        assert!(!permissions.can_modify()?);
+        assert!(!permissions.can_annotate()?);
+        assert!(!permissions.can_fill_forms()?);
+        assert!(!permissions.can_assemble()?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_revision_3_document_permissions() -> Result<(), PdfiumError> {
+        // The test document permits low quality printing, form filling, and extraction
+        // for accessibility, but denies everything else.
+
+        let pdfium = test_bind_to_pdfium();
+
+        let document = pdfium.load_pdf_from_file("./test/permissions-r3-test.pdf", None)?;
+
+        let permissions = document.permissions();
+
+        assert_eq!(
+            permissions.security_handler_revision()?,
+            PdfSecurityHandlerRevision::Revision3
+        );
+        assert!(permissions.is_encrypted()?);
+        assert!(permissions.can_print()?);
+        assert!(!permissions.can_print_high_quality()?);
+        assert!(permissions.can_print_only_low_quality()?);
+        assert!(!permissions.can_copy()?);
+        assert!(permissions.can_copy_for_accessibility()?);
+        assert!(!permissions.can_modify()?);
+        assert!(!permissions.can_annotate()?);
+        assert!(permissions.can_fill_forms()?);
+        assert!(!permissions.can_assemble()?);
+
+        Ok(())
     }
 }
@@ -0,0 +1,1804 @@
+//! Minimal, crate-internal reading and incremental updating of the raw PDF syntax in a
+//! saved document, for the small number of document-level structures that Pdfium offers
+//! no API to modify, such as the document information dictionary and the XMP metadata stream.
+//!
+//! Documents are located by following the final `startxref` offset to the most recent
+//! cross-reference section, which may be either a classic cross-reference table followed by
+//! a trailer or a cross-reference stream, and then walking the chain of `/Prev` entries.
+//! Pdfium writes a classic table when saving, but an incremental save leaves the sections of
+//! the original file in place, so both forms can appear in the same document. Objects held in
+//! compressed object streams can be read, but cannot be found by [PdfRawDocument::objects_containing()].
+
+use crate::error::PdfiumError;
+use once_cell::unsync::OnceCell;
+use std::collections::HashMap;
+
+/// The entries of a single PDF dictionary, as pairs of keys (without the leading `/`)
+/// and raw serialized values.
+pub(crate) type PdfRawDictionary = Vec<(String, String)>;
+
+/// The location of an object, as recorded in a cross-reference section.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(crate) enum PdfRawObjectLocation {
+    /// The object number is not in use.
+    Free,
+
+    /// The object is written directly into the file at the given byte offset.
+    Offset(usize),
+
+    /// The object is the object at the given index in the given compressed object stream.
+    Compressed { stream: u32, index: usize },
+}
+
+/// The generation number and location of every object listed in a document's
+/// cross-reference sections, keyed by object number.
+type PdfRawXref = HashMap<u32, (u16, PdfRawObjectLocation)>;
+
+/// Read-only access to the objects in a saved PDF file.
+pub(crate) struct PdfRawDocument<'a> {
+    bytes: &'a [u8],
+    trailer: PdfRawDictionary,
+    xref_offset: usize,
+    xref: OnceCell<Option<PdfRawXref>>,
+}
+
+impl<'a> PdfRawDocument<'a> {
+    /// Parses the trailer of the cross-reference section at the final `startxref` offset
+    /// in the given saved document bytes.
+    pub(crate) fn parse(bytes: &'a [u8]) -> Option<Self> {
+        let startxref = rfind(bytes, b"startxref")?;
+
+        let (xref_offset, _) = read_unsigned(
+            bytes,
+            skip_whitespace(bytes, startxref + b"startxref".len()),
+        )?;
+
+        let mut result = PdfRawDocument {
+            bytes,
+            trailer: Vec::new(),
+            xref_offset,
+            xref: OnceCell::new(),
+        };
+
+        result.trailer = match result.read_xref_section(xref_offset) {
+            Some((trailer, _)) => trailer,
+            None => {
+                // The startxref offset does not point to a cross-reference section. Recover
+                // by using the last trailer in the file, as most readers do; objects will be
+                // located by scanning for their headers.
+
+                let trailer = rfind(&bytes[..startxref], b"trailer")?;
+
+                read_dictionary(bytes, skip_whitespace(bytes, trailer + b"trailer".len()))?.0
+            }
+        };
+
+        Some(result)
+    }
+
+    /// Returns the entries in the final trailer dictionary.
+    #[inline]
+    pub(crate) fn trailer(&self) -> &PdfRawDictionary {
+        &self.trailer
+    }
+
+    /// Returns the byte offset of the final cross-reference section.
+    #[inline]
+    pub(crate) fn xref_offset(&self) -> usize {
+        self.xref_offset
+    }
+
+    /// Returns the object number of the document catalog.
+    #[inline]
+    pub(crate) fn root_object_number(&self) -> Option<u32> {
+        get(&self.trailer, "Root").and_then(parse_reference)
+    }
+
+    /// Returns the dictionary of the given indirect object.
+    pub(crate) fn object_dictionary(&self, object_number: u32) -> Option<PdfRawDictionary> {
+        match self.object_location(object_number) {
+            Some(PdfRawObjectLocation::Compressed { stream, index }) => {
+                let (data, position) = self.compressed_object(stream, index)?;
+
+                read_dictionary(data.as_slice(), position).map(|(dictionary, _)| dictionary)
+            }
+            _ => read_dictionary(self.bytes, self.object_body(object_number)?)
+                .map(|(dictionary, _)| dictionary),
+        }
+    }
+
+    /// Returns the value of the given indirect object, if it is an unsigned integer.
+    pub(crate) fn object_unsigned(&self, object_number: u32) -> Option<usize> {
+        match self.object_location(object_number) {
+            Some(PdfRawObjectLocation::Compressed { stream, index }) => {
+                let (data, position) = self.compressed_object(stream, index)?;
+
+                read_unsigned(data.as_slice(), skip_whitespace(data.as_slice(), position))
+                    .map(|(value, _)| value)
+            }
+            _ => {
+                read_unsigned(self.bytes, self.object_body(object_number)?).map(|(value, _)| value)
+            }
+        }
+    }
+
+    /// Returns the dictionary and raw (still encoded) data of the given indirect stream object.
+    #[inline]
+    pub(crate) fn object_stream(&self, object_number: u32) -> Option<(PdfRawDictionary, &'a [u8])> {
+        self.stream_at(self.object_body(object_number)?)
+    }
+
+    /// Returns the dictionary and raw (still encoded) data of the stream object
+    /// whose body starts at the given position.
+    fn stream_at(&self, position: usize) -> Option<(PdfRawDictionary, &'a [u8])> {
+        let (dictionary, position) = read_dictionary(self.bytes, position)?;
+
+        let position = skip_whitespace(self.bytes, position);
+
+        if !self.bytes[position..].starts_with(b"stream") {
+            return None;
+        }
+
+        // The stream keyword is followed by either CRLF or LF, never by CR alone.
+
+        let mut start = position + b"stream".len();
+
+        if self.bytes[start..].starts_with(b"\r\n") {
+            start += 2;
+        } else if self.bytes[start..].starts_with(b"\n") {
+            start += 1;
+        }
+
+        let length = get(&dictionary, "Length").and_then(|length| match parse_reference(length) {
+            Some(object_number) => self.object_unsigned(object_number),
+            None => length.parse().ok(),
+        });
+
+        let end = match length {
+            Some(length) if start + length <= self.bytes.len() => start + length,
+            _ => start + find(&self.bytes[start..], b"endstream")?,
+        };
+
+        Some((dictionary, &self.bytes[start..end]))
+    }
+
+    /// Returns the decoded data of the given compressed object stream, together with the
+    /// position in that data of the object at the given index in the stream.
+    pub(crate) fn compressed_object(&self, stream: u32, index: usize) -> Option<(Vec<u8>, usize)> {
+        let (dictionary, data) = self.object_stream(stream)?;
+
+        if get(&dictionary, "Type") != Some("/ObjStm") {
+            return None;
+        }
+
+        let count: usize = get(&dictionary, "N")?.parse().ok()?;
+
+        let first: usize = get(&dictionary, "First")?.parse().ok()?;
+
+        if index >= count {
+            return None;
+        }
+
+        let data = decode_stream(&dictionary, data)?;
+
+        // The stream begins with pairs of integers giving the object number of each object
+        // in the stream and its offset relative to the first object.
+
+        let mut position = 0;
+
+        let mut offset = 0;
+
+        for _ in 0..=index {
+            let (_, next) = read_unsigned(&data, skip_whitespace(&data, position))?;
+
+            let (next_offset, next) = read_unsigned(&data, skip_whitespace(&data, next))?;
+
+            offset = next_offset;
+            position = next;
+        }
+
+        let position = first + offset;
+
+        if position >= data.len() {
+            return None;
+        }
+
+        Some((data, position))
+    }
+
+    /// Returns the object numbers of all page objects in the document, in page order,
+    /// by walking the page tree starting at the document catalog.
+    pub(crate) fn page_object_numbers(&self) -> Option<Vec<u32>> {
+        let catalog = self.object_dictionary(self.root_object_number()?)?;
+
+        let mut result = Vec::new();
+
+        let mut pending = vec![get(&catalog, "Pages").and_then(parse_reference)?];
+
+        // Guard against malformed files containing a cycle in the page tree.
+
+        let mut remaining_nodes = 1_000_000;
+
+        while let Some(object_number) = pending.pop() {
+            remaining_nodes -= 1;
+
+            if remaining_nodes == 0 {
+                return None;
+            }
+
+            let node = self.object_dictionary(object_number)?;
+
+            match get(&node, "Kids") {
+                Some(kids) => {
+                    // Push children in reverse order so they are visited in document order.
+
+                    pending.extend(parse_references(kids).into_iter().rev());
+                }
+                None => result.push(object_number),
+            }
+        }
+
+        Some(result)
+    }
+
+    /// Returns the object numbers of all indirect objects whose serialized content includes
+    /// the given byte sequence. Only objects written directly into the file, rather than
+    /// inside compressed object streams, are found.
+    pub(crate) fn objects_containing(&self, needle: &[u8]) -> Vec<u32> {
+        let mut result = Vec::new();
+
+        let mut start = 0;
+
+        while let Some(position) = find(&self.bytes[start..], needle).map(|index| index + start) {
+            start = position + needle.len();
+
+            if let Some(object_number) = self.object_number_at(position) {
+                if !result.contains(&object_number) {
+                    result.push(object_number);
+                }
+            }
+        }
+
+        result
+    }
+
+    /// Returns the number of the indirect object whose header most closely precedes
+    /// the given position, if any.
+    fn object_number_at(&self, position: usize) -> Option<u32> {
+        let header = rfind(&self.bytes[..position], b"obj")?;
+
+        if header >= 3 && &self.bytes[header - 3..header] == b"end" {
+            // The given position lies between two objects.
+
+            return None;
+        }
+
+        // Walk backwards over the generation number and the object number.
+
+        let mut tokens = self.bytes[..header]
+            .rsplit(|c| is_whitespace(*c))
+            .filter(|token| !token.is_empty());
+
+        let _generation = tokens.next()?;
+
+        std::str::from_utf8(tokens.next()?).ok()?.parse().ok()
+    }
+
+    /// Returns the position of the first token following `obj` in the given indirect object,
+    /// which must be written directly into the file rather than into a compressed object stream.
+    fn object_body(&self, object_number: u32) -> Option<usize> {
+        let offset = match self.object_location(object_number) {
+            Some(PdfRawObjectLocation::Offset(offset)) => offset,
+            Some(PdfRawObjectLocation::Compressed { .. }) => return None,
+            _ => self.object_offset_from_scan(object_number)?,
+        };
+
+        let position = find(&self.bytes[offset..], b"obj")? + offset + b"obj".len();
+
+        Some(skip_whitespace(self.bytes, position))
+    }
+
+    /// Returns the location of the most recent definition of the given object recorded in
+    /// the chain of cross-reference sections, or `None` if the object is not in use or the
+    /// cross-reference sections cannot be read.
+    fn object_location(&self, object_number: u32) -> Option<PdfRawObjectLocation> {
+        match self.xref()?.get(&object_number) {
+            Some((_, PdfRawObjectLocation::Free)) | None => None,
+            Some((_, location)) => Some(*location),
+        }
+    }
+
+    /// Returns the object number, generation number, and location of every object in use
+    /// in the document, in ascending order of object number, by walking the chain of
+    /// cross-reference sections. Where an object is listed in more than one section,
+    /// only the most recent entry is used.
+    #[cfg(feature = "encryption")]
+    pub(crate) fn object_locations(&self) -> Option<Vec<(u32, u16, PdfRawObjectLocation)>> {
+        let mut result = self
+            .xref()?
+            .iter()
+            .filter(|(_, (_, location))| *location != PdfRawObjectLocation::Free)
+            .map(|(object_number, (generation, location))| (*object_number, *generation, *location))
+            .collect::<Vec<_>>();
+
+        result.sort_by_key(|(object_number, _, _)| *object_number);
+
+        Some(result)
+    }
+
+    /// Returns the merged entries of the chain of cross-reference sections, reading them
+    /// on first use. Returns `None` if any cross-reference section cannot be read.
+    fn xref(&self) -> Option<&PdfRawXref> {
+        self.xref
+            .get_or_init(|| {
+                let mut result = PdfRawXref::new();
+
+                let mut xref_offset = Some(self.xref_offset);
+
+                // Guard against malformed files containing a cycle of /Prev entries.
+
+                let mut remaining_sections = 256;
+
+                while let Some(offset) = xref_offset {
+                    remaining_sections -= 1;
+
+                    if remaining_sections == 0 {
+                        return None;
+                    }
+
+                    let (trailer, mut entries) = self.read_xref_section(offset)?;
+
+                    // In a hybrid-reference file, entries missing from a cross-reference table
+                    // are looked up in the cross-reference stream named by its trailer before
+                    // moving on to the previous section.
+
+                    if let Some(stream_offset) = get(&trailer, "XRefStm") {
+                        entries.extend(self.read_xref_section(stream_offset.parse().ok()?)?.1);
+                    }
+
+                    for (object_number, generation, location) in entries {
+                        // Entries in more recent sections take precedence.
+
+                        result
+                            .entry(object_number)
+                            .or_insert((generation, location));
+                    }
+
+                    xref_offset = get(&trailer, "Prev").and_then(|prev| prev.parse().ok());
+                }
+
+                Some(result)
+            })
+            .as_ref()
+    }
+
+    /// Reads the cross-reference section at the given offset, which may be either a classic
+    /// cross-reference table followed by a trailer or a cross-reference stream, returning its
+    /// trailer dictionary and the object number, generation number, and location of each entry.
+    /// For a cross-reference stream, the trailer is the stream dictionary, less the entries
+    /// that describe the stream itself.
+    #[allow(clippy::type_complexity)]
+    fn read_xref_section(
+        &self,
+        offset: usize,
+    ) -> Option<(PdfRawDictionary, Vec<(u32, u16, PdfRawObjectLocation)>)> {
+        if self.bytes.get(offset..)?.starts_with(b"xref") {
+            self.read_xref_table(offset + b"xref".len())
+        } else {
+            self.read_xref_stream(offset)
+        }
+    }
+
+    /// Reads the subsections of the classic cross-reference table starting at the given
+    /// position, immediately after the `xref` keyword, and the trailer that follows them.
+    #[allow(clippy::type_complexity)]
+    fn read_xref_table(
+        &self,
+        position: usize,
+    ) -> Option<(PdfRawDictionary, Vec<(u32, u16, PdfRawObjectLocation)>)> {
+        let mut entries = Vec::new();
+
+        let mut position = skip_whitespace(self.bytes, position);
+
+        while self.bytes.get(position)?.is_ascii_digit() {
+            let (first, next) = read_unsigned(self.bytes, position)?;
+
+            let (count, next) = read_unsigned(self.bytes, skip_whitespace(self.bytes, next))?;
+
+            position = skip_whitespace(self.bytes, next);
+
+            for index in 0..count {
+                let (entry_offset, next) = read_unsigned(self.bytes, position)?;
+
+                let (generation, next) =
+                    read_unsigned(self.bytes, skip_whitespace(self.bytes, next))?;
+
+                let kind_position = skip_whitespace(self.bytes, next);
+
+                let kind = *self.bytes.get(kind_position)?;
+
+                position = skip_whitespace(self.bytes, kind_position + 1);
+
+                entries.push((
+                    (first + index) as u32,
+                    generation as u16,
+                    if kind == b'n' {
+                        PdfRawObjectLocation::Offset(entry_offset)
+                    } else {
+                        PdfRawObjectLocation::Free
+                    },
+                ));
+            }
+        }
+
+        if !self.bytes[position..].starts_with(b"trailer") {
+            return None;
+        }
+
+        let (trailer, _) = read_dictionary(
+            self.bytes,
+            skip_whitespace(self.bytes, position + b"trailer".len()),
+        )?;
+
+        Some((trailer, entries))
+    }
+
+    /// Reads the cross-reference stream object at the given offset, as described in
+    /// Section 7.5.8 of the PDF Reference Manual, version 1.7, on page 67.
+    #[allow(clippy::type_complexity)]
+    fn read_xref_stream(
+        &self,
+        offset: usize,
+    ) -> Option<(PdfRawDictionary, Vec<(u32, u16, PdfRawObjectLocation)>)> {
+        // The offset must point directly at the stream's object header.
+
+        let (_, next) = read_unsigned(self.bytes, offset)?;
+
+        let (_, next) = read_unsigned(self.bytes, skip_whitespace(self.bytes, next))?;
+
+        let next = skip_whitespace(self.bytes, next);
+
+        if !self.bytes.get(next..)?.starts_with(b"obj") {
+            return None;
+        }
+
+        let (mut dictionary, data) =
+            self.stream_at(skip_whitespace(self.bytes, next + b"obj".len()))?;
+
+        if get(&dictionary, "Type") != Some("/XRef") {
+            return None;
+        }
+
+        let widths = parse_unsigned_array(get(&dictionary, "W")?)?;
+
+        if widths.len() != 3 || widths.iter().any(|width| *width > 8) {
+            return None;
+        }
+
+        let index = match get(&dictionary, "Index") {
+            Some(index) => parse_unsigned_array(index)?,
+            None => vec![0, get(&dictionary, "Size")?.parse().ok()?],
+        };
+
+        let row_length = widths.iter().sum::<usize>();
+
+        if row_length == 0 {
+            return None;
+        }
+
+        let data = decode_stream(&dictionary, data)?;
+
+        let mut rows = data.chunks_exact(row_length);
+
+        let mut entries = Vec::new();
+
+        for subsection in index.chunks_exact(2) {
+            for object_number in subsection[0]..subsection[0] + subsection[1] {
+                let row = rows.next()?;
+
+                // Each row holds three big-endian fields of the given widths. A missing first
+                // field defaults to type 1; missing second and third fields default to zero.
+
+                let (kind, row) = read_field(row, widths[0], 1);
+
+                let (second, row) = read_field(row, widths[1], 0);
+
+                let (third, _) = read_field(row, widths[2], 0);
+
+                let (generation, location) = match kind {
+                    1 => (third as u16, PdfRawObjectLocation::Offset(second)),
+                    2 => (
+                        0,
+                        PdfRawObjectLocation::Compressed {
+                            stream: second as u32,
+                            index: third,
+                        },
+                    ),
+                    // Type 0 entries are free, and entries of any other type
+                    // are to be treated as references to the null object.
+                    _ => (third as u16, PdfRawObjectLocation::Free),
+                };
+
+                entries.push((object_number as u32, generation, location));
+            }
+        }
+
+        dictionary.retain(|(key, _)| {
+            !matches!(
+                key.as_str(),
+                "Type"
+                    | "W"
+                    | "Index"
+                    | "Length"
+                    | "Filter"
+                    | "DecodeParms"
+                    | "F"
+                    | "FFilter"
+                    | "FDecodeParms"
+                    | "DL"
+            )
+        });
+
+        Some((dictionary, entries))
+    }
+
+    /// Looks up the byte offset of the most recent definition of the given object by scanning
+    /// the file for its object header. Used when the cross-reference table cannot be read.
+    fn object_offset_from_scan(&self, object_number: u32) -> Option<usize> {
+        let header = format!("{} 0 obj", object_number);
+
+        let header = header.as_bytes();
+
+        let mut end = self.bytes.len();
+
+        while let Some(position) = rfind(&self.bytes[..end], header) {
+            if position == 0 || is_whitespace(self.bytes[position - 1]) {
+                return Some(position);
+            }
+
+            end = position + header.len() - 1;
+        }
+
+        None
+    }
+}
+
+/// A set of new or replacement objects to be appended to a saved PDF file as an
+/// incremental update, as described in Section 7.5.6 of the PDF Reference Manual,
+/// version 1.7, on page 60.
+pub(crate) struct PdfIncrementalUpdate {
+    trailer: PdfRawDictionary,
+    previous_xref_offset: usize,
+    next_object_number: u32,
+    objects: Vec<(u32, Vec<u8>)>,
+}
+
+impl PdfIncrementalUpdate {
+    /// Creates a new, empty incremental update for the given saved document.
+    pub(crate) fn new(document: &PdfRawDocument) -> Result<Self, PdfiumError> {
+        if get(document.trailer(), "Encrypt").is_some() {
+            // Strings and streams in an encrypted document must themselves be encrypted,
+            // which we cannot do without access to the document's security handler.
+
+            return Err(PdfiumError::DocumentMetadataUpdateNotSupported);
+        }
+
+        let next_object_number = get(document.trailer(), "Size")
+            .and_then(|size| size.parse().ok())
+            .ok_or(PdfiumError::DocumentMetadataUpdateNotSupported)?;
+
+        Ok(PdfIncrementalUpdate {
+            trailer: document
+                .trailer()
+                .iter()
+                .filter(|(key, _)| !matches!(key.as_str(), "Size" | "Prev" | "XRefStm"))
+                .cloned()
+                .collect(),
+            previous_xref_offset: document.xref_offset(),
+            next_object_number,
+            objects: vec![],
+        })
+    }
+
+    /// Adds a new object with the given serialized content to this update,
+    /// returning the object number assigned to it.
+    pub(crate) fn add_object(&mut self, content: Vec<u8>) -> u32 {
+        let object_number = self.reserve_object_number();
+
+        self.objects.push((object_number, content));
+
+        object_number
+    }
+
+    /// Reserves a new object number in this update for an object whose content will be
+    /// provided later using [PdfIncrementalUpdate::replace_object()]. This allows objects
+    /// that refer to one another to be added.
+    pub(crate) fn reserve_object_number(&mut self) -> u32 {
+        let object_number = self.next_object_number;
+
+        self.next_object_number += 1;
+
+        object_number
+    }
+
+    /// Replaces the existing object with the given object number, or sets the content of an
+    /// object number reserved using [PdfIncrementalUpdate::reserve_object_number()], with the
+    /// given serialized content.
+    #[inline]
+    pub(crate) fn replace_object(&mut self, object_number: u32, content: Vec<u8>) {
+        self.objects.push((object_number, content));
+    }
+
+    /// Sets the given entry in the trailer of this update, replacing any existing value.
+    #[inline]
+    pub(crate) fn set_trailer_entry(&mut self, key: &str, value: String) {
+        set(&mut self.trailer, key, value);
+    }
+
+    /// Appends this update to the given saved document bytes.
+    pub(crate) fn append_to(mut self, bytes: &mut Vec<u8>) {
+        if self.objects.is_empty() {
+            return;
+        }
+
+        if !bytes.ends_with(b"\n") {
+            bytes.push(b'\n');
+        }
+
+        self.objects
+            .sort_by_key(|(object_number, _)| *object_number);
+
+        let mut offsets = Vec::with_capacity(self.objects.len());
+
+        for (object_number, content) in self.objects.iter() {
+            offsets.push((*object_number, bytes.len()));
+
+            bytes.extend_from_slice(format!("{} 0 obj\n", object_number).as_bytes());
+            bytes.extend_from_slice(content.as_slice());
+            bytes.extend_from_slice(b"\nendobj\n");
+        }
+
+        let xref_offset = bytes.len();
+
+        bytes.extend_from_slice(b"xref\n");
+
+        // Each run of consecutive object numbers forms a single cross-reference subsection.
+
+        let mut index = 0;
+
+        while index < offsets.len() {
+            let mut end = index + 1;
+
+            while end < offsets.len() && offsets[end].0 == offsets[end - 1].0 + 1 {
+                end += 1;
+            }
+
+            bytes.extend_from_slice(format!("{} {}\n", offsets[index].0, end - index).as_bytes());
+
+            for (_, offset) in offsets[index..end].iter() {
+                bytes.extend_from_slice(format!("{:010} 00000 n\r\n", offset).as_bytes());
+            }
+
+            index = end;
+        }
+
+        set(
+            &mut self.trailer,
+            "Size",
+            self.next_object_number.to_string(),
+        );
+        set(
+            &mut self.trailer,
+            "Prev",
+            self.previous_xref_offset.to_string(),
+        );
+
+        bytes.extend_from_slice(
+            format!(
+                "trailer\n{}\nstartxref\n{}\n%%EOF\n",
+                to_dictionary_string(&self.trailer),
+                xref_offset
+            )
+            .as_bytes(),
+        );
+    }
+}
+
+/// Returns the decoded data of a stream with the given dictionary and raw data.
+///
+/// Only unfiltered streams and streams using the `FlateDecode` filter, optionally with
+/// a PNG predictor, are supported; returns `None` for any other filter or predictor.
+pub(crate) fn decode_stream(dictionary: &PdfRawDictionary, data: &[u8]) -> Option<Vec<u8>> {
+    let filter = get(dictionary, "Filter").map(|filter| {
+        filter
+            .trim_start_matches('[')
+            .trim_end_matches(']')
+            .split_ascii_whitespace()
+            .collect::<Vec<_>>()
+    });
+
+    match filter.as_deref() {
+        None | Some([]) => Some(data.to_vec()),
+        Some(["/FlateDecode"]) | Some(["/Fl"]) => {
+            let data = miniz_oxide::inflate::decompress_to_vec_zlib(data).ok()?;
+
+            match get(dictionary, "DecodeParms") {
+                None | Some("null") => Some(data),
+                Some(parameters) => remove_png_predictor(
+                    &parse_dictionary(parameters.trim_start_matches('[').trim_end_matches(']'))?,
+                    data,
+                ),
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Reverses the PNG prediction applied to the given data, as described by the given
+/// `FlateDecode` decode parameters in Section 7.4.4.4 of the PDF Reference Manual,
+/// version 1.7, on page 27. TIFF prediction is not supported.
+fn remove_png_predictor(parameters: &PdfRawDictionary, data: Vec<u8>) -> Option<Vec<u8>> {
+    let parameter = |key: &str, default: usize| match get(parameters, key) {
+        Some(value) => value.parse::<usize>().ok(),
+        None => Some(default),
+    };
+
+    match parameter("Predictor", 1)? {
+        1 => return Some(data),
+        predictor if predictor < 10 => return None,
+        _ => {}
+    }
+
+    let bits_per_pixel = parameter("Colors", 1)? * parameter("BitsPerComponent", 8)?;
+
+    let bytes_per_pixel = ((bits_per_pixel + 7) / 8).max(1);
+
+    let row_length = (bits_per_pixel * parameter("Columns", 1)? + 7) / 8;
+
+    let mut result = Vec::with_capacity(data.len());
+
+    let mut previous = vec![0u8; row_length];
+
+    // Each row is preceded by a byte giving the PNG filter type used for that row.
+
+    for row in data.chunks(row_length + 1) {
+        let (kind, row) = (row[0], &row[1..]);
+
+        let start = result.len();
+
+        for (index, byte) in row.iter().enumerate() {
+            let left = if index >= bytes_per_pixel {
+                result[start + index - bytes_per_pixel]
+            } else {
+                0
+            };
+
+            let up = previous[index];
+
+            let up_left = if index >= bytes_per_pixel {
+                previous[index - bytes_per_pixel]
+            } else {
+                0
+            };
+
+            let prediction = match kind {
+                0 => 0,
+                1 => left,
+                2 => up,
+                3 => ((left as u16 + up as u16) / 2) as u8,
+                4 => {
+                    // The Paeth predictor picks whichever neighbour is closest to
+                    // left + up - up_left.
+
+                    let estimate = left as i16 + up as i16 - up_left as i16;
+
+                    let distance_left = (estimate - left as i16).abs();
+                    let distance_up = (estimate - up as i16).abs();
+                    let distance_up_left = (estimate - up_left as i16).abs();
+
+                    if distance_left <= distance_up && distance_left <= distance_up_left {
+                        left
+                    } else if distance_up <= distance_up_left {
+                        up
+                    } else {
+                        up_left
+                    }
+                }
+                _ => return None,
+            };
+
+            result.push(byte.wrapping_add(prediction));
+        }
+
+        previous[..row.len()].copy_from_slice(&result[start..]);
+    }
+
+    Some(result)
+}
+
+/// Returns the raw value of the given key in the given dictionary, if present.
+#[inline]
+pub(crate) fn get<'a>(dictionary: &'a PdfRawDictionary, key: &str) -> Option<&'a str> {
+    dictionary
+        .iter()
+        .find(|(candidate, _)| candidate == key)
+        .map(|(_, value)| value.as_str())
+}
+
+/// Sets the raw value of the given key in the given dictionary, replacing any existing value.
+pub(crate) fn set(dictionary: &mut PdfRawDictionary, key: &str, value: String) {
+    match dictionary
+        .iter_mut()
+        .find(|(candidate, _)| candidate == key)
+    {
+        Some(entry) => entry.1 = value,
+        None => dictionary.push((key.to_owned(), value)),
+    }
+}
+
+/// Serializes the given dictionary entries into PDF dictionary syntax.
+pub(crate) fn to_dictionary_string(dictionary: &PdfRawDictionary) -> String {
+    let mut result = String::from("<<");
+
+    for (key, value) in dictionary.iter() {
+        result.push_str(format!(" /{} {}", key, value).as_str());
+    }
+
+    result.push_str(" >>");
+
+    result
+}
+
+/// Returns the object number of the given serialized indirect reference, if it is one.
+pub(crate) fn parse_reference(value: &str) -> Option<u32> {
+    let mut tokens = value.split_ascii_whitespace();
+
+    match (tokens.next(), tokens.next(), tokens.next(), tokens.next()) {
+        (Some(object_number), Some(generation), Some("R"), None) => {
+            generation.parse::<u16>().ok()?;
+
+            object_number.parse().ok()
+        }
+        _ => None,
+    }
+}
+
+/// Returns the object numbers of all indirect references in the given serialized array.
+pub(crate) fn parse_references(value: &str) -> Vec<u32> {
+    let tokens = value
+        .trim_start_matches('[')
+        .trim_end_matches(']')
+        .split_ascii_whitespace()
+        .collect::<Vec<_>>();
+
+    tokens
+        .windows(3)
+        .filter(|window| window[2] == "R")
+        .filter_map(|window| parse_reference(window.join(" ").as_str()))
+        .collect()
+}
+
+/// Returns the values in the given serialized array of unsigned integers.
+fn parse_unsigned_array(value: &str) -> Option<Vec<usize>> {
+    value
+        .trim()
+        .strip_prefix('[')?
+        .strip_suffix(']')?
+        .split_ascii_whitespace()
+        .map(|token| token.parse().ok())
+        .collect()
+}
+
+/// Reads the big-endian unsigned integer in the first `width` bytes of the given data,
+/// returning it and the remaining data. Returns the given default value if `width` is zero.
+fn read_field(data: &[u8], width: usize, default: usize) -> (usize, &[u8]) {
+    if width == 0 {
+        return (default, data);
+    }
+
+    let (field, rest) = data.split_at(width);
+
+    (
+        field
+            .iter()
+            .fold(0, |result, byte| (result << 8) | *byte as usize),
+        rest,
+    )
+}
+
+/// Encodes the given string as a PDF text string. Strings containing only printable ASCII
+/// characters are written as literal strings; all other strings are written as hexadecimal
+/// strings in UTF-16BE format with a leading byte order mark.
+pub(crate) fn encode_text_string(value: &str) -> String {
+    if value.chars().all(|c| (' '..='~').contains(&c)) {
+        let mut result = String::with_capacity(value.len() + 2);
+
+        result.push('(');
+
+        for c in value.chars() {
+            if matches!(c, '(' | ')' | '\\') {
+                result.push('\\');
+            }
+
+            result.push(c);
+        }
+
+        result.push(')');
+
+        result
+    } else {
+        let mut result = String::from("<FEFF");
+
+        for word in value.encode_utf16() {
+            result.push_str(format!("{:04X}", word).as_str());
+        }
+
+        result.push('>');
+
+        result
+    }
+}
+
+/// Decodes the given serialized PDF text string, either a literal string or a hexadecimal
+/// string. Strings beginning with a UTF-16BE or UTF-8 byte order mark are decoded accordingly;
+/// all other strings are treated as PDFDocEncoding, approximated here as Latin-1.
+pub(crate) fn decode_text_string(value: &str) -> Option<String> {
+    let bytes = match PdfRawValue::parse(value.trim().as_bytes())? {
+        PdfRawValue::String(bytes) => bytes,
+        _ => return None,
+    };
+
+    if let Some(utf16) = bytes.strip_prefix(&[0xFE, 0xFF]) {
+        let words = utf16
+            .chunks_exact(2)
+            .map(|pair| u16::from_be_bytes([pair[0], pair[1]]))
+            .collect::<Vec<_>>();
+
+        Some(String::from_utf16_lossy(&words))
+    } else if let Some(utf8) = bytes.strip_prefix(&[0xEF, 0xBB, 0xBF]) {
+        Some(String::from_utf8_lossy(utf8).into_owned())
+    } else {
+        Some(bytes.iter().map(|c| *c as char).collect())
+    }
+}
+
+/// Returns the entries of the given serialized dictionary, if it is one.
+#[inline]
+pub(crate) fn parse_dictionary(value: &str) -> Option<PdfRawDictionary> {
+    read_dictionary(value.trim().as_bytes(), 0).map(|(dictionary, _)| dictionary)
+}
+
+/// Returns the names, without their leading `/`, in the given serialized name or array of names.
+pub(crate) fn parse_names(value: &str) -> Vec<String> {
+    value
+        .trim_start_matches('[')
+        .trim_end_matches(']')
+        .split('/')
+        .map(|name| name.trim())
+        .filter(|name| !name.is_empty())
+        .map(|name| name.to_owned())
+        .collect()
+}
+
+/// A parsed PDF value. Unlike the serialized values held in a [PdfRawDictionary], strings are
+/// decoded to the bytes they represent, and nested arrays and dictionaries are parsed; all other
+/// values, including indirect references, are kept as raw tokens.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum PdfRawValue {
+    String(Vec<u8>),
+    Array(Vec<PdfRawValue>),
+    Dictionary(Vec<(Vec<u8>, PdfRawValue)>),
+    Token(Vec<u8>),
+}
+
+impl PdfRawValue {
+    /// Parses the serialized value at the start of the given bytes.
+    #[inline]
+    pub(crate) fn parse(value: &[u8]) -> Option<Self> {
+        read_value(value, 0).map(|(value, _)| value)
+    }
+
+    /// Returns the value of the given key, including its leading slash, if this value
+    /// is a dictionary containing the key.
+    #[cfg(feature = "encryption")]
+    pub(crate) fn get(&self, key: &str) -> Option<&PdfRawValue> {
+        match self {
+            PdfRawValue::Dictionary(entries) => entries
+                .iter()
+                .find(|(entry_key, _)| entry_key.as_slice() == key.as_bytes())
+                .map(|(_, value)| value),
+            _ => None,
+        }
+    }
+
+    /// Returns the value of the given key, including its leading slash, if this value
+    /// is a dictionary containing the key and the value is a name.
+    #[cfg(feature = "encryption")]
+    pub(crate) fn get_name(&self, key: &str) -> Option<&[u8]> {
+        match self.get(key) {
+            Some(PdfRawValue::Token(token)) if token.starts_with(b"/") => Some(token.as_slice()),
+            _ => None,
+        }
+    }
+
+    /// Sets the value of the given key, including its leading slash, replacing any existing
+    /// value, if this value is a dictionary.
+    #[cfg(feature = "encryption")]
+    pub(crate) fn set(&mut self, key: &str, value: PdfRawValue) {
+        if let PdfRawValue::Dictionary(entries) = self {
+            match entries
+                .iter_mut()
+                .find(|(entry_key, _)| entry_key.as_slice() == key.as_bytes())
+            {
+                Some((_, existing)) => *existing = value,
+                None => entries.push((key.as_bytes().to_vec(), value)),
+            }
+        }
+    }
+
+    /// Appends the serialized form of this value to the given buffer.
+    /// Strings are always written in hexadecimal form.
+    #[cfg(feature = "encryption")]
+    pub(crate) fn write_to(&self, buffer: &mut Vec<u8>) {
+        match self {
+            PdfRawValue::String(data) => {
+                buffer.push(b'<');
+                buffer.extend_from_slice(to_hex(data).as_bytes());
+                buffer.push(b'>');
+            }
+            PdfRawValue::Array(elements) => {
+                buffer.push(b'[');
+
+                for (index, element) in elements.iter().enumerate() {
+                    if index > 0 {
+                        buffer.push(b' ');
+                    }
+
+                    element.write_to(buffer);
+                }
+
+                buffer.push(b']');
+            }
+            PdfRawValue::Dictionary(entries) => {
+                buffer.extend_from_slice(b"<<");
+
+                for (key, value) in entries.iter() {
+                    buffer.push(b' ');
+                    buffer.extend_from_slice(key);
+                    buffer.push(b' ');
+                    value.write_to(buffer);
+                }
+
+                buffer.extend_from_slice(b" >>");
+            }
+            PdfRawValue::Token(token) => buffer.extend_from_slice(token),
+        }
+    }
+}
+
+/// Reads the value starting at the given position, returning it and the position
+/// immediately following it.
+pub(crate) fn read_value(bytes: &[u8], position: usize) -> Option<(PdfRawValue, usize)> {
+    let mut position = skip_whitespace(bytes, position);
+
+    match *bytes.get(position)? {
+        b'<' if bytes.get(position + 1) == Some(&b'<') => {
+            position += 2;
+
+            let mut entries = Vec::new();
+
+            loop {
+                position = skip_whitespace(bytes, position);
+
+                if bytes.get(position..)?.starts_with(b">>") {
+                    return Some((PdfRawValue::Dictionary(entries), position + 2));
+                }
+
+                let key = match read_value(bytes, position)? {
+                    (PdfRawValue::Token(key), end) if key.starts_with(b"/") => {
+                        position = end;
+
+                        key
+                    }
+                    _ => return None,
+                };
+
+                let (value, end) = read_value(bytes, position)?;
+
+                entries.push((key, value));
+
+                position = end;
+            }
+        }
+        b'<' => {
+            let end = position + bytes[position..].iter().position(|c| *c == b'>')?;
+
+            let digits = bytes[position + 1..end]
+                .iter()
+                .filter(|c| !is_whitespace(**c))
+                .map(|c| (*c as char).to_digit(16).map(|digit| digit as u8))
+                .collect::<Option<Vec<_>>>()?;
+
+            // An odd final digit is treated as if it were followed by a zero.
+
+            Some((
+                PdfRawValue::String(
+                    digits
+                        .chunks(2)
+                        .map(|pair| (pair[0] << 4) | pair.get(1).copied().unwrap_or(0))
+                        .collect(),
+                ),
+                end + 1,
+            ))
+        }
+        b'(' => read_literal_string(bytes, position)
+            .map(|(value, end)| (PdfRawValue::String(value), end)),
+        b'[' => {
+            position += 1;
+
+            let mut elements = Vec::new();
+
+            loop {
+                position = skip_whitespace(bytes, position);
+
+                if *bytes.get(position)? == b']' {
+                    return Some((PdfRawValue::Array(elements), position + 1));
+                }
+
+                let (element, end) = read_value(bytes, position)?;
+
+                elements.push(element);
+
+                position = end;
+            }
+        }
+        b'>' | b')' | b']' | b'{' | b'}' => None,
+        _ => {
+            let (token, end) = read_token(bytes, position);
+
+            if token.is_empty() {
+                return None;
+            }
+
+            // An unsigned integer followed by another unsigned integer and the R keyword
+            // is an indirect reference, which we keep as a single token.
+
+            if token.iter().all(u8::is_ascii_digit) {
+                let (generation, generation_end) = read_token(bytes, skip_whitespace(bytes, end));
+
+                if !generation.is_empty() && generation.iter().all(u8::is_ascii_digit) {
+                    let (keyword, keyword_end) =
+                        read_token(bytes, skip_whitespace(bytes, generation_end));
+
+                    if keyword == b"R" {
+                        return Some((
+                            PdfRawValue::Token([token, b" ", generation, b" R"].concat()),
+                            keyword_end,
+                        ));
+                    }
+                }
+            }
+
+            Some((PdfRawValue::Token(token.to_vec()), end))
+        }
+    }
+}
+
+/// Reads the name, number, or keyword token starting at the given position, returning it
+/// and the position immediately following it.
+fn read_token(bytes: &[u8], position: usize) -> (&[u8], usize) {
+    let mut end = position;
+
+    // A name begins with a slash, which is itself a delimiter.
+
+    if bytes.get(end) == Some(&b'/') {
+        end += 1;
+    }
+
+    while end < bytes.len() && !is_whitespace(bytes[end]) && !is_delimiter(bytes[end]) {
+        end += 1;
+    }
+
+    (&bytes[position..end], end)
+}
+
+/// Reads the literal string starting at the given position, returning the bytes it represents,
+/// with any escape sequences resolved, and the position immediately following it.
+fn read_literal_string(bytes: &[u8], position: usize) -> Option<(Vec<u8>, usize)> {
+    let mut result = Vec::new();
+
+    let mut depth = 0;
+
+    let mut position = position + 1;
+
+    loop {
+        let c = *bytes.get(position)?;
+
+        position += 1;
+
+        match c {
+            b'(' => {
+                depth += 1;
+                result.push(c);
+            }
+            b')' if depth == 0 => return Some((result, position)),
+            b')' => {
+                depth -= 1;
+                result.push(c);
+            }
+            b'\\' => {
+                let escaped = *bytes.get(position)?;
+
+                position += 1;
+
+                match escaped {
+                    b'n' => result.push(b'\n'),
+                    b'r' => result.push(b'\r'),
+                    b't' => result.push(b'\t'),
+                    b'b' => result.push(0x08),
+                    b'f' => result.push(0x0C),
+                    b'0'..=b'7' => {
+                        let mut value = (escaped - b'0') as u32;
+
+                        for _ in 0..2 {
+                            match bytes.get(position) {
+                                Some(digit @ b'0'..=b'7') => {
+                                    value = value * 8 + (digit - b'0') as u32;
+                                    position += 1;
+                                }
+                                _ => break,
+                            }
+                        }
+
+                        result.push(value as u8);
+                    }
+                    b'\r' => {
+                        // A backslash at the end of a line continues the string
+                        // on the next line.
+
+                        if bytes.get(position) == Some(&b'\n') {
+                            position += 1;
+                        }
+                    }
+                    b'\n' => {}
+                    _ => result.push(escaped),
+                }
+            }
+            b'\r' => {
+                // End-of-line markers within a literal string are read as a single
+                // line feed.
+
+                if bytes.get(position) == Some(&b'\n') {
+                    position += 1;
+                }
+
+                result.push(b'\n');
+            }
+            _ => result.push(c),
+        }
+    }
+}
+
+/// Reads the dictionary starting at the given position, returning its entries
+/// and the position immediately following it.
+fn read_dictionary(bytes: &[u8], position: usize) -> Option<(PdfRawDictionary, usize)> {
+    if !bytes.get(position..)?.starts_with(b"<<") {
+        return None;
+    }
+
+    let mut position = position + 2;
+
+    let mut entries = Vec::new();
+
+    loop {
+        position = skip_whitespace(bytes, position);
+
+        if bytes.get(position..)?.starts_with(b">>") {
+            return Some((entries, position + 2));
+        }
+
+        if *bytes.get(position)? != b'/' {
+            return None;
+        }
+
+        let key_end = skip_object(bytes, position)?;
+
+        let key = String::from_utf8_lossy(&bytes[position + 1..key_end]).into_owned();
+
+        let value_start = skip_whitespace(bytes, key_end);
+
+        let mut value_end = skip_object(bytes, value_start)?;
+
+        // An indirect reference is serialized as three separate tokens:
+        // an object number, a generation number, and the keyword R.
+
+        if bytes[value_start].is_ascii_digit() {
+            let generation_start = skip_whitespace(bytes, value_end);
+
+            if bytes
+                .get(generation_start)
+                .map_or(false, |c| c.is_ascii_digit())
+            {
+                let generation_end = skip_object(bytes, generation_start)?;
+
+                let keyword_start = skip_whitespace(bytes, generation_end);
+
+                if bytes.get(keyword_start) == Some(&b'R')
+                    && bytes
+                        .get(keyword_start + 1)
+                        .map_or(true, |c| is_whitespace(*c) || is_delimiter(*c))
+                {
+                    value_end = keyword_start + 1;
+                }
+            }
+        }
+
+        let value = String::from_utf8_lossy(&bytes[value_start..value_end]).into_owned();
+
+        entries.push((key, value));
+
+        position = value_end;
+    }
+}
+
+/// Reads the unsigned integer starting at the given position, returning its value
+/// and the position immediately following it.
+pub(crate) fn read_unsigned(bytes: &[u8], position: usize) -> Option<(usize, usize)> {
+    let mut end = position;
+
+    while end < bytes.len() && bytes[end].is_ascii_digit() {
+        end += 1;
+    }
+
+    let value = std::str::from_utf8(&bytes[position..end])
+        .ok()?
+        .parse()
+        .ok()?;
+
+    Some((value, end))
+}
+
+/// Returns the position immediately following the serialized object starting at the
+/// given position.
+fn skip_object(bytes: &[u8], position: usize) -> Option<usize> {
+    match *bytes.get(position)? {
+        b'<' if bytes.get(position + 1) == Some(&b'<') => {
+            let mut position = position + 2;
+
+            loop {
+                position = skip_whitespace(bytes, position);
+
+                if bytes.get(position..position + 2)? == b">>" {
+                    return Some(position + 2);
+                }
+
+                position = skip_object(bytes, position)?;
+            }
+        }
+        b'[' => {
+            let mut position = position + 1;
+
+            loop {
+                position = skip_whitespace(bytes, position);
+
+                if *bytes.get(position)? == b']' {
+                    return Some(position + 1);
+                }
+
+                position = skip_object(bytes, position)?;
+            }
+        }
+        b'<' => Some(position + bytes[position..].iter().position(|c| *c == b'>')? + 1),
+        b'(' => {
+            let mut depth = 0;
+
+            let mut position = position;
+
+            loop {
+                match *bytes.get(position)? {
+                    b'\\' => position += 1,
+                    b'(' => depth += 1,
+                    b')' => {
+                        depth -= 1;
+
+                        if depth == 0 {
+                            return Some(position + 1);
+                        }
+                    }
+                    _ => {}
+                }
+
+                position += 1;
+            }
+        }
+        _ => {
+            // A name, number, boolean, null, or keyword; all extend to the
+            // next whitespace or delimiter character.
+
+            let mut position = position + 1;
+
+            while position < bytes.len()
+                && !is_whitespace(bytes[position])
+                && !is_delimiter(bytes[position])
+            {
+                position += 1;
+            }
+
+            Some(position)
+        }
+    }
+}
+
+/// Returns the position of the first non-whitespace character at or after the
+/// given position, skipping over any comments.
+pub(crate) fn skip_whitespace(bytes: &[u8], mut position: usize) -> usize {
+    while position < bytes.len() {
+        if is_whitespace(bytes[position]) {
+            position += 1;
+        } else if bytes[position] == b'%' {
+            while position < bytes.len() && bytes[position] != b'\n' && bytes[position] != b'\r' {
+                position += 1;
+            }
+        } else {
+            break;
+        }
+    }
+
+    position
+}
+
+/// Returns the given bytes as a string of uppercase hexadecimal digits.
+#[cfg(feature = "encryption")]
+pub(crate) fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02X}", byte)).collect()
+}
+
+#[inline]
+pub(crate) fn is_whitespace(c: u8) -> bool {
+    matches!(c, b'\0' | b'\t' | b'\n' | b'\x0C' | b'\r' | b' ')
+}
+
+#[inline]
+pub(crate) fn is_delimiter(c: u8) -> bool {
+    matches!(
+        c,
+        b'(' | b')' | b'<' | b'>' | b'[' | b']' | b'{' | b'}' | b'/' | b'%'
+    )
+}
+
+/// Returns the position of the first occurrence of the given needle in the given haystack.
+#[inline]
+pub(crate) fn find(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    haystack
+        .windows(needle.len())
+        .position(|window| window == needle)
+}
+
+/// Returns the position of the last occurrence of the given needle in the given haystack.
+#[inline]
+pub(crate) fn rfind(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    haystack
+        .windows(needle.len())
+        .rposition(|window| window == needle)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DOCUMENT: &[u8] = b"%PDF-1.7\n\
+        1 0 obj\n<< /Type /Catalog /Metadata 2 0 R >>\nendobj\n\
+        2 0 obj\n<< /Type /Metadata /Subtype /XML /Length 3 0 R >>\nstream\n<x:xmpmeta/>\nendstream\nendobj\n\
+        3 0 obj\n12\nendobj\n\
+        xref\n0 4\n0000000000 65535 f\r\n0000000009 00000 n\r\n0000000061 00000 n\r\n\
+        0000000156 00000 n\r\ntrailer\n<< /Size 4 /Root 1 0 R /ID [<0A1B> <2C3D>] >>\n\
+        startxref\n174\n%%EOF\n";
+
+    #[test]
+    fn test_read_objects_via_xref() {
+        let document = PdfRawDocument::parse(DOCUMENT).unwrap();
+
+        assert_eq!(document.root_object_number(), Some(1));
+        assert_eq!(document.xref_offset(), 174);
+        assert_eq!(
+            get(&document.object_dictionary(1).unwrap(), "Metadata"),
+            Some("2 0 R")
+        );
+
+        let (dictionary, data) = document.object_stream(2).unwrap();
+
+        assert_eq!(get(&dictionary, "Subtype"), Some("/XML"));
+        assert_eq!(data, b"<x:xmpmeta/>");
+    }
+
+    #[test]
+    fn test_read_objects_via_xref_stream() -> Result<(), PdfiumError> {
+        // An incremental update written with a cross-reference stream, with the replacement
+        // catalog held in a compressed object stream. The final trailer keyword in the file
+        // belongs to the original, now stale, cross-reference table.
+
+        let mut bytes = DOCUMENT.to_vec();
+
+        let object_stream_offset = bytes.len();
+
+        let contents: &[u8] = b"1 0 << /Type /Catalog /Metadata 2 0 R /Lang (en) >>";
+
+        bytes.extend_from_slice(
+            format!(
+                "4 0 obj\n<< /Type /ObjStm /N 1 /First 4 /Length {} >>\nstream\n",
+                contents.len()
+            )
+            .as_bytes(),
+        );
+        bytes.extend_from_slice(contents);
+        bytes.extend_from_slice(b"\nendstream\nendobj\n");
+
+        let xref_offset = bytes.len();
+
+        let mut entries = Vec::new();
+
+        for (kind, second, third) in [
+            (2u8, 4, 0u16),
+            (1, object_stream_offset as u32, 0),
+            (1, xref_offset as u32, 0),
+        ] {
+            entries.push(kind);
+            entries.extend_from_slice(&second.to_be_bytes());
+            entries.extend_from_slice(&third.to_be_bytes());
+        }
+
+        bytes.extend_from_slice(
+            format!(
+                "5 0 obj\n<< /Type /XRef /Size 6 /W [1 4 2] /Index [1 1 4 2] /Root 1 0 R \
+                /Prev 174 /Length {} >>\nstream\n",
+                entries.len()
+            )
+            .as_bytes(),
+        );
+        bytes.extend_from_slice(entries.as_slice());
+        bytes.extend_from_slice(
+            format!("\nendstream\nendobj\nstartxref\n{}\n%%EOF\n", xref_offset).as_bytes(),
+        );
+
+        let document = PdfRawDocument::parse(bytes.as_slice()).unwrap();
+
+        assert_eq!(document.xref_offset(), xref_offset);
+        assert_eq!(
+            document.trailer(),
+            &vec![
+                ("Size".to_owned(), "6".to_owned()),
+                ("Root".to_owned(), "1 0 R".to_owned()),
+                ("Prev".to_owned(), "174".to_owned()),
+            ]
+        );
+        assert_eq!(
+            get(&document.object_dictionary(1).unwrap(), "Lang"),
+            Some("(en)")
+        );
+        assert_eq!(document.object_stream(2).unwrap().1, b"<x:xmpmeta/>");
+
+        // A further update written with a classic cross-reference table chains back
+        // through the cross-reference stream to the original table.
+
+        let mut update = PdfIncrementalUpdate::new(&document)?;
+
+        let info = update.add_object(b"<< /Title (Updated) >>".to_vec());
+
+        update.set_trailer_entry("Info", format!("{} 0 R", info));
+        update.append_to(&mut bytes);
+
+        let document = PdfRawDocument::parse(bytes.as_slice()).unwrap();
+
+        assert_eq!(get(document.trailer(), "Info"), Some("6 0 R"));
+        assert_eq!(
+            get(&document.object_dictionary(1).unwrap(), "Lang"),
+            Some("(en)")
+        );
+        assert_eq!(
+            get(&document.object_dictionary(6).unwrap(), "Title"),
+            Some("(Updated)")
+        );
+        assert_eq!(document.object_stream(2).unwrap().1, b"<x:xmpmeta/>");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_walk_page_tree_and_find_objects() {
+        // The cross-reference offset is deliberately wrong, so objects are located by scanning.
+
+        let bytes: &[u8] = b"%PDF-1.7\n\
+            1 0 obj\n<< /Type /Catalog /Pages 2 0 R >>\nendobj\n\
+            2 0 obj\n<< /Type /Pages /Kids [3 0 R 5 0 R] /Count 2 >>\nendobj\n\
+            3 0 obj\n<< /Type /Pages /Kids [4 0 R] /Count 1 >>\nendobj\n\
+            4 0 obj\n<< /Type /Page /Parent 3 0 R >>\nendobj\n\
+            5 0 obj\n<< /Type /Page /Parent 2 0 R /Annots [6 0 R] >>\nendobj\n\
+            6 0 obj\n<< /Subtype /Link /Dest (marker) >>\nendobj\n\
+            trailer\n<< /Size 7 /Root 1 0 R >>\nstartxref\n0\n%%EOF\n";
+
+        let document = PdfRawDocument::parse(bytes).unwrap();
+
+        assert_eq!(document.page_object_numbers(), Some(vec![4, 5]));
+        assert_eq!(document.objects_containing(b"marker"), vec![6]);
+        assert!(document.objects_containing(b"missing").is_empty());
+        assert_eq!(parse_references("[3 0 R 5 0 R]"), vec![3, 5]);
+    }
+
+    #[test]
+    fn test_append_incremental_update() -> Result<(), PdfiumError> {
+        let mut bytes = DOCUMENT.to_vec();
+
+        let mut update = PdfIncrementalUpdate::new(&PdfRawDocument::parse(DOCUMENT).unwrap())?;
+
+        let info = update.add_object(b"<< /Title (Updated) >>".to_vec());
+
+        update.set_trailer_entry("Info", format!("{} 0 R", info));
+        update.replace_object(1, b"<< /Type /Catalog >>".to_vec());
+        update.append_to(&mut bytes);
+
+        let document = PdfRawDocument::parse(bytes.as_slice()).unwrap();
+
+        assert_eq!(
+            document.trailer(),
+            &vec![
+                ("Root".to_owned(), "1 0 R".to_owned()),
+                ("ID".to_owned(), "[<0A1B> <2C3D>]".to_owned()),
+                ("Info".to_owned(), "4 0 R".to_owned()),
+                ("Size".to_owned(), "5".to_owned()),
+                ("Prev".to_owned(), "174".to_owned()),
+            ]
+        );
+        assert_eq!(
+            get(&document.object_dictionary(4).unwrap(), "Title"),
+            Some("(Updated)")
+        );
+        assert_eq!(
+            get(&document.object_dictionary(1).unwrap(), "Metadata"),
+            None
+        );
+
+        // Objects not replaced by the update are found by following the /Prev chain.
+
+        assert_eq!(document.object_stream(2).unwrap().1, b"<x:xmpmeta/>");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_decode_flate_stream() {
+        let dictionary = vec![("Filter".to_owned(), "[/FlateDecode]".to_owned())];
+
+        let data = miniz_oxide::deflate::compress_to_vec_zlib(b"<x:xmpmeta/>", 6);
+
+        assert_eq!(
+            decode_stream(&dictionary, data.as_slice()),
+            Some(b"<x:xmpmeta/>".to_vec())
+        );
+        assert_eq!(
+            decode_stream(&vec![("Filter".to_owned(), "/LZWDecode".to_owned())], b""),
+            None
+        );
+    }
+
+    #[test]
+    fn test_decode_flate_stream_with_png_predictor() {
+        // Two rows of three bytes, encoded with the Up and Sub filters respectively.
+
+        let dictionary = vec![
+            ("Filter".to_owned(), "/FlateDecode".to_owned()),
+            (
+                "DecodeParms".to_owned(),
+                "<< /Predictor 12 /Columns 3 >>".to_owned(),
+            ),
+        ];
+
+        let data = miniz_oxide::deflate::compress_to_vec_zlib(&[2, 1, 0, 200, 1, 1, 2, 196], 6);
+
+        assert_eq!(
+            decode_stream(&dictionary, data.as_slice()),
+            Some(vec![1, 0, 200, 1, 3, 199])
+        );
+    }
+
+    #[test]
+    fn test_encode_text_string() {
+        assert_eq!(encode_text_string("Plain (text)"), "(Plain \\(text\\))");
+        assert_eq!(
+            encode_text_string("Ünïcode"),
+            "<FEFF00DC006E00EF0063006F00640065>"
+        );
+    }
+
+    #[test]
+    fn test_decode_text_strings_and_names() {
+        assert_eq!(
+            decode_text_string("(Layer \\(A\\)\\101\\n)"),
+            Some("Layer (A)A\n".to_owned())
+        );
+        assert_eq!(
+            decode_text_string("<FEFF00DC006E00EF0063006F00640065>"),
+            Some("Ünïcode".to_owned())
+        );
+        assert_eq!(decode_text_string("<4C61 79>"), Some("Lay".to_owned()));
+        assert_eq!(decode_text_string("/Name"), None);
+        assert_eq!(parse_names("[/View /Design]"), vec!["View", "Design"]);
+        assert_eq!(parse_names("/View"), vec!["View"]);
+
+        let dictionary =
+            parse_dictionary("<< /OCGs [5 0 R 6 0 R] /D << /OFF [6 0 R] >> >>").unwrap();
+
+        assert_eq!(
+            parse_references(get(&dictionary, "OCGs").unwrap()),
+            vec![5, 6]
+        );
+        assert_eq!(
+            get(
+                &parse_dictionary(get(&dictionary, "D").unwrap()).unwrap(),
+                "OFF"
+            ),
+            Some("[6 0 R]")
+        );
+    }
+
+    #[test]
+    fn test_read_values() {
+        let source = b"<< /Type /Annot /Rect [0 0 10.5 20] /P 12 0 R /Contents (a\\(b\\)\\101) \
+            /Hex <48 6>  /Nested << /A true >> >> trailing";
+
+        let (value, end) = read_value(source, 0).unwrap();
+
+        assert_eq!(&source[end..], b" trailing");
+
+        match &value {
+            PdfRawValue::Dictionary(entries) => {
+                assert_eq!(entries.len(), 6);
+                assert_eq!(
+                    entries[2],
+                    (b"/P".to_vec(), PdfRawValue::Token(b"12 0 R".to_vec()))
+                );
+                assert_eq!(
+                    entries[3],
+                    (
+                        b"/Contents".to_vec(),
+                        PdfRawValue::String(b"a(b)A".to_vec())
+                    )
+                );
+                assert_eq!(
+                    entries[4],
+                    (b"/Hex".to_vec(), PdfRawValue::String(vec![0x48, 0x60]))
+                );
+            }
+            _ => panic!("expected a dictionary"),
+        }
+
+        assert_eq!(
+            PdfRawValue::parse(b"(line\r\nbreak \\\nwrapped)"),
+            Some(PdfRawValue::String(b"line\nbreak wrapped".to_vec()))
+        );
+        assert_eq!(PdfRawValue::parse(b"<4G>"), None);
+        assert_eq!(PdfRawValue::parse(b"[1 2"), None);
+    }
+
+    #[test]
+    #[cfg(feature = "encryption")]
+    fn test_write_values() {
+        let source = b"<< /Type /Annot /Rect [0 0 10.5 20] /P 12 0 R /Contents (a\\(b\\)\\101) \
+            /Hex <48 6>  /Nested << /A true >> >>";
+
+        let mut value = PdfRawValue::parse(source).unwrap();
+
+        assert_eq!(value.get_name("/Type"), Some(b"/Annot".as_slice()));
+
+        value.set("/Type", PdfRawValue::Token(b"/Widget".to_vec()));
+
+        let mut written = Vec::new();
+
+        value.write_to(&mut written);
+
+        assert_eq!(
+            String::from_utf8(written).unwrap(),
+            "<< /Type /Widget /Rect [0 0 10.5 20] /P 12 0 R /Contents <6128622941> \
+            /Hex <4860> /Nested << /A true >> >>"
+        );
+    }
+}
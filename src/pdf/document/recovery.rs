@@ -0,0 +1,675 @@
+//! Defines the [PdfLoadRecovery] enum, controlling how hard `Pdfium::load_pdf_from_bytes_with_recovery()`
+//! tries to open a damaged document, and the [PdfLoadWarning] enum, describing the repairs
+//! that were made in order to open it.
+
+use crate::pdf::document::raw::{find, is_whitespace, rfind};
+use std::collections::BTreeMap;
+use std::fmt::Write;
+
+/// Controls the strategies `Pdfium::load_pdf_from_bytes_with_recovery()` may use to open
+/// a document that Pdfium cannot load as-is.
+///
+/// Each strategy is only attempted if all the preceding strategies failed. Every strategy
+/// runs in time proportional to the length of the document data, so loading always
+/// terminates, even when given pathological input.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum PdfLoadRecovery {
+    /// Loads the document data as-is. This is equivalent to calling
+    /// `Pdfium::load_pdf_from_byte_slice()`, except that a [PdfLoadWarning] is still
+    /// reported if Pdfium had to reconstruct the document's cross reference table.
+    None,
+
+    /// Loads the document data as-is; if that fails, loads the document again through
+    /// a reader that ends the data at the last `%%EOF` marker, ignoring any trailing garbage.
+    TruncateAtEof,
+
+    /// Attempts all of the strategies of [PdfLoadRecovery::TruncateAtEof]; if those fail,
+    /// repairs the document's cross reference information in memory and loads the
+    /// repaired data. If the final `startxref` offset does not point to the document's
+    /// cross reference table, the offset is corrected; otherwise, a new cross reference
+    /// table is rebuilt by scanning the document data for indirect objects.
+    ///
+    /// Cross reference tables cannot be rebuilt for encrypted documents.
+    Full,
+}
+
+impl Default for PdfLoadRecovery {
+    #[inline]
+    fn default() -> Self {
+        PdfLoadRecovery::Full
+    }
+}
+
+/// A single repair made when opening a damaged document.
+///
+/// Retrieve the warnings for a document by calling the `PdfDocument::load_warnings()` function.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PdfLoadWarning {
+    /// Pdfium found the document's cross reference table to be invalid, and rebuilt it
+    /// internally while loading the document.
+    CrossReferenceTableReconstructedByPdfium,
+
+    /// The given number of bytes of trailing data following the last `%%EOF` marker
+    /// in the document were ignored.
+    TrailingDataRemoved { bytes: usize },
+
+    /// The byte offset given by the final `startxref` keyword in the document did not point
+    /// to the document's cross reference table, and was corrected from the declared offset
+    /// to the actual offset.
+    StartXrefOffsetCorrected { declared: u64, actual: u64 },
+
+    /// The document's cross reference table was missing or unusable, and a new table
+    /// was rebuilt from the given number of indirect objects found in the document data.
+    CrossReferenceTableRebuilt { objects: usize },
+}
+
+/// Returns the length of the given document data up to and including the last `%%EOF`
+/// marker and any single end-of-line sequence immediately following it, if the data
+/// continues beyond that point with anything other than whitespace.
+pub(crate) fn truncate_at_last_eof(bytes: &[u8]) -> Option<usize> {
+    let mut end = rfind(bytes, b"%%EOF")? + b"%%EOF".len();
+
+    match bytes.get(end..end + 2) {
+        Some(b"\r\n") => end += 2,
+        _ => {
+            if matches!(bytes.get(end), Some(b'\r' | b'\n')) {
+                end += 1;
+            }
+        }
+    }
+
+    if bytes[end..].iter().all(|byte| is_whitespace(*byte)) {
+        // There is nothing to remove.
+
+        None
+    } else {
+        Some(end)
+    }
+}
+
+/// Attempts to repair the cross reference information in the given document data,
+/// returning the repaired data and a description of each repair that was made.
+///
+/// Returns `None` if the data could not be repaired.
+pub(crate) fn repair_cross_reference(bytes: &[u8]) -> Option<(Vec<u8>, Vec<PdfLoadWarning>)> {
+    let mut warnings = Vec::new();
+
+    let body = match truncate_at_last_eof(bytes) {
+        Some(end) => {
+            warnings.push(PdfLoadWarning::TrailingDataRemoved {
+                bytes: bytes.len() - end,
+            });
+
+            &bytes[..end]
+        }
+        None => bytes,
+    };
+
+    if let Some(repaired) = correct_startxref_offset(body) {
+        warnings.push(repaired.1);
+
+        return Some((repaired.0, warnings));
+    }
+
+    let (repaired, object_count) = rebuild_cross_reference_table(body)?;
+
+    warnings.push(PdfLoadWarning::CrossReferenceTableRebuilt {
+        objects: object_count,
+    });
+
+    Some((repaired, warnings))
+}
+
+/// Corrects the offset following the final `startxref` keyword in the given document data,
+/// if it does not point to the last cross reference table in the data.
+fn correct_startxref_offset(bytes: &[u8]) -> Option<(Vec<u8>, PdfLoadWarning)> {
+    let keyword = rfind(bytes, b"startxref")?;
+
+    let digits_start = keyword
+        + b"startxref".len()
+        + bytes[keyword + b"startxref".len()..]
+            .iter()
+            .take_while(|byte| is_whitespace(**byte))
+            .count();
+
+    let digits_end = digits_start
+        + bytes[digits_start..]
+            .iter()
+            .take_while(|byte| byte.is_ascii_digit())
+            .count();
+
+    let declared = std::str::from_utf8(&bytes[digits_start..digits_end])
+        .ok()
+        .and_then(|digits| digits.parse::<u64>().ok());
+
+    if let Some(declared) = declared {
+        let declared = declared as usize;
+
+        if declared < bytes.len()
+            && (bytes[declared..].starts_with(b"xref") || is_object_definition(&bytes[declared..]))
+        {
+            // The offset points to either a cross reference table or a cross reference stream,
+            // so there is nothing to correct.
+
+            return None;
+        }
+    }
+
+    // Find the last cross reference table keyword that is not part of the startxref keyword.
+
+    let mut search_end = keyword;
+
+    let actual = loop {
+        let candidate = rfind(&bytes[..search_end], b"xref")?;
+
+        let is_preceded_by_whitespace = candidate == 0 || is_whitespace(bytes[candidate - 1]);
+
+        let is_followed_by_whitespace = bytes
+            .get(candidate + b"xref".len())
+            .map(|byte| is_whitespace(*byte))
+            .unwrap_or(false);
+
+        if is_preceded_by_whitespace && is_followed_by_whitespace {
+            break candidate as u64;
+        }
+
+        search_end = candidate;
+    };
+
+    let mut repaired = Vec::with_capacity(bytes.len() + 20);
+
+    repaired.extend_from_slice(&bytes[..digits_start]);
+
+    if digits_start == digits_end {
+        // The offset is missing entirely, so make sure it is separated from the keyword.
+
+        repaired.push(b'\n');
+    }
+
+    repaired.extend_from_slice(actual.to_string().as_bytes());
+    repaired.extend_from_slice(&bytes[digits_end..]);
+
+    if digits_start == digits_end && rfind(&bytes[keyword..], b"%%EOF").is_none() {
+        repaired.extend_from_slice(b"\n%%EOF\n");
+    }
+
+    Some((
+        repaired,
+        PdfLoadWarning::StartXrefOffsetCorrected {
+            declared: declared.unwrap_or(0),
+            actual,
+        },
+    ))
+}
+
+/// Appends a new cross reference table and trailer to the given document data, listing
+/// every indirect object found by scanning the data. If an object number is defined more
+/// than once, the last definition is used, matching the behaviour of incremental updates.
+///
+/// Returns the repaired data and the number of objects in the new table, or `None` if
+/// the data is encrypted or no document catalog could be found.
+fn rebuild_cross_reference_table(bytes: &[u8]) -> Option<(Vec<u8>, usize)> {
+    if find(bytes, b"/Encrypt").is_some() {
+        // Objects in encrypted documents cannot be rebuilt without the encryption
+        // dictionary referenced by the original trailer.
+
+        return None;
+    }
+
+    let objects = scan_objects(bytes);
+
+    let mut positions = objects
+        .values()
+        .map(|(_, offset)| *offset)
+        .collect::<Vec<_>>();
+
+    positions.sort_unstable();
+
+    // The document catalog is the object whose dictionary declares its type as /Catalog.
+    // Each object's data is assumed to end at the next object definition.
+
+    let root = objects
+        .iter()
+        .rev()
+        .find(|(_, (_, offset))| {
+            let end = positions
+                .get(positions.partition_point(|position| position <= offset))
+                .copied()
+                .unwrap_or(bytes.len());
+
+            let object = &bytes[*offset..end];
+
+            is_catalog(&object[..find(object, b"endobj").unwrap_or(object.len())])
+        })
+        .map(|(number, (generation, _))| (*number, *generation))?;
+
+    let size = objects.keys().next_back().map(|number| number + 1)?;
+
+    let mut table = String::new();
+
+    let _ = write!(table, "\nxref\n0 {}\n", size);
+
+    for number in 0..size {
+        match objects.get(&number) {
+            Some((generation, offset)) => {
+                let _ = write!(table, "{:010} {:05} n\r\n", offset, generation);
+            }
+            None if number == 0 => table.push_str("0000000000 65535 f\r\n"),
+            None => table.push_str("0000000000 00000 f\r\n"),
+        }
+    }
+
+    // The table begins with a line feed, so the xref keyword itself starts one byte later.
+
+    let xref_offset = bytes.len() + 1;
+
+    let _ = write!(
+        table,
+        "trailer\n<< /Size {} /Root {} {} R >>\nstartxref\n{}\n%%EOF\n",
+        size, root.0, root.1, xref_offset
+    );
+
+    let mut repaired = Vec::with_capacity(bytes.len() + table.len());
+
+    repaired.extend_from_slice(bytes);
+    repaired.extend_from_slice(table.as_bytes());
+
+    Some((repaired, objects.len()))
+}
+
+/// Scans the given document data for indirect object definitions of the form `N G obj`,
+/// returning the generation number and byte offset of the last definition of
+/// each object number.
+fn scan_objects(bytes: &[u8]) -> BTreeMap<u32, (u16, usize)> {
+    // Limits the number of whitespace characters skipped when scanning backwards from
+    // an obj keyword, so that pathological runs of whitespace cannot cause quadratic
+    // scanning times.
+
+    const MAX_WHITESPACE: usize = 16;
+
+    let mut objects = BTreeMap::new();
+
+    let mut position = 0;
+
+    while let Some(found) = find(&bytes[position..], b"obj") {
+        let keyword = position + found;
+
+        position = keyword + b"obj".len();
+
+        // The keyword must stand alone, so that endobj and words such as object are ignored.
+
+        if keyword == 0
+            || !is_whitespace(bytes[keyword - 1])
+            || bytes
+                .get(position)
+                .map(|byte| byte.is_ascii_alphanumeric())
+                .unwrap_or(false)
+        {
+            continue;
+        }
+
+        let mut cursor = keyword;
+
+        let mut take_back = |predicate: fn(u8) -> bool, limit: usize| {
+            let end = cursor;
+
+            while cursor > 0 && end - cursor < limit && predicate(bytes[cursor - 1]) {
+                cursor -= 1;
+            }
+
+            (cursor, end)
+        };
+
+        take_back(is_whitespace, MAX_WHITESPACE);
+        let generation = take_back(|byte| byte.is_ascii_digit(), 5);
+        take_back(is_whitespace, MAX_WHITESPACE);
+        let number = take_back(|byte| byte.is_ascii_digit(), 10);
+
+        if generation.0 == generation.1
+            || number.0 == number.1
+            || (number.0 > 0 && !is_whitespace(bytes[number.0 - 1]))
+        {
+            continue;
+        }
+
+        let number_value = std::str::from_utf8(&bytes[number.0..number.1])
+            .ok()
+            .and_then(|digits| digits.parse::<u32>().ok());
+
+        let generation_value = std::str::from_utf8(&bytes[generation.0..generation.1])
+            .ok()
+            .and_then(|digits| digits.parse::<u16>().ok());
+
+        if let (Some(number_value), Some(generation_value)) = (number_value, generation_value) {
+            if number_value > 0 {
+                objects.insert(number_value, (generation_value, number.0));
+            }
+        }
+    }
+
+    objects
+}
+
+/// Returns `true` if the given data begins with an indirect object definition
+/// of the form `N G obj`.
+fn is_object_definition(bytes: &[u8]) -> bool {
+    let mut position = 0;
+
+    for (is_digit, limit) in [(true, 10), (false, 16), (true, 5), (false, 16)] {
+        let count = bytes[position..]
+            .iter()
+            .take(limit)
+            .take_while(|byte| {
+                if is_digit {
+                    byte.is_ascii_digit()
+                } else {
+                    is_whitespace(**byte)
+                }
+            })
+            .count();
+
+        if count == 0 {
+            return false;
+        }
+
+        position += count;
+    }
+
+    bytes[position..].starts_with(b"obj")
+}
+
+/// Returns `true` if the given object data contains a dictionary declaring its type as /Catalog.
+fn is_catalog(object: &[u8]) -> bool {
+    let mut position = 0;
+
+    while let Some(found) = find(&object[position..], b"/Type") {
+        position += found + b"/Type".len();
+
+        let value_start = position
+            + object[position..]
+                .iter()
+                .take_while(|byte| is_whitespace(**byte))
+                .count();
+
+        if object[value_start..].starts_with(b"/Catalog") {
+            return true;
+        }
+    }
+
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+    use crate::utils::test::test_bind_to_pdfium;
+
+    /// Returns a minimal, valid, single page document with a classic cross reference table.
+    fn minimal_pdf() -> Vec<u8> {
+        let objects = [
+            "<< /Type /Catalog /Pages 2 0 R >>",
+            "<< /Type /Pages /Kids [3 0 R] /Count 1 >>",
+            "<< /Type /Page /Parent 2 0 R /MediaBox [0 0 200 200] >>",
+        ];
+
+        let mut result = String::from("%PDF-1.7\n");
+
+        let mut offsets = Vec::new();
+
+        for (index, object) in objects.iter().enumerate() {
+            offsets.push(result.len());
+            result.push_str(&format!("{} 0 obj\n{}\nendobj\n", index + 1, object));
+        }
+
+        let xref = result.len();
+
+        result.push_str("xref\n0 4\n0000000000 65535 f\r\n");
+
+        for offset in offsets {
+            result.push_str(&format!("{:010} 00000 n\r\n", offset));
+        }
+
+        result.push_str(&format!(
+            "trailer\n<< /Size 4 /Root 1 0 R >>\nstartxref\n{}\n%%EOF\n",
+            xref
+        ));
+
+        result.into_bytes()
+    }
+
+    /// Returns a selection of pathological inputs that must neither panic nor hang.
+    fn pathological_inputs() -> Vec<Vec<u8>> {
+        // A simple linear congruential generator produces repeatable noise.
+
+        let mut state = 0x2545f491u32;
+
+        let noise = (0..100_000)
+            .map(|_| {
+                state = state.wrapping_mul(1_103_515_245).wrapping_add(12_345);
+
+                (state >> 16) as u8
+            })
+            .collect::<Vec<_>>();
+
+        vec![
+            Vec::new(),
+            b"%%EOF".to_vec(),
+            b"startxref".to_vec(),
+            b"xref startxref 0 %%EOF garbage".to_vec(),
+            b"obj ".repeat(50_000),
+            b"1 0 obj\n".repeat(20_000),
+            b"1 0 obj << /Type /Catalog >> endobj ".repeat(5_000),
+            [b"1".repeat(50_000), b" ".repeat(50_000), b"0 obj".to_vec()].concat(),
+            [b"startxref".to_vec(), b" ".repeat(100_000)].concat(),
+            noise,
+        ]
+    }
+
+    #[test]
+    fn test_truncate_at_last_eof() {
+        let original = minimal_pdf();
+
+        assert_eq!(truncate_at_last_eof(&original), None);
+        assert_eq!(
+            truncate_at_last_eof(&[original.as_slice(), b"\r\n  \n"].concat()),
+            None
+        );
+        assert_eq!(
+            truncate_at_last_eof(&[original.as_slice(), b"garbage %%EO"].concat()),
+            Some(original.len())
+        );
+        assert_eq!(truncate_at_last_eof(b"no marker"), None);
+    }
+
+    #[test]
+    fn test_repair_corrects_startxref_offset() {
+        let original = minimal_pdf();
+
+        let xref = original.windows(5).position(|w| w == b"xref\n").unwrap();
+
+        let damaged = String::from_utf8(original.clone())
+            .unwrap()
+            .replace(&format!("startxref\n{}\n", xref), "startxref\n99999\n")
+            .into_bytes();
+
+        let (repaired, warnings) = repair_cross_reference(&damaged).unwrap();
+
+        assert_eq!(repaired, original);
+        assert_eq!(
+            warnings,
+            vec![PdfLoadWarning::StartXrefOffsetCorrected {
+                declared: 99999,
+                actual: xref as u64,
+            }]
+        );
+
+        // An undamaged document needs no correction, so its cross reference table is rebuilt.
+
+        let (_, warnings) = repair_cross_reference(&original).unwrap();
+
+        assert_eq!(
+            warnings,
+            vec![PdfLoadWarning::CrossReferenceTableRebuilt { objects: 3 }]
+        );
+    }
+
+    #[test]
+    fn test_repair_rebuilds_missing_cross_reference_table() {
+        let original = minimal_pdf();
+
+        let xref = original.windows(5).position(|w| w == b"xref\n").unwrap();
+
+        // Truncate the document immediately before its cross reference table,
+        // then append some garbage.
+
+        let damaged = [&original[..xref], b"%%EOF\ngarbage".as_slice()].concat();
+
+        let (repaired, warnings) = repair_cross_reference(&damaged).unwrap();
+
+        assert_eq!(
+            warnings,
+            vec![
+                PdfLoadWarning::TrailingDataRemoved { bytes: 7 },
+                PdfLoadWarning::CrossReferenceTableRebuilt { objects: 3 },
+            ]
+        );
+
+        let repaired = String::from_utf8(repaired).unwrap();
+
+        assert!(repaired.contains("trailer\n<< /Size 4 /Root 1 0 R >>"));
+
+        // Each entry in the rebuilt table must point to its object definition.
+
+        let table = &repaired[repaired.rfind("\nxref\n").unwrap()..];
+
+        for number in 1..=3 {
+            let offset = repaired.find(&format!("{} 0 obj", number)).unwrap();
+
+            assert!(table.contains(&format!("{:010} 00000 n", offset)));
+        }
+
+        let startxref = table.find("startxref\n").unwrap() + "startxref\n".len();
+
+        let declared = table[startxref..]
+            .lines()
+            .next()
+            .unwrap()
+            .parse::<usize>()
+            .unwrap();
+
+        assert!(repaired[declared..].starts_with("xref\n"));
+    }
+
+    #[test]
+    fn test_repair_terminates_on_pathological_input() {
+        for input in pathological_inputs() {
+            if let Some((_, warnings)) = repair_cross_reference(&input) {
+                assert!(!warnings.is_empty());
+            }
+        }
+
+        // Encrypted documents cannot be rebuilt.
+
+        let encrypted = String::from_utf8(minimal_pdf())
+            .unwrap()
+            .replace("/Size 4", "/Size 4 /Encrypt 5 0 R");
+
+        // Remove the xref keyword, so the startxref offset cannot be corrected.
+
+        let xref = encrypted.find("xref\n").unwrap();
+
+        let encrypted = encrypted.as_bytes();
+
+        assert_eq!(
+            repair_cross_reference(&[&encrypted[..xref], &encrypted[xref + 4..]].concat()),
+            None
+        );
+    }
+
+    #[test]
+    fn test_load_with_recovery_tiers() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let original = minimal_pdf();
+
+        // Tier one: an undamaged document loads without repair.
+
+        let document =
+            pdfium.load_pdf_from_bytes_with_recovery(&original, None, PdfLoadRecovery::Full)?;
+
+        assert!(!document.was_repaired());
+        assert_eq!(document.pages().len(), 1);
+
+        drop(document);
+
+        // Tier two: trailing garbage is only ever removed by the truncating strategies.
+
+        let garbage = [
+            original.as_slice(),
+            b"startxref\n1\n\0\0\0garbage".as_slice(),
+        ]
+        .concat();
+
+        for recovery in [
+            PdfLoadRecovery::None,
+            PdfLoadRecovery::TruncateAtEof,
+            PdfLoadRecovery::Full,
+        ] {
+            match pdfium.load_pdf_from_bytes_with_recovery(&garbage, None, recovery) {
+                Ok(document) => {
+                    assert_eq!(document.pages().len(), 1);
+
+                    for warning in document.load_warnings() {
+                        match warning {
+                            PdfLoadWarning::CrossReferenceTableReconstructedByPdfium => {}
+                            PdfLoadWarning::TrailingDataRemoved { bytes } => {
+                                assert_ne!(recovery, PdfLoadRecovery::None);
+                                assert_eq!(*bytes, garbage.len() - original.len());
+                            }
+                            _ => panic!("unexpected warning {:?}", warning),
+                        }
+                    }
+                }
+                Err(error) => assert_eq!(recovery, PdfLoadRecovery::None, "{:?}", error),
+            }
+        }
+
+        // Tier three: a document truncated before its trailer can only be loaded
+        // by rebuilding its cross reference table.
+
+        let xref = original.windows(5).position(|w| w == b"xref\n").unwrap();
+
+        let truncated = &original[..xref];
+
+        assert!(pdfium
+            .load_pdf_from_bytes_with_recovery(truncated, None, PdfLoadRecovery::None)
+            .is_err());
+
+        assert!(pdfium
+            .load_pdf_from_bytes_with_recovery(truncated, None, PdfLoadRecovery::TruncateAtEof)
+            .is_err());
+
+        let document =
+            pdfium.load_pdf_from_bytes_with_recovery(truncated, None, PdfLoadRecovery::Full)?;
+
+        assert!(document.was_repaired());
+        assert_eq!(
+            document.load_warnings(),
+            &[PdfLoadWarning::CrossReferenceTableRebuilt { objects: 3 }]
+        );
+        assert_eq!(document.pages().len(), 1);
+
+        drop(document);
+
+        // Pathological input must fail to load, rather than hang.
+
+        for input in pathological_inputs() {
+            assert!(pdfium
+                .load_pdf_from_bytes_with_recovery(&input, None, PdfLoadRecovery::Full)
+                .is_err());
+        }
+
+        Ok(())
+    }
+}
@@ -7,7 +7,7 @@ use crate::error::{PdfiumError, PdfiumInternalError};
 use crate::utils::mem::create_byte_buffer;
 use crate::utils::utf16le::get_string_from_pdfium_utf16le_bytes;
 use std::ffi::{c_uint, CString};
-use std::os::raw::{c_char, c_void};
+use std::os::raw::{c_char, c_int, c_void};
 
 /// The modification detection permission (MDP) applicable to a single digital signature
 /// in a `PdfDocument`.
@@ -104,6 +104,90 @@ impl<'a> PdfSignature<'a> {
         buffer
     }
 
+    /// Returns the byte ranges of the containing `PdfDocument` covered by this [PdfSignature],
+    /// as a list of (starting byte offset, length in bytes) pairs.
+    ///
+    /// The byte ranges describe exactly which bytes of the document file were digested when
+    /// the signature was created. They usually cover the entire file apart from the hole
+    /// containing the signature value itself. `pdfium-render` does not validate signatures;
+    /// to do so, digest the given byte ranges of the original file and verify the digest
+    /// against the signature data returned by the [PdfSignature::bytes()] function using
+    /// an external cryptographic library.
+    pub fn byte_range(&self) -> Vec<(u64, u64)> {
+        // Retrieving the byte range from Pdfium is a two-step operation. First, we call
+        // FPDFSignatureObj_GetByteRange() with a null buffer; this will retrieve the number
+        // of integers in the byte range. If the number is zero, then there is no byte range
+        // associated with this signature.
+
+        // If the number is non-zero, then we reserve a buffer of the given length and call
+        // FPDFSignatureObj_GetByteRange() again with a pointer to the buffer; this will write
+        // the byte range to the buffer as pairs of (offset, length) integers.
+
+        let buffer_length =
+            self.bindings
+                .FPDFSignatureObj_GetByteRange(self.handle, std::ptr::null_mut(), 0);
+
+        if buffer_length == 0 {
+            // There is no byte range given for this signature.
+
+            return Vec::new();
+        }
+
+        let mut buffer: Vec<c_int> = vec![0; buffer_length as usize];
+
+        let result = self.bindings.FPDFSignatureObj_GetByteRange(
+            self.handle,
+            buffer.as_mut_ptr(),
+            buffer_length,
+        );
+
+        assert_eq!(result, buffer_length);
+
+        buffer
+            .chunks_exact(2)
+            .map(|pair| (pair[0].max(0) as u64, pair[1].max(0) as u64))
+            .collect()
+    }
+
+    /// Returns the encoding of the signature data returned by the [PdfSignature::bytes()]
+    /// function, if any, for example `adbe.pkcs7.detached` or `ETSI.CAdES.detached`.
+    pub fn sub_filter(&self) -> Option<String> {
+        // Retrieving the sub filter from Pdfium is a two-step operation. First, we call
+        // FPDFSignatureObj_GetSubFilter() with a null buffer; this will retrieve the length of
+        // the encoding name in bytes. If the length is zero, then there is no encoding
+        // associated with this signature.
+
+        // If the length is non-zero, then we reserve a byte buffer of the given
+        // length and call FPDFSignatureObj_GetSubFilter() again with a pointer to the buffer;
+        // this will write the encoding name to the buffer as an array of 7-bit ASCII characters.
+
+        let buffer_length =
+            self.bindings
+                .FPDFSignatureObj_GetSubFilter(self.handle, std::ptr::null_mut(), 0);
+
+        if buffer_length == 0 {
+            // There is no encoding given for this signature.
+
+            return None;
+        }
+
+        let mut buffer = create_byte_buffer(buffer_length as usize);
+
+        let result = self.bindings.FPDFSignatureObj_GetSubFilter(
+            self.handle,
+            buffer.as_mut_ptr() as *mut c_char,
+            buffer_length,
+        );
+
+        assert_eq!(result, buffer_length);
+
+        if let Ok(result) = CString::from_vec_with_nul(buffer) {
+            result.into_string().ok()
+        } else {
+            None
+        }
+    }
+
     /// Returns the reason for the signing, if any, as a plain text description provided by the
     /// creator of this [PdfSignature].
     pub fn reason(&self) -> Option<String> {
@@ -196,3 +280,51 @@ impl<'a> PdfSignature<'a> {
         )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::utils::test::test_bind_to_pdfium;
+
+    #[test]
+    fn test_signature_byte_range_and_contents() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let path = "./test/signatures-test.pdf";
+
+        let file_length = std::fs::metadata(path).unwrap().len();
+
+        let document = pdfium.load_pdf_from_file(path, None)?;
+
+        assert!(!document.signatures().is_empty());
+
+        for signature in document.signatures().iter() {
+            // The signature data should be a DER-encoded SEQUENCE.
+
+            let contents = signature.bytes();
+
+            assert!(contents.len() > 2);
+            assert_eq!(contents[0], 0x30);
+
+            // The byte range should cover the entire file apart from a single hole
+            // containing the signature data.
+
+            let byte_range = signature.byte_range();
+
+            assert_eq!(byte_range.len(), 2);
+
+            let (first_offset, first_length) = byte_range[0];
+
+            let (second_offset, second_length) = byte_range[1];
+
+            assert_eq!(first_offset, 0);
+            assert!(second_offset > first_offset + first_length);
+            assert!(second_offset - (first_offset + first_length) >= contents.len() as u64 * 2);
+            assert!(second_offset + second_length <= file_length);
+
+            assert!(signature.sub_filter().is_some());
+        }
+
+        Ok(())
+    }
+}
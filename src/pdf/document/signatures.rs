@@ -74,8 +74,9 @@ impl<'a> PdfSignatures<'a> {
             .FPDF_GetSignatureObject(self.document_handle, index as c_int);
 
         if handle.is_null() {
-            Err(PdfiumError::PdfiumLibraryInternalError(
-                PdfiumInternalError::Unknown,
+            Err(self.bindings().error_in(
+                "FPDF_GetSignatureObject",
+                PdfiumError::PdfiumLibraryInternalError(PdfiumInternalError::Unknown),
             ))
         } else {
             Ok(PdfSignature::from_pdfium(handle, self.bindings()))
@@ -0,0 +1,216 @@
+//! Defines the [PdfDocumentSplitIterator] struct, an iterator that lazily creates a new
+//! `PdfDocument` for each of a sequence of page ranges in a source `PdfDocument`.
+
+use crate::error::{PdfiumError, PdfiumInternalError};
+use crate::pdf::document::pages::{PdfPageIndex, PdfPages};
+use crate::pdf::document::{PdfDocument, PdfDocumentVersion};
+use std::ops::Range;
+use std::vec::IntoIter;
+
+/// An iterator over new [PdfDocument] objects, each containing a copy of one range of pages
+/// from a source [PdfDocument].
+///
+/// Each new document is only created when the iterator is advanced, so the pages of a large
+/// document can be split into many separate documents without holding all of them in memory
+/// at once, provided each document is dropped before the iterator is advanced again.
+pub struct PdfDocumentSplitIterator<'a, 'b> {
+    source: &'b PdfDocument<'a>,
+    ranges: IntoIter<Range<PdfPageIndex>>,
+}
+
+impl<'a, 'b> PdfDocumentSplitIterator<'a, 'b> {
+    /// Creates a new [PdfDocumentSplitIterator] over the given ranges of pages in the given
+    /// source [PdfDocument], checking that every range is non-empty, lies within
+    /// the source document, and does not overlap any other range.
+    pub(crate) fn new(
+        source: &'b PdfDocument<'a>,
+        ranges: impl IntoIterator<Item = Range<PdfPageIndex>>,
+    ) -> Result<Self, PdfiumError> {
+        let ranges = ranges.into_iter().collect::<Vec<_>>();
+
+        let page_count = source.pages().len();
+
+        for range in ranges.iter() {
+            if range.is_empty() {
+                return Err(PdfiumError::InvalidPageRange(format!(
+                    "the range {}..{} contains no pages",
+                    range.start, range.end
+                )));
+            }
+
+            if range.end > page_count {
+                return Err(PdfiumError::PageIndexOutOfBounds);
+            }
+        }
+
+        let mut sorted = ranges.iter().collect::<Vec<_>>();
+
+        sorted.sort_by_key(|range| range.start);
+
+        for pair in sorted.windows(2) {
+            if pair[1].start < pair[0].end {
+                return Err(PdfiumError::InvalidPageRange(format!(
+                    "the ranges {}..{} and {}..{} overlap",
+                    pair[0].start, pair[0].end, pair[1].start, pair[1].end
+                )));
+            }
+        }
+
+        Ok(Self::new_unchecked(source, ranges))
+    }
+
+    #[inline]
+    pub(crate) fn new_unchecked(
+        source: &'b PdfDocument<'a>,
+        ranges: Vec<Range<PdfPageIndex>>,
+    ) -> Self {
+        PdfDocumentSplitIterator {
+            source,
+            ranges: ranges.into_iter(),
+        }
+    }
+
+    /// Creates a new [PdfDocument] containing a copy of the given range of pages
+    /// in the source document.
+    fn create_document(&self, range: Range<PdfPageIndex>) -> Result<PdfDocument<'a>, PdfiumError> {
+        let bindings = self.source.bindings();
+
+        let handle = bindings.FPDF_CreateNewDocument();
+
+        if handle.is_null() {
+            return Err(bindings.error_in(
+                "FPDF_CreateNewDocument",
+                PdfiumError::PdfiumLibraryInternalError(PdfiumInternalError::Unknown),
+            ));
+        }
+
+        let mut document = PdfDocument::from_pdfium(handle, bindings);
+
+        document.set_version(PdfDocumentVersion::DEFAULT_VERSION);
+
+        PdfPages::copy_page_indices_between_documents(
+            self.source.handle(),
+            &range.collect::<Vec<_>>(),
+            handle,
+            0,
+            bindings,
+        )?;
+
+        Ok(document)
+    }
+}
+
+impl<'a, 'b> Iterator for PdfDocumentSplitIterator<'a, 'b> {
+    type Item = Result<PdfDocument<'a>, PdfiumError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let range = self.ranges.next()?;
+
+        Some(self.create_document(range))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.ranges.size_hint()
+    }
+}
+
+impl<'a, 'b> ExactSizeIterator for PdfDocumentSplitIterator<'a, 'b> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::utils::test::test_bind_to_pdfium;
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    // A shim that tracks how many split documents are alive at any one time.
+
+    struct TrackedDocument<'a> {
+        document: PdfDocument<'a>,
+        alive: Rc<Cell<usize>>,
+    }
+
+    impl<'a> TrackedDocument<'a> {
+        fn new(document: PdfDocument<'a>, alive: &Rc<Cell<usize>>) -> Self {
+            alive.set(alive.get() + 1);
+
+            TrackedDocument {
+                document,
+                alive: alive.clone(),
+            }
+        }
+    }
+
+    impl<'a> Drop for TrackedDocument<'a> {
+        fn drop(&mut self) {
+            self.alive.set(self.alive.get() - 1);
+        }
+    }
+
+    #[test]
+    fn test_split_into_single_pages_creates_documents_lazily() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut source = pdfium.create_new_pdf()?;
+
+        for _ in 0..50 {
+            source
+                .pages_mut()
+                .create_page_at_end(PdfPagePaperSize::a4())?;
+        }
+
+        let alive = Rc::new(Cell::new(0));
+
+        let mut count = 0;
+
+        for document in source.split_into_single_pages() {
+            let tracked = TrackedDocument::new(document?, &alive);
+
+            assert_eq!(alive.get(), 1);
+            assert_eq!(tracked.document.pages().len(), 1);
+
+            count += 1;
+        }
+
+        assert_eq!(count, 50);
+        assert_eq!(alive.get(), 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_split_validates_ranges_eagerly() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut source = pdfium.create_new_pdf()?;
+
+        for _ in 0..10 {
+            source
+                .pages_mut()
+                .create_page_at_end(PdfPagePaperSize::a4())?;
+        }
+
+        let page_counts = source
+            .split([0..3, 3..4, 6..10])?
+            .map(|document| document.map(|document| document.pages().len()))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        assert_eq!(page_counts, vec![3, 1, 4]);
+
+        assert!(matches!(
+            source.split([0..5, 4..6]),
+            Err(PdfiumError::InvalidPageRange(_))
+        ));
+        assert!(matches!(
+            source.split(std::iter::once(2..2)),
+            Err(PdfiumError::InvalidPageRange(_))
+        ));
+        assert!(matches!(
+            source.split(std::iter::once(8..11)),
+            Err(PdfiumError::PageIndexOutOfBounds)
+        ));
+
+        Ok(())
+    }
+}
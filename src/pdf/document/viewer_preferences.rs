@@ -0,0 +1,236 @@
+//! Defines the [PdfViewerPreferences] struct, exposing the viewer preferences set
+//! for a single `PdfDocument`.
+
+use crate::bindgen::{FPDF_DOCUMENT, FPDF_DUPLEXTYPE};
+use crate::bindings::PdfiumLibraryBindings;
+use crate::pdf::document::pages::PdfPageIndex;
+use crate::utils::mem::create_byte_buffer;
+use std::ffi::CString;
+use std::ops::Range;
+use std::os::raw::c_char;
+
+#[cfg(doc)]
+use crate::pdf::document::PdfDocument;
+
+/// The paper handling option that should be used when printing a [PdfDocument]
+/// from the print dialog, as specified by the `Duplex` viewer preference.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum PdfDuplexMode {
+    /// No paper handling option is specified. The print dialog should use its own default.
+    Undefined,
+
+    /// Print single-sided.
+    Simplex,
+
+    /// Print double-sided, flipping on the short edge of the sheet.
+    DuplexFlipShortEdge,
+
+    /// Print double-sided, flipping on the long edge of the sheet.
+    DuplexFlipLongEdge,
+}
+
+impl PdfDuplexMode {
+    #[inline]
+    pub(crate) fn from_pdfium(value: FPDF_DUPLEXTYPE) -> Self {
+        match value {
+            1 => PdfDuplexMode::Simplex,
+            2 => PdfDuplexMode::DuplexFlipShortEdge,
+            3 => PdfDuplexMode::DuplexFlipLongEdge,
+            _ => PdfDuplexMode::Undefined,
+        }
+    }
+}
+
+/// The viewer preferences for a single [PdfDocument], controlling how the document
+/// should be presented on screen and printed, as described in Section 8.1 of
+/// The PDF Reference, Sixth Edition, on page 577.
+///
+/// Viewer preferences are hints; a document need not specify any of them. Each function
+/// returns the default value given in The PDF Reference if the corresponding preference
+/// is not set.
+pub struct PdfViewerPreferences<'a> {
+    document_handle: FPDF_DOCUMENT,
+    bindings: &'a dyn PdfiumLibraryBindings,
+}
+
+impl<'a> PdfViewerPreferences<'a> {
+    #[inline]
+    pub(crate) fn from_pdfium(
+        document_handle: FPDF_DOCUMENT,
+        bindings: &'a dyn PdfiumLibraryBindings,
+    ) -> Self {
+        Self {
+            document_handle,
+            bindings,
+        }
+    }
+
+    /// Returns the [PdfiumLibraryBindings] used by this [PdfViewerPreferences] object.
+    #[inline]
+    pub fn bindings(&self) -> &'a dyn PdfiumLibraryBindings {
+        self.bindings
+    }
+
+    /// Returns `true` if the print dialog should scale pages to fit the printable area
+    /// when printing this [PdfDocument]. Returns `false` if the document specifies that
+    /// no page scaling should be applied.
+    ///
+    /// Defaults to `true` if the `PrintScaling` preference is not set.
+    #[inline]
+    pub fn print_scaling(&self) -> bool {
+        self.bindings.is_true(
+            self.bindings
+                .FPDF_VIEWERREF_GetPrintScaling(self.document_handle),
+        )
+    }
+
+    /// Returns the number of copies that should be printed when the print dialog
+    /// is opened for this [PdfDocument].
+    ///
+    /// Defaults to 1 if the `NumCopies` preference is not set.
+    #[inline]
+    pub fn num_copies(&self) -> u32 {
+        self.bindings
+            .FPDF_VIEWERREF_GetNumCopies(self.document_handle)
+            .max(1) as u32
+    }
+
+    /// Returns the paper handling option that should be used when printing this [PdfDocument].
+    ///
+    /// Defaults to [PdfDuplexMode::Undefined] if the `Duplex` preference is not set.
+    #[inline]
+    pub fn duplex(&self) -> PdfDuplexMode {
+        PdfDuplexMode::from_pdfium(self.bindings.FPDF_VIEWERREF_GetDuplex(self.document_handle))
+    }
+
+    /// Returns the ranges of pages that should be selected when the print dialog is opened
+    /// for this [PdfDocument].
+    ///
+    /// The `PrintPageRange` preference uses 1-based page numbers, with the last page
+    /// in each sub-range included; the returned ranges are converted to zero-based
+    /// [PdfPageIndex] values, with the end of each range excluded, so they can be
+    /// used directly with the document's `PdfPages` collection.
+    ///
+    /// Defaults to an empty list, indicating that no page range is specified,
+    /// if the `PrintPageRange` preference is not set.
+    pub fn print_page_ranges(&self) -> Vec<Range<PdfPageIndex>> {
+        let page_range = self
+            .bindings
+            .FPDF_VIEWERREF_GetPrintPageRange(self.document_handle);
+
+        if page_range.is_null() {
+            return Vec::new();
+        }
+
+        let count = self
+            .bindings
+            .FPDF_VIEWERREF_GetPrintPageRangeCount(page_range);
+
+        let elements = (0..count)
+            .map(|index| {
+                self.bindings
+                    .FPDF_VIEWERREF_GetPrintPageRangeElement(page_range, index)
+            })
+            .collect::<Vec<_>>();
+
+        // The page range is an array of (first page, last page) pairs. Malformed pairs
+        // are ignored.
+
+        elements
+            .chunks_exact(2)
+            .filter(|pair| pair[0] >= 1 && pair[1] >= pair[0])
+            .map(|pair| {
+                (pair[0] - 1).min(PdfPageIndex::MAX as i32) as PdfPageIndex
+                    ..pair[1].min(PdfPageIndex::MAX as i32) as PdfPageIndex
+            })
+            .collect()
+    }
+
+    /// Returns the value of the viewer preference with the given key, if that preference
+    /// is set and its value is a name. This can be used to retrieve preferences not otherwise
+    /// exposed by this [PdfViewerPreferences] object, such as `Direction`, `NonFullScreenPageMode`,
+    /// `ViewArea`, `ViewClip`, `PrintArea`, or `PrintClip`.
+    ///
+    /// Returns `None` if the preference is not set, or if its value is not a name.
+    pub fn name(&self, key: &str) -> Option<String> {
+        // Retrieving the value from Pdfium is a two-step operation. First, we call
+        // FPDF_VIEWERREF_GetName() with a null buffer; this will retrieve the length of
+        // the value in bytes, including a trailing null. If the length is zero, then
+        // the preference is not set.
+
+        // If the length is non-zero, then we reserve a byte buffer of the given
+        // length and call FPDF_VIEWERREF_GetName() again with a pointer to the buffer;
+        // this will write the value to the buffer as a null-terminated UTF-8 string.
+
+        let buffer_length = self.bindings.FPDF_VIEWERREF_GetName(
+            self.document_handle,
+            key,
+            std::ptr::null_mut(),
+            0,
+        );
+
+        if buffer_length == 0 {
+            // The preference is not set.
+
+            return None;
+        }
+
+        let mut buffer = create_byte_buffer(buffer_length as usize);
+
+        let result = self.bindings.FPDF_VIEWERREF_GetName(
+            self.document_handle,
+            key,
+            buffer.as_mut_ptr() as *mut c_char,
+            buffer_length,
+        );
+
+        assert_eq!(result, buffer_length);
+
+        if let Ok(result) = CString::from_vec_with_nul(buffer) {
+            result.into_string().ok()
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::utils::test::test_bind_to_pdfium;
+
+    #[test]
+    fn test_viewer_preferences() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let document = pdfium.load_pdf_from_file("./test/viewer-preferences-test.pdf", None)?;
+
+        let preferences = document.viewer_preferences();
+
+        assert!(!preferences.print_scaling());
+        assert_eq!(preferences.num_copies(), 2);
+        assert_eq!(preferences.duplex(), PdfDuplexMode::DuplexFlipLongEdge);
+        assert_eq!(preferences.print_page_ranges(), vec![0..1, 2..3]);
+        assert_eq!(preferences.name("Direction").as_deref(), Some("R2L"));
+        assert_eq!(preferences.name("PrintArea"), None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_viewer_preferences_defaults() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let document = pdfium.create_new_pdf()?;
+
+        let preferences = document.viewer_preferences();
+
+        assert!(preferences.print_scaling());
+        assert_eq!(preferences.num_copies(), 1);
+        assert_eq!(preferences.duplex(), PdfDuplexMode::Undefined);
+        assert!(preferences.print_page_ranges().is_empty());
+        assert_eq!(preferences.name("Direction"), None);
+
+        Ok(())
+    }
+}
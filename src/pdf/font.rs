@@ -9,10 +9,12 @@ use crate::bindings::PdfiumLibraryBindings;
 use crate::error::{PdfiumError, PdfiumInternalError};
 use crate::pdf::document::fonts::PdfFontBuiltin;
 use crate::pdf::document::PdfDocument;
-use crate::pdf::font::glyphs::PdfFontGlyphs;
+use crate::pdf::font::glyph::PdfFontGlyph;
+use crate::pdf::font::glyphs::{PdfFontGlyphIndex, PdfFontGlyphs};
 use crate::pdf::points::PdfPoints;
 use crate::utils::mem::create_byte_buffer;
 use bitflags::bitflags;
+use std::convert::TryFrom;
 use std::io::Read;
 use std::os::raw::{c_char, c_int, c_uint};
 
@@ -42,6 +44,7 @@ use web_sys::{window, Blob, Response};
 struct Blob;
 
 bitflags! {
+    #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
     pub(crate) struct FpdfFontDescriptorFlags: u32 {
         const FIXED_PITCH_BIT_1 =  0b00000000000000000000000000000001;
         const SERIF_BIT_2 =        0b00000000000000000000000000000010;
@@ -90,6 +93,110 @@ impl PdfFontWeight {
     }
 }
 
+/// The font descriptor flags of a font, as defined in Section 9.8.2 of the
+/// PDF Reference Manual, version 1.7, on page 459.
+///
+/// Pdfium may not reliably return the correct values of these flags for built-in fonts.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct PdfFontDescriptorFlags {
+    flags: FpdfFontDescriptorFlags,
+}
+
+impl PdfFontDescriptorFlags {
+    #[inline]
+    pub(crate) fn from_pdfium(flags: c_int) -> Self {
+        PdfFontDescriptorFlags {
+            flags: FpdfFontDescriptorFlags::from_bits_truncate(flags as u32),
+        }
+    }
+
+    /// Returns the raw bit values of these [PdfFontDescriptorFlags].
+    #[inline]
+    pub fn bits(&self) -> u32 {
+        self.flags.bits()
+    }
+
+    /// Returns `true` if all the glyphs in the font have the same width.
+    #[inline]
+    pub fn is_fixed_pitch(&self) -> bool {
+        self.flags
+            .contains(FpdfFontDescriptorFlags::FIXED_PITCH_BIT_1)
+    }
+
+    /// Returns `true` if all the glyphs in the font have the same width.
+    /// This is an alias for [PdfFontDescriptorFlags::is_fixed_pitch].
+    #[inline]
+    pub fn is_monospace(&self) -> bool {
+        self.is_fixed_pitch()
+    }
+
+    /// Returns `true` if the glyphs in the font have variable widths.
+    #[inline]
+    pub fn is_proportional_pitch(&self) -> bool {
+        !self.is_fixed_pitch()
+    }
+
+    /// Returns `true` if one or more glyphs in the font have serifs.
+    #[inline]
+    pub fn is_serif(&self) -> bool {
+        self.flags.contains(FpdfFontDescriptorFlags::SERIF_BIT_2)
+    }
+
+    /// Returns `true` if no glyphs in the font have serifs.
+    #[inline]
+    pub fn is_sans_serif(&self) -> bool {
+        !self.is_serif()
+    }
+
+    /// Returns `true` if the font contains glyphs outside the Adobe standard Latin character set.
+    #[inline]
+    pub fn is_symbolic(&self) -> bool {
+        self.flags.contains(FpdfFontDescriptorFlags::SYMBOLIC_BIT_3)
+    }
+
+    /// Returns `true` if the font does not contain glyphs outside the Adobe standard
+    /// Latin character set.
+    #[inline]
+    pub fn is_non_symbolic(&self) -> bool {
+        self.flags
+            .contains(FpdfFontDescriptorFlags::NON_SYMBOLIC_BIT_6)
+    }
+
+    /// Returns `true` if the glyphs in the font are designed to resemble cursive handwriting.
+    #[inline]
+    pub fn is_cursive(&self) -> bool {
+        self.flags.contains(FpdfFontDescriptorFlags::SCRIPT_BIT_4)
+    }
+
+    /// Returns `true` if the glyphs in the font include dominant vertical strokes that are slanted.
+    #[inline]
+    pub fn is_italic(&self) -> bool {
+        self.flags.contains(FpdfFontDescriptorFlags::ITALIC_BIT_7)
+    }
+
+    /// Returns `true` if the font contains no lowercase letters by design.
+    #[inline]
+    pub fn is_all_caps(&self) -> bool {
+        self.flags.contains(FpdfFontDescriptorFlags::ALL_CAP_BIT_17)
+    }
+
+    /// Returns `true` if the lowercase letters in the font have the same shapes as the
+    /// corresponding uppercase letters, sized proportionally.
+    #[inline]
+    pub fn is_small_caps(&self) -> bool {
+        self.flags
+            .contains(FpdfFontDescriptorFlags::SMALL_CAP_BIT_18)
+    }
+
+    /// Returns `true` if bold glyphs in the font are painted with extra pixels at very
+    /// small font sizes.
+    #[inline]
+    pub fn is_bold_reenforced(&self) -> bool {
+        self.flags
+            .contains(FpdfFontDescriptorFlags::FORCE_BOLD_BIT_19)
+    }
+}
+
 /// A single font used to render text in a [PdfDocument].
 ///
 /// The PDF specification defines 14 built-in fonts that can be used in any PDF file without
@@ -733,8 +840,9 @@ impl<'a> PdfFont<'a> {
         );
 
         if handle.is_null() {
-            Err(PdfiumError::PdfiumLibraryInternalError(
-                PdfiumInternalError::Unknown,
+            Err(document.bindings().error_in(
+                "FPDFText_LoadFont",
+                PdfiumError::PdfiumLibraryInternalError(PdfiumInternalError::Unknown),
             ))
         } else {
             Ok(PdfFont::from_pdfium(
@@ -768,45 +876,72 @@ impl<'a> PdfFont<'a> {
         self.family()
     }
 
-    // TODO: AJRC - 4-Aug-2024 - FPDFFont_GetBaseFontName() is in Pdfium export headers
-    // but changes not yet released. Tracking issue: https://github.com/ajrcarey/pdfium-render/issues/152
-    /// Returns the name of this [PdfFont].
-    // pub fn name(&self) -> String {
-    //     // Retrieving the font name from Pdfium is a two-step operation. First, we call
-    //     // FPDFFont_GetBaseFontName() with a null buffer; this will retrieve the length of
-    //     // the font name in bytes. If the length is zero, then there is no font name.
+    /// Returns the base name of this [PdfFont], typically the font's PostScript name.
+    ///
+    /// The base names of fonts that have been subsetted during embedding begin with a tag
+    /// of six uppercase letters followed by a plus sign, for example "EOODIA+Poetica".
+    /// Use the [PdfFont::is_subset()] function to check for this tag.
+    #[cfg(any(
+        feature = "pdfium_future",
+        feature = "pdfium_6996",
+        feature = "pdfium_6721",
+        feature = "pdfium_6666"
+    ))]
+    pub fn base_name(&self) -> String {
+        // Retrieving the base name from Pdfium is a two-step operation. First, we call
+        // FPDFFont_GetBaseFontName() with a null buffer; this will retrieve the length of
+        // the base name in bytes. If the length is zero, then there is no base name.
 
-    //     // If the length is non-zero, then we reserve a byte buffer of the given
-    //     // length and call FPDFFont_GetBaseFontName() again with a pointer to the buffer;
-    //     // this will write the font name into the buffer. Unlike most text handling in
-    //     // Pdfium, font names are returned in UTF-8 format.
+        // If the length is non-zero, then we reserve a byte buffer of the given
+        // length and call FPDFFont_GetBaseFontName() again with a pointer to the buffer;
+        // this will write the base name into the buffer. Unlike most text handling in
+        // Pdfium, font names are returned in UTF-8 format.
 
-    //     let buffer_length =
-    //         self.bindings
-    //             .FPDFFont_GetBaseFontName(self.handle, std::ptr::null_mut(), 0);
+        let buffer_length =
+            self.bindings
+                .FPDFFont_GetBaseFontName(self.handle, std::ptr::null_mut(), 0);
 
-    //     if buffer_length == 0 {
-    //         // The font name is not present.
+        if buffer_length == 0 {
+            // The base name is not present.
 
-    //         return String::new();
-    //     }
+            return String::new();
+        }
 
-    //     let mut buffer = create_byte_buffer(buffer_length as usize);
+        let mut buffer = create_byte_buffer(buffer_length as usize);
+
+        let result = self.bindings.FPDFFont_GetBaseFontName(
+            self.handle,
+            buffer.as_mut_ptr() as *mut c_char,
+            buffer_length,
+        );
 
-    //     let result = self.bindings.FPDFFont_GetBaseFontName(
-    //         self.handle,
-    //         buffer.as_mut_ptr() as *mut c_char,
-    //         buffer_length,
-    //     );
+        assert_eq!(result, buffer_length);
 
-    //     assert_eq!(result, buffer_length);
+        String::from_utf8(buffer)
+            // Trim any trailing nulls. All strings returned from Pdfium are generally terminated
+            // by one null byte.
+            .map(|str| str.trim_end_matches(char::from(0)).to_owned())
+            .unwrap_or_else(|_| String::new())
+    }
 
-    //     String::from_utf8(buffer)
-    //         // Trim any trailing nulls. All strings returned from Pdfium are generally terminated
-    //         // by one null byte.
-    //         .map(|str| str.trim_end_matches(char::from(0)).to_owned())
-    //         .unwrap_or_else(|_| String::new())
-    // }
+    /// Returns `true` if this [PdfFont] is embedded in the containing [PdfDocument] as a subset
+    /// of the full font, containing only the glyphs used in the document, as indicated by
+    /// a subset tag at the start of the font's base name.
+    #[cfg(any(
+        feature = "pdfium_future",
+        feature = "pdfium_6996",
+        feature = "pdfium_6721",
+        feature = "pdfium_6666"
+    ))]
+    pub fn is_subset(&self) -> bool {
+        let name = self.base_name();
+
+        let bytes = name.as_bytes();
+
+        bytes.len() > 7
+            && bytes[6] == b'+'
+            && bytes[..6].iter().all(|byte| byte.is_ascii_uppercase())
+    }
 
     /// Returns the family of this [PdfFont].
     pub fn family(&self) -> String {
@@ -906,9 +1041,12 @@ impl<'a> PdfFont<'a> {
     ///
     /// Pdfium may not reliably return the correct value of this property for built-in fonts.
     pub fn weight(&self) -> Result<PdfFontWeight, PdfiumError> {
-        PdfFontWeight::from_pdfium(self.bindings.FPDFFont_GetWeight(self.handle)).ok_or(
-            PdfiumError::PdfiumLibraryInternalError(PdfiumInternalError::Unknown),
-        )
+        PdfFontWeight::from_pdfium(self.bindings.FPDFFont_GetWeight(self.handle)).ok_or_else(|| {
+            self.bindings.error_in(
+                "FPDFFont_GetWeight",
+                PdfiumError::PdfiumLibraryInternalError(PdfiumInternalError::Unknown),
+            )
+        })
     }
 
     /// Returns the italic angle of this [PdfFont]. The italic angle is the angle,
@@ -926,8 +1064,9 @@ impl<'a> PdfFont<'a> {
         ) {
             Ok(angle)
         } else {
-            Err(PdfiumError::PdfiumLibraryInternalError(
-                PdfiumInternalError::Unknown,
+            Err(self.bindings.error_in(
+                "FPDFFont_GetItalicAngle",
+                PdfiumError::PdfiumLibraryInternalError(PdfiumInternalError::Unknown),
             ))
         }
     }
@@ -945,8 +1084,9 @@ impl<'a> PdfFont<'a> {
         )) {
             Ok(PdfPoints::new(ascent))
         } else {
-            Err(PdfiumError::PdfiumLibraryInternalError(
-                PdfiumInternalError::Unknown,
+            Err(self.bindings.error_in(
+                "FPDFFont_GetAscent",
+                PdfiumError::PdfiumLibraryInternalError(PdfiumInternalError::Unknown),
             ))
         }
     }
@@ -964,12 +1104,57 @@ impl<'a> PdfFont<'a> {
         )) {
             Ok(PdfPoints::new(descent))
         } else {
-            Err(PdfiumError::PdfiumLibraryInternalError(
-                PdfiumInternalError::Unknown,
+            Err(self.bindings.error_in(
+                "FPDFFont_GetDescent",
+                PdfiumError::PdfiumLibraryInternalError(PdfiumInternalError::Unknown),
             ))
         }
     }
 
+    /// Returns the line height of this [PdfFont] for the given font size, calculated as the
+    /// distance between the font's ascent and descent. Text laid out in consecutive lines
+    /// separated by this distance will not overlap.
+    pub fn line_height(&self, font_size: PdfPoints) -> Result<PdfPoints, PdfiumError> {
+        Ok(self.ascent(font_size)? - self.descent(font_size)?)
+    }
+
+    /// Returns the [PdfFontGlyph] this [PdfFont] uses to draw the given character, if any.
+    ///
+    /// Pdfium maps the given character to a glyph using the font's own character encoding.
+    /// `None` will be returned if Pdfium cannot retrieve a glyph width for the character,
+    /// or if the character lies outside the Unicode Basic Multilingual Plane.
+    pub fn glyph_for_char(&self, c: char) -> Option<PdfFontGlyph<'a>> {
+        let index = PdfFontGlyphIndex::try_from(c as u32).ok()?;
+
+        let mut width = 0.0;
+
+        if self.bindings.is_true(self.bindings.FPDFFont_GetGlyphWidth(
+            self.handle,
+            index as c_uint,
+            1.0,
+            &mut width,
+        )) {
+            Some(PdfFontGlyph::from_pdfium(self.handle, index, self.bindings))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the width of the given text when drawn in this [PdfFont] at the given font size,
+    /// calculated as the sum of the widths of the glyphs for each character in the text.
+    /// Characters with no glyph in this font are ignored.
+    ///
+    /// This allows text to be measured without first placing it onto a page. The returned width
+    /// does not take into account any kerning, or any character or word spacing that may be
+    /// applied to a text object.
+    pub fn text_width_at_font_size(&self, text: &str, font_size: PdfPoints) -> PdfPoints {
+        text.chars()
+            .filter_map(|c| self.glyph_for_char(c))
+            .fold(PdfPoints::ZERO, |width, glyph| {
+                width + glyph.width_at_font_size(font_size)
+            })
+    }
+
     /// Returns the raw font descriptor bitflags for the containing [PdfFont].
     #[inline]
     fn get_flags_bits(&self) -> FpdfFontDescriptorFlags {
@@ -1117,8 +1302,9 @@ impl<'a> PdfFont<'a> {
         match result {
             1 => Ok(true),
             0 => Ok(false),
-            _ => Err(PdfiumError::PdfiumLibraryInternalError(
-                PdfiumInternalError::Unknown,
+            _ => Err(self.bindings.error_in(
+                "FPDFFont_GetIsEmbedded",
+                PdfiumError::PdfiumLibraryInternalError(PdfiumInternalError::Unknown),
             )),
         }
     }
@@ -1166,8 +1352,9 @@ impl<'a> PdfFont<'a> {
 
             Ok(buffer)
         } else {
-            Err(PdfiumError::PdfiumLibraryInternalError(
-                PdfiumInternalError::Unknown,
+            Err(self.bindings().error_in(
+                "FPDFFont_GetFontData",
+                PdfiumError::PdfiumLibraryInternalError(PdfiumInternalError::Unknown),
             ))
         }
     }
@@ -1205,3 +1392,62 @@ impl<'a> Drop for PdfFont<'a> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::utils::test::test_bind_to_pdfium;
+
+    #[test]
+    fn test_measure_text_without_page() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let mut document = pdfium.create_new_pdf()?;
+
+        let token = document.fonts_mut().helvetica();
+
+        let font_size = PdfPoints::new(12.0);
+
+        let (measured_width, line_height) = {
+            let font = document.fonts().get(token).unwrap();
+
+            let wide = font.glyph_for_char('W').unwrap();
+
+            let narrow = font.glyph_for_char('i').unwrap();
+
+            assert!(wide.width_at_font_size(font_size) > narrow.width_at_font_size(font_size));
+
+            assert_eq!(
+                font.text_width_at_font_size("Wi", font_size),
+                wide.width_at_font_size(font_size) + narrow.width_at_font_size(font_size)
+            );
+
+            (
+                font.text_width_at_font_size("Hello, world", font_size),
+                font.line_height(font_size)?,
+            )
+        };
+
+        assert!(line_height > font_size * 0.8);
+        assert!(line_height < font_size * 1.5);
+
+        // The measured width should closely match the width of the same text placed on a page,
+        // allowing for the side bearings of the first and last glyphs.
+
+        let mut page = document
+            .pages_mut()
+            .create_page_at_start(PdfPagePaperSize::a4())?;
+
+        let object = page.objects_mut().create_text_object(
+            PdfPoints::new(100.0),
+            PdfPoints::new(100.0),
+            "Hello, world",
+            token,
+            font_size,
+        )?;
+
+        assert!((object.width()?.value - measured_width.value).abs() < measured_width.value * 0.05);
+
+        Ok(())
+    }
+}
@@ -5,6 +5,7 @@ use crate::bindgen::{FPDF_FONT, FPDF_GLYPHPATH};
 use crate::bindings::PdfiumLibraryBindings;
 use crate::error::{PdfiumError, PdfiumInternalError};
 use crate::pdf::font::glyphs::PdfFontGlyphIndex;
+use crate::pdf::path::outline::PdfPathOutline;
 use crate::pdf::path::segment::PdfPathSegment;
 use crate::pdf::path::segments::{PdfPathSegmentIndex, PdfPathSegments, PdfPathSegmentsIterator};
 use crate::pdf::points::PdfPoints;
@@ -63,13 +64,24 @@ impl<'a> PdfFontGlyph<'a> {
         );
 
         if handle.is_null() {
-            Err(PdfiumError::PdfiumLibraryInternalError(
-                PdfiumInternalError::Unknown,
+            Err(self.bindings().error_in(
+                "FPDFFont_GetGlyphPath",
+                PdfiumError::PdfiumLibraryInternalError(PdfiumInternalError::Unknown),
             ))
         } else {
             Ok(PdfFontGlyphPath::from_pdfium(handle, self.bindings()))
         }
     }
+
+    /// Returns the outline of this [PdfFontGlyph] when rendered at the given font size.
+    ///
+    /// The font size determines the scale of the outline; a font size of 1000 points
+    /// returns an outline measured in the 1000 units per em used by PDF glyph space.
+    #[inline]
+    pub fn outline_at_font_size(&self, size: PdfPoints) -> Result<PdfPathOutline, PdfiumError> {
+        self.segments_at_font_size(size)
+            .map(|segments| PdfPathOutline::from_segments(&segments))
+    }
 }
 
 /// The collection of [PdfPathSegment] objects inside a font glyph path.
@@ -108,8 +120,9 @@ impl<'a> PdfPathSegments<'a> for PdfFontGlyphPath<'a> {
             .FPDFGlyphPath_GetGlyphPathSegment(self.handle, index as c_int);
 
         if handle.is_null() {
-            Err(PdfiumError::PdfiumLibraryInternalError(
-                PdfiumInternalError::Unknown,
+            Err(self.bindings().error_in(
+                "FPDFGlyphPath_GetGlyphPathSegment",
+                PdfiumError::PdfiumLibraryInternalError(PdfiumInternalError::Unknown),
             ))
         } else {
             Ok(PdfPathSegment::from_pdfium(handle, None, self.bindings()))
@@ -1,10 +1,12 @@
 //! Defines the [PdfLink] struct, exposing functionality related to a single link contained
 //! within a [PdfPage], a [PdfPageAnnotation], or a [PdfBookmark].
 
-use crate::bindgen::{FPDF_DOCUMENT, FPDF_LINK};
+use crate::bindgen::{FPDF_DOCUMENT, FPDF_LINK, FS_RECTF};
 use crate::bindings::PdfiumLibraryBindings;
+use crate::error::PdfiumError;
 use crate::pdf::action::PdfAction;
 use crate::pdf::destination::PdfDestination;
+use crate::pdf::rect::PdfRect;
 
 #[cfg(doc)]
 use {
@@ -49,6 +51,28 @@ impl<'a> PdfLink<'a> {
         self.bindings
     }
 
+    /// Returns the bounding box of the clickable area of this [PdfLink] on its containing page.
+    pub fn bounds(&self) -> Result<PdfRect, PdfiumError> {
+        let mut rect = FS_RECTF {
+            left: 0.0,
+            top: 0.0,
+            right: 0.0,
+            bottom: 0.0,
+        };
+
+        if self.bindings().is_true(
+            self.bindings()
+                .FPDFLink_GetAnnotRect(self.handle(), &mut rect),
+        ) {
+            Ok(PdfRect::from_pdfium(rect))
+        } else {
+            Err(self.bindings().error_in(
+                "FPDFLink_GetAnnotRect",
+                PdfiumError::PdfiumFunctionReturnValueIndicatedFailure,
+            ))
+        }
+    }
+
     /// Returns the [PdfAction] associated with this [PdfLink], if any.
     ///
     /// The action indicates the behaviour that will occur when the user interacts with the
@@ -89,3 +113,36 @@ impl<'a> PdfLink<'a> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::utils::test::test_bind_to_pdfium;
+
+    #[test]
+    fn test_extract_uris_with_bounds() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let document = pdfium.load_pdf_from_file("./test/links-test.pdf", None)?;
+
+        let mut uris = Vec::new();
+
+        for (page_index, page) in document.pages().iter().enumerate() {
+            for link in page.links().iter() {
+                let bounds = link.bounds()?;
+
+                assert!(bounds.width().value > 0.0);
+                assert!(bounds.height().value > 0.0);
+
+                if let Some(PdfAction::Uri(action)) = link.action() {
+                    uris.push((page_index, bounds, action.uri()?));
+                }
+            }
+        }
+
+        assert!(!uris.is_empty());
+        assert!(uris.iter().all(|(_, _, uri)| !uri.is_empty()));
+
+        Ok(())
+    }
+}
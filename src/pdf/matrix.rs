@@ -4,6 +4,8 @@
 use crate::bindgen::FS_MATRIX;
 use crate::error::PdfiumError;
 use crate::pdf::points::PdfPoints;
+use crate::pdf::quad_points::PdfQuadPoints;
+use crate::pdf::rect::PdfRect;
 use crate::{create_transform_getters, create_transform_setters};
 use std::hash::{Hash, Hasher};
 use std::ops::{Add, Mul, Sub};
@@ -12,6 +14,65 @@ use vecmath::{mat3_add, mat3_det, mat3_inv, mat3_sub, mat3_transposed, row_mat3_
 /// The floating-point data type used internally by a [PdfMatrix].
 pub type PdfMatrixValue = f32;
 
+/// The individual translation, rotation, scale, and skew components of a [PdfMatrix],
+/// as returned by the [PdfMatrix::decompose()] function.
+///
+/// Applying the components in the order scale, skew, rotation, translation reproduces
+/// the decomposed matrix. All skew is expressed as a skew of the y axis; the x axis skew
+/// of a decomposition is always zero, since any skew of the x axis can be expressed as
+/// a combination of rotation, scaling, and y axis skew.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct PdfMatrixDecomposition {
+    translation: (PdfPoints, PdfPoints),
+    scale: (PdfMatrixValue, PdfMatrixValue),
+    rotation: PdfMatrixValue,
+    y_axis_skew: PdfMatrixValue,
+}
+
+impl PdfMatrixDecomposition {
+    /// Returns the horizontal and vertical translation component of this [PdfMatrixDecomposition].
+    #[inline]
+    pub fn translation(&self) -> (PdfPoints, PdfPoints) {
+        self.translation
+    }
+
+    /// Returns the horizontal and vertical scale factors of this [PdfMatrixDecomposition].
+    ///
+    /// The vertical scale factor is negative if the decomposed matrix includes a reflection.
+    #[inline]
+    pub fn scale(&self) -> (PdfMatrixValue, PdfMatrixValue) {
+        self.scale
+    }
+
+    /// Returns the counter-clockwise rotation component of this [PdfMatrixDecomposition],
+    /// in radians, in the range -π to π.
+    #[inline]
+    pub fn rotation_counter_clockwise_radians(&self) -> PdfMatrixValue {
+        self.rotation
+    }
+
+    /// Returns the counter-clockwise rotation component of this [PdfMatrixDecomposition],
+    /// in degrees, in the range -180 to 180.
+    #[inline]
+    pub fn rotation_counter_clockwise_degrees(&self) -> PdfMatrixValue {
+        self.rotation.to_degrees()
+    }
+
+    /// Returns the x axis and y axis skew angles of this [PdfMatrixDecomposition], in radians.
+    /// The x axis skew angle is always zero.
+    #[inline]
+    pub fn skew_radians(&self) -> (PdfMatrixValue, PdfMatrixValue) {
+        (0.0, self.y_axis_skew)
+    }
+
+    /// Returns the x axis and y axis skew angles of this [PdfMatrixDecomposition], in degrees.
+    /// The x axis skew angle is always zero.
+    #[inline]
+    pub fn skew_degrees(&self) -> (PdfMatrixValue, PdfMatrixValue) {
+        (0.0, self.y_axis_skew.to_degrees())
+    }
+}
+
 /// Six floating-point values, labelled `a`, `b`, `c`, `d`, `e`, and `f`, that represent
 /// the six configurable elements of a nine-element 3x3 PDF transformation matrix.
 ///
@@ -175,11 +236,57 @@ impl PdfMatrix {
         }
     }
 
-    /// Returns the inverse of this [PdfMatrix].
+    /// Returns `true` if this [PdfMatrix] is equal to [PdfMatrix::IDENTITY], allowing
+    /// for the same small tolerance used when comparing matrices for equality.
     #[inline]
-    pub fn invert(&self) -> PdfMatrix {
-        Self {
+    pub fn is_identity(&self) -> bool {
+        *self == Self::IDENTITY
+    }
+
+    /// Returns `true` if every value in this [PdfMatrix] differs from the corresponding value
+    /// in the given [PdfMatrix] by less than the given tolerance.
+    ///
+    /// Comparing matrices using `==` applies a fixed tolerance of 0.0001.
+    pub fn approx_eq(&self, other: &PdfMatrix, epsilon: PdfMatrixValue) -> bool {
+        (self.a() - other.a()).abs() < epsilon
+            && (self.b() - other.b()).abs() < epsilon
+            && (self.c() - other.c()).abs() < epsilon
+            && (self.d() - other.d()).abs() < epsilon
+            && (self.e() - other.e()).abs() < epsilon
+            && (self.f() - other.f()).abs() < epsilon
+    }
+
+    /// Returns `true` if this [PdfMatrix] can be inverted, i.e. if its determinant is non-zero.
+    ///
+    /// A matrix that cannot be inverted collapses the plane onto a line or a single point.
+    #[inline]
+    pub fn is_invertible(&self) -> bool {
+        let determinant = self.determinant();
+
+        determinant != 0.0 && determinant.is_finite()
+    }
+
+    /// Returns the inverse of this [PdfMatrix], or `None` if this [PdfMatrix] cannot be inverted.
+    ///
+    /// Applying a matrix and then its inverse returns any point to its original position.
+    pub fn invert(&self) -> Option<PdfMatrix> {
+        if !self.is_invertible() {
+            return None;
+        }
+
+        let result = Self {
             matrix: mat3_inv(self.matrix),
+        };
+
+        if result
+            .matrix
+            .iter()
+            .flatten()
+            .all(|value| value.is_finite())
+        {
+            Some(result)
+        } else {
+            None
         }
     }
 
@@ -214,6 +321,10 @@ impl PdfMatrix {
     }
 
     /// Returns the result of multiplying this [PdfMatrix] by the given [PdfMatrix].
+    ///
+    /// PDF transformation matrices are applied to row vectors, so the resulting matrix
+    /// applies the transformation represented by this [PdfMatrix] first, followed by
+    /// the transformation represented by the given [PdfMatrix].
     #[inline]
     pub fn multiply(&self, other: PdfMatrix) -> PdfMatrix {
         Self {
@@ -221,10 +332,20 @@ impl PdfMatrix {
         }
     }
 
+    /// Returns a [PdfMatrix] that applies the transformation represented by this [PdfMatrix],
+    /// then the transformation represented by the given [PdfMatrix].
+    ///
+    /// This is identical to [PdfMatrix::multiply()], but makes the order in which
+    /// the transformations are applied explicit.
+    #[inline]
+    pub fn then(&self, other: PdfMatrix) -> PdfMatrix {
+        self.multiply(other)
+    }
+
     /// Returns the result of applying this [PdfMatrix] to the given coordinate pair expressed
     /// as [PdfPoints].
     #[inline]
-    pub fn apply_to_points(&self, x: PdfPoints, y: PdfPoints) -> (PdfPoints, PdfPoints) {
+    pub fn transform_point(&self, x: PdfPoints, y: PdfPoints) -> (PdfPoints, PdfPoints) {
         // The formula for applying transform to coordinates is provided in
         // The PDF Reference Manual, version 1.7, on page 208.
 
@@ -234,6 +355,54 @@ impl PdfMatrix {
         )
     }
 
+    /// Returns the result of applying this [PdfMatrix] to the given coordinate pair expressed
+    /// as [PdfPoints].
+    ///
+    /// This is identical to [PdfMatrix::transform_point()].
+    #[inline]
+    pub fn apply_to_points(&self, x: PdfPoints, y: PdfPoints) -> (PdfPoints, PdfPoints) {
+        self.transform_point(x, y)
+    }
+
+    /// Returns the quadrilateral that results from applying this [PdfMatrix] to each corner
+    /// of the given [PdfRect].
+    ///
+    /// Unlike [PdfRect::transform()], which returns the smallest axis-aligned rectangle
+    /// enclosing the transformed corners, the returned [PdfQuadPoints] retain any rotation
+    /// or skew applied by this [PdfMatrix].
+    #[inline]
+    pub fn transform_rect(&self, rect: PdfRect) -> PdfQuadPoints {
+        rect.to_quad_points().transform(*self)
+    }
+
+    /// Decomposes this [PdfMatrix] into separate translation, rotation, scale, and skew
+    /// components, or returns `None` if this [PdfMatrix] cannot be inverted.
+    ///
+    /// Applying the scale, then the skew, then the rotation, then the translation of
+    /// the returned [PdfMatrixDecomposition] reproduces this [PdfMatrix].
+    pub fn decompose(&self) -> Option<PdfMatrixDecomposition> {
+        if !self.is_invertible() {
+            return None;
+        }
+
+        // The linear part of the matrix is decomposed as the product of a scale, a y axis skew,
+        // and a rotation, in that order. The first row of the matrix is the image of the x axis,
+        // which is affected only by the horizontal scale and the rotation.
+
+        let (a, b, c, d) = (self.a(), self.b(), self.c(), self.d());
+
+        let horizontal_scale = a.hypot(b);
+
+        let vertical_scale = self.determinant() / horizontal_scale;
+
+        Some(PdfMatrixDecomposition {
+            translation: (PdfPoints::new(self.e()), PdfPoints::new(self.f())),
+            scale: (horizontal_scale, vertical_scale),
+            rotation: b.atan2(a),
+            y_axis_skew: ((a * c + b * d) / self.determinant()).atan(),
+        })
+    }
+
     create_transform_setters!(
         Self,
         Result<Self, PdfiumError>,
@@ -288,13 +457,9 @@ impl PdfMatrix {
 // by hand when implementing Hash.
 
 impl PartialEq for PdfMatrix {
+    #[inline]
     fn eq(&self, other: &Self) -> bool {
-        (self.a() - other.a()).abs() < 0.0001
-            && (self.b() - other.b()).abs() < 0.0001
-            && (self.c() - other.c()).abs() < 0.0001
-            && (self.d() - other.d()).abs() < 0.0001
-            && (self.e() - other.e()).abs() < 0.0001
-            && (self.f() - other.f()).abs() < 0.0001
+        self.approx_eq(other, 0.0001)
     }
 }
 
@@ -361,4 +526,147 @@ mod tests {
         assert_eq!(result.0, x + delta_x);
         assert_eq!(result.1, y + delta_y);
     }
+
+    /// Returns a selection of invertible matrices combining translation, rotation,
+    /// scaling, reflection, and skewing.
+    fn invertible_matrices() -> Vec<PdfMatrix> {
+        let mut result = vec![PdfMatrix::IDENTITY];
+
+        for (scale_x, scale_y) in [(1.0, 1.0), (2.5, 0.5), (-1.0, 3.0), (0.25, -0.75)] {
+            for skew in [0.0, 0.3, -0.6] {
+                for rotation in [0.0, 0.5, 1.75, -2.9] {
+                    result.push(
+                        PdfMatrix::identity()
+                            .scale(scale_x, scale_y)
+                            .unwrap()
+                            .skew_radians(0.0, skew)
+                            .unwrap()
+                            .rotate_counter_clockwise_radians(rotation)
+                            .unwrap()
+                            .translate(PdfPoints::new(-40.0), PdfPoints::new(125.0))
+                            .unwrap(),
+                    );
+                }
+            }
+        }
+
+        result
+    }
+
+    #[test]
+    fn test_matrix_invert_round_trip() {
+        let points = [(0.0, 0.0), (100.0, -50.0), (-612.0, 792.0), (0.5, 0.25)];
+
+        for matrix in invertible_matrices() {
+            let inverse = matrix.invert().unwrap();
+
+            assert!((matrix * inverse).is_identity());
+            assert!(matrix.then(inverse).approx_eq(&PdfMatrix::IDENTITY, 0.0001));
+
+            for (x, y) in points {
+                let (tx, ty) = matrix.transform_point(PdfPoints::new(x), PdfPoints::new(y));
+                let (rx, ry) = inverse.transform_point(tx, ty);
+
+                assert!((rx.value - x).abs() < 0.01);
+                assert!((ry.value - y).abs() < 0.01);
+            }
+        }
+    }
+
+    #[test]
+    fn test_matrix_decompose_round_trip() {
+        for matrix in invertible_matrices() {
+            let decomposition = matrix.decompose().unwrap();
+
+            let (scale_x, scale_y) = decomposition.scale();
+            let (skew_x, skew_y) = decomposition.skew_radians();
+            let (translate_x, translate_y) = decomposition.translation();
+
+            assert_eq!(skew_x, 0.0);
+
+            let recomposed = PdfMatrix::identity()
+                .scale(scale_x, scale_y)
+                .unwrap()
+                .skew_radians(skew_x, skew_y)
+                .unwrap()
+                .rotate_counter_clockwise_radians(
+                    decomposition.rotation_counter_clockwise_radians(),
+                )
+                .unwrap()
+                .translate(translate_x, translate_y)
+                .unwrap();
+
+            assert!(recomposed.approx_eq(&matrix, 0.001));
+
+            // The transform getters must report the decomposed values.
+
+            assert_eq!(matrix.get_scale(), (scale_x, scale_y));
+            assert_eq!(matrix.get_skew_radians(), (skew_x, skew_y));
+            assert_eq!(
+                matrix.get_rotation_counter_clockwise_radians(),
+                decomposition.rotation_counter_clockwise_radians()
+            );
+        }
+    }
+
+    #[test]
+    fn test_matrix_decompose_combined_transforms() {
+        // A matrix that is both rotated and scaled should report its scale and rotation
+        // independently of one another.
+
+        let matrix = PdfMatrix::identity()
+            .scale(2.0, 3.0)
+            .unwrap()
+            .rotate_counter_clockwise_degrees(30.0)
+            .unwrap();
+
+        assert!((matrix.get_horizontal_scale() - 2.0).abs() < 0.0001);
+        assert!((matrix.get_vertical_scale() - 3.0).abs() < 0.0001);
+        assert!((matrix.get_rotation_counter_clockwise_degrees() - 30.0).abs() < 0.001);
+        assert!(matrix.get_y_axis_skew_degrees().abs() < 0.001);
+    }
+
+    #[test]
+    fn test_matrix_degenerate() {
+        let degenerate = [
+            PdfMatrix::ZERO,
+            PdfMatrix::new(1.0, 0.0, 0.0, 0.0, 10.0, 10.0),
+            PdfMatrix::new(1.0, 2.0, 2.0, 4.0, 0.0, 0.0),
+            PdfMatrix::new(0.0, 0.0, 3.0, 5.0, 0.0, 0.0),
+        ];
+
+        for matrix in degenerate {
+            assert!(!matrix.is_invertible());
+            assert!(matrix.invert().is_none());
+            assert!(matrix.decompose().is_none());
+            assert!(!matrix.is_identity());
+            assert_eq!(matrix.get_scale(), (0.0, 0.0));
+            assert_eq!(matrix.get_rotation_counter_clockwise_radians(), 0.0);
+        }
+    }
+
+    #[test]
+    fn test_matrix_transform_rect() {
+        let rect = PdfRect::new_from_values(0.0, 0.0, 10.0, 20.0);
+
+        let matrix = PdfMatrix::identity()
+            .rotate_counter_clockwise_degrees(90.0)
+            .unwrap()
+            .translate(PdfPoints::new(100.0), PdfPoints::ZERO)
+            .unwrap();
+
+        let quad = matrix.transform_rect(rect);
+
+        assert!((quad.area() - 200.0).abs() < 0.01);
+
+        let bounds = quad.to_axis_aligned_rect();
+
+        assert!((bounds.left().value - 90.0).abs() < 0.001);
+        assert!((bounds.right().value - 100.0).abs() < 0.001);
+        assert!((bounds.bottom().value - 0.0).abs() < 0.001);
+        assert!((bounds.top().value - 20.0).abs() < 0.001);
+
+        assert!(PdfMatrix::IDENTITY.is_identity());
+        assert!(PdfMatrix::IDENTITY.transform_rect(rect).to_rect() == rect);
+    }
 }
@@ -1,39 +1,183 @@
 //! Defines the [PdfClipPath] struct, exposing functionality related to a clip path.
-#[doc(hidden)]
+
 use crate::bindgen::FPDF_CLIPPATH;
 use crate::bindings::PdfiumLibraryBindings;
 use crate::error::{PdfiumError, PdfiumInternalError};
 use crate::pdf::path::segment::PdfPathSegment;
 use crate::pdf::path::segments::{PdfPathSegmentIndex, PdfPathSegments, PdfPathSegmentsIterator};
+use crate::pdf::rect::PdfRect;
 use std::convert::TryInto;
+use std::ops::{Range, RangeInclusive};
 use std::os::raw::c_int;
 
-// TODO: AJRC - 22/10/22 - "clip path" is a slight misnomer, since a single clip path can actually
-// contain zero or more path objects. Each path object can then return a PdfClipPathSegments object
-// that implements the PdfPathSegments trait. Want to complete implementation of top-level PdfClipPath
-// collection, then add clip path support to pages and page objects.
+#[cfg(doc)]
+use crate::pdf::document::page::{object::PdfPageObjectCommon, PdfPage};
+
+/// The zero-based index of a single path inside its containing [PdfClipPath].
+pub type PdfClipPathIndex = usize;
 
-#[allow(dead_code)]
-// The PdfClipPath struct is not currently used, but we expect it to be in future
+/// A clip path, defining the region of a page or page object that can be painted.
+/// Anything outside the clip path is not rendered.
+///
+/// A single clip path can contain zero or more paths. The segments of each path can be
+/// retrieved as a [PdfClipPathSegments] collection that implements the [PdfPathSegments] trait.
+///
+/// An existing clip path attached to a page object can be retrieved using the
+/// [PdfPageObjectCommon::clip_path()] function. A new rectangular clip path can be created
+/// using the [PdfClipPath::new_from_rect()] function, and applied to all the content on a page
+/// using the [PdfPage::insert_clip_path()] function.
 pub struct PdfClipPath<'a> {
-    // TODO: AJRC - 22/10/22 - this will contain a collection of paths
-    // each of which can return a PdfClipPathSegments object
     handle: FPDF_CLIPPATH,
+    is_owned: bool,
     bindings: &'a dyn PdfiumLibraryBindings,
 }
 
 impl<'a> PdfClipPath<'a> {
+    /// Creates a [PdfClipPath] wrapping a clip path handle owned by Pdfium, for example
+    /// a clip path attached to a page object. The handle will not be destroyed when the
+    /// returned [PdfClipPath] is dropped.
+    #[inline]
+    pub(crate) fn from_pdfium(
+        handle: FPDF_CLIPPATH,
+        bindings: &'a dyn PdfiumLibraryBindings,
+    ) -> Self {
+        PdfClipPath {
+            handle,
+            is_owned: false,
+            bindings,
+        }
+    }
+
+    /// Creates a new [PdfClipPath] containing a single rectangular path matching the
+    /// given bounds.
+    pub fn new_from_rect(
+        rect: PdfRect,
+        bindings: &'a dyn PdfiumLibraryBindings,
+    ) -> Result<Self, PdfiumError> {
+        let handle = bindings.FPDF_CreateClipPath(
+            rect.left().value,
+            rect.bottom().value,
+            rect.right().value,
+            rect.top().value,
+        );
+
+        if handle.is_null() {
+            Err(bindings.error_in(
+                "FPDF_CreateClipPath",
+                PdfiumError::PdfiumLibraryInternalError(PdfiumInternalError::Unknown),
+            ))
+        } else {
+            Ok(PdfClipPath {
+                handle,
+                is_owned: true,
+                bindings,
+            })
+        }
+    }
+
+    /// Returns the internal `FPDF_CLIPPATH` handle for this [PdfClipPath].
+    #[inline]
+    pub(crate) fn handle(&self) -> FPDF_CLIPPATH {
+        self.handle
+    }
+
+    /// Returns the [PdfiumLibraryBindings] used by this [PdfClipPath].
     #[inline]
     pub fn bindings(&self) -> &'a dyn PdfiumLibraryBindings {
         self.bindings
     }
+
+    /// Returns the number of paths in this [PdfClipPath].
+    #[inline]
+    pub fn len(&self) -> PdfClipPathIndex {
+        self.bindings
+            .FPDFClipPath_CountPaths(self.handle)
+            .try_into()
+            .unwrap_or(0)
+    }
+
+    /// Returns `true` if this [PdfClipPath] contains no paths.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns a Range from `0..(number of paths)` for this [PdfClipPath].
+    #[inline]
+    pub fn as_range(&self) -> Range<PdfClipPathIndex> {
+        0..self.len()
+    }
+
+    /// Returns an inclusive Range from `0..=(number of paths - 1)` for this [PdfClipPath].
+    #[inline]
+    pub fn as_range_inclusive(&self) -> RangeInclusive<PdfClipPathIndex> {
+        if self.is_empty() {
+            0..=0
+        } else {
+            0..=(self.len() - 1)
+        }
+    }
+
+    /// Returns the [PdfClipPathSegments] collection for the path at the given index
+    /// in this [PdfClipPath].
+    pub fn get(&self, index: PdfClipPathIndex) -> Result<PdfClipPathSegments<'a>, PdfiumError> {
+        if index >= self.len() {
+            return Err(PdfiumError::ClipPathIndexOutOfBounds);
+        }
+
+        Ok(PdfClipPathSegments::from_pdfium(
+            self.handle,
+            index as c_int,
+            self.bindings,
+        ))
+    }
+
+    /// Returns an iterator over the [PdfClipPathSegments] collections for all the paths
+    /// in this [PdfClipPath].
+    #[inline]
+    pub fn iter(&self) -> PdfClipPathIterator<'_, 'a> {
+        PdfClipPathIterator::new(self)
+    }
 }
 
 impl<'a> Drop for PdfClipPath<'a> {
     /// Closes this [PdfClipPath], releasing held memory.
     #[inline]
     fn drop(&mut self) {
-        self.bindings.FPDF_DestroyClipPath(self.handle)
+        // Clip paths owned by Pdfium, such as those attached to page objects,
+        // must not be destroyed.
+
+        if self.is_owned {
+            self.bindings.FPDF_DestroyClipPath(self.handle)
+        }
+    }
+}
+
+/// An iterator over the [PdfClipPathSegments] collections for all the paths in a [PdfClipPath].
+pub struct PdfClipPathIterator<'b, 'a> {
+    clip_path: &'b PdfClipPath<'a>,
+    next_index: PdfClipPathIndex,
+}
+
+impl<'b, 'a> PdfClipPathIterator<'b, 'a> {
+    #[inline]
+    pub(crate) fn new(clip_path: &'b PdfClipPath<'a>) -> Self {
+        PdfClipPathIterator {
+            clip_path,
+            next_index: 0,
+        }
+    }
+}
+
+impl<'b, 'a> Iterator for PdfClipPathIterator<'b, 'a> {
+    type Item = PdfClipPathSegments<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let next = self.clip_path.get(self.next_index);
+
+        self.next_index += 1;
+
+        next.ok()
     }
 }
 
@@ -46,8 +190,6 @@ pub struct PdfClipPathSegments<'a> {
 
 impl<'a> PdfClipPathSegments<'a> {
     #[inline]
-    #[allow(dead_code)]
-    // The from_pdfium() function is not currently used, but we expect it to be in future
     pub(crate) fn from_pdfium(
         handle: FPDF_CLIPPATH,
         path_index: c_int,
@@ -83,8 +225,9 @@ impl<'a> PdfPathSegments<'a> for PdfClipPathSegments<'a> {
         );
 
         if handle.is_null() {
-            Err(PdfiumError::PdfiumLibraryInternalError(
-                PdfiumInternalError::Unknown,
+            Err(self.bindings().error_in(
+                "FPDFClipPath_GetPathSegment",
+                PdfiumError::PdfiumLibraryInternalError(PdfiumInternalError::Unknown),
             ))
         } else {
             Ok(PdfPathSegment::from_pdfium(handle, None, self.bindings()))
@@ -96,3 +239,74 @@ impl<'a> PdfPathSegments<'a> for PdfClipPathSegments<'a> {
         PdfPathSegmentsIterator::new(self)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::utils::test::test_bind_to_pdfium;
+
+    #[test]
+    fn test_insert_clip_path() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let bytes = {
+            let mut document = pdfium.create_new_pdf()?;
+
+            let mut page =
+                document
+                    .pages_mut()
+                    .create_page_at_start(PdfPagePaperSize::new_custom(
+                        PdfPoints::new(200.0),
+                        PdfPoints::new(200.0),
+                    ))?;
+
+            // Fill the whole page with red, then clip the page content to the left half
+            // of the filled area.
+
+            let object = page.objects_mut().create_path_object_rect(
+                PdfRect::new_from_values(0.0, 0.0, 200.0, 200.0),
+                None,
+                None,
+                Some(PdfColor::RED),
+            )?;
+
+            let bounds = object.bounds()?.to_rect();
+
+            let clip_path = PdfClipPath::new_from_rect(
+                PdfRect::new(
+                    bounds.bottom(),
+                    bounds.left(),
+                    bounds.top(),
+                    bounds.left() + bounds.width() / 2.0,
+                ),
+                pdfium.bindings(),
+            )?;
+
+            assert_eq!(clip_path.len(), 1);
+            assert_eq!(clip_path.get(0)?.len(), 5);
+
+            page.insert_clip_path(&clip_path)?;
+
+            drop(page);
+
+            document.save_to_bytes()?
+        };
+
+        let document = pdfium.load_pdf_from_byte_vec(bytes, None)?;
+
+        let image = document
+            .pages()
+            .first()?
+            .render_with_config(&PdfRenderConfig::new().set_target_width(200))?
+            .as_image()
+            .into_rgba8();
+
+        // Pixels inside the clip should be painted red; pixels outside the clip should
+        // be untouched.
+
+        assert_eq!(image.get_pixel(50, 100).0, [255, 0, 0, 255]);
+        assert_eq!(image.get_pixel(150, 100).0, [255, 255, 255, 255]);
+
+        Ok(())
+    }
+}
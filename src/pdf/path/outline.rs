@@ -0,0 +1,1469 @@
+//! Defines the [PdfPathOutline] struct, an owned copy of the contours described by a
+//! `PdfPathSegments` collection that can be inspected or exported without holding onto
+//! any Pdfium handles.
+
+use crate::error::PdfiumError;
+use crate::pdf::path::segment::PdfPathSegmentType;
+use crate::pdf::path::segments::PdfPathSegments;
+use crate::pdf::points::PdfPoints;
+use crate::pdf::rect::PdfRect;
+use std::f32::consts::{FRAC_PI_2, PI};
+use std::fmt::Write;
+
+/// A single drawing command in a [PdfPathOutline]. All coordinates are expressed in
+/// PDF coordinate space, with the y axis pointing upwards.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum PdfPathCommand {
+    /// Begins a new contour at the given position.
+    MoveTo { x: f32, y: f32 },
+
+    /// Draws a straight line from the current position to the given position.
+    LineTo { x: f32, y: f32 },
+
+    /// Draws a quadratic Bézier curve from the current position to the given position,
+    /// using the given control point.
+    QuadTo { x1: f32, y1: f32, x: f32, y: f32 },
+
+    /// Draws a cubic Bézier curve from the current position to the given position,
+    /// using the two given control points.
+    CubicTo {
+        x1: f32,
+        y1: f32,
+        x2: f32,
+        y2: f32,
+        x: f32,
+        y: f32,
+    },
+
+    /// Closes the current contour by drawing a straight line back to its starting position.
+    Close,
+}
+
+/// The orientation of the y axis used when exporting a [PdfPathOutline] to SVG path data.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum PdfPathOrientation {
+    /// Coordinates are exported unchanged, with the y axis pointing upwards as in PDF
+    /// coordinate space.
+    PdfYUp,
+
+    /// Coordinates are flipped vertically within a viewport of the given height, with the
+    /// y axis pointing downwards as in SVG coordinate space.
+    SvgYDown { height: f32 },
+}
+
+impl PdfPathOrientation {
+    #[inline]
+    fn apply_to_y(&self, y: f32) -> f32 {
+        match self {
+            PdfPathOrientation::PdfYUp => y,
+            PdfPathOrientation::SvgYDown { height } => height - y,
+        }
+    }
+}
+
+/// A single contour in a [PdfPathOutline], beginning with a [PdfPathCommand::MoveTo] command.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PdfPathContour {
+    commands: Vec<PdfPathCommand>,
+}
+
+impl PdfPathContour {
+    /// Returns the drawing commands that make up this [PdfPathContour].
+    #[inline]
+    pub fn commands(&self) -> &[PdfPathCommand] {
+        self.commands.as_slice()
+    }
+
+    /// Returns `true` if this [PdfPathContour] ends with a [PdfPathCommand::Close] command.
+    #[inline]
+    pub fn is_closed(&self) -> bool {
+        matches!(self.commands.last(), Some(PdfPathCommand::Close))
+    }
+}
+
+/// An owned outline made up of one or more contours, resolved from the individual points
+/// in a `PdfPathSegments` collection such as a font glyph path or a path page object.
+///
+/// Pdfium reports each cubic Bézier curve as three consecutive `PdfPathSegment` objects,
+/// and reports quadratic Bézier curves, such as those in TrueType glyph outlines, as
+/// equivalent cubic curves. A [PdfPathOutline] combines the segments of each curve into a
+/// single [PdfPathCommand], recovering the original quadratic curve where the cubic curve
+/// reported by Pdfium is exactly equivalent to one.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PdfPathOutline {
+    contours: Vec<PdfPathContour>,
+}
+
+impl PdfPathOutline {
+    /// The tolerance, relative to the size of the curve, used when deciding whether a cubic
+    /// Bézier curve is equivalent to a quadratic Bézier curve.
+    const QUADRATIC_TOLERANCE: f32 = 0.0001;
+
+    /// Creates a new [PdfPathOutline] from the path segments in the given [PdfPathSegments]
+    /// collection.
+    pub fn from_segments<'a>(segments: &impl PdfPathSegments<'a>) -> Self {
+        Self::from_points((0..segments.len()).filter_map(|index| {
+            segments.get(index).ok().map(|segment| {
+                let (x, y) = segment.point();
+
+                (segment.segment_type(), x.value, y.value, segment.is_close())
+            })
+        }))
+    }
+
+    /// Creates a new [PdfPathOutline] from the type, destination position, and close flag
+    /// of each segment in a path.
+    pub(crate) fn from_points(
+        points: impl IntoIterator<Item = (PdfPathSegmentType, f32, f32, bool)>,
+    ) -> Self {
+        let mut contours = Vec::new();
+
+        let mut commands = Vec::new();
+
+        let mut bezier_points = Vec::with_capacity(3);
+
+        let mut start = (0.0, 0.0);
+
+        let mut current = (0.0, 0.0);
+
+        for (segment_type, x, y, is_close) in points {
+            match segment_type {
+                PdfPathSegmentType::MoveTo => {
+                    if !commands.is_empty() {
+                        contours.push(PdfPathContour { commands });
+
+                        commands = Vec::new();
+                    }
+
+                    bezier_points.clear();
+                    commands.push(PdfPathCommand::MoveTo { x, y });
+                    start = (x, y);
+                }
+                PdfPathSegmentType::LineTo => {
+                    if commands.is_empty() {
+                        commands.push(PdfPathCommand::MoveTo {
+                            x: current.0,
+                            y: current.1,
+                        });
+                    }
+
+                    commands.push(PdfPathCommand::LineTo { x, y });
+                }
+                PdfPathSegmentType::BezierTo => {
+                    bezier_points.push((x, y));
+
+                    if bezier_points.len() < 3 {
+                        // The close flag, if any, is carried by the final point of the curve.
+
+                        continue;
+                    }
+
+                    if commands.is_empty() {
+                        commands.push(PdfPathCommand::MoveTo {
+                            x: current.0,
+                            y: current.1,
+                        });
+                    }
+
+                    commands.push(Self::curve(
+                        current,
+                        bezier_points[0],
+                        bezier_points[1],
+                        bezier_points[2],
+                    ));
+
+                    bezier_points.clear();
+                }
+                PdfPathSegmentType::Unknown => continue,
+            }
+
+            current = (x, y);
+
+            if is_close {
+                commands.push(PdfPathCommand::Close);
+                current = start;
+            }
+        }
+
+        if !commands.is_empty() {
+            contours.push(PdfPathContour { commands });
+        }
+
+        Self { contours }
+    }
+
+    /// Returns a [PdfPathCommand::QuadTo] command if the given cubic Bézier curve is a
+    /// degree-elevated quadratic Bézier curve, or a [PdfPathCommand::CubicTo] command otherwise.
+    fn curve(p0: (f32, f32), c1: (f32, f32), c2: (f32, f32), p3: (f32, f32)) -> PdfPathCommand {
+        // A quadratic curve with control point q is elevated to a cubic curve with control
+        // points p0 + 2/3 (q - p0) and p3 + 2/3 (q - p3), so both control points of an
+        // elevated curve project back onto the same quadratic control point.
+
+        let q1 = ((3.0 * c1.0 - p0.0) / 2.0, (3.0 * c1.1 - p0.1) / 2.0);
+
+        let q2 = ((3.0 * c2.0 - p3.0) / 2.0, (3.0 * c2.1 - p3.1) / 2.0);
+
+        let scale = [p0.0, p0.1, c1.0, c1.1, c2.0, c2.1, p3.0, p3.1]
+            .iter()
+            .fold(1.0_f32, |scale, value| scale.max(value.abs()));
+
+        if (q1.0 - q2.0).abs() <= scale * Self::QUADRATIC_TOLERANCE
+            && (q1.1 - q2.1).abs() <= scale * Self::QUADRATIC_TOLERANCE
+        {
+            PdfPathCommand::QuadTo {
+                x1: (q1.0 + q2.0) / 2.0,
+                y1: (q1.1 + q2.1) / 2.0,
+                x: p3.0,
+                y: p3.1,
+            }
+        } else {
+            PdfPathCommand::CubicTo {
+                x1: c1.0,
+                y1: c1.1,
+                x2: c2.0,
+                y2: c2.1,
+                x: p3.0,
+                y: p3.1,
+            }
+        }
+    }
+
+    /// Returns the contours that make up this [PdfPathOutline].
+    #[inline]
+    pub fn contours(&self) -> &[PdfPathContour] {
+        self.contours.as_slice()
+    }
+
+    /// Returns `true` if this [PdfPathOutline] contains no contours.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.contours.is_empty()
+    }
+
+    /// Returns an iterator over all the drawing commands in all the contours in this
+    /// [PdfPathOutline].
+    #[inline]
+    pub fn commands(&self) -> impl Iterator<Item = PdfPathCommand> + '_ {
+        self.contours
+            .iter()
+            .flat_map(|contour| contour.commands.iter().copied())
+    }
+
+    /// Returns the smallest rectangle that fully contains every contour in this
+    /// [PdfPathOutline], taking into account the extremities of any curves.
+    /// An empty rectangle is returned if this [PdfPathOutline] is empty.
+    pub fn bounds(&self) -> PdfRect {
+        let mut bounds: Option<(f32, f32, f32, f32)> = None;
+
+        let mut include = |x: f32, y: f32| {
+            bounds = Some(match bounds {
+                None => (y, x, y, x),
+                Some((bottom, left, top, right)) => {
+                    (bottom.min(y), left.min(x), top.max(y), right.max(x))
+                }
+            });
+        };
+
+        let mut start = (0.0, 0.0);
+
+        let mut current = (0.0, 0.0);
+
+        for command in self.commands() {
+            match command {
+                PdfPathCommand::MoveTo { x, y } => {
+                    include(x, y);
+                    start = (x, y);
+                    current = (x, y);
+                }
+                PdfPathCommand::LineTo { x, y } => {
+                    include(x, y);
+                    current = (x, y);
+                }
+                PdfPathCommand::QuadTo { x1, y1, x, y } => {
+                    for &t in quadratic_extrema(current.0, x1, x)
+                        .iter()
+                        .chain(quadratic_extrema(current.1, y1, y).iter())
+                        .flatten()
+                    {
+                        include(
+                            quadratic_at(current.0, x1, x, t),
+                            quadratic_at(current.1, y1, y, t),
+                        );
+                    }
+
+                    include(x, y);
+                    current = (x, y);
+                }
+                PdfPathCommand::CubicTo {
+                    x1,
+                    y1,
+                    x2,
+                    y2,
+                    x,
+                    y,
+                } => {
+                    for &t in cubic_extrema(current.0, x1, x2, x)
+                        .iter()
+                        .chain(cubic_extrema(current.1, y1, y2, y).iter())
+                        .flatten()
+                    {
+                        include(
+                            cubic_at(current.0, x1, x2, x, t),
+                            cubic_at(current.1, y1, y2, y, t),
+                        );
+                    }
+
+                    include(x, y);
+                    current = (x, y);
+                }
+                PdfPathCommand::Close => current = start,
+            }
+        }
+
+        match bounds {
+            Some((bottom, left, top, right)) => PdfRect::new(
+                PdfPoints::new(bottom),
+                PdfPoints::new(left),
+                PdfPoints::new(top),
+                PdfPoints::new(right),
+            ),
+            None => PdfRect::zero(),
+        }
+    }
+
+    /// Returns the contours in this [PdfPathOutline] as SVG path data, suitable for use as
+    /// the `d` attribute of an SVG `<path>` element, with coordinates in the given orientation.
+    ///
+    /// Coordinates are written with at most three decimal places.
+    pub fn to_svg_path_data(&self, orientation: PdfPathOrientation) -> String {
+        let mut data = String::new();
+
+        for command in self.commands() {
+            if !data.is_empty() {
+                data.push(' ');
+            }
+
+            // Writing to a String cannot fail, so the results of write!() can be ignored.
+
+            let _ = match command {
+                PdfPathCommand::MoveTo { x, y } => write!(
+                    data,
+                    "M{} {}",
+                    format_number(x),
+                    format_number(orientation.apply_to_y(y))
+                ),
+                PdfPathCommand::LineTo { x, y } => write!(
+                    data,
+                    "L{} {}",
+                    format_number(x),
+                    format_number(orientation.apply_to_y(y))
+                ),
+                PdfPathCommand::QuadTo { x1, y1, x, y } => write!(
+                    data,
+                    "Q{} {} {} {}",
+                    format_number(x1),
+                    format_number(orientation.apply_to_y(y1)),
+                    format_number(x),
+                    format_number(orientation.apply_to_y(y))
+                ),
+                PdfPathCommand::CubicTo {
+                    x1,
+                    y1,
+                    x2,
+                    y2,
+                    x,
+                    y,
+                } => write!(
+                    data,
+                    "C{} {} {} {} {} {}",
+                    format_number(x1),
+                    format_number(orientation.apply_to_y(y1)),
+                    format_number(x2),
+                    format_number(orientation.apply_to_y(y2)),
+                    format_number(x),
+                    format_number(orientation.apply_to_y(y))
+                ),
+                PdfPathCommand::Close => write!(data, "Z"),
+            };
+        }
+
+        data
+    }
+
+    /// Creates a new [PdfPathOutline] from the given SVG path data, as used in the `d` attribute
+    /// of an SVG `<path>` element, with coordinates in the given orientation.
+    ///
+    /// All path commands in the SVG path mini-language are supported, in both their absolute
+    /// (upper case) and relative (lower case) forms. Elliptical arcs are approximated using
+    /// cubic Bézier curves, one for each quarter turn or part thereof. Smooth curve commands
+    /// reflect the control point of the preceding curve, as in SVG.
+    ///
+    /// Returns [PdfiumError::SvgPathDataSyntaxError] containing the byte offset of the problem
+    /// if the given path data cannot be parsed.
+    pub fn from_svg_path_data(
+        data: &str,
+        orientation: PdfPathOrientation,
+    ) -> Result<Self, PdfiumError> {
+        SvgPathDataParser::new(data, orientation).parse()
+    }
+
+    /// Approximates every contour in this [PdfPathOutline] with straight line segments,
+    /// returning the points of each resulting polyline together with a flag indicating
+    /// whether the polyline is closed.
+    ///
+    /// Curves are subdivided adaptively until no part of any curve deviates from its
+    /// approximating line segments by more than the given tolerance.
+    pub(crate) fn flatten(&self, tolerance: f32) -> Vec<(Vec<(f32, f32)>, bool)> {
+        let mut polylines = Vec::new();
+
+        for contour in self.contours.iter() {
+            let mut points: Vec<(f32, f32)> = Vec::new();
+
+            let mut start = (0.0, 0.0);
+
+            for command in contour.commands.iter() {
+                // Drawing may continue after a contour is closed, in which case it begins
+                // a new polyline from the start of the closed contour.
+
+                if points.is_empty() && *command != PdfPathCommand::Close {
+                    points.push(start);
+                }
+
+                let current = points.last().copied().unwrap_or(start);
+
+                match *command {
+                    PdfPathCommand::MoveTo { x, y } => {
+                        start = (x, y);
+                        points.clear();
+                        points.push(start);
+                    }
+                    PdfPathCommand::LineTo { x, y } => points.push((x, y)),
+                    PdfPathCommand::QuadTo { x1, y1, x, y } => flatten_cubic(
+                        current,
+                        (
+                            current.0 + 2.0 / 3.0 * (x1 - current.0),
+                            current.1 + 2.0 / 3.0 * (y1 - current.1),
+                        ),
+                        (x + 2.0 / 3.0 * (x1 - x), y + 2.0 / 3.0 * (y1 - y)),
+                        (x, y),
+                        tolerance,
+                        0,
+                        &mut points,
+                    ),
+                    PdfPathCommand::CubicTo {
+                        x1,
+                        y1,
+                        x2,
+                        y2,
+                        x,
+                        y,
+                    } => flatten_cubic(
+                        current,
+                        (x1, y1),
+                        (x2, y2),
+                        (x, y),
+                        tolerance,
+                        0,
+                        &mut points,
+                    ),
+                    PdfPathCommand::Close => {
+                        if !points.is_empty() {
+                            polylines.push((std::mem::take(&mut points), true));
+                        }
+                    }
+                }
+            }
+
+            if !points.is_empty() {
+                polylines.push((points, false));
+            }
+        }
+
+        polylines
+    }
+}
+
+/// Parses SVG path data into the contours of a [PdfPathOutline].
+struct SvgPathDataParser<'a> {
+    data: &'a [u8],
+    position: usize,
+    orientation: PdfPathOrientation,
+    contours: Vec<PdfPathContour>,
+    commands: Vec<PdfPathCommand>,
+
+    // All positions are tracked in the coordinate space of the path data. The orientation
+    // is only applied when commands are added to the outline.
+    start: (f32, f32),
+    current: (f32, f32),
+    previous_cubic_control: Option<(f32, f32)>,
+    previous_quadratic_control: Option<(f32, f32)>,
+}
+
+impl<'a> SvgPathDataParser<'a> {
+    fn new(data: &'a str, orientation: PdfPathOrientation) -> Self {
+        SvgPathDataParser {
+            data: data.as_bytes(),
+            position: 0,
+            orientation,
+            contours: Vec::new(),
+            commands: Vec::new(),
+            start: (0.0, 0.0),
+            current: (0.0, 0.0),
+            previous_cubic_control: None,
+            previous_quadratic_control: None,
+        }
+    }
+
+    fn parse(mut self) -> Result<PdfPathOutline, PdfiumError> {
+        let mut previous_command: Option<u8> = None;
+
+        loop {
+            self.skip_separators();
+
+            let byte = match self.data.get(self.position) {
+                Some(byte) => *byte,
+                None => break,
+            };
+
+            let command = if byte.is_ascii_alphabetic() {
+                self.position += 1;
+
+                byte
+            } else {
+                // A command letter may be omitted when the same command is repeated, except
+                // that additional coordinate pairs following a move-to are treated as line-tos.
+
+                match previous_command {
+                    Some(b'M') if self.is_at_number() => b'L',
+                    Some(b'm') if self.is_at_number() => b'l',
+                    Some(command) if command != b'Z' && command != b'z' && self.is_at_number() => {
+                        command
+                    }
+                    _ => return Err(self.error()),
+                }
+            };
+
+            if previous_command.is_none() && command != b'M' && command != b'm' {
+                // Path data must begin with a move-to command.
+
+                return Err(PdfiumError::SvgPathDataSyntaxError(self.position - 1));
+            }
+
+            self.parse_command(command)?;
+
+            previous_command = Some(command);
+        }
+
+        if !self.commands.is_empty() {
+            self.contours.push(PdfPathContour {
+                commands: self.commands,
+            });
+        }
+
+        Ok(PdfPathOutline {
+            contours: self.contours,
+        })
+    }
+
+    /// Parses the arguments of the given command and adds the resulting drawing commands
+    /// to the outline.
+    fn parse_command(&mut self, command: u8) -> Result<(), PdfiumError> {
+        let is_relative = command.is_ascii_lowercase();
+
+        let origin = if is_relative {
+            self.current
+        } else {
+            (0.0, 0.0)
+        };
+
+        let mut cubic_control = None;
+
+        let mut quadratic_control = None;
+
+        match command.to_ascii_uppercase() {
+            b'M' => {
+                let point = self.point(origin)?;
+
+                self.move_to(point);
+            }
+            b'L' => {
+                let point = self.point(origin)?;
+
+                self.line_to(point);
+            }
+            b'H' => {
+                let x = self.number()? + origin.0;
+
+                self.line_to((x, self.current.1));
+            }
+            b'V' => {
+                let y = self.number()? + origin.1;
+
+                self.line_to((self.current.0, y));
+            }
+            b'C' => {
+                let control1 = self.point(origin)?;
+                let control2 = self.point(origin)?;
+                let point = self.point(origin)?;
+
+                self.cubic_to(control1, control2, point);
+
+                cubic_control = Some(control2);
+            }
+            b'S' => {
+                let control1 = self.reflect(self.previous_cubic_control);
+                let control2 = self.point(origin)?;
+                let point = self.point(origin)?;
+
+                self.cubic_to(control1, control2, point);
+
+                cubic_control = Some(control2);
+            }
+            b'Q' => {
+                let control = self.point(origin)?;
+                let point = self.point(origin)?;
+
+                self.quadratic_to(control, point);
+
+                quadratic_control = Some(control);
+            }
+            b'T' => {
+                let control = self.reflect(self.previous_quadratic_control);
+                let point = self.point(origin)?;
+
+                self.quadratic_to(control, point);
+
+                quadratic_control = Some(control);
+            }
+            b'A' => {
+                let x_radius = self.number()?;
+                let y_radius = self.number()?;
+                let rotation = self.number()?;
+                let is_large_arc = self.flag()?;
+                let is_sweep = self.flag()?;
+                let point = self.point(origin)?;
+
+                self.arc_to(x_radius, y_radius, rotation, is_large_arc, is_sweep, point);
+            }
+            b'Z' => self.close(),
+            _ => return Err(PdfiumError::SvgPathDataSyntaxError(self.position - 1)),
+        }
+
+        self.previous_cubic_control = cubic_control;
+        self.previous_quadratic_control = quadratic_control;
+
+        Ok(())
+    }
+
+    fn move_to(&mut self, point: (f32, f32)) {
+        if !self.commands.is_empty() {
+            self.contours.push(PdfPathContour {
+                commands: std::mem::take(&mut self.commands),
+            });
+        }
+
+        let (x, y) = self.orient(point);
+
+        self.commands.push(PdfPathCommand::MoveTo { x, y });
+        self.start = point;
+        self.current = point;
+    }
+
+    /// Ensures the outline has an open contour to which drawing commands can be added.
+    /// Drawing that continues after a contour is closed begins a new contour at the
+    /// start of the closed contour.
+    fn begin_drawing(&mut self) {
+        if self.commands.is_empty() {
+            self.move_to(self.start);
+        }
+    }
+
+    fn line_to(&mut self, point: (f32, f32)) {
+        self.begin_drawing();
+
+        let (x, y) = self.orient(point);
+
+        self.commands.push(PdfPathCommand::LineTo { x, y });
+        self.current = point;
+    }
+
+    fn quadratic_to(&mut self, control: (f32, f32), point: (f32, f32)) {
+        self.begin_drawing();
+
+        let (x1, y1) = self.orient(control);
+        let (x, y) = self.orient(point);
+
+        self.commands.push(PdfPathCommand::QuadTo { x1, y1, x, y });
+        self.current = point;
+    }
+
+    fn cubic_to(&mut self, control1: (f32, f32), control2: (f32, f32), point: (f32, f32)) {
+        self.begin_drawing();
+
+        let (x1, y1) = self.orient(control1);
+        let (x2, y2) = self.orient(control2);
+        let (x, y) = self.orient(point);
+
+        self.commands.push(PdfPathCommand::CubicTo {
+            x1,
+            y1,
+            x2,
+            y2,
+            x,
+            y,
+        });
+        self.current = point;
+    }
+
+    /// Approximates an SVG elliptical arc from the current point to the given point using
+    /// cubic Bézier curves, following the endpoint to center parameterization conversion
+    /// described in the SVG specification, appendix B.2.4.
+    fn arc_to(
+        &mut self,
+        x_radius: f32,
+        y_radius: f32,
+        rotation: f32,
+        is_large_arc: bool,
+        is_sweep: bool,
+        point: (f32, f32),
+    ) {
+        let (x1, y1) = self.current;
+        let (x2, y2) = point;
+
+        if (x1, y1) == (x2, y2) {
+            // An arc with identical endpoints is omitted entirely.
+
+            return;
+        }
+
+        let mut rx = x_radius.abs();
+        let mut ry = y_radius.abs();
+
+        if rx == 0.0 || ry == 0.0 {
+            // An arc with a zero radius is treated as a straight line.
+
+            self.line_to(point);
+
+            return;
+        }
+
+        let (sin_phi, cos_phi) = rotation.to_radians().sin_cos();
+
+        let dx = (x1 - x2) / 2.0;
+        let dy = (y1 - y2) / 2.0;
+
+        let x1p = cos_phi * dx + sin_phi * dy;
+        let y1p = -sin_phi * dx + cos_phi * dy;
+
+        // Scale up radii that are too small to span the endpoints.
+
+        let lambda = (x1p * x1p) / (rx * rx) + (y1p * y1p) / (ry * ry);
+
+        if lambda > 1.0 {
+            rx *= lambda.sqrt();
+            ry *= lambda.sqrt();
+        }
+
+        let numerator = rx * rx * ry * ry - rx * rx * y1p * y1p - ry * ry * x1p * x1p;
+        let denominator = rx * rx * y1p * y1p + ry * ry * x1p * x1p;
+
+        let mut coefficient = (numerator / denominator).max(0.0).sqrt();
+
+        if is_large_arc == is_sweep {
+            coefficient = -coefficient;
+        }
+
+        let cxp = coefficient * rx * y1p / ry;
+        let cyp = -coefficient * ry * x1p / rx;
+
+        let cx = cos_phi * cxp - sin_phi * cyp + (x1 + x2) / 2.0;
+        let cy = sin_phi * cxp + cos_phi * cyp + (y1 + y2) / 2.0;
+
+        let angle =
+            |ux: f32, uy: f32, vx: f32, vy: f32| (ux * vy - uy * vx).atan2(ux * vx + uy * vy);
+
+        let start_angle = angle(1.0, 0.0, (x1p - cxp) / rx, (y1p - cyp) / ry);
+
+        let mut sweep_angle = angle(
+            (x1p - cxp) / rx,
+            (y1p - cyp) / ry,
+            (-x1p - cxp) / rx,
+            (-y1p - cyp) / ry,
+        );
+
+        if !is_sweep && sweep_angle > 0.0 {
+            sweep_angle -= 2.0 * PI;
+        } else if is_sweep && sweep_angle < 0.0 {
+            sweep_angle += 2.0 * PI;
+        }
+
+        // Each quarter turn or part thereof is approximated by a single cubic Bézier curve.
+
+        let count = (sweep_angle.abs() / FRAC_PI_2).ceil().max(1.0) as usize;
+
+        let delta = sweep_angle / count as f32;
+
+        let t = 4.0 / 3.0 * (delta / 4.0).tan();
+
+        let map = |ux: f32, uy: f32| {
+            (
+                cx + rx * ux * cos_phi - ry * uy * sin_phi,
+                cy + rx * ux * sin_phi + ry * uy * cos_phi,
+            )
+        };
+
+        for index in 0..count {
+            let (sin1, cos1) = (start_angle + delta * index as f32).sin_cos();
+            let (sin2, cos2) = (start_angle + delta * (index + 1) as f32).sin_cos();
+
+            let end = if index + 1 == count {
+                // Use the exact endpoint for the final curve to avoid accumulating rounding errors.
+
+                point
+            } else {
+                map(cos2, sin2)
+            };
+
+            self.cubic_to(
+                map(cos1 - t * sin1, sin1 + t * cos1),
+                map(cos2 + t * sin2, sin2 - t * cos2),
+                end,
+            );
+        }
+    }
+
+    fn close(&mut self) {
+        if !self.commands.is_empty() {
+            self.commands.push(PdfPathCommand::Close);
+            self.contours.push(PdfPathContour {
+                commands: std::mem::take(&mut self.commands),
+            });
+        }
+
+        self.current = self.start;
+    }
+
+    /// Returns the reflection of the given control point about the current point,
+    /// or the current point itself if there is no control point to reflect.
+    fn reflect(&self, control: Option<(f32, f32)>) -> (f32, f32) {
+        match control {
+            Some((x, y)) => (2.0 * self.current.0 - x, 2.0 * self.current.1 - y),
+            None => self.current,
+        }
+    }
+
+    #[inline]
+    fn orient(&self, (x, y): (f32, f32)) -> (f32, f32) {
+        (x, self.orientation.apply_to_y(y))
+    }
+
+    #[inline]
+    fn error(&self) -> PdfiumError {
+        PdfiumError::SvgPathDataSyntaxError(self.position)
+    }
+
+    /// Skips any whitespace and at most one comma.
+    fn skip_separators(&mut self) {
+        self.skip_whitespace();
+
+        if self.data.get(self.position) == Some(&b',') {
+            self.position += 1;
+
+            self.skip_whitespace();
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        while matches!(self.data.get(self.position), Some(byte) if byte.is_ascii_whitespace()) {
+            self.position += 1;
+        }
+    }
+
+    /// Returns `true` if the next non-separator character could begin a number.
+    fn is_at_number(&self) -> bool {
+        matches!(
+            self.data.get(self.position),
+            Some(byte) if byte.is_ascii_digit() || matches!(byte, b'+' | b'-' | b'.')
+        )
+    }
+
+    /// Parses a coordinate pair, offset by the given origin.
+    fn point(&mut self, origin: (f32, f32)) -> Result<(f32, f32), PdfiumError> {
+        let x = self.number()?;
+        let y = self.number()?;
+
+        Ok((x + origin.0, y + origin.1))
+    }
+
+    fn number(&mut self) -> Result<f32, PdfiumError> {
+        self.skip_separators();
+
+        let start = self.position;
+
+        let mut end = start;
+
+        if matches!(self.data.get(end), Some(b'+' | b'-')) {
+            end += 1;
+        }
+
+        let digits_start = end;
+
+        while matches!(self.data.get(end), Some(byte) if byte.is_ascii_digit()) {
+            end += 1;
+        }
+
+        let mut digit_count = end - digits_start;
+
+        if self.data.get(end) == Some(&b'.') {
+            end += 1;
+
+            let fraction_start = end;
+
+            while matches!(self.data.get(end), Some(byte) if byte.is_ascii_digit()) {
+                end += 1;
+            }
+
+            digit_count += end - fraction_start;
+        }
+
+        if digit_count == 0 {
+            return Err(self.error());
+        }
+
+        // An exponent is only consumed if it is complete, so that path data such as "1e"
+        // is reported as an error at the letter rather than within the number.
+
+        if matches!(self.data.get(end), Some(b'e' | b'E')) {
+            let mut exponent_end = end + 1;
+
+            if matches!(self.data.get(exponent_end), Some(b'+' | b'-')) {
+                exponent_end += 1;
+            }
+
+            let exponent_digits_start = exponent_end;
+
+            while matches!(self.data.get(exponent_end), Some(byte) if byte.is_ascii_digit()) {
+                exponent_end += 1;
+            }
+
+            if exponent_end > exponent_digits_start {
+                end = exponent_end;
+            }
+        }
+
+        // The bytes between start and end are all ASCII, so they are valid UTF-8.
+
+        let value = std::str::from_utf8(&self.data[start..end])
+            .ok()
+            .and_then(|text| text.parse::<f32>().ok())
+            .filter(|value| value.is_finite())
+            .ok_or(PdfiumError::SvgPathDataSyntaxError(start))?;
+
+        self.position = end;
+
+        Ok(value)
+    }
+
+    /// Parses an arc flag, which is a single `0` or `1` character that need not be
+    /// separated from the following argument.
+    fn flag(&mut self) -> Result<bool, PdfiumError> {
+        self.skip_separators();
+
+        let result = match self.data.get(self.position) {
+            Some(b'0') => false,
+            Some(b'1') => true,
+            _ => return Err(self.error()),
+        };
+
+        self.position += 1;
+
+        Ok(result)
+    }
+}
+
+/// Formats the given value with at most three decimal places, omitting any trailing zeros.
+fn format_number(value: f32) -> String {
+    let formatted = format!("{:.3}", value);
+
+    let formatted = formatted.trim_end_matches('0').trim_end_matches('.');
+
+    if formatted == "-0" {
+        "0".to_string()
+    } else {
+        formatted.to_string()
+    }
+}
+
+#[inline]
+fn quadratic_at(p0: f32, p1: f32, p2: f32, t: f32) -> f32 {
+    let mt = 1.0 - t;
+
+    mt * mt * p0 + 2.0 * mt * t * p1 + t * t * p2
+}
+
+#[inline]
+fn cubic_at(p0: f32, p1: f32, p2: f32, p3: f32, t: f32) -> f32 {
+    let mt = 1.0 - t;
+
+    mt * mt * mt * p0 + 3.0 * mt * mt * t * p1 + 3.0 * mt * t * t * p2 + t * t * t * p3
+}
+
+/// Returns the parameter value strictly inside the given quadratic Bézier curve at which
+/// its derivative is zero, if any.
+fn quadratic_extrema(p0: f32, p1: f32, p2: f32) -> [Option<f32>; 1] {
+    let denominator = p0 - 2.0 * p1 + p2;
+
+    if denominator == 0.0 {
+        [None]
+    } else {
+        [Some((p0 - p1) / denominator).filter(|t| *t > 0.0 && *t < 1.0)]
+    }
+}
+
+/// Returns the parameter values strictly inside the given cubic Bézier curve at which
+/// its derivative is zero, if any.
+fn cubic_extrema(p0: f32, p1: f32, p2: f32, p3: f32) -> [Option<f32>; 2] {
+    let a = -p0 + 3.0 * p1 - 3.0 * p2 + p3;
+
+    let b = 2.0 * (p0 - 2.0 * p1 + p2);
+
+    let c = p1 - p0;
+
+    let inside = |t: f32| Some(t).filter(|t| *t > 0.0 && *t < 1.0);
+
+    if a.abs() < f32::EPSILON {
+        if b.abs() < f32::EPSILON {
+            [None, None]
+        } else {
+            [inside(-c / b), None]
+        }
+    } else {
+        let discriminant = b * b - 4.0 * a * c;
+
+        if discriminant < 0.0 {
+            [None, None]
+        } else {
+            let root = discriminant.sqrt();
+
+            [
+                inside((-b + root) / (2.0 * a)),
+                inside((-b - root) / (2.0 * a)),
+            ]
+        }
+    }
+}
+
+/// The maximum number of times a single curve will be subdivided when flattening.
+const MAXIMUM_FLATTENING_DEPTH: u32 = 16;
+
+/// Appends points approximating the given cubic Bézier curve, excluding its starting point,
+/// to the given polyline, recursively subdividing the curve until it is flat enough.
+fn flatten_cubic(
+    p0: (f32, f32),
+    c1: (f32, f32),
+    c2: (f32, f32),
+    p3: (f32, f32),
+    tolerance: f32,
+    depth: u32,
+    points: &mut Vec<(f32, f32)>,
+) {
+    let is_flat = distance_to_segment(c1, p0, p3) <= tolerance
+        && distance_to_segment(c2, p0, p3) <= tolerance;
+
+    if is_flat || depth >= MAXIMUM_FLATTENING_DEPTH {
+        points.push(p3);
+
+        return;
+    }
+
+    // Split the curve in half using de Casteljau's algorithm.
+
+    let midpoint = |a: (f32, f32), b: (f32, f32)| ((a.0 + b.0) / 2.0, (a.1 + b.1) / 2.0);
+
+    let p01 = midpoint(p0, c1);
+    let p12 = midpoint(c1, c2);
+    let p23 = midpoint(c2, p3);
+    let p012 = midpoint(p01, p12);
+    let p123 = midpoint(p12, p23);
+    let p0123 = midpoint(p012, p123);
+
+    flatten_cubic(p0, p01, p012, p0123, tolerance, depth + 1, points);
+    flatten_cubic(p0123, p123, p23, p3, tolerance, depth + 1, points);
+}
+
+/// Returns the shortest distance from the given point to the line segment between
+/// the given start and end points.
+pub(crate) fn distance_to_segment(point: (f32, f32), start: (f32, f32), end: (f32, f32)) -> f32 {
+    let dx = end.0 - start.0;
+    let dy = end.1 - start.1;
+
+    let length_squared = dx * dx + dy * dy;
+
+    let t = if length_squared == 0.0 {
+        0.0
+    } else {
+        (((point.0 - start.0) * dx + (point.1 - start.1) * dy) / length_squared).clamp(0.0, 1.0)
+    };
+
+    let nearest = (start.0 + t * dx, start.1 + t * dy);
+
+    ((point.0 - nearest.0).powi(2) + (point.1 - nearest.1).powi(2)).sqrt()
+}
+
+/// Returns `true` if the given point lies inside the area enclosed by the given polylines,
+/// as returned by [PdfPathOutline::flatten()]. Every polyline is treated as closed.
+///
+/// If `is_even_odd` is `true`, the point is inside if a ray from the point crosses the
+/// polylines an odd number of times; otherwise, the point is inside if the polylines
+/// wind around the point a non-zero number of times.
+pub(crate) fn polylines_contain_point(
+    polylines: &[(Vec<(f32, f32)>, bool)],
+    point: (f32, f32),
+    is_even_odd: bool,
+) -> bool {
+    let mut crossings = 0;
+
+    let mut winding = 0;
+
+    for (points, _) in polylines.iter() {
+        for (index, start) in points.iter().enumerate() {
+            let end = points[(index + 1) % points.len()];
+
+            if (start.1 <= point.1) == (end.1 <= point.1) {
+                // This edge does not cross the horizontal ray extending from the point.
+
+                continue;
+            }
+
+            let x = start.0 + (point.1 - start.1) / (end.1 - start.1) * (end.0 - start.0);
+
+            if x > point.0 {
+                crossings += 1;
+                winding += if end.1 > start.1 { 1 } else { -1 };
+            }
+        }
+    }
+
+    if is_even_odd {
+        crossings % 2 == 1
+    } else {
+        winding != 0
+    }
+}
+
+/// Returns the shortest distance from the given point to any line segment in the given
+/// polylines, as returned by [PdfPathOutline::flatten()], or `None` if there are no line
+/// segments. Polylines consisting of a single point contain no line segments.
+pub(crate) fn polylines_distance_to_point(
+    polylines: &[(Vec<(f32, f32)>, bool)],
+    point: (f32, f32),
+) -> Option<f32> {
+    polylines
+        .iter()
+        .filter(|(points, _)| points.len() > 1)
+        .flat_map(|(points, is_closed)| {
+            let edges = if *is_closed {
+                points.len()
+            } else {
+                points.len() - 1
+            };
+
+            (0..edges).map(move |index| {
+                distance_to_segment(point, points[index], points[(index + 1) % points.len()])
+            })
+        })
+        .reduce(f32::min)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+    use crate::utils::test::test_bind_to_pdfium;
+
+    /// Returns the segments Pdfium reports for a rounded, closed contour made from two
+    /// degree-elevated quadratic curves and one true cubic curve, followed by an open
+    /// straight-line contour.
+    fn test_points() -> Vec<(PdfPathSegmentType, f32, f32, bool)> {
+        vec![
+            (PdfPathSegmentType::MoveTo, 0.0, 0.0, false),
+            (PdfPathSegmentType::LineTo, 300.0, 0.0, false),
+            // Quadratic curve from (300, 0) to (600, 300) with control point (600, 0).
+            (PdfPathSegmentType::BezierTo, 500.0, 0.0, false),
+            (PdfPathSegmentType::BezierTo, 600.0, 100.0, false),
+            (PdfPathSegmentType::BezierTo, 600.0, 300.0, false),
+            // Quadratic curve from (600, 300) to (300, 600) with control point (600, 600).
+            (PdfPathSegmentType::BezierTo, 600.0, 500.0, false),
+            (PdfPathSegmentType::BezierTo, 500.0, 600.0, false),
+            (PdfPathSegmentType::BezierTo, 300.0, 600.0, false),
+            // A true cubic curve back towards the start of the contour.
+            (PdfPathSegmentType::BezierTo, 100.0, 600.0, false),
+            (PdfPathSegmentType::BezierTo, 0.0, 200.0, false),
+            (PdfPathSegmentType::BezierTo, 0.0, 0.0, true),
+            (PdfPathSegmentType::MoveTo, 100.0, 700.0, false),
+            (PdfPathSegmentType::LineTo, 200.0, 750.5, false),
+        ]
+    }
+
+    #[test]
+    fn test_outline_resolves_contours_and_curves() {
+        let outline = PdfPathOutline::from_points(test_points());
+
+        assert_eq!(outline.contours().len(), 2);
+        assert!(outline.contours()[0].is_closed());
+        assert!(!outline.contours()[1].is_closed());
+
+        let commands = outline.commands().collect::<Vec<_>>();
+
+        assert_eq!(commands.len(), 8);
+        assert_eq!(
+            commands[2],
+            PdfPathCommand::QuadTo {
+                x1: 600.0,
+                y1: 0.0,
+                x: 600.0,
+                y: 300.0
+            }
+        );
+        assert_eq!(
+            commands[4],
+            PdfPathCommand::CubicTo {
+                x1: 100.0,
+                y1: 600.0,
+                x2: 0.0,
+                y2: 200.0,
+                x: 0.0,
+                y: 0.0
+            }
+        );
+        assert_eq!(commands[5], PdfPathCommand::Close);
+    }
+
+    #[test]
+    fn test_outline_svg_path_data() {
+        let outline = PdfPathOutline::from_points(test_points());
+
+        assert_eq!(
+            outline.to_svg_path_data(PdfPathOrientation::PdfYUp),
+            "M0 0 L300 0 Q600 0 600 300 Q600 600 300 600 C100 600 0 200 0 0 Z M100 700 L200 750.5"
+        );
+
+        assert_eq!(
+            outline.to_svg_path_data(PdfPathOrientation::SvgYDown { height: 1000.0 }),
+            "M0 1000 L300 1000 Q600 1000 600 700 Q600 400 300 400 C100 400 0 800 0 1000 Z M100 300 L200 249.5"
+        );
+    }
+
+    #[test]
+    fn test_outline_from_svg_path_data() -> Result<(), PdfiumError> {
+        let outline = PdfPathOutline::from_points(test_points());
+
+        // Parsing the output of to_svg_path_data() should recreate the original outline,
+        // in either orientation.
+
+        for orientation in [
+            PdfPathOrientation::PdfYUp,
+            PdfPathOrientation::SvgYDown { height: 1000.0 },
+        ] {
+            assert_eq!(
+                PdfPathOutline::from_svg_path_data(
+                    &outline.to_svg_path_data(orientation),
+                    orientation
+                )?,
+                outline
+            );
+        }
+
+        // Relative commands, implicit command repetition, horizontal and vertical lines,
+        // and smooth curves should all resolve to the same absolute commands.
+
+        let absolute = PdfPathOutline::from_svg_path_data(
+            "M 10 10 L 20 10 L 20 20 L 30 20 C 40 20 40 30 50 30 C 60 30 60 40 70 40 \
+                Q 80 40 80 50 Q 80 60 90 60 Z",
+            PdfPathOrientation::PdfYUp,
+        )?;
+
+        let relative = PdfPathOutline::from_svg_path_data(
+            "m10,10h10v10h10c10,0 10,10 20,10s10,10 20,10q10,0 10,10t10,10z",
+            PdfPathOrientation::PdfYUp,
+        )?;
+
+        assert_eq!(relative, absolute);
+
+        // Additional coordinate pairs following a move-to are treated as line-tos, and
+        // drawing after a closed contour begins a new contour at the same start point.
+
+        let outline = PdfPathOutline::from_svg_path_data(
+            "M0 0 10 0 10 10z l-5 5",
+            PdfPathOrientation::PdfYUp,
+        )?;
+
+        assert_eq!(
+            outline.to_svg_path_data(PdfPathOrientation::PdfYUp),
+            "M0 0 L10 0 L10 10 Z M0 0 L-5 5"
+        );
+
+        assert!(PdfPathOutline::from_svg_path_data("  ", PdfPathOrientation::PdfYUp)?.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_outline_from_svg_path_data_arc() -> Result<(), PdfiumError> {
+        // A clockwise semicircle in SVG's y-down coordinate space passes above its
+        // endpoints, i.e. through negative y values.
+
+        let outline = PdfPathOutline::from_svg_path_data(
+            "M 0 0 A 10 10 0 0 1 20 0",
+            PdfPathOrientation::PdfYUp,
+        )?;
+
+        let commands = outline.commands().collect::<Vec<_>>();
+
+        assert_eq!(commands.len(), 3);
+
+        match (commands[1], commands[2]) {
+            (
+                PdfPathCommand::CubicTo { x: x1, y: y1, .. },
+                PdfPathCommand::CubicTo { x: x2, y: y2, .. },
+            ) => {
+                assert!((x1 - 10.0).abs() < 0.001);
+                assert!((y1 + 10.0).abs() < 0.001);
+                assert_eq!((x2, y2), (20.0, 0.0));
+            }
+            _ => panic!("expected arc to be approximated by two cubic curves"),
+        }
+
+        // An arc with a zero radius is a straight line.
+
+        assert_eq!(
+            PdfPathOutline::from_svg_path_data(
+                "M 0 0 a 0 10 0 1 0 20 0",
+                PdfPathOrientation::PdfYUp
+            )?
+            .to_svg_path_data(PdfPathOrientation::PdfYUp),
+            "M0 0 L20 0"
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_outline_from_svg_path_data_errors() {
+        let error_offset =
+            |data: &str| match PdfPathOutline::from_svg_path_data(data, PdfPathOrientation::PdfYUp)
+            {
+                Err(PdfiumError::SvgPathDataSyntaxError(offset)) => Some(offset),
+                _ => None,
+            };
+
+        assert_eq!(error_offset("M 10 10 L 20 x"), Some(13));
+        assert_eq!(error_offset("M 10 10 X 5"), Some(8));
+        assert_eq!(error_offset("L 10 10"), Some(0));
+        assert_eq!(error_offset("M 10"), Some(4));
+        assert_eq!(error_offset("M 10 10 Z 5"), Some(10));
+        assert_eq!(error_offset("M 0 0 A 5 5 0 2 0 10 10"), Some(14));
+        assert_eq!(error_offset("M 1e 2"), Some(3));
+        assert_eq!(error_offset("M 1e2 2"), None);
+    }
+
+    #[test]
+    fn test_outline_bounds() {
+        let outline = PdfPathOutline::from_points(test_points());
+
+        let bounds = outline.bounds();
+
+        assert_eq!(bounds.left(), PdfPoints::new(0.0));
+        assert_eq!(bounds.bottom(), PdfPoints::new(0.0));
+        assert_eq!(bounds.right(), PdfPoints::new(600.0));
+        assert_eq!(bounds.top(), PdfPoints::new(750.5));
+
+        assert_eq!(
+            PdfPathOutline::from_points(Vec::new()).bounds(),
+            PdfRect::zero()
+        );
+    }
+
+    #[test]
+    fn test_outline_flatten() {
+        let outline = PdfPathOutline::from_points(test_points());
+
+        let polylines = outline.flatten(0.1);
+
+        assert_eq!(polylines.len(), 2);
+        assert!(polylines[0].1);
+        assert!(!polylines[1].1);
+        assert_eq!(polylines[1].0, vec![(100.0, 700.0), (200.0, 750.5)]);
+
+        assert!(polylines[0].0.len() > 10);
+        assert_eq!(polylines[0].0.last(), Some(&(0.0, 0.0)));
+        assert!(polylines_contain_point(&polylines, (300.0, 300.0), false));
+        assert!(!polylines_contain_point(&polylines, (590.0, 590.0), false));
+        assert_eq!(
+            polylines_distance_to_point(&polylines, (150.0, 700.0)),
+            Some(distance_to_segment(
+                (150.0, 700.0),
+                (100.0, 700.0),
+                (200.0, 750.5)
+            ))
+        );
+    }
+
+    #[test]
+    fn test_glyph_outline_svg_path_data() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let document = pdfium.load_pdf_from_file("./test/text-test.pdf", None)?;
+
+        let page = document.pages().first()?;
+
+        let object = page
+            .objects()
+            .iter()
+            .find(|object| object.as_text_object().is_some())
+            .unwrap();
+
+        let font = object.as_text_object().unwrap().font();
+
+        assert!(font.is_embedded()?);
+
+        // Extract the outline of the glyph at 1000 units per em.
+
+        let outline = font
+            .glyph_for_char('o')
+            .unwrap()
+            .outline_at_font_size(PdfPoints::new(1000.0))?;
+
+        assert!(!outline.is_empty());
+        assert!(outline.contours().iter().all(|contour| contour.is_closed()));
+
+        let bounds = outline.bounds();
+
+        assert!(bounds.width().value > 0.0 && bounds.width().value < 1000.0);
+        assert!(bounds.height().value > 0.0 && bounds.height().value < 1000.0);
+
+        let data = outline.to_svg_path_data(PdfPathOrientation::SvgYDown { height: 1000.0 });
+
+        assert!(data.starts_with('M'));
+        assert!(data.ends_with('Z'));
+        assert_eq!(
+            data.matches(|c: char| c.is_ascii_alphabetic()).count(),
+            outline.commands().count()
+        );
+
+        // A closed glyph outline must snapshot identically when exported twice.
+
+        assert_eq!(
+            data,
+            PdfPathOutline::from_segments(
+                &font
+                    .glyph_for_char('o')
+                    .unwrap()
+                    .segments_at_font_size(PdfPoints::new(1000.0))?
+            )
+            .to_svg_path_data(PdfPathOrientation::SvgYDown { height: 1000.0 })
+        );
+
+        Ok(())
+    }
+}
@@ -15,6 +15,7 @@ use {
 /// device-independent unit equal to 1/72 inches, roughly 0.358 mm. Points are converted to pixels
 /// when a [PdfPage] is rendered into a [PdfBitmap].
 #[derive(Debug, Copy, Clone, PartialOrd, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize), serde(transparent))]
 pub struct PdfPoints {
     pub value: f32,
 }
@@ -29,6 +30,16 @@ impl PdfPoints {
     /// A [PdfPoints] object with the smallest addressable finite negative value.
     pub const MIN: PdfPoints = PdfPoints::min();
 
+    // Conversions are performed in a single multiplication and division rather than by
+    // chaining through intermediate units, so that round trips lose as little precision
+    // as possible.
+
+    const POINTS_PER_INCH: f32 = 72.0;
+
+    const CM_PER_INCH: f32 = 2.54;
+
+    const MM_PER_INCH: f32 = 25.4;
+
     /// Creates a new [PdfPoints] object with the given value.
     #[inline]
     pub const fn new(value: f32) -> Self {
@@ -69,37 +80,37 @@ impl PdfPoints {
     /// Creates a new [PdfPoints] object from the given measurement in inches.
     #[inline]
     pub fn from_inches(inches: f32) -> Self {
-        Self::new(inches * 72.0)
+        Self::new(inches * Self::POINTS_PER_INCH)
     }
 
     /// Creates a new [PdfPoints] object from the given measurement in centimeters.
     #[inline]
     pub fn from_cm(cm: f32) -> Self {
-        Self::from_inches(cm / 2.54)
+        Self::new(cm * Self::POINTS_PER_INCH / Self::CM_PER_INCH)
     }
 
     /// Creates a new [PdfPoints] object from the given measurement in millimeters.
     #[inline]
     pub fn from_mm(mm: f32) -> Self {
-        Self::from_cm(mm / 10.0)
+        Self::new(mm * Self::POINTS_PER_INCH / Self::MM_PER_INCH)
     }
 
     /// Converts the value of this [PdfPoints] object to inches.
     #[inline]
     pub fn to_inches(&self) -> f32 {
-        self.value / 72.0
+        self.value / Self::POINTS_PER_INCH
     }
 
     /// Converts the value of this [PdfPoints] object to centimeters.
     #[inline]
     pub fn to_cm(&self) -> f32 {
-        self.to_inches() * 2.54
+        self.value * Self::CM_PER_INCH / Self::POINTS_PER_INCH
     }
 
     /// Converts the value of this [PdfPoints] object to millimeters.
     #[inline]
     pub fn to_mm(&self) -> f32 {
-        self.to_cm() * 10.0
+        self.value * Self::MM_PER_INCH / Self::POINTS_PER_INCH
     }
 
     /// Creates a new [PdfPoints] object with the absolute value of this [PdfPoints] object.
@@ -205,4 +216,34 @@ mod tests {
         assert_eq!(PdfPoints::ZERO, -PdfPoints::ZERO);
         assert!(PdfPoints::ZERO > PdfPoints::new(-1.0));
     }
+
+    #[test]
+    fn test_points_unit_conversions() {
+        assert_eq!(PdfPoints::from_inches(1.0).value, 72.0);
+        assert_eq!(PdfPoints::from_cm(2.54).value, 72.0);
+        assert_eq!(PdfPoints::from_mm(25.4).value, 72.0);
+        assert_eq!(PdfPoints::from_mm(-25.4).value, -72.0);
+
+        assert_eq!(PdfPoints::new(72.0).to_inches(), 1.0);
+        assert_eq!(PdfPoints::new(72.0).to_cm(), 2.54);
+        assert_eq!(PdfPoints::new(72.0).to_mm(), 25.4);
+        assert_eq!(PdfPoints::new(-36.0).to_inches(), -0.5);
+
+        // An A4 page is 210 mm x 297 mm.
+
+        assert!((PdfPoints::from_mm(210.0).value - 595.2756).abs() < 0.0001);
+        assert!((PdfPoints::from_mm(297.0).to_mm() - 297.0).abs() < 0.0001);
+    }
+
+    #[test]
+    fn test_points_arithmetic() {
+        let a = PdfPoints::new(10.0);
+        let b = PdfPoints::new(-4.0);
+
+        assert_eq!(a + b, PdfPoints::new(6.0));
+        assert_eq!(a - b, PdfPoints::new(14.0));
+        assert_eq!(b * 2.5, PdfPoints::new(-10.0));
+        assert_eq!(a / 4.0, PdfPoints::new(2.5));
+        assert!(b < a);
+    }
 }
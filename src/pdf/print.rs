@@ -0,0 +1,69 @@
+//! Defines the [PdfPrintMode] enum, controlling the output Pdfium generates when rendering
+//! pages to a Windows printer device context.
+
+use crate::bindgen::{
+    FPDF_PRINTMODE_EMF, FPDF_PRINTMODE_EMF_IMAGE_MASKS, FPDF_PRINTMODE_POSTSCRIPT2,
+    FPDF_PRINTMODE_POSTSCRIPT2_PASSTHROUGH, FPDF_PRINTMODE_POSTSCRIPT3,
+    FPDF_PRINTMODE_POSTSCRIPT3_PASSTHROUGH, FPDF_PRINTMODE_POSTSCRIPT3_TYPE42,
+    FPDF_PRINTMODE_POSTSCRIPT3_TYPE42_PASSTHROUGH, FPDF_PRINTMODE_TEXTONLY,
+};
+use std::os::raw::c_int;
+
+#[cfg(doc)]
+use crate::{pdf::document::page::PdfPage, pdfium::Pdfium};
+
+/// The output Pdfium should generate when rendering a [PdfPage] to a printer device context
+/// using [PdfPage::render_to_hdc()]. Set the print mode using [Pdfium::set_print_mode()].
+///
+/// The PostScript modes allow vector output to be sent to PostScript printers without
+/// first being converted to EMF.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum PdfPrintMode {
+    /// Output EMF. This is Pdfium's default print mode.
+    Emf,
+
+    /// Output text only, for character stream devices.
+    TextOnly,
+
+    /// Output level 2 PostScript into EMF as a series of GDI comments.
+    PostScript2,
+
+    /// Output level 3 PostScript into EMF as a series of GDI comments.
+    PostScript3,
+
+    /// Output level 2 PostScript via `ExtEscape()` in `PASSTHROUGH` mode.
+    PostScript2Passthrough,
+
+    /// Output level 3 PostScript via `ExtEscape()` in `PASSTHROUGH` mode.
+    PostScript3Passthrough,
+
+    /// Output EMF, with more efficient processing of documents containing image masks.
+    EmfImageMasks,
+
+    /// Output level 3 PostScript with embedded Type 42 fonts, when applicable,
+    /// into EMF as a series of GDI comments.
+    PostScript3Type42,
+
+    /// Output level 3 PostScript with embedded Type 42 fonts, when applicable,
+    /// via `ExtEscape()` in `PASSTHROUGH` mode.
+    PostScript3Type42Passthrough,
+}
+
+impl PdfPrintMode {
+    #[inline]
+    pub(crate) fn as_pdfium(&self) -> c_int {
+        (match self {
+            PdfPrintMode::Emf => FPDF_PRINTMODE_EMF,
+            PdfPrintMode::TextOnly => FPDF_PRINTMODE_TEXTONLY,
+            PdfPrintMode::PostScript2 => FPDF_PRINTMODE_POSTSCRIPT2,
+            PdfPrintMode::PostScript3 => FPDF_PRINTMODE_POSTSCRIPT3,
+            PdfPrintMode::PostScript2Passthrough => FPDF_PRINTMODE_POSTSCRIPT2_PASSTHROUGH,
+            PdfPrintMode::PostScript3Passthrough => FPDF_PRINTMODE_POSTSCRIPT3_PASSTHROUGH,
+            PdfPrintMode::EmfImageMasks => FPDF_PRINTMODE_EMF_IMAGE_MASKS,
+            PdfPrintMode::PostScript3Type42 => FPDF_PRINTMODE_POSTSCRIPT3_TYPE42,
+            PdfPrintMode::PostScript3Type42Passthrough => {
+                FPDF_PRINTMODE_POSTSCRIPT3_TYPE42_PASSTHROUGH
+            }
+        }) as c_int
+    }
+}
@@ -279,6 +279,106 @@ impl PdfQuadPoints {
         )
     }
 
+    /// Returns the smallest axis-aligned [PdfRect] that can completely enclose the
+    /// quadrilateral outlined by this [PdfQuadPoints].
+    ///
+    /// This is identical to [PdfQuadPoints::to_rect()]. For a quadrilateral that is rotated
+    /// or skewed, the returned rectangle will be larger than the quadrilateral itself.
+    #[inline]
+    pub fn to_axis_aligned_rect(&self) -> PdfRect {
+        self.to_rect()
+    }
+
+    /// Returns the area of the quadrilateral outlined by this [PdfQuadPoints],
+    /// in square points.
+    ///
+    /// The vertices are expected to outline a simple (non-self-intersecting) quadrilateral.
+    /// The area is always positive, regardless of whether the vertices are given in
+    /// clockwise or counter-clockwise order.
+    pub fn area(&self) -> f32 {
+        // The shoelace formula gives the area of any simple polygon from its vertices.
+
+        let vertices = self.vertices();
+
+        let twice_area: f32 = (0..4)
+            .map(|index| {
+                let (x1, y1) = vertices[index];
+                let (x2, y2) = vertices[(index + 1) % 4];
+
+                x1 * y2 - x2 * y1
+            })
+            .sum();
+
+        twice_area.abs() / 2.0
+    }
+
+    /// Returns `true` if the quadrilateral outlined by this [PdfQuadPoints] at least partially
+    /// overlaps the given rectangle.
+    ///
+    /// Unlike testing the rectangle against the result of [PdfQuadPoints::to_rect()],
+    /// this test is exact for rotated and skewed quadrilaterals. The vertices are expected
+    /// to outline a convex quadrilateral.
+    pub fn does_overlap_rect(&self, rect: &PdfRect) -> bool {
+        // By the separating axis theorem, two convex polygons do not overlap if and only if
+        // there is an axis, perpendicular to one of the edges of either polygon, onto which
+        // the projections of the two polygons do not overlap.
+
+        let quad = self.vertices();
+
+        let rect = [
+            (rect.left().value, rect.bottom().value),
+            (rect.right().value, rect.bottom().value),
+            (rect.right().value, rect.top().value),
+            (rect.left().value, rect.top().value),
+        ];
+
+        let project = |vertices: &[(f32, f32); 4], (axis_x, axis_y): (f32, f32)| {
+            vertices
+                .iter()
+                .map(|(x, y)| x * axis_x + y * axis_y)
+                .fold((f32::INFINITY, f32::NEG_INFINITY), |(min, max), value| {
+                    (min.min(value), max.max(value))
+                })
+        };
+
+        let axes = (0..4)
+            .map(|index| {
+                let (x1, y1) = quad[index];
+                let (x2, y2) = quad[(index + 1) % 4];
+
+                (y1 - y2, x2 - x1)
+            })
+            .chain([(1.0, 0.0), (0.0, 1.0)]);
+
+        for axis in axes {
+            if axis == (0.0, 0.0) {
+                // Degenerate edges do not define an axis.
+
+                continue;
+            }
+
+            let (quad_min, quad_max) = project(&quad, axis);
+            let (rect_min, rect_max) = project(&rect, axis);
+
+            if quad_max <= rect_min || rect_max <= quad_min {
+                return false;
+            }
+        }
+
+        true
+    }
+
+    /// Returns the four vertices of this [PdfQuadPoints] as raw coordinate pairs.
+    #[inline]
+    fn vertices(&self) -> [(f32, f32); 4] {
+        [
+            (self.x1.value, self.y1.value),
+            (self.x2.value, self.y2.value),
+            (self.x3.value, self.y3.value),
+            (self.x4.value, self.y4.value),
+        ]
+    }
+
     #[inline]
     pub(crate) fn as_pdfium(&self) -> FS_QUADPOINTSF {
         FS_QUADPOINTSF {
@@ -380,17 +480,50 @@ mod tests {
 
         // It would be incredibly elegant to test
 
-        // assert_eq!(q45.transform(m.invert()).to_rect(), r);
+        // assert_eq!(q45.transform(m.invert().unwrap()).to_rect(), r);
 
         // but sadly floating point rounding errors means the double-transformed values
         // are ever-so-slightly off (by a fraction of a PdfPoint). Let's test manually
         // so we can apply a comparison threshold.
 
-        let s = q45.transform(m.invert()).to_rect();
+        let s = q45.transform(m.invert().unwrap()).to_rect();
         let threshold = PdfPoints::new(0.001);
         assert!((s.top() - r.top()).abs() < threshold);
         assert!((s.bottom() - r.bottom()).abs() < threshold);
         assert!((s.left() - r.left()).abs() < threshold);
         assert!((s.right() - r.right()).abs() < threshold);
     }
+
+    #[test]
+    fn test_quadpoints_area_and_overlap() {
+        let r = PdfRect::new_from_values(0.0, 0.0, 10.0, 100.0);
+        assert_eq!(r.to_quad_points().area(), 1000.0);
+
+        // A thin rectangle rotated by 45 degrees covers a much larger axis-aligned rectangle.
+
+        let q45 = r.to_quad_points().transform(
+            PdfMatrix::identity()
+                .rotate_clockwise_degrees(45.0)
+                .unwrap(),
+        );
+
+        let r45 = q45.to_axis_aligned_rect();
+
+        assert!((q45.area() - 1000.0).abs() < 0.01);
+        assert!(r45.width().value * r45.height().value > 5.0 * q45.area());
+
+        // A rectangle in the top right corner of the axis-aligned bounds, away from the rotated
+        // quadrilateral, overlaps the bounds but not the quadrilateral itself.
+
+        let corner = PdfRect::new_from_values(
+            r45.top().value - 5.0,
+            r45.right().value - 5.0,
+            r45.top().value,
+            r45.right().value,
+        );
+
+        assert!(corner.does_overlap(&r45));
+        assert!(!q45.does_overlap_rect(&corner));
+        assert!(q45.does_overlap_rect(&r45));
+    }
 }
@@ -3,6 +3,7 @@
 use crate::bindgen::{FPDF_BOOL, FS_RECTF};
 use crate::bindings::PdfiumLibraryBindings;
 use crate::error::{PdfiumError, PdfiumInternalError};
+use crate::pdf::document::page::PdfPageRenderRotation;
 use crate::pdf::matrix::PdfMatrix;
 use crate::pdf::points::PdfPoints;
 use crate::pdf::quad_points::PdfQuadPoints;
@@ -165,6 +166,13 @@ impl PdfRect {
     #[inline]
     /// Returns `true` if the given point lies inside this [PdfRect].
     pub fn contains(&self, x: PdfPoints, y: PdfPoints) -> bool {
+        self.contains_point(x, y)
+    }
+
+    /// Returns `true` if the given point lies inside this [PdfRect]. Points lying exactly
+    /// on the edges of the rectangle are considered to be inside it.
+    #[inline]
+    pub fn contains_point(&self, x: PdfPoints, y: PdfPoints) -> bool {
         self.contains_x(x) && self.contains_y(y)
     }
 
@@ -201,6 +209,115 @@ impl PdfRect {
             && self.bottom() < other.top()
     }
 
+    /// Returns the horizontal and vertical coordinates of the center point of this [PdfRect].
+    #[inline]
+    pub fn center(&self) -> (PdfPoints, PdfPoints) {
+        (
+            (self.left() + self.right()) / 2.0,
+            (self.bottom() + self.top()) / 2.0,
+        )
+    }
+
+    /// Returns the smallest [PdfRect] that encloses both this rectangle and the given rectangle.
+    #[inline]
+    pub fn union(&self, other: &PdfRect) -> PdfRect {
+        PdfRect::new(
+            self.bottom().min(other.bottom()),
+            self.left().min(other.left()),
+            self.top().max(other.top()),
+            self.right().max(other.right()),
+        )
+    }
+
+    /// Returns the area shared by this [PdfRect] and the given rectangle, or `None` if
+    /// the two rectangles do not overlap. Rectangles that merely touch along an edge
+    /// or at a corner share no area, so `None` is also returned in this case.
+    pub fn intersect(&self, other: &PdfRect) -> Option<PdfRect> {
+        let result = PdfRect::new(
+            self.bottom().max(other.bottom()),
+            self.left().max(other.left()),
+            self.top().min(other.top()),
+            self.right().min(other.right()),
+        );
+
+        if result.width() > PdfPoints::ZERO && result.height() > PdfPoints::ZERO {
+            Some(result)
+        } else {
+            None
+        }
+    }
+
+    /// Returns a copy of this [PdfRect] with its left and right edges moved inwards by the
+    /// given horizontal distance and its top and bottom edges moved inwards by the given
+    /// vertical distance. Negative distances move the edges outwards.
+    ///
+    /// If the inset is larger than half the width or height of the rectangle, the edges
+    /// meet at the rectangle's center rather than crossing over each other.
+    pub fn inset(&self, dx: PdfPoints, dy: PdfPoints) -> PdfRect {
+        let (center_x, center_y) = self.center();
+
+        let left = self.left() + dx;
+        let right = self.right() - dx;
+        let bottom = self.bottom() + dy;
+        let top = self.top() - dy;
+
+        let (left, right) = if left > right {
+            (center_x, center_x)
+        } else {
+            (left, right)
+        };
+
+        let (bottom, top) = if bottom > top {
+            (center_y, center_y)
+        } else {
+            (bottom, top)
+        };
+
+        PdfRect::new(bottom, left, top, right)
+    }
+
+    /// Returns a copy of this [PdfRect] with its left and right edges moved outwards by the
+    /// given horizontal distance and its top and bottom edges moved outwards by the given
+    /// vertical distance. This is the reverse of [PdfRect::inset()].
+    #[inline]
+    pub fn expand(&self, dx: PdfPoints, dy: PdfPoints) -> PdfRect {
+        self.inset(-dx, -dy)
+    }
+
+    /// Returns a copy of this [PdfRect] moved by the given horizontal and vertical distances.
+    #[inline]
+    pub fn translate(&self, dx: PdfPoints, dy: PdfPoints) -> PdfRect {
+        PdfRect::new(
+            self.bottom() + dy,
+            self.left() + dx,
+            self.top() + dy,
+            self.right() + dx,
+        )
+    }
+
+    /// Returns a copy of this [PdfRect] scaled by the given factor, keeping the given
+    /// center point fixed in place. Pass the value returned by [PdfRect::center()] to
+    /// scale the rectangle about its own center.
+    pub fn scale_about(&self, center: (PdfPoints, PdfPoints), factor: f32) -> PdfRect {
+        let (x, y) = center;
+
+        let rect = PdfRect::new(
+            y + (self.bottom() - y) * factor,
+            x + (self.left() - x) * factor,
+            y + (self.top() - y) * factor,
+            x + (self.right() - x) * factor,
+        );
+
+        // A negative factor flips the rectangle, so its edges must be swapped back.
+
+        PdfRect::new(
+            rect.bottom().min(rect.top()),
+            rect.left().min(rect.right()),
+            rect.bottom().max(rect.top()),
+            rect.left().max(rect.right()),
+        )
+    }
+
     /// Returns the result of applying the given [PdfMatrix] to each corner point of this [PdfRect].
     #[inline]
     pub fn transform(&self, matrix: PdfMatrix) -> PdfRect {
@@ -217,6 +334,69 @@ impl PdfRect {
         )
     }
 
+    /// Returns the result of mapping this [PdfRect] from the coordinate space of an unrotated
+    /// `PdfPage` into the coordinate space of the same page displayed upright with the given
+    /// intrinsic clockwise rotation applied, as returned by `PdfPage::rotation()`.
+    ///
+    /// Pdfium reports the positions of page objects, text characters, and annotations in
+    /// the coordinate space of the unrotated page, but renders pages upright by default.
+    /// This function maps such positions onto the rendered page. [PdfRect::from_rotated_space()]
+    /// performs the reverse mapping, for example when hit-testing a position on the rendered page.
+    ///
+    /// The given width and height are those of the page before rotation. For a page with
+    /// an intrinsic rotation of 90 or 270 degrees, these are the values returned by
+    /// `PdfPage::height()` and `PdfPage::width()` respectively.
+    pub fn to_rotated_space(
+        &self,
+        rotation: PdfPageRenderRotation,
+        width: PdfPoints,
+        height: PdfPoints,
+    ) -> PdfRect {
+        let matrix = match rotation {
+            PdfPageRenderRotation::None => PdfMatrix::IDENTITY,
+            PdfPageRenderRotation::Degrees90 => {
+                PdfMatrix::new(0.0, -1.0, 1.0, 0.0, 0.0, width.value)
+            }
+            PdfPageRenderRotation::Degrees180 => {
+                PdfMatrix::new(-1.0, 0.0, 0.0, -1.0, width.value, height.value)
+            }
+            PdfPageRenderRotation::Degrees270 => {
+                PdfMatrix::new(0.0, 1.0, -1.0, 0.0, height.value, 0.0)
+            }
+        };
+
+        self.transform(matrix)
+    }
+
+    /// Returns the result of mapping this [PdfRect] from the coordinate space of a `PdfPage`
+    /// displayed upright with the given intrinsic clockwise rotation applied back into the
+    /// coordinate space of the unrotated page. This is the reverse of [PdfRect::to_rotated_space()].
+    ///
+    /// The given width and height are those of the page before rotation. For a page with
+    /// an intrinsic rotation of 90 or 270 degrees, these are the values returned by
+    /// `PdfPage::height()` and `PdfPage::width()` respectively.
+    pub fn from_rotated_space(
+        &self,
+        rotation: PdfPageRenderRotation,
+        width: PdfPoints,
+        height: PdfPoints,
+    ) -> PdfRect {
+        let matrix = match rotation {
+            PdfPageRenderRotation::None => PdfMatrix::IDENTITY,
+            PdfPageRenderRotation::Degrees90 => {
+                PdfMatrix::new(0.0, 1.0, -1.0, 0.0, width.value, 0.0)
+            }
+            PdfPageRenderRotation::Degrees180 => {
+                PdfMatrix::new(-1.0, 0.0, 0.0, -1.0, width.value, height.value)
+            }
+            PdfPageRenderRotation::Degrees270 => {
+                PdfMatrix::new(0.0, -1.0, 1.0, 0.0, 0.0, height.value)
+            }
+        };
+
+        self.transform(matrix)
+    }
+
     /// Returns the [PdfQuadPoints] quadrilateral representation of this [PdfRect].
     #[inline]
     pub fn to_quad_points(&self) -> PdfQuadPoints {
@@ -260,6 +440,24 @@ impl Hash for PdfRect {
     }
 }
 
+// Serialization is implemented by hand, rather than derived, so that serialized rectangles
+// do not depend on the deprecated public fields of PdfRect.
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for PdfRect {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeStruct;
+
+        let mut state = serializer.serialize_struct("PdfRect", 4)?;
+
+        state.serialize_field("left", &self.left())?;
+        state.serialize_field("bottom", &self.bottom())?;
+        state.serialize_field("right", &self.right())?;
+        state.serialize_field("top", &self.top())?;
+        state.end()
+    }
+}
+
 impl Display for PdfRect {
     #[inline]
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
@@ -307,6 +505,87 @@ mod tests {
             .does_overlap(&PdfRect::new_from_values(5.0, 8.0, 10.0, 10.0)));
     }
 
+    #[test]
+    fn test_rect_union_and_intersect() {
+        let a = PdfRect::new_from_values(-10.0, -20.0, 10.0, 20.0);
+        let b = PdfRect::new_from_values(0.0, 5.0, 30.0, 40.0);
+
+        assert_eq!(
+            a.union(&b),
+            PdfRect::new_from_values(-10.0, -20.0, 30.0, 40.0)
+        );
+        assert_eq!(
+            a.intersect(&b),
+            Some(PdfRect::new_from_values(0.0, 5.0, 10.0, 20.0))
+        );
+        assert_eq!(a.intersect(&b), b.intersect(&a));
+
+        // Disjoint rectangles, rectangles that only touch along an edge, and degenerate
+        // zero-area rectangles share no area.
+
+        assert_eq!(
+            a.intersect(&PdfRect::new_from_values(50.0, 50.0, 60.0, 60.0)),
+            None
+        );
+        assert_eq!(
+            a.intersect(&PdfRect::new_from_values(10.0, -20.0, 20.0, 20.0)),
+            None
+        );
+        assert_eq!(
+            a.intersect(&PdfRect::new_from_values(0.0, 0.0, 0.0, 5.0)),
+            None
+        );
+        assert_eq!(PdfRect::ZERO.intersect(&PdfRect::ZERO), None);
+
+        assert_eq!(
+            PdfRect::ZERO.union(&b),
+            PdfRect::new_from_values(0.0, 0.0, 30.0, 40.0)
+        );
+    }
+
+    #[test]
+    fn test_rect_inset_expand_and_translate() {
+        let rect = PdfRect::new_from_values(-10.0, -20.0, 10.0, 20.0);
+
+        assert_eq!(
+            rect.inset(PdfPoints::new(5.0), PdfPoints::new(2.0)),
+            PdfRect::new_from_values(-8.0, -15.0, 8.0, 15.0)
+        );
+        assert_eq!(
+            rect.expand(PdfPoints::new(5.0), PdfPoints::new(2.0)),
+            PdfRect::new_from_values(-12.0, -25.0, 12.0, 25.0)
+        );
+        assert_eq!(
+            rect.inset(PdfPoints::new(30.0), PdfPoints::new(30.0)),
+            PdfRect::ZERO
+        );
+        assert_eq!(
+            rect.translate(PdfPoints::new(-5.0), PdfPoints::new(100.0)),
+            PdfRect::new_from_values(90.0, -25.0, 110.0, 15.0)
+        );
+        assert_eq!(rect.center(), (PdfPoints::ZERO, PdfPoints::ZERO));
+        assert!(rect.contains_point(PdfPoints::new(-20.0), PdfPoints::new(10.0)));
+        assert!(!rect.contains_point(PdfPoints::new(-20.5), PdfPoints::new(0.0)));
+    }
+
+    #[test]
+    fn test_rect_scale_about() {
+        let rect = PdfRect::new_from_values(10.0, 10.0, 20.0, 30.0);
+
+        assert_eq!(
+            rect.scale_about(rect.center(), 2.0),
+            PdfRect::new_from_values(5.0, 0.0, 25.0, 40.0)
+        );
+        assert_eq!(
+            rect.scale_about((PdfPoints::ZERO, PdfPoints::ZERO), 0.5),
+            PdfRect::new_from_values(5.0, 5.0, 10.0, 15.0)
+        );
+        assert_eq!(
+            rect.scale_about((PdfPoints::ZERO, PdfPoints::ZERO), -1.0),
+            PdfRect::new_from_values(-20.0, -30.0, -10.0, -10.0)
+        );
+    }
+
     #[test]
     fn test_transform_rect() {
         let delta_x = PdfPoints::new(50.0);
@@ -328,4 +607,41 @@ mod tests {
         assert_eq!(result.left(), left + delta_x);
         assert_eq!(result.right(), right + delta_x);
     }
+
+    #[test]
+    fn test_rect_rotated_space() {
+        // A landscape page, 800 points wide and 600 points high before rotation,
+        // with a rectangle near its top-left corner.
+
+        let width = PdfPoints::new(800.0);
+        let height = PdfPoints::new(600.0);
+
+        let rect = PdfRect::new_from_values(500.0, 10.0, 590.0, 110.0);
+
+        let expected = [
+            (PdfPageRenderRotation::None, rect),
+            // Rotated clockwise, the top-left corner of the page moves to the top-right.
+            (
+                PdfPageRenderRotation::Degrees90,
+                PdfRect::new_from_values(690.0, 500.0, 790.0, 590.0),
+            ),
+            // Rotated upside down, the top-left corner moves to the bottom-right.
+            (
+                PdfPageRenderRotation::Degrees180,
+                PdfRect::new_from_values(10.0, 690.0, 100.0, 790.0),
+            ),
+            // Rotated counter-clockwise, the top-left corner moves to the bottom-left.
+            (
+                PdfPageRenderRotation::Degrees270,
+                PdfRect::new_from_values(10.0, 10.0, 110.0, 100.0),
+            ),
+        ];
+
+        for (rotation, expected) in expected.iter() {
+            let rotated = rect.to_rotated_space(*rotation, width, height);
+
+            assert_eq!(rotated, *expected);
+            assert_eq!(rotated.from_rotated_space(*rotation, width, height), rect);
+        }
+    }
 }
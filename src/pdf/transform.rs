@@ -333,6 +333,10 @@ macro_rules! create_transform_getters {
 
         /// Returns the current horizontal and vertical scale factors applied to
         #[doc = $doc_ref_period_ ]
+        ///
+        /// The scale factors are taken from the decomposition of the current transformation
+        /// matrix returned by [PdfMatrix::decompose()], so they are reported correctly even
+        /// if the object is also rotated or skewed.
         #[inline]
         pub fn get_scale(&self) -> (PdfMatrixValue, PdfMatrixValue) {
             (self.get_horizontal_scale(), self.get_vertical_scale())
@@ -340,24 +344,41 @@ macro_rules! create_transform_getters {
 
         /// Returns the current horizontal scale factor applied to
         #[doc = $doc_ref_period_ ]
+        ///
+        /// The scale factor is taken from the decomposition of the current transformation
+        /// matrix returned by [PdfMatrix::decompose()], so it is reported correctly even
+        /// if the object is also rotated or skewed.
         #[inline]
         pub fn get_horizontal_scale(&self) -> PdfMatrixValue {
-            self.matrix().map(|matrix| matrix.a()).unwrap_or(0.0)
+            self.matrix()
+                .ok()
+                .and_then(|matrix| matrix.decompose())
+                .map(|decomposition| decomposition.scale().0)
+                .unwrap_or(0.0)
         }
 
         /// Returns the current vertical scale factor applied to
         #[doc = $doc_ref_period_ ]
+        ///
+        /// The scale factor is taken from the decomposition of the current transformation
+        /// matrix returned by [PdfMatrix::decompose()], so it is reported correctly even
+        /// if the object is also rotated or skewed.
         #[inline]
         pub fn get_vertical_scale(&self) -> PdfMatrixValue {
-            self.matrix().map(|matrix| matrix.d()).unwrap_or(0.0)
+            self.matrix()
+                .ok()
+                .and_then(|matrix| matrix.decompose())
+                .map(|decomposition| decomposition.scale().1)
+                .unwrap_or(0.0)
         }
 
         /// Returns the counter-clockwise rotation applied to
         #[doc = $doc_ref_comma_ ]
         /// in degrees.
         ///
-        /// If the object is both rotated and skewed, the return value of this function will reflect
-        /// the combined operation.
+        /// The rotation is taken from the decomposition of the current transformation
+        /// matrix returned by [PdfMatrix::decompose()], and is the angle of the object's
+        /// transformed x axis.
         #[inline]
         pub fn get_rotation_counter_clockwise_degrees(&self) -> PdfMatrixValue {
             self.get_rotation_counter_clockwise_radians().to_degrees()
@@ -367,8 +388,9 @@ macro_rules! create_transform_getters {
         #[doc = $doc_ref_comma_ ]
         /// in degrees.
         ///
-        /// If the object is both rotated and skewed, the return value of this function will reflect
-        /// the combined operation.
+        /// The rotation is taken from the decomposition of the current transformation
+        /// matrix returned by [PdfMatrix::decompose()], and is the angle of the object's
+        /// transformed x axis.
         #[inline]
         pub fn get_rotation_clockwise_degrees(&self) -> PdfMatrixValue {
             -self.get_rotation_counter_clockwise_degrees()
@@ -378,12 +400,15 @@ macro_rules! create_transform_getters {
         #[doc = $doc_ref_comma_ ]
         /// in radians.
         ///
-        /// If the object is both rotated and skewed, the return value of this function will reflect
-        /// the combined operation.
+        /// The rotation is taken from the decomposition of the current transformation
+        /// matrix returned by [PdfMatrix::decompose()], and is the angle of the object's
+        /// transformed x axis.
         #[inline]
         pub fn get_rotation_counter_clockwise_radians(&self) -> PdfMatrixValue {
             self.matrix()
-                .map(|matrix| matrix.b().atan2(matrix.a()))
+                .ok()
+                .and_then(|matrix| matrix.decompose())
+                .map(|decomposition| decomposition.rotation_counter_clockwise_radians())
                 .unwrap_or(0.0)
         }
 
@@ -391,8 +416,9 @@ macro_rules! create_transform_getters {
         #[doc = $doc_ref_comma_ ]
         /// in radians.
         ///
-        /// If the object is both rotated and skewed, the return value of this function will reflect
-        /// the combined operation.
+        /// The rotation is taken from the decomposition of the current transformation
+        /// matrix returned by [PdfMatrix::decompose()], and is the angle of the object's
+        /// transformed x axis.
         #[inline]
         pub fn get_rotation_clockwise_radians(&self) -> PdfMatrixValue {
             -self.get_rotation_counter_clockwise_radians()
@@ -402,8 +428,9 @@ macro_rules! create_transform_getters {
         #[doc = $doc_ref_comma_ ]
         /// in degrees.
         ///
-        /// If the object is both rotated and skewed, the return value of this function will reflect
-        /// the combined operation.
+        /// The skew angles are taken from the decomposition of the current transformation
+        /// matrix returned by [PdfMatrix::decompose()], which expresses all skew as a skew
+        /// of the y axis. The x axis skew angle is always zero.
         #[inline]
         pub fn get_skew_degrees(&self) -> (PdfMatrixValue, PdfMatrixValue) {
             (
@@ -416,8 +443,9 @@ macro_rules! create_transform_getters {
         #[doc = $doc_ref_comma_ ]
         /// in degrees.
         ///
-        /// If the object is both rotated and skewed, the return value of this function will reflect
-        /// the combined operation.
+        /// The decomposition of the current transformation matrix returned by
+        /// [PdfMatrix::decompose()] expresses all skew as a skew of the y axis,
+        /// so the x axis skew angle is always zero.
         #[inline]
         pub fn get_x_axis_skew_degrees(&self) -> PdfMatrixValue {
             self.get_x_axis_skew_radians().to_degrees()
@@ -427,8 +455,9 @@ macro_rules! create_transform_getters {
         #[doc = $doc_ref_comma_ ]
         /// in degrees.
         ///
-        /// If the object is both rotated and skewed, the return value of this function will reflect
-        /// the combined operation.
+        /// The skew angle is taken from the decomposition of the current transformation
+        /// matrix returned by [PdfMatrix::decompose()], which expresses all skew as a skew
+        /// of the y axis.
         #[inline]
         pub fn get_y_axis_skew_degrees(&self) -> PdfMatrixValue {
             self.get_y_axis_skew_radians().to_degrees()
@@ -438,8 +467,9 @@ macro_rules! create_transform_getters {
         #[doc = $doc_ref_comma_ ]
         /// in radians.
         ///
-        /// If the object is both rotated and skewed, the return value of this function will reflect
-        /// the combined operation.
+        /// The skew angles are taken from the decomposition of the current transformation
+        /// matrix returned by [PdfMatrix::decompose()], which expresses all skew as a skew
+        /// of the y axis. The x axis skew angle is always zero.
         #[inline]
         pub fn get_skew_radians(&self) -> (PdfMatrixValue, PdfMatrixValue) {
             (
@@ -452,22 +482,32 @@ macro_rules! create_transform_getters {
         #[doc = $doc_ref_comma_ ]
         /// in radians.
         ///
-        /// If the object is both rotated and skewed, the return value of this function will reflect
-        /// the combined operation.
+        /// The decomposition of the current transformation matrix returned by
+        /// [PdfMatrix::decompose()] expresses all skew as a skew of the y axis,
+        /// so the x axis skew angle is always zero.
         #[inline]
         pub fn get_x_axis_skew_radians(&self) -> PdfMatrixValue {
-            self.matrix().map(|matrix| matrix.b().atan()).unwrap_or(0.0)
+            self.matrix()
+                .ok()
+                .and_then(|matrix| matrix.decompose())
+                .map(|decomposition| decomposition.skew_radians().0)
+                .unwrap_or(0.0)
         }
 
         /// Returns the current y axis skew applied to
         #[doc = $doc_ref_comma_ ]
         /// in radians.
         ///
-        /// If the object is both rotated and skewed, the return value of this function will reflect
-        /// the combined operation.
+        /// The skew angle is taken from the decomposition of the current transformation
+        /// matrix returned by [PdfMatrix::decompose()], which expresses all skew as a skew
+        /// of the y axis.
         #[inline]
         pub fn get_y_axis_skew_radians(&self) -> PdfMatrixValue {
-            self.matrix().map(|matrix| matrix.c().atan()).unwrap_or(0.0)
+            self.matrix()
+                .ok()
+                .and_then(|matrix| matrix.decompose())
+                .map(|decomposition| decomposition.skew_radians().1)
+                .unwrap_or(0.0)
         }
     };
     () => {
@@ -2,6 +2,9 @@
 
 use crate::bindings::PdfiumLibraryBindings;
 use crate::error::{PdfiumError, PdfiumInternalError};
+use crate::pdf::document::recovery::{
+    repair_cross_reference, truncate_at_last_eof, PdfLoadRecovery, PdfLoadWarning,
+};
 use crate::pdf::document::{PdfDocument, PdfDocumentVersion};
 use std::fmt::{Debug, Formatter};
 
@@ -16,24 +19,68 @@ use crate::bindings::static_bindings::StaticPdfiumBindings;
 
 #[cfg(not(target_arch = "wasm32"))]
 use {
+    crate::pdf::document::availability::{PdfDataProvider, PdfDocumentAvailability},
     crate::utils::files::get_pdfium_file_accessor_from_reader,
     std::fs::File,
-    std::io::{Read, Seek},
+    std::io::{Cursor, Read, Seek},
     std::path::Path,
 };
 
 #[cfg(target_arch = "wasm32")]
 use {
     crate::bindings::wasm::{PdfiumRenderWasmState, WasmPdfiumBindings},
+    crate::pdf::document::availability::PdfDocumentAvailability,
+    crate::pdf::document::fetch::{PdfFetchOptions, PdfFetchResult, PdfRangeFetcher},
     js_sys::{ArrayBuffer, Uint8Array},
-    wasm_bindgen::JsCast,
+    std::rc::Rc,
     wasm_bindgen_futures::JsFuture,
-    web_sys::{window, Blob, Response},
+    web_sys::Blob,
 };
 
+#[cfg(all(doc, not(target_arch = "wasm32")))]
+use crate::pdf::document::fetch::PdfFetchOptions;
+
 #[cfg(feature = "thread_safe")]
 use crate::bindings::thread_safe::ThreadSafePdfiumBindings;
 
+#[cfg(feature = "image_api")]
+use {crate::pdf::document::image_page::PdfImagePageOptions, std::borrow::Borrow};
+
+#[cfg(any(feature = "image_latest", feature = "image_025"))]
+use image_025::DynamicImage;
+
+#[cfg(feature = "image_024")]
+use image_024::DynamicImage;
+
+#[cfg(feature = "image_023")]
+use image_023::DynamicImage;
+
+#[cfg(all(doc, feature = "image_api"))]
+use crate::pdf::document::pages::PdfPages;
+
+#[cfg(all(feature = "threaded_render", not(target_arch = "wasm32")))]
+use {
+    crate::pdf::document::page::{
+        render_config::PdfRenderConfig,
+        render_parallel::{
+            PdfParallelRenderBindingsFactory, PdfParallelRenderIterator, PdfParallelRenderSource,
+        },
+    },
+    std::sync::Arc,
+};
+
+#[cfg(all(feature = "threaded_render", not(target_arch = "wasm32")))]
+use crate::pdf::document::pages::PdfPageIndex;
+
+#[cfg(all(doc, feature = "threaded_render", not(target_arch = "wasm32")))]
+use crate::pdf::document::page::render_parallel::PdfRenderedPage;
+
+#[cfg(any(doc, all(target_os = "windows", feature = "windows_print")))]
+use crate::pdf::print::PdfPrintMode;
+
+#[cfg(doc)]
+use crate::pdf::document::page::PdfPage;
+
 // The following dummy declaration is used only when running cargo doc.
 // It allows documentation of WASM-specific functionality to be included
 // in documentation generated on non-WASM targets.
@@ -230,6 +277,109 @@ impl Pdfium {
         })
     }
 
+    /// Attempts to open a [PdfDocument] from the given byte buffer, repairing the document
+    /// if necessary using the strategies permitted by the given [PdfLoadRecovery] mode.
+    ///
+    /// If the document is password protected, the given password will be used to unlock it.
+    /// Password errors are returned immediately, without attempting any repairs.
+    ///
+    /// Use the `PdfDocument::was_repaired()` and `PdfDocument::load_warnings()` functions
+    /// on the returned document to determine whether, and how, the document was repaired.
+    /// If every permitted strategy fails, the error from the initial attempt to load the
+    /// unmodified document data is returned.
+    pub fn load_pdf_from_bytes_with_recovery<'a>(
+        &'a self,
+        bytes: &'a [u8],
+        password: Option<&str>,
+        recovery: PdfLoadRecovery,
+    ) -> Result<PdfDocument<'a>, PdfiumError> {
+        let error = match self.load_pdf_from_byte_slice(bytes, password) {
+            Ok(mut document) => {
+                if !self.bindings.is_true(
+                    self.bindings
+                        .FPDF_DocumentHasValidCrossReferenceTable(document.handle()),
+                ) {
+                    document.set_load_warnings(vec![
+                        PdfLoadWarning::CrossReferenceTableReconstructedByPdfium,
+                    ]);
+                }
+
+                return Ok(document);
+            }
+            Err(error) => error,
+        };
+
+        if recovery == PdfLoadRecovery::None
+            || matches!(
+                error,
+                PdfiumError::PdfiumLibraryInternalError(PdfiumInternalError::PasswordError)
+            )
+        {
+            return Err(error);
+        }
+
+        if let Some(end) = truncate_at_last_eof(bytes) {
+            if let Ok(mut document) = self.load_pdf_from_truncated_bytes(&bytes[..end], password) {
+                document.set_load_warnings(vec![PdfLoadWarning::TrailingDataRemoved {
+                    bytes: bytes.len() - end,
+                }]);
+
+                return Ok(document);
+            }
+        }
+
+        if recovery == PdfLoadRecovery::Full {
+            if let Some((repaired, warnings)) = repair_cross_reference(bytes) {
+                if let Ok(mut document) = self.load_pdf_from_byte_vec(repaired, password) {
+                    document.set_load_warnings(warnings);
+
+                    return Ok(document);
+                }
+            }
+        }
+
+        Err(error)
+    }
+
+    /// Attempts to open a [PdfDocument] from the given byte buffer, which has been truncated
+    /// to exclude trailing data following the document, through a custom file access reader.
+    #[cfg(not(target_arch = "wasm32"))]
+    fn load_pdf_from_truncated_bytes<'a>(
+        &'a self,
+        bytes: &'a [u8],
+        password: Option<&str>,
+    ) -> Result<PdfDocument<'a>, PdfiumError> {
+        // Pdfium reads the document through a reader that reports the end of the truncated
+        // data as the end of the file, so the original data does not need to be copied.
+
+        let mut reader = get_pdfium_file_accessor_from_reader(Cursor::new(bytes))?;
+
+        Pdfium::pdfium_document_handle_to_result(
+            self.bindings
+                .FPDF_LoadCustomDocument(reader.as_fpdf_file_access_mut_ptr(), password),
+            self.bindings(),
+        )
+        .map(|mut document| {
+            document.set_file_access_reader(reader);
+
+            document
+        })
+    }
+
+    /// Attempts to open a [PdfDocument] from the given byte buffer, which has been truncated
+    /// to exclude trailing data following the document.
+    #[cfg(target_arch = "wasm32")]
+    #[inline]
+    fn load_pdf_from_truncated_bytes<'a>(
+        &'a self,
+        bytes: &'a [u8],
+        password: Option<&str>,
+    ) -> Result<PdfDocument<'a>, PdfiumError> {
+        // Custom file access readers are not available when compiling to WASM.
+
+        self.load_pdf_from_byte_slice(bytes, password)
+    }
+
     /// Attempts to open a [PdfDocument] from the given file path.
     ///
     /// If the document is password protected, the given password will be used
@@ -271,6 +421,11 @@ impl Pdfium {
     /// If the document is password protected, the given password will be used
     /// to unlock it.
     ///
+    /// The reader is owned by the returned [PdfDocument] and is dropped when the document
+    /// is dropped. Any error returned by the reader while Pdfium is reading from it is
+    /// reported to Pdfium as a failed read; if this happens while the document is being
+    /// loaded, loading fails and an error is returned.
+    ///
     /// This function is not available when compiling to WASM. You have several options for
     /// loading your PDF document data in WASM:
     /// * Use the [Pdfium::load_pdf_from_fetch()] function to download document data from a
@@ -290,7 +445,7 @@ impl Pdfium {
         reader: R,
         password: Option<&'a str>,
     ) -> Result<PdfDocument<'a>, PdfiumError> {
-        let mut reader = get_pdfium_file_accessor_from_reader(reader);
+        let mut reader = get_pdfium_file_accessor_from_reader(reader)?;
 
         Pdfium::pdfium_document_handle_to_result(
             self.bindings
@@ -307,39 +462,76 @@ impl Pdfium {
         })
     }
 
+    /// Creates a new [PdfDocumentAvailability] that tracks the availability of document data
+    /// supplied by the given [PdfDataProvider], allowing a document to be loaded progressively
+    /// while its data is still being downloaded. Once enough data is available, call
+    /// [PdfDocumentAvailability::into_document()] to retrieve the [PdfDocument].
+    ///
+    /// This function is not available when compiling to WASM.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn load_pdf_availability_from_provider<'a>(
+        &'a self,
+        provider: impl PdfDataProvider + 'a,
+    ) -> Result<PdfDocumentAvailability<'a>, PdfiumError> {
+        PdfDocumentAvailability::from_provider(provider, self.bindings())
+    }
+
     /// Attempts to open a [PdfDocument] by loading document data from the given URL.
     /// The Javascript `fetch()` API is used to download data over the network.
     ///
-    /// If the document is password protected, the given password will be used to unlock it.
+    /// By default, document data is downloaded on demand using HTTP `Range` requests,
+    /// guided by the byte ranges Pdfium asks for, so that the first page of a large document
+    /// - especially a linearized one - can be displayed before the remainder of the document
+    /// has been downloaded. The returned document is ready for its first page to be accessed;
+    /// use [PdfDocumentAvailability::fetch_page()] to download the data for other pages
+    /// before accessing them. If the server does not support range requests, answering the
+    /// first request with the entire document rather than with partial content, the entire
+    /// document is downloaded before it is opened.
+    ///
+    /// Downloading is configured by the given [PdfFetchOptions]. For backwards compatibility,
+    /// an `Option<&str>` password is also accepted in place of a [PdfFetchOptions] object.
+    ///
+    /// For cross-origin requests, the server must allow the `Range` request header and expose
+    /// the `Content-Range` response header for range requests to be used.
     ///
     /// This function is only available when compiling to WASM.
     #[cfg(any(doc, target_arch = "wasm32"))]
     pub async fn load_pdf_from_fetch<'a>(
         &'a self,
         url: impl ToString,
-        password: Option<&str>,
+        options: impl Into<PdfFetchOptions>,
     ) -> Result<PdfDocument<'a>, PdfiumError> {
-        if let Some(window) = window() {
-            let fetch_result = JsFuture::from(window.fetch_with_str(url.to_string().as_str()))
-                .await
-                .map_err(PdfiumError::WebSysFetchError)?;
+        let options = options.into();
+
+        let fetcher = match PdfRangeFetcher::connect(url.to_string().as_str(), &options).await? {
+            PdfFetchResult::Ranged(fetcher) => Rc::new(fetcher),
+            PdfFetchResult::Complete(bytes) => {
+                return self.load_pdf_from_byte_vec(bytes, options.password());
+            }
+        };
 
-            debug_assert!(fetch_result.is_instance_of::<Response>());
+        let mut availability =
+            PdfDocumentAvailability::from_provider(fetcher.buffer().clone(), self.bindings())?;
 
-            let response: Response = fetch_result
-                .dyn_into()
-                .map_err(|_| PdfiumError::WebSysInvalidResponseError)?;
+        availability.set_fetcher(fetcher.clone());
 
-            let blob: Blob =
-                JsFuture::from(response.blob().map_err(PdfiumError::WebSysFetchError)?)
-                    .await
-                    .map_err(PdfiumError::WebSysFetchError)?
-                    .into();
+        while !availability.is_document_available()? {
+            if !fetcher.fetch_hints(availability.download_hints()).await? {
+                // Every byte has been downloaded, but Pdfium still cannot load the document.
 
-            self.load_pdf_from_blob(blob, password).await
-        } else {
-            Err(PdfiumError::WebSysWindowObjectNotAvailable)
+                return Err(PdfiumError::DocumentDataNotYetAvailable);
+            }
         }
+
+        let document = availability.into_document(options.password())?;
+
+        if !document.pages().is_empty() {
+            if let Some(availability) = document.availability() {
+                availability.fetch_page(0).await?;
+            }
+        }
+
+        Ok(document)
     }
 
     /// Attempts to open a [PdfDocument] by loading document data from the given `Blob`.
@@ -385,6 +577,167 @@ impl Pdfium {
         })
     }
 
+    /// Creates a new [PdfDocument] in memory containing one page for each of the given images,
+    /// in order. Each page is created using the [PdfPages::create_page_from_image()] function,
+    /// configured by the given [PdfImagePageOptions].
+    ///
+    /// Since the same options are applied to every image, any JPEG data set using
+    /// [PdfImagePageOptions::pass_through_jpeg()] is ignored, and every image is
+    /// embedded losslessly.
+    ///
+    /// This function is only available when this crate's `image` feature is enabled.
+    #[cfg(feature = "image_api")]
+    pub fn create_pdf_from_images<I>(
+        &self,
+        images: I,
+        options: &PdfImagePageOptions,
+    ) -> Result<PdfDocument<'_>, PdfiumError>
+    where
+        I: IntoIterator,
+        I::Item: Borrow<DynamicImage>,
+    {
+        let mut document = self.create_new_pdf()?;
+
+        for image in images {
+            document
+                .pages_mut()
+                .create_page_from_image_impl(image.borrow(), options, None)?;
+        }
+
+        Ok(document)
+    }
+
+    /// Renders the pages at the given indices of the given document on a pool of
+    /// `worker_count` worker threads, returning an iterator that yields each
+    /// [PdfRenderedPage] in the same order as the given page indices.
+    ///
+    /// Each worker binds to Pdfium in the same way as [Pdfium::default()]. To control how
+    /// workers bind to Pdfium, use [Pdfium::render_pages_parallel_with_bindings()].
+    ///
+    /// This function is only available when this crate's `threaded_render` feature is enabled.
+    #[cfg(all(feature = "threaded_render", not(target_arch = "wasm32")))]
+    #[inline]
+    pub fn render_pages_parallel(
+        source: impl Into<PdfParallelRenderSource>,
+        page_indices: impl IntoIterator<Item = PdfPageIndex>,
+        config: PdfRenderConfig,
+        worker_count: usize,
+    ) -> Result<PdfParallelRenderIterator, PdfiumError> {
+        Pdfium::render_pages_parallel_with_bindings(
+            Pdfium::bind_to_default_library,
+            source,
+            page_indices,
+            config,
+            worker_count,
+        )
+    }
+
+    /// Renders the pages at the given indices of the given document on a pool of
+    /// `worker_count` worker threads, returning an iterator that yields each
+    /// [PdfRenderedPage] in the same order as the given page indices.
+    ///
+    /// Pdfium handles are never shared between threads. Instead, each worker calls the given
+    /// function once to bind to Pdfium, creates its own [Pdfium] instance, and opens its own
+    /// handle to the document for each page it renders; only the owned pixel data of each
+    /// rendered page is sent back to the calling thread. If a worker cannot bind to Pdfium
+    /// or open the document, the error is returned in place of the next page, and the
+    /// remaining pages are left to the other workers.
+    ///
+    /// Pdfium itself is not thread safe, and only one instance of Pdfium can be initialized
+    /// at a time in any process. When the `thread_safe` crate feature is enabled (as it is
+    /// whenever this function is available), bindings created by functions such as
+    /// [Pdfium::bind_to_library()] serialize access to Pdfium using a global lock that is held
+    /// for as long as any [Pdfium] instance is alive. As a result:
+    ///
+    /// * workers using such bindings take turns rendering pages, one worker at a time. A worker
+    ///   releases the lock after rendering each page, so that another worker can render the
+    ///   next page while the first passes its rendered page back to the calling thread; the
+    ///   rendering itself is never performed on multiple CPU cores simultaneously. To do that,
+    ///   render pages in separate processes.
+    /// * the calling thread must not hold a [Pdfium] instance of its own, otherwise the workers
+    ///   would wait on the lock forever. This function returns
+    ///   [PdfiumError::ParallelRenderPdfiumInUse] if it does. This function is an associated
+    ///   function rather than a method for this reason.
+    ///
+    /// Dropping the returned iterator stops the workers from starting any further pages.
+    ///
+    /// This function is only available when this crate's `threaded_render` feature is enabled.
+    #[cfg(all(feature = "threaded_render", not(target_arch = "wasm32")))]
+    pub fn render_pages_parallel_with_bindings(
+        bindings: impl Fn() -> Result<Box<dyn PdfiumLibraryBindings>, PdfiumError>
+            + Send
+            + Sync
+            + 'static,
+        source: impl Into<PdfParallelRenderSource>,
+        page_indices: impl IntoIterator<Item = PdfPageIndex>,
+        config: PdfRenderConfig,
+        worker_count: usize,
+    ) -> Result<PdfParallelRenderIterator, PdfiumError> {
+        let bindings: Arc<PdfParallelRenderBindingsFactory> = Arc::new(bindings);
+
+        PdfParallelRenderIterator::new(
+            bindings,
+            source.into(),
+            page_indices.into_iter().collect(),
+            config,
+            worker_count,
+        )
+    }
+
+    /// Sets the [PdfPrintMode] Pdfium should use when rendering pages to a printer
+    /// device context using [PdfPage::render_to_hdc()]. The print mode applies to all
+    /// subsequent rendering to device contexts by the bound Pdfium library, across all documents.
+    ///
+    /// This function is only available on Windows when this crate's `windows_print`
+    /// feature is enabled.
+    #[cfg(any(doc, all(target_os = "windows", feature = "windows_print")))]
+    #[inline]
+    pub fn set_print_mode(&self, mode: PdfPrintMode) {
+        self.bindings.FPDF_SetPrintMode(mode.as_pdfium());
+    }
+
+    /// Binds to a Pdfium library using the same strategy as [Pdfium::default()], returning
+    /// an error rather than panicking if no suitable Pdfium library can be loaded.
+    #[cfg(feature = "static")]
+    #[inline]
+    pub(crate) fn bind_to_default_library() -> Result<Box<dyn PdfiumLibraryBindings>, PdfiumError> {
+        Pdfium::bind_to_statically_linked_library()
+    }
+
+    /// Binds to a Pdfium library using the same strategy as [Pdfium::default()], returning
+    /// an error rather than panicking if no suitable Pdfium library can be loaded.
+    #[cfg(not(feature = "static"))]
+    #[cfg(not(target_arch = "wasm32"))]
+    pub(crate) fn bind_to_default_library() -> Result<Box<dyn PdfiumLibraryBindings>, PdfiumError> {
+        match Pdfium::bind_to_library(
+            // Attempt to bind to a Pdfium library in the current working directory...
+            Pdfium::pdfium_platform_library_name_at_path("./"),
+        ) {
+            Ok(bindings) => Ok(bindings),
+            Err(PdfiumError::LoadLibraryError(err)) => {
+                match err {
+                    libloading::Error::DlOpen { .. } => {
+                        // For DlOpen errors specifically, indicating the Pdfium library in the
+                        // current working directory does not exist or is corrupted, we attempt
+                        // to fall back to a system-provided library.
+
+                        Pdfium::bind_to_system_library()
+                    }
+                    _ => Err(PdfiumError::LoadLibraryError(err)),
+                }
+            }
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Binds to a Pdfium library using the same strategy as [Pdfium::default()], returning
+    /// an error rather than panicking if no suitable Pdfium library can be loaded.
+    #[cfg(target_arch = "wasm32")]
+    #[inline]
+    pub(crate) fn bind_to_default_library() -> Result<Box<dyn PdfiumLibraryBindings>, PdfiumError> {
+        Pdfium::bind_to_system_library()
+    }
+
     /// Returns a [PdfDocument] from the given `FPDF_DOCUMENT` handle, if possible.
     pub(crate) fn pdfium_document_handle_to_result(
         handle: crate::bindgen::FPDF_DOCUMENT,
@@ -439,7 +792,7 @@ impl Default for Pdfium {
     #[cfg(feature = "static")]
     #[inline]
     fn default() -> Self {
-        Pdfium::new(Pdfium::bind_to_statically_linked_library().unwrap())
+        Pdfium::new(Pdfium::bind_to_default_library().unwrap())
     }
 
     /// Binds to an external Pdfium library by first attempting to bind to a Pdfium library
@@ -451,27 +804,7 @@ impl Default for Pdfium {
     #[cfg(not(target_arch = "wasm32"))]
     #[inline]
     fn default() -> Self {
-        let bindings = match Pdfium::bind_to_library(
-            // Attempt to bind to a Pdfium library in the current working directory...
-            Pdfium::pdfium_platform_library_name_at_path("./"),
-        ) {
-            Ok(bindings) => Ok(bindings),
-            Err(PdfiumError::LoadLibraryError(err)) => {
-                match err {
-                    libloading::Error::DlOpen { desc: _ } => {
-                        // For DlOpen errors specifically, indicating the Pdfium library in the
-                        // current working directory does not exist or is corrupted, we attempt
-                        // to fall back to a system-provided library.
-
-                        Pdfium::bind_to_system_library()
-                    }
-                    _ => Err(PdfiumError::LoadLibraryError(err)),
-                }
-            }
-            Err(err) => Err(err),
-        };
-
-        Pdfium::new(bindings.unwrap())
+        Pdfium::new(Pdfium::bind_to_default_library().unwrap())
     }
 
     /// Binds to an external Pdfium library by attempting to a system-provided library.
@@ -479,7 +812,7 @@ impl Default for Pdfium {
     /// This function will panic if no suitable Pdfium library can be loaded.
     #[cfg(target_arch = "wasm32")]
     fn default() -> Self {
-        Pdfium::new(Pdfium::bind_to_system_library().unwrap())
+        Pdfium::new(Pdfium::bind_to_default_library().unwrap())
     }
 }
 
@@ -495,3 +828,65 @@ unsafe impl Sync for Pdfium {}
 
 #[cfg(feature = "sync")]
 unsafe impl Send for Pdfium {}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::utils::test::test_bind_to_pdfium;
+    use std::fs::File;
+    use std::io::{Cursor, Error, ErrorKind, Read, Seek, SeekFrom};
+
+    #[test]
+    fn test_load_pdf_from_reader_matches_byte_buffer() -> Result<(), PdfiumError> {
+        let pdfium = test_bind_to_pdfium();
+
+        let from_reader = pdfium.load_pdf_from_reader(
+            File::open("./test/text-test.pdf").map_err(PdfiumError::IoError)?,
+            None,
+        )?;
+
+        let from_bytes = pdfium.load_pdf_from_byte_vec(
+            std::fs::read("./test/text-test.pdf").map_err(PdfiumError::IoError)?,
+            None,
+        )?;
+
+        assert_eq!(from_reader.pages().len(), from_bytes.pages().len());
+
+        for (a, b) in from_reader.pages().iter().zip(from_bytes.pages().iter()) {
+            assert_eq!(a.text()?.all(), b.text()?.all());
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_load_pdf_from_failing_reader_returns_error() {
+        // A reader that fails every read beyond the first few bytes of the document.
+
+        struct FailingReader(Cursor<Vec<u8>>);
+
+        impl Read for FailingReader {
+            fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+                if self.0.position() > 16 {
+                    Err(Error::new(ErrorKind::Other, "simulated read failure"))
+                } else {
+                    self.0.read(buf)
+                }
+            }
+        }
+
+        impl Seek for FailingReader {
+            fn seek(&mut self, pos: SeekFrom) -> std::io::Result<u64> {
+                self.0.seek(pos)
+            }
+        }
+
+        let pdfium = test_bind_to_pdfium();
+
+        let bytes = std::fs::read("./test/text-test.pdf").unwrap();
+
+        assert!(pdfium
+            .load_pdf_from_reader(FailingReader(Cursor::new(bytes)), None)
+            .is_err());
+    }
+}
@@ -0,0 +1,206 @@
+//! Defines the [PdfiumSync] and [PdfDocumentSync] structs, wrappers around a [Pdfium] instance
+//! and the documents it loads that can be shared between threads, serializing every operation
+//! behind a global reentrant lock.
+
+use crate::bindings::thread_safe::lock_pdfium;
+use crate::error::PdfiumError;
+use crate::pdf::document::PdfDocument;
+use crate::pdfium::Pdfium;
+use std::fmt::{Debug, Formatter};
+
+/// A wrapper around a [Pdfium] instance that can be shared between threads.
+///
+/// Pdfium is not thread safe. When the `sync` crate feature is enabled, every individual call
+/// to Pdfium is serialized behind a global reentrant lock, but that alone does not prevent
+/// another thread's calls from being interleaved between two related calls - for example,
+/// between sizing a buffer and filling it, or between a failed call and the retrieval of
+/// its error code. [PdfiumSync] holds the same lock for the whole of each operation passed
+/// to [PdfiumSync::with()], so that no other thread can call Pdfium until the operation is
+/// complete.
+///
+/// The lock is reentrant, so callbacks from Pdfium into your own code - for example, custom
+/// readers passed to [Pdfium::load_pdf_from_reader()] - can safely call Pdfium again.
+///
+/// This struct is only available when this crate's `sync` feature is enabled.
+pub struct PdfiumSync {
+    pdfium: Pdfium,
+}
+
+impl PdfiumSync {
+    /// Creates a new [PdfiumSync] wrapping the given [Pdfium] instance.
+    #[inline]
+    pub fn new(pdfium: Pdfium) -> Self {
+        PdfiumSync { pdfium }
+    }
+
+    /// Calls the given function with the wrapped [Pdfium] instance, holding the global lock
+    /// over Pdfium until the function returns.
+    #[inline]
+    pub fn with<R>(&self, f: impl FnOnce(&Pdfium) -> R) -> R {
+        let _lock = lock_pdfium();
+
+        f(&self.pdfium)
+    }
+
+    /// Calls the given function to load a [PdfDocument] using the wrapped [Pdfium] instance,
+    /// holding the global lock over Pdfium until the function returns, and wraps the loaded
+    /// document in a new [PdfDocumentSync].
+    #[inline]
+    pub fn load<'a>(
+        &'a self,
+        f: impl FnOnce(&'a Pdfium) -> Result<PdfDocument<'a>, PdfiumError>,
+    ) -> Result<PdfDocumentSync<'a>, PdfiumError> {
+        let _lock = lock_pdfium();
+
+        f(&self.pdfium).map(PdfDocumentSync::new)
+    }
+}
+
+impl Debug for PdfiumSync {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PdfiumSync").finish()
+    }
+}
+
+/// A wrapper around a [PdfDocument] that can be shared between threads, holding the global
+/// lock over Pdfium for the whole of each operation passed to [PdfDocumentSync::with()] or
+/// [PdfDocumentSync::with_mut()]. See [PdfiumSync] for more details.
+///
+/// Any pages, objects, or other values returned from an operation and used after it has
+/// completed are no longer covered by the operation's lock; only the individual calls
+/// they make to Pdfium are serialized.
+///
+/// This struct is only available when this crate's `sync` feature is enabled.
+pub struct PdfDocumentSync<'a> {
+    document: Option<PdfDocument<'a>>,
+}
+
+impl<'a> PdfDocumentSync<'a> {
+    /// Creates a new [PdfDocumentSync] wrapping the given [PdfDocument].
+    #[inline]
+    pub fn new(document: PdfDocument<'a>) -> Self {
+        PdfDocumentSync {
+            document: Some(document),
+        }
+    }
+
+    /// Calls the given function with the wrapped [PdfDocument], holding the global lock
+    /// over Pdfium until the function returns.
+    #[inline]
+    pub fn with<R>(&self, f: impl FnOnce(&PdfDocument<'a>) -> R) -> R {
+        let _lock = lock_pdfium();
+
+        // The document is only ever taken when this PdfDocumentSync is dropped.
+
+        f(self.document.as_ref().unwrap())
+    }
+
+    /// Calls the given function with the wrapped [PdfDocument] for mutation, holding the
+    /// global lock over Pdfium until the function returns.
+    #[inline]
+    pub fn with_mut<R>(&mut self, f: impl FnOnce(&mut PdfDocument<'a>) -> R) -> R {
+        let _lock = lock_pdfium();
+
+        f(self.document.as_mut().unwrap())
+    }
+
+    /// Consumes this [PdfDocumentSync], returning the wrapped [PdfDocument].
+    #[inline]
+    pub fn into_inner(mut self) -> PdfDocument<'a> {
+        self.document.take().unwrap()
+    }
+}
+
+impl<'a> Drop for PdfDocumentSync<'a> {
+    /// Closes the wrapped [PdfDocument], if any, holding the global lock over Pdfium
+    /// while it is closed.
+    #[inline]
+    fn drop(&mut self) {
+        let _lock = lock_pdfium();
+
+        self.document.take();
+    }
+}
+
+impl<'a> Debug for PdfDocumentSync<'a> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PdfDocumentSync")
+            .field("document", &self.document)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::utils::test::test_bind_to_pdfium;
+    use std::sync::Mutex;
+
+    #[test]
+    fn test_render_pages_alternately_from_two_threads() -> Result<(), PdfiumError> {
+        // Checks that two threads sharing the same PdfDocumentSync can take turns rendering
+        // its pages, with each render holding the lock over Pdfium from start to finish.
+
+        let pdfium = PdfiumSync::new(test_bind_to_pdfium());
+
+        let document =
+            pdfium.load(|pdfium| pdfium.load_pdf_from_file("./test/export-test.pdf", None))?;
+
+        let page_count = document.with(|document| document.pages().len());
+
+        let render_config = PdfRenderConfig::new().set_target_width(400);
+
+        let rendered = Mutex::new(Vec::new());
+
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(2)
+            .build()
+            .unwrap();
+
+        pool.scope(|scope| {
+            for first_index in 0..2 {
+                let document = &document;
+                let render_config = &render_config;
+                let rendered = &rendered;
+
+                scope.spawn(move |_| {
+                    for index in (first_index..page_count).step_by(2) {
+                        let rgba = document
+                            .with(|document| {
+                                document
+                                    .pages()
+                                    .get(index)?
+                                    .render_with_config(render_config)
+                                    .map(|bitmap| bitmap.as_rgba_bytes())
+                            })
+                            .unwrap();
+
+                        rendered.lock().unwrap().push((index, rgba));
+                    }
+                });
+            }
+        });
+
+        let mut rendered = rendered.into_inner().unwrap();
+
+        rendered.sort_by_key(|(index, _)| *index);
+
+        assert_eq!(rendered.len(), page_count as usize);
+
+        for (index, rgba) in rendered {
+            let expected = document.with(|document| {
+                document
+                    .pages()
+                    .get(index)?
+                    .render_with_config(&render_config)
+                    .map(|bitmap| bitmap.as_rgba_bytes())
+            })?;
+
+            assert_eq!(rgba, expected);
+        }
+
+        Ok(())
+    }
+}
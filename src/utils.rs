@@ -83,6 +83,57 @@ pub(crate) mod pixels {
             .collect::<Vec<_>>()
     }
 
+    /// Converts the given byte array, containing pixel data encoded as four-channel BGRA with
+    /// zero or more empty alignment bytes at the end of each scanline, into pixel data encoded
+    /// as four-channel RGBA in a single copy.
+    #[inline]
+    pub(crate) fn aligned_bgra_to_rgba(bgra: &[u8], width: usize, stride: usize) -> Vec<u8> {
+        let mut rgba = strip_alignment(bgra, width * BYTES_PER_FOUR_CHANNEL_PIXEL, stride);
+
+        swap_red_and_blue_in_place(rgba.as_mut_slice());
+
+        rgba
+    }
+
+    /// Swaps the first and third channels of each pixel in the given byte array, containing
+    /// pixel data encoded as four-channel BGRA or RGBA, converting between the two in place.
+    #[inline]
+    pub(crate) fn swap_red_and_blue_in_place(pixels: &mut [u8]) {
+        for channels in pixels.chunks_exact_mut(BYTES_PER_FOUR_CHANNEL_PIXEL) {
+            channels.swap(0, 2);
+        }
+    }
+
+    /// Returns a copy of the given byte array, containing pixel data with zero or more empty
+    /// alignment bytes at the end of each scanline, with the alignment bytes removed. The length
+    /// in bytes of the pixel data in each scanline is given by the `row_length` parameter.
+    #[inline]
+    pub(crate) fn strip_alignment(pixels: &[u8], row_length: usize, stride: usize) -> Vec<u8> {
+        if row_length == stride {
+            return pixels.to_vec();
+        }
+
+        let mut result = Vec::with_capacity(row_length * (pixels.len() / stride.max(1)));
+
+        for scanline in pixels.chunks_exact(stride) {
+            result.extend_from_slice(&scanline[..row_length]);
+        }
+
+        result
+    }
+
+    /// Converts the given byte array, containing single-channel grayscale pixel data with
+    /// zero or more empty alignment bytes at the end of each scanline, into pixel data encoded
+    /// as four-channel RGBA. A new alpha channel is created with full opacity.
+    #[cfg_attr(not(feature = "image_api"), allow(dead_code))]
+    #[inline]
+    pub(crate) fn aligned_gray_to_rgba(gray: &[u8], width: usize, stride: usize) -> Vec<u8> {
+        gray.chunks_exact(stride)
+            .flat_map(|scanline| scanline[..width].iter())
+            .flat_map(|luma| [*luma, *luma, *luma, 255])
+            .collect::<Vec<_>>()
+    }
+
     /// Converts the given byte array, containing pixel data encoded as three-channel RGB,
     /// into pixel data encoded as four-channel BGRA. A new alpha channel is created with full opacity.
     #[inline]
@@ -178,23 +229,62 @@ pub(crate) mod mem {
 }
 
 pub(crate) mod utf16le {
-    use utf16string::{LittleEndian, WString};
+    // Pdfium passes strings across its API boundary as null-terminated sequences of UTF-16LE
+    // code units. Characters outside the Basic Multilingual Plane, such as emoji and many
+    // historic and CJK extension characters, are represented as surrogate pairs of two code
+    // units each. The functions below convert between Rust strings and Pdfium's representation
+    // in both directions using the standard library's UTF-16 support, so that any valid
+    // Rust &str survives a round trip through Pdfium unchanged.
+
+    /// Converts the given Rust &str into a buffer of UTF-16 code units, terminated by a
+    /// single null code unit. The returned buffer is correctly aligned for passing to Pdfium
+    /// as an `FPDF_WIDESTRING`.
+    #[inline]
+    pub(crate) fn get_pdfium_utf16le_words_from_str(str: &str) -> Vec<u16> {
+        // Pdfium expects C-style null termination. Since we are dealing with
+        // wide (16-bit) characters, the terminator is a single null code unit.
+
+        str.encode_utf16().chain(std::iter::once(0)).collect()
+    }
 
-    /// Converts the given Rust &str into an UTF16-LE encoded byte buffer.
+    /// Converts the given Rust &str into an UTF16-LE encoded byte buffer, terminated by
+    /// two null bytes.
     #[inline]
     pub(crate) fn get_pdfium_utf16le_bytes_from_str(str: &str) -> Vec<u8> {
-        let mut bytes = WString::<LittleEndian>::from(str).into_bytes();
+        get_pdfium_utf16le_words_from_str(str)
+            .into_iter()
+            .flat_map(u16::to_le_bytes)
+            .collect()
+    }
 
-        // Pdfium appears to expect C-style null termination. Since we are dealing with
-        // wide (16-bit) characters, we need two bytes of nulls.
+    /// Converts the given buffer of UTF-16 code units to a standard Rust String.
+    ///
+    /// Any trailing null code units are removed. The words are always decoded lossily:
+    /// unpaired surrogates, which Pdfium may occasionally return when extracting text from
+    /// malformed documents, are replaced with `U+FFFD REPLACEMENT CHARACTER`. Invalid UTF-16
+    /// therefore no longer causes `None` to be returned; `None` is returned only if the
+    /// resulting string is empty.
+    pub(crate) fn get_string_from_pdfium_utf16le_words(words: &[u16]) -> Option<String> {
+        let len = words
+            .iter()
+            .rposition(|word| *word != 0)
+            .map(|index| index + 1)
+            .unwrap_or(0);
+
+        if len == 0 {
+            return None;
+        }
 
-        bytes.push(0);
-        bytes.push(0);
+        let words = &words[..len];
 
-        bytes
+        Some(String::from_utf16_lossy(words))
     }
 
     /// Converts the bytes in the given buffer from UTF16-LE to a standard Rust String.
+    ///
+    /// Any trailing null code units are removed, as are any trailing odd bytes. Invalid UTF-16
+    /// is decoded lossily, as described in [get_string_from_pdfium_utf16le_words()]. Returns
+    /// `None` if the resulting string is empty.
     #[allow(unused_mut)] // The buffer must be mutable when compiling to WASM.
     pub(crate) fn get_string_from_pdfium_utf16le_bytes(mut buffer: Vec<u8>) -> Option<String> {
         #[cfg(target_arch = "wasm32")]
@@ -202,8 +292,8 @@ pub(crate) mod utf16le {
             use web_sys::TextDecoder;
 
             // Attempt to perform the conversion using the browser's native TextDecoder
-            // functionality; if that doesn't work, fall back to using the same WString method
-            // used in non-WASM builds.
+            // functionality; if that doesn't work, fall back to using the same
+            // method used in non-WASM builds.
 
             if let Ok(decoder) = TextDecoder::new_with_label("utf-16le") {
                 if let Ok(result) = decoder.decode_with_u8_array(&mut buffer) {
@@ -218,28 +308,22 @@ pub(crate) mod utf16le {
             }
         }
 
-        if let Ok(string) = WString::<LittleEndian>::from_utf16(buffer) {
-            // Trim any trailing nulls. UTF-16LE strings returned from Pdfium are generally
-            // terminated by two null bytes.
-
-            let result = string.to_utf8().trim_end_matches(char::from(0)).to_owned();
+        let words = buffer
+            .chunks_exact(2)
+            .map(|pair| u16::from_le_bytes([pair[0], pair[1]]))
+            .collect::<Vec<_>>();
 
-            if !result.is_empty() {
-                Some(result)
-            } else {
-                None
-            }
-        } else {
-            None
-        }
+        get_string_from_pdfium_utf16le_words(&words)
     }
 }
 
 pub(crate) mod files {
     use crate::bindgen::{FPDF_FILEACCESS, FPDF_FILEWRITE};
+    use crate::error::PdfiumError;
     use std::io::{Read, Seek, SeekFrom, Write};
     use std::ops::Deref;
     use std::os::raw::{c_int, c_uchar, c_ulong, c_void};
+    use std::panic::{self, AssertUnwindSafe};
     use std::ptr::null_mut;
     use std::slice;
 
@@ -277,8 +361,10 @@ pub(crate) mod files {
     // This function is never used when compiling to WASM.
     pub(crate) fn get_pdfium_file_accessor_from_reader<'a, R: Read + Seek + 'a>(
         mut reader: R,
-    ) -> Box<FpdfFileAccessExt<'a>> {
-        let content_length = reader.seek(SeekFrom::End(0)).unwrap_or(0) as c_ulong;
+    ) -> Result<Box<FpdfFileAccessExt<'a>>, PdfiumError> {
+        let content_length = reader
+            .seek(SeekFrom::End(0))
+            .map_err(PdfiumError::IoError)? as c_ulong;
 
         let mut result = Box::new(FpdfFileAccessExt {
             content_length,
@@ -295,7 +381,7 @@ pub(crate) mod files {
 
         result.as_mut().file_access_ptr = file_access_ptr as *mut FpdfFileAccessExt;
 
-        result
+        Ok(result)
     }
 
     trait PdfiumDocumentReader: Read + Seek {
@@ -336,26 +422,30 @@ pub(crate) mod files {
         }
     }
 
-    // The callback function invoked by Pdfium.
+    // The callback function invoked by Pdfium. Pdfium expects the requested block to be
+    // filled completely, and treats a return value of zero as a read failure. Any error or
+    // panic raised by the reader is converted into a read failure, since unwinding across
+    // the FFI boundary into Pdfium is undefined behaviour.
     pub(crate) extern "C" fn read_block_from_callback(
         file_access_ptr: *mut FpdfFileAccessExt,
         position: c_ulong,
         buf: *mut c_uchar,
         size: c_ulong,
     ) -> c_int {
-        unsafe {
+        let result = panic::catch_unwind(AssertUnwindSafe(|| unsafe {
             let reader = (*file_access_ptr).reader.as_mut();
 
             #[allow(clippy::unnecessary_cast)]
             // c_ulong isn't guaranteed to be u64 on all platforms
-            let result = match reader.seek(SeekFrom::Start(position as u64)) {
-                Ok(_) => reader
-                    .read(slice::from_raw_parts_mut(buf, size as usize))
-                    .unwrap_or(0),
-                Err(_) => 0,
-            };
-
-            result as c_int
+            reader
+                .seek(SeekFrom::Start(position as u64))
+                .and_then(|_| reader.read_exact(slice::from_raw_parts_mut(buf, size as usize)))
+        }));
+
+        match result {
+            // Any non-zero return value indicates success.
+            Ok(Ok(())) => size.max(1) as c_int,
+            _ => 0,
         }
     }
 
@@ -461,6 +551,7 @@ pub(crate) mod test {
 mod tests {
     use crate::utils::dates::*;
     use crate::utils::pixels::*;
+    use crate::utils::utf16le::*;
     use chrono::prelude::*;
 
     // Tests of color conversion functions.
@@ -548,6 +639,47 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_aligned_bgra_to_rgba() {
+        let data: [u8; 16] = [2, 1, 0, 3, 6, 5, 4, 7, 10, 9, 8, 11, 14, 13, 12, 15];
+
+        // Without alignment bytes, the result should match bgra_to_rgba().
+
+        assert_eq!(
+            aligned_bgra_to_rgba(data.as_slice(), 2, 8),
+            bgra_to_rgba(data.as_slice())
+        );
+
+        // Interpret the data as eight-byte scanlines with each line consisting of one pixel
+        // followed by four alignment bytes.
+
+        assert_eq!(
+            aligned_bgra_to_rgba(data.as_slice(), 1, 8),
+            [0, 1, 2, 3, 8, 9, 10, 11]
+        );
+    }
+
+    #[test]
+    fn test_aligned_gray_to_rgba() {
+        let data: [u8; 8] = [10, 20, 30, 0, 40, 50, 60, 0];
+
+        // Interpret the data as four-byte scanlines with each line consisting of three pixels
+        // followed by one alignment byte.
+
+        assert_eq!(
+            aligned_gray_to_rgba(data.as_slice(), 3, 4),
+            [
+                10, 10, 10, 255, 20, 20, 20, 255, 30, 30, 30, 255, 40, 40, 40, 255, 50, 50, 50,
+                255, 60, 60, 60, 255
+            ]
+        );
+
+        assert_eq!(
+            strip_alignment(data.as_slice(), 3, 4),
+            [10, 20, 30, 40, 50, 60]
+        );
+    }
+
     #[test]
     fn test_rgb_to_bgra() {
         let data: [u8; 15] = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14];
@@ -596,4 +728,66 @@ mod tests {
             "D:19981223195200-08'00'"
         )
     }
+
+    // Tests of UTF-16LE conversion functions.
+
+    const UTF16LE_SAMPLES: [&str; 6] = [
+        "Hello, world!",
+        "مرحبا بالعالم",
+        "नमस्ते दुनिया",
+        "你好，世界 𠜎𠜱𠝹",
+        "👋🌍 👩‍👩‍👧‍👦 🏳️‍🌈",
+        "e\u{0301} a\u{0308}\u{0323}",
+    ];
+
+    #[test]
+    fn test_utf16le_bytes_round_trip() {
+        for sample in UTF16LE_SAMPLES {
+            let bytes = get_pdfium_utf16le_bytes_from_str(sample);
+
+            assert_eq!(&bytes[bytes.len() - 2..], [0, 0]);
+            assert_eq!(
+                get_string_from_pdfium_utf16le_bytes(bytes).as_deref(),
+                Some(sample)
+            );
+        }
+    }
+
+    #[test]
+    fn test_utf16le_words_round_trip() {
+        for sample in UTF16LE_SAMPLES {
+            let words = get_pdfium_utf16le_words_from_str(sample);
+
+            assert_eq!(words.last(), Some(&0));
+            assert_eq!(
+                get_string_from_pdfium_utf16le_words(&words).as_deref(),
+                Some(sample)
+            );
+        }
+    }
+
+    #[test]
+    fn test_utf16le_surrogate_pairs() {
+        // U+1F600 GRINNING FACE is encoded as the surrogate pair D83D DE00.
+
+        assert_eq!(
+            get_pdfium_utf16le_bytes_from_str("😀"),
+            [0x3D, 0xD8, 0x00, 0xDE, 0, 0]
+        );
+
+        // An unpaired surrogate should be replaced rather than discarding the whole string.
+
+        assert_eq!(
+            get_string_from_pdfium_utf16le_words(&[0x41, 0xD83D, 0x42, 0]).as_deref(),
+            Some("A\u{FFFD}B")
+        );
+    }
+
+    #[test]
+    fn test_utf16le_empty_strings() {
+        assert_eq!(get_pdfium_utf16le_bytes_from_str(""), [0, 0]);
+        assert_eq!(get_string_from_pdfium_utf16le_bytes(vec![0, 0]), None);
+        assert_eq!(get_string_from_pdfium_utf16le_bytes(vec![]), None);
+        assert_eq!(get_string_from_pdfium_utf16le_words(&[0, 0, 0]), None);
+    }
 }